@@ -5,7 +5,7 @@ use crate::{
     tensor::AutodiffTensor,
 };
 use burn_tensor::{
-    backend::{AutodiffBackend, Backend},
+    backend::{AutodiffBackend, Backend, MemoryStats, RngState},
     ops::{BoolTensor, IntTensor, QuantizedTensor},
 };
 use core::marker::PhantomData;
@@ -47,9 +47,25 @@ impl<B: Backend, C: CheckpointStrategy> Backend for Autodiff<B, C> {
         B::seed(seed)
     }
 
+    fn rng_state() -> Option<RngState> {
+        B::rng_state()
+    }
+
+    fn set_rng_state(state: RngState) {
+        B::set_rng_state(state)
+    }
+
     fn sync(device: &B::Device) {
         B::sync(device)
     }
+
+    fn memory_stats(device: &B::Device) -> Option<MemoryStats> {
+        B::memory_stats(device)
+    }
+
+    fn set_memory_budget(device: &B::Device, budget: Option<u64>) {
+        B::set_memory_budget(device, budget)
+    }
 }
 
 impl<B: Backend, C: CheckpointStrategy> AutodiffBackend for Autodiff<B, C> {
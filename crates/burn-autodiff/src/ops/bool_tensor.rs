@@ -23,6 +23,10 @@ impl<B: Backend, C: CheckpointStrategy> BoolTensorOps<Self> for Autodiff<B, C> {
         B::bool_to_device(tensor, device)
     }
 
+    fn bool_to_device_async(tensor: BoolTensor<B>, device: &Device<B>) -> BoolTensor<B> {
+        B::bool_to_device_async(tensor, device)
+    }
+
     fn bool_device(tensor: &BoolTensor<B>) -> Device<B> {
         B::bool_device(tensor)
     }
@@ -0,0 +1,93 @@
+use super::{Backward, Ops, OpsKind};
+use crate::{
+    checkpoint::{base::Checkpointer, strategy::CheckpointStrategy},
+    grads::Gradients,
+    Autodiff,
+};
+use burn_tensor::{backend::Backend, ops::FloatTensor};
+
+/// A custom float operation with `N` inputs, defined outside of the [backend](Backend) trait.
+///
+/// This allows adding operations implemented with custom kernels, for instance using the tensor
+/// handles of the inner backend, without extending the backend trait. The forward pass is executed
+/// on the inner backend tensors and the backward pass computes the gradient of each input from the
+/// gradient of the output.
+///
+/// Use [custom_op](Autodiff::custom_op) to execute the operation with gradients tracking, or
+/// [forward](CustomOp::forward) directly when gradients aren't required.
+pub trait CustomOp<B: Backend, const N: usize>: Clone + Send + core::fmt::Debug + 'static {
+    /// Computes the output of the operation.
+    fn forward(&self, inputs: [FloatTensor<B>; N]) -> FloatTensor<B>;
+
+    /// Computes the gradient of each input from the inputs, the output and the gradient of the
+    /// output.
+    ///
+    /// Inputs that aren't differentiable can return `None`. The gradients of inputs that don't
+    /// require gradients are ignored.
+    fn backward(
+        &self,
+        inputs: [FloatTensor<B>; N],
+        output: FloatTensor<B>,
+        grad: FloatTensor<B>,
+    ) -> [Option<FloatTensor<B>>; N];
+}
+
+#[derive(new, Debug)]
+struct CustomOpBackward<O> {
+    op: O,
+}
+
+impl<B, O, const N: usize> Backward<B, N> for CustomOpBackward<O>
+where
+    B: Backend,
+    O: CustomOp<B, N>,
+{
+    type State = ([FloatTensor<B>; N], FloatTensor<B>);
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, N>,
+        grads: &mut Gradients,
+        _checkpointer: &mut Checkpointer,
+    ) {
+        let grad = grads.consume::<B>(&ops.node);
+        let (inputs, output) = ops.state;
+        let grads_inputs = self.op.backward(inputs, output, grad);
+
+        for (node, grad) in ops.parents.into_iter().zip(grads_inputs) {
+            if let (Some(node), Some(grad)) = (node, grad) {
+                grads.register::<B>(node.id, grad);
+            }
+        }
+    }
+}
+
+impl<B: Backend, C: CheckpointStrategy> Autodiff<B, C> {
+    /// Executes a [custom operation](CustomOp), registering its backward pass when any input
+    /// requires gradients.
+    ///
+    /// The inputs and the output are kept until the backward pass, since a custom operation
+    /// can't be recomputed during checkpointing.
+    pub fn custom_op<O, const N: usize>(op: O, inputs: [FloatTensor<Self>; N]) -> FloatTensor<Self>
+    where
+        O: CustomOp<B, N>,
+    {
+        let nodes = core::array::from_fn(|i| inputs[i].node.clone());
+        let backward = CustomOpBackward::new(op.clone());
+
+        match <CustomOpBackward<O> as Backward<B, N>>::prepare::<C>(backward, nodes)
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let inputs = inputs.map(|tensor| tensor.primitive);
+                let output = op.forward(inputs.clone());
+
+                prep.finish((inputs, output.clone()), output)
+            }
+            OpsKind::UnTracked(prep) => {
+                prep.finish(op.forward(inputs.map(|tensor| tensor.primitive)))
+            }
+        }
+    }
+}
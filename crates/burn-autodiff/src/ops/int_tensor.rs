@@ -19,6 +19,10 @@ impl<B: Backend, C: CheckpointStrategy> IntTensorOps<Self> for Autodiff<B, C> {
         B::int_to_device(tensor, device)
     }
 
+    fn int_to_device_async(tensor: IntTensor<B>, device: &Device<Self>) -> IntTensor<B> {
+        B::int_to_device_async(tensor, device)
+    }
+
     fn int_device(tensor: &IntTensor<B>) -> Device<Self> {
         B::int_device(tensor)
     }
@@ -349,6 +353,14 @@ impl<B: Backend, C: CheckpointStrategy> IntTensorOps<Self> for Autodiff<B, C> {
         B::int_argsort(tensor, dim, descending)
     }
 
+    fn int_kthvalue_with_indices(
+        tensor: IntTensor<Self>,
+        k: usize,
+        dim: usize,
+    ) -> (IntTensor<Self>, IntTensor<Self>) {
+        B::int_kthvalue_with_indices(tensor, k, dim)
+    }
+
     fn bitwise_and(lhs: IntTensor<Self>, rhs: IntTensor<Self>) -> IntTensor<Self> {
         B::bitwise_and(lhs, rhs)
     }
@@ -2,6 +2,7 @@ mod activation;
 mod backward;
 mod base;
 mod bool_tensor;
+mod custom;
 mod int_tensor;
 mod module;
 mod qtensor;
@@ -13,3 +14,4 @@ pub(crate) mod sort;
 
 pub use backward::*;
 pub use base::*;
+pub use custom::*;
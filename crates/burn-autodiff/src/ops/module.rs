@@ -1524,6 +1524,176 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
     ) -> MaxPool2dBackward<Self> {
         panic!("Can't differentiate max pool2d with indices backward.");
     }
+
+    fn avg_pool3d(
+        x: AutodiffTensor<B>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        count_include_pad: bool,
+    ) -> AutodiffTensor<B> {
+        #[derive(Debug)]
+        struct AvgPool3D;
+
+        impl<B: Backend> Backward<B, 1> for AvgPool3D {
+            type State = (NodeID, [usize; 3], [usize; 3], [usize; 3], bool);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let [node_parent] = ops.parents;
+                let grad = grads.consume::<B>(&ops.node);
+                let (x_state, kernel_size, stride, padding, count_include_pad) = ops.state;
+                let x = checkpointer.retrieve_node_output(x_state);
+
+                if let Some(node) = node_parent {
+                    let grad = B::avg_pool3d_backward(
+                        x,
+                        grad,
+                        kernel_size,
+                        stride,
+                        padding,
+                        count_include_pad,
+                    );
+                    grads.register::<B>(node.id, grad);
+                }
+            }
+        }
+
+        match AvgPool3D
+            .prepare::<C>([x.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let x_state = prep.checkpoint(&x);
+                prep.finish(
+                    (x_state, kernel_size, stride, padding, count_include_pad),
+                    B::avg_pool3d(
+                        x.primitive.clone(),
+                        kernel_size,
+                        stride,
+                        padding,
+                        count_include_pad,
+                    ),
+                )
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::avg_pool3d(
+                x.primitive,
+                kernel_size,
+                stride,
+                padding,
+                count_include_pad,
+            )),
+        }
+    }
+
+    fn avg_pool3d_backward(
+        _x: AutodiffTensor<B>,
+        _grad: AutodiffTensor<B>,
+        _kernel_size: [usize; 3],
+        _stride: [usize; 3],
+        _padding: [usize; 3],
+        _count_include_pad: bool,
+    ) -> AutodiffTensor<B> {
+        panic!("Can't differentiate avg pool 3d backward.");
+    }
+
+    fn max_pool3d(
+        x: AutodiffTensor<B>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+    ) -> AutodiffTensor<B> {
+        match MaxPool3D
+            .prepare::<C>([x.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let x_state = prep.checkpoint(&x);
+                let output =
+                    B::max_pool3d_with_indices(x.primitive, kernel_size, stride, padding, dilation);
+                prep.finish(
+                    (
+                        x_state,
+                        output.indices,
+                        kernel_size,
+                        stride,
+                        padding,
+                        dilation,
+                    ),
+                    output.output,
+                )
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::max_pool3d(
+                x.primitive,
+                kernel_size,
+                stride,
+                padding,
+                dilation,
+            )),
+        }
+    }
+
+    fn max_pool3d_with_indices(
+        x: AutodiffTensor<B>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+    ) -> MaxPool3dWithIndices<Self> {
+        match MaxPool3D
+            .prepare::<C>([x.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let x_state = prep.checkpoint(&x);
+
+                let output =
+                    B::max_pool3d_with_indices(x.primitive, kernel_size, stride, padding, dilation);
+
+                let output_tensor = prep.finish(
+                    (
+                        x_state,
+                        output.indices.clone(),
+                        kernel_size,
+                        stride,
+                        padding,
+                        dilation,
+                    ),
+                    output.output,
+                );
+
+                MaxPool3dWithIndices::new(output_tensor, output.indices)
+            }
+            OpsKind::UnTracked(prep) => {
+                let output =
+                    B::max_pool3d_with_indices(x.primitive, kernel_size, stride, padding, dilation);
+                let output_tensor = prep.finish(output.output);
+
+                MaxPool3dWithIndices::new(output_tensor, output.indices)
+            }
+        }
+    }
+
+    fn max_pool3d_with_indices_backward(
+        _x: AutodiffTensor<B>,
+        _kernel_size: [usize; 3],
+        _stride: [usize; 3],
+        _padding: [usize; 3],
+        _dilation: [usize; 3],
+        _output_grad: AutodiffTensor<B>,
+        _indices: IntTensor<B>,
+    ) -> MaxPool3dBackward<Self> {
+        panic!("Can't differentiate max pool3d with indices backward.");
+    }
+
     fn adaptive_avg_pool1d(x: AutodiffTensor<B>, output_size: usize) -> AutodiffTensor<B> {
         #[derive(Debug)]
         struct AdaptiveAvgPool1D;
@@ -1662,6 +1832,186 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
     ) -> <Autodiff<B> as Backend>::FloatTensorPrimitive {
         panic!("Can't differentiate interpolate backward.");
     }
+
+    fn rms_norm(x: AutodiffTensor<B>, gamma: AutodiffTensor<B>, epsilon: f64) -> AutodiffTensor<B> {
+        #[derive(Debug)]
+        struct RmsNorm;
+
+        impl<B: Backend> Backward<B, 2> for RmsNorm {
+            type State = (NodeID, NodeID, f64);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 2>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let [node_x, node_gamma] = ops.parents;
+                let grad = grads.consume::<B>(&ops.node);
+
+                let (x_state, gamma_state, epsilon) = ops.state;
+                let x = checkpointer.retrieve_node_output::<B::FloatTensorPrimitive>(x_state);
+                let gamma =
+                    checkpointer.retrieve_node_output::<B::FloatTensorPrimitive>(gamma_state);
+
+                let backward = B::rms_norm_backward(x, gamma, grad, epsilon);
+
+                if let Some(node) = node_x {
+                    grads.register::<B>(node.id, backward.x_grad)
+                }
+                if let Some(node) = node_gamma {
+                    grads.register::<B>(node.id, backward.gamma_grad)
+                }
+            }
+        }
+
+        match RmsNorm
+            .prepare::<C>([x.node.clone(), gamma.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let x_state = prep.checkpoint(&x);
+                let gamma_state = prep.checkpoint(&gamma);
+                prep.finish(
+                    (x_state, gamma_state, epsilon),
+                    B::rms_norm(x.primitive, gamma.primitive, epsilon),
+                )
+            }
+            OpsKind::UnTracked(prep) => {
+                prep.finish(B::rms_norm(x.primitive, gamma.primitive, epsilon))
+            }
+        }
+    }
+
+    fn rms_norm_backward(
+        _x: AutodiffTensor<B>,
+        _gamma: AutodiffTensor<B>,
+        _output_grad: AutodiffTensor<B>,
+        _epsilon: f64,
+    ) -> RmsNormBackward<Self> {
+        panic!("Can't differentiate rms norm backward.");
+    }
+
+    fn layer_norm(
+        x: AutodiffTensor<B>,
+        gamma: AutodiffTensor<B>,
+        beta: Option<AutodiffTensor<B>>,
+        epsilon: f64,
+    ) -> AutodiffTensor<B> {
+        #[derive(Debug)]
+        struct LayerNormWithBeta;
+        #[derive(Debug)]
+        struct LayerNormNoBeta;
+
+        impl<B: Backend> Backward<B, 3> for LayerNormWithBeta {
+            type State = (NodeID, NodeID, f64);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 3>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let [node_x, node_gamma, node_beta] = ops.parents;
+                let grad = grads.consume::<B>(&ops.node);
+
+                let (x_state, gamma_state, epsilon) = ops.state;
+                let x = checkpointer.retrieve_node_output::<B::FloatTensorPrimitive>(x_state);
+                let gamma =
+                    checkpointer.retrieve_node_output::<B::FloatTensorPrimitive>(gamma_state);
+
+                let backward = B::layer_norm_backward(x, gamma, grad, epsilon);
+
+                if let Some(node) = node_x {
+                    grads.register::<B>(node.id, backward.x_grad)
+                }
+                if let Some(node) = node_gamma {
+                    grads.register::<B>(node.id, backward.gamma_grad)
+                }
+                if let Some(node) = node_beta {
+                    grads.register::<B>(node.id, backward.beta_grad)
+                }
+            }
+        }
+
+        impl<B: Backend> Backward<B, 2> for LayerNormNoBeta {
+            type State = (NodeID, NodeID, f64);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 2>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let [node_x, node_gamma] = ops.parents;
+                let grad = grads.consume::<B>(&ops.node);
+
+                let (x_state, gamma_state, epsilon) = ops.state;
+                let x = checkpointer.retrieve_node_output::<B::FloatTensorPrimitive>(x_state);
+                let gamma =
+                    checkpointer.retrieve_node_output::<B::FloatTensorPrimitive>(gamma_state);
+
+                let backward = B::layer_norm_backward(x, gamma, grad, epsilon);
+
+                if let Some(node) = node_x {
+                    grads.register::<B>(node.id, backward.x_grad)
+                }
+                if let Some(node) = node_gamma {
+                    grads.register::<B>(node.id, backward.gamma_grad)
+                }
+            }
+        }
+
+        match beta {
+            Some(beta) => match LayerNormWithBeta
+                .prepare::<C>([x.node.clone(), gamma.node.clone(), beta.node.clone()])
+                .compute_bound()
+                .stateful()
+            {
+                OpsKind::Tracked(mut prep) => {
+                    let x_state = prep.checkpoint(&x);
+                    let gamma_state = prep.checkpoint(&gamma);
+                    prep.finish(
+                        (x_state, gamma_state, epsilon),
+                        B::layer_norm(x.primitive, gamma.primitive, Some(beta.primitive), epsilon),
+                    )
+                }
+                OpsKind::UnTracked(prep) => prep.finish(B::layer_norm(
+                    x.primitive,
+                    gamma.primitive,
+                    Some(beta.primitive),
+                    epsilon,
+                )),
+            },
+            None => match LayerNormNoBeta
+                .prepare::<C>([x.node.clone(), gamma.node.clone()])
+                .compute_bound()
+                .stateful()
+            {
+                OpsKind::Tracked(mut prep) => {
+                    let x_state = prep.checkpoint(&x);
+                    let gamma_state = prep.checkpoint(&gamma);
+                    prep.finish(
+                        (x_state, gamma_state, epsilon),
+                        B::layer_norm(x.primitive, gamma.primitive, None, epsilon),
+                    )
+                }
+                OpsKind::UnTracked(prep) => {
+                    prep.finish(B::layer_norm(x.primitive, gamma.primitive, None, epsilon))
+                }
+            },
+        }
+    }
+
+    fn layer_norm_backward(
+        _x: AutodiffTensor<B>,
+        _gamma: AutodiffTensor<B>,
+        _output_grad: AutodiffTensor<B>,
+        _epsilon: f64,
+    ) -> LayerNormBackward<Self> {
+        panic!("Can't differentiate layer norm backward.");
+    }
 }
 
 #[derive(Debug)]
@@ -1736,3 +2086,43 @@ impl<B: Backend> Backward<B, 1> for MaxPool2D {
         }
     }
 }
+
+#[derive(Debug)]
+struct MaxPool3D;
+
+impl<B: Backend> Backward<B, 1> for MaxPool3D {
+    type State = (
+        NodeID,
+        IntTensor<B>,
+        [usize; 3],
+        [usize; 3],
+        [usize; 3],
+        [usize; 3],
+    );
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 1>,
+        grads: &mut Gradients,
+        checkpointer: &mut Checkpointer,
+    ) {
+        let [node_parent] = ops.parents;
+        let grad = grads.consume::<B>(&ops.node);
+        let (x_state, indices, kernel_size, stride, padding, dilation) = ops.state;
+        let x = checkpointer.retrieve_node_output(x_state);
+
+        if let Some(node) = node_parent {
+            let grad = B::max_pool3d_with_indices_backward(
+                x,
+                kernel_size,
+                stride,
+                padding,
+                dilation,
+                grad,
+                indices,
+            );
+
+            grads.register::<B>(node.id, grad.x_grad);
+        }
+    }
+}
@@ -18,7 +18,8 @@ use crate::{
 use burn_tensor::{
     backend::Backend,
     ops::{BoolTensor, FloatElem, FloatTensor, FloatTensorOps, IntTensor},
-    Device, ElementConversion, Shape, TensorData, TensorMetadata,
+    Device, ElementConversion, ScatterReduceOptions, ScatterReduction, Shape, TensorData,
+    TensorMetadata,
 };
 
 use super::maxmin::MaxMinDim;
@@ -50,6 +51,93 @@ fn unsqueeze_like<B: Backend>(
     B::float_reshape(tensor, Shape::from(dims))
 }
 
+/// The partial derivatives of [scatter_reduce](FloatTensorOps::float_scatter_reduce) with respect
+/// to each element of the input tensor and of the values.
+///
+/// The gradient of the maximum and the minimum is split evenly between the elements equal to the
+/// output, and the gradient of the values of the product is non-finite when they are zero.
+fn scatter_reduce_weights<B: Backend>(
+    dim: usize,
+    tensor: B::FloatTensorPrimitive,
+    indices: IntTensor<B>,
+    values: B::FloatTensorPrimitive,
+    options: ScatterReduceOptions,
+) -> (B::FloatTensorPrimitive, B::FloatTensorPrimitive) {
+    let device = B::float_device(&tensor);
+    let shape = tensor.shape();
+    let shape_values = values.shape();
+
+    // The number of values scattered to each element.
+    let counts = B::float_scatter(
+        dim,
+        B::float_zeros(shape.clone(), &device),
+        indices.clone(),
+        B::float_ones(shape_values.clone(), &device),
+    );
+    // The elements of the input tensor taking part in the reduction.
+    let included = match options.include_self {
+        true => B::float_ones(shape.clone(), &device),
+        false => B::bool_into_float(B::float_equal_elem(counts.clone(), 0.0.elem())),
+    };
+
+    match options.reduction {
+        ScatterReduction::Sum => (included, B::float_ones(shape_values, &device)),
+        ScatterReduction::Mean => {
+            let scale = B::float_recip(B::float_add(counts, included.clone()));
+
+            (
+                B::float_mul(included, scale.clone()),
+                B::float_gather(dim, scale, indices),
+            )
+        }
+        ScatterReduction::Prod => {
+            let output =
+                B::float_scatter_reduce(dim, tensor, indices.clone(), values.clone(), options);
+            let product = B::float_scatter_reduce(
+                dim,
+                B::float_ones(shape, &device),
+                indices.clone(),
+                values.clone(),
+                ScatterReduceOptions::new(ScatterReduction::Prod),
+            );
+
+            (
+                B::float_mul(included, product),
+                B::float_div(B::float_gather(dim, output, indices), values),
+            )
+        }
+        ScatterReduction::Max | ScatterReduction::Min => {
+            let output = B::float_scatter_reduce(
+                dim,
+                tensor.clone(),
+                indices.clone(),
+                values.clone(),
+                options,
+            );
+            let selected_lhs = B::float_mul(
+                included,
+                B::bool_into_float(B::float_equal(tensor, output.clone())),
+            );
+            let selected_rhs = B::bool_into_float(B::float_equal(
+                values,
+                B::float_gather(dim, output, indices.clone()),
+            ));
+            let ties = B::float_scatter(
+                dim,
+                selected_lhs.clone(),
+                indices.clone(),
+                selected_rhs.clone(),
+            );
+            let scale = B::float_recip(B::float_clamp_min(ties, 1.0.elem()));
+
+            (
+                B::float_mul(selected_lhs, scale.clone()),
+                B::float_mul(selected_rhs, B::float_gather(dim, scale, indices)),
+            )
+        }
+    }
+}
+
 impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C> {
     fn float_from_data(data: TensorData, device: &Device<Self>) -> FloatTensor<Self> {
         AutodiffTensor::new(B::float_from_data(data, device))
@@ -75,6 +163,13 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         B::float_into_data(tensor.primitive).await
     }
 
+    fn float_into_data_slice(
+        tensor: FloatTensor<Self>,
+        ranges: Vec<std::ops::Range<usize>>,
+    ) -> impl std::future::Future<Output = TensorData> + 'static + Send {
+        B::float_into_data_slice(tensor.primitive, ranges)
+    }
+
     fn float_device(tensor: &FloatTensor<Self>) -> Device<Self> {
         B::float_device(&tensor.primitive)
     }
@@ -990,6 +1085,71 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         }
     }
 
+    fn float_scatter_reduce(
+        dim: usize,
+        tensor: FloatTensor<Self>,
+        indices: IntTensor<B>,
+        values: FloatTensor<Self>,
+        options: ScatterReduceOptions,
+    ) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct ScatterReduce;
+
+        impl<B: Backend> Backward<B, 2> for ScatterReduce {
+            type State = (usize, IntTensor<B>, NodeID, NodeID, ScatterReduceOptions);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 2>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let (dim, indices, tensor, values, options) = ops.state;
+                let tensor = checkpointer.retrieve_node_output(tensor);
+                let values = checkpointer.retrieve_node_output(values);
+                let (weights_lhs, weights_rhs) =
+                    scatter_reduce_weights::<B>(dim, tensor, indices.clone(), values, options);
+
+                binary::<B, _, _>(
+                    ops.parents,
+                    ops.node,
+                    grads,
+                    |grad| B::float_mul(grad, weights_lhs),
+                    |grad| B::float_mul(B::float_gather(dim, grad, indices), weights_rhs),
+                );
+            }
+        }
+
+        match ScatterReduce
+            .prepare::<C>([tensor.node.clone(), values.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let tensor_state = prep.checkpoint(&tensor);
+                let values_state = prep.checkpoint(&values);
+
+                prep.finish(
+                    (dim, indices.clone(), tensor_state, values_state, options),
+                    B::float_scatter_reduce(
+                        dim,
+                        tensor.primitive,
+                        indices,
+                        values.primitive,
+                        options,
+                    ),
+                )
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_scatter_reduce(
+                dim,
+                tensor.primitive,
+                indices,
+                values.primitive,
+                options,
+            )),
+        }
+    }
+
     fn float_select(
         tensor: FloatTensor<Self>,
         dim: usize,
@@ -1425,6 +1585,10 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         matches!(tensor.node.requirement, Requirement::Grad)
     }
 
+    fn float_is_external(tensor: &FloatTensor<Self>) -> bool {
+        B::float_is_external(&tensor.primitive)
+    }
+
     fn float_mean(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
         #[derive(Debug)]
         struct Mean;
@@ -2538,7 +2702,34 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
     }
 
     fn float_cast(tensor: FloatTensor<Self>, dtype: burn_tensor::FloatDType) -> FloatTensor<Self> {
-        AutodiffTensor::new(B::float_cast(tensor.primitive, dtype))
+        #[derive(Debug)]
+        struct Cast;
+
+        impl<B: Backend> Backward<B, 1> for Cast {
+            type State = burn_tensor::FloatDType;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                // The gradient has the dtype of the input, so parameters stored with a different
+                // precision than the computation receive gradients in their own precision.
+                let dtype = ops.state;
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    B::float_cast(grad, dtype)
+                });
+            }
+        }
+
+        match Cast.prepare::<C>([tensor.node]).compute_bound().stateful() {
+            OpsKind::Tracked(prep) => {
+                let dtype_old = tensor.primitive.dtype().into();
+                prep.finish(dtype_old, B::float_cast(tensor.primitive, dtype))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_cast(tensor.primitive, dtype)),
+        }
     }
 
     // TODO: Implement float_prod and float_sum
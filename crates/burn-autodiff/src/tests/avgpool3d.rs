@@ -0,0 +1,111 @@
+#[burn_tensor_testgen::testgen(ad_avg_pool3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::avg_pool3d;
+    use burn_tensor::Shape;
+
+    #[test]
+    fn test_avg_pool3d_include_pad() {
+        let test = AvgPool3dTestCase {
+            kernel_size: [2, 2, 3],
+            padding: [1, 0, 1],
+            stride: [1, 1, 1],
+            depth: 3,
+            height: 3,
+            width: 4,
+            count_include_pad: true,
+        };
+
+        test.assert_output(TestTensor::from_floats(
+            [[[
+                [
+                    [0.3333, 0.5000, 0.5000, 0.3333],
+                    [0.6667, 1.0000, 1.0000, 0.6667],
+                    [0.3333, 0.5000, 0.5000, 0.3333],
+                ],
+                [
+                    [0.3333, 0.5000, 0.5000, 0.3333],
+                    [0.6667, 1.0000, 1.0000, 0.6667],
+                    [0.3333, 0.5000, 0.5000, 0.3333],
+                ],
+                [
+                    [0.3333, 0.5000, 0.5000, 0.3333],
+                    [0.6667, 1.0000, 1.0000, 0.6667],
+                    [0.3333, 0.5000, 0.5000, 0.3333],
+                ],
+            ]]],
+            &Default::default(),
+        ));
+    }
+
+    #[test]
+    fn test_avg_pool3d_dont_include_pad() {
+        let test = AvgPool3dTestCase {
+            kernel_size: [2, 2, 3],
+            padding: [1, 0, 1],
+            stride: [1, 1, 1],
+            depth: 3,
+            height: 3,
+            width: 4,
+            count_include_pad: false,
+        };
+
+        test.assert_output(TestTensor::from_floats(
+            [[[
+                [
+                    [0.6250, 0.8750, 0.8750, 0.6250],
+                    [1.2500, 1.7500, 1.7500, 1.2500],
+                    [0.6250, 0.8750, 0.8750, 0.6250],
+                ],
+                [
+                    [0.4167, 0.5833, 0.5833, 0.4167],
+                    [0.8333, 1.1667, 1.1667, 0.8333],
+                    [0.4167, 0.5833, 0.5833, 0.4167],
+                ],
+                [
+                    [0.6250, 0.8750, 0.8750, 0.6250],
+                    [1.2500, 1.7500, 1.7500, 1.2500],
+                    [0.6250, 0.8750, 0.8750, 0.6250],
+                ],
+            ]]],
+            &Default::default(),
+        ));
+    }
+
+    struct AvgPool3dTestCase {
+        kernel_size: [usize; 3],
+        padding: [usize; 3],
+        stride: [usize; 3],
+        depth: usize,
+        height: usize,
+        width: usize,
+        count_include_pad: bool,
+    }
+
+    impl AvgPool3dTestCase {
+        fn assert_output(self, x_grad: TestTensor<5>) {
+            let shape_x = Shape::new([1, 1, self.depth, self.height, self.width]);
+            let device = Default::default();
+            let x = TestAutodiffTensor::from_data(
+                TestTensorInt::arange(0..shape_x.num_elements() as i64, &device)
+                    .reshape::<5, _>(shape_x)
+                    .into_data(),
+                &device,
+            )
+            .require_grad();
+            let output = avg_pool3d(
+                x.clone(),
+                self.kernel_size,
+                self.stride,
+                self.padding,
+                self.count_include_pad,
+            );
+            let grads = output.backward();
+            let x_grad_actual = x.grad(&grads).unwrap();
+
+            x_grad
+                .to_data()
+                .assert_approx_eq(&x_grad_actual.into_data(), 3);
+        }
+    }
+}
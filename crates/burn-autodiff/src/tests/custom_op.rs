@@ -0,0 +1,80 @@
+#[burn_tensor_testgen::testgen(ad_custom_op)]
+mod tests {
+    use super::*;
+    use burn_autodiff::ops::CustomOp;
+    use burn_tensor::{backend::Backend, ops::FloatTensor, Tensor, TensorData, TensorPrimitive};
+
+    #[derive(Clone, Debug)]
+    struct MulOp;
+
+    impl<B: Backend> CustomOp<B, 2> for MulOp {
+        fn forward(&self, [lhs, rhs]: [FloatTensor<B>; 2]) -> FloatTensor<B> {
+            B::float_mul(lhs, rhs)
+        }
+
+        fn backward(
+            &self,
+            [lhs, rhs]: [FloatTensor<B>; 2],
+            _output: FloatTensor<B>,
+            grad: FloatTensor<B>,
+        ) -> [Option<FloatTensor<B>>; 2] {
+            [
+                Some(B::float_mul(grad.clone(), rhs)),
+                Some(B::float_mul(grad, lhs)),
+            ]
+        }
+    }
+
+    fn custom_mul<const D: usize>(
+        lhs: TestAutodiffTensor<D>,
+        rhs: TestAutodiffTensor<D>,
+    ) -> TestAutodiffTensor<D> {
+        let output = TestAutodiffBackend::custom_op(
+            MulOp,
+            [lhs.into_primitive().tensor(), rhs.into_primitive().tensor()],
+        );
+
+        Tensor::from_primitive(TensorPrimitive::Float(output))
+    }
+
+    #[test]
+    fn should_diff_custom_op() {
+        let data_1 = TensorData::from([1.0, 7.0]);
+        let data_2 = TensorData::from([4.0, 7.0]);
+
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::<1>::from_data(data_1.clone(), &device).require_grad();
+        let tensor_2 = TestAutodiffTensor::from_data(data_2.clone(), &device).require_grad();
+
+        let tensor_3 = custom_mul(tensor_1.clone(), tensor_2.clone());
+        let grads = tensor_3.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        grad_1.to_data().assert_eq(&data_2, false);
+        grad_2.to_data().assert_eq(&data_1, false);
+        tensor_3
+            .into_data()
+            .assert_eq(&TensorData::from([4.0, 49.0]), false);
+    }
+
+    #[test]
+    fn should_diff_custom_op_with_untracked_input() {
+        let data_1 = TensorData::from([1.0, 7.0]);
+        let data_2 = TensorData::from([4.0, 7.0]);
+
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::<1>::from_data(data_1, &device).require_grad();
+        let tensor_2 = TestAutodiffTensor::from_data(data_2.clone(), &device);
+
+        let tensor_3 = custom_mul(tensor_1.clone(), tensor_2.clone());
+        let grads = tensor_3.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads);
+
+        grad_1.to_data().assert_eq(&data_2, false);
+        assert!(grad_2.is_none());
+    }
+}
@@ -0,0 +1,21 @@
+#[burn_tensor_testgen::testgen(ad_fold4d)]
+mod tests {
+    use super::*;
+    use burn_tensor::{module::fold4d, ops::UnfoldOptions, TensorData};
+
+    #[test]
+    fn test_fold4d_grad_is_unfold() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::<3>::ones([1, 6, 2], &device).require_grad();
+        let options = UnfoldOptions::new([1, 2], [0, 1], [1, 2]);
+
+        let output = fold4d(x.clone(), [3, 4], [2, 3], options);
+        let grads = output.sum().backward();
+
+        // The blocks elements in the padding don't contribute to the output.
+        x.grad(&grads).unwrap().into_data().assert_approx_eq(
+            &TensorData::from([[[0., 0.], [1., 1.], [1., 1.], [0., 0.], [1., 1.], [1., 1.]]]),
+            3,
+        );
+    }
+}
@@ -0,0 +1,59 @@
+#[burn_tensor_testgen::testgen(ad_grid_sample)]
+mod tests {
+    use super::*;
+    use burn_tensor::{
+        module::{affine_grid, grid_sample},
+        ops::GridSampleOptions,
+        TensorData,
+    };
+
+    #[test]
+    fn test_grid_sample_bilinear_grad() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::from_data(
+            TensorData::from([[[[0.0, 1.0, 2.0], [3.0, 4.0, 5.0], [6.0, 7.0, 8.0]]]]),
+            &device,
+        )
+        .require_grad();
+        let grid = TestAutodiffTensor::from_data(TensorData::from([[[[0.5, 0.25]]]]), &device)
+            .require_grad();
+
+        let output = grid_sample(x.clone(), grid.clone(), GridSampleOptions::default());
+        let grads = output.sum().backward();
+
+        // The input is linear, so the grid gradient is its slope scaled by half the input size.
+        x.grad(&grads).unwrap().into_data().assert_approx_eq(
+            &TensorData::from([[[
+                [0.0, 0.0, 0.0],
+                [0.0, 0.15625, 0.46875],
+                [0.0, 0.09375, 0.28125],
+            ]]]),
+            3,
+        );
+        grid.grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[[[1.5, 4.5]]]]), 3);
+    }
+
+    #[test]
+    fn test_affine_grid_grad() {
+        let device = Default::default();
+        let theta = TestAutodiffTensor::from_data(
+            TensorData::from([[[1.0, 0.0, 0.5], [0.0, 2.0, 0.0]]]),
+            &device,
+        )
+        .require_grad();
+
+        let grid = affine_grid(theta.clone(), [1, 1, 2, 2], true);
+        let grads = grid.sum().backward();
+
+        // The normalized coordinates of the output sum to zero, the translations to the
+        // number of points.
+        theta
+            .grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[[0.0, 0.0, 4.0], [0.0, 0.0, 4.0]]]), 3);
+    }
+}
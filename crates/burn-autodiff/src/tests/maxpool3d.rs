@@ -0,0 +1,66 @@
+#[burn_tensor_testgen::testgen(ad_max_pool3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::max_pool3d;
+
+    #[test]
+    fn test_max_pool3d_overlapping_windows() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::from_floats(
+            [[[
+                [
+                    [0.0, 7.0, 14.0, 21.0],
+                    [28.0, 35.0, 42.0, 1.0],
+                    [8.0, 15.0, 22.0, 29.0],
+                    [36.0, 43.0, 2.0, 9.0],
+                ],
+                [
+                    [16.0, 23.0, 30.0, 37.0],
+                    [44.0, 3.0, 10.0, 17.0],
+                    [24.0, 31.0, 38.0, 45.0],
+                    [4.0, 11.0, 18.0, 25.0],
+                ],
+                [
+                    [32.0, 39.0, 46.0, 5.0],
+                    [12.0, 19.0, 26.0, 33.0],
+                    [40.0, 47.0, 6.0, 13.0],
+                    [20.0, 27.0, 34.0, 41.0],
+                ],
+            ]]],
+            &device,
+        )
+        .require_grad();
+        let x_grad_expected = TestAutodiffTensor::<5>::from_floats(
+            [[[
+                [
+                    [0.0, 0.0, 0.0, 0.0],
+                    [0.0, 2.0, 3.0, 0.0],
+                    [0.0, 0.0, 0.0, 0.0],
+                    [0.0, 5.0, 0.0, 0.0],
+                ],
+                [
+                    [0.0, 0.0, 0.0, 0.0],
+                    [4.0, 0.0, 0.0, 0.0],
+                    [0.0, 0.0, 0.0, 3.0],
+                    [0.0, 0.0, 0.0, 0.0],
+                ],
+                [
+                    [0.0, 1.0, 2.0, 0.0],
+                    [0.0, 0.0, 0.0, 0.0],
+                    [0.0, 12.0, 0.0, 0.0],
+                    [0.0, 0.0, 0.0, 0.0],
+                ],
+            ]]],
+            &device,
+        );
+
+        let output = max_pool3d(x.clone(), [2, 3, 3], [1, 1, 2], [1, 1, 1], [1, 1, 1]);
+        let grads = output.backward();
+
+        // Asserts
+        let x_grad_actual = x.grad(&grads).unwrap();
+        x_grad_expected
+            .to_data()
+            .assert_approx_eq(&x_grad_actual.to_data(), 3);
+    }
+}
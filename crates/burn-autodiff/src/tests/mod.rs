@@ -7,9 +7,11 @@ mod add;
 mod aggregation;
 mod avgpool1d;
 mod avgpool2d;
+mod avgpool3d;
 mod backward;
 mod bridge;
 mod broadcast;
+mod cast;
 mod cat;
 mod ceil;
 mod checkpoint;
@@ -22,6 +24,7 @@ mod conv_transpose2d;
 mod conv_transpose3d;
 mod cos;
 mod cross_entropy;
+mod custom_op;
 mod deform_conv2d;
 mod div;
 mod erf;
@@ -29,9 +32,11 @@ mod exp;
 mod expand;
 mod flip;
 mod floor;
+mod fold4d;
 mod gather_scatter;
 mod gelu;
 mod gradients;
+mod grid_sample;
 mod log;
 mod log1p;
 mod log_sigmoid;
@@ -40,12 +45,14 @@ mod matmul;
 mod maxmin;
 mod maxpool1d;
 mod maxpool2d;
+mod maxpool3d;
 mod memory_management;
 mod mul;
 mod multithread;
 mod nearest_interpolate;
 mod neg;
 mod nonzero;
+mod norm;
 mod permute;
 mod pow;
 mod recip;
@@ -53,7 +60,10 @@ mod relu;
 mod remainder;
 mod repeat_dim;
 mod reshape;
+mod roi;
 mod round;
+mod scatter_reduce;
+mod segment;
 mod select;
 mod sigmoid;
 mod sign;
@@ -135,14 +145,20 @@ macro_rules! testgen_with_float_param {
         burn_autodiff::testgen_ad_conv_transpose1d!();
         burn_autodiff::testgen_ad_conv_transpose2d!();
         burn_autodiff::testgen_ad_conv_transpose3d!();
+        burn_autodiff::testgen_ad_fold4d!();
+        burn_autodiff::testgen_ad_grid_sample!();
+        burn_autodiff::testgen_ad_roi!();
         burn_autodiff::testgen_ad_max_pool1d!();
         burn_autodiff::testgen_ad_max_pool2d!();
+        burn_autodiff::testgen_ad_max_pool3d!();
         burn_autodiff::testgen_ad_avg_pool1d!();
         burn_autodiff::testgen_ad_avg_pool2d!();
+        burn_autodiff::testgen_ad_avg_pool3d!();
         burn_autodiff::testgen_ad_adaptive_avg_pool1d!();
         burn_autodiff::testgen_ad_adaptive_avg_pool2d!();
         burn_autodiff::testgen_module_backward!();
         burn_autodiff::testgen_ad_nearest_interpolate!();
+        burn_autodiff::testgen_ad_norm!();
 
         // Tensor
         burn_autodiff::testgen_ad_complex!();
@@ -150,15 +166,19 @@ macro_rules! testgen_with_float_param {
         burn_autodiff::testgen_ad_add!();
         burn_autodiff::testgen_ad_aggregation!();
         burn_autodiff::testgen_ad_maxmin!();
+        burn_autodiff::testgen_ad_cast!();
         burn_autodiff::testgen_ad_cat!();
         burn_autodiff::testgen_ad_cos!();
         burn_autodiff::testgen_ad_cross_entropy_loss!();
+        burn_autodiff::testgen_ad_custom_op!();
         burn_autodiff::testgen_ad_div!();
         burn_autodiff::testgen_ad_remainder!();
         burn_autodiff::testgen_ad_erf!();
         burn_autodiff::testgen_ad_exp!();
         burn_autodiff::testgen_ad_slice!();
         burn_autodiff::testgen_ad_gather_scatter!();
+        burn_autodiff::testgen_ad_scatter_reduce!();
+        burn_autodiff::testgen_ad_segment!();
         burn_autodiff::testgen_ad_select!();
         burn_autodiff::testgen_ad_log!();
         burn_autodiff::testgen_ad_log1p!();
@@ -0,0 +1,94 @@
+#[burn_tensor_testgen::testgen(ad_norm)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{layer_norm, rms_norm};
+    use burn_tensor::TensorData;
+
+    fn inputs() -> (
+        TestAutodiffTensor<3>,
+        TestAutodiffTensor<1>,
+        TestAutodiffTensor<1>,
+        TestAutodiffTensor<3>,
+    ) {
+        let device = Default::default();
+        let x = TestAutodiffTensor::from_data(
+            TensorData::from([
+                [[-0.5, 1.0, 2.0, 0.5], [3.0, -1.0, 0.0, 2.0]],
+                [[0.0, 0.3, 1.0, 1.0], [4.0, 2.0, -2.0, 0.0]],
+            ]),
+            &device,
+        );
+        let gamma = TestAutodiffTensor::from_data(TensorData::from([1.0, 0.5, 2.0, -1.0]), &device);
+        let beta = TestAutodiffTensor::from_data(TensorData::from([0.1, 0.2, -0.3, 0.0]), &device);
+        // Weights the output so the gradients aren't trivially zero.
+        let weights = TestAutodiffTensor::from_data(
+            TensorData::from([
+                [[1.0, -2.0, 0.5, 3.0], [0.0, 1.0, 2.0, -1.0]],
+                [[2.0, 1.0, -1.0, 0.5], [1.5, -0.5, 1.0, 2.0]],
+            ]),
+            &device,
+        );
+
+        (x, gamma, beta, weights)
+    }
+
+    #[test]
+    fn should_diff_rms_norm() {
+        let (x, gamma, _beta, weights) = inputs();
+        let x_1 = x.clone().require_grad();
+        let gamma_1 = gamma.clone().require_grad();
+        let x_2 = x.require_grad();
+        let gamma_2 = gamma.require_grad();
+
+        let output_1 = rms_norm(x_1.clone(), gamma_1.clone(), 1e-5);
+        let grads_1 = (output_1 * weights.clone()).sum().backward();
+
+        let rms = (x_2.clone().powf_scalar(2.0).mean_dim(2) + 1e-5).sqrt();
+        let output_2 = x_2.clone() / rms * gamma_2.clone().unsqueeze();
+        let grads_2 = (output_2 * weights).sum().backward();
+
+        x_1.grad(&grads_1)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&x_2.grad(&grads_2).unwrap().into_data(), 3);
+        gamma_1
+            .grad(&grads_1)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&gamma_2.grad(&grads_2).unwrap().into_data(), 3);
+    }
+
+    #[test]
+    fn should_diff_layer_norm() {
+        let (x, gamma, beta, weights) = inputs();
+        let x_1 = x.clone().require_grad();
+        let gamma_1 = gamma.clone().require_grad();
+        let beta_1 = beta.clone().require_grad();
+        let x_2 = x.require_grad();
+        let gamma_2 = gamma.require_grad();
+        let beta_2 = beta.require_grad();
+
+        let output_1 = layer_norm(x_1.clone(), gamma_1.clone(), Some(beta_1.clone()), 1e-5);
+        let grads_1 = (output_1 * weights.clone()).sum().backward();
+
+        let (var, mean) = x_2.clone().var_mean_bias(2);
+        let output_2 = (x_2.clone() - mean) / (var + 1e-5).sqrt() * gamma_2.clone().unsqueeze()
+            + beta_2.clone().unsqueeze();
+        let grads_2 = (output_2 * weights).sum().backward();
+
+        x_1.grad(&grads_1)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&x_2.grad(&grads_2).unwrap().into_data(), 3);
+        gamma_1
+            .grad(&grads_1)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&gamma_2.grad(&grads_2).unwrap().into_data(), 3);
+        beta_1
+            .grad(&grads_1)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&beta_2.grad(&grads_2).unwrap().into_data(), 3);
+    }
+}
@@ -0,0 +1,72 @@
+#[burn_tensor_testgen::testgen(ad_roi)]
+mod tests {
+    use super::*;
+    use burn_tensor::{
+        module::{roi_align, roi_pool},
+        ops::{RoiAlignOptions, RoiPoolOptions},
+        TensorData,
+    };
+
+    fn input() -> TestAutodiffTensor<4> {
+        TestAutodiffTensor::from_data(
+            TensorData::from([[[
+                [0.0, 1.0, 2.0, 3.0],
+                [4.0, 5.0, 6.0, 7.0],
+                [8.0, 9.0, 10.0, 11.0],
+                [12.0, 13.0, 14.0, 15.0],
+            ]]]),
+            &Default::default(),
+        )
+        .require_grad()
+    }
+
+    #[test]
+    fn test_roi_align_grad() {
+        let x = input();
+        let boxes = TestAutodiffTensor::from_data(
+            TensorData::from([[0.0, 0.0, 0.0, 3.0, 3.0]]),
+            &Default::default(),
+        );
+
+        let output = roi_align(
+            x.clone(),
+            boxes,
+            RoiAlignOptions::new([1, 1], 1.0, 1, false),
+        );
+        let grads = output.sum().backward();
+
+        // The single sampling point is at the center of the four middle pixels.
+        x.grad(&grads).unwrap().into_data().assert_approx_eq(
+            &TensorData::from([[[
+                [0.0, 0.0, 0.0, 0.0],
+                [0.0, 0.25, 0.25, 0.0],
+                [0.0, 0.25, 0.25, 0.0],
+                [0.0, 0.0, 0.0, 0.0],
+            ]]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_roi_pool_grad() {
+        let x = input();
+        let boxes = TestAutodiffTensor::from_data(
+            TensorData::from([[0.0, 0.0, 0.0, 3.0, 3.0]]),
+            &Default::default(),
+        );
+
+        let output = roi_pool(x.clone(), boxes, RoiPoolOptions::new([2, 2], 1.0));
+        let grads = output.sum().backward();
+
+        // The gradient flows to the maximum of each bin.
+        x.grad(&grads).unwrap().into_data().assert_approx_eq(
+            &TensorData::from([[[
+                [0.0, 0.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0, 1.0],
+                [0.0, 0.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0, 1.0],
+            ]]]),
+            3,
+        );
+    }
+}
@@ -0,0 +1,83 @@
+#[burn_tensor_testgen::testgen(ad_scatter_reduce)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Int, ScatterReduction, Tensor, TensorData};
+
+    fn input() -> (
+        TestAutodiffTensor<2>,
+        Tensor<TestAutodiffBackend, 2, Int>,
+        TestAutodiffTensor<2>,
+        TestAutodiffTensor<2>,
+    ) {
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::from_floats([[1.0, 2.0, 3.0]], &device).require_grad();
+        let indices = Tensor::<TestAutodiffBackend, 2, Int>::from_ints([[0, 0, 2]], &device);
+        let values = TestAutodiffTensor::from_floats([[4.0, 4.0, 1.0]], &device).require_grad();
+        let weights = TestAutodiffTensor::from_floats([[1.0, 2.0, 3.0]], &device);
+
+        (tensor, indices, values, weights)
+    }
+
+    #[test]
+    fn should_diff_scatter_reduce_mean() {
+        let (tensor, indices, values, weights) = input();
+
+        let output =
+            tensor
+                .clone()
+                .scatter_reduce(1, indices, values.clone(), ScatterReduction::Mean);
+        let grads = output.mul(weights).sum().backward();
+
+        let grad_tensor = tensor.grad(&grads).unwrap();
+        let grad_values = values.grad(&grads).unwrap();
+
+        grad_tensor
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[1.0 / 3.0, 2.0, 1.5]]), 3);
+        grad_values
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[1.0 / 3.0, 1.0 / 3.0, 1.5]]), 3);
+    }
+
+    #[test]
+    fn should_diff_scatter_reduce_max_splitting_ties() {
+        let (tensor, indices, values, weights) = input();
+
+        let output =
+            tensor
+                .clone()
+                .scatter_reduce(1, indices, values.clone(), ScatterReduction::Max);
+        let grads = output.mul(weights).sum().backward();
+
+        let grad_tensor = tensor.grad(&grads).unwrap();
+        let grad_values = values.grad(&grads).unwrap();
+
+        grad_tensor
+            .into_data()
+            .assert_eq(&TensorData::from([[0.0, 2.0, 3.0]]), false);
+        grad_values
+            .into_data()
+            .assert_eq(&TensorData::from([[0.5, 0.5, 0.0]]), false);
+    }
+
+    #[test]
+    fn should_diff_scatter_reduce_prod() {
+        let (tensor, indices, values, weights) = input();
+
+        let output =
+            tensor
+                .clone()
+                .scatter_reduce(1, indices, values.clone(), ScatterReduction::Prod);
+        let grads = output.mul(weights).sum().backward();
+
+        let grad_tensor = tensor.grad(&grads).unwrap();
+        let grad_values = values.grad(&grads).unwrap();
+
+        grad_tensor
+            .into_data()
+            .assert_eq(&TensorData::from([[16.0, 2.0, 3.0]]), false);
+        grad_values
+            .into_data()
+            .assert_eq(&TensorData::from([[4.0, 4.0, 9.0]]), false);
+    }
+}
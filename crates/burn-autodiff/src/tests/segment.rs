@@ -0,0 +1,45 @@
+#[burn_tensor_testgen::testgen(ad_segment)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Int, Tensor, TensorData};
+
+    #[test]
+    fn test_segment_sum_grad() {
+        let device = Default::default();
+        let tensor =
+            TestAutodiffTensor::<2>::from_floats([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]], &device)
+                .require_grad();
+        let weights = TestAutodiffTensor::<2>::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+        let ids = Tensor::<TestAutodiffBackend, 1, Int>::from_ints([0, 0, 1], &device);
+
+        let output = tensor.clone().segment_sum(ids, 2);
+        let grads = output.mul(weights).sum().backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+
+        grad.into_data().assert_eq(
+            &TensorData::from([[1.0, 2.0], [1.0, 2.0], [3.0, 4.0]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn test_unsorted_segment_max_grad() {
+        let device = Default::default();
+        let tensor =
+            TestAutodiffTensor::<2>::from_floats([[5.0, 6.0], [1.0, 2.0], [3.0, 4.0]], &device)
+                .require_grad();
+        let weights = TestAutodiffTensor::<2>::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+        let ids = Tensor::<TestAutodiffBackend, 1, Int>::from_ints([1, 0, 0], &device);
+
+        let output = tensor.clone().unsorted_segment_max(ids, 2);
+        let grads = output.mul(weights).sum().backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+
+        grad.into_data().assert_eq(
+            &TensorData::from([[3.0, 4.0], [0.0, 0.0], [1.0, 2.0]]),
+            false,
+        );
+    }
+}
@@ -1,17 +1,27 @@
 use std::marker::PhantomData;
+use std::sync::Mutex;
 
 use burn_tensor::{
-    backend::{Backend, DeviceId, DeviceOps},
+    backend::{Backend, DeviceId, DeviceOps, RngState},
     quantization::{QTensorPrimitive, QuantizationStrategy},
     Device,
 };
 use candle_core::{backend::BackendDevice, DeviceLocation};
+use rand::rngs::StdRng;
 
 use crate::{
     element::{CandleElement, FloatCandleElement, IntCandleElement},
     CandleQTensor, CandleTensor,
 };
 
+pub(crate) static RNG_STATE: Mutex<Option<RngState>> = Mutex::new(None);
+
+/// The generator of the next random operation.
+pub(crate) fn next_rng() -> StdRng {
+    let mut state = RNG_STATE.lock().unwrap();
+    state.get_or_insert_with(RngState::from_entropy).next_rng()
+}
+
 /// Tensor backend that uses the [candle](candle_core) crate for executing tensor operations.
 ///
 /// It is compatible with a wide range of hardware configurations, including CPUs and GPUs
@@ -182,8 +192,16 @@ impl<F: FloatCandleElement, I: IntCandleElement> Backend for Candle<F, I> {
     }
 
     fn seed(seed: u64) {
-        // TODO submit an issue at Candle
-        panic!("Manual seed not supported by Candle. ")
+        Self::set_rng_state(RngState::new(seed));
+    }
+
+    fn rng_state() -> Option<RngState> {
+        let mut state = RNG_STATE.lock().unwrap();
+        Some(*state.get_or_insert_with(RngState::from_entropy))
+    }
+
+    fn set_rng_state(state: RngState) {
+        *RNG_STATE.lock().unwrap() = Some(state);
     }
 
     fn sync(device: &Device<Self>) {
@@ -1,8 +1,9 @@
 use std::marker::PhantomData;
 
-use burn_tensor::{backend::Backend, Element, Shape, TensorData, TensorMetadata};
+use burn_tensor::{backend::Backend, Distribution, Element, Shape, TensorData, TensorMetadata};
 use candle_core::WithDType;
 use half::{bf16, f16};
+use rand::Rng;
 
 use crate::{
     element::{CandleElement, FloatCandleElement, IntCandleElement},
@@ -19,6 +20,45 @@ pub fn cat(tensors: Vec<CandleTensor>, dim: usize) -> CandleTensor {
 pub fn from_data<E: CandleElement>(data: TensorData, device: &CandleDevice) -> CandleTensor {
     CandleTensor::from_data::<E>(data, device.clone())
 }
+
+/// Take the generator of the next random operation, returning it when the tensor must be sampled
+/// on the host.
+///
+/// Candle can't seed the generator of the CPU and doesn't sample every distribution, so those
+/// tensors are sampled on the host with the seeded generator of the backend. Otherwise, the
+/// generator of the device is seeded from it.
+pub fn random_rng(
+    distribution: &Distribution,
+    device: &candle_core::Device,
+) -> Option<rand::rngs::StdRng> {
+    let mut rng = crate::backend::next_rng();
+
+    if device.is_cpu()
+        || matches!(
+            distribution,
+            Distribution::Gamma(..) | Distribution::Poisson(_)
+        )
+    {
+        return Some(rng);
+    }
+
+    device.set_seed(rng.gen()).unwrap();
+    None
+}
+
+/// Sample a random tensor on the host and copy it to the device.
+pub fn random_host<E: CandleElement>(
+    shape: Vec<usize>,
+    distribution: Distribution,
+    rng: &mut rand::rngs::StdRng,
+    device: &candle_core::Device,
+) -> CandleTensor {
+    let data = TensorData::random::<E, _, _>(shape, distribution, rng);
+    CandleTensor::new(
+        candle_core::Tensor::from_slice(data.as_slice::<E>().unwrap(), data.shape.clone(), device)
+            .unwrap(),
+    )
+}
 pub fn into_data(tensor: CandleTensor) -> TensorData {
     fn tensor_data_from_dtype<T: WithDType + Element>(tensor: &CandleTensor) -> TensorData {
         TensorData::new(
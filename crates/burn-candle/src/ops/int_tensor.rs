@@ -330,6 +330,15 @@ impl<F: FloatCandleElement, I: IntCandleElement> IntTensorOps<Self> for Candle<F
     ) -> IntTensor<Self> {
         let shape = shape.dims;
         let device = &(device.clone()).into();
+        if let Some(mut rng) = super::base::random_rng(&distribution, device) {
+            // Same range as the default distribution sampled by Candle.
+            let distribution = match distribution {
+                Distribution::Default => Distribution::Uniform(0.0, 255.0),
+                distribution => distribution,
+            };
+            return super::base::random_host::<I>(shape, distribution, &mut rng, device);
+        }
+
         match distribution {
             Distribution::Default => CandleTensor::new(
                 candle_core::Tensor::rand(0.elem::<F>(), 255.elem::<F>(), shape, device)
@@ -354,6 +363,22 @@ impl<F: FloatCandleElement, I: IntCandleElement> IntTensorOps<Self> for Candle<F
                 candle_core::Tensor::randn(mean.elem::<F>(), std.elem::<F>(), shape, device)
                     .unwrap(),
             ),
+            Distribution::Exponential(rate) => CandleTensor::new(
+                // Inverse transform sampling, `1 - u` is in (0, 1] so its log is finite.
+                candle_core::Tensor::rand(0.elem::<F>(), 1.elem::<F>(), shape, device)
+                    .unwrap()
+                    .affine(-1.0, 1.0)
+                    .unwrap()
+                    .log()
+                    .unwrap()
+                    .affine(-1.0 / rate, 0.0)
+                    .unwrap()
+                    .to_dtype(I::DTYPE)
+                    .unwrap(),
+            ),
+            Distribution::Gamma(_, _) | Distribution::Poisson(_) => {
+                unreachable!("Sampled on the host")
+            }
         }
     }
 
@@ -25,6 +25,10 @@ impl<F: FloatCandleElement, I: IntCandleElement> FloatTensorOps<Self> for Candle
     ) -> FloatTensor<Self> {
         let shape = shape.dims;
         let device = &(device.clone()).into();
+        if let Some(mut rng) = super::base::random_rng(&distribution, device) {
+            return super::base::random_host::<F>(shape, distribution, &mut rng, device);
+        }
+
         match distribution {
             Distribution::Default => CandleTensor::new(
                 candle_core::Tensor::rand(0.elem::<F>(), 1.elem::<F>(), shape, device)
@@ -49,6 +53,20 @@ impl<F: FloatCandleElement, I: IntCandleElement> FloatTensorOps<Self> for Candle
                 candle_core::Tensor::randn(mean.elem::<F>(), std.elem::<F>(), shape, device)
                     .unwrap(),
             ),
+            Distribution::Exponential(rate) => CandleTensor::new(
+                // Inverse transform sampling, `1 - u` is in (0, 1] so its log is finite.
+                candle_core::Tensor::rand(0.elem::<F>(), 1.elem::<F>(), shape, device)
+                    .unwrap()
+                    .affine(-1.0, 1.0)
+                    .unwrap()
+                    .log()
+                    .unwrap()
+                    .affine(-1.0 / rate, 0.0)
+                    .unwrap(),
+            ),
+            Distribution::Gamma(_, _) | Distribution::Poisson(_) => {
+                unreachable!("Sampled on the host")
+            }
         }
     }
 
@@ -64,6 +64,30 @@ mod tests {
         }
         assert_eq!(set.len(), EXPECTED_TOTAL_IDS);
     }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn stream_scope_test() {
+        let current = StreamId::current();
+
+        let (scoped, nested) = StreamId::scope(|| {
+            let scoped = StreamId::current();
+            let nested = StreamId::scope(StreamId::current);
+
+            assert_eq!(scoped, StreamId::current());
+            (scoped, nested)
+        });
+
+        assert_ne!(current, scoped);
+        assert_ne!(scoped, nested);
+        assert_eq!(current, StreamId::current());
+    }
+}
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    /// The id of the current [stream scope](StreamId::scope), if any.
+    static SCOPE: core::cell::Cell<Option<u64>> = const { core::cell::Cell::new(None) };
 }
 
 /// Unique identifier that can represent a stream based on the current thread id.
@@ -74,16 +98,46 @@ pub struct StreamId {
 }
 
 impl StreamId {
-    /// Get the current thread id.
+    /// Get the current stream id, which is the id of the current thread outside of a
+    /// [stream scope](StreamId::scope).
     pub fn current() -> Self {
         Self {
             #[cfg(feature = "std")]
-            value: Self::from_current_thread(),
+            value: SCOPE
+                .with(|scope| scope.get())
+                .unwrap_or_else(Self::from_current_thread),
             #[cfg(not(feature = "std"))]
             value: 0,
         }
     }
 
+    /// Execute the function with a new stream id.
+    ///
+    /// Backends keeping a queue of operations per stream id, like the fusion backend, register
+    /// the operations of the function on their own queue, which is only flushed when one of its
+    /// tensors is read or used by another stream, or when the backend is synchronized. This
+    /// partitions the queues, it doesn't execute them concurrently on the device.
+    pub fn scope<F: FnOnce() -> O, O>(func: F) -> O {
+        #[cfg(feature = "std")]
+        {
+            struct Reset(Option<u64>);
+
+            impl Drop for Reset {
+                fn drop(&mut self) {
+                    SCOPE.with(|scope| scope.set(self.0));
+                }
+            }
+
+            let previous = SCOPE.with(|scope| scope.replace(Some(IdGenerator::generate())));
+            let _reset = Reset(previous);
+
+            func()
+        }
+
+        #[cfg(not(feature = "std"))]
+        func()
+    }
+
     #[cfg(feature = "std")]
     fn from_current_thread() -> u64 {
         use core::hash::Hash;
@@ -1,3 +1,5 @@
+use crate as burn;
+use crate::config::Config;
 pub use crate::data::dataset::{Dataset, DatasetIterator};
 use core::iter::Iterator;
 
@@ -11,19 +13,67 @@ pub struct Progress {
     pub items_total: usize,
 }
 
+/// The state of a data loader iteration, which can be used to resume an interrupted iteration.
+#[derive(Config, Debug, PartialEq)]
+pub struct DataLoaderState {
+    /// The seed used to shuffle the items during the iteration, if they are shuffled.
+    pub seed: Option<u64>,
+    /// The number of items that have been processed.
+    pub items_processed: usize,
+    /// The number of values drawn from the rng seeded by the [seed](Self::seed), so the random
+    /// operations of the iteration can be resumed where they stopped.
+    #[config(default = 0)]
+    pub rng_position: usize,
+    /// The state of each data loader partition, when the items are split between multiple
+    /// data loaders.
+    pub partitions: Vec<DataLoaderState>,
+}
+
 /// A data loader iterator that can be used to iterate over a data loader.
 pub trait DataLoaderIterator<O>: Iterator<Item = O> {
     /// Returns the progress of the data loader.
     fn progress(&self) -> Progress;
+    /// Returns the state of the iteration after the last returned item.
+    ///
+    /// The default implementation only records the number of processed items, which is enough
+    /// to resume an iteration returning the items in the same order every time.
+    fn state(&self) -> DataLoaderState {
+        DataLoaderState::new(self.progress().items_processed, Vec::new())
+    }
 }
 
 /// A data loader that can be used to iterate over a dataset.
 pub trait DataLoader<O>: Send {
     /// Returns a boxed [iterator](DataLoaderIterator) to iterate over the data loader.
     fn iter<'a>(&'a self) -> Box<dyn DataLoaderIterator<O> + 'a>;
+    /// Returns a boxed [iterator](DataLoaderIterator) resuming the iteration of the given
+    /// [state](DataLoaderState), returning the items that were not processed yet in the same
+    /// order.
+    ///
+    /// The default implementation iterates over the processed items again before returning the
+    /// remaining ones, and ignores the shuffling seed of the state.
+    fn iter_from<'a>(&'a self, state: &DataLoaderState) -> Box<dyn DataLoaderIterator<O> + 'a> {
+        let mut iterator = self.iter();
+        while iterator.progress().items_processed < state.items_processed {
+            if iterator.next().is_none() {
+                break;
+            }
+        }
+        iterator
+    }
     /// The number of items (not the number of batches nor the number of iterations),
     /// corresponding to the items_total of the progress returned by the iterator.
     fn num_items(&self) -> usize;
+    /// Returns a new data loader containing the items in the range `start..end`, or `None` when
+    /// the data loader can't be sliced.
+    ///
+    /// The items are never shuffled, so every iterator returns the same batches in the same
+    /// order.
+    ///
+    /// The default implementation returns `None`.
+    fn slice(&self, _start: usize, _end: usize) -> Option<Box<dyn DynDataLoader<O>>> {
+        None
+    }
 }
 
 /// A super trait for [dataloader](DataLoader) that allows it to be cloned dynamically.
@@ -34,6 +84,24 @@ pub trait DynDataLoader<O>: DataLoader<O> {
     fn clone_dyn(&self) -> Box<dyn DynDataLoader<O>>;
 }
 
+impl<O> DataLoader<O> for Box<dyn DynDataLoader<O>> {
+    fn iter<'a>(&'a self) -> Box<dyn DataLoaderIterator<O> + 'a> {
+        self.as_ref().iter()
+    }
+
+    fn iter_from<'a>(&'a self, state: &DataLoaderState) -> Box<dyn DataLoaderIterator<O> + 'a> {
+        self.as_ref().iter_from(state)
+    }
+
+    fn num_items(&self) -> usize {
+        self.as_ref().num_items()
+    }
+
+    fn slice(&self, start: usize, end: usize) -> Option<Box<dyn DynDataLoader<O>>> {
+        self.as_ref().slice(start, end)
+    }
+}
+
 impl<D, O> DynDataLoader<O> for D
 where
     D: DataLoader<O> + Clone + 'static,
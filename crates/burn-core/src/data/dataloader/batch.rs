@@ -1,6 +1,6 @@
 use super::{
-    batcher::DynBatcher, BatchStrategy, DataLoader, DataLoaderIterator, DynDataLoader,
-    MultiThreadDataLoader, Progress,
+    batcher::DynBatcher, BatchStrategy, DataLoader, DataLoaderIterator, DataLoaderState,
+    DynDataLoader, MultiThreadDataLoader, Progress,
 };
 use burn_dataset::{
     transform::{PartialDataset, ShuffledDataset},
@@ -60,6 +60,7 @@ impl<I, O> BatchDataLoader<I, O> {
 /// A data loader iterator that can be used to iterate over a data loader.
 struct BatchDataloaderIterator<I, O> {
     current_index: usize,
+    seed: Option<u64>,
     strategy: Box<dyn BatchStrategy<I>>,
     dataset: Arc<dyn Dataset<I>>,
     batcher: Box<dyn DynBatcher<I, O>>,
@@ -119,26 +120,66 @@ where
         // When starting a new iteration, we first check if the dataloader was created with an rng,
         // implying that we should shuffle the dataset beforehand, while advancing the current
         // rng to ensure that each new iteration shuffles the dataset differently.
-        let dataset = match &self.rng {
-            Some(rng) => {
-                let mut rng = rng.lock();
-
-                Arc::new(ShuffledDataset::with_seed(
-                    self.dataset.clone(),
-                    rng.sample(Standard),
-                ))
-            }
+        let seed = self.rng.as_ref().map(|rng| {
+            let mut rng = rng.lock();
+            let seed = rng.sample(Standard);
+            // The rng is re-seeded from the seed of the iteration, so the following iterations
+            // are the same when resuming from the state of any iteration.
+            *rng = StdRng::seed_from_u64(seed);
+            seed
+        });
+
+        Box::new(self.iter_with_seed(seed, 0))
+    }
+
+    fn iter_from<'a>(&'a self, state: &DataLoaderState) -> Box<dyn DataLoaderIterator<O> + 'a> {
+        if let (Some(rng), Some(seed)) = (&self.rng, state.seed) {
+            *rng.lock() = StdRng::seed_from_u64(seed);
+        }
+
+        Box::new(self.iter_with_seed(state.seed, state.items_processed))
+    }
+
+    fn num_items(&self) -> usize {
+        self.dataset.len()
+    }
+
+    fn slice(&self, start: usize, end: usize) -> Option<Box<dyn DynDataLoader<O>>> {
+        let end = usize::min(end, self.dataset.len());
+        let dataset = PartialDataset::new(self.dataset.clone(), start, end);
+
+        Some(Box::new(BatchDataLoader::new(
+            self.strategy.clone_dyn(),
+            Arc::new(dataset),
+            self.batcher.clone_dyn(),
+            None,
+        )))
+    }
+}
+
+impl<I, O> BatchDataLoader<I, O>
+where
+    I: Send + Sync + Clone + 'static,
+    O: Send + 'static,
+{
+    fn iter_with_seed(
+        &self,
+        seed: Option<u64>,
+        items_processed: usize,
+    ) -> BatchDataloaderIterator<I, O> {
+        let dataset: Arc<dyn Dataset<I>> = match seed {
+            Some(seed) => Arc::new(ShuffledDataset::with_seed(self.dataset.clone(), seed)),
             None => self.dataset.clone(),
         };
-        Box::new(BatchDataloaderIterator::new(
+
+        let mut iterator = BatchDataloaderIterator::new(
             self.strategy.clone_dyn(),
             dataset,
             self.batcher.clone_dyn(),
-        ))
-    }
-
-    fn num_items(&self) -> usize {
-        self.dataset.len()
+            seed,
+        );
+        iterator.current_index = items_processed;
+        iterator
     }
 }
 
@@ -150,6 +191,7 @@ impl<I, O> BatchDataloaderIterator<I, O> {
     /// * `strategy` - The batch strategy.
     /// * `dataset` - The dataset.
     /// * `batcher` - The batcher.
+    /// * `seed` - The seed used to shuffle the dataset, if it is shuffled.
     ///
     /// # Returns
     ///
@@ -158,9 +200,11 @@ impl<I, O> BatchDataloaderIterator<I, O> {
         strategy: Box<dyn BatchStrategy<I>>,
         dataset: Arc<dyn Dataset<I>>,
         batcher: Box<dyn DynBatcher<I, O>>,
+        seed: Option<u64>,
     ) -> Self {
         BatchDataloaderIterator {
             current_index: 0,
+            seed,
             strategy,
             dataset,
             batcher,
@@ -193,6 +237,10 @@ impl<I, O> DataLoaderIterator<O> for BatchDataloaderIterator<I, O> {
     fn progress(&self) -> Progress {
         Progress::new(self.current_index, self.dataset.len())
     }
+
+    fn state(&self) -> DataLoaderState {
+        DataLoaderState::new(self.current_index, Vec::new()).with_seed(self.seed)
+    }
 }
 
 #[cfg(test)]
@@ -266,4 +314,86 @@ mod tests {
 
         assert_eq!(items_single_thread, items_multi_thread);
     }
+
+    #[test]
+    fn test_slice_dataloader_returns_the_same_items() {
+        let batcher = Box::new(TestBatcher::new());
+        let dataset = Arc::new(FakeDataset::<String>::new(27));
+        let dataloader = BatchDataLoader::multi_thread(
+            Box::new(FixBatchStrategy::new(5)),
+            dataset.clone(),
+            batcher,
+            4,
+            Some(StdRng::seed_from_u64(42)),
+        );
+
+        let dataloader = dataloader.slice(4, 12).unwrap();
+        let expected = (4..12)
+            .map(|index| dataset.get(index).unwrap())
+            .collect::<HashSet<_>>();
+
+        assert_eq!(dataloader.num_items(), 8);
+        for _ in 0..2 {
+            let items = dataloader.iter().flatten().collect::<HashSet<_>>();
+            assert_eq!(items, expected);
+        }
+    }
+
+    #[test]
+    fn test_resume_dataloader_returns_the_remaining_batches() {
+        let batcher = Box::new(TestBatcher::new());
+        let dataset = Arc::new(FakeDataset::<String>::new(27));
+        let create_dataloader = || {
+            BatchDataLoader::new(
+                Box::new(FixBatchStrategy::new(5)),
+                dataset.clone(),
+                batcher.clone_dyn(),
+                Some(StdRng::seed_from_u64(42)),
+            )
+        };
+
+        let dataloader = create_dataloader();
+        let _ = dataloader.iter().count();
+        let mut iterator = dataloader.iter();
+        let _ = iterator.next();
+        let _ = iterator.next();
+        let state = iterator.state();
+        let remaining = iterator.collect::<Vec<_>>();
+        let next_epoch = dataloader.iter().collect::<Vec<_>>();
+
+        let dataloader = create_dataloader();
+        let remaining_resumed = dataloader.iter_from(&state).collect::<Vec<_>>();
+        let next_epoch_resumed = dataloader.iter().collect::<Vec<_>>();
+
+        assert_eq!(state.items_processed, 10);
+        assert_eq!(remaining_resumed, remaining);
+        assert_eq!(next_epoch_resumed, next_epoch);
+    }
+
+    #[test]
+    fn test_resume_multi_thread_dataloader_returns_the_remaining_items() {
+        let batcher = Box::new(TestBatcher::new());
+        let dataset = Arc::new(FakeDataset::<String>::new(27));
+        let dataloader = BatchDataLoader::multi_thread(
+            Box::new(FixBatchStrategy::new(2)),
+            dataset,
+            batcher,
+            4,
+            Some(StdRng::seed_from_u64(42)),
+        );
+
+        let mut iterator = dataloader.iter();
+        let processed = iterator.next().unwrap();
+        let state = iterator.state();
+        let remaining = iterator.flatten().collect::<HashSet<_>>();
+        let remaining_resumed = dataloader
+            .iter_from(&state)
+            .flatten()
+            .collect::<HashSet<_>>();
+
+        assert_eq!(state.items_processed, processed.len());
+        assert_eq!(state.partitions.len(), 4);
+        assert_eq!(remaining.len(), 27 - processed.len());
+        assert_eq!(remaining_resumed, remaining);
+    }
 }
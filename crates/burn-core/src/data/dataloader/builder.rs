@@ -1,4 +1,7 @@
-use super::{batcher::DynBatcher, BatchDataLoader, BatchStrategy, DataLoader, FixBatchStrategy};
+use super::{
+    batcher::DynBatcher, BatchDataLoader, BatchStrategy, BatchTransform, DataLoader, DynDataLoader,
+    FixBatchStrategy, TransformDataLoader,
+};
 use burn_dataset::Dataset;
 use rand::{rngs::StdRng, SeedableRng};
 use std::sync::Arc;
@@ -9,6 +12,7 @@ pub struct DataLoaderBuilder<I, O> {
     batcher: Box<dyn DynBatcher<I, O>>,
     num_threads: Option<usize>,
     shuffle: Option<u64>,
+    transform: Option<(Arc<dyn BatchTransform<O>>, u64)>,
 }
 
 impl<I, O> DataLoaderBuilder<I, O>
@@ -34,6 +38,7 @@ where
             strategy: None,
             num_threads: None,
             shuffle: None,
+            transform: None,
         }
     }
 
@@ -82,6 +87,28 @@ where
         self
     }
 
+    /// Sets a transformation applied on each batch by the thread iterating over the data loader.
+    ///
+    /// Unlike the batcher, which runs on the data loader workers, the transformation runs on the
+    /// training thread, so augmentations can be expressed as tensor operations executed on the
+    /// training device after the batch is uploaded.
+    ///
+    /// # Arguments
+    ///
+    /// * `transform` - The transformation.
+    /// * `seed` - The seed of the rng provided to the transformation.
+    ///
+    /// # Returns
+    ///
+    /// The data loader builder.
+    pub fn transform<T>(mut self, transform: T, seed: u64) -> Self
+    where
+        T: BatchTransform<O> + 'static,
+    {
+        self.transform = Some((Arc::new(transform), seed));
+        self
+    }
+
     /// Builds the data loader.
     ///
     /// # Arguments
@@ -102,16 +129,22 @@ where
             Some(strategy) => strategy,
             None => Box::new(FixBatchStrategy::new(1)),
         };
-        if let Some(num_threads) = self.num_threads {
-            return Arc::new(BatchDataLoader::multi_thread(
+        let dataloader: Box<dyn DynDataLoader<O>> = match self.num_threads {
+            Some(num_threads) => Box::new(BatchDataLoader::multi_thread(
                 strategy,
                 dataset,
                 self.batcher,
                 num_threads,
                 rng,
-            ));
-        }
+            )),
+            None => Box::new(BatchDataLoader::new(strategy, dataset, self.batcher, rng)),
+        };
 
-        Arc::new(BatchDataLoader::new(strategy, dataset, self.batcher, rng))
+        match self.transform {
+            Some((transform, seed)) => {
+                Arc::new(TransformDataLoader::new(dataloader, transform, seed))
+            }
+            None => Arc::new(dataloader),
+        }
     }
 }
@@ -3,6 +3,7 @@ mod batch;
 mod builder;
 mod multithread;
 mod strategy;
+mod transform;
 
 /// Module for batching items.
 pub mod batcher;
@@ -12,3 +13,4 @@ pub use batch::*;
 pub use builder::*;
 pub use multithread::*;
 pub use strategy::*;
+pub use transform::*;
@@ -1,4 +1,4 @@
-use super::{DataLoader, DataLoaderIterator, DynDataLoader, Progress};
+use super::{DataLoader, DataLoaderIterator, DataLoaderState, DynDataLoader, Progress};
 use std::sync::mpsc;
 use std::thread;
 
@@ -13,7 +13,7 @@ pub struct MultiThreadDataLoader<O> {
 #[derive(Debug)]
 pub enum Message<O> {
     /// A batch of items.
-    Batch(usize, O, Progress),
+    Batch(usize, O, Progress, DataLoaderState),
 
     /// The thread is done.
     Done,
@@ -24,6 +24,7 @@ struct MultiThreadsDataloaderIterator<O> {
     workers: Vec<thread::JoinHandle<()>>,
     receiver: mpsc::Receiver<Message<O>>,
     progresses: Vec<Progress>,
+    states: Vec<DataLoaderState>,
 }
 
 impl<O> MultiThreadDataLoader<O> {
@@ -41,13 +42,75 @@ impl<O> MultiThreadDataLoader<O> {
     }
 }
 
+impl<O> Clone for MultiThreadDataLoader<O> {
+    fn clone(&self) -> Self {
+        Self {
+            dataloaders: self
+                .dataloaders
+                .iter()
+                .map(|dataloader| dataloader.clone_dyn())
+                .collect(),
+        }
+    }
+}
+
 impl<O> DataLoader<O> for MultiThreadDataLoader<O>
 where
     O: Send + 'static + std::fmt::Debug,
 {
     fn iter<'a>(&'a self) -> Box<dyn DataLoaderIterator<O> + 'a> {
+        self.iter_with_states(None)
+    }
+
+    fn iter_from<'a>(&'a self, state: &DataLoaderState) -> Box<dyn DataLoaderIterator<O> + 'a> {
+        assert_eq!(
+            state.partitions.len(),
+            self.dataloaders.len(),
+            "The state should have one partition per dataloader."
+        );
+
+        self.iter_with_states(Some(&state.partitions))
+    }
+
+    fn num_items(&self) -> usize {
+        self.dataloaders.iter().map(|dl| dl.num_items()).sum()
+    }
+
+    fn slice(&self, start: usize, end: usize) -> Option<Box<dyn DynDataLoader<O>>> {
+        // Each dataloader contains a contiguous partition of the items, so the range is mapped
+        // on the partitions that overlap with it.
+        let mut dataloaders = Vec::new();
+        let mut offset = 0;
+
+        for dataloader in self.dataloaders.iter() {
+            let num_items = dataloader.num_items();
+            let start_local = start.saturating_sub(offset);
+            let end_local = usize::min(end.saturating_sub(offset), num_items);
+
+            if start_local < end_local {
+                dataloaders.push(dataloader.slice(start_local, end_local)?);
+            }
+
+            offset += num_items;
+        }
+
+        Some(Box::new(MultiThreadDataLoader::new(dataloaders)))
+    }
+}
+
+impl<O> MultiThreadDataLoader<O>
+where
+    O: Send + 'static + std::fmt::Debug,
+{
+    fn iter_with_states<'a>(
+        &'a self,
+        states: Option<&[DataLoaderState]>,
+    ) -> Box<dyn DataLoaderIterator<O> + 'a> {
         let (sender, receiver) = mpsc::sync_channel::<Message<O>>(MAX_QUEUED_ITEMS);
 
+        // Each worker sends the state of its iterator before the first batch, so the shuffling
+        // seeds are known even when no batch has been processed yet.
+        let (state_sender, state_receiver) = mpsc::channel::<(usize, DataLoaderState)>();
         let mut progresses = Vec::with_capacity(self.dataloaders.len());
 
         let handlers: Vec<_> = self
@@ -57,14 +120,27 @@ where
             .map(|(index, dataloader)| {
                 let dataloader_cloned = dataloader.clone_dyn();
                 let sender_cloned = sender.clone();
-                progresses.push(Progress::new(0, dataloader_cloned.num_items()));
+                let state_sender_cloned = state_sender.clone();
+                let state = states.map(|states| states[index].clone());
+                let items_processed = state.as_ref().map(|state| state.items_processed);
+
+                progresses.push(Progress::new(
+                    items_processed.unwrap_or(0),
+                    dataloader_cloned.num_items(),
+                ));
 
                 thread::spawn(move || {
-                    let mut iterator = dataloader_cloned.iter();
+                    let mut iterator = match &state {
+                        Some(state) => dataloader_cloned.iter_from(state),
+                        None => dataloader_cloned.iter(),
+                    };
+                    state_sender_cloned.send((index, iterator.state())).ok();
+
                     while let Some(item) = iterator.next() {
                         let progress = iterator.progress();
+                        let state = iterator.state();
 
-                        match sender_cloned.send(Message::Batch(index, item, progress)) {
+                        match sender_cloned.send(Message::Batch(index, item, progress, state)) {
                             Ok(_) => {}
                             // The receiver is probably gone, no need to panic, just need to stop
                             // iterating.
@@ -77,14 +153,21 @@ where
             })
             .collect();
 
+        let mut initial_states = vec![DataLoaderState::new(0, Vec::new()); handlers.len()];
+        for _ in 0..handlers.len() {
+            let (index, state) = state_receiver
+                .recv()
+                .expect("Each worker should send its initial state.");
+            initial_states[index] = state;
+        }
+
         Box::new(MultiThreadsDataloaderIterator::new(
-            receiver, handlers, progresses,
+            receiver,
+            handlers,
+            progresses,
+            initial_states,
         ))
     }
-
-    fn num_items(&self) -> usize {
-        self.dataloaders.iter().map(|dl| dl.num_items()).sum()
-    }
 }
 
 impl<O> MultiThreadsDataloaderIterator<O> {
@@ -92,12 +175,14 @@ impl<O> MultiThreadsDataloaderIterator<O> {
         receiver: mpsc::Receiver<Message<O>>,
         workers: Vec<thread::JoinHandle<()>>,
         progresses: Vec<Progress>,
+        states: Vec<DataLoaderState>,
     ) -> Self {
         MultiThreadsDataloaderIterator {
             num_done: 0,
             workers,
             receiver,
             progresses,
+            states,
         }
     }
 }
@@ -113,6 +198,12 @@ impl<O: std::fmt::Debug> DataLoaderIterator<O> for MultiThreadsDataloaderIterato
 
         Progress::new(items_processed, items_total)
     }
+
+    fn state(&self) -> DataLoaderState {
+        let items_processed = self.states.iter().map(|state| state.items_processed).sum();
+
+        DataLoaderState::new(items_processed, self.states.clone())
+    }
 }
 
 impl<O: std::fmt::Debug> Iterator for MultiThreadsDataloaderIterator<O> {
@@ -128,10 +219,13 @@ impl<O: std::fmt::Debug> Iterator for MultiThreadsDataloaderIterator<O> {
             let item = item.unwrap();
 
             match item {
-                Message::Batch(index, item, progress) => {
+                Message::Batch(index, item, progress, state) => {
                     if let Some(current) = self.progresses.get_mut(index) {
                         *current = progress;
                     }
+                    if let Some(current) = self.states.get_mut(index) {
+                        *current = state;
+                    }
                     return Some(item);
                 }
                 Message::Done => {
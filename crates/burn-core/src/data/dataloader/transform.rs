@@ -0,0 +1,265 @@
+use super::{DataLoader, DataLoaderIterator, DataLoaderState, DynDataLoader, Progress};
+use rand::{distributions::Standard, rngs::StdRng, Rng, SeedableRng};
+use std::sync::Arc;
+
+/// A transformation applied on the batches of a [dataloader](DataLoader), after they are created
+/// by the [batcher](super::batcher::Batcher).
+///
+/// The transformation is applied on the thread iterating over the dataloader, which is the
+/// training thread, so it can be expressed with tensor operations on the batch already uploaded
+/// to the training device. Since backends execute tensor operations asynchronously, the
+/// augmentations are queued on the device along with the training step instead of consuming CPU
+/// time on the dataloader workers.
+pub trait BatchTransform<O>: Send + Sync {
+    /// Transforms the batch.
+    ///
+    /// # Arguments
+    ///
+    /// * `batch` - The batch to transform.
+    /// * `rng` - The rng to use for random transformations, seeded by the dataloader for each
+    ///   batch.
+    ///
+    /// # Returns
+    ///
+    /// The transformed batch.
+    fn transform(&self, batch: O, rng: &mut StdRng) -> O;
+}
+
+impl<O, F> BatchTransform<O> for F
+where
+    F: Fn(O, &mut StdRng) -> O + Send + Sync,
+{
+    fn transform(&self, batch: O, rng: &mut StdRng) -> O {
+        self(batch, rng)
+    }
+}
+
+/// A data loader applying a [transformation](BatchTransform) on the batches of another data
+/// loader.
+pub struct TransformDataLoader<O> {
+    dataloader: Box<dyn DynDataLoader<O>>,
+    transform: Arc<dyn BatchTransform<O>>,
+    rng: Arc<spin::Mutex<StdRng>>,
+}
+
+impl<O> Clone for TransformDataLoader<O> {
+    fn clone(&self) -> Self {
+        Self {
+            dataloader: self.dataloader.clone_dyn(),
+            transform: self.transform.clone(),
+            rng: self.rng.clone(),
+        }
+    }
+}
+
+impl<O> TransformDataLoader<O> {
+    /// Creates a new transform data loader.
+    ///
+    /// # Arguments
+    ///
+    /// * `dataloader` - The data loader creating the batches.
+    /// * `transform` - The transformation applied on each batch.
+    /// * `seed` - The seed of the rng used by the transformation.
+    ///
+    /// # Returns
+    ///
+    /// The transform data loader.
+    pub fn new(
+        dataloader: Box<dyn DynDataLoader<O>>,
+        transform: Arc<dyn BatchTransform<O>>,
+        seed: u64,
+    ) -> Self {
+        Self {
+            dataloader,
+            transform,
+            rng: Arc::new(spin::Mutex::new(StdRng::seed_from_u64(seed))),
+        }
+    }
+}
+
+struct TransformDataLoaderIterator<'a, O> {
+    iterator: Box<dyn DataLoaderIterator<O> + 'a>,
+    transform: &'a dyn BatchTransform<O>,
+    rng: StdRng,
+    seed: u64,
+    rng_position: usize,
+}
+
+impl<O> DataLoader<O> for TransformDataLoader<O>
+where
+    O: Send + 'static,
+{
+    fn iter<'a>(&'a self) -> Box<dyn DataLoaderIterator<O> + 'a> {
+        // Each iteration uses a different seed, drawn like the shuffling seeds.
+        let seed = {
+            let mut rng = self.rng.lock();
+            let seed = rng.sample(Standard);
+            *rng = StdRng::seed_from_u64(seed);
+            seed
+        };
+
+        Box::new(TransformDataLoaderIterator::new(
+            self.dataloader.iter(),
+            self.transform.as_ref(),
+            seed,
+            0,
+        ))
+    }
+
+    fn iter_from<'a>(&'a self, state: &DataLoaderState) -> Box<dyn DataLoaderIterator<O> + 'a> {
+        let seed = state
+            .seed
+            .expect("The state of a transform dataloader should have a seed.");
+        let inner = state
+            .partitions
+            .first()
+            .expect("The state of a transform dataloader should have a partition.");
+
+        *self.rng.lock() = StdRng::seed_from_u64(seed);
+
+        Box::new(TransformDataLoaderIterator::new(
+            self.dataloader.iter_from(inner),
+            self.transform.as_ref(),
+            seed,
+            state.rng_position,
+        ))
+    }
+
+    fn num_items(&self) -> usize {
+        self.dataloader.num_items()
+    }
+
+    fn slice(&self, start: usize, end: usize) -> Option<Box<dyn DynDataLoader<O>>> {
+        Some(Box::new(Self {
+            dataloader: self.dataloader.slice(start, end)?,
+            transform: self.transform.clone(),
+            rng: self.rng.clone(),
+        }))
+    }
+}
+
+impl<'a, O> TransformDataLoaderIterator<'a, O> {
+    fn new(
+        iterator: Box<dyn DataLoaderIterator<O> + 'a>,
+        transform: &'a dyn BatchTransform<O>,
+        seed: u64,
+        rng_position: usize,
+    ) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+        // Skip the seeds of the batches already transformed before the iteration was interrupted.
+        for _ in 0..rng_position {
+            rng.sample::<u64, _>(Standard);
+        }
+
+        Self {
+            iterator,
+            transform,
+            rng,
+            seed,
+            rng_position,
+        }
+    }
+}
+
+impl<O> Iterator for TransformDataLoaderIterator<'_, O> {
+    type Item = O;
+
+    fn next(&mut self) -> Option<O> {
+        let batch = self.iterator.next()?;
+
+        // Each batch is transformed with its own rng, so the number of values drawn by the
+        // transformation doesn't change the position of the iteration rng.
+        let mut rng = StdRng::seed_from_u64(self.rng.sample(Standard));
+        self.rng_position += 1;
+
+        Some(self.transform.transform(batch, &mut rng))
+    }
+}
+
+impl<O> DataLoaderIterator<O> for TransformDataLoaderIterator<'_, O> {
+    fn progress(&self) -> Progress {
+        self.iterator.progress()
+    }
+
+    fn state(&self) -> DataLoaderState {
+        let state = self.iterator.state();
+
+        DataLoaderState::new(state.items_processed, vec![state])
+            .with_seed(Some(self.seed))
+            .with_rng_position(self.rng_position)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::dataloader::{batcher::TestBatcher, BatchDataLoader, FixBatchStrategy};
+    use crate::data::dataset::FakeDataset;
+
+    fn transform_dataloader(seed: u64) -> TransformDataLoader<Vec<String>> {
+        let dataloader = BatchDataLoader::new(
+            Box::new(FixBatchStrategy::new(5)),
+            Arc::new(FakeDataset::<String>::new(27)),
+            Box::new(TestBatcher::new()),
+            None,
+        );
+        let transform = |mut batch: Vec<String>, rng: &mut StdRng| {
+            let tag: u32 = rng.gen();
+            for item in batch.iter_mut() {
+                item.push_str(&format!("-{tag}"));
+            }
+            batch
+        };
+
+        TransformDataLoader::new(Box::new(dataloader), Arc::new(transform), seed)
+    }
+
+    #[test]
+    fn test_transform_dataloader_applies_the_transform_on_each_batch() {
+        let dataloader = transform_dataloader(42);
+
+        let batches = dataloader.iter().collect::<Vec<_>>();
+
+        assert_eq!(batches.len(), 6);
+        for batch in batches {
+            let tag = batch[0].split('-').last().unwrap().to_string();
+            assert!(batch.iter().all(|item| item.ends_with(&format!("-{tag}"))));
+        }
+    }
+
+    #[test]
+    fn test_transform_dataloader_is_deterministic_with_the_same_seed() {
+        let tags = |dataloader: &TransformDataLoader<Vec<String>>| {
+            dataloader
+                .iter()
+                .map(|batch| batch[0].split('-').last().unwrap().to_string())
+                .collect::<Vec<_>>()
+        };
+
+        let dataloader_1 = transform_dataloader(42);
+        let dataloader_2 = transform_dataloader(42);
+
+        let tags_1 = tags(&dataloader_1);
+        assert_eq!(tags_1, tags(&dataloader_2));
+        assert_ne!(
+            tags_1,
+            tags(&dataloader_1),
+            "Each iteration should be different"
+        );
+    }
+
+    #[test]
+    fn test_resume_transform_dataloader_applies_the_same_transforms() {
+        let dataloader = transform_dataloader(42);
+        let mut iterator = dataloader.iter();
+        iterator.next();
+        iterator.next();
+        let state = iterator.state();
+        let remaining = iterator.collect::<Vec<_>>();
+
+        let resumed = dataloader.iter_from(&state).collect::<Vec<_>>();
+
+        assert_eq!(state.rng_position, 2);
+        assert_eq!(remaining, resumed);
+    }
+}
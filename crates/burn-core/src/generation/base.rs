@@ -0,0 +1,64 @@
+use alloc::vec::Vec;
+
+use crate::tensor::{backend::Backend, Int, Tensor};
+
+/// A model predicting the next token of sequences, which can be used to generate text with
+/// [sampling](super::GenerationConfig) or [beam search](super::BeamSearchConfig).
+///
+/// The model keeps the state of the previous positions in a cache, for instance the
+/// [key-value cache](crate::nn::attention::KvCache) of its attention layers, so only the new tokens
+/// are provided at each decoding step.
+pub trait LanguageModel<B: Backend> {
+    /// The state of the previous positions of the sequences.
+    type Cache;
+
+    /// Creates an empty cache.
+    fn init_cache(&self) -> Self::Cache;
+
+    /// Computes the logits of the next token of each sequence, given the new tokens of the
+    /// sequences, and updates the cache with them.
+    ///
+    /// # Shapes
+    ///
+    /// - tokens: `[batch_size, seq_length]`
+    /// - output: `[batch_size, vocab_size]`
+    fn forward_next(&self, tokens: Tensor<B, 2, Int>, cache: &mut Self::Cache) -> Tensor<B, 2>;
+
+    /// Reorders the sequences of the cache, where each new sequence continues the previous
+    /// sequence at the given index. Used by the [beam search](super::BeamSearchConfig).
+    ///
+    /// # Shapes
+    ///
+    /// - indices: `[batch_size]`
+    fn reorder_cache(&self, cache: &mut Self::Cache, indices: Tensor<B, 1, Int>);
+}
+
+/// Reads the tokens of each sequence.
+pub(crate) fn tokens_rows<B: Backend>(tokens: Tensor<B, 2, Int>) -> Vec<Vec<usize>> {
+    let [_, seq_length] = tokens.dims();
+    let tokens = tokens
+        .into_data()
+        .convert::<i64>()
+        .into_vec::<i64>()
+        .unwrap();
+
+    tokens
+        .chunks(seq_length.max(1))
+        .map(|sequence| sequence.iter().map(|token| *token as usize).collect())
+        .collect()
+}
+
+/// Reads the logits of each sequence.
+pub(crate) fn logits_rows<B: Backend>(logits: Tensor<B, 2>) -> Vec<Vec<f32>> {
+    let [_, vocab_size] = logits.dims();
+    let logits = logits
+        .into_data()
+        .convert::<f32>()
+        .into_vec::<f32>()
+        .unwrap();
+
+    logits
+        .chunks(vocab_size)
+        .map(|logits| logits.to_vec())
+        .collect()
+}
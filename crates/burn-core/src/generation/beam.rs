@@ -0,0 +1,233 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::{logits_rows, tokens_rows, LanguageModel};
+use crate as burn;
+use crate::config::Config;
+use crate::tensor::{activation::log_softmax, backend::Backend, Int, Tensor, TensorData};
+
+/// Configuration to generate tokens with a beam search over the predictions of a
+/// [language model](LanguageModel), using [generate](BeamSearchConfig::generate).
+///
+/// The most likely hypotheses of each sequence are kept at each step, and the hypothesis with
+/// the best score, normalized by its length, is returned.
+#[derive(Config, Debug)]
+pub struct BeamSearchConfig {
+    /// The maximum number of generated tokens.
+    pub max_new_tokens: usize,
+    /// The token ending a hypothesis, which is included in the generated tokens.
+    pub eos_token: Option<usize>,
+    /// The number of hypotheses kept for each sequence. Default: 4
+    #[config(default = 4)]
+    pub num_beams: usize,
+    /// The exponent of the length dividing the log-probability of the hypotheses, favoring
+    /// longer hypotheses when greater than zero. Default: 1.0
+    #[config(default = 1.0)]
+    pub length_penalty: f64,
+}
+
+#[derive(Clone)]
+struct Hypothesis {
+    tokens: Vec<usize>,
+    log_prob: f64,
+}
+
+impl BeamSearchConfig {
+    /// Generates the tokens following the prompts, returning the generated tokens of the best
+    /// hypothesis of each sequence.
+    ///
+    /// The batch of the model has `batch_size * num_beams` sequences, the hypotheses of a
+    /// sequence being contiguous.
+    ///
+    /// # Shapes
+    ///
+    /// - prompt: `[batch_size, prompt_length]`
+    pub fn generate<B: Backend, M: LanguageModel<B>>(
+        &self,
+        model: &M,
+        prompt: Tensor<B, 2, Int>,
+    ) -> Vec<Vec<usize>> {
+        assert!(self.num_beams > 0, "The number of beams must be positive");
+
+        let [batch_size, _] = prompt.dims();
+        let device = prompt.device();
+        let num_beams = self.num_beams;
+
+        let indices = (0..batch_size * num_beams)
+            .map(|i| (i / num_beams) as i64)
+            .collect::<Vec<_>>();
+        let mut input = prompt.select(0, Tensor::from_ints(indices.as_slice(), &device));
+        let last_tokens = tokens_rows(input.clone())
+            .into_iter()
+            .map(|tokens| tokens.last().copied().unwrap_or_default())
+            .collect::<Vec<_>>();
+
+        // Only the first hypothesis is active at first, since all of them are identical.
+        let mut beams = (0..batch_size)
+            .map(|_| {
+                (0..num_beams)
+                    .map(|beam| Hypothesis {
+                        tokens: Vec::new(),
+                        log_prob: if beam == 0 { 0.0 } else { f64::NEG_INFINITY },
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let mut finished = vec![Vec::<Hypothesis>::new(); batch_size];
+        let mut done = vec![false; batch_size];
+        let mut cache = model.init_cache();
+
+        for _ in 0..self.max_new_tokens {
+            let log_probs = logits_rows(log_softmax(model.forward_next(input, &mut cache), 1));
+            let mut reorder = Vec::with_capacity(batch_size * num_beams);
+            let mut next = Vec::with_capacity(batch_size * num_beams);
+
+            for item in 0..batch_size {
+                let offset = item * num_beams;
+
+                if done[item] {
+                    // Finished sequences are kept in the batch, but their tokens are ignored.
+                    for (beam, hypothesis) in beams[item].iter().enumerate() {
+                        reorder.push((offset + beam) as i64);
+                        next.push(Self::last_token(hypothesis, last_tokens[offset]));
+                    }
+                    continue;
+                }
+
+                let mut candidates = Vec::new();
+                for (beam, hypothesis) in beams[item].iter().enumerate() {
+                    if hypothesis.log_prob == f64::NEG_INFINITY {
+                        continue;
+                    }
+
+                    for (token, log_prob) in log_probs[offset + beam].iter().enumerate() {
+                        candidates.push((hypothesis.log_prob + *log_prob as f64, beam, token));
+                    }
+                }
+                candidates.sort_unstable_by(|a, b| b.0.total_cmp(&a.0));
+
+                // At most one candidate per hypothesis ends the hypothesis, so twice the number
+                // of beams leaves enough candidates to continue.
+                let mut continued = Vec::with_capacity(num_beams);
+                for (log_prob, beam, token) in candidates.into_iter().take(2 * num_beams) {
+                    let mut tokens = beams[item][beam].tokens.clone();
+                    tokens.push(token);
+                    let hypothesis = Hypothesis { tokens, log_prob };
+
+                    if self.eos_token == Some(token) {
+                        finished[item].push(hypothesis);
+                    } else if continued.len() < num_beams {
+                        continued.push((beam, hypothesis));
+                    }
+                }
+
+                done[item] = finished[item].len() >= num_beams;
+
+                while continued.len() < num_beams {
+                    let (beam, mut hypothesis) = continued[0].clone();
+                    hypothesis.log_prob = f64::NEG_INFINITY;
+                    continued.push((beam, hypothesis));
+                }
+
+                for (beam, hypothesis) in continued.iter() {
+                    reorder.push((offset + beam) as i64);
+                    next.push(Self::last_token(hypothesis, last_tokens[offset]));
+                }
+                beams[item] = continued
+                    .into_iter()
+                    .map(|(_, hypothesis)| hypothesis)
+                    .collect();
+            }
+
+            if done.iter().all(|done| *done) {
+                break;
+            }
+
+            model.reorder_cache(&mut cache, Tensor::from_ints(reorder.as_slice(), &device));
+            let next = TensorData::new(next, [batch_size * num_beams, 1]);
+            input = Tensor::from_ints(next, &device);
+        }
+
+        finished
+            .into_iter()
+            .zip(beams)
+            .zip(done)
+            .map(|((finished, beams), done)| {
+                let hypotheses = match done {
+                    true => finished,
+                    false => finished.into_iter().chain(beams).collect(),
+                };
+
+                hypotheses
+                    .into_iter()
+                    .filter(|hypothesis| hypothesis.log_prob > f64::NEG_INFINITY)
+                    .map(|hypothesis| (self.score(&hypothesis), hypothesis.tokens))
+                    .max_by(|a, b| a.0.total_cmp(&b.0))
+                    .map(|(_, tokens)| tokens)
+                    .unwrap_or_default()
+            })
+            .collect()
+    }
+
+    fn score(&self, hypothesis: &Hypothesis) -> f64 {
+        let length = hypothesis.tokens.len().max(1) as f64;
+        hypothesis.log_prob / length.powf(self.length_penalty)
+    }
+
+    fn last_token(hypothesis: &Hypothesis, prompt_token: usize) -> i64 {
+        hypothesis.tokens.last().copied().unwrap_or(prompt_token) as i64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generation::tests::BigramModel;
+    use crate::generation::GenerationConfig;
+    use crate::TestBackend;
+
+    #[test]
+    fn test_beam_search_finds_more_likely_sequence_than_greedy() {
+        let device = Default::default();
+        let model = BigramModel::<TestBackend>::new();
+        let prompt = Tensor::<TestBackend, 2, Int>::from_ints([[0]], &device);
+
+        let greedy = GenerationConfig::greedy(5)
+            .with_eos_token(Some(4))
+            .generate(&model, prompt.clone());
+        let beam = BeamSearchConfig::new(5)
+            .with_eos_token(Some(4))
+            .with_num_beams(2)
+            .generate(&model, prompt);
+
+        assert_eq!(greedy, vec![vec![1, 0, 1, 0, 1]]);
+        assert_eq!(beam, vec![vec![2, 4]]);
+    }
+
+    #[test]
+    fn test_beam_search_batch() {
+        let device = Default::default();
+        let model = BigramModel::<TestBackend>::new();
+        let prompt = Tensor::<TestBackend, 2, Int>::from_ints([[3, 0], [0, 2]], &device);
+
+        let generated = BeamSearchConfig::new(5)
+            .with_eos_token(Some(4))
+            .with_num_beams(2)
+            .generate(&model, prompt);
+
+        assert_eq!(generated, vec![vec![2, 4], vec![4]]);
+    }
+
+    #[test]
+    fn test_single_beam_is_greedy() {
+        let device = Default::default();
+        let model = BigramModel::<TestBackend>::new();
+        let prompt = Tensor::<TestBackend, 2, Int>::from_ints([[0]], &device);
+
+        let generated = BeamSearchConfig::new(4)
+            .with_num_beams(1)
+            .generate(&model, prompt);
+
+        assert_eq!(generated, vec![vec![1, 0, 1, 0]]);
+    }
+}
@@ -0,0 +1,67 @@
+mod base;
+mod beam;
+mod sampling;
+
+pub use base::*;
+pub use beam::*;
+pub use sampling::*;
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::LanguageModel;
+    use crate::tensor::{backend::Backend, Int, Tensor};
+    use alloc::vec;
+
+    /// A model predicting the next token from the last token of its cache, with a vocabulary of
+    /// five tokens, the last one ending the sequences.
+    pub(crate) struct BigramModel<B: Backend> {
+        log_probs: Tensor<B, 2>,
+    }
+
+    impl<B: Backend> BigramModel<B> {
+        pub(crate) fn new() -> Self {
+            let probs = Tensor::<B, 2>::from_floats(
+                [
+                    [0.01, 0.5, 0.38, 0.06, 0.05],
+                    [0.3, 0.2, 0.25, 0.1, 0.15],
+                    [0.025, 0.025, 0.025, 0.025, 0.9],
+                    [0.2, 0.2, 0.2, 0.2, 0.2],
+                    [0.2, 0.2, 0.2, 0.2, 0.2],
+                ],
+                &Default::default(),
+            );
+
+            Self {
+                log_probs: probs.log(),
+            }
+        }
+    }
+
+    impl<B: Backend> LanguageModel<B> for BigramModel<B> {
+        /// The tokens of each sequence.
+        type Cache = Option<Tensor<B, 2, Int>>;
+
+        fn init_cache(&self) -> Self::Cache {
+            None
+        }
+
+        fn forward_next(&self, tokens: Tensor<B, 2, Int>, cache: &mut Self::Cache) -> Tensor<B, 2> {
+            let tokens = match cache.take() {
+                Some(previous) => Tensor::cat(vec![previous, tokens], 1),
+                None => tokens,
+            };
+            let [batch_size, seq_length] = tokens.dims();
+            let last = tokens
+                .clone()
+                .narrow(1, seq_length - 1, 1)
+                .reshape([batch_size]);
+            *cache = Some(tokens);
+
+            self.log_probs.clone().select(0, last)
+        }
+
+        fn reorder_cache(&self, cache: &mut Self::Cache, indices: Tensor<B, 1, Int>) {
+            *cache = cache.take().map(|tokens| tokens.select(0, indices));
+        }
+    }
+}
@@ -0,0 +1,234 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use super::{logits_rows, tokens_rows, LanguageModel};
+use crate as burn;
+use crate::config::Config;
+use crate::tensor::{backend::Backend, Int, Tensor, TensorData};
+
+/// Configuration to generate tokens by sampling the next token of each sequence from the
+/// predictions of a [language model](LanguageModel), using [generate](GenerationConfig::generate).
+///
+/// The most likely token is selected (greedy decoding) when the temperature is zero.
+#[derive(Config, Debug)]
+pub struct GenerationConfig {
+    /// The maximum number of generated tokens.
+    pub max_new_tokens: usize,
+    /// The token ending a sequence, which is included in the generated tokens.
+    pub eos_token: Option<usize>,
+    /// The temperature dividing the logits, greedy decoding is used when zero. Default: 1.0
+    #[config(default = 1.0)]
+    pub temperature: f64,
+    /// Only sample among the `k` most likely tokens.
+    pub top_k: Option<usize>,
+    /// Only sample among the most likely tokens whose cumulative probability reaches `p`
+    /// (nucleus sampling).
+    pub top_p: Option<f64>,
+    /// The penalty applied to the logits of the tokens already in the sequence, dividing the
+    /// positive logits and multiplying the negative ones. Default: 1.0 (no penalty)
+    #[config(default = 1.0)]
+    pub repetition_penalty: f64,
+    /// The seed of the random number generator, a random seed is used when not set.
+    pub seed: Option<u64>,
+}
+
+impl GenerationConfig {
+    /// Creates a configuration for greedy decoding, always selecting the most likely token.
+    pub fn greedy(max_new_tokens: usize) -> Self {
+        Self::new(max_new_tokens).with_temperature(0.0)
+    }
+
+    /// Generates the tokens following the prompts, returning the generated tokens of each
+    /// sequence.
+    ///
+    /// # Shapes
+    ///
+    /// - prompt: `[batch_size, prompt_length]`
+    pub fn generate<B: Backend, M: LanguageModel<B>>(
+        &self,
+        model: &M,
+        prompt: Tensor<B, 2, Int>,
+    ) -> Vec<Vec<usize>> {
+        let [batch_size, _] = prompt.dims();
+        let device = prompt.device();
+
+        let mut rng = match self.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+        let mut sequences = tokens_rows(prompt.clone());
+        let mut generated = vec![Vec::new(); batch_size];
+        let mut done = vec![false; batch_size];
+        let mut cache = model.init_cache();
+        let mut input = prompt;
+
+        for _ in 0..self.max_new_tokens {
+            let logits = logits_rows(model.forward_next(input, &mut cache));
+            let mut next = Vec::with_capacity(batch_size);
+
+            for (i, logits) in logits.into_iter().enumerate() {
+                if done[i] {
+                    // Finished sequences are kept in the batch, but their tokens are ignored.
+                    next.push(sequences[i].last().copied().unwrap_or_default() as i64);
+                    continue;
+                }
+
+                let token = self.sample(logits, &sequences[i], &mut rng);
+                sequences[i].push(token);
+                generated[i].push(token);
+                done[i] = self.eos_token == Some(token);
+                next.push(token as i64);
+            }
+
+            if done.iter().all(|done| *done) {
+                break;
+            }
+
+            input = Tensor::from_ints(TensorData::new(next, [batch_size, 1]), &device);
+        }
+
+        generated
+    }
+
+    /// Samples the next token from the logits of a sequence.
+    fn sample(&self, mut logits: Vec<f32>, sequence: &[usize], rng: &mut StdRng) -> usize {
+        if self.repetition_penalty != 1.0 {
+            let penalty = self.repetition_penalty as f32;
+            let mut penalized = vec![false; logits.len()];
+
+            for token in sequence.iter().copied() {
+                if token < logits.len() && !penalized[token] {
+                    penalized[token] = true;
+                    logits[token] = match logits[token] > 0.0 {
+                        true => logits[token] / penalty,
+                        false => logits[token] * penalty,
+                    };
+                }
+            }
+        }
+
+        // Tokens sorted from the most to the least likely.
+        let mut tokens = (0..logits.len()).collect::<Vec<_>>();
+        tokens.sort_unstable_by(|a, b| logits[*b].total_cmp(&logits[*a]));
+
+        if self.temperature <= 0.0 {
+            return tokens[0];
+        }
+
+        if let Some(top_k) = self.top_k {
+            tokens.truncate(top_k.max(1));
+        }
+
+        let temperature = self.temperature as f32;
+        let max = logits[tokens[0]];
+        let mut probs = tokens
+            .iter()
+            .map(|token| ((logits[*token] - max) / temperature).exp())
+            .collect::<Vec<_>>();
+        let sum = probs.iter().sum::<f32>();
+        probs.iter_mut().for_each(|prob| *prob /= sum);
+
+        if let Some(top_p) = self.top_p {
+            let mut cumulative = 0.0;
+            let mut num_kept = 0;
+
+            for prob in probs.iter() {
+                num_kept += 1;
+                cumulative += prob;
+
+                if cumulative >= top_p as f32 {
+                    break;
+                }
+            }
+
+            tokens.truncate(num_kept);
+            probs.truncate(num_kept);
+        }
+
+        let mut threshold = rng.gen::<f32>() * probs.iter().sum::<f32>();
+
+        for (token, prob) in tokens.iter().zip(probs) {
+            threshold -= prob;
+
+            if threshold <= 0.0 {
+                return *token;
+            }
+        }
+
+        tokens[tokens.len() - 1]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generation::tests::BigramModel;
+    use crate::TestBackend;
+
+    fn prompt() -> Tensor<TestBackend, 2, Int> {
+        Tensor::from_ints([[0], [2]], &Default::default())
+    }
+
+    #[test]
+    fn test_greedy_generation() {
+        let model = BigramModel::<TestBackend>::new();
+
+        let generated = GenerationConfig::greedy(5)
+            .with_eos_token(Some(4))
+            .generate(&model, prompt());
+
+        assert_eq!(generated, vec![vec![1, 0, 1, 0, 1], vec![4]]);
+    }
+
+    #[test]
+    fn test_top_k_one_is_greedy() {
+        let model = BigramModel::<TestBackend>::new();
+
+        let generated = GenerationConfig::new(5)
+            .with_top_k(Some(1))
+            .with_eos_token(Some(4))
+            .generate(&model, prompt());
+
+        assert_eq!(generated, vec![vec![1, 0, 1, 0, 1], vec![4]]);
+    }
+
+    #[test]
+    fn test_top_p_keeps_the_most_likely_token() {
+        let model = BigramModel::<TestBackend>::new();
+
+        let generated = GenerationConfig::new(5)
+            .with_top_p(Some(0.1))
+            .with_temperature(2.0)
+            .with_eos_token(Some(4))
+            .with_seed(Some(42))
+            .generate(&model, prompt());
+
+        assert_eq!(generated, vec![vec![1, 0, 1, 0, 1], vec![4]]);
+    }
+
+    #[test]
+    fn test_sampling_is_reproducible_with_seed() {
+        let model = BigramModel::<TestBackend>::new();
+        let config = GenerationConfig::new(10).with_seed(Some(7));
+
+        let generated_1 = config.generate(&model, prompt());
+        let generated_2 = config.generate(&model, prompt());
+
+        assert_eq!(generated_1, generated_2);
+        assert_eq!(generated_1[0].len(), 10);
+    }
+
+    #[test]
+    fn test_repetition_penalty() {
+        let model = BigramModel::<TestBackend>::new();
+
+        let generated = GenerationConfig::greedy(3)
+            .with_repetition_penalty(4.0)
+            .generate(&model, prompt());
+
+        // Without penalty, the tokens 0 and 1 would be repeated.
+        assert_eq!(generated[0], vec![1, 2, 4]);
+    }
+}
@@ -26,6 +26,14 @@ pub mod optim;
 #[cfg(feature = "std")]
 pub mod lr_scheduler;
 
+/// Text generation module.
+#[cfg(feature = "std")]
+pub mod generation;
+
+/// Model serving module.
+#[cfg(feature = "std")]
+pub mod serving;
+
 /// Gradient clipping module.
 pub mod grad_clipping;
 
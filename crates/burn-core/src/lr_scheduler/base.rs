@@ -13,6 +13,18 @@ pub trait LrScheduler: Send + Sync {
     /// learning rate.
     fn step(&mut self) -> LearningRate;
 
+    /// The name of the validation metric the scheduler adapts the learning rate to, if any.
+    ///
+    /// When a name is returned, the learner reports the mean value of the metric over each
+    /// validation epoch with [on_metric](LrScheduler::on_metric).
+    fn metric_name(&self) -> Option<&str> {
+        None
+    }
+
+    /// Update the scheduler with the value of its [metric](LrScheduler::metric_name) at the end
+    /// of a validation epoch.
+    fn on_metric(&mut self, _value: f64) {}
+
     /// Get the current state of the scheduler as a [record](Record).
     fn to_record<B: Backend>(&self) -> Self::Record<B>;
 
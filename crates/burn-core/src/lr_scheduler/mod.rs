@@ -16,6 +16,9 @@ pub mod cosine;
 /// Step learning rate scheduler
 pub mod step;
 
+/// Learning rate scheduler reducing the learning rate when a metric stops improving
+pub mod plateau;
+
 mod base;
 
 pub use base::*;
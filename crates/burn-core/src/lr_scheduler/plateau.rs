@@ -0,0 +1,270 @@
+use burn_tensor::backend::Backend;
+
+use crate as burn;
+
+use super::{LrScheduler, String};
+use crate::{config::Config, LearningRate};
+
+/// Whether a metric improves when it decreases or when it increases.
+#[derive(Config, Debug, PartialEq, Eq)]
+pub enum PlateauMode {
+    /// The metric improves when it decreases, e.g. a loss.
+    Min,
+    /// The metric improves when it increases, e.g. an accuracy.
+    Max,
+}
+
+/// The configuration for creating a [plateau learning rate scheduler](PlateauLrScheduler).
+///
+/// This scheduler returns the learning rate `initial_lr` until the validation metric named
+/// `metric_name` hasn't improved for more than `patience` epochs. Then it multiplies the learning
+/// rate by `factor`, without going below `min_lr`, and waits for `cooldown` epochs before counting
+/// the epochs without improvement again.
+///
+/// The metric improves when it gets better than the best value so far by more than `threshold`
+/// times the best value.
+///
+/// ## Notes
+///
+/// The metric must be registered for the validation split, otherwise the learning rate is never
+/// reduced.
+#[derive(Config)]
+pub struct PlateauLrSchedulerConfig {
+    // The learning rate at the initial step.
+    initial_lr: LearningRate,
+    // The name of the validation metric, e.g. `LossMetric::<B>::NAME`.
+    metric_name: String,
+    /// Whether the metric improves when it decreases or when it increases. Default: Min.
+    #[config(default = "PlateauMode::Min")]
+    mode: PlateauMode,
+    /// The factor by which the learning rate is multiplied with each reduction. Default: 0.1.
+    #[config(default = 0.1)]
+    factor: f64,
+    /// The number of epochs without improvement before the learning rate is reduced. Default: 10.
+    #[config(default = 10)]
+    patience: usize,
+    /// The number of epochs to wait after a reduction before counting the epochs without
+    /// improvement again. Default: 0.
+    #[config(default = 0)]
+    cooldown: usize,
+    /// The lower bound of the learning rate. Default: 0.0.
+    #[config(default = 0.0)]
+    min_lr: LearningRate,
+    /// The relative change of the best value for the metric to be considered improved.
+    /// Default: 1e-4.
+    #[config(default = 1e-4)]
+    threshold: f64,
+}
+
+impl PlateauLrSchedulerConfig {
+    /// Initializes a [plateau learning rate scheduler](PlateauLrScheduler).
+    ///
+    /// # Errors
+    ///
+    /// An error will be returned if any of the following conditions is true:
+    ///
+    /// * `initial_lr` is not a positive number
+    /// * `factor` is out of range (0.0, 1.0)
+    /// * `min_lr` is negative
+    /// * `threshold` is negative
+    pub fn init(&self) -> Result<PlateauLrScheduler, String> {
+        if self.initial_lr <= 0.0 {
+            return Err("Initial learning rate must be greater than 0".into());
+        }
+        if self.factor <= 0.0 || self.factor >= 1.0 {
+            return Err("Factor must be greater than 0 and less than 1".into());
+        }
+        if self.min_lr < 0.0 {
+            return Err("Minimum learning rate must be at least 0".into());
+        }
+        if self.threshold < 0.0 {
+            return Err("Threshold must be at least 0".into());
+        }
+
+        Ok(PlateauLrScheduler {
+            lr: self.initial_lr,
+            metric_name: self.metric_name.clone(),
+            mode: self.mode.clone(),
+            factor: self.factor,
+            patience: self.patience,
+            cooldown: self.cooldown,
+            min_lr: self.min_lr,
+            threshold: self.threshold,
+            best: None,
+            num_bad_epochs: 0,
+            cooldown_counter: 0,
+        })
+    }
+}
+
+/// A learning rate scheduler reducing the learning rate when a validation metric stops improving.
+///
+/// See [PlateauLrSchedulerConfig] for more information.
+#[derive(Clone, Debug)]
+pub struct PlateauLrScheduler {
+    // The current learning rate.
+    lr: LearningRate,
+    metric_name: String,
+    mode: PlateauMode,
+    factor: f64,
+    patience: usize,
+    cooldown: usize,
+    min_lr: LearningRate,
+    threshold: f64,
+    // The best value of the metric so far, none before the first validation epoch.
+    best: Option<f64>,
+    // The number of epochs since the metric last improved.
+    num_bad_epochs: usize,
+    // The number of epochs left to wait after the last reduction.
+    cooldown_counter: usize,
+}
+
+impl PlateauLrScheduler {
+    fn is_improvement(&self, value: f64) -> bool {
+        let Some(best) = self.best else {
+            return true;
+        };
+
+        match self.mode {
+            PlateauMode::Min => value < best - self.threshold * best.abs(),
+            PlateauMode::Max => value > best + self.threshold * best.abs(),
+        }
+    }
+}
+
+impl LrScheduler for PlateauLrScheduler {
+    type Record<B: Backend> = (LearningRate, Option<f64>, usize, usize);
+
+    fn step(&mut self) -> LearningRate {
+        self.lr
+    }
+
+    fn metric_name(&self) -> Option<&str> {
+        Some(&self.metric_name)
+    }
+
+    fn on_metric(&mut self, value: f64) {
+        if self.is_improvement(value) {
+            self.best = Some(value);
+            self.num_bad_epochs = 0;
+        } else {
+            self.num_bad_epochs += 1;
+        }
+
+        if self.cooldown_counter > 0 {
+            self.cooldown_counter -= 1;
+            // Epochs without improvement are ignored during the cooldown.
+            self.num_bad_epochs = 0;
+        }
+
+        if self.num_bad_epochs > self.patience {
+            let lr = f64::max(self.lr * self.factor, self.min_lr);
+            if lr < self.lr {
+                log::info!(
+                    "No improvement of {} for {} epochs, reducing the learning rate from {} to {}",
+                    self.metric_name,
+                    self.num_bad_epochs,
+                    self.lr,
+                    lr
+                );
+                self.lr = lr;
+            }
+            self.cooldown_counter = self.cooldown;
+            self.num_bad_epochs = 0;
+        }
+    }
+
+    fn to_record<B: Backend>(&self) -> Self::Record<B> {
+        (
+            self.lr,
+            self.best,
+            self.num_bad_epochs,
+            self.cooldown_counter,
+        )
+    }
+
+    fn load_record<B: Backend>(mut self, record: Self::Record<B>) -> Self {
+        (
+            self.lr,
+            self.best,
+            self.num_bad_epochs,
+            self.cooldown_counter,
+        ) = record;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    fn apply(scheduler: &mut PlateauLrScheduler, values: &[f64]) -> alloc::vec::Vec<LearningRate> {
+        values
+            .iter()
+            .map(|value| {
+                scheduler.on_metric(*value);
+                scheduler.step()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_config_invalid_factor() {
+        let r = PlateauLrSchedulerConfig::new(0.1, "Loss".into())
+            .with_factor(1.0)
+            .init();
+        assert!(r.is_err(), "Should return an error");
+    }
+
+    #[test]
+    fn test_reduce_after_patience() {
+        let mut scheduler = PlateauLrSchedulerConfig::new(1.0, "Loss".into())
+            .with_factor(0.5)
+            .with_patience(2)
+            .init()
+            .unwrap();
+
+        assert_eq!(scheduler.metric_name(), Some("Loss"));
+        let lrs = apply(
+            &mut scheduler,
+            &[1.0, 0.5, 0.6, 0.5, 0.7, 0.4, 0.4, 0.4, 0.4],
+        );
+        assert_eq!(lrs, [1.0, 1.0, 1.0, 1.0, 0.5, 0.5, 0.5, 0.5, 0.25]);
+    }
+
+    #[test]
+    fn test_max_mode_with_cooldown_and_min_lr() {
+        let mut scheduler = PlateauLrSchedulerConfig::new(1.0, "Accuracy".into())
+            .with_mode(PlateauMode::Max)
+            .with_factor(0.5)
+            .with_patience(0)
+            .with_cooldown(1)
+            .with_min_lr(0.3)
+            .init()
+            .unwrap();
+
+        let lrs = apply(&mut scheduler, &[50.0, 60.0, 55.0, 55.0, 55.0, 55.0, 55.0]);
+        assert_eq!(lrs, [1.0, 1.0, 0.5, 0.5, 0.3, 0.3, 0.3]);
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let mut scheduler = PlateauLrSchedulerConfig::new(1.0, "Loss".into())
+            .with_patience(1)
+            .init()
+            .unwrap();
+        apply(&mut scheduler, &[1.0, 2.0, 2.0]);
+
+        let mut truth = scheduler.clone();
+        let record = scheduler.to_record::<TestBackend>();
+        let mut scheduler = PlateauLrSchedulerConfig::new(1.0, "Loss".into())
+            .with_patience(1)
+            .init()
+            .unwrap()
+            .load_record::<TestBackend>(record);
+
+        let values = [2.0, 2.0, 0.5, 0.6, 0.7];
+        assert_eq!(apply(&mut scheduler, &values), apply(&mut truth, &values));
+    }
+}
@@ -0,0 +1,140 @@
+use alloc::vec::Vec;
+
+use super::{Module, ModuleMapper, ModuleVisitor, ParamId};
+use crate::tensor::{backend::Backend, Shape, Tensor};
+
+/// Averages the float tensors of modules with the same structure.
+///
+/// This is used to merge models fine-tuned from the same checkpoint (model soup) or the last
+/// checkpoints of a training. The modules are added one at a time, so only the sums of their
+/// tensors are kept in memory.
+///
+/// The float tensors are averaged, including the running statistics of normalization layers.
+/// The integer and boolean tensors can't be averaged, so they are taken from the module passed to
+/// [average](ModuleAverager::average).
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let mut averager = ModuleAverager::new();
+/// for model in models.iter() {
+///     averager.add(model);
+/// }
+/// let model = averager.average(models[0].clone());
+/// ```
+pub struct ModuleAverager<B: Backend> {
+    sums: Vec<(Shape, Tensor<B, 1>)>,
+    count: usize,
+}
+
+impl<B: Backend> Default for ModuleAverager<B> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<B: Backend> ModuleAverager<B> {
+    /// Creates an averager without any module.
+    pub fn new() -> Self {
+        Self {
+            sums: Vec::new(),
+            count: 0,
+        }
+    }
+
+    /// Adds the float tensors of the module to the average.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the module doesn't have the same float tensors, with the same shapes, as the
+    /// modules already added.
+    pub fn add<M: Module<B>>(&mut self, module: &M) {
+        let mut visitor = ModuleSum {
+            sums: &mut self.sums,
+            index: 0,
+            is_first: self.count == 0,
+        };
+        module.visit(&mut visitor);
+        let num_visited = visitor.index;
+
+        assert_eq!(
+            num_visited,
+            self.sums.len(),
+            "The modules must have the same parameters to be averaged"
+        );
+        self.count += 1;
+    }
+
+    /// The number of modules added.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Replaces the float tensors of the module with the average of the modules added.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no module was added, or if the module doesn't have the same float tensors as the
+    /// modules added.
+    pub fn average<M: Module<B>>(self, module: M) -> M {
+        assert!(self.count > 0, "At least one module is required to average");
+
+        module.map(&mut ModuleAverage {
+            sums: self.sums,
+            count: self.count,
+            index: 0,
+        })
+    }
+}
+
+/// Sums the float tensors of modules, flattened in the order they are visited.
+struct ModuleSum<'a, B: Backend> {
+    sums: &'a mut Vec<(Shape, Tensor<B, 1>)>,
+    index: usize,
+    is_first: bool,
+}
+
+impl<B: Backend> ModuleVisitor<B> for ModuleSum<'_, B> {
+    fn visit_float<const D: usize>(&mut self, _id: ParamId, tensor: &Tensor<B, D>) {
+        let shape = tensor.shape();
+        let tensor = tensor.clone().reshape([shape.num_elements()]);
+
+        if self.is_first {
+            self.sums.push((shape, tensor));
+        } else {
+            let (expected, sum) = self
+                .sums
+                .get_mut(self.index)
+                .expect("The modules must have the same parameters to be averaged");
+            assert_eq!(
+                &shape, expected,
+                "The parameters of the modules must have the same shapes to be averaged"
+            );
+            *sum = sum.clone() + tensor;
+        }
+
+        self.index += 1;
+    }
+}
+
+/// Replaces the float tensors of a module with the average of the summed tensors.
+struct ModuleAverage<B: Backend> {
+    sums: Vec<(Shape, Tensor<B, 1>)>,
+    count: usize,
+    index: usize,
+}
+
+impl<B: Backend> ModuleMapper<B> for ModuleAverage<B> {
+    fn map_float<const D: usize>(&mut self, _id: ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let (shape, sum) = self
+            .sums
+            .get(self.index)
+            .cloned()
+            .expect("The module must have the same parameters as the averaged modules");
+        self.index += 1;
+
+        sum.div_scalar(self.count as f64)
+            .reshape(shape)
+            .to_device(&tensor.device())
+    }
+}
@@ -1,4 +1,4 @@
-use super::{ParamId, Quantizer};
+use super::{ModuleSummary, ParamId, Quantizer};
 use crate::{
     record::Record,
     tensor::backend::{AutodiffBackend, Backend},
@@ -125,6 +125,16 @@ pub trait Module<B: Backend>: Clone + Send + core::fmt::Debug {
         )
     }
 
+    /// Freeze the module, so its parameters aren't tracked by autodiff anymore and aren't updated
+    /// by optimizers, even when gradients were computed for them before freezing.
+    ///
+    /// This is the same as [no_grad](Module::no_grad), and is usually applied to a sub-module to
+    /// fine-tune the rest of the model, e.g. `model.encoder = model.encoder.freeze()`. A parameter
+    /// can be trained again with [set_require_grad](crate::module::Param::set_require_grad).
+    fn freeze(self) -> Self {
+        self.no_grad()
+    }
+
     /// Get the number of parameters the module has, including all of its sub-modules.
     fn num_params(&self) -> usize {
         module!(
@@ -136,6 +146,13 @@ pub trait Module<B: Backend>: Clone + Send + core::fmt::Debug {
             init = || 0
         )
     }
+
+    /// Summarize the parameters of the module, with the shape, data type and device of each
+    /// tensor, and the number of parameters and the memory of each sub-module.
+    fn summary(&self) -> ModuleSummary<B> {
+        ModuleSummary::new(self)
+    }
+
     /// Visit each tensor parameter in the module with a [visitor](ModuleVisitor).
     fn visit<Visitor: ModuleVisitor<B>>(&self, visitor: &mut Visitor);
 
@@ -217,6 +234,11 @@ pub trait ModuleVisitor<B: Backend> {
     fn visit_int<const D: usize>(&mut self, _id: ParamId, _tensor: &Tensor<B, D, Int>) {}
     /// Visit a bool tensor in the module.
     fn visit_bool<const D: usize>(&mut self, _id: ParamId, _tensor: &Tensor<B, D, Bool>) {}
+    /// Called before visiting a submodule or a tensor of the module, with the name of its field
+    /// or its index in a vector, an array or a tuple.
+    fn enter_module(&mut self, _name: &str) {}
+    /// Called after visiting the submodule or the tensor [entered](ModuleVisitor::enter_module).
+    fn exit_module(&mut self, _name: &str) {}
 }
 
 /// Module mapper trait.
@@ -0,0 +1,356 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use burn_tensor::{backend::Backend, DType, Shape, Tensor};
+
+use crate::module::{Module, ModuleVisitor, ParamId};
+
+/// Where a float tensor seen by a [dtype audit](DTypeAudit) comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DTypeSource {
+    /// A parameter of the audited module.
+    Param(ParamId),
+    /// An activation recorded during the forward pass, with the name given when recording it.
+    Activation(String),
+}
+
+impl core::fmt::Display for DTypeSource {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DTypeSource::Param(id) => write!(f, "param {id}"),
+            DTypeSource::Activation(name) => f.write_str(name),
+        }
+    }
+}
+
+/// The data type and device of a float tensor seen by a [dtype audit](DTypeAudit).
+#[derive(Debug, Clone)]
+pub struct DTypeUsage<B: Backend> {
+    /// Where the tensor comes from.
+    pub source: DTypeSource,
+    /// The shape of the tensor.
+    pub shape: Shape,
+    /// The data type of the tensor.
+    pub dtype: DType,
+    /// The device of the tensor.
+    pub device: B::Device,
+}
+
+/// A data type or device usage that slows down a model, found by a [dtype audit](DTypeAudit).
+#[derive(Debug, Clone)]
+pub enum DTypeIssue<B: Backend> {
+    /// A tensor is stored in `f64` while the model runs in another precision, which is often an
+    /// `f64` constant created by accident.
+    F64 {
+        /// The tensor stored in `f64`.
+        source: DTypeSource,
+    },
+    /// A tensor has another precision than the model, e.g. an `f32` tensor in an `f16` model.
+    Mismatch {
+        /// The tensor with another precision.
+        source: DTypeSource,
+        /// The data type of the tensor.
+        dtype: DType,
+    },
+    /// An activation was cast to another precision and back to the previous one.
+    RoundTripCast {
+        /// The activation in the other precision.
+        source: DTypeSource,
+        /// The precision of the activation.
+        dtype: DType,
+        /// The precision of the activations before and after it.
+        around: DType,
+    },
+    /// An activation isn't on the device of the previous activation, or of the parameters for
+    /// the first activation.
+    DeviceTransfer {
+        /// The transferred activation.
+        source: DTypeSource,
+        /// The device before the transfer.
+        from: B::Device,
+        /// The device of the activation.
+        to: B::Device,
+    },
+}
+
+impl<B: Backend> DTypeIssue<B> {
+    /// How to fix the issue.
+    pub fn recommendation(&self) -> String {
+        match self {
+            DTypeIssue::F64 { .. } => "Create the tensor with the float element of the backend, \
+                 or cast it to the precision of the model"
+                .to_string(),
+            DTypeIssue::Mismatch { dtype, .. } => format!(
+                "Cast the tensor to the precision of the model, or declare the parameter with \
+                 `Param::with_dtype` if storing it in {} is intended",
+                dtype.name()
+            ),
+            DTypeIssue::RoundTripCast { dtype, around, .. } => format!(
+                "Remove the casts to {} and back to {}",
+                dtype.name(),
+                around.name()
+            ),
+            DTypeIssue::DeviceTransfer { .. } => {
+                "Create the tensor on the device of the model instead of moving it".to_string()
+            }
+        }
+    }
+}
+
+impl<B: Backend> core::fmt::Display for DTypeIssue<B> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DTypeIssue::F64 { source } => write!(f, "{source} is stored in f64"),
+            DTypeIssue::Mismatch { source, dtype } => {
+                write!(f, "{source} is stored in {}", dtype.name())
+            }
+            DTypeIssue::RoundTripCast {
+                source,
+                dtype,
+                around,
+            } => write!(
+                f,
+                "{source} is cast from {} to {} and back",
+                around.name(),
+                dtype.name()
+            ),
+            DTypeIssue::DeviceTransfer { source, from, to } => {
+                write!(f, "{source} is moved from {from:?} to {to:?}")
+            }
+        }?;
+
+        write!(f, ": {}", self.recommendation())
+    }
+}
+
+/// Audit the data types and devices of the parameters of a module and of the activations of its
+/// forward pass, to find what silently slows down a mixed precision model.
+///
+/// The parameters are collected from the module, while the activations are recorded in order
+/// during the forward pass with [record](DTypeAudit::record). The [report](DTypeAudit::report)
+/// flags the `f64` tensors, the tensors with another precision than the model, the activations
+/// cast back and forth and the activations moved between devices.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let mut audit = DTypeAudit::new(&model, DType::F16);
+/// let x = model.embedding.forward(tokens);
+/// audit.record("embedding", &x);
+/// let x = model.encoder.forward(x);
+/// audit.record("encoder", &x);
+///
+/// println!("{}", audit.report());
+/// ```
+#[derive(Debug, Clone)]
+pub struct DTypeAudit<B: Backend> {
+    dtype: DType,
+    params: Vec<DTypeUsage<B>>,
+    activations: Vec<DTypeUsage<B>>,
+}
+
+impl<B: Backend> DTypeAudit<B> {
+    /// Audit the parameters of the module, the model being expected to run in the given float
+    /// data type.
+    pub fn new<M: Module<B>>(module: &M, dtype: DType) -> Self {
+        let mut visitor = ParamVisitor { params: Vec::new() };
+        module.visit(&mut visitor);
+
+        Self {
+            dtype,
+            params: visitor.params,
+            activations: Vec::new(),
+        }
+    }
+
+    /// Record an activation of the forward pass.
+    pub fn record<const D: usize>(&mut self, name: &str, tensor: &Tensor<B, D>) {
+        self.activations.push(DTypeUsage {
+            source: DTypeSource::Activation(name.to_string()),
+            shape: tensor.shape(),
+            dtype: tensor.dtype(),
+            device: tensor.device(),
+        });
+    }
+
+    /// The parameters of the module, in the order they are visited.
+    pub fn params(&self) -> &[DTypeUsage<B>] {
+        &self.params
+    }
+
+    /// The recorded activations, in the order they are recorded.
+    pub fn activations(&self) -> &[DTypeUsage<B>] {
+        &self.activations
+    }
+
+    /// The issues found in the parameters and the recorded activations.
+    pub fn report(&self) -> DTypeReport<B> {
+        let mut issues = Vec::new();
+
+        for usage in self.params.iter().chain(self.activations.iter()) {
+            if usage.dtype == self.dtype {
+                continue;
+            }
+
+            let source = usage.source.clone();
+            match usage.dtype {
+                DType::F64 => issues.push(DTypeIssue::F64 { source }),
+                dtype => issues.push(DTypeIssue::Mismatch { source, dtype }),
+            }
+        }
+
+        for window in self.activations.windows(3) {
+            let (before, current, after) = (&window[0], &window[1], &window[2]);
+
+            if current.dtype != before.dtype && before.dtype == after.dtype {
+                issues.push(DTypeIssue::RoundTripCast {
+                    source: current.source.clone(),
+                    dtype: current.dtype,
+                    around: before.dtype,
+                });
+            }
+        }
+
+        let mut previous = self.params.first();
+        for current in self.activations.iter() {
+            if let Some(previous) = previous {
+                if previous.device != current.device {
+                    issues.push(DTypeIssue::DeviceTransfer {
+                        source: current.source.clone(),
+                        from: previous.device.clone(),
+                        to: current.device.clone(),
+                    });
+                }
+            }
+            previous = Some(current);
+        }
+
+        DTypeReport {
+            dtype: self.dtype,
+            num_params: self.params.len(),
+            num_activations: self.activations.len(),
+            issues,
+        }
+    }
+}
+
+/// The issues found by a [dtype audit](DTypeAudit).
+#[derive(Debug, Clone)]
+pub struct DTypeReport<B: Backend> {
+    /// The float data type the model is expected to run in.
+    pub dtype: DType,
+    /// The number of audited parameters.
+    pub num_params: usize,
+    /// The number of audited activations.
+    pub num_activations: usize,
+    /// The issues, the data type issues first and then the device transfers.
+    pub issues: Vec<DTypeIssue<B>>,
+}
+
+impl<B: Backend> DTypeReport<B> {
+    /// If no issue was found.
+    pub fn is_empty(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+impl<B: Backend> core::fmt::Display for DTypeReport<B> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Audited {} params and {} activations in {}",
+            self.num_params,
+            self.num_activations,
+            self.dtype.name()
+        )?;
+
+        if self.issues.is_empty() {
+            return write!(f, ": no issue found");
+        }
+
+        writeln!(f, ":")?;
+        for issue in self.issues.iter() {
+            writeln!(f, "  {issue}")?;
+        }
+
+        Ok(())
+    }
+}
+
+struct ParamVisitor<B: Backend> {
+    params: Vec<DTypeUsage<B>>,
+}
+
+impl<B: Backend> ModuleVisitor<B> for ParamVisitor<B> {
+    fn visit_float<const D: usize>(&mut self, id: ParamId, tensor: &Tensor<B, D>) {
+        self.params.push(DTypeUsage {
+            source: DTypeSource::Param(id),
+            shape: tensor.shape(),
+            dtype: tensor.dtype(),
+            device: tensor.device(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::{Linear, LinearConfig};
+    use crate::TestBackend;
+    use burn_tensor::FloatDType;
+
+    fn linear() -> Linear<TestBackend> {
+        LinearConfig::new(2, 2).init(&Default::default())
+    }
+
+    #[test]
+    fn should_report_no_issue_for_a_consistent_model() {
+        let linear = linear();
+        let mut audit = DTypeAudit::new(&linear, DType::F32);
+
+        let x = linear.forward(Tensor::<TestBackend, 2>::ones([1, 2], &Default::default()));
+        audit.record("linear", &x);
+
+        let report = audit.report();
+        assert_eq!(audit.params().len(), 2);
+        assert!(report.is_empty());
+        assert_eq!(
+            report.to_string(),
+            "Audited 2 params and 1 activations in f32: no issue found"
+        );
+    }
+
+    #[test]
+    fn should_flag_f64_params_and_round_trip_casts() {
+        let mut linear = linear();
+        linear.weight = linear.weight.with_dtype(FloatDType::F64);
+        let mut audit = DTypeAudit::new(&linear, DType::F32);
+
+        let x = Tensor::<TestBackend, 2>::ones([1, 2], &Default::default());
+        audit.record("input", &x);
+        let x = x.cast(FloatDType::F64);
+        audit.record("upcast", &x);
+        let x = x.cast(FloatDType::F32);
+        audit.record("downcast", &x);
+
+        let report = audit.report();
+        assert_eq!(report.issues.len(), 3);
+        assert!(matches!(
+            &report.issues[0],
+            DTypeIssue::F64 {
+                source: DTypeSource::Param(id)
+            } if *id == linear.weight.id
+        ));
+        assert!(matches!(
+            &report.issues[1],
+            DTypeIssue::F64 {
+                source: DTypeSource::Activation(name)
+            } if name == "upcast"
+        ));
+        assert_eq!(
+            report.issues[2].to_string(),
+            "upcast is cast from f32 to f64 and back: Remove the casts to f64 and back to f32"
+        );
+    }
+}
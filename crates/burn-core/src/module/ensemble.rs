@@ -0,0 +1,405 @@
+use alloc::{format, vec::Vec};
+
+use super::{
+    AutodiffModule, Content, Module, ModuleAverager, ModuleDisplay, ModuleDisplayDefault,
+    ModuleMapper, ModuleVisitor,
+};
+use crate::tensor::{
+    backend::{AutodiffBackend, Backend},
+    ops::Device,
+    Tensor,
+};
+
+/// How the predictions of the models of an [ensemble](Ensemble) are combined.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum EnsembleReduction {
+    /// The predictions are averaged.
+    #[default]
+    Mean,
+    /// Each model votes for the class with its highest score, and the fraction of the votes
+    /// received by each class is returned, so the majority class has the highest score.
+    ///
+    /// The classes are expected along the last dimension of the predictions.
+    Vote,
+}
+
+/// An output that can be combined across the models of an [ensemble](Ensemble).
+pub trait EnsembleOutput: Sized {
+    /// Combines the outputs of the models, which all received the same input.
+    fn combine(outputs: Vec<Self>, reduction: EnsembleReduction) -> Self;
+}
+
+impl<B: Backend, const D: usize> EnsembleOutput for Tensor<B, D> {
+    fn combine(outputs: Vec<Self>, reduction: EnsembleReduction) -> Self {
+        let num_models = outputs.len();
+        let shape = outputs[0].shape();
+        let num_elements = shape.num_elements();
+
+        // The predictions are reduced in a single operation instead of one per model.
+        let outputs = outputs
+            .into_iter()
+            .map(|output| {
+                assert_eq!(
+                    output.shape(),
+                    shape,
+                    "The outputs of the models of an ensemble must have the same shape"
+                );
+
+                let output = match reduction {
+                    EnsembleReduction::Mean => output,
+                    EnsembleReduction::Vote => {
+                        let max = output.clone().max_dim(D - 1).expand(shape.clone());
+                        output.equal(max).float()
+                    }
+                };
+
+                output.reshape([1, num_elements])
+            })
+            .collect();
+
+        let combined = Tensor::cat(outputs, 0).sum_dim(0) / num_models as f64;
+
+        combined.reshape(shape)
+    }
+}
+
+/// An ensemble of models of the same type, whose predictions are combined at inference.
+///
+/// The models are run with the same input with [forward](Ensemble::forward), or concurrently with
+/// [forward_parallel](Ensemble::forward_parallel), and their outputs are combined with the [reduction](EnsembleReduction) of the ensemble. Models with compatible
+/// weights, for instance fine-tuned from the same checkpoint, can also be merged into a single
+/// model with [soup](Ensemble::soup).
+///
+/// The ensemble is a [module](Module), so it can be saved, loaded and moved between devices like
+/// the models it contains.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let ensemble = Ensemble::new(vec![model_1, model_2, model_3]);
+/// let output = ensemble.forward(input, |model, input| model.forward(input));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Ensemble<M> {
+    models: Vec<M>,
+    reduction: EnsembleReduction,
+}
+
+impl<M> Ensemble<M> {
+    /// Creates an ensemble averaging the predictions of the models.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no model.
+    pub fn new(models: Vec<M>) -> Self {
+        assert!(!models.is_empty(), "An ensemble needs at least one model");
+
+        Self {
+            models,
+            reduction: EnsembleReduction::Mean,
+        }
+    }
+
+    /// Sets how the predictions of the models are combined.
+    pub fn with_reduction(mut self, reduction: EnsembleReduction) -> Self {
+        self.reduction = reduction;
+        self
+    }
+
+    /// How the predictions of the models are combined.
+    pub fn reduction(&self) -> EnsembleReduction {
+        self.reduction
+    }
+
+    /// The models of the ensemble.
+    pub fn models(&self) -> &[M] {
+        &self.models
+    }
+
+    /// Returns the models of the ensemble.
+    pub fn into_models(self) -> Vec<M> {
+        self.models
+    }
+
+    /// Runs every model with the same input and combines their outputs.
+    ///
+    /// The input is cloned for each model, which doesn't copy the data of its tensors: the input
+    /// only needs to be uploaded to the device once.
+    pub fn forward<I, O, F>(&self, input: I, forward: F) -> O
+    where
+        I: Clone,
+        O: EnsembleOutput,
+        F: Fn(&M, I) -> O,
+    {
+        let outputs = self
+            .models
+            .iter()
+            .map(|model| forward(model, input.clone()))
+            .collect();
+
+        O::combine(outputs, self.reduction)
+    }
+
+    /// Runs every model with the same input concurrently, each on its own thread, and combines
+    /// their outputs.
+    ///
+    /// The work of all the models is submitted to the device together instead of one model after
+    /// the other, which reduces the latency of ensembles of small models. Each thread gets a clone
+    /// of its model, which doesn't copy the parameters.
+    #[cfg(feature = "std")]
+    pub fn forward_parallel<I, O, F>(&self, input: I, forward: F) -> O
+    where
+        M: Clone + Send,
+        I: Clone + Send,
+        O: EnsembleOutput + Send,
+        F: Fn(&M, I) -> O + Sync,
+    {
+        let forward = &forward;
+        let outputs = std::thread::scope(|scope| {
+            let handles = self
+                .models
+                .iter()
+                .map(|model| {
+                    let model = model.clone();
+                    let input = input.clone();
+                    scope.spawn(move || forward(&model, input))
+                })
+                .collect::<Vec<_>>();
+
+            handles
+                .into_iter()
+                .map(|handle| {
+                    handle
+                        .join()
+                        .unwrap_or_else(|payload| std::panic::resume_unwind(payload))
+                })
+                .collect()
+        });
+
+        O::combine(outputs, self.reduction)
+    }
+
+    /// Merges the models into a single model whose float parameters are the average of the
+    /// parameters of the models (model soup), using a [module averager](ModuleAverager).
+    ///
+    /// The other parameters are taken from the first model.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the models don't have the same parameters, with the same shapes.
+    pub fn soup<B: Backend>(&self) -> M
+    where
+        M: Module<B>,
+    {
+        let mut averager = ModuleAverager::new();
+
+        for model in self.models.iter() {
+            averager.add(model);
+        }
+
+        averager.average(self.models[0].clone())
+    }
+}
+
+impl<M, B> Module<B> for Ensemble<M>
+where
+    M: Module<B>,
+    B: Backend,
+{
+    type Record = Vec<M::Record>;
+
+    fn collect_devices(&self, devices: Vec<B::Device>) -> Vec<B::Device> {
+        self.models.collect_devices(devices)
+    }
+
+    fn fork(self, device: &Device<B>) -> Self {
+        Self {
+            models: self.models.fork(device),
+            reduction: self.reduction,
+        }
+    }
+
+    fn to_device(self, device: &Device<B>) -> Self {
+        Self {
+            models: self.models.to_device(device),
+            reduction: self.reduction,
+        }
+    }
+
+    fn num_params(&self) -> usize {
+        self.models.num_params()
+    }
+
+    fn visit<V: ModuleVisitor<B>>(&self, visitor: &mut V) {
+        self.models.visit(visitor)
+    }
+
+    fn map<Mapper: ModuleMapper<B>>(self, mapper: &mut Mapper) -> Self {
+        Self {
+            models: self.models.map(mapper),
+            reduction: self.reduction,
+        }
+    }
+
+    fn load_record(self, record: Self::Record) -> Self {
+        Self {
+            models: self.models.load_record(record),
+            reduction: self.reduction,
+        }
+    }
+
+    fn into_record(self) -> Self::Record {
+        self.models.into_record()
+    }
+}
+
+impl<M, B> AutodiffModule<B> for Ensemble<M>
+where
+    M: AutodiffModule<B>,
+    B: AutodiffBackend,
+{
+    type InnerModule = Ensemble<M::InnerModule>;
+
+    fn valid(&self) -> Self::InnerModule {
+        Ensemble {
+            models: self.models.valid(),
+            reduction: self.reduction,
+        }
+    }
+}
+
+impl<M: ModuleDisplay> ModuleDisplayDefault for Ensemble<M> {
+    fn content(&self, content: Content) -> Option<Content> {
+        self.models
+            .iter()
+            .enumerate()
+            .fold(content, |acc, (i, model)| {
+                let index = format!("{}", i);
+                acc.add(&index, model)
+            })
+            .add("reduction", &format!("{:?}", self.reduction))
+            .set_top_level_type(format!("Ensemble<0..{}>", self.models.len()).as_str())
+            .optional()
+    }
+}
+
+impl<M: ModuleDisplay> ModuleDisplay for Ensemble<M> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::{Linear, LinearConfig};
+    use crate::tensor::TensorData;
+    use crate::TestBackend;
+    use alloc::vec;
+
+    fn linear(weight: [[f32; 2]; 2], bias: [f32; 2]) -> Linear<TestBackend> {
+        let device = Default::default();
+        let linear = LinearConfig::new(2, 2).init::<TestBackend>(&device);
+
+        Linear {
+            weight: linear.weight.map(|_| Tensor::from_floats(weight, &device)),
+            bias: linear
+                .bias
+                .map(|param| param.map(|_| Tensor::from_floats(bias, &device))),
+        }
+    }
+
+    fn ensemble() -> Ensemble<Linear<TestBackend>> {
+        Ensemble::new(vec![
+            linear([[1.0, 0.0], [0.0, 1.0]], [0.0, 0.0]),
+            linear([[2.0, 0.0], [0.0, 0.0]], [1.0, 0.0]),
+            linear([[0.0, 0.0], [0.0, 2.0]], [0.0, 2.0]),
+        ])
+    }
+
+    #[test]
+    fn test_ensemble_mean() {
+        let input = Tensor::<TestBackend, 2>::from_floats([[1.0, 2.0]], &Default::default());
+
+        let output = ensemble().forward(input, |model, input| model.forward(input));
+
+        // Outputs: [1, 2], [3, 0], [0, 6]
+        let expected = TensorData::from([[4.0 / 3.0, 8.0 / 3.0]]);
+        output.into_data().assert_approx_eq(&expected, 5);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_ensemble_parallel_matches_sequential() {
+        let input = Tensor::<TestBackend, 2>::from_floats([[1.0, 2.0]], &Default::default());
+        let ensemble = ensemble();
+
+        let output = ensemble.forward_parallel(input.clone(), |model, input| model.forward(input));
+        let expected = ensemble.forward(input, |model, input| model.forward(input));
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 5);
+    }
+
+    #[test]
+    fn test_ensemble_vote() {
+        let input = Tensor::<TestBackend, 2>::from_floats([[1.0, 2.0]], &Default::default());
+
+        let output = ensemble()
+            .with_reduction(EnsembleReduction::Vote)
+            .forward(input, |model, input| model.forward(input));
+
+        // Votes: class 1, class 0, class 1
+        let expected = TensorData::from([[1.0 / 3.0, 2.0 / 3.0]]);
+        output.into_data().assert_approx_eq(&expected, 5);
+    }
+
+    #[test]
+    fn test_ensemble_soup() {
+        let model = ensemble().soup::<TestBackend>();
+
+        model
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[1.0, 0.0], [0.0, 1.0]]), 5);
+        model
+            .bias
+            .unwrap()
+            .val()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([1.0 / 3.0, 2.0 / 3.0]), 5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_ensemble_soup_incompatible_models() {
+        let device = Default::default();
+        let ensemble = Ensemble::new(vec![
+            LinearConfig::new(2, 2).init::<TestBackend>(&device),
+            LinearConfig::new(2, 3).init::<TestBackend>(&device),
+        ]);
+
+        ensemble.soup::<TestBackend>();
+    }
+
+    #[test]
+    fn test_ensemble_record() {
+        let ensemble = ensemble();
+        let record = ensemble.clone().into_record();
+        let device = Default::default();
+
+        let loaded = Ensemble::new(vec![
+            LinearConfig::new(2, 2).init::<TestBackend>(&device),
+            LinearConfig::new(2, 2).init::<TestBackend>(&device),
+            LinearConfig::new(2, 2).init::<TestBackend>(&device),
+        ])
+        .load_record(record);
+
+        for (model, expected) in loaded.models().iter().zip(ensemble.models()) {
+            model
+                .weight
+                .val()
+                .into_data()
+                .assert_eq(&expected.weight.val().into_data(), true);
+        }
+        assert_eq!(Module::num_params(&loaded), 18);
+    }
+}
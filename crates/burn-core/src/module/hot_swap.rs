@@ -0,0 +1,243 @@
+use core::marker::PhantomData;
+use core::ops::Deref;
+use std::sync::{Arc, Mutex};
+
+use super::Module;
+use crate::tensor::backend::Backend;
+
+/// The installed version of the module.
+#[derive(Debug)]
+struct Slot<M> {
+    module: M,
+    epoch: u64,
+    // Shared with the guards of the version, to count the requests still using it.
+    guards: Arc<()>,
+}
+
+/// A module whose weights can be replaced while it is used, so a running inference service can
+/// update its model without downtime.
+///
+/// Requests [load](Self::load) a guard holding the version of the module they started with, so in
+/// flight requests complete with the weights they started with while new requests use the new
+/// weights. The guard holds a clone of the module, which is cheap since the parameters aren't
+/// copied, and can be sent to another thread.
+///
+/// Each installed version starts a new epoch. The tensors of a version aren't updated in place:
+/// [reload](Self::reload) loads the record into a clone of the current version with
+/// [load_record](Module::load_record), which allocates new tensors, so the memory of both
+/// versions is used until the last request of the retired version completes.
+///
+/// The [model pool](crate::serving::ModelPool) and the [micro batcher](crate::serving::MicroBatcher)
+/// serve hot swapped modules, see [ModelPool::reload](crate::serving::ModelPool::reload) and
+/// [MicroBatcher::hot_swapped](crate::serving::MicroBatcher::hot_swapped).
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let model = HotSwapModule::new(model);
+///
+/// // In each request.
+/// let output = model.load().forward(input);
+///
+/// // In a background task, when a new checkpoint is available.
+/// model.reload_file("checkpoint", &recorder, &device)?;
+/// ```
+#[derive(Debug)]
+pub struct HotSwapModule<B: Backend, M: Module<B>> {
+    active: Mutex<Slot<M>>,
+    retired: Mutex<Option<Arc<()>>>,
+    backend: PhantomData<B>,
+}
+
+/// A version of a [hot swapped module](HotSwapModule), used by a request.
+#[derive(Debug, Clone)]
+pub struct HotSwapGuard<M> {
+    module: M,
+    epoch: u64,
+    _guards: Arc<()>,
+}
+
+impl<M> HotSwapGuard<M> {
+    /// The epoch at which this version of the module was installed.
+    pub fn epoch(&self) -> u64 {
+        self.epoch
+    }
+}
+
+impl<M> Deref for HotSwapGuard<M> {
+    type Target = M;
+
+    fn deref(&self) -> &Self::Target {
+        &self.module
+    }
+}
+
+impl<B: Backend, M: Module<B>> HotSwapModule<B, M> {
+    /// Creates a hot swapped module, starting at epoch zero.
+    pub fn new(module: M) -> Self {
+        Self {
+            active: Mutex::new(Slot {
+                module,
+                epoch: 0,
+                guards: Arc::new(()),
+            }),
+            retired: Mutex::new(None),
+            backend: PhantomData,
+        }
+    }
+
+    /// Loads the current version of the module.
+    ///
+    /// The guard keeps using this version, even if the module is swapped in the meantime.
+    pub fn load(&self) -> HotSwapGuard<M> {
+        let active = self.active.lock().unwrap();
+
+        HotSwapGuard {
+            module: active.module.clone(),
+            epoch: active.epoch,
+            _guards: active.guards.clone(),
+        }
+    }
+
+    /// The epoch of the current version of the module, incremented by each swap.
+    pub fn epoch(&self) -> u64 {
+        self.active.lock().unwrap().epoch
+    }
+
+    /// The number of guards still using the version retired by the last swap.
+    ///
+    /// The tensors of the retired version are released once this number reaches zero, unless
+    /// they are shared with the current version.
+    pub fn num_retired_guards(&self) -> usize {
+        match self.retired.lock().unwrap().as_ref() {
+            Some(guards) => Arc::strong_count(guards) - 1,
+            None => 0,
+        }
+    }
+
+    /// Installs a new version of the module, returning its epoch.
+    pub fn swap(&self, module: M) -> u64 {
+        let mut retired = self.retired.lock().unwrap();
+
+        self.install(&mut retired, module)
+    }
+
+    /// Loads the record into a clone of the current version and installs it, returning its epoch.
+    pub fn reload(&self, record: M::Record) -> u64 {
+        // Reloads are serialized by the lock, while requests keep loading the current version.
+        let mut retired = self.retired.lock().unwrap();
+        let module = self.active.lock().unwrap().module.clone();
+
+        self.install(&mut retired, module.load_record(record))
+    }
+
+    /// Loads the record from a file with the provided [file recorder](crate::record::FileRecorder)
+    /// and installs it, returning its epoch.
+    ///
+    /// The file is read before the module is swapped, so requests aren't blocked while loading.
+    pub fn reload_file<FR, PB>(
+        &self,
+        file_path: PB,
+        recorder: &FR,
+        device: &B::Device,
+    ) -> Result<u64, crate::record::RecorderError>
+    where
+        FR: crate::record::FileRecorder<B>,
+        PB: Into<std::path::PathBuf>,
+    {
+        let record = recorder.load(file_path.into(), device)?;
+
+        Ok(self.reload(record))
+    }
+
+    fn install(&self, retired: &mut Option<Arc<()>>, module: M) -> u64 {
+        let mut active = self.active.lock().unwrap();
+        let epoch = active.epoch + 1;
+        let previous = core::mem::replace(
+            &mut *active,
+            Slot {
+                module,
+                epoch,
+                guards: Arc::new(()),
+            },
+        );
+        core::mem::drop(active);
+
+        *retired = Some(previous.guards);
+
+        epoch
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::{Linear, LinearConfig};
+    use crate::tensor::{Tensor, TensorData};
+    use crate::TestBackend;
+
+    fn linear(weight: f32) -> Linear<TestBackend> {
+        let device = Default::default();
+        let linear = LinearConfig::new(1, 1)
+            .with_bias(false)
+            .init::<TestBackend>(&device);
+
+        Linear {
+            weight: linear
+                .weight
+                .map(|_| Tensor::from_floats([[weight]], &device)),
+            bias: None,
+        }
+    }
+
+    fn forward(model: &Linear<TestBackend>) -> TensorData {
+        model
+            .forward(Tensor::<TestBackend, 2>::from_floats(
+                [[1.0]],
+                &Default::default(),
+            ))
+            .into_data()
+    }
+
+    #[test]
+    fn test_in_flight_requests_keep_their_version() {
+        let model = HotSwapModule::new(linear(1.0));
+        let in_flight = model.load();
+
+        let epoch = model.reload(linear(2.0).into_record());
+
+        assert_eq!(epoch, 1);
+        assert_eq!(in_flight.epoch(), 0);
+        assert_eq!(model.num_retired_guards(), 1);
+        forward(&in_flight).assert_eq(&TensorData::from([[1.0]]), false);
+        forward(&model.load()).assert_eq(&TensorData::from([[2.0]]), false);
+
+        core::mem::drop(in_flight);
+        assert_eq!(model.num_retired_guards(), 0);
+    }
+
+    #[test]
+    fn test_reload_twice() {
+        let model = HotSwapModule::new(linear(1.0));
+
+        model.reload(linear(2.0).into_record());
+        let epoch = model.reload(linear(3.0).into_record());
+
+        assert_eq!(epoch, 2);
+        assert_eq!(model.epoch(), 2);
+        forward(&model.load()).assert_eq(&TensorData::from([[3.0]]), false);
+    }
+
+    #[test]
+    fn test_swap_from_other_thread() {
+        let model = Arc::new(HotSwapModule::new(linear(1.0)));
+        let reloader = model.clone();
+
+        std::thread::spawn(move || reloader.swap(linear(4.0)))
+            .join()
+            .unwrap();
+
+        assert_eq!(model.epoch(), 1);
+        forward(&model.load()).assert_eq(&TensorData::from([[4.0]]), false);
+    }
+}
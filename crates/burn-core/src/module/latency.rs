@@ -0,0 +1,192 @@
+use core::time::Duration;
+use std::time::Instant;
+
+use crate::tensor::backend::Backend;
+
+/// The time spent in a layer of a forward pass, measured by a [latency profiler](LatencyProfiler).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LayerLatency {
+    /// The name given when profiling the layer.
+    pub name: String,
+    /// The number of layers the layer is nested in.
+    pub depth: usize,
+    /// The time spent in the layer, including its nested layers.
+    pub duration: Duration,
+}
+
+/// Measure the latency of each layer of a forward pass.
+///
+/// The device is synchronized before and after each [layer](LatencyProfiler::layer), so the time
+/// of a layer is the time taken by the kernels it launches instead of the time to launch them,
+/// which is how asynchronous backends like the jit backends run. Synchronizing prevents the
+/// kernels of a layer from overlapping with the next ones, so the profiled pass is slower than a
+/// normal one, but the proportion of each layer stays representative.
+///
+/// Layers can be nested to attribute the time of a module to its submodules.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let mut profiler = LatencyProfiler::<B>::new(&device);
+/// let x = profiler.layer("embedding", |_| model.embedding.forward(tokens));
+/// let x = profiler.layer("encoder", |profiler| {
+///     model.blocks.iter().enumerate().fold(x, |x, (i, block)| {
+///         profiler.layer(&format!("block.{i}"), |_| block.forward(x))
+///     })
+/// });
+///
+/// println!("{}", profiler.report());
+/// ```
+#[derive(Debug)]
+pub struct LatencyProfiler<B: Backend> {
+    device: B::Device,
+    layers: Vec<LayerLatency>,
+    depth: usize,
+    start: Instant,
+}
+
+impl<B: Backend> LatencyProfiler<B> {
+    /// Create a profiler for the forward pass on the given device, waiting for the operations
+    /// already submitted to the device to complete.
+    pub fn new(device: &B::Device) -> Self {
+        B::sync(device);
+
+        Self {
+            device: device.clone(),
+            layers: Vec::new(),
+            depth: 0,
+            start: Instant::now(),
+        }
+    }
+
+    /// Run a layer of the forward pass and measure its latency.
+    pub fn layer<O, F: FnOnce(&mut Self) -> O>(&mut self, name: &str, forward: F) -> O {
+        // The layer is recorded before its nested layers, so the report lists them in order.
+        let index = self.layers.len();
+        self.layers.push(LayerLatency {
+            name: name.to_string(),
+            depth: self.depth,
+            duration: Duration::ZERO,
+        });
+
+        B::sync(&self.device);
+        let start = Instant::now();
+
+        self.depth += 1;
+        let output = forward(self);
+        self.depth -= 1;
+
+        B::sync(&self.device);
+        self.layers[index].duration = start.elapsed();
+
+        output
+    }
+
+    /// The latency of the profiled layers, in the order they started.
+    pub fn layers(&self) -> &[LayerLatency] {
+        &self.layers
+    }
+
+    /// The latency breakdown of the forward pass since the profiler was created.
+    pub fn report(&self) -> LatencyReport {
+        B::sync(&self.device);
+
+        LatencyReport {
+            total: self.start.elapsed(),
+            layers: self.layers.clone(),
+        }
+    }
+}
+
+/// The latency breakdown of a forward pass, measured by a [latency profiler](LatencyProfiler).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LatencyReport {
+    /// The time of the whole pass.
+    pub total: Duration,
+    /// The time of each layer, in the order they started.
+    pub layers: Vec<LayerLatency>,
+}
+
+impl LatencyReport {
+    /// The time spent outside of the top level layers.
+    pub fn unattributed(&self) -> Duration {
+        let attributed = self
+            .layers
+            .iter()
+            .filter(|layer| layer.depth == 0)
+            .map(|layer| layer.duration)
+            .sum();
+
+        self.total.saturating_sub(attributed)
+    }
+}
+
+impl core::fmt::Display for LatencyReport {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let total = self.total.as_secs_f64();
+        let percent = |duration: Duration| {
+            if total > 0.0 {
+                100.0 * duration.as_secs_f64() / total
+            } else {
+                0.0
+            }
+        };
+
+        writeln!(f, "Total: {:?}", self.total)?;
+        for layer in self.layers.iter() {
+            writeln!(
+                f,
+                "{:indent$}{}: {:?} ({:.1}%)",
+                "",
+                layer.name,
+                layer.duration,
+                percent(layer.duration),
+                indent = 2 * (layer.depth + 1)
+            )?;
+        }
+
+        let unattributed = self.unattributed();
+        writeln!(
+            f,
+            "  unattributed: {:?} ({:.1}%)",
+            unattributed,
+            percent(unattributed)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::LinearConfig;
+    use crate::tensor::Tensor;
+    use crate::TestBackend;
+
+    #[test]
+    fn should_profile_nested_layers_in_order() {
+        let device = Default::default();
+        let linear = LinearConfig::new(4, 4).init::<TestBackend>(&device);
+        let x = Tensor::<TestBackend, 2>::ones([2, 4], &device);
+
+        let mut profiler = LatencyProfiler::<TestBackend>::new(&device);
+        let x = profiler.layer("encoder", |profiler| {
+            let x = profiler.layer("linear1", |_| linear.forward(x));
+            profiler.layer("linear2", |_| linear.forward(x))
+        });
+        let _ = profiler.layer("head", |_| x.sum());
+
+        let report = profiler.report();
+        let layers = report
+            .layers
+            .iter()
+            .map(|layer| (layer.name.as_str(), layer.depth))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            layers,
+            [("encoder", 0), ("linear1", 1), ("linear2", 1), ("head", 0)]
+        );
+        assert!(report.layers[0].duration >= report.layers[1].duration);
+        assert!(report.total >= report.layers[0].duration + report.layers[3].duration);
+        assert!(report.to_string().contains("    linear1: "));
+    }
+}
@@ -1,9 +1,29 @@
+mod average;
 mod base;
 mod display;
+mod dtype_audit;
+mod ensemble;
+#[cfg(feature = "std")]
+mod hot_swap;
+#[cfg(feature = "std")]
+mod latency;
 mod param;
 mod quantize;
+mod summary;
+#[cfg(all(feature = "tch", feature = "std"))]
+mod tch_compat;
 
+pub use average::*;
 pub use base::*;
 pub use display::*;
+pub use dtype_audit::*;
+pub use ensemble::*;
+#[cfg(feature = "std")]
+pub use hot_swap::*;
+#[cfg(feature = "std")]
+pub use latency::*;
 pub use param::*;
 pub use quantize::*;
+pub use summary::*;
+#[cfg(all(feature = "tch", feature = "std"))]
+pub use tch_compat::*;
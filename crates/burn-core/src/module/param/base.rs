@@ -2,6 +2,7 @@ use super::ParamId;
 use alloc::boxed::Box;
 use alloc::format;
 use burn_common::stub::RwLock;
+use burn_tensor::FloatDType;
 use core::cell::OnceCell;
 use core::ops::Deref;
 
@@ -41,6 +42,12 @@ pub struct Param<T: Parameter> {
     /// when the lock is actually useful, waiting for the initialization to be completed before
     /// returning the value.
     initialization: Option<RwLock<Option<Uninitialized<T>>>>,
+    /// The data type declared for a float parameter, used when it is initialized and when a record
+    /// is loaded. The default float data type of the backend is used when none is declared.
+    dtype: Option<FloatDType>,
+    /// If the value wraps memory allocated and owned outside of burn, see
+    /// [is_external](Self::is_external).
+    external: bool,
 }
 
 impl<T: Parameter> core::fmt::Display for Param<T> {
@@ -91,6 +98,8 @@ impl<T: Parameter> Param<T> {
             id,
             state: OnceCell::from(value),
             initialization: None,
+            dtype: None,
+            external: false,
         }
     }
 
@@ -107,6 +116,8 @@ impl<T: Parameter> Param<T> {
                 device,
                 is_require_grad,
             }))),
+            dtype: None,
+            external: false,
         }
     }
 
@@ -142,6 +153,7 @@ impl<T: Parameter> Param<T> {
 
     /// Execute the given function on the inner value.
     pub fn map<F: FnOnce(T) -> T>(self, func: F) -> Self {
+        let dtype = self.dtype;
         let (id, tensor) = self.consume();
         let tensor = func(tensor);
 
@@ -149,9 +161,68 @@ impl<T: Parameter> Param<T> {
             id,
             state: OnceCell::from(tensor),
             initialization: None,
+            dtype,
+            external: false,
         }
     }
 
+    /// Execute the given function on the inner value, without triggering the initialization of a
+    /// lazy parameter.
+    pub(crate) fn lazy_map<F>(self, func: F) -> Self
+    where
+        F: FnOnce(T) -> T + Send + 'static,
+        T: 'static,
+        T::Device: 'static,
+    {
+        let uninitialized = match &self.initialization {
+            Some(init) => init.write().unwrap().take(),
+            None => None,
+        };
+
+        match uninitialized {
+            Some(value) => {
+                let init = value.init;
+                let mut param = Self::uninitialized(
+                    self.id,
+                    move |device, is_require_grad| func(init(device, is_require_grad)),
+                    value.device,
+                    value.is_require_grad,
+                );
+                param.dtype = self.dtype;
+                param
+            }
+            None => self.map(func),
+        }
+    }
+
+    /// The data type declared for the parameter, if any.
+    pub(crate) fn declared_dtype(&self) -> Option<FloatDType> {
+        self.dtype
+    }
+
+    /// Declare the data type of the parameter, without converting its value.
+    pub(crate) fn declare_dtype(mut self, dtype: Option<FloatDType>) -> Self {
+        self.dtype = dtype;
+        self
+    }
+
+    /// If the parameter value wraps memory allocated and owned outside of burn, for instance
+    /// shared with another process or allocated by another framework.
+    ///
+    /// The parameter only borrows the memory, which is never mutated in-place by burn. Replacing
+    /// the value, for instance when an optimizer updates the parameter or when a record is loaded,
+    /// writes the new value to memory managed by burn, after which the parameter isn't external
+    /// anymore.
+    pub fn is_external(&self) -> bool {
+        self.external
+    }
+
+    /// Declare if the parameter value wraps external memory, without changing its value.
+    pub(crate) fn declare_external(mut self, external: bool) -> Self {
+        self.external = external;
+        self
+    }
+
     /// The device on which the parameter is or will be initialized.
     ///
     /// This should be used instead of [crate::tensor::Tensor::device], since using the tensor
@@ -206,9 +277,16 @@ impl<T: Parameter> Param<T> {
 
     /// Override the gradient requirement for the current parameter.
     pub fn set_require_grad(self, require_grad: bool) -> Self {
+        // Only the gradient requirement changes, the value still wraps the same memory.
+        let external = self.external;
+
         let initialization = match &self.initialization {
             Some(init) => init,
-            None => return self.map(|tensor| tensor.set_require_grad(require_grad)),
+            None => {
+                return self
+                    .map(|tensor| tensor.set_require_grad(require_grad))
+                    .declare_external(external)
+            }
         };
 
         let mut init = initialization.write().unwrap();
@@ -226,12 +304,15 @@ impl<T: Parameter> Param<T> {
         }
 
         self.map(|tensor| tensor.set_require_grad(require_grad))
+            .declare_external(external)
     }
 }
 
 impl<T: Parameter> Clone for Param<T> {
     fn clone(&self) -> Self {
         Param::initialized(self.id, self.val())
+            .declare_dtype(self.dtype)
+            .declare_external(self.external)
     }
 }
 
@@ -8,7 +8,7 @@ use crate::tensor::{
     Tensor,
 };
 use alloc::{format, string::ToString, vec::Vec};
-use burn_tensor::{ops::Device, Bool, Float, Int, TensorData};
+use burn_tensor::{ops::Device, Bool, DType, Element, Float, FloatDType, Int, TensorData};
 
 impl<B: Backend, const D: usize> Parameter for Tensor<B, D, Float> {
     type Device = B::Device;
@@ -82,6 +82,80 @@ impl<B: Backend, const D: usize> Param<Tensor<B, D>> {
         let value = Tensor::from_data(data, device);
         Param::initialized(ParamId::new(), value.require_grad())
     }
+
+    /// Create a new parameter wrapping a float tensor whose memory is allocated and owned outside
+    /// of burn, for instance shared with another process or allocated by an inference runtime,
+    /// without copying it.
+    ///
+    /// The tensor is usually created from a backend primitive importing the external memory, such
+    /// as `TchTensor::from_external` for the LibTorch backend, which guarantees that the memory is
+    /// never mutated in-place.
+    ///
+    /// # Backend support
+    ///
+    /// Only the LibTorch backend can import external memory without copying it, with
+    /// `TchTensor::from_external` or `TchTensor::from_blob`. The other backends, including the
+    /// jit backends, don't have such a primitive: their tensors are created from data, which copies
+    /// the memory to buffers managed by the backend. The parameter is only marked as
+    /// [external](Param::is_external) when the backend reports that the tensor
+    /// [wraps external memory](Tensor::is_external); otherwise it's a regular parameter and the
+    /// external buffer can be released as soon as the tensor is created.
+    ///
+    /// # Ownership and mutability
+    ///
+    /// - The parameter only borrows the memory, which must stay valid as long as the parameter or
+    ///   any tensor derived from its value is alive.
+    /// - The parameter doesn't require gradients, so it isn't updated by optimizers. It can be
+    ///   marked as [requiring gradients](Param::set_require_grad) to fine-tune it, in which case
+    ///   the optimizer writes the updated value to memory managed by burn.
+    /// - Any operation replacing the value, like an optimizer step, [loading a record](Module::load_record)
+    ///   or [mapping the module](Module::map), leaves the external memory untouched and the
+    ///   parameter isn't [external](Param::is_external) anymore.
+    pub fn from_external(value: Tensor<B, D>) -> Self {
+        let external = value.is_external();
+        Param::initialized(ParamId::new(), value.set_require_grad(false)).declare_external(external)
+    }
+
+    /// Declare the floating point data type used to store the parameter.
+    ///
+    /// This allows modules to mix parameters of different precisions, for instance keeping the
+    /// normalization layers in `f32` while the embeddings are stored in `f16`. The value is
+    /// converted to the given data type, lazily if the parameter isn't initialized yet, and records
+    /// loaded into the parameter are converted to it as well. The parameter is saved with its own
+    /// precision, regardless of the [precision settings](crate::record::PrecisionSettings) of the
+    /// recorder.
+    ///
+    /// # Notes
+    ///
+    /// The gradients of the parameter have the same data type as the parameter, but most backends
+    /// don't have automatic type promotion, so the parameter should be [cast](Tensor::cast) to the
+    /// precision of the computation when used in the forward pass.
+    pub fn with_dtype(self, dtype: FloatDType) -> Self {
+        self.lazy_map(move |tensor| cast_param(tensor, dtype))
+            .declare_dtype(Some(dtype))
+    }
+
+    /// The data type in which the parameter is loaded from a record.
+    fn expected_dtype(&self) -> DType {
+        match self.declared_dtype() {
+            Some(dtype) => dtype.into(),
+            None => B::FloatElem::dtype(),
+        }
+    }
+}
+
+/// Cast a parameter value, keeping it a leaf of the autodiff graph.
+fn cast_param<B: Backend, const D: usize>(tensor: Tensor<B, D>, dtype: FloatDType) -> Tensor<B, D> {
+    if tensor.dtype() == DType::from(dtype) {
+        return tensor;
+    }
+
+    // Detaching keeps the gradient requirement, which would track the cast as an operation.
+    let is_require_grad = tensor.is_require_grad();
+    tensor
+        .set_require_grad(false)
+        .cast(dtype)
+        .set_require_grad(is_require_grad)
 }
 
 impl<const D: usize, B: Backend> Module<B> for Param<Tensor<B, D>> {
@@ -92,9 +166,10 @@ impl<const D: usize, B: Backend> Module<B> for Param<Tensor<B, D>> {
     }
 
     fn map<M: ModuleMapper<B>>(self, mapper: &mut M) -> Self {
+        let dtype = self.declared_dtype();
         let (id, tensor) = self.consume();
         let value = mapper.map_float(id, tensor);
-        Self::initialized(id, value)
+        Self::initialized(id, value).declare_dtype(dtype)
     }
 
     fn into_record(self) -> Self::Record {
@@ -106,16 +181,23 @@ impl<const D: usize, B: Backend> Module<B> for Param<Tensor<B, D>> {
 
         let expected_device = self.lazy_device();
         let expected_require_grad = self.lazy_is_require_grad();
+        let expected_dtype = self.expected_dtype();
 
         // Make sure we load the record into the same module device.
         if new_value.device() != expected_device {
             new_value = new_value.to_device(&expected_device).detach();
         }
 
+        // Make sure we load the record with the declared precision, since records keep the data
+        // type of each tensor.
+        if new_value.dtype() != expected_dtype {
+            new_value = new_value.set_require_grad(false).cast(expected_dtype);
+        }
+
         // Make sure we load the record with the same autodiff setting.
         new_value = new_value.set_require_grad(expected_require_grad);
 
-        Self::initialized(new_id, new_value)
+        Self::initialized(new_id, new_value).declare_dtype(self.declared_dtype())
     }
 
     fn to_device(self, device: &Device<B>) -> Self {
@@ -297,6 +379,7 @@ impl<const D: usize, B: AutodiffBackend> AutodiffModule<B> for Param<Tensor<B, D
 
     fn valid(&self) -> Self::InnerModule {
         Param::initialized(self.id, self.val().inner().set_require_grad(false))
+            .declare_external(self.is_external())
     }
 }
 
@@ -312,7 +395,7 @@ impl<const D: usize, B: AutodiffBackend> AutodiffModule<B> for Param<Tensor<B, D
     type InnerModule = Param<Tensor<B::InnerBackend, D, Bool>>;
 
     fn valid(&self) -> Self::InnerModule {
-        Param::initialized(self.id, self.val().inner())
+        Param::initialized(self.id, self.val().inner()).declare_dtype(self.declared_dtype())
     }
 }
 
@@ -321,7 +404,10 @@ mod tests {
     use super::*;
     use crate::{
         module::Module,
-        record::{BinBytesRecorder, FullPrecisionSettings, Recorder},
+        nn::Initializer,
+        record::{
+            BinBytesRecorder, FullPrecisionSettings, HalfPrecisionSettings, Record, Recorder,
+        },
         TestAutodiffBackend,
     };
 
@@ -350,4 +436,121 @@ mod tests {
         assert!(!no_grad_is_require_grad);
         assert!(with_default_is_require_grad);
     }
+
+    #[test]
+    fn test_with_dtype_lazy_initialization() {
+        let device = Default::default();
+        let param = Initializer::Ones
+            .init::<TestAutodiffBackend, 2, _>([2, 2], &device)
+            .with_dtype(FloatDType::F64);
+
+        assert_eq!(param.dtype(), DType::F64);
+        assert!(param.is_require_grad());
+    }
+
+    #[test]
+    fn test_load_record_declared_dtype() {
+        let device = Default::default();
+        let tensor = Tensor::<TestAutodiffBackend, 2>::ones([2, 2], &device);
+
+        let byte_recorder = BinBytesRecorder::<FullPrecisionSettings>::default();
+        let bytes = byte_recorder
+            .record(
+                Param::from_tensor(tensor.clone())
+                    .with_dtype(FloatDType::F64)
+                    .into_record(),
+                (),
+            )
+            .unwrap();
+
+        // The record is loaded with the backend float type and cast to the declared type.
+        let record: Param<Tensor<TestAutodiffBackend, 2>> =
+            byte_recorder.load(bytes.clone(), &device).unwrap();
+        assert_eq!(
+            record.dtype(),
+            <TestAutodiffBackend as Backend>::FloatElem::dtype()
+        );
+
+        let declared = Param::from_tensor(tensor.clone())
+            .with_dtype(FloatDType::F64)
+            .load_record(byte_recorder.load(bytes.clone(), &device).unwrap());
+        let default = Param::from_tensor(tensor).load_record(record);
+
+        assert_eq!(declared.dtype(), DType::F64);
+        assert!(declared.is_require_grad());
+        assert_eq!(declared.clone().dtype(), DType::F64);
+        assert_eq!(
+            default.dtype(),
+            <TestAutodiffBackend as Backend>::FloatElem::dtype()
+        );
+    }
+
+    #[test]
+    fn test_load_record_saved_with_unsupported_dtype() {
+        let device = Default::default();
+        let tensor = Tensor::<TestAutodiffBackend, 2>::ones([2, 2], &device);
+
+        // Data saved in half precision, which the test backend doesn't support, is converted to
+        // the backend float type.
+        let mut item = Param::from_tensor(tensor).into_item::<FullPrecisionSettings>();
+        item.param.data = item.param.data.convert::<burn_tensor::f16>();
+
+        let record = Param::<Tensor<TestAutodiffBackend, 2>>::from_item(item, &device);
+        assert_eq!(
+            record.dtype(),
+            <TestAutodiffBackend as Backend>::FloatElem::dtype()
+        );
+        record
+            .val()
+            .into_data()
+            .assert_eq(&TensorData::from([[1.0f32, 1.0], [1.0, 1.0]]), false);
+    }
+
+    #[test]
+    fn test_external_param_copied_by_backend() {
+        let device = Default::default();
+        let tensor = Tensor::<TestAutodiffBackend, 2>::ones([2, 2], &device);
+
+        // The test backend can't import external memory, so the tensor owns its data.
+        let param = Param::from_external(tensor);
+        assert!(!param.is_external());
+        assert!(!param.is_require_grad());
+    }
+
+    #[test]
+    fn test_external_param_ownership() {
+        let device = Default::default();
+        let tensor = Tensor::<TestAutodiffBackend, 2>::ones([2, 2], &device);
+
+        // Simulate a backend that wrapped the external memory.
+        let param = Param::initialized(ParamId::new(), tensor.clone().set_require_grad(false))
+            .declare_external(true);
+        assert!(param.is_external());
+        assert!(!param.is_require_grad());
+
+        // Changing the gradient requirement keeps the same memory.
+        let param = param.set_require_grad(true);
+        assert!(param.is_external());
+        assert!(param.clone().is_external());
+
+        // Loading a record replaces the value with memory managed by burn.
+        let record = Param::from_tensor(tensor.clone()).into_record();
+        let param = param.load_record(record);
+        assert!(!param.is_external());
+        assert!(param.is_require_grad());
+    }
+
+    #[test]
+    fn test_record_precision_only_kept_for_declared_dtype() {
+        let device = Default::default();
+        let tensor = Tensor::<TestAutodiffBackend, 2>::ones([2, 2], &device);
+
+        let default = Param::from_tensor(tensor.clone()).into_item::<HalfPrecisionSettings>();
+        let declared = Param::from_tensor(tensor)
+            .with_dtype(FloatDType::F32)
+            .into_item::<HalfPrecisionSettings>();
+
+        assert_eq!(default.param.data.dtype, DType::F16);
+        assert_eq!(declared.param.data.dtype, DType::F32);
+    }
 }
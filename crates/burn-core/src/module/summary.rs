@@ -0,0 +1,294 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use burn_tensor::{backend::Backend, Bool, DType, Int, Shape, Tensor};
+
+use crate::module::{Module, ModuleVisitor, ParamId};
+
+/// A tensor of a module, listed by a [module summary](ModuleSummary).
+#[derive(Debug, Clone)]
+pub struct ParamSummary<B: Backend> {
+    /// The identifier of the parameter.
+    pub id: ParamId,
+    /// The path of the parameter in the module, e.g. `encoder.layers.0.weight`.
+    pub path: String,
+    /// The shape of the tensor.
+    pub shape: Shape,
+    /// The data type of the tensor.
+    pub dtype: DType,
+    /// The device of the tensor.
+    pub device: B::Device,
+    /// If the tensor requires gradients.
+    pub trainable: bool,
+}
+
+impl<B: Backend> ParamSummary<B> {
+    /// The number of elements of the tensor.
+    pub fn num_params(&self) -> usize {
+        self.shape.num_elements()
+    }
+
+    /// The memory used by the tensor in bytes.
+    pub fn memory(&self) -> usize {
+        (self.num_params() * self.dtype.size_bits()).div_ceil(8)
+    }
+}
+
+/// A submodule of a module, listed by a [module summary](ModuleSummary).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubmoduleSummary {
+    /// The path of the submodule in the module, e.g. `encoder.layers.0`.
+    pub path: String,
+    /// The number of submodules the submodule is nested in.
+    pub depth: usize,
+    /// The number of parameters of the submodule, including its nested submodules.
+    pub num_params: usize,
+    /// The memory used by the parameters of the submodule in bytes.
+    pub memory: usize,
+}
+
+/// A structured report of the parameters of a module, like the `torchsummary` of PyTorch.
+///
+/// The submodules are listed in the order of their fields, each one with the number of
+/// parameters and the memory of its nested submodules. Submodules without parameters, like
+/// activations, aren't listed.
+#[derive(Debug, Clone)]
+pub struct ModuleSummary<B: Backend> {
+    /// The submodules, in the order they are visited.
+    pub submodules: Vec<SubmoduleSummary>,
+    /// The tensors of the module, in the order they are visited.
+    pub params: Vec<ParamSummary<B>>,
+}
+
+impl<B: Backend> ModuleSummary<B> {
+    /// Summarize the parameters of the module.
+    pub fn new<M: Module<B>>(module: &M) -> Self {
+        let mut visitor = SummaryVisitor {
+            path: Vec::new(),
+            stack: Vec::new(),
+            submodules: Vec::new(),
+            params: Vec::new(),
+        };
+        module.visit(&mut visitor);
+
+        // The fields holding a tensor are entered like submodules but are listed as parameters,
+        // and the fields without any tensor, like an absent optional bias, aren't listed.
+        let submodules = visitor
+            .submodules
+            .into_iter()
+            .filter_map(|(submodule, is_param)| {
+                (!is_param && submodule.num_params > 0).then_some(submodule)
+            })
+            .collect();
+
+        Self {
+            submodules,
+            params: visitor.params,
+        }
+    }
+
+    /// The total number of parameters.
+    pub fn num_params(&self) -> usize {
+        self.params.iter().map(|param| param.num_params()).sum()
+    }
+
+    /// The number of parameters requiring gradients.
+    pub fn num_trainable_params(&self) -> usize {
+        self.params
+            .iter()
+            .filter(|param| param.trainable)
+            .map(|param| param.num_params())
+            .sum()
+    }
+
+    /// The memory used by the parameters in bytes.
+    pub fn memory(&self) -> usize {
+        self.params.iter().map(|param| param.memory()).sum()
+    }
+}
+
+impl<B: Backend> core::fmt::Display for ModuleSummary<B> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        writeln!(f, "{:<40} {:>14} {:>12}", "Module", "Params", "Memory")?;
+        for submodule in self.submodules.iter() {
+            let name = submodule.path.rsplit('.').next().unwrap_or_default();
+            writeln!(
+                f,
+                "{:<40} {:>14} {:>12}",
+                format!("{:indent$}{name}", "", indent = 2 * submodule.depth),
+                submodule.num_params,
+                Memory(submodule.memory)
+            )?;
+        }
+
+        writeln!(f, "Total params: {}", self.num_params())?;
+        writeln!(f, "Trainable params: {}", self.num_trainable_params())?;
+        writeln!(f, "Memory: {}", Memory(self.memory()))
+    }
+}
+
+/// Display a number of bytes with a binary unit.
+struct Memory(usize);
+
+impl core::fmt::Display for Memory {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+        if self.0 < 1024 {
+            return f.pad(&format!("{} B", self.0));
+        }
+
+        let mut value = self.0 as f64 / 1024.0;
+        let mut unit = 0;
+        while value >= 1024.0 && unit < UNITS.len() - 1 {
+            value /= 1024.0;
+            unit += 1;
+        }
+
+        f.pad(&format!("{value:.1} {}", UNITS[unit]))
+    }
+}
+
+struct SummaryVisitor<B: Backend> {
+    path: Vec<String>,
+    // The indices of the entered submodules, with the innermost last.
+    stack: Vec<usize>,
+    // The submodules, with whether they hold a tensor directly.
+    submodules: Vec<(SubmoduleSummary, bool)>,
+    params: Vec<ParamSummary<B>>,
+}
+
+impl<B: Backend> SummaryVisitor<B> {
+    fn add(&mut self, id: ParamId, shape: Shape, dtype: DType, device: B::Device) {
+        self.add_param(ParamSummary {
+            id,
+            path: self.path.join("."),
+            shape,
+            dtype,
+            device,
+            trainable: false,
+        });
+    }
+
+    fn add_param(&mut self, param: ParamSummary<B>) {
+        if let Some((index, parents)) = self.stack.split_last() {
+            self.submodules[*index].1 = true;
+
+            for parent in parents {
+                let submodule = &mut self.submodules[*parent].0;
+                submodule.num_params += param.num_params();
+                submodule.memory += param.memory();
+            }
+        }
+
+        self.params.push(param);
+    }
+}
+
+impl<B: Backend> ModuleVisitor<B> for SummaryVisitor<B> {
+    fn visit_float<const D: usize>(&mut self, id: ParamId, tensor: &Tensor<B, D>) {
+        self.add_param(ParamSummary {
+            id,
+            path: self.path.join("."),
+            shape: tensor.shape(),
+            dtype: tensor.dtype(),
+            device: tensor.device(),
+            trainable: tensor.is_require_grad(),
+        });
+    }
+
+    fn visit_int<const D: usize>(&mut self, id: ParamId, tensor: &Tensor<B, D, Int>) {
+        self.add(id, tensor.shape(), tensor.dtype(), tensor.device());
+    }
+
+    fn visit_bool<const D: usize>(&mut self, id: ParamId, tensor: &Tensor<B, D, Bool>) {
+        self.add(id, tensor.shape(), tensor.dtype(), tensor.device());
+    }
+
+    fn enter_module(&mut self, name: &str) {
+        self.path.push(name.to_string());
+        self.stack.push(self.submodules.len());
+        self.submodules.push((
+            SubmoduleSummary {
+                path: self.path.join("."),
+                depth: self.stack.len() - 1,
+                num_params: 0,
+                memory: 0,
+            },
+            false,
+        ));
+    }
+
+    fn exit_module(&mut self, _name: &str) {
+        self.path.pop();
+        self.stack.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as burn;
+    use crate::nn::{Embedding, EmbeddingConfig, Linear, LinearConfig};
+    use crate::TestBackend;
+    use alloc::vec;
+
+    #[derive(Module, Debug)]
+    struct Model<B: Backend> {
+        embedding: Embedding<B>,
+        layers: Vec<Linear<B>>,
+    }
+
+    #[test]
+    fn should_summarize_nested_submodules() {
+        let device = Default::default();
+        let model = Model::<TestBackend> {
+            embedding: EmbeddingConfig::new(10, 4).init(&device),
+            layers: vec![
+                LinearConfig::new(4, 8).init(&device),
+                LinearConfig::new(8, 2).with_bias(false).init(&device),
+            ],
+        };
+
+        let summary = model.summary();
+        let submodules = summary
+            .submodules
+            .iter()
+            .map(|submodule| {
+                (
+                    submodule.path.as_str(),
+                    submodule.depth,
+                    submodule.num_params,
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            submodules,
+            [
+                ("embedding", 0, 40),
+                ("layers", 0, 56),
+                ("layers.0", 1, 40),
+                ("layers.1", 1, 16),
+            ]
+        );
+
+        let paths = summary
+            .params
+            .iter()
+            .map(|param| param.path.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            [
+                "embedding.weight",
+                "layers.0.weight",
+                "layers.0.bias",
+                "layers.1.weight"
+            ]
+        );
+        assert_eq!(summary.num_params(), model.num_params());
+        assert_eq!(summary.memory(), 96 * 4);
+        assert!(summary.to_string().contains("Total params: 96"));
+    }
+}
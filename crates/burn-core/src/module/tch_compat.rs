@@ -0,0 +1,268 @@
+use alloc::sync::Arc;
+use core::marker::PhantomData;
+use std::sync::Mutex;
+
+use burn_tch::{tch, LibTorch, QuantElement, TchElement, TchTensor};
+
+use super::{
+    ConstantRecord, Content, Devices, Module, ModuleDisplay, ModuleDisplayDefault, ModuleMapper,
+    ModuleVisitor, ParamId,
+};
+use crate::tensor::{Tensor, TensorPrimitive};
+
+/// A burn [module](Module) on the [LibTorch] backend usable as a [tch module](tch::nn::Module).
+///
+/// The float parameters of the module are moved into a [var store](tch::nn::VarStore), so they
+/// are trained by the optimizers of tch, and the burn module reads the variables without copying
+/// them. Since the operations of the [LibTorch] backend are tch operations, the gradients flow
+/// through the burn module like through any other tch module.
+///
+/// This allows porting a tch model layer by layer, while keeping its training loop.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let vs = tch::nn::VarStore::new(tch::Device::Cpu);
+/// let linear = LinearConfig::new(64, 32).init::<LibTorch>(&LibTorchDevice::Cpu);
+/// let linear = BurnModule::new(&(vs.root() / "linear"), linear, |linear, x: Tensor<_, 2>| {
+///     linear.forward(x)
+/// });
+///
+/// let model = tch::nn::seq().add(linear).add_fn(|xs| xs.relu());
+/// ```
+pub struct BurnModule<E, M, F, const D_IN: usize, const D_OUT: usize>
+where
+    E: TchElement,
+{
+    module: M,
+    forward: F,
+    _element: PhantomData<E>,
+}
+
+impl<E, M, F, const D_IN: usize, const D_OUT: usize> BurnModule<E, M, F, D_IN, D_OUT>
+where
+    E: TchElement,
+    M: Module<LibTorch<E>>,
+    F: Fn(&M, Tensor<LibTorch<E>, D_IN>) -> Tensor<LibTorch<E>, D_OUT> + Send,
+{
+    /// Wraps the module, registering its float parameters as variables of the given path.
+    ///
+    /// The parameters are named after their [id](ParamId), and the `forward` function computes
+    /// the output of the module.
+    pub fn new(path: &tch::nn::Path, module: M, forward: F) -> Self {
+        let module = module.map(&mut VarStoreMapper { path });
+
+        Self {
+            module,
+            forward,
+            _element: PhantomData,
+        }
+    }
+
+    /// The wrapped module, whose parameters share their memory with the var store.
+    pub fn module(&self) -> &M {
+        &self.module
+    }
+
+    /// Returns the wrapped module, whose parameters share their memory with the var store.
+    pub fn into_module(self) -> M {
+        self.module
+    }
+}
+
+impl<E, M, F, const D_IN: usize, const D_OUT: usize> core::fmt::Debug
+    for BurnModule<E, M, F, D_IN, D_OUT>
+where
+    E: TchElement,
+    M: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("BurnModule")
+            .field("module", &self.module)
+            .finish()
+    }
+}
+
+impl<E, M, F, const D_IN: usize, const D_OUT: usize> tch::nn::Module
+    for BurnModule<E, M, F, D_IN, D_OUT>
+where
+    E: TchElement,
+    M: Module<LibTorch<E>>,
+    F: Fn(&M, Tensor<LibTorch<E>, D_IN>) -> Tensor<LibTorch<E>, D_OUT> + Send,
+{
+    fn forward(&self, xs: &tch::Tensor) -> tch::Tensor {
+        let input = TchTensor::new(xs.shallow_clone());
+        // Holding a reference to the storage prevents burn from updating the input in place.
+        let _storage = input.storage.clone();
+
+        let output = (self.forward)(
+            &self.module,
+            Tensor::from_primitive(TensorPrimitive::Float(input)),
+        );
+
+        output.into_primitive().tensor().tensor
+    }
+}
+
+struct VarStoreMapper<'a, 'p> {
+    path: &'a tch::nn::Path<'p>,
+}
+
+impl<E: TchElement, Q: QuantElement> ModuleMapper<LibTorch<E, Q>> for VarStoreMapper<'_, '_> {
+    fn map_float<const D: usize>(
+        &mut self,
+        id: ParamId,
+        tensor: Tensor<LibTorch<E, Q>, D>,
+    ) -> Tensor<LibTorch<E, Q>, D> {
+        let tensor = tensor.into_primitive().tensor();
+        let variable = self.path.var_copy(&id.to_string(), &tensor.tensor);
+
+        Tensor::from_primitive(TensorPrimitive::Float(TchTensor::new(variable)))
+    }
+}
+
+/// A [tch module](tch::nn::Module) usable as a burn [module](Module) on the [LibTorch] backend.
+///
+/// The tensors are passed to the tch module without copies. The parameters of the tch module
+/// stay in its [var store](tch::nn::VarStore): they aren't part of the burn record, and are
+/// trained and saved with tch.
+#[derive(Clone)]
+pub struct TchModule {
+    module: Arc<Mutex<Box<dyn tch::nn::Module>>>,
+}
+
+impl TchModule {
+    /// Wraps the tch module.
+    pub fn new<M: tch::nn::Module + 'static>(module: M) -> Self {
+        Self {
+            module: Arc::new(Mutex::new(Box::new(module))),
+        }
+    }
+
+    /// Applies the tch module on the input tensor.
+    pub fn forward<E, Q, const D_IN: usize, const D_OUT: usize>(
+        &self,
+        input: Tensor<LibTorch<E, Q>, D_IN>,
+    ) -> Tensor<LibTorch<E, Q>, D_OUT>
+    where
+        E: TchElement,
+        Q: QuantElement,
+    {
+        let input = input.into_primitive().tensor();
+        let output = self
+            .module
+            .lock()
+            .expect("The tch module should not be poisoned")
+            .forward(&input.tensor);
+
+        assert_eq!(
+            output.dim(),
+            D_OUT,
+            "The output of the tch module should have {D_OUT} dimensions"
+        );
+
+        Tensor::from_primitive(TensorPrimitive::Float(TchTensor::new(output)))
+    }
+}
+
+impl core::fmt::Debug for TchModule {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.module.lock() {
+            Ok(module) => f.debug_tuple("TchModule").field(&module).finish(),
+            Err(_) => f.write_str("TchModule(<poisoned>)"),
+        }
+    }
+}
+
+impl<E: TchElement, Q: QuantElement> Module<LibTorch<E, Q>> for TchModule {
+    type Record = ConstantRecord;
+
+    fn collect_devices(&self, devices: Devices<LibTorch<E, Q>>) -> Devices<LibTorch<E, Q>> {
+        devices
+    }
+
+    fn fork(self, _device: &burn_tch::LibTorchDevice) -> Self {
+        self
+    }
+
+    fn to_device(self, _device: &burn_tch::LibTorchDevice) -> Self {
+        self
+    }
+
+    fn visit<V: ModuleVisitor<LibTorch<E, Q>>>(&self, _visitor: &mut V) {
+        // The parameters are managed by tch.
+    }
+
+    fn map<Mapper: ModuleMapper<LibTorch<E, Q>>>(self, _mapper: &mut Mapper) -> Self {
+        self
+    }
+
+    fn load_record(self, _record: Self::Record) -> Self {
+        self
+    }
+
+    fn into_record(self) -> Self::Record {
+        ConstantRecord::new()
+    }
+}
+
+impl ModuleDisplayDefault for TchModule {
+    fn content(&self, content: Content) -> Option<Content> {
+        content.add_formatted(&"TchModule").optional()
+    }
+}
+
+impl ModuleDisplay for TchModule {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::LinearConfig;
+    use burn_tch::LibTorchDevice;
+    use tch::nn::Module as _;
+
+    #[test]
+    fn burn_module_should_be_trained_by_tch() {
+        let vs = tch::nn::VarStore::new(tch::Device::Cpu);
+        let linear = LinearConfig::new(2, 3).init::<LibTorch>(&LibTorchDevice::Cpu);
+        let input = Tensor::<LibTorch, 2>::from_floats([[1.0, 2.0]], &LibTorchDevice::Cpu);
+        let expected = linear.forward(input.clone()).into_data();
+
+        let module = BurnModule::new(&vs.root(), linear, |linear, x: Tensor<_, 2>| {
+            linear.forward(x)
+        });
+        let output = module.forward(&input.into_primitive().tensor().tensor);
+
+        assert_eq!(vs.trainable_variables().len(), 2);
+        Tensor::<LibTorch, 2>::from_primitive(TensorPrimitive::Float(TchTensor::new(
+            output.shallow_clone(),
+        )))
+        .into_data()
+        .assert_approx_eq(&expected, 5);
+
+        output.sum(tch::Kind::Float).backward();
+        for variable in vs.trainable_variables() {
+            assert!(variable.grad().defined());
+        }
+    }
+
+    #[test]
+    fn tch_module_should_run_on_burn_tensors() {
+        let vs = tch::nn::VarStore::new(tch::Device::Cpu);
+        let linear = tch::nn::linear(vs.root(), 2, 3, Default::default());
+        let input = Tensor::<LibTorch, 2>::from_floats([[1.0, 2.0]], &LibTorchDevice::Cpu);
+        let expected = linear.forward(&input.clone().into_primitive().tensor().tensor);
+
+        let module = TchModule::new(linear);
+        let output: Tensor<LibTorch, 2> = module.forward(input);
+
+        output.into_data().assert_approx_eq(
+            &Tensor::<LibTorch, 2>::from_primitive(TensorPrimitive::Float(TchTensor::new(
+                expected,
+            )))
+            .into_data(),
+            5,
+        );
+        assert_eq!(Module::<LibTorch>::num_params(&module), 0);
+    }
+}
@@ -0,0 +1,227 @@
+use alloc::vec::Vec;
+
+use crate as burn;
+use crate::config::Config;
+use crate::module::{Content, DisplaySettings, Module, ModuleDisplay};
+use crate::tensor::backend::Backend;
+use crate::tensor::{Int, Tensor, TensorData};
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// Configuration to create an [AlibiBias](AlibiBias) layer using the [init function](AlibiBiasConfig::init).
+#[derive(Config, Debug)]
+pub struct AlibiBiasConfig {
+    /// The number of attention heads.
+    pub n_heads: usize,
+
+    /// The maximum bias, defining the slope of the first head. Defaults to 8.0
+    #[config(default = "8.0")]
+    pub max_bias: f32,
+}
+
+impl AlibiBiasConfig {
+    /// Initialize a new [AlibiBias](AlibiBias) module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of heads is zero.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> AlibiBias<B> {
+        assert!(self.n_heads > 0, "The number of heads must be positive");
+
+        // The slopes form a geometric sequence starting at `2^(-max_bias / n)` when the number
+        // of heads is a power of 2. Otherwise, the slopes of the closest lower power of 2 are
+        // completed with every other slope of the next power of 2.
+        let n_closest = 1 << self.n_heads.ilog2();
+        let base = 2.0f32.powf(-self.max_bias / n_closest as f32);
+        let base_extra = 2.0f32.powf(-self.max_bias / (2 * n_closest) as f32);
+
+        let slopes = (0..self.n_heads)
+            .map(|head| match head < n_closest {
+                true => base.powi(head as i32 + 1),
+                false => base_extra.powi(2 * (head - n_closest) as i32 + 1),
+            })
+            .collect::<Vec<_>>();
+
+        AlibiBias {
+            slopes: Tensor::from_data(TensorData::new(slopes, [self.n_heads]), device),
+            max_bias: self.max_bias,
+        }
+    }
+}
+
+/// Attention with Linear Biases (ALiBi).
+///
+/// Instead of adding positional information to the embeddings, ALiBi adds a bias to the attention
+/// scores proportional to the distance between the query and the key, with a different slope per
+/// head. It allows models to extrapolate to sequences longer than the ones seen during training.
+///
+/// Introduced in the paper: [Train Short, Test Long: Attention with Linear Biases Enables Input
+/// Length Extrapolation](https://arxiv.org/abs/2108.12409)
+///
+/// The bias can be added to the attention scores with [apply](AlibiBias::apply), or provided to
+/// the [multi-head attention](crate::nn::attention::MultiHeadAttention) with
+/// [MhaInput::attn_bias](crate::nn::attention::MhaInput::attn_bias).
+///
+/// Should be created using [AlibiBiasConfig].
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct AlibiBias<B: Backend> {
+    /// The slope of each head, of shape `[n_heads]`.
+    pub slopes: Tensor<B, 1>,
+    /// The maximum bias, defining the slope of the first head.
+    pub max_bias: f32,
+}
+
+impl<B: Backend> ModuleDisplay for AlibiBias<B> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        let [n_heads] = self.slopes.dims();
+        content
+            .add("n_heads", &n_heads)
+            .add("max_bias", &self.max_bias)
+            .optional()
+    }
+}
+
+impl<B: Backend> AlibiBias<B> {
+    /// Computes the attention bias between the queries and the keys.
+    ///
+    /// The queries are the last positions of the keys, so the bias stays correct when generating
+    /// with a cache, where only the new positions are queried against all the cached keys.
+    ///
+    /// # Shapes
+    ///
+    /// - output: `[1, n_heads, seq_length_query, seq_length_key]`
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are more queries than keys.
+    pub fn forward(&self, seq_length_query: usize, seq_length_key: usize) -> Tensor<B, 4> {
+        assert!(
+            seq_length_query <= seq_length_key,
+            "The number of queries ({seq_length_query}) must not exceed the number of keys ({seq_length_key})"
+        );
+
+        let device = self.slopes.device();
+        let [n_heads] = self.slopes.dims();
+        let offset = (seq_length_key - seq_length_query) as i64;
+
+        let query = Tensor::<B, 1, Int>::arange(offset..seq_length_key as i64, &device)
+            .reshape([seq_length_query, 1]);
+        let key = Tensor::<B, 1, Int>::arange(0..seq_length_key as i64, &device)
+            .reshape([1, seq_length_key]);
+
+        let distance = key.sub(query).abs().neg().float();
+
+        self.slopes.clone().reshape([1, n_heads, 1, 1])
+            * distance.reshape([1, 1, seq_length_query, seq_length_key])
+    }
+
+    /// Adds the attention bias to the attention scores.
+    ///
+    /// # Shapes
+    ///
+    /// - attn_scores: `[batch_size, n_heads, seq_length_query, seq_length_key]`
+    /// - output: `[batch_size, n_heads, seq_length_query, seq_length_key]`
+    pub fn apply(&self, attn_scores: Tensor<B, 4>) -> Tensor<B, 4> {
+        let [_, _, seq_length_query, seq_length_key] = attn_scores.dims();
+
+        attn_scores + self.forward(seq_length_query, seq_length_key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn test_alibi_slopes() {
+        let device = Default::default();
+        let alibi = AlibiBiasConfig::new(8).init::<TestBackend>(&device);
+
+        alibi.slopes.into_data().assert_approx_eq(
+            &TensorData::from([
+                0.5f32, 0.25, 0.125, 0.0625, 0.03125, 0.015625, 0.0078125, 0.00390625,
+            ]),
+            6,
+        );
+    }
+
+    #[test]
+    fn test_alibi_slopes_not_power_of_two() {
+        let device = Default::default();
+        let alibi = AlibiBiasConfig::new(6).init::<TestBackend>(&device);
+
+        alibi.slopes.into_data().assert_approx_eq(
+            &TensorData::from([0.25f32, 0.0625, 0.015625, 0.00390625, 0.5, 0.125]),
+            6,
+        );
+    }
+
+    #[test]
+    fn test_alibi_forward() {
+        let device = Default::default();
+        let alibi = AlibiBiasConfig::new(2).init::<TestBackend>(&device);
+
+        let bias = alibi.forward(3, 3);
+
+        bias.into_data().assert_approx_eq(
+            &TensorData::from([[
+                [
+                    [0.0f32, -0.0625, -0.125],
+                    [-0.0625, 0.0, -0.0625],
+                    [-0.125, -0.0625, 0.0],
+                ],
+                [
+                    [0.0, -0.00390625, -0.0078125],
+                    [-0.00390625, 0.0, -0.00390625],
+                    [-0.0078125, -0.00390625, 0.0],
+                ],
+            ]]),
+            6,
+        );
+    }
+
+    #[test]
+    fn test_alibi_forward_with_cache() {
+        let device = Default::default();
+        let alibi = AlibiBiasConfig::new(2).init::<TestBackend>(&device);
+
+        // The bias of the last query is the same when the previous positions are cached.
+        let full = alibi.forward(3, 3).narrow(2, 2, 1);
+        let cached = alibi.forward(1, 3);
+
+        cached.into_data().assert_approx_eq(&full.into_data(), 6);
+    }
+
+    #[test]
+    fn test_alibi_apply() {
+        let device = Default::default();
+        let alibi = AlibiBiasConfig::new(2).init::<TestBackend>(&device);
+        let scores = Tensor::<TestBackend, 4>::ones([2, 2, 3, 3], &device);
+
+        let output = alibi.apply(scores);
+        let expected = alibi.forward(3, 3).add_scalar(1.0).repeat_dim(0, 2);
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 6);
+    }
+
+    #[test]
+    fn display() {
+        let alibi = AlibiBiasConfig::new(4).init::<TestBackend>(&Default::default());
+
+        assert_eq!(
+            alloc::format!("{}", alibi),
+            "AlibiBias {n_heads: 4, max_bias: 8}"
+        );
+    }
+}
@@ -0,0 +1,331 @@
+use alloc::vec;
+
+use crate::tensor::{backend::Backend, Int, Tensor};
+
+/// Cache of the keys and values of a [multi-head attention](super::MultiHeadAttention) layer,
+/// used with [forward_kv_cache](super::MultiHeadAttention::forward_kv_cache) to decode tokens one
+/// at a time without recomputing the previous positions.
+///
+/// The cache either grows with each decoded token, or is pre-allocated for a maximum sequence
+/// length to avoid re-allocating the keys and values at each step. A cache with
+/// [attention sinks](Self::with_attention_sinks) keeps a bounded number of positions instead, so
+/// sequences of unbounded length can be decoded.
+pub struct KvCache<B: Backend> {
+    state: Option<KvCacheState<B>>,
+    max_seq_length: Option<usize>,
+    rolling_window: Option<RollingWindow>,
+    num_evicted: usize,
+}
+
+/// The first positions kept as attention sinks, followed by a sliding window of the most recent
+/// positions.
+#[derive(Clone, Copy)]
+struct RollingWindow {
+    num_sinks: usize,
+    window_size: usize,
+}
+
+struct KvCacheState<B: Backend> {
+    /// Shape `[batch_size, n_heads, capacity, d_k]`
+    key: Tensor<B, 4>,
+    /// Shape `[batch_size, n_heads, capacity, d_k]`
+    value: Tensor<B, 4>,
+    length: usize,
+}
+
+impl<B: Backend> Default for KvCache<B> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<B: Backend> KvCache<B> {
+    /// Creates an empty cache, growing as the tokens are decoded.
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            max_seq_length: None,
+            rolling_window: None,
+            num_evicted: 0,
+        }
+    }
+
+    /// Creates an empty cache pre-allocated for the given maximum sequence length.
+    ///
+    /// The memory is allocated with the first keys and values, since their shape isn't known before.
+    pub fn with_max_seq_length(max_seq_length: usize) -> Self {
+        Self {
+            state: None,
+            max_seq_length: Some(max_seq_length),
+            rolling_window: None,
+            num_evicted: 0,
+        }
+    }
+
+    /// Creates an empty cache keeping the first `num_sinks` positions and a sliding window of the
+    /// `window_size` most recent positions, as in StreamingLLM.
+    ///
+    /// The attention concentrates on the first tokens whatever their content, so keeping them as
+    /// attention sinks preserves the quality of the generation while the positions in between are
+    /// evicted. The positions are re-indexed after the evictions: the position of a token is its
+    /// index in the cache, so the next token is at position [len](Self::len) and the positions
+    /// stay smaller than `num_sinks + window_size`. The positional encodings, like rotary
+    /// encodings, must be applied with those positions, after retrieving the keys from the cache.
+    ///
+    /// The positions are evicted once the new positions are appended, so the new positions
+    /// always attend to all the positions of the cache.
+    pub fn with_attention_sinks(num_sinks: usize, window_size: usize) -> Self {
+        assert!(
+            window_size > 0,
+            "The sliding window must keep at least one position"
+        );
+
+        Self {
+            state: None,
+            max_seq_length: None,
+            rolling_window: Some(RollingWindow {
+                num_sinks,
+                window_size,
+            }),
+            num_evicted: 0,
+        }
+    }
+
+    /// The number of positions in the cache.
+    pub fn len(&self) -> usize {
+        self.state.as_ref().map(|state| state.length).unwrap_or(0)
+    }
+
+    /// Whether the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The number of positions evicted from a cache with
+    /// [attention sinks](Self::with_attention_sinks), so the number of positions decoded is
+    /// `len() + num_evicted()`.
+    pub fn num_evicted(&self) -> usize {
+        self.num_evicted
+    }
+
+    /// Removes all the positions from the cache, keeping the pre-allocated memory.
+    pub fn reset(&mut self) {
+        if let Some(state) = self.state.as_mut() {
+            state.length = 0;
+        }
+        self.num_evicted = 0;
+    }
+
+    /// Reorders the batch of the cache, for instance to follow the hypotheses selected during a
+    /// beam search.
+    ///
+    /// # Shapes
+    ///
+    /// - indices: `[batch_size]`, the index of the previous batch item of each new batch item.
+    pub fn reorder(&mut self, indices: Tensor<B, 1, Int>) {
+        if let Some(state) = self.state.as_mut() {
+            state.key = state.key.clone().select(0, indices.clone());
+            state.value = state.value.clone().select(0, indices);
+        }
+    }
+
+    /// Appends the keys and values of the new positions, returning the keys and values of all
+    /// the positions in the cache.
+    ///
+    /// # Shapes
+    ///
+    /// - key: `[batch_size, n_heads, seq_length, d_k]`
+    /// - value: `[batch_size, n_heads, seq_length, d_k]`
+    /// - output: `[batch_size, n_heads, cache_length, d_k]`
+    pub(crate) fn append(
+        &mut self,
+        key: Tensor<B, 4>,
+        value: Tensor<B, 4>,
+    ) -> (Tensor<B, 4>, Tensor<B, 4>) {
+        let [batch_size, n_heads, seq_length, d_k] = key.dims();
+        let length = self.len() + seq_length;
+
+        let state = match (self.state.take(), self.max_seq_length) {
+            (Some(state), None) if state.length > 0 => KvCacheState {
+                key: Tensor::cat(vec![state.key, key], 2),
+                value: Tensor::cat(vec![state.value, value], 2),
+                length,
+            },
+            (_, None) => KvCacheState { key, value, length },
+            (state, Some(max_seq_length)) => {
+                assert!(
+                    length <= max_seq_length,
+                    "The cache can't hold more than {max_seq_length} positions, got {length}"
+                );
+
+                let (key_cache, value_cache) = match state {
+                    Some(state) => (state.key, state.value),
+                    None => {
+                        let device = key.device();
+                        let shape = [batch_size, n_heads, max_seq_length, d_k];
+                        (Tensor::zeros(shape, &device), Tensor::zeros(shape, &device))
+                    }
+                };
+                let ranges = [
+                    0..batch_size,
+                    0..n_heads,
+                    length - seq_length..length,
+                    0..d_k,
+                ];
+
+                KvCacheState {
+                    key: key_cache.slice_assign(ranges.clone(), key),
+                    value: value_cache.slice_assign(ranges, value),
+                    length,
+                }
+            }
+        };
+
+        let output = match self.max_seq_length {
+            Some(_) => (
+                state.key.clone().narrow(2, 0, length),
+                state.value.clone().narrow(2, 0, length),
+            ),
+            None => (state.key.clone(), state.value.clone()),
+        };
+
+        self.state = Some(match self.rolling_window {
+            Some(window) => self.evict(state, window),
+            None => state,
+        });
+        output
+    }
+
+    /// Evicts the positions between the attention sinks and the sliding window.
+    fn evict(&mut self, state: KvCacheState<B>, window: RollingWindow) -> KvCacheState<B> {
+        let capacity = window.num_sinks + window.window_size;
+
+        if state.length <= capacity {
+            return state;
+        }
+
+        let start = state.length - window.window_size;
+        let keep = |tensor: Tensor<B, 4>| match window.num_sinks {
+            0 => tensor.narrow(2, start, window.window_size),
+            num_sinks => Tensor::cat(
+                vec![
+                    tensor.clone().narrow(2, 0, num_sinks),
+                    tensor.narrow(2, start, window.window_size),
+                ],
+                2,
+            ),
+        };
+
+        self.num_evicted += state.length - capacity;
+
+        KvCacheState {
+            key: keep(state.key),
+            value: keep(state.value),
+            length: capacity,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::{Distribution, TensorData};
+    use crate::TestBackend;
+
+    fn append_all(mut cache: KvCache<TestBackend>, tensor: Tensor<TestBackend, 4>) -> TensorData {
+        let [_, _, seq_length, _] = tensor.dims();
+        let mut output = None;
+
+        for i in 0..seq_length {
+            let token = tensor.clone().narrow(2, i, 1);
+            output = Some(cache.append(token.clone(), token).0);
+        }
+
+        assert_eq!(cache.len(), seq_length);
+        output.unwrap().into_data()
+    }
+
+    #[test]
+    fn test_kv_cache_append() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 4>::random([2, 3, 4, 5], Distribution::Default, &device);
+
+        let dynamic = append_all(KvCache::new(), tensor.clone());
+        let preallocated = append_all(KvCache::with_max_seq_length(6), tensor.clone());
+
+        dynamic.assert_eq(&tensor.into_data(), true);
+        preallocated.assert_eq(&dynamic, true);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_kv_cache_exceeding_max_seq_length() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 4>::zeros([1, 1, 3, 2], &device);
+
+        append_all(KvCache::with_max_seq_length(2), tensor);
+    }
+
+    #[test]
+    fn test_kv_cache_reorder() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 4>::random([2, 1, 3, 2], Distribution::Default, &device);
+        let mut cache = KvCache::with_max_seq_length(4);
+
+        cache.append(tensor.clone(), tensor.clone());
+        // Both hypotheses continue from the second batch item.
+        cache.reorder(Tensor::from_ints([1, 1], &device));
+
+        let item = tensor.narrow(0, 1, 1).repeat_dim(0, 2);
+        let token = item.clone().narrow(2, 0, 1);
+        let (key, _value) = cache.append(token.clone(), token.clone());
+
+        let expected = Tensor::cat(vec![item, token], 2);
+        key.into_data().assert_eq(&expected.into_data(), true);
+    }
+
+    #[test]
+    fn test_kv_cache_attention_sinks() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 4>::random([1, 2, 7, 3], Distribution::Default, &device);
+        let mut cache = KvCache::with_attention_sinks(2, 3);
+
+        for i in 0..7 {
+            let token = tensor.clone().narrow(2, i, 1);
+            let (key, _value) = cache.append(token.clone(), token);
+
+            // The new position attends to all the positions of the cache before the eviction.
+            assert_eq!(key.dims()[2], usize::min(i + 1, 6));
+        }
+
+        assert_eq!(cache.len(), 5);
+        assert_eq!(cache.num_evicted(), 2);
+
+        // The sinks are followed by the 3 most recent positions.
+        let token = Tensor::zeros([1, 2, 1, 3], &device);
+        let (key, _value) = cache.append(token.clone(), token.clone());
+        let expected = Tensor::cat(
+            vec![
+                tensor.clone().narrow(2, 0, 2),
+                tensor.narrow(2, 4, 3),
+                token,
+            ],
+            2,
+        );
+
+        key.into_data().assert_eq(&expected.into_data(), true);
+    }
+
+    #[test]
+    fn test_kv_cache_reset() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 4>::zeros([1, 1, 3, 2], &device);
+        let mut cache = KvCache::new();
+
+        cache.append(tensor.clone(), tensor);
+        assert_eq!(cache.len(), 3);
+
+        cache.reset();
+        assert!(cache.is_empty());
+    }
+}
@@ -1,6 +1,7 @@
 use crate as burn;
 
 use crate::module::{Content, DisplaySettings, Module, ModuleDisplay};
+use crate::nn::attention::KvCache;
 use crate::nn::cache::TensorCache;
 use crate::nn::Initializer;
 use crate::{
@@ -40,6 +41,15 @@ pub struct MultiHeadAttentionConfig {
         default = "Initializer::KaimingUniform{gain:1.0/num_traits::Float::sqrt(3.0), fan_out_only:false}"
     )]
     pub initializer: Initializer,
+    /// Attend to the keys and values in chunks of the given length with an online softmax,
+    /// without materializing the attention weights over the whole sequence. Default: None
+    ///
+    /// This bounds the activation memory when attending to long sequences, like the encoder
+    /// memory of a decoder's cross-attention, and is used by
+    /// [forward_context](MultiHeadAttention::forward_context) and
+    /// [forward_cache_context](MultiHeadAttention::forward_cache_context).
+    #[config(default = "None")]
+    pub chunk_size: Option<usize>,
 }
 
 /// The multihead attention module as describe in the paper [Attention Is All You Need](https://arxiv.org/abs/1706.03762).
@@ -77,6 +87,12 @@ pub struct MultiHeadAttention<B: Backend> {
     pub min_float: f64,
     /// Use "quiet softmax" instead of regular softmax.
     pub quiet_softmax: bool,
+    /// Length of the chunks of keys and values attended to at once, all of them when `None`.
+    ///
+    /// This is a breaking change for modules built with a struct literal instead of
+    /// [MultiHeadAttentionConfig::init], which must now set this field, to `None` to keep
+    /// attending to all the keys at once.
+    pub chunk_size: Option<usize>,
 }
 
 impl<B: Backend> ModuleDisplay for MultiHeadAttention<B> {
@@ -109,11 +125,17 @@ pub struct MhaInput<B: Backend> {
     value: Tensor<B, 3>,
     mask_pad: Option<Tensor<B, 2, Bool>>,
     mask_attn: Option<Tensor<B, 3, Bool>>,
+    attn_bias: Option<Tensor<B, 4>>,
 }
 
 impl MultiHeadAttentionConfig {
     /// Initialize a new [multihead attention](MultiHeadAttention) module.
     pub fn init<B: Backend>(&self, device: &B::Device) -> MultiHeadAttention<B> {
+        assert!(
+            self.chunk_size != Some(0),
+            "The chunk size of the attention must be greater than 0"
+        );
+
         let linear = |config: &Self| {
             nn::LinearConfig::new(config.d_model, config.d_model)
                 .with_initializer(self.initializer.clone())
@@ -131,6 +153,7 @@ impl MultiHeadAttentionConfig {
             d_k: self.d_model / self.n_heads,
             min_float: self.min_float,
             quiet_softmax: self.quiet_softmax,
+            chunk_size: self.chunk_size,
             d_model: self.d_model,
         }
     }
@@ -149,6 +172,7 @@ impl<B: Backend> MhaInput<B> {
             value: tensor,
             mask_pad: None,
             mask_attn: None,
+            attn_bias: None,
         }
     }
 
@@ -160,6 +184,7 @@ impl<B: Backend> MhaInput<B> {
             value,
             mask_pad: None,
             mask_attn: None,
+            attn_bias: None,
         }
     }
 
@@ -174,6 +199,17 @@ impl<B: Backend> MhaInput<B> {
         self.mask_attn = Some(mask_attn);
         self
     }
+
+    /// Register a bias added to the attention scores before the masks are applied, like the
+    /// [ALiBi](crate::nn::attention::AlibiBias) positional bias.
+    ///
+    /// # Shape
+    /// - attn_bias: `[batch_size, n_heads, seq_length_1, seq_length_2]`, where the batch and head
+    ///   dimensions can be broadcast.
+    pub fn attn_bias(mut self, attn_bias: Tensor<B, 4>) -> Self {
+        self.attn_bias = Some(attn_bias);
+        self
+    }
 }
 
 /// [Multihead attention](MultiHeadAttention) outputs.
@@ -204,7 +240,12 @@ impl<B: Backend> MultiHeadAttention<B> {
         let value = self.attention_linear(input.value, &self.value);
 
         let attn_scores = self.attn_scores(query, key);
-        let weights = self.attn_weights(attn_scores, input.mask_pad, input.mask_attn);
+        let weights = self.attn_weights(
+            attn_scores,
+            input.mask_pad,
+            input.mask_attn,
+            input.attn_bias,
+        );
 
         let context = weights.clone().matmul(value);
         let context = context
@@ -237,7 +278,12 @@ impl<B: Backend> MultiHeadAttention<B> {
             .forward(input.value, |t| self.attention_linear(t, &self.value));
 
         let attn_scores = self.attn_scores(query, key);
-        let weights = self.attn_weights(attn_scores, input.mask_pad, input.mask_attn);
+        let weights = self.attn_weights(
+            attn_scores,
+            input.mask_pad,
+            input.mask_attn,
+            input.attn_bias,
+        );
 
         let context = weights.clone().matmul(value);
         let context = context
@@ -249,6 +295,179 @@ impl<B: Backend> MultiHeadAttention<B> {
         MhaOutput { weights, context }
     }
 
+    /// Applies the forward pass on the new positions only, using a cache of the keys and values
+    /// of the previous positions.
+    ///
+    /// Contrary to [forward_cache](Self::forward_cache), the input only contains the new tokens,
+    /// so each decoding step doesn't recompute the previous positions. The masks and the attention
+    /// bias apply to all the positions of the cache, including the new ones.
+    ///
+    /// # Shapes
+    ///
+    /// - query: `[batch_size, seq_length_1, d_model]`
+    /// - key: `[batch_size, seq_length_1, d_model]`
+    /// - value: `[batch_size, seq_length_1, d_model]`
+    /// - mask_attn: `[batch_size, seq_length_1, cache_length]`
+    /// - output: `[batch_size, seq_length_1, d_model]`
+    pub fn forward_kv_cache(&self, input: MhaInput<B>, cache: &mut KvCache<B>) -> MhaOutput<B> {
+        let [batch_size, seq_length_1, d_model] = input.query.dims();
+
+        let query = self.attention_linear(input.query, &self.query);
+        let key = self.attention_linear(input.key, &self.key);
+        let value = self.attention_linear(input.value, &self.value);
+        let (key, value) = cache.append(key, value);
+
+        let attn_scores = self.attn_scores(query, key);
+        let weights = self.attn_weights(
+            attn_scores,
+            input.mask_pad,
+            input.mask_attn,
+            input.attn_bias,
+        );
+
+        let context = weights.clone().matmul(value);
+        let context = context
+            .swap_dims(1, 2)
+            .reshape([batch_size, seq_length_1, d_model]);
+        let context = self.output.forward(context);
+
+        MhaOutput { weights, context }
+    }
+
+    /// Applies the forward pass on the input tensors, returning the context only.
+    ///
+    /// When a [chunk size](MultiHeadAttentionConfig::chunk_size) is set, the keys and values are
+    /// attended to chunk by chunk with an online softmax, so the memory used by the attention
+    /// weights grows with the chunk size instead of the length of the keys.
+    ///
+    /// # Shapes
+    ///
+    /// - query: `[batch_size, seq_length_1, d_model]`
+    /// - key: `[batch_size, seq_length_2, d_model]`
+    /// - value: `[batch_size, seq_length_2, d_model]`
+    /// - output: `[batch_size, seq_length_1, d_model]`
+    pub fn forward_context(&self, input: MhaInput<B>) -> Tensor<B, 3> {
+        let [batch_size, seq_length_1, d_model] = input.query.dims();
+
+        let query = self.attention_linear(input.query, &self.query);
+        let key = self.attention_linear(input.key, &self.key);
+        let value = self.attention_linear(input.value, &self.value);
+
+        let context = self.context(
+            query,
+            key,
+            value,
+            input.mask_pad,
+            input.mask_attn,
+            input.attn_bias,
+        );
+        let context = context
+            .swap_dims(1, 2)
+            .reshape([batch_size, seq_length_1, d_model]);
+
+        self.output.forward(context)
+    }
+
+    /// Applies the forward pass using a cache, returning the context only.
+    ///
+    /// See [forward_context](Self::forward_context) and [forward_cache](Self::forward_cache).
+    pub fn forward_cache_context(
+        &self,
+        input: MhaInput<B>,
+        cache: &mut MhaCache<B>,
+    ) -> Tensor<B, 3> {
+        let [batch_size, seq_length_1, d_model] = input.query.dims();
+
+        let query = cache
+            .query
+            .forward(input.query, |t| self.attention_linear(t, &self.query));
+        let key = cache
+            .key
+            .forward(input.key, |t| self.attention_linear(t, &self.key));
+        let value = cache
+            .value
+            .forward(input.value, |t| self.attention_linear(t, &self.value));
+
+        let context = self.context(
+            query,
+            key,
+            value,
+            input.mask_pad,
+            input.mask_attn,
+            input.attn_bias,
+        );
+        let context = context
+            .swap_dims(1, 2)
+            .reshape([batch_size, seq_length_1, d_model]);
+
+        cache.output.forward(context, |t| self.output.forward(t))
+    }
+
+    fn context(
+        &self,
+        query: Tensor<B, 4>,
+        key: Tensor<B, 4>,
+        value: Tensor<B, 4>,
+        mask_pad: Option<Tensor<B, 2, Bool>>,
+        mask_attn: Option<Tensor<B, 3, Bool>>,
+        attn_bias: Option<Tensor<B, 4>>,
+    ) -> Tensor<B, 4> {
+        let Some(chunk_size) = self.chunk_size else {
+            let attn_scores = self.attn_scores(query, key);
+            let weights = self.attn_weights(attn_scores, mask_pad, mask_attn, attn_bias);
+
+            return weights.matmul(value);
+        };
+
+        let [_, _, seq_length_2, _] = key.dims();
+        // The running max of the scores, the sum of their exponentials and the context, which are
+        // rescaled when a chunk has a greater max.
+        let mut state: Option<(Tensor<B, 4>, Tensor<B, 4>, Tensor<B, 4>)> = None;
+
+        for start in (0..seq_length_2).step_by(chunk_size) {
+            let length = usize::min(chunk_size, seq_length_2 - start);
+            let attn_scores = self.attn_scores(query.clone(), key.clone().narrow(2, start, length));
+            let attn_scores = self.mask_scores(
+                attn_scores,
+                mask_pad.clone().map(|mask| mask.narrow(1, start, length)),
+                mask_attn.clone().map(|mask| mask.narrow(2, start, length)),
+                attn_bias.clone().map(|bias| match bias.dims()[3] {
+                    1 => bias,
+                    _ => bias.narrow(3, start, length),
+                }),
+            );
+            let value = value.clone().narrow(2, start, length);
+            let chunk_max = attn_scores.clone().detach().max_dim(3);
+
+            state = Some(match state {
+                None => {
+                    let weights = (attn_scores - chunk_max.clone()).exp();
+                    let sum = weights.clone().sum_dim(3);
+
+                    (chunk_max, sum, weights.matmul(value))
+                }
+                Some((max, sum, context)) => {
+                    let max_new = max.clone().max_pair(chunk_max);
+                    let scale = (max - max_new.clone()).exp();
+                    let weights = (attn_scores - max_new.clone()).exp();
+                    let sum = sum * scale.clone() + weights.clone().sum_dim(3);
+
+                    (max_new, sum, context * scale + weights.matmul(value))
+                }
+            });
+        }
+
+        // Like the quiet softmax activation, one is added to the sum of the exponentials shifted
+        // by the max.
+        let (_max, sum, context) = state.expect("The keys should not be empty");
+        let sum = match self.quiet_softmax {
+            true => sum.add_scalar(1),
+            false => sum,
+        };
+
+        context / sum
+    }
+
     fn attn_scores(&self, query: Tensor<B, 4>, key: Tensor<B, 4>) -> Tensor<B, 4> {
         let attn_scores = query
             .matmul(key.transpose())
@@ -258,11 +477,32 @@ impl<B: Backend> MultiHeadAttention<B> {
     }
 
     fn attn_weights(
+        &self,
+        attn_scores: Tensor<B, 4>,
+        mask_pad: Option<Tensor<B, 2, Bool>>,
+        mask_attn: Option<Tensor<B, 3, Bool>>,
+        attn_bias: Option<Tensor<B, 4>>,
+    ) -> Tensor<B, 4> {
+        let attn_scores = self.mask_scores(attn_scores, mask_pad, mask_attn, attn_bias);
+
+        if self.quiet_softmax {
+            activation::quiet_softmax(attn_scores, 3)
+        } else {
+            activation::softmax(attn_scores, 3)
+        }
+    }
+
+    fn mask_scores(
         &self,
         mut attn_scores: Tensor<B, 4>,
         mask_pad: Option<Tensor<B, 2, Bool>>,
         mask_attn: Option<Tensor<B, 3, Bool>>,
+        attn_bias: Option<Tensor<B, 4>>,
     ) -> Tensor<B, 4> {
+        if let Some(attn_bias) = attn_bias {
+            attn_scores = attn_scores + attn_bias;
+        }
+
         if let Some(mask_pad) = mask_pad {
             let [batch_size, seq_length] = mask_pad.dims();
 
@@ -281,11 +521,7 @@ impl<B: Backend> MultiHeadAttention<B> {
             );
         }
 
-        if self.quiet_softmax {
-            activation::quiet_softmax(attn_scores, 3)
-        } else {
-            activation::softmax(attn_scores, 3)
-        }
+        attn_scores
     }
 
     fn attention_linear(&self, x: Tensor<B, 3>, linear: &nn::Linear<B>) -> Tensor<B, 4> {
@@ -355,7 +591,10 @@ mod tests {
     use super::*;
     use crate::tensor::Int;
     use crate::tensor::{Distribution, Shape};
-    use crate::{nn::attention::generate_autoregressive_mask, TestBackend};
+    use crate::{
+        nn::attention::{generate_autoregressive_mask, AlibiBiasConfig},
+        TestBackend,
+    };
     use alloc::vec::Vec;
 
     #[test]
@@ -512,6 +751,124 @@ mod tests {
             .assert_approx_eq(&output_2.into_data(), 3);
     }
 
+    #[test]
+    fn test_alibi_bias_should_have_same_output_as_autoregressive_decoding() {
+        let [batch_size, seq_length, d_model, n_heads] = [3, 4, 12, 2];
+        let device = Default::default();
+        let mha = MultiHeadAttentionConfig::new(d_model, n_heads).init::<TestBackend>(&device);
+        let alibi = AlibiBiasConfig::new(n_heads).init::<TestBackend>(&device);
+
+        let tensor = Tensor::<TestBackend, 3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+            &device,
+        );
+        let mask_attn = generate_autoregressive_mask(batch_size, seq_length, &tensor.device());
+        let input = MhaInput::self_attn(tensor.clone())
+            .mask_attn(mask_attn)
+            .attn_bias(alibi.forward(seq_length, seq_length));
+
+        let output_1 = mha.forward(input);
+        let mut output_2 = Vec::new();
+        let mut cache = MhaCache::autoregressive();
+
+        for i in 1..seq_length + 1 {
+            let tensor = tensor.clone().slice([0..batch_size, 0..i, 0..d_model]);
+            let input = MhaInput::self_attn(tensor).attn_bias(alibi.forward(i, i));
+            let next_tok = mha.forward_cache(input, &mut cache).context.slice([
+                0..batch_size,
+                i - 1..i,
+                0..d_model,
+            ]);
+            output_2.push(next_tok);
+        }
+
+        let output_2 = Tensor::cat(output_2, 1);
+
+        output_1
+            .context
+            .into_data()
+            .assert_approx_eq(&output_2.into_data(), 3);
+    }
+
+    #[test]
+    fn test_kv_cache_should_have_same_output_as_autoregressive_mask() {
+        let [batch_size, seq_length, d_model, n_heads] = [3, 4, 12, 2];
+        let device = Default::default();
+        let mha = MultiHeadAttentionConfig::new(d_model, n_heads).init::<TestBackend>(&device);
+
+        let tensor = Tensor::<TestBackend, 3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+            &device,
+        );
+        let mask_attn = generate_autoregressive_mask(batch_size, seq_length, &tensor.device());
+        let input = MhaInput::self_attn(tensor.clone()).mask_attn(mask_attn);
+
+        let output_1 = mha.forward(input);
+
+        for mut cache in [KvCache::new(), KvCache::with_max_seq_length(seq_length)] {
+            let mut output_2 = Vec::new();
+
+            for i in 0..seq_length {
+                let token = tensor.clone().slice([0..batch_size, i..i + 1, 0..d_model]);
+                let input = MhaInput::self_attn(token);
+                output_2.push(mha.forward_kv_cache(input, &mut cache).context);
+            }
+
+            let output_2 = Tensor::cat(output_2, 1);
+
+            output_1
+                .context
+                .clone()
+                .into_data()
+                .assert_approx_eq(&output_2.into_data(), 3);
+        }
+    }
+
+    #[test]
+    fn test_chunked_context_should_have_same_output_as_forward() {
+        let [batch_size, seq_length_1, seq_length_2, d_model, n_heads] = [2, 3, 7, 12, 2];
+        let device = Default::default();
+        let alibi = AlibiBiasConfig::new(n_heads).init::<TestBackend>(&device);
+
+        for quiet_softmax in [false, true] {
+            let mha = MultiHeadAttentionConfig::new(d_model, n_heads)
+                .with_quiet_softmax(quiet_softmax)
+                .init::<TestBackend>(&device);
+            let mut mha_chunked = mha.clone();
+            mha_chunked.chunk_size = Some(3);
+
+            let memory = Tensor::<TestBackend, 3>::random(
+                [batch_size, seq_length_2, d_model],
+                Distribution::Default,
+                &device,
+            );
+            let mask_pad = Tensor::<TestBackend, 1, Int>::arange(0..seq_length_2 as i64, &device)
+                .greater_equal_elem(5)
+                .unsqueeze::<2>()
+                .repeat_dim(0, batch_size);
+            let input = MhaInput::new(
+                Tensor::random(
+                    [batch_size, seq_length_1, d_model],
+                    Distribution::Default,
+                    &device,
+                ),
+                memory.clone(),
+                memory,
+            )
+            .mask_pad(mask_pad)
+            .attn_bias(alibi.forward(seq_length_1, seq_length_2));
+
+            let output_1 = mha.forward(input.clone()).context;
+            let output_2 = mha_chunked.forward_context(input);
+
+            output_1
+                .into_data()
+                .assert_approx_eq(&output_2.into_data(), 3);
+        }
+    }
+
     #[test]
     fn display() {
         let config = MultiHeadAttentionConfig::new(2, 4);
@@ -1,5 +1,11 @@
+mod alibi;
+mod kv_cache;
 mod mask;
 mod mha;
+mod rope;
 
+pub use alibi::*;
+pub use kv_cache::*;
 pub use mask::*;
 pub use mha::*;
+pub use rope::*;
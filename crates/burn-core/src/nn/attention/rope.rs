@@ -2,6 +2,7 @@ use crate as burn;
 use crate::config::Config;
 use crate::module::{Content, DisplaySettings, Module, ModuleDisplay};
 use crate::tensor::backend::Backend;
+use crate::tensor::module;
 use crate::tensor::Int;
 use crate::tensor::Tensor;
 use alloc::vec;
@@ -21,6 +22,10 @@ pub struct RotaryEncodingConfig {
     /// Scaling factor for frequency computation. Defaults to 10000.0
     #[config(default = "10000.0")]
     pub theta: f32,
+
+    /// Number of leading features of the hidden dimension that are rotated, the remaining ones
+    /// being left unchanged (partial rotation, as used by GPT-NeoX). Defaults to `d_model`.
+    pub rotary_dim: Option<usize>,
 }
 
 impl RotaryEncodingConfig {
@@ -53,28 +58,34 @@ impl RotaryEncodingConfig {
     ///
     /// # Panics
     ///
-    /// Panics if the size of input embedding dimension is not even.
+    /// Panics if the number of rotated features is not even or greater than `d_model`.
     /// Panics if the theta parameter is not positive.
     fn initialize<B: Backend>(
         &self,
         scaling: impl Fn(Tensor<B, 1>) -> Tensor<B, 1>,
         device: &B::Device,
     ) -> RotaryEncoding<B> {
+        let rotary_dim = self.rotary_dim.unwrap_or(self.d_model);
+
         assert_eq!(
-            self.d_model % 2,
+            rotary_dim % 2,
             0,
             "The input embedding dimension must be even"
         );
+        assert!(
+            rotary_dim <= self.d_model,
+            "The number of rotated features must not exceed the input embedding dimension"
+        );
         assert!(
             self.theta > 0.0,
             "Theta parameter must be positive (default: 10000)."
         );
 
         // Calculate the rotation frequencies for positional embeddings based on the formula
-        // `theta_i = 1 / (theta ^ (2i / d_model)) for i in [0..d_model/2]`
-        let exponent = Tensor::<B, 1, Int>::arange_step(0..self.d_model as i64, 2, device)
+        // `theta_i = 1 / (theta ^ (2i / rotary_dim)) for i in [0..rotary_dim/2]`
+        let exponent = Tensor::<B, 1, Int>::arange_step(0..rotary_dim as i64, 2, device)
             .float()
-            .div_scalar(self.d_model as f32);
+            .div_scalar(rotary_dim as f32);
 
         // Calculate (10000 ^ (2i / d_model)) by using the log base property `exp(log(10000) * (2i / d_model))`
         // This is done since burn doesn't support exponentiation of scalar to tensor
@@ -89,21 +100,21 @@ impl RotaryEncodingConfig {
                 .float()
                 .unsqueeze()
                 .transpose()
-                .repeat_dim(1, self.d_model / 2)
+                .repeat_dim(1, rotary_dim / 2)
                 * theta_i.unsqueeze();
 
         // Convert frequency values to complex numbers (polar form)
         let p_cos = frequencies.clone().cos();
         let p_sin = frequencies.sin();
 
-        // Create the frequency tensor of shape (max_sequence_length, d_model, 2) with the real(cos)
-        // and imaginary(sin) components along last dimension
+        // Create the frequency tensor of shape (max_sequence_length, rotary_dim, 2) with the
+        // real(cos) and imaginary(sin) components along last dimension
         let freq_complex: Tensor<B, 3> = Tensor::cat(vec![p_cos, p_sin], 1)
-            .reshape([self.max_sequence_length, 2, self.d_model / 2])
+            .reshape([self.max_sequence_length, 2, rotary_dim / 2])
             .transpose()
             .unsqueeze_dim::<4>(2)
             .repeat_dim(2, 2)
-            .reshape([self.max_sequence_length, self.d_model, 2]);
+            .reshape([self.max_sequence_length, rotary_dim, 2]);
 
         RotaryEncoding {
             freq_complex,
@@ -124,7 +135,8 @@ impl RotaryEncodingConfig {
 #[derive(Module, Debug)]
 #[module(custom_display)]
 pub struct RotaryEncoding<B: Backend> {
-    /// Frequency Tensor of shape (max_sequence_length, d_model, 2) with real and imaginary components
+    /// Frequency Tensor of shape (max_sequence_length, rotary_dim, 2) with real and imaginary
+    /// components, where `rotary_dim` is the number of rotated features.
     pub freq_complex: Tensor<B, 3>,
     /// Maximum sequence length of input
     pub max_sequence_length: usize,
@@ -168,6 +180,9 @@ impl<B: Backend> RotaryEncoding<B> {
 
     /// Applies rotary positional encoding to a tensor of dimensions (..., seq_len, d_model)
     ///
+    /// When generating with a cache, `start` is the number of positions already processed, so
+    /// that the new tokens are rotated according to their position in the whole sequence.
+    ///
     /// Arguments:
     /// * `x` - Input tensor of shape (..., seq_len, d_model). Accommodate both 3D and 4D tensors
     ///    for (batch size, seq_len, hidden_dim) or (batch size, num_heads, seq_len, hidden_dim)
@@ -178,12 +193,61 @@ impl<B: Backend> RotaryEncoding<B> {
     /// * Output tensor with the same shape as input tensor after applying rotary encoding.
     ///
     /// Panics if the input tensor does not have at least 2 dimensions for sequence length and hidden dimension.
+    /// Panics if the positions exceed the maximum sequence length.
     pub fn apply<const D: usize>(&self, x: Tensor<B, D>, start: usize) -> Tensor<B, D> {
         assert!(
             D >= 2,
             "Input tensor must have at least 2 dimensions for sequence length and hidden dimension"
         );
 
+        let [max_sequence_length, rotary_dim, _] = self.freq_complex.dims();
+        let (seq_len, d_model) = (x.dims()[D - 2], x.dims()[D - 1]);
+
+        assert!(
+            start + seq_len <= max_sequence_length,
+            "The positions {}..{} exceed the maximum sequence length {max_sequence_length}",
+            start,
+            start + seq_len
+        );
+
+        // Only the first features are rotated with partial rotation, the others are passed through.
+        if rotary_dim < d_model {
+            let rotated = self.rotate(x.clone().narrow(D - 1, 0, rotary_dim), start);
+            let passed = x.narrow(D - 1, rotary_dim, d_model - rotary_dim);
+
+            return Tensor::cat(vec![rotated, passed], D - 1);
+        }
+
+        self.rotate(x, start)
+    }
+
+    /// Applies rotary positional encoding to the queries and keys of an attention layer, as
+    /// projected from the input sequence, and swaps their heads with the sequence.
+    ///
+    /// The rotation and the transposition are done in a single pass, with a fused kernel on the
+    /// backends supporting it, so the queries and keys are directly in the layout expected by the
+    /// attention or the [cache](super::KvCache).
+    ///
+    /// Arguments:
+    /// * `queries` - Queries of shape (batch size, seq_len, num_heads, head_dim).
+    /// * `keys` - Keys of shape (batch size, seq_len, num_kv_heads, head_dim).
+    /// * `start` - Sequence start position index.
+    ///
+    /// Returns:
+    /// * The queries of shape (batch size, num_heads, seq_len, head_dim) and the keys of shape
+    ///   (batch size, num_kv_heads, seq_len, head_dim) after applying rotary encoding.
+    ///
+    /// Panics if the positions exceed the maximum sequence length.
+    pub fn apply_qk(
+        &self,
+        queries: Tensor<B, 4>,
+        keys: Tensor<B, 4>,
+        start: usize,
+    ) -> (Tensor<B, 4>, Tensor<B, 4>) {
+        module::rotary_qk(queries, keys, self.freq_complex.clone(), start)
+    }
+
+    fn rotate<const D: usize>(&self, x: Tensor<B, D>, start: usize) -> Tensor<B, D> {
         let device = x.device();
         let input_shape = x.shape();
 
@@ -208,14 +272,16 @@ impl<B: Backend> RotaryEncoding<B> {
                 .slice([start..start + seq_len])
                 .unsqueeze();
 
-        // Sum the real and imaginary components to get output tensor and reshape to original shape
-        out.sum_dim(D - 1).reshape(input_shape)
+        // Sum the real and imaginary components, in the last dimension of the 4D output whatever
+        // the rank of the input, to get output tensor and reshape to original shape
+        out.sum_dim(3).reshape(input_shape)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::tensor::Distribution;
     use crate::TestBackend;
 
     #[test]
@@ -255,6 +321,40 @@ mod tests {
             .assert_approx_eq(&expected_output.to_data(), 4);
     }
 
+    #[test]
+    fn test_rotary_encoding_forward_3d() {
+        let device = Default::default();
+        let rotary_encoding = RotaryEncodingConfig::new(10, 4).init::<TestBackend>(&device);
+
+        // Input = [Batch size, Seq_len, d_model]
+        let input = Tensor::<TestBackend, 3>::from_floats(
+            [
+                [[1.0, 2.0, 3.0, 4.0], [5.0, 6.0, 7.0, 8.0]],
+                [[9.0, 10.0, 11.0, 12.0], [13.0, 14.0, 15.0, 16.0]],
+            ],
+            &device,
+        );
+
+        let output = rotary_encoding.forward(input);
+        let expected_output = Tensor::<TestBackend, 3>::from_floats(
+            [
+                [
+                    [1.0000, 2.0000, 3.0000, 4.0000],
+                    [-2.3473, 7.4492, 6.9197, 8.0696],
+                ],
+                [
+                    [9.0000, 10.0000, 11.0000, 12.0000],
+                    [-4.7567, 18.5034, 14.8393, 16.1492],
+                ],
+            ],
+            &device,
+        );
+
+        output
+            .to_data()
+            .assert_approx_eq(&expected_output.to_data(), 4);
+    }
+
     #[test]
     fn test_zero_input_rotary_encoding_forward() {
         let device = Default::default();
@@ -399,6 +499,73 @@ mod tests {
             .assert_approx_eq(&expected_freqs.to_data(), 4);
     }
 
+    #[test]
+    fn test_partial_rotary_encoding_apply() {
+        let device = Default::default();
+        let partial = RotaryEncodingConfig::new(10, 6)
+            .with_rotary_dim(Some(4))
+            .init::<TestBackend>(&device);
+        let full = RotaryEncodingConfig::new(10, 4).init::<TestBackend>(&device);
+
+        let input = Tensor::<TestBackend, 3>::from_floats(
+            [[
+                [1.0, 2.0, 3.0, 4.0, 5.0, 6.0],
+                [7.0, 8.0, 9.0, 10.0, 11.0, 12.0],
+            ]],
+            &device,
+        );
+
+        // Only the first 4 features are rotated, at the positions following the cached ones.
+        let output = partial.apply(input.clone(), 2);
+        let expected = Tensor::cat(
+            vec![
+                full.apply(input.clone().narrow(2, 0, 4), 2),
+                input.narrow(2, 4, 2),
+            ],
+            2,
+        );
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 4);
+    }
+
+    #[test]
+    fn test_apply_qk() {
+        let device = Default::default();
+        let rotary_encoding = RotaryEncodingConfig::new(10, 8)
+            .with_rotary_dim(Some(4))
+            .init::<TestBackend>(&device);
+
+        let queries =
+            Tensor::<TestBackend, 4>::random([2, 3, 4, 8], Distribution::Default, &device);
+        let keys = Tensor::<TestBackend, 4>::random([2, 3, 2, 8], Distribution::Default, &device);
+
+        let (queries_rotated, keys_rotated) =
+            rotary_encoding.apply_qk(queries.clone(), keys.clone(), 5);
+
+        queries_rotated.into_data().assert_approx_eq(
+            &rotary_encoding
+                .apply(queries.swap_dims(1, 2), 5)
+                .into_data(),
+            4,
+        );
+        keys_rotated.into_data().assert_approx_eq(
+            &rotary_encoding.apply(keys.swap_dims(1, 2), 5).into_data(),
+            4,
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_apply_exceeding_max_sequence_length() {
+        let device = Default::default();
+        let rotary_encoding = RotaryEncodingConfig::new(4, 4).init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 3>::zeros([1, 2, 4], &device);
+
+        let _output = rotary_encoding.apply(input, 3);
+    }
+
     #[test]
     fn display() {
         let config = RotaryEncodingConfig::new(10, 4);
@@ -0,0 +1,451 @@
+use alloc::vec::Vec;
+
+use crate as burn;
+use crate::config::Config;
+use crate::module::Param;
+use crate::module::{Content, DisplaySettings, Module, ModuleDisplay};
+use crate::nn::Initializer;
+use crate::tensor::backend::Backend;
+use crate::tensor::{Bool, Int, Tensor};
+
+/// Configuration to create a [linear-chain CRF](Crf) layer using the [init function](CrfConfig::init).
+#[derive(Config, Debug)]
+pub struct CrfConfig {
+    /// The number of tags.
+    pub num_tags: usize,
+    /// The type of function used to initialize the transition scores.
+    /// Default: Uniform(-0.1, 0.1)
+    #[config(default = "Initializer::Uniform{min:-0.1, max:0.1}")]
+    pub initializer: Initializer,
+}
+
+/// Linear-chain conditional random field (CRF) layer.
+///
+/// The layer learns the scores of the transitions between tags, which are combined with the
+/// emission scores of a model to score whole tag sequences. It is commonly used as the output
+/// layer of sequence labeling models, such as named entity recognition.
+///
+/// The [loss](Crf::loss) is the negative log-likelihood of the tag sequences computed with the
+/// forward algorithm, and the most likely tag sequences are [decoded](Crf::decode) with the
+/// Viterbi algorithm.
+///
+/// Should be created using [CrfConfig].
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct Crf<B: Backend> {
+    /// The scores of starting a sequence with each tag, of shape `[num_tags]`.
+    pub start_transitions: Param<Tensor<B, 1>>,
+    /// The scores of ending a sequence with each tag, of shape `[num_tags]`.
+    pub end_transitions: Param<Tensor<B, 1>>,
+    /// The scores of the transitions from a tag (row) to the next tag (column), of shape
+    /// `[num_tags, num_tags]`.
+    pub transitions: Param<Tensor<B, 2>>,
+}
+
+impl<B: Backend> ModuleDisplay for Crf<B> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        let [num_tags] = self.start_transitions.shape().dims();
+
+        content.add("num_tags", &num_tags).optional()
+    }
+}
+
+impl CrfConfig {
+    /// Initialize a new [linear-chain CRF](Crf) layer.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> Crf<B> {
+        Crf {
+            start_transitions: self.initializer.init([self.num_tags], device),
+            end_transitions: self.initializer.init([self.num_tags], device),
+            transitions: self
+                .initializer
+                .init([self.num_tags, self.num_tags], device),
+        }
+    }
+}
+
+impl<B: Backend> Crf<B> {
+    /// Computes the negative log-likelihood of the tag sequences, averaged over the batch.
+    ///
+    /// The padding mask is `true` for the padded positions, which must be at the end of the
+    /// sequences. The first position of each sequence can't be padded.
+    ///
+    /// # Shapes
+    ///
+    /// - emissions: `[batch_size, seq_length, num_tags]`
+    /// - tags: `[batch_size, seq_length]`
+    /// - mask_pad: `[batch_size, seq_length]`
+    /// - output: `[1]`
+    pub fn loss(
+        &self,
+        emissions: Tensor<B, 3>,
+        tags: Tensor<B, 2, Int>,
+        mask_pad: Option<Tensor<B, 2, Bool>>,
+    ) -> Tensor<B, 1> {
+        self.log_likelihood(emissions, tags, mask_pad).neg().mean()
+    }
+
+    /// Computes the log-likelihood of each tag sequence.
+    ///
+    /// The padding mask is `true` for the padded positions, which must be at the end of the
+    /// sequences. The first position of each sequence can't be padded.
+    ///
+    /// # Shapes
+    ///
+    /// - emissions: `[batch_size, seq_length, num_tags]`
+    /// - tags: `[batch_size, seq_length]`
+    /// - mask_pad: `[batch_size, seq_length]`
+    /// - output: `[batch_size]`
+    pub fn log_likelihood(
+        &self,
+        emissions: Tensor<B, 3>,
+        tags: Tensor<B, 2, Int>,
+        mask_pad: Option<Tensor<B, 2, Bool>>,
+    ) -> Tensor<B, 1> {
+        let mask = self.mask(&emissions, mask_pad);
+        let numerator = self.score(emissions.clone(), tags, mask.clone());
+        let denominator = self.partition(emissions, mask);
+
+        numerator - denominator
+    }
+
+    /// Decodes the most likely tag sequences with the Viterbi algorithm.
+    ///
+    /// The padding mask is `true` for the padded positions, which must be at the end of the
+    /// sequences, and the tags of the padded positions are set to zero.
+    ///
+    /// # Shapes
+    ///
+    /// - emissions: `[batch_size, seq_length, num_tags]`
+    /// - mask_pad: `[batch_size, seq_length]`
+    /// - output: `[batch_size, seq_length]`
+    pub fn decode(
+        &self,
+        emissions: Tensor<B, 3>,
+        mask_pad: Option<Tensor<B, 2, Bool>>,
+    ) -> Tensor<B, 2, Int> {
+        let [batch_size, seq_length, num_tags] = emissions.dims();
+        let device = emissions.device();
+        let mask = self.mask(&emissions, mask_pad.clone());
+        let transitions = self.transitions.val().unsqueeze::<3>();
+
+        // The padded positions keep the tag of the next position when backtracking.
+        let identity = Tensor::<B, 1, Int>::arange(0..num_tags as i64, &device)
+            .unsqueeze::<2>()
+            .repeat_dim(0, batch_size);
+
+        let mut score = self.start_transitions.val().unsqueeze() + emission(&emissions, 0);
+        let mut history = Vec::with_capacity(seq_length.saturating_sub(1));
+
+        for i in 1..seq_length {
+            let (best, indices) =
+                (score.clone().unsqueeze_dim::<3>(2) + transitions.clone()).max_dim_with_indices(1);
+            let next = best.squeeze::<2>(1) + emission(&emissions, i);
+            let is_padded = mask
+                .clone()
+                .narrow(1, i, 1)
+                .bool_not()
+                .repeat_dim(1, num_tags);
+
+            score = next.mask_where(is_padded.clone(), score);
+            history.push(
+                indices
+                    .squeeze::<2>(1)
+                    .mask_where(is_padded, identity.clone()),
+            );
+        }
+
+        score = score + self.end_transitions.val().unsqueeze();
+
+        let mut tag = score.argmax(1);
+        let mut tags = Vec::with_capacity(seq_length);
+        tags.push(tag.clone());
+
+        for indices in history.into_iter().rev() {
+            tag = indices.gather(1, tag);
+            tags.push(tag.clone());
+        }
+        tags.reverse();
+
+        let tags = Tensor::cat(tags, 1);
+
+        match mask_pad {
+            Some(mask_pad) => tags.mask_fill(mask_pad, 0),
+            None => tags,
+        }
+    }
+
+    /// The mask of the valid positions, of shape `[batch_size, seq_length]`.
+    fn mask(
+        &self,
+        emissions: &Tensor<B, 3>,
+        mask_pad: Option<Tensor<B, 2, Bool>>,
+    ) -> Tensor<B, 2, Bool> {
+        let [batch_size, seq_length, _] = emissions.dims();
+
+        match mask_pad {
+            Some(mask_pad) => mask_pad.bool_not(),
+            None => Tensor::<B, 2, Int>::ones([batch_size, seq_length], &emissions.device())
+                .equal_elem(1),
+        }
+    }
+
+    /// The score of the given tag sequences, of shape `[batch_size]`.
+    fn score(
+        &self,
+        emissions: Tensor<B, 3>,
+        tags: Tensor<B, 2, Int>,
+        mask: Tensor<B, 2, Bool>,
+    ) -> Tensor<B, 1> {
+        let [batch_size, seq_length, num_tags] = emissions.dims();
+        let mask_float = mask.clone().float();
+
+        let first_tags = tags.clone().narrow(1, 0, 1).squeeze::<1>(1);
+        let mut score = self.start_transitions.val().select(0, first_tags);
+
+        let emission_scores = emissions
+            .gather(2, tags.clone().unsqueeze_dim(2))
+            .squeeze::<2>(2)
+            * mask_float.clone();
+        score = score + emission_scores.sum_dim(1).squeeze(1);
+
+        if seq_length > 1 {
+            let previous = tags.clone().narrow(1, 0, seq_length - 1);
+            let next = tags.clone().narrow(1, 1, seq_length - 1);
+            let indices = (previous.mul_scalar(num_tags as i64) + next)
+                .reshape([batch_size * (seq_length - 1)]);
+
+            let transition_scores = self
+                .transitions
+                .val()
+                .reshape([num_tags * num_tags])
+                .select(0, indices)
+                .reshape([batch_size, seq_length - 1])
+                * mask_float.narrow(1, 1, seq_length - 1);
+            score = score + transition_scores.sum_dim(1).squeeze(1);
+        }
+
+        let last = mask.int().sum_dim(1).sub_scalar(1);
+        let last_tags = tags.gather(1, last).squeeze::<1>(1);
+
+        score + self.end_transitions.val().select(0, last_tags)
+    }
+
+    /// The log of the sum of the scores of all the tag sequences, of shape `[batch_size]`.
+    fn partition(&self, emissions: Tensor<B, 3>, mask: Tensor<B, 2, Bool>) -> Tensor<B, 1> {
+        let [_, seq_length, num_tags] = emissions.dims();
+        let transitions = self.transitions.val().unsqueeze::<3>();
+
+        let mut alpha = self.start_transitions.val().unsqueeze() + emission(&emissions, 0);
+
+        for i in 1..seq_length {
+            let scores = alpha.clone().unsqueeze_dim::<3>(2)
+                + transitions.clone()
+                + emission(&emissions, i).unsqueeze_dim(1);
+            let next = log_sum_exp(scores, 1).squeeze::<2>(1);
+            let is_padded = mask
+                .clone()
+                .narrow(1, i, 1)
+                .bool_not()
+                .repeat_dim(1, num_tags);
+
+            alpha = next.mask_where(is_padded, alpha);
+        }
+
+        alpha = alpha + self.end_transitions.val().unsqueeze();
+
+        log_sum_exp(alpha, 1).squeeze(1)
+    }
+}
+
+/// The emission scores of the given position, of shape `[batch_size, num_tags]`.
+fn emission<B: Backend>(emissions: &Tensor<B, 3>, position: usize) -> Tensor<B, 2> {
+    emissions.clone().narrow(1, position, 1).squeeze(1)
+}
+
+fn log_sum_exp<B: Backend, const D: usize>(tensor: Tensor<B, D>, dim: usize) -> Tensor<B, D> {
+    // The maximum is subtracted for numerical stability, it doesn't change the gradients.
+    let max = tensor.clone().detach().max_dim(dim);
+
+    (tensor - max.clone()).exp().sum_dim(dim).log() + max
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::TensorData;
+    use crate::{TestAutodiffBackend, TestBackend};
+
+    type TestCrf = Crf<TestBackend>;
+
+    fn crf<B: Backend>(device: &B::Device) -> Crf<B> {
+        Crf {
+            start_transitions: Param::from_data([0.5, -0.2, 0.1], device),
+            end_transitions: Param::from_data([-0.3, 0.4, 0.2], device),
+            transitions: Param::from_data(
+                [[0.2, -0.5, 0.3], [0.6, 0.1, -0.4], [-0.2, 0.7, 0.0]],
+                device,
+            ),
+        }
+    }
+
+    fn emissions(device: &<TestBackend as Backend>::Device) -> Tensor<TestBackend, 3> {
+        Tensor::from_floats(
+            [
+                [
+                    [1.0, 0.2, -0.5],
+                    [0.1, 0.8, 0.3],
+                    [-0.4, 0.5, 1.2],
+                    [0.3, -0.1, 0.6],
+                ],
+                [
+                    [0.0, 0.9, 0.1],
+                    [0.7, -0.3, 0.2],
+                    [0.4, 0.4, -0.8],
+                    [0.5, 0.2, 0.1],
+                ],
+            ],
+            device,
+        )
+    }
+
+    /// Scores every tag sequence of the first `length` positions of a batch item, returning the
+    /// log-likelihood of the best sequence and its tags.
+    fn brute_force(
+        crf: &TestCrf,
+        emissions: &Tensor<TestBackend, 3>,
+        item: usize,
+        length: usize,
+    ) -> (f32, Vec<i64>) {
+        let start = crf
+            .start_transitions
+            .val()
+            .into_data()
+            .to_vec::<f32>()
+            .unwrap();
+        let end = crf
+            .end_transitions
+            .val()
+            .into_data()
+            .to_vec::<f32>()
+            .unwrap();
+        let transitions = crf.transitions.val().into_data().to_vec::<f32>().unwrap();
+        let emissions = emissions
+            .clone()
+            .narrow(0, item, 1)
+            .into_data()
+            .to_vec::<f32>()
+            .unwrap();
+        let num_tags = start.len();
+
+        let mut log_z_terms = Vec::new();
+        let mut best = (f32::NEG_INFINITY, Vec::new());
+
+        for path in 0..num_tags.pow(length as u32) {
+            let tags = (0..length)
+                .map(|i| (path / num_tags.pow(i as u32)) % num_tags)
+                .collect::<Vec<_>>();
+
+            let mut score = start[tags[0]] + end[tags[length - 1]];
+            for (i, tag) in tags.iter().enumerate() {
+                score += emissions[i * num_tags + tag];
+                if i > 0 {
+                    score += transitions[tags[i - 1] * num_tags + tag];
+                }
+            }
+
+            log_z_terms.push(score);
+            if score > best.0 {
+                best = (score, tags.iter().map(|tag| *tag as i64).collect());
+            }
+        }
+
+        let log_z = log_z_terms
+            .iter()
+            .map(|score| score.exp())
+            .sum::<f32>()
+            .ln();
+
+        (best.0 - log_z, best.1)
+    }
+
+    #[test]
+    fn test_crf_decode_and_log_likelihood() {
+        let device = Default::default();
+        let crf = crf::<TestBackend>(&device);
+        let emissions = emissions(&device);
+
+        let tags = crf.decode(emissions.clone(), None);
+        let log_likelihood = crf.log_likelihood(emissions.clone(), tags.clone(), None);
+
+        let (log_likelihood_0, tags_0) = brute_force(&crf, &emissions, 0, 4);
+        let (log_likelihood_1, tags_1) = brute_force(&crf, &emissions, 1, 4);
+
+        tags.into_data()
+            .assert_eq(&TensorData::new([tags_0, tags_1].concat(), [2, 4]), false);
+        log_likelihood
+            .into_data()
+            .assert_approx_eq(&TensorData::from([log_likelihood_0, log_likelihood_1]), 4);
+    }
+
+    #[test]
+    fn test_crf_with_padding() {
+        let device = Default::default();
+        let crf = crf::<TestBackend>(&device);
+        let emissions = emissions(&device);
+        let mask_pad = Tensor::<TestBackend, 2, Bool>::from_bool(
+            TensorData::from([[false, false, false, false], [false, false, true, true]]),
+            &device,
+        );
+
+        let tags = crf.decode(emissions.clone(), Some(mask_pad.clone()));
+        let log_likelihood = crf.log_likelihood(emissions.clone(), tags.clone(), Some(mask_pad));
+
+        let (log_likelihood_0, tags_0) = brute_force(&crf, &emissions, 0, 4);
+        let (log_likelihood_1, tags_1) = brute_force(&crf, &emissions, 1, 2);
+
+        tags.into_data().assert_eq(
+            &TensorData::new([tags_0, tags_1, alloc::vec![0, 0]].concat(), [2, 4]),
+            false,
+        );
+        log_likelihood
+            .into_data()
+            .assert_approx_eq(&TensorData::from([log_likelihood_0, log_likelihood_1]), 4);
+    }
+
+    #[test]
+    fn test_crf_loss_backward() {
+        let device = Default::default();
+        let crf = crf::<TestAutodiffBackend>(&device);
+        let emissions =
+            Tensor::<TestAutodiffBackend, 3>::from_data(emissions(&device).into_data(), &device)
+                .require_grad();
+        let tags =
+            Tensor::<TestAutodiffBackend, 2, Int>::from_ints([[0, 1, 2, 0], [1, 0, 0, 2]], &device);
+
+        let loss = crf.loss(emissions.clone(), tags, None);
+        let grads = loss.backward();
+
+        assert!(crf.transitions.grad(&grads).is_some());
+        // The gradient of the emissions of each position is the expected tag probability minus the
+        // gold tag, which sums to zero over the tags.
+        emissions
+            .grad(&grads)
+            .unwrap()
+            .sum_dim(2)
+            .into_data()
+            .assert_approx_eq(&TensorData::zeros::<f32, _>([2, 4, 1]), 4);
+    }
+
+    #[test]
+    fn display() {
+        let layer = CrfConfig::new(5).init::<TestBackend>(&Default::default());
+
+        assert_eq!(alloc::format!("{}", layer), "Crf {num_tags: 5, params: 35}");
+    }
+}
@@ -0,0 +1,112 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::{Content, DisplaySettings, Module, ModuleDisplay};
+
+use burn_tensor::backend::Backend;
+use burn_tensor::module::fold4d;
+use burn_tensor::ops::UnfoldOptions;
+use burn_tensor::Tensor;
+
+/// Configuration to create a [fold 4d](Fold4d) layer using the [init function](Fold4dConfig::init).
+#[derive(Config, Debug)]
+pub struct Fold4dConfig {
+    /// The size of the output, `[height, width]`.
+    pub output_size: [usize; 2],
+    /// The size of the kernel.
+    pub kernel_size: [usize; 2],
+    /// The stride of the convolution.
+    #[config(default = "[1, 1]")]
+    pub stride: [usize; 2],
+    /// Spacing between kernel elements.
+    #[config(default = "[1, 1]")]
+    pub dilation: [usize; 2],
+    /// The padding configuration.
+    #[config(default = "[0, 0]")]
+    pub padding: [usize; 2],
+}
+
+/// Four-dimensional folding, the inverse of [unfolding](crate::nn::Unfold4d) where the values of
+/// overlapping blocks are summed.
+///
+/// Should be created with [Fold4dConfig].
+#[derive(Module, Clone, Debug)]
+#[module(custom_display)]
+pub struct Fold4d {
+    /// The size of the output, `[height, width]`.
+    pub output_size: [usize; 2],
+    /// The size of the kernel.
+    pub kernel_size: [usize; 2],
+    /// The stride of the convolution.
+    pub stride: [usize; 2],
+    /// Spacing between kernel elements.
+    pub dilation: [usize; 2],
+    /// The padding configuration.
+    pub padding: [usize; 2],
+}
+
+impl ModuleDisplay for Fold4d {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content
+            .add("output_size", &alloc::format!("{:?}", &self.output_size))
+            .add("kernel_size", &alloc::format!("{:?}", &self.kernel_size))
+            .add("stride", &alloc::format!("{:?}", &self.stride))
+            .add("dilation", &alloc::format!("{:?}", &self.dilation))
+            .add("padding", &alloc::format!("{:?}", &self.padding))
+            .optional()
+    }
+}
+
+impl Fold4dConfig {
+    /// Initializes a new [Fold4d] module.
+    pub fn init(&self) -> Fold4d {
+        Fold4d {
+            output_size: self.output_size,
+            kernel_size: self.kernel_size,
+            stride: self.stride,
+            dilation: self.dilation,
+            padding: self.padding,
+        }
+    }
+}
+
+impl Fold4d {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// See [fold4d](crate::tensor::module::fold4d) for more information.
+    ///
+    /// # Shapes
+    ///
+    /// input:   `[batch_size, channels_out * kernel_size_1 * kernel_size_2, number of blocks]`
+    /// returns: `[batch_size, channels_out, output_size_1, output_size_2]`
+    pub fn forward<B: Backend>(&self, input: Tensor<B, 3>) -> Tensor<B, 4> {
+        fold4d(
+            input,
+            self.output_size,
+            self.kernel_size,
+            UnfoldOptions::new(self.stride, self.padding, self.dilation),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        let config = Fold4dConfig::new([8, 8], [3, 3]);
+        let fold = config.init();
+
+        assert_eq!(
+            alloc::format!("{}", fold),
+            "Fold4d {output_size: [8, 8], kernel_size: [3, 3], stride: [1, 1], dilation: [1, 1], padding: [0, 0]}"
+        );
+    }
+}
@@ -3,10 +3,12 @@ use crate::tensor::Shape;
 use crate::config::Config;
 use crate::module::{Param, ParamId};
 use crate::tensor::backend::Backend;
-use crate::tensor::{Distribution, Tensor};
+use crate::tensor::{Distribution, Tensor, TensorData};
 
 use crate as burn;
 
+use rand::{rngs::StdRng, RngCore, SeedableRng};
+
 #[cfg(not(feature = "std"))]
 use num_traits::Float;
 
@@ -70,6 +72,54 @@ pub enum Initializer {
     },
 }
 
+#[cfg(feature = "std")]
+std::thread_local! {
+    static PORTABLE_INIT_RNG: core::cell::RefCell<Option<StdRng>> = const { core::cell::RefCell::new(None) };
+}
+
+#[cfg(not(feature = "std"))]
+static PORTABLE_INIT_RNG: spin::Mutex<Option<StdRng>> = spin::Mutex::new(None);
+
+/// Enables the portable initialization of the parameters, seeded with the given seed.
+///
+/// The random values of the [initializers](Initializer) are drawn on the host and uploaded to the
+/// device instead of being generated by the backend, so the same seed yields the same initial
+/// weights on every backend. Each parameter draws its own seed when it is created, so the values
+/// don't depend on the order in which the lazily initialized parameters are materialized.
+///
+/// With the `std` feature, the portable initialization applies to the parameters created on the
+/// current thread.
+pub fn seed_portable_init(seed: u64) {
+    set_portable_init_rng(Some(StdRng::seed_from_u64(seed)));
+}
+
+/// Disables the [portable initialization](seed_portable_init), the random values are generated
+/// by the backend again.
+pub fn disable_portable_init() {
+    set_portable_init_rng(None);
+}
+
+#[cfg(feature = "std")]
+fn set_portable_init_rng(rng: Option<StdRng>) {
+    PORTABLE_INIT_RNG.with(|state| *state.borrow_mut() = rng);
+}
+
+#[cfg(not(feature = "std"))]
+fn set_portable_init_rng(rng: Option<StdRng>) {
+    *PORTABLE_INIT_RNG.lock() = rng;
+}
+
+/// The seed of a parameter, when the portable initialization is enabled.
+#[cfg(feature = "std")]
+fn next_portable_seed() -> Option<u64> {
+    PORTABLE_INIT_RNG.with(|state| state.borrow_mut().as_mut().map(|rng| rng.next_u64()))
+}
+
+#[cfg(not(feature = "std"))]
+fn next_portable_seed() -> Option<u64> {
+    PORTABLE_INIT_RNG.lock().as_mut().map(|rng| rng.next_u64())
+}
+
 impl Initializer {
     /// Inits a tensor parameter of given shape with values depending on initializer kind.
     ///
@@ -99,11 +149,12 @@ impl Initializer {
         let device = device.clone();
         let shape: Shape = shape.into();
         let config = self.clone();
+        let seed = next_portable_seed();
 
         Param::uninitialized(
             ParamId::new(),
             move |device, require_grad| {
-                let mut tensor = config.init_tensor(shape.clone(), fan_in, fan_out, device);
+                let mut tensor = config.init_tensor(shape.clone(), fan_in, fan_out, seed, device);
 
                 if require_grad {
                     tensor = tensor.require_grad();
@@ -121,6 +172,7 @@ impl Initializer {
         shape: S,
         fan_in: Option<usize>,
         fan_out: Option<usize>,
+        seed: Option<u64>,
         device: &B::Device,
     ) -> Tensor<B, D> {
         let shape = shape.into();
@@ -128,23 +180,23 @@ impl Initializer {
             Initializer::Constant { value } => Tensor::<B, D>::full(shape, *value, device),
             Initializer::Ones => Tensor::<B, D>::ones(shape, device),
             Initializer::Zeros => Tensor::<B, D>::zeros(shape, device),
-            Initializer::Uniform { min, max } => uniform_draw(shape, *min, *max, device),
-            Initializer::Normal { mean, std } => normal_draw(shape, *mean, *std, device),
+            Initializer::Uniform { min, max } => uniform_draw(shape, *min, *max, seed, device),
+            Initializer::Normal { mean, std } => normal_draw(shape, *mean, *std, seed, device),
             Initializer::KaimingUniform { gain, fan_out_only } => {
                 let a = 3.0f64.sqrt() * *gain * self.kaiming_std(*fan_out_only, fan_in, fan_out);
-                uniform_draw(shape, -a, a, device)
+                uniform_draw(shape, -a, a, seed, device)
             }
             Initializer::KaimingNormal { gain, fan_out_only } => {
                 let std = *gain * self.kaiming_std(*fan_out_only, fan_in, fan_out);
-                normal_draw(shape, 0.0, std, device)
+                normal_draw(shape, 0.0, std, seed, device)
             }
             Initializer::XavierUniform { gain } => {
                 let a = 3.0f64.sqrt() * *gain * self.xavier_std(fan_in, fan_out);
-                uniform_draw(shape, -a, a, device)
+                uniform_draw(shape, -a, a, seed, device)
             }
             Initializer::XavierNormal { gain } => {
                 let std = *gain * self.xavier_std(fan_in, fan_out);
-                normal_draw(shape, 0.0, std, device)
+                normal_draw(shape, 0.0, std, seed, device)
             }
         }
     }
@@ -180,27 +232,47 @@ fn uniform_draw<B: Backend, const D: usize, S: Into<Shape>>(
     shape: S,
     low: f64,
     high: f64,
+    seed: Option<u64>,
     device: &B::Device,
 ) -> Tensor<B, D> {
     let distribution = Distribution::Uniform(low, high);
-    Tensor::<B, D>::random(shape, distribution, device)
+    random_draw(shape, distribution, seed, device)
 }
 
 fn normal_draw<B: Backend, const D: usize, S: Into<Shape>>(
     shape: S,
     mean: f64,
     std: f64,
+    seed: Option<u64>,
     device: &B::Device,
 ) -> Tensor<B, D> {
     let distribution = Distribution::Normal(mean, std);
-    Tensor::<B, D>::random(shape, distribution, device)
+    random_draw(shape, distribution, seed, device)
+}
+
+fn random_draw<B: Backend, const D: usize, S: Into<Shape>>(
+    shape: S,
+    distribution: Distribution,
+    seed: Option<u64>,
+    device: &B::Device,
+) -> Tensor<B, D> {
+    let Some(seed) = seed else {
+        return Tensor::<B, D>::random(shape, distribution, device);
+    };
+
+    // The values are drawn in f32 on the host, so they are the same for every backend.
+    let mut rng = StdRng::seed_from_u64(seed);
+    let shape: Shape = shape.into();
+    let data = TensorData::random::<f32, _, _>(shape.dims, distribution, &mut rng);
+
+    Tensor::<B, D>::from_data(data.convert::<B::FloatElem>(), device)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    use crate::tensor::{ElementConversion, TensorData};
+    use crate::tensor::ElementConversion;
     use num_traits::Pow;
 
     pub type TB = burn_ndarray::NdArray<f32>;
@@ -434,4 +506,35 @@ mod tests {
             .init([fan_out, fan_in], &Default::default())
             .into_value();
     }
+
+    #[test]
+    fn initializer_portable_init_is_deterministic() {
+        let init = |materialize_first: usize| {
+            seed_portable_init(42);
+            let initializer = Initializer::KaimingUniform {
+                gain: 1.0,
+                fan_out_only: false,
+            };
+            let params: [Param<Tensor<TB, 2>>; 2] = [
+                initializer.init_with([4, 3], Some(4), None, &Default::default()),
+                initializer.init_with([4, 3], Some(4), None, &Default::default()),
+            ];
+            disable_portable_init();
+
+            // The parameters are lazily initialized, in any order.
+            let first = params[materialize_first].val().into_data();
+            let second = params[1 - materialize_first].val().into_data();
+            match materialize_first {
+                0 => [first, second],
+                _ => [second, first],
+            }
+        };
+
+        let [a, b] = init(0);
+        let [a_reversed, b_reversed] = init(1);
+
+        a.assert_eq(&a_reversed, true);
+        b.assert_eq(&b_reversed, true);
+        assert_ne!(a, b);
+    }
 }
@@ -20,6 +20,9 @@ pub struct CrossEntropyLossConfig {
     ///
     /// The loss of a specific sample will simply be given by: weight * log(p(x)) * 1,
     ///
+    /// The weights of an imbalanced dataset can be computed from its class frequencies with
+    /// `ClassFrequencies::class_weights` of `burn::data::dataset::transform`.
+    ///
     /// # Pre-conditions
     ///   - The order of the weight vector should correspond to the label integer assignment.
     ///   - Targets assigned negative Int's will not be allowed.
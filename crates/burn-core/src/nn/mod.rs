@@ -19,8 +19,10 @@ pub mod transformer;
 /// Interpolate module
 pub mod interpolate;
 
+mod crf;
 mod dropout;
 mod embedding;
+mod fold;
 mod gelu;
 mod hard_sigmoid;
 mod initializer;
@@ -32,14 +34,15 @@ mod pos_encoding;
 mod prelu;
 mod relu;
 mod rnn;
-mod rope_encoding;
 mod sigmoid;
 mod swiglu;
 mod tanh;
 mod unfold;
 
+pub use crf::*;
 pub use dropout::*;
 pub use embedding::*;
+pub use fold::*;
 pub use gelu::*;
 pub use hard_sigmoid::*;
 pub use initializer::*;
@@ -50,8 +53,9 @@ pub use padding::*;
 pub use pos_encoding::*;
 pub use prelu::*;
 pub use relu::*;
+// The rotary encoding is part of the attention module, but is kept here for compatibility.
+pub use attention::{RotaryEncoding, RotaryEncodingConfig, RotaryEncodingRecord};
 pub use rnn::*;
-pub use rope_encoding::*;
 pub use sigmoid::*;
 pub use swiglu::*;
 pub use tanh::*;
@@ -1,6 +1,7 @@
 use crate as burn;
 use crate::module::{Content, DisplaySettings, ModuleDisplay};
 
+use crate::nn::norm::calibration::next_calibration_batch;
 use crate::nn::Initializer;
 use crate::{
     config::Config,
@@ -96,6 +97,10 @@ impl<const D: usize, B: Backend> BatchNorm<B, D> {
             );
         }
 
+        if let Some(num_batches) = next_calibration_batch(self.running_mean.id()) {
+            return self.forward_calibration(input, num_batches);
+        }
+
         match B::ad_enabled() {
             true => self.forward_train(input),
             false => self.forward_inference(input),
@@ -115,6 +120,30 @@ impl<const D: usize, B: Backend> BatchNorm<B, D> {
     }
 
     fn forward_train<const DI: usize>(&self, input: Tensor<B, DI>) -> Tensor<B, DI> {
+        self.forward_batch_stats(input, self.momentum)
+    }
+
+    /// The running statistics are the average of the statistics of the calibration batches, see
+    /// [recalibrate_batch_norm](crate::nn::recalibrate_batch_norm).
+    fn forward_calibration<const DI: usize>(
+        &self,
+        input: Tensor<B, DI>,
+        num_batches: usize,
+    ) -> Tensor<B, DI> {
+        let output = self.forward_batch_stats(input, 1.0 / (num_batches + 1) as f64);
+
+        // Apply the update right away, since the recalibration may end with this batch.
+        self.running_mean.value_sync();
+        self.running_var.value_sync();
+
+        output
+    }
+
+    fn forward_batch_stats<const DI: usize>(
+        &self,
+        input: Tensor<B, DI>,
+        momentum: f64,
+    ) -> Tensor<B, DI> {
         let device = input.device();
         let dims = input.dims();
         let batch_size = dims[0];
@@ -147,16 +176,16 @@ impl<const D: usize, B: Backend> BatchNorm<B, D> {
         let running_mean = self.running_mean.value_sync().to_device(&device);
         let running_var = self.running_var.value_sync().to_device(&device);
 
-        let running_mean = running_mean.mul_scalar(1.0 - self.momentum).add(
+        let running_mean = running_mean.mul_scalar(1.0 - momentum).add(
             mean.clone()
                 .detach()
-                .mul_scalar(self.momentum)
+                .mul_scalar(momentum)
                 .reshape([channels]),
         );
-        let running_var = running_var.mul_scalar(1.0 - self.momentum).add(
+        let running_var = running_var.mul_scalar(1.0 - momentum).add(
             var.clone()
                 .detach()
-                .mul_scalar(self.momentum)
+                .mul_scalar(momentum)
                 .reshape([channels]),
         );
 
@@ -209,8 +238,9 @@ impl<const D: usize, B: Backend> ModuleDisplay for BatchNorm<B, D> {
 #[cfg(test)]
 mod tests_1d {
     use super::*;
+    use crate::nn::recalibrate_batch_norm;
     use crate::tensor::TensorData;
-    use crate::{module::AutodiffModule, TestAutodiffBackend};
+    use crate::{module::AutodiffModule, TestAutodiffBackend, TestBackend};
 
     #[test]
     fn batch_norm_forward_train() {
@@ -250,6 +280,31 @@ mod tests_1d {
         output.to_data().assert_approx_eq(&expected, 2);
     }
 
+    #[test]
+    fn batch_norm_recalibration() {
+        let device = Default::default();
+        let module = BatchNormConfig::new(3).init::<TestBackend, 1>(&device);
+        let batches = [
+            input_tensor::<TestBackend>(&device),
+            input_tensor::<TestBackend>(&device).add_scalar(1.0),
+        ];
+
+        let num_batches =
+            recalibrate_batch_norm(&module, batches, |module, batch| module.forward(batch));
+
+        assert_eq!(num_batches, 2);
+        module
+            .running_mean
+            .value()
+            .to_data()
+            .assert_approx_eq(&TensorData::from([1.1149, 1.1266, 1.2288]), 3);
+        module
+            .running_var
+            .value()
+            .to_data()
+            .assert_approx_eq(&TensorData::from([0.0904, 0.1360, 0.0166]), 3);
+    }
+
     fn input_tensor<B: Backend>(device: &B::Device) -> Tensor<B, 3> {
         Tensor::<B, 3>::from_floats(
             [
@@ -0,0 +1,116 @@
+use alloc::vec::Vec;
+
+use crate::module::ParamId;
+
+/// The number of batches each [batch norm](super::BatchNorm) layer was calibrated with, identified
+/// by the id of its running mean, `None` when no recalibration is in progress.
+type Layers = Option<Vec<(ParamId, usize)>>;
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    static CALIBRATION: core::cell::RefCell<Layers> = const { core::cell::RefCell::new(None) };
+}
+
+#[cfg(not(feature = "std"))]
+static CALIBRATION: spin::Mutex<Layers> = spin::Mutex::new(None);
+
+#[cfg(feature = "std")]
+fn with_calibration<R>(func: impl FnOnce(&mut Layers) -> R) -> R {
+    CALIBRATION.with(|state| func(&mut state.borrow_mut()))
+}
+
+#[cfg(not(feature = "std"))]
+fn with_calibration<R>(func: impl FnOnce(&mut Layers) -> R) -> R {
+    func(&mut CALIBRATION.lock())
+}
+
+/// Recompute the running statistics of the [batch norm](super::BatchNorm) layers of a model by
+/// streaming calibration batches through it.
+///
+/// While the batches are processed, the batch norm layers normalize their input with the
+/// statistics of the batch and replace their running mean and variance with the average of the
+/// statistics of all calibration batches, instead of updating them with their momentum. This is
+/// required when the running statistics don't match the weights anymore, e.g. after training with
+/// strong augmentations, swapping the weights with their exponential moving average or pruning.
+///
+/// The forward function only streams the batches through the model, its output is dropped. The
+/// running states are shared between the clones of the model, which are recalibrated as well.
+///
+/// With the `std` feature, the recalibration applies to the forward passes executed on the current
+/// thread.
+///
+/// Returns the number of calibration batches.
+///
+/// # Panics
+///
+/// If a recalibration is already in progress.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let ema_model = ema.model();
+/// recalibrate_batch_norm(&ema_model, dataloader.iter(), |model, batch| {
+///     model.forward(batch.images)
+/// });
+/// ```
+pub fn recalibrate_batch_norm<M, I, O, F>(
+    model: &M,
+    batches: impl IntoIterator<Item = I>,
+    mut forward: F,
+) -> usize
+where
+    F: FnMut(&M, I) -> O,
+{
+    let _calibration = Calibration::start();
+    let mut num_batches = 0;
+
+    for batch in batches {
+        core::mem::drop(forward(model, batch));
+        num_batches += 1;
+    }
+
+    num_batches
+}
+
+/// The number of batches the [batch norm](super::BatchNorm) layer with the given running mean was
+/// already calibrated with, registering the current one, `None` when no recalibration is in progress.
+pub(crate) fn next_calibration_batch(running_mean: ParamId) -> Option<usize> {
+    with_calibration(|calibration| {
+        let layers = calibration.as_mut()?;
+
+        match layers.iter_mut().find(|(id, _)| *id == running_mean) {
+            Some((_, num_batches)) => {
+                let previous = *num_batches;
+                *num_batches += 1;
+                Some(previous)
+            }
+            None => {
+                layers.push((running_mean, 1));
+                Some(0)
+            }
+        }
+    })
+}
+
+/// Ends the calibration when dropped, even when the forward function panics.
+struct Calibration;
+
+impl Calibration {
+    fn start() -> Self {
+        with_calibration(|calibration| {
+            if calibration.is_some() {
+                panic!("A batch norm recalibration is already in progress");
+            }
+
+            *calibration = Some(Vec::new());
+        });
+
+        Self
+    }
+}
+
+impl Drop for Calibration {
+    fn drop(&mut self) {
+        with_calibration(|calibration| *calibration = None);
+    }
+}
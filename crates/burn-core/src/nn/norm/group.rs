@@ -6,6 +6,7 @@ use crate::module::Module;
 use crate::module::Param;
 use crate::module::{Content, DisplaySettings, ModuleDisplay};
 use crate::tensor::backend::Backend;
+use crate::tensor::module::layer_norm;
 use crate::tensor::Tensor;
 
 /// Configuration to create a [GroupNorm](GroupNorm) layer using the [init function](GroupNormConfig::init).
@@ -35,6 +36,10 @@ pub struct GroupNormConfig {
 /// - `γ` is the learnable weight
 /// - `β` is the learnable bias
 ///
+/// Each group is normalized as `(X - mean) / sqrt(var + ε)`, like PyTorch. Previous versions
+/// computed `(X - mean) / (sqrt(var) + ε)`, so outputs differ slightly for groups with a small
+/// variance compared to `ε`.
+///
 /// Should be created using [GroupNormConfig](GroupNormConfig).
 #[derive(Module, Debug)]
 #[module(custom_display)]
@@ -167,11 +172,11 @@ pub(crate) fn group_norm<B: Backend, const D: usize>(
     let hidden_size = shape.dims[2..].iter().product::<usize>() * num_channels / num_groups;
     let input = input.reshape([batch_size, num_groups, hidden_size]);
 
-    let mean = input.clone().sum_dim(2) / hidden_size as f64;
-    let input = input.sub(mean);
-
-    let var = input.clone().powf_scalar(2.).sum_dim(2) / hidden_size as f64;
-    let input_normalized = input.div(var.sqrt().add_scalar(epsilon));
+    // Each group is normalized with the fused layer norm op, so the forward and backward passes
+    // each run as a single kernel. The affine transformation is per channel and not per element of
+    // the group, so it's applied afterward.
+    let ones = Tensor::ones([hidden_size], &input.device());
+    let input_normalized = layer_norm(input, ones, None, epsilon);
 
     if affine {
         let mut affine_shape = [1; D];
@@ -316,6 +321,98 @@ mod tests {
         output.to_data().assert_approx_eq(&expected, 3);
     }
 
+    #[test]
+    fn group_norm_forward_large_epsilon() {
+        let device = Default::default();
+        let module = GroupNormConfig::new(3, 6)
+            .with_epsilon(1e-1)
+            .init::<TestBackend>(&device);
+
+        let input = Tensor::<TestBackend, 3>::from_data(
+            TensorData::from([
+                [
+                    [0.3345, 0.4429, 0.6639],
+                    [0.5041, 0.4175, 0.8437],
+                    [0.6159, 0.3758, 0.4071],
+                    [0.5417, 0.5785, 0.7671],
+                    [0.3837, 0.9883, 0.0420],
+                    [0.4808, 0.8989, 0.6144],
+                ],
+                [
+                    [0.3930, 0.2098, 0.0602],
+                    [0.2298, 0.9425, 0.0333],
+                    [0.7409, 0.8172, 0.8879],
+                    [0.4846, 0.0486, 0.2029],
+                    [0.6741, 0.9765, 0.6864],
+                    [0.2827, 0.5534, 0.2125],
+                ],
+            ]),
+            &device,
+        );
+
+        let output = module.forward(input);
+
+        // The epsilon is added to the variance and not to the standard deviation.
+        let expected = TensorData::from([
+            [
+                [-0.5562, -0.2546, 0.3602],
+                [-0.0844, -0.3253, 0.8603],
+                [0.1993, -0.5021, -0.4107],
+                [-0.0175, 0.0900, 0.6410],
+                [-0.4110, 0.9372, -1.1730],
+                [-0.1945, 0.7379, 0.1034],
+            ],
+            [
+                [0.1853, -0.2309, -0.5708],
+                [-0.1855, 1.4338, -0.6319],
+                [0.4716, 0.6425, 0.8009],
+                [-0.1025, -1.0791, -0.7335],
+                [0.2690, 1.0097, 0.2992],
+                [-0.6897, -0.0266, -0.8616],
+            ],
+        ]);
+        output.to_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn group_norm_backward_should_match_elementwise_ops() {
+        use crate::tensor::Distribution;
+        use crate::TestAutodiffBackend;
+
+        let device = Default::default();
+        let module = GroupNormConfig::new(2, 4).init::<TestAutodiffBackend>(&device);
+        let input =
+            Tensor::<TestAutodiffBackend, 3>::random([2, 4, 5], Distribution::Default, &device);
+        let weights =
+            Tensor::<TestAutodiffBackend, 3>::random([2, 4, 5], Distribution::Default, &device);
+
+        let x = input.clone().require_grad();
+        let grads = (module.forward(x.clone()) * weights.clone())
+            .sum()
+            .backward();
+        let x_grad = x.grad(&grads).unwrap();
+        let gamma_grad = module.gamma.as_ref().unwrap().grad(&grads).unwrap();
+
+        let x_ref = input.require_grad();
+        let normalized = {
+            let x = x_ref.clone().reshape([2, 2, 10]);
+            let centered = x.clone() - x.mean_dim(2);
+            let var = centered.clone().powf_scalar(2.0).mean_dim(2);
+            (centered / var.add_scalar(module.epsilon).sqrt()).reshape([2, 4, 5])
+        };
+        let output_ref = normalized * module.gamma.as_ref().unwrap().val().reshape([1, 4, 1])
+            + module.beta.as_ref().unwrap().val().reshape([1, 4, 1]);
+        let grads_ref = (output_ref * weights).sum().backward();
+        let x_grad_ref = x_ref.grad(&grads_ref).unwrap();
+        let gamma_grad_ref = module.gamma.as_ref().unwrap().grad(&grads_ref).unwrap();
+
+        x_grad.to_data().assert_approx_eq(&x_grad_ref.to_data(), 3);
+        gamma_grad
+            .to_data()
+            .assert_approx_eq(&gamma_grad_ref.to_data(), 3);
+    }
+
     #[test]
     fn display() {
         let config = GroupNormConfig::new(3, 6);
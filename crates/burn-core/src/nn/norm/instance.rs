@@ -24,6 +24,10 @@ pub struct InstanceNormConfig {
 
 /// Applies Instance Normalization over a tensor as described in the paper [Instance Normalization](https://arxiv.org/abs/1607.08022)
 ///
+/// Each channel is normalized as `(X - mean) / sqrt(var + ε)`, like PyTorch. Previous versions
+/// computed `(X - mean) / (sqrt(var) + ε)`, so outputs differ slightly for channels with a small
+/// variance compared to `ε`.
+///
 /// Should be created using [InstanceNormConfig](InstanceNormConfig).
 #[derive(Module, Debug)]
 #[module(custom_display)]
@@ -190,20 +194,20 @@ mod tests {
 
         let expected = TensorData::from([
             [
-                [-1.06458, -0.2738, 1.33838],
-                [-0.45848, -0.92929, 1.38777],
-                [1.40388, -0.84877, -0.55511],
-                [-0.88515, -0.51245, 1.3976],
-                [-0.22397, 1.32124, -1.09727],
-                [-1.05468, 1.34316, -0.28848],
+                [-1.0644, -0.2738, 1.3381],
+                [-0.4584, -0.9292, 1.3876],
+                [1.4034, -0.8485, -0.5549],
+                [-0.8848, -0.5122, 1.3970],
+                [-0.2240, 1.3212, -1.0973],
+                [-1.0546, 1.3430, -0.2884],
             ],
             [
-                [1.26372, -0.08229, -1.18144],
-                [-0.44049, 1.38403, -0.94354],
-                [-1.23979, 0.03109, 1.2087],
-                [1.32524, -1.08999, -0.23524],
-                [-0.75061, 1.4132, -0.66259],
-                [-0.45469, 1.38697, -0.93228],
+                [1.2635, -0.0823, -1.1812],
+                [-0.4405, 1.3840, -0.9435],
+                [-1.2383, 0.0311, 1.2072],
+                [1.3251, -1.0899, -0.2352],
+                [-0.7505, 1.4129, -0.6625],
+                [-0.4546, 1.3867, -0.9321],
             ],
         ]);
         output.to_data().assert_approx_eq(&expected, 3);
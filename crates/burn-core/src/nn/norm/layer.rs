@@ -7,6 +7,7 @@ use crate::module::ModuleDisplay;
 use crate::module::Param;
 use crate::nn::Initializer;
 use crate::tensor::backend::Backend;
+use crate::tensor::module::layer_norm;
 use crate::tensor::Tensor;
 
 /// Configuration to create a [LayerNorm](LayerNorm) layer using the [init function](LayerNormConfig::init).
@@ -65,13 +66,7 @@ impl<B: Backend> LayerNorm<B> {
     /// - input: `[..., any, d_model]`
     /// - output: `[..., any, d_model]`
     pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
-        let (var, mean) = input.clone().var_mean_bias(D - 1);
-
-        let input_normalized = input.sub(mean).div(var.add_scalar(self.epsilon).sqrt());
-
-        input_normalized
-            .mul(self.gamma.val().unsqueeze())
-            .add(self.beta.val().unsqueeze())
+        layer_norm(input, self.gamma.val(), Some(self.beta.val()), self.epsilon)
     }
 }
 
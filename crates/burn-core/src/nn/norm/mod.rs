@@ -1,10 +1,12 @@
 mod batch;
+mod calibration;
 mod group;
 mod instance;
 mod layer;
 mod rms;
 
 pub use batch::*;
+pub use calibration::*;
 pub use group::*;
 pub use instance::*;
 pub use layer::*;
@@ -1,5 +1,3 @@
-use burn_tensor::DType;
-
 use crate as burn;
 
 use crate::config::Config;
@@ -8,6 +6,7 @@ use crate::module::Param;
 use crate::module::{Content, DisplaySettings, ModuleDisplay};
 use crate::nn::Initializer;
 use crate::tensor::backend::Backend;
+use crate::tensor::module::rms_norm;
 use crate::tensor::Tensor;
 
 /// Configuration to create a [RMS Norm](RmsNorm) layer using the [init function](RmsNormConfig::init).
@@ -69,11 +68,7 @@ impl<B: Backend> RmsNorm<B> {
     /// - input: `[..., any, d_model]`
     /// - output: `[..., any, d_model]`
     pub fn forward<const D: usize>(&self, x: Tensor<B, D>) -> Tensor<B, D> {
-        // Calculate the root-mean-square norm of the input tensor along the last dimension
-        let dtype = x.dtype();
-        let rms =
-            (x.clone().cast(DType::F32).powf_scalar(2.0).mean_dim(D - 1) + self.epsilon).sqrt();
-        (x / rms.cast(dtype)) * self.gamma.val().unsqueeze()
+        rms_norm(x, self.gamma.val(), self.epsilon)
     }
 }
 
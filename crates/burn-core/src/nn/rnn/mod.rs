@@ -6,5 +6,8 @@ pub mod gru;
 /// Long Short-Term Memory module.
 pub mod lstm;
 
+mod tbptt;
+
 pub use gate_controller::*;
 pub use lstm::*;
+pub use tbptt::*;
@@ -0,0 +1,226 @@
+use alloc::vec::Vec;
+
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
+
+#[cfg(not(target_has_atomic = "ptr"))]
+use portable_atomic_util::Arc;
+
+use burn_common::stub::Mutex;
+
+use crate::nn::LstmState;
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+
+/// A recurrent state that can be detached from the computation of the previous steps.
+pub trait RecurrentState {
+    /// Detach the state from the autodiff graph, so that the gradients stop flowing through the
+    /// steps that produced it.
+    fn detach(self) -> Self;
+}
+
+impl<B: Backend, const D: usize> RecurrentState for Tensor<B, D> {
+    fn detach(self) -> Self {
+        Tensor::detach(self)
+    }
+}
+
+impl<B: Backend, const D: usize> RecurrentState for LstmState<B, D> {
+    fn detach(self) -> Self {
+        LstmState::new(self.cell.detach(), self.hidden.detach())
+    }
+}
+
+impl<S: RecurrentState> RecurrentState for Option<S> {
+    fn detach(self) -> Self {
+        self.map(RecurrentState::detach)
+    }
+}
+
+impl<S: RecurrentState> RecurrentState for Vec<S> {
+    fn detach(self) -> Self {
+        self.into_iter().map(RecurrentState::detach).collect()
+    }
+}
+
+impl<S1: RecurrentState, S2: RecurrentState> RecurrentState for (S1, S2) {
+    fn detach(self) -> Self {
+        (self.0.detach(), self.1.detach())
+    }
+}
+
+/// Truncated backpropagation through time.
+///
+/// The sequences are processed in chunks of a fixed number of steps. The recurrent state is
+/// carried from one chunk to the next, but detached, so the gradients of each chunk only flow
+/// through its own steps. This bounds the memory and the cost of the backward pass of long or
+/// streaming sequences.
+///
+/// The state is also carried across calls, so a stream split into consecutive batches can be
+/// trained one batch per step: the first chunk of a batch starts from the last state of the
+/// previous batch. The handle is cheap to clone and the clones share the carried state, so it can
+/// be kept in a model with [Ignored](crate::module::Ignored) and [reset](Self::reset) at the
+/// boundaries of the streams.
+pub struct TruncatedBptt<S> {
+    steps: usize,
+    state: Arc<Mutex<Option<S>>>,
+}
+
+impl<S: RecurrentState> TruncatedBptt<S> {
+    /// Create a truncated backpropagation through time, backpropagating through the given number
+    /// of steps.
+    ///
+    /// # Panics
+    ///
+    /// If the number of steps is zero.
+    pub fn new(steps: usize) -> Self {
+        assert!(
+            steps > 0,
+            "Truncated BPTT needs at least one step per chunk"
+        );
+
+        Self {
+            steps,
+            state: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// The number of steps the gradients flow through.
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    /// Split the input `[batch_size, seq_length, d_input]` into chunks of at most
+    /// [steps](Self::steps) steps along the sequence dimension.
+    pub fn chunks<B: Backend>(&self, input: Tensor<B, 3>) -> Vec<Tensor<B, 3>> {
+        let [_, seq_length, _] = input.dims();
+
+        (0..seq_length)
+            .step_by(self.steps)
+            .map(|start| {
+                let length = usize::min(self.steps, seq_length - start);
+                input.clone().narrow(1, start, length)
+            })
+            .collect()
+    }
+
+    /// Run the recurrent forward function on each chunk of the input, starting from the carried
+    /// state, and carry the last state for the next call.
+    ///
+    /// The forward function receives the chunk with the state of the previous chunk, detached,
+    /// and returns its output with the new state. The outputs of the chunks are returned in order,
+    /// e.g. to sum their losses before a single backward pass.
+    pub fn forward<B, O, F>(&self, input: Tensor<B, 3>, mut forward: F) -> Vec<O>
+    where
+        B: Backend,
+        F: FnMut(Tensor<B, 3>, Option<S>) -> (O, S),
+    {
+        let mut state = self.take();
+        let mut outputs = Vec::new();
+
+        for chunk in self.chunks(input) {
+            let (output, next) = forward(chunk, state);
+            outputs.push(output);
+            state = Some(next.detach());
+        }
+
+        if let Some(state) = state {
+            self.carry(state);
+        }
+
+        outputs
+    }
+
+    /// Take the carried state, leaving no state to carry.
+    pub fn take(&self) -> Option<S> {
+        self.state.lock().unwrap().take()
+    }
+
+    /// Carry the given state to the next chunk, detached from its history.
+    pub fn carry(&self, state: S) {
+        *self.state.lock().unwrap() = Some(state.detach());
+    }
+
+    /// Drop the carried state, the next chunk starts a new sequence.
+    pub fn reset(&self) {
+        *self.state.lock().unwrap() = None;
+    }
+}
+
+impl<S> Clone for TruncatedBptt<S> {
+    fn clone(&self) -> Self {
+        Self {
+            steps: self.steps,
+            state: self.state.clone(),
+        }
+    }
+}
+
+impl<S> core::fmt::Debug for TruncatedBptt<S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("TruncatedBptt")
+            .field("steps", &self.steps)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::TensorData;
+    use crate::TestAutodiffBackend;
+
+    #[test]
+    fn should_split_sequence_into_chunks() {
+        let device = Default::default();
+        let tbptt = TruncatedBptt::<Tensor<TestAutodiffBackend, 2>>::new(2);
+        let input = Tensor::<TestAutodiffBackend, 3>::zeros([1, 5, 3], &device);
+
+        let chunks = tbptt.chunks(input);
+
+        let lengths = chunks
+            .iter()
+            .map(|chunk| chunk.dims()[1])
+            .collect::<Vec<_>>();
+        assert_eq!(lengths, vec![2, 2, 1]);
+    }
+
+    #[test]
+    fn should_backpropagate_through_last_chunk_only() {
+        let device = Default::default();
+        let tbptt = TruncatedBptt::new(2);
+        let weight = Tensor::<TestAutodiffBackend, 1>::from_floats([2.0], &device).require_grad();
+        let input = Tensor::<TestAutodiffBackend, 3>::ones([1, 4, 1], &device);
+
+        // The state is multiplied by the weight at each step, h_t = w * h_{t-1} + x_t.
+        let mut outputs = tbptt.forward(input, |chunk, state: Option<Tensor<_, 2>>| {
+            let [_, steps, _] = chunk.dims();
+            let mut hidden = state.unwrap_or_else(|| Tensor::zeros([1, 1], &device));
+
+            for step in 0..steps {
+                let x = chunk.clone().narrow(1, step, 1).reshape([1, 1]);
+                hidden = hidden.mul(weight.clone().unsqueeze()).add(x);
+            }
+
+            (hidden.clone(), hidden)
+        });
+
+        // h_2 = 3 is carried detached, h_4 = w * (3 * w + 1) + 1, so dh_4/dw = 6 * w + 1.
+        let output = outputs.pop().unwrap();
+        output
+            .to_data()
+            .assert_eq(&TensorData::from([[15.0]]), false);
+        let grads = output.backward();
+        weight
+            .grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_eq(&TensorData::from([13.0]), false);
+
+        let carried = tbptt.take().unwrap();
+        assert!(!carried.is_require_grad());
+        carried
+            .to_data()
+            .assert_eq(&TensorData::from([[15.0]]), false);
+    }
+}
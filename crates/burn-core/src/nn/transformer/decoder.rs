@@ -34,6 +34,14 @@ pub struct TransformerDecoderConfig {
     /// Layer norm will be applied first instead of after the other modules.
     #[config(default = false)]
     pub norm_first: bool,
+    /// Apply the sublayers in parallel on the input of the layer, each through its own layer norm,
+    /// and sum their outputs to the residual, as in GPT-J and PaLM, instead of one after the
+    /// other. Requires [norm first](TransformerDecoderConfig::norm_first). Default: false
+    #[config(default = false)]
+    pub parallel: bool,
+    /// The epsilon of the layer norms, required for numerical stability. Default: 1e-5
+    #[config(default = 1e-5)]
+    pub norm_epsilon: f64,
     /// Use "quiet softmax" instead of regular softmax.
     ///
     /// - Usage may improve performance by allowing attention heads to deposit no information (if the sequence contains no information relevant to that head).
@@ -47,6 +55,12 @@ pub struct TransformerDecoderConfig {
         default = "Initializer::KaimingUniform{gain:1.0/num_traits::Float::sqrt(3.0), fan_out_only:false}"
     )]
     pub initializer: Initializer,
+    /// Attend to the memory in chunks of the given length in the cross-attention, bounding the
+    /// memory used by the attention weights over long encoder outputs. Default: None
+    ///
+    /// See [chunk size](MultiHeadAttentionConfig::chunk_size).
+    #[config(default = "None")]
+    pub cross_attn_chunk_size: Option<usize>,
 }
 
 /// The transformer decoder module as describe in the paper [Attention Is All You Need](https://arxiv.org/abs/1706.03762).
@@ -80,6 +94,9 @@ pub struct TransformerDecoder<B: Backend> {
     /// Layer norm will be applied first instead of after the other modules.
     pub norm_first: bool,
 
+    /// The sublayers are applied in parallel on the input of each layer.
+    pub parallel: bool,
+
     /// Use "quiet softmax" instead of regular softmax.
     pub quiet_softmax: bool,
 }
@@ -99,6 +116,7 @@ impl<B: Backend> ModuleDisplay for TransformerDecoder<B> {
             .add("n_layers", &self.n_layers)
             .add("dropout", &self.dropout)
             .add("norm_first", &self.norm_first)
+            .add("parallel", &self.parallel)
             .add("quiet_softmax", &self.quiet_softmax)
             .optional()
     }
@@ -106,7 +124,16 @@ impl<B: Backend> ModuleDisplay for TransformerDecoder<B> {
 
 impl TransformerDecoderConfig {
     /// Initialize a new [Transformer Decoder](TransformerDecoder) module.
+    ///
+    /// # Panics
+    ///
+    /// If the layout is [parallel](Self::parallel) without [norm first](Self::norm_first).
     pub fn init<B: Backend>(&self, device: &B::Device) -> TransformerDecoder<B> {
+        assert!(
+            !self.parallel || self.norm_first,
+            "The parallel layout requires the layer norms to be applied first"
+        );
+
         let layers = (0..self.n_layers)
             .map(|_| TransformerDecoderLayer::new(self, device))
             .collect::<Vec<_>>();
@@ -119,6 +146,7 @@ impl TransformerDecoderConfig {
             n_layers: self.n_layers,
             dropout: self.dropout,
             norm_first: self.norm_first,
+            parallel: self.parallel,
             quiet_softmax: self.quiet_softmax,
         }
     }
@@ -184,6 +212,7 @@ pub struct TransformerDecoderLayer<B: Backend> {
     norm_3: LayerNorm<B>,
     dropout: Dropout,
     norm_first: bool,
+    parallel: bool,
 }
 
 struct TransformerDecoderLayerAutoregressiveCache<B: Backend> {
@@ -237,10 +266,17 @@ impl<B: Backend> TransformerDecoderLayer<B> {
             .with_initializer(config.initializer.clone())
             .with_dropout(config.dropout)
             .with_quiet_softmax(config.quiet_softmax)
+            .with_chunk_size(config.cross_attn_chunk_size)
+            .init(device);
+        let norm_1 = LayerNormConfig::new(config.d_model)
+            .with_epsilon(config.norm_epsilon)
+            .init(device);
+        let norm_2 = LayerNormConfig::new(config.d_model)
+            .with_epsilon(config.norm_epsilon)
+            .init(device);
+        let norm_3 = LayerNormConfig::new(config.d_model)
+            .with_epsilon(config.norm_epsilon)
             .init(device);
-        let norm_1 = LayerNormConfig::new(config.d_model).init(device);
-        let norm_2 = LayerNormConfig::new(config.d_model).init(device);
-        let norm_3 = LayerNormConfig::new(config.d_model).init(device);
         let dropout = DropoutConfig::new(config.dropout).init();
         let pwff = PositionWiseFeedForwardConfig::new(config.d_model, config.d_ff)
             .with_dropout(config.dropout)
@@ -255,6 +291,7 @@ impl<B: Backend> TransformerDecoderLayer<B> {
             pwff,
             dropout,
             norm_first: config.norm_first,
+            parallel: config.parallel,
         }
     }
 
@@ -280,11 +317,15 @@ impl<B: Backend> TransformerDecoderLayer<B> {
         let residual_path = self.self_attn.forward(self_attn_input).context;
 
         let residual_path = self.dropout.forward(residual_path);
+        let layer_input = x.clone();
         let mut x = x + residual_path;
 
         // Cross attention residual path.
         // Normalize.
-        let residual_path = if self.norm_first {
+        let residual_path = if self.parallel {
+            // The cross attention reads the input of the layer instead of the self attention output.
+            self.norm_1.forward(layer_input.clone())
+        } else if self.norm_first {
             self.norm_1.forward(x.clone())
         } else {
             x = self.norm_1.forward(x);
@@ -300,14 +341,16 @@ impl<B: Backend> TransformerDecoderLayer<B> {
         if let Some(mask_attn) = &input.memory_mask_attn {
             cross_attn_input = cross_attn_input.mask_attn(mask_attn.clone());
         }
-        let residual_path = self.cross_attn.forward(cross_attn_input).context;
+        let residual_path = self.cross_attn.forward_context(cross_attn_input);
 
         let residual_path = self.dropout.forward(residual_path);
         let mut x = x + residual_path;
 
         // Feed forward residual path.
         // Normalize.
-        let residual_path = if self.norm_first {
+        let residual_path = if self.parallel {
+            self.norm_2.forward(layer_input)
+        } else if self.norm_first {
             self.norm_2.forward(x.clone())
         } else {
             x = self.norm_2.forward(x);
@@ -358,11 +401,16 @@ impl<B: Backend> TransformerDecoderLayer<B> {
             .context;
 
         let residual_path = self.dropout.forward(residual_path);
+        let layer_input = x.clone();
         let mut x = x + residual_path;
 
         // Cross attention residual path.
         // Normalize.
-        let residual_path = if self.norm_first {
+        let residual_path = if self.parallel {
+            cache
+                .norm_1
+                .forward_autoregressive(layer_input.clone(), 1, |x| self.norm_1.forward(x))
+        } else if self.norm_first {
             cache
                 .norm_1
                 .forward_autoregressive(x.clone(), 1, |x| self.norm_1.forward(x))
@@ -384,15 +432,18 @@ impl<B: Backend> TransformerDecoderLayer<B> {
         }
         let residual_path = self
             .cross_attn
-            .forward_cache(cross_attn_input, &mut cache.cross_attn)
-            .context;
+            .forward_cache_context(cross_attn_input, &mut cache.cross_attn);
 
         let residual_path = self.dropout.forward(residual_path);
         let mut x = x + residual_path;
 
         // Feed forward residual path.
         // Normalize.
-        let residual_path = if self.norm_first {
+        let residual_path = if self.parallel {
+            cache
+                .norm_2
+                .forward_autoregressive(layer_input, 1, |x| self.norm_2.forward(x))
+        } else if self.norm_first {
             cache
                 .norm_2
                 .forward_autoregressive(x.clone(), 1, |x| self.norm_2.forward(x))
@@ -480,6 +531,59 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_autoregressive_parallel() {
+        let [d_model, d_ff, n_heads, num_layers] = [12, 24, 2, 3];
+        TestBackend::seed(0);
+
+        test_autoregressive(
+            TransformerDecoderConfig::new(d_model, d_ff, n_heads, num_layers)
+                .with_norm_first(true)
+                .with_parallel(true),
+        )
+    }
+
+    #[test]
+    fn test_autoregressive_chunked_cross_attention() {
+        let [d_model, d_ff, n_heads, num_layers] = [12, 24, 2, 3];
+        TestBackend::seed(0);
+
+        test_autoregressive(
+            TransformerDecoderConfig::new(d_model, d_ff, n_heads, num_layers)
+                .with_cross_attn_chunk_size(Some(3)),
+        )
+    }
+
+    #[test]
+    fn test_chunked_cross_attention_should_have_same_output() {
+        let device = Default::default();
+        let [batch_size, seq_length, memory_length, d_model] = [2, 3, 10, 12];
+        let transformer = TransformerDecoderConfig::new(d_model, 24, 2, 2).init(&device);
+        let mut transformer_chunked = transformer.clone();
+        for layer in transformer_chunked.layers.iter_mut() {
+            layer.cross_attn.chunk_size = Some(4);
+        }
+
+        let memory = Tensor::<TestBackend, 3>::random(
+            [batch_size, memory_length, d_model],
+            Distribution::Default,
+            &device,
+        );
+        let target = Tensor::<TestBackend, 3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+            &device,
+        );
+
+        let output_1 =
+            transformer.forward(TransformerDecoderInput::new(target.clone(), memory.clone()));
+        let output_2 = transformer_chunked.forward(TransformerDecoderInput::new(target, memory));
+
+        output_1
+            .into_data()
+            .assert_approx_eq(&output_2.into_data(), 3);
+    }
+
     fn test_autoregressive(config: TransformerDecoderConfig) {
         let device = Default::default();
         let [batch_size, seq_length, d_model] = [3, 4, config.d_model];
@@ -534,7 +638,7 @@ mod tests {
         assert_eq!(
             alloc::format!("{}", transformer),
             "TransformerDecoder {d_model: 2, d_ff: 4, n_heads: 2, n_layers: 3, \
-            dropout: 0.1, norm_first: false, quiet_softmax: false, params: 246}"
+            dropout: 0.1, norm_first: false, parallel: false, quiet_softmax: false, params: 246}"
         );
     }
 }
@@ -33,6 +33,14 @@ pub struct TransformerEncoderConfig {
     /// Layer norm will be applied first instead of after the other modules.
     #[config(default = false)]
     pub norm_first: bool,
+    /// Apply the sublayers in parallel on the input of the layer, each through its own layer norm,
+    /// and sum their outputs to the residual, as in GPT-J and PaLM, instead of one after the
+    /// other. Requires [norm first](TransformerEncoderConfig::norm_first). Default: false
+    #[config(default = false)]
+    pub parallel: bool,
+    /// The epsilon of the layer norms, required for numerical stability. Default: 1e-5
+    #[config(default = 1e-5)]
+    pub norm_epsilon: f64,
     /// Use "quiet softmax" instead of regular softmax.
     ///
     /// - Usage may improve performance by allowing attention heads to deposit no information (if the sequence contains no information relevant to that head).
@@ -79,6 +87,9 @@ pub struct TransformerEncoder<B: Backend> {
     /// Layer norm will be applied first instead of after the other modules.
     pub norm_first: bool,
 
+    /// The sublayers are applied in parallel on the input of each layer.
+    pub parallel: bool,
+
     /// Use "quiet softmax" instead of regular softmax.
     pub quiet_softmax: bool,
 }
@@ -98,6 +109,7 @@ impl<B: Backend> ModuleDisplay for TransformerEncoder<B> {
             .add("n_layers", &self.n_layers)
             .add("dropout", &self.dropout)
             .add("norm_first", &self.norm_first)
+            .add("parallel", &self.parallel)
             .add("quiet_softmax", &self.quiet_softmax)
             .optional()
     }
@@ -135,7 +147,16 @@ impl<B: Backend> TransformerEncoderInput<B> {
 }
 impl TransformerEncoderConfig {
     /// Initialize a new [transformer encoder](TransformerEncoder) module.
+    ///
+    /// # Panics
+    ///
+    /// If the layout is [parallel](Self::parallel) without [norm first](Self::norm_first).
     pub fn init<B: Backend>(&self, device: &B::Device) -> TransformerEncoder<B> {
+        assert!(
+            !self.parallel || self.norm_first,
+            "The parallel layout requires the layer norms to be applied first"
+        );
+
         let layers = (0..self.n_layers)
             .map(|_| TransformerEncoderLayer::new(self, device))
             .collect::<Vec<_>>();
@@ -148,6 +169,7 @@ impl TransformerEncoderConfig {
             n_layers: self.n_layers,
             dropout: self.dropout,
             norm_first: self.norm_first,
+            parallel: self.parallel,
             quiet_softmax: self.quiet_softmax,
         }
     }
@@ -212,6 +234,7 @@ pub struct TransformerEncoderLayer<B: Backend> {
     norm_2: LayerNorm<B>,
     dropout: Dropout,
     norm_first: bool,
+    parallel: bool,
 }
 
 impl<B: Backend> TransformerEncoderLayer<B> {
@@ -221,8 +244,12 @@ impl<B: Backend> TransformerEncoderLayer<B> {
             .with_dropout(config.dropout)
             .with_quiet_softmax(config.quiet_softmax)
             .init(device);
-        let norm_1 = LayerNormConfig::new(config.d_model).init(device);
-        let norm_2 = LayerNormConfig::new(config.d_model).init(device);
+        let norm_1 = LayerNormConfig::new(config.d_model)
+            .with_epsilon(config.norm_epsilon)
+            .init(device);
+        let norm_2 = LayerNormConfig::new(config.d_model)
+            .with_epsilon(config.norm_epsilon)
+            .init(device);
         let dropout = DropoutConfig::new(config.dropout).init();
         let pwff = PositionWiseFeedForwardConfig::new(config.d_model, config.d_ff)
             .with_initializer(config.initializer.clone())
@@ -236,6 +263,7 @@ impl<B: Backend> TransformerEncoderLayer<B> {
             pwff,
             dropout,
             norm_first: config.norm_first,
+            parallel: config.parallel,
         }
     }
 
@@ -265,11 +293,15 @@ impl<B: Backend> TransformerEncoderLayer<B> {
         let residual_path = self.mha.forward(input_mhs).context;
 
         let residual_path = self.dropout.forward(residual_path);
+        let layer_input = x.clone();
         let mut x = x + residual_path;
 
         // Feed forward residual path.
         // Normalize.
-        let residual_path = if self.norm_first {
+        let residual_path = if self.parallel {
+            // The feed-forward reads the input of the layer instead of the attention output.
+            self.norm_1.forward(layer_input)
+        } else if self.norm_first {
             self.norm_1.forward(x.clone())
         } else {
             x = self.norm_1.forward(x);
@@ -319,11 +351,16 @@ impl<B: Backend> TransformerEncoderLayer<B> {
         let residual_path = self.mha.forward_cache(input_mhs, &mut cache.mha).context;
 
         let residual_path = self.dropout.forward(residual_path);
+        let layer_input = x.clone();
         let mut x = x + residual_path;
 
         // Feed forward residual path.
         // Normalize.
-        let residual_path = if self.norm_first {
+        let residual_path = if self.parallel {
+            cache
+                .norm_1
+                .forward_autoregressive(layer_input, 1, |x| self.norm_1.forward(x))
+        } else if self.norm_first {
             cache
                 .norm_1
                 .forward_autoregressive(x.clone(), 1, |x| self.norm_1.forward(x))
@@ -411,6 +448,53 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_autoregressive_parallel() {
+        let [d_model, d_ff, n_heads, num_layers] = [12, 24, 2, 3];
+        test_autoregressive(
+            TransformerEncoderConfig::new(d_model, d_ff, n_heads, num_layers)
+                .with_norm_first(true)
+                .with_parallel(true),
+        )
+    }
+
+    #[test]
+    fn test_parallel_should_sum_the_sublayers() {
+        let device = Default::default();
+        let [batch_size, seq_length, d_model] = [2, 3, 12];
+        let transformer = TransformerEncoderConfig::new(d_model, 24, 2, 1)
+            .with_norm_first(true)
+            .with_parallel(true)
+            .with_norm_epsilon(1e-6)
+            .init::<TestBackend>(&device);
+        let layer = &transformer.layers[0];
+        let tensor = Tensor::<TestBackend, 3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+            &device,
+        );
+
+        let output = transformer.forward(TransformerEncoderInput::new(tensor.clone()));
+        let attn = layer
+            .mha
+            .forward(MhaInput::self_attn(layer.norm_2.forward(tensor.clone())))
+            .context;
+        let ff = layer.pwff.forward(layer.norm_1.forward(tensor.clone()));
+
+        assert!(alloc::format!("{}", layer.norm_1).contains("epsilon: 0.000001"));
+        output
+            .into_data()
+            .assert_approx_eq(&(tensor + attn + ff).into_data(), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "parallel layout requires the layer norms to be applied first")]
+    fn test_parallel_should_require_norm_first() {
+        TransformerEncoderConfig::new(12, 24, 2, 1)
+            .with_parallel(true)
+            .init::<TestBackend>(&Default::default());
+    }
+
     fn test_autoregressive(config: TransformerEncoderConfig) {
         let [batch_size, seq_length, d_model] = [3, 4, config.d_model];
         let device = Default::default();
@@ -452,7 +536,8 @@ mod tests {
         assert_eq!(
             alloc::format!("{}", transformer),
             "TransformerEncoder {d_model: 2, d_ff: 4, n_heads: 2, \
-            n_layers: 3, dropout: 0.1, norm_first: false, quiet_softmax: false, params: 162}"
+            n_layers: 3, dropout: 0.1, norm_first: false, parallel: false, quiet_softmax: false, \
+            params: 162}"
         );
     }
 }
@@ -9,6 +9,7 @@ use super::GradientsParams;
 /// Accumulate gradients into a single [Gradients](AutodiffBackend::Gradients) object.
 pub struct GradientsAccumulator<M> {
     grads: GradientsParams,
+    count: usize,
     phantom: PhantomData<M>,
 }
 
@@ -23,6 +24,7 @@ impl<M> GradientsAccumulator<M> {
     pub fn new() -> Self {
         Self {
             grads: GradientsParams::new(),
+            count: 0,
             phantom: PhantomData,
         }
     }
@@ -36,12 +38,33 @@ impl<M> GradientsAccumulator<M> {
     {
         let mut visitor = ModuleGradsAccumulator::<M>::new(&mut self.grads, grads);
         module.visit(&mut visitor);
+        self.count += 1;
     }
 
     /// Return the accumulated gradients and reset the accumulator state.
     pub fn grads(&mut self) -> GradientsParams {
         let mut grads = GradientsParams::new();
         core::mem::swap(&mut self.grads, &mut grads);
+        self.count = 0;
+
+        grads
+    }
+
+    /// Return the mean of the accumulated gradients and reset the accumulator state.
+    ///
+    /// This is equivalent to scaling the loss of each accumulated backward pass by the inverse of
+    /// the number of accumulated gradients.
+    pub fn grads_mean<B: AutodiffBackend>(&mut self, module: &M) -> GradientsParams
+    where
+        M: AutodiffModule<B>,
+    {
+        let count = self.count;
+        let mut grads = self.grads();
+
+        if count > 1 {
+            let mut visitor = ModuleGradsScaler::<M>::new(&mut grads, 1.0 / count as f64);
+            module.visit(&mut visitor);
+        }
 
         grads
     }
@@ -71,12 +94,28 @@ impl<B: AutodiffBackend, M: AutodiffModule<B>> ModuleVisitor<B> for ModuleGradsA
     }
 }
 
+#[derive(new)]
+struct ModuleGradsScaler<'a, M> {
+    grads: &'a mut GradientsParams,
+    factor: f64,
+    phantom: PhantomData<M>,
+}
+
+impl<B: AutodiffBackend, M: AutodiffModule<B>> ModuleVisitor<B> for ModuleGradsScaler<'_, M> {
+    fn visit_float<const D: usize>(&mut self, id: ParamId, _tensor: &Tensor<B, D>) {
+        if let Some(grad) = self.grads.remove::<B::InnerBackend, D>(id) {
+            self.grads
+                .register::<B::InnerBackend, D>(id, grad.mul_scalar(self.factor));
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{
         nn::{Linear, LinearConfig},
-        TestAutodiffBackend,
+        TestAutodiffBackend, TestBackend,
     };
     use burn_tensor::{backend::Backend, Distribution};
 
@@ -111,6 +150,26 @@ mod tests {
         assert_eq!(grads.len(), 2)
     }
 
+    #[test]
+    fn test_accumulate_gradients_mean() {
+        let device = Default::default();
+        let mut accumulator = GradientsAccumulator::new();
+        let layer = layer::<TestAutodiffBackend>(&device);
+        let input = random_tensor(&device);
+        let grads_1 = GradientsParams::from_grads(layer.forward(input.clone()).backward(), &layer);
+        let grads_2 = GradientsParams::from_grads(layer.forward(input.clone()).backward(), &layer);
+        let expected = GradientsParams::from_grads(layer.forward(input).backward(), &layer);
+
+        accumulator.accumulate(&layer, grads_1);
+        accumulator.accumulate(&layer, grads_2);
+
+        let grads = accumulator.grads_mean(&layer);
+        let grad = grads.get::<TestBackend, 2>(layer.weight.id).unwrap();
+        let grad_expected = expected.get::<TestBackend, 2>(layer.weight.id).unwrap();
+        grad.into_data()
+            .assert_approx_eq(&grad_expected.into_data(), 3);
+    }
+
     fn layer<B: Backend>(device: &B::Device) -> Linear<B> {
         LinearConfig::new(20, 20).with_bias(true).init(device)
     }
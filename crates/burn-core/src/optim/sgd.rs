@@ -100,8 +100,9 @@ mod tests {
     use super::*;
     use crate::{
         grad_clipping::GradientClipping,
+        module::Module,
         nn::{Linear, LinearConfig},
-        optim::{GradientsParams, Optimizer},
+        optim::{group::ParamGroup, GradientsParams, Optimizer},
         tensor::{Distribution, Shape},
         TestAutodiffBackend, TestBackend,
     };
@@ -156,6 +157,58 @@ mod tests {
         assert_eq!(record.len(), state_restored.len());
     }
 
+    #[test]
+    fn should_not_update_frozen_params() {
+        let device = Default::default();
+        let layer = layer::<TestAutodiffBackend>(&device);
+        let mut optim = sgd_with_all();
+        let loss = layer.forward(random_tensor(&device));
+        let grads = GradientsParams::from_grads(loss.backward(), &layer);
+
+        // The gradients computed before freezing the layer are ignored.
+        let frozen = optim.step(LEARNING_RATE, layer.clone().freeze(), grads);
+
+        assert!(!frozen.weight.is_require_grad());
+        frozen
+            .weight
+            .val()
+            .into_data()
+            .assert_eq(&layer.weight.val().into_data(), true);
+        assert!(optim.to_record().is_empty());
+    }
+
+    #[test]
+    fn should_update_param_groups_with_their_hyperparameters() {
+        let device = Default::default();
+        let layer = layer::<TestAutodiffBackend>(&device);
+        let x = random_tensor(&device);
+        let grads = || GradientsParams::from_grads(layer.forward(x.clone()).backward(), &layer);
+
+        let bias = ParamGroup::new(&layer, |param| param.path == "bias").with_lr_scale(0.0);
+        let weight = ParamGroup::new(&layer, |param| param.path == "weight")
+            .with_optimizer(SgdConfig::new().init::<TestAutodiffBackend, Linear<_>>());
+        let mut optim = sgd_with_all()
+            .with_param_group(bias)
+            .with_param_group(weight);
+        let updated = optim.step(LEARNING_RATE, layer.clone(), grads());
+
+        // The weight is updated without momentum and weight decay.
+        let mut plain = SgdConfig::new().init();
+        let expected = plain.step(LEARNING_RATE, layer.clone(), grads());
+
+        updated
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&expected.weight.val().into_data(), 3);
+        updated
+            .bias
+            .unwrap()
+            .val()
+            .into_data()
+            .assert_eq(&layer.bias.unwrap().val().into_data(), true);
+    }
+
     fn random_tensor<B: Backend>(device: &B::Device) -> Tensor<B, 2> {
         Tensor::<B, 2>::random(Shape::new([2, 20]), Distribution::Default, device)
     }
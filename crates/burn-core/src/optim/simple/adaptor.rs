@@ -1,10 +1,11 @@
-use super::{record::AdaptorRecord, SimpleOptimizer};
+use super::{group::ParamGroup, record::AdaptorRecord, SimpleOptimizer};
 use crate::{
     grad_clipping::GradientClipping,
     module::{AutodiffModule, ModuleMapper, ParamId},
     optim::{GradientsParams, Optimizer},
     LearningRate,
 };
+use alloc::vec::Vec;
 use burn_tensor::{backend::AutodiffBackend, Tensor};
 use core::marker::PhantomData;
 use hashbrown::HashMap;
@@ -22,6 +23,7 @@ where
     records: HashMap<ParamId, AdaptorRecord<O, B>>,
     module: PhantomData<M>,
     grad_clipping: Option<GradientClipping>,
+    groups: Vec<ParamGroup<O>>,
 }
 
 impl<O, B, M> From<O> for OptimizerAdaptor<O, M, B>
@@ -36,6 +38,7 @@ where
             records: HashMap::new(),
             module: PhantomData,
             grad_clipping: None,
+            groups: Vec::new(),
         }
     }
 }
@@ -60,6 +63,28 @@ where
         self
     }
 
+    /// Adds a group of parameters updated with their own hyperparameters.
+    ///
+    /// A parameter in multiple groups is updated with the first group added containing it, and
+    /// the parameters in no group are updated with the optimizer and the learning rate as is.
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The parameter group.
+    ///
+    /// # Returns
+    ///
+    /// The optimizer.
+    pub fn with_param_group(mut self, group: ParamGroup<O>) -> Self {
+        self.groups.push(group);
+        self
+    }
+
+    /// Consumes the adaptor and returns the wrapped optimizer.
+    pub fn into_inner(self) -> O {
+        self.optim
+    }
+
     #[cfg(test)]
     pub(crate) fn has_gradient_clipping(&self) -> bool {
         self.grad_clipping.is_some()
@@ -77,6 +102,7 @@ where
     fn step(&mut self, lr: LearningRate, module: M, mut grads: GradientsParams) -> M {
         let mut mapper = SimpleOptimizerMapper::<M, B, O>::new(
             &self.optim,
+            &self.groups,
             &mut self.records,
             &mut grads,
             lr,
@@ -103,6 +129,7 @@ where
     O: SimpleOptimizer<B::InnerBackend>,
 {
     optimizer: &'a O,
+    groups: &'a [ParamGroup<O>],
     records: &'a mut HashMap<ParamId, AdaptorRecord<O, B>>,
     grads: &'a mut GradientsParams,
     lr: LearningRate,
@@ -119,6 +146,11 @@ where
     fn map_float<const D: usize>(&mut self, id: ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
         let grad = self.grads.remove(id);
 
+        // Frozen parameters are skipped, even with gradients computed before freezing them.
+        if !tensor.is_require_grad() {
+            return tensor;
+        }
+
         if let Some(grad) = grad {
             let device = grad.device();
             let is_require_grad = tensor.is_require_grad();
@@ -130,8 +162,16 @@ where
                 grad
             };
 
-            let (tensor, state) = self.optimizer.step(
-                self.lr,
+            let (optimizer, lr) = match self.groups.iter().find(|group| group.contains(&id)) {
+                Some(group) => (
+                    group.optimizer().unwrap_or(self.optimizer),
+                    self.lr * group.lr_scale(),
+                ),
+                None => (self.optimizer, self.lr),
+            };
+
+            let (tensor, state) = optimizer.step(
+                lr,
                 tensor.inner(),
                 clipped_grad,
                 record.map(|record| O::to_device(record.into_state(), &device)),
@@ -0,0 +1,102 @@
+use super::{adaptor::OptimizerAdaptor, SimpleOptimizer};
+use crate::module::{AutodiffModule, Module, ParamId, ParamSummary};
+use burn_tensor::backend::{AutodiffBackend, Backend};
+use hashbrown::HashSet;
+
+/// A group of parameters updated with their own hyperparameters by an
+/// [optimizer adaptor](OptimizerAdaptor).
+///
+/// The parameters of a group are updated with the learning rate multiplied by the group scale,
+/// and with the optimizer of the group when one is set, e.g. to exclude the biases and the norms
+/// from weight decay.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let head = ParamGroup::new(&model, |param| param.path.starts_with("head."))
+///     .with_lr_scale(10.0);
+/// let no_decay = ParamGroup::new(&model, |param| param.shape.num_dims() == 1).with_optimizer(
+///     AdamWConfig::new()
+///         .with_weight_decay(0.0)
+///         .init::<B, Model<B>>(),
+/// );
+///
+/// let optim = AdamWConfig::new()
+///     .with_weight_decay(0.01)
+///     .init()
+///     .with_param_group(head)
+///     .with_param_group(no_decay);
+/// ```
+#[derive(Clone)]
+pub struct ParamGroup<O> {
+    params: HashSet<ParamId>,
+    lr_scale: f64,
+    optim: Option<O>,
+}
+
+impl<O> ParamGroup<O> {
+    /// Create a group with the parameters of the module matching the predicate, e.g. on the
+    /// [path](ParamSummary::path) of the parameters.
+    ///
+    /// The parameters are matched once, so the group keeps the parameters of the module even if
+    /// its structure changes afterward.
+    pub fn new<B, M, P>(module: &M, predicate: P) -> Self
+    where
+        B: Backend,
+        M: Module<B>,
+        P: Fn(&ParamSummary<B>) -> bool,
+    {
+        Self::from_params(
+            module
+                .summary()
+                .params
+                .into_iter()
+                .filter(|param| predicate(param))
+                .map(|param| param.id),
+        )
+    }
+
+    /// Create a group with the given parameters, e.g. collected by a
+    /// [module visitor](crate::module::ModuleVisitor).
+    pub fn from_params<I: IntoIterator<Item = ParamId>>(params: I) -> Self {
+        Self {
+            params: params.into_iter().collect(),
+            lr_scale: 1.0,
+            optim: None,
+        }
+    }
+
+    /// Sets the factor multiplying the learning rate of the parameters of the group.
+    pub fn with_lr_scale(mut self, lr_scale: f64) -> Self {
+        self.lr_scale = lr_scale;
+        self
+    }
+
+    /// Sets the optimizer updating the parameters of the group, with its own hyperparameters.
+    ///
+    /// Only the optimizer is kept, its gradient clipping and parameter groups are ignored.
+    pub fn with_optimizer<M, B>(mut self, optim: OptimizerAdaptor<O, M, B>) -> Self
+    where
+        O: SimpleOptimizer<B::InnerBackend>,
+        M: AutodiffModule<B>,
+        B: AutodiffBackend,
+    {
+        self.optim = Some(optim.into_inner());
+        self
+    }
+
+    /// If the group contains the parameter.
+    pub fn contains(&self, id: &ParamId) -> bool {
+        self.params.contains(id)
+    }
+
+    /// The factor multiplying the learning rate of the parameters of the group.
+    pub fn lr_scale(&self) -> f64 {
+        self.lr_scale
+    }
+
+    /// The optimizer of the group, if it has its own.
+    pub fn optimizer(&self) -> Option<&O> {
+        self.optim.as_ref()
+    }
+}
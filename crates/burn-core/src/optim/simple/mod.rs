@@ -4,5 +4,8 @@ pub use base::*;
 /// Adaptor module for optimizers.
 pub mod adaptor;
 
+/// Parameter group module for optimizers.
+pub mod group;
+
 /// Record module for optimizers.
 pub mod record;
@@ -53,6 +53,44 @@ pub struct NamedMpkFileRecorder<S: PrecisionSettings> {
     _settings: PhantomData<S>,
 }
 
+/// File recorder using the [named msgpack](rmp_serde) format compressed with [zstd].
+///
+/// Zstd compresses faster than gzip for a similar ratio, which matters for the checkpoints of
+/// large models. Combined with [half precision](super::HalfPrecisionSettings), the floats are also
+/// stored as `f16` while the model keeps training in full precision.
+#[cfg(feature = "record-zstd")]
+#[derive(Debug, Clone)]
+pub struct NamedMpkZstdFileRecorder<S: PrecisionSettings> {
+    level: i32,
+    _settings: PhantomData<S>,
+}
+
+#[cfg(feature = "record-zstd")]
+impl<S: PrecisionSettings> NamedMpkZstdFileRecorder<S> {
+    /// Create a new recorder with the default compression level.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the compression level, from 1 (fastest) to 22 (smallest files).
+    ///
+    /// Zero selects the default level of zstd.
+    pub fn with_level(mut self, level: i32) -> Self {
+        self.level = level;
+        self
+    }
+}
+
+#[cfg(feature = "record-zstd")]
+impl<S: PrecisionSettings> Default for NamedMpkZstdFileRecorder<S> {
+    fn default() -> Self {
+        Self {
+            level: zstd::DEFAULT_COMPRESSION_LEVEL,
+            _settings: PhantomData,
+        }
+    }
+}
+
 impl<S: PrecisionSettings, B: Backend> FileRecorder<B> for BinGzFileRecorder<S> {
     fn file_extension() -> &'static str {
         "bin.gz"
@@ -86,6 +124,13 @@ impl<S: PrecisionSettings, B: Backend> FileRecorder<B> for NamedMpkFileRecorder<
     }
 }
 
+#[cfg(feature = "record-zstd")]
+impl<S: PrecisionSettings, B: Backend> FileRecorder<B> for NamedMpkZstdFileRecorder<S> {
+    fn file_extension() -> &'static str {
+        "mpk.zst"
+    }
+}
+
 macro_rules! str2reader {
     (
         $file:expr
@@ -297,6 +342,43 @@ impl<S: PrecisionSettings, B: Backend> Recorder<B> for NamedMpkFileRecorder<S> {
     }
 }
 
+#[cfg(feature = "record-zstd")]
+impl<S: PrecisionSettings, B: Backend> Recorder<B> for NamedMpkZstdFileRecorder<S> {
+    type Settings = S;
+    type RecordArgs = PathBuf;
+    type RecordOutput = ();
+    type LoadArgs = PathBuf;
+
+    fn save_item<I: Serialize>(
+        &self,
+        item: I,
+        mut file: Self::RecordArgs,
+    ) -> Result<(), RecorderError> {
+        let writer = str2writer!(file)?;
+        let mut writer = zstd::Encoder::new(writer, self.level)
+            .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+        rmp_serde::encode::write_named(&mut writer, &item)
+            .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+
+        // The frame is only complete once the encoder is finished.
+        writer
+            .finish()
+            .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+
+        Ok(())
+    }
+
+    fn load_item<I: DeserializeOwned>(&self, mut file: Self::LoadArgs) -> Result<I, RecorderError> {
+        let reader = str2reader!(file)?;
+        let reader = zstd::Decoder::with_buffer(reader)
+            .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+        let state = rmp_serde::decode::from_read(reader)
+            .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+
+        Ok(state)
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -309,7 +391,7 @@ mod tests {
             conv::{Conv2d, Conv2dConfig},
             Linear, LinearConfig,
         },
-        record::{BinBytesRecorder, FullPrecisionSettings},
+        record::{BinBytesRecorder, FullPrecisionSettings, HalfPrecisionSettings},
         TestBackend,
     };
 
@@ -352,6 +434,35 @@ mod tests {
         test_can_save_and_load(NamedMpkFileRecorder::<FullPrecisionSettings>::default())
     }
 
+    #[cfg(feature = "record-zstd")]
+    #[test]
+    fn test_can_save_and_load_mpkzstd_format() {
+        test_can_save_and_load(
+            NamedMpkZstdFileRecorder::<FullPrecisionSettings>::new().with_level(19),
+        )
+    }
+
+    #[test]
+    fn test_can_save_and_load_half_precision() {
+        let recorder = NamedMpkFileRecorder::<HalfPrecisionSettings>::default();
+        let path = file_path().with_file_name("burn_test_file_recorder_half");
+        let device = Default::default();
+        let model_before = create_model(&device);
+        recorder
+            .record(model_before.clone().into_record(), path.clone())
+            .unwrap();
+
+        let model_after = create_model(&device).load_record(recorder.load(path, &device).unwrap());
+
+        // The model is loaded back in full precision, with the values rounded to f16.
+        model_after
+            .linear1
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&model_before.linear1.weight.val().into_data(), 2);
+    }
+
     fn test_can_save_and_load<Recorder>(recorder: Recorder)
     where
         Recorder: FileRecorder<TestBackend>,
@@ -1,11 +1,11 @@
 use alloc::{string::String, vec, vec::Vec};
 use core::{fmt, marker::PhantomData};
 
-use super::tensor::{BoolTensorSerde, FloatTensorSerde, IntTensorSerde};
+use super::tensor::{BoolTensorSerde, FloatParamSerde, IntTensorSerde};
 use super::{PrecisionSettings, Record};
 use crate::module::{Param, ParamId};
 
-use burn_tensor::{backend::Backend, Bool, Int, Tensor};
+use burn_tensor::{backend::Backend, Bool, DType, Int, Tensor};
 
 use hashbrown::HashMap;
 use serde::{
@@ -145,25 +145,42 @@ where
 #[derive(new, Debug, Clone, Serialize, Deserialize)]
 pub struct ParamSerde<T> {
     id: String,
-    param: T,
+    pub(crate) param: T,
 }
 
 impl<B, const D: usize> Record<B> for Param<Tensor<B, D>>
 where
     B: Backend,
 {
-    type Item<S: PrecisionSettings> = ParamSerde<FloatTensorSerde<S>>;
+    type Item<S: PrecisionSettings> = ParamSerde<FloatParamSerde<S>>;
 
     fn into_item<S: PrecisionSettings>(self) -> Self::Item<S> {
+        let declared = self.declared_dtype().is_some();
         let (id, tensor) = self.consume();
-        ParamSerde::new(id.serialize(), tensor.into_item())
+        let data = tensor.into_data();
+        let data = match data.dtype {
+            DType::QFloat(_) => data, // do not convert quantized tensors
+            _ if declared => data,    // keep the precision declared by the parameter
+            _ => data.convert::<S::FloatElem>(),
+        };
+
+        ParamSerde::new(id.serialize(), FloatParamSerde::new(data))
     }
 
     fn from_item<S: PrecisionSettings>(item: Self::Item<S>, device: &B::Device) -> Self {
+        let data = item.param.data;
+        // Data saved by a parameter declaring its precision is cast back to that precision when
+        // the record is loaded into the module, since backends may not support creating tensors
+        // of any float type from data.
+        let data = match data.dtype {
+            DType::QFloat(_) => data, // do not convert quantized tensors
+            _ => data.convert::<B::FloatElem>(),
+        };
+
         Param::initialized(
             ParamId::deserialize(&item.id),
-            Tensor::from_item(item.param, device).require_grad(), // Same behavior as when we create a new
-                                                                  // Param from a tensor.
+            Tensor::from_data(data, device).require_grad(), // Same behavior as when we create a new
+                                                            // Param from a tensor.
         )
     }
 }
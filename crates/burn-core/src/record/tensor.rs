@@ -34,6 +34,18 @@ pub struct FloatTensorSerde<S: PrecisionSettings> {
     _e: PhantomData<S::FloatElem>,
 }
 
+/// This struct implements serde to lazily serialize and deserialize a float parameter
+/// using the given [record settings](RecordSettings).
+///
+/// Unlike [FloatTensorSerde], float data saved with another precision than the settings is kept
+/// as is, since only the parameters declaring their [data type](crate::module::Param::with_dtype)
+/// are saved with their own precision.
+#[derive(new, Clone, Debug)]
+pub struct FloatParamSerde<S: PrecisionSettings> {
+    pub(crate) data: TensorData,
+    _e: PhantomData<S::FloatElem>,
+}
+
 /// This struct implements serde to lazily serialize and deserialize an int tensor
 /// using the given [record settings](RecordSettings).
 #[derive(new, Clone, Debug)]
@@ -70,6 +82,31 @@ impl<'de, S: PrecisionSettings> Deserialize<'de> for FloatTensorSerde<S> {
     }
 }
 
+impl<S: PrecisionSettings> Serialize for FloatParamSerde<S> {
+    fn serialize<Se>(&self, serializer: Se) -> Result<Se::Ok, Se::Error>
+    where
+        Se: serde::Serializer,
+    {
+        self.data.serialize(serializer)
+    }
+}
+
+impl<'de, S: PrecisionSettings> Deserialize<'de> for FloatParamSerde<S> {
+    fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
+    where
+        De: serde::Deserializer<'de>,
+    {
+        let data = TensorData::deserialize(deserializer)?;
+        let data = if data.dtype.is_float() {
+            data // saved with the precision of the settings or the one declared by the parameter
+        } else {
+            data.convert::<S::FloatElem>()
+        };
+
+        Ok(Self::new(data))
+    }
+}
+
 impl<S: PrecisionSettings> Serialize for IntTensorSerde<S> {
     fn serialize<Se>(&self, serializer: Se) -> Result<Se::Ok, Se::Error>
     where
@@ -0,0 +1,405 @@
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+use crate as burn;
+use crate::config::Config;
+use crate::module::{HotSwapModule, Module};
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+
+/// Configuration of a [micro batcher](MicroBatcher).
+#[derive(Config, Debug)]
+pub struct MicroBatcherConfig {
+    /// The maximum number of requests executed in one batch.
+    #[config(default = 32)]
+    pub max_batch_size: usize,
+    /// The maximum time, in milliseconds, the first request of a batch waits for other requests.
+    #[config(default = 5)]
+    pub max_delay_ms: u64,
+}
+
+/// Error that can occur when sending a request to a [micro batcher](MicroBatcher).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MicroBatchError {
+    /// The deadline of the request was reached before its batch was executed.
+    DeadlineExceeded,
+    /// The batcher stopped, for instance because the forward pass of a previous batch panicked.
+    Stopped,
+}
+
+impl core::fmt::Display for MicroBatchError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(format!("{self:?}").as_str())
+    }
+}
+
+impl core::error::Error for MicroBatchError {}
+
+/// The number of batches and requests executed by a [micro batcher](MicroBatcher).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MicroBatchStats {
+    /// The number of batches executed.
+    pub batches: usize,
+    /// The number of requests executed.
+    pub requests: usize,
+    /// The number of requests rejected because their deadline was reached.
+    pub expired: usize,
+}
+
+struct Request<I, O> {
+    input: I,
+    deadline: Option<Instant>,
+    sender: mpsc::Sender<Result<O, MicroBatchError>>,
+}
+
+#[derive(Default)]
+struct Counters {
+    batches: AtomicUsize,
+    requests: AtomicUsize,
+    expired: AtomicUsize,
+}
+
+/// Execute the concurrent requests to a small model in batches, so that the device runs a few
+/// large batches instead of many batches of one item.
+///
+/// The model is moved to its own thread on the given device. The first request of a batch waits
+/// at most [max delay](MicroBatcherConfig::max_delay_ms) for other requests, the batch being
+/// executed as soon as it is full. A request with a deadline is never delayed past it to wait
+/// for other requests, and is rejected when its deadline is reached before its batch is executed.
+///
+/// The batcher is meant to be shared between the threads handling the requests, for instance in
+/// an [Arc], with one batcher per model and device.
+pub struct MicroBatcher<B: Backend, I, O> {
+    sender: Option<mpsc::Sender<Request<I, O>>>,
+    worker: Option<JoinHandle<()>>,
+    counters: Arc<Counters>,
+    _b: PhantomData<B>,
+}
+
+impl<B, I, O> MicroBatcher<B, I, O>
+where
+    B: Backend,
+    I: Send + 'static,
+    O: Send + 'static,
+{
+    /// Serve the model on the given device, executing the batches with the given function.
+    ///
+    /// The function receives the inputs of the requests of a batch, and returns their outputs in
+    /// the same order.
+    pub fn new<M, F>(model: M, device: B::Device, config: MicroBatcherConfig, forward: F) -> Self
+    where
+        M: Module<B> + 'static,
+        F: Fn(&M, Vec<I>, &B::Device) -> Vec<O> + Send + 'static,
+    {
+        let model = HotSwapModule::new(model.to_device(&device));
+
+        Self::hot_swapped(Arc::new(model), device, config, forward)
+    }
+
+    /// Serve a [hot swapped module](HotSwapModule) on the given device, executing the batches with
+    /// the given function.
+    ///
+    /// Each batch is executed with the version of the module installed when the batch starts, so
+    /// the module can be [reloaded](HotSwapModule::reload) while the batcher serves requests.
+    pub fn hot_swapped<M, F>(
+        model: Arc<HotSwapModule<B, M>>,
+        device: B::Device,
+        config: MicroBatcherConfig,
+        forward: F,
+    ) -> Self
+    where
+        M: Module<B> + 'static,
+        F: Fn(&M, Vec<I>, &B::Device) -> Vec<O> + Send + 'static,
+    {
+        assert!(
+            config.max_batch_size > 0,
+            "A batch must contain at least one request"
+        );
+
+        let (sender, receiver) = mpsc::channel();
+        let counters = Arc::new(Counters::default());
+        let worker = {
+            let counters = counters.clone();
+
+            std::thread::spawn(move || {
+                while let Some(batch) = next_batch(&receiver, &config, &counters) {
+                    let (inputs, senders): (Vec<_>, Vec<_>) = batch
+                        .into_iter()
+                        .map(|request| (request.input, request.sender))
+                        .unzip();
+                    // Moving the version is a no-op when it was installed on the device.
+                    let module = M::clone(&model.load()).to_device(&device);
+                    let outputs = forward(&module, inputs, &device);
+                    assert_eq!(
+                        outputs.len(),
+                        senders.len(),
+                        "The forward function must return one output per input"
+                    );
+
+                    counters.batches.fetch_add(1, Ordering::Relaxed);
+                    counters
+                        .requests
+                        .fetch_add(senders.len(), Ordering::Relaxed);
+                    for (sender, output) in senders.into_iter().zip(outputs) {
+                        // The caller may have stopped waiting.
+                        let _ = sender.send(Ok(output));
+                    }
+                }
+            })
+        };
+
+        Self {
+            sender: Some(sender),
+            worker: Some(worker),
+            counters,
+            _b: PhantomData,
+        }
+    }
+
+    /// Execute a request, waiting for its batch to be executed.
+    pub fn infer(&self, input: I) -> Result<O, MicroBatchError> {
+        self.send(input, None)
+    }
+
+    /// Execute a request, which is rejected if its batch isn't executed before the deadline.
+    pub fn infer_with_deadline(&self, input: I, deadline: Instant) -> Result<O, MicroBatchError> {
+        self.send(input, Some(deadline))
+    }
+
+    /// The number of batches and requests executed so far.
+    pub fn stats(&self) -> MicroBatchStats {
+        MicroBatchStats {
+            batches: self.counters.batches.load(Ordering::Relaxed),
+            requests: self.counters.requests.load(Ordering::Relaxed),
+            expired: self.counters.expired.load(Ordering::Relaxed),
+        }
+    }
+
+    fn send(&self, input: I, deadline: Option<Instant>) -> Result<O, MicroBatchError> {
+        let (sender, receiver) = mpsc::channel();
+        let request = Request {
+            input,
+            deadline,
+            sender,
+        };
+
+        self.sender
+            .as_ref()
+            .unwrap()
+            .send(request)
+            .map_err(|_| MicroBatchError::Stopped)?;
+
+        receiver.recv().map_err(|_| MicroBatchError::Stopped)?
+    }
+}
+
+impl<B, const D: usize, const D2: usize> MicroBatcher<B, Tensor<B, D>, Tensor<B, D2>>
+where
+    B: Backend,
+{
+    /// Serve a model whose inputs and outputs are batched on their first dimension.
+    ///
+    /// The inputs of the requests, each with one or more items, are concatenated on the device of
+    /// the model, and the output is split back into the outputs of the requests.
+    pub fn tensors<M, F>(
+        model: M,
+        device: B::Device,
+        config: MicroBatcherConfig,
+        forward: F,
+    ) -> Self
+    where
+        M: Module<B> + 'static,
+        F: Fn(&M, Tensor<B, D>) -> Tensor<B, D2> + Send + 'static,
+    {
+        Self::new(model, device, config, move |model, inputs, device| {
+            let sizes = inputs
+                .iter()
+                .map(|input| input.dims()[0])
+                .collect::<Vec<_>>();
+            let inputs = inputs
+                .into_iter()
+                .map(|input| input.to_device(device))
+                .collect();
+
+            forward(model, Tensor::cat(inputs, 0)).split_with_sizes(sizes, 0)
+        })
+    }
+}
+
+impl<B: Backend, I, O> Drop for MicroBatcher<B, I, O> {
+    fn drop(&mut self) {
+        // Closing the channel stops the worker once the pending requests are executed.
+        drop(self.sender.take());
+
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+}
+
+/// Wait for the next batch, or `None` when the batcher is dropped.
+fn next_batch<I, O>(
+    receiver: &mpsc::Receiver<Request<I, O>>,
+    config: &MicroBatcherConfig,
+    counters: &Counters,
+) -> Option<Vec<Request<I, O>>> {
+    let max_delay = Duration::from_millis(config.max_delay_ms);
+    let mut batch = Vec::with_capacity(config.max_batch_size);
+    let mut execute_at: Option<Instant> = None;
+
+    while batch.len() < config.max_batch_size {
+        let request = match execute_at {
+            None => match receiver.recv() {
+                Ok(request) => request,
+                Err(_) => return None,
+            },
+            Some(execute_at) => {
+                let timeout = execute_at.saturating_duration_since(Instant::now());
+                match receiver.recv_timeout(timeout) {
+                    Ok(request) => request,
+                    Err(RecvTimeoutError::Timeout) => break,
+                    Err(RecvTimeoutError::Disconnected) => break,
+                }
+            }
+        };
+
+        if let Some(deadline) = request.deadline {
+            if deadline <= Instant::now() {
+                counters.expired.fetch_add(1, Ordering::Relaxed);
+                let _ = request.sender.send(Err(MicroBatchError::DeadlineExceeded));
+                continue;
+            }
+        }
+
+        // The batch is executed after the max delay of its first request, or earlier to meet
+        // the deadline of a request.
+        let first_execute_at = execute_at.unwrap_or_else(|| Instant::now() + max_delay);
+        execute_at = Some(match request.deadline {
+            Some(deadline) => first_execute_at.min(deadline),
+            None => first_execute_at,
+        });
+        batch.push(request);
+    }
+
+    Some(batch)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::{Linear, LinearConfig};
+    use crate::tensor::TensorData;
+    use crate::TestBackend;
+    use std::thread;
+
+    fn batcher(
+        config: MicroBatcherConfig,
+    ) -> (
+        Linear<TestBackend>,
+        MicroBatcher<TestBackend, Tensor<TestBackend, 2>, Tensor<TestBackend, 2>>,
+    ) {
+        let device = Default::default();
+        let linear = LinearConfig::new(2, 3).init(&device);
+        let batcher = MicroBatcher::tensors(linear.clone(), device, config, |linear, input| {
+            linear.forward(input)
+        });
+
+        (linear, batcher)
+    }
+
+    #[test]
+    fn concurrent_requests_should_be_batched() {
+        let (linear, batcher) = batcher(
+            MicroBatcherConfig::new()
+                .with_max_batch_size(4)
+                .with_max_delay_ms(10_000),
+        );
+        let batcher = Arc::new(batcher);
+        let device = Default::default();
+
+        // The batch is executed as soon as it is full, without waiting for the max delay.
+        let handles = (0..4)
+            .map(|i| {
+                let batcher = batcher.clone();
+                let input = Tensor::<TestBackend, 2>::from_data(
+                    [[i as f32, 1.0], [2.0, i as f32]],
+                    &device,
+                );
+                let expected = linear.forward(input.clone()).into_data();
+                thread::spawn(move || {
+                    let output = batcher.infer(input).unwrap();
+                    output.into_data().assert_approx_eq(&expected, 3);
+                })
+            })
+            .collect::<Vec<_>>();
+        handles
+            .into_iter()
+            .for_each(|handle| handle.join().unwrap());
+
+        assert_eq!(
+            batcher.stats(),
+            MicroBatchStats {
+                batches: 1,
+                requests: 4,
+                expired: 0
+            }
+        );
+    }
+
+    #[test]
+    fn request_should_be_executed_after_the_max_delay() {
+        let (_, batcher) = batcher(MicroBatcherConfig::new().with_max_delay_ms(1));
+        let input = Tensor::from_data(TensorData::from([[1.0, 2.0]]), &Default::default());
+
+        let output = batcher.infer(input).unwrap();
+
+        assert_eq!(output.dims(), [1, 3]);
+        assert_eq!(batcher.stats().batches, 1);
+    }
+
+    #[test]
+    fn deadline_should_flush_the_batch() {
+        let (_, batcher) = batcher(MicroBatcherConfig::new().with_max_delay_ms(60_000));
+        let input = Tensor::<TestBackend, 2>::ones([1, 2], &Default::default());
+
+        // Without the deadline, the request would wait for a minute.
+        let output =
+            batcher.infer_with_deadline(input.clone(), Instant::now() + Duration::from_millis(5));
+        assert!(output.is_ok());
+
+        let output = batcher.infer_with_deadline(input, Instant::now());
+        assert_eq!(output.unwrap_err(), MicroBatchError::DeadlineExceeded);
+        assert_eq!(batcher.stats().expired, 1);
+    }
+
+    #[test]
+    fn batches_should_use_the_reloaded_module() {
+        let device = <TestBackend as Backend>::Device::default();
+        let linear = LinearConfig::new(2, 2).with_bias(false);
+        let model = Arc::new(HotSwapModule::new(linear.init::<TestBackend>(&device)));
+        let batcher = MicroBatcher::hot_swapped(
+            model.clone(),
+            device.clone(),
+            MicroBatcherConfig::new().with_max_delay_ms(1),
+            |linear: &Linear<TestBackend>, inputs: Vec<Tensor<TestBackend, 2>>, _| {
+                inputs
+                    .into_iter()
+                    .map(|input| linear.forward(input))
+                    .collect()
+            },
+        );
+
+        let mut record = linear.init::<TestBackend>(&device).into_record();
+        record.weight = record.weight.map(|_| Tensor::ones([2, 2], &device));
+        model.reload(record);
+
+        let output = batcher.infer(Tensor::ones([1, 2], &device)).unwrap();
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[2.0, 2.0]]), false);
+    }
+}
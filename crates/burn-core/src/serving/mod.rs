@@ -0,0 +1,5 @@
+mod batching;
+mod pool;
+
+pub use batching::*;
+pub use pool::*;
@@ -0,0 +1,543 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+
+use crate as burn;
+use crate::config::Config;
+use crate::module::{HotSwapModule, Module};
+use crate::tensor::backend::Backend;
+
+/// Configuration of a model served by a [model pool](ModelPool).
+#[derive(Config, Debug)]
+pub struct PooledModelConfig {
+    /// The maximum number of requests executed at the same time by each replica of the model.
+    #[config(default = 1)]
+    pub max_concurrency: usize,
+    /// The maximum number of requests waiting for a replica, no limit when not provided.
+    pub max_queued: Option<usize>,
+}
+
+/// The identifier of a session, a sequence of requests sharing a state on a device, for instance
+/// the key-value cache of a language model.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct SessionId(u64);
+
+impl SessionId {
+    /// Create a new unique session id.
+    pub fn new() -> Self {
+        static COUNTER: AtomicU64 = AtomicU64::new(0);
+        Self(COUNTER.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+impl Default for SessionId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Options of a request to a [model pool](ModelPool).
+#[derive(Debug, Clone)]
+pub struct RequestOptions<B: Backend> {
+    session: Option<SessionId>,
+    device: Option<B::Device>,
+}
+
+impl<B: Backend> Default for RequestOptions<B> {
+    fn default() -> Self {
+        Self {
+            session: None,
+            device: None,
+        }
+    }
+}
+
+impl<B: Backend> RequestOptions<B> {
+    /// Execute the request on the replica of the session, the first request of the session
+    /// choosing the replica.
+    pub fn with_session(mut self, session: SessionId) -> Self {
+        self.session = Some(session);
+        self
+    }
+
+    /// Execute the request on a replica of the model on the given device.
+    pub fn with_device(mut self, device: B::Device) -> Self {
+        self.device = Some(device);
+        self
+    }
+}
+
+/// Error that can occur when sending a request to a [model pool](ModelPool).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ModelPoolError {
+    /// No model is loaded with the given name.
+    UnknownModel(String),
+    /// The model has no replica on the requested device.
+    UnknownDevice(String),
+    /// The session is bound to a replica on another device than the requested one.
+    SessionDeviceMismatch(String),
+    /// The maximum number of waiting requests is reached.
+    QueueFull(String),
+}
+
+impl core::fmt::Display for ModelPoolError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(format!("{self:?}").as_str())
+    }
+}
+
+impl core::error::Error for ModelPoolError {}
+
+/// The load of a replica of a model.
+#[derive(Debug, Clone)]
+pub struct ReplicaStatus<B: Backend> {
+    /// The device of the replica.
+    pub device: B::Device,
+    /// The number of requests being executed.
+    pub active: usize,
+    /// The number of sessions bound to the replica.
+    pub sessions: usize,
+    /// The epoch of the version of the model used by the replica, incremented by each
+    /// [reload](ModelPool::reload).
+    pub epoch: u64,
+}
+
+/// The load of a model of a [model pool](ModelPool).
+#[derive(Debug, Clone)]
+pub struct ModelStatus<B: Backend> {
+    /// The replicas of the model.
+    pub replicas: Vec<ReplicaStatus<B>>,
+    /// The number of requests waiting for a replica.
+    pub queued: usize,
+}
+
+/// Serve several models from one process, each model having replicas on one or more devices.
+///
+/// Each replica executes a limited number of requests at the same time, the other requests wait
+/// for a replica to be available. Requests of the same [session](SessionId) are executed by the
+/// same replica, so the state they share stays on its device. Since replicas are selected by
+/// device, models can be spread on the devices of different backends with the router backend.
+///
+/// The pool is meant to be shared between the threads handling the requests, for instance in an
+/// [Arc].
+pub struct ModelPool<B: Backend, M: Module<B>> {
+    models: Mutex<HashMap<String, Arc<PooledModel<B, M>>>>,
+}
+
+struct PooledModel<B: Backend, M: Module<B>> {
+    config: PooledModelConfig,
+    replicas: Vec<Replica<B, M>>,
+    state: Mutex<SchedulerState>,
+    available: Condvar,
+    // Serializes the reloads, so the replicas install the versions in the same order.
+    reloading: Mutex<()>,
+}
+
+struct Replica<B: Backend, M: Module<B>> {
+    device: B::Device,
+    model: HotSwapModule<B, M>,
+}
+
+#[derive(Default)]
+struct SchedulerState {
+    active: Vec<usize>,
+    queued: usize,
+    sessions: HashMap<SessionId, usize>,
+}
+
+/// A slot of a replica, released when dropped.
+struct Permit<'a, B: Backend, M: Module<B>> {
+    model: &'a PooledModel<B, M>,
+    replica: usize,
+}
+
+impl<B: Backend, M: Module<B>> Default for ModelPool<B, M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<B: Backend, M: Module<B>> ModelPool<B, M> {
+    /// Create an empty pool.
+    pub fn new() -> Self {
+        Self {
+            models: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Load a model with a replica on each of the given devices, replacing the model with the
+    /// same name.
+    ///
+    /// The requests being executed by the replaced model are completed, but its sessions are
+    /// forgotten.
+    pub fn load(
+        &self,
+        name: impl Into<String>,
+        module: M,
+        devices: &[B::Device],
+        config: PooledModelConfig,
+    ) {
+        assert!(!devices.is_empty(), "A model needs at least one replica");
+        assert!(
+            config.max_concurrency > 0,
+            "A replica must execute at least one request at a time"
+        );
+
+        let replicas = devices
+            .iter()
+            .map(|device| Replica {
+                device: device.clone(),
+                model: HotSwapModule::new(module.clone().to_device(device)),
+            })
+            .collect::<Vec<_>>();
+        let state = SchedulerState {
+            active: vec![0; replicas.len()],
+            ..Default::default()
+        };
+        let model = PooledModel {
+            config,
+            replicas,
+            state: Mutex::new(state),
+            available: Condvar::new(),
+            reloading: Mutex::new(()),
+        };
+
+        self.models
+            .lock()
+            .unwrap()
+            .insert(name.into(), Arc::new(model));
+    }
+
+    /// Load a record into the replicas of a model, returning the epoch of the new version.
+    ///
+    /// The weights of the replicas are [hot swapped](HotSwapModule): the requests being executed
+    /// complete with the previous weights while the next requests use the new ones, and the
+    /// sessions are kept.
+    pub fn reload(&self, name: &str, record: M::Record) -> Result<u64, ModelPoolError> {
+        let model = self.model(name)?;
+        let _reloading = model.reloading.lock().unwrap();
+
+        let (first, others) = model
+            .replicas
+            .split_first()
+            .expect("A model has at least one replica");
+        let epoch = first.model.reload(record);
+        let module = first.model.load();
+
+        for replica in others {
+            replica
+                .model
+                .swap(M::clone(&module).to_device(&replica.device));
+        }
+
+        Ok(epoch)
+    }
+
+    /// Unload a model, returning if it was loaded.
+    ///
+    /// The requests being executed are completed, while the waiting requests still wait for the
+    /// replicas of the unloaded model.
+    pub fn unload(&self, name: &str) -> bool {
+        self.models.lock().unwrap().remove(name).is_some()
+    }
+
+    /// The names of the loaded models.
+    pub fn models(&self) -> Vec<String> {
+        self.models.lock().unwrap().keys().cloned().collect()
+    }
+
+    /// Execute a request with a replica of the model, waiting for a replica to be available.
+    ///
+    /// The replica is selected from the session and the device of the options, the least busy
+    /// replica being selected otherwise. The function receives a clone of the replica, which is
+    /// cheap since the parameters aren't copied, and its device. The request keeps the version of
+    /// the model it started with when the model is [reloaded](Self::reload).
+    pub fn run<O>(
+        &self,
+        name: &str,
+        options: RequestOptions<B>,
+        func: impl FnOnce(M, &B::Device) -> O,
+    ) -> Result<O, ModelPoolError> {
+        let model = self.model(name)?;
+        let permit = model.acquire(name, &options)?;
+        let replica = &model.replicas[permit.replica];
+        let version = replica.model.load();
+
+        Ok(func(M::clone(&version), &replica.device))
+    }
+
+    /// End a session, so that its next requests can be executed by any replica.
+    pub fn end_session(&self, name: &str, session: SessionId) -> Result<(), ModelPoolError> {
+        let model = self.model(name)?;
+        model.state.lock().unwrap().sessions.remove(&session);
+
+        Ok(())
+    }
+
+    /// The load of the model.
+    pub fn status(&self, name: &str) -> Result<ModelStatus<B>, ModelPoolError> {
+        let model = self.model(name)?;
+        let state = model.state.lock().unwrap();
+
+        let replicas = model
+            .replicas
+            .iter()
+            .enumerate()
+            .map(|(index, replica)| ReplicaStatus {
+                device: replica.device.clone(),
+                active: state.active[index],
+                sessions: state.sessions.values().filter(|r| **r == index).count(),
+                epoch: replica.model.epoch(),
+            })
+            .collect();
+
+        Ok(ModelStatus {
+            replicas,
+            queued: state.queued,
+        })
+    }
+
+    fn model(&self, name: &str) -> Result<Arc<PooledModel<B, M>>, ModelPoolError> {
+        self.models
+            .lock()
+            .unwrap()
+            .get(name)
+            .cloned()
+            .ok_or_else(|| ModelPoolError::UnknownModel(name.to_string()))
+    }
+}
+
+impl<B: Backend, M: Module<B>> PooledModel<B, M> {
+    fn acquire(
+        &self,
+        name: &str,
+        options: &RequestOptions<B>,
+    ) -> Result<Permit<'_, B, M>, ModelPoolError> {
+        let candidates = self.candidates(name, options)?;
+        let mut state = self.state.lock().unwrap();
+        let mut queued = false;
+
+        loop {
+            // The replica of the session may have been chosen by a concurrent request.
+            let bound = options
+                .session
+                .and_then(|session| state.sessions.get(&session).copied());
+            let candidates = match bound {
+                Some(replica) if candidates.contains(&replica) => vec![replica],
+                Some(replica) => {
+                    return Err(ModelPoolError::SessionDeviceMismatch(format!(
+                        "The session of model '{name}' is bound to device {:?}",
+                        self.replicas[replica].device
+                    )))
+                }
+                None => candidates.clone(),
+            };
+
+            let available = candidates
+                .iter()
+                .copied()
+                .filter(|replica| state.active[*replica] < self.config.max_concurrency)
+                .min_by_key(|replica| state.active[*replica]);
+
+            if let Some(replica) = available {
+                state.active[replica] += 1;
+                if let Some(session) = options.session {
+                    state.sessions.insert(session, replica);
+                }
+                if queued {
+                    state.queued -= 1;
+                }
+
+                return Ok(Permit {
+                    model: self,
+                    replica,
+                });
+            }
+
+            if !queued {
+                if let Some(max_queued) = self.config.max_queued {
+                    if state.queued >= max_queued {
+                        return Err(ModelPoolError::QueueFull(format!(
+                            "{max_queued} requests are already waiting for model '{name}'"
+                        )));
+                    }
+                }
+                state.queued += 1;
+                queued = true;
+            }
+
+            state = self.available.wait(state).unwrap();
+        }
+    }
+
+    /// The replicas that can execute the request, without considering its session.
+    fn candidates(
+        &self,
+        name: &str,
+        options: &RequestOptions<B>,
+    ) -> Result<Vec<usize>, ModelPoolError> {
+        let candidates = (0..self.replicas.len())
+            .filter(|index| match &options.device {
+                Some(device) => &self.replicas[*index].device == device,
+                None => true,
+            })
+            .collect::<Vec<_>>();
+
+        match candidates.is_empty() {
+            true => Err(ModelPoolError::UnknownDevice(format!(
+                "Model '{name}' has no replica on device {:?}",
+                options.device
+            ))),
+            false => Ok(candidates),
+        }
+    }
+}
+
+impl<B: Backend, M: Module<B>> Drop for Permit<'_, B, M> {
+    fn drop(&mut self) {
+        self.model.state.lock().unwrap().active[self.replica] -= 1;
+        self.model.available.notify_all();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::{Linear, LinearConfig};
+    use crate::tensor::{Tensor, TensorData};
+    use crate::TestBackend;
+    use std::sync::mpsc;
+    use std::thread;
+    use std::time::Duration;
+
+    fn pool() -> ModelPool<TestBackend, Linear<TestBackend>> {
+        let device = <TestBackend as Backend>::Device::default();
+        let pool = ModelPool::new();
+        let linear = LinearConfig::new(2, 2).init(&device);
+
+        pool.load(
+            "linear",
+            linear,
+            &[device.clone(), device],
+            PooledModelConfig::new().with_max_queued(Some(1)),
+        );
+        pool
+    }
+
+    #[test]
+    fn session_should_stick_to_its_replica() {
+        let pool = pool();
+        let first = RequestOptions::default().with_session(SessionId::new());
+        let session = SessionId::new();
+        let second = RequestOptions::default().with_session(session);
+
+        // The first session is bound to the first replica, so the second session is bound to the
+        // second replica while the first one is busy.
+        pool.run("linear", first.clone(), |_, _| {
+            pool.run("linear", second.clone(), |_, _| ()).unwrap();
+        })
+        .unwrap();
+
+        // Requests of the second session are executed by its replica, even when both are idle.
+        pool.run("linear", second, |_, _| {
+            let status = pool.status("linear").unwrap();
+            assert_eq!(status.replicas[0].active, 0);
+            assert_eq!(status.replicas[1].active, 1);
+        })
+        .unwrap();
+
+        pool.end_session("linear", session).unwrap();
+        let status = pool.status("linear").unwrap();
+        assert_eq!(status.replicas[0].sessions, 1);
+        assert_eq!(status.replicas[1].sessions, 0);
+    }
+
+    #[test]
+    fn requests_should_wait_for_an_available_replica() {
+        let pool = Arc::new(pool());
+        let (started, wait_started) = mpsc::channel();
+        let (release, wait_release) = mpsc::channel::<()>();
+        let wait_release = Arc::new(Mutex::new(wait_release));
+
+        // Both replicas execute a request.
+        let handles = (0..2)
+            .map(|_| {
+                let pool = pool.clone();
+                let started = started.clone();
+                let wait_release = wait_release.clone();
+                thread::spawn(move || {
+                    pool.run("linear", RequestOptions::default(), |_, _| {
+                        started.send(()).unwrap();
+                        wait_release.lock().unwrap().recv().unwrap();
+                    })
+                    .unwrap();
+                })
+            })
+            .collect::<Vec<_>>();
+        wait_started.recv().unwrap();
+        wait_started.recv().unwrap();
+
+        // A third request waits, and a fourth one is rejected since the queue is full.
+        let waiting = {
+            let pool = pool.clone();
+            thread::spawn(move || pool.run("linear", RequestOptions::default(), |_, _| 42))
+        };
+        while pool.status("linear").unwrap().queued == 0 {
+            thread::sleep(Duration::from_millis(1));
+        }
+        let rejected = pool.run("linear", RequestOptions::default(), |_, _| 0);
+        assert!(matches!(rejected, Err(ModelPoolError::QueueFull(_))));
+
+        release.send(()).unwrap();
+        release.send(()).unwrap();
+        assert_eq!(waiting.join().unwrap(), Ok(42));
+        handles
+            .into_iter()
+            .for_each(|handle| handle.join().unwrap());
+    }
+
+    #[test]
+    fn reload_should_keep_the_version_of_running_requests() {
+        let pool = pool();
+        let device = <TestBackend as Backend>::Device::default();
+        let mut record = LinearConfig::new(2, 2)
+            .init::<TestBackend>(&device)
+            .into_record();
+        record.weight = record.weight.map(|_| Tensor::ones([2, 2], &device));
+
+        let (previous, epoch) = pool
+            .run("linear", RequestOptions::default(), |model, _| {
+                let epoch = pool.reload("linear", record).unwrap();
+                (model.weight.val(), epoch)
+            })
+            .unwrap();
+        let current = pool
+            .run("linear", RequestOptions::default(), |model, _| {
+                model.weight.val()
+            })
+            .unwrap();
+
+        assert_eq!(epoch, 1);
+        let status = pool.status("linear").unwrap();
+        assert!(status.replicas.iter().all(|replica| replica.epoch == 1));
+        current
+            .into_data()
+            .assert_eq(&TensorData::from([[1.0, 1.0], [1.0, 1.0]]), false);
+        assert_ne!(
+            previous.into_data(),
+            TensorData::from([[1.0f32, 1.0], [1.0, 1.0]])
+        );
+    }
+
+    #[test]
+    fn unknown_model_should_be_an_error() {
+        let pool = pool();
+
+        let result = pool.run("other", RequestOptions::default(), |_, _| ());
+
+        assert_eq!(
+            result,
+            Err(ModelPoolError::UnknownModel("other".to_string()))
+        );
+    }
+}
@@ -0,0 +1,299 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::Dataset;
+
+/// The training dynamics of a sample, with one observation per epoch.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SampleDynamics {
+    /// The epochs where the sample was observed.
+    pub epochs: Vec<usize>,
+    /// The loss of the sample at each observed epoch.
+    pub losses: Vec<f32>,
+    /// The probability predicted for the label of the sample at each observed epoch.
+    pub confidences: Vec<f32>,
+    /// If the prediction was correct at each observed epoch.
+    pub correct: Vec<bool>,
+}
+
+impl SampleDynamics {
+    /// Record an observation of the sample, replacing the previous observation of the same epoch.
+    pub fn record(&mut self, epoch: usize, loss: f32, confidence: f32, correct: bool) {
+        if self.epochs.last() == Some(&epoch) {
+            self.losses.pop();
+            self.confidences.pop();
+            self.correct.pop();
+        } else {
+            self.epochs.push(epoch);
+        }
+
+        self.losses.push(loss);
+        self.confidences.push(confidence);
+        self.correct.push(correct);
+    }
+
+    /// The mean loss over the observed epochs.
+    pub fn mean_loss(&self) -> f32 {
+        mean(&self.losses)
+    }
+
+    /// The mean probability predicted for the label over the observed epochs.
+    pub fn mean_confidence(&self) -> f32 {
+        mean(&self.confidences)
+    }
+
+    /// The standard deviation of the probability predicted for the label over the observed
+    /// epochs.
+    pub fn variability(&self) -> f32 {
+        if self.confidences.is_empty() {
+            return 0.0;
+        }
+
+        let mean = self.mean_confidence();
+        let variance = self
+            .confidences
+            .iter()
+            .map(|confidence| (confidence - mean).powi(2))
+            .sum::<f32>()
+            / self.confidences.len() as f32;
+
+        variance.sqrt()
+    }
+
+    /// The fraction of the observed epochs where the prediction was correct.
+    pub fn accuracy(&self) -> f32 {
+        if self.correct.is_empty() {
+            return 0.0;
+        }
+
+        self.correct.iter().filter(|correct| **correct).count() as f32 / self.correct.len() as f32
+    }
+
+    /// The number of times the sample was forgotten, i.e. predicted correctly at an epoch and
+    /// incorrectly at the next observed epoch.
+    pub fn forgetting_events(&self) -> usize {
+        self.correct
+            .windows(2)
+            .filter(|window| window[0] && !window[1])
+            .count()
+    }
+}
+
+fn mean(values: &[f32]) -> f32 {
+    if values.is_empty() {
+        return 0.0;
+    }
+
+    values.iter().sum::<f32>() / values.len() as f32
+}
+
+/// The statistics of the training dynamics of a sample.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SampleReport {
+    /// The index of the sample in the dataset.
+    pub index: usize,
+    /// The number of observed epochs.
+    pub epochs: usize,
+    /// The mean loss, see [SampleDynamics::mean_loss].
+    pub mean_loss: f32,
+    /// The mean confidence, see [SampleDynamics::mean_confidence].
+    pub mean_confidence: f32,
+    /// The variability of the confidence, see [SampleDynamics::variability].
+    pub variability: f32,
+    /// The accuracy, see [SampleDynamics::accuracy].
+    pub accuracy: f32,
+    /// The number of forgetting events, see [SampleDynamics::forgetting_events].
+    pub forgetting_events: usize,
+}
+
+/// The training dynamics of the samples of a dataset, used to find the samples that are likely
+/// mislabeled or hard to learn.
+///
+/// The samples are identified by their index in the dataset, which can be kept along the items
+/// with an [indexed dataset](crate::transform::IndexedDataset). The rankings follow dataset
+/// cartography: samples the model is consistently not confident about are likely mislabeled,
+/// while samples with a variable confidence are ambiguous.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TrainingDynamics {
+    samples: HashMap<usize, SampleDynamics>,
+}
+
+impl TrainingDynamics {
+    /// Create empty training dynamics.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record an observation of the sample with the given index.
+    pub fn record(
+        &mut self,
+        index: usize,
+        epoch: usize,
+        loss: f32,
+        confidence: f32,
+        correct: bool,
+    ) {
+        self.samples
+            .entry(index)
+            .or_default()
+            .record(epoch, loss, confidence, correct);
+    }
+
+    /// The training dynamics of the sample with the given index.
+    pub fn sample(&self, index: usize) -> Option<&SampleDynamics> {
+        self.samples.get(&index)
+    }
+
+    /// The statistics of the sample with the given index.
+    pub fn report(&self, index: usize) -> Option<SampleReport> {
+        self.samples
+            .get(&index)
+            .map(|sample| Self::sample_report(index, sample))
+    }
+
+    /// The number of observed samples.
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// If no sample has been observed.
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// The samples that are likely mislabeled, with the lowest mean confidence first.
+    pub fn likely_mislabeled(&self, count: usize) -> Vec<SampleReport> {
+        self.ranked(count, |a, b| {
+            a.mean_confidence
+                .total_cmp(&b.mean_confidence)
+                .then(a.variability.total_cmp(&b.variability))
+        })
+    }
+
+    /// The most ambiguous samples, with the most variable confidence first.
+    pub fn most_ambiguous(&self, count: usize) -> Vec<SampleReport> {
+        self.ranked(count, |a, b| b.variability.total_cmp(&a.variability))
+    }
+
+    /// The hardest samples, with the highest mean loss first.
+    pub fn hardest(&self, count: usize) -> Vec<SampleReport> {
+        self.ranked(count, |a, b| b.mean_loss.total_cmp(&a.mean_loss))
+    }
+
+    /// The most forgotten samples, with the most forgetting events first.
+    pub fn most_forgotten(&self, count: usize) -> Vec<SampleReport> {
+        self.ranked(count, |a, b| {
+            b.forgetting_events
+                .cmp(&a.forgetting_events)
+                .then(b.mean_loss.total_cmp(&a.mean_loss))
+        })
+    }
+
+    /// Get the items of the reported samples from the dataset, to inspect or clean them.
+    pub fn inspect<I, D: Dataset<I> + ?Sized>(
+        dataset: &D,
+        reports: &[SampleReport],
+    ) -> Vec<(SampleReport, I)> {
+        reports
+            .iter()
+            .filter_map(|report| dataset.get(report.index).map(|item| (report.clone(), item)))
+            .collect()
+    }
+
+    fn ranked<F>(&self, count: usize, compare: F) -> Vec<SampleReport>
+    where
+        F: Fn(&SampleReport, &SampleReport) -> Ordering,
+    {
+        let mut reports = self
+            .samples
+            .iter()
+            .map(|(index, sample)| Self::sample_report(*index, sample))
+            .collect::<Vec<_>>();
+
+        reports.sort_by(|a, b| compare(a, b).then(a.index.cmp(&b.index)));
+        reports.truncate(count);
+        reports
+    }
+
+    fn sample_report(index: usize, sample: &SampleDynamics) -> SampleReport {
+        SampleReport {
+            index,
+            epochs: sample.epochs.len(),
+            mean_loss: sample.mean_loss(),
+            mean_confidence: sample.mean_confidence(),
+            variability: sample.variability(),
+            accuracy: sample.accuracy(),
+            forgetting_events: sample.forgetting_events(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::InMemDataset;
+
+    fn dynamics() -> TrainingDynamics {
+        let mut dynamics = TrainingDynamics::new();
+
+        for epoch in 0..4 {
+            // Learned quickly.
+            dynamics.record(0, epoch, 0.1, 0.9, true);
+            // Never learned, likely mislabeled.
+            dynamics.record(1, epoch, 2.5, 0.05, false);
+            // Learned and forgotten every other epoch.
+            let learned = epoch % 2 == 0;
+            let confidence = if learned { 0.8 } else { 0.2 };
+            dynamics.record(2, epoch, 1.0, confidence, learned);
+        }
+
+        dynamics
+    }
+
+    #[test]
+    fn should_keep_one_observation_per_epoch() {
+        let mut sample = SampleDynamics::default();
+
+        sample.record(0, 1.0, 0.5, false);
+        sample.record(0, 0.5, 0.7, true);
+        sample.record(1, 0.2, 0.9, true);
+
+        assert_eq!(sample.epochs, vec![0, 1]);
+        assert_eq!(sample.losses, vec![0.5, 0.2]);
+        assert_eq!(sample.accuracy(), 1.0);
+    }
+
+    #[test]
+    fn should_count_forgetting_events() {
+        let dynamics = dynamics();
+
+        assert_eq!(dynamics.report(0).unwrap().forgetting_events, 0);
+        assert_eq!(dynamics.report(1).unwrap().forgetting_events, 0);
+        assert_eq!(dynamics.report(2).unwrap().forgetting_events, 2);
+    }
+
+    #[test]
+    fn should_rank_samples() {
+        let dynamics = dynamics();
+        let indices =
+            |reports: Vec<SampleReport>| reports.iter().map(|r| r.index).collect::<Vec<_>>();
+
+        assert_eq!(indices(dynamics.likely_mislabeled(2)), vec![1, 2]);
+        assert_eq!(indices(dynamics.most_ambiguous(1)), vec![2]);
+        assert_eq!(indices(dynamics.hardest(3)), vec![1, 2, 0]);
+        assert_eq!(indices(dynamics.most_forgotten(1)), vec![2]);
+    }
+
+    #[test]
+    fn should_inspect_reported_items() {
+        let dynamics = dynamics();
+        let dataset = InMemDataset::new(vec!["cat", "dog", "bird"]);
+
+        let items = TrainingDynamics::inspect(&dataset, &dynamics.likely_mislabeled(1));
+
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].1, "dog");
+    }
+}
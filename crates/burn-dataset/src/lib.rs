@@ -16,6 +16,12 @@ pub mod source;
 /// Transformations to be used with datasets.
 pub mod transform;
 
+/// Data-quality audit utilities, to find the samples that are likely mislabeled or hard to learn.
+pub mod audit;
+
+/// Synthetic datasets generated from a seed, for tests, examples and benchmarks.
+pub mod synthetic;
+
 /// Audio datasets.
 #[cfg(feature = "audio")]
 pub mod audio;
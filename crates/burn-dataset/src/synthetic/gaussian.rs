@@ -0,0 +1,136 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+use super::{item_rng, sample_normal};
+use crate::{Dataset, DatasetIterator};
+
+/// A sample of a synthetic classification dataset.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClassificationItem {
+    /// The features of the sample.
+    pub features: Vec<f32>,
+    /// The class of the sample.
+    pub label: usize,
+}
+
+/// Classification dataset sampled from a mixture of gaussians, one per class.
+///
+/// The centers of the classes are drawn uniformly in `[-spread, spread]` for each feature and the
+/// samples are drawn around the center of their class with the given standard deviation, so the
+/// difficulty of the task is set by the ratio of the two. The classes are balanced, the sample at
+/// `index` having the class `index % num_classes`.
+///
+/// The samples are generated on access from the seed of the dataset, so large datasets can be
+/// used to benchmark training loops without being kept in memory.
+#[derive(Debug, Clone)]
+pub struct GaussianMixtureDataset {
+    size: usize,
+    centers: Vec<Vec<f32>>,
+    std: f32,
+    seed: u64,
+}
+
+impl GaussianMixtureDataset {
+    /// Create a dataset of `size` samples with `num_features` features in `num_classes` classes,
+    /// with centers spread in `[-1, 1]` and a standard deviation of `0.1`.
+    pub fn new(size: usize, num_classes: usize, num_features: usize) -> Self {
+        Self::with_params(size, num_classes, num_features, 1.0, 0.1, 0)
+    }
+
+    /// Create a dataset with the given spread of the centers, standard deviation of the samples
+    /// and seed.
+    ///
+    /// # Panics
+    ///
+    /// If there is no class.
+    pub fn with_params(
+        size: usize,
+        num_classes: usize,
+        num_features: usize,
+        spread: f32,
+        std: f32,
+        seed: u64,
+    ) -> Self {
+        assert!(num_classes > 0, "The mixture needs at least one class");
+
+        let mut rng = StdRng::seed_from_u64(seed);
+        let centers = (0..num_classes)
+            .map(|_| {
+                (0..num_features)
+                    .map(|_| rng.gen_range(-spread..=spread))
+                    .collect()
+            })
+            .collect();
+
+        Self {
+            size,
+            centers,
+            std,
+            seed,
+        }
+    }
+
+    /// The centers of the classes.
+    pub fn centers(&self) -> &[Vec<f32>] {
+        &self.centers
+    }
+}
+
+impl Dataset<ClassificationItem> for GaussianMixtureDataset {
+    fn get(&self, index: usize) -> Option<ClassificationItem> {
+        if index >= self.size {
+            return None;
+        }
+
+        let mut rng = item_rng(self.seed, index);
+        let label = index % self.centers.len();
+        let features = self.centers[label]
+            .iter()
+            .map(|center| center + self.std * sample_normal(&mut rng))
+            .collect();
+
+        Some(ClassificationItem { features, label })
+    }
+
+    fn len(&self) -> usize {
+        self.size
+    }
+
+    fn iter(&self) -> DatasetIterator<'_, ClassificationItem> {
+        DatasetIterator::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_sample_around_the_centers() {
+        let dataset = GaussianMixtureDataset::with_params(3000, 3, 2, 5.0, 0.5, 42);
+        let mut sums = vec![[0.0; 2]; 3];
+
+        for item in dataset.iter() {
+            assert_eq!(item.features.len(), 2);
+            for (sum, feature) in sums[item.label].iter_mut().zip(item.features) {
+                *sum += feature;
+            }
+        }
+
+        for (sum, center) in sums.iter().zip(dataset.centers()) {
+            for (sum, center) in sum.iter().zip(center) {
+                assert!((sum / 1000.0 - center).abs() < 0.1);
+            }
+        }
+    }
+
+    #[test]
+    fn should_generate_the_same_items_with_the_same_seed() {
+        let dataset_1 = GaussianMixtureDataset::new(10, 2, 4);
+        let dataset_2 = GaussianMixtureDataset::new(10, 2, 4);
+
+        assert_eq!(dataset_1.get(7), dataset_2.get(7));
+        assert_ne!(dataset_1.get(7), dataset_1.get(5));
+        assert_eq!(dataset_1.get(10), None);
+    }
+}
@@ -0,0 +1,25 @@
+mod gaussian;
+mod sequence;
+mod teacher;
+
+pub use gaussian::*;
+pub use sequence::*;
+pub use teacher::*;
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// The random number generator of the item at the given index.
+///
+/// Each item is generated from its own seed, so the items are the same whatever the order they
+/// are accessed in and the datasets don't have to keep them in memory.
+fn item_rng(seed: u64, index: usize) -> StdRng {
+    StdRng::seed_from_u64(seed ^ (index as u64 + 1).wrapping_mul(0x9E37_79B9_7F4A_7C15))
+}
+
+/// Sample from the standard normal distribution using the Box-Muller transform.
+fn sample_normal<R: Rng>(rng: &mut R) -> f32 {
+    let u1 = 1.0 - rng.gen::<f32>();
+    let u2 = rng.gen::<f32>();
+
+    (-2.0 * u1.ln()).sqrt() * (2.0 * core::f32::consts::PI * u2).cos()
+}
@@ -0,0 +1,149 @@
+use rand::{rngs::StdRng, Rng};
+use serde::{Deserialize, Serialize};
+
+use super::item_rng;
+use crate::{Dataset, DatasetIterator};
+
+/// A sample of a synthetic token sequence dataset.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenSequenceItem {
+    /// The input tokens.
+    pub tokens: Vec<usize>,
+    /// The tokens to predict, with the same length as the input.
+    pub targets: Vec<usize>,
+}
+
+/// The structure of the sequences of a [token sequence dataset](TokenSequenceDataset).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SequenceTask {
+    /// Random tokens, the targets being the same tokens.
+    Copy,
+    /// Random tokens, the targets being the tokens in the reverse order.
+    Reverse,
+    /// Random tokens, the targets being the tokens sorted in increasing order.
+    Sort,
+    /// Tokens counting up from a random start modulo the vocabulary size, the targets being the
+    /// next token at each position, so that the next token can always be predicted.
+    Counting,
+}
+
+/// Token sequences with a known structure, to test sequence models and language modeling
+/// training loops without downloading a corpus.
+///
+/// The samples are generated on access, see [GaussianMixtureDataset](super::GaussianMixtureDataset).
+#[derive(Debug, Clone)]
+pub struct TokenSequenceDataset {
+    size: usize,
+    seq_length: usize,
+    vocab_size: usize,
+    task: SequenceTask,
+    seed: u64,
+}
+
+impl TokenSequenceDataset {
+    /// Create a dataset of `size` sequences of `seq_length` tokens in `0..vocab_size` with the
+    /// structure of the given task.
+    ///
+    /// # Panics
+    ///
+    /// If the vocabulary is empty.
+    pub fn new(size: usize, seq_length: usize, vocab_size: usize, task: SequenceTask) -> Self {
+        assert!(vocab_size > 0, "The vocabulary needs at least one token");
+
+        Self {
+            size,
+            seq_length,
+            vocab_size,
+            task,
+            seed: 0,
+        }
+    }
+
+    /// Generate the sequences from the given seed.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+}
+
+impl Dataset<TokenSequenceItem> for TokenSequenceDataset {
+    fn get(&self, index: usize) -> Option<TokenSequenceItem> {
+        if index >= self.size {
+            return None;
+        }
+
+        let mut rng = item_rng(self.seed, index);
+        let random_tokens = |rng: &mut StdRng| {
+            (0..self.seq_length)
+                .map(|_| rng.gen_range(0..self.vocab_size))
+                .collect::<Vec<_>>()
+        };
+
+        let item = match self.task {
+            SequenceTask::Copy => {
+                let tokens = random_tokens(&mut rng);
+                TokenSequenceItem {
+                    targets: tokens.clone(),
+                    tokens,
+                }
+            }
+            SequenceTask::Reverse => {
+                let tokens = random_tokens(&mut rng);
+                TokenSequenceItem {
+                    targets: tokens.iter().rev().copied().collect(),
+                    tokens,
+                }
+            }
+            SequenceTask::Sort => {
+                let tokens = random_tokens(&mut rng);
+                let mut targets = tokens.clone();
+                targets.sort_unstable();
+                TokenSequenceItem { tokens, targets }
+            }
+            SequenceTask::Counting => {
+                let start = rng.gen_range(0..self.vocab_size);
+                let token = |position: usize| (start + position) % self.vocab_size;
+                TokenSequenceItem {
+                    tokens: (0..self.seq_length).map(token).collect(),
+                    targets: (1..=self.seq_length).map(token).collect(),
+                }
+            }
+        };
+
+        Some(item)
+    }
+
+    fn len(&self) -> usize {
+        self.size
+    }
+
+    fn iter(&self) -> DatasetIterator<'_, TokenSequenceItem> {
+        DatasetIterator::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_generate_sequences_with_the_task_structure() {
+        let dataset = |task| TokenSequenceDataset::new(4, 6, 10, task).with_seed(3);
+
+        for item in dataset(SequenceTask::Reverse).iter() {
+            let mut reversed = item.tokens.clone();
+            reversed.reverse();
+            assert_eq!(item.targets, reversed);
+        }
+
+        for item in dataset(SequenceTask::Sort).iter() {
+            assert!(item.targets.windows(2).all(|pair| pair[0] <= pair[1]));
+        }
+
+        for item in dataset(SequenceTask::Counting).iter() {
+            assert!(item.tokens.iter().all(|token| *token < 10));
+            assert_eq!(item.targets[..5], item.tokens[1..]);
+            assert_eq!(item.targets[5], (item.tokens[5] + 1) % 10);
+        }
+    }
+}
@@ -0,0 +1,151 @@
+use rand::{rngs::StdRng, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+use super::{item_rng, sample_normal};
+use crate::{Dataset, DatasetIterator};
+
+/// A sample of a synthetic regression dataset.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RegressionItem {
+    /// The features of the sample.
+    pub features: Vec<f32>,
+    /// The targets of the sample.
+    pub targets: Vec<f32>,
+}
+
+/// Regression dataset labeled by a random teacher network.
+///
+/// The features are drawn from the standard normal distribution and the targets are the outputs
+/// of a network with one hidden layer and a `tanh` activation, whose weights are drawn from the
+/// seed of the dataset, plus gaussian noise. A student network with enough capacity can fit the
+/// targets up to the noise, which makes the expected loss of a training loop known.
+///
+/// The samples are generated on access, see [GaussianMixtureDataset](super::GaussianMixtureDataset).
+#[derive(Debug, Clone)]
+pub struct TeacherRegressionDataset {
+    size: usize,
+    num_features: usize,
+    hidden: Vec<(Vec<f32>, f32)>,
+    output: Vec<(Vec<f32>, f32)>,
+    noise_std: f32,
+    seed: u64,
+}
+
+impl TeacherRegressionDataset {
+    /// Create a dataset of `size` samples with `num_features` features and `num_targets` targets,
+    /// labeled by a teacher with 32 hidden units and without noise.
+    pub fn new(size: usize, num_features: usize, num_targets: usize) -> Self {
+        Self::with_params(size, num_features, num_targets, 32, 0.0, 0)
+    }
+
+    /// Create a dataset labeled by a teacher with the given number of hidden units, with the
+    /// given standard deviation of the noise added to the targets and seed.
+    pub fn with_params(
+        size: usize,
+        num_features: usize,
+        num_targets: usize,
+        hidden_size: usize,
+        noise_std: f32,
+        seed: u64,
+    ) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+        // The weights are scaled so that the pre-activations have a unit variance.
+        let mut layer = |fan_in: usize, fan_out: usize| {
+            let scale = 1.0 / (fan_in.max(1) as f32).sqrt();
+
+            (0..fan_out)
+                .map(|_| {
+                    let weights = (0..fan_in)
+                        .map(|_| scale * sample_normal(&mut rng))
+                        .collect();
+                    (weights, 0.1 * sample_normal(&mut rng))
+                })
+                .collect::<Vec<_>>()
+        };
+        let hidden = layer(num_features, hidden_size);
+        let output = layer(hidden_size, num_targets);
+
+        Self {
+            size,
+            num_features,
+            hidden,
+            output,
+            noise_std,
+            seed,
+        }
+    }
+
+    /// The targets predicted by the teacher for the given features, without noise.
+    pub fn teacher(&self, features: &[f32]) -> Vec<f32> {
+        let linear = |layer: &[(Vec<f32>, f32)], input: &[f32]| {
+            layer
+                .iter()
+                .map(|(weights, bias)| {
+                    bias + weights.iter().zip(input).map(|(w, x)| w * x).sum::<f32>()
+                })
+                .collect::<Vec<_>>()
+        };
+        let hidden = linear(&self.hidden, features)
+            .into_iter()
+            .map(f32::tanh)
+            .collect::<Vec<_>>();
+
+        linear(&self.output, &hidden)
+    }
+}
+
+impl Dataset<RegressionItem> for TeacherRegressionDataset {
+    fn get(&self, index: usize) -> Option<RegressionItem> {
+        if index >= self.size {
+            return None;
+        }
+
+        let mut rng = item_rng(self.seed, index);
+        let features = (0..self.num_features)
+            .map(|_| sample_normal(&mut rng))
+            .collect::<Vec<_>>();
+        let targets = self
+            .teacher(&features)
+            .into_iter()
+            .map(|target| target + self.noise_std * sample_normal(&mut rng))
+            .collect();
+
+        Some(RegressionItem { features, targets })
+    }
+
+    fn len(&self) -> usize {
+        self.size
+    }
+
+    fn iter(&self) -> DatasetIterator<'_, RegressionItem> {
+        DatasetIterator::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_label_with_the_teacher() {
+        let dataset = TeacherRegressionDataset::new(8, 5, 2);
+
+        for item in dataset.iter() {
+            assert_eq!(item.features.len(), 5);
+            assert_eq!(item.targets, dataset.teacher(&item.features));
+        }
+    }
+
+    #[test]
+    fn should_add_noise_to_the_targets() {
+        let dataset = TeacherRegressionDataset::with_params(2000, 3, 1, 16, 0.5, 7);
+
+        let variance = dataset
+            .iter()
+            .map(|item| (item.targets[0] - dataset.teacher(&item.features)[0]).powi(2))
+            .sum::<f32>()
+            / 2000.0;
+
+        assert!((variance - 0.25).abs() < 0.05);
+    }
+}
@@ -0,0 +1,160 @@
+use crate::Dataset;
+
+/// How [class frequencies](ClassFrequencies) are turned into class weights.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ClassWeighting {
+    /// Weight each class by the inverse of its number of items, `n / (num_classes * n_c)`.
+    InverseFrequency,
+    /// Weight each class by the inverse of its effective number of items,
+    /// `(1 - beta) / (1 - beta^n_c)`, from
+    /// [Class-Balanced Loss Based on Effective Number of Samples](https://arxiv.org/abs/1901.05555).
+    ///
+    /// A `beta` close to 1, e.g. 0.999, approaches the inverse frequency, while 0 gives the same
+    /// weight to every class.
+    EffectiveNumber {
+        /// The hyperparameter in `[0, 1)` controlling how fast the effective number saturates.
+        beta: f64,
+    },
+}
+
+/// The number of items of each class of a dataset.
+///
+/// The class weights can be passed to the weighted cross-entropy loss, while the
+/// [class balanced sampler](crate::transform::SamplerDataset::class_balanced) samples each class
+/// with the same probability.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let frequencies = ClassFrequencies::from_dataset(&dataset, 10, |item| item.label);
+/// let weights = frequencies.class_weights(ClassWeighting::EffectiveNumber { beta: 0.999 });
+/// let loss = CrossEntropyLossConfig::new()
+///     .with_weights(Some(weights))
+///     .init(&device);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClassFrequencies {
+    counts: Vec<usize>,
+}
+
+impl ClassFrequencies {
+    /// Count the items of each class of a dataset, the class of an item being given by the
+    /// `label` function.
+    ///
+    /// # Panics
+    ///
+    /// If a label isn't lower than the number of classes.
+    pub fn from_dataset<I, D, F>(dataset: &D, num_classes: usize, label: F) -> Self
+    where
+        D: Dataset<I>,
+        F: Fn(&I) -> usize,
+    {
+        Self::from_labels(dataset.iter().map(|item| label(&item)), num_classes)
+    }
+
+    /// Count the occurrences of each class in the labels.
+    ///
+    /// # Panics
+    ///
+    /// If a label isn't lower than the number of classes.
+    pub fn from_labels<L: IntoIterator<Item = usize>>(labels: L, num_classes: usize) -> Self {
+        let mut counts = vec![0; num_classes];
+
+        for label in labels {
+            assert!(
+                label < num_classes,
+                "Label {label} is out of range for {num_classes} classes"
+            );
+            counts[label] += 1;
+        }
+
+        Self { counts }
+    }
+
+    /// The number of items of each class.
+    pub fn counts(&self) -> &[usize] {
+        &self.counts
+    }
+
+    /// The number of classes.
+    pub fn num_classes(&self) -> usize {
+        self.counts.len()
+    }
+
+    /// The total number of items.
+    pub fn total(&self) -> usize {
+        self.counts.iter().sum()
+    }
+
+    /// The weight of each class, normalized so the weights sum to the number of classes.
+    ///
+    /// A class without items gets the weight of a class with a single item, since the weights
+    /// of the cross-entropy loss must be positive.
+    pub fn class_weights(&self, weighting: ClassWeighting) -> Vec<f32> {
+        let num_classes = self.num_classes();
+        let total = self.total().max(1) as f64;
+
+        let weights: Vec<f64> = self
+            .counts
+            .iter()
+            .map(|count| {
+                let count = (*count).max(1) as f64;
+                match weighting {
+                    ClassWeighting::InverseFrequency => total / (num_classes as f64 * count),
+                    ClassWeighting::EffectiveNumber { beta } => {
+                        (1.0 - beta) / (1.0 - beta.powf(count))
+                    }
+                }
+            })
+            .collect();
+
+        let sum: f64 = weights.iter().sum();
+        weights
+            .into_iter()
+            .map(|weight| (weight * num_classes as f64 / sum) as f32)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::InMemDataset;
+
+    fn frequencies() -> ClassFrequencies {
+        let dataset = InMemDataset::new(vec![0, 0, 0, 1, 2, 2, 0, 0]);
+        ClassFrequencies::from_dataset(&dataset, 4, |label| *label)
+    }
+
+    #[test]
+    fn should_count_the_items_of_each_class() {
+        let frequencies = frequencies();
+
+        assert_eq!(frequencies.counts(), [5, 1, 2, 0]);
+        assert_eq!(frequencies.total(), 8);
+    }
+
+    #[test]
+    fn should_weight_classes_by_inverse_frequency() {
+        let weights = frequencies().class_weights(ClassWeighting::InverseFrequency);
+
+        // Proportional to 1/5, 1, 1/2 and 1, summing to 4.
+        let expected = [0.2, 1.0, 0.5, 1.0].map(|weight| weight * 4.0 / 2.7);
+        for (weight, expected) in weights.iter().zip(expected) {
+            assert!((weight - expected).abs() < 1e-6, "{weight} != {expected}");
+        }
+    }
+
+    #[test]
+    fn should_weight_classes_by_effective_number() {
+        let frequencies = frequencies();
+
+        let uniform = frequencies.class_weights(ClassWeighting::EffectiveNumber { beta: 0.0 });
+        assert_eq!(uniform, [1.0; 4]);
+
+        let weights = frequencies.class_weights(ClassWeighting::EffectiveNumber { beta: 0.9 });
+        assert!(weights[0] < weights[2] && weights[2] < weights[1]);
+        assert_eq!(weights[1], weights[3]);
+        assert!((weights.iter().sum::<f32>() - 4.0).abs() < 1e-5);
+    }
+}
@@ -0,0 +1,55 @@
+use crate::Dataset;
+use std::marker::PhantomData;
+
+/// An item with its index in the dataset it comes from.
+#[derive(new, Debug, Clone, PartialEq)]
+pub struct Indexed<I> {
+    /// The index of the item.
+    pub index: usize,
+    /// The item.
+    pub item: I,
+}
+
+/// Dataset yielding the items of an inner dataset with their index, so that the statistics
+/// computed on the items during training can be traced back to the samples of the dataset.
+///
+/// The indices are the ones of the inner dataset, so it should wrap the dataset before it is
+/// shuffled or split.
+#[derive(new)]
+pub struct IndexedDataset<D, I> {
+    dataset: D,
+    input: PhantomData<I>,
+}
+
+impl<D, I> Dataset<Indexed<I>> for IndexedDataset<D, I>
+where
+    D: Dataset<I>,
+    I: Send + Sync,
+{
+    fn get(&self, index: usize) -> Option<Indexed<I>> {
+        self.dataset
+            .get(index)
+            .map(|item| Indexed::new(index, item))
+    }
+
+    fn len(&self) -> usize {
+        self.dataset.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_data, transform::ShuffledDataset, InMemDataset};
+
+    #[test]
+    fn indices_should_survive_shuffling() {
+        let items = test_data::string_items();
+        let dataset = IndexedDataset::new(InMemDataset::new(items.clone()));
+        let dataset = ShuffledDataset::with_seed(dataset, 42);
+
+        for indexed in dataset.iter() {
+            assert_eq!(items[indexed.index], indexed.item);
+        }
+    }
+}
@@ -1,11 +1,15 @@
+mod balance;
 mod composed;
+mod indexed;
 mod mapper;
 mod partial;
 mod random;
 mod sampler;
 mod window;
 
+pub use balance::*;
 pub use composed::*;
+pub use indexed::*;
 pub use mapper::*;
 pub use partial::*;
 pub use random::*;
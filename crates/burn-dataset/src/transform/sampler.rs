@@ -1,5 +1,10 @@
-use crate::Dataset;
-use rand::{distributions::Uniform, rngs::StdRng, seq::IteratorRandom, Rng, SeedableRng};
+use crate::{transform::ClassFrequencies, Dataset};
+use rand::{
+    distributions::{Uniform, WeightedIndex},
+    rngs::StdRng,
+    seq::IteratorRandom,
+    Rng, SeedableRng,
+};
 use std::{marker::PhantomData, ops::DerefMut, sync::Mutex};
 
 /// Sample items from a dataset.
@@ -14,6 +19,10 @@ use std::{marker::PhantomData, ops::DerefMut, sync::Mutex};
 ///   [shuffled dataset](crate::transform::ShuffledDataset), but with more flexibility since you can
 ///   set the dataset to an arbitrary size. Once every item has been used, a new cycle is
 ///   created with a new random suffle.
+///
+/// * Weighted: Each item is sampled with replacement with a probability proportional to its
+///   weight, e.g. to [balance the classes](SamplerDataset::class_balanced) of an imbalanced
+///   dataset.
 pub struct SamplerDataset<D, I> {
     dataset: D,
     size: usize,
@@ -24,6 +33,7 @@ pub struct SamplerDataset<D, I> {
 enum SamplerState {
     WithReplacement(StdRng),
     WithoutReplacement(StdRng, Vec<usize>),
+    Weighted(StdRng, WeightedIndex<f64>),
 }
 
 impl<D, I> SamplerDataset<D, I>
@@ -59,6 +69,50 @@ where
         }
     }
 
+    /// Creates a new sampler dataset with replacement, sampling each item with a probability
+    /// proportional to its weight.
+    ///
+    /// # Panics
+    ///
+    /// If the number of weights isn't the length of the dataset, if a weight is negative or if
+    /// all the weights are zero.
+    pub fn weighted(dataset: D, size: usize, weights: Vec<f64>) -> Self {
+        assert_eq!(
+            weights.len(),
+            dataset.len(),
+            "The sampler should have a weight for each item of the dataset"
+        );
+        let weights = WeightedIndex::new(weights).expect("Valid sampling weights");
+
+        Self {
+            dataset,
+            size,
+            state: Mutex::new(SamplerState::Weighted(StdRng::from_entropy(), weights)),
+            input: PhantomData,
+        }
+    }
+
+    /// Creates a new sampler dataset with replacement, sampling each class with the same
+    /// probability.
+    ///
+    /// Each item is weighted by the inverse of the number of items of its class, given by the
+    /// `label` function.
+    pub fn class_balanced<F>(dataset: D, size: usize, label: F) -> Self
+    where
+        F: Fn(&I) -> usize,
+    {
+        let labels: Vec<usize> = dataset.iter().map(|item| label(&item)).collect();
+        let num_classes = labels.iter().max().map_or(0, |max| max + 1);
+        let frequencies = ClassFrequencies::from_labels(labels.iter().copied(), num_classes);
+
+        let weights = labels
+            .iter()
+            .map(|label| 1.0 / frequencies.counts()[*label] as f64)
+            .collect();
+
+        Self::weighted(dataset, size, weights)
+    }
+
     fn index(&self) -> usize {
         let mut state = self.state.lock().unwrap();
 
@@ -72,6 +126,7 @@ where
 
                 indices.pop().expect("Indices are refilled when empty.")
             }
+            SamplerState::Weighted(rng, weights) => rng.sample(&*weights),
         }
     }
 }
@@ -97,7 +152,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::FakeDataset;
+    use crate::{FakeDataset, InMemDataset};
     use std::collections::HashMap;
 
     #[test]
@@ -143,4 +198,20 @@ mod tests {
         }
         assert_eq!(total, factor * len_original);
     }
+
+    #[test]
+    fn sampler_dataset_class_balanced() {
+        // 90 items of class 0 and 10 items of class 1.
+        let items: Vec<usize> = (0..100).map(|i| usize::from(i >= 90)).collect();
+        let dataset_sampler =
+            SamplerDataset::class_balanced(InMemDataset::new(items), 2000, |label| *label);
+
+        let num_ones = dataset_sampler.iter().filter(|label| *label == 1).count();
+
+        assert_eq!(dataset_sampler.len(), 2000);
+        assert!(
+            (800..1200).contains(&num_ones),
+            "{num_ones} items of class 1"
+        );
+    }
 }
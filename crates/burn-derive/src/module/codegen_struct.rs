@@ -30,8 +30,13 @@ impl ModuleCodegen for StructModuleCodegen {
 
     fn gen_visit(&self) -> TokenStream {
         let body = self.gen_fields_fn(|name| {
+            let label = name.to_string();
+            let label = label.trim_start_matches("r#");
+
             quote! {
+                burn::module::ModuleVisitor::<B>::enter_module(visitor, #label);
                 burn::module::Module::visit(&self.#name, visitor);
+                burn::module::ModuleVisitor::<B>::exit_module(visitor, #label);
             }
         });
 
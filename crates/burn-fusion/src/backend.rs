@@ -1,6 +1,6 @@
 use crate::{client::FusionClient, stream::Context, FusionClientLocator, FusionTensor};
 use burn_tensor::{
-    backend::{Backend, DeviceOps},
+    backend::{Backend, DeviceOps, MemoryStats, RngState},
     ops::{BoolTensor, FloatTensor, IntTensor, QuantizedTensor},
     repr::{OperationDescription, ReprBackend, TensorHandle},
     Device, Element,
@@ -47,12 +47,28 @@ impl<B: FusionBackend> Backend for Fusion<B> {
         B::seed(seed);
     }
 
+    fn rng_state() -> Option<RngState> {
+        B::rng_state()
+    }
+
+    fn set_rng_state(state: RngState) {
+        B::set_rng_state(state)
+    }
+
     fn sync(device: &Self::Device) {
         let client = CLIENTS.client::<B::FusionRuntime>(&device.clone());
         client.drain();
         B::sync(device);
     }
 
+    fn memory_stats(device: &Self::Device) -> Option<MemoryStats> {
+        B::memory_stats(device)
+    }
+
+    fn set_memory_budget(device: &Self::Device, budget: Option<u64>) {
+        B::set_memory_budget(device, budget)
+    }
+
     fn ad_enabled() -> bool {
         false
     }
@@ -49,8 +49,7 @@ where
     }
 
     fn drain(&self) {
-        let id = StreamId::current();
-        self.server.lock().drain_stream(id);
+        self.server.lock().drain_streams();
     }
 
     fn tensor_uninitialized(&self, shape: Vec<usize>, dtype: DType) -> FusionTensor<R> {
@@ -9,6 +9,7 @@ use crate::{
     client::FusionClient,
     get_client,
     stream::{execution::Operation, StreamId},
+    tensor::is_identity_permutation,
     Fusion, FusionBackend,
 };
 use burn_tensor::{
@@ -429,12 +430,22 @@ impl<B: FusionBackend> BoolTensorOps<Self> for Fusion<B> {
             }
         }
 
+        let mut axes = (0..tensor.shape.len()).collect::<Vec<_>>();
+        axes.swap(dim1, dim2);
+
+        if tensor.view.is_some() || dim1 == dim2 {
+            return Self::bool_permute(tensor, &axes);
+        }
+
         let stream = tensor.stream;
         let mut shape = tensor.shape.clone();
         shape[dim1] = tensor.shape[dim2];
         shape[dim2] = tensor.shape[dim1];
 
-        let out = tensor.client.tensor_uninitialized(shape, DType::Bool);
+        let out = tensor
+            .client
+            .tensor_uninitialized(shape, DType::Bool)
+            .with_view(&tensor, axes);
 
         let desc = SwapDimsDescription {
             input: tensor.into_description(),
@@ -466,16 +477,26 @@ impl<B: FusionBackend> BoolTensorOps<Self> for Fusion<B> {
             }
         }
 
+        // Permutations of a view are applied to the tensor it is a view of, and permutations
+        // cancelling each other don't launch anything.
+        let (tensor, axes) = tensor.resolve_permutation(axes);
+        if is_identity_permutation(&axes) {
+            return tensor;
+        }
+
         let stream = tensor.stream;
 
         // Change the shape of the tensor to match the new axes
         let shape = axes.iter().map(|x| tensor.shape[*x]).collect();
 
-        let out = tensor.client.tensor_uninitialized(shape, DType::Bool);
+        let out = tensor
+            .client
+            .tensor_uninitialized(shape, DType::Bool)
+            .with_view(&tensor, axes.clone());
 
         let desc = PermuteOperationDescription {
             input: tensor.into_description(),
-            axes: axes.to_vec(),
+            axes,
             out: out.to_description_out(),
         };
 
@@ -5,6 +5,7 @@ use crate::{
     ops::binary::check_binary_op_types,
     scalar_float2int_ops, scalar_float_cmp_ops, scalar_float_ops,
     stream::{execution::Operation, StreamId},
+    tensor::is_identity_permutation,
     unary_float_ops, Fusion, FusionBackend,
 };
 use burn_tensor::{
@@ -608,6 +609,56 @@ impl<B: FusionBackend> FloatTensorOps<Self> for Fusion<B> {
         out
     }
 
+    #[cfg(feature = "fp8")]
+    fn float_matmul_fp8(
+        lhs: FloatTensor<Self>,
+        lhs_scaling: burn_tensor::Fp8Scaling,
+        rhs: FloatTensor<Self>,
+        rhs_scaling: burn_tensor::Fp8Scaling,
+    ) -> FloatTensor<Self> {
+        #[derive(new)]
+        struct MatmulFp8Ops<B: FusionBackend> {
+            desc: CustomOpDescription,
+            lhs_scaling: burn_tensor::Fp8Scaling,
+            rhs_scaling: burn_tensor::Fp8Scaling,
+            _b: PhantomData<B>,
+        }
+
+        impl<B: FusionBackend> Operation<B::FusionRuntime> for MatmulFp8Ops<B> {
+            fn execute(self: Box<Self>, handles: &mut HandleContainer<B::Handle>) {
+                let ([lhs, rhs], [out]) = self.desc.consume();
+                let lhs = handles.get_float_tensor::<B>(&lhs);
+                let rhs = handles.get_float_tensor::<B>(&rhs);
+                let output = B::float_matmul_fp8(lhs, self.lhs_scaling, rhs, self.rhs_scaling);
+
+                handles.register_float_tensor::<B>(&out.id, output);
+            }
+        }
+
+        let stream_1 = lhs.stream;
+        let stream_2 = rhs.stream;
+        let dtype = lhs.dtype;
+        let ndims = lhs.shape.len();
+        let mut shape = lhs.shape.clone();
+        shape[ndims - 1] = rhs.shape[ndims - 1];
+
+        let out = lhs.client.tensor_uninitialized(shape, dtype);
+        // Custom descriptions only declare their tensors, the scalings are kept by the operation.
+        let desc = CustomOpDescription::new(
+            "matmul_fp8",
+            &[lhs.into_description(), rhs.into_description()],
+            &[out.to_description_out()],
+        );
+
+        out.client.register(
+            vec![stream_1, stream_2],
+            OperationDescription::Custom(desc.clone()),
+            MatmulFp8Ops::<B>::new(desc, lhs_scaling, rhs_scaling),
+        );
+
+        out
+    }
+
     fn float_swap_dims(tensor: FloatTensor<Self>, dim1: usize, dim2: usize) -> FloatTensor<Self> {
         #[derive(new)]
         struct SwapDimsOps<B: FusionBackend> {
@@ -623,13 +674,23 @@ impl<B: FusionBackend> FloatTensorOps<Self> for Fusion<B> {
             }
         }
 
+        let mut axes = (0..tensor.shape.len()).collect::<Vec<_>>();
+        axes.swap(dim1, dim2);
+
+        if tensor.view.is_some() || dim1 == dim2 {
+            return Self::float_permute(tensor, &axes);
+        }
+
         let stream = tensor.stream;
         let dtype = tensor.dtype;
         let mut shape = tensor.shape.clone();
         shape[dim1] = tensor.shape[dim2];
         shape[dim2] = tensor.shape[dim1];
 
-        let mut out = tensor.client.tensor_uninitialized(shape, dtype);
+        let mut out = tensor
+            .client
+            .tensor_uninitialized(shape, dtype)
+            .with_view(&tensor, axes);
 
         let desc = SwapDimsDescription {
             input: tensor.into_description(),
@@ -2082,16 +2143,26 @@ impl<B: FusionBackend> FloatTensorOps<Self> for Fusion<B> {
             }
         }
 
+        // Permutations of a view are applied to the tensor it is a view of, and permutations
+        // cancelling each other don't launch anything.
+        let (tensor, axes) = tensor.resolve_permutation(axes);
+        if is_identity_permutation(&axes) {
+            return tensor;
+        }
+
         let stream = tensor.stream;
 
         // Change the shape of the tensor to match the new axes
         let shape = axes.iter().map(|x| tensor.shape[*x]).collect();
 
-        let out = tensor.client.tensor_uninitialized(shape, tensor.dtype);
+        let out = tensor
+            .client
+            .tensor_uninitialized(shape, tensor.dtype)
+            .with_view(&tensor, axes.clone());
 
         let desc = PermuteOperationDescription {
             input: tensor.into_description(),
-            axes: axes.to_vec(),
+            axes,
             out: out.to_description_out(),
         };
 
@@ -2261,7 +2332,7 @@ impl<B: FusionBackend> FloatTensorOps<Self> for Fusion<B> {
         let stream = tensor.stream;
         let out = tensor
             .client
-            .tensor_uninitialized(tensor.shape.clone(), dtype.clone().into());
+            .tensor_uninitialized(tensor.shape.clone(), dtype.into());
 
         let desc = UnaryOperationDescription {
             input: tensor.into_description(),
@@ -3,6 +3,7 @@ use crate::{
     client::FusionClient,
     get_client, scalar_int_cmp_ops, scalar_int_ops,
     stream::{execution::Operation, StreamId},
+    tensor::is_identity_permutation,
     unary_int_ops, Fusion, FusionBackend,
 };
 use burn_tensor::{
@@ -1450,6 +1451,13 @@ impl<B: FusionBackend> IntTensorOps<Self> for Fusion<B> {
             }
         }
 
+        let mut axes = (0..tensor.shape.len()).collect::<Vec<_>>();
+        axes.swap(dim1, dim2);
+
+        if tensor.view.is_some() || dim1 == dim2 {
+            return Self::int_permute(tensor, &axes);
+        }
+
         let stream = tensor.stream;
         let mut shape = tensor.shape.clone();
         shape[dim1] = tensor.shape[dim2];
@@ -1457,7 +1465,8 @@ impl<B: FusionBackend> IntTensorOps<Self> for Fusion<B> {
 
         let out = tensor
             .client
-            .tensor_uninitialized(shape, B::IntElem::dtype());
+            .tensor_uninitialized(shape, B::IntElem::dtype())
+            .with_view(&tensor, axes);
 
         let desc = SwapDimsDescription {
             input: tensor.into_description(),
@@ -1718,6 +1727,13 @@ impl<B: FusionBackend> IntTensorOps<Self> for Fusion<B> {
             }
         }
 
+        // Permutations of a view are applied to the tensor it is a view of, and permutations
+        // cancelling each other don't launch anything.
+        let (tensor, axes) = tensor.resolve_permutation(axes);
+        if is_identity_permutation(&axes) {
+            return tensor;
+        }
+
         let stream = tensor.stream;
 
         // Change the shape of the tensor to match the new axes
@@ -1725,11 +1741,12 @@ impl<B: FusionBackend> IntTensorOps<Self> for Fusion<B> {
 
         let out = tensor
             .client
-            .tensor_uninitialized(shape, B::IntElem::dtype());
+            .tensor_uninitialized(shape, B::IntElem::dtype())
+            .with_view(&tensor, axes.clone());
 
         let desc = PermuteOperationDescription {
             input: tensor.into_description(),
-            axes: axes.to_vec(),
+            axes,
             out: out.to_description_out(),
         };
 
@@ -6,14 +6,35 @@ use burn_tensor::{
             calculate_pool_output_size,
         },
         ConvOptions, ConvTransposeOptions, DeformConv2dBackward, DeformConvOptions, FloatTensor,
-        IntTensor, InterpolateOptions, MaxPool1dBackward, MaxPool1dWithIndices, MaxPool2dBackward,
-        MaxPool2dWithIndices, ModuleOps,
+        IntTensor, InterpolateOptions, LayerNormBackward, MaxPool1dBackward, MaxPool1dWithIndices,
+        MaxPool2dBackward, MaxPool2dWithIndices, MaxPool3dBackward, MaxPool3dWithIndices,
+        ModuleOps, RmsNormBackward, RotaryQk,
     },
     repr::*,
     Element,
 };
 use std::marker::PhantomData;
 
+fn max_pool3d_output_shape(
+    shape: &[usize],
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    dilation: [usize; 3],
+) -> Vec<usize> {
+    let size = |dim: usize| {
+        calculate_pool_output_size(
+            kernel_size[dim],
+            stride[dim],
+            padding[dim],
+            dilation[dim],
+            shape[dim + 2],
+        )
+    };
+
+    vec![shape[0], shape[1], size(0), size(1), size(2)]
+}
+
 macro_rules! make_ops {
     ($name:ident, $desc:ty, $fn:expr) => {
         #[derive(new)]
@@ -1184,6 +1205,267 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
         MaxPool2dBackward::new(out)
     }
 
+    fn avg_pool3d(
+        x: FloatTensor<Self>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        count_include_pad: bool,
+    ) -> FloatTensor<Self> {
+        make_ops!(
+            AvgPool3dOps,
+            AvgPool3dDescription,
+            |args: AvgPool3dDescription, handles: &mut HandleContainer<B::Handle>| {
+                let x = handles.get_float_tensor::<B>(&args.x);
+                let output = B::avg_pool3d(
+                    x,
+                    args.kernel_size,
+                    args.stride,
+                    args.padding,
+                    args.count_include_pad,
+                );
+
+                handles.register_float_tensor::<B>(&args.out.id, output);
+            }
+        );
+
+        let size_0 =
+            calculate_pool_output_size(kernel_size[0], stride[0], padding[0], 1, x.shape[2]);
+        let size_1 =
+            calculate_pool_output_size(kernel_size[1], stride[1], padding[1], 1, x.shape[3]);
+        let size_2 =
+            calculate_pool_output_size(kernel_size[2], stride[2], padding[2], 1, x.shape[4]);
+
+        let stream = x.stream;
+        let shape = vec![x.shape[0], x.shape[1], size_0, size_1, size_2];
+        let out = x.client.tensor_uninitialized(shape, B::FloatElem::dtype());
+
+        let desc = AvgPool3dDescription {
+            x: x.into_description(),
+            kernel_size,
+            stride,
+            padding,
+            count_include_pad,
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationDescription::Module(ModuleOperationDescription::AvgPool3d(desc.clone())),
+            AvgPool3dOps::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn avg_pool3d_backward(
+        x: FloatTensor<Self>,
+        grad: FloatTensor<Self>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        count_include_pad: bool,
+    ) -> FloatTensor<Self> {
+        make_ops!(
+            AvgPool3dBackwardOps,
+            AvgPool3dBackwardDescription,
+            |args: AvgPool3dBackwardDescription, handles: &mut HandleContainer<B::Handle>| {
+                let x = handles.get_float_tensor::<B>(&args.x);
+                let grad = handles.get_float_tensor::<B>(&args.grad);
+                let output = B::avg_pool3d_backward(
+                    x,
+                    grad,
+                    args.kernel_size,
+                    args.stride,
+                    args.padding,
+                    args.count_include_pad,
+                );
+
+                handles.register_float_tensor::<B>(&args.out.id, output);
+            }
+        );
+
+        let stream_1 = x.stream;
+        let stream_2 = grad.stream;
+        let out = x
+            .client
+            .tensor_uninitialized(x.shape.clone(), B::FloatElem::dtype());
+
+        let desc = AvgPool3dBackwardDescription {
+            x: x.into_description(),
+            grad: grad.into_description(),
+            kernel_size,
+            stride,
+            padding,
+            count_include_pad,
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            vec![stream_1, stream_2],
+            OperationDescription::Module(ModuleOperationDescription::AvgPool3dBackward(
+                desc.clone(),
+            )),
+            AvgPool3dBackwardOps::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn max_pool3d(
+        x: FloatTensor<Self>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+    ) -> FloatTensor<Self> {
+        make_ops!(
+            MaxPool3dOps,
+            MaxPool3dDescription,
+            |args: MaxPool3dDescription, handles: &mut HandleContainer<B::Handle>| {
+                let x = handles.get_float_tensor::<B>(&args.x);
+                let output = B::max_pool3d(
+                    x,
+                    args.kernel_size,
+                    args.stride,
+                    args.padding,
+                    args.dilation,
+                );
+
+                handles.register_float_tensor::<B>(&args.out.id, output);
+            }
+        );
+
+        let stream = x.stream;
+        let shape = max_pool3d_output_shape(&x.shape, kernel_size, stride, padding, dilation);
+        let out = x.client.tensor_uninitialized(shape, B::FloatElem::dtype());
+
+        let desc = MaxPool3dDescription {
+            x: x.into_description(),
+            kernel_size,
+            stride,
+            padding,
+            dilation,
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationDescription::Module(ModuleOperationDescription::MaxPool3d(desc.clone())),
+            MaxPool3dOps::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn max_pool3d_with_indices(
+        x: FloatTensor<Self>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+    ) -> MaxPool3dWithIndices<Self> {
+        make_ops!(
+            MaxPool3dWithIndicesOps,
+            MaxPool3dWithIndicesDescription,
+            |args: MaxPool3dWithIndicesDescription, handles: &mut HandleContainer<B::Handle>| {
+                let x = handles.get_float_tensor::<B>(&args.x);
+                let output = B::max_pool3d_with_indices(
+                    x,
+                    args.kernel_size,
+                    args.stride,
+                    args.padding,
+                    args.dilation,
+                );
+
+                handles.register_float_tensor::<B>(&args.out.id, output.output);
+                handles.register_int_tensor::<B>(&args.out_indices.id, output.indices);
+            }
+        );
+
+        let stream = x.stream;
+        let shape = max_pool3d_output_shape(&x.shape, kernel_size, stride, padding, dilation);
+        let out = x
+            .client
+            .tensor_uninitialized(shape.clone(), B::FloatElem::dtype());
+        let out_indices = x.client.tensor_uninitialized(shape, B::IntElem::dtype());
+
+        let desc = MaxPool3dWithIndicesDescription {
+            x: x.into_description(),
+            kernel_size,
+            stride,
+            padding,
+            dilation,
+            out: out.to_description_out(),
+            out_indices: out_indices.to_description_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationDescription::Module(ModuleOperationDescription::MaxPool3dWithIndices(
+                desc.clone(),
+            )),
+            MaxPool3dWithIndicesOps::<B>::new(desc),
+        );
+
+        MaxPool3dWithIndices::new(out, out_indices)
+    }
+
+    fn max_pool3d_with_indices_backward(
+        x: FloatTensor<Self>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+        output_grad: FloatTensor<Self>,
+        indices: IntTensor<Self>,
+    ) -> MaxPool3dBackward<Self> {
+        make_ops!(
+            MaxPool3dWithIndicesBackwardOps,
+            MaxPool3dWithIndicesBackwardDescription,
+            |args: MaxPool3dWithIndicesBackwardDescription,
+             handles: &mut HandleContainer<B::Handle>| {
+                let x = handles.get_float_tensor::<B>(&args.x);
+                let grad = handles.get_float_tensor::<B>(&args.grad);
+                let indices = handles.get_int_tensor::<B>(&args.indices);
+                let output = B::max_pool3d_with_indices_backward(
+                    x,
+                    args.kernel_size,
+                    args.stride,
+                    args.padding,
+                    args.dilation,
+                    grad,
+                    indices,
+                );
+
+                handles.register_float_tensor::<B>(&args.out.id, output.x_grad);
+            }
+        );
+
+        let stream_1 = x.stream;
+        let stream_2 = output_grad.stream;
+        let stream_3 = indices.stream;
+        let out = x
+            .client
+            .tensor_uninitialized(x.shape.clone(), B::FloatElem::dtype());
+
+        let desc = MaxPool3dWithIndicesBackwardDescription {
+            x: x.into_description(),
+            grad: output_grad.into_description(),
+            indices: indices.into_description(),
+            kernel_size,
+            stride,
+            padding,
+            dilation,
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            vec![stream_1, stream_2, stream_3],
+            OperationDescription::Module(ModuleOperationDescription::MaxPool3dWithIndicesBackward(
+                desc.clone(),
+            )),
+            MaxPool3dWithIndicesBackwardOps::<B>::new(desc),
+        );
+
+        MaxPool3dBackward::new(out)
+    }
+
     fn adaptive_avg_pool1d(x: FloatTensor<Self>, output_size: usize) -> FloatTensor<Self> {
         make_ops!(
             AdaptiveAvgPool1dOps,
@@ -1402,4 +1684,221 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
         );
         out
     }
+
+    fn rms_norm(x: FloatTensor<Self>, gamma: FloatTensor<Self>, epsilon: f64) -> FloatTensor<Self> {
+        make_ops!(
+            RmsNormOps,
+            RmsNormDescription,
+            |args: RmsNormDescription, handles: &mut HandleContainer<B::Handle>| {
+                let x = handles.get_float_tensor::<B>(&args.x);
+                let gamma = handles.get_float_tensor::<B>(&args.gamma);
+                let output = B::rms_norm(x, gamma, args.epsilon);
+
+                handles.register_float_tensor::<B>(&args.out.id, output);
+            }
+        );
+
+        let stream_1 = x.stream;
+        let stream_2 = gamma.stream;
+        let out = x.client.tensor_uninitialized(x.shape.clone(), x.dtype);
+
+        let desc = RmsNormDescription {
+            x: x.into_description(),
+            gamma: gamma.into_description(),
+            epsilon,
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            vec![stream_1, stream_2],
+            OperationDescription::Module(ModuleOperationDescription::RmsNorm(desc.clone())),
+            RmsNormOps::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn rms_norm_backward(
+        x: FloatTensor<Self>,
+        gamma: FloatTensor<Self>,
+        output_grad: FloatTensor<Self>,
+        epsilon: f64,
+    ) -> RmsNormBackward<Self> {
+        make_ops!(
+            RmsNormBackwardOps,
+            RmsNormBackwardDescription,
+            |args: RmsNormBackwardDescription, handles: &mut HandleContainer<B::Handle>| {
+                let x = handles.get_float_tensor::<B>(&args.x);
+                let gamma = handles.get_float_tensor::<B>(&args.gamma);
+                let grad = handles.get_float_tensor::<B>(&args.grad);
+                let output = B::rms_norm_backward(x, gamma, grad, args.epsilon);
+
+                handles.register_float_tensor::<B>(&args.out_x_grad.id, output.x_grad);
+                handles.register_float_tensor::<B>(&args.out_gamma_grad.id, output.gamma_grad);
+            }
+        );
+
+        let streams = vec![x.stream, gamma.stream, output_grad.stream];
+        let x_grad = x.client.tensor_uninitialized(x.shape.clone(), x.dtype);
+        let gamma_grad = x
+            .client
+            .tensor_uninitialized(gamma.shape.clone(), gamma.dtype);
+
+        let desc = RmsNormBackwardDescription {
+            x: x.into_description(),
+            gamma: gamma.into_description(),
+            grad: output_grad.into_description(),
+            epsilon,
+            out_x_grad: x_grad.to_description_out(),
+            out_gamma_grad: gamma_grad.to_description_out(),
+        };
+        x_grad.client.register(
+            streams,
+            OperationDescription::Module(ModuleOperationDescription::RmsNormBackward(desc.clone())),
+            RmsNormBackwardOps::<B>::new(desc),
+        );
+
+        RmsNormBackward::new(x_grad, gamma_grad)
+    }
+
+    fn layer_norm(
+        x: FloatTensor<Self>,
+        gamma: FloatTensor<Self>,
+        beta: Option<FloatTensor<Self>>,
+        epsilon: f64,
+    ) -> FloatTensor<Self> {
+        make_ops!(
+            LayerNormOps,
+            LayerNormDescription,
+            |args: LayerNormDescription, handles: &mut HandleContainer<B::Handle>| {
+                let x = handles.get_float_tensor::<B>(&args.x);
+                let gamma = handles.get_float_tensor::<B>(&args.gamma);
+                let beta = args
+                    .beta
+                    .as_ref()
+                    .map(|beta| handles.get_float_tensor::<B>(beta));
+                let output = B::layer_norm(x, gamma, beta, args.epsilon);
+
+                handles.register_float_tensor::<B>(&args.out.id, output);
+            }
+        );
+
+        let mut streams = vec![x.stream, gamma.stream];
+        if let Some(beta) = beta.as_ref() {
+            streams.push(beta.stream);
+        }
+        let out = x.client.tensor_uninitialized(x.shape.clone(), x.dtype);
+
+        let desc = LayerNormDescription {
+            x: x.into_description(),
+            gamma: gamma.into_description(),
+            beta: beta.map(|beta| beta.into_description()),
+            epsilon,
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            streams,
+            OperationDescription::Module(ModuleOperationDescription::LayerNorm(desc.clone())),
+            LayerNormOps::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn layer_norm_backward(
+        x: FloatTensor<Self>,
+        gamma: FloatTensor<Self>,
+        output_grad: FloatTensor<Self>,
+        epsilon: f64,
+    ) -> LayerNormBackward<Self> {
+        make_ops!(
+            LayerNormBackwardOps,
+            LayerNormBackwardDescription,
+            |args: LayerNormBackwardDescription, handles: &mut HandleContainer<B::Handle>| {
+                let x = handles.get_float_tensor::<B>(&args.x);
+                let gamma = handles.get_float_tensor::<B>(&args.gamma);
+                let grad = handles.get_float_tensor::<B>(&args.grad);
+                let output = B::layer_norm_backward(x, gamma, grad, args.epsilon);
+
+                handles.register_float_tensor::<B>(&args.out_x_grad.id, output.x_grad);
+                handles.register_float_tensor::<B>(&args.out_gamma_grad.id, output.gamma_grad);
+                handles.register_float_tensor::<B>(&args.out_beta_grad.id, output.beta_grad);
+            }
+        );
+
+        let streams = vec![x.stream, gamma.stream, output_grad.stream];
+        let x_grad = x.client.tensor_uninitialized(x.shape.clone(), x.dtype);
+        let gamma_grad = x
+            .client
+            .tensor_uninitialized(gamma.shape.clone(), gamma.dtype);
+        let beta_grad = x
+            .client
+            .tensor_uninitialized(gamma.shape.clone(), gamma.dtype);
+
+        let desc = LayerNormBackwardDescription {
+            x: x.into_description(),
+            gamma: gamma.into_description(),
+            grad: output_grad.into_description(),
+            epsilon,
+            out_x_grad: x_grad.to_description_out(),
+            out_gamma_grad: gamma_grad.to_description_out(),
+            out_beta_grad: beta_grad.to_description_out(),
+        };
+        x_grad.client.register(
+            streams,
+            OperationDescription::Module(ModuleOperationDescription::LayerNormBackward(
+                desc.clone(),
+            )),
+            LayerNormBackwardOps::<B>::new(desc),
+        );
+
+        LayerNormBackward::new(x_grad, gamma_grad, beta_grad)
+    }
+
+    fn rotary_qk(
+        queries: FloatTensor<Self>,
+        keys: FloatTensor<Self>,
+        frequencies: FloatTensor<Self>,
+        start: usize,
+    ) -> RotaryQk<Self> {
+        make_ops!(
+            RotaryQkOps,
+            RotaryQkDescription,
+            |args: RotaryQkDescription, handles: &mut HandleContainer<B::Handle>| {
+                let queries = handles.get_float_tensor::<B>(&args.queries);
+                let keys = handles.get_float_tensor::<B>(&args.keys);
+                let frequencies = handles.get_float_tensor::<B>(&args.frequencies);
+                let output = B::rotary_qk(queries, keys, frequencies, args.start);
+
+                handles.register_float_tensor::<B>(&args.out_queries.id, output.queries);
+                handles.register_float_tensor::<B>(&args.out_keys.id, output.keys);
+            }
+        );
+
+        let streams = vec![queries.stream, keys.stream, frequencies.stream];
+        // The heads are swapped with the sequence.
+        let mut queries_shape = queries.shape.clone();
+        let mut keys_shape = keys.shape.clone();
+        queries_shape.swap(1, 2);
+        keys_shape.swap(1, 2);
+        let out_queries = queries
+            .client
+            .tensor_uninitialized(queries_shape, queries.dtype);
+        let out_keys = queries.client.tensor_uninitialized(keys_shape, keys.dtype);
+
+        let desc = RotaryQkDescription {
+            queries: queries.into_description(),
+            keys: keys.into_description(),
+            frequencies: frequencies.into_description(),
+            start,
+            out_queries: out_queries.to_description_out(),
+            out_keys: out_keys.to_description_out(),
+        };
+        out_queries.client.register(
+            streams,
+            OperationDescription::Module(ModuleOperationDescription::RotaryQk(desc.clone())),
+            RotaryQkOps::<B>::new(desc),
+        );
+
+        RotaryQk::new(out_queries, out_keys)
+    }
 }
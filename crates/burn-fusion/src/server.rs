@@ -35,6 +35,10 @@ where
         self.streams.drain(&mut self.handles, id)
     }
 
+    pub fn drain_streams(&mut self) {
+        self.streams.drain_all(&mut self.handles)
+    }
+
     pub fn create_empty_handle(&mut self) -> Arc<TensorId> {
         self.handles.create_tensor_uninit()
     }
@@ -1,8 +1,15 @@
 use std::collections::BTreeSet;
 
-use super::{execution::Operation, OperationConverter, RelativeOps};
+use super::{
+    cse::{self, CommonSubexpressions},
+    execution::Operation,
+    OperationConverter, RelativeOps,
+};
 use crate::FusionRuntime;
-use burn_tensor::repr::{OperationDescription, TensorId};
+use burn_tensor::repr::{
+    CustomOpDescription, HandleContainer, OperationDescription, TensorDescription, TensorId,
+    TensorStatus,
+};
 
 pub use burn_common::id::StreamId;
 
@@ -20,6 +27,7 @@ pub struct OperationQueue<R: FusionRuntime> {
     pub(crate) converter: OperationConverter,
     pub(crate) operations: Vec<Box<dyn Operation<R>>>,
     pub(crate) ids: BTreeSet<TensorId>,
+    pub(crate) subexpressions: CommonSubexpressions,
 }
 
 impl<R: FusionRuntime> Default for OperationQueue<R> {
@@ -37,6 +45,7 @@ impl<R: FusionRuntime> OperationQueue<R> {
             converter: OperationConverter::default(),
             operations: Vec::new(),
             ids: BTreeSet::new(),
+            subexpressions: CommonSubexpressions::default(),
         }
     }
 
@@ -45,16 +54,65 @@ impl<R: FusionRuntime> OperationQueue<R> {
     /// The new [operation description](OperationDescription) will be converted to a local
     /// representation that can be reused when the same pattern emerge in different but similar
     /// scenario, so that the same optimization can be used.
+    ///
+    /// When an operation already in the queue computes the same tensors, the new operation is
+    /// replaced by an operation reusing those tensors.
     pub fn add(&mut self, global: OperationDescription, operation: Box<dyn Operation<R>>) {
+        let (global, operation) = match self.subexpressions.find(&self.global, &global) {
+            Some(reused) => {
+                self.subexpressions
+                    .register(&cse::outputs(&global), &reused);
+                Self::reuse(global, reused)
+            }
+            None => (global, operation),
+        };
+
         for node in global.nodes() {
             self.ids.insert(node.id);
         }
         let relative = global.to_relative(&mut self.converter);
+        self.subexpressions.push(self.global.len(), &global);
         self.relative.push(relative);
         self.global.push(global);
         self.operations.push(operation);
     }
 
+    /// Replace an operation with an operation registering the reused tensors as its outputs.
+    ///
+    /// The inputs consumed by the replaced operation are declared as inputs, so that they are
+    /// still freed after the execution.
+    fn reuse(
+        global: OperationDescription,
+        reused: Vec<TensorDescription>,
+    ) -> (OperationDescription, Box<dyn Operation<R>>) {
+        let outputs = cse::outputs(&global);
+        let mut inputs = reused
+            .iter()
+            .map(|tensor| TensorDescription {
+                status: TensorStatus::ReadOnly,
+                ..tensor.clone()
+            })
+            .collect::<Vec<_>>();
+        let num_reused = inputs.len();
+
+        inputs.extend(
+            global
+                .nodes()
+                .into_iter()
+                .filter(|node| node.status == TensorStatus::ReadWrite)
+                .cloned(),
+        );
+
+        let outputs = outputs.into_iter().cloned().collect::<Vec<_>>();
+        let desc = CustomOpDescription::new("reuse", &inputs, &outputs);
+        let operation = ReuseOperation {
+            reused: inputs[..num_reused].to_vec(),
+            outputs,
+        };
+
+        (OperationDescription::Custom(desc), Box::new(operation))
+    }
+
     /// The size of the queue.
     pub fn len(&self) -> usize {
         self.global.len()
@@ -66,6 +124,21 @@ impl<R: FusionRuntime> OperationQueue<R> {
     }
 }
 
+/// Register the handles of the reused tensors as the handles of the outputs.
+struct ReuseOperation {
+    reused: Vec<TensorDescription>,
+    outputs: Vec<TensorDescription>,
+}
+
+impl<R: FusionRuntime> Operation<R> for ReuseOperation {
+    fn execute(self: Box<Self>, handles: &mut HandleContainer<R::FusionHandle>) {
+        for (reused, output) in self.reused.iter().zip(self.outputs.iter()) {
+            let handle = handles.get_handle(&reused.id, &reused.status);
+            handles.register_handle(output.id, handle);
+        }
+    }
+}
+
 #[cfg(all(test, feature = "std"))]
 mod tests {
     use super::*;
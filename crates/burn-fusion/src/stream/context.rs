@@ -46,6 +46,9 @@ pub(crate) struct OperationConverter {
     /// Only useful to create new shape ID.
     /// You should use tensor descriptions to retrieve the proper shape.
     shapes_global2relative: HashMap<usize, usize>,
+    /// Convert the read-write tensors as read-only, so that operations only differing by the last
+    /// use of their inputs have the same relative representation.
+    ignore_status: bool,
     scalar_f32: Vec<f32>,
     scalar_f16: Vec<f16>,
     scalar_bf16: Vec<bf16>,
@@ -156,6 +159,30 @@ trait RelativeOpsScalar<E: Element> {
 }
 
 impl OperationConverter {
+    /// Create a converter ignoring the [status](TensorStatus) of the read-write tensors, used to
+    /// compare operations.
+    pub(crate) fn ignoring_status() -> Self {
+        Self {
+            ignore_status: true,
+            ..Default::default()
+        }
+    }
+
+    /// If the scalars found by both converters are the same.
+    pub(crate) fn same_scalars(&self, other: &Self) -> bool {
+        self.scalar_f32 == other.scalar_f32
+            && self.scalar_f16 == other.scalar_f16
+            && self.scalar_bf16 == other.scalar_bf16
+            && self.scalar_i64 == other.scalar_i64
+            && self.scalar_i32 == other.scalar_i32
+            && self.scalar_i16 == other.scalar_i16
+            && self.scalar_i8 == other.scalar_i8
+            && self.scalar_u64 == other.scalar_u64
+            && self.scalar_u32 == other.scalar_u32
+            && self.scalar_u16 == other.scalar_u16
+            && self.scalar_u8 == other.scalar_u8
+    }
+
     pub(crate) fn context<'a, H>(
         &'a mut self,
         handles: &'a mut HandleContainer<H>,
@@ -510,6 +537,62 @@ impl RelativeOps for ModuleOperationDescription {
                     },
                 )
             }
+            ModuleOperationDescription::AvgPool3d(desc) => {
+                ModuleOperationDescription::AvgPool3d(AvgPool3dDescription {
+                    x: desc.x.to_relative(converter),
+                    kernel_size: desc.kernel_size,
+                    stride: desc.stride,
+                    padding: desc.padding,
+                    count_include_pad: desc.count_include_pad,
+                    out: desc.out.to_relative(converter),
+                })
+            }
+            ModuleOperationDescription::AvgPool3dBackward(desc) => {
+                ModuleOperationDescription::AvgPool3dBackward(AvgPool3dBackwardDescription {
+                    x: desc.x.to_relative(converter),
+                    grad: desc.grad.to_relative(converter),
+                    kernel_size: desc.kernel_size,
+                    stride: desc.stride,
+                    padding: desc.padding,
+                    count_include_pad: desc.count_include_pad,
+                    out: desc.out.to_relative(converter),
+                })
+            }
+            ModuleOperationDescription::MaxPool3d(desc) => {
+                ModuleOperationDescription::MaxPool3d(MaxPool3dDescription {
+                    x: desc.x.to_relative(converter),
+                    kernel_size: desc.kernel_size,
+                    stride: desc.stride,
+                    padding: desc.padding,
+                    dilation: desc.dilation,
+                    out: desc.out.to_relative(converter),
+                })
+            }
+            ModuleOperationDescription::MaxPool3dWithIndices(desc) => {
+                ModuleOperationDescription::MaxPool3dWithIndices(MaxPool3dWithIndicesDescription {
+                    x: desc.x.to_relative(converter),
+                    kernel_size: desc.kernel_size,
+                    stride: desc.stride,
+                    padding: desc.padding,
+                    dilation: desc.dilation,
+                    out: desc.out.to_relative(converter),
+                    out_indices: desc.out_indices.to_relative(converter),
+                })
+            }
+            ModuleOperationDescription::MaxPool3dWithIndicesBackward(desc) => {
+                ModuleOperationDescription::MaxPool3dWithIndicesBackward(
+                    MaxPool3dWithIndicesBackwardDescription {
+                        x: desc.x.to_relative(converter),
+                        grad: desc.grad.to_relative(converter),
+                        indices: desc.indices.to_relative(converter),
+                        kernel_size: desc.kernel_size,
+                        stride: desc.stride,
+                        padding: desc.padding,
+                        dilation: desc.dilation,
+                        out: desc.out.to_relative(converter),
+                    },
+                )
+            }
             ModuleOperationDescription::Interpolate(desc) => {
                 ModuleOperationDescription::Interpolate(InterpolateDescription {
                     x: desc.x.to_relative(converter),
@@ -527,6 +610,54 @@ impl RelativeOps for ModuleOperationDescription {
                     out: desc.out.to_relative(converter),
                 })
             }
+            ModuleOperationDescription::RmsNorm(desc) => {
+                ModuleOperationDescription::RmsNorm(RmsNormDescription {
+                    x: desc.x.to_relative(converter),
+                    gamma: desc.gamma.to_relative(converter),
+                    epsilon: desc.epsilon,
+                    out: desc.out.to_relative(converter),
+                })
+            }
+            ModuleOperationDescription::RmsNormBackward(desc) => {
+                ModuleOperationDescription::RmsNormBackward(RmsNormBackwardDescription {
+                    x: desc.x.to_relative(converter),
+                    gamma: desc.gamma.to_relative(converter),
+                    grad: desc.grad.to_relative(converter),
+                    epsilon: desc.epsilon,
+                    out_x_grad: desc.out_x_grad.to_relative(converter),
+                    out_gamma_grad: desc.out_gamma_grad.to_relative(converter),
+                })
+            }
+            ModuleOperationDescription::LayerNorm(desc) => {
+                ModuleOperationDescription::LayerNorm(LayerNormDescription {
+                    x: desc.x.to_relative(converter),
+                    gamma: desc.gamma.to_relative(converter),
+                    beta: desc.beta.as_ref().map(|t| t.to_relative(converter)),
+                    epsilon: desc.epsilon,
+                    out: desc.out.to_relative(converter),
+                })
+            }
+            ModuleOperationDescription::LayerNormBackward(desc) => {
+                ModuleOperationDescription::LayerNormBackward(LayerNormBackwardDescription {
+                    x: desc.x.to_relative(converter),
+                    gamma: desc.gamma.to_relative(converter),
+                    grad: desc.grad.to_relative(converter),
+                    epsilon: desc.epsilon,
+                    out_x_grad: desc.out_x_grad.to_relative(converter),
+                    out_gamma_grad: desc.out_gamma_grad.to_relative(converter),
+                    out_beta_grad: desc.out_beta_grad.to_relative(converter),
+                })
+            }
+            ModuleOperationDescription::RotaryQk(desc) => {
+                ModuleOperationDescription::RotaryQk(RotaryQkDescription {
+                    queries: desc.queries.to_relative(converter),
+                    keys: desc.keys.to_relative(converter),
+                    frequencies: desc.frequencies.to_relative(converter),
+                    start: desc.start,
+                    out_queries: desc.out_queries.to_relative(converter),
+                    out_keys: desc.out_keys.to_relative(converter),
+                })
+            }
         }
     }
 }
@@ -1245,11 +1376,16 @@ impl RelativeOps for TensorDescription {
             }
         }
 
+        let status = match (converter.ignore_status, &self.status) {
+            (true, TensorStatus::ReadWrite) => TensorStatus::ReadOnly,
+            (_, status) => status.clone(),
+        };
+
         // We create the relative tensor.
         let relative_tensor = TensorDescription {
             id: relative_id,
             shape: relative_shape,
-            status: self.status.clone(),
+            status,
             dtype: self.dtype,
         };
 
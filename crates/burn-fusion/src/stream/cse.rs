@@ -0,0 +1,359 @@
+use core::hash::BuildHasher;
+
+use burn_tensor::repr::{
+    FloatOperationDescription, NumericOperationDescription, OperationDescription,
+    TensorDescription, TensorId, TensorStatus,
+};
+use hashbrown::{DefaultHashBuilder, HashMap, HashSet};
+
+use super::{OperationConverter, RelativeOps};
+
+/// Detect the operations computing the same tensors as an operation already registered in a
+/// stream segment, so that their outputs can reuse the tensors computed by the first operation.
+///
+/// Two operations are the same when they have the same description over the same input tensors.
+/// Since the outputs of a reused operation are considered the same tensors as the outputs of the
+/// original operation, the operations on them can be reused as well.
+///
+/// Only the operations launching their own kernels, like matrix multiplications, convolutions or
+/// reductions, are reused. Element-wise operations are cheaper to recompute in the kernel fusing
+/// them than to read from memory, and reusing them would break their fusion.
+///
+/// Subgraphs computed only from constants aren't folded either. The operations creating constant
+/// tensors, like `full` or `ones`, and the element-wise operations on them are fused with the
+/// operations using them, so they are computed in the fused kernel without reading any memory.
+#[derive(Default)]
+pub(crate) struct CommonSubexpressions {
+    /// The outputs of the reused operations, mapped to the tensors they reuse.
+    aliases: HashMap<TensorId, TensorId>,
+    /// The positions in the segment of the reusable operations, by [key](Self::key).
+    positions: HashMap<u64, Vec<usize>>,
+    /// The tensors consumed by an operation of the segment.
+    consumed: HashSet<TensorId>,
+    hasher: DefaultHashBuilder,
+}
+
+impl CommonSubexpressions {
+    /// Find the outputs of an operation in the segment that computes the same tensors as the
+    /// given operation, in the same order as its outputs.
+    ///
+    /// The outputs are only returned when they can still be read after the whole segment, i.e.
+    /// they aren't consumed by a later operation.
+    pub(crate) fn find(
+        &self,
+        segment: &[OperationDescription],
+        operation: &OperationDescription,
+    ) -> Option<Vec<TensorDescription>> {
+        if !is_reusable(operation) {
+            return None;
+        }
+
+        let position = self
+            .positions
+            .get(&self.key(operation))?
+            .iter()
+            .find(|position| self.same(&segment[**position], operation))?;
+
+        let outputs = outputs(&segment[*position]);
+        if outputs
+            .iter()
+            .any(|output| self.consumed.contains(&output.id))
+        {
+            return None;
+        }
+
+        Some(outputs.into_iter().cloned().collect())
+    }
+
+    /// Register the operation added to the segment at the given position.
+    pub(crate) fn push(&mut self, position: usize, operation: &OperationDescription) {
+        for node in operation.nodes() {
+            if node.status == TensorStatus::ReadWrite {
+                self.consumed.insert(node.id);
+            }
+        }
+
+        if is_reusable(operation) {
+            let key = self.key(operation);
+            self.positions.entry(key).or_default().push(position);
+        }
+    }
+
+    /// Register the outputs of an operation that reuse the given tensors.
+    pub(crate) fn register(
+        &mut self,
+        outputs: &[&TensorDescription],
+        reused: &[TensorDescription],
+    ) {
+        for (output, reused) in outputs.iter().zip(reused) {
+            let id = self.resolve(&reused.id);
+            self.aliases.insert(output.id, id);
+        }
+    }
+
+    /// Forget the reused tensors that aren't part of the segment anymore, and index the
+    /// operations left in the segment.
+    pub(crate) fn retain(&mut self, segment: &[OperationDescription]) {
+        self.positions.clear();
+        self.consumed.clear();
+
+        if segment.is_empty() {
+            self.aliases.clear();
+            return;
+        }
+
+        let ids = segment
+            .iter()
+            .flat_map(|desc| desc.nodes())
+            .map(|node| node.id)
+            .collect::<HashSet<_>>();
+
+        self.aliases.retain(|_, id| ids.contains(id));
+
+        for (position, operation) in segment.iter().enumerate() {
+            self.push(position, operation);
+        }
+    }
+
+    fn resolve(&self, id: &TensorId) -> TensorId {
+        *self.aliases.get(id).unwrap_or(id)
+    }
+
+    /// The key of an operation, which is the same for operations with the same relative
+    /// description over the same input tensors.
+    fn key(&self, operation: &OperationDescription) -> u64 {
+        let mut converter = OperationConverter::ignoring_status();
+        let relative = operation.to_relative(&mut converter);
+        let inputs = operation
+            .nodes()
+            .into_iter()
+            .filter(|node| node.status != TensorStatus::NotInit)
+            .map(|node| self.resolve(&node.id))
+            .collect::<Vec<_>>();
+
+        self.hasher.hash_one((relative, inputs))
+    }
+
+    fn same(&self, lhs: &OperationDescription, rhs: &OperationDescription) -> bool {
+        let nodes_lhs = lhs.nodes();
+        let nodes_rhs = rhs.nodes();
+
+        if nodes_lhs.len() != nodes_rhs.len() {
+            return false;
+        }
+
+        // Outputs must have the same shapes, inputs must be the same tensors.
+        for (a, b) in nodes_lhs.iter().zip(nodes_rhs.iter()) {
+            if a.shape != b.shape || a.dtype != b.dtype {
+                return false;
+            }
+
+            let same_tensor = match (&a.status, &b.status) {
+                (TensorStatus::NotInit, TensorStatus::NotInit) => true,
+                (TensorStatus::NotInit, _) | (_, TensorStatus::NotInit) => false,
+                _ => self.resolve(&a.id) == self.resolve(&b.id),
+            };
+
+            if !same_tensor {
+                return false;
+            }
+        }
+
+        // The rest of the descriptions, including the scalars, must be the same.
+        let mut converter_lhs = OperationConverter::ignoring_status();
+        let mut converter_rhs = OperationConverter::ignoring_status();
+        let relative_lhs = lhs.to_relative(&mut converter_lhs);
+        let relative_rhs = rhs.to_relative(&mut converter_rhs);
+
+        relative_lhs == relative_rhs && converter_lhs.same_scalars(&converter_rhs)
+    }
+}
+
+/// The tensors created by an operation.
+pub(crate) fn outputs(operation: &OperationDescription) -> Vec<&TensorDescription> {
+    operation
+        .nodes()
+        .into_iter()
+        .filter(|node| node.status == TensorStatus::NotInit)
+        .collect()
+}
+
+/// If the operation launches its own kernel, and its result only depends on its description and
+/// its inputs.
+fn is_reusable(operation: &OperationDescription) -> bool {
+    match operation {
+        OperationDescription::Module(_) => true,
+        OperationDescription::Float(_, FloatOperationDescription::Matmul(_)) => true,
+        OperationDescription::NumericFloat(_, op) => is_reusable_numeric(op),
+        OperationDescription::NumericInt(_, op) => is_reusable_numeric(op),
+        _ => false,
+    }
+}
+
+fn is_reusable_numeric<E>(operation: &NumericOperationDescription<E>) -> bool {
+    matches!(
+        operation,
+        NumericOperationDescription::Gather(_)
+            | NumericOperationDescription::Select(_)
+            | NumericOperationDescription::Mean(_)
+            | NumericOperationDescription::MeanDim(_)
+            | NumericOperationDescription::Sum(_)
+            | NumericOperationDescription::SumDim(_)
+            | NumericOperationDescription::Prod(_)
+            | NumericOperationDescription::ProdDim(_)
+            | NumericOperationDescription::Max(_)
+            | NumericOperationDescription::MaxDim(_)
+            | NumericOperationDescription::MaxDimWithIndices(_)
+            | NumericOperationDescription::Min(_)
+            | NumericOperationDescription::MinDim(_)
+            | NumericOperationDescription::MinDimWithIndices(_)
+            | NumericOperationDescription::ArgMax(_)
+            | NumericOperationDescription::ArgMin(_)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn_tensor::{
+        repr::{ScalarOperationDescription, UnaryOperationDescription},
+        DType,
+    };
+
+    #[test]
+    fn should_reuse_same_operation_on_same_inputs() {
+        let (cse, segment) = segment(vec![sum(0, TensorStatus::ReadOnly, 1)]);
+
+        let reused = cse.find(&segment, &sum(0, TensorStatus::ReadWrite, 2));
+
+        assert_eq!(reused.unwrap()[0].id, TensorId::new(1));
+        assert!(cse
+            .find(&segment, &sum(3, TensorStatus::ReadOnly, 2))
+            .is_none());
+        assert!(cse.find(&segment, &sum_dim(0, 0, 2)).is_none());
+    }
+
+    #[test]
+    fn should_not_reuse_operation_with_different_scalars() {
+        let (cse, segment) = segment(vec![sum_dim(0, 0, 1)]);
+
+        assert!(cse.find(&segment, &sum_dim(0, 1, 2)).is_none());
+        assert!(cse.find(&segment, &sum_dim(0, 0, 2)).is_some());
+    }
+
+    #[test]
+    fn should_not_reuse_element_wise_operations() {
+        let (cse, segment) = segment(vec![exp(0, TensorStatus::ReadOnly, 1)]);
+
+        assert!(cse
+            .find(&segment, &exp(0, TensorStatus::ReadOnly, 2))
+            .is_none());
+    }
+
+    #[test]
+    fn should_reuse_subgraph_computed_twice() {
+        let (mut cse, mut segment) =
+            segment(vec![sum(0, TensorStatus::ReadOnly, 1), sum_dim(1, 0, 2)]);
+
+        // The second sum reuses the first one.
+        let operation = sum(0, TensorStatus::ReadOnly, 3);
+        let reused = cse.find(&segment, &operation).unwrap();
+        cse.register(&outputs(&operation), &reused);
+        cse.push(segment.len(), &operation);
+        segment.push(operation);
+
+        // So the operations on the second sum reuse the ones on the first sum.
+        let reused = cse.find(&segment, &sum_dim(3, 0, 4));
+        assert_eq!(reused.unwrap()[0].id, TensorId::new(2));
+    }
+
+    #[test]
+    fn should_not_reuse_consumed_outputs() {
+        let (cse, segment) = segment(vec![
+            sum(0, TensorStatus::ReadOnly, 1),
+            exp(1, TensorStatus::ReadWrite, 2),
+        ]);
+
+        assert!(cse
+            .find(&segment, &sum(0, TensorStatus::ReadOnly, 3))
+            .is_none());
+    }
+
+    #[test]
+    fn should_index_operations_left_after_drain() {
+        let (mut cse, mut segment) = segment(vec![
+            sum(0, TensorStatus::ReadOnly, 1),
+            sum(2, TensorStatus::ReadOnly, 3),
+        ]);
+
+        segment.remove(0);
+        cse.retain(&segment);
+
+        assert!(cse
+            .find(&segment, &sum(0, TensorStatus::ReadOnly, 4))
+            .is_none());
+        let reused = cse.find(&segment, &sum(2, TensorStatus::ReadOnly, 4));
+        assert_eq!(reused.unwrap()[0].id, TensorId::new(3));
+    }
+
+    fn segment(
+        operations: Vec<OperationDescription>,
+    ) -> (CommonSubexpressions, Vec<OperationDescription>) {
+        let mut cse = CommonSubexpressions::default();
+
+        for (position, operation) in operations.iter().enumerate() {
+            cse.push(position, operation);
+        }
+
+        (cse, operations)
+    }
+
+    fn tensor(id: u64, status: TensorStatus) -> TensorDescription {
+        TensorDescription {
+            id: TensorId::new(id),
+            shape: vec![32, 32],
+            status,
+            dtype: DType::F32,
+        }
+    }
+
+    fn exp(input: u64, status: TensorStatus, out: u64) -> OperationDescription {
+        OperationDescription::Float(
+            DType::F32,
+            FloatOperationDescription::Exp(UnaryOperationDescription {
+                input: tensor(input, status),
+                out: tensor(out, TensorStatus::NotInit),
+            }),
+        )
+    }
+
+    fn sum(input: u64, status: TensorStatus, out: u64) -> OperationDescription {
+        OperationDescription::NumericFloat(
+            DType::F32,
+            NumericOperationDescription::Sum(UnaryOperationDescription {
+                input: tensor(input, status),
+                out: TensorDescription {
+                    shape: vec![1],
+                    ..tensor(out, TensorStatus::NotInit)
+                },
+            }),
+        )
+    }
+
+    fn sum_dim(lhs: u64, dim: usize, out: u64) -> OperationDescription {
+        let mut shape = vec![32, 32];
+        shape[dim] = 1;
+
+        OperationDescription::NumericFloat(
+            DType::F32,
+            NumericOperationDescription::SumDim(ScalarOperationDescription {
+                lhs: tensor(lhs, TensorStatus::ReadOnly),
+                rhs: dim,
+                out: TensorDescription {
+                    shape,
+                    ..tensor(out, TensorStatus::NotInit)
+                },
+            }),
+        )
+    }
+}
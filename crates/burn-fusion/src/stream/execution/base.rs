@@ -73,6 +73,7 @@ impl<R: FusionRuntime> OperationQueue<R> {
             .for_each(|tensor| handles.free(tensor));
 
         self.global.drain(0..num_drained);
+        self.subexpressions.retain(&self.global);
         self.reset_relative();
     }
 
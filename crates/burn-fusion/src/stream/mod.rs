@@ -3,6 +3,7 @@ pub(crate) mod store;
 
 mod base;
 mod context;
+mod cse;
 mod multi;
 
 pub use base::*;
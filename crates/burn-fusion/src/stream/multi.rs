@@ -76,6 +76,16 @@ impl<R: FusionRuntime> MultiStream<R> {
         }
     }
 
+    /// Drain all the streams, including the streams of the [scopes](StreamId::scope) that
+    /// ended without their tensors being used by another stream.
+    pub fn drain_all(&mut self, handles: &mut HandleContainer<R::FusionHandle>) {
+        let ids = self.streams.keys().copied().collect::<Vec<_>>();
+
+        for id in ids {
+            self.drain(handles, id);
+        }
+    }
+
     /// When one of the provided streams is different from the current stream, we drain them.
     ///
     /// Returns the current stream id.
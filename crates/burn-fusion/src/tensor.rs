@@ -4,7 +4,10 @@ use burn_tensor::{
     repr::{TensorDescription, TensorId, TensorStatus},
     DType, Shape, TensorData, TensorMetadata,
 };
-use std::{future::Future, sync::Arc};
+use std::{
+    future::Future,
+    sync::{Arc, Weak},
+};
 
 /// Tensor primitive for the [fusion backend](crate::FusionBackend) for all kind.
 pub struct FusionTensor<R: FusionRuntime> {
@@ -23,6 +26,23 @@ pub struct FusionTensor<R: FusionRuntime> {
     // When a tensor is dropped and is still an orphan, we need to register it as such to avoid
     // memory leak. Otherwise, the cleanup is going to happen during a graph execution.
     pub(crate) is_orphan: bool,
+    /// The tensor this tensor is a permuted view of, if any.
+    pub(crate) view: Option<Arc<PermutedView>>,
+}
+
+/// A tensor obtained by permuting the dimensions of another tensor.
+///
+/// While the permuted tensor is alive, consecutive permutations are applied directly to it, and
+/// permutations cancelling each other are removed altogether. The view doesn't keep the permuted
+/// tensor alive, so its buffer can be freed as soon as it isn't used anymore.
+pub(crate) struct PermutedView {
+    /// The id of the permuted tensor, which is never a view itself.
+    id: Weak<TensorId>,
+    shape: Vec<usize>,
+    dtype: DType,
+    stream: StreamId,
+    /// The axes of the permutation.
+    axes: Vec<usize>,
 }
 
 impl<R: FusionRuntime> Clone for FusionTensor<R> {
@@ -34,6 +54,7 @@ impl<R: FusionRuntime> Clone for FusionTensor<R> {
             dtype: self.dtype,
             is_orphan: self.is_orphan,
             stream: self.stream,
+            view: self.view.clone(),
         }
     }
 }
@@ -78,9 +99,46 @@ impl<R: FusionRuntime> FusionTensor<R> {
             dtype,
             is_orphan: true,
             stream,
+            view: None,
         }
     }
 
+    /// Returns the tensor to permute with the given axes.
+    ///
+    /// When the tensor is a permuted view of a tensor that is still alive, the permutations are
+    /// composed so that the tensor it is a view of is permuted directly.
+    pub(crate) fn resolve_permutation(self, axes: &[usize]) -> (Self, Vec<usize>) {
+        let Some(view) = &self.view else {
+            return (self, axes.to_vec());
+        };
+        let Some(id) = view.id.upgrade() else {
+            return (self, axes.to_vec());
+        };
+
+        let axes = axes.iter().map(|axis| view.axes[*axis]).collect();
+        let source = Self::new(
+            id,
+            view.shape.clone(),
+            view.dtype,
+            self.client.clone(),
+            view.stream,
+        );
+
+        (source, axes)
+    }
+
+    /// Marks the tensor as a view of the source tensor permuted with the given axes.
+    pub(crate) fn with_view(mut self, source: &Self, axes: Vec<usize>) -> Self {
+        self.view = Some(Arc::new(PermutedView {
+            id: Arc::downgrade(&source.id),
+            shape: source.shape.clone(),
+            dtype: source.dtype,
+            stream: source.stream,
+            axes,
+        }));
+        self
+    }
+
     fn status(&self) -> TensorStatus {
         if Arc::strong_count(&self.id) <= 1 {
             TensorStatus::ReadWrite
@@ -190,3 +248,8 @@ impl<R: FusionRuntime> QTensorPrimitive for FusionTensor<R> {
         }
     }
 }
+
+/// If permuting with the given axes leaves the tensor unchanged.
+pub(crate) fn is_identity_permutation(axes: &[usize]) -> bool {
+    axes.iter().enumerate().all(|(i, axis)| i == *axis)
+}
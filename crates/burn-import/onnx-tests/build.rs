@@ -88,6 +88,7 @@ fn main() {
         .input("tests/reduce_sum/reduce_sum_opset13.onnx")
         .input("tests/relu/relu.onnx")
         .input("tests/reshape/reshape.onnx")
+        .input("tests/reshape/reshape_shape.onnx")
         .input("tests/resize/resize_with_sizes.onnx")
         .input("tests/resize/resize_1d_linear_scale.onnx")
         .input("tests/resize/resize_1d_nearest_scale.onnx")
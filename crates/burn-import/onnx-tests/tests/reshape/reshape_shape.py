@@ -0,0 +1,67 @@
+#!/usr/bin/env python3
+
+# used to generate model: reshape_shape.onnx
+
+# The batch size is a symbolic dim, so the target shape of the Reshape comes from
+# a Shape node instead of a constant and is only known at runtime.
+# Hence this model is exported using onnx directly
+
+import onnx
+import onnx.helper
+
+
+def build_model():
+    return onnx.helper.make_model(
+        ir_version=8,
+        opset_imports=[onnx.helper.make_operatorsetid("", 16)],
+        graph=onnx.helper.make_graph(name="main_graph", nodes=[
+            onnx.helper.make_node(
+                "Shape",
+                inputs=["input2"],
+                outputs=["shape1"],
+                name="/Shape"
+            ),
+            onnx.helper.make_node(
+                "Reshape",
+                inputs=["input1", "shape1"],
+                outputs=["output1"],
+                name="/Reshape"
+            ),
+        ],
+        inputs=[
+            onnx.helper.make_value_info(
+                name="input1",
+                type_proto=onnx.helper.make_tensor_type_proto(
+                    elem_type=onnx.TensorProto.FLOAT, shape=["batch", 6]
+                ),
+            ),
+            onnx.helper.make_value_info(
+                name="input2",
+                type_proto=onnx.helper.make_tensor_type_proto(
+                    elem_type=onnx.TensorProto.FLOAT, shape=["batch", 2, 3]
+                ),
+            ),
+        ],
+        outputs=[
+            onnx.helper.make_value_info(
+                name="output1",
+                type_proto=onnx.helper.make_tensor_type_proto(
+                    elem_type=onnx.TensorProto.FLOAT, shape=["batch", 2, 3]
+                ),
+            )
+        ]),
+    )
+
+
+def main():
+    onnx_model = build_model()
+    file_name = "reshape_shape.onnx"
+
+    # Ensure valid ONNX:
+    onnx.checker.check_model(onnx_model)
+
+    onnx.save(onnx_model, file_name)
+
+
+if __name__ == "__main__":
+    main()
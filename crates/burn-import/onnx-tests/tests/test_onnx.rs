@@ -97,6 +97,7 @@ include_models!(
     reduce_sum_opset13,
     relu,
     reshape,
+    reshape_shape,
     resize_with_sizes,
     resize_1d_linear_scale,
     resize_1d_nearest_scale,
@@ -948,6 +949,27 @@ mod tests {
         output.to_data().assert_eq(&expected, true);
     }
 
+    #[test]
+    fn reshape_shape() {
+        // The batch size is a symbolic dim, so the target shape is read at runtime
+        let device = Default::default();
+        let model: reshape_shape::Model<Backend> = reshape_shape::Model::new(&device);
+
+        for batch_size in [1, 4] {
+            let input1 = Tensor::<Backend, 1, Int>::arange(0..batch_size * 6, &device)
+                .float()
+                .reshape([batch_size as usize, 6]);
+            let input2 = Tensor::<Backend, 3>::zeros([batch_size as usize, 2, 3], &device);
+
+            let output = model.forward(input1.clone(), input2);
+
+            assert_eq!(output.shape(), Shape::from([batch_size as usize, 2, 3]));
+            output
+                .to_data()
+                .assert_eq(&input1.reshape([batch_size as usize, 2, 3]).to_data(), true);
+        }
+    }
+
     #[test]
     fn resize_with_sizes() {
         // Initialize the model without weights (because the exported file does not contain them)
@@ -0,0 +1,96 @@
+use burn::{
+    module::Module,
+    nn::conv::{Conv2d, Conv2dConfig},
+    tensor::backend::Backend,
+};
+
+#[derive(Module, Debug)]
+pub struct Net<B: Backend> {
+    conv1: Conv2d<B>,
+    conv2: Conv2d<B>,
+}
+
+impl<B: Backend> Net<B> {
+    /// Create a new model.
+    pub fn init(device: &B::Device) -> Self {
+        let conv1 = Conv2dConfig::new([2, 2], [2, 2]).init(device);
+        let conv2 = Conv2dConfig::new([2, 2], [2, 2])
+            .with_bias(false)
+            .init(device);
+        Self { conv1, conv2 }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    type TestBackend = burn_ndarray::NdArray<f32>;
+
+    use burn::record::{FullPrecisionSettings, Recorder};
+    use burn_import::pytorch::{LoadArgs, PyTorchFileRecorder};
+
+    use super::*;
+
+    fn load(load_args: LoadArgs) -> Net<TestBackend> {
+        let device = Default::default();
+        let record = PyTorchFileRecorder::<FullPrecisionSettings>::default()
+            .load(load_args, &device)
+            .expect("Should decode state successfully");
+
+        Net::<TestBackend>::init(&device).load_record(record)
+    }
+
+    #[test]
+    fn split_and_concat_should_restore_the_tensors() {
+        let expected = load(
+            LoadArgs::new("tests/key_remap/key_remap.pt".into())
+                .with_key_remap("conv\\.(.*)", "$1"),
+        );
+
+        let model = load(
+            LoadArgs::new("tests/key_remap/key_remap.pt".into())
+                .with_key_remap("conv\\.(.*)", "$1")
+                // Split the output channels, e.g. "conv1.weight" -> "conv1.weight.0"
+                .with_split("^(conv1\\.weight)$", 0, &["$1.0", "$1.1"])
+                // Merge them back, e.g. "conv1.weight.0" -> "conv1.weight"
+                .with_concat("^(conv1\\.weight)\\.(?<shard>[0-9]+)$", 0, "$1")
+                // Transposing twice keeps the tensor unchanged
+                .with_transpose("^conv2\\.weight$")
+                .with_transpose("^conv2\\.weight$")
+                .with_debug_print(),
+        );
+
+        model
+            .conv1
+            .weight
+            .val()
+            .to_data()
+            .assert_eq(&expected.conv1.weight.val().to_data(), true);
+        model
+            .conv2
+            .weight
+            .val()
+            .to_data()
+            .assert_eq(&expected.conv2.weight.val().to_data(), true);
+    }
+
+    #[test]
+    fn transpose_should_swap_the_last_dimensions() {
+        let expected = load(
+            LoadArgs::new("tests/key_remap/key_remap.pt".into())
+                .with_key_remap("conv\\.(.*)", "$1"),
+        );
+
+        let model = load(
+            LoadArgs::new("tests/key_remap/key_remap.pt".into())
+                .with_key_remap("conv\\.(.*)", "$1")
+                .with_transpose("^conv2\\.weight$"),
+        );
+
+        model
+            .conv2
+            .weight
+            .val()
+            .to_data()
+            .assert_eq(&expected.conv2.weight.val().swap_dims(2, 3).to_data(), true);
+    }
+}
@@ -13,6 +13,7 @@ mod group_norm;
 mod integer;
 mod key_remap;
 mod key_remap_chained;
+mod key_remap_rules;
 mod layer_norm;
 mod linear;
 mod missing_module_field;
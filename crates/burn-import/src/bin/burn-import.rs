@@ -0,0 +1,91 @@
+use std::path::PathBuf;
+
+use burn_import::onnx::{ModelGen, RecordType};
+use clap::{Parser, ValueEnum};
+
+/// Convert an ONNX model to a Rust module and a record in one shot, without a build script.
+///
+/// The weights of PyTorch and safetensors files are loaded with the layout of the modules of the
+/// record they are loaded into, so they can't be converted without the model: load them with
+/// `PyTorchFileRecorder` or `SafetensorsFileRecorder` into the record of your model instead.
+#[derive(Parser, Debug)]
+#[command(name = "burn-import", version, about)]
+struct Args {
+    /// The ONNX model to convert (`.onnx`).
+    #[arg(value_parser = parse_onnx)]
+    input: PathBuf,
+
+    /// The directory where the Rust module and the record are written.
+    #[arg(short, long, default_value = ".")]
+    out_dir: PathBuf,
+
+    /// The precision of the weights.
+    #[arg(short, long, value_enum, default_value_t = Precision::Full)]
+    precision: Precision,
+
+    /// The format of the record.
+    #[arg(short, long, value_enum, default_value_t = RecordFormat::NamedMpk)]
+    record_type: RecordFormat,
+
+    /// Embed the weights in the generated source code.
+    #[arg(long)]
+    embed_states: bool,
+
+    /// Also write the parsed ONNX graph, for debugging.
+    #[arg(long)]
+    development: bool,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum Precision {
+    Full,
+    Half,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum RecordFormat {
+    NamedMpk,
+    NamedMpkGz,
+    Bincode,
+    PrettyJson,
+}
+
+impl From<RecordFormat> for RecordType {
+    fn from(format: RecordFormat) -> Self {
+        match format {
+            RecordFormat::NamedMpk => RecordType::NamedMpk,
+            RecordFormat::NamedMpkGz => RecordType::NamedMpkGz,
+            RecordFormat::Bincode => RecordType::Bincode,
+            RecordFormat::PrettyJson => RecordType::PrettyJson,
+        }
+    }
+}
+
+/// Reject the weights files, which can only be converted once loaded into the record of a model.
+fn parse_onnx(input: &str) -> Result<PathBuf, String> {
+    let input = PathBuf::from(input);
+
+    match input.extension().and_then(|extension| extension.to_str()) {
+        Some("pt") | Some("pth") | Some("safetensors") | Some("json") => Err(format!(
+            "{} contains weights without the model, which can't be converted without the layout \
+             of its modules: load it with PyTorchFileRecorder or SafetensorsFileRecorder into the \
+             record of your model, or export the model to ONNX",
+            input.display()
+        )),
+        _ => Ok(input),
+    }
+}
+
+/// Takes an ONNX file and generates a Rust module and a record from it
+fn main() {
+    let args = Args::parse();
+
+    ModelGen::new()
+        .input(args.input.to_str().expect("Valid input path"))
+        .out_dir(args.out_dir.to_str().expect("Valid output directory"))
+        .development(args.development)
+        .half_precision(matches!(args.precision, Precision::Half))
+        .record_type(args.record_type.into())
+        .embed_states(args.embed_states)
+        .run_from_cli();
+}
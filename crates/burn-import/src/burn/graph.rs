@@ -1,7 +1,7 @@
 use super::{BurnImports, Scope, Type};
 use crate::burn::{
     node::{Node, NodeCodegen},
-    TensorKind, TensorType,
+    SequenceType, TensorKind, TensorType,
 };
 use burn::record::{
     BinFileRecorder, BurnRecord, FileRecorder, NamedMpkFileRecorder, NamedMpkGzFileRecorder,
@@ -270,12 +270,28 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
                 Type::Tensor(TensorType {
                     kind: TensorKind::Bool,
                     ..
+                })
+                | Type::Sequence(SequenceType {
+                    tensor:
+                        TensorType {
+                            kind: TensorKind::Bool,
+                            ..
+                        },
+                    ..
                 }) => {
                     self.imports.register("burn::tensor::Bool");
                 }
                 Type::Tensor(TensorType {
                     kind: TensorKind::Int,
                     ..
+                })
+                | Type::Sequence(SequenceType {
+                    tensor:
+                        TensorType {
+                            kind: TensorKind::Int,
+                            ..
+                        },
+                    ..
                 }) => {
                     self.imports.register("burn::tensor::Int");
                 }
@@ -293,6 +309,9 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
                 Type::Scalar(_) => None,
                 Type::Other(_) => None,
                 Type::Shape(_) => None,
+                // The sequence is reference counted like a tensor, since it's cloned when used
+                // more than once.
+                Type::Sequence(sequence) => Some(sequence.tensor),
             }
         }
 
@@ -3,18 +3,21 @@ use std::marker::PhantomData;
 use super::{
     argmax::ArgMaxNode, avg_pool1d::AvgPool1dNode, avg_pool2d::AvgPool2dNode,
     batch_norm::BatchNormNode, binary::BinaryNode, clip::ClipNode, concat::ConcatNode,
-    constant::ConstantNode, constant_of_shape::ConstantOfShapeNode, conv1d::Conv1dNode,
-    conv2d::Conv2dNode, conv3d::Conv3dNode, conv_transpose_1d::ConvTranspose1dNode,
+    concat_from_sequence::ConcatFromSequenceNode, constant::ConstantNode,
+    constant_of_shape::ConstantOfShapeNode, conv1d::Conv1dNode, conv2d::Conv2dNode,
+    conv3d::Conv3dNode, conv_transpose_1d::ConvTranspose1dNode,
     conv_transpose_2d::ConvTranspose2dNode, conv_transpose_3d::ConvTranspose3dNode,
     dropout::DropoutNode, expand::ExpandNode, gather::GatherNode,
     gather_elements::GatherElementsNode, global_avg_pool::GlobalAvgPoolNode,
     layer_norm::LayerNormNode, linear::LinearNode, mask_where::WhereNode, matmul::MatmulNode,
-    max_pool1d::MaxPool1dNode, max_pool2d::MaxPool2dNode, mean::MeanNode, pad::PadNode,
-    prelu::PReluNode, random_normal::RandomNormalNode, random_normal_like::RandomNormalLikeNode,
+    max_pool1d::MaxPool1dNode, max_pool2d::MaxPool2dNode, mean::MeanNode,
+    non_max_suppression::NonMaxSuppressionNode, pad::PadNode, prelu::PReluNode,
+    random_normal::RandomNormalNode, random_normal_like::RandomNormalLikeNode,
     random_uniform::RandomUniformNode, random_uniform_like::RandomUniformLikeNode,
-    range::RangeNode, reshape::ReshapeNode, resize::ResizeNode, slice::SliceNode,
-    squeeze::SqueezeNode, sum::SumNode, tile::TileNode, trilu::TriluNode, unary::UnaryNode,
-    unsqueeze::UnsqueezeNode,
+    range::RangeNode, reshape::ReshapeNode, resize::ResizeNode, roi_align::RoiAlignNode,
+    sequence_at::SequenceAtNode, sequence_construct::SequenceConstructNode, slice::SliceNode,
+    split_to_sequence::SplitToSequenceNode, squeeze::SqueezeNode, sum::SumNode, tile::TileNode,
+    trilu::TriluNode, unary::UnaryNode, unsqueeze::UnsqueezeNode,
 };
 use crate::burn::{BurnImports, Scope, Type};
 use burn::record::PrecisionSettings;
@@ -90,6 +93,7 @@ pub enum Node<PS: PrecisionSettings> {
     Binary(BinaryNode),
     Clip(ClipNode),
     Concat(ConcatNode),
+    ConcatFromSequence(ConcatFromSequenceNode),
     Constant(ConstantNode),
     Conv1d(Conv1dNode),
     Conv2d(Conv2dNode),
@@ -109,11 +113,16 @@ pub enum Node<PS: PrecisionSettings> {
     MaxPool1d(MaxPool1dNode),
     MaxPool2d(MaxPool2dNode),
     Mean(MeanNode),
+    NonMaxSuppression(NonMaxSuppressionNode),
     Pad(PadNode),
     Range(RangeNode),
     Reshape(ReshapeNode),
     Resize(ResizeNode),
+    RoiAlign(RoiAlignNode),
+    SequenceAt(SequenceAtNode),
+    SequenceConstruct(SequenceConstructNode),
     Slice(SliceNode),
+    SplitToSequence(SplitToSequenceNode),
     Squeeze(SqueezeNode),
     Sum(SumNode),
     Tile(TileNode),
@@ -142,6 +151,7 @@ macro_rules! match_all {
             Node::Binary(node) => $func(node),
             Node::Clip(node) => $func(node),
             Node::Concat(node) => $func(node),
+            Node::ConcatFromSequence(node) => $func(node),
             Node::Constant(node) => $func(node),
             Node::Conv1d(node) => $func(node),
             Node::Conv2d(node) => $func(node),
@@ -161,11 +171,16 @@ macro_rules! match_all {
             Node::MaxPool1d(node) => $func(node),
             Node::MaxPool2d(node) => $func(node),
             Node::Mean(node) => $func(node),
+            Node::NonMaxSuppression(node) => $func(node),
             Node::Pad(node) => $func(node),
             Node::Range(node) => $func(node),
             Node::Reshape(node) => $func(node),
             Node::Resize(node) => $func(node),
+            Node::RoiAlign(node) => $func(node),
+            Node::SequenceAt(node) => $func(node),
+            Node::SequenceConstruct(node) => $func(node),
             Node::Slice(node) => $func(node),
+            Node::SplitToSequence(node) => $func(node),
             Node::Squeeze(node) => $func(node),
             Node::Sum(node) => $func(node),
             Node::Tile(node) => $func(node),
@@ -201,6 +216,7 @@ impl<PS: PrecisionSettings> Node<PS> {
             Node::BatchNorm(_) => "batch_norm",
             Node::Binary(binary) => binary.binary_type.as_str(),
             Node::Concat(_) => "concat",
+            Node::ConcatFromSequence(_) => "concat_from_sequence",
             Node::Clip(_) => "clip",
             Node::Constant(_) => "constant",
             Node::Conv1d(_) => "conv1d",
@@ -221,11 +237,16 @@ impl<PS: PrecisionSettings> Node<PS> {
             Node::MaxPool1d(_) => "max_pool1d",
             Node::MaxPool2d(_) => "max_pool2d",
             Node::Mean(_) => "mean",
+            Node::NonMaxSuppression(_) => "non_max_suppression",
             Node::Pad(_) => "pad",
             Node::Range(_) => "range",
             Node::Reshape(_) => "reshape",
             Node::Resize(_) => "resize",
+            Node::RoiAlign(_) => "roi_align",
+            Node::SequenceAt(_) => "sequence_at",
+            Node::SequenceConstruct(_) => "sequence_construct",
             Node::Slice(_) => "slice",
+            Node::SplitToSequence(_) => "split_to_sequence",
             Node::Squeeze(_) => "squeeze",
             Node::Sum(_) => "add",
             Node::Tile(_) => "tile",
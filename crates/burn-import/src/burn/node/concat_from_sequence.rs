@@ -0,0 +1,115 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, SequenceType, TensorType, ToTokens, Type};
+
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+#[derive(Debug, Clone, new)]
+pub struct ConcatFromSequenceNode {
+    pub input: SequenceType,
+    pub output: TensorType,
+    pub dim: usize,
+    /// Stack the tensors along a new dimension instead of concatenating them.
+    pub new_axis: bool,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for ConcatFromSequenceNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Sequence(self.input.clone())]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input.tensor, node_position);
+        let output = &self.output.name;
+        let dim = self.dim.to_tokens();
+
+        if self.new_axis {
+            let output_dim = self.output.dim.to_tokens();
+            quote! {
+                let #output = burn::tensor::Tensor::stack::<#output_dim>(#input, #dim);
+            }
+        } else {
+            quote! {
+                let #output = burn::tensor::Tensor::cat(#input, #dim);
+            }
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::ConcatFromSequence(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph, node::test::assert_tokens, SequenceType, TensorKind, TensorType,
+    };
+
+    #[test]
+    fn test_codegen_concat_from_sequence() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(ConcatFromSequenceNode::new(
+            SequenceType::new("sequence", 2, TensorKind::Float),
+            TensorType::new_float("tensor1", 2),
+            1,
+            false,
+        ));
+        graph.register(ConcatFromSequenceNode::new(
+            SequenceType::new("sequence", 2, TensorKind::Float),
+            TensorType::new_float("tensor2", 3),
+            0,
+            true,
+        ));
+
+        graph.register_input_output(
+            vec!["sequence".to_string()],
+            vec!["tensor1".to_string(), "tensor2".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model<B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(
+                    &self,
+                    sequence: Vec<Tensor<B, 2> >
+                ) -> (Tensor<B, 2>, Tensor<B, 3>) {
+                    let tensor1 = burn::tensor::Tensor::cat(sequence.clone(), 1);
+                    let tensor2 = burn::tensor::Tensor::stack::<3>(sequence, 0);
+
+                    (tensor1, tensor2)
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
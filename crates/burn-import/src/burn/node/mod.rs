@@ -7,6 +7,7 @@ pub(crate) mod batch_norm;
 pub(crate) mod binary;
 pub(crate) mod clip;
 pub(crate) mod concat;
+pub(crate) mod concat_from_sequence;
 pub(crate) mod constant;
 pub(crate) mod constant_of_shape;
 pub(crate) mod conv1d;
@@ -27,6 +28,7 @@ pub(crate) mod matmul;
 pub(crate) mod max_pool1d;
 pub(crate) mod max_pool2d;
 pub(crate) mod mean;
+pub(crate) mod non_max_suppression;
 pub(crate) mod pad;
 pub(crate) mod prelu;
 pub(crate) mod random_normal;
@@ -36,7 +38,11 @@ pub(crate) mod random_uniform_like;
 pub(crate) mod range;
 pub(crate) mod reshape;
 pub(crate) mod resize;
+pub(crate) mod roi_align;
+pub(crate) mod sequence_at;
+pub(crate) mod sequence_construct;
 pub(crate) mod slice;
+pub(crate) mod split_to_sequence;
 pub(crate) mod squeeze;
 pub(crate) mod sum;
 pub(crate) mod tile;
@@ -8,7 +8,13 @@ use quote::quote;
 pub struct ReshapeNode {
     pub input: TensorType,
     pub output: TensorType,
-    pub shape: Vec<i64>,
+    pub shape: ReshapeShape,
+}
+
+#[derive(Debug, Clone)]
+pub enum ReshapeShape {
+    Static(Vec<i64>),
+    Runtime(Type),
 }
 
 impl<PS: PrecisionSettings> NodeCodegen<PS> for ReshapeNode {
@@ -17,16 +23,39 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for ReshapeNode {
     }
 
     fn input_types(&self) -> Vec<Type> {
-        vec![Type::Tensor(self.input.clone())]
+        let input = Type::Tensor(self.input.clone());
+        // The shape is only an input when it is computed at runtime, e.g. from the batch size.
+        match &self.shape {
+            ReshapeShape::Static(_) => vec![input],
+            ReshapeShape::Runtime(rt_type) => vec![input, rt_type.clone()],
+        }
     }
 
     fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
         let input = scope.tensor_use_owned(&self.input, node_position);
         let output = &self.output.name;
-        let shape_values = &self.shape.to_tokens();
+
+        let shape = match &self.shape {
+            ReshapeShape::Static(static_shape) => static_shape.to_tokens(),
+            ReshapeShape::Runtime(Type::Tensor(shape_tensor)) => {
+                let tensor_name = &shape_tensor.name;
+                let dim = self.output.dim;
+                // The shape can contain 0 (copy the input dim) and -1 (inferred dim), which are
+                // handled by the i32 reshape args, so the tensor is downloaded and converted.
+                quote! {
+                    TryInto::<[i32; #dim]>::try_into(#tensor_name.to_data().convert::<i32>().as_slice::<i32>().unwrap()).unwrap()
+                }
+            }
+            ReshapeShape::Runtime(Type::Shape(shape)) => {
+                // The dims of a shape are known, so it can be passed to reshape directly
+                let shape_name = &shape.name;
+                quote! { #shape_name }
+            }
+            _ => panic!("Invalid shape source {:?}", self.shape),
+        };
 
         quote! {
-            let #output = #input.reshape(#shape_values);
+            let #output = #input.reshape(#shape);
         }
     }
 
@@ -43,7 +72,7 @@ mod tests {
     use crate::burn::{
         graph::BurnGraph,
         node::{reshape::ReshapeNode, test::assert_tokens},
-        TensorType,
+        ShapeType, TensorType,
     };
 
     #[test]
@@ -53,7 +82,7 @@ mod tests {
         graph.register(ReshapeNode::new(
             TensorType::new_float("tensor1", 4),
             TensorType::new_float("tensor2", 4),
-            [4, 4, 4, 4].into(),
+            ReshapeShape::Static([4, 4, 4, 4].into()),
         ));
 
         graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
@@ -89,4 +118,115 @@ mod tests {
 
         assert_tokens(graph.codegen(), expected);
     }
+
+    #[test]
+    fn test_codegen_reshape_shape() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(ReshapeNode::new(
+            TensorType::new_float("tensor1", 4),
+            TensorType::new_float("tensor2", 2),
+            ReshapeShape::Runtime(Type::Shape(ShapeType::new("shape1", 2))),
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string(), "shape1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(
+                    &self,
+                    tensor1: Tensor<B, 4>,
+                    shape1: [usize; 2],
+                ) -> Tensor<B, 2> {
+                    let tensor2 = tensor1.reshape(shape1);
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_reshape_tensor() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        let mut shape_tensor_type = TensorType::new_int("tensor3", 1);
+        shape_tensor_type.shape = Some(vec![2]);
+
+        graph.register(ReshapeNode::new(
+            TensorType::new_float("tensor1", 4),
+            TensorType::new_float("tensor2", 2),
+            ReshapeShape::Runtime(Type::Tensor(shape_tensor_type)),
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string(), "tensor3".to_string()],
+            vec!["tensor2".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::tensor::Int;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(
+                    &self,
+                    tensor1: Tensor<B, 4>,
+                    tensor3: Tensor<B, 1, Int>,
+                ) -> Tensor<B, 2> {
+                    let tensor2 = tensor1.reshape(
+                        TryInto::<[i32; 2usize]>::try_into(
+                            tensor3.to_data().convert::<i32>().as_slice::<i32>().unwrap()
+                        )
+                        .unwrap()
+                    );
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
 }
@@ -0,0 +1,142 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{BurnImports, Scope, TensorType, ToTokens, Type};
+use burn::config::Config;
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+#[derive(Config, Debug)]
+pub struct RoiAlignConfig {
+    pub output_size: [usize; 2],
+    pub spatial_scale: f32,
+    pub sampling_ratio: usize,
+    pub aligned: bool,
+}
+
+#[derive(Debug, Clone, new)]
+pub struct RoiAlignNode {
+    pub input: TensorType,
+    pub rois: TensorType,
+    pub batch_indices: TensorType,
+    pub output: TensorType,
+    pub config: RoiAlignConfig,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for RoiAlignNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![
+            Type::Tensor(self.input.clone()),
+            Type::Tensor(self.rois.clone()),
+            Type::Tensor(self.batch_indices.clone()),
+        ]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let rois = scope.tensor_use_owned(&self.rois, node_position);
+        let batch_indices = scope.tensor_use_owned(&self.batch_indices, node_position);
+        let output = &self.output.name;
+
+        let output_size = self.config.output_size.to_tokens();
+        let spatial_scale = self.config.spatial_scale.to_tokens();
+        let sampling_ratio = self.config.sampling_ratio.to_tokens();
+        let aligned = self.config.aligned;
+
+        // ONNX gives the batch indices of the boxes separately, while Burn expects them as the
+        // first coordinate of each box.
+        quote! {
+            let #output = roi_align(
+                #input,
+                Tensor::cat(vec![#batch_indices.float().unsqueeze_dim::<2>(1), #rois], 1),
+                RoiAlignOptions::new(#output_size, #spatial_scale, #sampling_ratio, #aligned),
+            );
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        imports.register("burn::tensor::module::roi_align");
+        imports.register("burn::tensor::ops::RoiAlignOptions");
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::RoiAlign(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{graph::BurnGraph, node::test::assert_tokens, TensorType};
+
+    #[test]
+    fn test_codegen_roi_align() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+        let config = RoiAlignConfig::new([7, 7], 0.0625, 2, true);
+
+        graph.register(RoiAlignNode::new(
+            TensorType::new_float("input", 4),
+            TensorType::new_float("rois", 2),
+            TensorType::new_int("batch_indices", 1),
+            TensorType::new_float("output", 4),
+            config,
+        ));
+        graph.register_input_output(
+            vec![
+                "input".to_string(),
+                "rois".to_string(),
+                "batch_indices".to_string(),
+            ],
+            vec!["output".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::tensor::module::roi_align;
+            use burn::tensor::ops::RoiAlignOptions;
+            use burn::tensor::Int;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model<B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(
+                    &self,
+                    input: Tensor<B, 4>,
+                    rois: Tensor<B, 2>,
+                    batch_indices: Tensor<B, 1, Int>
+                ) -> Tensor<B, 4> {
+                    let output = roi_align(
+                        input,
+                        Tensor::cat(vec![batch_indices.float().unsqueeze_dim::<2>(1), rois], 1),
+                        RoiAlignOptions::new([7, 7], 0.0625, 2, true),
+                    );
+
+                    output
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -0,0 +1,113 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, SequenceType, TensorType, ToTokens, Type};
+
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+#[derive(Debug, Clone, new)]
+pub struct SequenceAtNode {
+    pub input: SequenceType,
+    pub output: TensorType,
+    /// The position of the tensor in the sequence, counted from the end when negative.
+    pub position: i64,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for SequenceAtNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Sequence(self.input.clone())]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        // The tensor is cloned out of the sequence, so the sequence itself is only borrowed.
+        scope.tensor_use_owned(&self.input.tensor, node_position);
+
+        let input = &self.input.name;
+        let output = &self.output.name;
+        let index = if self.position < 0 {
+            let offset = (self.position.unsigned_abs() as usize).to_tokens();
+            quote! { #input.len() - #offset }
+        } else {
+            (self.position as usize).to_tokens()
+        };
+
+        quote! {
+            let #output = #input[#index].clone();
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::SequenceAt(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph, node::test::assert_tokens, SequenceType, TensorKind, TensorType,
+    };
+
+    #[test]
+    fn test_codegen_sequence_at() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(SequenceAtNode::new(
+            SequenceType::new("sequence", 2, TensorKind::Float),
+            TensorType::new_float("tensor1", 2),
+            0,
+        ));
+        graph.register(SequenceAtNode::new(
+            SequenceType::new("sequence", 2, TensorKind::Float),
+            TensorType::new_float("tensor2", 2),
+            -1,
+        ));
+
+        graph.register_input_output(
+            vec!["sequence".to_string()],
+            vec!["tensor1".to_string(), "tensor2".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model<B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(
+                    &self,
+                    sequence: Vec<Tensor<B, 2> >
+                ) -> (Tensor<B, 2>, Tensor<B, 2>) {
+                    let tensor1 = sequence[0].clone();
+                    let tensor2 = sequence[sequence.len() - 1].clone();
+
+                    (tensor1, tensor2)
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -0,0 +1,106 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, SequenceType, TensorType, Type};
+
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+#[derive(Debug, Clone, new)]
+pub struct SequenceConstructNode {
+    pub inputs: Vec<TensorType>,
+    pub output: SequenceType,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for SequenceConstructNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Sequence(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        self.inputs
+            .iter()
+            .map(|t| Type::Tensor(t.clone()))
+            .collect()
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let inputs = self
+            .inputs
+            .iter()
+            .map(|t| scope.tensor_use_owned(t, node_position));
+
+        let output = &self.output.name;
+
+        quote! {
+            let #output = vec![#(#inputs),*];
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::SequenceConstruct(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph, node::test::assert_tokens, SequenceType, TensorKind, TensorType,
+    };
+
+    #[test]
+    fn test_codegen_sequence_construct() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(SequenceConstructNode::new(
+            vec![
+                TensorType::new_float("tensor1", 2),
+                TensorType::new_float("tensor2", 2),
+            ],
+            SequenceType::new("sequence", 2, TensorKind::Float),
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string(), "tensor2".to_string()],
+            vec!["sequence".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model<B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(
+                    &self,
+                    tensor1: Tensor<B, 2>,
+                    tensor2: Tensor<B, 2>
+                ) -> Vec<Tensor<B, 2> > {
+                    let sequence = vec![tensor1, tensor2];
+
+                    sequence
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -0,0 +1,147 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, SequenceType, TensorType, ToTokens, Type};
+
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// How the tensor is split into a sequence.
+#[derive(Debug, Clone)]
+pub enum SequenceSplit {
+    /// Chunks of size 1, squeezed along the split dimension unless `keepdims` is set.
+    Chunks { keepdims: bool },
+    /// Chunks of the same size, the last one being smaller if the dimension isn't divisible.
+    Size(usize),
+    /// Chunks of the given sizes.
+    Sizes(Vec<usize>),
+}
+
+#[derive(Debug, Clone, new)]
+pub struct SplitToSequenceNode {
+    pub input: TensorType,
+    pub output: SequenceType,
+    pub dim: usize,
+    pub split: SequenceSplit,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for SplitToSequenceNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Sequence(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+        let dim = self.dim.to_tokens();
+
+        match &self.split {
+            SequenceSplit::Chunks { keepdims: true } => quote! {
+                let #output = #input.split(1, #dim);
+            },
+            SequenceSplit::Chunks { keepdims: false } => {
+                let output_dim = self.output.tensor.dim.to_tokens();
+                quote! {
+                    let #output = #input
+                        .split(1, #dim)
+                        .into_iter()
+                        .map(|tensor| tensor.squeeze::<#output_dim>(#dim))
+                        .collect::<Vec<_>>();
+                }
+            }
+            SequenceSplit::Size(size) => {
+                let size = size.to_tokens();
+                quote! {
+                    let #output = #input.split(#size, #dim);
+                }
+            }
+            SequenceSplit::Sizes(sizes) => {
+                let sizes = sizes.to_tokens();
+                quote! {
+                    let #output = #input.split_with_sizes(#sizes.into(), #dim);
+                }
+            }
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::SplitToSequence(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph, node::test::assert_tokens, SequenceType, TensorKind, TensorType,
+    };
+
+    #[test]
+    fn test_codegen_split_to_sequence() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(SplitToSequenceNode::new(
+            TensorType::new_float("tensor", 3),
+            SequenceType::new("sequence1", 2, TensorKind::Float),
+            1,
+            SequenceSplit::Chunks { keepdims: false },
+        ));
+        graph.register(SplitToSequenceNode::new(
+            TensorType::new_float("tensor", 3),
+            SequenceType::new("sequence2", 3, TensorKind::Float),
+            2,
+            SequenceSplit::Sizes(vec![2, 3]),
+        ));
+
+        graph.register_input_output(
+            vec!["tensor".to_string()],
+            vec!["sequence1".to_string(), "sequence2".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model<B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(
+                    &self,
+                    tensor: Tensor<B, 3>
+                ) -> (Vec<Tensor<B, 2> >, Vec<Tensor<B, 3> >) {
+                    let sequence1 = tensor
+                        .clone()
+                        .split(1, 1)
+                        .into_iter()
+                        .map(|tensor| tensor.squeeze::<2>(1))
+                        .collect::<Vec<_>>();
+                    let sequence2 = tensor.split_with_sizes([2, 3].into(), 2);
+
+                    (sequence1, sequence2)
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
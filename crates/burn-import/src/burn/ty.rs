@@ -41,6 +41,14 @@ pub struct ShapeType {
     pub dim: usize,
 }
 
+/// A sequence of tensors with the same type, represented by a `Vec` of tensors.
+#[derive(Debug, Clone)]
+pub struct SequenceType {
+    pub name: Ident,
+    /// The type of the tensors, named like the sequence.
+    pub tensor: TensorType,
+}
+
 #[derive(Debug, Clone)]
 pub struct OtherType {
     pub name: Ident,
@@ -58,6 +66,9 @@ pub enum Type {
     /// Shape type.
     Shape(ShapeType),
 
+    /// Sequence type.
+    Sequence(SequenceType),
+
     // Other type (more flexible type).
     Other(OtherType),
 }
@@ -78,6 +89,7 @@ impl Type {
             Type::Tensor(tensor) => &tensor.name,
             Type::Scalar(scalar) => &scalar.name,
             Type::Shape(shape) => &shape.name,
+            Type::Sequence(sequence) => &sequence.name,
             Type::Other(other) => &other.name,
         }
     }
@@ -86,6 +98,7 @@ impl Type {
             Type::Tensor(tensor) => tensor.ty(),
             Type::Scalar(scalar) => scalar.ty(),
             Type::Shape(shape) => shape.ty(),
+            Type::Sequence(sequence) => sequence.ty(),
             Type::Other(other) => other.ty(),
         }
     }
@@ -110,6 +123,13 @@ impl Type {
             panic!("Called Type::as_shape on {self:?}!");
         }
     }
+    pub fn as_sequence(&self) -> &SequenceType {
+        if let Self::Sequence(s) = self {
+            s
+        } else {
+            panic!("Called Type::as_sequence on {self:?}!");
+        }
+    }
 }
 
 impl ScalarType {
@@ -271,6 +291,20 @@ impl TensorType {
     }
 }
 
+impl SequenceType {
+    pub fn new<S: AsRef<str>>(name: S, dim: usize, kind: TensorKind) -> Self {
+        let tensor = TensorType::new(name, dim, kind, None);
+        Self {
+            name: tensor.name.clone(),
+            tensor,
+        }
+    }
+    pub fn ty(&self) -> TokenStream {
+        let tensor = self.tensor.ty();
+        quote! { Vec<#tensor> }
+    }
+}
+
 impl OtherType {
     pub fn new<S: AsRef<str>>(name: S, tokens: TokenStream) -> Self {
         if name.as_ref().is_empty() {
@@ -0,0 +1,122 @@
+use burn::{
+    module::Param,
+    record::{PrecisionSettings, Record},
+    tensor::{backend::Backend, Tensor},
+};
+
+use burn::record::serde::{
+    adapter::{BurnModuleAdapter, DefaultAdapter},
+    data::NestedValue,
+    ser::Serializer,
+};
+
+use serde::Serialize;
+
+/// A Keras adapter for the Burn module used during deserialization.
+///
+/// Keras names the weights of the layers differently than Burn, and stores the convolution
+/// kernels with the channels last. The dense kernels are stored as `[d_input, d_output]` like the
+/// Burn linear weights, so they only need to be renamed.
+pub struct KerasAdapter<PS: PrecisionSettings, B: Backend> {
+    _precision_settings: std::marker::PhantomData<(PS, B)>,
+}
+
+impl<PS: PrecisionSettings, B: Backend> BurnModuleAdapter for KerasAdapter<PS, B> {
+    fn adapt_linear(data: NestedValue) -> NestedValue {
+        rename(data, &[("kernel", "weight")])
+    }
+
+    fn adapt_conv1d(data: NestedValue) -> NestedValue {
+        // [kernel_size, channels_in, channels_out] -> [channels_out, channels_in, kernel_size]
+        permute_kernel::<PS, B, 3>(data, [2, 1, 0])
+    }
+
+    fn adapt_conv2d(data: NestedValue) -> NestedValue {
+        // [height, width, channels_in, channels_out] -> [channels_out, channels_in, height, width]
+        permute_kernel::<PS, B, 4>(data, [3, 2, 0, 1])
+    }
+
+    fn adapt_conv3d(data: NestedValue) -> NestedValue {
+        permute_kernel::<PS, B, 5>(data, [4, 3, 0, 1, 2])
+    }
+
+    fn adapt_conv_transpose_1d(data: NestedValue) -> NestedValue {
+        // [kernel_size, channels_out, channels_in] -> [channels_in, channels_out, kernel_size]
+        permute_kernel::<PS, B, 3>(data, [2, 1, 0])
+    }
+
+    fn adapt_conv_transpose_2d(data: NestedValue) -> NestedValue {
+        permute_kernel::<PS, B, 4>(data, [3, 2, 0, 1])
+    }
+
+    fn adapt_conv_transpose_3d(data: NestedValue) -> NestedValue {
+        permute_kernel::<PS, B, 5>(data, [4, 3, 0, 1, 2])
+    }
+
+    fn adapt_embedding(data: NestedValue) -> NestedValue {
+        rename(data, &[("embeddings", "weight")])
+    }
+
+    fn adapt_batch_norm(data: NestedValue) -> NestedValue {
+        rename(
+            data,
+            &[
+                ("moving_mean", "running_mean"),
+                ("moving_variance", "running_var"),
+            ],
+        )
+    }
+}
+
+/// Helper function to rename the parameters of a module, the missing parameters being ignored.
+fn rename(data: NestedValue, names: &[(&str, &str)]) -> NestedValue {
+    // Get the current module in the form of map.
+    let mut map = data.as_map().expect("Failed to get map from NestedValue");
+
+    for (from, to) in names {
+        if let Some(value) = map.remove(*from) {
+            map.insert(to.to_string(), value);
+        }
+    }
+
+    NestedValue::Map(map)
+}
+
+/// Helper function to permute the dimensions of a convolution kernel into a weight.
+fn permute_kernel<PS, B, const D: usize>(data: NestedValue, axes: [isize; D]) -> NestedValue
+where
+    PS: PrecisionSettings,
+    B: Backend,
+{
+    let mut map = data.as_map().expect("Failed to get map from NestedValue");
+
+    let Some(kernel) = map.remove("kernel") else {
+        return NestedValue::Map(map);
+    };
+
+    // Convert the kernel to a tensor (use default device, since it's quick operation).
+    let kernel: Param<Tensor<B, D>> = kernel
+        .try_into_record::<_, PS, DefaultAdapter, B>(&B::Device::default())
+        .expect("Failed to deserialize kernel");
+
+    // Do not capture permute op when using autodiff backend
+    let kernel = kernel.set_require_grad(false);
+    let weight = Param::from_tensor(kernel.val().permute(axes));
+
+    map.insert("weight".to_owned(), serialize::<PS, _, D>(weight));
+
+    NestedValue::Map(map)
+}
+
+/// Helper function to serialize a param tensor.
+fn serialize<PS, B, const D: usize>(val: Param<Tensor<B, D>>) -> NestedValue
+where
+    B: Backend,
+    PS: PrecisionSettings,
+{
+    let serializer = Serializer::new();
+
+    val.into_item::<PS>()
+        .serialize(serializer)
+        .expect("Failed to serialize the item")
+}
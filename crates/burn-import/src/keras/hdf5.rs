@@ -0,0 +1,959 @@
+//! A minimal reader of the HDF5 files written by Keras through h5py.
+//!
+//! Only the parts of the format used to store weights are supported: the groups, stored either
+//! in a symbol table or as compact links, and the numeric datasets with a contiguous or compact
+//! layout. Chunked datasets, used when the data is compressed, aren't supported.
+//!
+//! See the [HDF5 file format specification](https://docs.hdfgroup.org/hdf5/develop/_f_m_t3.html).
+
+use std::collections::HashSet;
+use std::path::Path;
+
+use burn::tensor::TensorData;
+use half::f16;
+
+use super::error::Error;
+
+const SIGNATURE: &[u8] = b"\x89HDF\r\n\x1a\n";
+const UNDEFINED_ADDRESS: u64 = u64::MAX;
+
+const MSG_DATASPACE: u16 = 0x0001;
+const MSG_LINK_INFO: u16 = 0x0002;
+const MSG_DATATYPE: u16 = 0x0003;
+const MSG_LINK: u16 = 0x0006;
+const MSG_DATA_LAYOUT: u16 = 0x0008;
+const MSG_CONTINUATION: u16 = 0x0010;
+const MSG_SYMBOL_TABLE: u16 = 0x0011;
+
+/// A numeric dataset of an HDF5 file.
+#[derive(Debug, Clone)]
+pub(crate) struct Dataset {
+    /// The path of the dataset, its name and the names of its groups separated by `/`.
+    pub path: String,
+    /// The values of the dataset.
+    pub data: TensorData,
+}
+
+/// Read the numeric datasets of an HDF5 file.
+pub(crate) fn read_datasets(path: &Path) -> Result<Vec<Dataset>, Error> {
+    let bytes = std::fs::read(path)?;
+    let file = Hdf5File::new(&bytes)?;
+
+    let mut datasets = Vec::new();
+    let mut visited = HashSet::new();
+    file.visit(file.root, "", &mut datasets, &mut visited)?;
+
+    Ok(datasets)
+}
+
+fn invalid(message: &str) -> Error {
+    Error::Hdf5(format!("invalid file: {message}"))
+}
+
+fn unsupported(message: String) -> Error {
+    Error::Hdf5(format!("unsupported feature: {message}"))
+}
+
+/// Read little endian values from a slice of the file.
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    offset_size: usize,
+    length_size: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn bytes(&mut self, len: usize) -> Result<&'a [u8], Error> {
+        let bytes = self
+            .pos
+            .checked_add(len)
+            .and_then(|end| self.bytes.get(self.pos..end))
+            .ok_or_else(|| invalid("unexpected end of data"))?;
+        self.pos += len;
+
+        Ok(bytes)
+    }
+
+    fn skip(&mut self, len: usize) -> Result<(), Error> {
+        self.bytes(len).map(|_| ())
+    }
+
+    fn u8(&mut self) -> Result<u8, Error> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16, Error> {
+        Ok(self.uint(2)? as u16)
+    }
+
+    fn u32(&mut self) -> Result<u32, Error> {
+        Ok(self.uint(4)? as u32)
+    }
+
+    fn uint(&mut self, size: usize) -> Result<u64, Error> {
+        Ok(uint(self.bytes(size)?, false))
+    }
+
+    /// Read an address, all the bits being set for an undefined address.
+    fn offset(&mut self) -> Result<u64, Error> {
+        let size = self.offset_size;
+        let value = self.uint(size)?;
+
+        if size < 8 && value == (1 << (size * 8)) - 1 {
+            Ok(UNDEFINED_ADDRESS)
+        } else {
+            Ok(value)
+        }
+    }
+
+    fn length(&mut self) -> Result<u64, Error> {
+        self.uint(self.length_size)
+    }
+
+    fn signature(&mut self, signature: &[u8]) -> Result<(), Error> {
+        if self.bytes(signature.len())? != signature {
+            return Err(invalid(&format!(
+                "expected the {} signature",
+                String::from_utf8_lossy(signature)
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/// An unsigned integer of at most 8 bytes.
+fn uint(bytes: &[u8], big_endian: bool) -> u64 {
+    let fold = |value: u64, byte: &u8| (value << 8) | *byte as u64;
+
+    if big_endian {
+        bytes.iter().fold(0, fold)
+    } else {
+        bytes.iter().rev().fold(0, fold)
+    }
+}
+
+struct Message<'a> {
+    kind: u16,
+    data: &'a [u8],
+}
+
+enum DataType {
+    Int { size: usize, signed: bool },
+    Float { size: usize },
+}
+
+struct Hdf5File<'a> {
+    bytes: &'a [u8],
+    base_address: u64,
+    offset_size: usize,
+    length_size: usize,
+    root: u64,
+}
+
+impl<'a> Hdf5File<'a> {
+    fn new(bytes: &'a [u8]) -> Result<Self, Error> {
+        // The superblock is at the start of the file, or after a user block whose size is a
+        // power of two from 512 bytes.
+        let mut start = 0;
+        while bytes.get(start..start + SIGNATURE.len()) != Some(SIGNATURE) {
+            start = if start == 0 { 512 } else { start * 2 };
+
+            if start >= bytes.len() {
+                return Err(invalid("the HDF5 signature wasn't found"));
+            }
+        }
+
+        let mut file = Self {
+            bytes,
+            base_address: 0,
+            offset_size: 8,
+            length_size: 8,
+            root: 0,
+        };
+
+        let mut reader = file.reader(start as u64 + SIGNATURE.len() as u64)?;
+        let version = reader.u8()?;
+        match version {
+            0 | 1 => {
+                // Free-space, root group symbol table entry and shared header message versions
+                // and a reserved byte.
+                reader.skip(4)?;
+                reader.offset_size = reader.u8()? as usize;
+                reader.length_size = reader.u8()? as usize;
+                // Reserved byte, group leaf and internal node K and file consistency flags.
+                reader.skip(9)?;
+                if version == 1 {
+                    // Indexed storage internal node K and reserved bytes.
+                    reader.skip(4)?;
+                }
+                file.base_address = reader.offset()?;
+                // Free-space info, end of file and driver info addresses, then the root group
+                // symbol table entry starting with its link name offset.
+                reader.skip(4 * reader.offset_size)?;
+                file.root = reader.offset()?;
+            }
+            2 | 3 => {
+                reader.offset_size = reader.u8()? as usize;
+                reader.length_size = reader.u8()? as usize;
+                // File consistency flags.
+                reader.skip(1)?;
+                file.base_address = reader.offset()?;
+                // Superblock extension and end of file addresses.
+                reader.skip(2 * reader.offset_size)?;
+                file.root = reader.offset()?;
+            }
+            version => return Err(unsupported(format!("superblock version {version}"))),
+        }
+
+        if ![2, 4, 8].contains(&reader.offset_size) || ![2, 4, 8].contains(&reader.length_size) {
+            return Err(invalid("the size of the offsets and lengths"));
+        }
+        file.offset_size = reader.offset_size;
+        file.length_size = reader.length_size;
+
+        Ok(file)
+    }
+
+    fn reader(&self, address: u64) -> Result<Reader<'a>, Error> {
+        let pos = address
+            .checked_add(self.base_address)
+            .filter(|pos| *pos < self.bytes.len() as u64)
+            .ok_or_else(|| invalid("address out of the file"))?;
+
+        Ok(Reader {
+            bytes: self.bytes,
+            pos: pos as usize,
+            offset_size: self.offset_size,
+            length_size: self.length_size,
+        })
+    }
+
+    /// Read the datasets of the object at the given address and of its children.
+    fn visit(
+        &self,
+        address: u64,
+        path: &str,
+        datasets: &mut Vec<Dataset>,
+        visited: &mut HashSet<u64>,
+    ) -> Result<(), Error> {
+        // Hard links can point to an object already visited.
+        if !visited.insert(address) {
+            return Ok(());
+        }
+
+        let messages = self.messages(address)?;
+
+        if messages.iter().any(|msg| msg.kind == MSG_DATA_LAYOUT) {
+            match self.dataset(&messages)? {
+                Some(data) => datasets.push(Dataset {
+                    path: path.to_string(),
+                    data,
+                }),
+                None => log::debug!("Skipping the non-numeric dataset {path}"),
+            }
+
+            return Ok(());
+        }
+
+        for (name, child) in self.links(&messages)? {
+            let child_path = if path.is_empty() {
+                name
+            } else {
+                format!("{path}/{name}")
+            };
+            self.visit(child, &child_path, datasets, visited)?;
+        }
+
+        Ok(())
+    }
+
+    /// Read the messages of the object header at the given address, following the
+    /// continuation blocks.
+    fn messages(&self, address: u64) -> Result<Vec<Message<'a>>, Error> {
+        let mut reader = self.reader(address)?;
+        let mut messages = Vec::new();
+        let mut continuations = Vec::new();
+
+        if reader.bytes.get(reader.pos..reader.pos + 4) == Some(&b"OHDR"[..]) {
+            reader.skip(4)?;
+            if reader.u8()? != 2 {
+                return Err(invalid("object header version"));
+            }
+            let flags = reader.u8()?;
+            if flags & 0x20 != 0 {
+                // Access, modification, change and birth times.
+                reader.skip(16)?;
+            }
+            if flags & 0x10 != 0 {
+                // Maximum compact and minimum dense attribute counts.
+                reader.skip(4)?;
+            }
+            let size = reader.uint(1 << (flags & 0x03))? as usize;
+            let chunk = reader.bytes(size)?;
+            self.v2_messages(chunk, flags, &mut messages, &mut continuations)?;
+
+            while let Some((address, length)) = continuations.pop() {
+                let mut reader = self.reader(address)?;
+                reader.signature(b"OCHK")?;
+                // The block ends with a checksum.
+                let chunk = reader.bytes((length as usize).saturating_sub(8))?;
+                self.v2_messages(chunk, flags, &mut messages, &mut continuations)?;
+            }
+        } else {
+            if reader.u8()? != 1 {
+                return Err(invalid("object header version"));
+            }
+            // Reserved byte, number of messages and object reference count.
+            reader.skip(7)?;
+            let size = reader.u32()? as usize;
+            // The messages are aligned on 8 bytes.
+            reader.skip(4)?;
+            let chunk = reader.bytes(size)?;
+            self.v1_messages(chunk, &mut messages, &mut continuations)?;
+
+            while let Some((address, length)) = continuations.pop() {
+                let chunk = self.reader(address)?.bytes(length as usize)?;
+                self.v1_messages(chunk, &mut messages, &mut continuations)?;
+            }
+        }
+
+        Ok(messages)
+    }
+
+    fn v1_messages(
+        &self,
+        chunk: &'a [u8],
+        messages: &mut Vec<Message<'a>>,
+        continuations: &mut Vec<(u64, u64)>,
+    ) -> Result<(), Error> {
+        let mut reader = self.chunk_reader(chunk);
+
+        while reader.pos + 8 <= chunk.len() {
+            let kind = reader.u16()?;
+            let size = reader.u16()? as usize;
+            // Flags and reserved bytes.
+            reader.skip(4)?;
+            let data = reader.bytes(size)?;
+            self.push_message(Message { kind, data }, messages, continuations)?;
+        }
+
+        Ok(())
+    }
+
+    fn v2_messages(
+        &self,
+        chunk: &'a [u8],
+        flags: u8,
+        messages: &mut Vec<Message<'a>>,
+        continuations: &mut Vec<(u64, u64)>,
+    ) -> Result<(), Error> {
+        let mut reader = self.chunk_reader(chunk);
+        let creation_order_size = if flags & 0x04 != 0 { 2 } else { 0 };
+
+        // The end of the chunk can be a gap smaller than a message header.
+        while reader.pos + 4 + creation_order_size <= chunk.len() {
+            let kind = reader.u8()? as u16;
+            let size = reader.u16()? as usize;
+            // Flags and creation order.
+            reader.skip(1 + creation_order_size)?;
+            let data = reader.bytes(size)?;
+            self.push_message(Message { kind, data }, messages, continuations)?;
+        }
+
+        Ok(())
+    }
+
+    fn push_message(
+        &self,
+        message: Message<'a>,
+        messages: &mut Vec<Message<'a>>,
+        continuations: &mut Vec<(u64, u64)>,
+    ) -> Result<(), Error> {
+        if message.kind == MSG_CONTINUATION {
+            let mut reader = self.chunk_reader(message.data);
+            continuations.push((reader.offset()?, reader.length()?));
+        } else {
+            messages.push(message);
+        }
+
+        Ok(())
+    }
+
+    fn chunk_reader(&self, chunk: &'a [u8]) -> Reader<'a> {
+        Reader {
+            bytes: chunk,
+            pos: 0,
+            offset_size: self.offset_size,
+            length_size: self.length_size,
+        }
+    }
+
+    /// The names and object header addresses of the children of a group.
+    fn links(&self, messages: &[Message<'a>]) -> Result<Vec<(String, u64)>, Error> {
+        let mut links = Vec::new();
+
+        for message in messages {
+            let mut reader = self.chunk_reader(message.data);
+
+            match message.kind {
+                MSG_SYMBOL_TABLE => {
+                    let btree = reader.offset()?;
+                    let heap = reader.offset()?;
+                    let heap_data = self.local_heap_data(heap)?;
+                    self.group_btree(btree, heap_data, &mut links)?;
+                }
+                MSG_LINK => {
+                    if let Some(link) = self.link(&mut reader)? {
+                        links.push(link);
+                    }
+                }
+                MSG_LINK_INFO => {
+                    // Version and flags, then the maximum creation index when tracked.
+                    reader.skip(1)?;
+                    if reader.u8()? & 0x01 != 0 {
+                        reader.skip(8)?;
+                    }
+                    if reader.offset()? != UNDEFINED_ADDRESS {
+                        return Err(unsupported("groups with dense link storage".into()));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(links)
+    }
+
+    /// The address of the data segment of a local heap.
+    fn local_heap_data(&self, address: u64) -> Result<u64, Error> {
+        let mut reader = self.reader(address)?;
+        reader.signature(b"HEAP")?;
+        // Version, reserved bytes, data segment size and offset to the head of the free list.
+        reader.skip(4 + 2 * reader.length_size)?;
+
+        reader.offset()
+    }
+
+    fn group_btree(
+        &self,
+        address: u64,
+        heap_data: u64,
+        links: &mut Vec<(String, u64)>,
+    ) -> Result<(), Error> {
+        let mut reader = self.reader(address)?;
+        reader.signature(b"TREE")?;
+        if reader.u8()? != 0 {
+            return Err(invalid("expected a group B-tree node"));
+        }
+        let level = reader.u8()?;
+        let entries = reader.u16()?;
+        // Left and right siblings.
+        reader.skip(2 * reader.offset_size)?;
+
+        for _ in 0..entries {
+            // The key, an offset in the local heap.
+            reader.skip(reader.length_size)?;
+            let child = reader.offset()?;
+
+            if level > 0 {
+                self.group_btree(child, heap_data, links)?;
+            } else {
+                self.symbol_table_node(child, heap_data, links)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn symbol_table_node(
+        &self,
+        address: u64,
+        heap_data: u64,
+        links: &mut Vec<(String, u64)>,
+    ) -> Result<(), Error> {
+        let mut reader = self.reader(address)?;
+        reader.signature(b"SNOD")?;
+        // Version and reserved byte.
+        reader.skip(2)?;
+        let symbols = reader.u16()?;
+
+        for _ in 0..symbols {
+            let name_offset = reader.offset()?;
+            let object = reader.offset()?;
+            // Cache type, reserved bytes and scratch-pad space.
+            reader.skip(24)?;
+
+            links.push((self.string(heap_data + name_offset)?, object));
+        }
+
+        Ok(())
+    }
+
+    /// A hard link, soft and external links being ignored.
+    fn link(&self, reader: &mut Reader<'a>) -> Result<Option<(String, u64)>, Error> {
+        // Version.
+        reader.skip(1)?;
+        let flags = reader.u8()?;
+        let link_type = if flags & 0x08 != 0 { reader.u8()? } else { 0 };
+        if flags & 0x04 != 0 {
+            // Creation order.
+            reader.skip(8)?;
+        }
+        if flags & 0x10 != 0 {
+            // Character set.
+            reader.skip(1)?;
+        }
+        let name_size = reader.uint(1 << (flags & 0x03))? as usize;
+        let name = String::from_utf8_lossy(reader.bytes(name_size)?).into_owned();
+
+        match link_type {
+            0 => Ok(Some((name, reader.offset()?))),
+            _ => {
+                log::debug!("Skipping the soft or external link {name}");
+                Ok(None)
+            }
+        }
+    }
+
+    /// A null terminated string.
+    fn string(&self, address: u64) -> Result<String, Error> {
+        let reader = self.reader(address)?;
+        let bytes = &reader.bytes[reader.pos..];
+        let end = bytes
+            .iter()
+            .position(|byte| *byte == 0)
+            .ok_or_else(|| invalid("unterminated string"))?;
+
+        Ok(String::from_utf8_lossy(&bytes[..end]).into_owned())
+    }
+
+    /// The values of a dataset, or `None` when they aren't numbers.
+    fn dataset(&self, messages: &[Message<'a>]) -> Result<Option<TensorData>, Error> {
+        let message = |kind| {
+            messages
+                .iter()
+                .find(|msg| msg.kind == kind)
+                .map(|msg| self.chunk_reader(msg.data))
+                .ok_or_else(|| invalid("dataset without dataspace, datatype or layout"))
+        };
+
+        let Some(shape) = self.dataspace(&mut message(MSG_DATASPACE)?)? else {
+            return Ok(None);
+        };
+        let (data_type, big_endian) = match self.datatype(&mut message(MSG_DATATYPE)?)? {
+            Some(data_type) => data_type,
+            None => return Ok(None),
+        };
+        let elem_size = match data_type {
+            DataType::Int { size, .. } | DataType::Float { size } => size,
+        };
+        let size = shape.iter().product::<usize>() * elem_size;
+        let bytes = self.layout(&mut message(MSG_DATA_LAYOUT)?, size)?;
+        let elems = bytes.chunks_exact(elem_size);
+
+        let data = match data_type {
+            DataType::Float { size: 2 } => TensorData::new(
+                elems
+                    .map(|elem| f16::from_bits(uint(elem, big_endian) as u16))
+                    .collect::<Vec<_>>(),
+                shape,
+            ),
+            DataType::Float { size: 4 } => TensorData::new(
+                elems
+                    .map(|elem| f32::from_bits(uint(elem, big_endian) as u32))
+                    .collect::<Vec<_>>(),
+                shape,
+            ),
+            DataType::Float { .. } => TensorData::new(
+                elems
+                    .map(|elem| f64::from_bits(uint(elem, big_endian)))
+                    .collect::<Vec<_>>(),
+                shape,
+            ),
+            DataType::Int { size, signed } => {
+                let shift = 64 - 8 * size as u32;
+                TensorData::new(
+                    elems
+                        .map(|elem| {
+                            let value = uint(elem, big_endian) << shift;
+                            match signed {
+                                true => (value as i64) >> shift,
+                                false => (value >> shift) as i64,
+                            }
+                        })
+                        .collect::<Vec<_>>(),
+                    shape,
+                )
+            }
+        };
+
+        Ok(Some(data))
+    }
+
+    /// The shape of a dataspace, or `None` for a null dataspace.
+    fn dataspace(&self, reader: &mut Reader<'a>) -> Result<Option<Vec<usize>>, Error> {
+        let version = reader.u8()?;
+        let rank = reader.u8()? as usize;
+        // Flags.
+        reader.skip(1)?;
+        match version {
+            1 => reader.skip(5)?,
+            2 => {
+                if reader.u8()? == 2 {
+                    return Ok(None);
+                }
+            }
+            version => return Err(unsupported(format!("dataspace version {version}"))),
+        }
+
+        let shape = (0..rank)
+            .map(|_| reader.length().map(|dim| dim as usize))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Some(shape))
+    }
+
+    /// The numeric type of a dataset with its byte order, or `None` for other types.
+    fn datatype(&self, reader: &mut Reader<'a>) -> Result<Option<(DataType, bool)>, Error> {
+        let class = reader.u8()? & 0x0F;
+        let bits = reader.u8()?;
+        // The other bit fields.
+        reader.skip(2)?;
+        let size = reader.u32()? as usize;
+        let big_endian = bits & 0x01 != 0;
+
+        let data_type = match class {
+            0 if [1, 2, 4, 8].contains(&size) => DataType::Int {
+                size,
+                signed: bits & 0x08 != 0,
+            },
+            1 if [2, 4, 8].contains(&size) => DataType::Float { size },
+            1 => return Err(unsupported(format!("floats of {size} bytes"))),
+            _ => return Ok(None),
+        };
+
+        Ok(Some((data_type, big_endian)))
+    }
+
+    /// The raw values of a dataset of the given size in bytes.
+    fn layout(&self, reader: &mut Reader<'a>, size: usize) -> Result<Vec<u8>, Error> {
+        let version = reader.u8()?;
+
+        let (class, address) = match version {
+            1 | 2 => {
+                let rank = reader.u8()? as usize;
+                let class = reader.u8()?;
+                // Reserved bytes.
+                reader.skip(5)?;
+                let address = match class {
+                    0 => None,
+                    _ => Some(reader.offset()?),
+                };
+                // The dimensions of the layout, followed by the size of the compact data.
+                reader.skip(4 * rank)?;
+                if class == 0 {
+                    reader.skip(4)?;
+                }
+                (class, address)
+            }
+            3 | 4 => {
+                let class = reader.u8()?;
+                let address = match class {
+                    0 => {
+                        reader.skip(2)?;
+                        None
+                    }
+                    1 => Some(reader.offset()?),
+                    _ => None,
+                };
+                (class, address)
+            }
+            version => return Err(unsupported(format!("data layout version {version}"))),
+        };
+
+        match (class, address) {
+            // The data is stored in the layout message.
+            (0, _) => Ok(reader.bytes(size)?.to_vec()),
+            // The data was never written.
+            (1, Some(UNDEFINED_ADDRESS)) => Ok(vec![0; size]),
+            (1, Some(address)) => Ok(self.reader(address)?.bytes(size)?.to_vec()),
+            _ => Err(unsupported(
+                "chunked datasets, e.g. compressed weights".into(),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// Write an HDF5 file with the structures written by h5py by default: a version 0 superblock,
+    /// version 1 object headers and the groups stored in symbol tables.
+    ///
+    /// The groups are given as the paths of their datasets, with the values stored as
+    /// little endian `f32`.
+    pub(crate) fn write_hdf5(datasets: &[(&str, Vec<usize>, Vec<f32>)]) -> Vec<u8> {
+        let mut writer = Writer { bytes: Vec::new() };
+
+        // Superblock version 0, with 8 bytes offsets and lengths.
+        writer.bytes.extend_from_slice(SIGNATURE);
+        writer.bytes.extend_from_slice(&[0, 0, 0, 0, 0, 8, 8, 0]);
+        writer.u16(4);
+        writer.u16(16);
+        writer.u32(0);
+        // Base, free-space, end of file and driver info addresses.
+        writer.u64(0);
+        writer.u64(UNDEFINED_ADDRESS);
+        let end_of_file = writer.reserve();
+        writer.u64(UNDEFINED_ADDRESS);
+        // The root group symbol table entry.
+        writer.u64(0);
+        let root = writer.reserve();
+        writer.bytes.extend_from_slice(&[0; 24]);
+
+        let root_address = writer.group("", datasets);
+        writer.patch(root, root_address);
+        let len = writer.bytes.len() as u64;
+        writer.patch(end_of_file, len);
+
+        writer.bytes
+    }
+
+    struct Writer {
+        bytes: Vec<u8>,
+    }
+
+    impl Writer {
+        fn u16(&mut self, value: u16) {
+            self.bytes.extend_from_slice(&value.to_le_bytes());
+        }
+
+        fn u32(&mut self, value: u32) {
+            self.bytes.extend_from_slice(&value.to_le_bytes());
+        }
+
+        fn u64(&mut self, value: u64) {
+            self.bytes.extend_from_slice(&value.to_le_bytes());
+        }
+
+        fn reserve(&mut self) -> usize {
+            self.u64(0);
+            self.bytes.len() - 8
+        }
+
+        fn patch(&mut self, pos: usize, value: u64) {
+            self.bytes[pos..pos + 8].copy_from_slice(&value.to_le_bytes());
+        }
+
+        fn align(&mut self) {
+            while self.bytes.len() % 8 != 0 {
+                self.bytes.push(0);
+            }
+        }
+
+        /// Write the object header and return its address.
+        fn object_header(&mut self, messages: &[(u16, Vec<u8>)]) -> u64 {
+            self.align();
+            let address = self.bytes.len() as u64;
+            let size = messages
+                .iter()
+                .map(|(_, data)| 8 + data.len().next_multiple_of(8))
+                .sum::<usize>();
+
+            self.bytes.extend_from_slice(&[1, 0]);
+            self.u16(messages.len() as u16);
+            self.u32(1);
+            self.u32(size as u32);
+            self.u32(0);
+            for (kind, data) in messages {
+                self.u16(*kind);
+                self.u16(data.len().next_multiple_of(8) as u16);
+                self.u32(0);
+                self.bytes.extend_from_slice(data);
+                self.align();
+            }
+
+            address
+        }
+
+        /// Write a group with the datasets whose path starts with the given prefix.
+        fn group(&mut self, prefix: &str, datasets: &[(&str, Vec<usize>, Vec<f32>)]) -> u64 {
+            let mut children: Vec<(String, u64)> = Vec::new();
+
+            for (path, shape, values) in datasets {
+                let Some(rest) = path.strip_prefix(prefix) else {
+                    continue;
+                };
+                let name = rest.split('/').next().unwrap().to_string();
+                if children.iter().any(|(child, _)| *child == name) {
+                    continue;
+                }
+
+                let address = match rest.contains('/') {
+                    true => self.group(&format!("{prefix}{name}/"), datasets),
+                    false => self.dataset(shape, values),
+                };
+                children.push((name, address));
+            }
+
+            // The local heap with the names of the children, the first byte being the empty
+            // string.
+            let mut heap = vec![0];
+            let mut name_offsets = Vec::new();
+            for (name, _) in children.iter() {
+                name_offsets.push(heap.len() as u64);
+                heap.extend_from_slice(name.as_bytes());
+                heap.push(0);
+            }
+            heap.resize(heap.len().next_multiple_of(8), 0);
+
+            self.align();
+            let heap_data = self.bytes.len() as u64;
+            self.bytes.extend_from_slice(&heap);
+
+            let heap_address = self.bytes.len() as u64;
+            self.bytes.extend_from_slice(b"HEAP");
+            self.bytes.extend_from_slice(&[0; 4]);
+            self.u64(heap.len() as u64);
+            self.u64(UNDEFINED_ADDRESS);
+            self.u64(heap_data);
+
+            let node_address = self.bytes.len() as u64;
+            self.bytes.extend_from_slice(b"SNOD");
+            self.bytes.extend_from_slice(&[1, 0]);
+            self.u16(children.len() as u16);
+            for ((_, address), name_offset) in children.iter().zip(name_offsets) {
+                self.u64(name_offset);
+                self.u64(*address);
+                self.bytes.extend_from_slice(&[0; 24]);
+            }
+
+            let btree_address = self.bytes.len() as u64;
+            self.bytes.extend_from_slice(b"TREE");
+            self.bytes.extend_from_slice(&[0, 0]);
+            self.u16(1);
+            self.u64(UNDEFINED_ADDRESS);
+            self.u64(UNDEFINED_ADDRESS);
+            self.u64(0);
+            self.u64(node_address);
+            self.u64(heap.len() as u64 - 1);
+
+            let mut symbol_table = btree_address.to_le_bytes().to_vec();
+            symbol_table.extend_from_slice(&heap_address.to_le_bytes());
+
+            self.object_header(&[(MSG_SYMBOL_TABLE, symbol_table)])
+        }
+
+        fn dataset(&mut self, shape: &[usize], values: &[f32]) -> u64 {
+            self.align();
+            let data_address = self.bytes.len() as u64;
+            for value in values {
+                self.bytes.extend_from_slice(&value.to_le_bytes());
+            }
+
+            let mut dataspace = vec![1, shape.len() as u8, 0, 0, 0, 0, 0, 0];
+            for dim in shape {
+                dataspace.extend_from_slice(&(*dim as u64).to_le_bytes());
+            }
+
+            // Little endian IEEE float of 4 bytes.
+            let mut datatype = vec![0x11, 0x20, 0x1F, 0x00];
+            datatype.extend_from_slice(&4u32.to_le_bytes());
+            datatype.extend_from_slice(&0u16.to_le_bytes());
+            datatype.extend_from_slice(&32u16.to_le_bytes());
+            datatype.extend_from_slice(&[23, 8, 0, 23]);
+            datatype.extend_from_slice(&127u32.to_le_bytes());
+
+            let mut layout = vec![3, 1];
+            layout.extend_from_slice(&data_address.to_le_bytes());
+            layout.extend_from_slice(&(values.len() as u64 * 4).to_le_bytes());
+
+            self.object_header(&[
+                (MSG_DATASPACE, dataspace),
+                (MSG_DATATYPE, datatype),
+                (MSG_DATA_LAYOUT, layout),
+            ])
+        }
+    }
+
+    #[test]
+    fn should_read_nested_datasets() {
+        let bytes = write_hdf5(&[
+            (
+                "dense/dense/kernel:0",
+                vec![2, 3],
+                vec![1., 2., 3., 4., 5., 6.],
+            ),
+            ("dense/dense/bias:0", vec![3], vec![7., 8., 9.]),
+            ("scale", vec![], vec![0.5]),
+        ]);
+        let file = Hdf5File::new(&bytes).unwrap();
+        let mut datasets = Vec::new();
+        file.visit(file.root, "", &mut datasets, &mut HashSet::new())
+            .unwrap();
+
+        datasets.sort_by(|a, b| a.path.cmp(&b.path));
+        let paths = datasets
+            .iter()
+            .map(|dataset| dataset.path.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            ["dense/dense/bias:0", "dense/dense/kernel:0", "scale"]
+        );
+        datasets[1]
+            .data
+            .assert_eq(&TensorData::from([[1f32, 2., 3.], [4., 5., 6.]]), true);
+        datasets[2]
+            .data
+            .assert_eq(&TensorData::new(vec![0.5f32], Vec::<usize>::new()), true);
+    }
+
+    #[test]
+    fn should_reject_other_files() {
+        assert!(Hdf5File::new(b"not an HDF5 file").is_err());
+    }
+
+    /// The fixtures written by h5py, generated by `tests/data/keras/export_weights.py`.
+    const FIXTURES: &str = "tests/data/keras";
+
+    fn assert_dense_weights(datasets: &[Dataset]) {
+        let data = |path: &str| {
+            &datasets
+                .iter()
+                .find(|dataset| dataset.path == path)
+                .unwrap_or_else(|| panic!("the dataset {path} wasn't read"))
+                .data
+        };
+
+        data("layers/dense/vars/0")
+            .assert_eq(&TensorData::from([[0f32, 0.5, 1.], [1.5, 2., 2.5]]), true);
+        data("layers/dense/vars/1").assert_eq(&TensorData::from([-1f32, 0., 1.]), true);
+    }
+
+    #[test]
+    #[ignore = "The fixtures must be generated with tests/data/keras/export_weights.py"]
+    fn should_read_keras_weights_file() {
+        let datasets = read_datasets(&Path::new(FIXTURES).join("dense.weights.h5")).unwrap();
+
+        assert_dense_weights(&datasets);
+    }
+
+    #[test]
+    #[ignore = "The fixtures must be generated with tests/data/keras/export_weights.py"]
+    fn should_read_latest_file_format() {
+        let datasets = read_datasets(&Path::new(FIXTURES).join("dense_latest.weights.h5")).unwrap();
+
+        assert_dense_weights(&datasets);
+    }
+
+    #[test]
+    #[ignore = "The fixtures must be generated with tests/data/keras/export_weights.py"]
+    fn should_reject_dense_link_storage() {
+        let error = read_datasets(&Path::new(FIXTURES).join("dense_links.h5")).unwrap_err();
+
+        assert!(error.to_string().contains("dense link storage"));
+    }
+}
@@ -0,0 +1,6 @@
+mod adapter;
+mod error;
+mod hdf5;
+mod reader;
+mod recorder;
+pub use recorder::{KerasFileRecorder, LoadArgs};
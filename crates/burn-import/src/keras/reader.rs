@@ -0,0 +1,169 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use super::{adapter::KerasAdapter, error::Error, hdf5::read_datasets};
+
+use burn::{
+    module::ParamId,
+    record::{
+        serde::{
+            data::{remap, unflatten, NestedValue, Serializable},
+            de::Deserializer,
+            error,
+            ser::Serializer,
+        },
+        PrecisionSettings,
+    },
+    tensor::{backend::Backend, TensorData},
+};
+
+use regex::Regex;
+use serde::{de::DeserializeOwned, Serialize};
+
+/// The group in which Keras saves the weights of a whole model, next to its configuration and the
+/// weights of its optimizer.
+const MODEL_WEIGHTS_GROUP: &str = "model_weights/";
+
+/// Deserializes a Keras H5 file.
+///
+/// # Arguments
+///
+/// * `path` - A string slice that holds the path of the file to read.
+/// * `key_remap` - A vector of tuples containing a regular expression and a replacement string.
+pub fn from_file<PS, D, B>(
+    path: &Path,
+    key_remap: Vec<(Regex, String)>,
+    debug: bool,
+) -> Result<D, Error>
+where
+    D: DeserializeOwned,
+    PS: PrecisionSettings,
+    B: Backend,
+{
+    let mut datasets = read_datasets(path)?;
+
+    // Only keep the model weights of a file saved with `model.save`.
+    if datasets
+        .iter()
+        .any(|dataset| dataset.path.starts_with(MODEL_WEIGHTS_GROUP))
+    {
+        datasets.retain(|dataset| dataset.path.starts_with(MODEL_WEIGHTS_GROUP));
+    }
+
+    if datasets.is_empty() {
+        return Err(Error::Other(format!(
+            "No weights found in {}",
+            path.display()
+        )));
+    }
+
+    let tensors: HashMap<String, KerasTensor> = datasets
+        .into_iter()
+        .map(|dataset| (key(&dataset.path), KerasTensor(dataset.data)))
+        .collect();
+
+    // Remap the keys (replace the keys in the map with the new keys)
+    let (tensors, remapped_keys) = remap(tensors, key_remap);
+
+    // Print the remapped keys if debug is enabled
+    if debug {
+        let mut remapped_keys = remapped_keys;
+        remapped_keys.sort();
+        println!("Debug information of keys and tensor shapes:\n---");
+        for (new_key, old_key) in remapped_keys {
+            if old_key != new_key {
+                println!("Original Key: {old_key}");
+                println!("Remapped Key: {new_key}");
+            } else {
+                println!("Key: {}", new_key);
+            }
+
+            let data = &tensors[&new_key].0;
+            println!("Shape: {:?}", data.shape);
+            println!("Dtype: {:?}", data.dtype);
+            println!("---");
+        }
+    }
+
+    // Convert the map of tensors to a nested value data structure
+    let nested_value = unflatten::<PS, _>(tensors)?;
+
+    // Create a deserializer with Keras adapter and nested value
+    let deserializer = Deserializer::<KerasAdapter<PS, B>>::new(nested_value, true);
+
+    // Deserialize the nested value into a record type
+    let value = D::deserialize(deserializer)?;
+    Ok(value)
+}
+
+/// The key of a dataset, with the groups separated by dots like the fields of a record.
+///
+/// The `model_weights` group and the `:0` suffix of the variable names are removed, and a group
+/// nested in a group with the same name is merged with it, since Keras 2 saves the weights of a
+/// layer in a group named after the layer, e.g. `dense/dense/kernel:0` becomes `dense.kernel`.
+fn key(path: &str) -> String {
+    let path = path.strip_prefix(MODEL_WEIGHTS_GROUP).unwrap_or(path);
+    let mut parts: Vec<&str> = Vec::new();
+
+    for part in path.split('/') {
+        let part = match part.rsplit_once(':') {
+            Some((name, index)) if index.parse::<usize>().is_ok() => name,
+            _ => part,
+        };
+
+        if parts.last() != Some(&part) {
+            parts.push(part);
+        }
+    }
+
+    parts.join(".")
+}
+
+/// The values of a Keras variable.
+struct KerasTensor(TensorData);
+
+/// Serializes a Keras variable.
+///
+/// Variables are wrapped in a `Param` struct (learnable parameters) and serialized as a
+/// `TensorData` struct, with the values converted to the `FloatElem` or `IntElem` of the
+/// precision settings.
+impl Serializable for KerasTensor {
+    fn serialize<PS>(&self, serializer: Serializer) -> Result<NestedValue, error::Error>
+    where
+        PS: PrecisionSettings,
+    {
+        let data = if self.0.dtype.is_float() {
+            self.0.clone().convert::<PS::FloatElem>()
+        } else {
+            self.0.clone().convert::<PS::IntElem>()
+        };
+        let shape = data.shape.clone();
+        let (dtype, bytes) = (data.dtype, data.into_bytes());
+
+        let mut tensor_data: HashMap<String, NestedValue> = HashMap::new();
+        tensor_data.insert("bytes".into(), NestedValue::Bytes(bytes));
+        tensor_data.insert("shape".into(), shape.serialize(serializer.clone())?);
+        tensor_data.insert("dtype".into(), dtype.serialize(serializer)?);
+
+        let mut param: HashMap<String, NestedValue> = HashMap::new();
+        param.insert("id".into(), NestedValue::String(ParamId::new().serialize()));
+        param.insert("param".into(), NestedValue::Map(tensor_data));
+
+        Ok(NestedValue::Map(param))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn key_should_merge_the_layer_groups() {
+        assert_eq!(key("model_weights/dense/dense/kernel:0"), "dense.kernel");
+        assert_eq!(
+            key("sequential/sequential/conv2d/bias:0"),
+            "sequential.conv2d.bias"
+        );
+        assert_eq!(key("layers/dense/vars/0"), "layers.dense.vars.0");
+    }
+}
@@ -0,0 +1,244 @@
+use core::marker::PhantomData;
+use std::path::PathBuf;
+
+use burn::{
+    record::{PrecisionSettings, Record, Recorder, RecorderError},
+    tensor::backend::Backend,
+};
+
+use regex::Regex;
+use serde::{de::DeserializeOwned, Serialize};
+
+use super::reader::from_file;
+
+/// A recorder that loads the weights of Keras models saved in H5 files (`.h5`) into Burn modules.
+///
+/// Both the files saved with `model.save_weights` and with `model.save` are supported. The keys
+/// of the weights are the names of the layers and of their variables, e.g. `dense.kernel` for
+/// the `dense/dense/kernel:0` dataset saved by Keras 2. The kernels, embeddings and moving
+/// statistics are mapped to the parameters of the corresponding Burn modules, the convolution
+/// kernels being permuted from channels last to channels first.
+///
+/// LoadArgs can be used to remap keys or file path.
+/// See [LoadArgs](struct.LoadArgs.html) for more information.
+///
+/// # Notes
+///
+/// The weights must be saved in the HDF5 format, the variables of a TensorFlow SavedModel
+/// directory aren't supported. Compressed or chunked datasets aren't supported either.
+#[derive(new, Debug, Default, Clone)]
+pub struct KerasFileRecorder<PS: PrecisionSettings> {
+    _settings: PhantomData<PS>,
+}
+
+impl<PS: PrecisionSettings, B: Backend> Recorder<B> for KerasFileRecorder<PS> {
+    type Settings = PS;
+    type RecordArgs = PathBuf;
+    type RecordOutput = ();
+    type LoadArgs = LoadArgs;
+
+    fn save_item<I: Serialize>(
+        &self,
+        _item: I,
+        _file: Self::RecordArgs,
+    ) -> Result<(), RecorderError> {
+        unimplemented!("save_item not implemented for KerasFileRecorder")
+    }
+
+    fn load_item<I: DeserializeOwned>(&self, _file: Self::LoadArgs) -> Result<I, RecorderError> {
+        unimplemented!("load_item not implemented for KerasFileRecorder")
+    }
+
+    fn load<R: Record<B>>(
+        &self,
+        args: Self::LoadArgs,
+        device: &B::Device,
+    ) -> Result<R, RecorderError> {
+        let item =
+            from_file::<PS, R::Item<Self::Settings>, B>(&args.file, args.key_remap, args.debug)?;
+        Ok(R::from_item(item, device))
+    }
+}
+
+/// Arguments for loading a Keras file.
+///
+/// # Fields
+///
+/// * `file` - The path to the file to load.
+/// * `key_remap` - A vector of tuples containing a regular expression and a replacement string.
+///                See [regex::Regex::replace](https://docs.rs/regex/latest/regex/struct.Regex.html#method.replace)
+///                for more information.
+///
+/// # Notes
+///
+/// Use [Netron](https://github.com/lutzroeder/netron) or `h5ls -r` to inspect the datasets of the
+/// Keras file (.h5 extension), or enable the debug print to list the keys.
+///
+/// # Examples
+///
+/// ```text
+/// use burn_import::keras::{KerasFileRecorder, LoadArgs};
+/// use burn::record::FullPrecisionSettings;
+/// use burn::record::Recorder;
+///
+/// // Keras 3 saves the variables of the layers by index, e.g. `layers/dense/vars/0`.
+/// let args = LoadArgs::new("model.weights.h5".into())
+///     .with_key_remap("^layers\\.", "")
+///     .with_key_remap("vars\\.0$", "kernel")
+///     .with_key_remap("vars\\.1$", "bias");
+///
+/// let record = KerasFileRecorder::<FullPrecisionSettings>::default()
+///     .load(args, &device)
+///     .expect("Should decode state successfully");
+/// ```
+#[derive(Debug, Clone)]
+pub struct LoadArgs {
+    /// The path to the file to load.
+    pub file: PathBuf,
+
+    /// A list of key remappings.
+    pub key_remap: Vec<(Regex, String)>,
+
+    /// Whether to print debug information.
+    pub debug: bool,
+}
+
+impl LoadArgs {
+    /// Creates a new `LoadArgs` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `file` - The path to the file to load.
+    pub fn new(file: PathBuf) -> Self {
+        Self {
+            file,
+            key_remap: Vec::new(),
+            debug: false,
+        }
+    }
+
+    /// Sets key remapping.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The Regex pattern to be replaced.
+    /// * `replacement` - The pattern to replace with.
+    ///
+    /// See [Regex](https://docs.rs/regex/1.5.4/regex/#syntax) for the pattern syntax and
+    /// [Replacement](https://docs.rs/regex/latest/regex/struct.Regex.html#method.replace) for the
+    /// replacement syntax.
+    pub fn with_key_remap(mut self, pattern: &str, replacement: &str) -> Self {
+        let regex = Regex::new(pattern).expect("Valid regex");
+
+        self.key_remap.push((regex, replacement.into()));
+        self
+    }
+
+    /// Sets printing debug information on.
+    pub fn with_debug_print(mut self) -> Self {
+        self.debug = true;
+        self
+    }
+}
+
+impl From<PathBuf> for LoadArgs {
+    fn from(val: PathBuf) -> Self {
+        LoadArgs::new(val)
+    }
+}
+
+impl From<String> for LoadArgs {
+    fn from(val: String) -> Self {
+        LoadArgs::new(val.into())
+    }
+}
+
+impl From<&str> for LoadArgs {
+    fn from(val: &str) -> Self {
+        LoadArgs::new(val.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keras::hdf5::tests::write_hdf5;
+    use burn::{
+        module::Module,
+        nn::{
+            conv::{Conv2d, Conv2dConfig},
+            Linear, LinearConfig,
+        },
+        record::FullPrecisionSettings,
+        tensor::TensorData,
+    };
+    use burn_ndarray::NdArray;
+
+    type TestBackend = NdArray<f32>;
+
+    #[derive(Module, Debug)]
+    struct Net<B: Backend> {
+        conv2d: Conv2d<B>,
+        dense: Linear<B>,
+    }
+
+    #[test]
+    fn should_load_keras_weights() {
+        let file = std::env::temp_dir().join("burn_import_keras_weights.h5");
+        let kernel = (0..8).map(|value| value as f32).collect::<Vec<_>>();
+        let bytes = write_hdf5(&[
+            // A 1x2 convolution from 2 to 2 channels, channels last.
+            (
+                "model_weights/conv2d/conv2d/kernel:0",
+                vec![1, 2, 2, 2],
+                kernel,
+            ),
+            (
+                "model_weights/conv2d/conv2d/bias:0",
+                vec![2],
+                vec![0.1, 0.2],
+            ),
+            (
+                "model_weights/dense/dense/kernel:0",
+                vec![2, 1],
+                vec![3.0, 4.0],
+            ),
+            ("model_weights/dense/dense/bias:0", vec![1], vec![5.0]),
+            ("optimizer_weights/iterations:0", vec![], vec![10.0]),
+        ]);
+        std::fs::write(&file, bytes).unwrap();
+
+        let device = Default::default();
+        let record: NetRecord<TestBackend> = KerasFileRecorder::<FullPrecisionSettings>::default()
+            .load(file.clone().into(), &device)
+            .unwrap();
+        std::fs::remove_file(file).unwrap();
+
+        let net = Net {
+            conv2d: Conv2dConfig::new([2, 2], [1, 2]).init(&device),
+            dense: LinearConfig::new(2, 1).init(&device),
+        }
+        .load_record(record);
+
+        // The kernel [height, width, channels_in, channels_out] is permuted to
+        // [channels_out, channels_in, height, width].
+        net.conv2d.weight.val().into_data().assert_eq(
+            &TensorData::from([
+                [[[0.0f32, 4.0]], [[2.0, 6.0]]],
+                [[[1.0, 5.0]], [[3.0, 7.0]]],
+            ]),
+            true,
+        );
+        net.dense
+            .weight
+            .val()
+            .into_data()
+            .assert_eq(&TensorData::from([[3.0f32], [4.0]]), true);
+        net.dense
+            .bias
+            .unwrap()
+            .val()
+            .into_data()
+            .assert_eq(&TensorData::from([5.0f32]), true);
+    }
+}
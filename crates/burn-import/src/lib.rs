@@ -10,7 +10,12 @@
 //! aligns the imported model with Burn's model and converts tensor data into a format compatible with
 //! Burn.
 
-#[cfg(any(feature = "pytorch", feature = "onnx"))]
+#[cfg(any(
+    feature = "pytorch",
+    feature = "onnx",
+    feature = "keras",
+    feature = "safetensors"
+))]
 #[macro_use]
 extern crate derive_new;
 
@@ -30,5 +35,13 @@ pub mod burn;
 #[cfg(feature = "pytorch")]
 pub mod pytorch;
 
+/// The safetensors module for recorder.
+#[cfg(feature = "safetensors")]
+pub mod safetensors;
+
+/// The Keras module for recorder.
+#[cfg(feature = "keras")]
+pub mod keras;
+
 mod formatter;
 pub use formatter::*;
@@ -9,7 +9,9 @@ use burn::nn::{
 };
 
 use crate::burn::node::{
-    expand::ExpandShape, pad::PadConfig, tile::TileConfig, trilu::TriluConfig,
+    expand::ExpandShape, non_max_suppression::NonMaxSuppressionConfig, pad::PadConfig,
+    reshape::ReshapeShape, roi_align::RoiAlignConfig, split_to_sequence::SequenceSplit,
+    tile::TileConfig, trilu::TriluConfig,
 };
 use onnx_ir::ir::{ArgType, AttributeValue, Data, ElementType, Node};
 
@@ -919,6 +921,163 @@ pub fn trilu_config(node: &Node) -> TriluConfig {
     TriluConfig::new(upper, diagonal)
 }
 
+/// Create a NonMaxSuppressionConfig from the attributes and the constant inputs of the node
+pub fn non_max_suppression_config(node: &Node) -> NonMaxSuppressionConfig {
+    let center_point_box = node
+        .attrs
+        .get("center_point_box")
+        .map(|value| value.clone().into_i64() != 0)
+        .unwrap_or(false);
+
+    // The optional inputs are scalars coming from constant nodes, an empty input takes the
+    // default value of the spec
+    let input_value = |index: usize| {
+        node.inputs
+            .get(index)
+            .filter(|input| !input.name.is_empty())
+            .map(|input| match &input.value {
+                Some(value) => value.clone().into_scalar(),
+                None => panic!("NonMaxSuppression: only constant thresholds are supported"),
+            })
+    };
+
+    // No box is selected when the maximum number of boxes isn't provided
+    let max_output_boxes_per_class = match input_value(2) {
+        Some(Data::Int64(value)) => value.max(0) as usize,
+        Some(Data::Int32(value)) => value.max(0) as usize,
+        None => 0,
+        Some(value) => panic!("NonMaxSuppression: invalid max_output_boxes_per_class {value:?}"),
+    };
+    let iou_threshold = input_value(3).map(|value| value.into_f32()).unwrap_or(0.0);
+    let score_threshold = input_value(4).map(|value| value.into_f32());
+
+    NonMaxSuppressionConfig::new(max_output_boxes_per_class, iou_threshold, center_point_box)
+        .with_score_threshold(score_threshold)
+}
+
+/// Create a RoiAlignConfig from the attributes of the node
+pub fn roi_align_config(node: &Node) -> RoiAlignConfig {
+    let mut output_height = 1;
+    let mut output_width = 1;
+    let mut sampling_ratio = 0;
+    let mut spatial_scale = 1.0;
+    // The default mode since opset 16, the previous opsets are normalized to `output_half_pixel`
+    let mut aligned = true;
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "output_height" => output_height = value.clone().into_i64() as usize,
+            "output_width" => output_width = value.clone().into_i64() as usize,
+            "sampling_ratio" => sampling_ratio = value.clone().into_i64().max(0) as usize,
+            "spatial_scale" => spatial_scale = value.clone().into_f32(),
+            "coordinate_transformation_mode" => {
+                aligned = match value.clone().into_string().as_str() {
+                    "half_pixel" => true,
+                    "output_half_pixel" => false,
+                    mode => panic!("RoiAlign: unsupported coordinate transformation mode {mode}"),
+                }
+            }
+            "mode" => {
+                let mode = value.clone().into_string();
+                if mode != "avg" {
+                    panic!("RoiAlign: only the avg mode is supported, got {mode}");
+                }
+            }
+            _ => {}
+        }
+    }
+
+    RoiAlignConfig::new(
+        [output_height, output_width],
+        spatial_scale,
+        sampling_ratio,
+        aligned,
+    )
+}
+
+/// Get the position of the tensor taken from a sequence by a SequenceAt node
+pub fn sequence_at_config(node: &Node) -> i64 {
+    match &node.inputs[1].value {
+        Some(Data::Int64(position)) => *position,
+        Some(Data::Int32(position)) => *position as i64,
+        Some(Data::Int64s(position)) if position.len() == 1 => position[0],
+        Some(Data::Int32s(position)) if position.len() == 1 => position[0] as i64,
+        Some(value) => panic!("SequenceAt: invalid position {value:?}"),
+        None => panic!("SequenceAt: only constant positions are supported"),
+    }
+}
+
+/// Get the dimension of a ConcatFromSequence node, and if the tensors are stacked along a new
+/// dimension
+pub fn concat_from_sequence_config(node: &Node) -> (usize, bool) {
+    let rank = match &node.inputs[0].ty {
+        ArgType::Sequence(tensor) => tensor.dim as i64,
+        _ => panic!("ConcatFromSequence: only sequence input is valid"),
+    };
+
+    let mut axis = None;
+    let mut new_axis = false;
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "axis" => axis = Some(value.clone().into_i64()),
+            "new_axis" => new_axis = value.clone().into_i64() != 0,
+            _ => {}
+        }
+    }
+
+    let mut axis = axis.expect("ConcatFromSequence: axis attribute is required");
+
+    // if axis is negative, it is counted from the end of the output
+    if axis < 0 {
+        axis += rank + new_axis as i64;
+    }
+
+    (axis as usize, new_axis)
+}
+
+/// Get the dimension and the split of a SplitToSequence node
+pub fn split_to_sequence_config(node: &Node) -> (usize, SequenceSplit) {
+    let rank = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.dim as i64,
+        _ => panic!("SplitToSequence: only tensor input is valid"),
+    };
+
+    let mut axis = 0;
+    let mut keepdims = true;
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "axis" => axis = value.clone().into_i64(),
+            "keepdims" => keepdims = value.clone().into_i64() != 0,
+            _ => {}
+        }
+    }
+
+    // if axis is negative, it is counted from the end
+    if axis < 0 {
+        axis += rank;
+    }
+
+    let split = match node.inputs.get(1).filter(|input| !input.name.is_empty()) {
+        None => SequenceSplit::Chunks { keepdims },
+        Some(input) => match &input.value {
+            Some(Data::Int64(size)) => SequenceSplit::Size(*size as usize),
+            Some(Data::Int32(size)) => SequenceSplit::Size(*size as usize),
+            Some(Data::Int64s(sizes)) => {
+                SequenceSplit::Sizes(sizes.iter().map(|size| *size as usize).collect())
+            }
+            Some(Data::Int32s(sizes)) => {
+                SequenceSplit::Sizes(sizes.iter().map(|size| *size as usize).collect())
+            }
+            Some(value) => panic!("SplitToSequence: invalid split {value:?}"),
+            None => panic!("SplitToSequence: only constant splits are supported"),
+        },
+    };
+
+    (axis as usize, split)
+}
+
 /// Create a PadConfig from the attributes of the node
 pub fn pad_config(node: &Node) -> PadConfig {
     fn get_pads_input(node: &Node) -> Vec<i64> {
@@ -1188,7 +1347,7 @@ pub fn hard_sigmoid_config(node: &Node) -> (f64, f64) {
     (alpha, beta)
 }
 
-pub fn reshape_config(node: &Node) -> Vec<i64> {
+pub fn reshape_config(node: &Node) -> ReshapeShape {
     let mut allowzero = 0;
 
     for (key, value) in node.attrs.iter() {
@@ -1205,23 +1364,29 @@ pub fn reshape_config(node: &Node) -> Vec<i64> {
     }
 
     // TODO: check "shape" attribute
-    if node.inputs.len() != 2 || node.inputs[1].value.is_none() {
+    if node.inputs.len() != 2 {
         panic!("Reshape: shape tensor must be present for {:?}", node);
     }
 
-    let input_value = &node.inputs[1].value;
     match &node.inputs[1].ty {
         ArgType::Tensor(tensor) => {
             assert_eq!(tensor.dim, 1, "Reshape: shape tensor must be 1D");
-
-            if let Some(Data::Int64s(shape)) = input_value.as_ref() {
-                shape.clone()
-            } else {
-                panic!("Tensor data type must be int64")
-            }
+        }
+        ArgType::Shape(_) => {
+            // Shapes are always 1-D int64 data, so nothing to assert here
         }
         _ => panic!("Only tensor input is valid for shape"),
     }
+
+    match node.inputs[1].value.as_ref() {
+        Some(Data::Int64s(shape)) => ReshapeShape::Static(shape.clone()),
+        None => {
+            // The shape depends on the inputs, e.g. a batch size or sequence length only known at
+            // runtime, so it is read when running the model
+            ReshapeShape::Runtime(crate::burn::Type::from(&node.inputs[1]))
+        }
+        Some(_) => panic!("Tensor data type must be int64"),
+    }
 }
 
 pub fn resize_config(node: &Node) -> (String, Vec<f32>, Vec<usize>) {
@@ -22,6 +22,7 @@ use crate::{
             binary::BinaryNode,
             clip::ClipNode,
             concat::ConcatNode,
+            concat_from_sequence::ConcatFromSequenceNode,
             constant::{ConstantNode, ConstantValue},
             constant_of_shape::ConstantOfShapeNode,
             conv1d::Conv1dNode,
@@ -41,6 +42,7 @@ use crate::{
             matmul::MatmulNode,
             max_pool1d::MaxPool1dNode,
             max_pool2d::MaxPool2dNode,
+            non_max_suppression::NonMaxSuppressionNode,
             pad::PadNode,
             prelu::PReluNode,
             random_normal::RandomNormalNode,
@@ -50,7 +52,11 @@ use crate::{
             range::RangeNode,
             reshape::ReshapeNode,
             resize::ResizeNode,
+            roi_align::RoiAlignNode,
+            sequence_at::SequenceAtNode,
+            sequence_construct::SequenceConstructNode,
             slice::SliceNode,
+            split_to_sequence::SplitToSequenceNode,
             squeeze::SqueezeNode,
             sum::SumNode,
             tile::TileNode,
@@ -58,7 +64,7 @@ use crate::{
             unary::UnaryNode,
             unsqueeze::UnsqueezeNode,
         },
-        ScalarKind, ScalarType, ShapeType, TensorKind, TensorType, Type,
+        ScalarKind, ScalarType, SequenceType, ShapeType, TensorKind, TensorType, Type,
     },
     format_tokens,
     logger::init_log,
@@ -66,13 +72,15 @@ use crate::{
 
 use super::op_configuration::{
     argmax_config, avg_pool1d_config, avg_pool2d_config, batch_norm_config, clip_config,
-    concat_config, conv1d_config, conv2d_config, conv3d_config, conv_transpose1d_config,
-    conv_transpose2d_config, conv_transpose3d_config, dropout_config, expand_config,
-    flatten_config, gather_config, hard_sigmoid_config, layer_norm_config, leaky_relu_config,
-    linear_config, log_softmax_config, max_pool1d_config, max_pool2d_config, pad_config,
-    reduce_max_config, reduce_mean_config, reduce_min_config, reduce_prod_config,
-    reduce_sum_config, reshape_config, resize_config, shape_config, slice_config, softmax_config,
-    squeeze_config, tile_config, transpose_config, trilu_config, unsqueeze_config,
+    concat_config, concat_from_sequence_config, conv1d_config, conv2d_config, conv3d_config,
+    conv_transpose1d_config, conv_transpose2d_config, conv_transpose3d_config, dropout_config,
+    expand_config, flatten_config, gather_config, hard_sigmoid_config, layer_norm_config,
+    leaky_relu_config, linear_config, log_softmax_config, max_pool1d_config, max_pool2d_config,
+    non_max_suppression_config, pad_config, reduce_max_config, reduce_mean_config,
+    reduce_min_config, reduce_prod_config, reduce_sum_config, reshape_config, resize_config,
+    roi_align_config, sequence_at_config, shape_config, slice_config, softmax_config,
+    split_to_sequence_config, squeeze_config, tile_config, transpose_config, trilu_config,
+    unsqueeze_config,
 };
 use onnx_ir::{
     convert_constant_value,
@@ -276,7 +284,21 @@ impl ParsedOnnxGraph {
                 NodeType::MaxPool1d => graph.register(Self::max_pool1d_conversion(node)),
                 NodeType::MaxPool2d => graph.register(Self::max_pool2d_conversion(node)),
                 NodeType::Mean => graph.register(Self::mean_conversion(node)),
+                NodeType::NonMaxSuppression => {
+                    graph.register(Self::non_max_suppression_conversion(node))
+                }
                 NodeType::PRelu => graph.register(Self::prelu_conversion::<PS>(node)),
+                NodeType::RoiAlign => graph.register(Self::roi_align_conversion(node)),
+                NodeType::SequenceAt => graph.register(Self::sequence_at_conversion(node)),
+                NodeType::SequenceConstruct => {
+                    graph.register(Self::sequence_construct_conversion(node))
+                }
+                NodeType::ConcatFromSequence => {
+                    graph.register(Self::concat_from_sequence_conversion(node))
+                }
+                NodeType::SplitToSequence => {
+                    graph.register(Self::split_to_sequence_conversion(node))
+                }
                 NodeType::AveragePool1d => graph.register(Self::avg_pool_1d_conversion(node)),
                 NodeType::AveragePool2d => graph.register(Self::avg_pool_2d_conversion(node)),
                 NodeType::MatMul => graph.register(Self::matmul_conversion(node)),
@@ -431,6 +453,7 @@ impl ParsedOnnxGraph {
                 _ => panic!("Unsupported constant tensor type: {:?} ", elem_type),
             },
             ArgType::Shape(_) => panic!("Shape is not supported as constant value."),
+            ArgType::Sequence(_) => panic!("Sequence is not supported as constant value."),
         };
 
         ConstantNode::new(node.name.clone(), const_value, Type::from(output))
@@ -1040,6 +1063,56 @@ impl ParsedOnnxGraph {
         MeanNode::new(inputs, output)
     }
 
+    fn non_max_suppression_conversion(node: Node) -> NonMaxSuppressionNode {
+        let boxes = TensorType::from(node.inputs.first().unwrap());
+        let scores = TensorType::from(node.inputs.get(1).unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let config = non_max_suppression_config(&node);
+
+        NonMaxSuppressionNode::new(boxes, scores, output, config)
+    }
+
+    fn roi_align_conversion(node: Node) -> RoiAlignNode {
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let rois = TensorType::from(node.inputs.get(1).unwrap());
+        let batch_indices = TensorType::from(node.inputs.get(2).unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let config = roi_align_config(&node);
+
+        RoiAlignNode::new(input, rois, batch_indices, output, config)
+    }
+
+    fn sequence_construct_conversion(node: Node) -> SequenceConstructNode {
+        let inputs = node.inputs.iter().map(TensorType::from).collect();
+        let output = SequenceType::from(node.outputs.first().unwrap());
+
+        SequenceConstructNode::new(inputs, output)
+    }
+
+    fn sequence_at_conversion(node: Node) -> SequenceAtNode {
+        let input = SequenceType::from(node.inputs.first().unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let position = sequence_at_config(&node);
+
+        SequenceAtNode::new(input, output, position)
+    }
+
+    fn concat_from_sequence_conversion(node: Node) -> ConcatFromSequenceNode {
+        let input = SequenceType::from(node.inputs.first().unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let (dim, new_axis) = concat_from_sequence_config(&node);
+
+        ConcatFromSequenceNode::new(input, output, dim, new_axis)
+    }
+
+    fn split_to_sequence_conversion(node: Node) -> SplitToSequenceNode {
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let output = SequenceType::from(node.outputs.first().unwrap());
+        let (dim, split) = split_to_sequence_config(&node);
+
+        SplitToSequenceNode::new(input, output, dim, split)
+    }
+
     fn prelu_conversion<PS: PrecisionSettings>(node: Node) -> PReluNode {
         let input = TensorType::from(node.inputs.first().unwrap());
         let output = TensorType::from(node.outputs.first().unwrap());
@@ -1335,6 +1408,19 @@ impl From<&OnnxArgument> for TensorType {
         }
     }
 }
+impl From<&OnnxArgument> for SequenceType {
+    fn from(arg: &OnnxArgument) -> Self {
+        match &arg.ty {
+            ArgType::Sequence(tensor) => SequenceType::new(
+                arg.name.clone(),
+                tensor.dim,
+                tensor.elem_type.clone().into(),
+            ),
+            _ => panic!("Can't transform {:?} to sequence.", arg.ty),
+        }
+    }
+}
+
 impl From<&OnnxArgument> for Type {
     fn from(arg: &OnnxArgument) -> Self {
         match &arg.ty {
@@ -1358,6 +1444,11 @@ impl From<&OnnxArgument> for Type {
                 Type::Scalar(ScalarType::new(arg.name.clone(), elem_type.into()))
             }
             ArgType::Shape(dim) => Type::Shape(ShapeType::new(arg.name.clone(), *dim)),
+            ArgType::Sequence(tensor) => Type::Sequence(SequenceType::new(
+                arg.name.clone(),
+                tensor.dim,
+                tensor.elem_type.clone().into(),
+            )),
         }
     }
 }
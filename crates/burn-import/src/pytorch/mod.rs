@@ -3,5 +3,10 @@ mod config;
 mod error;
 mod reader;
 mod recorder;
+mod rules;
 pub use config::config_from_file;
 pub use recorder::{LoadArgs, PyTorchFileRecorder};
+pub use rules::TensorRule;
+
+#[cfg(feature = "safetensors")]
+pub(crate) use adapter::PyTorchAdapter;
@@ -2,7 +2,11 @@ use core::ops::Deref;
 use std::collections::HashMap;
 use std::path::Path;
 
-use super::{adapter::PyTorchAdapter, error::Error};
+use super::{
+    adapter::PyTorchAdapter,
+    error::Error,
+    rules::{apply_rules, TensorRule},
+};
 
 use burn::{
     module::ParamId,
@@ -30,10 +34,12 @@ use serde::{de::DeserializeOwned, Serialize};
 ///
 /// * `path` - A string slice that holds the path of the file to read.
 /// * `key_remap` - A vector of tuples containing a regular expression and a replacement string.
+/// * `tensor_rules` - The rules transforming the tensors after the keys are remapped.
 /// * `top_level_key` - An optional top-level key to load state_dict from a dictionary.
 pub fn from_file<PS, D, B>(
     path: &Path,
     key_remap: Vec<(Regex, String)>,
+    tensor_rules: &[TensorRule],
     top_level_key: Option<&str>,
     debug: bool,
 ) -> Result<D, Error>
@@ -42,15 +48,24 @@ where
     PS: PrecisionSettings,
     B: Backend,
 {
+    check_not_torchscript(path)?;
+
     // Read the pickle file and return a vector of Candle tensors
-    let tensors: HashMap<String, CandleTensor> = pickle::read_all_with_key(path, top_level_key)?
-        .into_iter()
-        .map(|(key, tensor)| (key, CandleTensor(tensor)))
-        .collect();
+    let tensors: HashMap<String, candle_core::Tensor> =
+        pickle::read_all_with_key(path, top_level_key)?
+            .into_iter()
+            .collect();
 
     // Remap the keys (replace the keys in the map with the new keys)
     let (tensors, remapped_keys) = remap(tensors, key_remap);
 
+    // Transpose, split or concatenate the tensors with the remapped keys
+    let (tensors, remapped_keys) = apply_rules(tensors, remapped_keys, tensor_rules)?;
+    let tensors: HashMap<String, CandleTensor> = tensors
+        .into_iter()
+        .map(|(key, tensor)| (key, CandleTensor(tensor)))
+        .collect();
+
     // Print the remapped keys if debug is enabled
     if debug {
         let mut remapped_keys = remapped_keys;
@@ -83,6 +98,31 @@ where
     Ok(value)
 }
 
+/// Reject TorchScript archives, which contain a module with its compiled code instead of a
+/// state_dict, with an error explaining how to export the model.
+///
+/// Files that aren't zip archives, like the legacy PyTorch format, are left to the pickle reader.
+fn check_not_torchscript(path: &Path) -> Result<(), Error> {
+    let Ok(archive) = zip::ZipArchive::new(std::fs::File::open(path)?) else {
+        return Ok(());
+    };
+
+    let is_torchscript = archive
+        .file_names()
+        .any(|name| name.contains("/code/") || name.ends_with("/constants.pkl"));
+
+    if is_torchscript {
+        return Err(Error::Other(format!(
+            "{} is a TorchScript archive, which isn't supported: save the state_dict of the \
+             module with `torch.save(model.state_dict(), path)` to load its weights, or export \
+             the model to ONNX with `torch.onnx.export` to generate its code",
+            path.display()
+        )));
+    }
+
+    Ok(())
+}
+
 /// Serializes a candle tensor.
 ///
 /// Tensors are wrapped in a `Param` struct (learnable parameters) and serialized as a `TensorData` struct.
@@ -170,3 +210,53 @@ impl Deref for CandleTensor {
         &self.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use zip::{write::SimpleFileOptions, ZipWriter};
+
+    /// Write a zip archive with the given files, like the ones saved by `torch.save`.
+    fn write_archive(path: &Path, files: &[&str]) {
+        let mut archive = ZipWriter::new(std::fs::File::create(path).unwrap());
+        for file in files {
+            archive
+                .start_file(*file, SimpleFileOptions::default())
+                .unwrap();
+            archive.write_all(b"").unwrap();
+        }
+        archive.finish().unwrap();
+    }
+
+    #[test]
+    fn should_reject_torchscript_archives() {
+        let file = std::env::temp_dir().join("burn_import_torchscript.pt");
+        write_archive(
+            &file,
+            &[
+                "model/data.pkl",
+                "model/code/__torch__.py",
+                "model/constants.pkl",
+            ],
+        );
+
+        let result = check_not_torchscript(&file);
+        std::fs::remove_file(file).unwrap();
+
+        assert!(
+            matches!(result, Err(Error::Other(message)) if message.contains("torch.onnx.export"))
+        );
+    }
+
+    #[test]
+    fn should_accept_state_dict_archives() {
+        let file = std::env::temp_dir().join("burn_import_state_dict.pt");
+        write_archive(&file, &["model/data.pkl", "model/data/0", "model/version"]);
+
+        let result = check_not_torchscript(&file);
+        std::fs::remove_file(file).unwrap();
+
+        assert!(result.is_ok());
+    }
+}
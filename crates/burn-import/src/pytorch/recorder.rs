@@ -9,7 +9,7 @@ use burn::{
 use regex::Regex;
 use serde::{de::DeserializeOwned, Serialize};
 
-use super::reader::from_file;
+use super::{reader::from_file, rules::TensorRule};
 
 /// A recorder that loads PyTorch files (`.pt`) into Burn modules.
 ///
@@ -47,6 +47,7 @@ impl<PS: PrecisionSettings, B: Backend> Recorder<B> for PyTorchFileRecorder<PS>
         let item = from_file::<PS, R::Item<Self::Settings>, B>(
             &args.file,
             args.key_remap,
+            &args.tensor_rules,
             args.top_level_key.as_deref(), // Convert Option<String> to Option<&str>
             args.debug,
         )?;
@@ -62,6 +63,8 @@ impl<PS: PrecisionSettings, B: Backend> Recorder<B> for PyTorchFileRecorder<PS>
 /// * `key_remap` - A vector of tuples containing a regular expression and a replacement string.
 ///                See [regex::Regex::replace](https://docs.rs/regex/latest/regex/struct.Regex.html#method.replace)
 ///                for more information.
+/// * `tensor_rules` - The rules transposing, splitting or concatenating the tensors after the keys
+///                    are remapped. See [TensorRule](enum.TensorRule.html) for more information.
 ///
 /// # Notes
 ///
@@ -82,6 +85,18 @@ impl<PS: PrecisionSettings, B: Backend> Recorder<B> for PyTorchFileRecorder<PS>
 ///   .load(args)
 ///   .expect("Should decode state successfully");
 /// ```
+///
+/// Hugging Face transformers often need their tensors to be reshaped as well, e.g. to split the
+/// fused projection of the attention of GPT-2 saved as a `Conv1D` layer:
+///
+/// ```text
+/// let args = LoadArgs::new("gpt2.pt".into())
+///     // "h.0.attn.c_attn.weight" -> "blocks.0.attn.qkv.weight"
+///     .with_key_remap("^h\\.([0-9]+)\\.attn\\.c_attn", "blocks.$1.attn.qkv")
+///     // The Conv1D weights are [d_input, d_output], not [d_output, d_input] like nn.Linear
+///     .with_transpose("attn\\.qkv\\.weight$")
+///     .with_split("^(.*)\\.qkv\\.(weight|bias)$", 0, &["$1.query.$2", "$1.key.$2", "$1.value.$2"]);
+/// ```
 #[derive(Debug, Clone)]
 pub struct LoadArgs {
     /// The path to the file to load.
@@ -90,6 +105,9 @@ pub struct LoadArgs {
     /// A list of key remappings.
     pub key_remap: Vec<(Regex, String)>,
 
+    /// A list of rules transforming the tensors, applied after the key remappings.
+    pub tensor_rules: Vec<TensorRule>,
+
     /// Top-level key to load state_dict from the file.
     /// Sometimes the state_dict is nested under a top-level key in a dict.
     pub top_level_key: Option<String>,
@@ -108,6 +126,7 @@ impl LoadArgs {
         Self {
             file,
             key_remap: Vec::new(),
+            tensor_rules: Vec::new(),
             top_level_key: None,
             debug: false,
         }
@@ -130,6 +149,56 @@ impl LoadArgs {
         self
     }
 
+    /// Transposes the last two dimensions of the tensors matching a pattern.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The Regex pattern of the remapped keys of the tensors.
+    pub fn with_transpose(mut self, pattern: &str) -> Self {
+        let pattern = Regex::new(pattern).expect("Valid regex");
+
+        self.tensor_rules.push(TensorRule::Transpose { pattern });
+        self
+    }
+
+    /// Splits the tensors matching a pattern into chunks of the same size.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The Regex pattern of the remapped keys of the tensors.
+    /// * `dim` - The dimension along which the tensors are split.
+    /// * `replacements` - The keys of the chunks, which can refer to the captures of the pattern.
+    pub fn with_split(mut self, pattern: &str, dim: usize, replacements: &[&str]) -> Self {
+        let pattern = Regex::new(pattern).expect("Valid regex");
+        let replacements = replacements.iter().map(|r| r.to_string()).collect();
+
+        self.tensor_rules.push(TensorRule::Split {
+            pattern,
+            dim,
+            replacements,
+        });
+        self
+    }
+
+    /// Concatenates the tensors matching a pattern and replaced by the same key.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The Regex pattern of the remapped keys of the tensors, with a `shard` named
+    ///               group matching the position of each tensor, e.g. `(?<shard>[0-9]+)`.
+    /// * `dim` - The dimension along which the tensors are concatenated.
+    /// * `replacement` - The key of the concatenated tensor.
+    pub fn with_concat(mut self, pattern: &str, dim: usize, replacement: &str) -> Self {
+        let pattern = Regex::new(pattern).expect("Valid regex");
+
+        self.tensor_rules.push(TensorRule::Concat {
+            pattern,
+            dim,
+            replacement: replacement.into(),
+        });
+        self
+    }
+
     /// Sets the top-level key to load state_dict from the file.
     /// Sometimes the state_dict is nested under a top-level key in a dict.
     ///
@@ -0,0 +1,154 @@
+use std::collections::HashMap;
+
+use candle_core::Tensor;
+use regex::Regex;
+
+use super::error::Error;
+
+/// A rule transforming the tensors of a PyTorch file while loading it, applied after the keys are
+/// remapped.
+///
+/// The rules are applied in the order they are added, each one to the keys produced by the
+/// previous ones. The replacements can refer to the capture groups of the pattern, like the key
+/// remapping, e.g. `$1` or `${layer}`.
+#[derive(Debug, Clone)]
+pub enum TensorRule {
+    /// Swap the last two dimensions of the tensors whose key matches the pattern, e.g. for the
+    /// `Conv1D` layers of GPT-2 storing the linear weights as `[d_input, d_output]`.
+    Transpose {
+        /// The pattern of the keys of the tensors to transpose.
+        pattern: Regex,
+    },
+
+    /// Split the tensors whose key matches the pattern into chunks of the same size along a
+    /// dimension, e.g. a fused QKV projection into the query, key and value projections.
+    Split {
+        /// The pattern of the keys of the tensors to split.
+        pattern: Regex,
+        /// The dimension along which the tensors are split.
+        dim: usize,
+        /// The keys of the chunks, one per chunk.
+        replacements: Vec<String>,
+    },
+
+    /// Concatenate along a dimension the tensors whose key matches the pattern and is replaced by
+    /// the same key, e.g. the shards of a tensor saved by a tensor parallel model.
+    ///
+    /// The pattern must have a `shard` named group matching the position of the tensor in the
+    /// concatenation, e.g. `(?<name>.*)\.shard(?<shard>\d+)` replaced by `$name`.
+    Concat {
+        /// The pattern of the keys of the tensors to concatenate.
+        pattern: Regex,
+        /// The dimension along which the tensors are concatenated.
+        dim: usize,
+        /// The key of the concatenated tensor.
+        replacement: String,
+    },
+}
+
+/// Apply the rules to the tensors.
+///
+/// The remapped keys, pairs of a new key and of the original key, are updated with the keys of
+/// the transformed tensors.
+pub(crate) fn apply_rules(
+    mut tensors: HashMap<String, Tensor>,
+    remapped_keys: Vec<(String, String)>,
+    rules: &[TensorRule],
+) -> Result<(HashMap<String, Tensor>, Vec<(String, String)>), Error> {
+    if rules.is_empty() {
+        return Ok((tensors, remapped_keys));
+    }
+
+    let mut original_keys: HashMap<String, String> = remapped_keys.into_iter().collect();
+
+    for rule in rules {
+        match rule {
+            TensorRule::Transpose { pattern } => {
+                for (key, tensor) in tensors.iter_mut() {
+                    if pattern.is_match(key) {
+                        let rank = tensor.rank();
+                        if rank < 2 {
+                            return Err(Error::Other(format!(
+                                "Can't transpose {key} with {rank} dimension(s)"
+                            )));
+                        }
+                        *tensor = tensor.t()?.contiguous()?;
+                    }
+                }
+            }
+            TensorRule::Split {
+                pattern,
+                dim,
+                replacements,
+            } => {
+                let keys: Vec<String> = tensors
+                    .keys()
+                    .filter(|key| pattern.is_match(key))
+                    .cloned()
+                    .collect();
+
+                for key in keys {
+                    let tensor = tensors.remove(&key).unwrap();
+                    let original_key = original_keys.remove(&key).unwrap_or(key.clone());
+
+                    let size = tensor.dim(*dim)?;
+                    if size % replacements.len() != 0 {
+                        return Err(Error::Other(format!(
+                            "Can't split the dimension {dim} of size {size} of {key} into {} \
+                             chunks",
+                            replacements.len()
+                        )));
+                    }
+
+                    let chunks = tensor.chunk(replacements.len(), *dim)?;
+                    for (chunk, replacement) in chunks.into_iter().zip(replacements) {
+                        let new_key = pattern.replace(&key, replacement.as_str()).to_string();
+                        original_keys.insert(new_key.clone(), original_key.clone());
+                        tensors.insert(new_key, chunk.contiguous()?);
+                    }
+                }
+            }
+            TensorRule::Concat {
+                pattern,
+                dim,
+                replacement,
+            } => {
+                let mut shards: HashMap<String, Vec<(usize, String)>> = HashMap::new();
+
+                for key in tensors.keys() {
+                    let Some(captures) = pattern.captures(key) else {
+                        continue;
+                    };
+                    let shard = captures
+                        .name("shard")
+                        .and_then(|shard| shard.as_str().parse::<usize>().ok())
+                        .ok_or_else(|| {
+                            Error::Other(format!("No shard index in {key} for {pattern}"))
+                        })?;
+                    let new_key = pattern.replace(key, replacement.as_str()).to_string();
+
+                    shards
+                        .entry(new_key)
+                        .or_default()
+                        .push((shard, key.clone()));
+                }
+
+                for (new_key, mut keys) in shards {
+                    keys.sort();
+
+                    let mut parts = Vec::with_capacity(keys.len());
+                    let mut original_parts = Vec::with_capacity(keys.len());
+                    for (_, key) in keys {
+                        parts.push(tensors.remove(&key).unwrap());
+                        original_parts.push(original_keys.remove(&key).unwrap_or(key));
+                    }
+
+                    original_keys.insert(new_key.clone(), original_parts.join(", "));
+                    tensors.insert(new_key, Tensor::cat(&parts, *dim)?);
+                }
+            }
+        }
+    }
+
+    Ok((tensors, original_keys.into_iter().collect()))
+}
@@ -0,0 +1,27 @@
+use burn::record::{serde::error, RecorderError};
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("Serde error: {0}")]
+    Serde(#[from] error::Error),
+
+    #[error("Candle safetensors error: {0}")]
+    Candle(#[from] candle_core::Error),
+
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Index error: {0}")]
+    Index(#[from] serde_json::Error),
+
+    // Add other kinds of errors as needed
+    #[error("other error: {0}")]
+    Other(String),
+}
+
+// Implement From trait for Error to RecorderError
+impl From<Error> for RecorderError {
+    fn from(error: Error) -> Self {
+        RecorderError::DeserializeError(error.to_string())
+    }
+}
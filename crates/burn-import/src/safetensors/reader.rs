@@ -0,0 +1,223 @@
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+use std::sync::Arc;
+
+use super::{error::Error, recorder::AdapterType};
+use crate::pytorch::PyTorchAdapter;
+
+use burn::{
+    module::ParamId,
+    record::{
+        serde::{
+            adapter::DefaultAdapter,
+            data::{remap, unflatten, NestedValue, Serializable},
+            de::Deserializer,
+            error,
+            ser::Serializer,
+        },
+        PrecisionSettings,
+    },
+    tensor::{backend::Backend, TensorData},
+};
+
+use candle_core::{safetensors::MmapedSafetensors, DType, Device};
+use half::{bf16, f16};
+use regex::Regex;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+/// The index of a sharded checkpoint, e.g. `model.safetensors.index.json`, mapping each tensor to
+/// the shard storing it.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub(super) struct ShardIndex {
+    #[serde(default)]
+    pub metadata: BTreeMap<String, serde_json::Value>,
+    pub weight_map: BTreeMap<String, String>,
+}
+
+/// Deserializes a safetensors file, or the shards listed by the index of a sharded checkpoint.
+///
+/// # Arguments
+///
+/// * `path` - The path of the safetensors file or of the index (`.json` extension).
+/// * `key_remap` - A vector of tuples containing a regular expression and a replacement string.
+/// * `adapter_type` - The layout of the tensors.
+pub fn from_file<PS, D, B>(
+    path: &Path,
+    key_remap: Vec<(Regex, String)>,
+    adapter_type: AdapterType,
+    debug: bool,
+) -> Result<D, Error>
+where
+    D: DeserializeOwned,
+    PS: PrecisionSettings,
+    B: Backend,
+{
+    let tensors = match path.extension().and_then(|extension| extension.to_str()) {
+        Some("json") => tensors_from_index(path)?,
+        _ => tensors_from_shard(Arc::new(map_shard(path)?))?,
+    };
+
+    // Remap the keys (replace the keys in the map with the new keys)
+    let (tensors, remapped_keys) = remap(tensors, key_remap);
+
+    // Print the remapped keys if debug is enabled
+    if debug {
+        let mut remapped_keys = remapped_keys;
+        remapped_keys.sort();
+        println!("Debug information of keys and tensor shapes:\n---");
+        for (new_key, old_key) in remapped_keys {
+            if old_key != new_key {
+                println!("Original Key: {old_key}");
+                println!("Remapped Key: {new_key}");
+            } else {
+                println!("Key: {}", new_key);
+            }
+
+            let tensor = &tensors[&new_key];
+            let view = tensor.shard.get(&tensor.name)?;
+            println!("Shape: {:?}", view.shape());
+            println!("Dtype: {:?}", view.dtype());
+            println!("---");
+        }
+    }
+
+    // Convert the map of tensors to a nested value data structure
+    let nested_value = unflatten::<PS, _>(tensors)?;
+
+    // Deserialize the nested value into a record type
+    let value = match adapter_type {
+        AdapterType::PyTorch => D::deserialize(Deserializer::<PyTorchAdapter<PS, B>>::new(
+            nested_value,
+            true,
+        ))?,
+        AdapterType::NoAdapter => {
+            D::deserialize(Deserializer::<DefaultAdapter>::new(nested_value, true))?
+        }
+    };
+    Ok(value)
+}
+
+/// The tensors of a sharded checkpoint, each one read from the shard the index maps it to.
+///
+/// The shards are memory mapped, so a tensor is only read from its shard when it is converted.
+fn tensors_from_index(path: &Path) -> Result<HashMap<String, SafetensorsTensor>, Error> {
+    let index: ShardIndex = serde_json::from_reader(std::fs::File::open(path)?)?;
+    let directory = path.parent().unwrap_or(Path::new(""));
+
+    let mut shards: HashMap<&String, Arc<MmapedSafetensors>> = HashMap::new();
+    let mut tensors = HashMap::with_capacity(index.weight_map.len());
+
+    for (name, file) in index.weight_map.iter() {
+        let shard = match shards.get(file) {
+            Some(shard) => shard.clone(),
+            None => {
+                let shard = Arc::new(map_shard(&directory.join(file))?);
+                shards.insert(file, shard.clone());
+                shard
+            }
+        };
+
+        // Fail early if the index references a tensor missing from its shard.
+        shard.get(name)?;
+        let tensor = SafetensorsTensor {
+            shard,
+            name: name.clone(),
+        };
+        tensors.insert(name.clone(), tensor);
+    }
+
+    Ok(tensors)
+}
+
+/// All the tensors of a single safetensors file.
+fn tensors_from_shard(
+    shard: Arc<MmapedSafetensors>,
+) -> Result<HashMap<String, SafetensorsTensor>, Error> {
+    let names = shard
+        .tensors()
+        .into_iter()
+        .map(|(name, _)| name)
+        .collect::<Vec<_>>();
+
+    Ok(names
+        .into_iter()
+        .map(|name| {
+            let tensor = SafetensorsTensor {
+                shard: shard.clone(),
+                name: name.clone(),
+            };
+            (name, tensor)
+        })
+        .collect())
+}
+
+/// Memory maps a safetensors file.
+fn map_shard(path: &Path) -> Result<MmapedSafetensors, Error> {
+    // SAFETY: the file is only read, and is expected not to be modified while it is loaded.
+    Ok(unsafe { MmapedSafetensors::new(path)? })
+}
+
+/// Convert a candle tensor to tensor data, keeping its data type.
+fn to_tensor_data(tensor: candle_core::Tensor) -> Result<TensorData, Error> {
+    let shape = tensor.dims().to_vec();
+    let tensor = tensor.flatten_all()?;
+
+    let data = match tensor.dtype() {
+        DType::U8 => TensorData::new(tensor.to_vec1::<u8>()?, shape),
+        DType::U32 => TensorData::new(tensor.to_vec1::<u32>()?, shape),
+        DType::I64 => TensorData::new(tensor.to_vec1::<i64>()?, shape),
+        DType::BF16 => TensorData::new(tensor.to_vec1::<bf16>()?, shape),
+        DType::F16 => TensorData::new(tensor.to_vec1::<f16>()?, shape),
+        DType::F32 => TensorData::new(tensor.to_vec1::<f32>()?, shape),
+        DType::F64 => TensorData::new(tensor.to_vec1::<f64>()?, shape),
+    };
+
+    Ok(data)
+}
+
+/// A tensor of a memory mapped safetensors shard, read when it is serialized.
+struct SafetensorsTensor {
+    shard: Arc<MmapedSafetensors>,
+    name: String,
+}
+
+impl SafetensorsTensor {
+    /// Reads the values of the tensor from its shard.
+    fn read(&self) -> Result<TensorData, Error> {
+        to_tensor_data(self.shard.load(&self.name, &Device::Cpu)?)
+    }
+}
+
+/// Serializes a safetensors tensor.
+///
+/// The tensor is read from its shard, wrapped in a `Param` struct (learnable parameters) and
+/// serialized as a `TensorData` struct, with the values converted to the `FloatElem` or `IntElem`
+/// of the precision settings.
+impl Serializable for SafetensorsTensor {
+    fn serialize<PS>(&self, serializer: Serializer) -> Result<NestedValue, error::Error>
+    where
+        PS: PrecisionSettings,
+    {
+        let data = self
+            .read()
+            .map_err(|err| error::Error::Other(err.to_string()))?;
+        let data = if data.dtype.is_float() {
+            data.convert::<PS::FloatElem>()
+        } else {
+            data.convert::<PS::IntElem>()
+        };
+        let shape = data.shape.clone();
+        let (dtype, bytes) = (data.dtype, data.into_bytes());
+
+        let mut tensor_data: HashMap<String, NestedValue> = HashMap::new();
+        tensor_data.insert("bytes".into(), NestedValue::Bytes(bytes));
+        tensor_data.insert("shape".into(), shape.serialize(serializer.clone())?);
+        tensor_data.insert("dtype".into(), dtype.serialize(serializer)?);
+
+        let mut param: HashMap<String, NestedValue> = HashMap::new();
+        param.insert("id".into(), NestedValue::String(ParamId::new().serialize()));
+        param.insert("param".into(), NestedValue::Map(tensor_data));
+
+        Ok(NestedValue::Map(param))
+    }
+}
@@ -0,0 +1,405 @@
+use core::marker::PhantomData;
+use std::path::PathBuf;
+
+use burn::{
+    record::{serde::ser::Serializer, PrecisionSettings, Record, Recorder, RecorderError},
+    tensor::backend::Backend,
+};
+
+use regex::Regex;
+use serde::{de::DeserializeOwned, Serialize};
+
+use super::{reader::from_file, writer::save_file};
+
+/// A recorder that loads and saves safetensors files (`.safetensors`), including the sharded
+/// checkpoints of Hugging Face made of `model-00001-of-0000N.safetensors` files listed by a
+/// `model.safetensors.index.json` index.
+///
+/// The tensors are loaded with the PyTorch layout by default, since it's the one of the models
+/// published on Hugging Face, while the records are saved with the Burn layout and are loaded
+/// back with [AdapterType::NoAdapter].
+///
+/// LoadArgs can be used to remap keys or file path, and SaveArgs to shard the saved records.
+/// See [LoadArgs](struct.LoadArgs.html) and [SaveArgs](struct.SaveArgs.html) for more
+/// information.
+#[derive(new, Debug, Default, Clone)]
+pub struct SafetensorsFileRecorder<PS: PrecisionSettings> {
+    _settings: PhantomData<PS>,
+}
+
+impl<PS: PrecisionSettings, B: Backend> Recorder<B> for SafetensorsFileRecorder<PS> {
+    type Settings = PS;
+    type RecordArgs = SaveArgs;
+    type RecordOutput = ();
+    type LoadArgs = LoadArgs;
+
+    fn record<R: Record<B>>(&self, record: R, args: Self::RecordArgs) -> Result<(), RecorderError> {
+        // Only the tensors are saved, so the record isn't wrapped with its metadata.
+        self.save_item(record.into_item::<PS>(), args)
+    }
+
+    fn save_item<I: Serialize>(
+        &self,
+        item: I,
+        args: Self::RecordArgs,
+    ) -> Result<(), RecorderError> {
+        let value = item
+            .serialize(Serializer::new())
+            .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+
+        save_file(value, &args.file, args.max_shard_size)
+            .map_err(|err| RecorderError::Unknown(err.to_string()))
+    }
+
+    fn load_item<I: DeserializeOwned>(&self, _file: Self::LoadArgs) -> Result<I, RecorderError> {
+        unimplemented!("load_item not implemented for SafetensorsFileRecorder")
+    }
+
+    fn load<R: Record<B>>(
+        &self,
+        args: Self::LoadArgs,
+        device: &B::Device,
+    ) -> Result<R, RecorderError> {
+        let item = from_file::<PS, R::Item<Self::Settings>, B>(
+            &args.file,
+            args.key_remap,
+            args.adapter_type,
+            args.debug,
+        )?;
+        Ok(R::from_item(item, device))
+    }
+}
+
+/// The layout of the tensors of a safetensors file.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AdapterType {
+    /// The PyTorch layout, e.g. the linear weights are `[d_output, d_input]`.
+    #[default]
+    PyTorch,
+
+    /// The Burn layout, used to save the records.
+    NoAdapter,
+}
+
+/// Arguments for loading a safetensors file.
+///
+/// # Fields
+///
+/// * `file` - The path to the file to load, or to the index of a sharded checkpoint (`.json`
+///            extension), whose shards are memory mapped and read one tensor at a time.
+/// * `key_remap` - A vector of tuples containing a regular expression and a replacement string.
+///                See [regex::Regex::replace](https://docs.rs/regex/latest/regex/struct.Regex.html#method.replace)
+///                for more information.
+/// * `adapter_type` - The layout of the tensors.
+///
+/// # Examples
+///
+/// ```text
+/// use burn_import::safetensors::{LoadArgs, SafetensorsFileRecorder};
+/// use burn::record::FullPrecisionSettings;
+/// use burn::record::Recorder;
+///
+/// let args = LoadArgs::new("model.safetensors.index.json".into())
+///     .with_key_remap("^model\\.", "");
+///
+/// let record = SafetensorsFileRecorder::<FullPrecisionSettings>::default()
+///     .load(args, &device)
+///     .expect("Should decode state successfully");
+/// ```
+#[derive(Debug, Clone)]
+pub struct LoadArgs {
+    /// The path to the file to load.
+    pub file: PathBuf,
+
+    /// A list of key remappings.
+    pub key_remap: Vec<(Regex, String)>,
+
+    /// The layout of the tensors.
+    pub adapter_type: AdapterType,
+
+    /// Whether to print debug information.
+    pub debug: bool,
+}
+
+impl LoadArgs {
+    /// Creates a new `LoadArgs` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `file` - The path to the file to load.
+    pub fn new(file: PathBuf) -> Self {
+        Self {
+            file,
+            key_remap: Vec::new(),
+            adapter_type: AdapterType::default(),
+            debug: false,
+        }
+    }
+
+    /// Sets key remapping.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The Regex pattern to be replaced.
+    /// * `replacement` - The pattern to replace with.
+    ///
+    /// See [Regex](https://docs.rs/regex/1.5.4/regex/#syntax) for the pattern syntax and
+    /// [Replacement](https://docs.rs/regex/latest/regex/struct.Regex.html#method.replace) for the
+    /// replacement syntax.
+    pub fn with_key_remap(mut self, pattern: &str, replacement: &str) -> Self {
+        let regex = Regex::new(pattern).expect("Valid regex");
+
+        self.key_remap.push((regex, replacement.into()));
+        self
+    }
+
+    /// Sets the layout of the tensors.
+    pub fn with_adapter_type(mut self, adapter_type: AdapterType) -> Self {
+        self.adapter_type = adapter_type;
+        self
+    }
+
+    /// Sets printing debug information on.
+    pub fn with_debug_print(mut self) -> Self {
+        self.debug = true;
+        self
+    }
+}
+
+impl From<PathBuf> for LoadArgs {
+    fn from(val: PathBuf) -> Self {
+        LoadArgs::new(val)
+    }
+}
+
+impl From<String> for LoadArgs {
+    fn from(val: String) -> Self {
+        LoadArgs::new(val.into())
+    }
+}
+
+impl From<&str> for LoadArgs {
+    fn from(val: &str) -> Self {
+        LoadArgs::new(val.into())
+    }
+}
+
+/// Arguments for saving a safetensors file.
+///
+/// # Fields
+///
+/// * `file` - The path of the file to save, e.g. `model.safetensors`.
+/// * `max_shard_size` - The maximum size of a shard in bytes. The record is saved to a single file
+///                      when it fits, otherwise to `model-00001-of-0000N.safetensors` shards
+///                      listed by the `model.safetensors.index.json` index.
+#[derive(Debug, Clone)]
+pub struct SaveArgs {
+    /// The path of the file to save.
+    pub file: PathBuf,
+
+    /// The maximum size of a shard in bytes.
+    pub max_shard_size: Option<usize>,
+}
+
+impl SaveArgs {
+    /// Creates a new `SaveArgs` instance, saving the record to a single file.
+    ///
+    /// # Arguments
+    ///
+    /// * `file` - The path of the file to save.
+    pub fn new(file: PathBuf) -> Self {
+        Self {
+            file,
+            max_shard_size: None,
+        }
+    }
+
+    /// Sets the maximum size of a shard in bytes.
+    pub fn with_max_shard_size(mut self, max_shard_size: usize) -> Self {
+        self.max_shard_size = Some(max_shard_size);
+        self
+    }
+}
+
+impl From<PathBuf> for SaveArgs {
+    fn from(val: PathBuf) -> Self {
+        SaveArgs::new(val)
+    }
+}
+
+impl From<&str> for SaveArgs {
+    fn from(val: &str) -> Self {
+        SaveArgs::new(val.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn::{
+        module::{Module, Param, ParamId},
+        nn::{Linear, LinearConfig},
+        record::FullPrecisionSettings,
+        tensor::{Int, Tensor as BurnTensor, TensorData},
+    };
+    use burn_ndarray::NdArray;
+    use candle_core::{Device, Tensor};
+    use std::collections::HashMap;
+
+    type TestBackend = NdArray<f32>;
+
+    #[derive(Module, Debug)]
+    struct Net<B: Backend> {
+        fc1: Linear<B>,
+        fc2: Linear<B>,
+    }
+
+    impl<B: Backend> Net<B> {
+        fn new(device: &B::Device) -> Self {
+            Self {
+                fc1: LinearConfig::new(4, 8).init(device),
+                fc2: LinearConfig::new(8, 2).init(device),
+            }
+        }
+    }
+
+    #[derive(Record)]
+    struct IndicesRecord<B: Backend> {
+        indices: Param<BurnTensor<B, 1, Int>>,
+    }
+
+    #[derive(Record)]
+    struct StepRecord<B: Backend> {
+        weight: Param<BurnTensor<B, 1>>,
+        step: usize,
+    }
+
+    /// Settings saving the integer tensors as `u64`, which can't be saved without loss.
+    #[derive(Debug, Default, Clone)]
+    struct U64Settings;
+
+    impl PrecisionSettings for U64Settings {
+        type FloatElem = f32;
+        type IntElem = u64;
+    }
+
+    fn indices_record(
+        indices: [i32; 4],
+        device: &<TestBackend as Backend>::Device,
+    ) -> IndicesRecord<TestBackend> {
+        IndicesRecord {
+            indices: Param::initialized(ParamId::new(), BurnTensor::from_ints(indices, device)),
+        }
+    }
+
+    #[test]
+    fn should_widen_int_tensors_to_i64() {
+        let file = std::env::temp_dir().join("burn_import_safetensors_widen.safetensors");
+        let device = Default::default();
+        let recorder = SafetensorsFileRecorder::<FullPrecisionSettings>::default();
+
+        // The i32 integers of the full precision settings are saved as i64.
+        let record = indices_record([-3, 0, 7, i32::MAX], &device);
+        Recorder::<TestBackend>::record(&recorder, record, file.clone().into()).unwrap();
+        let tensors = candle_core::safetensors::load(&file, &Device::Cpu).unwrap();
+        assert_eq!(tensors["indices"].dtype(), candle_core::DType::I64);
+
+        let args = LoadArgs::new(file.clone()).with_adapter_type(AdapterType::NoAdapter);
+        let record: IndicesRecord<TestBackend> = recorder.load(args, &device).unwrap();
+        std::fs::remove_file(file).unwrap();
+
+        record
+            .indices
+            .val()
+            .into_data()
+            .assert_eq(&TensorData::from([-3i64, 0, 7, i32::MAX as i64]), false);
+    }
+
+    #[test]
+    fn should_not_save_u64_tensors() {
+        let file = std::env::temp_dir().join("burn_import_safetensors_u64.safetensors");
+        let device = Default::default();
+        let recorder = SafetensorsFileRecorder::<U64Settings>::default();
+
+        let record = indices_record([0, 3, 7, 12], &device);
+
+        let result = Recorder::<TestBackend>::record(&recorder, record, file.clone().into());
+
+        assert!(result.is_err());
+        assert!(!file.exists());
+    }
+
+    #[test]
+    fn should_not_save_fields_other_than_tensors() {
+        let file = std::env::temp_dir().join("burn_import_safetensors_step.safetensors");
+        let device = Default::default();
+        let recorder = SafetensorsFileRecorder::<FullPrecisionSettings>::default();
+        let record = StepRecord::<TestBackend> {
+            weight: Param::from_tensor(BurnTensor::ones([4], &device)),
+            step: 12,
+        };
+
+        let result = Recorder::<TestBackend>::record(&recorder, record, file.clone().into());
+
+        assert!(result.is_err());
+        assert!(!file.exists());
+    }
+
+    #[test]
+    fn should_save_and_load_sharded_records() {
+        let directory = std::env::temp_dir().join("burn_import_safetensors_sharded");
+        std::fs::create_dir_all(&directory).unwrap();
+        let device = Default::default();
+        let net = Net::<TestBackend>::new(&device);
+        let recorder = SafetensorsFileRecorder::<FullPrecisionSettings>::default();
+
+        // The 128 bytes weight of fc1 doesn't fit with the other tensors, so 3 shards are saved.
+        let args = SaveArgs::new(directory.join("model.safetensors")).with_max_shard_size(128);
+        Recorder::<TestBackend>::record(&recorder, net.clone().into_record(), args).unwrap();
+
+        let index = directory.join("model.safetensors.index.json");
+        assert!(directory.join("model-00002-of-00003.safetensors").exists());
+
+        let args = LoadArgs::new(index).with_adapter_type(AdapterType::NoAdapter);
+        let record: NetRecord<TestBackend> = recorder.load(args, &device).unwrap();
+        std::fs::remove_dir_all(directory).unwrap();
+
+        let loaded = Net::<TestBackend>::new(&device).load_record(record);
+        for (expected, actual) in [(&net.fc1, &loaded.fc1), (&net.fc2, &loaded.fc2)] {
+            actual
+                .weight
+                .val()
+                .into_data()
+                .assert_eq(&expected.weight.val().into_data(), true);
+            actual
+                .bias
+                .as_ref()
+                .unwrap()
+                .val()
+                .into_data()
+                .assert_eq(&expected.bias.as_ref().unwrap().val().into_data(), true);
+        }
+    }
+
+    #[test]
+    fn should_load_pytorch_layout() {
+        let file = std::env::temp_dir().join("burn_import_safetensors_pytorch.safetensors");
+        let weight = Tensor::new(&[[1.0f32, 2.0], [3.0, 4.0], [5.0, 6.0]], &Device::Cpu).unwrap();
+        let bias = Tensor::new(&[0.5f32, 0.5, 0.5], &Device::Cpu).unwrap();
+        candle_core::safetensors::save(&HashMap::from([("weight", weight), ("bias", bias)]), &file)
+            .unwrap();
+
+        let device = Default::default();
+        let record = SafetensorsFileRecorder::<FullPrecisionSettings>::default()
+            .load(file.clone().into(), &device)
+            .unwrap();
+        std::fs::remove_file(file).unwrap();
+
+        // The [d_output, d_input] weight is transposed to [d_input, d_output].
+        let linear: Linear<TestBackend> = LinearConfig::new(2, 3).init(&device).load_record(record);
+        linear.weight.val().into_data().assert_eq(
+            &TensorData::from([[1.0f32, 3.0, 5.0], [2.0, 4.0, 6.0]]),
+            true,
+        );
+    }
+}
@@ -0,0 +1,231 @@
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+
+use super::{error::Error, reader::ShardIndex};
+
+use burn::{
+    record::serde::data::NestedValue,
+    tensor::{DType, TensorData},
+};
+
+use candle_core::{safetensors, Device};
+
+/// Save the tensors of a serialized record to a safetensors file, or to shards of at most
+/// `max_shard_size` bytes listed by an index when the tensors don't fit in a single shard.
+///
+/// The shards of `model.safetensors` are saved next to it as `model-00001-of-0000N.safetensors`
+/// with the `model.safetensors.index.json` index, like the sharded checkpoints of Hugging Face.
+/// A tensor larger than the maximum size gets a shard of its own.
+///
+/// The `i32`, `i16`, `i8` and `u16` tensors are widened to `i64`, since safetensors files are
+/// loaded with candle which doesn't support those data types. The widening is lossless and the
+/// integer tensors are converted to the integer element of the precision settings when loaded.
+/// The `u64` tensors can't be widened without loss, so an error is returned.
+///
+/// An error is returned when the record contains a field that isn't a tensor, since it couldn't
+/// be loaded back from the file.
+pub fn save_file(
+    value: NestedValue,
+    path: &Path,
+    max_shard_size: Option<usize>,
+) -> Result<(), Error> {
+    let mut tensors = Vec::new();
+    collect_tensors(value, String::new(), &mut tensors)?;
+    tensors.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    // Group the tensors into shards in the order of their keys
+    let mut shards: Vec<Vec<(String, TensorData)>> = vec![Vec::new()];
+    let mut shard_size = 0;
+    let mut total_size = 0;
+    for (key, data) in tensors {
+        let size = data.as_bytes().len();
+        let current = shards.last_mut().unwrap();
+
+        if let Some(max_shard_size) = max_shard_size {
+            if !current.is_empty() && shard_size + size > max_shard_size {
+                shards.push(Vec::new());
+                shard_size = 0;
+            }
+        }
+
+        shard_size += size;
+        total_size += size;
+        shards.last_mut().unwrap().push((key, data));
+    }
+
+    if shards.len() == 1 {
+        return save_shard(shards.pop().unwrap(), path);
+    }
+
+    let directory = path.parent().unwrap_or(Path::new(""));
+    let stem = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .map(|name| name.trim_end_matches(".safetensors"))
+        .ok_or_else(|| Error::Other(format!("Invalid file name {}", path.display())))?;
+
+    let num_shards = shards.len();
+    let mut index = ShardIndex::default();
+    index
+        .metadata
+        .insert("total_size".into(), serde_json::Value::from(total_size));
+
+    for (i, shard) in shards.into_iter().enumerate() {
+        let file = format!("{stem}-{:05}-of-{num_shards:05}.safetensors", i + 1);
+        for (key, _) in shard.iter() {
+            index.weight_map.insert(key.clone(), file.clone());
+        }
+        save_shard(shard, &directory.join(file))?;
+    }
+
+    let index_path: PathBuf = directory.join(format!("{stem}.safetensors.index.json"));
+    serde_json::to_writer_pretty(std::fs::File::create(index_path)?, &index)?;
+
+    Ok(())
+}
+
+/// Save tensors to a single safetensors file.
+fn save_shard(tensors: Vec<(String, TensorData)>, path: &Path) -> Result<(), Error> {
+    let mut candle_tensors = HashMap::with_capacity(tensors.len());
+
+    for (key, data) in tensors {
+        // Candle only supports some of the data types, the others are widened when lossless
+        let (data, dtype) = match data.dtype {
+            DType::F64 => (data, candle_core::DType::F64),
+            DType::F32 => (data, candle_core::DType::F32),
+            DType::F16 => (data, candle_core::DType::F16),
+            DType::BF16 => (data, candle_core::DType::BF16),
+            DType::I64 => (data, candle_core::DType::I64),
+            DType::U32 => (data, candle_core::DType::U32),
+            DType::U8 | DType::Bool => (data, candle_core::DType::U8),
+            DType::I32 | DType::I16 | DType::I8 | DType::U16 => {
+                (data.convert::<i64>(), candle_core::DType::I64)
+            }
+            DType::U64 => {
+                return Err(Error::Other(format!(
+                    "Can't save the u64 tensor {key} without loss"
+                )))
+            }
+            dtype => {
+                return Err(Error::Other(format!(
+                    "Unsupported data type {dtype:?} for {key}"
+                )))
+            }
+        };
+
+        let tensor = candle_core::Tensor::from_raw_buffer(
+            data.as_bytes(),
+            dtype,
+            &data.shape,
+            &Device::Cpu,
+        )?;
+        candle_tensors.insert(key, tensor);
+    }
+
+    safetensors::save(&candle_tensors, path)?;
+
+    Ok(())
+}
+
+/// Collect the tensors of a serialized record, with the path of their fields as keys.
+fn collect_tensors(
+    value: NestedValue,
+    key: String,
+    tensors: &mut Vec<(String, TensorData)>,
+) -> Result<(), Error> {
+    let child_key = |name: &str| {
+        if key.is_empty() {
+            name.to_string()
+        } else {
+            format!("{key}.{name}")
+        }
+    };
+
+    match value {
+        NestedValue::Map(mut map) => {
+            // A param is serialized as its id and its tensor data
+            if let Some(NestedValue::Map(param)) = map.remove("param") {
+                if param.contains_key("bytes") {
+                    tensors.push((key.clone(), to_tensor_data(param, &key)?));
+                    return Ok(());
+                }
+                map.insert("param".into(), NestedValue::Map(param));
+            }
+
+            let map: BTreeMap<String, NestedValue> = map.into_iter().collect();
+            for (name, value) in map {
+                collect_tensors(value, child_key(&name), tensors)?;
+            }
+        }
+        NestedValue::Vec(values) => {
+            for (i, value) in values.into_iter().enumerate() {
+                collect_tensors(value, child_key(&i.to_string()), tensors)?;
+            }
+        }
+        // Constant fields and `None` params are serialized without any value
+        NestedValue::Default(_) => {}
+        _ => {
+            return Err(Error::Other(format!(
+                "The field {key} is not a tensor and can't be saved to a safetensors file"
+            )))
+        }
+    }
+
+    Ok(())
+}
+
+/// Convert the serialized fields of a tensor data.
+fn to_tensor_data(
+    mut fields: HashMap<String, NestedValue>,
+    key: &str,
+) -> Result<TensorData, Error> {
+    let invalid = |field: &str| Error::Other(format!("Invalid {field} of the tensor {key}"));
+
+    let bytes = match fields.remove("bytes") {
+        Some(NestedValue::U8s(bytes)) => bytes,
+        Some(NestedValue::Bytes(bytes)) => bytes.to_vec(),
+        _ => return Err(invalid("bytes")),
+    };
+
+    let shape = match fields.remove("shape") {
+        Some(NestedValue::Vec(dims)) => dims
+            .into_iter()
+            .map(|dim| match dim {
+                NestedValue::U64(dim) => Ok(dim as usize),
+                NestedValue::I64(dim) => Ok(dim as usize),
+                NestedValue::U8(dim) => Ok(dim as usize),
+                NestedValue::U16(dim) => Ok(dim as usize),
+                _ => Err(invalid("shape")),
+            })
+            .collect::<Result<Vec<_>, _>>()?,
+        Some(NestedValue::U8s(dims)) => dims.into_iter().map(|dim| dim as usize).collect(),
+        Some(NestedValue::U16s(dims)) => dims.into_iter().map(|dim| dim as usize).collect(),
+        _ => return Err(invalid("shape")),
+    };
+
+    // The data type is serialized as a unit variant of the `DType` enum
+    let dtype = match fields.remove("dtype") {
+        Some(NestedValue::Map(mut dtype)) => match dtype.remove("DType") {
+            Some(NestedValue::String(name)) => match name.as_str() {
+                "F64" => DType::F64,
+                "F32" => DType::F32,
+                "F16" => DType::F16,
+                "BF16" => DType::BF16,
+                "I64" => DType::I64,
+                "I32" => DType::I32,
+                "I16" => DType::I16,
+                "I8" => DType::I8,
+                "U64" => DType::U64,
+                "U32" => DType::U32,
+                "U16" => DType::U16,
+                "U8" => DType::U8,
+                "Bool" => DType::Bool,
+                _ => return Err(invalid("dtype")),
+            },
+            _ => return Err(invalid("dtype")),
+        },
+        _ => return Err(invalid("dtype")),
+    };
+
+    Ok(TensorData::from_bytes(bytes, shape, dtype))
+}
@@ -0,0 +1,44 @@
+#!/usr/bin/env python3
+
+# Generates the HDF5 fixtures of the Keras reader tests (`src/keras/hdf5.rs`).
+#
+# Requirements: `pip install keras h5py numpy`
+
+import os
+
+# The weights are only saved, so the numpy backend is enough.
+os.environ.setdefault("KERAS_BACKEND", "numpy")
+
+import h5py
+import keras
+import numpy as np
+
+
+def main():
+    # Values exactly representable as f32, so the tests can compare them exactly.
+    kernel = np.arange(6, dtype=np.float32).reshape(2, 3) * 0.5
+    bias = np.array([-1.0, 0.0, 1.0], dtype=np.float32)
+
+    # Saved by Keras, with the structures written by h5py by default.
+    model = keras.Sequential([keras.Input(shape=(2,)), keras.layers.Dense(3, name="dense")])
+    model.get_layer("dense").set_weights([kernel, bias])
+    model.save_weights("dense.weights.h5")
+
+    # The same weights with the latest file format: version 2 object headers, groups stored as
+    # link messages and attribute messages.
+    with h5py.File("dense_latest.weights.h5", "w", libver="latest", track_order=True) as file:
+        file.attrs["keras_version"] = keras.__version__
+        group = file.create_group("layers/dense/vars")
+        group.attrs["name"] = "dense"
+        group.create_dataset("0", data=kernel)
+        group.create_dataset("1", data=bias)
+
+    # More than 8 links, so the links of the group are stored in a fractal heap.
+    with h5py.File("dense_links.h5", "w", libver="latest") as file:
+        group = file.create_group("vars")
+        for i in range(10):
+            group.create_dataset(str(i), data=np.zeros(1, dtype=np.float32))
+
+
+if __name__ == "__main__":
+    main()
@@ -1,7 +1,8 @@
-use crate::{element::BoolElement, tensor::JitTensor, FloatElement, IntElement, JitRuntime};
-use burn_tensor::backend::{Backend, DeviceOps};
+use crate::{
+    element::BoolElement, memory, tensor::JitTensor, FloatElement, IntElement, JitRuntime,
+};
+use burn_tensor::backend::{Backend, DeviceOps, MemoryStats, RngState};
 use cubecl::server::ComputeServer;
-use rand::{rngs::StdRng, SeedableRng};
 use std::{marker::PhantomData, sync::Mutex};
 
 #[cfg(not(feature = "fusion"))]
@@ -10,7 +11,7 @@ use burn_tensor::{
     repr::{ReprBackend, TensorHandle},
 };
 
-pub(crate) static SEED: Mutex<Option<StdRng>> = Mutex::new(None);
+pub(crate) static RNG_STATE: Mutex<Option<RngState>> = Mutex::new(None);
 
 /// Generic tensor backend that can be compiled just-in-time to any shader runtime
 #[derive(new)]
@@ -47,9 +48,16 @@ where
     }
 
     fn seed(seed: u64) {
-        let rng = StdRng::seed_from_u64(seed);
-        let mut seed = SEED.lock().unwrap();
-        *seed = Some(rng);
+        Self::set_rng_state(RngState::new(seed));
+    }
+
+    fn rng_state() -> Option<RngState> {
+        let mut state = RNG_STATE.lock().unwrap();
+        Some(*state.get_or_insert_with(RngState::from_entropy))
+    }
+
+    fn set_rng_state(state: RngState) {
+        *RNG_STATE.lock().unwrap() = Some(state);
     }
 
     fn ad_enabled() -> bool {
@@ -57,9 +65,20 @@ where
     }
 
     fn sync(device: &Self::Device) {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("sync", ?device).entered();
+
         let client = R::client(device);
         futures_lite::future::block_on(client.sync());
     }
+
+    fn memory_stats(device: &Self::Device) -> Option<MemoryStats> {
+        Some(memory::memory_stats::<R>(device))
+    }
+
+    fn set_memory_budget(device: &Self::Device, budget: Option<u64>) {
+        memory::set_memory_budget::<R>(device, budget)
+    }
 }
 
 impl<R: JitRuntime, F: FloatElement, I: IntElement, BT: BoolElement> core::fmt::Debug
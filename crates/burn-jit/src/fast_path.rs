@@ -0,0 +1,122 @@
+use core::fmt::{Debug, Display};
+use std::{collections::HashMap, sync::Mutex};
+
+/// The number of times each fast path was rejected, by kernel and reason.
+static MISSES: Mutex<Option<HashMap<FastPathMiss, usize>>> = Mutex::new(None);
+
+/// A fast kernel that was rejected at runtime, with the reason it couldn't be launched.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FastPathMiss {
+    /// The name of the rejected kernel, e.g. `conv2d_implicit_gemm`.
+    pub kernel: &'static str,
+    /// Why the kernel was rejected, e.g. the shape not being supported or a missing feature.
+    pub reason: String,
+}
+
+/// The fast paths missed since the start of the run or the last
+/// [reset](reset_fast_path_report), to adjust the dimensions of a model so that it hits the
+/// fast kernels.
+///
+/// When autotune is enabled, the fast paths are tried once per tuning key, so a miss is
+/// recorded when the kernels are benchmarked rather than at each launch.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FastPathReport {
+    /// The missed fast paths with the number of times they were rejected, most frequent first.
+    pub misses: Vec<(FastPathMiss, usize)>,
+}
+
+impl FastPathReport {
+    /// If no fast path was missed.
+    pub fn is_empty(&self) -> bool {
+        self.misses.is_empty()
+    }
+}
+
+impl Display for FastPathReport {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.misses.is_empty() {
+            return write!(f, "No fast path missed");
+        }
+
+        writeln!(f, "Missed fast paths:")?;
+        for (miss, count) in self.misses.iter() {
+            writeln!(f, "  {} ({count}x): {}", miss.kernel, miss.reason)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Record that a fast kernel was rejected, logging the first rejection of each kernel and
+/// reason.
+pub(crate) fn record_fast_path_miss<E: Debug>(kernel: &'static str, reason: &E) {
+    let reason = format!("{reason:?}").trim().to_string();
+    let miss = FastPathMiss {
+        kernel,
+        reason: reason.clone(),
+    };
+
+    let mut misses = MISSES.lock().unwrap();
+    let count = misses
+        .get_or_insert_with(Default::default)
+        .entry(miss)
+        .or_default();
+    *count += 1;
+
+    if *count == 1 {
+        log::info!("Fast path {kernel} missed: {reason}");
+    }
+}
+
+/// The fast paths missed since the start of the run or the last
+/// [reset](reset_fast_path_report).
+pub fn fast_path_report() -> FastPathReport {
+    let mut misses = MISSES
+        .lock()
+        .unwrap()
+        .iter()
+        .flat_map(|misses| misses.iter())
+        .map(|(miss, count)| (miss.clone(), *count))
+        .collect::<Vec<_>>();
+
+    misses.sort_by(|(a, a_count), (b, b_count)| {
+        b_count
+            .cmp(a_count)
+            .then(a.kernel.cmp(b.kernel))
+            .then(a.reason.cmp(&b.reason))
+    });
+
+    FastPathReport { misses }
+}
+
+/// Clear the missed fast paths, e.g. between two runs with different model dimensions.
+pub fn reset_fast_path_report() {
+    *MISSES.lock().unwrap() = None;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_aggregate_fast_path_misses() {
+        record_fast_path_miss("test_kernel", &"Shape not divisible by 16");
+        record_fast_path_miss("test_kernel", &"Shape not divisible by 16");
+        record_fast_path_miss("test_kernel", &"Feature missing");
+
+        let report = fast_path_report();
+        let count = |reason: &str| {
+            report
+                .misses
+                .iter()
+                .find(|(miss, _)| miss.kernel == "test_kernel" && miss.reason.contains(reason))
+                .map(|(_, count)| *count)
+        };
+
+        assert_eq!(count("Shape not divisible"), Some(2));
+        assert_eq!(count("Feature missing"), Some(1));
+        assert!(report
+            .to_string()
+            .contains("test_kernel (2x): \"Shape not divisible by 16\""));
+    }
+}
@@ -1,7 +1,9 @@
 use super::elemwise::optimization::{ElemwiseOptimization, ElemwiseOptimizationState};
 use super::matmul::optimization::{MatmulOptimization, MatmulOptimizationState};
+use super::reduce::optimization::{ReduceOptimization, ReduceOptimizationState};
 use crate::fusion::elemwise::builder::ElementWiseBuilder;
 use crate::fusion::matmul::builder::MatmulBuilder;
+use crate::fusion::reduce::builder::ReduceBuilder;
 use crate::BoolElement;
 use crate::{kernel, tensor::JitTensor, FloatElement, IntElement, JitBackend, JitRuntime};
 
@@ -23,6 +25,8 @@ pub enum JitOptimization<R: JitRuntime> {
     ElementWise(ElemwiseOptimization<R>),
     /// Matrix multiplication optimization.
     Matmul(MatmulOptimization<R>),
+    /// Reduction optimization.
+    Reduce(ReduceOptimization<R>),
 }
 
 /// Fusion optimization state type for JIT.
@@ -34,6 +38,8 @@ pub enum JitOptimizationState {
     ElementWise(ElemwiseOptimizationState),
     /// Matrix multiplication optimization state.
     Matmul(MatmulOptimizationState),
+    /// Reduction optimization state.
+    Reduce(ReduceOptimizationState),
 }
 
 impl<R, BT> burn_fusion::Optimization<FusionJitRuntime<R, BT>> for JitOptimization<R>
@@ -42,9 +48,36 @@ where
     BT: BoolElement,
 {
     fn execute(&mut self, context: &mut burn_fusion::stream::Context<'_, JitFusionHandle<R>>) {
+        #[cfg(feature = "tracing")]
+        let _span = match self {
+            Self::ElementWise(op) => {
+                tracing::debug_span!(
+                    "fused_kernel",
+                    kind = "elemwise",
+                    num_ops = op.num_ops_fused()
+                )
+            }
+            Self::Matmul(op) => {
+                tracing::debug_span!(
+                    "fused_kernel",
+                    kind = "matmul",
+                    num_ops = op.num_ops_fused()
+                )
+            }
+            Self::Reduce(op) => {
+                tracing::debug_span!(
+                    "fused_kernel",
+                    kind = "reduce",
+                    num_ops = op.num_ops_fused()
+                )
+            }
+        }
+        .entered();
+
         match self {
             Self::ElementWise(op) => op.execute::<BT>(context),
             Self::Matmul(op) => op.execute::<BT>(context),
+            Self::Reduce(op) => op.execute::<BT>(context),
         }
     }
 
@@ -52,6 +85,7 @@ where
         match self {
             Self::ElementWise(op) => op.num_ops_fused(),
             Self::Matmul(op) => op.num_ops_fused(),
+            Self::Reduce(op) => op.num_ops_fused(),
         }
     }
 
@@ -59,6 +93,7 @@ where
         match self {
             Self::ElementWise(value) => JitOptimizationState::ElementWise(value.to_state()),
             Self::Matmul(value) => JitOptimizationState::Matmul(value.to_state()),
+            Self::Reduce(value) => JitOptimizationState::Reduce(value.to_state()),
         }
     }
 
@@ -70,6 +105,9 @@ where
             JitOptimizationState::Matmul(state) => {
                 Self::Matmul(MatmulOptimization::from_state(device, state))
             }
+            JitOptimizationState::Reduce(state) => {
+                Self::Reduce(ReduceOptimization::from_state(device, state))
+            }
         }
     }
 }
@@ -134,6 +172,10 @@ impl<R: JitRuntime, BT: BoolElement> FusionRuntime for FusionJitRuntime<R, BT> {
                 device.clone(),
                 BT::as_elem_native_unchecked().into(),
             )),
+            Box::new(ReduceBuilder::<R>::new(
+                device.clone(),
+                BT::as_elem_native_unchecked().into(),
+            )),
         ]
     }
 }
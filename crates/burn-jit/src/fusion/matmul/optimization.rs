@@ -1,5 +1,7 @@
 use std::any::TypeId;
 
+#[cfg(not(feature = "autotune"))]
+use crate::fast_path::record_fast_path_miss;
 use crate::fusion::elemwise::optimization::ElemwiseRunner;
 use crate::fusion::on_write::ir::ElemwisePrecision;
 use crate::kernel::matmul;
@@ -87,7 +89,8 @@ impl<R: JitRuntime> MatmulOptimization<R> {
         fused_matmul_autotune::<R, BT>(self, context);
 
         #[cfg(not(feature = "autotune"))]
-        if self.execute_fused::<BT>(context).is_err() {
+        if let Err(err) = self.execute_fused::<BT>(context) {
+            record_fast_path_miss("fused_matmul", &err);
             self.execute_fallback::<BT>(context);
         }
     }
@@ -59,6 +59,8 @@ pub(crate) fn create_key<R: JitRuntime>(
     let key = MatmulAutotuneKey::from_shape(
         &lhs.shape.clone().into(),
         &rhs.shape.clone().into(),
+        // The fused matmul handles the layout of its inputs, without the strided kernel.
+        false,
         out.dtype,
     );
     FusedMatmulAutotuneKey::new(key, opt.len)
@@ -3,6 +3,7 @@ mod base;
 pub(crate) mod elemwise;
 pub(crate) mod matmul;
 pub(crate) mod on_write;
+pub(crate) mod reduce;
 pub(crate) mod tune;
 
 pub use base::*;
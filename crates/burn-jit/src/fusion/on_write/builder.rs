@@ -1,5 +1,8 @@
 use super::{
-    ir::{Arg, BinaryElemwiseArgs, ElemwiseOp, ElemwisePrecision, UnaryElemwiseArgs},
+    ir::{
+        Arg, BinaryElemwiseArgs, ElemwiseOp, ElemwisePrecision, ReduceElemwiseArgs, ReduceKind,
+        UnaryElemwiseArgs,
+    },
     trace::FuseOnWriteTrace,
     trace_builder::FuseOnWriteTraceBuilder,
 };
@@ -7,8 +10,8 @@ use burn_fusion::{OptimizationBuilder, OptimizationProperties, OptimizationStatu
 use burn_tensor::{
     repr::{
         BaseOperationDescription, BinaryOperationDescription, FloatOperationDescription,
-        NumericOperationDescription, OperationDescription, ScalarOperationDescription,
-        TensorDescription, UnaryOperationDescription,
+        NumericOperationDescription, OperationDescription, ReshapeDescription,
+        ScalarOperationDescription, TensorDescription, UnaryOperationDescription,
     },
     Element,
 };
@@ -21,6 +24,17 @@ pub(crate) struct FuseOnWriteBuilder {
     status: OptimizationStatus,
     num_ops: usize,
     max_bindings: u32,
+    fuse_reductions: bool,
+    reduce_axis: Option<ReduceAxis>,
+    num_reductions: usize,
+}
+
+/// The axis along which the fused reductions are executed.
+#[derive(Clone, Copy)]
+struct ReduceAxis {
+    axis: usize,
+    /// The size of the axis once reduced, which is relative to the fused operations.
+    reduced: usize,
 }
 
 struct TryFuseBuilder {
@@ -55,8 +69,8 @@ impl TryFuseBuilder {
         true
     }
 
-    fn build(&self) -> FuseOnWriteTrace {
-        self.builder.build()
+    fn build(&self, shape_ref: Vec<usize>) -> FuseOnWriteTrace {
+        self.builder.build(shape_ref)
     }
 }
 
@@ -108,7 +122,7 @@ impl OptimizationBuilder<FuseOnWriteTrace> for FuseOnWriteBuilder {
     }
 
     fn build(&self) -> FuseOnWriteTrace {
-        self.builder.build()
+        self.builder.build(self.current_output_shape.clone())
     }
 
     fn len(&self) -> usize {
@@ -120,6 +134,8 @@ impl OptimizationBuilder<FuseOnWriteTrace> for FuseOnWriteBuilder {
         self.status = OptimizationStatus::Open;
         self.builder = TryFuseBuilder::new(self.max_bindings, self.builder.builder.bool_precision);
         self.current_output_shape.clear();
+        self.reduce_axis = None;
+        self.num_reductions = 0;
     }
 
     fn status(&self) -> OptimizationStatus {
@@ -144,9 +160,34 @@ impl FuseOnWriteBuilder {
             max_bindings,
             current_output_shape: Vec::new(),
             status: OptimizationStatus::Open,
+            fuse_reductions: false,
+            reduce_axis: None,
+            num_reductions: 0,
         }
     }
 
+    /// Also fuse reductions along a single axis, which requires a runner that reads the whole
+    /// axis before executing the operations that follow a reduction.
+    pub fn with_reductions(mut self) -> Self {
+        self.fuse_reductions = true;
+        self
+    }
+
+    /// The axis of the fused reductions, if any.
+    pub fn reduce_axis(&self) -> Option<usize> {
+        self.reduce_axis.map(|reduce| reduce.axis)
+    }
+
+    /// The number of fused reductions.
+    pub fn num_reductions(&self) -> usize {
+        self.num_reductions
+    }
+
+    /// If a global tensor can be used as the reference layout of the fused operations.
+    pub fn has_reference(&self) -> bool {
+        self.builder.builder.has_global(&self.current_output_shape)
+    }
+
     pub fn close(&mut self) {
         self.status = OptimizationStatus::Closed;
     }
@@ -172,6 +213,7 @@ impl FuseOnWriteBuilder {
             BaseOperationDescription::Cast(desc) => self.register_unary_ops(desc, |input, out| {
                 ElemwiseOp::Assign(UnaryElemwiseArgs { input, out })
             }),
+            BaseOperationDescription::Reshape(desc) => self.register_reshape(desc),
             _ => false,
         }
     }
@@ -354,6 +396,18 @@ impl FuseOnWriteBuilder {
                     build.register_operation(ElemwiseOp::Assign(UnaryElemwiseArgs { input, out }))
                 })
             }
+            NumericOperationDescription::SumDim(desc) => {
+                self.register_reduce(desc, ReduceKind::Sum)
+            }
+            NumericOperationDescription::MeanDim(desc) => {
+                self.register_reduce(desc, ReduceKind::Mean)
+            }
+            NumericOperationDescription::MaxDim(desc) => {
+                self.register_reduce(desc, ReduceKind::Max)
+            }
+            NumericOperationDescription::MinDim(desc) => {
+                self.register_reduce(desc, ReduceKind::Min)
+            }
             NumericOperationDescription::Full((desc, elem)) => {
                 if !self.output_is_compatible(desc) {
                     return false;
@@ -424,13 +478,90 @@ impl FuseOnWriteBuilder {
         })
     }
 
+    /// Reshaped tensors are read as broadcast inputs, like a bias unsqueezed before being added to
+    /// the output of a matmul.
+    fn register_reshape(&mut self, desc: &ReshapeDescription) -> bool {
+        // The fused operations can only read inputs with their own rank.
+        if self.current_output_shape.len() != desc.out.shape.len() {
+            return false;
+        }
+
+        let mut registered = false;
+        let added = self.builder.register(|build| {
+            registered = build.input_reshaped(&desc.input, &desc.out).is_some();
+        });
+
+        added && registered
+    }
+
+    fn register_reduce(
+        &mut self,
+        desc: &ScalarOperationDescription<usize>,
+        kind: ReduceKind,
+    ) -> bool {
+        // Reductions are accumulated in full precision, which is only exact for floats.
+        if !self.fuse_reductions || !desc.out.dtype.is_float() {
+            return false;
+        }
+
+        let reduce = ReduceAxis {
+            axis: desc.rhs,
+            reduced: desc.out.shape[desc.rhs],
+        };
+
+        if let Some(current) = self.reduce_axis {
+            if current.axis != reduce.axis {
+                return false;
+            }
+        }
+
+        // The input of the reduction has the shape of the fused element wise operations.
+        if self.current_output_shape.is_empty() {
+            self.current_output_shape.clone_from(&desc.lhs.shape);
+        } else if self.current_output_shape != desc.lhs.shape {
+            return false;
+        }
+
+        let registered = self.builder.register(|build| {
+            let input = build.input(&desc.lhs);
+            let out = build.output(&desc.out);
+
+            build.register_operation(ElemwiseOp::Reduce(ReduceElemwiseArgs { input, out, kind }))
+        });
+
+        if registered {
+            self.reduce_axis = Some(reduce);
+            self.num_reductions += 1;
+        }
+
+        registered
+    }
+
     fn output_is_compatible(&mut self, out: &TensorDescription) -> bool {
         if self.current_output_shape.is_empty() {
             self.current_output_shape.clone_from(&out.shape);
-        } else if self.current_output_shape != out.shape {
+        } else if self.current_output_shape != out.shape && !self.is_reduced_shape(&out.shape) {
             return false;
         }
 
         true
     }
+
+    /// If the shape is the current output shape reduced along the reduction axis, so that the
+    /// result of a reduction can be used by the following operations.
+    fn is_reduced_shape(&self, shape: &[usize]) -> bool {
+        let Some(reduce) = self.reduce_axis else {
+            return false;
+        };
+
+        shape.len() == self.current_output_shape.len()
+            && shape
+                .iter()
+                .zip(self.current_output_shape.iter())
+                .enumerate()
+                .all(|(dim, (size, current))| match dim == reduce.axis {
+                    true => *size == reduce.reduced,
+                    false => size == current,
+                })
+    }
 }
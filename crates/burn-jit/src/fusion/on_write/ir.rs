@@ -85,6 +85,10 @@ pub enum ElemwiseOp {
         rhs: Arg,
         out: Arg,
     },
+    /// Reduce the input along the reduction axis of the fused kernel.
+    ///
+    /// It can only be executed by runners that read the whole axis before writing its result.
+    Reduce(ReduceElemwiseArgs),
 }
 
 #[derive(CubeLaunch)]
@@ -212,6 +216,25 @@ pub struct LocalArgs {
     pub l_bool: Registry<u32, Line<bool>>,
 }
 
+#[derive(CubeType, Clone, Debug, Hash, PartialEq, Eq, Serialize, Deserialize)]
+/// Reduce [element wise operation](ElemwiseOp) arguments.
+pub struct ReduceElemwiseArgs {
+    pub input: Arg,
+    pub out: Arg,
+    pub kind: ReduceKind,
+}
+
+#[derive(
+    CubeType, Clone, Copy, Debug, Hash, PartialEq, Eq, Serialize, Deserialize, PartialOrd, Ord,
+)]
+/// The kind of [reduction](ReduceElemwiseArgs).
+pub enum ReduceKind {
+    Sum,
+    Mean,
+    Max,
+    Min,
+}
+
 #[derive(CubeType, Clone, Debug, Hash, PartialEq, Eq, Serialize, Deserialize)]
 /// Unary [element wise operation](ElemwiseOp) arguments.
 pub struct UnaryElemwiseArgs {
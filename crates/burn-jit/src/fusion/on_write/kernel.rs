@@ -15,7 +15,51 @@ pub fn fuse_on_write<E: CubePrimitive>(
     #[comptime] write_args: Sequence<Arg>,
     #[comptime] config: &ElemwiseConfig,
 ) {
-    let mut locals = LocalArgs {
+    let mut locals = init_locals();
+
+    fuse::<E>(
+        inputs,
+        outputs,
+        &mut locals,
+        write_pos,
+        write_values,
+        write_args,
+        config,
+    );
+}
+
+#[cube]
+/// Fuse element-wise operations at the given read position and return the value of `read_arg`.
+///
+/// Like [fuse_on_write], you can start by writing some elements using `write_values` and
+/// `write_args`.
+pub fn fuse_on_read<E: CubePrimitive>(
+    inputs: &GlobalArgs,
+    outputs: &mut GlobalArgs,
+    read_pos: u32,
+    write_values: Registry<Arg, Line<E>>,
+    #[comptime] write_args: Sequence<Arg>,
+    #[comptime] read_arg: Arg,
+    #[comptime] config: &ElemwiseConfig,
+) -> Line<E> {
+    let mut locals = init_locals();
+
+    fuse::<E>(
+        inputs,
+        outputs,
+        &mut locals,
+        read_pos,
+        write_values,
+        write_args,
+        config,
+    );
+
+    read::<E>(inputs, outputs, &locals, read_pos, read_arg, config)
+}
+
+#[cube]
+fn init_locals() -> LocalArgs {
+    LocalArgs {
         l_f32: Registry::<u32, Line<f32>>::new(),
         l_f16: Registry::<u32, Line<f16>>::new(),
         l_bf16: Registry::<u32, Line<bf16>>::new(),
@@ -28,15 +72,26 @@ pub fn fuse_on_write<E: CubePrimitive>(
         l_u16: Registry::<u32, Line<u16>>::new(),
         l_u8: Registry::<u32, Line<u8>>::new(),
         l_bool: Registry::<u32, Line<bool>>::new(),
-    };
+    }
+}
 
+#[cube]
+fn fuse<E: CubePrimitive>(
+    inputs: &GlobalArgs,
+    outputs: &mut GlobalArgs,
+    locals: &mut LocalArgs,
+    write_pos: u32,
+    write_values: Registry<Arg, Line<E>>,
+    #[comptime] write_args: Sequence<Arg>,
+    #[comptime] config: &ElemwiseConfig,
+) {
     // Write the values given as arguments.
     #[unroll]
     for i in 0..write_args.len() {
         let arg = comptime![*write_args.index(i)];
         let val = write_values.find(arg);
 
-        write::<E>(inputs, outputs, &mut locals, write_pos, val, arg, config);
+        write::<E>(inputs, outputs, locals, write_pos, val, arg, config);
     }
 
     #[unroll]
@@ -46,507 +101,489 @@ pub fn fuse_on_write<E: CubePrimitive>(
         match op {
             ElemwiseOp::Add(op) => match op.out.precision() {
                 ElemwisePrecision::F32 => {
-                    add::<f32>(inputs, outputs, &mut locals, write_pos, op, config)
+                    add::<f32>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::F16 => {
-                    add::<f16>(inputs, outputs, &mut locals, write_pos, op, config)
+                    add::<f16>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::BF16 => {
-                    add::<bf16>(inputs, outputs, &mut locals, write_pos, op, config)
+                    add::<bf16>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::I64 => {
-                    add::<i64>(inputs, outputs, &mut locals, write_pos, op, config)
+                    add::<i64>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::I32 => {
-                    add::<i32>(inputs, outputs, &mut locals, write_pos, op, config)
+                    add::<i32>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::I16 => {
-                    add::<i16>(inputs, outputs, &mut locals, write_pos, op, config)
-                }
-                ElemwisePrecision::I8 => {
-                    add::<i8>(inputs, outputs, &mut locals, write_pos, op, config)
+                    add::<i16>(inputs, outputs, locals, write_pos, op, config)
                 }
+                ElemwisePrecision::I8 => add::<i8>(inputs, outputs, locals, write_pos, op, config),
                 ElemwisePrecision::U64 => {
-                    add::<u64>(inputs, outputs, &mut locals, write_pos, op, config)
+                    add::<u64>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::U32 => {
-                    add::<u32>(inputs, outputs, &mut locals, write_pos, op, config)
+                    add::<u32>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::U16 => {
-                    add::<u16>(inputs, outputs, &mut locals, write_pos, op, config)
-                }
-                ElemwisePrecision::U8 => {
-                    add::<u8>(inputs, outputs, &mut locals, write_pos, op, config)
+                    add::<u16>(inputs, outputs, locals, write_pos, op, config)
                 }
+                ElemwisePrecision::U8 => add::<u8>(inputs, outputs, locals, write_pos, op, config),
                 _ => comptime![panic!("Unsupported precision {op:?}")],
             },
             ElemwiseOp::Div(op) => match op.out.precision() {
                 ElemwisePrecision::F32 => {
-                    div::<f32>(inputs, outputs, &mut locals, write_pos, op, config)
+                    div::<f32>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::F16 => {
-                    div::<f16>(inputs, outputs, &mut locals, write_pos, op, config)
+                    div::<f16>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::BF16 => {
-                    div::<bf16>(inputs, outputs, &mut locals, write_pos, op, config)
+                    div::<bf16>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::I64 => {
-                    div::<i64>(inputs, outputs, &mut locals, write_pos, op, config)
+                    div::<i64>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::I32 => {
-                    div::<i32>(inputs, outputs, &mut locals, write_pos, op, config)
+                    div::<i32>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::I16 => {
-                    div::<i16>(inputs, outputs, &mut locals, write_pos, op, config)
-                }
-                ElemwisePrecision::I8 => {
-                    div::<i8>(inputs, outputs, &mut locals, write_pos, op, config)
+                    div::<i16>(inputs, outputs, locals, write_pos, op, config)
                 }
+                ElemwisePrecision::I8 => div::<i8>(inputs, outputs, locals, write_pos, op, config),
                 ElemwisePrecision::U64 => {
-                    div::<u64>(inputs, outputs, &mut locals, write_pos, op, config)
+                    div::<u64>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::U32 => {
-                    div::<u32>(inputs, outputs, &mut locals, write_pos, op, config)
+                    div::<u32>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::U16 => {
-                    div::<u16>(inputs, outputs, &mut locals, write_pos, op, config)
-                }
-                ElemwisePrecision::U8 => {
-                    div::<u8>(inputs, outputs, &mut locals, write_pos, op, config)
+                    div::<u16>(inputs, outputs, locals, write_pos, op, config)
                 }
+                ElemwisePrecision::U8 => div::<u8>(inputs, outputs, locals, write_pos, op, config),
                 _ => comptime![panic!("Unsupported precision {op:?}")],
             },
             ElemwiseOp::Sub(op) => match op.out.precision() {
                 ElemwisePrecision::F32 => {
-                    sub::<f32>(inputs, outputs, &mut locals, write_pos, op, config)
+                    sub::<f32>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::F16 => {
-                    sub::<f16>(inputs, outputs, &mut locals, write_pos, op, config)
+                    sub::<f16>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::BF16 => {
-                    sub::<bf16>(inputs, outputs, &mut locals, write_pos, op, config)
+                    sub::<bf16>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::I64 => {
-                    sub::<i64>(inputs, outputs, &mut locals, write_pos, op, config)
+                    sub::<i64>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::I32 => {
-                    sub::<i32>(inputs, outputs, &mut locals, write_pos, op, config)
+                    sub::<i32>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::I16 => {
-                    sub::<i16>(inputs, outputs, &mut locals, write_pos, op, config)
-                }
-                ElemwisePrecision::I8 => {
-                    sub::<i8>(inputs, outputs, &mut locals, write_pos, op, config)
+                    sub::<i16>(inputs, outputs, locals, write_pos, op, config)
                 }
+                ElemwisePrecision::I8 => sub::<i8>(inputs, outputs, locals, write_pos, op, config),
                 ElemwisePrecision::U64 => {
-                    sub::<u64>(inputs, outputs, &mut locals, write_pos, op, config)
+                    sub::<u64>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::U32 => {
-                    sub::<u32>(inputs, outputs, &mut locals, write_pos, op, config)
+                    sub::<u32>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::U16 => {
-                    sub::<u16>(inputs, outputs, &mut locals, write_pos, op, config)
-                }
-                ElemwisePrecision::U8 => {
-                    sub::<u8>(inputs, outputs, &mut locals, write_pos, op, config)
+                    sub::<u16>(inputs, outputs, locals, write_pos, op, config)
                 }
+                ElemwisePrecision::U8 => sub::<u8>(inputs, outputs, locals, write_pos, op, config),
                 _ => comptime![panic!("Unsupported precision {op:?}")],
             },
             ElemwiseOp::Mul(op) => match op.out.precision() {
                 ElemwisePrecision::F32 => {
-                    mul::<f32>(inputs, outputs, &mut locals, write_pos, op, config)
+                    mul::<f32>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::F16 => {
-                    mul::<f16>(inputs, outputs, &mut locals, write_pos, op, config)
+                    mul::<f16>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::BF16 => {
-                    mul::<bf16>(inputs, outputs, &mut locals, write_pos, op, config)
+                    mul::<bf16>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::I64 => {
-                    mul::<i64>(inputs, outputs, &mut locals, write_pos, op, config)
+                    mul::<i64>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::I32 => {
-                    mul::<i32>(inputs, outputs, &mut locals, write_pos, op, config)
+                    mul::<i32>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::I16 => {
-                    mul::<i16>(inputs, outputs, &mut locals, write_pos, op, config)
-                }
-                ElemwisePrecision::I8 => {
-                    mul::<i8>(inputs, outputs, &mut locals, write_pos, op, config)
+                    mul::<i16>(inputs, outputs, locals, write_pos, op, config)
                 }
+                ElemwisePrecision::I8 => mul::<i8>(inputs, outputs, locals, write_pos, op, config),
                 ElemwisePrecision::U64 => {
-                    mul::<u64>(inputs, outputs, &mut locals, write_pos, op, config)
+                    mul::<u64>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::U32 => {
-                    mul::<u32>(inputs, outputs, &mut locals, write_pos, op, config)
+                    mul::<u32>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::U16 => {
-                    mul::<u16>(inputs, outputs, &mut locals, write_pos, op, config)
-                }
-                ElemwisePrecision::U8 => {
-                    mul::<u8>(inputs, outputs, &mut locals, write_pos, op, config)
+                    mul::<u16>(inputs, outputs, locals, write_pos, op, config)
                 }
+                ElemwisePrecision::U8 => mul::<u8>(inputs, outputs, locals, write_pos, op, config),
                 _ => comptime![panic!("Unsupported precision {op:?}")],
             },
             ElemwiseOp::Powf(op) => match op.out.precision() {
                 ElemwisePrecision::F32 => {
-                    powf::<f32>(inputs, outputs, &mut locals, write_pos, op, config)
+                    powf::<f32>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::F16 => {
-                    powf::<f16>(inputs, outputs, &mut locals, write_pos, op, config)
+                    powf::<f16>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::BF16 => {
-                    powf::<bf16>(inputs, outputs, &mut locals, write_pos, op, config)
+                    powf::<bf16>(inputs, outputs, locals, write_pos, op, config)
                 }
                 _ => comptime![panic!("Unsupported precision {op:?}")],
             },
             ElemwiseOp::Erf(op) => match op.out.precision() {
                 ElemwisePrecision::F32 => {
-                    erf::<f32>(inputs, outputs, &mut locals, write_pos, op, config)
+                    erf::<f32>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::F16 => {
-                    erf::<f16>(inputs, outputs, &mut locals, write_pos, op, config)
+                    erf::<f16>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::BF16 => {
-                    erf::<bf16>(inputs, outputs, &mut locals, write_pos, op, config)
+                    erf::<bf16>(inputs, outputs, locals, write_pos, op, config)
                 }
                 _ => comptime![panic!("Unsupported precision {op:?}")],
             },
             ElemwiseOp::Abs(op) => match op.out.precision() {
                 ElemwisePrecision::F32 => {
-                    abs::<f32>(inputs, outputs, &mut locals, write_pos, op, config)
+                    abs::<f32>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::F16 => {
-                    abs::<f16>(inputs, outputs, &mut locals, write_pos, op, config)
+                    abs::<f16>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::BF16 => {
-                    abs::<bf16>(inputs, outputs, &mut locals, write_pos, op, config)
+                    abs::<bf16>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::U64 => {
-                    assign::<u64>(inputs, outputs, &mut locals, write_pos, op, config)
+                    assign::<u64>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::U32 => {
-                    assign::<u32>(inputs, outputs, &mut locals, write_pos, op, config)
+                    assign::<u32>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::U16 => {
-                    assign::<u16>(inputs, outputs, &mut locals, write_pos, op, config)
+                    assign::<u16>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::U8 => {
-                    assign::<u8>(inputs, outputs, &mut locals, write_pos, op, config)
+                    assign::<u8>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::I64 => {
-                    abs::<i64>(inputs, outputs, &mut locals, write_pos, op, config)
+                    abs::<i64>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::I32 => {
-                    abs::<i32>(inputs, outputs, &mut locals, write_pos, op, config)
+                    abs::<i32>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::I16 => {
-                    abs::<i16>(inputs, outputs, &mut locals, write_pos, op, config)
-                }
-                ElemwisePrecision::I8 => {
-                    abs::<i8>(inputs, outputs, &mut locals, write_pos, op, config)
+                    abs::<i16>(inputs, outputs, locals, write_pos, op, config)
                 }
+                ElemwisePrecision::I8 => abs::<i8>(inputs, outputs, locals, write_pos, op, config),
                 _ => comptime![panic!("Unsupported precision {op:?}")],
             },
             ElemwiseOp::Log(op) => match op.out.precision() {
                 ElemwisePrecision::F32 => {
-                    log::<f32>(inputs, outputs, &mut locals, write_pos, op, config)
+                    log::<f32>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::F16 => {
-                    log::<f16>(inputs, outputs, &mut locals, write_pos, op, config)
+                    log::<f16>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::BF16 => {
-                    log::<bf16>(inputs, outputs, &mut locals, write_pos, op, config)
+                    log::<bf16>(inputs, outputs, locals, write_pos, op, config)
                 }
                 _ => comptime![panic!("Unsupported precision {op:?}")],
             },
             ElemwiseOp::Log1p(op) => match op.out.precision() {
                 ElemwisePrecision::F32 => {
-                    log1p::<f32>(inputs, outputs, &mut locals, write_pos, op, config)
+                    log1p::<f32>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::F16 => {
-                    log1p::<f16>(inputs, outputs, &mut locals, write_pos, op, config)
+                    log1p::<f16>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::BF16 => {
-                    log1p::<bf16>(inputs, outputs, &mut locals, write_pos, op, config)
+                    log1p::<bf16>(inputs, outputs, locals, write_pos, op, config)
                 }
                 _ => comptime![panic!("Unsupported precision {op:?}")],
             },
             ElemwiseOp::Recip(op) => match op.out.precision() {
                 ElemwisePrecision::F32 => {
-                    recip::<f32>(inputs, outputs, &mut locals, write_pos, op, config)
+                    recip::<f32>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::F16 => {
-                    recip::<f16>(inputs, outputs, &mut locals, write_pos, op, config)
+                    recip::<f16>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::BF16 => {
-                    recip::<bf16>(inputs, outputs, &mut locals, write_pos, op, config)
+                    recip::<bf16>(inputs, outputs, locals, write_pos, op, config)
                 }
                 _ => comptime![panic!("Unsupported precision {op:?}")],
             },
             ElemwiseOp::Assign(op) => match op.out.precision() {
                 ElemwisePrecision::F32 => {
-                    assign::<f32>(inputs, outputs, &mut locals, write_pos, op, config)
+                    assign::<f32>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::F16 => {
-                    assign::<f16>(inputs, outputs, &mut locals, write_pos, op, config)
+                    assign::<f16>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::BF16 => {
-                    assign::<bf16>(inputs, outputs, &mut locals, write_pos, op, config)
+                    assign::<bf16>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::I64 => {
-                    assign::<i64>(inputs, outputs, &mut locals, write_pos, op, config)
+                    assign::<i64>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::I32 => {
-                    assign::<i32>(inputs, outputs, &mut locals, write_pos, op, config)
+                    assign::<i32>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::I16 => {
-                    assign::<i16>(inputs, outputs, &mut locals, write_pos, op, config)
+                    assign::<i16>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::I8 => {
-                    assign::<i8>(inputs, outputs, &mut locals, write_pos, op, config)
+                    assign::<i8>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::U64 => {
-                    assign::<u64>(inputs, outputs, &mut locals, write_pos, op, config)
+                    assign::<u64>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::U32 => {
-                    assign::<u32>(inputs, outputs, &mut locals, write_pos, op, config)
+                    assign::<u32>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::U16 => {
-                    assign::<u16>(inputs, outputs, &mut locals, write_pos, op, config)
+                    assign::<u16>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::U8 => {
-                    assign::<u8>(inputs, outputs, &mut locals, write_pos, op, config)
+                    assign::<u8>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::Bool => {
-                    assign::<bool>(inputs, outputs, &mut locals, write_pos, op, config)
+                    assign::<bool>(inputs, outputs, locals, write_pos, op, config)
                 }
             },
             ElemwiseOp::Exp(op) => match op.out.precision() {
                 ElemwisePrecision::F32 => {
-                    exp::<f32>(inputs, outputs, &mut locals, write_pos, op, config)
+                    exp::<f32>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::F16 => {
-                    exp::<f16>(inputs, outputs, &mut locals, write_pos, op, config)
+                    exp::<f16>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::BF16 => {
-                    exp::<bf16>(inputs, outputs, &mut locals, write_pos, op, config)
+                    exp::<bf16>(inputs, outputs, locals, write_pos, op, config)
                 }
                 _ => comptime![panic!("Unsupported precision {op:?}")],
             },
             ElemwiseOp::Cos(op) => match op.out.precision() {
                 ElemwisePrecision::F32 => {
-                    cos::<f32>(inputs, outputs, &mut locals, write_pos, op, config)
+                    cos::<f32>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::F16 => {
-                    cos::<f16>(inputs, outputs, &mut locals, write_pos, op, config)
+                    cos::<f16>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::BF16 => {
-                    cos::<bf16>(inputs, outputs, &mut locals, write_pos, op, config)
+                    cos::<bf16>(inputs, outputs, locals, write_pos, op, config)
                 }
                 _ => comptime![panic!("Unsupported precision {op:?}")],
             },
             ElemwiseOp::Sin(op) => match op.out.precision() {
                 ElemwisePrecision::F32 => {
-                    sin::<f32>(inputs, outputs, &mut locals, write_pos, op, config)
+                    sin::<f32>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::F16 => {
-                    sin::<f16>(inputs, outputs, &mut locals, write_pos, op, config)
+                    sin::<f16>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::BF16 => {
-                    sin::<bf16>(inputs, outputs, &mut locals, write_pos, op, config)
+                    sin::<bf16>(inputs, outputs, locals, write_pos, op, config)
                 }
                 _ => comptime![panic!("Unsupported precision {op:?}")],
             },
             ElemwiseOp::Tanh(op) => match op.out.precision() {
                 ElemwisePrecision::F32 => {
-                    tanh::<f32>(inputs, outputs, &mut locals, write_pos, op, config)
+                    tanh::<f32>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::F16 => {
-                    tanh::<f16>(inputs, outputs, &mut locals, write_pos, op, config)
+                    tanh::<f16>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::BF16 => {
-                    tanh::<bf16>(inputs, outputs, &mut locals, write_pos, op, config)
+                    tanh::<bf16>(inputs, outputs, locals, write_pos, op, config)
                 }
                 _ => comptime![panic!("Unsupported precision {op:?}")],
             },
             ElemwiseOp::Equal(op) => match op.lhs.precision() {
                 ElemwisePrecision::F32 => {
-                    equal::<f32>(inputs, outputs, &mut locals, write_pos, op, config)
+                    equal::<f32>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::F16 => {
-                    equal::<f16>(inputs, outputs, &mut locals, write_pos, op, config)
+                    equal::<f16>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::BF16 => {
-                    equal::<bf16>(inputs, outputs, &mut locals, write_pos, op, config)
+                    equal::<bf16>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::I64 => {
-                    equal::<i64>(inputs, outputs, &mut locals, write_pos, op, config)
+                    equal::<i64>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::I32 => {
-                    equal::<i32>(inputs, outputs, &mut locals, write_pos, op, config)
+                    equal::<i32>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::I16 => {
-                    equal::<i16>(inputs, outputs, &mut locals, write_pos, op, config)
+                    equal::<i16>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::I8 => {
-                    equal::<i8>(inputs, outputs, &mut locals, write_pos, op, config)
+                    equal::<i8>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::U64 => {
-                    equal::<u64>(inputs, outputs, &mut locals, write_pos, op, config)
+                    equal::<u64>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::U32 => {
-                    equal::<u32>(inputs, outputs, &mut locals, write_pos, op, config)
+                    equal::<u32>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::U16 => {
-                    equal::<u16>(inputs, outputs, &mut locals, write_pos, op, config)
+                    equal::<u16>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::U8 => {
-                    equal::<u8>(inputs, outputs, &mut locals, write_pos, op, config)
+                    equal::<u8>(inputs, outputs, locals, write_pos, op, config)
                 }
                 _ => comptime![panic!("Unsupported precision {op:?}")],
             },
             ElemwiseOp::Greater(op) => match op.lhs.precision() {
                 ElemwisePrecision::F32 => {
-                    greater::<f32>(inputs, outputs, &mut locals, write_pos, op, config)
+                    greater::<f32>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::F16 => {
-                    greater::<f16>(inputs, outputs, &mut locals, write_pos, op, config)
+                    greater::<f16>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::BF16 => {
-                    greater::<bf16>(inputs, outputs, &mut locals, write_pos, op, config)
+                    greater::<bf16>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::I64 => {
-                    greater::<i64>(inputs, outputs, &mut locals, write_pos, op, config)
+                    greater::<i64>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::I32 => {
-                    greater::<i32>(inputs, outputs, &mut locals, write_pos, op, config)
+                    greater::<i32>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::I16 => {
-                    greater::<i16>(inputs, outputs, &mut locals, write_pos, op, config)
+                    greater::<i16>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::I8 => {
-                    greater::<i8>(inputs, outputs, &mut locals, write_pos, op, config)
+                    greater::<i8>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::U64 => {
-                    greater::<u64>(inputs, outputs, &mut locals, write_pos, op, config)
+                    greater::<u64>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::U32 => {
-                    greater::<u32>(inputs, outputs, &mut locals, write_pos, op, config)
+                    greater::<u32>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::U16 => {
-                    greater::<u16>(inputs, outputs, &mut locals, write_pos, op, config)
+                    greater::<u16>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::U8 => {
-                    greater::<u8>(inputs, outputs, &mut locals, write_pos, op, config)
+                    greater::<u8>(inputs, outputs, locals, write_pos, op, config)
                 }
                 _ => comptime![panic!("Unsupported precision {op:?}")],
             },
             ElemwiseOp::GreaterEqual(op) => match op.lhs.precision() {
                 ElemwisePrecision::F32 => {
-                    greater_equal::<f32>(inputs, outputs, &mut locals, write_pos, op, config)
+                    greater_equal::<f32>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::F16 => {
-                    greater_equal::<f16>(inputs, outputs, &mut locals, write_pos, op, config)
+                    greater_equal::<f16>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::BF16 => {
-                    greater_equal::<bf16>(inputs, outputs, &mut locals, write_pos, op, config)
+                    greater_equal::<bf16>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::I64 => {
-                    greater_equal::<i64>(inputs, outputs, &mut locals, write_pos, op, config)
+                    greater_equal::<i64>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::I32 => {
-                    greater_equal::<i32>(inputs, outputs, &mut locals, write_pos, op, config)
+                    greater_equal::<i32>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::I16 => {
-                    greater_equal::<i16>(inputs, outputs, &mut locals, write_pos, op, config)
+                    greater_equal::<i16>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::I8 => {
-                    greater_equal::<i8>(inputs, outputs, &mut locals, write_pos, op, config)
+                    greater_equal::<i8>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::U64 => {
-                    greater_equal::<u64>(inputs, outputs, &mut locals, write_pos, op, config)
+                    greater_equal::<u64>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::U32 => {
-                    greater_equal::<u32>(inputs, outputs, &mut locals, write_pos, op, config)
+                    greater_equal::<u32>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::U16 => {
-                    greater_equal::<u16>(inputs, outputs, &mut locals, write_pos, op, config)
+                    greater_equal::<u16>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::U8 => {
-                    greater_equal::<u8>(inputs, outputs, &mut locals, write_pos, op, config)
+                    greater_equal::<u8>(inputs, outputs, locals, write_pos, op, config)
                 }
                 _ => comptime![panic!("Unsupported precision {op:?}")],
             },
             ElemwiseOp::Lower(op) => match op.lhs.precision() {
                 ElemwisePrecision::F32 => {
-                    lower::<f32>(inputs, outputs, &mut locals, write_pos, op, config)
+                    lower::<f32>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::F16 => {
-                    lower::<f16>(inputs, outputs, &mut locals, write_pos, op, config)
+                    lower::<f16>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::BF16 => {
-                    lower::<bf16>(inputs, outputs, &mut locals, write_pos, op, config)
+                    lower::<bf16>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::I64 => {
-                    lower::<i64>(inputs, outputs, &mut locals, write_pos, op, config)
+                    lower::<i64>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::I32 => {
-                    lower::<i32>(inputs, outputs, &mut locals, write_pos, op, config)
+                    lower::<i32>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::I16 => {
-                    lower::<i16>(inputs, outputs, &mut locals, write_pos, op, config)
+                    lower::<i16>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::I8 => {
-                    lower::<i8>(inputs, outputs, &mut locals, write_pos, op, config)
+                    lower::<i8>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::U64 => {
-                    lower::<u64>(inputs, outputs, &mut locals, write_pos, op, config)
+                    lower::<u64>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::U32 => {
-                    lower::<u32>(inputs, outputs, &mut locals, write_pos, op, config)
+                    lower::<u32>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::U16 => {
-                    lower::<u16>(inputs, outputs, &mut locals, write_pos, op, config)
+                    lower::<u16>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::U8 => {
-                    lower::<u8>(inputs, outputs, &mut locals, write_pos, op, config)
+                    lower::<u8>(inputs, outputs, locals, write_pos, op, config)
                 }
                 _ => comptime![panic!("Unsupported precision {op:?}")],
             },
             ElemwiseOp::LowerEqual(op) => match op.lhs.precision() {
                 ElemwisePrecision::F32 => {
-                    lower_equal::<f32>(inputs, outputs, &mut locals, write_pos, op, config)
+                    lower_equal::<f32>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::F16 => {
-                    lower_equal::<f16>(inputs, outputs, &mut locals, write_pos, op, config)
+                    lower_equal::<f16>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::BF16 => {
-                    lower_equal::<bf16>(inputs, outputs, &mut locals, write_pos, op, config)
+                    lower_equal::<bf16>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::I64 => {
-                    lower_equal::<i64>(inputs, outputs, &mut locals, write_pos, op, config)
+                    lower_equal::<i64>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::I32 => {
-                    lower_equal::<i32>(inputs, outputs, &mut locals, write_pos, op, config)
+                    lower_equal::<i32>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::I16 => {
-                    lower_equal::<i16>(inputs, outputs, &mut locals, write_pos, op, config)
+                    lower_equal::<i16>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::I8 => {
-                    lower_equal::<i8>(inputs, outputs, &mut locals, write_pos, op, config)
+                    lower_equal::<i8>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::U64 => {
-                    lower_equal::<u64>(inputs, outputs, &mut locals, write_pos, op, config)
+                    lower_equal::<u64>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::U32 => {
-                    lower_equal::<u32>(inputs, outputs, &mut locals, write_pos, op, config)
+                    lower_equal::<u32>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::U16 => {
-                    lower_equal::<u16>(inputs, outputs, &mut locals, write_pos, op, config)
+                    lower_equal::<u16>(inputs, outputs, locals, write_pos, op, config)
                 }
                 ElemwisePrecision::U8 => {
-                    lower_equal::<u8>(inputs, outputs, &mut locals, write_pos, op, config)
+                    lower_equal::<u8>(inputs, outputs, locals, write_pos, op, config)
                 }
                 _ => comptime![panic!("Unsupported precision {op:?}")],
             },
@@ -557,128 +594,43 @@ pub fn fuse_on_write<E: CubePrimitive>(
                 out,
             } => match out.precision() {
                 ElemwisePrecision::F32 => conditional_assign::<f32>(
-                    inputs,
-                    outputs,
-                    &mut locals,
-                    write_pos,
-                    cond,
-                    lhs,
-                    rhs,
-                    out,
-                    config,
+                    inputs, outputs, locals, write_pos, cond, lhs, rhs, out, config,
                 ),
                 ElemwisePrecision::F16 => conditional_assign::<f16>(
-                    inputs,
-                    outputs,
-                    &mut locals,
-                    write_pos,
-                    cond,
-                    lhs,
-                    rhs,
-                    out,
-                    config,
+                    inputs, outputs, locals, write_pos, cond, lhs, rhs, out, config,
                 ),
                 ElemwisePrecision::BF16 => conditional_assign::<bf16>(
-                    inputs,
-                    outputs,
-                    &mut locals,
-                    write_pos,
-                    cond,
-                    lhs,
-                    rhs,
-                    out,
-                    config,
+                    inputs, outputs, locals, write_pos, cond, lhs, rhs, out, config,
                 ),
                 ElemwisePrecision::I64 => conditional_assign::<i64>(
-                    inputs,
-                    outputs,
-                    &mut locals,
-                    write_pos,
-                    cond,
-                    lhs,
-                    rhs,
-                    out,
-                    config,
+                    inputs, outputs, locals, write_pos, cond, lhs, rhs, out, config,
                 ),
                 ElemwisePrecision::I32 => conditional_assign::<i32>(
-                    inputs,
-                    outputs,
-                    &mut locals,
-                    write_pos,
-                    cond,
-                    lhs,
-                    rhs,
-                    out,
-                    config,
+                    inputs, outputs, locals, write_pos, cond, lhs, rhs, out, config,
                 ),
                 ElemwisePrecision::I16 => conditional_assign::<i16>(
-                    inputs,
-                    outputs,
-                    &mut locals,
-                    write_pos,
-                    cond,
-                    lhs,
-                    rhs,
-                    out,
-                    config,
+                    inputs, outputs, locals, write_pos, cond, lhs, rhs, out, config,
                 ),
                 ElemwisePrecision::I8 => conditional_assign::<i8>(
-                    inputs,
-                    outputs,
-                    &mut locals,
-                    write_pos,
-                    cond,
-                    lhs,
-                    rhs,
-                    out,
-                    config,
+                    inputs, outputs, locals, write_pos, cond, lhs, rhs, out, config,
                 ),
                 ElemwisePrecision::U64 => conditional_assign::<u64>(
-                    inputs,
-                    outputs,
-                    &mut locals,
-                    write_pos,
-                    cond,
-                    lhs,
-                    rhs,
-                    out,
-                    config,
+                    inputs, outputs, locals, write_pos, cond, lhs, rhs, out, config,
                 ),
                 ElemwisePrecision::U32 => conditional_assign::<u32>(
-                    inputs,
-                    outputs,
-                    &mut locals,
-                    write_pos,
-                    cond,
-                    lhs,
-                    rhs,
-                    out,
-                    config,
+                    inputs, outputs, locals, write_pos, cond, lhs, rhs, out, config,
                 ),
                 ElemwisePrecision::U16 => conditional_assign::<u16>(
-                    inputs,
-                    outputs,
-                    &mut locals,
-                    write_pos,
-                    cond,
-                    lhs,
-                    rhs,
-                    out,
-                    config,
+                    inputs, outputs, locals, write_pos, cond, lhs, rhs, out, config,
                 ),
                 ElemwisePrecision::U8 => conditional_assign::<u8>(
-                    inputs,
-                    outputs,
-                    &mut locals,
-                    write_pos,
-                    cond,
-                    lhs,
-                    rhs,
-                    out,
-                    config,
+                    inputs, outputs, locals, write_pos, cond, lhs, rhs, out, config,
                 ),
                 _ => comptime![panic!("Unsupported precision {op:?}")],
             },
+            ElemwiseOp::Reduce(_) => {
+                comptime![panic!("Reductions must be executed by a reduce runner")]
+            }
         }
     }
 }
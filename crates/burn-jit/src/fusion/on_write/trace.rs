@@ -1,17 +1,24 @@
 use crate::{
     fusion::{on_write::ir::LayoutInfo, strides_dyn_rank, JitFusionHandle},
-    BoolElement, JitRuntime,
+    memory, ops, BoolElement, JitRuntime,
 };
 
 use super::ir::{Arg, ElemwiseConfig, ElemwiseOp, ElemwisePrecision, GlobalArgsLaunch};
 use burn_fusion::stream::Context;
 use burn_tensor::{
     repr::{TensorDescription, TensorId, TensorStatus},
-    DType,
+    DType, Shape,
 };
 use cubecl::{ir::Elem, prelude::*};
 use serde::{Deserialize, Serialize};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex};
+
+/// The maximum number of [launch plans](LaunchPlan) kept for a trace.
+///
+/// The [keys](LaunchKey) don't depend on the shapes of the inputs, so a trace only has a few
+/// plans, one for each combination of input layouts it is run with.
+const MAX_LAUNCH_PLANS: usize = 32;
 
 #[derive(new, Clone, Serialize, Deserialize, Debug)]
 /// Trace containing all element wise operations as well as reads and writes.
@@ -23,6 +30,18 @@ pub struct FuseOnWriteTrace {
     reads: BTreeMap<TensorId, ElemwiseOp>,
     writes: BTreeMap<TensorId, ElemwiseOp>,
     inputs_unhandled: Vec<TensorId>,
+    reshapes: Vec<ReshapedInput>,
+    shape_ref: Vec<usize>,
+    #[new(default)]
+    #[serde(skip)]
+    plans: LaunchPlans,
+}
+
+#[derive(new, Clone, Serialize, Deserialize, Debug)]
+/// An input that is the output of a reshape, the reshape being executed when running the trace.
+pub struct ReshapedInput {
+    original: TensorDescription,
+    reshaped: TensorDescription,
 }
 
 /// A trace runner is responsible for determining the vectorization factor as well as launching
@@ -43,56 +62,64 @@ pub trait TraceRunner<R: JitRuntime> {
 
     /// The vectorization factor for all inputs and outputs.
     fn vectorization<'a>(
+        &self,
         handles_inputs: impl Iterator<Item = &'a JitFusionHandle<R>>,
         inputs: impl Iterator<Item = &'a TensorDescription>,
         outputs: impl Iterator<Item = &'a TensorDescription>,
     ) -> u8 {
-        // The default version uses the last dimension as vectorization axis and assumes a
-        // perpendicular contiguous line.
-
-        let vectorization_input = |handle: &JitFusionHandle<R>, desc: &TensorDescription| {
-            let rank = handle.strides.len();
+        vectorization_last_dim::<R>(handles_inputs, inputs, outputs)
+    }
+}
 
-            // Last dimension strides should be 1, otherwise vecX won't be contiguous.
-            if handle.strides[rank - 1] != 1 {
-                return 1;
-            }
+/// The vectorization factor using the last dimension as vectorization axis, assuming a
+/// perpendicular contiguous line.
+pub(crate) fn vectorization_last_dim<'a, R: JitRuntime>(
+    handles_inputs: impl Iterator<Item = &'a JitFusionHandle<R>>,
+    inputs: impl Iterator<Item = &'a TensorDescription>,
+    outputs: impl Iterator<Item = &'a TensorDescription>,
+) -> u8 {
+    let vectorization_input = |handle: &JitFusionHandle<R>, desc: &TensorDescription| {
+        let rank = handle.strides.len();
+
+        // Last dimension strides should be 1, otherwise vecX won't be contiguous.
+        if handle.strides[rank - 1] != 1 {
+            return 1;
+        }
 
-            for s in R::line_size_elem(&desc.dtype.into()) {
-                // The last dimension should be a multiple of the vector size.
-                if desc.shape[rank - 1] % s as usize == 0 {
-                    return s;
-                }
+        for s in R::line_size_elem(&desc.dtype.into()) {
+            // The last dimension should be a multiple of the vector size.
+            if desc.shape[rank - 1] % s as usize == 0 {
+                return s;
             }
+        }
 
-            1
-        };
+        1
+    };
 
-        let vectorization_output = |desc: &TensorDescription| {
-            let rank = desc.shape.len();
+    let vectorization_output = |desc: &TensorDescription| {
+        let rank = desc.shape.len();
 
-            for s in R::line_size_elem(&desc.dtype.into()) {
-                // The last dimension should be a multiple of the vector size.
-                if desc.shape[rank - 1] % s as usize == 0 {
-                    return s;
-                }
+        for s in R::line_size_elem(&desc.dtype.into()) {
+            // The last dimension should be a multiple of the vector size.
+            if desc.shape[rank - 1] % s as usize == 0 {
+                return s;
             }
+        }
 
-            1
-        };
-
-        let mut output = u8::MAX;
+        1
+    };
 
-        for (handle, tensor) in handles_inputs.zip(inputs) {
-            output = Ord::min(vectorization_input(handle, tensor), output);
-        }
+    let mut output = u8::MAX;
 
-        for tensor in outputs {
-            output = Ord::min(vectorization_output(tensor), output);
-        }
+    for (handle, tensor) in handles_inputs.zip(inputs) {
+        output = Ord::min(vectorization_input(handle, tensor), output);
+    }
 
-        output
+    for tensor in outputs {
+        output = Ord::min(vectorization_output(tensor), output);
     }
+
+    output
 }
 
 #[derive(Debug)]
@@ -109,6 +136,118 @@ struct LaunchAnalysis<'a, R: JitRuntime> {
     vectorization: u8,
 }
 
+/// The handles of a trace with the [plan](LaunchPlan) to launch it.
+struct Launch<R: JitRuntime> {
+    handle_inputs: Vec<HandleInput<R>>,
+    handle_outputs: Vec<HandleOutput<R>>,
+    vectorization: u8,
+    plan: Arc<LaunchPlan>,
+}
+
+/// What the analysis of a trace found: where the outputs are written and the config of the
+/// kernel.
+#[derive(Debug)]
+struct LaunchPlan {
+    outputs: Vec<OutputPlan>,
+    config: ElemwiseConfig,
+}
+
+#[derive(Debug)]
+enum OutputPlan {
+    /// The output is written in place of the input at the given position.
+    Alias { input_pos: usize },
+    /// The output is written to a new buffer.
+    Owned,
+}
+
+/// Everything the analysis of a trace depends on besides the trace itself, the relative shapes of
+/// the trace already telling which dimensions are equal.
+///
+/// The analysis only compares the layouts of the inputs with each other, so the key records which
+/// inputs share the same layout instead of their strides. A trace is therefore analysed once for
+/// all the sizes of its dynamic dimensions, like the batch size or the sequence length, whether
+/// its inputs are contiguous or not.
+#[derive(Hash, PartialEq, Eq, Debug)]
+struct LaunchKey {
+    inputs: Vec<InputKey>,
+    vectorization: u8,
+}
+
+#[derive(Hash, PartialEq, Eq, Debug)]
+struct InputKey {
+    /// If the input can be written in place.
+    inplace: bool,
+    layout: InputLayout,
+}
+
+#[derive(Hash, PartialEq, Eq, Debug)]
+enum InputLayout {
+    Contiguous,
+    /// The input isn't contiguous and has the same shape and strides as the input at the given
+    /// position, which is the first input with this layout.
+    Strided {
+        same_as: usize,
+    },
+}
+
+impl LaunchKey {
+    fn new<R: JitRuntime>(analysis: &LaunchAnalysis<'_, R>) -> Self {
+        let inputs = analysis
+            .handle_inputs
+            .iter()
+            .enumerate()
+            .map(|(pos, input)| {
+                let inplace = analysis
+                    .potential_inplaces
+                    .iter()
+                    .any(|pi| pi.input_pos == pos);
+                let layout = if input.handle.strides == strides_dyn_rank(&input.global_shape) {
+                    InputLayout::Contiguous
+                } else {
+                    let same_as = analysis
+                        .handle_inputs
+                        .iter()
+                        .position(|other| {
+                            other.handle.strides == input.handle.strides
+                                && other.global_shape == input.global_shape
+                        })
+                        .unwrap_or(pos);
+
+                    InputLayout::Strided { same_as }
+                };
+
+                InputKey { inplace, layout }
+            })
+            .collect();
+
+        Self {
+            inputs,
+            vectorization: analysis.vectorization,
+        }
+    }
+}
+
+/// The [launch plans](LaunchPlan) of a trace, so that running the trace again with the same
+/// [kind of inputs](LaunchKey) skips its analysis.
+#[derive(Default, Clone, Debug)]
+struct LaunchPlans {
+    plans: Arc<Mutex<HashMap<LaunchKey, Arc<LaunchPlan>>>>,
+}
+
+impl LaunchPlans {
+    fn get(&self, key: &LaunchKey) -> Option<Arc<LaunchPlan>> {
+        self.plans.lock().unwrap().get(key).cloned()
+    }
+
+    fn insert(&self, key: LaunchKey, plan: Arc<LaunchPlan>) {
+        let mut plans = self.plans.lock().unwrap();
+
+        if plans.len() < MAX_LAUNCH_PLANS {
+            plans.insert(key, plan);
+        }
+    }
+}
+
 #[derive(Debug)]
 enum HandleOutput<R: JitRuntime> {
     Alias {
@@ -147,6 +286,18 @@ struct PotentialInplace<'a> {
 }
 
 impl FuseOnWriteTrace {
+    /// The outputs that don't have the reference shape, being broadcast over it.
+    pub fn broadcast_outputs(&self) -> Vec<Arg> {
+        self.outputs
+            .iter()
+            .filter(|(_, tensor)| tensor.shape != self.shape_ref)
+            .map(|(precision, tensor)| {
+                let index = self.outputs.get_index(precision, tensor.id).unwrap();
+                Arg::Output(index as u32, precision, LayoutInfo::Unknown)
+            })
+            .collect()
+    }
+
     /// Run a trace with the given [runner](TraceRunner).
     pub fn run<R: JitRuntime, BT: BoolElement, Runner: TraceRunner<R>>(
         &self,
@@ -155,37 +306,14 @@ impl FuseOnWriteTrace {
         context: &mut Context<'_, JitFusionHandle<R>>,
         runner: &Runner,
     ) -> Result<(), Runner::Error> {
-        let analysis = self.analyse::<R, BT, Runner>(client, device, context);
+        let launch = self.launch::<R, BT, Runner>(client, device, context, runner);
 
-        let inputs = self.register_inputs(context, &analysis.handle_inputs, analysis.vectorization);
-        let outputs =
-            self.register_outputs::<_, BT>(&analysis.handle_outputs, analysis.vectorization);
+        let inputs = self.register_inputs(context, &launch.handle_inputs, launch.vectorization);
+        let outputs = self.register_outputs::<_, BT>(&launch.handle_outputs, launch.vectorization);
 
-        let mut ops = Sequence::new();
-        for op in analysis.reads.into_values() {
-            ops.push(op);
-        }
-
-        for op in self.ops.iter() {
-            ops.push(op.clone());
-        }
-
-        for op in analysis.writes.into_values() {
-            ops.push(op);
-        }
-
-        let config = ElemwiseConfig {
-            rank: analysis.rank as u32,
-            ref_layout: analysis
-                .reference
-                .expect("An output should exist for the fused kernel")
-                .layout,
-            ops,
-        };
-
-        match Runner::run(runner, client, inputs, outputs, &config) {
+        match Runner::run(runner, client, inputs, outputs, &launch.plan.config) {
             Err(err) => {
-                self.rollback(context, analysis.handle_inputs, analysis.handle_outputs);
+                self.rollback(context, launch.handle_inputs, launch.handle_outputs);
                 Err(err)
             }
             Ok(val) => Ok(val),
@@ -213,12 +341,15 @@ impl FuseOnWriteTrace {
         }
     }
 
-    fn analyse<'a, R: JitRuntime, BT: BoolElement, Runner: TraceRunner<R>>(
-        &'a self,
+    /// Get the handles of the trace and the plan to launch it, analysing the trace only when it
+    /// wasn't run before with the same [kind of inputs](LaunchKey).
+    fn launch<R: JitRuntime, BT: BoolElement, Runner: TraceRunner<R>>(
+        &self,
         client: &ComputeClient<R::Server, R::Channel>,
         device: &R::Device,
         context: &mut Context<'_, JitFusionHandle<R>>,
-    ) -> LaunchAnalysis<'a, R> {
+        runner: &Runner,
+    ) -> Launch<R> {
         let mut analysis = LaunchAnalysis {
             potential_inplaces: Vec::new(),
             global_inputs: Vec::new(),
@@ -226,22 +357,179 @@ impl FuseOnWriteTrace {
             handle_inputs: Vec::new(),
             handle_outputs: Vec::new(),
             reference: None,
-            reads: self.reads.clone(),
-            writes: self.writes.clone(),
+            reads: BTreeMap::new(),
+            writes: BTreeMap::new(),
             rank: 1,
             vectorization: 1,
         };
 
+        self.register_reshapes(context);
         self.analyse_inputs(context, &mut analysis);
-        self.analyse_outputs::<_, BT>(client, device, context, &mut analysis);
 
-        analysis.vectorization = Runner::vectorization(
+        for (_, tensor_relative) in self.outputs.iter() {
+            let tensor_global = context.tensors.get(&tensor_relative.id).unwrap().clone();
+            analysis.global_outputs.push(tensor_global);
+        }
+
+        analysis.vectorization = runner.vectorization(
             analysis.handle_inputs.iter().map(|item| &item.handle),
             analysis.global_inputs.iter(),
             analysis.global_outputs.iter(),
         );
 
-        analysis
+        let key = LaunchKey::new(&analysis);
+        let plan = match self.plans.get(&key) {
+            Some(plan) => {
+                self.register_planned_outputs::<R, BT>(
+                    client,
+                    device,
+                    context,
+                    &plan,
+                    &mut analysis,
+                );
+                plan
+            }
+            None => {
+                analysis.reads = self.reads.clone();
+                analysis.writes = self.writes.clone();
+                self.analyse_outputs::<_, BT>(client, device, context, &mut analysis);
+
+                let plan = Arc::new(self.plan(&mut analysis));
+                self.plans.insert(key, plan.clone());
+                plan
+            }
+        };
+
+        Launch {
+            handle_inputs: analysis.handle_inputs,
+            handle_outputs: analysis.handle_outputs,
+            vectorization: analysis.vectorization,
+            plan,
+        }
+    }
+
+    /// Create the plan found by the analysis, to be reused by the next runs.
+    fn plan<R: JitRuntime>(&self, analysis: &mut LaunchAnalysis<'_, R>) -> LaunchPlan {
+        let mut ops = Sequence::new();
+        for op in core::mem::take(&mut analysis.reads).into_values() {
+            ops.push(op);
+        }
+
+        for op in self.ops.iter() {
+            ops.push(op.clone());
+        }
+
+        for op in core::mem::take(&mut analysis.writes).into_values() {
+            ops.push(op);
+        }
+
+        let outputs = analysis
+            .handle_outputs
+            .iter()
+            .map(|output| match output {
+                HandleOutput::Alias { input_pos, .. } => OutputPlan::Alias {
+                    input_pos: *input_pos,
+                },
+                HandleOutput::Owned { .. } => OutputPlan::Owned,
+            })
+            .collect();
+
+        let config = ElemwiseConfig {
+            rank: analysis.rank as u32,
+            ref_layout: analysis
+                .reference
+                .take()
+                .expect("An output should exist for the fused kernel")
+                .layout,
+            ops,
+        };
+
+        LaunchPlan { outputs, config }
+    }
+
+    /// Register the outputs where the plan of a previous analysis writes them.
+    fn register_planned_outputs<R: JitRuntime, BT: BoolElement>(
+        &self,
+        client: &ComputeClient<R::Server, R::Channel>,
+        device: &R::Device,
+        context: &mut Context<'_, JitFusionHandle<R>>,
+        plan: &LaunchPlan,
+        analysis: &mut LaunchAnalysis<'_, R>,
+    ) {
+        let outputs = self.outputs.iter().zip(plan.outputs.iter());
+
+        for ((precision, _), (output, tensor_global)) in outputs.zip(analysis.global_outputs.iter())
+        {
+            match output {
+                OutputPlan::Alias { input_pos } => {
+                    let handle = analysis.handle_inputs[*input_pos].handle.clone();
+
+                    context.handles.register_handle(tensor_global.id, handle);
+                    analysis.handle_outputs.push(HandleOutput::Alias {
+                        input_pos: *input_pos,
+                        precision,
+                    });
+                }
+                OutputPlan::Owned => {
+                    let handle = Self::output_handle::<R, BT>(client, device, tensor_global);
+
+                    context
+                        .handles
+                        .register_handle(tensor_global.id, handle.clone());
+                    analysis.handle_outputs.push(HandleOutput::Owned {
+                        precision,
+                        handle,
+                        global_shape: tensor_global.shape.clone(),
+                        global_id: tensor_global.id,
+                    });
+                }
+            }
+        }
+    }
+
+    fn output_handle<R: JitRuntime, BT: BoolElement>(
+        client: &ComputeClient<R::Server, R::Channel>,
+        device: &R::Device,
+        tensor_global: &TensorDescription,
+    ) -> JitFusionHandle<R> {
+        // We encode bool tensors as `B`.
+        let dtype = match tensor_global.dtype {
+            DType::Bool => BT::dtype(),
+            _ => tensor_global.dtype,
+        };
+        let size = tensor_global.shape.iter().product::<usize>() * Elem::from(dtype).size();
+
+        JitFusionHandle {
+            client: client.clone(),
+            handle: memory::empty::<R>(client, device, size),
+            device: device.clone(),
+            strides: strides_dyn_rank(&tensor_global.shape),
+            dtype,
+        }
+    }
+
+    fn register_reshapes<R: JitRuntime>(&self, context: &mut Context<'_, JitFusionHandle<R>>) {
+        for reshape in self.reshapes.iter() {
+            let reshaped = context.tensors.get(&reshape.reshaped.id).unwrap().clone();
+
+            // The reshape might already be registered by a previous run that was rolled back.
+            if context.handles.has_handle(&reshaped.id) {
+                continue;
+            }
+
+            let original = context.tensors.get(&reshape.original.id).unwrap().clone();
+            let handle = context
+                .handles
+                .get_handle(&original.id, &reshape.original.status);
+            let tensor = ops::reshape(
+                handle.into_tensor(Shape::from(original.shape)),
+                Shape::from(reshaped.shape),
+            );
+
+            context
+                .handles
+                .register_handle(reshaped.id, JitFusionHandle::from(tensor));
+        }
     }
 
     fn analyse_inputs<'a, R: JitRuntime>(
@@ -287,16 +575,20 @@ impl FuseOnWriteTrace {
         context: &mut Context<'_, JitFusionHandle<R>>,
         analysis: &mut LaunchAnalysis<'a, R>,
     ) {
-        for (precision, tensor_relative) in self.outputs.iter() {
-            let tensor_global = context.tensors.get(&tensor_relative.id).unwrap().clone();
+        for (pos, (precision, tensor_relative)) in self.outputs.iter().enumerate() {
+            let tensor_global = analysis.global_outputs[pos].clone();
             let strides = strides_dyn_rank(&tensor_global.shape);
+            // Outputs that are broadcast over the reference can't be used as the reference, and
+            // can't be written in place of an input that might still be read by other units.
+            let is_broadcast = tensor_relative.shape != self.shape_ref;
 
             if let Some(index) = analysis
                 .potential_inplaces
                 .iter()
                 .enumerate()
                 .find(|(_pos, pi)| {
-                    pi.tensor_relative.dtype == tensor_global.dtype
+                    !is_broadcast
+                        && pi.tensor_relative.dtype == tensor_global.dtype
                         && pi.tensor_relative.shape == tensor_relative.shape
                         && pi.strides == strides
                 })
@@ -340,11 +632,15 @@ impl FuseOnWriteTrace {
                     input_pos: potential_inplace.input_pos,
                     precision,
                 });
-                analysis.global_outputs.push(tensor_global);
             } else {
-                if analysis.reference.is_none() {
+                if analysis.reference.is_none() && !is_broadcast {
+                    let index_output = self
+                        .outputs
+                        .get_index(precision, tensor_relative.id)
+                        .unwrap();
+
                     analysis.reference = Some(Reference {
-                        layout: Arg::Output(0, precision, LayoutInfo::IsRef),
+                        layout: Arg::Output(index_output as u32, precision, LayoutInfo::IsRef),
                         shape: tensor_global.shape.clone(),
                         strides: strides.clone(),
                     });
@@ -364,20 +660,7 @@ impl FuseOnWriteTrace {
                     }
                 }
 
-                // We encode bool tensors as `B`.
-                let dtype = match tensor_global.dtype {
-                    DType::Bool => BT::dtype(),
-                    _ => tensor_global.dtype,
-                };
-                let size = tensor_global.shape.iter().product::<usize>() * Elem::from(dtype).size();
-
-                let handle = JitFusionHandle {
-                    client: client.clone(),
-                    handle: client.empty(size),
-                    device: device.clone(),
-                    strides,
-                    dtype,
-                };
+                let handle = Self::output_handle::<R, BT>(client, device, &tensor_global);
 
                 analysis.rank = usize::max(tensor_global.shape.len(), analysis.rank);
                 context
@@ -390,13 +673,43 @@ impl FuseOnWriteTrace {
                     global_shape: tensor_global.shape.clone(),
                     global_id: tensor_global.id,
                 });
-                analysis.global_outputs.push(tensor_global);
             }
         }
 
+        if analysis.reference.is_none() {
+            self.reference_from_inputs(analysis);
+        }
+
         Self::add_layout_info_inputs(analysis);
     }
 
+    /// Use an input as the reference when all outputs are broadcast over it.
+    fn reference_from_inputs<R: JitRuntime>(&self, analysis: &mut LaunchAnalysis<'_, R>) {
+        for (i, (precision, tensor_relative)) in self.inputs.iter().enumerate() {
+            if tensor_relative.shape != self.shape_ref {
+                continue;
+            }
+
+            let handle_input = analysis.handle_inputs.get(i).unwrap();
+            let index_input = self
+                .inputs
+                .get_index(precision, tensor_relative.id)
+                .unwrap();
+
+            analysis.reference = Some(Reference {
+                layout: Arg::Input(index_input as u32, precision, LayoutInfo::IsRef),
+                shape: handle_input.global_shape.clone(),
+                strides: handle_input.handle.strides.clone(),
+            });
+
+            if let Some(ElemwiseOp::Assign(op)) = analysis.reads.get_mut(&tensor_relative.id) {
+                op.input.add_layout_info(LayoutInfo::IsRef);
+            }
+
+            return;
+        }
+    }
+
     fn add_layout_info_inputs<R: JitRuntime>(analysis: &mut LaunchAnalysis<'_, R>) {
         for hi in analysis.handle_inputs.iter() {
             if let Some(reference) = analysis.reference.as_ref() {
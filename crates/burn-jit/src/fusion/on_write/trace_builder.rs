@@ -1,6 +1,6 @@
 use super::{
     ir::{Arg, BinaryElemwiseArgs, ElemwiseOp, ElemwisePrecision, LayoutInfo, UnaryElemwiseArgs},
-    trace::{FuseOnWriteTrace, RegisteredTensors},
+    trace::{FuseOnWriteTrace, RegisteredTensors, ReshapedInput},
 };
 use burn_tensor::{
     repr::{TensorDescription, TensorId, TensorStatus},
@@ -19,6 +19,7 @@ pub struct FuseOnWriteTraceBuilder {
     pub bool_precision: ElemwisePrecision,
     outputs_unhandled: Vec<Arg>,
     inputs_unhandled: Vec<TensorId>,
+    reshapes: Vec<ReshapedInput>,
 }
 
 impl FuseOnWriteTraceBuilder {
@@ -33,6 +34,7 @@ impl FuseOnWriteTraceBuilder {
             bool_precision,
             outputs_unhandled: Vec::new(),
             inputs_unhandled: Vec::new(),
+            reshapes: Vec::new(),
         }
     }
 
@@ -106,6 +108,35 @@ impl FuseOnWriteTraceBuilder {
         }
     }
 
+    /// Read the output of a reshape as an input, so that broadcast tensors like biases can be
+    /// reshaped without breaking the fusion.
+    ///
+    /// Returns `None` when the tensor is computed or already read by the fused operations.
+    pub fn input_reshaped(
+        &mut self,
+        tensor: &TensorDescription,
+        output: &TensorDescription,
+    ) -> Option<Arg> {
+        let precision = tensor.dtype.into();
+
+        // Bool tensors are encoded as bool_precision.
+        let precision_input = match precision {
+            ElemwisePrecision::Bool => self.bool_precision,
+            _ => precision,
+        };
+
+        if self.locals.get(precision, tensor.id).is_some()
+            || self.inputs.get(precision_input, tensor.id).is_some()
+        {
+            return None;
+        }
+
+        self.reshapes
+            .push(ReshapedInput::new(tensor.clone(), output.clone()));
+
+        Some(self.input(output))
+    }
+
     pub fn output(&mut self, tensor: &TensorDescription) -> Arg {
         let precision = tensor.dtype.into();
 
@@ -143,7 +174,18 @@ impl FuseOnWriteTraceBuilder {
         Arg::Scalar(new_index, precision)
     }
 
-    pub fn build(&self) -> FuseOnWriteTrace {
+    /// If a global tensor with the given shape is read or written, so that it can be used as the
+    /// reference layout.
+    pub fn has_global(&self, shape: &[usize]) -> bool {
+        let outputs = self.output_tensors();
+
+        self.inputs
+            .iter()
+            .chain(outputs.iter())
+            .any(|(_, tensor)| tensor.shape == shape)
+    }
+
+    pub fn build(&self, shape_ref: Vec<usize>) -> FuseOnWriteTrace {
         let inputs = self.inputs.clone();
         let outputs = self.output_tensors();
         let ops = self.ops.clone();
@@ -174,6 +216,8 @@ impl FuseOnWriteTraceBuilder {
             reads,
             writes,
             self.inputs_unhandled.clone(),
+            self.reshapes.clone(),
+            shape_ref,
         )
     }
 
@@ -331,6 +375,10 @@ impl FuseOnWriteTraceBuilder {
                 &mut local_tensor_ids_input,
                 &mut local_tensor_ids_output,
             ),
+            ElemwiseOp::Reduce(op) => {
+                mark(&op.input, &mut local_tensor_ids_input);
+                mark(&op.out, &mut local_tensor_ids_output);
+            }
         };
 
         // For all operators, mark their local tensor id in the proper set.
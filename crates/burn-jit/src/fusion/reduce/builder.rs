@@ -0,0 +1,75 @@
+use burn_fusion::{OptimizationBuilder, OptimizationProperties};
+use burn_tensor::repr::OperationDescription;
+
+use crate::{
+    fusion::{
+        on_write::{builder::FuseOnWriteBuilder, ir::ElemwisePrecision},
+        JitOptimization,
+    },
+    JitRuntime,
+};
+
+use super::optimization::ReduceOptimization;
+
+/// Fused reductions along a single axis with the element wise operations reading their inputs
+/// and their results, like softmax and layer norm.
+pub(crate) struct ReduceBuilder<R: JitRuntime> {
+    builder: FuseOnWriteBuilder,
+    device: R::Device,
+}
+
+impl<R: JitRuntime> ReduceBuilder<R> {
+    pub fn new(device: R::Device, bool_precision: ElemwisePrecision) -> Self {
+        let client = R::client(&device);
+        let props = client.properties();
+        let max_bindings = props.hardware_properties().max_bindings;
+
+        Self {
+            builder: FuseOnWriteBuilder::new(max_bindings, bool_precision).with_reductions(),
+            device,
+        }
+    }
+}
+
+impl<R: JitRuntime> OptimizationBuilder<JitOptimization<R>> for ReduceBuilder<R> {
+    fn register(&mut self, operation: &OperationDescription) {
+        self.builder.register(operation)
+    }
+
+    fn build(&self) -> JitOptimization<R> {
+        let client = R::client(&self.device);
+        let trace = self.builder.build();
+        let axis = self
+            .builder
+            .reduce_axis()
+            .expect("A reduction should be fused");
+        let reduce =
+            ReduceOptimization::<R>::new(trace, client, self.device.clone(), self.len(), axis);
+
+        JitOptimization::Reduce(reduce)
+    }
+
+    fn reset(&mut self) {
+        self.builder.reset()
+    }
+
+    fn status(&self) -> burn_fusion::OptimizationStatus {
+        self.builder.status()
+    }
+
+    fn properties(&self) -> OptimizationProperties {
+        let mut properties = self.builder.properties();
+        let num_reductions = self.builder.num_reductions();
+        // Without reductions, the element wise optimization is a better fit, and reductions that
+        // aren't fused with element wise operations are faster with the autotuned reduce kernels.
+        properties.ready = properties.ready
+            && num_reductions > 0
+            && self.builder.len() > num_reductions
+            && self.builder.has_reference();
+        properties
+    }
+
+    fn len(&self) -> usize {
+        self.builder.len()
+    }
+}
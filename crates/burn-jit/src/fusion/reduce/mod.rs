@@ -0,0 +1,2 @@
+pub(crate) mod builder;
+pub(crate) mod optimization;
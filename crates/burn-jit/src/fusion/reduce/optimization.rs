@@ -0,0 +1,432 @@
+use crate::fusion::on_write::{
+    io::{global_shape, global_stride},
+    kernel::{fuse_on_read, fuse_on_write},
+};
+use crate::{fusion::JitFusionHandle, BoolElement, JitRuntime};
+use burn_fusion::stream::Context;
+use burn_tensor::repr::TensorDescription;
+use cubecl::{calculate_cube_count_elemwise, client::ComputeClient, prelude::*, CubeDim};
+use serde::{Deserialize, Serialize};
+
+use crate::fusion::on_write::{
+    ir::{
+        Arg, ElemwiseConfig, ElemwiseOp, GlobalArgs, GlobalArgsLaunch, ReduceElemwiseArgs,
+        ReduceKind,
+    },
+    trace::{vectorization_last_dim, FuseOnWriteTrace, TraceRunner},
+};
+
+/// Number of units reducing each row.
+const CUBE_SIZE: u32 = 256;
+
+#[derive(new)]
+/// Fuse reductions along a single axis with element wise operations into a single kernel.
+pub struct ReduceOptimization<R: JitRuntime> {
+    trace: FuseOnWriteTrace,
+    client: ComputeClient<R::Server, R::Channel>,
+    device: R::Device,
+    len: usize,
+    axis: usize,
+}
+
+#[derive(Serialize, Deserialize)]
+/// State for the [reduce optimization](ReduceOptimization).
+pub struct ReduceOptimizationState {
+    trace: FuseOnWriteTrace,
+    len: usize,
+    axis: usize,
+}
+
+impl<R: JitRuntime> ReduceOptimization<R> {
+    /// Execute the optimization.
+    pub fn execute<BT: BoolElement>(&mut self, context: &mut Context<'_, JitFusionHandle<R>>) {
+        let runner = FusedReduce::new(self.axis, self.trace.broadcast_outputs());
+
+        self.trace
+            .run::<R, BT, FusedReduce>(&self.client, &self.device, context, &runner)
+            .unwrap();
+    }
+
+    /// Number of operations fused, including the reductions.
+    pub fn num_ops_fused(&self) -> usize {
+        self.len
+    }
+
+    /// Create an optimization from its [state](ReduceOptimizationState).
+    pub fn from_state(device: &R::Device, state: ReduceOptimizationState) -> Self {
+        Self {
+            trace: state.trace,
+            len: state.len,
+            axis: state.axis,
+            client: R::client(device),
+            device: device.clone(),
+        }
+    }
+
+    /// Convert the optimization to its [state](ReduceOptimizationState).
+    pub fn to_state(&self) -> ReduceOptimizationState {
+        ReduceOptimizationState {
+            trace: self.trace.clone(),
+            len: self.len,
+            axis: self.axis,
+        }
+    }
+}
+
+#[derive(new)]
+/// Run the fused operations with one cube per row of the reduction axis.
+pub struct FusedReduce {
+    axis: usize,
+    /// The outputs that are reduced along the axis, written once per row.
+    row_outputs: Vec<Arg>,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+/// Configuration of the [fused reduce kernel](reduce_fuse).
+pub struct ReduceConfig {
+    /// The axis along which the reductions are executed.
+    pub axis: u32,
+    /// The line size of the reads and writes, only greater than one when reducing the last axis.
+    pub line_size: u32,
+    /// The reductions in their execution order.
+    pub stages: Vec<ReduceStage>,
+    /// The operations writing the outputs that have the reference shape.
+    pub elements: ElemwiseConfig,
+    /// The operations writing the outputs that are reduced along the axis.
+    pub rows: ElemwiseConfig,
+    pub write_elements: bool,
+    pub write_rows: bool,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+/// A reduction, with the element wise operations computing its input.
+pub struct ReduceStage {
+    pub config: ElemwiseConfig,
+    pub reduce: ReduceElemwiseArgs,
+}
+
+impl FusedReduce {
+    /// Split the fused operations at each reduction.
+    ///
+    /// The operations before a reduction are executed again by each following stage, since the
+    /// values of a row can't be kept in registers.
+    fn config(&self, config: &ElemwiseConfig, line_size: u8) -> ReduceConfig {
+        let elemwise_config = |ops: &[ElemwiseOp]| {
+            let mut sequence = Sequence::new();
+            for op in ops {
+                sequence.push(op.clone());
+            }
+
+            ElemwiseConfig {
+                rank: config.rank,
+                ref_layout: config.ref_layout,
+                ops: sequence,
+            }
+        };
+
+        let mut ops = Vec::new();
+        let mut stages = Vec::new();
+        let mut writes_elements = Vec::new();
+        let mut writes_rows = Vec::new();
+
+        for i in 0..config.ops.len() {
+            match config.ops.index(i) {
+                ElemwiseOp::Reduce(reduce) => stages.push(ReduceStage {
+                    config: elemwise_config(&ops),
+                    reduce: reduce.clone(),
+                }),
+                ElemwiseOp::Assign(op) if matches!(op.out, Arg::Output(..)) => {
+                    match self.is_row_output(&op.out) {
+                        true => writes_rows.push(ElemwiseOp::Assign(op.clone())),
+                        false => writes_elements.push(ElemwiseOp::Assign(op.clone())),
+                    }
+                }
+                op => ops.push(op.clone()),
+            }
+        }
+
+        let write_elements = !writes_elements.is_empty();
+        let write_rows = !writes_rows.is_empty();
+
+        ReduceConfig {
+            axis: self.axis as u32,
+            line_size: line_size as u32,
+            stages,
+            elements: elemwise_config(&[ops.clone(), writes_elements].concat()),
+            rows: elemwise_config(&[ops, writes_rows].concat()),
+            write_elements,
+            write_rows,
+        }
+    }
+
+    fn is_row_output(&self, arg: &Arg) -> bool {
+        let Arg::Output(index, precision, _) = arg else {
+            return false;
+        };
+
+        self.row_outputs
+            .iter()
+            .any(|output| matches!(output, Arg::Output(i, p, _) if i == index && p == precision))
+    }
+}
+
+impl<R: JitRuntime> TraceRunner<R> for FusedReduce {
+    type Error = (); // No error possible
+
+    fn run<'a>(
+        &'a self,
+        client: &'a ComputeClient<R::Server, R::Channel>,
+        inputs: GlobalArgsLaunch<'a, R>,
+        outputs: GlobalArgsLaunch<'a, R>,
+        config: &'a ElemwiseConfig,
+    ) -> Result<(), Self::Error> {
+        let (shape, line_size) = match config.ref_layout {
+            Arg::Input(..) => (
+                inputs.shape(&config.ref_layout),
+                inputs.line_size(&config.ref_layout),
+            ),
+            Arg::Output(..) => (
+                outputs.shape(&config.ref_layout),
+                outputs.line_size(&config.ref_layout),
+            ),
+            _ => panic!("Invalid reference layout"),
+        };
+        let num_rows = shape
+            .iter()
+            .enumerate()
+            .filter(|(dim, _)| *dim != self.axis)
+            .map(|(_, size)| *size)
+            .product::<usize>();
+
+        if num_rows == 0 || shape[self.axis] == 0 {
+            return Ok(());
+        }
+
+        // One cube per row.
+        let cube_count = calculate_cube_count_elemwise(num_rows, CubeDim::new(1, 1, 1));
+        let cube_dim = CubeDim::new(CUBE_SIZE, 1, 1);
+
+        unsafe {
+            reduce_fuse::launch_unchecked(
+                client,
+                cube_count,
+                cube_dim,
+                inputs,
+                outputs,
+                ScalarArg::new(num_rows as u32),
+                self.config(config, line_size),
+            );
+        };
+
+        Ok(())
+    }
+
+    fn vectorization<'a>(
+        &self,
+        handles_inputs: impl Iterator<Item = &'a JitFusionHandle<R>>,
+        inputs: impl Iterator<Item = &'a TensorDescription>,
+        outputs: impl Iterator<Item = &'a TensorDescription>,
+    ) -> u8 {
+        let outputs = outputs.collect::<Vec<_>>();
+        let rank = outputs
+            .first()
+            .map(|tensor| tensor.shape.len())
+            .unwrap_or(0);
+
+        // The units of a cube read consecutive lines of a row only when reducing the last axis.
+        // The outputs reduced along the axis have a last dimension of one, so they disable the
+        // vectorization since they are written with the same line size.
+        if self.axis + 1 != rank {
+            return 1;
+        }
+
+        vectorization_last_dim::<R>(handles_inputs, inputs, outputs.into_iter())
+    }
+}
+
+#[cube(launch_unchecked)]
+fn reduce_fuse(
+    inputs: &GlobalArgs,
+    outputs: &mut GlobalArgs,
+    num_rows: u32,
+    #[comptime] config: &ReduceConfig,
+) {
+    let row = CUBE_POS;
+
+    if row >= num_rows {
+        terminate!();
+    }
+
+    let line_size = comptime![config.line_size];
+    let axis = comptime![config.axis].runtime();
+    let axis_size = ref_shape(inputs, outputs, axis, &config.elements);
+    // The reads are contiguous along the axis when vectorized, since it's the last one.
+    let axis_stride = ref_stride(inputs, outputs, axis, &config.elements);
+    let num_lines = axis_size / line_size;
+    let row_offset = ref_row_offset(inputs, outputs, row, axis, &config.elements) / line_size;
+
+    let mut shared = SharedMemory::<f32>::new(CUBE_SIZE);
+    let mut values = Registry::<Arg, Line<f32>>::new();
+    let mut args = comptime![Sequence::<Arg>::new()];
+
+    #[unroll]
+    for index in 0..comptime![config.stages.len() as u32] {
+        let stage = comptime![config.stages[index as usize].clone()];
+        let kind = comptime![stage.reduce.kind];
+        let mut accumulator = init_value::<f32>(kind);
+
+        for i in range_stepped(UNIT_POS, num_lines, CUBE_DIM) {
+            let value = fuse_on_read::<f32>(
+                inputs,
+                outputs,
+                row_offset + i * axis_stride,
+                values.clone(),
+                args.clone(),
+                comptime![stage.reduce.input],
+                &stage.config,
+            );
+
+            #[unroll]
+            for j in 0..line_size {
+                accumulator = combine::<f32>(accumulator, value[j], kind);
+            }
+        }
+
+        let mut result = reduce_cube::<f32>(&mut shared, accumulator, kind);
+
+        if comptime![kind == ReduceKind::Mean] {
+            result /= f32::cast_from(axis_size);
+        }
+
+        // The result of the reduction is written as a local for the following stages.
+        values.insert(
+            comptime![stage.reduce.out],
+            Line::empty(line_size).fill(result),
+        );
+        comptime![args.push(stage.reduce.out)];
+    }
+
+    if comptime![config.write_elements] {
+        for i in range_stepped(UNIT_POS, num_lines, CUBE_DIM) {
+            fuse_on_write::<f32>(
+                inputs,
+                outputs,
+                row_offset + i * axis_stride,
+                values.clone(),
+                args.clone(),
+                &config.elements,
+            );
+        }
+    }
+
+    if comptime![config.write_rows] {
+        // Wait for all units to read the inputs, since the outputs reduced along the axis can be
+        // written in place of broadcast inputs.
+        sync_units();
+
+        if UNIT_POS == 0 {
+            fuse_on_write::<f32>(inputs, outputs, row_offset, values, args, &config.rows);
+        }
+    }
+}
+
+#[cube]
+fn init_value<F: Float>(#[comptime] kind: ReduceKind) -> F {
+    match comptime![kind] {
+        ReduceKind::Sum => F::new(0.0),
+        ReduceKind::Mean => F::new(0.0),
+        ReduceKind::Max => F::min_value(),
+        ReduceKind::Min => F::max_value(),
+    }
+}
+
+#[cube]
+fn combine<F: Float>(lhs: F, rhs: F, #[comptime] kind: ReduceKind) -> F {
+    match comptime![kind] {
+        ReduceKind::Sum => lhs + rhs,
+        ReduceKind::Mean => lhs + rhs,
+        ReduceKind::Max => F::max(lhs, rhs),
+        ReduceKind::Min => F::min(lhs, rhs),
+    }
+}
+
+/// Reduce the values of all units of the cube with a tree reduction in shared memory.
+#[cube]
+fn reduce_cube<F: Float>(
+    shared: &mut SharedMemory<F>,
+    value: F,
+    #[comptime] kind: ReduceKind,
+) -> F {
+    shared[UNIT_POS] = value;
+    sync_units();
+
+    #[unroll]
+    for i in 0..comptime![CUBE_SIZE.ilog2()] {
+        let stride = comptime![CUBE_SIZE >> (i + 1)];
+
+        if UNIT_POS < stride {
+            shared[UNIT_POS] = combine::<F>(shared[UNIT_POS], shared[UNIT_POS + stride], kind);
+        }
+        sync_units();
+    }
+
+    let result = shared[0];
+    // The shared memory is reused by the next reduction.
+    sync_units();
+
+    result
+}
+
+#[cube]
+fn ref_shape(
+    inputs: &GlobalArgs,
+    outputs: &GlobalArgs,
+    dim: u32,
+    #[comptime] config: &ElemwiseConfig,
+) -> u32 {
+    match comptime![config.ref_layout] {
+        Arg::Input(pos, precision, _) => global_shape(inputs, dim, pos, precision),
+        Arg::Output(pos, precision, _) => global_shape(outputs, dim, pos, precision),
+        _ => comptime![panic!("Invalid ref layout.")],
+    }
+}
+
+#[cube]
+fn ref_stride(
+    inputs: &GlobalArgs,
+    outputs: &GlobalArgs,
+    dim: u32,
+    #[comptime] config: &ElemwiseConfig,
+) -> u32 {
+    match comptime![config.ref_layout] {
+        Arg::Input(pos, precision, _) => global_stride(inputs, dim, pos, precision),
+        Arg::Output(pos, precision, _) => global_stride(outputs, dim, pos, precision),
+        _ => comptime![panic!("Invalid ref layout.")],
+    }
+}
+
+/// The offset of the first element of the row in the reference layout.
+#[cube]
+fn ref_row_offset(
+    inputs: &GlobalArgs,
+    outputs: &GlobalArgs,
+    row: u32,
+    axis: u32,
+    #[comptime] config: &ElemwiseConfig,
+) -> u32 {
+    let rank = comptime![config.rank].runtime();
+    let mut remaining = row;
+    let mut offset = 0u32;
+
+    for i in 0..rank {
+        let dim = rank - 1 - i;
+
+        if dim != axis {
+            let shape = ref_shape(inputs, outputs, dim, config);
+            offset += (remaining % shape) * ref_stride(inputs, outputs, dim, config);
+            remaining /= shape;
+        }
+    }
+
+    offset
+}
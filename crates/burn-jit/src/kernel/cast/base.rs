@@ -1,4 +1,4 @@
-use crate::{tensor::JitTensor, JitElement, JitRuntime};
+use crate::{memory, tensor::JitTensor, JitElement, JitRuntime};
 use cubecl::linalg::tensor::index_offset_with_layout;
 use cubecl::{calculate_cube_count_elemwise, prelude::*, tensor_vectorization_factor};
 use std::any::TypeId;
@@ -52,7 +52,11 @@ pub fn cast<R: JitRuntime, EI: JitElement, EO: JitElement>(input: JitTensor<R>)
     let cube_count =
         calculate_cube_count_elemwise(num_elems / vectorization_factor as usize, cube_dim);
     let client = input.client.clone();
-    let handle = client.empty(num_elems * core::mem::size_of::<EO>());
+    let handle = memory::empty::<R>(
+        &client,
+        &input.device,
+        num_elems * core::mem::size_of::<EO>(),
+    );
     let output = JitTensor::new_contiguous(
         client.clone(),
         input.device.clone(),
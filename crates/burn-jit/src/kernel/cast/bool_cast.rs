@@ -1,4 +1,4 @@
-use crate::{tensor::JitTensor, BoolElement, JitElement, JitRuntime};
+use crate::{memory, tensor::JitTensor, BoolElement, JitElement, JitRuntime};
 use cubecl::{calculate_cube_count_elemwise, prelude::*, CubeDim};
 
 #[cube(launch)]
@@ -20,7 +20,11 @@ pub fn bool_cast<R: JitRuntime, BT: BoolElement, EO: JitElement>(
     tensor: JitTensor<R>,
 ) -> JitTensor<R> {
     let num_elems = tensor.shape.num_elements();
-    let buffer = tensor.client.empty(num_elems * core::mem::size_of::<EO>());
+    let buffer = memory::empty::<R>(
+        &tensor.client,
+        &tensor.device,
+        num_elems * core::mem::size_of::<EO>(),
+    );
     let output = JitTensor::new_contiguous(
         tensor.client.clone(),
         tensor.device.clone(),
@@ -7,8 +7,9 @@ use crate::{
 #[cfg(feature = "autotune")]
 use super::{conv2d_autotune, conv_transpose2d_autotune};
 use super::{
-    conv2d_direct, conv2d_im2col, conv_transpose2d_col2im, conv_transpose2d_direct,
-    gemm::launch::conv2d_gemm_cmma_large_m, implicit_gemm::conv2d_implicit_gemm,
+    conv2d_depthwise, conv2d_direct, conv2d_im2col, conv_transpose2d_col2im,
+    conv_transpose2d_direct, gemm::launch::conv2d_gemm_cmma_large_m,
+    implicit_gemm::conv2d_implicit_gemm, winograd::conv2d_winograd,
 };
 
 /// The strategy to be used when launching a convolution kernel.
@@ -20,6 +21,12 @@ pub enum Conv2dStrategy {
     Autotune,
     /// GEMM (im2col) based implementation of convolution. Significantly increased memory usage.
     Gemm,
+    /// Direct convolution specialized for depthwise convolutions, where the number of groups is
+    /// the number of input channels.
+    Depthwise,
+    /// Winograd F(2x2, 3x3) convolution, computing 2x2 output tiles with fewer multiplications.
+    /// Only supports 3x3 kernels with a stride of 1, no dilation and a single group.
+    Winograd,
     /// Implicit GEMM implementation of convolution. Lower memory usage but requires CMMA and
     /// has constraints on tensor shape.
     ImplicitGemm,
@@ -83,6 +90,8 @@ pub fn conv2d<R: JitRuntime, E: FloatElement>(
         #[cfg(feature = "autotune")]
         Conv2dStrategy::Autotune => Ok(conv2d_autotune::<R, E>(input, weight, bias, options)),
         Conv2dStrategy::Gemm => conv2d_im2col::<R, E>(input, weight, bias, options),
+        Conv2dStrategy::Depthwise => conv2d_depthwise::<R, E>(input, weight, bias, options),
+        Conv2dStrategy::Winograd => conv2d_winograd::<R, E>(input, weight, bias, options),
         Conv2dStrategy::ImplicitGemm => conv2d_implicit_gemm::<R, E>(input, weight, bias, options),
         Conv2dStrategy::ImplicitGemmComplex => {
             conv2d_gemm_cmma_large_m::<R, E>(input, weight, bias, options)
@@ -17,6 +17,7 @@ use super::{
     selection::{Balanced, ConvSelector, Large},
 };
 use crate::{
+    fast_path::record_fast_path_miss,
     kernel::{
         conv::{
             conv2d::gemm::{
@@ -109,7 +110,9 @@ where
     SP::EG: JitElement,
 {
     if options.groups != 1 {
-        return Err(ConvLaunchError::Groups(options.groups));
+        let err = ConvLaunchError::Groups(options.groups);
+        record_fast_path_miss("conv2d_gemm_cmma", &err);
+        return Err(err);
     }
 
     let [batch_size, in_channels, height, width] = input.shape.dims();
@@ -10,10 +10,12 @@ use cubecl::{
     prelude::*,
     Compiler, CubeCount, CubeDim, Feature,
 };
-use half::f16;
+use half::{bf16, f16};
+use std::any::TypeId;
 
 use crate::{
-    kernel::{conv::ConvLaunchError, into_contiguous, slice, slice_assign},
+    fast_path::record_fast_path_miss,
+    kernel::{cast, conv::ConvLaunchError, into_contiguous, slice, slice_assign},
     ops::{
         numeric::{empty_device, zeros_device},
         permute,
@@ -26,6 +28,8 @@ use super::nchw_to_nhwc;
 
 /// Perform a 2D convolution using the implicit GEMM algorithm. Requires `cmma` to be available.
 ///
+/// Grouped convolutions launch one GEMM per group.
+///
 /// * `input` - The input feature map
 /// * `weight` - The weights (filter) applied to each kernel
 /// * `bias` - The bias added to each channel
@@ -37,12 +41,36 @@ pub fn conv2d_implicit_gemm<R: JitRuntime, F: FloatElement>(
     bias: Option<JitTensor<R>>,
     options: ConvOptions<2>,
 ) -> Result<JitTensor<R>, ConvLaunchError> {
+    if options.groups != 1 {
+        return conv2d_implicit_gemm_grouped::<R, F>(input, weight, bias, options);
+    }
+
     let is_tf32 = F::as_elem_native_unchecked() == Elem::Float(FloatKind::F32)
         && input
             .client
             .properties()
             .feature_enabled(Feature::Type(Elem::Float(FloatKind::TF32)));
 
+    if is_tf32 {
+        implicit_gemm::<R, F, tf32, F>(input, weight, bias, options)
+    } else if TypeId::of::<F>() == TypeId::of::<bf16>() {
+        // The products of bf16 matrices can only be accumulated in f32.
+        let out = implicit_gemm::<R, F, bf16, f32>(input, weight, bias, options)?;
+        Ok(cast::<R, f32, F>(out))
+    } else {
+        implicit_gemm::<R, F, f16, F>(input, weight, bias, options)
+    }
+}
+
+/// Launch the implicit GEMM, with the matrices of the CMMA instructions in `FMat` and their
+/// products accumulated in `FAcc`, the element type of the output.
+fn implicit_gemm<R: JitRuntime, F: FloatElement, FMat: Float, FAcc: FloatElement>(
+    input: JitTensor<R>,
+    weight: JitTensor<R>,
+    bias: Option<JitTensor<R>>,
+    options: ConvOptions<2>,
+) -> Result<JitTensor<R>, ConvLaunchError> {
+    let is_tf32 = FMat::as_elem_native_unchecked() == Elem::Float(FloatKind::TF32);
     let k_target = if is_tf32 { 8 } else { 16 };
 
     let [batch_size, in_channels, height, width] = input.shape.dims();
@@ -76,7 +104,8 @@ pub fn conv2d_implicit_gemm<R: JitRuntime, F: FloatElement>(
         out_h,
         out_w,
         &input.client,
-    )?;
+    )
+    .inspect_err(|err| record_fast_path_miss("conv2d_implicit_gemm", err))?;
 
     // If input is contiguous NCHW, use custom transpose kernel
     let input = match input.is_contiguous() {
@@ -86,7 +115,7 @@ pub fn conv2d_implicit_gemm<R: JitRuntime, F: FloatElement>(
     let weight = into_contiguous(permute(weight, &[2, 3, 1, 0]));
 
     let out_shape = Shape::new([padded_batch_size, out_h, out_w, padded_out_channels]);
-    let out = empty_device::<R, F>(input.client.clone(), input.device.clone(), out_shape);
+    let out = empty_device::<R, FAcc>(input.client.clone(), input.device.clone(), out_shape);
 
     // Implicit GEMM matrix size
     let gemm_m = (padded_batch_size * out_h * out_w) as u32;
@@ -118,15 +147,19 @@ pub fn conv2d_implicit_gemm<R: JitRuntime, F: FloatElement>(
         find_common_vec(out_channels, weight_elems_per_thread, supported_vecs);
 
     let has_bias = bias.is_some();
-    let bias = match bias {
+    // The bias initializes the accumulators.
+    let bias = match bias.map(cast::<R, F, FAcc>) {
         Some(bias) if out_channels == padded_out_channels => bias,
         Some(bias) => {
             let shape = Shape::new([padded_out_channels]);
-            let padded_bias = zeros_device::<R, F>(bias.client.clone(), bias.device.clone(), shape);
+            let padded_bias =
+                zeros_device::<R, FAcc>(bias.client.clone(), bias.device.clone(), shape);
             #[allow(clippy::single_range_in_vec_init)]
-            slice_assign::<R, F>(padded_bias, &[0..out_channels], bias)
+            slice_assign::<R, FAcc>(padded_bias, &[0..out_channels], bias)
+        }
+        None => {
+            empty_device::<R, FAcc>(input.client.clone(), input.device.clone(), Shape::new([1]))
         }
-        None => empty_device::<R, F>(input.client.clone(), input.device.clone(), Shape::new([1])),
     };
 
     let settings = GemmSettings {
@@ -158,19 +191,14 @@ pub fn conv2d_implicit_gemm<R: JitRuntime, F: FloatElement>(
 
     let cube_count = CubeCount::Static(cube_count_x, cube_count_y, 1);
 
-    let launch = match is_tf32 {
-        false => implicit_gemm_kernel::launch::<F, f16, R>,
-        true => implicit_gemm_kernel::launch::<F, tf32, R>,
-    };
-
-    launch(
+    implicit_gemm_kernel::launch::<F, FMat, FAcc, R>(
         &input.client,
         cube_count,
         cube_dim,
         input.as_tensor_arg::<F>(input_vectorization),
         weight.as_tensor_arg::<F>(weight_vectorization),
-        bias.as_tensor_arg::<F>(1),
-        out.as_tensor_arg::<F>(1),
+        bias.as_tensor_arg::<FAcc>(1),
+        out.as_tensor_arg::<FAcc>(1),
         DimensionsLaunch::new(
             ScalarArg::new(gemm_m),
             ScalarArg::new(gemm_n),
@@ -200,12 +228,90 @@ pub fn conv2d_implicit_gemm<R: JitRuntime, F: FloatElement>(
         },
     );
 
-    let out = slice::<R, F>(out, &[0..batch_size, 0..out_h, 0..out_w, 0..out_channels]);
+    let out = slice::<R, FAcc>(out, &[0..batch_size, 0..out_h, 0..out_w, 0..out_channels]);
 
     // Reset to NCHW
     Ok(permute(out, &[0, 3, 1, 2]))
 }
 
+/// Perform a grouped 2D convolution by launching the implicit GEMM once per group, each group
+/// being an independent convolution over a slice of the input channels.
+fn conv2d_implicit_gemm_grouped<R: JitRuntime, F: FloatElement>(
+    input: JitTensor<R>,
+    weight: JitTensor<R>,
+    bias: Option<JitTensor<R>>,
+    options: ConvOptions<2>,
+) -> Result<JitTensor<R>, ConvLaunchError> {
+    let groups = options.groups;
+    let [batch_size, _, height, width] = input.shape.dims();
+    let [out_channels, in_channels_per_group, kernel_h, kernel_w] = weight.shape.dims();
+    let out_channels_per_group = out_channels / groups;
+
+    let out_h = calculate_conv_output_size(
+        kernel_h,
+        options.stride[0],
+        options.padding[0],
+        options.dilation[0],
+        height,
+    );
+    let out_w = calculate_conv_output_size(
+        kernel_w,
+        options.stride[1],
+        options.padding[1],
+        options.dilation[1],
+        width,
+    );
+
+    // All groups have the same problem size, so they are checked once before launching anything.
+    check_availability::<R, F>(
+        batch_size,
+        in_channels_per_group,
+        out_channels_per_group,
+        [kernel_h, kernel_w],
+        1,
+        out_h,
+        out_w,
+        &input.client,
+    )
+    .inspect_err(|err| record_fast_path_miss("conv2d_implicit_gemm", err))?;
+
+    let out_shape = Shape::new([batch_size, out_channels, out_h, out_w]);
+    let mut out = empty_device::<R, F>(input.client.clone(), input.device.clone(), out_shape);
+    let group_options = ConvOptions::new(options.stride, options.padding, options.dilation, 1);
+
+    for group in 0..groups {
+        let in_channels = group * in_channels_per_group..(group + 1) * in_channels_per_group;
+        let out_channels = group * out_channels_per_group..(group + 1) * out_channels_per_group;
+
+        let input = slice::<R, F>(
+            input.clone(),
+            &[0..batch_size, in_channels, 0..height, 0..width],
+        );
+        let weight = slice::<R, F>(
+            weight.clone(),
+            &[
+                out_channels.clone(),
+                0..in_channels_per_group,
+                0..kernel_h,
+                0..kernel_w,
+            ],
+        );
+        #[allow(clippy::single_range_in_vec_init)]
+        let bias = bias
+            .as_ref()
+            .map(|bias| slice::<R, F>(bias.clone(), &[out_channels.clone()]));
+
+        let group_out = conv2d_implicit_gemm::<R, F>(input, weight, bias, group_options.clone())?;
+        out = slice_assign::<R, F>(
+            out,
+            &[0..batch_size, out_channels, 0..out_h, 0..out_w],
+            group_out,
+        );
+    }
+
+    Ok(out)
+}
+
 fn find_common_vec(channels: usize, elems_per_thread: u32, supported_vecs: &[u8]) -> u8 {
     let channels = channels as u8;
     let elems_per_thread = elems_per_thread as u8;
@@ -285,11 +391,11 @@ struct Matrices<F: Float, FAcc: Float> {
 
 #[allow(clippy::collapsible_else_if)]
 #[cube(launch)]
-fn implicit_gemm_kernel<F: Float, FMat: Float>(
+fn implicit_gemm_kernel<F: Float, FMat: Float, FAcc: Float>(
     input: &Tensor<Line<F>>,
     weight: &Tensor<Line<F>>,
-    bias: &Tensor<F>,
-    out: &mut Tensor<F>,
+    bias: &Tensor<FAcc>,
+    out: &mut Tensor<FAcc>,
     dims: &Dimensions,
     args: &ConvArgs,
     #[comptime] gemm_settings: GemmSettings,
@@ -337,7 +443,7 @@ fn implicit_gemm_kernel<F: Float, FMat: Float>(
     let mut out = out.slice_mut(out_pos, out_pos + cmma_out_tile_size);
 
     if conv_settings.aligned || pos.global_m < dims.gemm_m && pos.global_n < dims.gemm_n {
-        execute_gemm::<F, FMat>(
+        execute_gemm::<F, FMat, FAcc>(
             input,
             weight,
             bias,
@@ -438,11 +544,11 @@ fn make_matrices<F: Float, FAcc: Float>(
 }
 
 #[cube]
-fn execute_gemm<F: Float, FMat: Float>(
+fn execute_gemm<F: Float, FMat: Float, FAcc: Float>(
     input: &Tensor<Line<F>>,
     weight: &Tensor<Line<F>>,
-    bias: &Tensor<F>,
-    out: &mut SliceMut<F>,
+    bias: &Tensor<FAcc>,
+    out: &mut SliceMut<FAcc>,
     input_tile: &mut SliceMut<Line<FMat>>,
     weight_tile: &mut SliceMut<Line<FMat>>,
     dims: &Dimensions,
@@ -454,7 +560,7 @@ fn execute_gemm<F: Float, FMat: Float>(
     let GemmSettings { cmma_n, cmma_k, .. } = g_settings;
     let has_bias = k_settings.has_bias;
 
-    let matrices = make_matrices::<FMat, F>(g_settings, has_bias);
+    let matrices = make_matrices::<FMat, FAcc>(g_settings, has_bias);
     if has_bias {
         let bias_tile = bias.slice(pos.global_n, pos.global_n + cmma_n);
         cmma::load_with_layout(&matrices.acc, &bias_tile, 0, MatrixLayout::RowMajor);
@@ -476,7 +582,12 @@ fn execute_gemm<F: Float, FMat: Float>(
         cmma::load(&matrices.b, &weight_tile.to_slice(), cmma_n);
         cmma::load(&matrices.a, &input_tile.to_slice(), cmma_k);
 
-        cmma::execute::<FMat, FMat, F, F>(&matrices.a, &matrices.b, &matrices.acc, &matrices.acc);
+        cmma::execute::<FMat, FMat, FAcc, FAcc>(
+            &matrices.a,
+            &matrices.b,
+            &matrices.acc,
+            &matrices.acc,
+        );
     }
 
     cmma::store(out, &matrices.acc, dims.gemm_n, MatrixLayout::RowMajor);
@@ -754,18 +865,26 @@ fn find_cmma_size<R: JitRuntime, F: Float>(
 fn supported_cmma_sizes<R: JitRuntime, F: Float>(
     client: &ComputeClient<R::Server, R::Channel>,
 ) -> Vec<(u8, u8, u8)> {
-    let (requested_sizes, matrix_elem) = match (
+    let (requested_sizes, matrix_elem, accumulator_elem) = match (
         F::as_elem_native_unchecked(),
         client
             .properties()
             .feature_enabled(Feature::Type(tf32::as_elem_native_unchecked())),
     ) {
-        (Elem::Float(FloatKind::F32), true) => {
-            (vec![(16, 8, 16)], tf32::as_elem_native_unchecked())
-        }
+        (Elem::Float(FloatKind::F32), true) => (
+            vec![(16, 8, 16)],
+            tf32::as_elem_native_unchecked(),
+            F::as_elem_native_unchecked(),
+        ),
+        (Elem::Float(FloatKind::BF16), _) => (
+            vec![(16, 16, 16), (32, 16, 8), (8, 16, 32)],
+            bf16::as_elem_native_unchecked(),
+            f32::as_elem_native_unchecked(),
+        ),
         _ => (
             vec![(16, 16, 16), (32, 16, 8), (8, 16, 32)],
             f16::as_elem_native_unchecked(),
+            F::as_elem_native_unchecked(),
         ),
     };
 
@@ -776,7 +895,7 @@ fn supported_cmma_sizes<R: JitRuntime, F: Float>(
             client.properties().feature_enabled(Feature::Cmma {
                 a: matrix_elem,
                 b: matrix_elem,
-                c: F::as_elem_native_unchecked(),
+                c: accumulator_elem,
                 m: *m,
                 k: *k,
                 n: *n,
@@ -1,16 +1,19 @@
 mod base;
 mod col2im;
+mod depthwise;
 mod direct;
 mod gemm;
 mod im2col;
 mod implicit_gemm;
 mod layout_swap;
 mod transpose_direct;
+mod winograd;
 
 mod tune;
 
 pub use base::*;
 pub use col2im::*;
+pub use depthwise::*;
 pub use direct::*;
 pub use gemm::*;
 pub use im2col::*;
@@ -18,3 +21,4 @@ pub use implicit_gemm::*;
 pub use layout_swap::*;
 pub use transpose_direct::*;
 pub use tune::*;
+pub use winograd::*;
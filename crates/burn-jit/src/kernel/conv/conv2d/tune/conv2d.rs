@@ -5,8 +5,8 @@ use super::Conv2dAutotuneKey;
 use crate::{
     kernel::{
         conv::{
-            conv2d_direct, conv2d_gemm_cmma_balanced, conv2d_gemm_cmma_large_m, conv2d_im2col,
-            conv2d_implicit_gemm,
+            conv2d_depthwise, conv2d_direct, conv2d_gemm_cmma_balanced, conv2d_gemm_cmma_large_m,
+            conv2d_im2col, conv2d_implicit_gemm, conv2d_winograd,
         },
         prng::random_uniform,
     },
@@ -27,10 +27,12 @@ pub fn conv2d_autotune<R: JitRuntime, E: FloatElement>(
 
     let tunables = TunableSet::new(create_key::<R, E>, create_conv2d_input::<R, E>)
         .with_tunable(conv2d_direct::<R, E>)
+        .with_tunable(conv2d_depthwise::<R, E>)
         .with_tunable(conv2d_im2col::<R, E>)
         .with_tunable(conv2d_implicit_gemm::<R, E>)
         .with_tunable(conv2d_gemm_cmma_large_m::<R, E>)
-        .with_tunable(conv2d_gemm_cmma_balanced::<R, E>);
+        .with_tunable(conv2d_gemm_cmma_balanced::<R, E>)
+        .with_tunable(conv2d_winograd::<R, E>);
 
     TUNER.execute(
         &JitTuneId::new::<R>(&input.device),
@@ -0,0 +1,322 @@
+use burn_tensor::{
+    ops::{conv::calculate_conv_output_size, ConvOptions},
+    Shape,
+};
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+
+use crate::{
+    kernel::{
+        conv::ConvLaunchError,
+        into_contiguous,
+        matmul::{matmul, MatmulStrategy},
+    },
+    ops::numeric::{empty_device, zeros_device},
+    tensor::JitTensor,
+    FloatElement, JitRuntime,
+};
+
+/// Size of the output tile computed from each input tile, F(2x2, 3x3).
+const TILE_OUT: u32 = 2;
+/// Size of the input tile, and of the transformed tiles.
+const TILE_IN: u32 = 4;
+
+#[derive(CubeLaunch)]
+struct WinogradArgs {
+    padding_0: u32,
+    padding_1: u32,
+    tiles_0: u32,
+    tiles_1: u32,
+    num_tiles: u32,
+}
+
+/// Transforms each 3x3 filter into a 4x4 tile: `U = G g G^T`.
+///
+/// The output has the shape `[16, out_channels, in_channels]`.
+#[cube(launch)]
+fn winograd_filter_kernel<F: Float>(weight: &Tensor<F>, output: &mut Tensor<F>) {
+    let out_channels = weight.shape(0);
+    let in_channels = weight.shape(1);
+
+    if ABSOLUTE_POS >= out_channels * in_channels {
+        terminate!();
+    }
+
+    let oc = ABSOLUTE_POS / in_channels;
+    let ic = ABSOLUTE_POS % in_channels;
+    let index_weight = oc * weight.stride(0) + ic * weight.stride(1);
+    let half = F::new(0.5);
+
+    // G g, a 4x3 matrix.
+    let mut rows = Array::<F>::new(12);
+    #[unroll]
+    for col in 0..3 {
+        let g0 = weight[index_weight + col * weight.stride(3)];
+        let g1 = weight[index_weight + weight.stride(2) + col * weight.stride(3)];
+        let g2 = weight[index_weight + 2 * weight.stride(2) + col * weight.stride(3)];
+
+        rows[col] = g0;
+        rows[3 + col] = (g0 + g1 + g2) * half;
+        rows[6 + col] = (g0 - g1 + g2) * half;
+        rows[9 + col] = g2;
+    }
+
+    // (G g) G^T, a 4x4 matrix.
+    let stride = output.stride(0);
+    let index_output = oc * output.stride(1) + ic * output.stride(2);
+
+    #[unroll]
+    for row in 0..4 {
+        let a = rows[row * 3];
+        let b = rows[row * 3 + 1];
+        let c = rows[row * 3 + 2];
+
+        output[index_output + (row * 4) * stride] = a;
+        output[index_output + (row * 4 + 1) * stride] = (a + b + c) * half;
+        output[index_output + (row * 4 + 2) * stride] = (a - b + c) * half;
+        output[index_output + (row * 4 + 3) * stride] = c;
+    }
+}
+
+/// Transforms each overlapping 4x4 input tile: `V = B^T d B`.
+///
+/// The output has the shape `[16, in_channels, num_tiles]`, where the tiles of all the batches
+/// are contiguous.
+#[cube(launch)]
+fn winograd_input_kernel<F: Float>(input: &Tensor<F>, output: &mut Tensor<F>, args: &WinogradArgs) {
+    let in_channels = input.shape(1);
+
+    if ABSOLUTE_POS >= in_channels * args.num_tiles {
+        terminate!();
+    }
+
+    let ic = ABSOLUTE_POS / args.num_tiles;
+    let tile = ABSOLUTE_POS % args.num_tiles;
+    let tiles_per_batch = args.tiles_0 * args.tiles_1;
+    let b = tile / tiles_per_batch;
+    let tile_0 = tile % tiles_per_batch / args.tiles_1;
+    let tile_1 = tile % args.tiles_1;
+
+    let height = input.shape(2);
+    let width = input.shape(3);
+    let index_input = b * input.stride(0) + ic * input.stride(1);
+
+    // Load the tile, with zeros in the padding.
+    let mut d = Array::<F>::new(16);
+    #[unroll]
+    for row in 0..4 {
+        let ih = tile_0 * 2 + row;
+
+        #[unroll]
+        for col in 0..4 {
+            let iw = tile_1 * 2 + col;
+            let within_padding = ih >= args.padding_0
+                && ih < height + args.padding_0
+                && iw >= args.padding_1
+                && iw < width + args.padding_1;
+
+            d[row * 4 + col] = F::new(0.0);
+            if within_padding {
+                d[row * 4 + col] = input[index_input
+                    + (ih - args.padding_0) * input.stride(2)
+                    + (iw - args.padding_1) * input.stride(3)];
+            }
+        }
+    }
+
+    // B^T d
+    let mut rows = Array::<F>::new(16);
+    #[unroll]
+    for col in 0..4 {
+        let d0 = d[col];
+        let d1 = d[4 + col];
+        let d2 = d[8 + col];
+        let d3 = d[12 + col];
+
+        rows[col] = d0 - d2;
+        rows[4 + col] = d1 + d2;
+        rows[8 + col] = d2 - d1;
+        rows[12 + col] = d1 - d3;
+    }
+
+    // (B^T d) B
+    let stride = output.stride(0);
+    let index_output = ic * output.stride(1) + tile * output.stride(2);
+
+    #[unroll]
+    for row in 0..4 {
+        let r0 = rows[row * 4];
+        let r1 = rows[row * 4 + 1];
+        let r2 = rows[row * 4 + 2];
+        let r3 = rows[row * 4 + 3];
+
+        output[index_output + (row * 4) * stride] = r0 - r2;
+        output[index_output + (row * 4 + 1) * stride] = r1 + r2;
+        output[index_output + (row * 4 + 2) * stride] = r2 - r1;
+        output[index_output + (row * 4 + 3) * stride] = r1 - r3;
+    }
+}
+
+/// Transforms the products of the tiles back into 2x2 output tiles: `Y = A^T m A`.
+#[cube(launch)]
+fn winograd_output_kernel<F: Float>(
+    products: &Tensor<F>,
+    bias: &Tensor<F>,
+    output: &mut Tensor<F>,
+    args: &WinogradArgs,
+) {
+    let out_channels = output.shape(1);
+
+    if ABSOLUTE_POS >= out_channels * args.num_tiles {
+        terminate!();
+    }
+
+    let oc = ABSOLUTE_POS / args.num_tiles;
+    let tile = ABSOLUTE_POS % args.num_tiles;
+    let tiles_per_batch = args.tiles_0 * args.tiles_1;
+    let b = tile / tiles_per_batch;
+    let tile_0 = tile % tiles_per_batch / args.tiles_1;
+    let tile_1 = tile % args.tiles_1;
+
+    let stride = products.stride(0);
+    let index_products = oc * products.stride(1) + tile * products.stride(2);
+
+    // A^T m, a 2x4 matrix.
+    let mut rows = Array::<F>::new(8);
+    #[unroll]
+    for col in 0..4 {
+        let m0 = products[index_products + col * stride];
+        let m1 = products[index_products + (4 + col) * stride];
+        let m2 = products[index_products + (8 + col) * stride];
+        let m3 = products[index_products + (12 + col) * stride];
+
+        rows[col] = m0 + m1 + m2;
+        rows[4 + col] = m1 - m2 - m3;
+    }
+
+    let out_h = output.shape(2);
+    let out_w = output.shape(3);
+    let index_output = b * output.stride(0) + oc * output.stride(1);
+    let bias_value = bias[oc];
+
+    // (A^T m) A, the last tiles being cropped when the output size is odd.
+    #[unroll]
+    for row in 0..2 {
+        let r0 = rows[row * 4];
+        let r1 = rows[row * 4 + 1];
+        let r2 = rows[row * 4 + 2];
+        let r3 = rows[row * 4 + 3];
+        let oh = tile_0 * 2 + row;
+        let ow = tile_1 * 2;
+        let index_row = index_output + oh * output.stride(2);
+
+        if oh < out_h {
+            output[index_row + ow * output.stride(3)] = r0 + r1 + r2 + bias_value;
+
+            if ow + 1 < out_w {
+                output[index_row + (ow + 1) * output.stride(3)] = r1 - r2 - r3 + bias_value;
+            }
+        }
+    }
+}
+
+/// Perform a 2D convolution using the Winograd F(2x2, 3x3) algorithm, which needs 2.25 times
+/// fewer multiplications than the direct convolution.
+///
+/// Only 3x3 kernels with a stride and dilation of 1 and a single group are supported.
+///
+/// * `input` - The input feature map
+/// * `weight` - The weights (filter) applied to each kernel
+/// * `bias` - The bias added to each channel
+/// * `options` - The options to use for the convolution
+///
+pub fn conv2d_winograd<R: JitRuntime, E: FloatElement>(
+    input: JitTensor<R>,
+    weight: JitTensor<R>,
+    bias: Option<JitTensor<R>>,
+    options: ConvOptions<2>,
+) -> Result<JitTensor<R>, ConvLaunchError> {
+    let [batch_size, in_channels, in_height, in_width] = input.shape.dims();
+    let [out_channels, _, kernel_h, kernel_w] = weight.shape.dims();
+
+    if kernel_h != 3
+        || kernel_w != 3
+        || options.stride != [1, 1]
+        || options.dilation != [1, 1]
+        || options.groups != 1
+    {
+        return Err(ConvLaunchError::Winograd);
+    }
+
+    let out_h = calculate_conv_output_size(3, 1, options.padding[0], 1, in_height);
+    let out_w = calculate_conv_output_size(3, 1, options.padding[1], 1, in_width);
+    let tiles_0 = out_h.div_ceil(TILE_OUT as usize);
+    let tiles_1 = out_w.div_ceil(TILE_OUT as usize);
+    let num_tiles = batch_size * tiles_0 * tiles_1;
+    let tile_elems = (TILE_IN * TILE_IN) as usize;
+
+    let client = input.client.clone();
+    let device = input.device.clone();
+    let input = into_contiguous(input);
+    let cube_dim = CubeDim::default();
+
+    let args = || {
+        WinogradArgsLaunch::new(
+            ScalarArg::new(options.padding[0] as u32),
+            ScalarArg::new(options.padding[1] as u32),
+            ScalarArg::new(tiles_0 as u32),
+            ScalarArg::new(tiles_1 as u32),
+            ScalarArg::new(num_tiles as u32),
+        )
+    };
+
+    let filters = empty_device::<R, E>(
+        client.clone(),
+        device.clone(),
+        Shape::new([tile_elems, out_channels, in_channels]),
+    );
+    winograd_filter_kernel::launch::<E, R>(
+        &client,
+        calculate_cube_count_elemwise(out_channels * in_channels, cube_dim),
+        cube_dim,
+        weight.as_tensor_arg::<E>(1),
+        filters.as_tensor_arg::<E>(1),
+    );
+
+    let tiles = empty_device::<R, E>(
+        client.clone(),
+        device.clone(),
+        Shape::new([tile_elems, in_channels, num_tiles]),
+    );
+    winograd_input_kernel::launch::<E, R>(
+        &client,
+        calculate_cube_count_elemwise(in_channels * num_tiles, cube_dim),
+        cube_dim,
+        input.as_tensor_arg::<E>(1),
+        tiles.as_tensor_arg::<E>(1),
+        args(),
+    );
+
+    // One matmul for each of the 16 elements of the transformed tiles.
+    let products = matmul::<R, E>(filters, tiles, None, MatmulStrategy::default())?;
+
+    let bias = match bias {
+        Some(bias) => bias,
+        None => zeros_device::<R, E>(client.clone(), device.clone(), Shape::new([out_channels])),
+    };
+    let output = empty_device::<R, E>(
+        client.clone(),
+        device,
+        Shape::new([batch_size, out_channels, out_h, out_w]),
+    );
+    winograd_output_kernel::launch::<E, R>(
+        &client,
+        calculate_cube_count_elemwise(out_channels * num_tiles, cube_dim),
+        cube_dim,
+        products.as_tensor_arg::<E>(1),
+        bias.as_tensor_arg::<E>(1),
+        output.as_tensor_arg::<E>(1),
+        args(),
+    );
+
+    Ok(output)
+}
@@ -0,0 +1,53 @@
+use burn_tensor::ops::ConvOptions;
+
+use crate::{kernel::conv::ConvLaunchError, tensor::JitTensor, FloatElement, JitRuntime};
+
+#[cfg(feature = "autotune")]
+use super::conv3d_autotune;
+use super::{conv3d_direct, conv3d_im2col};
+
+/// The strategy to be used when launching a 3D convolution kernel.
+pub enum Conv3dStrategy {
+    /// A simple direct convolution.
+    Direct,
+    #[cfg(feature = "autotune")]
+    /// Using autotune to choose the best kernel based on runtime information.
+    Autotune,
+    /// GEMM (im2col) based implementation of convolution. Significantly increased memory usage.
+    Gemm,
+}
+
+impl Default for Conv3dStrategy {
+    fn default() -> Self {
+        // if autotune is enabled, default to autotune
+        #[cfg(feature = "autotune")]
+        return Conv3dStrategy::Autotune;
+
+        // if autotune is disabled, default to the more memory-conservative algorithm
+        #[cfg(not(feature = "autotune"))]
+        Conv3dStrategy::Direct
+    }
+}
+
+/// Perform a 3D convolution with the given strategy
+///
+/// * `input` - The input feature map
+/// * `weight` - The weights (filter) applied to each kernel
+/// * `bias` - The bias added to each channel
+/// * `options` - The options to use for the convolution
+/// * `strategy` - The convolution algorithm to use. Autotune will pick the fastest available option.
+///
+pub fn conv3d<R: JitRuntime, E: FloatElement>(
+    input: JitTensor<R>,
+    weight: JitTensor<R>,
+    bias: Option<JitTensor<R>>,
+    options: ConvOptions<3>,
+    strategy: Conv3dStrategy,
+) -> Result<JitTensor<R>, ConvLaunchError> {
+    match strategy {
+        Conv3dStrategy::Direct => conv3d_direct::<R, E>(input, weight, bias, options),
+        #[cfg(feature = "autotune")]
+        Conv3dStrategy::Autotune => Ok(conv3d_autotune::<R, E>(input, weight, bias, options)),
+        Conv3dStrategy::Gemm => conv3d_im2col::<R, E>(input, weight, bias, options),
+    }
+}
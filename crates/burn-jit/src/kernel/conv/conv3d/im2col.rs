@@ -0,0 +1,342 @@
+use burn_tensor::{
+    ops::{conv::calculate_conv_output_size, ConvOptions},
+    Shape,
+};
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+
+use crate::{
+    kernel::{
+        conv::{batches_per_run, index, ConvLaunchError},
+        into_contiguous, launch_binop,
+        matmul::{matmul, MatmulStrategy},
+        AddOp,
+    },
+    ops::{numeric::empty_device, reshape, swap_dims},
+    tensor::JitTensor,
+    FloatElement, JitRuntime,
+};
+
+#[derive(CubeLaunch)]
+struct Im2Col3dArgs {
+    stride_d: u32,
+    stride_h: u32,
+    stride_w: u32,
+    dilation_d: u32,
+    dilation_h: u32,
+    dilation_w: u32,
+    padding_d: u32,
+    padding_h: u32,
+    padding_w: u32,
+
+    kernel_d: u32,
+    kernel_h: u32,
+    kernel_w: u32,
+    out_d: u32,
+    out_h: u32,
+    out_w: u32,
+
+    col_size_1: u32,
+    num_elements: u32,
+}
+
+#[cube(launch_unchecked)]
+fn im2col_3d_kernel<F: Float>(
+    image: &Tensor<F>,
+    columns: &mut Tensor<F>,
+    args: &Im2Col3dArgs,
+    #[comptime] kernel_w_unroll: Option<u32>,
+    #[comptime] has_padding: bool,
+) {
+    // position shape: [in_channels, batch_size, out_d, out_h, out_w]
+    // columns shape: [[in_channels, kernel_d, kernel_h, kernel_w], [batch_size, out_d, out_h, out_w]]
+
+    let batch_size = image.shape(0);
+    let depth = image.shape(2);
+    let height = image.shape(3);
+    let width = image.shape(4);
+
+    let out_d = args.out_d;
+    let out_h = args.out_h;
+    let out_w = args.out_w;
+
+    if ABSOLUTE_POS >= args.num_elements {
+        terminate!();
+    }
+
+    let out_x = ABSOLUTE_POS % out_w;
+    let out_y = ABSOLUTE_POS / out_w % out_h;
+    let out_z = ABSOLUTE_POS / (out_w * out_h) % out_d;
+    let batch = ABSOLUTE_POS / (out_w * out_h * out_d) % batch_size;
+    let channel = ABSOLUTE_POS / (out_w * out_h * out_d * batch_size) % image.shape(1);
+
+    let kernel_w = kernel_w_unroll.unwrap_or(args.kernel_w);
+    let unroll_w = kernel_w_unroll.is_some();
+
+    let image_idx = batch * image.stride(0) + channel * image.stride(1);
+    let col_idx = channel * args.kernel_d * args.kernel_h * kernel_w * args.col_size_1
+        + batch * out_d * out_h * out_w
+        + out_z * out_h * out_w
+        + out_y * out_w
+        + out_x;
+
+    for kernel_z in 0..args.kernel_d {
+        for kernel_y in 0..args.kernel_h {
+            #[unroll(unroll_w)]
+            for kernel_x in 0..kernel_w {
+                let kernel_pos = (kernel_z * args.kernel_h + kernel_y) * kernel_w + kernel_x;
+                let col_pos = col_idx + kernel_pos * args.col_size_1;
+
+                if has_padding {
+                    let z = (out_z * args.stride_d + kernel_z * args.dilation_d) as i32
+                        - args.padding_d as i32;
+                    let y = (out_y * args.stride_h + kernel_y * args.dilation_h) as i32
+                        - args.padding_h as i32;
+                    let x = (out_x * args.stride_w + kernel_x * args.dilation_w) as i32
+                        - args.padding_w as i32;
+                    if z >= 0
+                        && y >= 0
+                        && x >= 0
+                        && z < depth as i32
+                        && y < height as i32
+                        && x < width as i32
+                    {
+                        let image_ptr = image_idx
+                            + z as u32 * image.stride(2)
+                            + y as u32 * image.stride(3)
+                            + x as u32 * image.stride(4);
+                        columns[col_pos] = image[image_ptr];
+                    } else {
+                        columns[col_pos] = F::new(0.0)
+                    };
+                } else {
+                    let z = out_z * args.stride_d + kernel_z * args.dilation_d;
+                    let y = out_y * args.stride_h + kernel_y * args.dilation_h;
+                    let x = out_x * args.stride_w + kernel_x * args.dilation_w;
+                    let image_ptr =
+                        image_idx + z * image.stride(2) + y * image.stride(3) + x * image.stride(4);
+                    columns[col_pos] = image[image_ptr];
+                }
+            }
+        }
+    }
+}
+
+fn im2col_3d<R: JitRuntime, E: FloatElement>(
+    input: JitTensor<R>,
+    options: ConvOptions<3>,
+    kernel_size: [usize; 3],
+    out_size: [usize; 3],
+) -> JitTensor<R> {
+    let input = into_contiguous(input);
+    let [batch_size, in_channels, _, _, _] = input.shape.dims();
+    let [kernel_d, kernel_h, kernel_w] = kernel_size;
+    let [out_d, out_h, out_w] = out_size;
+
+    let col_shape_0 = in_channels * kernel_d * kernel_h * kernel_w;
+    let col_shape_1 = batch_size * out_d * out_h * out_w;
+    let shape_col = Shape::new([col_shape_0, col_shape_1]);
+    let columns = empty_device::<R, E>(
+        input.client.clone(),
+        input.device.clone(),
+        shape_col.clone(),
+    );
+
+    let num_elems = in_channels * batch_size * out_d * out_h * out_w;
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(num_elems, cube_dim);
+
+    let kernel_w_unroll = (kernel_w <= 8).then_some(kernel_w as u32);
+
+    let vectorization = 1;
+
+    unsafe {
+        im2col_3d_kernel::launch_unchecked::<E, R>(
+            &input.client,
+            cube_count,
+            cube_dim,
+            input.as_handle_ref().as_tensor_arg(vectorization),
+            columns.as_handle_ref().as_tensor_arg(vectorization),
+            Im2Col3dArgsLaunch::new(
+                ScalarArg::new(options.stride[0] as u32),
+                ScalarArg::new(options.stride[1] as u32),
+                ScalarArg::new(options.stride[2] as u32),
+                ScalarArg::new(options.dilation[0] as u32),
+                ScalarArg::new(options.dilation[1] as u32),
+                ScalarArg::new(options.dilation[2] as u32),
+                ScalarArg::new(options.padding[0] as u32),
+                ScalarArg::new(options.padding[1] as u32),
+                ScalarArg::new(options.padding[2] as u32),
+                ScalarArg::new(kernel_d as u32),
+                ScalarArg::new(kernel_h as u32),
+                ScalarArg::new(kernel_w as u32),
+                ScalarArg::new(out_d as u32),
+                ScalarArg::new(out_h as u32),
+                ScalarArg::new(out_w as u32),
+                ScalarArg::new(col_shape_1 as u32),
+                ScalarArg::new(num_elems as u32),
+            ),
+            kernel_w_unroll,
+            options.padding != [0, 0, 0],
+        )
+    };
+
+    columns
+}
+
+/// Perform a 3D convolution using the GEMM (im2col) algorithm.
+///
+/// * `input` - The input feature map
+/// * `weight` - The weights (filter) applied to each kernel
+/// * `bias` - The bias added to each channel
+/// * `options` - The options to use for the convolution
+///
+pub fn conv3d_im2col<R: JitRuntime, E: FloatElement>(
+    input: JitTensor<R>,
+    weight: JitTensor<R>,
+    bias: Option<JitTensor<R>>,
+    options: ConvOptions<3>,
+) -> Result<JitTensor<R>, ConvLaunchError> {
+    let [batch_size, in_channels, in_depth, in_height, in_width] = input.shape.dims();
+    let [out_channels, _, kernel_d, kernel_h, kernel_w] = weight.shape.dims();
+    let groups = options.groups;
+    let out_c_per_group = out_channels / groups;
+
+    let out_d = calculate_conv_output_size(
+        kernel_d,
+        options.stride[0],
+        options.padding[0],
+        options.dilation[0],
+        in_depth,
+    );
+    let out_h = calculate_conv_output_size(
+        kernel_h,
+        options.stride[1],
+        options.padding[1],
+        options.dilation[1],
+        in_height,
+    );
+    let out_w = calculate_conv_output_size(
+        kernel_w,
+        options.stride[2],
+        options.padding[2],
+        options.dilation[2],
+        in_width,
+    );
+
+    if kernel_d == 1
+        && kernel_h == 1
+        && kernel_w == 1
+        && in_depth == out_d
+        && in_height == out_h
+        && in_width == out_w
+    {
+        // Special case for 1x1x1 kernels, the input doesn't need to be unfolded
+        return execute_1x1x1_kernel::<R, E>(input, weight, bias, options);
+    }
+
+    let out_size = [out_d, out_h, out_w];
+    let batches_per_run = batches_per_run(batch_size, out_d * out_h, out_w)
+        .expect("Volume too large to run even one batch at once");
+    let matmul_shape = Shape::new([
+        groups,
+        out_c_per_group,
+        batches_per_run * out_d * out_h * out_w,
+    ]);
+
+    let mut out = if batches_per_run != batch_size {
+        let runs = batch_size / batches_per_run;
+        let out_shape = Shape::new([runs, out_channels, batches_per_run, out_d, out_h, out_w]);
+        let out = empty_device::<R, E>(input.client.clone(), input.device.clone(), out_shape);
+        let in_shape = Shape::new([
+            runs,
+            batches_per_run,
+            in_channels,
+            in_depth,
+            in_height,
+            in_width,
+        ]);
+        let input = reshape(input, in_shape);
+        let in_shape_run =
+            Shape::new([batches_per_run, in_channels, in_depth, in_height, in_width]);
+        for run in 0..runs {
+            let input = index::<R, E>(input.clone(), run);
+            let input = reshape(input, in_shape_run.clone());
+            let out_slice = index::<R, E>(out.clone(), run);
+            let out_slice = reshape(out_slice, matmul_shape.clone());
+            execute::<R, E>(input, weight.clone(), out_slice, options.clone(), out_size)?;
+        }
+        let out = swap_dims(out, 1, 2);
+        reshape(
+            out,
+            Shape::new([batch_size, out_channels, out_d, out_h, out_w]),
+        )
+    } else {
+        let out = empty_device::<R, E>(input.client.clone(), input.device.clone(), matmul_shape);
+        execute::<R, E>(input, weight, out.clone(), options, out_size)?;
+        let out = reshape(
+            out,
+            Shape::new([out_channels, batch_size, out_d, out_h, out_w]),
+        );
+        swap_dims(out, 0, 1)
+    };
+
+    if let Some(bias) = bias {
+        let bias = reshape(bias, Shape::new([1, out_channels, 1, 1, 1]));
+        out = launch_binop::<R, E, AddOp>(out, bias)
+    }
+
+    Ok(out)
+}
+
+fn execute_1x1x1_kernel<R: JitRuntime, E: FloatElement>(
+    input: JitTensor<R>,
+    weight: JitTensor<R>,
+    bias: Option<JitTensor<R>>,
+    options: ConvOptions<3>,
+) -> Result<JitTensor<R>, ConvLaunchError> {
+    let [batch_size, _, depth, height, width] = input.shape.dims();
+    let [out_channels, in_c_per_grp, _, _, _] = weight.shape.dims();
+    let groups = options.groups;
+    let out_c_per_grp = out_channels / groups;
+
+    let input = swap_dims(input, 0, 1); // [CNDHW]
+
+    let weight = reshape(weight, Shape::new([groups, out_c_per_grp, in_c_per_grp]));
+    let in_shape = Shape::new([groups, in_c_per_grp, batch_size * depth * height * width]);
+    let input = reshape(input, in_shape);
+    let out = matmul::<R, E>(weight, input, None, MatmulStrategy::default())?;
+    let mut out = reshape(
+        out,
+        Shape::new([out_channels, batch_size, depth, height, width]),
+    );
+
+    if let Some(bias) = bias {
+        let bias = reshape(bias, Shape::new([out_channels, 1, 1, 1, 1]));
+        out = launch_binop::<R, E, AddOp>(out, bias)
+    }
+
+    Ok(swap_dims(out, 0, 1))
+}
+
+fn execute<R: JitRuntime, E: FloatElement>(
+    input: JitTensor<R>,
+    weight: JitTensor<R>,
+    out: JitTensor<R>,
+    options: ConvOptions<3>,
+    out_size: [usize; 3],
+) -> Result<(), ConvLaunchError> {
+    let [out_channels, _, kernel_d, kernel_h, kernel_w] = weight.shape.dims();
+    let groups = options.groups;
+
+    let columns = im2col_3d::<R, E>(input, options, [kernel_d, kernel_h, kernel_w], out_size);
+    let [col_shape_0, col_shape_1] = columns.shape.dims();
+    let col_shape_0 = col_shape_0 / groups;
+    let out_c_per_group = out_channels / groups;
+
+    let columns = reshape(columns, Shape::new([groups, col_shape_0, col_shape_1]));
+    let weight = reshape(weight, Shape::new([groups, out_c_per_group, col_shape_0]));
+
+    matmul::<R, E>(weight, columns, Some(out), Default::default())?;
+
+    Ok(())
+}
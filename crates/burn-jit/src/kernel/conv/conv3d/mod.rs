@@ -0,0 +1,10 @@
+mod base;
+mod direct;
+mod im2col;
+
+mod tune;
+
+pub use base::*;
+pub use direct::*;
+pub use im2col::*;
+pub use tune::*;
@@ -0,0 +1,105 @@
+use burn_tensor::{ops::ConvOptions, ElementConversion, Shape};
+use cubecl::tune::{local_tuner, LocalTuner, TunableSet};
+
+use super::Conv3dAutotuneKey;
+use crate::{
+    kernel::{
+        conv::{conv3d_direct, conv3d_im2col},
+        prng::random_uniform,
+    },
+    tensor::JitTensor,
+    FloatElement, JitAutotuneKey, JitRuntime, JitTuneId,
+};
+
+/// Executes autotune on conv3d operations
+pub fn conv3d_autotune<R: JitRuntime, E: FloatElement>(
+    input: JitTensor<R>,
+    weights: JitTensor<R>,
+    bias: Option<JitTensor<R>>,
+    options: ConvOptions<3>,
+) -> JitTensor<R> {
+    let client = input.client.clone();
+
+    static TUNER: LocalTuner<JitAutotuneKey, JitTuneId> = local_tuner!();
+
+    let tunables = TunableSet::new(create_key::<R, E>, create_conv3d_input::<R, E>)
+        .with_tunable(conv3d_direct::<R, E>)
+        .with_tunable(conv3d_im2col::<R, E>);
+
+    TUNER.execute(
+        &JitTuneId::new::<R>(&input.device),
+        &client,
+        &tunables,
+        (input, weights, bias, options),
+    )
+}
+
+pub fn create_conv3d_input<R: JitRuntime, E: FloatElement>(
+    key: &JitAutotuneKey,
+    input: &JitTensor<R>,
+    _weights: &JitTensor<R>,
+    _bias: &Option<JitTensor<R>>,
+    options: &ConvOptions<3>,
+) -> (
+    JitTensor<R>,
+    JitTensor<R>,
+    Option<JitTensor<R>>,
+    ConvOptions<3>,
+) {
+    let device = &input.device;
+    let key = match key {
+        JitAutotuneKey::Conv3d(key) => key,
+        _ => unreachable!(),
+    };
+
+    let random_bounds: (E, E) = ((-1.0).elem::<E>(), (1.0).elem::<E>());
+    let input_shape = Shape::new([
+        key.batch_size,
+        key.in_channels,
+        key.depth,
+        key.height,
+        key.width,
+    ]);
+    let input = random_uniform(input_shape, device, random_bounds.0, random_bounds.1);
+    let c_per_grp = key.in_channels / key.groups;
+    let [kernel_d, kernel_h, kernel_w] = key.kernel_size;
+    let weight_shape = Shape::new([key.out_channels, c_per_grp, kernel_d, kernel_h, kernel_w]);
+    let weights = random_uniform(weight_shape, device, random_bounds.0, random_bounds.1);
+    let bias_shape = Shape::new([key.out_channels]);
+    let bias = key
+        .has_bias
+        .then(|| random_uniform(bias_shape, device, random_bounds.0, random_bounds.1));
+
+    (input, weights, bias, options.clone())
+}
+
+fn create_key<R: JitRuntime, E: FloatElement>(
+    input: &JitTensor<R>,
+    weights: &JitTensor<R>,
+    bias: &Option<JitTensor<R>>,
+    options: &ConvOptions<3>,
+) -> JitAutotuneKey {
+    let [batch_size, in_channels, depth, height, width] = input.shape.dims();
+    let [out_channels, _, kernel_d, kernel_h, kernel_w] = weights.shape.dims();
+    let ConvOptions {
+        stride,
+        padding,
+        dilation,
+        groups,
+    } = options.clone();
+    JitAutotuneKey::Conv3d(Conv3dAutotuneKey::new(
+        [kernel_d, kernel_h, kernel_w],
+        stride,
+        padding,
+        dilation,
+        groups,
+        in_channels,
+        out_channels,
+        depth,
+        height,
+        width,
+        batch_size,
+        bias.is_some(),
+        E::dtype(),
+    ))
+}
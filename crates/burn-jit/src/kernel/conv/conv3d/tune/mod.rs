@@ -0,0 +1,8 @@
+#[cfg(feature = "autotune")]
+mod conv3d;
+
+#[cfg(feature = "autotune")]
+pub use conv3d::*;
+
+mod key;
+pub use key::*;
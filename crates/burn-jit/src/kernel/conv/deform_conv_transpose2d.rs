@@ -1,6 +1,7 @@
 use std::marker::PhantomData;
 
 use burn_tensor::{
+    backend::check_deterministic,
     ops::{DeformConv2dBackward, DeformConvOptions, FloatTensorOps as _},
     Shape,
 };
@@ -441,6 +442,9 @@ fn compute_input_grad<R: JitRuntime, E: FloatElement>(
     kernel_dims: (usize, usize),
     input_shape: Shape,
 ) -> JitTensor<R> {
+    // The gradients of overlapping sampling locations are accumulated with atomics.
+    check_deterministic("deform_conv2d_backward");
+
     let client = offset.client.clone();
     let device = offset.device.clone();
 
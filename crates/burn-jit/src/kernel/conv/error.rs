@@ -4,6 +4,8 @@ use cubecl::{linalg::matmul::kernels::MatmulLaunchError, tune::AutotuneError};
 pub enum ConvLaunchError {
     Matmul(MatmulLaunchError),
     Groups(usize),
+    Depthwise { groups: usize, in_channels: usize },
+    Winograd,
     Unknown,
 }
 
@@ -19,6 +21,21 @@ impl Debug for ConvLaunchError {
                     "Unable to launch matmul because groups must be one, is actually {groups}",
                 )
             }
+            ConvLaunchError::Depthwise {
+                groups,
+                in_channels,
+            } => {
+                writeln!(
+                    f,
+                    "Unable to launch depthwise convolution because groups must be {in_channels}, is actually {groups}",
+                )
+            }
+            ConvLaunchError::Winograd => {
+                writeln!(
+                    f,
+                    "Unable to launch Winograd convolution, only 3x3 kernels with stride 1, dilation 1 and one group are supported",
+                )
+            }
             ConvLaunchError::Unknown => write!(f, "Unknown"),
         }
     }
@@ -13,3 +13,4 @@ pub(crate) use deform_conv_transpose2d::*;
 pub(crate) use error::*;
 
 pub use conv2d::{conv2d, conv_transpose2d, nchw_to_nhwc, Conv2dStrategy, ConvTranspose2dStrategy};
+pub use conv3d::{conv3d, Conv3dStrategy};
@@ -0,0 +1,488 @@
+use crate::{
+    kernel::{
+        into_contiguous,
+        matmul::{matmul, MatmulStrategy},
+    },
+    memory,
+    tensor::JitTensor,
+    FloatElement, JitRuntime,
+};
+use burn_tensor::{DType, Fp8Format, Fp8Scaling, Shape, TensorData};
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+
+#[cfg(feature = "autotune")]
+use crate::{kernel::matmul::MatmulAutotuneKey, JitAutotuneKey, JitTuneId};
+#[cfg(feature = "autotune")]
+use cubecl::tune::{local_tuner, LocalTuner, TunableSet};
+
+/// Shift the bits to the right, rounding half to even.
+#[cube]
+fn round_shift(value: u32, shift: u32) -> u32 {
+    (value + (1u32 << (shift - 1)) - 1 + ((value >> shift) & 1)) >> shift
+}
+
+/// Convert a float to the bits of the closest value of the format, see [Fp8Format::encode].
+#[cube]
+fn encode_fp8(value: f32, #[comptime] format: Fp8Format) -> u32 {
+    let mantissa_bits = comptime!(format.mantissa_bits());
+    let bias = comptime!(format.bias() as u32);
+    let max_bits = comptime!(format.max_bits() as u32);
+    let infinity_bits = comptime!(match format {
+        Fp8Format::E4M3 => format.max_bits() as u32,
+        Fp8Format::E5M2 => 0x7C,
+    });
+
+    let bits = u32::bitcast_from(value);
+    let sign = (bits >> 24) & 0x80;
+    let abs = bits & 0x7FFF_FFFF;
+    let mut result = 0u32;
+
+    if abs > 0x7F80_0000 {
+        result = 0x7F;
+    } else if abs == 0x7F80_0000 {
+        result = infinity_bits;
+    } else if abs < comptime!((128 - bias) << 23) {
+        // Subnormal values are multiples of the smallest subnormal value.
+        let exponent = abs >> 23;
+        let shift = comptime!(151 - bias - mantissa_bits) - exponent;
+
+        if exponent != 0 && shift <= 24 {
+            result = round_shift((abs & 0x7F_FFFF) | 0x80_0000, shift);
+        }
+    } else {
+        let rounded = round_shift(abs, comptime!(23 - mantissa_bits));
+        result = Min::min(rounded - comptime!((127 - bias) << mantissa_bits), max_bits);
+    }
+
+    sign | result
+}
+
+/// Convert the bits of a value of the format to a float, see [Fp8Format::decode].
+#[cube]
+fn decode_fp8(bits: u32, #[comptime] format: Fp8Format) -> f32 {
+    let mantissa_bits = comptime!(format.mantissa_bits());
+    let bias = comptime!(format.bias() as u32);
+    let max_exponent = comptime!((1u32 << (7 - format.mantissa_bits())) - 1);
+    let smallest_subnormal = comptime!(format.decode(1));
+
+    let sign = (bits & 0x80) << 24;
+    let exponent = (bits & 0x7F) >> mantissa_bits;
+    let mantissa = bits & comptime!((1u32 << format.mantissa_bits()) - 1);
+
+    let mut magnitude = f32::bitcast_from(
+        ((exponent + comptime!(127 - bias)) << 23) | (mantissa << comptime!(23 - mantissa_bits)),
+    );
+
+    if exponent == 0 {
+        magnitude = f32::cast_from(mantissa) * f32::new(smallest_subnormal);
+    }
+
+    if comptime!(format == Fp8Format::E4M3) {
+        if bits & 0x7F == 0x7F {
+            magnitude = f32::new(f32::NAN);
+        }
+    } else if exponent == max_exponent {
+        magnitude = f32::new(f32::NAN);
+
+        if mantissa == 0 {
+            magnitude = f32::new(f32::INFINITY);
+        }
+    }
+
+    f32::bitcast_from(sign | u32::bitcast_from(magnitude))
+}
+
+/// Read the bits of the value at the given index, four values being packed in each `u32`.
+#[cube]
+fn read_fp8(input: &Array<u32>, index: u32) -> u32 {
+    (input[index / 4] >> ((index % 4) * 8)) & 0xFF
+}
+
+#[cube(launch_unchecked)]
+fn quantize_fp8_kernel<F: Float>(
+    input: &Tensor<F>,
+    scale: f32,
+    output: &mut Array<u32>,
+    #[comptime] format: Fp8Format,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        terminate!();
+    }
+
+    let num_packed = comptime!(4);
+    let mut packed = 0u32;
+
+    #[unroll]
+    for i in 0..num_packed {
+        let index = ABSOLUTE_POS * num_packed + i;
+
+        if index < input.len() {
+            let value = f32::cast_from(input[index]) / scale;
+            packed |= encode_fp8(value, format) << (8 * i);
+        }
+    }
+
+    output[ABSOLUTE_POS] = packed;
+}
+
+#[cube(launch_unchecked)]
+fn dequantize_fp8_kernel<F: Float>(
+    input: &Array<u32>,
+    scale: f32,
+    output: &mut Tensor<F>,
+    #[comptime] format: Fp8Format,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        terminate!();
+    }
+
+    let value = decode_fp8(read_fp8(input, ABSOLUTE_POS), format);
+    output[ABSOLUTE_POS] = F::cast_from(value * scale);
+}
+
+#[cube(launch_unchecked)]
+fn scaled_matmul_fp8_kernel<F: Float>(
+    lhs: &Array<u32>,
+    rhs: &Array<u32>,
+    scale: f32,
+    k: u32,
+    output: &mut Tensor<F>,
+    #[comptime] lhs_format: Fp8Format,
+    #[comptime] rhs_format: Fp8Format,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        terminate!();
+    }
+
+    let rank = output.rank();
+    let m = output.shape(rank - 2);
+    let n = output.shape(rank - 1);
+
+    let batch = ABSOLUTE_POS / (m * n);
+    let row = (ABSOLUTE_POS / n) % m;
+    let col = ABSOLUTE_POS % n;
+
+    let lhs_offset = (batch * m + row) * k;
+    let rhs_offset = batch * k * n + col;
+
+    // The products are accumulated in f32, whatever the output precision.
+    let mut sum = f32::new(0.0);
+    for i in 0..k {
+        let a = decode_fp8(read_fp8(lhs, lhs_offset + i), lhs_format);
+        let b = decode_fp8(read_fp8(rhs, rhs_offset + i * n), rhs_format);
+        sum += a * b;
+    }
+
+    output[ABSOLUTE_POS] = F::cast_from(sum * scale);
+}
+
+/// Convert a float tensor to an FP8 tensor, storing the values divided by the scale.
+///
+/// The scale maps the range of the tensor to the range of the format, it is usually the maximum
+/// absolute value of the tensor divided by the [largest value](Fp8Format::max) of the format.
+/// Values out of range saturate to the largest value of the format.
+///
+/// FP8 tensors are contiguous, with four values packed in each `u32` of their buffer, and only
+/// support the operations of this module.
+pub fn quantize_fp8<R: JitRuntime, F: FloatElement>(
+    tensor: JitTensor<R>,
+    scale: f32,
+    format: Fp8Format,
+) -> JitTensor<R> {
+    let tensor = into_contiguous(tensor);
+    let client = tensor.client.clone();
+    let num_words = tensor.shape.num_elements().div_ceil(4);
+
+    let handle = memory::empty::<R>(
+        &client,
+        &tensor.device,
+        num_words * core::mem::size_of::<u32>(),
+    );
+    let output = JitTensor::new_contiguous(
+        client.clone(),
+        tensor.device.clone(),
+        tensor.shape.clone(),
+        handle,
+        format.dtype(),
+    );
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(num_words, cube_dim);
+
+    unsafe {
+        quantize_fp8_kernel::launch_unchecked::<F, R>(
+            &client,
+            cube_count,
+            cube_dim,
+            tensor.as_tensor_arg::<F>(1),
+            ScalarArg::new(scale),
+            output.as_array_arg::<u32>(1),
+            format,
+        )
+    };
+
+    output
+}
+
+/// Convert an FP8 tensor to a float tensor, multiplying its values by the scale.
+pub fn dequantize_fp8<R: JitRuntime, F: FloatElement>(
+    tensor: JitTensor<R>,
+    scale: f32,
+) -> JitTensor<R> {
+    let format = fp8_format(&tensor);
+    let client = tensor.client.clone();
+    let num_elems = tensor.shape.num_elements();
+
+    let handle = memory::empty::<R>(
+        &client,
+        &tensor.device,
+        num_elems * core::mem::size_of::<F>(),
+    );
+    let output = JitTensor::new_contiguous(
+        client.clone(),
+        tensor.device.clone(),
+        tensor.shape.clone(),
+        handle,
+        F::dtype(),
+    );
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(num_elems, cube_dim);
+
+    unsafe {
+        dequantize_fp8_kernel::launch_unchecked::<F, R>(
+            &client,
+            cube_count,
+            cube_dim,
+            tensor.as_array_arg::<u32>(1),
+            ScalarArg::new(scale),
+            output.as_tensor_arg::<F>(1),
+            format,
+        )
+    };
+
+    output
+}
+
+/// Multiply two float tensors after converting them to FP8, see
+/// [matmul_fp8](burn_tensor::Tensor::matmul_fp8).
+pub fn matmul_fp8<R: JitRuntime, F: FloatElement>(
+    lhs: JitTensor<R>,
+    lhs_scaling: Fp8Scaling,
+    rhs: JitTensor<R>,
+    rhs_scaling: Fp8Scaling,
+) -> JitTensor<R> {
+    let lhs = quantize_fp8::<R, F>(lhs, lhs_scaling.scale, lhs_scaling.format);
+    let rhs = quantize_fp8::<R, F>(rhs, rhs_scaling.scale, rhs_scaling.format);
+
+    scaled_matmul_fp8::<R, F>(lhs, lhs_scaling.scale, rhs, rhs_scaling.scale)
+}
+
+/// Multiply two FP8 tensors, the result being multiplied by the product of their scales.
+///
+/// The tensors must have the same batch dimensions, and the products are accumulated in `f32`.
+///
+/// The direct kernel reads the FP8 values without shared memory, so it is only fast for small
+/// matrices. With autotune, it is tuned against the tiled matmul of the dequantized tensors,
+/// which is used otherwise.
+pub fn scaled_matmul_fp8<R: JitRuntime, F: FloatElement>(
+    lhs: JitTensor<R>,
+    lhs_scale: f32,
+    rhs: JitTensor<R>,
+    rhs_scale: f32,
+) -> JitTensor<R> {
+    check_scaled_matmul(&lhs, &rhs);
+
+    #[cfg(feature = "autotune")]
+    {
+        scaled_matmul_fp8_autotune::<R, F>(lhs, lhs_scale, rhs, rhs_scale)
+    }
+
+    #[cfg(not(feature = "autotune"))]
+    {
+        scaled_matmul_fp8_dequantized::<R, F>(lhs, lhs_scale, rhs, rhs_scale)
+            .expect("Matmul of the dequantized tensors should launch")
+    }
+}
+
+/// Multiply two FP8 tensors with a kernel computing each output value from the FP8 values.
+pub fn scaled_matmul_fp8_direct<R: JitRuntime, F: FloatElement>(
+    lhs: JitTensor<R>,
+    lhs_scale: f32,
+    rhs: JitTensor<R>,
+    rhs_scale: f32,
+) -> JitTensor<R> {
+    let lhs_format = fp8_format(&lhs);
+    let rhs_format = fp8_format(&rhs);
+    let shape = check_scaled_matmul(&lhs, &rhs);
+    let rank = shape.num_dims();
+    let k = lhs.shape.dims[rank - 1];
+
+    let client = lhs.client.clone();
+    let num_elems = shape.num_elements();
+    let handle = memory::empty::<R>(&client, &lhs.device, num_elems * core::mem::size_of::<F>());
+    let output = JitTensor::new_contiguous(
+        client.clone(),
+        lhs.device.clone(),
+        shape,
+        handle,
+        F::dtype(),
+    );
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(num_elems, cube_dim);
+
+    unsafe {
+        scaled_matmul_fp8_kernel::launch_unchecked::<F, R>(
+            &client,
+            cube_count,
+            cube_dim,
+            lhs.as_array_arg::<u32>(1),
+            rhs.as_array_arg::<u32>(1),
+            ScalarArg::new(lhs_scale * rhs_scale),
+            ScalarArg::new(k as u32),
+            output.as_tensor_arg::<F>(1),
+            lhs_format,
+            rhs_format,
+        )
+    };
+
+    output
+}
+
+/// Multiply two FP8 tensors by dequantizing them, using the tiled float matmul kernels.
+pub fn scaled_matmul_fp8_dequantized<R: JitRuntime, F: FloatElement>(
+    lhs: JitTensor<R>,
+    lhs_scale: f32,
+    rhs: JitTensor<R>,
+    rhs_scale: f32,
+) -> Result<JitTensor<R>, String> {
+    let lhs = dequantize_fp8::<R, F>(lhs, lhs_scale);
+    let rhs = dequantize_fp8::<R, F>(rhs, rhs_scale);
+
+    matmul::<R, F>(lhs, rhs, None, MatmulStrategy::default()).map_err(|err| format!("{err:?}"))
+}
+
+/// Check the shapes of the tensors of a scaled matmul, returning the output shape.
+fn check_scaled_matmul<R: JitRuntime>(lhs: &JitTensor<R>, rhs: &JitTensor<R>) -> Shape {
+    let rank = lhs.shape.num_dims();
+
+    assert!(
+        rank >= 2 && rank == rhs.shape.num_dims(),
+        "Scaled matmul requires tensors of the same rank, at least 2"
+    );
+    assert_eq!(
+        lhs.shape.dims[..rank - 2],
+        rhs.shape.dims[..rank - 2],
+        "Scaled matmul requires tensors with the same batch dimensions"
+    );
+    assert_eq!(
+        lhs.shape.dims[rank - 1],
+        rhs.shape.dims[rank - 2],
+        "Scaled matmul requires the inner dimensions to match"
+    );
+
+    let mut dims = lhs.shape.dims.clone();
+    dims[rank - 1] = rhs.shape.dims[rank - 1];
+
+    Shape::from(dims)
+}
+
+#[cfg(feature = "autotune")]
+fn scaled_matmul_fp8_autotune<R: JitRuntime, F: FloatElement>(
+    lhs: JitTensor<R>,
+    lhs_scale: f32,
+    rhs: JitTensor<R>,
+    rhs_scale: f32,
+) -> JitTensor<R> {
+    let client = lhs.client.clone();
+
+    static TUNER: LocalTuner<JitAutotuneKey, JitTuneId> = local_tuner!();
+
+    let tunables = TunableSet::new(create_key::<R, F>, create_input::<R>)
+        .with_tunable(scaled_matmul_fp8_direct_tunable::<R, F>)
+        .with_tunable(scaled_matmul_fp8_dequantized::<R, F>);
+
+    TUNER.execute(
+        &JitTuneId::new::<R>(&lhs.device),
+        &client,
+        &tunables,
+        (lhs, lhs_scale, rhs, rhs_scale),
+    )
+}
+
+#[cfg(feature = "autotune")]
+fn create_key<R: JitRuntime, F: FloatElement>(
+    lhs: &JitTensor<R>,
+    _lhs_scale: &f32,
+    rhs: &JitTensor<R>,
+    _rhs_scale: &f32,
+) -> JitAutotuneKey {
+    JitAutotuneKey::Matmul(MatmulAutotuneKey::from_shape(
+        &lhs.shape,
+        &rhs.shape,
+        false,
+        F::dtype(),
+    ))
+}
+
+/// The FP8 tensors are only read by the kernels, so they are reused by all the tunables.
+#[cfg(feature = "autotune")]
+fn create_input<R: JitRuntime>(
+    _key: &JitAutotuneKey,
+    lhs: &JitTensor<R>,
+    lhs_scale: &f32,
+    rhs: &JitTensor<R>,
+    rhs_scale: &f32,
+) -> (JitTensor<R>, f32, JitTensor<R>, f32) {
+    (lhs.clone(), *lhs_scale, rhs.clone(), *rhs_scale)
+}
+
+#[cfg(feature = "autotune")]
+fn scaled_matmul_fp8_direct_tunable<R: JitRuntime, F: FloatElement>(
+    lhs: JitTensor<R>,
+    lhs_scale: f32,
+    rhs: JitTensor<R>,
+    rhs_scale: f32,
+) -> Result<JitTensor<R>, String> {
+    Ok(scaled_matmul_fp8_direct::<R, F>(
+        lhs, lhs_scale, rhs, rhs_scale,
+    ))
+}
+
+/// Create an FP8 tensor from data of an FP8 type.
+pub fn fp8_from_data<R: JitRuntime>(data: TensorData, device: &R::Device) -> JitTensor<R> {
+    let client = R::client(device);
+    let dtype = data.dtype;
+    let shape = Shape::from(data.shape.clone());
+
+    assert!(
+        matches!(dtype, DType::F8E4M3 | DType::F8E5M2),
+        "Expected fp8 data, got {dtype:?}"
+    );
+
+    // The values are packed in `u32`, so the buffer is padded to a multiple of four bytes.
+    let mut bytes = data.into_bytes().to_vec();
+    bytes.resize(bytes.len().div_ceil(4) * 4, 0);
+    let handle = memory::create::<R>(&client, device, &bytes);
+
+    JitTensor::new_contiguous(client, device.clone(), shape, handle, dtype)
+}
+
+/// Read the data of an FP8 tensor.
+pub fn fp8_into_data<R: JitRuntime>(tensor: JitTensor<R>) -> TensorData {
+    fp8_format(&tensor);
+
+    let num_elems = tensor.shape.num_elements();
+    let bytes = tensor.client.read_one(tensor.handle.binding());
+
+    TensorData::from_bytes(bytes[..num_elems].to_vec(), tensor.shape.dims, tensor.dtype)
+}
+
+fn fp8_format<R: JitRuntime>(tensor: &JitTensor<R>) -> Fp8Format {
+    match tensor.dtype {
+        DType::F8E4M3 => Fp8Format::E4M3,
+        DType::F8E5M2 => Fp8Format::E5M2,
+        dtype => panic!("Expected an fp8 tensor, got {dtype:?}"),
+    }
+}
@@ -0,0 +1,189 @@
+use burn_tensor::{
+    ops::{GridSampleMode, GridSampleOptions, GridSamplePaddingMode},
+    Shape,
+};
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+
+use crate::{ops::numeric::empty_device, tensor::JitTensor, FloatElement, JitRuntime};
+
+/// Sample the input at the location of the grid, one output element per invocation.
+#[cube(launch_unchecked)]
+fn grid_sample_kernel<F: Float>(
+    input: &Tensor<F>,
+    grid: &Tensor<F>,
+    output: &mut Tensor<F>,
+    #[comptime] bilinear: bool,
+    #[comptime] padding: PaddingKind,
+    #[comptime] align_corners: bool,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        terminate!();
+    }
+
+    let batch = ABSOLUTE_POS / output.stride(0) % output.shape(0);
+    let channel = ABSOLUTE_POS / output.stride(1) % output.shape(1);
+    let y = ABSOLUTE_POS / output.stride(2) % output.shape(2);
+    let x = ABSOLUTE_POS / output.stride(3) % output.shape(3);
+
+    let index_grid = batch * grid.stride(0) + y * grid.stride(1) + x * grid.stride(2);
+    let coord_x = source_coordinate::<F>(grid[index_grid], input.shape(3), padding, align_corners);
+    let coord_y = source_coordinate::<F>(
+        grid[index_grid + grid.stride(3)],
+        input.shape(2),
+        padding,
+        align_corners,
+    );
+
+    let index_input = batch * input.stride(0) + channel * input.stride(1);
+    let mut value = F::new(0.0);
+
+    if comptime![bilinear] {
+        let x0 = Floor::floor(coord_x);
+        let y0 = Floor::floor(coord_y);
+        let x1 = x0 + F::new(1.0);
+        let y1 = y0 + F::new(1.0);
+
+        let weight_x1 = coord_x - x0;
+        let weight_x0 = F::new(1.0) - weight_x1;
+        let weight_y1 = coord_y - y0;
+        let weight_y0 = F::new(1.0) - weight_y1;
+
+        value = pixel::<F>(input, index_input, x0, y0) * weight_x0 * weight_y0
+            + pixel::<F>(input, index_input, x1, y0) * weight_x1 * weight_y0
+            + pixel::<F>(input, index_input, x0, y1) * weight_x0 * weight_y1
+            + pixel::<F>(input, index_input, x1, y1) * weight_x1 * weight_y1;
+    } else {
+        value = pixel::<F>(
+            input,
+            index_input,
+            Round::round(coord_x),
+            Round::round(coord_y),
+        );
+    }
+
+    output[ABSOLUTE_POS] = value;
+}
+
+/// Converts a normalized coordinate of the grid into a coordinate in the input, in pixels.
+#[cube]
+fn source_coordinate<F: Float>(
+    coordinate: F,
+    size: u32,
+    #[comptime] padding: PaddingKind,
+    #[comptime] align_corners: bool,
+) -> F {
+    let size = F::cast_from(size);
+    let half = F::new(0.5);
+    let mut scale = size * half;
+
+    if comptime![align_corners] {
+        scale = (size - F::new(1.0)) * half;
+    }
+
+    let mut coordinate = coordinate * scale + (size - F::new(1.0)) * half;
+
+    if comptime![padding == PaddingKind::Reflection] {
+        // Reflected by the centers of the border pixels when the corners are aligned, otherwise
+        // by their outer edges.
+        if comptime![align_corners] {
+            coordinate = reflect_coordinate::<F>(coordinate, F::new(0.0), size - F::new(1.0));
+        } else {
+            coordinate = reflect_coordinate::<F>(coordinate, -half, size);
+        }
+    }
+
+    if comptime![padding != PaddingKind::Zeros] {
+        coordinate = Max::max(coordinate, F::new(0.0));
+        coordinate = Min::min(coordinate, size - F::new(1.0));
+    }
+
+    coordinate
+}
+
+/// Reflects the coordinate into `[min, min + span]`, as many times as needed.
+#[cube]
+fn reflect_coordinate<F: Float>(coordinate: F, min: F, span: F) -> F {
+    let mut reflected = F::new(0.0);
+
+    if span > F::new(0.0) {
+        let distance = Abs::abs(coordinate - min);
+        let flips = Floor::floor(distance / span);
+        let extra = distance - flips * span;
+        reflected = extra + min;
+
+        // An odd number of reflections flips the direction of the coordinate.
+        if flips - F::new(2.0) * Floor::floor(flips / F::new(2.0)) == F::new(1.0) {
+            reflected = span - extra + min;
+        }
+    }
+
+    reflected
+}
+
+/// The pixel at the given integer coordinates, zero outside of the input.
+#[cube]
+fn pixel<F: Float>(input: &Tensor<F>, index: u32, x: F, y: F) -> F {
+    let within_input = x >= F::new(0.0)
+        && x <= F::cast_from(input.shape(3) - 1)
+        && y >= F::new(0.0)
+        && y <= F::cast_from(input.shape(2) - 1);
+
+    let mut value = F::new(0.0);
+
+    if within_input {
+        value = input
+            [index + u32::cast_from(y) * input.stride(2) + u32::cast_from(x) * input.stride(3)];
+    }
+
+    value
+}
+
+/// The padding mode of the grid sampling, known at compile time.
+#[derive(CubeType, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum PaddingKind {
+    Zeros,
+    Border,
+    Reflection,
+}
+
+impl From<GridSamplePaddingMode> for PaddingKind {
+    fn from(padding_mode: GridSamplePaddingMode) -> Self {
+        match padding_mode {
+            GridSamplePaddingMode::Zeros => Self::Zeros,
+            GridSamplePaddingMode::Border => Self::Border,
+            GridSamplePaddingMode::Reflection => Self::Reflection,
+        }
+    }
+}
+
+/// Sample the input at the locations of the grid.
+pub(crate) fn grid_sample<R: JitRuntime, E: FloatElement>(
+    input: JitTensor<R>,
+    grid: JitTensor<R>,
+    options: GridSampleOptions,
+) -> JitTensor<R> {
+    let [batch_size, channels, _, _] = input.shape.dims();
+    let [_, height_out, width_out, _] = grid.shape.dims();
+
+    let shape_out = Shape::new([batch_size, channels, height_out, width_out]);
+    let output = empty_device::<R, E>(input.client.clone(), input.device.clone(), shape_out);
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(output.shape.num_elements(), cube_dim);
+
+    unsafe {
+        grid_sample_kernel::launch_unchecked::<E, R>(
+            &input.client,
+            cube_count,
+            cube_dim,
+            input.as_tensor_arg::<E>(1),
+            grid.as_tensor_arg::<E>(1),
+            output.as_tensor_arg::<E>(1),
+            options.mode == GridSampleMode::Bilinear,
+            PaddingKind::from(options.padding_mode),
+            options.align_corners,
+        );
+    }
+
+    output
+}
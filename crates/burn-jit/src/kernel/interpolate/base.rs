@@ -1,5 +1,5 @@
 use crate::{
-    kernel::into_contiguous, ops::numeric::empty_device, tensor::JitTensor, FloatElement,
+    kernel::into_contiguous, memory, ops::numeric::empty_device, tensor::JitTensor, FloatElement,
     JitRuntime,
 };
 use burn_tensor::{
@@ -46,7 +46,11 @@ pub fn interpolate_backward<R: JitRuntime, E: FloatElement>(
     let out_grad = into_contiguous(out_grad);
     let output_shape = input.shape.clone();
     let num_elems = input.shape.num_elements();
-    let buffer = input.client.empty(num_elems * core::mem::size_of::<E>());
+    let buffer = memory::empty::<R>(
+        &input.client,
+        &input.device,
+        num_elems * core::mem::size_of::<E>(),
+    );
     let output = JitTensor::new_contiguous(
         input.client.clone(),
         input.device.clone(),
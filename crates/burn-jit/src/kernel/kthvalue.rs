@@ -0,0 +1,114 @@
+use crate::{ops::numeric::empty_device, tensor::JitTensor, IntElement, JitRuntime};
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+
+/// Select the `k`-th smallest value of each row along `dim`, one row per invocation.
+///
+/// The value is found with a radix-select style bisection over the range of values of the row:
+/// each step counts the elements smaller or equal to the middle of the range, which only needs
+/// comparisons and halves the range. It takes at most the number of bits of the element type
+/// steps and doesn't need any extra memory, as opposed to sorting the row.
+#[cube(launch_unchecked)]
+fn kthvalue_kernel<I: Int>(
+    input: &Tensor<I>,
+    values: &mut Tensor<I>,
+    indices: &mut Tensor<I>,
+    k: u32,
+    dim: u32,
+    #[comptime] rank: u32,
+) {
+    if ABSOLUTE_POS >= values.len() {
+        terminate!();
+    }
+
+    let mut offset = 0;
+
+    #[unroll]
+    for i in 0..rank {
+        if i != dim {
+            let index = ABSOLUTE_POS / values.stride(i) % values.shape(i);
+            offset += index * input.stride(i);
+        }
+    }
+
+    let stride = input.stride(dim);
+    let length = input.shape(dim);
+
+    let mut low = input[offset];
+    let mut high = low;
+
+    for j in 1..length {
+        let value = input[offset + j * stride];
+        low = Min::min(low, value);
+        high = Max::max(high, value);
+    }
+
+    let one = I::new(1);
+
+    while low < high {
+        // Floor of the mean without overflowing.
+        let middle = (low >> one) + (high >> one) + (low & high & one);
+        let mut count = 0;
+
+        for j in 0..length {
+            if input[offset + j * stride] <= middle {
+                count += 1;
+            }
+        }
+
+        if count > k {
+            high = middle;
+        } else {
+            low = middle + one;
+        }
+    }
+
+    let mut index = 0;
+    let mut found = false;
+
+    for j in 0..length {
+        if !found && input[offset + j * stride] == low {
+            index = j;
+            found = true;
+        }
+    }
+
+    values[ABSOLUTE_POS] = low;
+    indices[ABSOLUTE_POS] = I::cast_from(index);
+}
+
+/// Find the `k`-th smallest element along `dim` and its index, without sorting.
+pub(crate) fn kthvalue_with_indices<R: JitRuntime, E: IntElement>(
+    tensor: JitTensor<R>,
+    k: usize,
+    dim: usize,
+) -> (JitTensor<R>, JitTensor<R>) {
+    let ndims = tensor.shape.num_dims();
+    let mut shape_out = tensor.shape.clone();
+    shape_out.dims[dim] = 1;
+
+    let values = empty_device::<R, E>(
+        tensor.client.clone(),
+        tensor.device.clone(),
+        shape_out.clone(),
+    );
+    let indices = empty_device::<R, E>(tensor.client.clone(), tensor.device.clone(), shape_out);
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(values.shape.num_elements(), cube_dim);
+
+    unsafe {
+        kthvalue_kernel::launch_unchecked::<E, R>(
+            &tensor.client,
+            cube_count,
+            cube_dim,
+            tensor.as_tensor_arg::<E>(1),
+            values.as_tensor_arg::<E>(1),
+            indices.as_tensor_arg::<E>(1),
+            ScalarArg::new(k as u32),
+            ScalarArg::new(dim as u32),
+            ndims as u32,
+        );
+    }
+
+    (values, indices)
+}
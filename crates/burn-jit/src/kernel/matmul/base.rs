@@ -1,6 +1,6 @@
 use cubecl::linalg::matmul::kernels::MatmulLaunchError;
 
-use super::init_matmul_output;
+use super::{init_matmul_output, matmul_cmma, matmul_strided};
 use crate::{tensor::JitTensor, FloatElement, JitRuntime};
 
 #[cfg(feature = "autotune")]
@@ -13,6 +13,10 @@ pub enum MatmulStrategy {
     Autotune,
     /// Cube implementation of matmul.
     Cube,
+    /// Tiled matmul using CMMA instructions with double buffered shared memory.
+    Cmma,
+    /// Matmul reading the inputs with their strides, without copying them.
+    Strided,
 }
 
 impl Default for MatmulStrategy {
@@ -49,6 +53,8 @@ pub fn matmul<R: JitRuntime, E: FloatElement>(
 
             Ok(out)
         }
+        MatmulStrategy::Cmma => matmul_cmma::<R, E>(lhs, rhs, out),
+        MatmulStrategy::Strided => Ok(matmul_strided::<R, E>(lhs, rhs, out)),
         #[cfg(feature = "autotune")]
         MatmulStrategy::Autotune => Ok(matmul_autotune::<R, E>(lhs, rhs, out)),
     }
@@ -0,0 +1,523 @@
+use cmma::{Matrix, MatrixIdent, MatrixLayout};
+use cubecl::{
+    ir::{Elem, FloatKind},
+    linalg::matmul::kernels::{MatmulAvailabilityError, MatmulLaunchError},
+    prelude::*,
+    Compiler, CubeCount, CubeDim, Feature,
+};
+use half::{bf16, f16};
+use std::any::TypeId;
+
+use super::init_matmul_output;
+use crate::{fast_path::record_fast_path_miss, tensor::JitTensor, FloatElement, JitRuntime};
+
+/// Number of warps of a cube along the rows and the columns of the output.
+const WARPS_M: u32 = 2;
+const WARPS_N: u32 = 2;
+/// Number of CMMA output tiles computed by a warp along the rows and the columns of the output.
+const TILES_M: u32 = 2;
+const TILES_N: u32 = 2;
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+struct CmmaConfig {
+    cmma_m: u32,
+    cmma_n: u32,
+    cmma_k: u32,
+    warp_size: u32,
+}
+
+impl CmmaConfig {
+    fn num_units(&self) -> u32 {
+        self.warp_size * WARPS_M * WARPS_N
+    }
+
+    fn block_m(&self) -> u32 {
+        self.cmma_m * TILES_M * WARPS_M
+    }
+
+    fn block_n(&self) -> u32 {
+        self.cmma_n * TILES_N * WARPS_N
+    }
+
+    fn lhs_stage_size(&self) -> u32 {
+        self.block_m() * self.cmma_k
+    }
+
+    fn rhs_stage_size(&self) -> u32 {
+        self.cmma_k * self.block_n()
+    }
+}
+
+/// Tiled matmul using the tensor cores of the device through CMMA instructions.
+///
+/// Each cube computes a 64x64 output tile, with four warps each accumulating a 32x32 sub-tile.
+/// The tiles of the inputs are staged in shared memory with double buffering: the next tiles
+/// are fetched from global memory before the current ones are multiplied, so that the loads
+/// are overlapped with the CMMA instructions and the units only synchronize once per step.
+///
+/// Like the implicit GEMM convolution, `f32` inputs are multiplied as `tf32` when supported and
+/// as `f16` otherwise, while `bf16` inputs are always accumulated in `f32`.
+pub fn matmul_cmma<R: JitRuntime, F: FloatElement>(
+    lhs: JitTensor<R>,
+    rhs: JitTensor<R>,
+    out: Option<JitTensor<R>>,
+) -> Result<JitTensor<R>, MatmulLaunchError> {
+    let is_tf32 = F::as_elem_native_unchecked() == Elem::Float(FloatKind::F32)
+        && lhs
+            .client
+            .properties()
+            .feature_enabled(Feature::Type(Elem::Float(FloatKind::TF32)));
+
+    let out = if is_tf32 {
+        launch_cmma::<R, F, tf32, F>(lhs, rhs, out)
+    } else if TypeId::of::<F>() == TypeId::of::<bf16>() {
+        // The products of bf16 matrices can only be accumulated in f32.
+        launch_cmma::<R, F, bf16, f32>(lhs, rhs, out)
+    } else {
+        launch_cmma::<R, F, f16, F>(lhs, rhs, out)
+    };
+
+    out.inspect_err(|err| record_fast_path_miss("matmul_cmma", err))
+}
+
+/// Launch the matmul, with the matrices of the CMMA instructions in `FMat` and their products
+/// accumulated in `FAcc`.
+fn launch_cmma<R: JitRuntime, F: FloatElement, FMat: Float, FAcc: Float>(
+    lhs: JitTensor<R>,
+    rhs: JitTensor<R>,
+    out: Option<JitTensor<R>>,
+) -> Result<JitTensor<R>, MatmulLaunchError> {
+    let client = lhs.client.clone();
+    let is_tf32 = FMat::as_elem_native_unchecked() == Elem::Float(FloatKind::TF32);
+    let (cmma_m, cmma_n, cmma_k) = (16, 16, if is_tf32 { 8 } else { 16 });
+
+    let cmma_available = client.properties().feature_enabled(Feature::Cmma {
+        a: FMat::as_elem_native_unchecked(),
+        b: FMat::as_elem_native_unchecked(),
+        c: FAcc::as_elem_native_unchecked(),
+        m: cmma_m as u8,
+        k: cmma_k as u8,
+        n: cmma_n as u8,
+    });
+    if !cmma_available {
+        return Err(MatmulLaunchError::Unavailable(
+            MatmulAvailabilityError::CmmaInstructionUnavailable {
+                input: F::as_elem_native_unchecked(),
+                output: FAcc::as_elem_native_unchecked(),
+                m: cmma_m,
+                n: cmma_n,
+                k: cmma_k,
+            },
+        ));
+    }
+
+    let topology = client.properties().hardware_properties();
+    if topology.plane_size_min < 32 || topology.plane_size_min != topology.plane_size_max {
+        return Err(MatmulLaunchError::Unavailable(
+            MatmulAvailabilityError::PlaneDimUnsupported {
+                plane_dim: topology.plane_size_min,
+            },
+        ));
+    }
+
+    let config = CmmaConfig {
+        cmma_m,
+        cmma_n,
+        cmma_k,
+        warp_size: topology.plane_size_min,
+    };
+
+    // Two stages of both inputs, and the scratch tiles used to write the accumulators.
+    let smem_size =
+        2 * (config.lhs_stage_size() + config.rhs_stage_size()) as usize * size_of::<FMat>()
+            + (WARPS_M * WARPS_N * cmma_m * cmma_n) as usize * size_of::<FAcc>();
+    if <R::Compiler as Compiler>::max_shared_memory_size() < smem_size {
+        return Err(MatmulLaunchError::InvalidConfig(Box::new(
+            "Not enough shared memory",
+        )));
+    }
+
+    let out = out.unwrap_or_else(|| init_matmul_output::<R, F>(&lhs, &rhs));
+
+    let rank = out.shape.num_dims();
+    let m = out.shape.dims[rank - 2] as u32;
+    let n = out.shape.dims[rank - 1] as u32;
+    let batches = out.shape.dims[..rank - 2].iter().product::<usize>() as u32;
+
+    let cube_dim = CubeDim::new(config.num_units(), 1, 1);
+    let cube_count = CubeCount::Static(
+        n.div_ceil(config.block_n()),
+        m.div_ceil(config.block_m()),
+        batches,
+    );
+
+    unsafe {
+        matmul_cmma_kernel::launch_unchecked::<F, FMat, FAcc, R>(
+            &client,
+            cube_count,
+            cube_dim,
+            lhs.as_tensor_arg::<F>(1),
+            rhs.as_tensor_arg::<F>(1),
+            out.as_tensor_arg::<F>(1),
+            config,
+        )
+    };
+
+    Ok(out)
+}
+
+#[cube(launch_unchecked)]
+fn matmul_cmma_kernel<F: Float, FMat: Float, FAcc: Float>(
+    lhs: &Tensor<F>,
+    rhs: &Tensor<F>,
+    out: &mut Tensor<F>,
+    #[comptime] config: CmmaConfig,
+) {
+    let rank = out.rank();
+    let m = lhs.shape(rank - 2);
+    let k = lhs.shape(rank - 1);
+    let n = rhs.shape(rank - 1);
+
+    // Offsets of the batch of the cube, the batch dimensions of the inputs being broadcast.
+    let mut remaining = CUBE_POS_Z;
+    let mut lhs_offset = 0u32;
+    let mut rhs_offset = 0u32;
+    let mut out_offset = 0u32;
+    for i in 0..rank - 2 {
+        let axis = rank - 3 - i;
+        let coordinate = remaining % out.shape(axis);
+        remaining = remaining / out.shape(axis);
+
+        out_offset += coordinate * out.stride(axis);
+        lhs_offset += (coordinate % lhs.shape(axis)) * lhs.stride(axis);
+        rhs_offset += (coordinate % rhs.shape(axis)) * rhs.stride(axis);
+    }
+
+    let block_row = CUBE_POS_Y * comptime!(config.block_m());
+    let block_col = CUBE_POS_X * comptime!(config.block_n());
+
+    let warp_index = UNIT_POS_X / config.warp_size;
+    let warp_row = warp_index / WARPS_N;
+    let warp_col = warp_index % WARPS_N;
+
+    let mut lhs_tile = SharedMemory::<FMat>::new(comptime!(2 * config.lhs_stage_size()));
+    let mut rhs_tile = SharedMemory::<FMat>::new(comptime!(2 * config.rhs_stage_size()));
+    let mut lhs_fetched =
+        Array::<FMat>::new(comptime!(config.lhs_stage_size() / config.num_units()));
+    let mut rhs_fetched =
+        Array::<FMat>::new(comptime!(config.rhs_stage_size() / config.num_units()));
+
+    let mut accumulators = Sequence::<Matrix<FAcc>>::new();
+    #[unroll]
+    for _ in 0..comptime!(TILES_M * TILES_N) {
+        accumulators.push(Matrix::<FAcc>::from_value(
+            MatrixIdent::Accumulator,
+            config.cmma_m,
+            config.cmma_n,
+            config.cmma_k,
+            MatrixLayout::Undefined,
+            FAcc::new(0.0),
+        ));
+    }
+
+    // The first stage is filled before the loop.
+    fetch_tile(
+        lhs,
+        &mut lhs_fetched,
+        lhs_offset,
+        block_row,
+        0,
+        m,
+        k,
+        comptime!(config.block_m()),
+        config.cmma_k,
+        config,
+    );
+    fetch_tile(
+        rhs,
+        &mut rhs_fetched,
+        rhs_offset,
+        0,
+        block_col,
+        k,
+        n,
+        config.cmma_k,
+        comptime!(config.block_n()),
+        config,
+    );
+    store_tile(
+        &lhs_fetched,
+        &mut lhs_tile,
+        0,
+        comptime!(config.lhs_stage_size()),
+        config,
+    );
+    store_tile(
+        &rhs_fetched,
+        &mut rhs_tile,
+        0,
+        comptime!(config.rhs_stage_size()),
+        config,
+    );
+    sync_units();
+
+    let num_steps = (k + config.cmma_k - 1) / config.cmma_k;
+
+    for step in 0..num_steps {
+        let stage = step % 2;
+        let has_next = step + 1 < num_steps;
+
+        // The next tiles are fetched in registers before the CMMA instructions are issued, and
+        // only written to the other stage afterward, so that the loads are overlapped with the
+        // computation.
+        if has_next {
+            let next_k = (step + 1) * config.cmma_k;
+            fetch_tile(
+                lhs,
+                &mut lhs_fetched,
+                lhs_offset,
+                block_row,
+                next_k,
+                m,
+                k,
+                comptime!(config.block_m()),
+                config.cmma_k,
+                config,
+            );
+            fetch_tile(
+                rhs,
+                &mut rhs_fetched,
+                rhs_offset,
+                next_k,
+                block_col,
+                k,
+                n,
+                config.cmma_k,
+                comptime!(config.block_n()),
+                config,
+            );
+        }
+
+        compute_stage::<FMat, FAcc>(
+            &lhs_tile,
+            &rhs_tile,
+            &accumulators,
+            warp_row,
+            warp_col,
+            stage,
+            config,
+        );
+
+        // The other stage was last read during the previous step, before its synchronization.
+        if has_next {
+            store_tile(
+                &lhs_fetched,
+                &mut lhs_tile,
+                1 - stage,
+                comptime!(config.lhs_stage_size()),
+                config,
+            );
+            store_tile(
+                &rhs_fetched,
+                &mut rhs_tile,
+                1 - stage,
+                comptime!(config.rhs_stage_size()),
+                config,
+            );
+        }
+        sync_units();
+    }
+
+    write_output::<F, FAcc>(
+        out,
+        &accumulators,
+        out_offset,
+        block_row,
+        block_col,
+        warp_index,
+        warp_row,
+        warp_col,
+        m,
+        n,
+        config,
+    );
+}
+
+/// Fetch the elements of a tile loaded by the unit, the elements out of the matrix being zeros.
+#[cube]
+fn fetch_tile<F: Float, FMat: Float>(
+    input: &Tensor<F>,
+    fetched: &mut Array<FMat>,
+    offset: u32,
+    row: u32,
+    col: u32,
+    num_rows: u32,
+    num_cols: u32,
+    #[comptime] tile_rows: u32,
+    #[comptime] tile_cols: u32,
+    #[comptime] config: CmmaConfig,
+) {
+    let rank = input.rank();
+    let stride_row = input.stride(rank - 2);
+    let stride_col = input.stride(rank - 1);
+    let num_units = comptime!(config.num_units());
+
+    #[unroll]
+    for i in 0..comptime!(tile_rows * tile_cols / num_units) {
+        let index = i * num_units + UNIT_POS_X;
+        let global_row = row + index / tile_cols;
+        let global_col = col + index % tile_cols;
+
+        let mut value = FMat::new(0.0);
+        if global_row < num_rows && global_col < num_cols {
+            value =
+                FMat::cast_from(input[offset + global_row * stride_row + global_col * stride_col]);
+        }
+
+        fetched[i] = value;
+    }
+}
+
+/// Write the fetched elements of the unit to the given stage of a tile.
+#[cube]
+fn store_tile<FMat: Float>(
+    fetched: &Array<FMat>,
+    tile: &mut SharedMemory<FMat>,
+    stage: u32,
+    #[comptime] stage_size: u32,
+    #[comptime] config: CmmaConfig,
+) {
+    let num_units = comptime!(config.num_units());
+    let stage_offset = stage * stage_size;
+
+    #[unroll]
+    for i in 0..comptime!(stage_size / num_units) {
+        tile[stage_offset + i * num_units + UNIT_POS_X] = fetched[i];
+    }
+}
+
+/// Accumulate the products of the sub-tiles of the warp in the given stage.
+#[cube]
+fn compute_stage<FMat: Float, FAcc: Float>(
+    lhs_tile: &SharedMemory<FMat>,
+    rhs_tile: &SharedMemory<FMat>,
+    accumulators: &Sequence<Matrix<FAcc>>,
+    warp_row: u32,
+    warp_col: u32,
+    stage: u32,
+    #[comptime] config: CmmaConfig,
+) {
+    let CmmaConfig {
+        cmma_m,
+        cmma_n,
+        cmma_k,
+        ..
+    } = config;
+    let block_n = comptime!(config.block_n());
+    let lhs_stage = stage * comptime!(config.lhs_stage_size());
+    let rhs_stage = stage * comptime!(config.rhs_stage_size());
+
+    #[unroll]
+    for i in 0..TILES_M {
+        let row = (warp_row * TILES_M + i) * cmma_m;
+        let lhs_start = lhs_stage + row * cmma_k;
+        let a = unsafe {
+            Matrix::<FMat>::uninitialized(
+                MatrixIdent::A,
+                cmma_m,
+                cmma_n,
+                cmma_k,
+                MatrixLayout::RowMajor,
+            )
+        };
+        cmma::load(
+            &a,
+            &lhs_tile.slice(lhs_start, lhs_start + cmma_m * cmma_k),
+            cmma_k,
+        );
+
+        #[unroll]
+        for j in 0..TILES_N {
+            let col = (warp_col * TILES_N + j) * cmma_n;
+            let rhs_start = rhs_stage + col;
+            let b = unsafe {
+                Matrix::<FMat>::uninitialized(
+                    MatrixIdent::B,
+                    cmma_m,
+                    cmma_n,
+                    cmma_k,
+                    MatrixLayout::RowMajor,
+                )
+            };
+            cmma::load(
+                &b,
+                &rhs_tile.slice(rhs_start, rhs_start + (cmma_k - 1) * block_n + cmma_n),
+                block_n,
+            );
+
+            let accumulator = accumulators.index(i * TILES_N + j);
+            cmma::execute::<FMat, FMat, FAcc, FAcc>(&a, &b, accumulator, accumulator);
+        }
+    }
+}
+
+/// Write the accumulators of the warp to the output, through a scratch tile in shared memory so
+/// that the values out of the output are skipped and the values are cast to the output type.
+#[cube]
+fn write_output<F: Float, FAcc: Float>(
+    out: &mut Tensor<F>,
+    accumulators: &Sequence<Matrix<FAcc>>,
+    offset: u32,
+    block_row: u32,
+    block_col: u32,
+    warp_index: u32,
+    warp_row: u32,
+    warp_col: u32,
+    m: u32,
+    n: u32,
+    #[comptime] config: CmmaConfig,
+) {
+    let CmmaConfig {
+        cmma_m,
+        cmma_n,
+        warp_size,
+        ..
+    } = config;
+    let tile_size = comptime!(cmma_m * cmma_n);
+    let rank = out.rank();
+    let stride_row = out.stride(rank - 2);
+    let stride_col = out.stride(rank - 1);
+
+    let mut scratch = SharedMemory::<FAcc>::new(comptime!(WARPS_M * WARPS_N * cmma_m * cmma_n));
+    let scratch_start = warp_index * tile_size;
+    let lane = UNIT_POS_X % warp_size;
+
+    #[unroll]
+    for i in 0..TILES_M {
+        #[unroll]
+        for j in 0..TILES_N {
+            let row = block_row + (warp_row * TILES_M + i) * cmma_m;
+            let col = block_col + (warp_col * TILES_N + j) * cmma_n;
+
+            let mut tile = scratch.slice_mut(scratch_start, scratch_start + tile_size);
+            cmma::store(
+                &mut tile,
+                accumulators.index(i * TILES_N + j),
+                cmma_n,
+                MatrixLayout::RowMajor,
+            );
+            sync_units();
+
+            for index in range_stepped(lane, tile_size, warp_size) {
+                let global_row = row + index / cmma_n;
+                let global_col = col + index % cmma_n;
+
+                if global_row < m && global_col < n {
+                    out[offset + global_row * stride_row + global_col * stride_col] =
+                        F::cast_from(scratch[scratch_start + index]);
+                }
+            }
+            sync_units();
+        }
+    }
+}
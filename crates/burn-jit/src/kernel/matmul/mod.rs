@@ -1,9 +1,13 @@
 mod base;
+mod cmma;
+mod strided;
 mod tune;
 
 /// Contains utilitary for matmul operation
 pub mod utils;
 
 pub use base::*;
+pub use cmma::*;
+pub use strided::*;
 pub use tune::*;
 pub use utils::*;
@@ -0,0 +1,136 @@
+use cubecl::{prelude::*, CubeCount, CubeDim};
+
+use super::init_matmul_output;
+use crate::{tensor::JitTensor, FloatElement, JitRuntime};
+
+/// Number of rows and columns of the output computed by each unit.
+const TILE_SIZE: u32 = 4;
+/// Number of units of a cube along the rows and the columns of the output.
+const CUBE_SIZE: u32 = 16;
+
+/// Matmul reading the inputs with their strides, so that transposed matrices, permuted batches
+/// and broadcast batches are multiplied without being copied to a contiguous layout first.
+///
+/// Each operand can be row-major or column-major independently, which avoids the copies of the
+/// keys, queries and values of attention layers, whose heads are swapped with the sequence.
+/// The kernel doesn't use shared memory, so it is only selected by autotune when it is faster than
+/// copying the inputs for the tiled kernels.
+pub fn matmul_strided<R: JitRuntime, F: FloatElement>(
+    lhs: JitTensor<R>,
+    rhs: JitTensor<R>,
+    out: Option<JitTensor<R>>,
+) -> JitTensor<R> {
+    let out = out.unwrap_or_else(|| init_matmul_output::<R, F>(&lhs, &rhs));
+
+    let rank = out.shape.num_dims();
+    let m = out.shape.dims[rank - 2] as u32;
+    let n = out.shape.dims[rank - 1] as u32;
+    let batches = out.shape.dims[..rank - 2].iter().product::<usize>() as u32;
+
+    let cube_dim = CubeDim::new(CUBE_SIZE, CUBE_SIZE, 1);
+    let cube_count = CubeCount::Static(
+        n.div_ceil(TILE_SIZE * CUBE_SIZE),
+        m.div_ceil(TILE_SIZE * CUBE_SIZE),
+        batches,
+    );
+
+    unsafe {
+        matmul_strided_kernel::launch_unchecked::<F, R>(
+            &lhs.client,
+            cube_count,
+            cube_dim,
+            lhs.as_tensor_arg::<F>(1),
+            rhs.as_tensor_arg::<F>(1),
+            out.as_tensor_arg::<F>(1),
+            TILE_SIZE,
+        )
+    };
+
+    out
+}
+
+#[cube(launch_unchecked)]
+fn matmul_strided_kernel<F: Float>(
+    lhs: &Tensor<F>,
+    rhs: &Tensor<F>,
+    out: &mut Tensor<F>,
+    #[comptime] tile_size: u32,
+) {
+    let rank = out.rank();
+    let m = lhs.shape(rank - 2);
+    let k = lhs.shape(rank - 1);
+    let n = rhs.shape(rank - 1);
+
+    let row = ABSOLUTE_POS_Y * tile_size;
+    let col = ABSOLUTE_POS_X * tile_size;
+
+    if row >= m || col >= n {
+        terminate!();
+    }
+
+    // Offsets of the batch of the cube, the batch dimensions of the inputs being broadcast.
+    let mut remaining = CUBE_POS_Z;
+    let mut lhs_offset = 0u32;
+    let mut rhs_offset = 0u32;
+    let mut out_offset = 0u32;
+    for i in 0..rank - 2 {
+        let axis = rank - 3 - i;
+        let coordinate = remaining % out.shape(axis);
+        remaining = remaining / out.shape(axis);
+
+        out_offset += coordinate * out.stride(axis);
+        lhs_offset += (coordinate % lhs.shape(axis)) * lhs.stride(axis);
+        rhs_offset += (coordinate % rhs.shape(axis)) * rhs.stride(axis);
+    }
+
+    let lhs_stride_row = lhs.stride(rank - 2);
+    let lhs_stride_col = lhs.stride(rank - 1);
+    let rhs_stride_row = rhs.stride(rank - 2);
+    let rhs_stride_col = rhs.stride(rank - 1);
+
+    let mut accumulators = Array::<F>::new(comptime!(tile_size * tile_size));
+    let mut rhs_values = Array::<F>::new(tile_size);
+
+    #[unroll]
+    for i in 0..comptime!(tile_size * tile_size) {
+        accumulators[i] = F::new(0.0);
+    }
+
+    for i in 0..k {
+        #[unroll]
+        for c in 0..tile_size {
+            let mut value = F::new(0.0);
+            if col + c < n {
+                value = rhs[rhs_offset + i * rhs_stride_row + (col + c) * rhs_stride_col];
+            }
+            rhs_values[c] = value;
+        }
+
+        #[unroll]
+        for r in 0..tile_size {
+            let mut value = F::new(0.0);
+            if row + r < m {
+                value = lhs[lhs_offset + (row + r) * lhs_stride_row + i * lhs_stride_col];
+            }
+
+            #[unroll]
+            for c in 0..tile_size {
+                accumulators[r * tile_size + c] += value * rhs_values[c];
+            }
+        }
+    }
+
+    let out_stride_row = out.stride(rank - 2);
+    let out_stride_col = out.stride(rank - 1);
+
+    #[unroll]
+    for r in 0..tile_size {
+        #[unroll]
+        for c in 0..tile_size {
+            if row + r < m && col + c < n {
+                out[out_offset + (row + r) * out_stride_row + (col + c) * out_stride_col] =
+                    accumulators[r * tile_size + c];
+            }
+        }
+    }
+}
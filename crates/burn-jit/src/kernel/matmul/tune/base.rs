@@ -1,4 +1,4 @@
-use burn_tensor::{Element, ElementConversion};
+use burn_tensor::{Element, ElementConversion, Shape};
 use cubecl::{
     linalg::matmul::{kernels::tiling2d::Tiling2dConfig, Strategy},
     tune::{local_tuner, LocalTuner, TunableSet},
@@ -6,7 +6,13 @@ use cubecl::{
 
 use crate::{
     element::FloatElement,
-    kernel::{matmul::utils::init_matmul_output, prng::random_like_uniform},
+    kernel::{
+        matmul::{
+            matmul_cmma, matmul_strided, requires_copy,
+            utils::{init_matmul_output, storage_size},
+        },
+        prng::random_uniform,
+    },
     ops::numeric::empty_device,
     tensor::JitTensor,
     tune_key::JitAutotuneKey,
@@ -22,14 +28,35 @@ fn matmul_input_gen<R: JitRuntime, E: FloatElement>(
     out: &JitTensor<R>,
 ) -> (JitTensor<R>, JitTensor<R>, JitTensor<R>) {
     let random_bounds: (E, E) = ((-10.0).elem::<E>(), (10.0).elem::<E>());
-    let lhs = random_like_uniform(lhs, random_bounds.0, random_bounds.1);
-    let rhs = random_like_uniform(rhs, random_bounds.0, random_bounds.1);
+    let lhs = random_like_layout(lhs, random_bounds.0, random_bounds.1);
+    let rhs = random_like_layout(rhs, random_bounds.0, random_bounds.1);
 
     let out = empty_device::<R, E>(out.client.clone(), out.device.clone(), out.shape.clone());
 
     (lhs, rhs, out)
 }
 
+/// Create a random tensor with the same strides as the given tensor, so that the kernels are
+/// benchmarked on the same memory layout, including the copies it requires.
+fn random_like_layout<R: JitRuntime, E: FloatElement>(
+    tensor: &JitTensor<R>,
+    lower_bound: E,
+    upper_bound: E,
+) -> JitTensor<R> {
+    let size = storage_size(&tensor.shape.dims, &tensor.strides);
+    let random =
+        random_uniform::<R, E>(Shape::new([size]), &tensor.device, lower_bound, upper_bound);
+
+    JitTensor::new(
+        random.client,
+        random.handle,
+        tensor.shape.clone(),
+        random.device,
+        tensor.strides.clone(),
+        random.dtype,
+    )
+}
+
 /// Executes autotune on matmul operations
 pub fn matmul_autotune<R: JitRuntime, E: FloatElement + Element>(
     lhs: JitTensor<R>,
@@ -45,7 +72,9 @@ pub fn matmul_autotune<R: JitRuntime, E: FloatElement + Element>(
     let tunables = TunableSet::new(create_key::<R, E>, matmul_input_gen::<R, E>)
         .with_tunable(matmul_tiling2d::<R, E>)
         .with_tunable(matmul_accelerated::<R, E>)
-        .with_tunable(matmul_simple::<R, E>);
+        .with_tunable(matmul_cmma_double_buffered::<R, E>)
+        .with_tunable(matmul_simple::<R, E>)
+        .with_tunable(matmul_strided_tunable::<R, E>);
 
     TUNER.execute(
         &JitTuneId::new::<R>(&lhs.device),
@@ -72,6 +101,32 @@ fn matmul_accelerated<R: JitRuntime, E: FloatElement>(
     .map_err(|err| format!("{err:?}"))
 }
 
+fn matmul_cmma_double_buffered<R: JitRuntime, E: FloatElement>(
+    lhs: JitTensor<R>,
+    rhs: JitTensor<R>,
+    out: JitTensor<R>,
+) -> Result<(), String> {
+    matmul_cmma::<R, E>(lhs, rhs, Some(out))
+        .map(|_| ())
+        .map_err(|err| format!("{err:?}"))
+}
+
+fn matmul_strided_tunable<R: JitRuntime, E: FloatElement>(
+    lhs: JitTensor<R>,
+    rhs: JitTensor<R>,
+    out: JitTensor<R>,
+) -> Result<(), String> {
+    // The strided kernel is only a candidate when the other kernels would copy an input.
+    if !requires_copy(&lhs.shape.dims, &lhs.strides)
+        && !requires_copy(&rhs.shape.dims, &rhs.strides)
+    {
+        return Err("The inputs don't require a copy".into());
+    }
+
+    matmul_strided::<R, E>(lhs, rhs, Some(out));
+    Ok(())
+}
+
 fn matmul_tiling2d<R: JitRuntime, E: FloatElement>(
     lhs: JitTensor<R>,
     rhs: JitTensor<R>,
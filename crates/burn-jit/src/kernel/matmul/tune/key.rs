@@ -1,4 +1,6 @@
-use crate::{tensor::JitTensor, FloatElement, JitAutotuneKey, JitRuntime};
+use crate::{
+    kernel::matmul::requires_copy, tensor::JitTensor, FloatElement, JitAutotuneKey, JitRuntime,
+};
 use burn_tensor::{DType, Shape};
 use core::fmt::Debug;
 use cubecl::AutotuneKey;
@@ -10,6 +12,7 @@ use std::{cmp::max, hash::Hash};
 pub struct MatmulAutotuneKey {
     round: bool,     // True when all matmul dims are multiples of 64
     broadcast: bool, // True when there are differences in batch size
+    strided: bool,   // True when an input must be copied to be read by the cubecl kernels
     #[autotune(anchor)]
     m: usize,
     #[autotune(anchor)]
@@ -22,7 +25,14 @@ pub struct MatmulAutotuneKey {
 }
 
 impl MatmulAutotuneKey {
-    pub(crate) fn from_shape(lhs_shape: &Shape, rhs_shape: &Shape, dtype: DType) -> Self {
+    /// Create the key of a matmul, `strided` being true when an input must be copied to be read
+    /// by the cubecl kernels.
+    pub(crate) fn from_shape(
+        lhs_shape: &Shape,
+        rhs_shape: &Shape,
+        strided: bool,
+        dtype: DType,
+    ) -> Self {
         let ndims = lhs_shape.num_dims();
         let m = lhs_shape.dims[ndims - 2];
         let k = lhs_shape.dims[ndims - 1];
@@ -43,7 +53,7 @@ impl MatmulAutotuneKey {
 
         let round = m % 64 == 0 && k % 64 == 0 && n % 64 == 0;
 
-        Self::new(round, broadcast, m, k, n, batch_product, dtype)
+        Self::new(round, broadcast, strided, m, k, n, batch_product, dtype)
     }
 }
 
@@ -52,9 +62,13 @@ pub(crate) fn create_key<R: JitRuntime, E: FloatElement>(
     rhs: &JitTensor<R>,
     _out: &JitTensor<R>,
 ) -> JitAutotuneKey {
+    let strided = requires_copy(&lhs.shape.dims, &lhs.strides)
+        || requires_copy(&rhs.shape.dims, &rhs.strides);
+
     JitAutotuneKey::Matmul(MatmulAutotuneKey::from_shape(
         &lhs.shape,
         &rhs.shape,
+        strided,
         E::dtype(),
     ))
 }
@@ -67,7 +81,7 @@ mod tests {
     fn matmul_autotune_key_all_same_and_round() {
         let lhs_shape: Shape = [4, 512, 512].into();
         let rhs_shape: Shape = [4, 512, 512].into();
-        let key = MatmulAutotuneKey::from_shape(&lhs_shape, &rhs_shape, DType::F32);
+        let key = MatmulAutotuneKey::from_shape(&lhs_shape, &rhs_shape, false, DType::F32);
 
         assert!(key.round);
         assert!(!key.broadcast);
@@ -80,7 +94,7 @@ mod tests {
     fn matmul_autotune_key_all_different() {
         let lhs_shape: Shape = [2, 3, 511, 512].into();
         let rhs_shape: Shape = [3, 2, 512, 513].into();
-        let key = MatmulAutotuneKey::from_shape(&lhs_shape, &rhs_shape, DType::F32);
+        let key = MatmulAutotuneKey::from_shape(&lhs_shape, &rhs_shape, false, DType::F32);
 
         assert!(!key.round);
         assert!(key.broadcast);
@@ -94,8 +108,19 @@ mod tests {
     fn matmul_autotune_key_large_batch() {
         let lhs_shape: Shape = [128, 512, 511, 512].into();
         let rhs_shape: Shape = [200, 400, 512, 513].into();
-        let key = MatmulAutotuneKey::from_shape(&lhs_shape, &rhs_shape, DType::F32);
+        let key = MatmulAutotuneKey::from_shape(&lhs_shape, &rhs_shape, false, DType::F32);
 
         assert_eq!(key.batch, 256);
     }
+
+    #[test]
+    fn matmul_autotune_key_strided() {
+        let lhs_shape: Shape = [2, 8, 64, 64].into();
+        let rhs_shape: Shape = [2, 8, 64, 64].into();
+        let contiguous = MatmulAutotuneKey::from_shape(&lhs_shape, &rhs_shape, false, DType::F32);
+        let strided = MatmulAutotuneKey::from_shape(&lhs_shape, &rhs_shape, true, DType::F32);
+
+        assert!(strided.strided);
+        assert_ne!(contiguous, strided);
+    }
 }
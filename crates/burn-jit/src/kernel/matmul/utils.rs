@@ -24,3 +24,76 @@ pub(crate) fn shape_out<R: JitRuntime>(lhs: &JitTensor<R>, rhs: &JitTensor<R>) -
     shape_out[ndims - 1] = rhs.shape.dims[ndims - 1];
     Shape::from(shape_out)
 }
+
+/// Whether the matrices of a tensor must be copied to be read by the matmul kernels of cubecl,
+/// which only support row-major and column-major matrices with batches stored in order.
+pub(crate) fn requires_copy(shape: &[usize], strides: &[usize]) -> bool {
+    let rank = shape.len();
+    if rank < 2 {
+        return false;
+    }
+
+    let (rows, cols) = (shape[rank - 2], shape[rank - 1]);
+    let (row_stride, col_stride) = (strides[rank - 2], strides[rank - 1]);
+    let row_major = col_stride == 1 && row_stride >= cols;
+    let col_major = row_stride == 1 && col_stride >= rows;
+
+    if !row_major && !col_major {
+        return true;
+    }
+
+    let mut matrix_size = rows * cols;
+    for axis in (0..rank - 2).rev() {
+        if shape[axis] == 1 {
+            continue;
+        }
+        if strides[axis] < matrix_size {
+            return true;
+        }
+        matrix_size = strides[axis] * shape[axis];
+    }
+
+    false
+}
+
+/// The number of elements of the buffer of a tensor with the given shape and strides.
+pub(crate) fn storage_size(shape: &[usize], strides: &[usize]) -> usize {
+    if shape.contains(&0) {
+        return 0;
+    }
+
+    1 + shape
+        .iter()
+        .zip(strides)
+        .map(|(dim, stride)| (dim - 1) * stride)
+        .sum::<usize>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn contiguous_and_transposed_matrices_should_not_be_copied() {
+        assert!(!requires_copy(&[2, 3, 4], &[12, 4, 1]));
+        assert!(!requires_copy(&[2, 3, 4], &[12, 1, 3]));
+        assert!(!requires_copy(&[1, 3, 4], &[4, 1, 3]));
+    }
+
+    #[test]
+    fn permuted_batches_should_be_copied() {
+        // Heads swapped with the sequence, like the queries of an attention layer.
+        assert!(requires_copy(&[2, 8, 16, 32], &[4096, 32, 256, 1]));
+        // Batch dimension expanded from a single matrix.
+        assert!(requires_copy(&[4, 16, 32], &[0, 32, 1]));
+        // Neither rows nor columns are contiguous.
+        assert!(requires_copy(&[16, 32], &[64, 2]));
+    }
+
+    #[test]
+    fn storage_size_should_cover_the_last_element() {
+        assert_eq!(storage_size(&[2, 8, 16, 32], &[4096, 32, 256, 1]), 8192);
+        assert_eq!(storage_size(&[4, 16, 32], &[0, 32, 1]), 512);
+        assert_eq!(storage_size(&[4, 0, 32], &[0, 32, 1]), 0);
+    }
+}
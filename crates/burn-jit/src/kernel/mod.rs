@@ -4,11 +4,18 @@ mod cast;
 mod clamp;
 mod comparison;
 mod contiguous;
+mod grid_sample;
 mod index;
+mod kthvalue;
 mod mask;
+mod roi;
+mod rotary;
+mod searchsorted;
+mod segment;
 mod unary_float;
 mod unary_int;
 mod unary_numeric;
+mod unfold;
 
 pub(crate) use binary::*;
 pub(crate) use binary_int::*;
@@ -24,10 +31,15 @@ pub use cubecl::Kernel;
 
 /// Convolution kernels
 pub mod conv;
+/// FP8 conversion and scaled matmul kernels (experimental)
+#[cfg(feature = "fp8")]
+pub mod fp8;
 /// Interpolation kernels
 pub mod interpolate;
 /// Matmul kernels
 pub mod matmul;
+/// Normalization kernels
+pub(crate) mod norm;
 /// Pooling kernels
 pub mod pool;
 /// Pseudo-random number generator kernels
@@ -39,4 +51,11 @@ pub mod reduce;
 
 pub(crate) use clamp::*;
 pub(crate) use comparison::*;
+pub(crate) use grid_sample::*;
 pub(crate) use index::*;
+pub(crate) use kthvalue::*;
+pub(crate) use roi::*;
+pub(crate) use rotary::*;
+pub(crate) use searchsorted::*;
+pub(crate) use segment::*;
+pub(crate) use unfold::*;
@@ -0,0 +1,92 @@
+use burn_tensor::Shape;
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+
+use crate::{
+    kernel::{
+        cast,
+        reduce::{reduce_dim, Sum},
+    },
+    ops::reshape,
+    tensor::JitTensor,
+    FloatElement, JitRuntime,
+};
+
+/// The number of units of the cubes normalizing a row.
+pub(crate) const CUBE_SIZE: u32 = 256;
+
+/// The minimum number of rows accumulated by a unit for the gradients of the affine parameters.
+const ROWS_PER_PARTIAL: usize = 64;
+
+/// Sum the values of all units of the cube with a tree reduction in shared memory.
+#[cube]
+pub(crate) fn sum_cube<F: Float>(shared: &mut SharedMemory<F>, value: F) -> F {
+    shared[UNIT_POS] = value;
+    sync_units();
+
+    #[unroll]
+    for i in 0..comptime![CUBE_SIZE.ilog2()] {
+        let stride = comptime![CUBE_SIZE >> (i + 1)];
+
+        if UNIT_POS < stride {
+            shared[UNIT_POS] += shared[UNIT_POS + stride];
+        }
+        sync_units();
+    }
+
+    let result = shared[0];
+    // The shared memory is reused by the next reduction.
+    sync_units();
+
+    result
+}
+
+/// The launch configuration of the kernels normalizing the rows, with one cube per row.
+pub(crate) fn row_launch(num_rows: usize) -> (CubeCount, CubeDim) {
+    let cube_count = calculate_cube_count_elemwise(num_rows, CubeDim::new(1, 1, 1));
+
+    (cube_count, CubeDim::new(CUBE_SIZE, 1, 1))
+}
+
+/// The launch configuration of the kernels accumulating the gradients of the affine parameters.
+///
+/// Each unit sums a feature over a chunk of rows, so the reads are coalesced along the features
+/// and the rows are split between many cubes. The partial sums are then reduced by
+/// [sum_partials].
+pub(crate) struct PartialLaunch {
+    pub cube_count: CubeCount,
+    pub cube_dim: CubeDim,
+    pub rows_per_partial: u32,
+    pub shape: Shape,
+}
+
+impl PartialLaunch {
+    pub fn new(num_rows: usize, d_model: usize) -> Self {
+        let max_partials = u16::MAX as usize;
+        let rows_per_partial = ROWS_PER_PARTIAL.max(num_rows.div_ceil(max_partials));
+        let num_partials = num_rows.div_ceil(rows_per_partial).max(1);
+        let cube_dim = CubeDim::new(CUBE_SIZE, 1, 1);
+        let cube_count = CubeCount::Static(
+            d_model.div_ceil(CUBE_SIZE as usize) as u32,
+            num_partials as u32,
+            1,
+        );
+
+        Self {
+            cube_count,
+            cube_dim,
+            rows_per_partial: rows_per_partial as u32,
+            shape: Shape::new([num_partials, d_model]),
+        }
+    }
+}
+
+/// Sum the partial gradients of an affine parameter computed with a [PartialLaunch], and cast
+/// them back to the element type.
+pub(crate) fn sum_partials<R: JitRuntime, E: FloatElement, Acc: FloatElement>(
+    partials: JitTensor<R>,
+    shape: Shape,
+) -> JitTensor<R> {
+    let sum = reduce_dim::<R, Acc, Acc, Sum>(partials, 0, Default::default()).unwrap();
+
+    reshape(cast::<R, Acc, E>(sum), shape)
+}
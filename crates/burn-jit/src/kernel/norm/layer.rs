@@ -0,0 +1,265 @@
+use burn_tensor::{ElementConversion, Shape};
+use cubecl::prelude::*;
+
+use super::{row_launch, sum_cube, sum_partials, PartialLaunch, CUBE_SIZE};
+use crate::{
+    kernel::{into_contiguous, reduce::is_half_precision},
+    ops::numeric::{empty_device, zeros_device},
+    tensor::JitTensor,
+    FloatElement, JitRuntime,
+};
+
+/// Normalizes each row of the input, one cube per row.
+#[cube(launch_unchecked)]
+fn layer_norm_kernel<F: Float, Acc: Float>(
+    input: &Tensor<F>,
+    gamma: &Tensor<F>,
+    beta: &Tensor<F>,
+    output: &mut Tensor<F>,
+    epsilon: Acc,
+    num_rows: u32,
+) {
+    let row = CUBE_POS;
+
+    if row >= num_rows {
+        terminate!();
+    }
+
+    let d_model = gamma.len();
+    let offset = row * d_model;
+    let mut shared = SharedMemory::<Acc>::new(CUBE_SIZE);
+    let (mean, rstd) = layer_stats::<F, Acc>(input, &mut shared, offset, d_model, epsilon);
+
+    for i in range_stepped(UNIT_POS, d_model, CUBE_DIM) {
+        let normalized = F::cast_from((Acc::cast_from(input[offset + i]) - mean) * rstd);
+        output[offset + i] = normalized * gamma[i] + beta[i];
+    }
+}
+
+/// Computes the gradient of each row of the input, one cube per row, and saves the statistics of
+/// the rows for the gradients of gamma and beta.
+#[cube(launch_unchecked)]
+fn layer_norm_backward_kernel<F: Float, Acc: Float>(
+    input: &Tensor<F>,
+    gamma: &Tensor<F>,
+    grad: &Tensor<F>,
+    x_grad: &mut Tensor<F>,
+    means: &mut Tensor<Acc>,
+    rstds: &mut Tensor<Acc>,
+    epsilon: Acc,
+    num_rows: u32,
+) {
+    let row = CUBE_POS;
+
+    if row >= num_rows {
+        terminate!();
+    }
+
+    let d_model = gamma.len();
+    let offset = row * d_model;
+    let mut shared = SharedMemory::<Acc>::new(CUBE_SIZE);
+    let (mean, rstd) = layer_stats::<F, Acc>(input, &mut shared, offset, d_model, epsilon);
+
+    // mean(grad_normalized) and mean(grad_normalized * x_normalized)
+    let mut grad_mean = Acc::new(0.0);
+    let mut projection = Acc::new(0.0);
+    for i in range_stepped(UNIT_POS, d_model, CUBE_DIM) {
+        let grad_normalized = Acc::cast_from(grad[offset + i]) * Acc::cast_from(gamma[i]);
+        let x_normalized = (Acc::cast_from(input[offset + i]) - mean) * rstd;
+        grad_mean += grad_normalized;
+        projection += grad_normalized * x_normalized;
+    }
+    let grad_mean = sum_cube::<Acc>(&mut shared, grad_mean) / Acc::cast_from(d_model);
+    let projection = sum_cube::<Acc>(&mut shared, projection) / Acc::cast_from(d_model);
+
+    for i in range_stepped(UNIT_POS, d_model, CUBE_DIM) {
+        let grad_normalized = Acc::cast_from(grad[offset + i]) * Acc::cast_from(gamma[i]);
+        let x_normalized = (Acc::cast_from(input[offset + i]) - mean) * rstd;
+        x_grad[offset + i] =
+            F::cast_from(rstd * (grad_normalized - grad_mean - x_normalized * projection));
+    }
+
+    if UNIT_POS == 0 {
+        means[row] = mean;
+        rstds[row] = rstd;
+    }
+}
+
+/// Accumulates the gradients of gamma and beta over a chunk of rows, one unit per feature.
+#[cube(launch_unchecked)]
+fn layer_norm_affine_backward_kernel<F: Float, Acc: Float>(
+    input: &Tensor<F>,
+    grad: &Tensor<F>,
+    means: &Tensor<Acc>,
+    rstds: &Tensor<Acc>,
+    gamma_partials: &mut Tensor<Acc>,
+    beta_partials: &mut Tensor<Acc>,
+    rows_per_partial: u32,
+) {
+    let d_model = gamma_partials.shape(1);
+    let feature = ABSOLUTE_POS_X;
+
+    if feature >= d_model {
+        terminate!();
+    }
+
+    let row_start = CUBE_POS_Y * rows_per_partial;
+    let row_end = Min::min(row_start + rows_per_partial, rstds.len());
+
+    let mut gamma_sum = Acc::new(0.0);
+    let mut beta_sum = Acc::new(0.0);
+    for row in row_start..row_end {
+        let index = row * d_model + feature;
+        let grad = Acc::cast_from(grad[index]);
+        let x_normalized = (Acc::cast_from(input[index]) - means[row]) * rstds[row];
+        gamma_sum += grad * x_normalized;
+        beta_sum += grad;
+    }
+
+    gamma_partials[CUBE_POS_Y * d_model + feature] = gamma_sum;
+    beta_partials[CUBE_POS_Y * d_model + feature] = beta_sum;
+}
+
+/// The mean and the reciprocal of the standard deviation of a row, reduced over all units of the
+/// cube.
+#[cube]
+fn layer_stats<F: Float, Acc: Float>(
+    input: &Tensor<F>,
+    shared: &mut SharedMemory<Acc>,
+    offset: u32,
+    d_model: u32,
+    epsilon: Acc,
+) -> (Acc, Acc) {
+    let mut sum = Acc::new(0.0);
+    for i in range_stepped(UNIT_POS, d_model, CUBE_DIM) {
+        sum += Acc::cast_from(input[offset + i]);
+    }
+    let mean = sum_cube::<Acc>(shared, sum) / Acc::cast_from(d_model);
+
+    let mut var = Acc::new(0.0);
+    for i in range_stepped(UNIT_POS, d_model, CUBE_DIM) {
+        let centered = Acc::cast_from(input[offset + i]) - mean;
+        var += centered * centered;
+    }
+    let var = sum_cube::<Acc>(shared, var) / Acc::cast_from(d_model);
+
+    (mean, Acc::new(1.0) / Acc::sqrt(var + epsilon))
+}
+
+pub(crate) fn layer_norm<R: JitRuntime, E: FloatElement>(
+    x: JitTensor<R>,
+    gamma: JitTensor<R>,
+    beta: Option<JitTensor<R>>,
+    epsilon: f64,
+) -> JitTensor<R> {
+    // Half precision values are accumulated in f32, other types keep their own precision.
+    if is_half_precision::<E>() {
+        launch_layer_norm::<R, E, f32>(x, gamma, beta, epsilon)
+    } else {
+        launch_layer_norm::<R, E, E>(x, gamma, beta, epsilon)
+    }
+}
+
+pub(crate) fn layer_norm_backward<R: JitRuntime, E: FloatElement>(
+    x: JitTensor<R>,
+    gamma: JitTensor<R>,
+    grad: JitTensor<R>,
+    epsilon: f64,
+) -> (JitTensor<R>, JitTensor<R>, JitTensor<R>) {
+    if is_half_precision::<E>() {
+        launch_layer_norm_backward::<R, E, f32>(x, gamma, grad, epsilon)
+    } else {
+        launch_layer_norm_backward::<R, E, E>(x, gamma, grad, epsilon)
+    }
+}
+
+fn launch_layer_norm<R: JitRuntime, E: FloatElement, Acc: FloatElement>(
+    x: JitTensor<R>,
+    gamma: JitTensor<R>,
+    beta: Option<JitTensor<R>>,
+    epsilon: f64,
+) -> JitTensor<R> {
+    let x = into_contiguous(x);
+    let gamma = into_contiguous(gamma);
+    let beta = match beta {
+        Some(beta) => into_contiguous(beta),
+        None => zeros_device::<R, E>(x.client.clone(), x.device.clone(), gamma.shape.clone()),
+    };
+    let num_rows = x.shape.num_elements() / gamma.shape.num_elements();
+
+    let output = empty_device::<R, E>(x.client.clone(), x.device.clone(), x.shape.clone());
+    let (cube_count, cube_dim) = row_launch(num_rows);
+
+    unsafe {
+        layer_norm_kernel::launch_unchecked::<E, Acc, R>(
+            &x.client,
+            cube_count,
+            cube_dim,
+            x.as_tensor_arg::<E>(1),
+            gamma.as_tensor_arg::<E>(1),
+            beta.as_tensor_arg::<E>(1),
+            output.as_tensor_arg::<E>(1),
+            ScalarArg::new(epsilon.elem::<Acc>()),
+            ScalarArg::new(num_rows as u32),
+        )
+    };
+
+    output
+}
+
+fn launch_layer_norm_backward<R: JitRuntime, E: FloatElement, Acc: FloatElement>(
+    x: JitTensor<R>,
+    gamma: JitTensor<R>,
+    grad: JitTensor<R>,
+    epsilon: f64,
+) -> (JitTensor<R>, JitTensor<R>, JitTensor<R>) {
+    let x = into_contiguous(x);
+    let gamma = into_contiguous(gamma);
+    let grad = into_contiguous(grad);
+    let d_model = gamma.shape.num_elements();
+    let num_rows = x.shape.num_elements() / d_model;
+
+    let client = x.client.clone();
+    let device = x.device.clone();
+    let partial = PartialLaunch::new(num_rows, d_model);
+    let x_grad = empty_device::<R, E>(client.clone(), device.clone(), x.shape.clone());
+    let means = empty_device::<R, Acc>(client.clone(), device.clone(), Shape::new([num_rows]));
+    let rstds = empty_device::<R, Acc>(client.clone(), device.clone(), Shape::new([num_rows]));
+    let gamma_partials =
+        empty_device::<R, Acc>(client.clone(), device.clone(), partial.shape.clone());
+    let beta_partials = empty_device::<R, Acc>(client.clone(), device, partial.shape.clone());
+    let (cube_count, cube_dim) = row_launch(num_rows);
+
+    unsafe {
+        layer_norm_backward_kernel::launch_unchecked::<E, Acc, R>(
+            &client,
+            cube_count,
+            cube_dim,
+            x.as_tensor_arg::<E>(1),
+            gamma.as_tensor_arg::<E>(1),
+            grad.as_tensor_arg::<E>(1),
+            x_grad.as_tensor_arg::<E>(1),
+            means.as_tensor_arg::<Acc>(1),
+            rstds.as_tensor_arg::<Acc>(1),
+            ScalarArg::new(epsilon.elem::<Acc>()),
+            ScalarArg::new(num_rows as u32),
+        );
+        layer_norm_affine_backward_kernel::launch_unchecked::<E, Acc, R>(
+            &client,
+            partial.cube_count,
+            partial.cube_dim,
+            x.as_tensor_arg::<E>(1),
+            grad.as_tensor_arg::<E>(1),
+            means.as_tensor_arg::<Acc>(1),
+            rstds.as_tensor_arg::<Acc>(1),
+            gamma_partials.as_tensor_arg::<Acc>(1),
+            beta_partials.as_tensor_arg::<Acc>(1),
+            ScalarArg::new(partial.rows_per_partial),
+        );
+    };
+
+    let gamma_grad = sum_partials::<R, E, Acc>(gamma_partials, gamma.shape.clone());
+    let beta_grad = sum_partials::<R, E, Acc>(beta_partials, gamma.shape);
+
+    (x_grad, gamma_grad, beta_grad)
+}
@@ -0,0 +1,7 @@
+mod base;
+mod layer;
+mod rms;
+
+pub(crate) use base::*;
+pub(crate) use layer::*;
+pub(crate) use rms::*;
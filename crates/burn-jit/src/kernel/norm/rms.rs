@@ -0,0 +1,229 @@
+use burn_tensor::{ElementConversion, Shape};
+use cubecl::prelude::*;
+
+use super::{row_launch, sum_cube, sum_partials, PartialLaunch, CUBE_SIZE};
+use crate::{
+    kernel::{into_contiguous, reduce::is_half_precision},
+    ops::numeric::empty_device,
+    tensor::JitTensor,
+    FloatElement, JitRuntime,
+};
+
+/// Normalizes each row of the input, one cube per row.
+#[cube(launch_unchecked)]
+fn rms_norm_kernel<F: Float, Acc: Float>(
+    input: &Tensor<F>,
+    gamma: &Tensor<F>,
+    output: &mut Tensor<F>,
+    epsilon: Acc,
+    num_rows: u32,
+) {
+    let row = CUBE_POS;
+
+    if row >= num_rows {
+        terminate!();
+    }
+
+    let d_model = gamma.len();
+    let offset = row * d_model;
+    let mut shared = SharedMemory::<Acc>::new(CUBE_SIZE);
+    let rstd = rms_rstd::<F, Acc>(input, &mut shared, offset, d_model, epsilon);
+
+    for i in range_stepped(UNIT_POS, d_model, CUBE_DIM) {
+        let normalized = F::cast_from(Acc::cast_from(input[offset + i]) * rstd);
+        output[offset + i] = normalized * gamma[i];
+    }
+}
+
+/// Computes the gradient of each row of the input, one cube per row, and saves the reciprocal of
+/// the root mean square of the rows for the gradient of gamma.
+#[cube(launch_unchecked)]
+fn rms_norm_backward_kernel<F: Float, Acc: Float>(
+    input: &Tensor<F>,
+    gamma: &Tensor<F>,
+    grad: &Tensor<F>,
+    x_grad: &mut Tensor<F>,
+    rstds: &mut Tensor<Acc>,
+    epsilon: Acc,
+    num_rows: u32,
+) {
+    let row = CUBE_POS;
+
+    if row >= num_rows {
+        terminate!();
+    }
+
+    let d_model = gamma.len();
+    let offset = row * d_model;
+    let mut shared = SharedMemory::<Acc>::new(CUBE_SIZE);
+    let rstd = rms_rstd::<F, Acc>(input, &mut shared, offset, d_model, epsilon);
+
+    // mean(grad_normalized * x_normalized)
+    let mut projection = Acc::new(0.0);
+    for i in range_stepped(UNIT_POS, d_model, CUBE_DIM) {
+        let grad_normalized = Acc::cast_from(grad[offset + i]) * Acc::cast_from(gamma[i]);
+        projection += grad_normalized * Acc::cast_from(input[offset + i]) * rstd;
+    }
+    let projection = sum_cube::<Acc>(&mut shared, projection) / Acc::cast_from(d_model);
+
+    for i in range_stepped(UNIT_POS, d_model, CUBE_DIM) {
+        let grad_normalized = Acc::cast_from(grad[offset + i]) * Acc::cast_from(gamma[i]);
+        let x_normalized = Acc::cast_from(input[offset + i]) * rstd;
+        x_grad[offset + i] = F::cast_from(rstd * (grad_normalized - x_normalized * projection));
+    }
+
+    if UNIT_POS == 0 {
+        rstds[row] = rstd;
+    }
+}
+
+/// Accumulates the gradient of gamma over a chunk of rows, one unit per feature.
+#[cube(launch_unchecked)]
+fn rms_norm_gamma_backward_kernel<F: Float, Acc: Float>(
+    input: &Tensor<F>,
+    grad: &Tensor<F>,
+    rstds: &Tensor<Acc>,
+    partials: &mut Tensor<Acc>,
+    rows_per_partial: u32,
+) {
+    let d_model = partials.shape(1);
+    let feature = ABSOLUTE_POS_X;
+
+    if feature >= d_model {
+        terminate!();
+    }
+
+    let row_start = CUBE_POS_Y * rows_per_partial;
+    let row_end = Min::min(row_start + rows_per_partial, rstds.len());
+
+    let mut sum = Acc::new(0.0);
+    for row in row_start..row_end {
+        let index = row * d_model + feature;
+        sum += Acc::cast_from(grad[index]) * Acc::cast_from(input[index]) * rstds[row];
+    }
+
+    partials[CUBE_POS_Y * d_model + feature] = sum;
+}
+
+/// The reciprocal of the root mean square of a row, reduced over all units of the cube.
+#[cube]
+fn rms_rstd<F: Float, Acc: Float>(
+    input: &Tensor<F>,
+    shared: &mut SharedMemory<Acc>,
+    offset: u32,
+    d_model: u32,
+    epsilon: Acc,
+) -> Acc {
+    let mut sum = Acc::new(0.0);
+    for i in range_stepped(UNIT_POS, d_model, CUBE_DIM) {
+        let value = Acc::cast_from(input[offset + i]);
+        sum += value * value;
+    }
+    let sum = sum_cube::<Acc>(shared, sum);
+
+    Acc::new(1.0) / Acc::sqrt(sum / Acc::cast_from(d_model) + epsilon)
+}
+
+pub(crate) fn rms_norm<R: JitRuntime, E: FloatElement>(
+    x: JitTensor<R>,
+    gamma: JitTensor<R>,
+    epsilon: f64,
+) -> JitTensor<R> {
+    // Half precision values are accumulated in f32, other types keep their own precision.
+    if is_half_precision::<E>() {
+        launch_rms_norm::<R, E, f32>(x, gamma, epsilon)
+    } else {
+        launch_rms_norm::<R, E, E>(x, gamma, epsilon)
+    }
+}
+
+pub(crate) fn rms_norm_backward<R: JitRuntime, E: FloatElement>(
+    x: JitTensor<R>,
+    gamma: JitTensor<R>,
+    grad: JitTensor<R>,
+    epsilon: f64,
+) -> (JitTensor<R>, JitTensor<R>) {
+    if is_half_precision::<E>() {
+        launch_rms_norm_backward::<R, E, f32>(x, gamma, grad, epsilon)
+    } else {
+        launch_rms_norm_backward::<R, E, E>(x, gamma, grad, epsilon)
+    }
+}
+
+fn launch_rms_norm<R: JitRuntime, E: FloatElement, Acc: FloatElement>(
+    x: JitTensor<R>,
+    gamma: JitTensor<R>,
+    epsilon: f64,
+) -> JitTensor<R> {
+    let x = into_contiguous(x);
+    let gamma = into_contiguous(gamma);
+    let num_rows = x.shape.num_elements() / gamma.shape.num_elements();
+
+    let output = empty_device::<R, E>(x.client.clone(), x.device.clone(), x.shape.clone());
+    let (cube_count, cube_dim) = row_launch(num_rows);
+
+    unsafe {
+        rms_norm_kernel::launch_unchecked::<E, Acc, R>(
+            &x.client,
+            cube_count,
+            cube_dim,
+            x.as_tensor_arg::<E>(1),
+            gamma.as_tensor_arg::<E>(1),
+            output.as_tensor_arg::<E>(1),
+            ScalarArg::new(epsilon.elem::<Acc>()),
+            ScalarArg::new(num_rows as u32),
+        )
+    };
+
+    output
+}
+
+fn launch_rms_norm_backward<R: JitRuntime, E: FloatElement, Acc: FloatElement>(
+    x: JitTensor<R>,
+    gamma: JitTensor<R>,
+    grad: JitTensor<R>,
+    epsilon: f64,
+) -> (JitTensor<R>, JitTensor<R>) {
+    let x = into_contiguous(x);
+    let gamma = into_contiguous(gamma);
+    let grad = into_contiguous(grad);
+    let d_model = gamma.shape.num_elements();
+    let num_rows = x.shape.num_elements() / d_model;
+
+    let client = x.client.clone();
+    let device = x.device.clone();
+    let partial = PartialLaunch::new(num_rows, d_model);
+    let x_grad = empty_device::<R, E>(client.clone(), device.clone(), x.shape.clone());
+    let rstds = empty_device::<R, Acc>(client.clone(), device.clone(), Shape::new([num_rows]));
+    let gamma_partials = empty_device::<R, Acc>(client.clone(), device, partial.shape.clone());
+    let (cube_count, cube_dim) = row_launch(num_rows);
+
+    unsafe {
+        rms_norm_backward_kernel::launch_unchecked::<E, Acc, R>(
+            &client,
+            cube_count,
+            cube_dim,
+            x.as_tensor_arg::<E>(1),
+            gamma.as_tensor_arg::<E>(1),
+            grad.as_tensor_arg::<E>(1),
+            x_grad.as_tensor_arg::<E>(1),
+            rstds.as_tensor_arg::<Acc>(1),
+            ScalarArg::new(epsilon.elem::<Acc>()),
+            ScalarArg::new(num_rows as u32),
+        );
+        rms_norm_gamma_backward_kernel::launch_unchecked::<E, Acc, R>(
+            &client,
+            partial.cube_count,
+            partial.cube_dim,
+            x.as_tensor_arg::<E>(1),
+            grad.as_tensor_arg::<E>(1),
+            rstds.as_tensor_arg::<Acc>(1),
+            gamma_partials.as_tensor_arg::<Acc>(1),
+            ScalarArg::new(partial.rows_per_partial),
+        );
+    };
+
+    let gamma_grad = sum_partials::<R, E, Acc>(gamma_partials, gamma.shape);
+
+    (x_grad, gamma_grad)
+}
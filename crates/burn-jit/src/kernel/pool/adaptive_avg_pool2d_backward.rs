@@ -1,4 +1,4 @@
-use crate::{element::JitElement, tensor::JitTensor, JitRuntime};
+use crate::{element::JitElement, memory, tensor::JitTensor, JitRuntime};
 use cubecl::{calculate_cube_count_elemwise, prelude::*};
 
 #[cube(launch)]
@@ -86,7 +86,8 @@ pub(crate) fn adaptive_avg_pool2d_backward<R: JitRuntime, E: JitElement>(
 ) -> JitTensor<R> {
     let output_shape = x.shape.clone();
     let num_elems = output_shape.num_elements();
-    let output_buffer = x.client.empty(num_elems * core::mem::size_of::<E>());
+    let output_buffer =
+        memory::empty::<R>(&x.client, &x.device, num_elems * core::mem::size_of::<E>());
     let output = JitTensor::new_contiguous(
         x.client.clone(),
         x.device.clone(),
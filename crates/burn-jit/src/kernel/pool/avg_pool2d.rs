@@ -6,7 +6,7 @@ use burn_tensor::{ops::conv::calculate_pool_output_size, Shape};
 use cubecl::prelude::*;
 use cubecl::{calculate_cube_count_elemwise, prelude::ScalarArg, CubeDim};
 
-struct AvgPoolStrategy;
+pub(super) struct AvgPoolStrategy;
 
 impl Pool2dDirectStrategyFamily for AvgPoolStrategy {
     type Indices = ();
@@ -16,9 +16,9 @@ impl Pool2dDirectStrategyFamily for AvgPoolStrategy {
 
 #[derive(CubeType, Debug, PartialEq, Eq, Hash, Clone, Copy)]
 pub struct AvgPoolStrategyConfig {
-    kernel_size_h: u32,
-    kernel_size_w: u32,
-    count_include_pad: bool,
+    /// The number of elements of the pooling window.
+    pub(super) kernel_numel: u32,
+    pub(super) count_include_pad: bool,
 }
 
 #[cube]
@@ -30,7 +30,7 @@ impl<N: Numeric> Pool2dDirectStrategy<N> for AvgPoolStrategy {
     fn initialize(#[comptime] config: &Self::Config) -> Self::Accumulator {
         let sum = N::from_int(0);
         let count = comptime! {if config.count_include_pad {
-            config.kernel_size_h * config.kernel_size_w
+            config.kernel_numel
         } else {
             0u32
         }};
@@ -113,8 +113,7 @@ pub(crate) fn avg_pool2d<R: JitRuntime, E: JitElement>(
         ),
         (kernel_size[0] as u32, kernel_size[1] as u32),
         AvgPoolStrategyConfig {
-            kernel_size_h: kernel_size[0] as u32,
-            kernel_size_w: kernel_size[1] as u32,
+            kernel_numel: (kernel_size[0] * kernel_size[1]) as u32,
             count_include_pad,
         },
     );
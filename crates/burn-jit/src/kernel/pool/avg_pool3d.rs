@@ -0,0 +1,43 @@
+use super::{
+    avg_pool2d::{AvgPoolStrategy, AvgPoolStrategyConfig},
+    pool3d::{pool3d_args, pool3d_direct, pool3d_output_shape},
+};
+use crate::{element::JitElement, ops::numeric::empty_device, tensor::JitTensor, JitRuntime};
+use cubecl::{calculate_cube_count_elemwise, prelude::*, CubeDim};
+
+pub(crate) fn avg_pool3d<R: JitRuntime, E: JitElement>(
+    x: JitTensor<R>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    count_include_pad: bool,
+) -> JitTensor<R> {
+    let dilation = [1, 1, 1];
+
+    let shape_out = pool3d_output_shape(&x, kernel_size, stride, padding, dilation);
+    let output = empty_device::<R, E>(x.client.clone(), x.device.clone(), shape_out);
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(output.shape.num_elements(), cube_dim);
+
+    pool3d_direct::launch::<E, AvgPoolStrategy, R>(
+        &x.client,
+        cube_count,
+        cube_dim,
+        x.as_tensor_arg::<E>(1),
+        output.as_tensor_arg::<E>(1),
+        (),
+        pool3d_args(stride, padding, dilation),
+        (
+            kernel_size[0] as u32,
+            kernel_size[1] as u32,
+            kernel_size[2] as u32,
+        ),
+        AvgPoolStrategyConfig {
+            kernel_numel: kernel_size.iter().product::<usize>() as u32,
+            count_include_pad,
+        },
+    );
+
+    output
+}
@@ -5,8 +5,8 @@ use crate::{element::JitElement, ops::numeric::empty_device, tensor::JitTensor,
 use burn_tensor::{ops::conv::calculate_pool_output_size, Shape};
 use cubecl::{calculate_cube_count_elemwise, prelude::*, CubeDim};
 
-struct MaxPoolStrategy;
-struct MaxPoolWithIndicesStrategy;
+pub(super) struct MaxPoolStrategy;
+pub(super) struct MaxPoolWithIndicesStrategy;
 
 impl Pool2dDirectStrategyFamily for MaxPoolStrategy {
     type Indices = ();
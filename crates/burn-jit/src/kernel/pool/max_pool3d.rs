@@ -0,0 +1,71 @@
+use super::{
+    max_pool2d::{MaxPoolStrategy, MaxPoolWithIndicesStrategy},
+    pool3d::{pool3d_args, pool3d_direct, pool3d_output_shape},
+};
+use crate::{element::JitElement, ops::numeric::empty_device, tensor::JitTensor, JitRuntime};
+use cubecl::{calculate_cube_count_elemwise, prelude::*, CubeDim};
+
+pub(crate) fn max_pool3d<R: JitRuntime, E: JitElement>(
+    x: JitTensor<R>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    dilation: [usize; 3],
+) -> JitTensor<R> {
+    let shape_out = pool3d_output_shape(&x, kernel_size, stride, padding, dilation);
+    let output = empty_device::<R, E>(x.client.clone(), x.device.clone(), shape_out);
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(output.shape.num_elements(), cube_dim);
+
+    pool3d_direct::launch::<E, MaxPoolStrategy, R>(
+        &x.client,
+        cube_count,
+        cube_dim,
+        x.as_tensor_arg::<E>(1),
+        output.as_tensor_arg::<E>(1),
+        (),
+        pool3d_args(stride, padding, dilation),
+        (
+            kernel_size[0] as u32,
+            kernel_size[1] as u32,
+            kernel_size[2] as u32,
+        ),
+        (),
+    );
+
+    output
+}
+
+pub(crate) fn max_pool3d_with_indices<R: JitRuntime, E: JitElement, I: JitElement>(
+    x: JitTensor<R>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    dilation: [usize; 3],
+) -> (JitTensor<R>, JitTensor<R>) {
+    let shape_out = pool3d_output_shape(&x, kernel_size, stride, padding, dilation);
+    let output = empty_device::<R, E>(x.client.clone(), x.device.clone(), shape_out.clone());
+    let indices = empty_device::<R, I>(x.client.clone(), x.device.clone(), shape_out);
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(output.shape.num_elements(), cube_dim);
+
+    pool3d_direct::launch::<E, MaxPoolWithIndicesStrategy, R>(
+        &x.client,
+        cube_count,
+        cube_dim,
+        x.as_tensor_arg::<E>(1),
+        output.as_tensor_arg::<E>(1),
+        indices.as_tensor_arg::<I>(1),
+        pool3d_args(stride, padding, dilation),
+        (
+            kernel_size[0] as u32,
+            kernel_size[1] as u32,
+            kernel_size[2] as u32,
+        ),
+        (),
+    );
+
+    (output, indices)
+}
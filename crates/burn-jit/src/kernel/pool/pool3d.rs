@@ -0,0 +1,162 @@
+use burn_tensor::{ops::conv::calculate_pool_output_size, Shape};
+use cubecl::prelude::*;
+
+use super::pool2d::{Pool2dDirectStrategy, Pool2dDirectStrategyFamily};
+use crate::{tensor::JitTensor, JitRuntime};
+
+#[derive(CubeLaunch)]
+pub struct Pool3dDirectArgs {
+    pub strides_0: u32,
+    pub strides_1: u32,
+    pub strides_2: u32,
+    pub dilation_0: u32,
+    pub dilation_1: u32,
+    pub dilation_2: u32,
+    pub padding_0: u32,
+    pub padding_1: u32,
+    pub padding_2: u32,
+}
+
+/// Direct 3D pooling, reusing the [2D pooling strategies](Pool2dDirectStrategy) which don't depend
+/// on the number of dimensions.
+///
+/// The indices passed to the strategy are flattened over the depth, height and width of the input.
+#[cube(launch)]
+pub fn pool3d_direct<E: Numeric, S: Pool2dDirectStrategyFamily>(
+    input: &Tensor<E>,
+    output: &mut Tensor<E>,
+    indices: &mut S::Indices,
+    args: &Pool3dDirectArgs,
+    #[comptime] kernel_size: (u32, u32, u32),
+    #[comptime] config: &S::Config,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        terminate!();
+    }
+
+    let b = (ABSOLUTE_POS / output.stride(0)) % output.shape(0);
+    let c = (ABSOLUTE_POS / output.stride(1)) % output.shape(1);
+    let od = (ABSOLUTE_POS / output.stride(2)) % output.shape(2);
+    let oh = (ABSOLUTE_POS / output.stride(3)) % output.shape(3);
+    let ow = (ABSOLUTE_POS / output.stride(4)) % output.shape(4);
+
+    let (input_shape_2, input_shape_3, input_shape_4) =
+        (input.shape(2), input.shape(3), input.shape(4));
+
+    let mut accumulator = S::Pool2d::<E>::initialize(config);
+
+    let index_input_01 = b * input.stride(0) + c * input.stride(1);
+
+    let border_back = input_shape_2 + args.padding_0;
+    let border_bottom = input_shape_3 + args.padding_1;
+    let border_right = input_shape_4 + args.padding_2;
+
+    for kd in 0..kernel_size.0 {
+        let id = od * args.strides_0 + kd * args.dilation_0;
+
+        // Slices in the padding are skipped entirely.
+        if id >= args.padding_0 && id < border_back {
+            let id_pad = id - args.padding_0;
+            let index_input_2 = index_input_01 + id_pad * input.stride(2);
+
+            for kh in 0..kernel_size.1 {
+                let ih = oh * args.strides_1 + kh * args.dilation_1;
+                let within_padding_h = ih >= args.padding_1 && ih < border_bottom;
+
+                for kw in 0..kernel_size.2 {
+                    let iw = ow * args.strides_2 + kw * args.dilation_2;
+                    let within_padding_w = iw >= args.padding_2 && iw < border_right;
+
+                    if within_padding_h && within_padding_w {
+                        let ih_pad = ih - args.padding_1;
+                        let iw_pad = iw - args.padding_2;
+
+                        let index_input =
+                            index_input_2 + ih_pad * input.stride(3) + iw_pad * input.stride(4);
+                        let index_flat = (id_pad * input_shape_3 + ih_pad) * input_shape_4 + iw_pad;
+
+                        S::Pool2d::<E>::accumulate(
+                            config,
+                            &mut accumulator,
+                            index_flat,
+                            input[index_input],
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    S::Pool2d::<E>::store(config, ABSOLUTE_POS, output, indices, accumulator);
+}
+
+pub(super) fn pool3d_output_shape<R: JitRuntime>(
+    x: &JitTensor<R>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    dilation: [usize; 3],
+) -> Shape {
+    let [batch_size, channels, _, _, _] = x.shape.dims();
+    let size = |dim: usize| {
+        calculate_pool_output_size(
+            kernel_size[dim],
+            stride[dim],
+            padding[dim],
+            dilation[dim],
+            x.shape.dims[dim + 2],
+        )
+    };
+
+    Shape::new([batch_size, channels, size(0), size(1), size(2)])
+}
+
+pub(super) fn pool3d_args<R: JitRuntime>(
+    stride: [usize; 3],
+    padding: [usize; 3],
+    dilation: [usize; 3],
+) -> Pool3dDirectArgsLaunch<'static, R> {
+    Pool3dDirectArgsLaunch::new(
+        ScalarArg::new(stride[0] as u32),
+        ScalarArg::new(stride[1] as u32),
+        ScalarArg::new(stride[2] as u32),
+        ScalarArg::new(dilation[0] as u32),
+        ScalarArg::new(dilation[1] as u32),
+        ScalarArg::new(dilation[2] as u32),
+        ScalarArg::new(padding[0] as u32),
+        ScalarArg::new(padding[1] as u32),
+        ScalarArg::new(padding[2] as u32),
+    )
+}
+
+#[derive(CubeLaunch)]
+pub(crate) struct Pool3dBackwardArgs {
+    pub stride_0: i32,
+    pub stride_1: i32,
+    pub stride_2: i32,
+    pub dilation_0: i32,
+    pub dilation_1: i32,
+    pub dilation_2: i32,
+    pub padding_0: i32,
+    pub padding_1: i32,
+    pub padding_2: i32,
+}
+
+/// The range of the output positions whose window may contain the input position along one
+/// dimension.
+#[cube]
+pub(crate) fn loop_range(
+    index: i32,
+    grad_size: u32,
+    stride: i32,
+    dilation: i32,
+    padding: i32,
+    #[comptime] kernel_size: i32,
+) -> (u32, u32) {
+    let kms = dilation * kernel_size - stride;
+
+    let start = Max::max((index + padding - kms) / stride, 0) as u32;
+    let end = Min::min(Max::max(kms, 0) as u32 + start, grad_size - 1) + 1;
+
+    (start, end)
+}
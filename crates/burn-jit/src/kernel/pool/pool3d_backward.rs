@@ -0,0 +1,267 @@
+use crate::{
+    element::JitElement, kernel::into_contiguous, ops::numeric::empty_device, tensor::JitTensor,
+    IntElement, JitRuntime,
+};
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+
+use super::pool3d::{loop_range, Pool3dBackwardArgs, Pool3dBackwardArgsLaunch};
+
+#[cube(launch_unchecked)]
+fn max_pool3d_with_indices_backward_kernel<E: Numeric, I: Int>(
+    grad: &Tensor<E>,
+    indices: &Tensor<I>,
+    output: &mut Tensor<E>,
+    args: &Pool3dBackwardArgs,
+    #[comptime] kernel_size_0: i32,
+    #[comptime] kernel_size_1: i32,
+    #[comptime] kernel_size_2: i32,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        terminate!();
+    }
+
+    let batch = ABSOLUTE_POS / output.stride(0) % output.shape(0);
+    let channel = ABSOLUTE_POS / output.stride(1) % output.shape(1);
+    let id = ABSOLUTE_POS / output.stride(2) % output.shape(2);
+    let ih = ABSOLUTE_POS / output.stride(3) % output.shape(3);
+    let iw = ABSOLUTE_POS / output.stride(4) % output.shape(4);
+
+    let index_current = (id * output.shape(3) + ih) * output.shape(4) + iw;
+
+    let (od_start, od_end) = loop_range(
+        id as i32,
+        grad.shape(2),
+        args.stride_0,
+        args.dilation_0,
+        args.padding_0,
+        kernel_size_0,
+    );
+    let (oh_start, oh_end) = loop_range(
+        ih as i32,
+        grad.shape(3),
+        args.stride_1,
+        args.dilation_1,
+        args.padding_1,
+        kernel_size_1,
+    );
+    let (ow_start, ow_end) = loop_range(
+        iw as i32,
+        grad.shape(4),
+        args.stride_2,
+        args.dilation_2,
+        args.padding_2,
+        kernel_size_2,
+    );
+
+    let mut grad_acc = E::from_int(0);
+
+    let index_base = batch * grad.stride(0) + channel * grad.stride(1);
+
+    for od in od_start..od_end {
+        for oh in oh_start..oh_end {
+            for ow in ow_start..ow_end {
+                let index =
+                    index_base + od * grad.stride(2) + oh * grad.stride(3) + ow * grad.stride(4);
+                let index_max = u32::cast_from(indices[index]);
+
+                grad_acc += select(index_max == index_current, grad[index], E::from_int(0));
+            }
+        }
+    }
+
+    output[ABSOLUTE_POS] = grad_acc;
+}
+
+#[cube(launch_unchecked)]
+fn avg_pool3d_backward_kernel<E: Numeric>(
+    grad: &Tensor<E>,
+    output: &mut Tensor<E>,
+    args: &Pool3dBackwardArgs,
+    #[comptime] kernel_size_0: i32,
+    #[comptime] kernel_size_1: i32,
+    #[comptime] kernel_size_2: i32,
+    #[comptime] count_include_pad: bool,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        terminate!();
+    }
+
+    let batch = ABSOLUTE_POS / output.stride(0) % output.shape(0);
+    let channel = ABSOLUTE_POS / output.stride(1) % output.shape(1);
+    let id = ABSOLUTE_POS / output.stride(2) % output.shape(2);
+    let ih = ABSOLUTE_POS / output.stride(3) % output.shape(3);
+    let iw = ABSOLUTE_POS / output.stride(4) % output.shape(4);
+
+    let (od_start, od_end) = loop_range(
+        id as i32,
+        grad.shape(2),
+        args.stride_0,
+        1,
+        args.padding_0,
+        kernel_size_0,
+    );
+    let (oh_start, oh_end) = loop_range(
+        ih as i32,
+        grad.shape(3),
+        args.stride_1,
+        1,
+        args.padding_1,
+        kernel_size_1,
+    );
+    let (ow_start, ow_end) = loop_range(
+        iw as i32,
+        grad.shape(4),
+        args.stride_2,
+        1,
+        args.padding_2,
+        kernel_size_2,
+    );
+
+    let padding_0 = args.padding_0 as u32;
+    let padding_1 = args.padding_1 as u32;
+    let padding_2 = args.padding_2 as u32;
+    let stride_0 = args.stride_0 as u32;
+    let stride_1 = args.stride_1 as u32;
+    let stride_2 = args.stride_2 as u32;
+    let kernel_size_0 = comptime![kernel_size_0 as u32];
+    let kernel_size_1 = comptime![kernel_size_1 as u32];
+    let kernel_size_2 = comptime![kernel_size_2 as u32];
+
+    let index_base = batch * grad.stride(0) + channel * grad.stride(1);
+
+    // Positions in the padded input.
+    let pos_d = id + padding_0;
+    let pos_h = ih + padding_1;
+    let pos_w = iw + padding_2;
+    let border_back = output.shape(2) + padding_0;
+    let border_bottom = output.shape(3) + padding_1;
+    let border_right = output.shape(4) + padding_2;
+
+    let mut grad_acc = E::from_int(0);
+
+    for od in od_start..od_end {
+        let d_start = od * stride_0;
+        let d_end = d_start + kernel_size_0;
+
+        if pos_d >= d_start && pos_d < d_end {
+            let d_count = Min::min(d_end, border_back) - Max::max(d_start, padding_0);
+
+            for oh in oh_start..oh_end {
+                let h_start = oh * stride_1;
+                let h_end = h_start + kernel_size_1;
+
+                if pos_h >= h_start && pos_h < h_end {
+                    let h_count = Min::min(h_end, border_bottom) - Max::max(h_start, padding_1);
+
+                    for ow in ow_start..ow_end {
+                        let w_start = ow * stride_2;
+                        let w_end = w_start + kernel_size_2;
+
+                        if pos_w >= w_start && pos_w < w_end {
+                            let index = index_base
+                                + od * grad.stride(2)
+                                + oh * grad.stride(3)
+                                + ow * grad.stride(4);
+
+                            if count_include_pad {
+                                let count = kernel_size_0 * kernel_size_1 * kernel_size_2;
+                                grad_acc += grad[index] / E::cast_from(count);
+                            } else {
+                                let w_count =
+                                    Min::min(w_end, border_right) - Max::max(w_start, padding_2);
+                                let count = d_count * h_count * w_count;
+                                grad_acc += grad[index] / E::cast_from(count);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    output[ABSOLUTE_POS] = grad_acc;
+}
+
+fn backward_args<R: JitRuntime>(
+    stride: [usize; 3],
+    padding: [usize; 3],
+    dilation: [usize; 3],
+) -> Pool3dBackwardArgsLaunch<'static, R> {
+    Pool3dBackwardArgsLaunch::new(
+        ScalarArg::new(stride[0] as i32),
+        ScalarArg::new(stride[1] as i32),
+        ScalarArg::new(stride[2] as i32),
+        ScalarArg::new(dilation[0] as i32),
+        ScalarArg::new(dilation[1] as i32),
+        ScalarArg::new(dilation[2] as i32),
+        ScalarArg::new(padding[0] as i32),
+        ScalarArg::new(padding[1] as i32),
+        ScalarArg::new(padding[2] as i32),
+    )
+}
+
+pub(crate) fn max_pool3d_with_indices_backward<R: JitRuntime, E: JitElement, I: IntElement>(
+    x: JitTensor<R>,
+    grad: JitTensor<R>,
+    indices: JitTensor<R>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    dilation: [usize; 3],
+) -> JitTensor<R> {
+    let grad = into_contiguous(grad);
+    let indices = into_contiguous(indices);
+
+    let output = empty_device::<R, E>(x.client.clone(), x.device.clone(), x.shape.clone());
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(output.shape.num_elements(), cube_dim);
+
+    unsafe {
+        max_pool3d_with_indices_backward_kernel::launch_unchecked::<E, I, R>(
+            &x.client,
+            cube_count,
+            cube_dim,
+            grad.as_tensor_arg::<E>(1),
+            indices.as_tensor_arg::<I>(1),
+            output.as_tensor_arg::<E>(1),
+            backward_args(stride, padding, dilation),
+            kernel_size[0] as i32,
+            kernel_size[1] as i32,
+            kernel_size[2] as i32,
+        )
+    };
+
+    output
+}
+
+pub(crate) fn avg_pool3d_backward<R: JitRuntime, E: JitElement>(
+    x: JitTensor<R>,
+    grad: JitTensor<R>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    count_include_pad: bool,
+) -> JitTensor<R> {
+    let grad = into_contiguous(grad);
+
+    let output = empty_device::<R, E>(x.client.clone(), x.device.clone(), x.shape.clone());
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(output.shape.num_elements(), cube_dim);
+
+    unsafe {
+        avg_pool3d_backward_kernel::launch_unchecked::<E, R>(
+            &grad.client,
+            cube_count,
+            cube_dim,
+            grad.as_tensor_arg::<E>(1),
+            output.as_tensor_arg::<E>(1),
+            backward_args(stride, padding, [1, 1, 1]),
+            kernel_size[0] as i32,
+            kernel_size[1] as i32,
+            kernel_size[2] as i32,
+            count_include_pad,
+        )
+    };
+
+    output
+}
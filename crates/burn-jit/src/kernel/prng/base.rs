@@ -1,7 +1,8 @@
 use cubecl::prelude::*;
+use std::f32::consts::PI;
 
-use crate::{ops::numeric::empty_device, tensor::JitTensor, JitElement, JitRuntime, SEED};
-use burn_common::rand::get_seeded_rng;
+use crate::{ops::numeric::empty_device, tensor::JitTensor, JitElement, JitRuntime, RNG_STATE};
+use burn_tensor::backend::RngState;
 use burn_tensor::Shape;
 use rand::Rng;
 
@@ -47,16 +48,12 @@ fn prng_cube_count(num_elems: usize, cube_dim: CubeDim, n_values_per_thread: usi
 }
 
 pub(crate) fn get_seeds() -> [u32; 4] {
-    let mut seed = SEED.lock().unwrap();
-    let mut rng = match seed.as_ref() {
-        Some(rng_seeded) => rng_seeded.clone(),
-        None => get_seeded_rng(),
-    };
+    let mut state = RNG_STATE.lock().unwrap();
+    let mut rng = state.get_or_insert_with(RngState::from_entropy).next_rng();
     let mut seeds: Vec<u32> = Vec::with_capacity(4);
     for _ in 0..4 {
         seeds.push(rng.gen());
     }
-    *seed = Some(rng);
 
     seeds.try_into().unwrap()
 }
@@ -157,6 +154,38 @@ pub(crate) fn cast_uint_to_float(int_random: u32) -> f32 {
     f32::cast_from(int_random) * tmp
 }
 
+/// Draws a uniform value in `[0, 1]`, advancing the state of the generator.
+#[cube]
+pub(crate) fn next_uniform(
+    state_0: &mut u32,
+    state_1: &mut u32,
+    state_2: &mut u32,
+    state_3: &mut u32,
+) -> f32 {
+    *state_0 = taus_step_0(*state_0);
+    *state_1 = taus_step_1(*state_1);
+    *state_2 = taus_step_2(*state_2);
+    *state_3 = lcg_step(*state_3);
+
+    cast_uint_to_float(*state_0 ^ *state_1 ^ *state_2 ^ *state_3)
+}
+
+/// Draws a standard normal value with the Box-Muller transform, advancing the state of the
+/// generator.
+#[cube]
+pub(crate) fn next_normal(
+    state_0: &mut u32,
+    state_1: &mut u32,
+    state_2: &mut u32,
+    state_3: &mut u32,
+) -> f32 {
+    let unit_0 = next_uniform(state_0, state_1, state_2, state_3);
+    let unit_1 = next_uniform(state_0, state_1, state_2, state_3);
+
+    let coeff = Sqrt::sqrt(Log::log(unit_0) * -2.0);
+    f32::cos(2.0 * PI * unit_1) * coeff
+}
+
 #[allow(missing_docs)]
 pub mod tests_utils {
     use burn_tensor::Element;
@@ -0,0 +1,60 @@
+use burn_tensor::Shape;
+use cubecl::prelude::*;
+
+use crate::{kernel::prng::next_uniform, tensor::JitTensor, JitElement, JitRuntime};
+
+use super::{random, PrngArgs, PrngRuntime};
+
+#[derive(CubeLaunch)]
+pub(crate) struct Exponential<E: Numeric> {
+    rate: E,
+}
+
+#[cube]
+impl<E: JitElement> PrngRuntime<E> for Exponential<E> {
+    fn inner_loop(
+        args: Exponential<E>,
+        write_index_base: u32,
+        n_invocations: u32,
+        #[comptime] n_values_per_thread: u32,
+        state_0: &mut u32,
+        state_1: &mut u32,
+        state_2: &mut u32,
+        state_3: &mut u32,
+        output: &mut Tensor<E>,
+    ) {
+        let scale = -1.0 / f32::cast_from(args.rate);
+
+        let should_unroll = n_values_per_thread <= 8;
+
+        #[unroll(should_unroll)]
+        for i in 0..n_values_per_thread {
+            let unit = next_uniform(state_0, state_1, state_2, state_3);
+
+            // Inverse transform sampling, zero is excluded so the log is finite
+            let unit = f32::max(unit, 2.328_306_4e-10f32);
+            let exponential = Log::log(unit) * scale;
+
+            let write_index = i * n_invocations + write_index_base;
+
+            output[write_index] = E::cast_from(exponential);
+        }
+    }
+}
+
+impl<E: JitElement> PrngArgs<E> for Exponential<E> {
+    type Args = Self;
+
+    fn args<'a, R: Runtime>(self) -> ExponentialLaunch<'a, E, R> {
+        ExponentialLaunch::new(ScalarArg::new(self.rate))
+    }
+}
+
+/// Pseudo-random generator with exponential distribution
+pub fn random_exponential<R: JitRuntime, E: JitElement>(
+    shape: Shape,
+    device: &R::Device,
+    rate: E,
+) -> JitTensor<R> {
+    random(shape, device, Exponential { rate })
+}
@@ -0,0 +1,100 @@
+use burn_tensor::Shape;
+use cubecl::prelude::*;
+
+use crate::{
+    kernel::prng::{next_normal, next_uniform},
+    tensor::JitTensor,
+    JitElement, JitRuntime,
+};
+
+use super::{random, PrngArgs, PrngRuntime};
+
+#[derive(CubeLaunch)]
+pub(crate) struct Gamma<E: Numeric> {
+    shape: E,
+    scale: E,
+}
+
+#[cube]
+impl<E: JitElement> PrngRuntime<E> for Gamma<E> {
+    fn inner_loop(
+        args: Gamma<E>,
+        write_index_base: u32,
+        n_invocations: u32,
+        #[comptime] n_values_per_thread: u32,
+        state_0: &mut u32,
+        state_1: &mut u32,
+        state_2: &mut u32,
+        state_3: &mut u32,
+        output: &mut Tensor<E>,
+    ) {
+        let shape = f32::cast_from(args.shape);
+        let scale = f32::cast_from(args.scale);
+
+        // Marsaglia and Tsang's method requires a shape of at least one, smaller shapes are
+        // boosted with `gamma(shape) = gamma(shape + 1) * u^(1 / shape)`.
+        let boost = shape < 1.0;
+        let mut shape_boosted = shape;
+        if boost {
+            shape_boosted = shape + 1.0;
+        }
+
+        let d = shape_boosted - 1.0 / 3.0;
+        let c = 1.0 / Sqrt::sqrt(9.0 * d);
+
+        for i in 0..n_values_per_thread {
+            let mut gamma = 0.0f32;
+
+            // Rejection sampling, accepting more than 95% of the candidates for any shape
+            loop {
+                let normal = next_normal(state_0, state_1, state_2, state_3);
+                let v = 1.0 + c * normal;
+
+                if v > 0.0 {
+                    let v_cubed = v * v * v;
+                    let unit = next_uniform(state_0, state_1, state_2, state_3);
+                    let bound = 0.5 * normal * normal + d - d * v_cubed + d * Log::log(v_cubed);
+
+                    if Log::log(unit) < bound {
+                        gamma = d * v_cubed;
+                        break;
+                    }
+                }
+            }
+
+            if boost {
+                let unit = next_uniform(state_0, state_1, state_2, state_3);
+                gamma *= f32::powf(unit, 1.0 / shape);
+            }
+
+            let write_index = i * n_invocations + write_index_base;
+
+            output[write_index] = E::cast_from(gamma * scale);
+        }
+    }
+}
+
+impl<E: JitElement> PrngArgs<E> for Gamma<E> {
+    type Args = Self;
+
+    fn args<'a, R: Runtime>(self) -> GammaLaunch<'a, E, R> {
+        GammaLaunch::new(ScalarArg::new(self.shape), ScalarArg::new(self.scale))
+    }
+}
+
+/// Pseudo-random generator with gamma distribution
+pub fn random_gamma<R: JitRuntime, E: JitElement>(
+    shape: Shape,
+    device: &R::Device,
+    gamma_shape: E,
+    scale: E,
+) -> JitTensor<R> {
+    random(
+        shape,
+        device,
+        Gamma {
+            shape: gamma_shape,
+            scale,
+        },
+    )
+}
@@ -1,9 +1,15 @@
 mod base;
 mod bernoulli;
+mod exponential;
+mod gamma;
 mod normal;
+mod poisson;
 mod uniform;
 
 pub use base::*;
 pub use bernoulli::*;
+pub use exponential::*;
+pub use gamma::*;
 pub use normal::*;
+pub use poisson::*;
 pub use uniform::*;
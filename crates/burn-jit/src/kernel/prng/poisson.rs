@@ -0,0 +1,135 @@
+use burn_tensor::Shape;
+use cubecl::prelude::*;
+
+use crate::{kernel::prng::next_uniform, tensor::JitTensor, JitElement, JitRuntime};
+
+use super::{random, PrngArgs, PrngRuntime};
+
+#[derive(CubeLaunch)]
+pub(crate) struct Poisson<E: Numeric> {
+    rate: E,
+}
+
+#[cube]
+impl<E: JitElement> PrngRuntime<E> for Poisson<E> {
+    fn inner_loop(
+        args: Poisson<E>,
+        write_index_base: u32,
+        n_invocations: u32,
+        #[comptime] n_values_per_thread: u32,
+        state_0: &mut u32,
+        state_1: &mut u32,
+        state_2: &mut u32,
+        state_3: &mut u32,
+        output: &mut Tensor<E>,
+    ) {
+        let rate = f32::cast_from(args.rate);
+
+        for i in 0..n_values_per_thread {
+            let mut poisson = 0.0f32;
+
+            if rate < 10.0 {
+                poisson = poisson_multiplication(rate, state_0, state_1, state_2, state_3);
+            } else {
+                poisson = poisson_ptrs(rate, state_0, state_1, state_2, state_3);
+            }
+
+            let write_index = i * n_invocations + write_index_base;
+
+            output[write_index] = E::cast_from(poisson);
+        }
+    }
+}
+
+/// Knuth's method, counting the uniform values multiplied before their product drops below
+/// `exp(-rate)`, which takes `rate + 1` draws on average.
+#[cube]
+fn poisson_multiplication(
+    rate: f32,
+    state_0: &mut u32,
+    state_1: &mut u32,
+    state_2: &mut u32,
+    state_3: &mut u32,
+) -> f32 {
+    let limit = f32::exp(rate * -1.0);
+    let mut count = 0.0f32;
+    let mut product = next_uniform(state_0, state_1, state_2, state_3);
+
+    loop {
+        if product <= limit {
+            break;
+        }
+
+        count += 1.0;
+        product *= next_uniform(state_0, state_1, state_2, state_3);
+    }
+
+    count
+}
+
+/// Hörmann's transformed rejection with squeeze (PTRS), for rates of at least 10.
+#[cube]
+fn poisson_ptrs(
+    rate: f32,
+    state_0: &mut u32,
+    state_1: &mut u32,
+    state_2: &mut u32,
+    state_3: &mut u32,
+) -> f32 {
+    let log_rate = Log::log(rate);
+    let b = 0.931 + 2.53 * Sqrt::sqrt(rate);
+    let a = -0.059 + 0.02483 * b;
+    let log_inv_alpha = Log::log(1.1239 + 1.1328 / (b - 3.4));
+    let v_r = 0.9277 - 3.6224 / (b - 2.0);
+    let mut poisson = 0.0f32;
+
+    loop {
+        let u = next_uniform(state_0, state_1, state_2, state_3) - 0.5;
+        let v = next_uniform(state_0, state_1, state_2, state_3);
+        let us = 0.5 - f32::abs(u);
+        let k = f32::floor((2.0 * a / us + b) * u + rate + 0.43);
+
+        // Squeeze, accepting most candidates without computing the density
+        if us >= 0.07 && v <= v_r {
+            poisson = k;
+            break;
+        }
+
+        if k >= 0.0 && (us >= 0.013 || v <= us) {
+            let log_v = Log::log(v) + log_inv_alpha - Log::log(a / (us * us) + b);
+
+            if log_v <= k * log_rate - rate - log_factorial(k) {
+                poisson = k;
+                break;
+            }
+        }
+    }
+
+    poisson
+}
+
+/// The Stirling series of `ln(k!)`, accurate to `2e-3` for any non-negative integer `k`.
+#[cube]
+fn log_factorial(k: f32) -> f32 {
+    let x = k + 1.0;
+    let x_inv = 1.0 / x;
+
+    (x - 0.5) * Log::log(x) - x + 0.918_938_5 + x_inv * (1.0 / 12.0 - x_inv * x_inv / 360.0)
+}
+
+impl<E: JitElement> PrngArgs<E> for Poisson<E> {
+    type Args = Self;
+
+    fn args<'a, R: Runtime>(self) -> PoissonLaunch<'a, E, R> {
+        PoissonLaunch::new(ScalarArg::new(self.rate))
+    }
+}
+
+/// Pseudo-random generator with Poisson distribution
+pub fn random_poisson<R: JitRuntime, E: JitElement>(
+    shape: Shape,
+    device: &R::Device,
+    rate: E,
+) -> JitTensor<R> {
+    random(shape, device, Poisson { rate })
+}
@@ -1,3 +1,4 @@
+use crate::memory;
 use crate::tensor::JitTensor;
 use crate::FloatElement;
 use crate::{JitElement, JitRuntime};
@@ -107,6 +108,79 @@ pub(crate) fn dequantize_per_tensor_symmetric_int8_kernel(
     }
 }
 
+#[cube]
+pub(crate) fn extract_sub_byte(value: u32, index: u32, #[comptime] bits: u32) -> i32 {
+    // Move the segment to the most significant bits, then shift it back to extend the sign
+    let shift = comptime!(32 - bits);
+    i32::bitcast_from(value << (shift - index * bits)) >> shift
+}
+
+/// Dequantize the sub-byte values, one output value per invocation.
+#[cube(launch_unchecked)]
+pub(crate) fn dequantize_per_tensor_sub_byte_kernel(
+    input: &QTensor,
+    output: &mut Tensor<f32>,
+    #[comptime] scheme: QuantizationScheme,
+    #[comptime] bits: u32,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        terminate!();
+    }
+
+    let qparams = QParams::new(scheme);
+    let (scale, offset) = qparams.values(input);
+
+    let num_packed = comptime!(32 / bits);
+    // Input line size is fixed to 1
+    let value = input[ABSOLUTE_POS / num_packed][0];
+    let value = extract_sub_byte(value, ABSOLUTE_POS % num_packed, bits);
+
+    // x = scale * (x_q - offset), where the offset is zero for symmetric quantization
+    output[ABSOLUTE_POS] = scale * f32::cast_from(value - offset);
+}
+
+pub(crate) fn dequantize_per_tensor_sub_byte<R, F>(
+    tensor: JitTensor<R>,
+    scheme: QuantizationScheme,
+) -> JitTensor<R>
+where
+    R: JitRuntime,
+    F: JitElement,
+{
+    let num_out_elems = tensor.shape.num_elements();
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(num_out_elems, cube_dim);
+
+    let client = tensor.client.clone();
+    let handle = memory::empty::<R>(
+        &client,
+        &tensor.device,
+        num_out_elems * core::mem::size_of::<F>(),
+    );
+
+    let output = JitTensor::new_contiguous(
+        client.clone(),
+        tensor.device.clone(),
+        tensor.shape.clone(),
+        handle,
+        F::dtype(),
+    );
+
+    unsafe {
+        dequantize_per_tensor_sub_byte_kernel::launch_unchecked::<R>(
+            &client,
+            cube_count,
+            cube_dim,
+            tensor.as_array_arg::<u32>(1),
+            output.as_tensor_arg::<F>(1),
+            scheme,
+            scheme.q_type().bits() as u32,
+        )
+    };
+
+    output
+}
+
 pub(crate) fn dequantize_per_tensor<R, F>(tensor: JitTensor<R>) -> JitTensor<R>
 where
     R: JitRuntime,
@@ -122,7 +196,11 @@ where
     let cube_count = calculate_cube_count_elemwise(num_elems / line_size_in as usize, cube_dim);
 
     let client = tensor.client.clone();
-    let handle = client.empty(num_out_elems * core::mem::size_of::<F>());
+    let handle = memory::empty::<R>(
+        &client,
+        &tensor.device,
+        num_out_elems * core::mem::size_of::<F>(),
+    );
 
     let output = JitTensor::new_contiguous(
         client.clone(),
@@ -158,6 +236,7 @@ where
                     )
                 };
             }
+            _ => unreachable!("Sub-byte values are dequantized by a dedicated kernel"),
         }
     }
 
@@ -170,5 +249,10 @@ where
     R: JitRuntime,
     F: FloatElement,
 {
-    dequantize_per_tensor::<R, F>(tensor)
+    match tensor.dtype {
+        DType::QFloat(scheme) if scheme.q_type() != QuantizationType::QInt8 => {
+            dequantize_per_tensor_sub_byte::<R, F>(tensor, scheme)
+        }
+        _ => dequantize_per_tensor::<R, F>(tensor),
+    }
 }
@@ -1,7 +1,9 @@
 mod dequantize;
+mod packed;
 mod qtensor;
 mod quantize;
 
 pub use dequantize::*;
+pub(crate) use packed::*;
 pub use qtensor::*;
 pub use quantize::*;
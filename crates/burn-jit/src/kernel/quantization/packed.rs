@@ -0,0 +1,261 @@
+use std::ops::Range;
+
+use burn_tensor::{quantization::QuantizationScheme, DType, Shape};
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+
+use crate::{kernel::into_contiguous, memory, tensor::JitTensor, IntElement, JitRuntime};
+
+/// Read the quantized value at the logical position `index` of the packed values.
+#[cube]
+fn read_packed(input: &Tensor<u32>, index: u32, #[comptime] bits: u32) -> u32 {
+    let num_packed = comptime!(32 / bits);
+    let mask = comptime!(((1u64 << bits) - 1) as u32);
+
+    (input[index / num_packed] >> ((index % num_packed) * bits)) & mask
+}
+
+/// Copy the quantization parameters at the end of the input buffer, returns `true` if the output
+/// position is one of them.
+#[cube]
+fn copy_qparams(input: &Tensor<u32>, output: &mut Tensor<u32>, num_words: u32) -> bool {
+    let is_qparam = ABSOLUTE_POS >= num_words;
+
+    if is_qparam {
+        output[ABSOLUTE_POS] = input[input.len() - (output.len() - ABSOLUTE_POS)];
+    }
+
+    is_qparam
+}
+
+/// Slice the packed values, one output `u32` per invocation.
+#[cube(launch_unchecked)]
+fn slice_packed_kernel(
+    input: &Tensor<u32>,
+    output: &mut Tensor<u32>,
+    indices: Sequence<u32>,
+    num_values: u32,
+    #[comptime] rank: u32,
+    #[comptime] bits: u32,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        terminate!();
+    }
+
+    let num_packed = comptime!(32 / bits);
+
+    if copy_qparams(input, output, (num_values + num_packed - 1) / num_packed) {
+        terminate!();
+    }
+
+    let mut v_packed = 0;
+
+    #[unroll]
+    for i in 0..num_packed {
+        let index = ABSOLUTE_POS * num_packed + i;
+
+        // The padding after the last value is left to zero
+        if index < num_values {
+            let mut offset_input = 0;
+
+            #[unroll]
+            for d in 0..rank {
+                let range_start = *indices.index(d);
+                let offset_local = index / output.stride(d) % output.shape(d) + range_start;
+
+                offset_input += offset_local * input.stride(d);
+            }
+
+            v_packed |= read_packed(input, offset_input, bits) << (i * bits);
+        }
+    }
+
+    output[ABSOLUTE_POS] = v_packed;
+}
+
+/// Gather the packed values along `dim`, one output `u32` per invocation.
+///
+/// The indices have the same shape as the output, or only index `dim` when selecting.
+#[cube(launch_unchecked)]
+fn gather_packed_kernel<I: Numeric>(
+    input: &Tensor<u32>,
+    indices: &Tensor<I>,
+    output: &mut Tensor<u32>,
+    dim: u32,
+    num_values: u32,
+    #[comptime] rank: u32,
+    #[comptime] bits: u32,
+    #[comptime] select: bool,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        terminate!();
+    }
+
+    let num_packed = comptime!(32 / bits);
+
+    if copy_qparams(input, output, (num_values + num_packed - 1) / num_packed) {
+        terminate!();
+    }
+
+    let mut v_packed = 0;
+
+    #[unroll]
+    for i in 0..num_packed {
+        let index = ABSOLUTE_POS * num_packed + i;
+
+        // The padding after the last value is left to zero
+        if index < num_values {
+            let mut offset_input = 0;
+
+            #[unroll]
+            for d in 0..rank {
+                let mut offset_local = index / output.stride(d) % output.shape(d);
+
+                if d == dim {
+                    if comptime![select] {
+                        offset_local = u32::cast_from(indices[offset_local]);
+                    } else {
+                        offset_local = u32::cast_from(indices[index]);
+                    }
+                }
+
+                offset_input += offset_local * input.stride(d);
+            }
+
+            v_packed |= read_packed(input, offset_input, bits) << (i * bits);
+        }
+    }
+
+    output[ABSOLUTE_POS] = v_packed;
+}
+
+/// The number of `u32` quantization parameters appended to the packed values.
+fn num_qparams(scheme: &QuantizationScheme) -> usize {
+    match scheme {
+        QuantizationScheme::PerTensorAffine(_) => 2,
+        QuantizationScheme::PerTensorSymmetric(_) => 1,
+    }
+}
+
+/// Create an empty quantized tensor, with room for the packed values and quantization parameters.
+fn empty_packed<R: JitRuntime>(
+    tensor: &JitTensor<R>,
+    shape: Shape,
+    scheme: QuantizationScheme,
+) -> JitTensor<R> {
+    let num_words = usize::div_ceil(shape.num_elements(), scheme.q_type().num_packed());
+    let size = (num_words + num_qparams(&scheme)) * core::mem::size_of::<u32>();
+    let handle = memory::empty::<R>(&tensor.client, &tensor.device, size);
+
+    JitTensor::new_contiguous(
+        tensor.client.clone(),
+        tensor.device.clone(),
+        shape,
+        handle,
+        DType::QFloat(scheme),
+    )
+}
+
+fn qfloat_scheme<R: JitRuntime>(tensor: &JitTensor<R>) -> QuantizationScheme {
+    match tensor.dtype {
+        DType::QFloat(scheme) => scheme,
+        _ => panic!(
+            "Invalid dtype (expected DType::QFloat, got {:?})",
+            tensor.dtype
+        ),
+    }
+}
+
+/// Slice a quantized tensor, repacking the values of the slice.
+pub(crate) fn slice_packed<R: JitRuntime>(
+    tensor: JitTensor<R>,
+    ranges: &[Range<usize>],
+) -> JitTensor<R> {
+    let scheme = qfloat_scheme(&tensor);
+    let ndims = tensor.shape.num_dims();
+    let mut dims = tensor.shape.dims.clone();
+    let mut indices_sequence = SequenceArg::<R, u32>::new();
+
+    for i in 0..ndims {
+        let start = ranges.get(i).map(|range| range.start).unwrap_or(0);
+        indices_sequence.push(ScalarArg::new(start as u32));
+
+        if let Some(range) = ranges.get(i) {
+            dims[i] = range.end - range.start;
+        }
+    }
+
+    let output = empty_packed(&tensor, Shape::from(dims), scheme);
+    let num_values = output.shape.num_elements();
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(
+        num_values.div_ceil(scheme.q_type().num_packed()) + num_qparams(&scheme),
+        cube_dim,
+    );
+
+    unsafe {
+        slice_packed_kernel::launch_unchecked::<R>(
+            &tensor.client,
+            cube_count,
+            cube_dim,
+            tensor.as_tensor_arg::<u32>(1),
+            output.as_tensor_arg::<u32>(1),
+            indices_sequence,
+            ScalarArg::new(num_values as u32),
+            ndims as u32,
+            scheme.q_type().bits() as u32,
+        )
+    };
+
+    output
+}
+
+/// Gather the values of a quantized tensor along `dim`, repacking the gathered values.
+///
+/// When selecting, the indices only index `dim` and the other dimensions are kept.
+pub(crate) fn gather_packed<R: JitRuntime, I: IntElement>(
+    dim: usize,
+    tensor: JitTensor<R>,
+    indices: JitTensor<R>,
+    select: bool,
+) -> JitTensor<R> {
+    let scheme = qfloat_scheme(&tensor);
+    let ndims = tensor.shape.num_dims();
+    let indices = into_contiguous(indices);
+
+    let shape_output = match select {
+        true => {
+            let mut dims = tensor.shape.dims.clone();
+            dims[dim] = indices.shape.num_elements();
+            Shape::from(dims)
+        }
+        false => indices.shape.clone(),
+    };
+
+    let output = empty_packed(&tensor, shape_output, scheme);
+    let num_values = output.shape.num_elements();
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(
+        num_values.div_ceil(scheme.q_type().num_packed()) + num_qparams(&scheme),
+        cube_dim,
+    );
+
+    unsafe {
+        gather_packed_kernel::launch_unchecked::<I, R>(
+            &tensor.client,
+            cube_count,
+            cube_dim,
+            tensor.as_tensor_arg::<u32>(1),
+            indices.as_tensor_arg::<I>(1),
+            output.as_tensor_arg::<u32>(1),
+            ScalarArg::new(dim as u32),
+            ScalarArg::new(num_values as u32),
+            ndims as u32,
+            scheme.q_type().bits() as u32,
+            select,
+        )
+    };
+
+    output
+}
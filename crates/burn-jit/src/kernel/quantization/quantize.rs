@@ -1,3 +1,4 @@
+use crate::memory;
 use crate::tensor::JitTensor;
 use crate::FloatElement;
 use crate::{IntElement, JitElement, JitRuntime};
@@ -181,8 +182,11 @@ where
     let dummy_array = vec![1; ndims];
     if let Some(offset) = offset {
         // Scale and offset qparams are also packed in the tensor dat
-        let handle = client
-            .empty(output_num_elems + core::mem::size_of::<f32>() + core::mem::size_of::<i32>());
+        let handle = memory::empty::<R>(
+            &client,
+            &tensor.device,
+            output_num_elems + core::mem::size_of::<f32>() + core::mem::size_of::<i32>(),
+        );
         let output = JitTensor::new_contiguous(
             client.clone(),
             tensor.device.clone(),
@@ -208,7 +212,11 @@ where
         output
     } else {
         // Scale qparam is also packed in the tensor data
-        let handle = client.empty(output_num_elems + core::mem::size_of::<f32>());
+        let handle = memory::empty::<R>(
+            &client,
+            &tensor.device,
+            output_num_elems + core::mem::size_of::<f32>(),
+        );
         let output = JitTensor::new_contiguous(
             client.clone(),
             tensor.device.clone(),
@@ -235,6 +243,130 @@ where
     }
 }
 
+/// Quantize the sub-byte values packed into a `u32`, one output value per invocation.
+#[cube(launch_unchecked)]
+pub(crate) fn quantize_per_tensor_sub_byte_kernel(
+    input: &Tensor<f32>,
+    scale: &Tensor<f32>,
+    offset: &Tensor<i32>,
+    range_min: f32,
+    range_max: f32,
+    output: &mut Array<u32>,
+    #[comptime] affine: bool,
+    #[comptime] bits: u32,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        terminate!();
+    }
+
+    let scale = scale[0];
+
+    // Cast the scale to u32 and write the value in the output
+    if ABSOLUTE_POS == output.len() - 1 {
+        output[ABSOLUTE_POS] = u32::bitcast_from(scale);
+        terminate!();
+    }
+
+    let mut zero_point = 0;
+
+    if comptime![affine] {
+        zero_point = offset[0];
+
+        // Cast the offset to u32 and write the value in the output
+        if ABSOLUTE_POS == output.len() - 2 {
+            output[ABSOLUTE_POS] = u32::bitcast_from(zero_point);
+            terminate!();
+        }
+    }
+
+    let num_packed = comptime!(32 / bits);
+    let mask = comptime!((1u32 << bits) - 1);
+    let mut v_packed = 0;
+
+    #[unroll]
+    for i in 0..num_packed {
+        let index = ABSOLUTE_POS * num_packed + i;
+
+        // The padding after the last value is left to zero
+        if index < input.len() {
+            // x_q = clamp(round(x / scale + offset), a, b)
+            let value = Round::round(input[index] / scale + f32::cast_from(zero_point));
+            let value = i32::cast_from(Min::min(Max::max(value, range_min), range_max));
+            // Shift and combine into u32
+            v_packed |= (u32::bitcast_from(value) & mask) << (i * bits);
+        }
+    }
+
+    output[ABSOLUTE_POS] = v_packed;
+}
+
+pub(crate) fn quantize_per_tensor_sub_byte<R, F, I>(
+    tensor: JitTensor<R>,
+    scale: JitTensor<R>,
+    offset: Option<JitTensor<R>>,
+    scheme: QuantizationScheme,
+) -> JitTensor<R>
+where
+    R: JitRuntime,
+    F: JitElement,
+    I: IntElement,
+{
+    let q_type = scheme.q_type();
+    let ndims = tensor.shape.num_dims();
+    let num_elems = tensor.shape.num_elements();
+    let client = tensor.client.clone();
+    // Scale and offset qparams are also packed in the tensor data
+    let num_qparams = if offset.is_some() { 2 } else { 1 };
+    let output_num_elems = usize::div_ceil(num_elems, q_type.num_packed()) + num_qparams;
+
+    let (a, b) = q_type.range();
+    let (range_min, range_max) = match scheme {
+        QuantizationScheme::PerTensorAffine(_) => (a, b),
+        QuantizationScheme::PerTensorSymmetric(_) => (-b, b),
+    };
+
+    let handle = memory::empty::<R>(
+        &client,
+        &tensor.device,
+        output_num_elems * core::mem::size_of::<u32>(),
+    );
+    let output = JitTensor::new_contiguous(
+        client.clone(),
+        tensor.device.clone(),
+        tensor.shape.clone(),
+        handle,
+        burn_tensor::DType::QFloat(scheme),
+    );
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(output_num_elems, cube_dim);
+    let dummy_array = vec![1; ndims];
+    // Symmetric quantization doesn't read the offset, so the scale is bound in its place.
+    let offset_handle = offset
+        .as_ref()
+        .map(|offset| &offset.handle)
+        .unwrap_or(&scale.handle);
+
+    unsafe {
+        quantize_per_tensor_sub_byte_kernel::launch_unchecked::<R>(
+            &client,
+            cube_count,
+            cube_dim,
+            tensor.as_tensor_arg::<F>(1),
+            // Ignore shape and stride
+            TensorArg::from_raw_parts::<F>(&scale.handle, &dummy_array, &dummy_array, 1),
+            TensorArg::from_raw_parts::<I>(offset_handle, &dummy_array, &dummy_array, 1),
+            ScalarArg::new(range_min as f32),
+            ScalarArg::new(range_max as f32),
+            output.as_array_arg::<u32>(1),
+            offset.is_some(),
+            q_type.bits() as u32,
+        )
+    };
+
+    output
+}
+
 /// Convert the tensor to a lower precision data type based on the quantization scheme and parameters.
 pub fn quantize<R, F, I>(
     tensor: JitTensor<R>,
@@ -253,6 +385,9 @@ where
             QuantizationType::QInt8 => {
                 quantize_per_tensor::<R, F, I>(tensor, scale, offset, *scheme)
             }
+            QuantizationType::QInt4 | QuantizationType::QInt2 => {
+                quantize_per_tensor_sub_byte::<R, F, I>(tensor, scale, offset, *scheme)
+            }
         },
     }
 }
@@ -1,6 +1,7 @@
 use super::{autotune_reduce, autotune_sum};
 use crate::{
     element::JitElement,
+    kernel::cast,
     ops::{from_data, numeric::empty_device},
     tensor::JitTensor,
     JitRuntime,
@@ -8,6 +9,8 @@ use crate::{
 use burn_tensor::{Shape, TensorData};
 pub use cubecl::reduce::instructions::{ArgMax, ArgMin, Mean, Prod, Sum};
 use cubecl::reduce::shared_sum;
+use half::{bf16, f16};
+use std::any::TypeId;
 
 /// Specialize reduce function to compute the sum of all elements of the `input` tensor and return
 /// the value into a single-element tensor of shape `1 x 1 x 1 x ...` with the same rank as `input`.
@@ -79,6 +82,56 @@ pub fn reduce<Run: JitRuntime, In: JitElement, Out: JitElement, Rd: cubecl::redu
     Ok(tensor)
 }
 
+/// Compute the sum of all elements of the float `tensor` like [sum], accumulating half precision
+/// values in `f32`.
+///
+/// Half precision floats only have 8 (`bf16`) or 11 (`f16`) bits of mantissa, so the contribution
+/// of each element is quickly lost when many of them are accumulated in half precision.
+pub fn sum_float<Run: JitRuntime, E: JitElement>(
+    tensor: JitTensor<Run>,
+    strategy: SumStrategy,
+) -> Result<JitTensor<Run>, cubecl::reduce::ReduceError> {
+    if !is_half_precision::<E>() {
+        return sum::<Run, E>(tensor, strategy);
+    }
+
+    let output = sum::<Run, f32>(cast::<Run, E, f32>(tensor), strategy)?;
+    Ok(cast::<Run, f32, E>(output))
+}
+
+/// Reduce all elements of the float `tensor` like [reduce], accumulating half precision values in
+/// `f32`, see [sum_float].
+pub fn reduce_float<Run: JitRuntime, E: JitElement, Rd: cubecl::reduce::Reduce>(
+    tensor: JitTensor<Run>,
+    strategy: ReduceStrategy,
+) -> Result<JitTensor<Run>, cubecl::reduce::ReduceError> {
+    if !is_half_precision::<E>() {
+        return reduce::<Run, E, E, Rd>(tensor, strategy);
+    }
+
+    let output = reduce::<Run, f32, f32, Rd>(cast::<Run, E, f32>(tensor), strategy)?;
+    Ok(cast::<Run, f32, E>(output))
+}
+
+/// Reduce the given `dim` of the float `tensor` like [reduce_dim], accumulating half precision
+/// values in `f32`, see [sum_float].
+pub fn reduce_dim_float<Run: JitRuntime, E: JitElement, Rd: cubecl::reduce::Reduce>(
+    tensor: JitTensor<Run>,
+    dim: usize,
+    strategy: ReduceStrategy,
+) -> Result<JitTensor<Run>, cubecl::reduce::ReduceError> {
+    if !is_half_precision::<E>() {
+        return reduce_dim::<Run, E, E, Rd>(tensor, dim, strategy);
+    }
+
+    let output = reduce_dim::<Run, f32, f32, Rd>(cast::<Run, E, f32>(tensor), dim, strategy)?;
+    Ok(cast::<Run, f32, E>(output))
+}
+
+pub(crate) fn is_half_precision<E: JitElement>() -> bool {
+    TypeId::of::<E>() == TypeId::of::<f16>() || TypeId::of::<E>() == TypeId::of::<bf16>()
+}
+
 fn argsort(shape: &[usize]) -> Vec<usize> {
     let mut indices = (0..shape.len()).collect::<Vec<_>>();
     indices.sort_by_key(|&i| &shape[i]);
@@ -0,0 +1,266 @@
+use burn_tensor::{
+    ops::{RoiAlignOptions, RoiPoolOptions},
+    Shape,
+};
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+
+use crate::{ops::numeric::empty_device, tensor::JitTensor, FloatElement, JitRuntime};
+
+/// Average the bilinear interpolation of the sampling points of a bin, one output element per
+/// invocation.
+#[cube(launch_unchecked)]
+fn roi_align_kernel<F: Float>(
+    input: &Tensor<F>,
+    boxes: &Tensor<F>,
+    output: &mut Tensor<F>,
+    spatial_scale: f32,
+    sampling_ratio: u32,
+    #[comptime] aligned: bool,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        terminate!();
+    }
+
+    let roi = ABSOLUTE_POS / output.stride(0) % output.shape(0);
+    let channel = ABSOLUTE_POS / output.stride(1) % output.shape(1);
+    let ph = ABSOLUTE_POS / output.stride(2) % output.shape(2);
+    let pw = ABSOLUTE_POS / output.stride(3) % output.shape(3);
+
+    let index_box = roi * boxes.stride(0);
+    let stride_box = boxes.stride(1);
+    let scale = F::cast_from(spatial_scale);
+    let mut offset = F::new(0.0);
+
+    if comptime![aligned] {
+        offset = F::new(0.5);
+    }
+
+    let batch = u32::cast_from(boxes[index_box]);
+    let start_x = boxes[index_box + stride_box] * scale - offset;
+    let start_y = boxes[index_box + 2 * stride_box] * scale - offset;
+    let mut roi_width = boxes[index_box + 3 * stride_box] * scale - offset - start_x;
+    let mut roi_height = boxes[index_box + 4 * stride_box] * scale - offset - start_y;
+
+    if comptime![!aligned] {
+        // Malformed boxes are forced to be 1x1.
+        roi_width = Max::max(roi_width, F::new(1.0));
+        roi_height = Max::max(roi_height, F::new(1.0));
+    }
+
+    let bin_height = roi_height / F::cast_from(output.shape(2));
+    let bin_width = roi_width / F::cast_from(output.shape(3));
+    let mut grid_height = sampling_ratio;
+    let mut grid_width = sampling_ratio;
+
+    if sampling_ratio == 0 {
+        grid_height = u32::cast_from(Ceil::ceil(bin_height));
+        grid_width = u32::cast_from(Ceil::ceil(bin_width));
+    }
+
+    let index_input = batch * input.stride(0) + channel * input.stride(1);
+    let mut sum = F::new(0.0);
+
+    for iy in 0..grid_height {
+        let y = start_y
+            + F::cast_from(ph) * bin_height
+            + (F::cast_from(iy) + F::new(0.5)) * bin_height / F::cast_from(grid_height);
+
+        for ix in 0..grid_width {
+            let x = start_x
+                + F::cast_from(pw) * bin_width
+                + (F::cast_from(ix) + F::new(0.5)) * bin_width / F::cast_from(grid_width);
+
+            sum += bilinear_interpolate::<F>(input, index_input, y, x);
+        }
+    }
+
+    output[ABSOLUTE_POS] = sum / F::cast_from(Max::max(grid_height * grid_width, 1));
+}
+
+/// The bilinear interpolation at `(y, x)`, zero when the point is more than one pixel outside of
+/// the input.
+#[cube]
+fn bilinear_interpolate<F: Float>(input: &Tensor<F>, index: u32, y: F, x: F) -> F {
+    let height = input.shape(2);
+    let width = input.shape(3);
+    let mut value = F::new(0.0);
+
+    if y >= F::new(-1.0)
+        && y <= F::cast_from(height)
+        && x >= F::new(-1.0)
+        && x <= F::cast_from(width)
+    {
+        let y = Max::max(y, F::new(0.0));
+        let x = Max::max(x, F::new(0.0));
+
+        let mut y_low = u32::cast_from(y);
+        let mut y_high = y_low + 1;
+        let mut ly = y - F::cast_from(y_low);
+
+        if y_low >= height - 1 {
+            y_low = height - 1;
+            y_high = height - 1;
+            ly = F::new(0.0);
+        }
+
+        let mut x_low = u32::cast_from(x);
+        let mut x_high = x_low + 1;
+        let mut lx = x - F::cast_from(x_low);
+
+        if x_low >= width - 1 {
+            x_low = width - 1;
+            x_high = width - 1;
+            lx = F::new(0.0);
+        }
+
+        let hy = F::new(1.0) - ly;
+        let hx = F::new(1.0) - lx;
+        let index_low = index + y_low * input.stride(2);
+        let index_high = index + y_high * input.stride(2);
+
+        value = hy * hx * input[index_low + x_low * input.stride(3)]
+            + hy * lx * input[index_low + x_high * input.stride(3)]
+            + ly * hx * input[index_high + x_low * input.stride(3)]
+            + ly * lx * input[index_high + x_high * input.stride(3)];
+    }
+
+    value
+}
+
+/// Take the maximum of the pixels of a bin, one output element per invocation.
+#[cube(launch_unchecked)]
+fn roi_pool_kernel<F: Float>(
+    input: &Tensor<F>,
+    boxes: &Tensor<F>,
+    output: &mut Tensor<F>,
+    spatial_scale: f32,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        terminate!();
+    }
+
+    let roi = ABSOLUTE_POS / output.stride(0) % output.shape(0);
+    let channel = ABSOLUTE_POS / output.stride(1) % output.shape(1);
+    let ph = ABSOLUTE_POS / output.stride(2) % output.shape(2);
+    let pw = ABSOLUTE_POS / output.stride(3) % output.shape(3);
+
+    let index_box = roi * boxes.stride(0);
+    let stride_box = boxes.stride(1);
+    let scale = F::cast_from(spatial_scale);
+
+    let batch = u32::cast_from(boxes[index_box]);
+    let start_x = i32::cast_from(Round::round(boxes[index_box + stride_box] * scale));
+    let start_y = i32::cast_from(Round::round(boxes[index_box + 2 * stride_box] * scale));
+    let end_x = i32::cast_from(Round::round(boxes[index_box + 3 * stride_box] * scale));
+    let end_y = i32::cast_from(Round::round(boxes[index_box + 4 * stride_box] * scale));
+
+    // Malformed boxes are forced to be 1x1.
+    let roi_width = Max::max(end_x - start_x + 1, 1);
+    let roi_height = Max::max(end_y - start_y + 1, 1);
+    let bin_height = F::cast_from(roi_height) / F::cast_from(output.shape(2));
+    let bin_width = F::cast_from(roi_width) / F::cast_from(output.shape(3));
+
+    let y_start = bin_bound::<F>(ph, bin_height, start_y, input.shape(2), false);
+    let y_end = bin_bound::<F>(ph, bin_height, start_y, input.shape(2), true);
+    let x_start = bin_bound::<F>(pw, bin_width, start_x, input.shape(3), false);
+    let x_end = bin_bound::<F>(pw, bin_width, start_x, input.shape(3), true);
+
+    let index_input = batch * input.stride(0) + channel * input.stride(1);
+    let mut max = F::min_value();
+
+    for y in y_start..y_end {
+        for x in x_start..x_end {
+            max = Max::max(
+                max,
+                input[index_input + y * input.stride(2) + x * input.stride(3)],
+            );
+        }
+    }
+
+    // Empty bins are zero.
+    if y_end <= y_start || x_end <= x_start {
+        max = F::new(0.0);
+    }
+
+    output[ABSOLUTE_POS] = max;
+}
+
+/// The first pixel of a bin, or the end of a bin, clamped to the input.
+///
+/// The bins include all the pixels they overlap.
+#[cube]
+fn bin_bound<F: Float>(bin: u32, bin_size: F, start: i32, size: u32, #[comptime] end: bool) -> u32 {
+    let mut pixel = i32::cast_from(Floor::floor(F::cast_from(bin) * bin_size));
+
+    if comptime![end] {
+        pixel = i32::cast_from(Ceil::ceil(F::cast_from(bin + 1) * bin_size));
+    }
+
+    u32::cast_from(Min::min(Max::max(pixel + start, 0), i32::cast_from(size)))
+}
+
+/// Pool each region of interest into a fixed size output with RoIAlign.
+pub(crate) fn roi_align<R: JitRuntime, E: FloatElement>(
+    x: JitTensor<R>,
+    boxes: JitTensor<R>,
+    options: RoiAlignOptions,
+) -> JitTensor<R> {
+    let output = roi_output::<R, E>(&x, &boxes, options.output_size);
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(output.shape.num_elements(), cube_dim);
+
+    unsafe {
+        roi_align_kernel::launch_unchecked::<E, R>(
+            &x.client,
+            cube_count,
+            cube_dim,
+            x.as_tensor_arg::<E>(1),
+            boxes.as_tensor_arg::<E>(1),
+            output.as_tensor_arg::<E>(1),
+            ScalarArg::new(options.spatial_scale),
+            ScalarArg::new(options.sampling_ratio as u32),
+            options.aligned,
+        );
+    }
+
+    output
+}
+
+/// Pool each region of interest into a fixed size output with RoIPool.
+pub(crate) fn roi_pool<R: JitRuntime, E: FloatElement>(
+    x: JitTensor<R>,
+    boxes: JitTensor<R>,
+    options: RoiPoolOptions,
+) -> JitTensor<R> {
+    let output = roi_output::<R, E>(&x, &boxes, options.output_size);
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(output.shape.num_elements(), cube_dim);
+
+    unsafe {
+        roi_pool_kernel::launch_unchecked::<E, R>(
+            &x.client,
+            cube_count,
+            cube_dim,
+            x.as_tensor_arg::<E>(1),
+            boxes.as_tensor_arg::<E>(1),
+            output.as_tensor_arg::<E>(1),
+            ScalarArg::new(options.spatial_scale),
+        );
+    }
+
+    output
+}
+
+fn roi_output<R: JitRuntime, E: FloatElement>(
+    x: &JitTensor<R>,
+    boxes: &JitTensor<R>,
+    output_size: [usize; 2],
+) -> JitTensor<R> {
+    let [_, channels, _, _] = x.shape.dims();
+    let [num_boxes, _] = boxes.shape.dims();
+    let shape_out = Shape::new([num_boxes, channels, output_size[0], output_size[1]]);
+
+    empty_device::<R, E>(x.client.clone(), x.device.clone(), shape_out)
+}
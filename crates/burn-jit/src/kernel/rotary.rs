@@ -0,0 +1,113 @@
+use burn_tensor::Shape;
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+
+use crate::{ops::numeric::empty_device, tensor::JitTensor, FloatElement, JitRuntime};
+
+/// Rotates the queries and the keys in a single pass, one unit per pair of features.
+///
+/// The inputs are read with their strides, with the layout `[batch_size, seq_length, n_heads,
+/// d_head]` of the projections, and the outputs are written contiguously with the layout
+/// `[batch_size, n_heads, seq_length, d_head]` of the attention.
+#[cube(launch_unchecked)]
+fn rotary_qk_kernel<F: Float>(
+    queries: &Tensor<F>,
+    keys: &Tensor<F>,
+    frequencies: &Tensor<F>,
+    out_queries: &mut Tensor<F>,
+    out_keys: &mut Tensor<F>,
+    start: u32,
+) {
+    let num_pairs_queries = out_queries.len() / 2;
+    let num_pairs_keys = out_keys.len() / 2;
+
+    if ABSOLUTE_POS >= num_pairs_queries + num_pairs_keys {
+        terminate!();
+    }
+
+    if ABSOLUTE_POS < num_pairs_queries {
+        rotate_pair(queries, frequencies, out_queries, ABSOLUTE_POS * 2, start);
+    } else {
+        let index = (ABSOLUTE_POS - num_pairs_queries) * 2;
+        rotate_pair(keys, frequencies, out_keys, index, start);
+    }
+}
+
+/// Rotates the pair of features starting at the given index of the output, or copies it when it
+/// is not part of the rotated features.
+#[cube]
+fn rotate_pair<F: Float>(
+    input: &Tensor<F>,
+    frequencies: &Tensor<F>,
+    output: &mut Tensor<F>,
+    index: u32,
+    start: u32,
+) {
+    let d_head = output.shape(3);
+    let seq_length = output.shape(2);
+    let n_heads = output.shape(1);
+
+    let feature = index % d_head;
+    let position = (index / d_head) % seq_length;
+    let head = (index / (d_head * seq_length)) % n_heads;
+    let batch = index / (d_head * seq_length * n_heads);
+
+    let offset = batch * input.stride(0)
+        + position * input.stride(1)
+        + head * input.stride(2)
+        + feature * input.stride(3);
+    let first = input[offset];
+    let second = input[offset + input.stride(3)];
+
+    if feature < frequencies.shape(1) {
+        let offset = (start + position) * frequencies.stride(0) + feature * frequencies.stride(1);
+        let cos = frequencies[offset];
+        let sin = frequencies[offset + frequencies.stride(2)];
+
+        output[index] = first * cos - second * sin;
+        output[index + 1] = second * cos + first * sin;
+    } else {
+        output[index] = first;
+        output[index + 1] = second;
+    }
+}
+
+pub(crate) fn rotary_qk<R: JitRuntime, E: FloatElement>(
+    queries: JitTensor<R>,
+    keys: JitTensor<R>,
+    frequencies: JitTensor<R>,
+    start: usize,
+) -> (JitTensor<R>, JitTensor<R>) {
+    let client = queries.client.clone();
+    let device = queries.device.clone();
+
+    let out_queries =
+        empty_device::<R, E>(client.clone(), device.clone(), attention_shape(&queries));
+    let out_keys = empty_device::<R, E>(client.clone(), device, attention_shape(&keys));
+
+    let num_pairs = (out_queries.shape.num_elements() + out_keys.shape.num_elements()) / 2;
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(num_pairs, cube_dim);
+
+    unsafe {
+        rotary_qk_kernel::launch_unchecked::<E, R>(
+            &client,
+            cube_count,
+            cube_dim,
+            queries.as_tensor_arg::<E>(1),
+            keys.as_tensor_arg::<E>(1),
+            frequencies.as_tensor_arg::<E>(1),
+            out_queries.as_tensor_arg::<E>(1),
+            out_keys.as_tensor_arg::<E>(1),
+            ScalarArg::new(start as u32),
+        )
+    };
+
+    (out_queries, out_keys)
+}
+
+/// The shape of the tensor with its heads swapped with the sequence.
+fn attention_shape<R: JitRuntime>(tensor: &JitTensor<R>) -> Shape {
+    let [batch_size, seq_length, n_heads, d_head] = tensor.shape.dims();
+
+    Shape::new([batch_size, n_heads, seq_length, d_head])
+}
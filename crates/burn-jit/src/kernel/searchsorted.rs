@@ -0,0 +1,73 @@
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+
+use crate::{
+    kernel::into_contiguous, ops::numeric::empty_device, tensor::JitTensor, FloatElement,
+    IntElement, JitRuntime,
+};
+
+/// Find the index of one value per invocation, with a binary search in the sorted sequence.
+#[cube(launch_unchecked)]
+fn searchsorted_kernel<F: Float, I: Int>(
+    sorted_sequence: &Tensor<F>,
+    values: &Tensor<F>,
+    output: &mut Tensor<I>,
+    #[comptime] right: bool,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        terminate!();
+    }
+
+    let value = values[ABSOLUTE_POS];
+    let stride = sorted_sequence.stride(0);
+    let mut low = 0;
+    let mut high = sorted_sequence.shape(0);
+
+    while low < high {
+        let middle = (low + high) / 2;
+        let current = sorted_sequence[middle * stride];
+
+        let mut before = current < value;
+        if comptime![right] {
+            before = current <= value;
+        }
+
+        if before {
+            low = middle + 1;
+        } else {
+            high = middle;
+        }
+    }
+
+    output[ABSOLUTE_POS] = I::cast_from(low);
+}
+
+/// Find the indices where the values would be inserted in the sorted sequence to keep it sorted.
+pub(crate) fn searchsorted<R: JitRuntime, E: FloatElement, I: IntElement>(
+    sorted_sequence: JitTensor<R>,
+    values: JitTensor<R>,
+    right: bool,
+) -> JitTensor<R> {
+    let values = into_contiguous(values);
+    let output = empty_device::<R, I>(
+        values.client.clone(),
+        values.device.clone(),
+        values.shape.clone(),
+    );
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(output.shape.num_elements(), cube_dim);
+
+    unsafe {
+        searchsorted_kernel::launch_unchecked::<E, I, R>(
+            &values.client,
+            cube_count,
+            cube_dim,
+            sorted_sequence.as_tensor_arg::<E>(1),
+            values.as_tensor_arg::<E>(1),
+            output.as_tensor_arg::<I>(1),
+            right,
+        );
+    }
+
+    output
+}
@@ -0,0 +1,261 @@
+use burn_tensor::{SegmentReduction, Shape};
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+
+use crate::{
+    kernel::into_contiguous,
+    ops::{
+        numeric::{empty_device, ones_device},
+        reshape,
+    },
+    tensor::JitTensor,
+    FloatElement, IntElement, JitRuntime,
+};
+
+/// Reduce the rows of each segment, one output element per invocation.
+///
+/// The ids are sorted, so the rows of a segment are contiguous and their range is found with two
+/// binary searches. No atomics are needed, which keeps the maximum supported on every runtime.
+#[cube(launch_unchecked)]
+fn segment_reduce_kernel<F: Float, I: Int>(
+    input: &Tensor<F>,
+    ids: &Tensor<I>,
+    output: &mut Tensor<F>,
+    #[comptime] is_max: bool,
+    #[comptime] is_mean: bool,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        terminate!();
+    }
+
+    let num_cols = output.shape(1);
+    let segment = ABSOLUTE_POS / num_cols;
+    let col = ABSOLUTE_POS % num_cols;
+
+    let start = lower_bound::<I>(ids, segment);
+    let end = lower_bound::<I>(ids, segment + 1);
+
+    let mut accumulator = F::new(0.0);
+    if comptime![is_max] {
+        accumulator = F::min_value();
+    }
+
+    for row in start..end {
+        let value = input[row * input.stride(0) + col * input.stride(1)];
+
+        if comptime![is_max] {
+            accumulator = Max::max(accumulator, value);
+        } else {
+            accumulator += value;
+        }
+    }
+
+    if comptime![is_mean] {
+        accumulator /= F::cast_from(Max::max(end - start, 1));
+    }
+
+    if start == end {
+        accumulator = F::new(0.0);
+    }
+
+    output[ABSOLUTE_POS] = accumulator;
+}
+
+/// Reduce the rows of each segment, one output column per invocation.
+///
+/// The ids are in any order, so each invocation accumulates the rows of its column sequentially,
+/// which doesn't need atomics nor sorting the ids. The counts of the segments are only read to
+/// average the sums and to zero the empty segments of the maximum.
+#[cube(launch_unchecked)]
+fn unsorted_segment_reduce_kernel<F: Float, I: Int>(
+    input: &Tensor<F>,
+    ids: &Tensor<I>,
+    counts: &Tensor<F>,
+    output: &mut Tensor<F>,
+    #[comptime] is_max: bool,
+    #[comptime] is_mean: bool,
+) {
+    if ABSOLUTE_POS >= output.shape(1) {
+        terminate!();
+    }
+
+    let col = ABSOLUTE_POS;
+    let num_segments = output.shape(0);
+    let stride_segment = output.stride(0);
+    let offset_col = col * output.stride(1);
+
+    let mut initial = F::new(0.0);
+    if comptime![is_max] {
+        initial = F::min_value();
+    }
+
+    for segment in 0..num_segments {
+        output[segment * stride_segment + offset_col] = initial;
+    }
+
+    for row in 0..input.shape(0) {
+        let index = u32::cast_from(ids[row]) * stride_segment + offset_col;
+        let value = input[row * input.stride(0) + col * input.stride(1)];
+
+        if comptime![is_max] {
+            output[index] = Max::max(output[index], value);
+        } else {
+            output[index] += value;
+        }
+    }
+
+    if comptime![is_max || is_mean] {
+        for segment in 0..num_segments {
+            let index = segment * stride_segment + offset_col;
+            let count = counts[segment];
+
+            if comptime![is_mean] {
+                output[index] /= Max::max(count, F::new(1.0));
+            }
+
+            if count == F::new(0.0) {
+                output[index] = F::new(0.0);
+            }
+        }
+    }
+}
+
+/// The index of the first id greater or equal to `value`.
+#[cube]
+fn lower_bound<I: Int>(ids: &Tensor<I>, value: u32) -> u32 {
+    let mut low = 0;
+    let mut high = ids.shape(0);
+
+    while low < high {
+        let middle = (low + high) / 2;
+
+        if u32::cast_from(ids[middle]) < value {
+            low = middle + 1;
+        } else {
+            high = middle;
+        }
+    }
+
+    low
+}
+
+/// Reduce the slices along the first dimension sharing the same segment id, where the ids are
+/// sorted in ascending order.
+pub(crate) fn segment_reduce<R: JitRuntime, E: FloatElement, I: IntElement>(
+    tensor: JitTensor<R>,
+    ids: JitTensor<R>,
+    num_segments: usize,
+    reduction: SegmentReduction,
+) -> JitTensor<R> {
+    let mut shape = tensor.shape.clone();
+    let num_elements = shape.dims[0];
+    let num_cols = shape.num_elements() / num_elements.max(1);
+
+    let tensor = reshape(
+        into_contiguous(tensor),
+        Shape::new([num_elements, num_cols]),
+    );
+    let output = empty_device::<R, E>(
+        tensor.client.clone(),
+        tensor.device.clone(),
+        Shape::new([num_segments, num_cols]),
+    );
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(output.shape.num_elements(), cube_dim);
+
+    unsafe {
+        segment_reduce_kernel::launch_unchecked::<E, I, R>(
+            &tensor.client,
+            cube_count,
+            cube_dim,
+            tensor.as_tensor_arg::<E>(1),
+            into_contiguous(ids).as_tensor_arg::<I>(1),
+            output.as_tensor_arg::<E>(1),
+            reduction == SegmentReduction::Max,
+            reduction == SegmentReduction::Mean,
+        );
+    }
+
+    shape.dims[0] = num_segments;
+    reshape(output, shape)
+}
+
+/// Reduce the slices along the first dimension sharing the same segment id, where the ids are in
+/// any order.
+pub(crate) fn unsorted_segment_reduce<R: JitRuntime, E: FloatElement, I: IntElement>(
+    tensor: JitTensor<R>,
+    ids: JitTensor<R>,
+    num_segments: usize,
+    reduction: SegmentReduction,
+) -> JitTensor<R> {
+    let mut shape = tensor.shape.clone();
+    let num_elements = shape.dims[0];
+    let num_cols = shape.num_elements() / num_elements.max(1);
+
+    let tensor = reshape(
+        into_contiguous(tensor),
+        Shape::new([num_elements, num_cols]),
+    );
+    let ids = into_contiguous(ids);
+
+    let counts = match reduction {
+        SegmentReduction::Sum => None,
+        SegmentReduction::Mean | SegmentReduction::Max => {
+            let ones = ones_device::<R, E>(
+                tensor.client.clone(),
+                tensor.device.clone(),
+                Shape::new([num_elements, 1]),
+            );
+
+            Some(launch_unsorted::<R, E, I>(
+                &ones,
+                &ids,
+                None,
+                num_segments,
+                SegmentReduction::Sum,
+            ))
+        }
+    };
+
+    let output =
+        launch_unsorted::<R, E, I>(&tensor, &ids, counts.as_ref(), num_segments, reduction);
+
+    shape.dims[0] = num_segments;
+    reshape(output, shape)
+}
+
+fn launch_unsorted<R: JitRuntime, E: FloatElement, I: IntElement>(
+    tensor: &JitTensor<R>,
+    ids: &JitTensor<R>,
+    counts: Option<&JitTensor<R>>,
+    num_segments: usize,
+    reduction: SegmentReduction,
+) -> JitTensor<R> {
+    let [_, num_cols] = tensor.shape.dims();
+    let output = empty_device::<R, E>(
+        tensor.client.clone(),
+        tensor.device.clone(),
+        Shape::new([num_segments, num_cols]),
+    );
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(num_cols, cube_dim);
+    // The counts aren't read when summing, so the input is bound in their place.
+    let counts = counts.unwrap_or(tensor);
+
+    unsafe {
+        unsorted_segment_reduce_kernel::launch_unchecked::<E, I, R>(
+            &tensor.client,
+            cube_count,
+            cube_dim,
+            tensor.as_tensor_arg::<E>(1),
+            ids.as_tensor_arg::<I>(1),
+            counts.as_tensor_arg::<E>(1),
+            output.as_tensor_arg::<E>(1),
+            reduction == SegmentReduction::Max,
+            reduction == SegmentReduction::Mean,
+        );
+    }
+
+    output
+}
@@ -0,0 +1,207 @@
+use burn_tensor::{
+    ops::{conv::calculate_conv_output_size, UnfoldOptions},
+    Shape,
+};
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+
+use crate::{
+    kernel::into_contiguous, ops::numeric::empty_device, tensor::JitTensor, FloatElement,
+    JitRuntime,
+};
+
+#[derive(CubeLaunch)]
+struct UnfoldArgs {
+    kernel_h: u32,
+    kernel_w: u32,
+    blocks_h: u32,
+    blocks_w: u32,
+
+    pad_h: u32,
+    pad_w: u32,
+    dilation_h: u32,
+    dilation_w: u32,
+    stride_h: u32,
+    stride_w: u32,
+}
+
+/// Copy each element of the blocks into the columns, one column element per invocation.
+#[cube(launch_unchecked)]
+fn unfold4d_kernel<F: Float>(image: &Tensor<F>, columns: &mut Tensor<F>, args: &UnfoldArgs) {
+    if ABSOLUTE_POS >= columns.len() {
+        terminate!();
+    }
+
+    let block = ABSOLUTE_POS % columns.shape(2);
+    let channel_col = ABSOLUTE_POS / columns.stride(1) % columns.shape(1);
+    let batch = ABSOLUTE_POS / columns.stride(0);
+
+    let kernel_numel = args.kernel_h * args.kernel_w;
+    let channel = channel_col / kernel_numel;
+    let kernel_y = channel_col % kernel_numel / args.kernel_w;
+    let kernel_x = channel_col % args.kernel_w;
+
+    // Coordinates in the padded image.
+    let im_y = block / args.blocks_w * args.stride_h + kernel_y * args.dilation_h;
+    let im_x = block % args.blocks_w * args.stride_w + kernel_x * args.dilation_w;
+
+    let within_padding = im_y >= args.pad_h
+        && im_y < image.shape(2) + args.pad_h
+        && im_x >= args.pad_w
+        && im_x < image.shape(3) + args.pad_w;
+
+    let mut value = F::new(0.0);
+
+    if within_padding {
+        value = image[batch * image.stride(0)
+            + channel * image.stride(1)
+            + (im_y - args.pad_h) * image.stride(2)
+            + (im_x - args.pad_w) * image.stride(3)];
+    }
+
+    columns[ABSOLUTE_POS] = value;
+}
+
+/// Sum the elements of the blocks overlapping each pixel, one pixel per invocation.
+///
+/// Each pixel gathers its values instead of the blocks scattering them, so no atomics are needed.
+#[cube(launch_unchecked)]
+fn fold4d_kernel<F: Float>(columns: &Tensor<F>, image: &mut Tensor<F>, args: &UnfoldArgs) {
+    if ABSOLUTE_POS >= image.len() {
+        terminate!();
+    }
+
+    // Coordinates in the padded image.
+    let im_x = ABSOLUTE_POS % image.shape(3) + args.pad_w;
+    let im_y = ABSOLUTE_POS / image.stride(2) % image.shape(2) + args.pad_h;
+    let channel = ABSOLUTE_POS / image.stride(1) % image.shape(1);
+    let batch = ABSOLUTE_POS / image.stride(0);
+
+    let index_batch = batch * columns.stride(0);
+    let channel_col = channel * args.kernel_h * args.kernel_w;
+    let mut sum = F::new(0.0);
+
+    for kernel_y in 0..args.kernel_h {
+        let offset_y = kernel_y * args.dilation_h;
+
+        if im_y >= offset_y
+            && (im_y - offset_y) % args.stride_h == 0
+            && (im_y - offset_y) / args.stride_h < args.blocks_h
+        {
+            let block_y = (im_y - offset_y) / args.stride_h;
+
+            for kernel_x in 0..args.kernel_w {
+                let offset_x = kernel_x * args.dilation_w;
+
+                if im_x >= offset_x
+                    && (im_x - offset_x) % args.stride_w == 0
+                    && (im_x - offset_x) / args.stride_w < args.blocks_w
+                {
+                    let block_x = (im_x - offset_x) / args.stride_w;
+                    let index = index_batch
+                        + (channel_col + kernel_y * args.kernel_w + kernel_x) * columns.stride(1)
+                        + (block_y * args.blocks_w + block_x) * columns.stride(2);
+
+                    sum += columns[index];
+                }
+            }
+        }
+    }
+
+    image[ABSOLUTE_POS] = sum;
+}
+
+fn unfold_args<R: JitRuntime>(
+    kernel_size: [usize; 2],
+    blocks: [usize; 2],
+    options: &UnfoldOptions,
+) -> UnfoldArgsLaunch<'static, R> {
+    UnfoldArgsLaunch::new(
+        ScalarArg::new(kernel_size[0] as u32),
+        ScalarArg::new(kernel_size[1] as u32),
+        ScalarArg::new(blocks[0] as u32),
+        ScalarArg::new(blocks[1] as u32),
+        ScalarArg::new(options.padding[0] as u32),
+        ScalarArg::new(options.padding[1] as u32),
+        ScalarArg::new(options.dilation[0] as u32),
+        ScalarArg::new(options.dilation[1] as u32),
+        ScalarArg::new(options.stride[0] as u32),
+        ScalarArg::new(options.stride[1] as u32),
+    )
+}
+
+fn num_blocks(size: [usize; 2], kernel_size: [usize; 2], options: &UnfoldOptions) -> [usize; 2] {
+    [0, 1].map(|i| {
+        calculate_conv_output_size(
+            kernel_size[i],
+            options.stride[i],
+            options.padding[i],
+            options.dilation[i],
+            size[i],
+        )
+    })
+}
+
+/// Extract the sliding blocks of the image into columns (im2col).
+pub(crate) fn unfold4d<R: JitRuntime, E: FloatElement>(
+    x: JitTensor<R>,
+    kernel_size: [usize; 2],
+    options: UnfoldOptions,
+) -> JitTensor<R> {
+    let [batch_size, channels, height, width] = x.shape.dims();
+    let blocks = num_blocks([height, width], kernel_size, &options);
+
+    let shape_out = Shape::new([
+        batch_size,
+        channels * kernel_size[0] * kernel_size[1],
+        blocks[0] * blocks[1],
+    ]);
+    let output = empty_device::<R, E>(x.client.clone(), x.device.clone(), shape_out);
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(output.shape.num_elements(), cube_dim);
+
+    unsafe {
+        unfold4d_kernel::launch_unchecked::<E, R>(
+            &x.client,
+            cube_count,
+            cube_dim,
+            x.as_tensor_arg::<E>(1),
+            output.as_tensor_arg::<E>(1),
+            unfold_args(kernel_size, blocks, &options),
+        );
+    }
+
+    output
+}
+
+/// Sum the columns back into an image of `output_size` (col2im).
+pub(crate) fn fold4d<R: JitRuntime, E: FloatElement>(
+    x: JitTensor<R>,
+    output_size: [usize; 2],
+    kernel_size: [usize; 2],
+    options: UnfoldOptions,
+) -> JitTensor<R> {
+    let [batch_size, channels_col, _num_blocks] = x.shape.dims();
+    let blocks = num_blocks(output_size, kernel_size, &options);
+    let channels = channels_col / (kernel_size[0] * kernel_size[1]);
+
+    let x = into_contiguous(x);
+    let shape_out = Shape::new([batch_size, channels, output_size[0], output_size[1]]);
+    let output = empty_device::<R, E>(x.client.clone(), x.device.clone(), shape_out);
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(output.shape.num_elements(), cube_dim);
+
+    unsafe {
+        fold4d_kernel::launch_unchecked::<E, R>(
+            &x.client,
+            cube_count,
+            cube_dim,
+            x.as_tensor_arg::<E>(1),
+            output.as_tensor_arg::<E>(1),
+            unfold_args(kernel_size, blocks, &options),
+        );
+    }
+
+    output
+}
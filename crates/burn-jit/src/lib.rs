@@ -22,8 +22,11 @@ use cubecl::{compute::CubeTask, Feature, Runtime};
 pub use element::{BoolElement, FloatElement, IntElement, JitElement};
 
 mod backend;
+mod fast_path;
+mod memory;
 
 pub use backend::*;
+pub use fast_path::{fast_path_report, reset_fast_path_report, FastPathMiss, FastPathReport};
 
 // Re-export cubecl.
 pub use cubecl;
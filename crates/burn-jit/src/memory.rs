@@ -0,0 +1,145 @@
+use burn_tensor::backend::{DeviceId, DeviceOps, MemoryStats};
+use cubecl::{client::ComputeClient, server::Handle};
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        RwLock,
+    },
+};
+
+use crate::JitRuntime;
+
+/// The memory budget and the peak memory usage of each device.
+///
+/// The devices are only locked for writing when they are first used, the allocations only read
+/// and update the atomics of their device.
+static DEVICES: RwLock<Option<HashMap<DeviceId, DeviceMemory>>> = RwLock::new(None);
+
+/// The budget of a device without one.
+const NO_BUDGET: u64 = u64::MAX;
+
+struct DeviceMemory {
+    budget: AtomicU64,
+    peak_bytes_in_use: AtomicU64,
+}
+
+impl Default for DeviceMemory {
+    fn default() -> Self {
+        Self {
+            budget: AtomicU64::new(NO_BUDGET),
+            peak_bytes_in_use: AtomicU64::new(0),
+        }
+    }
+}
+
+/// The memory used on the device.
+pub(crate) fn memory_stats<R: JitRuntime>(device: &R::Device) -> MemoryStats {
+    let usage = R::client(device).memory_usage();
+    let peak_bytes_in_use = with_memory(device, |memory| {
+        let peak = memory
+            .peak_bytes_in_use
+            .fetch_max(usage.bytes_in_use, Ordering::Relaxed);
+        peak.max(usage.bytes_in_use)
+    });
+
+    MemoryStats {
+        bytes_in_use: usage.bytes_in_use,
+        bytes_reserved: usage.bytes_reserved,
+        peak_bytes_in_use,
+    }
+}
+
+/// Set the number of bytes the memory pools of the device should stay under.
+pub(crate) fn set_memory_budget<R: JitRuntime>(device: &R::Device, budget: Option<u64>) {
+    with_memory(device, |memory| {
+        memory
+            .budget
+            .store(budget.unwrap_or(NO_BUDGET), Ordering::Relaxed)
+    });
+}
+
+/// Allocate an empty buffer of `size` bytes on the device.
+///
+/// The tensors of the backend are allocated with this function or with [create], which keep
+/// the memory pools under the budget of the device and track its peak memory usage.
+pub(crate) fn empty<R: JitRuntime>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    device: &R::Device,
+    size: usize,
+) -> Handle {
+    reserve::<R>(client, device, size);
+    let handle = client.empty(size);
+    track_peak::<R>(client, device);
+
+    handle
+}
+
+/// Allocate a buffer on the device initialized with the given bytes.
+///
+/// See [empty].
+pub(crate) fn create<R: JitRuntime>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    device: &R::Device,
+    bytes: &[u8],
+) -> Handle {
+    reserve::<R>(client, device, bytes.len());
+    let handle = client.create(bytes);
+    track_peak::<R>(client, device);
+
+    handle
+}
+
+/// Deallocate the unused chunks of the memory pools of the device when allocating `size` bytes
+/// would exceed its budget.
+fn reserve<R: JitRuntime>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    device: &R::Device,
+    size: usize,
+) {
+    let budget = with_memory(device, |memory| memory.budget.load(Ordering::Relaxed));
+    if budget == NO_BUDGET {
+        return;
+    }
+
+    let bytes_reserved = client.memory_usage().bytes_reserved;
+    if bytes_reserved + size as u64 > budget {
+        log::info!(
+            "Allocating {size} bytes would exceed the memory budget of {budget} bytes with \
+             {bytes_reserved} bytes reserved, deallocating the unused memory"
+        );
+        client.memory_cleanup();
+    }
+}
+
+/// Update the peak memory usage of the device after an allocation.
+fn track_peak<R: JitRuntime>(client: &ComputeClient<R::Server, R::Channel>, device: &R::Device) {
+    let bytes_in_use = client.memory_usage().bytes_in_use;
+
+    with_memory(device, |memory| {
+        memory
+            .peak_bytes_in_use
+            .fetch_max(bytes_in_use, Ordering::Relaxed)
+    });
+}
+
+fn with_memory<D: DeviceOps, O>(device: &D, func: impl FnOnce(&DeviceMemory) -> O) -> O {
+    let id = device.id();
+
+    if let Some(memory) = DEVICES
+        .read()
+        .unwrap()
+        .as_ref()
+        .and_then(|devices| devices.get(&id))
+    {
+        return func(memory);
+    }
+
+    let mut devices = DEVICES.write().unwrap();
+    let memory = devices
+        .get_or_insert_with(HashMap::new)
+        .entry(id)
+        .or_default();
+
+    func(memory)
+}
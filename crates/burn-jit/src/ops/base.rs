@@ -1,4 +1,4 @@
-use crate::{element::JitElement, kernel, tensor::JitTensor, BoolElement, JitRuntime};
+use crate::{element::JitElement, kernel, memory, tensor::JitTensor, BoolElement, JitRuntime};
 use burn_tensor::{Shape, TensorData};
 use cubecl::tensor_vectorization_factor;
 
@@ -8,7 +8,7 @@ pub(crate) fn from_data<R: JitRuntime, E: JitElement>(
 ) -> JitTensor<R> {
     let shape: Shape = (&data.shape).into();
     let client = R::client(device);
-    let buffer = client.create(data.convert::<E>().as_bytes());
+    let buffer = memory::create::<R>(&client, device, data.convert::<E>().as_bytes());
 
     JitTensor::new_contiguous(client, device.clone(), shape, buffer, E::dtype())
 }
@@ -60,7 +60,11 @@ pub(crate) fn empty<R: JitRuntime, E: JitElement>(
     device: &R::Device,
 ) -> JitTensor<R> {
     let client = R::client(device);
-    let buffer = client.empty(shape.num_elements() * core::mem::size_of::<E>());
+    let buffer = memory::empty::<R>(
+        &client,
+        device,
+        shape.num_elements() * core::mem::size_of::<E>(),
+    );
 
     JitTensor::new_contiguous(client, device.clone(), shape, buffer, E::dtype())
 }
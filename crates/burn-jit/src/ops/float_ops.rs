@@ -1,5 +1,8 @@
 use super::{expand, numeric, permute};
-use crate::kernel::prng::{random_bernoulli, random_normal, random_uniform};
+use crate::kernel::prng::{
+    random_bernoulli, random_exponential, random_gamma, random_normal, random_poisson,
+    random_uniform,
+};
 use crate::kernel::unary_basic::BasicFloatUnaryKind;
 use crate::kernel::{
     self, launch_unary_float, reduce, unary_basic, FloatUnaryOp, FloatUnaryOpFamily,
@@ -11,8 +14,9 @@ use crate::{
 use crate::{execute_with_dtype, JitBackend};
 use crate::{FloatElement, IntElement, JitRuntime};
 use burn_tensor::ops::{BoolTensor, Device, FloatElem, FloatTensor, IntTensor};
-use burn_tensor::{ops::FloatTensorOps, Distribution, Shape, TensorData};
+use burn_tensor::ops::{FloatTensorOps, IntTensorOps};
 use burn_tensor::{DType, ElementConversion, FloatDType};
+use burn_tensor::{Distribution, SegmentReduction, Shape, TensorData};
 use cubecl::prelude::*;
 use half::{bf16, f16};
 use std::ops::Range;
@@ -42,6 +46,11 @@ where
             Distribution::Normal(mean, std) => {
                 random_normal(shape, device, mean.elem::<F>(), std.elem())
             }
+            Distribution::Exponential(rate) => random_exponential(shape, device, rate.elem::<F>()),
+            Distribution::Gamma(gamma_shape, scale) => {
+                random_gamma(shape, device, gamma_shape.elem::<F>(), scale.elem())
+            }
+            Distribution::Poisson(rate) => random_poisson(shape, device, rate.elem::<F>()),
         }
     }
 
@@ -169,6 +178,20 @@ where
         )
     }
 
+    #[cfg(feature = "fp8")]
+    fn float_matmul_fp8(
+        lhs: FloatTensor<Self>,
+        lhs_scaling: burn_tensor::Fp8Scaling,
+        rhs: FloatTensor<Self>,
+        rhs_scaling: burn_tensor::Fp8Scaling,
+    ) -> FloatTensor<Self> {
+        execute_with_dtype!(
+            float(lhs.dtype, rhs.dtype),
+            E,
+            kernel::fp8::matmul_fp8::<R, E>(lhs, lhs_scaling, rhs, rhs_scaling)
+        )
+    }
+
     fn float_swap_dims(tensor: FloatTensor<Self>, dim1: usize, dim2: usize) -> FloatTensor<Self> {
         super::swap_dims(tensor, dim1, dim2)
     }
@@ -355,7 +378,7 @@ where
         execute_with_dtype!(
             float(tensor.dtype),
             E,
-            reduce::sum::<R, E>(tensor, Default::default()).unwrap()
+            reduce::sum_float::<R, E>(tensor, Default::default()).unwrap()
         )
     }
 
@@ -363,7 +386,7 @@ where
         execute_with_dtype!(
             float(tensor.dtype),
             E,
-            reduce::reduce_dim::<R, E, E, reduce::Sum>(tensor, dim, Default::default()).unwrap()
+            reduce::reduce_dim_float::<R, E, reduce::Sum>(tensor, dim, Default::default()).unwrap()
         )
     }
 
@@ -371,7 +394,8 @@ where
         execute_with_dtype!(
             float(tensor.dtype),
             E,
-            reduce::reduce_dim::<R, E, E, reduce::Mean>(tensor, dim, Default::default()).unwrap()
+            reduce::reduce_dim_float::<R, E, reduce::Mean>(tensor, dim, Default::default())
+                .unwrap()
         )
     }
 
@@ -379,7 +403,7 @@ where
         execute_with_dtype!(
             float(tensor.dtype),
             E,
-            reduce::reduce::<R, E, E, reduce::Prod>(tensor, Default::default()).unwrap()
+            reduce::reduce_float::<R, E, reduce::Prod>(tensor, Default::default()).unwrap()
         )
     }
 
@@ -387,7 +411,8 @@ where
         execute_with_dtype!(
             float(tensor.dtype),
             E,
-            reduce::reduce_dim::<R, E, E, reduce::Prod>(tensor, dim, Default::default()).unwrap()
+            reduce::reduce_dim_float::<R, E, reduce::Prod>(tensor, dim, Default::default())
+                .unwrap()
         )
     }
 
@@ -527,6 +552,35 @@ where
         )
     }
 
+    fn float_segment_reduce(
+        tensor: FloatTensor<Self>,
+        ids: IntTensor<Self>,
+        num_segments: usize,
+        reduction: SegmentReduction,
+        sorted: bool,
+    ) -> FloatTensor<Self> {
+        execute_with_dtype!(float(tensor.dtype), E, {
+            match sorted {
+                true => kernel::segment_reduce::<R, E, I>(tensor, ids, num_segments, reduction),
+                false => {
+                    kernel::unsorted_segment_reduce::<R, E, I>(tensor, ids, num_segments, reduction)
+                }
+            }
+        })
+    }
+
+    fn float_searchsorted(
+        sorted_sequence: FloatTensor<Self>,
+        values: FloatTensor<Self>,
+        right: bool,
+    ) -> IntTensor<Self> {
+        execute_with_dtype!(
+            float(values.dtype),
+            E,
+            kernel::searchsorted::<R, E, I>(sorted_sequence, values, right)
+        )
+    }
+
     fn float_cast(tensor: FloatTensor<Self>, dtype: FloatDType) -> FloatTensor<Self> {
         match (tensor.dtype, dtype) {
             (DType::F64, FloatDType::F64)
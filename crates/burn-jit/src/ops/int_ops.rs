@@ -7,7 +7,10 @@ use crate::kernel::{
 };
 use crate::{
     element::BoolElement,
-    kernel::prng::{random_bernoulli, random_normal, random_uniform},
+    kernel::prng::{
+        random_bernoulli, random_exponential, random_gamma, random_normal, random_poisson,
+        random_uniform,
+    },
 };
 use crate::{kernel, FloatElement, IntElement, JitBackend, JitRuntime};
 use burn_tensor::ops::{BoolTensor, Device, FloatTensor, IntElem, IntTensor};
@@ -225,6 +228,32 @@ where
         reduce::reduce_dim::<R, I, I, reduce::ArgMin>(tensor, dim, Default::default()).unwrap()
     }
 
+    fn int_kthvalue_with_indices(
+        tensor: IntTensor<Self>,
+        k: usize,
+        dim: usize,
+    ) -> (IntTensor<Self>, IntTensor<Self>) {
+        kernel::kthvalue_with_indices::<R, I>(tensor, k, dim)
+    }
+
+    fn int_bincount(tensor: IntTensor<Self>, min_length: usize) -> IntTensor<Self> {
+        // Only the largest value is read back to size the output, the values are counted on the
+        // device with a scatter add.
+        let num_bins = match tensor.shape.num_elements() {
+            0 => 0,
+            _ => {
+                let max = super::into_data_sync::<R, I>(Self::int_max(tensor.clone()));
+                max.iter::<i64>().next().unwrap() as usize + 1
+            }
+        };
+
+        let device = tensor.device.clone();
+        let ones = Self::int_ones(tensor.shape.clone(), &device);
+        let zeros = Self::int_zeros(Shape::new([num_bins.max(min_length)]), &device);
+
+        kernel::select_assign::<R, I, I>(zeros, 0, tensor, ones)
+    }
+
     fn int_clamp(
         tensor: IntTensor<Self>,
         min: IntElem<Self>,
@@ -282,6 +311,11 @@ where
             Distribution::Normal(mean, std) => {
                 random_normal(shape, device, mean.elem::<F>(), std.elem())
             }
+            Distribution::Exponential(rate) => random_exponential(shape, device, rate.elem::<F>()),
+            Distribution::Gamma(gamma_shape, scale) => {
+                random_gamma(shape, device, gamma_shape.elem::<F>(), scale.elem())
+            }
+            Distribution::Poisson(rate) => random_poisson(shape, device, rate.elem::<F>()),
         };
 
         kernel::cast::<R, F, I>(float_tensor)
@@ -2,13 +2,15 @@ use crate::{
     element::BoolElement,
     kernel::{
         self,
-        conv::{Conv2dStrategy, ConvTranspose2dStrategy},
+        conv::{Conv2dStrategy, Conv3dStrategy, ConvTranspose2dStrategy},
     },
     FloatElement, IntElement, JitBackend, JitRuntime,
 };
 use burn_tensor::ops::{
-    ConvOptions, ConvTransposeOptions, DeformConv2dBackward, DeformConvOptions, InterpolateOptions,
-    MaxPool2dBackward, MaxPool2dWithIndices, ModuleOps,
+    ConvOptions, ConvTransposeOptions, DeformConv2dBackward, DeformConvOptions, GridSampleOptions,
+    InterpolateOptions, LayerNormBackward, MaxPool2dBackward, MaxPool2dWithIndices,
+    MaxPool3dBackward, MaxPool3dWithIndices, ModuleOps, RmsNormBackward, RoiAlignOptions,
+    RoiPoolOptions, RotaryQk, UnfoldOptions,
 };
 use burn_tensor::ops::{FloatTensor, IntTensor};
 
@@ -66,7 +68,7 @@ where
         bias: Option<FloatTensor<Self>>,
         options: ConvOptions<3>,
     ) -> FloatTensor<Self> {
-        kernel::conv::conv3d::<R, F>(x, weight, bias, options)
+        kernel::conv::conv3d::<R, F>(x, weight, bias, options, Conv3dStrategy::default()).unwrap()
     }
 
     fn conv_transpose2d(
@@ -94,6 +96,23 @@ where
         kernel::conv::conv_transpose3d::<R, F>(x, weight, bias, options)
     }
 
+    fn unfold4d(
+        x: FloatTensor<Self>,
+        kernel_size: [usize; 2],
+        options: UnfoldOptions,
+    ) -> FloatTensor<Self> {
+        kernel::unfold4d::<R, F>(x, kernel_size, options)
+    }
+
+    fn fold4d(
+        x: FloatTensor<Self>,
+        output_size: [usize; 2],
+        kernel_size: [usize; 2],
+        options: UnfoldOptions,
+    ) -> FloatTensor<Self> {
+        kernel::fold4d::<R, F>(x, output_size, kernel_size, options)
+    }
+
     fn avg_pool2d(
         x: FloatTensor<Self>,
         kernel_size: [usize; 2],
@@ -170,6 +189,82 @@ where
         ))
     }
 
+    fn avg_pool3d(
+        x: FloatTensor<Self>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        count_include_pad: bool,
+    ) -> FloatTensor<Self> {
+        kernel::pool::avg_pool3d::<R, F>(x, kernel_size, stride, padding, count_include_pad)
+    }
+
+    fn avg_pool3d_backward(
+        x: FloatTensor<Self>,
+        grad: FloatTensor<Self>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        count_include_pad: bool,
+    ) -> FloatTensor<Self> {
+        kernel::pool::avg_pool3d_backward::<R, F>(
+            x,
+            grad,
+            kernel_size,
+            stride,
+            padding,
+            count_include_pad,
+        )
+    }
+
+    fn max_pool3d(
+        x: FloatTensor<Self>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+    ) -> FloatTensor<Self> {
+        kernel::pool::max_pool3d::<R, F>(x, kernel_size, stride, padding, dilation)
+    }
+
+    fn max_pool3d_with_indices(
+        x: FloatTensor<Self>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+    ) -> MaxPool3dWithIndices<Self> {
+        let (output, indices) = kernel::pool::max_pool3d_with_indices::<R, F, I>(
+            x,
+            kernel_size,
+            stride,
+            padding,
+            dilation,
+        );
+
+        MaxPool3dWithIndices::new(output, indices)
+    }
+
+    fn max_pool3d_with_indices_backward(
+        x: FloatTensor<Self>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+        output_grad: FloatTensor<Self>,
+        indices: IntTensor<Self>,
+    ) -> MaxPool3dBackward<Self> {
+        MaxPool3dBackward::new(kernel::pool::max_pool3d_with_indices_backward::<R, F, I>(
+            x,
+            output_grad,
+            indices,
+            kernel_size,
+            stride,
+            padding,
+            dilation,
+        ))
+    }
+
     fn adaptive_avg_pool2d(x: FloatTensor<Self>, output_size: [usize; 2]) -> FloatTensor<Self> {
         kernel::pool::adaptive_avg_pool2d::<R, F>(x, output_size)
     }
@@ -197,4 +292,76 @@ where
     ) -> FloatTensor<Self> {
         kernel::interpolate::interpolate_backward::<R, F>(x, grad, output_size, options)
     }
+
+    fn grid_sample(
+        x: FloatTensor<Self>,
+        grid: FloatTensor<Self>,
+        options: GridSampleOptions,
+    ) -> FloatTensor<Self> {
+        kernel::grid_sample::<R, F>(x, grid, options)
+    }
+
+    fn roi_align(
+        x: FloatTensor<Self>,
+        boxes: FloatTensor<Self>,
+        options: RoiAlignOptions,
+    ) -> FloatTensor<Self> {
+        kernel::roi_align::<R, F>(x, boxes, options)
+    }
+
+    fn roi_pool(
+        x: FloatTensor<Self>,
+        boxes: FloatTensor<Self>,
+        options: RoiPoolOptions,
+    ) -> FloatTensor<Self> {
+        kernel::roi_pool::<R, F>(x, boxes, options)
+    }
+
+    fn rms_norm(x: FloatTensor<Self>, gamma: FloatTensor<Self>, epsilon: f64) -> FloatTensor<Self> {
+        kernel::norm::rms_norm::<R, F>(x, gamma, epsilon)
+    }
+
+    fn rms_norm_backward(
+        x: FloatTensor<Self>,
+        gamma: FloatTensor<Self>,
+        output_grad: FloatTensor<Self>,
+        epsilon: f64,
+    ) -> RmsNormBackward<Self> {
+        let (x_grad, gamma_grad) =
+            kernel::norm::rms_norm_backward::<R, F>(x, gamma, output_grad, epsilon);
+
+        RmsNormBackward::new(x_grad, gamma_grad)
+    }
+
+    fn layer_norm(
+        x: FloatTensor<Self>,
+        gamma: FloatTensor<Self>,
+        beta: Option<FloatTensor<Self>>,
+        epsilon: f64,
+    ) -> FloatTensor<Self> {
+        kernel::norm::layer_norm::<R, F>(x, gamma, beta, epsilon)
+    }
+
+    fn layer_norm_backward(
+        x: FloatTensor<Self>,
+        gamma: FloatTensor<Self>,
+        output_grad: FloatTensor<Self>,
+        epsilon: f64,
+    ) -> LayerNormBackward<Self> {
+        let (x_grad, gamma_grad, beta_grad) =
+            kernel::norm::layer_norm_backward::<R, F>(x, gamma, output_grad, epsilon);
+
+        LayerNormBackward::new(x_grad, gamma_grad, beta_grad)
+    }
+
+    fn rotary_qk(
+        queries: FloatTensor<Self>,
+        keys: FloatTensor<Self>,
+        frequencies: FloatTensor<Self>,
+        start: usize,
+    ) -> RotaryQk<Self> {
+        let (queries, keys) = kernel::rotary_qk::<R, F>(queries, keys, frequencies, start);
+
+        RotaryQk::new(queries, keys)
+    }
 }
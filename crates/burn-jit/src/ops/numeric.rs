@@ -2,7 +2,7 @@ use crate::kernel::{
     launch_binop, launch_binop_int, launch_scalar_binop, launch_scalar_binop_int, AddOp,
     BitwiseAndOp, BitwiseOrOp, BitwiseXorOp, DivOp, MulOp, PowOp, RemainderOp, SubOp,
 };
-use crate::{element::JitElement, tensor::JitTensor};
+use crate::{element::JitElement, memory, tensor::JitTensor};
 use crate::{FloatElement, IntElement, JitRuntime};
 use burn_tensor::{ElementConversion, Shape};
 use cubecl::client::ComputeClient;
@@ -89,7 +89,8 @@ pub fn empty_device<R: JitRuntime, E: JitElement>(
     device: R::Device,
     shape: Shape,
 ) -> JitTensor<R> {
-    let buffer = client.empty(shape.num_elements() * core::mem::size_of::<E>());
+    let size = shape.num_elements() * core::mem::size_of::<E>();
+    let buffer = memory::empty::<R>(&client, &device, size);
 
     JitTensor::new_contiguous(client, device, shape, buffer, E::dtype())
 }
@@ -2,12 +2,12 @@ use std::ops::Range;
 
 use burn_tensor::{
     ops::{FloatTensor, IntTensor, QTensorOps, QuantizedTensor},
-    quantization::{QuantizationParametersPrimitive, QuantizationScheme, QuantizationType},
+    quantization::{QuantizationParametersPrimitive, QuantizationScheme},
     DType, Device, Shape, TensorData,
 };
 
 use crate::{
-    element::BoolElement, kernel, tensor::JitTensor, FloatElement, IntElement, JitBackend,
+    element::BoolElement, kernel, memory, tensor::JitTensor, FloatElement, IntElement, JitBackend,
     JitRuntime,
 };
 
@@ -19,7 +19,7 @@ fn new_qtensor<R: JitRuntime, S: Into<Shape>>(
     device: &R::Device,
 ) -> JitTensor<R> {
     let client = R::client(device);
-    let buffer = client.create(data);
+    let buffer = memory::create::<R>(&client, device, data);
 
     JitTensor::new_contiguous(
         client,
@@ -39,14 +39,11 @@ where
 {
     fn q_from_data(data: TensorData, device: &Device<Self>) -> QuantizedTensor<Self> {
         match data.dtype {
-            DType::QFloat(scheme) => match scheme {
-                QuantizationScheme::PerTensorAffine(QuantizationType::QInt8)
-                | QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt8) => {
-                    // TensorData quantized representation is the same, with multiple quantized values
-                    // packed into u32 and quantization parameters appended to the bytes
-                    new_qtensor(data.as_bytes(), data.shape.clone(), scheme, device)
-                }
-            },
+            DType::QFloat(scheme) => {
+                // TensorData quantized representation is the same, with multiple quantized values
+                // packed into u32 and quantization parameters appended to the bytes
+                new_qtensor(data.as_bytes(), data.shape.clone(), scheme, device)
+            }
             _ => panic!(
                 "Invalid dtype (expected DType::QFloat, got {:?})",
                 data.dtype
@@ -102,23 +99,23 @@ where
     }
 
     fn q_gather(
-        _dim: usize,
-        _tensor: QuantizedTensor<Self>,
-        _indices: IntTensor<Self>,
+        dim: usize,
+        tensor: QuantizedTensor<Self>,
+        indices: IntTensor<Self>,
     ) -> QuantizedTensor<Self> {
-        unimplemented!()
+        kernel::quantization::gather_packed::<R, I>(dim, tensor, indices, false)
     }
 
     fn q_select(
-        _tensor: QuantizedTensor<Self>,
-        _dim: usize,
-        _indices: IntTensor<Self>,
+        tensor: QuantizedTensor<Self>,
+        dim: usize,
+        indices: IntTensor<Self>,
     ) -> QuantizedTensor<Self> {
-        unimplemented!()
+        kernel::quantization::gather_packed::<R, I>(dim, tensor, indices, true)
     }
 
-    fn q_slice(_tensor: QuantizedTensor<Self>, _ranges: &[Range<usize>]) -> QuantizedTensor<Self> {
-        unimplemented!()
+    fn q_slice(tensor: QuantizedTensor<Self>, ranges: &[Range<usize>]) -> QuantizedTensor<Self> {
+        kernel::quantization::slice_packed(tensor, ranges)
     }
 
     fn q_expand(_tensor: QuantizedTensor<Self>, _shape: Shape) -> QuantizedTensor<Self> {
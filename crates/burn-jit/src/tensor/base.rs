@@ -1,5 +1,6 @@
 use crate::element::JitElement;
 use crate::kernel::{launch_unary_numeric, NumericUnaryOp, NumericUnaryOpFamily};
+use crate::memory;
 use crate::JitRuntime;
 use burn_tensor::quantization::QTensorPrimitive;
 use burn_tensor::{DType, Shape, TensorMetadata};
@@ -11,6 +12,11 @@ use cubecl::server::Handle;
 use std::marker::PhantomData;
 
 /// The basic tensor primitive struct.
+///
+/// Custom kernels can be launched on the tensor using its [client](JitTensor::client) and its
+/// [handle](JitTensor::as_handle_ref). Kernels launched on the same client are executed in the
+/// order they are submitted, so a custom kernel can read the output of previous operations and
+/// write tensors read by the following ones without any synchronization.
 #[derive(new)]
 pub struct JitTensor<R: JitRuntime> {
     /// Compute client for the [runtime](JitRuntime).
@@ -226,6 +232,12 @@ where
         }
     }
 
+    /// Create an uninitialized tensor with a contiguous memory layout on the same client and
+    /// device as the current tensor, which can be used as the output of a custom kernel.
+    pub fn new_empty<E: JitElement>(&self, shape: Shape) -> Self {
+        crate::ops::numeric::empty_device::<R, E>(self.client.clone(), self.device.clone(), shape)
+    }
+
     /// Change the context of the current tensor and return the newly transferred tensor.
     pub fn to_client(
         &self,
@@ -236,7 +248,7 @@ where
             self.client.read_one_async(self.handle.clone().binding()),
         )
         .expect("Can only change client synchronously");
-        let handle = client.create(&bytes);
+        let handle = memory::create::<R>(&client, &device, &bytes);
 
         Self {
             client,
@@ -2,7 +2,10 @@
 mod tests {
     use super::*;
     use burn_jit::{
-        kernel::{conv::nchw_to_nhwc, into_contiguous},
+        kernel::{
+            conv::{conv2d as conv2d_kernel, nchw_to_nhwc, Conv2dStrategy},
+            into_contiguous,
+        },
         tests::into_data_sync,
     };
     use burn_tensor::{backend::Backend, module, Distribution, Tensor};
@@ -55,6 +58,111 @@ mod tests {
             .assert_approx_eq(&output_ref.into_data(), 2);
     }
 
+    #[test]
+    fn depthwise_conv2d_should_match_reference_backend() {
+        let test_device = Default::default();
+        let input =
+            Tensor::<TestBackend, 4>::random([2, 8, 13, 11], Distribution::Default, &test_device);
+        let weight =
+            Tensor::<TestBackend, 4>::random([16, 1, 3, 3], Distribution::Default, &test_device);
+        let bias = Tensor::<TestBackend, 1>::random([16], Distribution::Default, &test_device);
+        let ref_device = Default::default();
+
+        let input_ref = Tensor::<ReferenceBackend, 4>::from_data(input.to_data(), &ref_device);
+        let weight_ref = Tensor::<ReferenceBackend, 4>::from_data(weight.to_data(), &ref_device);
+        let bias_ref = Tensor::<ReferenceBackend, 1>::from_data(bias.to_data(), &ref_device);
+
+        let options = burn_tensor::ops::ConvOptions::new([2, 1], [1, 2], [1, 2], 8);
+
+        type Float = <TestBackend as Backend>::FloatElem;
+
+        let output = conv2d_kernel::<TestRuntime, Float>(
+            input.into_primitive().tensor(),
+            weight.into_primitive().tensor(),
+            Some(bias.into_primitive().tensor()),
+            options.clone(),
+            Conv2dStrategy::Depthwise,
+        )
+        .unwrap();
+        let output_ref = module::conv2d(input_ref, weight_ref, Some(bias_ref), options);
+
+        into_data_sync::<TestRuntime, Float>(output).assert_approx_eq(&output_ref.into_data(), 3);
+    }
+
+    #[test]
+    fn depthwise_conv2d_should_reject_grouped_convolution() {
+        let test_device = Default::default();
+        let input =
+            Tensor::<TestBackend, 4>::random([1, 8, 5, 5], Distribution::Default, &test_device);
+        let weight =
+            Tensor::<TestBackend, 4>::random([8, 2, 3, 3], Distribution::Default, &test_device);
+
+        type Float = <TestBackend as Backend>::FloatElem;
+
+        let output = conv2d_kernel::<TestRuntime, Float>(
+            input.into_primitive().tensor(),
+            weight.into_primitive().tensor(),
+            None,
+            burn_tensor::ops::ConvOptions::new([1, 1], [1, 1], [1, 1], 4),
+            Conv2dStrategy::Depthwise,
+        );
+
+        assert!(output.is_err());
+    }
+
+    #[test]
+    fn winograd_conv2d_should_match_reference_backend() {
+        let test_device = Default::default();
+        let input =
+            Tensor::<TestBackend, 4>::random([2, 6, 9, 12], Distribution::Default, &test_device);
+        let weight =
+            Tensor::<TestBackend, 4>::random([5, 6, 3, 3], Distribution::Default, &test_device);
+        let bias = Tensor::<TestBackend, 1>::random([5], Distribution::Default, &test_device);
+        let ref_device = Default::default();
+
+        let input_ref = Tensor::<ReferenceBackend, 4>::from_data(input.to_data(), &ref_device);
+        let weight_ref = Tensor::<ReferenceBackend, 4>::from_data(weight.to_data(), &ref_device);
+        let bias_ref = Tensor::<ReferenceBackend, 1>::from_data(bias.to_data(), &ref_device);
+
+        // Odd output height, so the last row of tiles is cropped.
+        let options = burn_tensor::ops::ConvOptions::new([1, 1], [1, 2], [1, 1], 1);
+
+        type Float = <TestBackend as Backend>::FloatElem;
+
+        let output = conv2d_kernel::<TestRuntime, Float>(
+            input.into_primitive().tensor(),
+            weight.into_primitive().tensor(),
+            Some(bias.into_primitive().tensor()),
+            options.clone(),
+            Conv2dStrategy::Winograd,
+        )
+        .unwrap();
+        let output_ref = module::conv2d(input_ref, weight_ref, Some(bias_ref), options);
+
+        into_data_sync::<TestRuntime, Float>(output).assert_approx_eq(&output_ref.into_data(), 3);
+    }
+
+    #[test]
+    fn winograd_conv2d_should_reject_strided_convolution() {
+        let test_device = Default::default();
+        let input =
+            Tensor::<TestBackend, 4>::random([1, 4, 8, 8], Distribution::Default, &test_device);
+        let weight =
+            Tensor::<TestBackend, 4>::random([4, 4, 3, 3], Distribution::Default, &test_device);
+
+        type Float = <TestBackend as Backend>::FloatElem;
+
+        let output = conv2d_kernel::<TestRuntime, Float>(
+            input.into_primitive().tensor(),
+            weight.into_primitive().tensor(),
+            None,
+            burn_tensor::ops::ConvOptions::new([2, 2], [1, 1], [1, 1], 1),
+            Conv2dStrategy::Winograd,
+        );
+
+        assert!(output.is_err());
+    }
+
     /// Regression test for bias loader in new implicit GEMM
     #[test]
     fn conv2d_should_match_reference_backend_bias_regression() {
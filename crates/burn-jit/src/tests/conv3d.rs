@@ -1,7 +1,11 @@
 #[burn_tensor_testgen::testgen(conv3d)]
 mod tests {
     use super::*;
-    use burn_tensor::{module, Distribution, Tensor};
+    use burn_jit::{
+        kernel::conv::{conv3d as conv3d_kernel, Conv3dStrategy},
+        tests::into_data_sync,
+    };
+    use burn_tensor::{backend::Backend, module, Distribution, Tensor};
 
     #[test]
     fn conv3d_should_match_reference_backend() {
@@ -29,4 +33,81 @@ mod tests {
             .into_data()
             .assert_approx_eq(&output_ref.into_data(), 3);
     }
+
+    #[test]
+    fn conv3d_direct_should_match_reference_backend() {
+        test_strategy(
+            Conv3dStrategy::Direct,
+            [2, 4, 7, 9, 8],
+            [6, 2, 3, 2, 3],
+            burn_tensor::ops::ConvOptions::new([1, 2, 1], [1, 0, 2], [2, 1, 1], 2),
+        );
+    }
+
+    #[test]
+    fn conv3d_im2col_should_match_reference_backend() {
+        test_strategy(
+            Conv3dStrategy::Gemm,
+            [2, 4, 7, 9, 8],
+            [6, 2, 3, 2, 3],
+            burn_tensor::ops::ConvOptions::new([1, 2, 1], [1, 0, 2], [2, 1, 1], 2),
+        );
+    }
+
+    #[test]
+    fn conv3d_im2col_should_match_reference_backend_without_padding() {
+        test_strategy(
+            Conv3dStrategy::Gemm,
+            [3, 3, 6, 5, 7],
+            [4, 3, 2, 3, 2],
+            burn_tensor::ops::ConvOptions::new([2, 1, 1], [0, 0, 0], [1, 1, 2], 1),
+        );
+    }
+
+    #[test]
+    fn conv3d_im2col_should_match_reference_backend_1x1x1() {
+        test_strategy(
+            Conv3dStrategy::Gemm,
+            [2, 4, 3, 5, 4],
+            [6, 2, 1, 1, 1],
+            burn_tensor::ops::ConvOptions::new([1, 1, 1], [0, 0, 0], [1, 1, 1], 2),
+        );
+    }
+
+    fn test_strategy(
+        strategy: Conv3dStrategy,
+        input_shape: [usize; 5],
+        weight_shape: [usize; 5],
+        options: burn_tensor::ops::ConvOptions<3>,
+    ) {
+        let test_device = Default::default();
+        let input =
+            Tensor::<TestBackend, 5>::random(input_shape, Distribution::Default, &test_device);
+        let weight =
+            Tensor::<TestBackend, 5>::random(weight_shape, Distribution::Default, &test_device);
+        let bias = Tensor::<TestBackend, 1>::random(
+            [weight_shape[0]],
+            Distribution::Default,
+            &test_device,
+        );
+        let ref_device = Default::default();
+
+        let input_ref = Tensor::<ReferenceBackend, 5>::from_data(input.to_data(), &ref_device);
+        let weight_ref = Tensor::<ReferenceBackend, 5>::from_data(weight.to_data(), &ref_device);
+        let bias_ref = Tensor::<ReferenceBackend, 1>::from_data(bias.to_data(), &ref_device);
+
+        type Float = <TestBackend as Backend>::FloatElem;
+
+        let output = conv3d_kernel::<TestRuntime, Float>(
+            input.into_primitive().tensor(),
+            weight.into_primitive().tensor(),
+            Some(bias.into_primitive().tensor()),
+            options.clone(),
+            strategy,
+        )
+        .unwrap();
+        let output_ref = module::conv3d(input_ref, weight_ref, Some(bias_ref), options);
+
+        into_data_sync::<TestRuntime, Float>(output).assert_approx_eq(&output_ref.into_data(), 3);
+    }
 }
@@ -0,0 +1,24 @@
+#[burn_tensor_testgen::testgen(exponential)]
+mod tests {
+    use super::*;
+    use burn_tensor::{backend::Backend, Distribution, Tensor, TensorData};
+    use serial_test::serial;
+
+    #[test]
+    #[serial]
+    fn empirical_mean_and_variance_close_to_expectation() {
+        TestBackend::seed(0);
+        let device = Default::default();
+        let rate = 0.5;
+        let tensor =
+            Tensor::<TestBackend, 2>::random([100, 100], Distribution::Exponential(rate), &device);
+
+        let (variance, mean) = tensor.reshape([10_000]).var_mean(0);
+
+        mean.into_data()
+            .assert_approx_eq(&TensorData::from([1. / rate as f32]), 1);
+        variance
+            .into_data()
+            .assert_approx_eq(&TensorData::from([1. / (rate * rate) as f32]), 0);
+    }
+}
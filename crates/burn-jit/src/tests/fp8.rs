@@ -0,0 +1,115 @@
+#[burn_tensor_testgen::testgen(fp8)]
+mod tests {
+    use super::*;
+    use burn_jit::kernel::fp8::{
+        dequantize_fp8, fp8_from_data, fp8_into_data, quantize_fp8, scaled_matmul_fp8_direct,
+    };
+    use burn_tensor::{Fp8Format, Fp8Scaling, Tensor, TensorData, TensorPrimitive, F8E4M3};
+
+    #[test]
+    fn should_quantize_like_reference_conversion() {
+        let device = Default::default();
+        let values = [
+            -448.0, -1.1875, -0.001, 0.0, 0.3, 1.0625, 100.0, 1000.0, 2.5,
+        ];
+        let tensor = Tensor::<TestBackend, 1>::from_floats(values, &device);
+
+        let output = quantize_fp8::<TestRuntime, f32>(
+            tensor.into_primitive().tensor(),
+            1.0,
+            Fp8Format::E4M3,
+        );
+
+        let expected = values.map(F8E4M3::from_f32);
+        fp8_into_data(output).assert_eq(&TensorData::from(expected), true);
+    }
+
+    #[test]
+    fn should_dequantize_with_scale() {
+        let device = Default::default();
+        let data = TensorData::from([F8E4M3::from_f32(1.5), F8E4M3::from_f32(-0.25)]);
+        let tensor = fp8_from_data::<TestRuntime>(data, &device);
+
+        let output = dequantize_fp8::<TestRuntime, f32>(tensor, 2.0);
+        let output = Tensor::<TestBackend, 1>::from_primitive(TensorPrimitive::Float(output));
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([3.0, -0.5]), false);
+    }
+
+    #[test]
+    fn should_match_matmul_of_dequantized_tensors() {
+        let device = Default::default();
+        let lhs = TestTensor::<3>::from_floats(
+            [
+                [[1.0, -2.0, 0.5], [3.0, 0.25, -1.5]],
+                [[0.1, 2.0, -3.0], [4.0, 1.0, 0.0]],
+            ],
+            &device,
+        );
+        let rhs = TestTensor::<3>::from_floats(
+            [
+                [[2.0, 1.0], [-1.0, 0.5], [0.0, 3.0]],
+                [[1.5, -2.0], [0.25, 1.0], [-1.0, 2.0]],
+            ],
+            &device,
+        );
+        let (lhs_scale, rhs_scale) = (0.5, 0.25);
+
+        let lhs = quantize_fp8::<TestRuntime, f32>(
+            lhs.into_primitive().tensor(),
+            lhs_scale,
+            Fp8Format::E4M3,
+        );
+        let rhs = quantize_fp8::<TestRuntime, f32>(
+            rhs.into_primitive().tensor(),
+            rhs_scale,
+            Fp8Format::E5M2,
+        );
+
+        let lhs_float = dequantize_fp8::<TestRuntime, f32>(lhs.clone(), lhs_scale);
+        let rhs_float = dequantize_fp8::<TestRuntime, f32>(rhs.clone(), rhs_scale);
+        let expected = TestTensor::<3>::from_primitive(TensorPrimitive::Float(lhs_float)).matmul(
+            TestTensor::from_primitive(TensorPrimitive::Float(rhs_float)),
+        );
+        let output = scaled_matmul_fp8_direct::<TestRuntime, f32>(lhs, lhs_scale, rhs, rhs_scale);
+        let output = TestTensor::<3>::from_primitive(TensorPrimitive::Float(output));
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 4);
+    }
+
+    #[test]
+    fn should_match_matmul_for_representable_values() {
+        let device = Default::default();
+        let lhs = TestTensor::<2>::from_floats([[1.0, -2.0], [0.5, 3.0]], &device);
+        let rhs = TestTensor::<2>::from_floats([[2.0, 1.0], [-1.0, 0.25]], &device);
+        let scaling = Fp8Scaling::new(Fp8Format::E4M3, 1.0);
+
+        let output = lhs.clone().matmul_fp8(rhs.clone(), scaling, scaling);
+
+        output
+            .into_data()
+            .assert_approx_eq(&lhs.matmul(rhs).into_data(), 3);
+    }
+
+    #[test]
+    fn should_round_inputs_of_matmul_fp8() {
+        let device = Default::default();
+        let lhs = TestTensor::<2>::from_floats([[1.1, 2.0]], &device);
+        let rhs = TestTensor::<2>::from_floats([[1.0], [0.5]], &device);
+
+        // 1.1 is rounded to 1.125 in E4M3, and 0.5 is 1.0 scaled by 0.5.
+        let output = lhs.matmul_fp8(
+            rhs,
+            Fp8Scaling::new(Fp8Format::E4M3, 1.0),
+            Fp8Scaling::new(Fp8Format::E5M2, 0.5),
+        );
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[2.125]]), 3);
+    }
+}
@@ -0,0 +1,45 @@
+#[burn_tensor_testgen::testgen(gamma)]
+mod tests {
+    use super::*;
+    use burn_tensor::{backend::Backend, Distribution, Tensor, TensorData};
+    use serial_test::serial;
+
+    #[test]
+    #[serial]
+    fn empirical_mean_and_variance_close_to_expectation() {
+        TestBackend::seed(0);
+        let device = Default::default();
+        let (shape, scale) = (3., 2.);
+        let tensor = Tensor::<TestBackend, 2>::random(
+            [100, 100],
+            Distribution::Gamma(shape, scale),
+            &device,
+        );
+
+        let (variance, mean) = tensor.reshape([10_000]).var_mean(0);
+
+        mean.into_data()
+            .assert_approx_eq(&TensorData::from([(shape * scale) as f32]), 0);
+        variance
+            .into_data()
+            .assert_approx_eq(&TensorData::from([(shape * scale * scale) as f32]), 0);
+    }
+
+    #[test]
+    #[serial]
+    fn empirical_mean_close_to_expectation_for_small_shape() {
+        TestBackend::seed(0);
+        let device = Default::default();
+        let tensor =
+            Tensor::<TestBackend, 2>::random([100, 100], Distribution::Gamma(0.3, 1.), &device);
+
+        tensor
+            .clone()
+            .into_data()
+            .assert_within_range(0.0..f32::INFINITY);
+        tensor
+            .mean()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.3f32]), 1);
+    }
+}
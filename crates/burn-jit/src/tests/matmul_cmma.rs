@@ -0,0 +1,77 @@
+#[burn_tensor_testgen::testgen(matmul_cmma)]
+mod tests {
+    use super::*;
+    use burn_jit::kernel::matmul::{matmul, MatmulStrategy};
+    use burn_tensor::{backend::Backend, Distribution, Tensor, TensorPrimitive};
+
+    #[test]
+    fn cmma_should_match_reference_backend_aligned() {
+        test_cmma([2, 128, 64], [2, 64, 128]);
+    }
+
+    #[test]
+    fn cmma_should_match_reference_backend_unaligned() {
+        test_cmma([3, 70, 37], [3, 37, 95]);
+    }
+
+    #[test]
+    fn cmma_should_match_reference_backend_broadcast() {
+        test_cmma([2, 1, 33, 40], [1, 3, 40, 17]);
+    }
+
+    #[test]
+    fn cmma_should_match_reference_backend_transposed() {
+        let device = Default::default();
+        let lhs = TestTensor::<3>::random([2, 48, 80], Distribution::Default, &device);
+        let rhs = TestTensor::<3>::random([2, 48, 80], Distribution::Default, &device);
+        let ref_device = Default::default();
+        let lhs_ref = ReferenceTensor::<3>::from_data(lhs.to_data(), &ref_device);
+        let rhs_ref = ReferenceTensor::<3>::from_data(rhs.to_data(), &ref_device);
+
+        let Some(output) = matmul_cmma(lhs.transpose(), rhs) else {
+            return;
+        };
+        let expected = lhs_ref.transpose().matmul(rhs_ref);
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 1);
+    }
+
+    fn test_cmma<const D: usize>(shape_lhs: [usize; D], shape_rhs: [usize; D]) {
+        let device = Default::default();
+        let lhs = TestTensor::<D>::random(shape_lhs, Distribution::Default, &device);
+        let rhs = TestTensor::<D>::random(shape_rhs, Distribution::Default, &device);
+        let ref_device = Default::default();
+        let lhs_ref = ReferenceTensor::<D>::from_data(lhs.to_data(), &ref_device);
+        let rhs_ref = ReferenceTensor::<D>::from_data(rhs.to_data(), &ref_device);
+
+        let Some(output) = matmul_cmma(lhs, rhs) else {
+            return;
+        };
+        let expected = lhs_ref.matmul(rhs_ref);
+
+        // The inputs are multiplied with a reduced precision.
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 1);
+    }
+
+    /// Launch the CMMA matmul, `None` when the device doesn't support it.
+    fn matmul_cmma<const D: usize>(
+        lhs: TestTensor<D>,
+        rhs: TestTensor<D>,
+    ) -> Option<TestTensor<D>> {
+        type Float = <TestBackend as Backend>::FloatElem;
+
+        let output = matmul::<TestRuntime, Float>(
+            lhs.into_primitive().tensor(),
+            rhs.into_primitive().tensor(),
+            None,
+            MatmulStrategy::Cmma,
+        )
+        .ok()?;
+
+        Some(Tensor::from_primitive(TensorPrimitive::Float(output)))
+    }
+}
@@ -0,0 +1,74 @@
+#[burn_tensor_testgen::testgen(matmul_strided)]
+mod tests {
+    use super::*;
+    use burn_jit::kernel::matmul::{matmul, MatmulStrategy};
+    use burn_tensor::{backend::Backend, Distribution, Tensor, TensorPrimitive};
+
+    #[test]
+    fn strided_should_match_reference_backend_swapped_heads() {
+        // Queries and keys of an attention layer, with the heads swapped with the sequence.
+        let device = Default::default();
+        let queries = TestTensor::<4>::random([2, 9, 4, 8], Distribution::Default, &device);
+        let keys = TestTensor::<4>::random([2, 9, 4, 8], Distribution::Default, &device);
+        let ref_device = Default::default();
+        let queries_ref = ReferenceTensor::<4>::from_data(queries.to_data(), &ref_device);
+        let keys_ref = ReferenceTensor::<4>::from_data(keys.to_data(), &ref_device);
+
+        let output = matmul_strided(queries.swap_dims(1, 2), keys.swap_dims(1, 2).transpose());
+        let expected = queries_ref
+            .swap_dims(1, 2)
+            .matmul(keys_ref.swap_dims(1, 2).transpose());
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    fn strided_should_match_reference_backend_expanded_batch() {
+        let device = Default::default();
+        let lhs = TestTensor::<3>::random([3, 17, 10], Distribution::Default, &device);
+        let rhs = TestTensor::<2>::random([10, 21], Distribution::Default, &device);
+        let ref_device = Default::default();
+        let lhs_ref = ReferenceTensor::<3>::from_data(lhs.to_data(), &ref_device);
+        let rhs_ref = ReferenceTensor::<2>::from_data(rhs.to_data(), &ref_device);
+
+        let output = matmul_strided(lhs, rhs.unsqueeze::<3>().expand([3, 10, 21]));
+        let expected = lhs_ref.matmul(rhs_ref.unsqueeze());
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    fn strided_should_match_reference_backend_column_major() {
+        let device = Default::default();
+        let lhs = TestTensor::<3>::random([2, 70, 33], Distribution::Default, &device);
+        let rhs = TestTensor::<3>::random([2, 65, 70], Distribution::Default, &device);
+        let ref_device = Default::default();
+        let lhs_ref = ReferenceTensor::<3>::from_data(lhs.to_data(), &ref_device);
+        let rhs_ref = ReferenceTensor::<3>::from_data(rhs.to_data(), &ref_device);
+
+        let output = matmul_strided(lhs.transpose(), rhs.transpose());
+        let expected = lhs_ref.transpose().matmul(rhs_ref.transpose());
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    fn matmul_strided<const D: usize>(lhs: TestTensor<D>, rhs: TestTensor<D>) -> TestTensor<D> {
+        type Float = <TestBackend as Backend>::FloatElem;
+
+        let output = matmul::<TestRuntime, Float>(
+            lhs.into_primitive().tensor(),
+            rhs.into_primitive().tensor(),
+            None,
+            MatmulStrategy::Strided,
+        )
+        .unwrap();
+
+        Tensor::from_primitive(TensorPrimitive::Float(output))
+    }
+}
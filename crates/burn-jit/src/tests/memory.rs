@@ -0,0 +1,64 @@
+#[burn_tensor_testgen::testgen(memory)]
+mod tests {
+    use super::*;
+    use burn_tensor::{backend::Backend, Distribution, Tensor};
+
+    #[test]
+    fn memory_stats_should_count_allocated_tensors() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 2>::random([256, 256], Distribution::Default, &device);
+        TestBackend::sync(&device);
+
+        let stats = TestBackend::memory_stats(&device).expect("Jit backends track their memory");
+
+        assert!(stats.bytes_in_use >= 256 * 256 * 4);
+        assert!(stats.bytes_reserved >= stats.bytes_in_use);
+        assert!(stats.peak_bytes_in_use >= stats.bytes_in_use);
+        core::mem::drop(tensor);
+    }
+
+    #[test]
+    fn memory_stats_should_track_peak_at_allocation() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 2>::ones([512, 512], &device);
+        core::mem::drop(tensor);
+        TestBackend::sync(&device);
+
+        // The stats weren't read while the tensor was allocated.
+        let stats = TestBackend::memory_stats(&device).expect("Jit backends track their memory");
+
+        assert!(stats.peak_bytes_in_use >= 512 * 512 * 4);
+    }
+
+    #[test]
+    fn should_allocate_over_memory_budget() {
+        let device = Default::default();
+        let _budget = MemoryBudget::set(&device, 1024);
+
+        let tensor = Tensor::<TestBackend, 2>::ones([128, 128], &device);
+        let output = (tensor.clone() + tensor).sum();
+
+        assert_eq!(output.into_scalar(), 2.0 * 128.0 * 128.0);
+    }
+
+    /// Removes the memory budget of the device when dropped, even if the test panics, since the
+    /// device is shared with the other tests.
+    struct MemoryBudget {
+        device: <TestBackend as Backend>::Device,
+    }
+
+    impl MemoryBudget {
+        fn set(device: &<TestBackend as Backend>::Device, budget: u64) -> Self {
+            TestBackend::set_memory_budget(device, Some(budget));
+            Self {
+                device: device.clone(),
+            }
+        }
+    }
+
+    impl Drop for MemoryBudget {
+        fn drop(&mut self) {
+            TestBackend::set_memory_budget(&self.device, None);
+        }
+    }
+}
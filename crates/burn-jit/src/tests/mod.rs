@@ -9,17 +9,29 @@ mod conv2d;
 mod conv3d;
 mod conv_transpose2d;
 mod conv_transpose3d;
+mod exponential;
+#[cfg(feature = "fp8")]
+mod fp8;
+mod gamma;
 mod gather;
 mod mask_fill;
 mod mask_where;
 mod matmul;
+mod matmul_cmma;
+mod matmul_strided;
 mod max_pool2d;
 mod max_pool2d_backward;
+mod memory;
+mod norm;
 mod normal;
+mod poisson;
 mod quantization;
 mod reduce;
 mod repeat_dim;
+mod rotary;
 mod scatter;
+mod searchsorted;
+mod segment;
 mod select;
 mod select_assign;
 mod slice;
@@ -53,9 +65,15 @@ macro_rules! testgen_all {
                 burn_jit::testgen_conv_transpose2d!();
                 burn_jit::testgen_conv_transpose3d!();
 
+                burn_jit::testgen_matmul_cmma!();
+                burn_jit::testgen_matmul_strided!();
+
                 burn_jit::testgen_repeat_dim!();
                 burn_jit::testgen_gather!();
                 burn_jit::testgen_scatter!();
+                burn_jit::testgen_searchsorted!();
+                burn_jit::testgen_segment!();
+                burn_jit::testgen_memory!();
 
                 burn_jit::testgen_select!();
                 burn_jit::testgen_select_assign!();
@@ -70,8 +88,14 @@ macro_rules! testgen_all {
                 burn_jit::testgen_max_pool2d!();
                 burn_jit::testgen_max_pool2d_backward!();
 
+                burn_jit::testgen_norm!();
+                burn_jit::testgen_rotary!();
+
                 burn_jit::testgen_bernoulli!();
                 burn_jit::testgen_normal!();
+                burn_jit::testgen_exponential!();
+                burn_jit::testgen_gamma!();
+                burn_jit::testgen_poisson!();
                 burn_jit::testgen_uniform!();
 
                 burn_jit::testgen_cast!();
@@ -82,6 +106,7 @@ macro_rules! testgen_all {
                 burn_jit::testgen_reduce!();
 
                 burn_jit::testgen_quantization!();
+                burn_jit::testgen_fp8!();
             }
         }
         mod jit_fusion {
@@ -90,6 +115,13 @@ macro_rules! testgen_all {
     };
 }
 
+/// The FP8 kernels are only tested when the feature is enabled.
+#[cfg(not(feature = "fp8"))]
+#[macro_export]
+macro_rules! testgen_fp8 {
+    () => {};
+}
+
 #[macro_export]
 macro_rules! testgen_jit {
     () => {
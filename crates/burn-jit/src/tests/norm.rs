@@ -0,0 +1,124 @@
+#[burn_tensor_testgen::testgen(norm)]
+mod tests {
+    use super::*;
+    use burn_tensor::{module, ops::ModuleOps, Distribution, Tensor, TensorPrimitive};
+
+    const EPSILON: f64 = 1e-5;
+
+    fn inputs(
+        shape: [usize; 3],
+    ) -> (
+        Tensor<TestBackend, 3>,
+        Tensor<TestBackend, 1>,
+        Tensor<TestBackend, 1>,
+        Tensor<TestBackend, 3>,
+    ) {
+        let device = Default::default();
+        let x = Tensor::random(shape, Distribution::Default, &device);
+        let gamma = Tensor::random([shape[2]], Distribution::Default, &device);
+        let beta = Tensor::random([shape[2]], Distribution::Default, &device);
+        let grad = Tensor::random(shape, Distribution::Default, &device);
+
+        (x, gamma, beta, grad)
+    }
+
+    fn reference<const D: usize>(tensor: &Tensor<TestBackend, D>) -> Tensor<ReferenceBackend, D> {
+        Tensor::from_data(tensor.to_data(), &Default::default())
+    }
+
+    fn assert_approx_eq<const D: usize>(
+        primitive: <TestBackend as burn_tensor::backend::Backend>::FloatTensorPrimitive,
+        primitive_ref: <ReferenceBackend as burn_tensor::backend::Backend>::FloatTensorPrimitive,
+        precision: usize,
+    ) {
+        Tensor::<TestBackend, D>::from_primitive(TensorPrimitive::Float(primitive))
+            .into_data()
+            .assert_approx_eq(
+                &Tensor::<ReferenceBackend, D>::from_primitive(TensorPrimitive::Float(
+                    primitive_ref,
+                ))
+                .into_data(),
+                precision,
+            );
+    }
+
+    #[test]
+    fn rms_norm_should_match_reference_backend() {
+        test_rms_norm([4, 17, 96], 3);
+    }
+
+    #[test]
+    fn rms_norm_should_match_reference_backend_with_rows_larger_than_cube() {
+        // More features than units per cube and more rows than a partial sum of the gradients.
+        test_rms_norm([3, 70, 600], 2);
+    }
+
+    #[test]
+    fn layer_norm_should_match_reference_backend() {
+        test_layer_norm([4, 17, 96], 3);
+    }
+
+    #[test]
+    fn layer_norm_should_match_reference_backend_with_rows_larger_than_cube() {
+        test_layer_norm([3, 70, 600], 2);
+    }
+
+    fn test_rms_norm(shape: [usize; 3], precision: usize) {
+        let (x, gamma, _beta, grad) = inputs(shape);
+        let (x_ref, gamma_ref, grad_ref) = (reference(&x), reference(&gamma), reference(&grad));
+
+        let output = module::rms_norm(x.clone(), gamma.clone(), EPSILON);
+        let output_ref = module::rms_norm(x_ref.clone(), gamma_ref.clone(), EPSILON);
+        output
+            .into_data()
+            .assert_approx_eq(&output_ref.into_data(), precision);
+
+        let backward = TestBackend::rms_norm_backward(
+            x.into_primitive().tensor(),
+            gamma.into_primitive().tensor(),
+            grad.into_primitive().tensor(),
+            EPSILON,
+        );
+        let backward_ref = ReferenceBackend::rms_norm_backward(
+            x_ref.into_primitive().tensor(),
+            gamma_ref.into_primitive().tensor(),
+            grad_ref.into_primitive().tensor(),
+            EPSILON,
+        );
+        assert_approx_eq::<3>(backward.x_grad, backward_ref.x_grad, precision);
+        assert_approx_eq::<1>(backward.gamma_grad, backward_ref.gamma_grad, precision);
+    }
+
+    fn test_layer_norm(shape: [usize; 3], precision: usize) {
+        let (x, gamma, beta, grad) = inputs(shape);
+        let (x_ref, gamma_ref, beta_ref, grad_ref) = (
+            reference(&x),
+            reference(&gamma),
+            reference(&beta),
+            reference(&grad),
+        );
+
+        let output = module::layer_norm(x.clone(), gamma.clone(), Some(beta), EPSILON);
+        let output_ref =
+            module::layer_norm(x_ref.clone(), gamma_ref.clone(), Some(beta_ref), EPSILON);
+        output
+            .into_data()
+            .assert_approx_eq(&output_ref.into_data(), precision);
+
+        let backward = TestBackend::layer_norm_backward(
+            x.into_primitive().tensor(),
+            gamma.into_primitive().tensor(),
+            grad.into_primitive().tensor(),
+            EPSILON,
+        );
+        let backward_ref = ReferenceBackend::layer_norm_backward(
+            x_ref.into_primitive().tensor(),
+            gamma_ref.into_primitive().tensor(),
+            grad_ref.into_primitive().tensor(),
+            EPSILON,
+        );
+        assert_approx_eq::<3>(backward.x_grad, backward_ref.x_grad, precision);
+        assert_approx_eq::<1>(backward.gamma_grad, backward_ref.gamma_grad, precision);
+        assert_approx_eq::<1>(backward.beta_grad, backward_ref.beta_grad, precision);
+    }
+}
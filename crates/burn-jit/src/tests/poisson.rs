@@ -0,0 +1,40 @@
+#[burn_tensor_testgen::testgen(poisson)]
+mod tests {
+    use super::*;
+    use burn_tensor::{backend::Backend, Distribution, ElementConversion, Tensor};
+    use serial_test::serial;
+
+    #[test]
+    #[serial]
+    fn empirical_mean_and_variance_close_to_expectation() {
+        TestBackend::seed(0);
+        let device = Default::default();
+
+        // Both the multiplication method for small rates and the rejection method for larger ones
+        for rate in [2.5, 40.] {
+            let tensor =
+                Tensor::<TestBackend, 2>::random([100, 100], Distribution::Poisson(rate), &device);
+            let data = tensor.clone().into_data();
+            for value in data.iter::<f32>() {
+                assert!(
+                    value >= 0.0 && value == value.round(),
+                    "Invalid sample {value}"
+                );
+            }
+
+            let (variance, mean) = tensor.reshape([10_000]).var_mean(0);
+            let tolerance = rate as f32 * 0.05;
+
+            let mean = mean.into_scalar().elem::<f32>();
+            let variance = variance.into_scalar().elem::<f32>();
+            assert!(
+                (mean - rate as f32).abs() < tolerance,
+                "Mean {mean} for rate {rate}"
+            );
+            assert!(
+                (variance - rate as f32).abs() < 2. * tolerance,
+                "Variance {variance} for rate {rate}"
+            );
+        }
+    }
+}
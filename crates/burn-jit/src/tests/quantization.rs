@@ -3,7 +3,7 @@ mod tests {
     use super::*;
     use burn_tensor::{
         quantization::{QuantizationScheme, QuantizationType},
-        Tensor,
+        Int, Tensor,
     };
 
     #[test]
@@ -79,4 +79,111 @@ mod tests {
 
         output.to_data().assert_approx_eq(&output_ref.to_data(), 3);
     }
+
+    #[test]
+    fn should_quantize_dequantize_symmetric_int4() {
+        let scheme = QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt4);
+        let input = Tensor::<TestBackend, 1>::from_floats(
+            [-1.8, -1.0, 0.0, 0.5, 0.0, 1.2, -0.3, 0.7, 1.5],
+            &Default::default(),
+        );
+        let input_ref =
+            Tensor::<ReferenceBackend, 1>::from_data(input.to_data(), &Default::default());
+
+        let output = input.quantize_dynamic(&scheme);
+        let output_ref = input_ref.quantize_dynamic(&scheme);
+
+        output.to_data().assert_eq(&output_ref.to_data(), false);
+
+        let output = output.dequantize();
+        let output_ref = output_ref.dequantize();
+
+        output.to_data().assert_approx_eq(&output_ref.to_data(), 3);
+    }
+
+    #[test]
+    fn should_quantize_dequantize_affine_int4() {
+        let scheme = QuantizationScheme::PerTensorAffine(QuantizationType::QInt4);
+        let input = Tensor::<TestBackend, 1>::from_floats(
+            [-1.8, -1.0, 0.0, 0.5, 0.0, 1.2, -0.3, 0.7, 1.5],
+            &Default::default(),
+        );
+        let input_ref =
+            Tensor::<ReferenceBackend, 1>::from_data(input.to_data(), &Default::default());
+
+        let output = input.quantize_dynamic(&scheme);
+        let output_ref = input_ref.quantize_dynamic(&scheme);
+
+        output.to_data().assert_eq(&output_ref.to_data(), false);
+
+        let output = output.dequantize();
+        let output_ref = output_ref.dequantize();
+
+        output.to_data().assert_approx_eq(&output_ref.to_data(), 3);
+    }
+
+    #[test]
+    fn should_quantize_dequantize_affine_int2() {
+        let scheme = QuantizationScheme::PerTensorAffine(QuantizationType::QInt2);
+        let input = Tensor::<TestBackend, 1>::from_floats(
+            [-1.8, -1.0, 0.0, 0.5, 0.0, 1.2, -0.3, 0.7, 1.5],
+            &Default::default(),
+        );
+        let input_ref =
+            Tensor::<ReferenceBackend, 1>::from_data(input.to_data(), &Default::default());
+
+        let output = input.quantize_dynamic(&scheme);
+        let output_ref = input_ref.quantize_dynamic(&scheme);
+
+        output.to_data().assert_eq(&output_ref.to_data(), false);
+
+        let output = output.dequantize();
+        let output_ref = output_ref.dequantize();
+
+        output.to_data().assert_approx_eq(&output_ref.to_data(), 3);
+    }
+
+    #[test]
+    fn should_slice_packed_int4() {
+        let scheme = QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt4);
+        let input = Tensor::<TestBackend, 1>::arange(0..36, &Default::default())
+            .float()
+            .sub_scalar(18.0)
+            .reshape([4, 9]);
+        let input_ref =
+            Tensor::<ReferenceBackend, 2>::from_data(input.to_data(), &Default::default());
+
+        let output = input.quantize_dynamic(&scheme).slice([1..3, 2..7]);
+        let output_ref = input_ref.quantize_dynamic(&scheme).slice([1..3, 2..7]);
+
+        output.to_data().assert_eq(&output_ref.to_data(), false);
+    }
+
+    #[test]
+    fn should_select_and_gather_packed_int4() {
+        let scheme = QuantizationScheme::PerTensorAffine(QuantizationType::QInt4);
+        let device = Default::default();
+        let input = Tensor::<TestBackend, 1>::arange(0..12, &device)
+            .float()
+            .reshape([3, 4]);
+        let input_ref = Tensor::<ReferenceBackend, 2>::from_data(input.to_data(), &device);
+        let input = input.quantize_dynamic(&scheme);
+        let input_ref = input_ref.quantize_dynamic(&scheme);
+
+        let indices = Tensor::<TestBackend, 1, Int>::from_ints([2, 0], &device);
+        let indices_ref = Tensor::<ReferenceBackend, 1, Int>::from_data(indices.to_data(), &device);
+
+        let output = input.clone().select(0, indices);
+        let output_ref = input_ref.clone().select(0, indices_ref);
+
+        output.to_data().assert_eq(&output_ref.to_data(), false);
+
+        let indices = Tensor::<TestBackend, 2, Int>::from_ints([[3, 0], [1, 1], [0, 2]], &device);
+        let indices_ref = Tensor::<ReferenceBackend, 2, Int>::from_data(indices.to_data(), &device);
+
+        let output = input.gather(1, indices);
+        let output_ref = input_ref.gather(1, indices_ref);
+
+        output.to_data().assert_eq(&output_ref.to_data(), false);
+    }
 }
@@ -0,0 +1,69 @@
+#[burn_tensor_testgen::testgen(rotary)]
+mod tests {
+    use super::*;
+    use burn_tensor::{module, Distribution, Tensor};
+
+    fn reference<const D: usize>(tensor: &Tensor<TestBackend, D>) -> Tensor<ReferenceBackend, D> {
+        Tensor::from_data(tensor.to_data(), &Default::default())
+    }
+
+    fn rotary_qk_should_match_reference_backend(rotary_dim: usize, start: usize) {
+        let device = Default::default();
+        let queries =
+            Tensor::<TestBackend, 4>::random([2, 7, 8, 32], Distribution::Default, &device);
+        let keys = Tensor::<TestBackend, 4>::random([2, 7, 2, 32], Distribution::Default, &device);
+        let frequencies =
+            Tensor::<TestBackend, 3>::random([16, rotary_dim, 2], Distribution::Default, &device);
+        let (queries_ref, keys_ref, frequencies_ref) = (
+            reference(&queries),
+            reference(&keys),
+            reference(&frequencies),
+        );
+
+        let (queries, keys) = module::rotary_qk(queries, keys, frequencies, start);
+        let (queries_ref, keys_ref) =
+            module::rotary_qk(queries_ref, keys_ref, frequencies_ref, start);
+
+        queries
+            .into_data()
+            .assert_approx_eq(&queries_ref.into_data(), 3);
+        keys.into_data().assert_approx_eq(&keys_ref.into_data(), 3);
+    }
+
+    #[test]
+    fn rotary_qk_full_rotation() {
+        rotary_qk_should_match_reference_backend(32, 0);
+    }
+
+    #[test]
+    fn rotary_qk_partial_rotation_with_start() {
+        rotary_qk_should_match_reference_backend(16, 5);
+    }
+
+    #[test]
+    fn rotary_qk_strided_inputs() {
+        let device = Default::default();
+        // Projections of shape [batch_size, n_heads, seq_length, d_head] swapped to the expected
+        // layout, which is read without being copied.
+        let queries =
+            Tensor::<TestBackend, 4>::random([1, 4, 9, 16], Distribution::Default, &device)
+                .swap_dims(1, 2);
+        let keys = Tensor::<TestBackend, 4>::random([1, 4, 9, 16], Distribution::Default, &device)
+            .swap_dims(1, 2);
+        let frequencies =
+            Tensor::<TestBackend, 3>::random([9, 16, 2], Distribution::Default, &device);
+        let (queries_ref, keys_ref, frequencies_ref) = (
+            reference(&queries),
+            reference(&keys),
+            reference(&frequencies),
+        );
+
+        let (queries, keys) = module::rotary_qk(queries, keys, frequencies, 0);
+        let (queries_ref, keys_ref) = module::rotary_qk(queries_ref, keys_ref, frequencies_ref, 0);
+
+        queries
+            .into_data()
+            .assert_approx_eq(&queries_ref.into_data(), 3);
+        keys.into_data().assert_approx_eq(&keys_ref.into_data(), 3);
+    }
+}
@@ -0,0 +1,35 @@
+#[burn_tensor_testgen::testgen(searchsorted)]
+mod tests {
+    use super::*;
+    use burn_tensor::{backend::Backend, Distribution, Tensor};
+
+    #[test]
+    fn searchsorted_should_match_reference_left() {
+        test_same_as_ref(false);
+    }
+
+    #[test]
+    fn searchsorted_should_match_reference_right() {
+        test_same_as_ref(true);
+    }
+
+    fn test_same_as_ref(right: bool) {
+        TestBackend::seed(0);
+        let device = Default::default();
+        // Rounded values, so that some values are equal to elements of the sequence.
+        let sorted =
+            Tensor::<TestBackend, 1>::random([257], Distribution::Uniform(0., 50.), &device)
+                .round()
+                .sort(0);
+        let values =
+            Tensor::<TestBackend, 3>::random([4, 33, 17], Distribution::Uniform(-5., 55.), &device)
+                .round();
+        let sorted_ref = Tensor::<ReferenceBackend, 1>::from_data(sorted.to_data(), &device);
+        let values_ref = Tensor::<ReferenceBackend, 3>::from_data(values.to_data(), &device);
+
+        let actual = sorted.searchsorted(values, right);
+        let expected = sorted_ref.searchsorted(values_ref, right);
+
+        expected.into_data().assert_eq(&actual.into_data(), false);
+    }
+}
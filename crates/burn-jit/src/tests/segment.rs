@@ -0,0 +1,64 @@
+#[burn_tensor_testgen::testgen(segment)]
+mod tests {
+    use super::*;
+    use burn_tensor::{backend::Backend, Distribution, Int, Tensor};
+
+    #[test]
+    fn segment_sum_should_match_reference_sorted() {
+        test_same_as_ref(
+            |tensor, ids| tensor.segment_sum(ids, 12),
+            |tensor, ids| tensor.segment_sum(ids, 12),
+            true,
+        );
+    }
+
+    #[test]
+    fn segment_mean_should_match_reference_unsorted() {
+        test_same_as_ref(
+            |tensor, ids| tensor.unsorted_segment_mean(ids, 12),
+            |tensor, ids| tensor.unsorted_segment_mean(ids, 12),
+            false,
+        );
+    }
+
+    #[test]
+    fn segment_max_should_match_reference_unsorted() {
+        test_same_as_ref(
+            |tensor, ids| tensor.unsorted_segment_max(ids, 12),
+            |tensor, ids| tensor.unsorted_segment_max(ids, 12),
+            false,
+        );
+    }
+
+    fn test_same_as_ref(
+        op: impl Fn(Tensor<TestBackend, 3>, Tensor<TestBackend, 1, Int>) -> Tensor<TestBackend, 3>,
+        op_ref: impl Fn(
+            Tensor<ReferenceBackend, 3>,
+            Tensor<ReferenceBackend, 1, Int>,
+        ) -> Tensor<ReferenceBackend, 3>,
+        sorted: bool,
+    ) {
+        TestBackend::seed(0);
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 3>::random([300, 4, 70], Distribution::Default, &device);
+        // Segments 10 and 11 are left empty.
+        let ids = Tensor::<TestBackend, 1, Int>::from_data(
+            Tensor::<TestBackend, 1>::random([300], Distribution::Uniform(0., 10.), &device)
+                .into_data(),
+            &device,
+        );
+        let ids = match sorted {
+            true => ids.sort(0),
+            false => ids,
+        };
+        let tensor_ref = Tensor::<ReferenceBackend, 3>::from_data(tensor.to_data(), &device);
+        let ids_ref = Tensor::<ReferenceBackend, 1, Int>::from_data(ids.to_data(), &device);
+
+        let actual = op(tensor, ids);
+        let expected = op_ref(tensor_ref, ids_ref);
+
+        expected
+            .into_data()
+            .assert_approx_eq(&actual.into_data(), 3);
+    }
+}
@@ -1,5 +1,5 @@
 use crate::kernel::{
-    conv::{Conv2dAutotuneKey, ConvTranspose2dAutotuneKey},
+    conv::{Conv2dAutotuneKey, Conv3dAutotuneKey, ConvTranspose2dAutotuneKey},
     matmul::MatmulAutotuneKey,
     reduce::{ReduceAutotuneKey, SumAutotuneKey},
 };
@@ -20,6 +20,8 @@ pub enum JitAutotuneKey {
     Conv2d(Conv2dAutotuneKey),
     /// Key for transpose convolution operations
     ConvTranspose2d(ConvTranspose2dAutotuneKey),
+    /// Key for 3D convolution operations
+    Conv3d(Conv3dAutotuneKey),
 }
 
 impl Display for JitAutotuneKey {
@@ -30,6 +32,7 @@ impl Display for JitAutotuneKey {
             JitAutotuneKey::Sum(reduce_key) => std::fmt::Display::fmt(&reduce_key, f),
             JitAutotuneKey::Conv2d(conv2d_key) => std::fmt::Display::fmt(&conv2d_key, f),
             JitAutotuneKey::ConvTranspose2d(conv2d_key) => std::fmt::Display::fmt(&conv2d_key, f),
+            JitAutotuneKey::Conv3d(conv3d_key) => std::fmt::Display::fmt(&conv3d_key, f),
         }
     }
 }
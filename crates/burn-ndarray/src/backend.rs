@@ -2,13 +2,19 @@ use crate::element::{FloatNdArrayElement, IntNdArrayElement, QuantElement};
 use crate::{NdArrayQTensor, NdArrayTensor, NdArrayTensorFloat};
 use alloc::string::String;
 use burn_common::stub::Mutex;
-use burn_tensor::backend::{Backend, DeviceId, DeviceOps};
+use burn_tensor::backend::{Backend, DeviceId, DeviceOps, RngState};
 use burn_tensor::ops::{BoolTensor, FloatTensor, IntTensor, QuantizedTensor};
 use burn_tensor::repr::{HandleKind, ReprBackend, TensorHandle};
 use core::marker::PhantomData;
-use rand::{rngs::StdRng, SeedableRng};
+use rand::rngs::StdRng;
 
-pub(crate) static SEED: Mutex<Option<StdRng>> = Mutex::new(None);
+pub(crate) static RNG_STATE: Mutex<Option<RngState>> = Mutex::new(None);
+
+/// The generator of the next random operation.
+pub(crate) fn next_rng() -> StdRng {
+    let mut state = RNG_STATE.lock().unwrap();
+    state.get_or_insert_with(RngState::from_entropy).next_rng()
+}
 
 /// The device type for the ndarray backend.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -66,9 +72,16 @@ impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> Backend for
     }
 
     fn seed(seed: u64) {
-        let rng = StdRng::seed_from_u64(seed);
-        let mut seed = SEED.lock().unwrap();
-        *seed = Some(rng);
+        Self::set_rng_state(RngState::new(seed));
+    }
+
+    fn rng_state() -> Option<RngState> {
+        let mut state = RNG_STATE.lock().unwrap();
+        Some(*state.get_or_insert_with(RngState::from_entropy))
+    }
+
+    fn set_rng_state(state: RngState) {
+        *RNG_STATE.lock().unwrap() = Some(state);
     }
 }
 
@@ -1,7 +1,6 @@
 // Language
 use alloc::vec;
 use alloc::vec::Vec;
-use burn_common::rand::get_seeded_rng;
 use burn_tensor::ops::FloatTensor;
 use burn_tensor::ops::IntTensorOps;
 use burn_tensor::Distribution;
@@ -17,8 +16,8 @@ use crate::element::IntNdArrayElement;
 use crate::element::QuantElement;
 use crate::execute_with_float_dtype;
 use crate::new_tensor_float;
+use crate::{next_rng, NdArrayDevice};
 use crate::{tensor::NdArrayTensor, NdArray};
-use crate::{NdArrayDevice, SEED};
 
 // Workspace crates
 use burn_tensor::{backend::Backend, Shape, TensorData};
@@ -296,12 +295,7 @@ impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> IntTensorOps
         distribution: Distribution,
         device: &NdArrayDevice,
     ) -> NdArrayTensor<I> {
-        let mut seed = SEED.lock().unwrap();
-        let mut rng = if let Some(rng_seeded) = seed.as_ref() {
-            rng_seeded.clone()
-        } else {
-            get_seeded_rng()
-        };
+        let mut rng = next_rng();
 
         let effective_distribution = if distribution == Distribution::Default {
             Distribution::Uniform(0.0, 255.0) // Assuming UniformInt is the integer variant
@@ -309,12 +303,10 @@ impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> IntTensorOps
             distribution
         };
 
-        let tensor = Self::int_from_data(
+        Self::int_from_data(
             TensorData::random::<i64, _, _>(shape, effective_distribution, &mut rng),
             device,
-        );
-        *seed = Some(rng);
-        tensor
+        )
     }
 
     fn int_powi(lhs: NdArrayTensor<I>, rhs: NdArrayTensor<I>) -> NdArrayTensor<I> {
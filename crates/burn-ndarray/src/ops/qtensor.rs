@@ -3,8 +3,8 @@ use core::ops::Range;
 use burn_tensor::{
     ops::{FloatTensor, IntTensor, QTensorOps, QuantizedTensor},
     quantization::{
-        AffineQuantization, QParams, QuantizationParametersPrimitive, QuantizationScheme,
-        QuantizationStrategy, QuantizationType, QuantizedBytes, SymmetricQuantization,
+        QParams, QuantizationParametersPrimitive, QuantizationScheme, QuantizationStrategy,
+        QuantizedBytes,
     },
     DType, ElementConversion, Shape, TensorData, TensorMetadata,
 };
@@ -44,23 +44,19 @@ impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> QTensorOps<S
                     num_elements,
                 };
 
-                match scheme {
-                    QuantizationScheme::PerTensorAffine(QuantizationType::QInt8)
-                    | QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt8) => {
-                        let (values, qparams) = q_bytes.into_vec_i8();
-
-                        let data = TensorData::new(values, shape).convert::<Q>();
-                        let qparams = QParams {
-                            scale: qparams.scale,
-                            offset: qparams.offset.map(|x| x.elem::<Q>()),
-                        };
-
-                        NdArrayQTensor {
-                            qtensor: NdArrayTensor::<Q>::from_data(data),
-                            scheme,
-                            qparams,
-                        }
-                    }
+                // Sub-byte values are unpacked to i8
+                let (values, qparams) = q_bytes.into_vec_i8();
+
+                let data = TensorData::new(values, shape).convert::<Q>();
+                let qparams = QParams {
+                    scale: qparams.scale,
+                    offset: qparams.offset.map(|x| x.elem::<Q>()),
+                };
+
+                NdArrayQTensor {
+                    qtensor: NdArrayTensor::<Q>::from_data(data),
+                    scheme,
+                    qparams,
                 }
             }
             _ => panic!(
@@ -75,41 +71,13 @@ impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> QTensorOps<S
         scheme: &QuantizationScheme,
         qparams: QuantizationParametersPrimitive<Self>,
     ) -> QuantizedTensor<Self> {
-        let (strategy, qparams) = match scheme {
-            QuantizationScheme::PerTensorAffine(dtype) => match dtype {
-                QuantizationType::QInt8 => {
-                    let scale = into_data_f(qparams.scale).iter().next().unwrap();
-                    let offset = into_data(qparams.offset.unwrap())
-                        .iter::<Q>()
-                        .next()
-                        .unwrap();
-                    (
-                        QuantizationStrategy::PerTensorAffineInt8(AffineQuantization::init(
-                            scale,
-                            offset.elem(),
-                        )),
-                        QParams {
-                            scale,
-                            offset: Some(offset),
-                        },
-                    )
-                }
-            },
-            QuantizationScheme::PerTensorSymmetric(dtype) => match dtype {
-                QuantizationType::QInt8 => {
-                    let scale = into_data_f(qparams.scale).iter().next().unwrap();
-                    (
-                        QuantizationStrategy::PerTensorSymmetricInt8(SymmetricQuantization::init(
-                            scale,
-                        )),
-                        QParams {
-                            scale,
-                            offset: None,
-                        },
-                    )
-                }
-            },
-        };
+        let scale = into_data_f(qparams.scale).iter().next().unwrap();
+        let offset = qparams
+            .offset
+            .map(|offset| into_data(offset).iter::<Q>().next().unwrap());
+        let strategy =
+            QuantizationStrategy::from_scheme(*scheme, scale, offset.map(|offset| offset.elem()));
+        let qparams = QParams { scale, offset };
 
         let shape = tensor.shape();
         let data = into_data_f(tensor).with_quantization(strategy);
@@ -8,11 +8,12 @@ use ndarray::Zip;
 // Current crate
 use super::{matmul::matmul, NdArrayMathOps, NdArrayOps};
 use crate::element::{ExpElement, FloatNdArrayElement, IntNdArrayElement, QuantElement};
-use crate::{execute_with_float_dtype, new_tensor_float, NdArrayDevice, NdArrayTensorFloat, SEED};
+use crate::{
+    execute_with_float_dtype, new_tensor_float, next_rng, NdArrayDevice, NdArrayTensorFloat,
+};
 use crate::{tensor::NdArrayTensor, NdArray};
 
 // Workspace crates
-use burn_common::rand::get_seeded_rng;
 use burn_tensor::{backend::Backend, ops::FloatTensorOps, ElementConversion, Shape, TensorData};
 use burn_tensor::{Distribution, FloatDType};
 
@@ -50,18 +51,11 @@ impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> FloatTensorO
         distribution: Distribution,
         device: &NdArrayDevice,
     ) -> FloatTensor<Self> {
-        let mut seed = SEED.lock().unwrap();
-        let mut rng = if let Some(rng_seeded) = seed.as_ref() {
-            rng_seeded.clone()
-        } else {
-            get_seeded_rng()
-        };
-        let tensor = Self::float_from_data(
+        let mut rng = next_rng();
+        Self::float_from_data(
             TensorData::random::<E, _, _>(shape, distribution, &mut rng),
             device,
-        );
-        *seed = Some(rng);
-        tensor
+        )
     }
 
     async fn float_into_data(tensor: FloatTensor<Self>) -> TensorData {
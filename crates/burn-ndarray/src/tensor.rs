@@ -1,8 +1,5 @@
 use burn_tensor::{
-    quantization::{
-        AffineQuantization, QParams, QTensorPrimitive, QuantizationScheme, QuantizationStrategy,
-        QuantizationType, SymmetricQuantization,
-    },
+    quantization::{QParams, QTensorPrimitive, QuantizationScheme, QuantizationStrategy},
     DType, Element, Shape, TensorData, TensorMetadata,
 };
 
@@ -355,19 +352,11 @@ pub struct NdArrayQTensor<Q: QuantElement> {
 impl<Q: QuantElement> NdArrayQTensor<Q> {
     /// Returns the quantization strategy, including quantization parameters, for the given tensor.
     pub fn strategy(&self) -> QuantizationStrategy {
-        match self.scheme {
-            QuantizationScheme::PerTensorAffine(QuantizationType::QInt8) => {
-                QuantizationStrategy::PerTensorAffineInt8(AffineQuantization::init(
-                    self.qparams.scale,
-                    self.qparams.offset.unwrap().elem(),
-                ))
-            }
-            QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt8) => {
-                QuantizationStrategy::PerTensorSymmetricInt8(SymmetricQuantization::init(
-                    self.qparams.scale,
-                ))
-            }
-        }
+        QuantizationStrategy::from_scheme(
+            self.scheme,
+            self.qparams.scale,
+            self.qparams.offset.map(|offset| offset.elem()),
+        )
     }
 }
 
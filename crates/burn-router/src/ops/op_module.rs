@@ -1,4 +1,4 @@
-use alloc::{boxed::Box, vec};
+use alloc::{boxed::Box, vec, vec::Vec};
 
 use burn_tensor::ops::conv::{
     calculate_conv_output_size, calculate_conv_transpose_output_size, calculate_pool_output_size,
@@ -8,24 +8,48 @@ use burn_tensor::ops::{
     IntElem, ModuleOps,
 };
 use burn_tensor::ops::{
-    IntTensor, InterpolateOptions, MaxPool1dBackward, MaxPool1dWithIndices, MaxPool2dBackward,
-    MaxPool2dWithIndices,
+    IntTensor, InterpolateOptions, LayerNormBackward, MaxPool1dBackward, MaxPool1dWithIndices,
+    MaxPool2dBackward, MaxPool2dWithIndices, MaxPool3dBackward, MaxPool3dWithIndices,
+    RmsNormBackward, RotaryQk,
 };
 use burn_tensor::repr::{
     AdaptiveAvgPool1dBackwardDescription, AdaptiveAvgPool1dDescription,
     AdaptiveAvgPool2dBackwardDescription, AdaptiveAvgPool2dDescription,
     AvgPool1dBackwardDescription, AvgPool1dDescription, AvgPool2dBackwardDescription,
-    AvgPool2dDescription, Conv1dDescription, Conv2dDescription, Conv3dDescription,
-    ConvTranspose1dDescription, ConvTranspose2dDescription, ConvTranspose3dDescription,
-    DeformConv2dBackwardDescription, DeformConv2dDescription, InterpolateBackwardDescription,
-    InterpolateDescription, MaxPool1dDescription, MaxPool1dWithIndicesBackwardDescription,
+    AvgPool2dDescription, AvgPool3dBackwardDescription, AvgPool3dDescription, Conv1dDescription,
+    Conv2dDescription, Conv3dDescription, ConvTranspose1dDescription, ConvTranspose2dDescription,
+    ConvTranspose3dDescription, DeformConv2dBackwardDescription, DeformConv2dDescription,
+    InterpolateBackwardDescription, InterpolateDescription, LayerNormBackwardDescription,
+    LayerNormDescription, MaxPool1dDescription, MaxPool1dWithIndicesBackwardDescription,
     MaxPool1dWithIndicesDescription, MaxPool2dDescription, MaxPool2dWithIndicesBackwardDescription,
-    MaxPool2dWithIndicesDescription, ModuleOperationDescription, OperationDescription,
+    MaxPool2dWithIndicesDescription, MaxPool3dDescription, MaxPool3dWithIndicesBackwardDescription,
+    MaxPool3dWithIndicesDescription, ModuleOperationDescription, OperationDescription,
+    RmsNormBackwardDescription, RmsNormDescription, RotaryQkDescription,
 };
 use burn_tensor::Element;
 
 use crate::{BackendRouter, RunnerChannel, RunnerClient};
 
+fn max_pool3d_output_shape(
+    shape: &[usize],
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    dilation: [usize; 3],
+) -> Vec<usize> {
+    let size = |dim: usize| {
+        calculate_pool_output_size(
+            kernel_size[dim],
+            stride[dim],
+            padding[dim],
+            dilation[dim],
+            shape[dim + 2],
+        )
+    };
+
+    vec![shape[0], shape[1], size(0), size(1), size(2)]
+}
+
 impl<R: RunnerChannel> ModuleOps<Self> for BackendRouter<R> {
     fn conv1d(
         x: FloatTensor<Self>,
@@ -602,6 +626,154 @@ impl<R: RunnerChannel> ModuleOps<Self> for BackendRouter<R> {
         MaxPool2dBackward::new(out)
     }
 
+    fn avg_pool3d(
+        x: FloatTensor<Self>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        count_include_pad: bool,
+    ) -> FloatTensor<Self> {
+        let size_0 =
+            calculate_pool_output_size(kernel_size[0], stride[0], padding[0], 1, x.shape[2]);
+        let size_1 =
+            calculate_pool_output_size(kernel_size[1], stride[1], padding[1], 1, x.shape[3]);
+        let size_2 =
+            calculate_pool_output_size(kernel_size[2], stride[2], padding[2], 1, x.shape[4]);
+
+        let shape = vec![x.shape[0], x.shape[1], size_0, size_1, size_2];
+        let client = x.client.clone();
+        let out = client.register_empty_tensor(shape, x.dtype);
+
+        let desc = AvgPool3dDescription {
+            x: x.into_description(),
+            kernel_size,
+            stride,
+            padding,
+            count_include_pad,
+            out: out.to_description_out(),
+        };
+
+        client.register(OperationDescription::Module(
+            ModuleOperationDescription::AvgPool3d(desc),
+        ));
+
+        out
+    }
+
+    fn avg_pool3d_backward(
+        x: FloatTensor<Self>,
+        grad: FloatTensor<Self>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        count_include_pad: bool,
+    ) -> FloatTensor<Self> {
+        let client = x.client.clone();
+        let out = client.register_empty_tensor(x.shape.clone(), x.dtype);
+
+        let desc = AvgPool3dBackwardDescription {
+            x: x.into_description(),
+            grad: grad.into_description(),
+            kernel_size,
+            stride,
+            padding,
+            count_include_pad,
+            out: out.to_description_out(),
+        };
+
+        client.register(OperationDescription::Module(
+            ModuleOperationDescription::AvgPool3dBackward(desc),
+        ));
+
+        out
+    }
+
+    fn max_pool3d(
+        x: FloatTensor<Self>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+    ) -> FloatTensor<Self> {
+        let shape = max_pool3d_output_shape(&x.shape, kernel_size, stride, padding, dilation);
+        let client = x.client.clone();
+        let out = client.register_empty_tensor(shape, x.dtype);
+
+        let desc = MaxPool3dDescription {
+            x: x.into_description(),
+            kernel_size,
+            stride,
+            padding,
+            dilation,
+            out: out.to_description_out(),
+        };
+
+        client.register(OperationDescription::Module(
+            ModuleOperationDescription::MaxPool3d(desc),
+        ));
+
+        out
+    }
+
+    fn max_pool3d_with_indices(
+        x: FloatTensor<Self>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+    ) -> MaxPool3dWithIndices<Self> {
+        let shape = max_pool3d_output_shape(&x.shape, kernel_size, stride, padding, dilation);
+        let client = x.client.clone();
+        let out = client.register_empty_tensor(shape.clone(), x.dtype);
+        let out_indices = client.register_empty_tensor(shape, IntElem::<Self>::dtype());
+
+        let desc = MaxPool3dWithIndicesDescription {
+            x: x.into_description(),
+            kernel_size,
+            stride,
+            padding,
+            dilation,
+            out: out.to_description_out(),
+            out_indices: out_indices.to_description_out(),
+        };
+
+        client.register(OperationDescription::Module(
+            ModuleOperationDescription::MaxPool3dWithIndices(desc),
+        ));
+
+        MaxPool3dWithIndices::new(out, out_indices)
+    }
+
+    fn max_pool3d_with_indices_backward(
+        x: FloatTensor<Self>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+        output_grad: FloatTensor<Self>,
+        indices: IntTensor<Self>,
+    ) -> MaxPool3dBackward<Self> {
+        let client = x.client.clone();
+        let out = client.register_empty_tensor(x.shape.clone(), x.dtype);
+
+        let desc = MaxPool3dWithIndicesBackwardDescription {
+            x: x.into_description(),
+            grad: output_grad.into_description(),
+            indices: indices.into_description(),
+            kernel_size,
+            stride,
+            padding,
+            dilation,
+            out: out.to_description_out(),
+        };
+
+        client.register(OperationDescription::Module(
+            ModuleOperationDescription::MaxPool3dWithIndicesBackward(desc),
+        ));
+
+        MaxPool3dBackward::new(out)
+    }
+
     fn adaptive_avg_pool1d(x: FloatTensor<Self>, output_size: usize) -> FloatTensor<Self> {
         let shape = vec![x.shape[0], x.shape[1], output_size];
 
@@ -818,4 +990,132 @@ impl<R: RunnerChannel> ModuleOps<Self> for BackendRouter<R> {
 
         DeformConv2dBackward::new(input_grad, offset_grad, weight_grad, mask_grad, bias_grad)
     }
+
+    fn rms_norm(x: FloatTensor<Self>, gamma: FloatTensor<Self>, epsilon: f64) -> FloatTensor<Self> {
+        let client = x.client.clone();
+        let out = client.register_empty_tensor(x.shape.clone(), x.dtype);
+
+        let desc = RmsNormDescription {
+            x: x.into_description(),
+            gamma: gamma.into_description(),
+            epsilon,
+            out: out.to_description_out(),
+        };
+
+        client.register(OperationDescription::Module(
+            ModuleOperationDescription::RmsNorm(desc),
+        ));
+
+        out
+    }
+
+    fn rms_norm_backward(
+        x: FloatTensor<Self>,
+        gamma: FloatTensor<Self>,
+        output_grad: FloatTensor<Self>,
+        epsilon: f64,
+    ) -> RmsNormBackward<Self> {
+        let client = x.client.clone();
+        let x_grad = client.register_empty_tensor(x.shape.clone(), x.dtype);
+        let gamma_grad = client.register_empty_tensor(gamma.shape.clone(), gamma.dtype);
+
+        let desc = RmsNormBackwardDescription {
+            x: x.into_description(),
+            gamma: gamma.into_description(),
+            grad: output_grad.into_description(),
+            epsilon,
+            out_x_grad: x_grad.to_description_out(),
+            out_gamma_grad: gamma_grad.to_description_out(),
+        };
+
+        client.register(OperationDescription::Module(
+            ModuleOperationDescription::RmsNormBackward(desc),
+        ));
+
+        RmsNormBackward::new(x_grad, gamma_grad)
+    }
+
+    fn layer_norm(
+        x: FloatTensor<Self>,
+        gamma: FloatTensor<Self>,
+        beta: Option<FloatTensor<Self>>,
+        epsilon: f64,
+    ) -> FloatTensor<Self> {
+        let client = x.client.clone();
+        let out = client.register_empty_tensor(x.shape.clone(), x.dtype);
+
+        let desc = LayerNormDescription {
+            x: x.into_description(),
+            gamma: gamma.into_description(),
+            beta: beta.map(|beta| beta.into_description()),
+            epsilon,
+            out: out.to_description_out(),
+        };
+
+        client.register(OperationDescription::Module(
+            ModuleOperationDescription::LayerNorm(desc),
+        ));
+
+        out
+    }
+
+    fn layer_norm_backward(
+        x: FloatTensor<Self>,
+        gamma: FloatTensor<Self>,
+        output_grad: FloatTensor<Self>,
+        epsilon: f64,
+    ) -> LayerNormBackward<Self> {
+        let client = x.client.clone();
+        let x_grad = client.register_empty_tensor(x.shape.clone(), x.dtype);
+        let gamma_grad = client.register_empty_tensor(gamma.shape.clone(), gamma.dtype);
+        let beta_grad = client.register_empty_tensor(gamma.shape.clone(), gamma.dtype);
+
+        let desc = LayerNormBackwardDescription {
+            x: x.into_description(),
+            gamma: gamma.into_description(),
+            grad: output_grad.into_description(),
+            epsilon,
+            out_x_grad: x_grad.to_description_out(),
+            out_gamma_grad: gamma_grad.to_description_out(),
+            out_beta_grad: beta_grad.to_description_out(),
+        };
+
+        client.register(OperationDescription::Module(
+            ModuleOperationDescription::LayerNormBackward(desc),
+        ));
+
+        LayerNormBackward::new(x_grad, gamma_grad, beta_grad)
+    }
+
+    fn rotary_qk(
+        queries: FloatTensor<Self>,
+        keys: FloatTensor<Self>,
+        frequencies: FloatTensor<Self>,
+        start: usize,
+    ) -> RotaryQk<Self> {
+        let client = queries.client.clone();
+
+        // The heads are swapped with the sequence.
+        let mut queries_shape = queries.shape.clone();
+        let mut keys_shape = keys.shape.clone();
+        queries_shape.swap(1, 2);
+        keys_shape.swap(1, 2);
+        let out_queries = client.register_empty_tensor(queries_shape, queries.dtype);
+        let out_keys = client.register_empty_tensor(keys_shape, keys.dtype);
+
+        let desc = RotaryQkDescription {
+            queries: queries.into_description(),
+            keys: keys.into_description(),
+            frequencies: frequencies.into_description(),
+            start,
+            out_queries: out_queries.to_description_out(),
+            out_keys: out_keys.to_description_out(),
+        };
+
+        client.register(OperationDescription::Module(
+            ModuleOperationDescription::RotaryQk(desc),
+        ));
+
+        RotaryQk::new(out_queries, out_keys)
+    }
 }
@@ -1197,6 +1197,73 @@ impl<B: ReprBackend> RunnerClient for Runner<B> {
                     );
                     handles.register_float_tensor::<B>(&desc.out.id, output.x_grad);
                 }
+                ModuleOperationDescription::AvgPool3d(desc) => {
+                    let x = handles.get_float_tensor::<B>(&desc.x);
+
+                    let output = B::avg_pool3d(
+                        x,
+                        desc.kernel_size,
+                        desc.stride,
+                        desc.padding,
+                        desc.count_include_pad,
+                    );
+                    handles.register_float_tensor::<B>(&desc.out.id, output);
+                }
+                ModuleOperationDescription::AvgPool3dBackward(desc) => {
+                    let x = handles.get_float_tensor::<B>(&desc.x);
+                    let grad = handles.get_float_tensor::<B>(&desc.grad);
+
+                    let output = B::avg_pool3d_backward(
+                        x,
+                        grad,
+                        desc.kernel_size,
+                        desc.stride,
+                        desc.padding,
+                        desc.count_include_pad,
+                    );
+                    handles.register_float_tensor::<B>(&desc.out.id, output);
+                }
+                ModuleOperationDescription::MaxPool3d(desc) => {
+                    let x = handles.get_float_tensor::<B>(&desc.x);
+
+                    let output = B::max_pool3d(
+                        x,
+                        desc.kernel_size,
+                        desc.stride,
+                        desc.padding,
+                        desc.dilation,
+                    );
+                    handles.register_float_tensor::<B>(&desc.out.id, output);
+                }
+                ModuleOperationDescription::MaxPool3dWithIndices(desc) => {
+                    let x = handles.get_float_tensor::<B>(&desc.x);
+
+                    let output = B::max_pool3d_with_indices(
+                        x,
+                        desc.kernel_size,
+                        desc.stride,
+                        desc.padding,
+                        desc.dilation,
+                    );
+                    handles.register_float_tensor::<B>(&desc.out.id, output.output);
+                    handles.register_int_tensor::<B>(&desc.out_indices.id, output.indices);
+                }
+                ModuleOperationDescription::MaxPool3dWithIndicesBackward(desc) => {
+                    let x = handles.get_float_tensor::<B>(&desc.x);
+                    let output_grad = handles.get_float_tensor::<B>(&desc.grad);
+                    let indices = handles.get_int_tensor::<B>(&desc.indices);
+
+                    let output = B::max_pool3d_with_indices_backward(
+                        x,
+                        desc.kernel_size,
+                        desc.stride,
+                        desc.padding,
+                        desc.dilation,
+                        output_grad,
+                        indices,
+                    );
+                    handles.register_float_tensor::<B>(&desc.out.id, output.x_grad);
+                }
                 ModuleOperationDescription::Interpolate(desc) => {
                     let x = handles.get_float_tensor::<B>(&desc.x);
 
@@ -1215,6 +1282,52 @@ impl<B: ReprBackend> RunnerClient for Runner<B> {
                     );
                     handles.register_float_tensor::<B>(&desc.out.id, output);
                 }
+                ModuleOperationDescription::RmsNorm(desc) => {
+                    let x = handles.get_float_tensor::<B>(&desc.x);
+                    let gamma = handles.get_float_tensor::<B>(&desc.gamma);
+
+                    let output = B::rms_norm(x, gamma, desc.epsilon);
+                    handles.register_float_tensor::<B>(&desc.out.id, output);
+                }
+                ModuleOperationDescription::RmsNormBackward(desc) => {
+                    let x = handles.get_float_tensor::<B>(&desc.x);
+                    let gamma = handles.get_float_tensor::<B>(&desc.gamma);
+                    let grad = handles.get_float_tensor::<B>(&desc.grad);
+
+                    let output = B::rms_norm_backward(x, gamma, grad, desc.epsilon);
+                    handles.register_float_tensor::<B>(&desc.out_x_grad.id, output.x_grad);
+                    handles.register_float_tensor::<B>(&desc.out_gamma_grad.id, output.gamma_grad);
+                }
+                ModuleOperationDescription::LayerNorm(desc) => {
+                    let x = handles.get_float_tensor::<B>(&desc.x);
+                    let gamma = handles.get_float_tensor::<B>(&desc.gamma);
+                    let beta = desc
+                        .beta
+                        .as_ref()
+                        .map(|beta| handles.get_float_tensor::<B>(beta));
+
+                    let output = B::layer_norm(x, gamma, beta, desc.epsilon);
+                    handles.register_float_tensor::<B>(&desc.out.id, output);
+                }
+                ModuleOperationDescription::LayerNormBackward(desc) => {
+                    let x = handles.get_float_tensor::<B>(&desc.x);
+                    let gamma = handles.get_float_tensor::<B>(&desc.gamma);
+                    let grad = handles.get_float_tensor::<B>(&desc.grad);
+
+                    let output = B::layer_norm_backward(x, gamma, grad, desc.epsilon);
+                    handles.register_float_tensor::<B>(&desc.out_x_grad.id, output.x_grad);
+                    handles.register_float_tensor::<B>(&desc.out_gamma_grad.id, output.gamma_grad);
+                    handles.register_float_tensor::<B>(&desc.out_beta_grad.id, output.beta_grad);
+                }
+                ModuleOperationDescription::RotaryQk(desc) => {
+                    let queries = handles.get_float_tensor::<B>(&desc.queries);
+                    let keys = handles.get_float_tensor::<B>(&desc.keys);
+                    let frequencies = handles.get_float_tensor::<B>(&desc.frequencies);
+
+                    let output = B::rotary_qk(queries, keys, frequencies, desc.start);
+                    handles.register_float_tensor::<B>(&desc.out_queries.id, output.queries);
+                    handles.register_float_tensor::<B>(&desc.out_keys.id, output.keys);
+                }
             },
             OperationDescription::Custom(_) => {
                 panic!("Can't execute custom operation here")
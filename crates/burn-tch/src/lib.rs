@@ -13,6 +13,9 @@ pub use backend::*;
 pub use element::*;
 pub use tensor::*;
 
+/// The tch crate, to build modules interoperating with the backend.
+pub use tch;
+
 #[cfg(test)]
 mod tests {
     extern crate alloc;
@@ -21,6 +21,19 @@ impl TchOps {
         TchTensor::new(tensor.tensor.to(device))
     }
 
+    pub fn to_device_async(tensor: TchTensor, device: &LibTorchDevice) -> TchTensor {
+        let device = (*device).into();
+
+        if tensor.tensor.device() == device {
+            return tensor;
+        }
+
+        // The copy only overlaps with the host when the source is in pinned memory, otherwise
+        // LibTorch falls back to a blocking copy.
+        let kind = tensor.tensor.kind();
+        TchTensor::new(tensor.tensor.to_device_(device, kind, true, false))
+    }
+
     pub fn reshape(tensor: TchTensor, shape: Shape) -> TchTensor {
         let shape_tch: TchShape = shape.into();
 
@@ -339,6 +352,17 @@ impl TchOps {
         (tensor, indices)
     }
 
+    pub fn kthvalue_with_indices(
+        tensor: TchTensor,
+        k: usize,
+        dim: usize,
+    ) -> (TchTensor, TchTensor) {
+        // LibTorch uses a one-based rank.
+        let (tensor, indices) = tensor.tensor.kthvalue(k as i64 + 1, dim as i64, true);
+
+        (TchTensor::new(tensor), TchTensor::new(indices))
+    }
+
     pub fn min_dim(tensor: TchTensor, dim: usize) -> TchTensor {
         let storage = tensor.storage.clone();
         let (tensor, _indices) = tensor.tensor.min_dim(dim as i64, true);
@@ -23,6 +23,10 @@ impl<E: TchElement, Q: QuantElement> BoolTensorOps<Self> for LibTorch<E, Q> {
         TchOps::to_device(tensor, device)
     }
 
+    fn bool_to_device_async(tensor: TchTensor, device: &LibTorchDevice) -> TchTensor {
+        TchOps::to_device_async(tensor, device)
+    }
+
     fn bool_reshape(tensor: TchTensor, shape: Shape) -> TchTensor {
         TchOps::reshape(tensor, shape)
     }
@@ -2,7 +2,7 @@ use std::ops::Range;
 
 use burn_tensor::{
     backend::Backend,
-    ops::{IntTensor, IntTensorOps},
+    ops::{FloatTensorOps, IntTensor, IntTensorOps},
     Distribution, Shape, TensorData, TensorMetadata,
 };
 
@@ -30,6 +30,10 @@ impl<E: TchElement, Q: QuantElement> IntTensorOps<Self> for LibTorch<E, Q> {
         TchOps::to_device(tensor, device)
     }
 
+    fn int_to_device_async(tensor: TchTensor, device: &LibTorchDevice) -> TchTensor {
+        TchOps::to_device_async(tensor, device)
+    }
+
     fn int_reshape(tensor: TchTensor, shape: Shape) -> TchTensor {
         TchOps::reshape(tensor, shape)
     }
@@ -379,9 +383,20 @@ impl<E: TchElement, Q: QuantElement> IntTensorOps<Self> for LibTorch<E, Q> {
                 let mut tensor = TchTensor::empty::<i64>(shape, *device);
                 tensor.mut_ops(|tensor| tensor.normal_(mean, std)).unwrap()
             }
+            Distribution::Exponential(_) | Distribution::Gamma(_, _) | Distribution::Poisson(_) => {
+                // LibTorch only samples those distributions as floats.
+                let tensor = Self::float_random(shape, distribution, device);
+                TchTensor::new(tensor.tensor.to_kind(tch::Kind::Int64))
+            }
         }
     }
 
+    fn int_randperm(n: usize, device: &LibTorchDevice) -> TchTensor {
+        let device: tch::Device = (*device).into();
+
+        TchTensor::new(tch::Tensor::randperm(n as i64, (tch::Kind::Int64, device)))
+    }
+
     fn int_arange(range: Range<i64>, device: &LibTorchDevice) -> TchTensor {
         let device: tch::Device = (*device).into();
         let mut tensor = tch::Tensor::arange(range.end - range.start, (tch::Kind::Int64, device));
@@ -417,6 +432,14 @@ impl<E: TchElement, Q: QuantElement> IntTensorOps<Self> for LibTorch<E, Q> {
         TchOps::argsort(tensor, dim, descending)
     }
 
+    fn int_kthvalue_with_indices(
+        tensor: IntTensor<Self>,
+        k: usize,
+        dim: usize,
+    ) -> (IntTensor<Self>, IntTensor<Self>) {
+        TchOps::kthvalue_with_indices(tensor, k, dim)
+    }
+
     fn bitwise_and(lhs: IntTensor<Self>, rhs: IntTensor<Self>) -> IntTensor<Self> {
         TchOps::bitwise_and(lhs, rhs)
     }
@@ -1,12 +1,12 @@
 use std::ops::Range;
 
 use burn_tensor::{
-    ops::{FloatTensor, IntTensor, QTensorOps, QuantizedTensor},
+    ops::{FloatTensor, FloatTensorOps, IntTensor, QTensorOps, QuantizedTensor},
     quantization::{
-        QParams, QuantizationParametersPrimitive, QuantizationScheme, QuantizationType,
-        QuantizedBytes,
+        CalibrationRange, QParams, QuantizationParametersPrimitive, QuantizationScheme,
+        QuantizationType, QuantizedBytes,
     },
-    DType, Shape, TensorData, TensorMetadata,
+    DType, Shape, Tensor, TensorData, TensorMetadata, TensorPrimitive,
 };
 
 use crate::{LibTorch, LibTorchDevice, QuantElement, TchElement, TchQTensor, TchShape, TchTensor};
@@ -24,16 +24,45 @@ fn quantize<E: TchElement, Q: QuantElement>(
         tensor = tensor.to_kind(tch::Kind::Float);
     }
 
-    match scheme {
-        QuantizationScheme::PerTensorAffine(QuantizationType::QInt8) => tensor.quantize_per_tensor(
+    let qtensor = match scheme {
+        QuantizationScheme::PerTensorAffine(_) => tensor.quantize_per_tensor(
             qparams.scale.elem(),
             qparams.offset.unwrap().elem(),
             tch::Kind::QInt8,
         ),
-        QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt8) => {
+        QuantizationScheme::PerTensorSymmetric(_) => {
             tensor.quantize_per_tensor(qparams.scale.elem(), 0, tch::Kind::QInt8)
         }
-    }
+    };
+
+    clamp_sub_byte(qtensor, scheme)
+}
+
+/// LibTorch only supports `QInt8` quantized tensors, so the sub-byte quantization types are
+/// stored in `QInt8` tensors with their values clamped to the range of the type, like the `i8`
+/// values of their quantization strategy.
+///
+/// The values aren't packed: sub-byte quantized tensors use one byte per value on this backend, so
+/// they don't save memory compared to `QInt8`.
+fn clamp_sub_byte(qtensor: tch::Tensor, scheme: &QuantizationScheme) -> tch::Tensor {
+    let (a, b) = match scheme {
+        QuantizationScheme::PerTensorAffine(QuantizationType::QInt8)
+        | QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt8) => return qtensor,
+        QuantizationScheme::PerTensorAffine(dtype) => dtype.range(),
+        QuantizationScheme::PerTensorSymmetric(dtype) => {
+            let (_, b) = dtype.range();
+            (-b, b)
+        }
+    };
+
+    let scale = qtensor.q_scale();
+    let zero_point = qtensor.q_zero_point();
+    let values = qtensor
+        .int_repr()
+        .clamp(a as i64, b as i64)
+        .to_kind(tch::Kind::Float);
+
+    ((values - zero_point) * scale).quantize_per_tensor(scale, zero_point, tch::Kind::QInt8)
 }
 
 impl<E: TchElement, Q: QuantElement> QTensorOps<Self> for LibTorch<E, Q> {
@@ -82,13 +111,13 @@ impl<E: TchElement, Q: QuantElement> QTensorOps<Self> for LibTorch<E, Q> {
         }
 
         let qtensor = match scheme {
-            QuantizationScheme::PerTensorAffine(dtype) => match dtype {
-                QuantizationType::QInt8 => tensor.tensor.quantize_per_tensor_tensor_qparams(
+            QuantizationScheme::PerTensorAffine(_) => {
+                tensor.tensor.quantize_per_tensor_tensor_qparams(
                     &qparams.scale.tensor,
                     &qparams.offset.unwrap().tensor,
                     tch::Kind::QInt8,
-                ),
-            },
+                )
+            }
             QuantizationScheme::PerTensorSymmetric(_) => {
                 tensor.tensor.quantize_per_tensor_tensor_qparams(
                     &qparams.scale.tensor,
@@ -99,7 +128,7 @@ impl<E: TchElement, Q: QuantElement> QTensorOps<Self> for LibTorch<E, Q> {
         };
 
         TchQTensor {
-            qtensor: TchTensor::new(qtensor),
+            qtensor: TchTensor::new(clamp_sub_byte(qtensor, scheme)),
             scheme: *scheme,
         }
     }
@@ -108,22 +137,32 @@ impl<E: TchElement, Q: QuantElement> QTensorOps<Self> for LibTorch<E, Q> {
         tensor: FloatTensor<Self>,
         scheme: &QuantizationScheme,
     ) -> QuantizedTensor<Self> {
+        if scheme.q_type() != QuantizationType::QInt8 {
+            // LibTorch only computes the quantization parameters of `QInt8`
+            let range = CalibrationRange {
+                min: Tensor::from_primitive(TensorPrimitive::Float(Self::float_min(
+                    tensor.clone(),
+                ))),
+                max: Tensor::from_primitive(TensorPrimitive::Float(Self::float_max(
+                    tensor.clone(),
+                ))),
+            };
+            let qparams = scheme.compute_q_params(range).into();
+            return Self::quantize(tensor, scheme, qparams);
+        }
+
         let qtensor = match &scheme {
-            QuantizationScheme::PerTensorAffine(dtype) => match dtype {
-                // Notes on `reduce_range`:
-                // https://github.com/pytorch/pytorch/issues/93140
-                // https://onnxruntime.ai/docs/performance/model-optimizations/quantization.html#data-type-selection
-                QuantizationType::QInt8 => tensor
-                    .tensor
-                    .quantize_per_tensor_dynamic(tch::Kind::QInt8, /*reduce_range*/ false),
-            },
-            QuantizationScheme::PerTensorSymmetric(dtype) => {
+            // Notes on `reduce_range`:
+            // https://github.com/pytorch/pytorch/issues/93140
+            // https://onnxruntime.ai/docs/performance/model-optimizations/quantization.html#data-type-selection
+            QuantizationScheme::PerTensorAffine(_) => tensor
+                .tensor
+                .quantize_per_tensor_dynamic(tch::Kind::QInt8, /*reduce_range*/ false),
+            QuantizationScheme::PerTensorSymmetric(_) => {
                 log::warn!("LibTorch backend does not support symmetric per-tensor scheme for dynamic quantization, reverting to the default per-tensor affine quantization");
-                match dtype {
-                    QuantizationType::QInt8 => tensor
-                        .tensor
-                        .quantize_per_tensor_dynamic(tch::Kind::QInt8, /*reduce_range*/ false),
-                }
+                tensor
+                    .tensor
+                    .quantize_per_tensor_dynamic(tch::Kind::QInt8, /*reduce_range*/ false)
             }
         };
 
@@ -39,6 +39,21 @@ impl<E: TchElement, Q: QuantElement> FloatTensorOps<Self> for LibTorch<E, Q> {
                 let mut tensor = TchTensor::empty::<E>(shape, *device);
                 tensor.mut_ops(|tensor| tensor.normal_(mean, std)).unwrap()
             }
+            Distribution::Exponential(rate) => {
+                let mut tensor = TchTensor::empty::<E>(shape, *device);
+                tensor.mut_ops(|tensor| tensor.exponential_(rate)).unwrap()
+            }
+            Distribution::Gamma(concentration, scale) => {
+                let shape = TchShape::from(shape);
+                let concentration =
+                    tch::Tensor::full(shape.dims, concentration, (E::KIND, (*device).into()));
+                TchTensor::new(concentration.internal_standard_gamma() * scale)
+            }
+            Distribution::Poisson(rate) => {
+                let shape = TchShape::from(shape);
+                let rate = tch::Tensor::full(shape.dims, rate, (E::KIND, (*device).into()));
+                TchTensor::new(rate.poisson())
+            }
         }
     }
 
@@ -88,10 +103,18 @@ impl<E: TchElement, Q: QuantElement> FloatTensorOps<Self> for LibTorch<E, Q> {
         tensor.tensor.device().into()
     }
 
+    fn float_is_external(tensor: &TchTensor) -> bool {
+        tensor.storage.is_external()
+    }
+
     fn float_to_device(tensor: TchTensor, device: &LibTorchDevice) -> TchTensor {
         TchOps::to_device(tensor, device)
     }
 
+    fn float_to_device_async(tensor: TchTensor, device: &LibTorchDevice) -> TchTensor {
+        TchOps::to_device_async(tensor, device)
+    }
+
     fn float_empty(shape: Shape, device: &<LibTorch<E> as Backend>::Device) -> TchTensor {
         let tensor = tch::Tensor::empty(TchShape::from(shape).dims, (E::KIND, (*device).into()));
 
@@ -1,9 +1,6 @@
 use crate::{LibTorchDevice, TchElement};
 use burn_tensor::{
-    quantization::{
-        AffineQuantization, QTensorPrimitive, QuantizationScheme, QuantizationStrategy,
-        QuantizationType, SymmetricQuantization,
-    },
+    quantization::{QTensorPrimitive, QuantizationScheme, QuantizationStrategy},
     DType, Shape, TensorData, TensorMetadata,
 };
 use libc::c_void;
@@ -30,6 +27,13 @@ pub enum Storage {
         /// Storage reference for the whole buffer.
         buffer_ref: StorageRef,
     },
+    /// When a tensor, or a part of it, uses a buffer allocated and owned outside of burn.
+    ///
+    /// The buffer is never mutated in-place, every operation writes its result to a new buffer.
+    External {
+        /// Storage reference for the whole buffer.
+        buffer_ref: StorageRef,
+    },
 }
 
 impl Storage {
@@ -43,6 +47,7 @@ impl Storage {
             Storage::Owned {
                 buffer_ref: start_ref,
             } => Arc::strong_count(start_ref) == 1,
+            Storage::External { .. } => false,
         }
     }
 
@@ -56,8 +61,16 @@ impl Storage {
             Storage::Owned {
                 buffer_ref: start_ref,
             } => start_ref,
+            Storage::External {
+                buffer_ref: start_ref,
+            } => start_ref,
         }
     }
+
+    /// Check if the storage is allocated outside of burn.
+    pub fn is_external(&self) -> bool {
+        matches!(self, Storage::External { .. })
+    }
 }
 
 /// A tensor using the tch backend.
@@ -109,6 +122,52 @@ impl TchTensor {
         Self { tensor, storage }
     }
 
+    /// Create a tensor wrapping a buffer allocated and owned outside of burn, for instance memory
+    /// shared with another process or allocated by another framework, without copying it.
+    ///
+    /// The buffer is never mutated by burn: operations that would otherwise reuse the input
+    /// memory write their result to a new buffer instead, so the owner of the memory can keep
+    /// using it. The tensor, and every tensor sharing its storage, only borrows the buffer, which
+    /// must outlive them.
+    pub fn from_external(tensor: tch::Tensor) -> Self {
+        #[allow(clippy::arc_with_non_send_sync)]
+        let storage = Storage::External {
+            buffer_ref: Arc::new(tensor.data_ptr()),
+        };
+
+        Self { tensor, storage }
+    }
+
+    /// Create a tensor wrapping a contiguous buffer of elements allocated and owned outside of
+    /// burn, without copying it. See [from_external](TchTensor::from_external) for the ownership
+    /// and mutability rules.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must point to a buffer of `shape.num_elements()` elements of type `E` on the
+    /// given device, which must stay valid until every tensor sharing its storage is dropped.
+    pub unsafe fn from_blob<E: TchElement>(
+        ptr: *const E,
+        shape: Shape,
+        device: LibTorchDevice,
+    ) -> Self {
+        let shape_tch = TchShape::from(shape);
+        let mut strides = vec![1; shape_tch.dims.len()];
+        for i in (0..shape_tch.dims.len().saturating_sub(1)).rev() {
+            strides[i] = strides[i + 1] * shape_tch.dims[i + 1];
+        }
+
+        let tensor = tch::Tensor::from_blob(
+            ptr as *const u8,
+            &shape_tch.dims,
+            &strides,
+            E::KIND,
+            device.into(),
+        );
+
+        Self::from_external(tensor)
+    }
+
     /// Create a tensor that was created from an operation executed on a parent tensor.
     ///
     /// If the child tensor shared the same storage as its parent, it will be cloned, effectively
@@ -128,6 +187,9 @@ impl TchTensor {
             }
             Storage::Owned {
                 buffer_ref: start_ref,
+            }
+            | Storage::External {
+                buffer_ref: start_ref,
             } => {
                 if storage_child == *start_ref.as_ref() {
                     is_a_new_tensor = false;
@@ -148,6 +210,14 @@ impl TchTensor {
 
     /// Create a tensor that uses a part of its parent tensor such as slice and narrow.
     pub fn partial(tensor: tch::Tensor, storage_parent: Storage) -> Self {
+        // A view of an external buffer stays read only, even when its parent is dropped.
+        if storage_parent.is_external() {
+            return Self {
+                tensor,
+                storage: storage_parent,
+            };
+        }
+
         let storage = Storage::View {
             buffer_ref: storage_parent.buffer_ref().clone(),
             #[allow(clippy::arc_with_non_send_sync)]
@@ -331,26 +401,15 @@ pub struct TchQTensor {
 impl TchQTensor {
     /// Returns the quantization strategy, including quantization parameters, for the given tensor.
     pub fn strategy(&self) -> QuantizationStrategy {
-        match &self.scheme {
-            QuantizationScheme::PerTensorAffine(dtype) => match dtype {
-                QuantizationType::QInt8 => {
-                    let scale = self.qtensor.tensor.q_scale();
-                    let offset = self.qtensor.tensor.q_zero_point();
-                    QuantizationStrategy::PerTensorAffineInt8(AffineQuantization::init(
-                        scale as f32,
-                        offset as i8,
-                    ))
-                }
-            },
-            QuantizationScheme::PerTensorSymmetric(dtype) => match dtype {
-                QuantizationType::QInt8 => {
-                    let scale = self.qtensor.tensor.q_scale();
-                    QuantizationStrategy::PerTensorSymmetricInt8(SymmetricQuantization::init(
-                        scale as f32,
-                    ))
-                }
-            },
-        }
+        let scale = self.qtensor.tensor.q_scale() as f32;
+        let offset = match &self.scheme {
+            QuantizationScheme::PerTensorAffine(_) => {
+                Some(self.qtensor.tensor.q_zero_point() as i8)
+            }
+            QuantizationScheme::PerTensorSymmetric(_) => None,
+        };
+
+        QuantizationStrategy::from_scheme(self.scheme, scale, offset)
     }
 }
 
@@ -376,7 +435,9 @@ mod tests {
 
     use super::*;
     use burn_tensor::ops::QTensorOps;
-    use burn_tensor::quantization::QuantizationParametersPrimitive;
+    use burn_tensor::quantization::{
+        AffineQuantization, QuantizationParametersPrimitive, QuantizationType,
+    };
     use burn_tensor::{Distribution, Tensor, TensorPrimitive};
     use rand::prelude::StdRng;
     use rand::SeedableRng;
@@ -437,6 +498,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn should_not_update_external_buffer_inplace() {
+        let buffer = [4.0f32, 4.0, 4.0, 4.0];
+        let tensor = unsafe {
+            TchTensor::from_blob(buffer.as_ptr(), Shape::new([2, 2]), LibTorchDevice::Cpu)
+        };
+        let tensor = Tensor::<LibTorch<f32>, 2>::from_primitive(TensorPrimitive::Float(tensor));
+        assert!(tensor.is_external());
+
+        let output = tensor.slice([0..1, 0..2]).add_scalar(2.0);
+        assert!(!output.is_external());
+
+        assert_eq!(output.to_data().as_slice::<f32>().unwrap(), &[6.0, 6.0]);
+        assert_eq!(buffer, [4.0, 4.0, 4.0, 4.0]);
+    }
+
     #[test]
     fn should_support_qtensor_strategy() {
         let tensor =
@@ -457,4 +534,29 @@ mod tests {
             QuantizationStrategy::PerTensorAffineInt8(AffineQuantization::init(0.009_019_608, 72))
         );
     }
+
+    #[test]
+    fn should_support_sub_byte_quantization() {
+        let device = Default::default();
+        let tensor = Tensor::<LibTorch<f32>, 1>::from_floats([-1.8, -1.0, 0.0, 0.5], &device);
+        let scheme = QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt4);
+
+        // The values are quantized in [-7, 7] with a scale of 3.6 / 14.
+        let qtensor = tensor.quantize_dynamic(&scheme);
+        let expected = TensorData::from([-1.8, -1.028_571_4, 0.0, 0.514_285_7]);
+
+        qtensor
+            .clone()
+            .dequantize()
+            .into_data()
+            .assert_approx_eq(&expected, 4);
+
+        // The packed values are loaded back.
+        let data = qtensor.into_data();
+        assert_eq!(data.dtype, DType::QFloat(scheme));
+        Tensor::<LibTorch<f32>, 1>::from_data(data, &device)
+            .dequantize()
+            .into_data()
+            .assert_approx_eq(&expected, 4);
+    }
 }
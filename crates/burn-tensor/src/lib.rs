@@ -46,6 +46,10 @@ mod cube {
                 crate::DType::U16 => Elem::UInt(UIntKind::U16),
                 crate::DType::U8 => Elem::UInt(UIntKind::U8),
                 crate::DType::Bool => Elem::Bool,
+                #[cfg(feature = "fp8")]
+                crate::DType::F8E4M3 | crate::DType::F8E5M2 => {
+                    panic!("fp8 types are only supported as packed storage.")
+                }
                 crate::DType::QFloat(_) => panic!("quantized type is not supported yet."),
             }
         }
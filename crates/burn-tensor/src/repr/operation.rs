@@ -189,10 +189,34 @@ pub enum ModuleOperationDescription {
     /// Operation corresponding to
     /// [max pool 2d with indices backward](crate::ops::ModuleOps::max_pool2d_with_indices_backward).
     MaxPool2dWithIndicesBackward(MaxPool2dWithIndicesBackwardDescription),
+    /// Operation corresponding to [avg pool 3d](crate::ops::ModuleOps::avg_pool3d).
+    AvgPool3d(AvgPool3dDescription),
+    /// Operation corresponding to
+    /// [avg pool 3d backward](crate::ops::ModuleOps::avg_pool3d_backward).
+    AvgPool3dBackward(AvgPool3dBackwardDescription),
+    /// Operation corresponding to
+    /// [max pool 3d](crate::ops::ModuleOps::max_pool3d).
+    MaxPool3d(MaxPool3dDescription),
+    /// Operation corresponding to
+    /// [max pool 3d with indices](crate::ops::ModuleOps::max_pool3d_with_indices).
+    MaxPool3dWithIndices(MaxPool3dWithIndicesDescription),
+    /// Operation corresponding to
+    /// [max pool 3d with indices backward](crate::ops::ModuleOps::max_pool3d_with_indices_backward).
+    MaxPool3dWithIndicesBackward(MaxPool3dWithIndicesBackwardDescription),
     /// Operation corresponding to [interpolate](crate::ops::ModuleOps::interpolate).
     Interpolate(InterpolateDescription),
     /// Operation corresponding to [interpolate backward](crate::ops::ModuleOps::interpolate_backward).
     InterpolateBackward(InterpolateBackwardDescription),
+    /// Operation corresponding to [rms norm](crate::ops::ModuleOps::rms_norm).
+    RmsNorm(RmsNormDescription),
+    /// Operation corresponding to [rms norm backward](crate::ops::ModuleOps::rms_norm_backward).
+    RmsNormBackward(RmsNormBackwardDescription),
+    /// Operation corresponding to [layer norm](crate::ops::ModuleOps::layer_norm).
+    LayerNorm(LayerNormDescription),
+    /// Operation corresponding to [layer norm backward](crate::ops::ModuleOps::layer_norm_backward).
+    LayerNormBackward(LayerNormBackwardDescription),
+    /// Operation corresponding to [rotary qk](crate::ops::ModuleOps::rotary_qk).
+    RotaryQk(RotaryQkDescription),
 }
 
 /// Basic operations that can be done on any tensor type.
@@ -1299,6 +1323,65 @@ pub struct MaxPool2dWithIndicesBackwardDescription {
     pub out: TensorDescription,
 }
 
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct AvgPool3dDescription {
+    pub x: TensorDescription,
+    pub kernel_size: [usize; 3],
+    pub stride: [usize; 3],
+    pub padding: [usize; 3],
+    pub count_include_pad: bool,
+    pub out: TensorDescription,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct AvgPool3dBackwardDescription {
+    pub x: TensorDescription,
+    pub grad: TensorDescription,
+    pub kernel_size: [usize; 3],
+    pub stride: [usize; 3],
+    pub padding: [usize; 3],
+    pub count_include_pad: bool,
+    pub out: TensorDescription,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct MaxPool3dDescription {
+    pub x: TensorDescription,
+    pub kernel_size: [usize; 3],
+    pub stride: [usize; 3],
+    pub padding: [usize; 3],
+    pub dilation: [usize; 3],
+    pub out: TensorDescription,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct MaxPool3dWithIndicesDescription {
+    pub x: TensorDescription,
+    pub kernel_size: [usize; 3],
+    pub stride: [usize; 3],
+    pub padding: [usize; 3],
+    pub dilation: [usize; 3],
+    pub out: TensorDescription,
+    pub out_indices: TensorDescription,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct MaxPool3dWithIndicesBackwardDescription {
+    pub x: TensorDescription,
+    pub grad: TensorDescription,
+    pub indices: TensorDescription,
+    pub kernel_size: [usize; 3],
+    pub stride: [usize; 3],
+    pub padding: [usize; 3],
+    pub dilation: [usize; 3],
+    pub out: TensorDescription,
+}
+
 #[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
 #[allow(missing_docs)]
 pub enum InterpolateModeDescription {
@@ -1368,6 +1451,59 @@ pub struct InterpolateBackwardDescription {
     pub out: TensorDescription,
 }
 
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct RmsNormDescription {
+    pub x: TensorDescription,
+    pub gamma: TensorDescription,
+    pub epsilon: f64,
+    pub out: TensorDescription,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct RmsNormBackwardDescription {
+    pub x: TensorDescription,
+    pub gamma: TensorDescription,
+    pub grad: TensorDescription,
+    pub epsilon: f64,
+    pub out_x_grad: TensorDescription,
+    pub out_gamma_grad: TensorDescription,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct LayerNormDescription {
+    pub x: TensorDescription,
+    pub gamma: TensorDescription,
+    pub beta: Option<TensorDescription>,
+    pub epsilon: f64,
+    pub out: TensorDescription,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct LayerNormBackwardDescription {
+    pub x: TensorDescription,
+    pub gamma: TensorDescription,
+    pub grad: TensorDescription,
+    pub epsilon: f64,
+    pub out_x_grad: TensorDescription,
+    pub out_gamma_grad: TensorDescription,
+    pub out_beta_grad: TensorDescription,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct RotaryQkDescription {
+    pub queries: TensorDescription,
+    pub keys: TensorDescription,
+    pub frequencies: TensorDescription,
+    pub start: usize,
+    pub out_queries: TensorDescription,
+    pub out_keys: TensorDescription,
+}
+
 impl OperationDescription {
     /// Cleanup the remaining tensor handles that have not been used.
     pub fn nodes(&self) -> Vec<&TensorDescription> {
@@ -1759,12 +1895,65 @@ impl ModuleOperationDescription {
             ModuleOperationDescription::MaxPool2dWithIndicesBackward(desc) => {
                 vec![&desc.x, &desc.out, &desc.indices, &desc.grad]
             }
+            ModuleOperationDescription::AvgPool3d(desc) => {
+                vec![&desc.x, &desc.out]
+            }
+            ModuleOperationDescription::AvgPool3dBackward(desc) => {
+                vec![&desc.x, &desc.out, &desc.grad]
+            }
+            ModuleOperationDescription::MaxPool3d(desc) => {
+                vec![&desc.x, &desc.out]
+            }
+            ModuleOperationDescription::MaxPool3dWithIndices(desc) => {
+                vec![&desc.x, &desc.out, &desc.out_indices]
+            }
+            ModuleOperationDescription::MaxPool3dWithIndicesBackward(desc) => {
+                vec![&desc.x, &desc.out, &desc.indices, &desc.grad]
+            }
             ModuleOperationDescription::Interpolate(desc) => {
                 vec![&desc.x, &desc.out]
             }
             ModuleOperationDescription::InterpolateBackward(desc) => {
                 vec![&desc.x, &desc.out, &desc.grad]
             }
+            ModuleOperationDescription::RmsNorm(desc) => {
+                vec![&desc.x, &desc.gamma, &desc.out]
+            }
+            ModuleOperationDescription::RmsNormBackward(desc) => {
+                vec![
+                    &desc.x,
+                    &desc.gamma,
+                    &desc.grad,
+                    &desc.out_x_grad,
+                    &desc.out_gamma_grad,
+                ]
+            }
+            ModuleOperationDescription::LayerNorm(desc) => {
+                if let Some(beta) = &desc.beta {
+                    vec![&desc.x, &desc.gamma, beta, &desc.out]
+                } else {
+                    vec![&desc.x, &desc.gamma, &desc.out]
+                }
+            }
+            ModuleOperationDescription::LayerNormBackward(desc) => {
+                vec![
+                    &desc.x,
+                    &desc.gamma,
+                    &desc.grad,
+                    &desc.out_x_grad,
+                    &desc.out_gamma_grad,
+                    &desc.out_beta_grad,
+                ]
+            }
+            ModuleOperationDescription::RotaryQk(desc) => {
+                vec![
+                    &desc.queries,
+                    &desc.keys,
+                    &desc.frequencies,
+                    &desc.out_queries,
+                    &desc.out_keys,
+                ]
+            }
         }
     }
 }
@@ -1784,6 +1973,9 @@ impl core::hash::Hash for RandomOperationDescription {
             Distribution::Bernoulli(_) => 2u8.hash(state),
             Distribution::Uniform(_, _) => 3u8.hash(state),
             Distribution::Normal(_, _) => 4u8.hash(state),
+            Distribution::Exponential(_) => 5u8.hash(state),
+            Distribution::Gamma(_, _) => 6u8.hash(state),
+            Distribution::Poisson(_) => 7u8.hash(state),
         }
     }
 }
@@ -1803,6 +1995,44 @@ impl<E> core::hash::Hash for MaskFillOperationDescription<E> {
     }
 }
 
+impl core::hash::Hash for RmsNormDescription {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.x.hash(state);
+        self.gamma.hash(state);
+        self.out.hash(state);
+    }
+}
+
+impl core::hash::Hash for RmsNormBackwardDescription {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.x.hash(state);
+        self.gamma.hash(state);
+        self.grad.hash(state);
+        self.out_x_grad.hash(state);
+        self.out_gamma_grad.hash(state);
+    }
+}
+
+impl core::hash::Hash for LayerNormDescription {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.x.hash(state);
+        self.gamma.hash(state);
+        self.beta.hash(state);
+        self.out.hash(state);
+    }
+}
+
+impl core::hash::Hash for LayerNormBackwardDescription {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.x.hash(state);
+        self.gamma.hash(state);
+        self.grad.hash(state);
+        self.out_x_grad.hash(state);
+        self.out_gamma_grad.hash(state);
+        self.out_beta_grad.hash(state);
+    }
+}
+
 impl<E> core::hash::Hash for ClampOperationDescription<E> {
     fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         self.tensor.hash(state);
@@ -22,7 +22,7 @@ use crate::{
 };
 use crate::{DType, Element, TensorPrimitive};
 
-use super::{TensorMetadata, Transaction};
+use super::{DeviceTransfer, TensorMetadata, Transaction};
 
 /// A tensor with a given backend, shape and data type.
 ///
@@ -70,7 +70,7 @@ use super::{TensorMetadata, Transaction};
 ///     println!("{indexed}");
 /// }
 /// ```
-#[derive(new, Clone, Debug)]
+#[derive(new, Clone)]
 pub struct Tensor<B, const D: usize, K = Float>
 where
     B: Backend,
@@ -911,6 +911,14 @@ where
         Self::new(K::to_device(self.primitive, device))
     }
 
+    /// Starts the transfer of the tensor to the given device, without waiting for it to complete.
+    ///
+    /// This allows overlapping host-to-device uploads with computations, see
+    /// [DeviceTransfer](DeviceTransfer) for more details.
+    pub fn to_device_async(self, device: &B::Device) -> DeviceTransfer<B, D, K> {
+        DeviceTransfer::new(Self::new(K::to_device_async(self.primitive, device)))
+    }
+
     /// Converts the data of the current tensor.
     ///
     /// # Note
@@ -937,14 +945,68 @@ where
         self.clone().into_data()
     }
 
-    /// Returns the data of the current tensor.
-    pub async fn into_data_async(self) -> TensorData {
-        K::into_data_async(self.primitive).await
+    /// Returns the data of the current tensor without blocking.
+    ///
+    /// The returned future doesn't borrow the tensor, so it can be sent to another thread and
+    /// awaited there while the current thread keeps enqueuing operations.
+    pub fn into_data_async(self) -> impl Future<Output = TensorData> + 'static + Send {
+        K::into_data_async(self.primitive)
     }
 
-    /// Returns the data of the current tensor.
-    pub async fn to_data_async(&self) -> TensorData {
-        self.clone().into_data_async().await
+    /// Returns the data of the current tensor without blocking.
+    ///
+    /// See [into_data_async](Self::into_data_async).
+    pub fn to_data_async(&self) -> impl Future<Output = TensorData> + 'static + Send {
+        self.clone().into_data_async()
+    }
+
+    /// Converts the data of the elements within the given ranges, see [slice](Self::slice) for
+    /// the supported ranges.
+    ///
+    /// Only the selected elements are read, which is much cheaper than reading the whole tensor
+    /// when inspecting a few rows or values of a large tensor, e.g. in metrics or when debugging.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let tensor = Tensor::<B, 1, Int>::arange(0..1000, &device).reshape([100, 10]);
+    ///
+    ///     // Read the first two values of the last row
+    ///     let data = tensor.into_data_slice([99..100, 0..2]);
+    ///     assert_eq!(data.shape, vec![1, 2]);
+    /// }
+    /// ```
+    pub fn into_data_slice<const D2: usize, R: RangesArg<D2>>(self, ranges: R) -> TensorData {
+        crate::try_read_sync(self.into_data_slice_async(ranges)).expect(
+            "Failed to read tensor data synchronously.
+        This can happen on platforms that don't support blocking futures like WASM.
+        If possible, try using into_data_slice_async instead.",
+        )
+    }
+
+    /// Converts the data of the elements within the given ranges.
+    ///
+    /// See [into_data_slice](Self::into_data_slice).
+    pub fn to_data_slice<const D2: usize, R: RangesArg<D2>>(&self, ranges: R) -> TensorData {
+        self.clone().into_data_slice(ranges)
+    }
+
+    /// Returns the data of the elements within the given ranges without blocking.
+    ///
+    /// See [into_data_slice](Self::into_data_slice).
+    pub fn into_data_slice_async<const D2: usize, R: RangesArg<D2>>(
+        self,
+        ranges: R,
+    ) -> impl Future<Output = TensorData> + 'static + Send {
+        let ranges = ranges.into_ranges(self.shape());
+
+        check!(TensorCheck::slice::<D, D2>(&self.shape(), &ranges));
+        K::into_data_slice_async(self.primitive, ranges.to_vec())
     }
 
     /// Create a tensor from the given data on the given device.
@@ -1648,165 +1710,122 @@ impl<B, const D: usize, K> Tensor<B, D, K>
 where
     B: Backend,
     K: BasicOps<B>,
-    <K as BasicOps<B>>::Elem: Debug,
 {
-    #[inline]
-    fn push_newline_indent(acc: &mut String, indent: usize) {
-        acc.push('\n');
-        for _ in 0..indent {
-            acc.push(' ');
+    /// Formats the elements of the tensor with the given print options.
+    ///
+    /// When the tensor is summarized, only the first and last `edge_items` of each long dimension
+    /// are gathered, so the data read from the device stays small whatever the size of the tensor.
+    /// The nested dimensions are separated by new lines, or by spaces when `compact` is set.
+    fn fmt_data(&self, print_options: &PrintOptions, compact: bool) -> String {
+        let dims = self.dims();
+        let edge_items = print_options.edge_items;
+        let summarize = self.shape().num_elements() > print_options.threshold;
+        let truncated = dims.map(|size| summarize && size > 2 * edge_items);
+
+        let mut tensor = self.clone();
+        for (dim, size) in dims.iter().enumerate() {
+            if truncated[dim] {
+                let start = tensor.clone().narrow(dim, 0, edge_items);
+                let end = tensor.narrow(dim, size - edge_items, edge_items);
+                tensor = Tensor::cat(vec![start, end], dim);
+            }
         }
+
+        let shown = tensor.dims();
+        let Some(data) = burn_common::reader::try_read_sync(tensor.into_data_async()) else {
+            return String::from("<Tensor data not available>");
+        };
+        let elems = data
+            .iter::<K::Elem>()
+            .map(|elem| match (print_options.precision, K::name()) {
+                (Some(p), "Float") => format!("{:.1$}", elem, p),
+                _ => format!("{:?}", elem),
+            })
+            .collect::<Vec<_>>();
+
+        let mut acc = String::new();
+        acc.push('[');
+        Self::fmt_recursive(&mut acc, &elems, &shown, &truncated, edge_items, 0, compact);
+        acc.push(']');
+        acc
     }
-    fn fmt_inner_tensor(
-        &self,
+
+    /// Recursively formats the elements of the dimension `depth` and appends them to the
+    /// accumulator, inserting an ellipsis after the first `edge_items` of the truncated dimensions.
+    fn fmt_recursive(
         acc: &mut String,
+        elems: &[String],
+        dims: &[usize],
+        truncated: &[bool],
+        edge_items: usize,
         depth: usize,
-        multi_index: &mut [usize],
-        range: (usize, usize),
-        precision: Option<usize>,
+        compact: bool,
     ) {
-        let (start, end) = range;
-        for i in start..end {
-            if i > 0 {
-                acc.push_str(", ");
+        let separator = |acc: &mut String| match compact {
+            true => acc.push(' '),
+            false => {
+                acc.push('\n');
+                acc.extend(repeat(' ').take(depth + 1));
             }
-            multi_index[depth] = i;
-            let range: [core::ops::Range<usize>; D] =
-                core::array::from_fn(|i| multi_index[i]..multi_index[i] + 1);
-
-            let data =
-                burn_common::reader::try_read_sync(self.clone().slice(range).into_data_async());
-
-            if let Some(data) = data {
-                let elem = data.iter::<<K as BasicOps<B>>::Elem>().next().unwrap();
-                match (precision, K::name()) {
-                    (Some(p), "Float") => acc.push_str(&format!("{:.1$}", elem, p)),
-                    _ => acc.push_str(&format!("{:?}", elem)),
+        };
+
+        if depth == dims.len() - 1 {
+            for (i, elem) in elems.iter().enumerate() {
+                if i > 0 {
+                    acc.push_str(", ");
                 }
-            } else {
-                acc.push_str("<Tensor data not available>");
+                if truncated[depth] && i == edge_items {
+                    acc.push_str("..., ");
+                }
+                acc.push_str(elem);
             }
+            return;
         }
-    }
 
-    fn fmt_outer_tensor(
-        &self,
-        acc: &mut String,
-        depth: usize,
-        multi_index: &mut [usize],
-        print_options: &PrintOptions,
-        summarize: bool,
-        range: (usize, usize),
-    ) {
-        let (start, end) = range;
-        for i in start..end {
-            if i > start {
+        let block_size = dims[depth + 1..].iter().product::<usize>();
+        for i in 0..dims[depth] {
+            if i > 0 {
                 acc.push(',');
-                Self::push_newline_indent(acc, depth + 1);
+                separator(acc);
+            }
+            if truncated[depth] && i == edge_items {
+                acc.push_str("...");
+                separator(acc);
             }
             acc.push('[');
-            multi_index[depth] = i;
-            self.display_recursive(acc, depth + 1, multi_index, print_options, summarize);
+            Self::fmt_recursive(
+                acc,
+                &elems[i * block_size..(i + 1) * block_size],
+                dims,
+                truncated,
+                edge_items,
+                depth + 1,
+                compact,
+            );
             acc.push(']');
         }
     }
 
-    /// Recursively formats the tensor data for display and appends it to the provided accumulator string.
-    ///
-    /// This function is designed to work with tensors of any dimensionality.
-    /// It traverses the tensor dimensions recursively, converting the elements
-    /// to strings and appending them to the accumulator string with the
-    /// appropriate formatting.
-    ///
-    /// # Arguments
-    ///
-    /// * `acc` - A mutable reference to a `String` used as an accumulator for the formatted output.
-    /// * `depth` - The current depth of the tensor dimensions being processed.
-    /// * `multi_index` - A mutable slice of `usize` representing the current indices in each dimension.
-    fn display_recursive(
-        &self,
-        acc: &mut String,
-        depth: usize,
-        multi_index: &mut [usize],
-        print_options: &PrintOptions,
-        summarize: bool,
-    ) {
-        let edge_items = print_options.edge_items;
-
-        if depth == 0 {
-            acc.push('[');
+    /// The data type displayed for the tensor.
+    fn display_dtype(&self) -> DType {
+        // Bool tensors might be encoded in a different type, which we abstract for the display
+        if TypeId::of::<K::Elem>() == TypeId::of::<bool>() {
+            DType::Bool
+        } else {
+            self.primitive.dtype()
         }
+    }
 
-        if depth == self.dims().len() - 1 {
-            // if we are at the innermost dimension, just push its elements into the accumulator
-            if summarize && self.dims()[depth] > 2 * edge_items {
-                // print the starting `edge_items` elements
-                self.fmt_inner_tensor(
-                    acc,
-                    depth,
-                    multi_index,
-                    (0, edge_items),
-                    print_options.precision,
-                );
-                acc.push_str(", ...");
-                // print the last `edge_items` elements
-                self.fmt_inner_tensor(
-                    acc,
-                    depth,
-                    multi_index,
-                    (self.dims()[depth] - edge_items, self.dims()[depth]),
-                    print_options.precision,
-                );
-            } else {
-                // print all the elements
-                self.fmt_inner_tensor(
-                    acc,
-                    depth,
-                    multi_index,
-                    (0, self.dims()[depth]),
-                    print_options.precision,
-                );
-            }
-        } else {
-            // otherwise, iterate through the current dimension and recursively display the inner tensors
-            if summarize && self.dims()[depth] > 2 * edge_items {
-                self.fmt_outer_tensor(
-                    acc,
-                    depth,
-                    multi_index,
-                    print_options,
-                    summarize,
-                    (0, edge_items),
-                );
+    /// The print options, with the precision overridden by the formatter if set, e.g. when the
+    /// tensor is printed using the `{:.3}` syntax.
+    fn display_options(f: &core::fmt::Formatter<'_>) -> PrintOptions {
+        let mut print_options = print_options();
 
-                acc.push(',');
-                Self::push_newline_indent(acc, depth + 1);
-                acc.push_str("...");
-                Self::push_newline_indent(acc, depth + 1);
-
-                self.fmt_outer_tensor(
-                    acc,
-                    depth,
-                    multi_index,
-                    print_options,
-                    summarize,
-                    (self.dims()[depth] - edge_items, self.dims()[depth]),
-                );
-            } else {
-                self.fmt_outer_tensor(
-                    acc,
-                    depth,
-                    multi_index,
-                    print_options,
-                    summarize,
-                    (0, self.dims()[depth]),
-                );
-            }
+        if let Some(precision) = f.precision() {
+            print_options.precision = Some(precision);
         }
 
-        if depth == 0 {
-            acc.push(']');
-        }
+        print_options
     }
 }
 
@@ -1848,6 +1867,11 @@ pub fn set_print_options(options: PrintOptions) {
     *print_opts = options;
 }
 
+/// Get the current print options
+pub fn print_options() -> PrintOptions {
+    PRINT_OPTS.read().unwrap().clone()
+}
+
 /// Pretty print tensors
 impl<B, const D: usize, K> core::fmt::Display for Tensor<B, D, K>
 where
@@ -1857,46 +1881,42 @@ where
     <K as BasicOps<B>>::Elem: Debug,
 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        writeln!(f, "Tensor {{")?;
-
-        {
-            // Do not lock the mutex for the whole function
-            let mut po = { PRINT_OPTS.read().unwrap().clone() };
-
-            // Override the precision if it is set from the formatter
-            // This will be possible when the tensor is printed using the `{:.*}` syntax
-            if let Some(precision) = f.precision() {
-                po.precision = Some(precision);
-            }
-
-            let mut acc = String::new();
-            let mut multi_index = vec![0; D];
-            let summarize = self.shape().num_elements() > po.threshold;
-
-            self.display_recursive(&mut acc, 0, &mut multi_index, &po, summarize);
-
-            writeln!(f, "  data:")?;
-            write!(f, "{acc}")?;
-            writeln!(f, ",")?;
-        }
+        let print_options = Self::display_options(f);
 
+        writeln!(f, "Tensor {{")?;
+        writeln!(f, "  data:")?;
+        write!(f, "{}", self.fmt_data(&print_options, false))?;
+        writeln!(f, ",")?;
         writeln!(f, "  shape:  {:?},", self.dims())?;
         writeln!(f, "  device:  {:?},", self.device())?;
         writeln!(f, "  backend:  {:?},", B::name())?;
         writeln!(f, "  kind:  {:?},", K::name())?;
-
-        // Bool tensors might be encoded in a different type, which we abstract for the display
-        let dtype = if TypeId::of::<K::Elem>() == TypeId::of::<bool>() {
-            DType::Bool
-        } else {
-            self.primitive.dtype()
-        };
-
-        writeln!(f, "  dtype:  {:?},", dtype.name())?;
+        writeln!(f, "  dtype:  {:?},", self.display_dtype().name())?;
         write!(f, "}}")
     }
 }
 
+/// Print tensors on a single line, with the same summarized values and
+/// [print options](PrintOptions) as the display.
+impl<B, const D: usize, K> core::fmt::Debug for Tensor<B, D, K>
+where
+    B: Backend,
+    K: BasicOps<B>,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let print_options = Self::display_options(f);
+
+        write!(
+            f,
+            "Tensor {{ data: {}, shape: {:?}, device: {:?}, dtype: {:?} }}",
+            self.fmt_data(&print_options, true),
+            self.dims(),
+            self.device(),
+            self.display_dtype().name(),
+        )
+    }
+}
+
 /// Transpose marker (zero-size type). Used to sugar the transpose of a tensor, e.g.
 /// ```rust
 /// use burn_tensor::backend::Backend;
@@ -2104,6 +2124,19 @@ pub trait BasicOps<B: Backend>: TensorKind<B> {
     /// which is more high-level and designed for public use.
     fn to_device(tensor: Self::Primitive, device: &B::Device) -> Self::Primitive;
 
+    /// Moves the tensor to the given device without blocking the host until the copy is done.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For moving a tensor to a device, users should prefer the
+    /// [Tensor::to_device_async](Tensor::to_device_async) function, which is more high-level and
+    /// designed for public use.
+    fn to_device_async(tensor: Self::Primitive, device: &B::Device) -> Self::Primitive;
+
     /// Extracts the data from the tensor asynchronously.
     ///
     /// # Arguments
@@ -2126,6 +2159,22 @@ pub trait BasicOps<B: Backend>: TensorKind<B> {
         tensor: Self::Primitive,
     ) -> impl Future<Output = TensorData> + 'static + Send;
 
+    /// Read the data of the elements within the given ranges of the tensor.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For extracting the data of a part of a tensor, users should prefer the
+    /// [Tensor::into_data_slice](Tensor::into_data_slice) function, which is more high-level and
+    /// designed for public use.
+    fn into_data_slice_async(
+        tensor: Self::Primitive,
+        ranges: Vec<Range<usize>>,
+    ) -> impl Future<Output = TensorData> + 'static + Send;
+
     /// Read the data from the tensor using a transaction.
     ///
     /// # Remarks
@@ -2491,6 +2540,17 @@ impl<B: Backend> BasicOps<B> for Float {
         }
     }
 
+    fn to_device_async(tensor: Self::Primitive, device: &Device<B>) -> Self::Primitive {
+        match tensor {
+            TensorPrimitive::Float(tensor) => {
+                TensorPrimitive::Float(B::float_to_device_async(tensor, device))
+            }
+            TensorPrimitive::QFloat(tensor) => {
+                TensorPrimitive::QFloat(B::q_to_device(tensor, device))
+            }
+        }
+    }
+
     async fn into_data_async(tensor: Self::Primitive) -> TensorData {
         match tensor {
             TensorPrimitive::Float(tensor) => B::float_into_data(tensor).await,
@@ -2498,6 +2558,20 @@ impl<B: Backend> BasicOps<B> for Float {
         }
     }
 
+    fn into_data_slice_async(
+        tensor: Self::Primitive,
+        ranges: Vec<Range<usize>>,
+    ) -> impl Future<Output = TensorData> + 'static + Send {
+        async move {
+            match tensor {
+                TensorPrimitive::Float(tensor) => B::float_into_data_slice(tensor, ranges).await,
+                TensorPrimitive::QFloat(tensor) => {
+                    B::q_into_data(B::q_slice(tensor, &ranges)).await
+                }
+            }
+        }
+    }
+
     fn from_data(data: TensorData, device: &B::Device) -> Self::Primitive {
         match data.dtype {
             DType::QFloat(_strategy) => TensorPrimitive::QFloat(B::q_from_data(data, device)),
@@ -2669,10 +2743,21 @@ impl<B: Backend> BasicOps<B> for Int {
         B::int_to_device(tensor, device)
     }
 
+    fn to_device_async(tensor: Self::Primitive, device: &Device<B>) -> Self::Primitive {
+        B::int_to_device_async(tensor, device)
+    }
+
     async fn into_data_async(tensor: Self::Primitive) -> TensorData {
         B::int_into_data(tensor).await
     }
 
+    fn into_data_slice_async(
+        tensor: Self::Primitive,
+        ranges: Vec<Range<usize>>,
+    ) -> impl Future<Output = TensorData> + 'static + Send {
+        B::int_into_data_slice(tensor, ranges)
+    }
+
     fn from_data(data: TensorData, device: &B::Device) -> Self::Primitive {
         B::int_from_data(data, device)
     }
@@ -2781,10 +2866,21 @@ impl<B: Backend> BasicOps<B> for Bool {
         B::bool_to_device(tensor, device)
     }
 
+    fn to_device_async(tensor: Self::Primitive, device: &Device<B>) -> Self::Primitive {
+        B::bool_to_device_async(tensor, device)
+    }
+
     async fn into_data_async(tensor: Self::Primitive) -> TensorData {
         B::bool_into_data(tensor).await
     }
 
+    fn into_data_slice_async(
+        tensor: Self::Primitive,
+        ranges: Vec<Range<usize>>,
+    ) -> impl Future<Output = TensorData> + 'static + Send {
+        B::bool_into_data_slice(tensor, ranges)
+    }
+
     fn from_data(data: TensorData, device: &B::Device) -> Self::Primitive {
         B::bool_from_data(data, device)
     }
@@ -1,4 +1,4 @@
-use crate::{backend::Backend, BasicOps, Numeric, Shape, Tensor};
+use crate::{backend::Backend, BasicOps, Distribution, Numeric, Shape, Tensor};
 use alloc::format;
 use alloc::string::{String, ToString};
 use alloc::vec;
@@ -345,6 +345,88 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn diagonal<const D: usize, const D2: usize>(ops: &str) -> Self {
+        let mut check = Self::Ok;
+
+        if D < 2 {
+            check = check.register(
+                ops,
+                TensorError::new(format!(
+                    "The input tensor must have at least 2 dimensions, got {D}"
+                )),
+            );
+        }
+
+        if D2 + 1 != D {
+            check = check.register(
+                ops,
+                TensorError::new(format!(
+                    "The diagonal must have one dimension less than the tensor, got {D2} and {D}"
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn put_on_diagonal(shape_values: &Shape, shape_expected: &Shape) -> Self {
+        let mut check = Self::Ok;
+
+        if shape_values != shape_expected {
+            check = check.register(
+                "Put on diagonal",
+                TensorError::new("The values must have the shape of the diagonal").details(
+                    format!(
+                        "The shape of the values ({:?}) differs from the shape of the diagonal \
+                         ({:?})",
+                        shape_values.dims, shape_expected.dims
+                    ),
+                ),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn bands<const D: usize>(
+        ops: &str,
+        shape: &Shape,
+        lower: usize,
+        upper: usize,
+    ) -> Self {
+        let mut check = Self::Ok;
+        let width = lower + upper + 1;
+
+        if D >= 2 && shape.dims[D - 2] != width {
+            check = check.register(
+                ops,
+                TensorError::new(
+                    "The bands must have one row per diagonal of the band".to_string(),
+                )
+                .details(format!(
+                    "Expected {width} diagonals for {lower} lower and {upper} upper diagonals, got \
+                     {}",
+                    shape.dims[D - 2]
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn toeplitz(shape_column: &Shape, shape_row: &Shape) -> Self {
+        let mut check = Self::Ok;
+
+        if shape_column.num_elements() == 0 || shape_row.num_elements() == 0 {
+            check = check.register(
+                "Toeplitz",
+                TensorError::new("The first column and the first row must not be empty"),
+            );
+        }
+
+        check
+    }
+
     pub(crate) fn squeeze<const D2: usize>(dim: usize, tensor_dims: &[usize]) -> Self {
         let mut check = Self::Ok;
         // This should actually be to check that the dimension to squeeze
@@ -1006,6 +1088,168 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn kthvalue<const D: usize>(ops: &str, shape: &Shape, k: usize, dim: usize) -> Self {
+        let mut check = Self::Ok;
+
+        if dim >= D {
+            check = check.register(
+                ops,
+                TensorError::new(format!(
+                    "Can't select along axis ({dim}) of a tensor with ({D}) dimensions"
+                )),
+            );
+        } else if k >= shape.dims[dim] {
+            check = check.register(
+                ops,
+                TensorError::new(format!(
+                    "The rank k ({k}) must be smaller than the size of axis ({dim}) which is ({})",
+                    shape.dims[dim]
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn mask_assign(shape: &Shape, shape_mask: &Shape) -> Self {
+        let mut check = Self::Ok;
+
+        if shape != shape_mask {
+            check = check.register(
+                "Mask Assign",
+                TensorError::new("The mask should have the same shape as the tensor.").details(
+                    format!(
+                        "Tensor shape {:?}, mask shape {:?}.",
+                        shape.dims, shape_mask.dims
+                    ),
+                ),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn mask_assign_values(num_masked: usize, num_values: usize) -> Self {
+        let mut check = Self::Ok;
+
+        if num_masked != num_values {
+            check = check.register(
+                "Mask Assign",
+                TensorError::new(
+                    "The number of values should match the number of true elements in the mask.",
+                )
+                .details(format!(
+                    "The mask has {num_masked} true elements, but {num_values} values were \
+                     provided."
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn segment(ops: &str, shape: &Shape, shape_ids: &Shape) -> Self {
+        let mut check = Self::Ok;
+
+        if shape_ids.dims[0] != shape.dims[0] {
+            check = check.register(
+                ops,
+                TensorError::new(
+                    "The ids must have the same size as the first dimension of the tensor"
+                        .to_string(),
+                )
+                .details(format!(
+                    "The size of the ids ({}) differs from the first dimension ({})",
+                    shape_ids.dims[0], shape.dims[0]
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn searchsorted<const D: usize>() -> Self {
+        let mut check = Self::Ok;
+
+        if D != 1 {
+            check = check.register(
+                "Searchsorted",
+                TensorError::new("The sorted sequence must be one-dimensional".to_string())
+                    .details(format!("The sorted sequence has {D} dimensions")),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn multinomial(shape: &Shape, num_samples: usize, replacement: bool) -> Self {
+        let mut check = Self::Ok;
+        let num_categories = shape.dims[shape.num_dims() - 1];
+
+        if num_samples == 0 {
+            check = check.register(
+                "Multinomial",
+                TensorError::new("The number of samples must be greater than zero".to_string()),
+            );
+        }
+
+        if !replacement && num_samples > num_categories {
+            check = check.register(
+                "Multinomial",
+                TensorError::new(
+                    "Can't draw more samples than categories without replacement".to_string(),
+                )
+                .details(format!(
+                    "The number of samples ({num_samples}) is greater than the number of categories ({num_categories})",
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn distribution(distribution: &Distribution) -> Self {
+        let mut check = Self::Ok;
+        let positive = |value: f64| value.is_finite() && value > 0.0;
+
+        let (valid, details) = match *distribution {
+            Distribution::Default => (true, String::new()),
+            Distribution::Bernoulli(prob) => (
+                (0.0..=1.0).contains(&prob),
+                format!("The probability ({prob}) must be between 0 and 1"),
+            ),
+            Distribution::Uniform(low, high) => (
+                low.is_finite() && high.is_finite() && low < high,
+                format!("The lower bound ({low}) must be less than the upper bound ({high})"),
+            ),
+            Distribution::Normal(mean, std) => (
+                mean.is_finite() && std.is_finite() && std >= 0.0,
+                format!(
+                    "The mean ({mean}) must be finite and the standard deviation ({std}) \
+                     non-negative"
+                ),
+            ),
+            Distribution::Exponential(rate) | Distribution::Poisson(rate) => (
+                positive(rate),
+                format!("The rate ({rate}) must be positive"),
+            ),
+            Distribution::Gamma(shape, scale) => (
+                positive(shape) && positive(scale),
+                format!("The shape ({shape}) and the scale ({scale}) must be positive"),
+            ),
+        };
+
+        if !valid {
+            check = check.register(
+                "Random",
+                TensorError::new("Invalid parameters for the distribution".to_string())
+                    .details(details),
+            );
+        }
+
+        check
+    }
+
     pub(crate) fn split<const D: usize>(
         tensor_dims: &[usize],
         split_size: usize,
@@ -2,10 +2,10 @@ use crate::check::TensorCheck;
 use crate::quantization::{QuantizationParameters, QuantizationScheme};
 use crate::tensor::backend::Backend;
 use crate::tensor::stats;
-use crate::tensor::{Distribution, TensorData};
+use crate::tensor::{Distribution, Shape, TensorData};
 use crate::Tensor;
 use crate::{check, FloatDType};
-use crate::{Int, TensorPrimitive};
+use crate::{Int, SegmentReduction, TensorPrimitive};
 
 impl<const D: usize, B> Tensor<B, D>
 where
@@ -164,6 +164,7 @@ where
     /// Returns a new tensor with the same shape and device as the current tensor filled random
     /// values sampled from the given distribution.
     pub fn random_like(&self, distribution: Distribution) -> Self {
+        check!(TensorCheck::distribution(&distribution));
         Tensor::new(TensorPrimitive::Float(B::float_random(
             self.shape(),
             distribution,
@@ -171,6 +172,36 @@ where
         )))
     }
 
+    /// Returns a new tensor with the given shape, whose slices along the last dimension are
+    /// sampled from a symmetric Dirichlet distribution with the given concentration.
+    ///
+    /// Each slice is a vector of non-negative values summing to one, drawn by normalizing
+    /// samples of a [gamma distribution](Distribution::Gamma).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let mixing_weights = Tensor::<B, 2>::random_dirichlet([8, 3], 0.5, &device);
+    ///    // Each row sums to one, e.g. [0.62, 0.03, 0.35]
+    ///    println!("{mixing_weights}");
+    /// }
+    /// ```
+    pub fn random_dirichlet<S: Into<Shape>>(
+        shape: S,
+        concentration: f64,
+        device: &B::Device,
+    ) -> Self {
+        let samples = Self::random(shape, Distribution::Gamma(concentration, 1.0), device);
+        let total = samples.clone().sum_dim(D - 1);
+
+        samples / total
+    }
+
     /// Applies the matrix multiplication operation.
     ///
     /// `C = AB`
@@ -186,6 +217,34 @@ where
         )))
     }
 
+    /// Applies the matrix multiplication operation after converting both tensors to FP8
+    /// (experimental).
+    ///
+    /// `C = (A / a) (B / b) * a * b`, where `a` and `b` are the scales of the tensors and the
+    /// divided values are rounded to their FP8 format. The products are accumulated in `f32`.
+    ///
+    /// Backends without FP8 kernels, including the autodiff backend, compute the matrix
+    /// multiplication with their float precision instead.
+    ///
+    /// # Panics
+    ///
+    /// If the two tensors don't have a compatible shape, or don't have the same batch dimensions.
+    #[cfg(feature = "fp8")]
+    pub fn matmul_fp8(
+        self,
+        other: Self,
+        lhs_scaling: crate::Fp8Scaling,
+        rhs_scaling: crate::Fp8Scaling,
+    ) -> Self {
+        check!(TensorCheck::matmul(&self, &other));
+        Self::new(TensorPrimitive::Float(B::float_matmul_fp8(
+            self.primitive.tensor(),
+            lhs_scaling,
+            other.primitive.tensor(),
+            rhs_scaling,
+        )))
+    }
+
     /// Calculate the variance along the given dimension.
     pub fn var(self, dim: usize) -> Self {
         stats::var(self, dim)
@@ -248,6 +307,17 @@ where
         }
     }
 
+    /// Returns true if the tensor wraps memory allocated outside of the backend, which was
+    /// imported without copying it.
+    ///
+    /// Only backends with a primitive importing external memory, like LibTorch, return true.
+    pub fn is_external(&self) -> bool {
+        match &self.primitive {
+            TensorPrimitive::Float(tensor) => B::float_is_external(tensor),
+            TensorPrimitive::QFloat(_) => false,
+        }
+    }
+
     /// Mark the tensor as tracked or untracked depending on the require_grad argument.
     /// When tracked, the gradients will be available after the backward pass.
     ///
@@ -333,4 +403,190 @@ where
     pub fn dequantize(self) -> Tensor<B, D> {
         Tensor::new(TensorPrimitive::Float(self.primitive.tensor()))
     }
+
+    /// Sum the slices along the first dimension sharing the same segment id.
+    ///
+    /// The `ids` must be sorted in ascending order and be smaller than `num_segments`, see
+    /// [unsorted_segment_sum](Tensor::unsorted_segment_sum) otherwise. Empty segments are zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 2>::from_data([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]], &device);
+    ///    let ids = Tensor::<B, 1, Int>::from_data([0, 0, 2], &device);
+    ///    let tensor = tensor.segment_sum(ids, 3);
+    ///    // [[4.0, 6.0], [0.0, 0.0], [5.0, 6.0]]
+    ///    println!("{tensor}");
+    /// }
+    /// ```
+    pub fn segment_sum(self, ids: Tensor<B, 1, Int>, num_segments: usize) -> Self {
+        self.segment(ids, num_segments, SegmentReduction::Sum, true)
+    }
+
+    /// Average the slices along the first dimension sharing the same segment id.
+    ///
+    /// The `ids` must be sorted in ascending order and be smaller than `num_segments`, see
+    /// [unsorted_segment_mean](Tensor::unsorted_segment_mean) otherwise. Empty segments are zero.
+    pub fn segment_mean(self, ids: Tensor<B, 1, Int>, num_segments: usize) -> Self {
+        self.segment(ids, num_segments, SegmentReduction::Mean, true)
+    }
+
+    /// Find the maximum of the slices along the first dimension sharing the same segment id.
+    ///
+    /// The `ids` must be sorted in ascending order and be smaller than `num_segments`, see
+    /// [unsorted_segment_max](Tensor::unsorted_segment_max) otherwise. Empty segments are zero.
+    pub fn segment_max(self, ids: Tensor<B, 1, Int>, num_segments: usize) -> Self {
+        self.segment(ids, num_segments, SegmentReduction::Max, true)
+    }
+
+    /// Sum the slices along the first dimension sharing the same segment id.
+    ///
+    /// The `ids` can be in any order, and must be smaller than `num_segments`. Empty segments are
+    /// zero.
+    pub fn unsorted_segment_sum(self, ids: Tensor<B, 1, Int>, num_segments: usize) -> Self {
+        self.segment(ids, num_segments, SegmentReduction::Sum, false)
+    }
+
+    /// Average the slices along the first dimension sharing the same segment id.
+    ///
+    /// The `ids` can be in any order, and must be smaller than `num_segments`. Empty segments are
+    /// zero.
+    pub fn unsorted_segment_mean(self, ids: Tensor<B, 1, Int>, num_segments: usize) -> Self {
+        self.segment(ids, num_segments, SegmentReduction::Mean, false)
+    }
+
+    /// Find the maximum of the slices along the first dimension sharing the same segment id.
+    ///
+    /// The `ids` can be in any order, and must be smaller than `num_segments`. Empty segments are
+    /// zero.
+    pub fn unsorted_segment_max(self, ids: Tensor<B, 1, Int>, num_segments: usize) -> Self {
+        self.segment(ids, num_segments, SegmentReduction::Max, false)
+    }
+
+    /// Draw `num_samples` indices from the categorical distributions given by the probabilities
+    /// of the last dimension.
+    ///
+    /// The probabilities must be non-negative but don't have to sum to one. Without replacement,
+    /// each category is drawn at most once, so `num_samples` can't exceed the number of
+    /// categories.
+    ///
+    /// # Returns
+    ///
+    /// The indices of the drawn categories, of shape `[..., num_samples]`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, activation};
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let logits = Tensor::<B, 2>::from_data([[1.0, 2.0, 0.5], [0.0, 3.0, 1.0]], &device);
+    ///    let next_tokens = activation::softmax(logits, 1).multinomial(1, false);
+    ///    // Shape [2, 1]
+    ///    println!("{next_tokens}");
+    /// }
+    /// ```
+    pub fn multinomial(self, num_samples: usize, replacement: bool) -> Tensor<B, D, Int> {
+        check!(TensorCheck::multinomial(
+            &self.shape(),
+            num_samples,
+            replacement
+        ));
+
+        Tensor::new(B::float_multinomial(
+            self.primitive.tensor(),
+            num_samples,
+            replacement,
+        ))
+    }
+
+    /// Find the indices where the `values` would be inserted in the tensor to keep it sorted.
+    ///
+    /// The tensor must be one-dimensional and sorted in ascending order. The index of a value is
+    /// before the elements equal to it, or after them when `right` is true.
+    ///
+    /// # Returns
+    ///
+    /// The indices in `[0, num_elements]`, of the same shape as the `values`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let sorted = Tensor::<B, 1>::from_data([1.0, 3.0, 5.0, 7.0], &device);
+    ///    let values = Tensor::<B, 2>::from_data([[3.0, 6.0], [0.0, 9.0]], &device);
+    ///    let indices = sorted.searchsorted(values, false);
+    ///    // [[1, 3], [0, 4]]
+    ///    println!("{indices}");
+    /// }
+    /// ```
+    pub fn searchsorted<const D2: usize>(
+        self,
+        values: Tensor<B, D2>,
+        right: bool,
+    ) -> Tensor<B, D2, Int> {
+        check!(TensorCheck::searchsorted::<D>());
+
+        Tensor::new(B::float_searchsorted(
+            self.primitive.tensor(),
+            values.primitive.tensor(),
+            right,
+        ))
+    }
+
+    /// Find the index of the bucket of each element, the buckets being delimited by the sorted
+    /// `boundaries`.
+    ///
+    /// An element `x` is in the bucket `i` when `boundaries[i - 1] < x <= boundaries[i]`, or
+    /// when `boundaries[i - 1] <= x < boundaries[i]` if `right` is true. The elements smaller
+    /// than the first boundary are in the bucket `0` and the elements greater than the last
+    /// boundary in the bucket `num_boundaries`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let ages = Tensor::<B, 1>::from_data([4.0, 18.0, 35.0, 70.0], &device);
+    ///    let boundaries = Tensor::<B, 1>::from_data([12.0, 18.0, 65.0], &device);
+    ///    let buckets = ages.bucketize(boundaries, true);
+    ///    // [0, 2, 2, 3]
+    ///    println!("{buckets}");
+    /// }
+    /// ```
+    pub fn bucketize(self, boundaries: Tensor<B, 1>, right: bool) -> Tensor<B, D, Int> {
+        boundaries.searchsorted(self, right)
+    }
+
+    fn segment(
+        self,
+        ids: Tensor<B, 1, Int>,
+        num_segments: usize,
+        reduction: SegmentReduction,
+        sorted: bool,
+    ) -> Self {
+        check!(TensorCheck::segment("Segment", &self.shape(), &ids.shape()));
+
+        Self::new(TensorPrimitive::Float(B::float_segment_reduce(
+            self.primitive.tensor(),
+            ids.primitive,
+            num_segments,
+            reduction,
+            sorted,
+        )))
+    }
 }
@@ -1,5 +1,6 @@
 use crate::{
-    backend::Backend, cartesian_grid, Float, Int, Shape, Tensor, TensorData, TensorPrimitive,
+    backend::Backend, cartesian_grid, check, check::TensorCheck, Float, Int, Shape, Tensor,
+    TensorData, TensorPrimitive,
 };
 
 use core::ops::Range;
@@ -27,6 +28,128 @@ where
     pub fn arange_step(range: Range<i64>, step: usize, device: &B::Device) -> Self {
         Tensor::new(B::int_arange_step(range, step, device))
     }
+
+    /// Returns a random permutation of the integers from `0` to `n - 1`, on the specified device.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let indices = Tensor::<B, 1, Int>::randperm(5, &device);
+    ///    // A shuffling of [0, 1, 2, 3, 4], e.g. [3, 0, 4, 1, 2]
+    ///    println!("{indices}");
+    /// }
+    /// ```
+    pub fn randperm(n: usize, device: &B::Device) -> Self {
+        Tensor::new(B::int_randperm(n, device))
+    }
+
+    /// Count the number of occurrences of each value of the tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `min_length` - The minimum number of bins.
+    ///
+    /// # Returns
+    ///
+    /// A tensor of shape `[max(max_value + 1, min_length)]` with the count of each value, where
+    /// the values must be non-negative.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 1, Int>::from_ints([1, 3, 1, 0], &device);
+    ///    let counts = tensor.bincount(0);
+    ///    // [1, 2, 0, 1]
+    ///    println!("{counts}");
+    /// }
+    /// ```
+    pub fn bincount(self, min_length: usize) -> Self {
+        Tensor::new(B::int_bincount(self.primitive, min_length))
+    }
+
+    /// Returns the sorted unique values of the tensor.
+    ///
+    /// See [unique_with_inverse_and_counts](Tensor::unique_with_inverse_and_counts) to also get
+    /// the inverse indices and the counts.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 1, Int>::from_ints([3, 1, 3, 2, 1], &device);
+    ///    let unique = tensor.unique();
+    ///    // [1, 2, 3]
+    ///    println!("{unique}");
+    /// }
+    /// ```
+    pub fn unique(self) -> Self {
+        self.unique_with_inverse_and_counts().0
+    }
+
+    /// Returns the sorted unique values of the tensor, along with the index of the unique value of
+    /// each element and the number of elements of each unique value.
+    ///
+    /// The unique values indexed by the inverse indices are equal to the tensor.
+    pub fn unique_with_inverse_and_counts(self) -> (Self, Self, Self) {
+        let (unique, inverse, counts) = B::int_unique(self.primitive, false);
+
+        (
+            Tensor::new(unique),
+            Tensor::new(inverse),
+            Tensor::new(counts),
+        )
+    }
+
+    /// Returns the values of the tensor where consecutive repeated values are merged.
+    ///
+    /// See
+    /// [unique_consecutive_with_inverse_and_counts](Tensor::unique_consecutive_with_inverse_and_counts)
+    /// to also get the inverse indices and the counts.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 1, Int>::from_ints([3, 3, 1, 1, 3], &device);
+    ///    let unique = tensor.unique_consecutive();
+    ///    // [3, 1, 3]
+    ///    println!("{unique}");
+    /// }
+    /// ```
+    pub fn unique_consecutive(self) -> Self {
+        self.unique_consecutive_with_inverse_and_counts().0
+    }
+
+    /// Returns the values of the tensor where consecutive repeated values are merged, along with
+    /// the index of the merged value of each element and the number of elements of each merged
+    /// value.
+    pub fn unique_consecutive_with_inverse_and_counts(self) -> (Self, Self, Self) {
+        let (unique, inverse, counts) = B::int_unique(self.primitive, true);
+
+        (
+            Tensor::new(unique),
+            Tensor::new(inverse),
+            Tensor::new(counts),
+        )
+    }
 }
 
 impl<const D: usize, B> Tensor<B, D, Int>
@@ -154,4 +277,92 @@ where
     pub fn bitwise_right_shift_scalar(self, other: B::IntElem) -> Self {
         Self::new(B::bitwise_right_shift_scalar(self.primitive, other))
     }
+
+    /// Find the `k`-th smallest value along the given dimension, where `k` is zero-based.
+    ///
+    /// The value is selected without sorting the whole dimension, and the size of `dim` in the
+    /// output is one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 2, Int>::from_data([[7, 1, 4], [2, 9, 5]], &device);
+    ///    let tensor = tensor.kthvalue(1, 1);
+    ///    // [[4], [5]]
+    ///    println!("{tensor}");
+    /// }
+    /// ```
+    pub fn kthvalue(self, k: usize, dim: usize) -> Self {
+        self.kthvalue_with_indices(k, dim).0
+    }
+
+    /// Find the `k`-th smallest value along the given dimension, where `k` is zero-based.
+    ///
+    /// Also returns the indices of the values along `dim`. When the value is repeated, any of its
+    /// indices can be returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 2, Int>::from_data([[7, 1, 4], [2, 9, 5]], &device);
+    ///    let (tensor, indices) = tensor.kthvalue_with_indices(1, 1);
+    ///    // [[4], [5]]
+    ///    println!("{tensor}");
+    ///    // [[2], [2]]
+    ///    println!("{indices}");
+    /// }
+    /// ```
+    pub fn kthvalue_with_indices(self, k: usize, dim: usize) -> (Self, Tensor<B, D, Int>) {
+        check!(TensorCheck::kthvalue::<D>(
+            "KthValue",
+            &self.shape(),
+            k,
+            dim
+        ));
+
+        let (values, indices) = B::int_kthvalue_with_indices(self.primitive, k, dim);
+        (Self::new(values), Tensor::new(indices))
+    }
+
+    /// Find the median value along the given dimension.
+    ///
+    /// When the size of `dim` is even, the lower of the two middle values is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 2, Int>::from_data([[7, 1, 4, 3], [2, 9, 5, 6]], &device);
+    ///    let tensor = tensor.median_dim(1);
+    ///    // [[3], [5]]
+    ///    println!("{tensor}");
+    /// }
+    /// ```
+    pub fn median_dim(self, dim: usize) -> Self {
+        self.median_dim_with_indices(dim).0
+    }
+
+    /// Find the median value along the given dimension.
+    ///
+    /// Also returns the indices of the values along `dim`.
+    pub fn median_dim_with_indices(self, dim: usize) -> (Self, Tensor<B, D, Int>) {
+        check!(TensorCheck::aggregate_dim::<D>("MedianDim", dim));
+
+        let k = self.dims()[dim].saturating_sub(1) / 2;
+        self.kthvalue_with_indices(k, dim)
+    }
 }
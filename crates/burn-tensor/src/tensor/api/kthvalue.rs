@@ -0,0 +1,106 @@
+use crate::{
+    backend::Backend,
+    ops::{IntElem, IntTensor},
+    BasicOps, Element, ElementComparison, ElementConversion, TensorData, TensorKind,
+};
+use alloc::{vec, vec::Vec};
+use burn_common::reader::try_read_sync;
+
+/// Find the `k`-th smallest element of the input `tensor` along a given dimension, along with
+/// its index.
+///
+/// The elements are selected with quickselect, without sorting the whole dimension.
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor.
+/// * `k` - The zero-based rank of the element to select.
+/// * `dim` - The axis along which to select.
+///
+/// # Returns
+///
+/// A tensor with the same shape as the input tensor except for the size of `dim` which is one,
+/// where the elements are the `k`-th smallest values, and the corresponding indices along `dim`.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn kthvalue_with_indices<B: Backend, K: TensorKind<B> + BasicOps<B>>(
+    tensor: K::Primitive,
+    k: usize,
+    dim: usize,
+) -> (K::Primitive, IntTensor<B>)
+where
+    <K as BasicOps<B>>::Elem: Element,
+{
+    let device = K::device(&tensor);
+    let data = try_read_sync(K::into_data_async(tensor)).expect("Failed to synchronously read tensor data. This operation is not supported until this backend has a GPU selection implementation.");
+
+    let (values, indices) = kthvalue_data::<B, K>(data, k, dim);
+
+    (
+        K::from_data(values, &device),
+        B::int_from_data(indices, &device),
+    )
+}
+
+fn kthvalue_data<B: Backend, K: TensorKind<B> + BasicOps<B>>(
+    data: TensorData,
+    k: usize,
+    dim: usize,
+) -> (TensorData, TensorData)
+where
+    <K as BasicOps<B>>::Elem: Element,
+{
+    let dims = data.shape.clone();
+    let ndims = dims.len();
+    let strides = compute_strides(&dims);
+
+    let mut dims_out = dims.clone();
+    dims_out[dim] = 1;
+    let strides_out = compute_strides(&dims_out);
+    let num_elements_out = dims_out.iter().product::<usize>();
+
+    let slice = data.as_slice::<<K as BasicOps<B>>::Elem>().unwrap();
+    let mut values = Vec::with_capacity(num_elements_out);
+    let mut indices = Vec::with_capacity(num_elements_out);
+    let mut elements = vec![];
+
+    for id in 0..num_elements_out {
+        let mut offset = 0;
+        for d in 0..ndims {
+            if d != dim {
+                offset += id / strides_out[d] % dims_out[d] * strides[d];
+            }
+        }
+
+        elements.clear();
+        elements.extend((0..dims[dim]).map(|i| (i, slice[offset + i * strides[dim]])));
+
+        let (_, (index, value), _) = elements.select_nth_unstable_by(k, |(_, a), (_, b)| a.cmp(b));
+
+        values.push(*value);
+        indices.push((*index as i64).elem::<IntElem<B>>());
+    }
+
+    (
+        TensorData::new(values, dims_out.clone()),
+        TensorData::new(indices, dims_out),
+    )
+}
+
+/// Computes the steps for each dimension when traversing an array.
+fn compute_strides(dims: &[usize]) -> Vec<usize> {
+    let mut strides = vec![0; dims.len()];
+    let mut current = 1;
+
+    dims.iter().enumerate().rev().for_each(|(index, val)| {
+        strides[index] = current;
+        current *= val;
+    });
+
+    strides
+}
@@ -9,11 +9,19 @@ mod chunk;
 mod float;
 mod int;
 mod kind;
+mod kthvalue;
+mod multinomial;
 mod narrow;
 mod numeric;
+mod scatter_reduce;
+mod searchsorted;
+mod segment;
 mod sort;
 mod split;
+mod summary;
 mod transaction;
+mod transfer;
+mod unique;
 
 pub use argwhere::argwhere_data;
 pub use autodiff::*;
@@ -21,8 +29,16 @@ pub use base::*;
 pub use cartesian_grid::cartesian_grid;
 pub use chunk::chunk;
 pub use kind::*;
+pub use kthvalue::kthvalue_with_indices;
+pub use multinomial::multinomial;
 pub use narrow::narrow;
 pub use numeric::*;
+pub use scatter_reduce::{scatter_reduce, ScatterReduceOptions, ScatterReduction};
+pub use searchsorted::searchsorted;
+pub use segment::{segment_reduce, SegmentReduction};
 pub use sort::{argsort, sort, sort_with_indices};
 pub use split::{split, split_with_sizes};
+pub use summary::TensorSummary;
 pub use transaction::*;
+pub use transfer::*;
+pub use unique::{bincount, unique};
@@ -0,0 +1,73 @@
+use crate::{
+    backend::Backend,
+    ops::{FloatTensor, IntTensor},
+    Distribution, ElementConversion, Shape, TensorMetadata,
+};
+use alloc::vec::Vec;
+
+/// Draw indices of categories from the probabilities of the last dimension of the input `tensor`.
+///
+/// The samples are drawn with the Gumbel-max trick: the index of the maximum of the log
+/// probabilities perturbed with Gumbel noise follows the categorical distribution, so it only
+/// requires random, element-wise and arg max operations. Without replacement, the indices of the
+/// largest perturbed log probabilities are taken instead, which is the Gumbel-top-k trick.
+///
+/// # Arguments
+///
+/// * `tensor` - The probabilities of shape `[..., num_categories]`, which don't have to sum to one.
+/// * `num_samples` - The number of samples drawn from each distribution.
+/// * `replacement` - If a category can be drawn more than once.
+///
+/// # Returns
+///
+/// The indices of the drawn categories, of shape `[..., num_samples]`.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn multinomial<B: Backend>(
+    tensor: FloatTensor<B>,
+    num_samples: usize,
+    replacement: bool,
+) -> IntTensor<B> {
+    let device = B::float_device(&tensor);
+    let shape = tensor.shape();
+    let dim = shape.num_dims() - 1;
+    let log_probs = B::float_log(tensor);
+
+    if !replacement {
+        let perturbed = B::float_add(log_probs, gumbel::<B>(shape, &device));
+        let indices = B::float_argsort(perturbed, dim, true);
+
+        return B::int_narrow(indices, dim, 0, num_samples);
+    }
+
+    // Each sample is drawn from its own perturbation of the log probabilities, of shape
+    // `[..., num_samples, num_categories]`.
+    let mut dims = shape.dims.clone();
+    dims.insert(dim, 1);
+    let log_probs = B::float_reshape(log_probs, Shape::from(dims.clone()));
+    dims[dim] = num_samples;
+    let log_probs = B::float_expand(log_probs, Shape::from(dims.clone()));
+
+    let perturbed = B::float_add(log_probs, gumbel::<B>(Shape::from(dims), &device));
+    let indices = B::float_argmax(perturbed, dim + 1);
+
+    let mut dims: Vec<usize> = shape.dims;
+    dims[dim] = num_samples;
+    B::int_reshape(indices, Shape::from(dims))
+}
+
+/// Standard Gumbel noise `-log(-log(u))`, where `u` is uniform.
+fn gumbel<B: Backend>(shape: Shape, device: &B::Device) -> FloatTensor<B> {
+    let uniform = B::float_random(shape, Distribution::Default, device);
+    let exponential = B::float_neg(B::float_log(uniform));
+    // A uniform sample rounded to one would give an infinite noise, always drawing its category.
+    // The bound stays representable in half precision.
+    let exponential = B::float_clamp_min(exponential, 1e-7_f64.elem());
+
+    B::float_neg(B::float_log(exponential))
+}
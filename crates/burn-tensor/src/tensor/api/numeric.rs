@@ -8,8 +8,8 @@ use crate::{
     check,
     check::TensorCheck,
     ops::{Device, IntTensor},
-    BasicOps, Bool, Distribution, Element, ElementConversion, Float, Int, Shape, Tensor,
-    TensorKind,
+    BasicOps, Bool, Distribution, Element, ElementConversion, Float, Int, ScatterReduceOptions,
+    Shape, Tensor, TensorKind,
 };
 
 impl<B, const D: usize, K> Tensor<B, D, K>
@@ -895,6 +895,112 @@ where
         Self::new(K::mask_fill(self.primitive, mask.primitive, value.elem()))
     }
 
+    /// Update the elements of the tensor where the mask is true with the given values, taken in
+    /// row-major order.
+    ///
+    /// This is the equivalent of `tensor[mask] = values` in NumPy and PyTorch. Unlike
+    /// [mask_where](Tensor::mask_where), the values only contain the elements to assign, so their
+    /// number must match the number of true elements in the mask.
+    ///
+    /// # Panics
+    ///
+    /// If the mask doesn't have the same shape as the tensor, or if the number of values doesn't
+    /// match the number of true elements in the mask.
+    ///
+    /// # Notes
+    ///
+    /// The number of true elements in the mask is read from the device, which requires
+    /// synchronizing the backend.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, Bool};
+    ///
+    /// fn example<B: Backend>() {
+    ///   let device = B::Device::default();
+    ///   let tensor = Tensor::<B, 2>::from_data([[1.0, -2.0, 3.0], [5.0, 9.0, 6.0]], &device);
+    ///   let mask = Tensor::<B, 2, Bool>::from_data([[true, false, true], [false, true, false]], &device);
+    ///   let values = Tensor::<B, 1>::from_data([7.0, 8.0, 9.0], &device);
+    ///   let tensor = tensor.mask_assign(mask, values);
+    ///   println!("{tensor}");
+    ///   // [[7.0, -2.0, 8.0], [5.0, 9.0, 6.0]]
+    /// }
+    /// ```
+    pub fn mask_assign(self, mask: Tensor<B, D, Bool>, values: Tensor<B, 1, K>) -> Self {
+        let shape = self.shape();
+        check!(TensorCheck::mask_assign(&shape, &mask.shape()));
+
+        let num_elements = shape.num_elements();
+        let indices = mask.clone().reshape([num_elements]).argwhere();
+        let [num_masked, _] = indices.dims();
+        check!(TensorCheck::mask_assign_values(
+            num_masked,
+            values.dims()[0]
+        ));
+
+        if num_masked == 0 {
+            return self;
+        }
+
+        // The masked elements are set to zero first, since the values are added to the selected
+        // elements.
+        self.mask_fill(mask, 0)
+            .reshape([num_elements])
+            .select_assign(0, indices.reshape([num_masked]), values)
+            .reshape(shape)
+    }
+
+    /// Fill the elements at the given indices along the given dimension with the value.
+    ///
+    /// This is the equivalent of `tensor.index_fill_(dim, indices, value)` in PyTorch.
+    ///
+    /// # Panics
+    ///
+    /// If the dimension is higher than the tensor rank.
+    ///
+    /// # Warning
+    /// Not all backends have runtime bound checks for the indices, so make sure the they are valid.
+    /// Otherwise, out of bounds indices could lead to unexpected results instead of panicking.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, Int};
+    ///
+    /// fn example<B: Backend>() {
+    ///   let device = B::Device::default();
+    ///   let tensor = Tensor::<B, 2>::from_data([[1.0, -2.0, 3.0], [5.0, 9.0, 6.0]], &device);
+    ///   let indices = Tensor::<B, 1, Int>::from_data([0, 2], &device);
+    ///   let tensor = tensor.index_fill(1, indices, 0.0);
+    ///   println!("{tensor}");
+    ///   // [[0.0, -2.0, 0.0], [0.0, 9.0, 0.0]]
+    /// }
+    /// ```
+    pub fn index_fill<E: ElementConversion>(
+        self,
+        dim: usize,
+        indices: Tensor<B, 1, Int>,
+        value: E,
+    ) -> Self {
+        check!(TensorCheck::dim_ops::<D>("Index Fill", dim));
+
+        let shape = self.shape();
+        let size = shape.dims[dim];
+        let mut mask_dims = [1; D];
+        mask_dims[dim] = size;
+
+        let mask = Tensor::<B, 1, Int>::zeros([size], &self.device())
+            .select_assign(0, indices.clone(), indices.ones_like())
+            .greater_elem(0)
+            .reshape(mask_dims)
+            .expand(shape);
+
+        self.mask_fill(mask, value)
+    }
+
     /// Gather tensor elements corresponding to the given indices from the specified dim.
     ///
     /// Example using a 3D tensor:
@@ -956,6 +1062,58 @@ where
         ))
     }
 
+    /// Assign the gathered elements corresponding to the given indices along the specified dimension
+    /// from the value tensor to the original tensor, reducing the values assigned to the same element.
+    ///
+    /// Example using a 3D tensor with the max reduction:
+    ///
+    /// `input[indices[i, j, k], j, k] = max(input[indices[i, j, k], j, k], values[i, j, k]); // dim = 0`
+    /// `input[i, indices[i, j, k], k] = max(input[i, indices[i, j, k], k], values[i, j, k]); // dim = 1`
+    /// `input[i, j, indices[i, j, k]] = max(input[i, j, indices[i, j, k]], values[i, j, k]); // dim = 2`
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The axis along which to scatter the values.
+    /// * `indices` - The indices of the elements the values are assigned to.
+    /// * `values` - The values to scatter.
+    /// * `options` - The [reduction](crate::ScatterReduction) of the values, or the
+    ///   [options](ScatterReduceOptions) to exclude the original elements from the reduction or to
+    ///   request a deterministic result.
+    ///
+    /// # Notes
+    ///
+    /// The index tensor should have the same shape as the original tensor except for the specified
+    /// dimension. The value and index tensors should have the same shape.
+    ///
+    /// The elements that no value is assigned to are left unchanged. With the mean reduction, the
+    /// original element counts as one of the values unless it is excluded.
+    ///
+    /// # Warning
+    /// Not all backends have runtime bound checks for the indices, so make sure the they are valid.
+    /// Otherwise, out of bounds indices could lead to unexpected results instead of panicking.
+    pub fn scatter_reduce(
+        self,
+        dim: usize,
+        indices: Tensor<B, D, Int>,
+        values: Self,
+        options: impl Into<ScatterReduceOptions>,
+    ) -> Self {
+        check!(TensorCheck::scatter::<D>(
+            dim,
+            &self.shape(),
+            &indices.shape(),
+            &values.shape()
+        ));
+
+        Self::new(K::scatter_reduce(
+            dim,
+            self.primitive,
+            indices.primitive,
+            values.primitive,
+            options.into(),
+        ))
+    }
+
     /// Select the tensor elements along the given dimension corresponding to the given indices.
     ///
     /// Example using a 3D tensor:
@@ -1454,6 +1612,256 @@ where
         self.mask_fill(mask, 0)
     }
 
+    /// Returns the diagonal at `offset` of a matrix (2-D tensor) or batch of matrices.
+    ///
+    /// A positive `offset` selects a diagonal above the main diagonal, a negative one a diagonal
+    /// below it. The output has one dimension less than the input, the last one being the length
+    /// of the diagonal.
+    ///
+    /// # Example
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = Default::default();
+    ///    let tensor = Tensor::<B, 2>::from_floats(
+    ///        [
+    ///          [1.0, 2.0, 3.0],
+    ///          [4.0, 5.0, 6.0],
+    ///          [7.0, 8.0, 9.0]
+    ///        ],
+    ///        &device
+    ///    );
+    ///    let diagonal = tensor.diagonal::<1>(-1);
+    ///    println!("{diagonal}");
+    ///    // [4.0, 8.0]
+    /// }
+    /// ```
+    pub fn diagonal<const D2: usize>(self, offset: i64) -> Tensor<B, D2, K> {
+        check!(TensorCheck::diagonal::<D, D2>("Diagonal"));
+
+        let dims = self.dims();
+        let (first_row, first_col, length) = diagonal_range(dims[D - 2], dims[D - 1], offset);
+        let device = self.device();
+
+        let mut ranges = dims.map(|dim| 0..dim);
+        ranges[D - 2] = first_row..first_row + length;
+
+        let mut shape_indices = dims;
+        shape_indices[D - 2] = length;
+        shape_indices[D - 1] = 1;
+        let indices =
+            Tensor::<B, 1, Int>::arange(first_col as i64..(first_col + length) as i64, &device)
+                .reshape([length, 1])
+                .unsqueeze::<D>()
+                .expand(shape_indices);
+
+        let mut shape_output = [0; D2];
+        shape_output.copy_from_slice(&shape_indices[..D2]);
+
+        self.slice(ranges)
+            .gather(D - 1, indices)
+            .reshape(shape_output)
+    }
+
+    /// Replaces the diagonal at `offset` of a matrix (2-D tensor) or batch of matrices by the
+    /// given values, the other elements being kept.
+    ///
+    /// The values have one dimension less than the tensor, the last one being the length of the
+    /// diagonal, see [diagonal](Tensor::diagonal).
+    ///
+    /// # Example
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = Default::default();
+    ///    let tensor = Tensor::<B, 2>::zeros([3, 3], &device);
+    ///    let values = Tensor::<B, 1>::from_floats([1.0, 2.0], &device);
+    ///    let tensor = tensor.put_on_diagonal(values, 1);
+    ///    println!("{tensor}");
+    ///    // [
+    ///    //   [0.0, 1.0, 0.0],
+    ///    //   [0.0, 0.0, 2.0],
+    ///    //   [0.0, 0.0, 0.0]
+    ///    // ]
+    /// }
+    /// ```
+    pub fn put_on_diagonal<const D2: usize>(self, values: Tensor<B, D2, K>, offset: i64) -> Self {
+        check!(TensorCheck::diagonal::<D, D2>("Put on diagonal"));
+
+        let dims = self.dims();
+        let (first_row, first_col, length) = diagonal_range(dims[D - 2], dims[D - 1], offset);
+        let device = self.device();
+
+        let mut shape_values = dims;
+        shape_values[D - 2] = length;
+        shape_values[D - 1] = 1;
+        check!(TensorCheck::put_on_diagonal(
+            &values.shape(),
+            &Shape::from(shape_values[..D2].to_vec())
+        ));
+
+        let mut ranges = dims.map(|dim| 0..dim);
+        ranges[D - 2] = first_row..first_row + length;
+
+        // Within the rows of the diagonal, it starts at the first row.
+        let mask =
+            Tensor::<B, 2, Bool>::diag_mask([length, dims[D - 1]], first_col as i64, &device)
+                .bool_not()
+                .unsqueeze::<D>();
+        let indices =
+            Tensor::<B, 1, Int>::arange(first_col as i64..(first_col + length) as i64, &device)
+                .reshape([length, 1])
+                .unsqueeze::<D>()
+                .expand(shape_values);
+
+        let rows = self
+            .clone()
+            .slice(ranges.clone())
+            .mask_fill(mask, 0)
+            .scatter(D - 1, indices, values.reshape(shape_values));
+
+        self.slice_assign(ranges, rows)
+    }
+
+    /// Returns the band of a matrix (2-D tensor) or batch of matrices, with `lower` diagonals below
+    /// the main diagonal and `upper` diagonals above it, the other elements being set to 0.
+    ///
+    /// The band is kept in the dense format, see [bands](Tensor::bands) for a compact format.
+    pub fn band(self, lower: usize, upper: usize) -> Self {
+        self.triu(-(lower as i64)).tril(upper as i64)
+    }
+
+    /// Returns the diagonals of the band of a matrix (2-D tensor) or batch of matrices, with
+    /// `lower` diagonals below the main diagonal and `upper` diagonals above it.
+    ///
+    /// The matrices of shape `[..., rows, cols]` are stored in a compact format of shape
+    /// `[..., lower + upper + 1, rows]`, where the element `[..., lower + k, i]` is the element
+    /// `[..., i, i + k]` of the matrix, or 0 when outside of the matrix. Only the band is gathered,
+    /// so the zeros outside of it aren't materialized.
+    ///
+    /// # Example
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = Default::default();
+    ///    let tensor = Tensor::<B, 2>::from_floats(
+    ///        [
+    ///          [1.0, 2.0, 3.0],
+    ///          [4.0, 5.0, 6.0],
+    ///          [7.0, 8.0, 9.0]
+    ///        ],
+    ///        &device
+    ///    );
+    ///    let bands = tensor.bands(1, 1);
+    ///    println!("{bands}");
+    ///    // [
+    ///    //   [0.0, 4.0, 8.0],
+    ///    //   [1.0, 5.0, 9.0],
+    ///    //   [2.0, 6.0, 0.0]
+    ///    // ]
+    /// }
+    /// ```
+    pub fn bands(self, lower: usize, upper: usize) -> Self {
+        check!(TensorCheck::tri::<{ D }>());
+
+        let dims = self.dims();
+        let (indices, outside) = band_indices::<B, D>(&dims, lower, upper, &self.device());
+
+        self.gather(D - 1, indices)
+            .mask_fill(outside, 0)
+            .swap_dims(D - 2, D - 1)
+    }
+
+    /// Creates matrices of `cols` columns from the diagonals of their band, stored in the compact
+    /// format returned by [bands](Tensor::bands).
+    pub fn from_bands(bands: Self, lower: usize, upper: usize, cols: usize) -> Self {
+        check!(TensorCheck::tri::<{ D }>());
+        check!(TensorCheck::bands::<D>(
+            "From bands",
+            &bands.shape(),
+            lower,
+            upper
+        ));
+
+        let mut dims = bands.dims();
+        dims[D - 2] = dims[D - 1];
+        dims[D - 1] = cols;
+
+        let device = bands.device();
+        let (indices, outside) = band_indices::<B, D>(&dims, lower, upper, &device);
+        let values = bands.swap_dims(D - 2, D - 1).mask_fill(outside, 0);
+
+        Self::zeros(dims, &device).scatter(D - 1, indices, values)
+    }
+
+    /// Performs the matrix multiplication of banded matrices, stored in the compact format returned
+    /// by [bands](Tensor::bands), with the `rhs` matrices.
+    ///
+    /// Only the diagonals of the band are multiplied, which takes `lower + upper + 1` products per
+    /// element of the output instead of the number of columns of the banded matrices.
+    ///
+    /// # Shapes
+    ///
+    /// - self: `[..., lower + upper + 1, rows]`
+    /// - rhs: `[..., cols, n]`
+    /// - output: `[..., rows, n]`
+    pub fn banded_matmul(self, rhs: Self, lower: usize, upper: usize) -> Self {
+        check!(TensorCheck::tri::<{ D }>());
+        check!(TensorCheck::bands::<D>(
+            "Banded matmul",
+            &self.shape(),
+            lower,
+            upper
+        ));
+
+        let dims_rhs = rhs.dims();
+        let rows = self.dims()[D - 1];
+        let cols = dims_rhs[D - 2];
+        let device = rhs.device();
+
+        // The rows of the rhs are padded, so the shifted rows of each diagonal are in range.
+        let mut shape_top = dims_rhs;
+        shape_top[D - 2] = lower;
+        let mut shape_bottom = dims_rhs;
+        shape_bottom[D - 2] = (rows + upper).saturating_sub(cols);
+        let rhs = Tensor::cat(
+            alloc::vec![
+                Self::zeros(shape_top, &device),
+                rhs,
+                Self::zeros(shape_bottom, &device),
+            ],
+            D - 2,
+        );
+
+        let mut ranges_band = self.dims().map(|dim| 0..dim);
+        let mut ranges_rhs = rhs.dims().map(|dim| 0..dim);
+        let mut output: Option<Self> = None;
+
+        for band in 0..lower + upper + 1 {
+            ranges_band[D - 2] = band..band + 1;
+            ranges_rhs[D - 2] = band..band + rows;
+
+            let diagonal = self
+                .clone()
+                .slice(ranges_band.clone())
+                .swap_dims(D - 2, D - 1);
+            let product = diagonal.mul(rhs.clone().slice(ranges_rhs.clone()));
+
+            output = Some(match output {
+                Some(output) => output.add(product),
+                None => product,
+            });
+        }
+
+        output.unwrap()
+    }
+
     /// Applies element wise power operation with a float Tensor
     ///
     /// # Arguments
@@ -1563,6 +1971,8 @@ where
     /// and `atol` is the absolute tolerance.
     /// ```
     ///
+    /// Equal elements are always close, including infinities with the same sign.
+    ///
     /// # Arguments
     ///
     /// * `other` - The tensor to compare with.
@@ -1592,10 +2002,21 @@ where
         let rtol = rtol.unwrap_or(1e-5);
         let atol = atol.unwrap_or(1e-8);
 
-        Tensor::new(K::lower_equal(
+        // The difference of equal infinities is NaN, so equal elements are masked.
+        let equal = K::equal(self.primitive.clone(), other.primitive.clone());
+        let diff = K::mask_fill(
             K::abs(K::sub(self.primitive, other.primitive.clone())),
-            K::add_scalar(K::mul_scalar(K::abs(other.primitive), rtol), atol),
-        ))
+            equal,
+            0.elem(),
+        );
+
+        // The tolerance of an infinite element is infinite (`x - x` isn't zero), so it's zeroed
+        // for only the equal elements to be close to it.
+        let tolerance = K::add_scalar(K::mul_scalar(K::abs(other.primitive), rtol), atol);
+        let is_infinite = K::not_equal_elem(K::sub(tolerance.clone(), tolerance.clone()), 0.elem());
+        let tolerance = K::mask_fill(tolerance, is_infinite, 0.elem());
+
+        Tensor::new(K::lower_equal(diff, tolerance))
     }
 
     /// Checks if all elements are close to another tensor.
@@ -1623,6 +2044,10 @@ where
     ///
     /// # Remarks
     ///
+    /// This reads the result back from the device. To keep the check on the device, e.g. to
+    /// combine it with other device-side logic, use `self.is_close(other, rtol, atol).all()`,
+    /// which returns a one-element boolean tensor.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -1703,6 +2128,7 @@ where
         distribution: Distribution,
         device: &B::Device,
     ) -> Self {
+        check!(TensorCheck::distribution(&distribution));
         Self::new(K::random(shape.into(), distribution, device))
     }
 
@@ -2210,6 +2636,98 @@ where
         let zeros = K::zeros([size, size].into(), device);
         Self::new(K::scatter(0, zeros, indices.primitive, ones))
     }
+
+    /// Creates a Toeplitz matrix, constant along each diagonal, from its first column and its
+    /// first row.
+    ///
+    /// The element `[i, j]` is `column[i - j]` when `i >= j`, otherwise `row[j - i]`, so the first
+    /// element of the row is ignored. A lower triangular Toeplitz matrix, whose product with a
+    /// signal is its causal convolution with the column, is created with a row of zeros.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - The first column of the matrix, of size `rows`.
+    /// * `row` - The first row of the matrix, of size `cols`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = Default::default();
+    ///    let column = Tensor::<B, 1>::from_floats([1.0, 2.0, 3.0], &device);
+    ///    let row = Tensor::<B, 1>::from_floats([1.0, 4.0], &device);
+    ///    let tensor = Tensor::toeplitz(column, row);
+    ///    println!("{tensor}");
+    ///    // [
+    ///    //   [1.0, 4.0],
+    ///    //   [2.0, 1.0],
+    ///    //   [3.0, 2.0]
+    ///    // ]
+    /// }
+    /// ```
+    pub fn toeplitz(column: Tensor<B, 1, K>, row: Tensor<B, 1, K>) -> Self {
+        check!(TensorCheck::toeplitz(&column.shape(), &row.shape()));
+
+        let [rows] = column.dims();
+        let [cols] = row.dims();
+        let device = column.device();
+
+        // The values of the diagonals, from the top-right to the bottom-left corner.
+        let values = Tensor::cat(alloc::vec![row.slice([1..cols]).flip([0]), column], 0);
+
+        let row_index = Tensor::<B, 1, Int>::arange(0..rows as i64, &device).reshape([rows, 1]);
+        let col_index = Tensor::<B, 1, Int>::arange(0..cols as i64, &device).reshape([1, cols]);
+        let indices = row_index.sub(col_index).add_scalar(cols as i64 - 1);
+
+        values
+            .select(0, indices.reshape([rows * cols]))
+            .reshape([rows, cols])
+    }
+}
+
+/// The first row, the first column and the length of the diagonal at `offset` of a matrix.
+fn diagonal_range(rows: usize, cols: usize, offset: i64) -> (usize, usize, usize) {
+    let first_row = offset.min(0).unsigned_abs() as usize;
+    let first_col = offset.max(0) as usize;
+    let length = usize::min(
+        rows.saturating_sub(first_row),
+        cols.saturating_sub(first_col),
+    );
+
+    (first_row, first_col, length)
+}
+
+/// The column indices of the band of matrices of shape `dims`, clamped to the matrices, and the
+/// mask of the indices outside of the matrices.
+///
+/// Both have the shape `[..., rows, lower + upper + 1]`, the mask being broadcast on the batch
+/// dimensions.
+fn band_indices<B: Backend, const D: usize>(
+    dims: &[usize; D],
+    lower: usize,
+    upper: usize,
+    device: &B::Device,
+) -> (Tensor<B, D, Int>, Tensor<B, D, Bool>) {
+    let rows = dims[D - 2];
+    let cols = dims[D - 1];
+    let width = lower + upper + 1;
+
+    let row = Tensor::<B, 1, Int>::arange(0..rows as i64, device).reshape([rows, 1]);
+    let band =
+        Tensor::<B, 1, Int>::arange(-(lower as i64)..upper as i64 + 1, device).reshape([1, width]);
+    let indices = row.add(band);
+    let clamped = indices.clone().clamp(0, cols as i64 - 1);
+    let outside = clamped.clone().not_equal(indices);
+
+    let mut shape = *dims;
+    shape[D - 1] = width;
+
+    (
+        clamped.unsqueeze::<D>().expand(shape),
+        outside.unsqueeze::<D>(),
+    )
 }
 
 /// Trait that list all operations that can be applied on all numerical tensors.
@@ -3011,6 +3529,39 @@ where
         values: Self::Primitive,
     ) -> Self::Primitive;
 
+    /// Scatters elements into a tensor along an axis, reducing the elements scattered to the same
+    /// position.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The axis along which to scatter elements.
+    /// * `tensor` - The tensor to scatter elements into.
+    /// * `indices` - The indices of the elements to scatter.
+    /// * `values` - The values to scatter into the tensor.
+    /// * `options` - The reduction of the values.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where the elements at the specified
+    /// indices are the reduction of the corresponding elements of the values tensor.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For scattering elements into a tensor along an axis, users should prefer the
+    /// [Tensor::scatter_reduce](Tensor::scatter_reduce) function, which is more high-level and
+    /// designed for public use.
+    fn scatter_reduce(
+        dim: usize,
+        tensor: Self::Primitive,
+        indices: B::IntTensorPrimitive,
+        values: Self::Primitive,
+        options: ScatterReduceOptions,
+    ) -> Self::Primitive;
+
     /// Select tensor elements along the given dimension corresponding for the given indices.
     ///
     /// # Arguments
@@ -3608,6 +4159,16 @@ impl<B: Backend> Numeric<B> for Int {
         B::int_scatter(dim, tensor, indices, values)
     }
 
+    fn scatter_reduce(
+        dim: usize,
+        tensor: Self::Primitive,
+        indices: B::IntTensorPrimitive,
+        values: Self::Primitive,
+        options: ScatterReduceOptions,
+    ) -> Self::Primitive {
+        B::int_scatter_reduce(dim, tensor, indices, values, options)
+    }
+
     fn argmax(tensor: Self::Primitive, dim: usize) -> IntTensor<B> {
         B::int_argmax(tensor, dim)
     }
@@ -4020,6 +4581,23 @@ impl<B: Backend> Numeric<B> for Float {
         }
     }
 
+    fn scatter_reduce(
+        dim: usize,
+        tensor: Self::Primitive,
+        indices: B::IntTensorPrimitive,
+        values: Self::Primitive,
+        options: ScatterReduceOptions,
+    ) -> Self::Primitive {
+        // Quantized tensors are reduced in full precision.
+        TensorPrimitive::Float(B::float_scatter_reduce(
+            dim,
+            tensor.tensor(),
+            indices,
+            values.tensor(),
+            options,
+        ))
+    }
+
     fn argmax(tensor: Self::Primitive, dim: usize) -> IntTensor<B> {
         match tensor {
             TensorPrimitive::Float(tensor) => B::float_argmax(tensor, dim),
@@ -0,0 +1,202 @@
+use crate::{backend::Backend, ops::IntTensor, BasicOps, Element, TensorData, TensorKind};
+use alloc::{vec, vec::Vec};
+use burn_common::reader::try_read_sync;
+use core::ops::{Add, Div, Mul};
+
+/// The reduction applied to the values scattered to the same element, see
+/// [scatter_reduce](crate::Tensor::scatter_reduce).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScatterReduction {
+    /// The sum of the values.
+    Sum,
+    /// The product of the values.
+    Prod,
+    /// The mean of the values.
+    Mean,
+    /// The maximum of the values.
+    Max,
+    /// The minimum of the values.
+    Min,
+}
+
+/// Options of [scatter_reduce](crate::Tensor::scatter_reduce).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ScatterReduceOptions {
+    /// The reduction applied to the values scattered to the same element.
+    pub reduction: ScatterReduction,
+    /// If the elements of the input tensor are reduced with the values scattered to them,
+    /// otherwise they are replaced by the reduction of the values. `true` by default.
+    pub include_self: bool,
+    /// If the result must be the same for each run, at the cost of speed for backends reducing
+    /// the values in parallel with atomics, whose order changes the rounding of floats.
+    /// `false` by default.
+    pub deterministic: bool,
+}
+
+impl ScatterReduceOptions {
+    /// Creates the options of the reduction, including the elements of the input tensor.
+    pub fn new(reduction: ScatterReduction) -> Self {
+        Self {
+            reduction,
+            include_self: true,
+            deterministic: false,
+        }
+    }
+
+    /// Sets if the elements of the input tensor are reduced with the values scattered to them.
+    pub fn with_include_self(mut self, include_self: bool) -> Self {
+        self.include_self = include_self;
+        self
+    }
+
+    /// Sets if the result must be the same for each run.
+    pub fn with_deterministic(mut self, deterministic: bool) -> Self {
+        self.deterministic = deterministic;
+        self
+    }
+}
+
+impl From<ScatterReduction> for ScatterReduceOptions {
+    fn from(reduction: ScatterReduction) -> Self {
+        Self::new(reduction)
+    }
+}
+
+/// Scatter the values into the input `tensor` along a given dimension, reducing the values
+/// scattered to the same element.
+///
+/// The values are reduced sequentially on the host in the order of their positions, so the result
+/// is always deterministic.
+///
+/// # Arguments
+///
+/// * `dim` - The axis along which to scatter the values.
+/// * `tensor` - The input tensor.
+/// * `indices` - The indices along `dim` of the elements the values are scattered to.
+/// * `values` - The values to scatter, with the same shape as the indices.
+/// * `options` - The reduction of the values.
+///
+/// # Returns
+///
+/// A tensor with the same shape as the input tensor.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn scatter_reduce<B: Backend, K: TensorKind<B> + BasicOps<B>>(
+    dim: usize,
+    tensor: K::Primitive,
+    indices: IntTensor<B>,
+    values: K::Primitive,
+    options: ScatterReduceOptions,
+) -> K::Primitive
+where
+    <K as BasicOps<B>>::Elem: Element,
+{
+    let device = K::device(&tensor);
+    let message = "Failed to synchronously read tensor data. This operation is not supported until this backend has a GPU scatter reduce implementation.";
+    let tensor = try_read_sync(K::into_data_async(tensor)).expect(message);
+    let indices = try_read_sync(B::int_into_data(indices)).expect(message);
+    let values = try_read_sync(K::into_data_async(values)).expect(message);
+
+    let shape = tensor.shape.clone();
+    let index_shape = indices.shape.clone();
+    let indices = indices.iter::<i64>().collect::<Vec<_>>();
+
+    let output = match tensor.dtype.is_float() {
+        true => TensorData::new(
+            scatter_reduce_elements::<f64>(
+                tensor.iter().collect(),
+                &shape,
+                &indices,
+                &index_shape,
+                values.iter().collect(),
+                dim,
+                options,
+            ),
+            shape.clone(),
+        ),
+        false => TensorData::new(
+            scatter_reduce_elements::<i64>(
+                tensor.iter().collect(),
+                &shape,
+                &indices,
+                &index_shape,
+                values.iter().collect(),
+                dim,
+                options,
+            ),
+            shape.clone(),
+        ),
+    };
+
+    K::from_data(output.convert::<<K as BasicOps<B>>::Elem>(), &device)
+}
+
+fn scatter_reduce_elements<E>(
+    mut output: Vec<E>,
+    shape: &[usize],
+    indices: &[i64],
+    index_shape: &[usize],
+    values: Vec<E>,
+    dim: usize,
+    options: ScatterReduceOptions,
+) -> Vec<E>
+where
+    E: Copy + PartialOrd + Add<Output = E> + Mul<Output = E> + Div<Output = E> + From<u32>,
+{
+    let strides = compute_strides(shape);
+    let index_strides = compute_strides(index_shape);
+    let mut counts = vec![0u32; output.len()];
+
+    for (position, (index, value)) in indices.iter().zip(values).enumerate() {
+        let mut target = 0;
+        for d in 0..shape.len() {
+            let coordinate = match d == dim {
+                true => *index as usize,
+                false => position / index_strides[d] % index_shape[d],
+            };
+            target += coordinate * strides[d];
+        }
+
+        let current = output[target];
+        output[target] = match counts[target] == 0 && !options.include_self {
+            true => value,
+            false => match options.reduction {
+                ScatterReduction::Sum | ScatterReduction::Mean => current + value,
+                ScatterReduction::Prod => current * value,
+                ScatterReduction::Max if value > current => value,
+                ScatterReduction::Min if value < current => value,
+                ScatterReduction::Max | ScatterReduction::Min => current,
+            },
+        };
+        counts[target] += 1;
+    }
+
+    if options.reduction == ScatterReduction::Mean {
+        for (element, count) in output.iter_mut().zip(counts) {
+            let count = count + options.include_self as u32;
+
+            if count > 1 {
+                *element = *element / E::from(count);
+            }
+        }
+    }
+
+    output
+}
+
+fn compute_strides(dims: &[usize]) -> Vec<usize> {
+    let mut strides = vec![0; dims.len()];
+    let mut current = 1;
+
+    dims.iter().enumerate().rev().for_each(|(index, val)| {
+        strides[index] = current;
+        current *= val;
+    });
+
+    strides
+}
@@ -0,0 +1,55 @@
+use crate::{
+    backend::Backend,
+    ops::{FloatTensor, IntTensor},
+    Shape, TensorMetadata,
+};
+
+/// Find the indices where the `values` would be inserted in the `sorted_sequence` to keep it
+/// sorted.
+///
+/// Each value is compared to every element of the sequence, the index being the number of
+/// elements before the value, so it only requires comparison and sum operations.
+///
+/// # Arguments
+///
+/// * `sorted_sequence` - The sequence sorted in ascending order, of shape `[num_elements]`.
+/// * `values` - The values to insert, of any shape.
+/// * `right` - If the index is after the elements equal to the value, instead of before them.
+///
+/// # Returns
+///
+/// The indices, of the same shape as the `values`.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn searchsorted<B: Backend>(
+    sorted_sequence: FloatTensor<B>,
+    values: FloatTensor<B>,
+    right: bool,
+) -> IntTensor<B> {
+    let shape = values.shape();
+    let num_values = shape.num_elements();
+    let num_elements = sorted_sequence.shape().num_elements();
+    let shape_all = Shape::new([num_values, num_elements]);
+
+    let values = B::float_expand(
+        B::float_reshape(values, Shape::new([num_values, 1])),
+        shape_all.clone(),
+    );
+    let sorted_sequence = B::float_expand(
+        B::float_reshape(sorted_sequence, Shape::new([1, num_elements])),
+        shape_all,
+    );
+
+    let before = match right {
+        true => B::float_lower_equal(sorted_sequence, values),
+        false => B::float_lower(sorted_sequence, values),
+    };
+    let indices = B::int_sum_dim(B::bool_into_int(before), 1);
+
+    B::int_reshape(indices, shape)
+}
@@ -0,0 +1,129 @@
+use crate::{
+    backend::Backend,
+    ops::{FloatTensor, IntTensor},
+    ElementConversion, Shape, TensorMetadata,
+};
+
+/// The reduction applied to the elements of each segment, see
+/// [segment_sum](crate::Tensor::segment_sum).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SegmentReduction {
+    /// The sum of the elements.
+    Sum,
+    /// The mean of the elements.
+    Mean,
+    /// The maximum of the elements.
+    Max,
+}
+
+/// Reduce the slices of the input `tensor` along the first dimension sharing the same segment id.
+///
+/// The reduction is computed with a scatter for the sum and the mean, and with a mask of the
+/// segments for the maximum, so the ids don't have to be sorted.
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor, of shape `[num_elements, ...]`.
+/// * `ids` - The segment id of each slice, of shape `[num_elements]`.
+/// * `num_segments` - The number of segments.
+/// * `reduction` - The reduction applied to the slices of each segment.
+///
+/// # Returns
+///
+/// A tensor of shape `[num_segments, ...]`, where empty segments are zero.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn segment_reduce<B: Backend>(
+    tensor: FloatTensor<B>,
+    ids: IntTensor<B>,
+    num_segments: usize,
+    reduction: SegmentReduction,
+) -> FloatTensor<B> {
+    let device = B::float_device(&tensor);
+    let mut shape = tensor.shape();
+    let num_elements = shape.dims[0];
+    let num_cols = shape.num_elements() / num_elements.max(1);
+    let tensor = B::float_reshape(tensor, Shape::new([num_elements, num_cols]));
+
+    let counts = B::float_select_assign(
+        B::float_zeros(Shape::new([num_segments, 1]), &device),
+        0,
+        ids.clone(),
+        B::float_ones(Shape::new([num_elements, 1]), &device),
+    );
+
+    let output = match reduction {
+        SegmentReduction::Sum => segment_sum::<B>(tensor, ids, num_segments),
+        SegmentReduction::Mean => B::float_div(
+            segment_sum::<B>(tensor, ids, num_segments),
+            B::float_clamp_min(counts, 1.0f32.elem()),
+        ),
+        SegmentReduction::Max => {
+            let output = segment_max::<B>(tensor, ids, num_segments);
+            let empty = B::float_equal_elem(counts, 0.0f32.elem());
+            let empty = B::bool_expand(empty, Shape::new([num_segments, num_cols]));
+
+            B::float_mask_fill(output, empty, 0.0f32.elem())
+        }
+    };
+
+    shape.dims[0] = num_segments;
+    B::float_reshape(output, shape)
+}
+
+fn segment_sum<B: Backend>(
+    tensor: FloatTensor<B>,
+    ids: IntTensor<B>,
+    num_segments: usize,
+) -> FloatTensor<B> {
+    let [_, num_cols] = tensor.shape().dims();
+    let output = B::float_zeros(
+        Shape::new([num_segments, num_cols]),
+        &B::float_device(&tensor),
+    );
+
+    B::float_select_assign(output, 0, ids, tensor)
+}
+
+/// Computes the maximum of each segment over a `[num_elements, num_segments, num_cols]` view of
+/// the input, where the elements of the other segments are masked.
+fn segment_max<B: Backend>(
+    tensor: FloatTensor<B>,
+    ids: IntTensor<B>,
+    num_segments: usize,
+) -> FloatTensor<B> {
+    let device = B::float_device(&tensor);
+    let [num_elements, num_cols] = tensor.shape().dims();
+    let shape = Shape::new([num_elements, num_segments, num_cols]);
+
+    let ids = B::int_expand(
+        B::int_reshape(ids, Shape::new([num_elements, 1])),
+        Shape::new([num_elements, num_segments]),
+    );
+    let segments = B::int_expand(
+        B::int_reshape(
+            B::int_arange(0..num_segments as i64, &device),
+            Shape::new([1, num_segments]),
+        ),
+        Shape::new([num_elements, num_segments]),
+    );
+    let other_segments = B::bool_not(B::int_equal(ids, segments));
+    let other_segments = B::bool_expand(
+        B::bool_reshape(other_segments, Shape::new([num_elements, num_segments, 1])),
+        shape.clone(),
+    );
+
+    let tensor = B::float_expand(
+        B::float_reshape(tensor, Shape::new([num_elements, 1, num_cols])),
+        shape,
+    );
+    let tensor = B::float_mask_fill(tensor, other_segments, f32::NEG_INFINITY.elem());
+    let output = B::float_max_dim(tensor, 0);
+
+    B::float_reshape(output, Shape::new([num_segments, num_cols]))
+}
@@ -0,0 +1,84 @@
+use core::fmt::Display;
+
+use crate::backend::Backend;
+use crate::{DType, Shape, Tensor, TensorData, Transaction};
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// Statistics of the values of a float tensor, see [Tensor::summary].
+#[derive(Debug, Clone, PartialEq)]
+pub struct TensorSummary {
+    /// The shape of the tensor.
+    pub shape: Shape,
+    /// The data type of the tensor.
+    pub dtype: DType,
+    /// The minimum value.
+    pub min: f64,
+    /// The maximum value.
+    pub max: f64,
+    /// The mean of the values.
+    pub mean: f64,
+    /// The standard deviation of the values, without the Bessel's correction.
+    pub std: f64,
+}
+
+impl Display for TensorSummary {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let precision = f.precision().unwrap_or(4);
+
+        write!(
+            f,
+            "shape: {:?}, dtype: {}, min: {:.p$}, max: {:.p$}, mean: {:.p$}, std: {:.p$}",
+            self.shape.dims,
+            self.dtype.name(),
+            self.min,
+            self.max,
+            self.mean,
+            self.std,
+            p = precision,
+        )
+    }
+}
+
+impl<B: Backend, const D: usize> Tensor<B, D> {
+    /// Summarize the values of the tensor with their minimum, maximum, mean and standard
+    /// deviation, which is more readable than the values themselves for large tensors.
+    ///
+    /// The statistics are computed on the device and read in a single transfer.
+    ///
+    /// # Panics
+    ///
+    /// If the tensor is empty.
+    pub fn summary(&self) -> TensorSummary {
+        let shape = self.shape();
+        let dtype = self.dtype();
+        assert!(
+            shape.num_elements() > 0,
+            "Can't summarize an empty tensor of shape {:?}",
+            shape.dims
+        );
+
+        let values = self.clone().reshape([shape.num_elements()]);
+        let (var, mean) = values.clone().var_mean_bias(0);
+
+        let [min, max, mean, var] = Transaction::default()
+            .register(values.clone().min())
+            .register(values.max())
+            .register(mean)
+            .register(var)
+            .execute()
+            .try_into()
+            .expect("Correct amount of tensor data");
+        let scalar = |data: TensorData| data.iter::<f64>().next().unwrap();
+
+        TensorSummary {
+            shape,
+            dtype,
+            min: scalar(min),
+            max: scalar(max),
+            mean: scalar(mean),
+            std: scalar(var).sqrt(),
+        }
+    }
+}
@@ -50,7 +50,10 @@ impl<B: Backend> Transaction<B> {
 
     /// Executes the transaction asynchronously and returns the [data](TensorData) in the same order
     /// in which they were [registered](Self::register).
-    pub fn execute_async(self) -> impl Future<Output = Vec<TensorData>> {
+    ///
+    /// The returned future doesn't borrow the tensors, so it can be awaited on another thread
+    /// without blocking the current one.
+    pub fn execute_async(self) -> impl Future<Output = Vec<TensorData>> + 'static + Send {
         let fut = B::tr_execute(self.op);
 
         async move {
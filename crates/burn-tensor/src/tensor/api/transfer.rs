@@ -0,0 +1,81 @@
+use super::{BasicOps, Tensor, TensorKind};
+use crate::{backend::Backend, Float};
+
+/// A [tensor](Tensor) being transferred to a device, created with
+/// [to_device_async](Tensor::to_device_async).
+///
+/// The transfer is started with the non-blocking copy of the backend, so the data can be
+/// uploaded while other computations are executed, for instance to prefetch the next batch of a
+/// training loop (double buffering). Backends without non-blocking copies fall back to
+/// [to_device](Tensor::to_device), in which case the transfer may already be completed when it is
+/// returned.
+///
+/// The [tensor](Self::tensor) can be used right away: the operations using it are executed on the
+/// device after the transfer. The completion can be awaited explicitly with
+/// [completed](Self::completed), or with [wait](Self::wait) when blocking is acceptable.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let mut next = batches.next().map(|batch| batch.to_device_async(&device));
+///
+/// while let Some(transfer) = next.take() {
+///     // Queue the upload of the following batch while computing on the current one.
+///     next = batches.next().map(|batch| batch.to_device_async(&device));
+///     let output = model.forward(transfer.tensor());
+/// }
+/// ```
+pub struct DeviceTransfer<B: Backend, const D: usize, K: TensorKind<B> = Float> {
+    tensor: Tensor<B, D, K>,
+}
+
+impl<B, const D: usize, K> DeviceTransfer<B, D, K>
+where
+    B: Backend,
+    K: BasicOps<B>,
+{
+    pub(crate) fn new(tensor: Tensor<B, D, K>) -> Self {
+        Self { tensor }
+    }
+
+    /// Returns the transferred tensor, which can be used before the transfer is completed.
+    pub fn tensor(&self) -> Tensor<B, D, K> {
+        self.tensor.clone()
+    }
+
+    /// Returns the device the tensor is transferred to.
+    pub fn device(&self) -> B::Device {
+        self.tensor.device()
+    }
+
+    /// Returns the transferred tensor, without waiting for the transfer to be completed.
+    pub fn into_tensor(self) -> Tensor<B, D, K> {
+        self.tensor
+    }
+
+    /// Blocks until all the operations queued on the device, including the transfer, are
+    /// completed, and returns the transferred tensor.
+    pub fn wait(self) -> Tensor<B, D, K> {
+        B::sync(&self.tensor.device());
+        self.tensor
+    }
+
+    /// Completes once the tensor is available on the device, and returns the transferred tensor.
+    ///
+    /// Only the transferred tensor is awaited, not the other operations queued on the device.
+    pub async fn completed(self) -> Tensor<B, D, K> {
+        let num_elements = self.tensor.shape().num_elements();
+
+        if num_elements > 0 {
+            // Reading a single element requires the transfer to be completed.
+            self.tensor
+                .clone()
+                .reshape([num_elements])
+                .slice([0..1])
+                .into_data_async()
+                .await;
+        }
+
+        self.tensor
+    }
+}
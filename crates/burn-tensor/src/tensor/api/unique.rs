@@ -0,0 +1,127 @@
+use crate::{backend::Backend, ops::IntTensor, TensorData};
+use alloc::{vec, vec::Vec};
+use burn_common::reader::try_read_sync;
+
+/// Count the number of occurrences of each value of the input `tensor`.
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor of shape `[num_elements]`, with non-negative values.
+/// * `min_length` - The minimum number of bins.
+///
+/// # Returns
+///
+/// A tensor of shape `[max(max_value + 1, min_length)]` with the count of each value.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn bincount<B: Backend>(tensor: IntTensor<B>, min_length: usize) -> IntTensor<B> {
+    let device = B::int_device(&tensor);
+    let values = read_values::<B>(tensor);
+
+    let num_bins = values
+        .iter()
+        .map(|value| {
+            assert!(*value >= 0, "Can't count negative values, got {value}");
+            *value as usize + 1
+        })
+        .max()
+        .unwrap_or(0)
+        .max(min_length);
+
+    let mut counts = vec![0i64; num_bins];
+    for value in values {
+        counts[value as usize] += 1;
+    }
+
+    B::int_from_data(
+        TensorData::new(counts, [num_bins]).convert::<B::IntElem>(),
+        &device,
+    )
+}
+
+/// Find the unique values of the input `tensor`, along with the inverse indices and the counts.
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor of shape `[num_elements]`.
+/// * `consecutive` - If only the consecutive repeated values are merged, in which case the
+///   values are kept in their order. Otherwise, the unique values are sorted.
+///
+/// # Returns
+///
+/// The unique values, the index of the unique value of each element of the input tensor, and the
+/// number of elements of each unique value.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn unique<B: Backend>(
+    tensor: IntTensor<B>,
+    consecutive: bool,
+) -> (IntTensor<B>, IntTensor<B>, IntTensor<B>) {
+    let device = B::int_device(&tensor);
+    let values = read_values::<B>(tensor);
+
+    let (unique, inverse, counts) = match consecutive {
+        true => unique_consecutive_values(&values),
+        false => unique_sorted_values(&values),
+    };
+
+    let from_vec = |values: Vec<i64>| {
+        let shape = [values.len()];
+        B::int_from_data(
+            TensorData::new(values, shape).convert::<B::IntElem>(),
+            &device,
+        )
+    };
+
+    (from_vec(unique), from_vec(inverse), from_vec(counts))
+}
+
+fn unique_consecutive_values(values: &[i64]) -> (Vec<i64>, Vec<i64>, Vec<i64>) {
+    let mut unique: Vec<i64> = Vec::new();
+    let mut counts: Vec<i64> = Vec::new();
+    let mut inverse = Vec::with_capacity(values.len());
+
+    for value in values {
+        match unique.last() {
+            Some(last) if last == value => *counts.last_mut().unwrap() += 1,
+            _ => {
+                unique.push(*value);
+                counts.push(1);
+            }
+        }
+
+        inverse.push(unique.len() as i64 - 1);
+    }
+
+    (unique, inverse, counts)
+}
+
+fn unique_sorted_values(values: &[i64]) -> (Vec<i64>, Vec<i64>, Vec<i64>) {
+    let mut sorted = values.to_vec();
+    sorted.sort_unstable();
+
+    let (unique, _, counts) = unique_consecutive_values(&sorted);
+    let inverse = values
+        .iter()
+        .map(|value| unique.binary_search(value).unwrap() as i64)
+        .collect();
+
+    (unique, inverse, counts)
+}
+
+fn read_values<B: Backend>(tensor: IntTensor<B>) -> Vec<i64> {
+    try_read_sync(B::int_into_data(tensor))
+        .expect("Failed to synchronously read tensor data. This operation is not supported until this backend has a GPU implementation.")
+        .iter::<i64>()
+        .collect()
+}
@@ -4,7 +4,7 @@ use crate::tensor::Element;
 use crate::TensorMetadata;
 use crate::{ops::*, quantization::QTensorPrimitive};
 
-use super::DeviceOps;
+use super::{DeviceOps, MemoryStats, RngState};
 
 /// This trait defines all types and functions needed for a backend to be used with burn.
 ///
@@ -102,8 +102,60 @@ pub trait Backend:
     /// Seed the backend.
     fn seed(seed: u64);
 
+    /// The current state of the random number generator of the backend, which can be saved with a
+    /// checkpoint and [restored](Backend::set_rng_state) to reproduce the following random
+    /// operations, like dropout and random initialization.
+    ///
+    /// The backend is seeded from entropy when it wasn't seeded before. Returns `None` when the
+    /// backend doesn't expose the state of its random number generator.
+    fn rng_state() -> Option<RngState> {
+        None
+    }
+
+    /// Restore the state of the random number generator of the backend, returned by
+    /// [rng_state](Backend::rng_state).
+    fn set_rng_state(_state: RngState) {
+        panic!(
+            "The backend {} doesn't support restoring the state of its random number generator",
+            Self::name()
+        )
+    }
+
+    /// Enable or disable the deterministic mode.
+    ///
+    /// In deterministic mode, the operations give the same results for the same inputs across
+    /// runs: random operations panic when the backend wasn't [seeded](Backend::seed), and the
+    /// operations without a deterministic implementation on the backend panic instead of
+    /// silently returning results that can't be reproduced.
+    ///
+    /// The mode is shared by all backends.
+    fn set_deterministic(deterministic: bool) {
+        super::set_deterministic(deterministic)
+    }
+
+    /// If the deterministic mode is [enabled](Backend::set_deterministic).
+    fn is_deterministic() -> bool {
+        super::is_deterministic()
+    }
+
     /// Sync the backend, ensure that all computation are finished.
     fn sync(_device: &Self::Device) {}
+
+    /// The memory used by the backend on the device, to diagnose out of memory errors.
+    ///
+    /// Returns `None` when the backend doesn't manage the memory of the device.
+    fn memory_stats(_device: &Self::Device) -> Option<MemoryStats> {
+        None
+    }
+
+    /// Set the number of bytes the memory pools of the device should stay under, or remove the
+    /// budget with `None`.
+    ///
+    /// When an allocation would exceed the budget, the chunks of the pools that aren't used by
+    /// any tensor are deallocated first. The budget isn't a hard limit: the allocation still
+    /// happens when the tensors in use exceed it. Backends that don't manage the memory of the
+    /// device ignore the budget.
+    fn set_memory_budget(_device: &Self::Device, _budget: Option<u64>) {}
 }
 
 /// Trait that allows a backend to support autodiff.
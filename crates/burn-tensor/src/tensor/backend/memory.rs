@@ -0,0 +1,63 @@
+/// The memory used by a backend on a device, see [memory_stats](super::Backend::memory_stats).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MemoryStats {
+    /// The bytes used by the tensors.
+    pub bytes_in_use: u64,
+    /// The bytes reserved by the memory pools of the device, including the bytes in use.
+    pub bytes_reserved: u64,
+    /// The highest number of bytes in use observed on the device.
+    pub peak_bytes_in_use: u64,
+}
+
+impl core::fmt::Display for MemoryStats {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{} in use, {} reserved, {} peak in use",
+            Bytes(self.bytes_in_use),
+            Bytes(self.bytes_reserved),
+            Bytes(self.peak_bytes_in_use)
+        )
+    }
+}
+
+struct Bytes(u64);
+
+impl core::fmt::Display for Bytes {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+        if self.0 < 1024 {
+            return write!(f, "{} B", self.0);
+        }
+
+        let mut value = self.0 as f64 / 1024.0;
+        let mut unit = 0;
+        while value >= 1024.0 && unit < UNITS.len() - 1 {
+            value /= 1024.0;
+            unit += 1;
+        }
+
+        write!(f, "{value:.2} {}", UNITS[unit])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn should_display_memory_stats() {
+        let stats = MemoryStats {
+            bytes_in_use: 512,
+            bytes_reserved: 3 * 1024 * 1024,
+            peak_bytes_in_use: 1536,
+        };
+
+        assert_eq!(
+            stats.to_string(),
+            "512 B in use, 3.00 MiB reserved, 1.50 KiB peak in use"
+        );
+    }
+}
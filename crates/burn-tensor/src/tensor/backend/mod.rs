@@ -1,8 +1,14 @@
 mod base;
 mod device;
+mod memory;
+mod rng;
+mod stream;
 
 pub use base::*;
 pub use device::*;
+pub use memory::*;
+pub use rng::*;
+pub use stream::*;
 
 // Not needed for now, useful for different tensor memory layout
 // pub mod conversion;
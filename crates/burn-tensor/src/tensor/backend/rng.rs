@@ -0,0 +1,105 @@
+use burn_common::rand::get_seeded_rng;
+use core::sync::atomic::{AtomicBool, Ordering};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+static DETERMINISTIC: AtomicBool = AtomicBool::new(false);
+
+/// The state of the random number generator of a backend.
+///
+/// Each random operation draws its numbers from a generator derived from the seed and the number
+/// of random operations executed since the backend was seeded, so the state can be saved with a
+/// checkpoint and [restored](crate::backend::Backend::set_rng_state) to resume a training with the
+/// same random numbers, for instance the same dropout masks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct RngState {
+    /// The seed of the generator.
+    pub seed: u64,
+    /// The number of random operations executed since the generator was seeded.
+    pub offset: u64,
+}
+
+impl RngState {
+    /// Create the state of a generator seeded with the given seed.
+    pub fn new(seed: u64) -> Self {
+        Self { seed, offset: 0 }
+    }
+
+    /// Create the state of a generator seeded from entropy, used when the backend wasn't seeded.
+    ///
+    /// # Panics
+    ///
+    /// In [deterministic mode](crate::backend::Backend::set_deterministic), since the random
+    /// numbers wouldn't be reproducible.
+    pub fn from_entropy() -> Self {
+        if is_deterministic() {
+            panic!("The backend must be seeded before executing random operations in deterministic mode");
+        }
+
+        Self::new(get_seeded_rng().gen())
+    }
+
+    /// The generator of the next random operation, advancing the state.
+    pub fn next_rng(&mut self) -> StdRng {
+        let mut seed = [0; 32];
+        seed[0..8].copy_from_slice(&self.seed.to_le_bytes());
+        seed[8..16].copy_from_slice(&self.offset.to_le_bytes());
+        self.offset += 1;
+
+        StdRng::from_seed(seed)
+    }
+}
+
+/// Enable or disable the deterministic mode of all backends, see
+/// [set_deterministic](crate::backend::Backend::set_deterministic).
+pub fn set_deterministic(deterministic: bool) {
+    DETERMINISTIC.store(deterministic, Ordering::Relaxed);
+}
+
+/// If the deterministic mode is enabled.
+pub fn is_deterministic() -> bool {
+    DETERMINISTIC.load(Ordering::Relaxed)
+}
+
+/// Make sure an operation whose result isn't reproducible, for instance because it accumulates
+/// floating point values with atomics in an arbitrary order, isn't executed in deterministic mode.
+///
+/// # Panics
+///
+/// In [deterministic mode](crate::backend::Backend::set_deterministic).
+pub fn check_deterministic(operation: &str) {
+    if is_deterministic() {
+        panic!("The operation '{operation}' doesn't have a deterministic implementation on this backend, it can't be executed in deterministic mode");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn restored_state_should_draw_the_same_numbers() {
+        let mut state = RngState::new(42);
+        state.next_rng();
+        let saved = state;
+
+        let expected: u64 = state.next_rng().gen();
+        let mut restored = saved;
+
+        assert_eq!(restored.next_rng().gen::<u64>(), expected);
+        assert_eq!(
+            restored,
+            RngState {
+                seed: 42,
+                offset: 2
+            }
+        );
+    }
+
+    #[test]
+    fn each_operation_should_draw_different_numbers() {
+        let mut state = RngState::new(42);
+
+        assert_ne!(state.next_rng().gen::<u64>(), state.next_rng().gen::<u64>());
+    }
+}
@@ -0,0 +1,28 @@
+use burn_common::id::StreamId;
+
+/// Execute the function with its operations registered on a separate queue.
+///
+/// The operations of an independent branch of a model can be registered on their own queue, so
+/// that they are optimized together instead of being interleaved with the operations of the main
+/// path. A queue is flushed when one of its tensors is read or used by the operations of another
+/// queue.
+///
+/// # Notes
+///
+/// Only the backends with a queue of operations per stream, like the fusion backend, partition
+/// their queues, the operations are still executed one after the other on the device. Other
+/// backends ignore the scope.
+///
+/// Synchronizing the [backend](crate::backend::Backend::sync) flushes the queues of every scope,
+/// including the scopes that ended without their tensors being used.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// // The branch is optimized separately from the main path, until both are added.
+/// let branch = stream(|| self.branch.forward(x.clone()));
+/// let output = self.main.forward(x) + branch;
+/// ```
+pub fn stream<F: FnOnce() -> O, O>(func: F) -> O {
+    StreamId::scope(func)
+}
@@ -3,17 +3,18 @@ use core::f32;
 use alloc::boxed::Box;
 use alloc::format;
 use alloc::string::String;
+use alloc::vec;
 use alloc::vec::Vec;
 use bytemuck::{checked::CheckedCastError, AnyBitPattern};
 use half::{bf16, f16};
 
 use crate::{
-    quantization::{
-        Quantization, QuantizationScheme, QuantizationStrategy, QuantizationType, QuantizedBytes,
-    },
+    quantization::{QuantizationStrategy, QuantizationType, QuantizedBytes},
     tensor::bytes::Bytes,
     DType, Distribution, Element, ElementConversion,
 };
+#[cfg(feature = "fp8")]
+use crate::{F8E4M3, F8E5M2};
 
 use num_traits::pow::Pow;
 
@@ -223,28 +224,37 @@ impl TensorData {
                         .iter()
                         .map(|e: &f64| e.elem::<E>()),
                 ),
+                #[cfg(feature = "fp8")]
+                DType::F8E4M3 => Box::new(
+                    bytemuck::checked::cast_slice(&self.bytes)
+                        .iter()
+                        .map(|e: &F8E4M3| e.elem::<E>()),
+                ),
+                #[cfg(feature = "fp8")]
+                DType::F8E5M2 => Box::new(
+                    bytemuck::checked::cast_slice(&self.bytes)
+                        .iter()
+                        .map(|e: &F8E5M2| e.elem::<E>()),
+                ),
                 // bool is a byte value equal to either 0 or 1
                 DType::Bool => Box::new(self.bytes.iter().map(|e| e.elem::<E>())),
-                DType::QFloat(scheme) => match scheme {
-                    QuantizationScheme::PerTensorAffine(QuantizationType::QInt8)
-                    | QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt8) => {
-                        // Quantized int8 values
-                        let q_bytes = QuantizedBytes {
-                            bytes: self.bytes.clone(),
-                            scheme,
-                            num_elements: self.num_elements(),
-                        };
-                        let (values, _) = q_bytes.into_vec_i8();
-
-                        Box::new(
-                            values
-                                .iter()
-                                .map(|e: &i8| e.elem::<E>())
-                                .collect::<Vec<_>>()
-                                .into_iter(),
-                        )
-                    }
-                },
+                DType::QFloat(scheme) => {
+                    // Quantized values, with the sub-byte values unpacked to i8
+                    let q_bytes = QuantizedBytes {
+                        bytes: self.bytes.clone(),
+                        scheme,
+                        num_elements: self.num_elements(),
+                    };
+                    let (values, _) = q_bytes.into_vec_i8();
+
+                    Box::new(
+                        values
+                            .iter()
+                            .map(|e: &i8| e.elem::<E>())
+                            .collect::<Vec<_>>()
+                            .into_iter(),
+                    )
+                }
             }
         }
     }
@@ -258,6 +268,102 @@ impl TensorData {
         shape.iter().product()
     }
 
+    /// Returns the elements within the given ranges, one per dimension starting from the first,
+    /// the remaining dimensions being fully selected.
+    ///
+    /// Only the selected values are copied, the fully selected trailing dimensions being copied
+    /// at once, so a few rows of large data can be inspected cheaply. To avoid reading the whole
+    /// tensor in the first place, prefer [Tensor::into_data_slice](crate::Tensor::into_data_slice).
+    ///
+    /// # Panics
+    ///
+    /// If the data is quantized or a range is out of bounds.
+    pub fn slice(&self, ranges: &[core::ops::Range<usize>]) -> TensorData {
+        if let DType::QFloat(_) = self.dtype {
+            panic!("Slicing quantized data isn't supported, dequantize it first");
+        }
+
+        let rank = self.shape.len();
+        assert!(
+            ranges.len() <= rank,
+            "Got {} ranges for data of rank {rank}",
+            ranges.len()
+        );
+        let ranges = (0..rank)
+            .map(|dim| ranges.get(dim).cloned().unwrap_or(0..self.shape[dim]))
+            .collect::<Vec<_>>();
+        for (dim, range) in ranges.iter().enumerate() {
+            assert!(
+                range.start <= range.end && range.end <= self.shape[dim],
+                "Range {range:?} out of bounds for dim {dim} of size {}",
+                self.shape[dim]
+            );
+        }
+
+        let shape = ranges
+            .iter()
+            .map(|range| range.end - range.start)
+            .collect::<Vec<_>>();
+        let num_elements = Self::numel(&shape);
+        let elem_size = self.dtype.size();
+        let mut bytes = Vec::with_capacity(num_elements * elem_size);
+
+        if num_elements == 0 {
+            return Self::from_bytes(bytes, shape, self.dtype);
+        }
+
+        let mut strides = vec![1; rank];
+        for dim in (0..rank.saturating_sub(1)).rev() {
+            strides[dim] = strides[dim + 1] * self.shape[dim + 1];
+        }
+
+        // The fully selected trailing dims and the last partially selected one are contiguous,
+        // so they are copied as a single run for each index of the outer dims.
+        let mut outer = rank;
+        while outer > 0 && ranges[outer - 1] == (0..self.shape[outer - 1]) {
+            outer -= 1;
+        }
+        let (run_start, run_len) = match outer {
+            0 => (0, num_elements),
+            _ => {
+                outer -= 1;
+                (
+                    ranges[outer].start * strides[outer],
+                    shape[outer..].iter().product::<usize>(),
+                )
+            }
+        };
+
+        let mut index = ranges[..outer]
+            .iter()
+            .map(|range| range.start)
+            .collect::<Vec<_>>();
+        loop {
+            let offset = run_start
+                + index
+                    .iter()
+                    .zip(strides.iter())
+                    .map(|(i, stride)| i * stride)
+                    .sum::<usize>();
+            let start = offset * elem_size;
+            bytes.extend_from_slice(&self.bytes[start..start + run_len * elem_size]);
+
+            // Increment the index of the outer dims, starting with the last one
+            let mut dim = outer;
+            loop {
+                if dim == 0 {
+                    return Self::from_bytes(bytes, shape, self.dtype);
+                }
+                dim -= 1;
+                index[dim] += 1;
+                if index[dim] < ranges[dim].end {
+                    break;
+                }
+                index[dim] = ranges[dim].start;
+            }
+        }
+    }
+
     /// Populates the data with random values.
     pub fn random<E: Element, R: RngCore, S: Into<Vec<usize>>>(
         shape: S,
@@ -325,6 +431,10 @@ impl TensorData {
                 DType::F32 => self.convert_inplace::<f32, E>(),
                 DType::F16 => self.convert_inplace::<f16, E>(),
                 DType::BF16 => self.convert_inplace::<bf16, E>(),
+                #[cfg(feature = "fp8")]
+                DType::F8E4M3 => self.convert_inplace::<F8E4M3, E>(),
+                #[cfg(feature = "fp8")]
+                DType::F8E5M2 => self.convert_inplace::<F8E5M2, E>(),
                 DType::I64 => self.convert_inplace::<i64, E>(),
                 DType::I32 => self.convert_inplace::<i32, E>(),
                 DType::I16 => self.convert_inplace::<i16, E>(),
@@ -380,18 +490,11 @@ impl TensorData {
             DType::F32,
             "Only f32 data type can be quantized"
         );
-        match &quantization {
-            QuantizationStrategy::PerTensorAffineInt8(strategy) => TensorData::quantized(
-                strategy.quantize(self.as_slice().unwrap()),
-                self.shape,
-                quantization,
-            ),
-            QuantizationStrategy::PerTensorSymmetricInt8(strategy) => TensorData::quantized(
-                strategy.quantize(self.as_slice().unwrap()),
-                self.shape,
-                quantization,
-            ),
-        }
+        TensorData::quantized(
+            quantization.quantize(self.as_slice().unwrap()),
+            self.shape,
+            quantization,
+        )
     }
 
     /// Dequantizes the data according to its quantization scheme.
@@ -457,6 +560,10 @@ impl TensorData {
             DType::F32 => self.assert_eq_elem::<f32>(other),
             DType::F16 => self.assert_eq_elem::<f16>(other),
             DType::BF16 => self.assert_eq_elem::<bf16>(other),
+            #[cfg(feature = "fp8")]
+            DType::F8E4M3 => self.assert_eq_elem::<F8E4M3>(other),
+            #[cfg(feature = "fp8")]
+            DType::F8E5M2 => self.assert_eq_elem::<F8E5M2>(other),
             DType::I64 => self.assert_eq_elem::<i64>(other),
             DType::I32 => self.assert_eq_elem::<i32>(other),
             DType::I16 => self.assert_eq_elem::<i16>(other),
@@ -473,17 +580,11 @@ impl TensorData {
                 } else {
                     panic!("Quantized data differs from other not quantized data")
                 };
-                match (q, q_other) {
-                    (
-                        QuantizationScheme::PerTensorAffine(QuantizationType::QInt8),
-                        QuantizationScheme::PerTensorAffine(QuantizationType::QInt8),
-                    )
-                    | (
-                        QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt8),
-                        QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt8),
-                    ) => self.assert_eq_elem::<i8>(other),
-                    _ => panic!("Quantization schemes differ ({:?} != {:?})", q, q_other),
+                if q != q_other {
+                    panic!("Quantization schemes differ ({:?} != {:?})", q, q_other)
                 }
+                // Sub-byte values are unpacked to i8
+                self.assert_eq_elem::<i8>(other)
             }
         }
     }
@@ -754,6 +855,10 @@ impl core::fmt::Display for TensorData {
             DType::F32 => format!("{:?}", self.as_slice::<f32>().unwrap()),
             DType::F16 => format!("{:?}", self.as_slice::<f16>().unwrap()),
             DType::BF16 => format!("{:?}", self.as_slice::<bf16>().unwrap()),
+            #[cfg(feature = "fp8")]
+            DType::F8E4M3 => format!("{:?}", self.as_slice::<F8E4M3>().unwrap()),
+            #[cfg(feature = "fp8")]
+            DType::F8E5M2 => format!("{:?}", self.as_slice::<F8E5M2>().unwrap()),
             DType::I64 => format!("{:?}", self.as_slice::<i64>().unwrap()),
             DType::I32 => format!("{:?}", self.as_slice::<i32>().unwrap()),
             DType::I16 => format!("{:?}", self.as_slice::<i16>().unwrap()),
@@ -763,11 +868,13 @@ impl core::fmt::Display for TensorData {
             DType::U16 => format!("{:?}", self.as_slice::<u16>().unwrap()),
             DType::U8 => format!("{:?}", self.as_slice::<u8>().unwrap()),
             DType::Bool => format!("{:?}", self.as_slice::<bool>().unwrap()),
-            DType::QFloat(scheme) => match scheme {
-                QuantizationScheme::PerTensorAffine(QuantizationType::QInt8)
-                | QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt8) => {
+            DType::QFloat(scheme) => match scheme.q_type() {
+                QuantizationType::QInt8 => {
                     format!("{:?} {scheme:?}", self.try_as_slice::<i8>().unwrap())
                 }
+                QuantizationType::QInt4 | QuantizationType::QInt2 => {
+                    format!("{:?} {scheme:?}", self.iter::<i8>().collect::<Vec<_>>())
+                }
             },
         };
         f.write_str(fmt.as_str())
@@ -781,6 +888,10 @@ fn compare_floats(value: f64, other: f64, ty: DType, tolerance: f64) -> Option<(
         DType::F32 => f32::EPSILON as f64,
         DType::F16 => half::f16::EPSILON.to_f64(),
         DType::BF16 => half::bf16::EPSILON.to_f64(),
+        #[cfg(feature = "fp8")]
+        DType::F8E4M3 => 0.125,
+        #[cfg(feature = "fp8")]
+        DType::F8E5M2 => 0.25,
         _ => unreachable!(),
     };
     let tolerance_norm = epsilon_deviations * epsilon;
@@ -800,7 +911,10 @@ fn compare_floats(value: f64, other: f64, ty: DType, tolerance: f64) -> Option<(
 
 #[cfg(test)]
 mod tests {
-    use crate::{quantization::AffineQuantization, Shape};
+    use crate::{
+        quantization::{AffineQuantization, SymmetricQuantization},
+        Shape,
+    };
 
     use super::*;
     use alloc::vec;
@@ -939,4 +1053,46 @@ mod tests {
 
         output.assert_approx_eq(&TensorData::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]), 4);
     }
+
+    #[test]
+    fn should_support_quantize_int4() {
+        let data = TensorData::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+
+        let output = data.with_quantization(QuantizationStrategy::PerTensorSymmetricInt4(
+            SymmetricQuantization::init(5.0 / 7.0),
+        ));
+
+        assert_eq!(output.bytes.len(), 2 * core::mem::size_of::<u32>());
+        assert_eq!(
+            output.iter::<i8>().collect::<Vec<_>>(),
+            vec![0, 1, 3, 4, 6, 7]
+        );
+        output.dequantize().unwrap().assert_approx_eq(
+            &TensorData::from([[0.0, 0.7143, 2.1429], [2.8571, 4.2857, 5.0]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn should_slice_data() {
+        let data = TensorData::new((0..24).collect::<Vec<i32>>(), [2, 3, 4]);
+
+        data.slice(&[1..2, 0..2, 1..3])
+            .assert_eq(&TensorData::from([[[13, 14], [17, 18]]]), true);
+        data.slice(&[0..2, 1..2]).assert_eq(
+            &TensorData::from([[[4, 5, 6, 7]], [[16, 17, 18, 19]]]),
+            true,
+        );
+        data.slice(&[1..2]).assert_eq(
+            &TensorData::new((12..24).collect::<Vec<i32>>(), [1, 3, 4]),
+            true,
+        );
+        assert_eq!(data.slice(&[0..2, 1..1]).shape, vec![2, 0, 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn should_panic_slicing_data_out_of_bounds() {
+        TensorData::from([1.0, 2.0]).slice(&[1..3]);
+    }
 }
@@ -3,19 +3,62 @@ use rand::{distributions::Standard, Rng, RngCore};
 use crate::{Element, ElementConversion};
 
 /// Distribution for random value of a tensor.
+///
+/// Every backend samples the same distributions with the same parameters, the samples being
+/// converted to the element type of the tensor. The parameters are checked when sampling a
+/// tensor, invalid parameters panic with the same error on every backend.
 #[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum Distribution {
     /// Uniform distribution from 0 (inclusive) to 1 (exclusive).
     Default,
 
-    /// Bernoulli distribution with the given probability.
+    /// Bernoulli distribution with the given probability of sampling 1, between 0 and 1, the
+    /// other values being 0.
     Bernoulli(f64),
 
-    /// Uniform distribution. The range is inclusive.
+    /// Uniform distribution from the lower bound (inclusive) to the upper bound (exclusive).
     Uniform(f64, f64),
 
-    /// Normal distribution with the given mean and standard deviation.
+    /// Normal distribution with the given mean and non-negative standard deviation.
     Normal(f64, f64),
+
+    /// Exponential distribution with the given positive rate, the mean being `1 / rate`.
+    Exponential(f64),
+
+    /// Gamma distribution with the given positive shape and scale, the mean being
+    /// `shape * scale`.
+    Gamma(f64, f64),
+
+    /// Poisson distribution with the given positive rate, the values are non-negative integers.
+    Poisson(f64),
+}
+
+impl Distribution {
+    /// The mean of the distribution.
+    pub fn mean(&self) -> f64 {
+        match *self {
+            Distribution::Default => 0.5,
+            Distribution::Bernoulli(prob) => prob,
+            Distribution::Uniform(low, high) => (low + high) / 2.0,
+            Distribution::Normal(mean, _) => mean,
+            Distribution::Exponential(rate) => 1.0 / rate,
+            Distribution::Gamma(shape, scale) => shape * scale,
+            Distribution::Poisson(rate) => rate,
+        }
+    }
+
+    /// The variance of the distribution.
+    pub fn variance(&self) -> f64 {
+        match *self {
+            Distribution::Default => 1.0 / 12.0,
+            Distribution::Bernoulli(prob) => prob * (1.0 - prob),
+            Distribution::Uniform(low, high) => (high - low).powi(2) / 12.0,
+            Distribution::Normal(_, std) => std * std,
+            Distribution::Exponential(rate) => 1.0 / (rate * rate),
+            Distribution::Gamma(shape, scale) => shape * scale * scale,
+            Distribution::Poisson(rate) => rate,
+        }
+    }
 }
 
 /// Distribution sampler for random value of a tensor.
@@ -47,6 +90,15 @@ where
 
     /// Normal distribution.
     Normal(rand_distr::Normal<f64>),
+
+    /// Exponential distribution.
+    Exponential(rand_distr::Exp<f64>),
+
+    /// Gamma distribution.
+    Gamma(rand_distr::Gamma<f64>),
+
+    /// Poisson distribution.
+    Poisson(rand_distr::Poisson<f64>),
 }
 
 impl<E, R> DistributionSampler<'_, E, R>
@@ -69,6 +121,11 @@ where
                 }
             }
             DistributionSamplerKind::Normal(distribution) => self.rng.sample(distribution).elem(),
+            DistributionSamplerKind::Exponential(distribution) => {
+                self.rng.sample(distribution).elem()
+            }
+            DistributionSamplerKind::Gamma(distribution) => self.rng.sample(distribution).elem(),
+            DistributionSamplerKind::Poisson(distribution) => self.rng.sample(distribution).elem(),
         }
     }
 }
@@ -102,6 +159,15 @@ impl Distribution {
             Distribution::Normal(mean, std) => {
                 DistributionSamplerKind::Normal(rand_distr::Normal::new(mean, std).unwrap())
             }
+            Distribution::Exponential(rate) => {
+                DistributionSamplerKind::Exponential(rand_distr::Exp::new(rate).unwrap())
+            }
+            Distribution::Gamma(shape, scale) => {
+                DistributionSamplerKind::Gamma(rand_distr::Gamma::new(shape, scale).unwrap())
+            }
+            Distribution::Poisson(rate) => {
+                DistributionSamplerKind::Poisson(rand_distr::Poisson::<f64>::new(rate).unwrap())
+            }
         };
 
         DistributionSampler::new(kind, rng)
@@ -1,5 +1,7 @@
 use core::cmp::Ordering;
 
+#[cfg(feature = "fp8")]
+use super::fp8::{F8E4M3, F8E5M2};
 use crate::{
     cast::ToElement,
     quantization::{QuantizationScheme, QuantizationType},
@@ -244,6 +246,30 @@ make_element!(
     dtype DType::BF16
 );
 
+#[cfg(feature = "fp8")]
+make_element!(
+    ty F8E4M3 Precision::Other,
+    convert |elem: &dyn ToElement| F8E4M3::from_f32(elem.to_f32()),
+    random |distribution: Distribution, rng: &mut R| {
+        let sample: f32 = distribution.sampler(rng).sample();
+        F8E4M3::from_f32(sample)
+    },
+    cmp |a: &F8E4M3, b: &F8E4M3| a.to_f32().total_cmp(&b.to_f32()),
+    dtype DType::F8E4M3
+);
+
+#[cfg(feature = "fp8")]
+make_element!(
+    ty F8E5M2 Precision::Other,
+    convert |elem: &dyn ToElement| F8E5M2::from_f32(elem.to_f32()),
+    random |distribution: Distribution, rng: &mut R| {
+        let sample: f32 = distribution.sampler(rng).sample();
+        F8E5M2::from_f32(sample)
+    },
+    cmp |a: &F8E5M2, b: &F8E5M2| a.to_f32().total_cmp(&b.to_f32()),
+    dtype DType::F8E5M2
+);
+
 #[cfg(feature = "cubecl")]
 make_element!(
     ty flex32 Precision::Half,
@@ -267,13 +293,25 @@ make_element!(
     dtype DType::Bool
 );
 
+/// The data type of the elements of a tensor.
+///
+/// The enum is non-exhaustive, since data types can be added without a major release: matches on
+/// data types outside of this crate need a wildcard arm, usually panicking for unsupported types.
+/// The experimental FP8 types are only available with the `fp8` feature.
 #[allow(missing_docs)]
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
 pub enum DType {
     F64,
     F32,
     F16,
     BF16,
+    /// 8-bit float with 4 exponent bits and 3 mantissa bits (experimental).
+    #[cfg(feature = "fp8")]
+    F8E4M3,
+    /// 8-bit float with 5 exponent bits and 2 mantissa bits (experimental).
+    #[cfg(feature = "fp8")]
+    F8E5M2,
     I64,
     I32,
     I16,
@@ -294,6 +332,8 @@ impl DType {
             DType::F32 => core::mem::size_of::<f32>(),
             DType::F16 => core::mem::size_of::<f16>(),
             DType::BF16 => core::mem::size_of::<bf16>(),
+            #[cfg(feature = "fp8")]
+            DType::F8E4M3 | DType::F8E5M2 => core::mem::size_of::<u8>(),
             DType::I64 => core::mem::size_of::<i64>(),
             DType::I32 => core::mem::size_of::<i32>(),
             DType::I16 => core::mem::size_of::<i16>(),
@@ -307,12 +347,31 @@ impl DType {
                 QuantizationScheme::PerTensorAffine(qtype)
                 | QuantizationScheme::PerTensorSymmetric(qtype) => match qtype {
                     QuantizationType::QInt8 => core::mem::size_of::<i8>(),
+                    // Sub-byte values are unpacked to i8
+                    QuantizationType::QInt4 | QuantizationType::QInt2 => core::mem::size_of::<i8>(),
                 },
             },
         }
     }
+
+    /// Returns the size of a type in bits, which is smaller than a byte for packed sub-byte
+    /// quantized types.
+    pub const fn size_bits(&self) -> usize {
+        match self {
+            DType::QFloat(
+                QuantizationScheme::PerTensorAffine(qtype)
+                | QuantizationScheme::PerTensorSymmetric(qtype),
+            ) => qtype.bits(),
+            _ => self.size() * 8,
+        }
+    }
     /// Returns true if the data type is a floating point type.
     pub fn is_float(&self) -> bool {
+        #[cfg(feature = "fp8")]
+        if matches!(self, DType::F8E4M3 | DType::F8E5M2) {
+            return true;
+        }
+
         matches!(self, DType::F64 | DType::F32 | DType::F16 | DType::BF16)
     }
     /// Returns true if the data type is a signed integer type.
@@ -332,6 +391,10 @@ impl DType {
             DType::F32 => "f32",
             DType::F16 => "f16",
             DType::BF16 => "bf16",
+            #[cfg(feature = "fp8")]
+            DType::F8E4M3 => "f8e4m3",
+            #[cfg(feature = "fp8")]
+            DType::F8E5M2 => "f8e5m2",
             DType::I64 => "i64",
             DType::I32 => "i32",
             DType::I16 => "i16",
@@ -347,7 +410,7 @@ impl DType {
 }
 
 #[allow(missing_docs)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FloatDType {
     F64,
     F32,
@@ -0,0 +1,306 @@
+use core::fmt::{Debug, Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+use super::cast::ToElement;
+use crate::DType;
+
+/// An 8-bit floating point format.
+///
+/// FP8 values are a storage format: they are converted to a wider float type to be computed on,
+/// with a scale mapping the range of the tensor to the small range of the format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Fp8Format {
+    /// 4 exponent bits and 3 mantissa bits, without infinities, for weights and activations.
+    E4M3,
+    /// 5 exponent bits and 2 mantissa bits, with a wider range but less precision, for
+    /// gradients.
+    E5M2,
+}
+
+impl Fp8Format {
+    /// The number of mantissa bits.
+    pub const fn mantissa_bits(&self) -> u32 {
+        match self {
+            Fp8Format::E4M3 => 3,
+            Fp8Format::E5M2 => 2,
+        }
+    }
+
+    /// The exponent bias.
+    pub const fn bias(&self) -> i32 {
+        match self {
+            Fp8Format::E4M3 => 7,
+            Fp8Format::E5M2 => 15,
+        }
+    }
+
+    /// The bits of the largest finite value.
+    pub const fn max_bits(&self) -> u8 {
+        match self {
+            Fp8Format::E4M3 => 0x7E,
+            Fp8Format::E5M2 => 0x7B,
+        }
+    }
+
+    /// The largest finite value.
+    pub fn max(&self) -> f32 {
+        self.decode(self.max_bits())
+    }
+
+    /// The data type of the tensors stored in this format.
+    pub const fn dtype(&self) -> DType {
+        match self {
+            Fp8Format::E4M3 => DType::F8E4M3,
+            Fp8Format::E5M2 => DType::F8E5M2,
+        }
+    }
+
+    /// Convert the value to the closest value of the format, rounding half to even.
+    ///
+    /// Finite values out of the range of the format saturate to the largest finite value, while
+    /// infinities are kept when the format supports them.
+    pub fn encode(&self, value: f32) -> u8 {
+        let bits = value.to_bits();
+        let sign = ((bits >> 24) & 0x80) as u8;
+        let abs = bits & 0x7FFF_FFFF;
+        let mantissa_bits = self.mantissa_bits();
+        let bias = self.bias();
+
+        if abs > 0x7F80_0000 {
+            return sign | 0x7F;
+        }
+        if abs == 0x7F80_0000 {
+            return match self {
+                Fp8Format::E4M3 => sign | self.max_bits(),
+                Fp8Format::E5M2 => sign | 0x7C,
+            };
+        }
+
+        let min_normal = ((127 + 1 - bias) as u32) << 23;
+        if abs < min_normal {
+            // Subnormal values are multiples of the smallest subnormal value, obtained by
+            // shifting the significand to the exponent of the smallest subnormal value.
+            let exponent = abs >> 23;
+            let shift = (151 - bias) as u32 - mantissa_bits - exponent;
+            if exponent == 0 || shift > 24 {
+                return sign;
+            }
+
+            let significand = (abs & 0x7F_FFFF) | 0x80_0000;
+            return sign | round_shift(significand, shift) as u8;
+        }
+
+        let rounded = round_shift(abs, 23 - mantissa_bits);
+        let rebias = ((127 - bias) as u32) << mantissa_bits;
+
+        sign | (rounded - rebias).min(self.max_bits() as u32) as u8
+    }
+
+    /// Convert the bits of a value of the format to a float.
+    pub fn decode(&self, bits: u8) -> f32 {
+        let sign = ((bits & 0x80) as u32) << 24;
+        let mantissa_bits = self.mantissa_bits();
+        let bias = self.bias();
+        let exponent = ((bits & 0x7F) >> mantissa_bits) as u32;
+        let mantissa = (bits & ((1 << mantissa_bits) - 1)) as u32;
+        let max_exponent = (1 << (7 - mantissa_bits)) - 1;
+
+        match self {
+            Fp8Format::E4M3 if bits & 0x7F == 0x7F => return f32::from_bits(sign | 0x7FC0_0000),
+            Fp8Format::E5M2 if exponent == max_exponent => {
+                return match mantissa {
+                    0 => f32::from_bits(sign | 0x7F80_0000),
+                    _ => f32::from_bits(sign | 0x7FC0_0000),
+                }
+            }
+            _ => {}
+        }
+
+        if exponent == 0 {
+            let quantum = f32::from_bits(((127 + 1 - bias) as u32 - mantissa_bits) << 23);
+            let value = mantissa as f32 * quantum;
+            return f32::from_bits(sign | value.to_bits());
+        }
+
+        f32::from_bits(
+            sign | ((exponent + (127 - bias) as u32) << 23) | (mantissa << (23 - mantissa_bits)),
+        )
+    }
+}
+
+/// How a tensor is converted to an FP8 format: its values are divided by the scale, then rounded
+/// to the closest value of the format.
+///
+/// The scale maps the range of the tensor to the range of the format, it is usually the maximum
+/// absolute value of the tensor divided by the [largest value](Fp8Format::max) of the format.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Fp8Scaling {
+    /// The format the values are converted to.
+    pub format: Fp8Format,
+    /// The scale dividing the values.
+    pub scale: f32,
+}
+
+impl Fp8Scaling {
+    /// Create a new scaling with the given format and scale.
+    pub fn new(format: Fp8Format, scale: f32) -> Self {
+        Self { format, scale }
+    }
+
+    /// The scaling mapping the maximum absolute value of a tensor to the largest value of the
+    /// format.
+    pub fn from_max_abs(format: Fp8Format, max_abs: f32) -> Self {
+        Self::new(format, max_abs / format.max())
+    }
+}
+
+/// Shift the bits to the right, rounding half to even.
+fn round_shift(value: u32, shift: u32) -> u32 {
+    (value + (1 << (shift - 1)) - 1 + ((value >> shift) & 1)) >> shift
+}
+
+macro_rules! fp8_type {
+    ($name:ident, $format:expr, $doc:literal) => {
+        #[doc = $doc]
+        #[derive(Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+        #[repr(transparent)]
+        pub struct $name(u8);
+
+        impl $name {
+            /// The format of the type.
+            pub const FORMAT: Fp8Format = $format;
+
+            /// Create a value from its bits.
+            pub const fn from_bits(bits: u8) -> Self {
+                Self(bits)
+            }
+
+            /// The bits of the value.
+            pub const fn to_bits(self) -> u8 {
+                self.0
+            }
+
+            /// Convert a float to the closest value, see [encode](Fp8Format::encode).
+            pub fn from_f32(value: f32) -> Self {
+                Self(Self::FORMAT.encode(value))
+            }
+
+            /// Convert the value to a float.
+            pub fn to_f32(self) -> f32 {
+                Self::FORMAT.decode(self.0)
+            }
+        }
+
+        // SAFETY: the type is a transparent wrapper around a byte, for which every bit pattern
+        // is valid.
+        unsafe impl bytemuck::Zeroable for $name {}
+        // SAFETY: see above.
+        unsafe impl bytemuck::Pod for $name {}
+
+        impl Debug for $name {
+            fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                Debug::fmt(&self.to_f32(), f)
+            }
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                Display::fmt(&self.to_f32(), f)
+            }
+        }
+
+        impl ToElement for $name {
+            #[inline]
+            fn to_i64(&self) -> i64 {
+                Self::to_f32(*self).to_i64()
+            }
+            #[inline]
+            fn to_u64(&self) -> u64 {
+                Self::to_f32(*self).to_u64()
+            }
+            #[inline]
+            fn to_i8(&self) -> i8 {
+                Self::to_f32(*self).to_i8()
+            }
+            #[inline]
+            fn to_u8(&self) -> u8 {
+                Self::to_f32(*self).to_u8()
+            }
+            #[inline]
+            fn to_i16(&self) -> i16 {
+                Self::to_f32(*self).to_i16()
+            }
+            #[inline]
+            fn to_u16(&self) -> u16 {
+                Self::to_f32(*self).to_u16()
+            }
+            #[inline]
+            fn to_i32(&self) -> i32 {
+                Self::to_f32(*self).to_i32()
+            }
+            #[inline]
+            fn to_u32(&self) -> u32 {
+                Self::to_f32(*self).to_u32()
+            }
+            #[inline]
+            fn to_f32(&self) -> f32 {
+                Self::to_f32(*self)
+            }
+            #[inline]
+            fn to_f64(&self) -> f64 {
+                Self::to_f32(*self) as f64
+            }
+        }
+    };
+}
+
+fp8_type!(
+    F8E4M3,
+    Fp8Format::E4M3,
+    "An 8-bit float with 4 exponent bits and 3 mantissa bits (experimental)."
+);
+fp8_type!(
+    F8E5M2,
+    Fp8Format::E5M2,
+    "An 8-bit float with 5 exponent bits and 2 mantissa bits (experimental)."
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_round_trip_all_values() {
+        for format in [Fp8Format::E4M3, Fp8Format::E5M2] {
+            for bits in 0..=u8::MAX {
+                let value = format.decode(bits);
+
+                if value.is_nan() {
+                    assert!(format.encode(value) & 0x7F == 0x7F);
+                } else if bits & 0x7F != 0 {
+                    assert_eq!(format.encode(value), bits, "{format:?} {bits:#x}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn should_encode_known_values() {
+        assert_eq!(F8E4M3::from_f32(448.0).to_bits(), 0x7E);
+        assert_eq!(F8E4M3::from_f32(1000.0).to_f32(), 448.0);
+        assert_eq!(F8E4M3::from_f32(-1.0).to_bits(), 0xB8);
+        assert_eq!(F8E4M3::from_f32(2f32.powi(-9)).to_bits(), 0x01);
+        assert_eq!(F8E5M2::from_f32(57344.0).to_bits(), 0x7B);
+        assert_eq!(F8E5M2::from_f32(f32::INFINITY).to_bits(), 0x7C);
+        assert_eq!(F8E5M2::from_f32(1.5).to_f32(), 1.5);
+    }
+
+    #[test]
+    fn should_round_half_to_even() {
+        // 1.0625 is halfway between 1.0 and 1.125, the even mantissa is 1.0.
+        assert_eq!(F8E4M3::from_f32(1.0625).to_f32(), 1.0);
+        // 1.1875 is halfway between 1.125 and 1.25, the even mantissa is 1.25.
+        assert_eq!(F8E4M3::from_f32(1.1875).to_f32(), 1.25);
+    }
+}
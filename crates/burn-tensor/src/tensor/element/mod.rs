@@ -1,6 +1,10 @@
 mod base;
+#[cfg(feature = "fp8")]
+mod fp8;
 
 /// Tensor element casting.
 pub mod cast;
 
 pub use base::*;
+#[cfg(feature = "fp8")]
+pub use fp8::*;
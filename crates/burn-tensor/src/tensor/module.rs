@@ -1,6 +1,9 @@
 use crate::{
     backend::Backend,
-    ops::{ConvOptions, ConvTransposeOptions, InterpolateOptions, UnfoldOptions},
+    ops::{
+        ConvOptions, ConvTransposeOptions, GridSampleOptions, InterpolateOptions, NmsOptions,
+        RoiAlignOptions, RoiPoolOptions, UnfoldOptions,
+    },
     Int, Tensor, TensorPrimitive,
 };
 
@@ -159,6 +162,53 @@ where
     )))
 }
 
+/// Applies a [3D to 4D fold](crate::ops::ModuleOps::fold4d).
+///
+/// # Panics
+///
+/// Panics if the number of blocks doesn't match the number of blocks the unfolding of a tensor of
+/// `output_size` produces with the same options.
+pub fn fold4d<B>(
+    x: Tensor<B, 3>,
+    output_size: [usize; 2],
+    kernel_size: [usize; 2],
+    options: UnfoldOptions,
+) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    let [_, channels_col, num_blocks] = x.dims();
+    let expected = [0, 1]
+        .map(|i| {
+            crate::ops::conv::calculate_conv_output_size(
+                kernel_size[i],
+                options.stride[i],
+                options.padding[i],
+                options.dilation[i],
+                output_size[i],
+            )
+        })
+        .iter()
+        .product::<usize>();
+
+    assert_eq!(
+        num_blocks, expected,
+        "The number of blocks doesn't match the output size, kernel size and options"
+    );
+    assert_eq!(
+        channels_col % (kernel_size[0] * kernel_size[1]),
+        0,
+        "The number of channels must be divisible by the number of elements of the kernel"
+    );
+
+    Tensor::new(TensorPrimitive::Float(B::fold4d(
+        x.primitive.tensor(),
+        output_size,
+        kernel_size,
+        options,
+    )))
+}
+
 /// Applies a [1D max pooling](crate::ops::ModuleOps::max_pool1d).
 pub fn max_pool1d<B>(
     x: Tensor<B, 3>,
@@ -279,6 +329,66 @@ where
     )
 }
 
+/// Applies a [3D max pooling](crate::ops::ModuleOps::max_pool3d).
+pub fn max_pool3d<B>(
+    x: Tensor<B, 5>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    dilation: [usize; 3],
+) -> Tensor<B, 5>
+where
+    B: Backend,
+{
+    Tensor::new(TensorPrimitive::Float(B::max_pool3d(
+        x.primitive.tensor(),
+        kernel_size,
+        stride,
+        padding,
+        dilation,
+    )))
+}
+
+/// Applies a [3D max pooling with indices](crate::ops::ModuleOps::max_pool3d_with_indices).
+pub fn max_pool3d_with_indices<B>(
+    x: Tensor<B, 5>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    dilation: [usize; 3],
+) -> (Tensor<B, 5>, Tensor<B, 5, Int>)
+where
+    B: Backend,
+{
+    let output =
+        B::max_pool3d_with_indices(x.primitive.tensor(), kernel_size, stride, padding, dilation);
+
+    (
+        Tensor::new(TensorPrimitive::Float(output.output)),
+        Tensor::new(output.indices),
+    )
+}
+
+/// Applies a [3D avg pooling](crate::ops::ModuleOps::avg_pool3d).
+pub fn avg_pool3d<B>(
+    x: Tensor<B, 5>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    count_include_pad: bool,
+) -> Tensor<B, 5>
+where
+    B: Backend,
+{
+    Tensor::new(TensorPrimitive::Float(B::avg_pool3d(
+        x.primitive.tensor(),
+        kernel_size,
+        stride,
+        padding,
+        count_include_pad,
+    )))
+}
+
 /// Applies a [2D adaptive avg pooling](crate::ops::ModuleOps::adaptive_avg_pool2d).
 pub fn adaptive_avg_pool2d<B>(x: Tensor<B, 4>, output_size: [usize; 2]) -> Tensor<B, 4>
 where
@@ -316,3 +426,236 @@ where
         options,
     )))
 }
+
+/// Applies a [2D grid sampling](crate::ops::ModuleOps::grid_sample).
+///
+/// # Panics
+///
+/// Panics if the grid doesn't have the shape `[batch_size, height_out, width_out, 2]`.
+pub fn grid_sample<B>(
+    x: Tensor<B, 4>,
+    grid: Tensor<B, 4>,
+    options: GridSampleOptions,
+) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    let [batch_size, ..] = x.dims();
+    let [grid_batch_size, _, _, num_coordinates] = grid.dims();
+
+    assert_eq!(
+        batch_size, grid_batch_size,
+        "The grid should have the same batch size as the input"
+    );
+    assert_eq!(
+        num_coordinates, 2,
+        "The last dimension of the grid should contain the x and y coordinates"
+    );
+
+    Tensor::new(TensorPrimitive::Float(B::grid_sample(
+        x.primitive.tensor(),
+        grid.primitive.tensor(),
+        options,
+    )))
+}
+
+/// Generates the [affine sampling grid](crate::ops::ModuleOps::affine_grid) of the output of
+/// size `[batch_size, channels, height_out, width_out]`, to be used with [grid_sample].
+///
+/// # Panics
+///
+/// Panics if theta doesn't have the shape `[batch_size, 2, 3]`.
+pub fn affine_grid<B>(theta: Tensor<B, 3>, size: [usize; 4], align_corners: bool) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    let [batch_size, rows, cols] = theta.dims();
+
+    assert_eq!(
+        [batch_size, rows, cols],
+        [size[0], 2, 3],
+        "Theta should be [batch_size, 2, 3] for an output of size {size:?}"
+    );
+
+    Tensor::new(TensorPrimitive::Float(B::affine_grid(
+        theta.primitive.tensor(),
+        size,
+        align_corners,
+    )))
+}
+
+/// Applies a [region of interest align](crate::ops::ModuleOps::roi_align).
+///
+/// # Panics
+///
+/// Panics if the boxes don't have the shape `[num_boxes, 5]`.
+pub fn roi_align<B>(x: Tensor<B, 4>, boxes: Tensor<B, 2>, options: RoiAlignOptions) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    let [_, num_coordinates] = boxes.dims();
+    assert_eq!(
+        num_coordinates, 5,
+        "Each box should be [batch_index, x1, y1, x2, y2]"
+    );
+
+    Tensor::new(TensorPrimitive::Float(B::roi_align(
+        x.primitive.tensor(),
+        boxes.primitive.tensor(),
+        options,
+    )))
+}
+
+/// Applies a [region of interest pooling](crate::ops::ModuleOps::roi_pool).
+///
+/// # Panics
+///
+/// Panics if the boxes don't have the shape `[num_boxes, 5]`.
+pub fn roi_pool<B>(x: Tensor<B, 4>, boxes: Tensor<B, 2>, options: RoiPoolOptions) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    let [_, num_coordinates] = boxes.dims();
+    assert_eq!(
+        num_coordinates, 5,
+        "Each box should be [batch_index, x1, y1, x2, y2]"
+    );
+
+    Tensor::new(TensorPrimitive::Float(B::roi_pool(
+        x.primitive.tensor(),
+        boxes.primitive.tensor(),
+        options,
+    )))
+}
+
+/// Applies a [non-maximum suppression](crate::ops::ModuleOps::nms) to the boxes of each batch
+/// and class, suppressing the boxes overlapping a box with a higher score by more than the
+/// intersection over union threshold.
+///
+/// Returns the selected boxes as `[batch_index, class_index, box_index]`.
+pub fn nms<B>(boxes: Tensor<B, 3>, scores: Tensor<B, 3>, iou_threshold: f32) -> Tensor<B, 2, Int>
+where
+    B: Backend,
+{
+    nms_with_options(
+        boxes,
+        scores,
+        NmsOptions::new(iou_threshold, None, None, false),
+    )
+}
+
+/// Applies a [non-maximum suppression](crate::ops::ModuleOps::nms) with the given options.
+///
+/// # Panics
+///
+/// Panics if the boxes and the scores don't have the shapes `[batch_size, num_boxes, 4]` and
+/// `[batch_size, num_classes, num_boxes]`.
+pub fn nms_with_options<B>(
+    boxes: Tensor<B, 3>,
+    scores: Tensor<B, 3>,
+    options: NmsOptions,
+) -> Tensor<B, 2, Int>
+where
+    B: Backend,
+{
+    let [batch_size, num_boxes, num_coordinates] = boxes.dims();
+    let [batch_size_scores, _, num_boxes_scores] = scores.dims();
+    assert_eq!(num_coordinates, 4, "Each box should have 4 coordinates");
+    assert_eq!(
+        [batch_size, num_boxes],
+        [batch_size_scores, num_boxes_scores],
+        "The scores should be [batch_size, num_classes, num_boxes]"
+    );
+
+    Tensor::new(B::nms(
+        boxes.primitive.tensor(),
+        scores.primitive.tensor(),
+        options,
+    ))
+}
+
+/// Applies a [root mean square normalization](crate::ops::ModuleOps::rms_norm) over the last
+/// dimension.
+pub fn rms_norm<B, const D: usize>(
+    x: Tensor<B, D>,
+    gamma: Tensor<B, 1>,
+    epsilon: f64,
+) -> Tensor<B, D>
+where
+    B: Backend,
+{
+    Tensor::new(TensorPrimitive::Float(B::rms_norm(
+        x.primitive.tensor(),
+        gamma.primitive.tensor(),
+        epsilon,
+    )))
+}
+
+/// Applies a [layer normalization](crate::ops::ModuleOps::layer_norm) over the last dimension.
+pub fn layer_norm<B, const D: usize>(
+    x: Tensor<B, D>,
+    gamma: Tensor<B, 1>,
+    beta: Option<Tensor<B, 1>>,
+    epsilon: f64,
+) -> Tensor<B, D>
+where
+    B: Backend,
+{
+    Tensor::new(TensorPrimitive::Float(B::layer_norm(
+        x.primitive.tensor(),
+        gamma.primitive.tensor(),
+        beta.map(|beta| beta.primitive.tensor()),
+        epsilon,
+    )))
+}
+
+/// Applies [rotary position embeddings](crate::ops::ModuleOps::rotary_qk) to the queries and keys
+/// of an attention layer, returning them with their heads swapped with the sequence.
+///
+/// # Shapes
+///
+/// queries:     `[batch_size, seq_length, n_heads, d_head]`,
+/// keys:        `[batch_size, seq_length, n_heads_kv, d_head]`,
+/// frequencies: `[max_seq_length, rotary_dim, 2]`,
+/// output:      `[batch_size, n_heads, seq_length, d_head]` and
+///              `[batch_size, n_heads_kv, seq_length, d_head]`,
+///
+/// # Panics
+///
+/// Panics if the number of rotated features is odd or greater than the size of the heads, or if
+/// the positions exceed the maximum sequence length of the frequencies.
+pub fn rotary_qk<B>(
+    queries: Tensor<B, 4>,
+    keys: Tensor<B, 4>,
+    frequencies: Tensor<B, 3>,
+    start: usize,
+) -> (Tensor<B, 4>, Tensor<B, 4>)
+where
+    B: Backend,
+{
+    let [_, seq_length, _, d_head] = queries.dims();
+    let [max_seq_length, rotary_dim, _] = frequencies.dims();
+
+    assert!(
+        rotary_dim % 2 == 0 && rotary_dim <= d_head,
+        "The number of rotated features {rotary_dim} must be even and not exceed the size of the heads {d_head}"
+    );
+    assert!(
+        start + seq_length <= max_seq_length,
+        "The positions {}..{} exceed the maximum sequence length {max_seq_length}",
+        start,
+        start + seq_length
+    );
+
+    let output = B::rotary_qk(
+        queries.primitive.tensor(),
+        keys.primitive.tensor(),
+        frequencies.primitive.tensor(),
+        start,
+    );
+
+    (
+        Tensor::new(TensorPrimitive::Float(output.queries)),
+        Tensor::new(TensorPrimitive::Float(output.keys)),
+    )
+}
@@ -0,0 +1,411 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::backend::Backend;
+use crate::{BasicOps, Float, Numeric, Shape, Tensor};
+
+/// The names of the dimensions of a tensor, known at runtime.
+///
+/// Unlike [named dimensions](crate::NamedDims), the names aren't part of the type, which makes
+/// them easier to attach to existing code, like attention layers whose dimensions are often
+/// swapped and reshaped. The names are checked in debug builds only.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DimNames<const D: usize> {
+    names: [&'static str; D],
+}
+
+impl<const D: usize> DimNames<D> {
+    /// Create the names of the dimensions.
+    ///
+    /// # Panics
+    ///
+    /// If the same name is used twice, in debug builds.
+    pub fn new(names: [&'static str; D]) -> Self {
+        #[cfg(debug_assertions)]
+        for (i, name) in names.iter().enumerate() {
+            if names[..i].contains(name) {
+                panic!("Duplicate dim '{name}' in {}", Self::format(&names));
+            }
+        }
+
+        Self { names }
+    }
+
+    /// The names of the dimensions.
+    pub fn names(&self) -> &[&'static str; D] {
+        &self.names
+    }
+
+    /// The index of the dimension with the given name.
+    ///
+    /// # Panics
+    ///
+    /// If no dimension or multiple dimensions have the given name.
+    pub fn index(&self, name: &str) -> usize {
+        let index = self
+            .names
+            .iter()
+            .position(|current| *current == name)
+            .unwrap_or_else(|| panic!("No dim '{name}' in {self}"));
+
+        if self.names[index + 1..].contains(&name) {
+            panic!("Ambiguous dim '{name}' in {self}, rename the dimensions to address them");
+        }
+
+        index
+    }
+
+    /// Check that the dimensions have the expected names, in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// With the first dimension that doesn't have the expected name, e.g.
+    /// `expected dim 'seq' got 'heads'`.
+    pub fn check(&self, expected: &Self) {
+        #[cfg(debug_assertions)]
+        for (dim, (name, expected_name)) in self.names.iter().zip(expected.names.iter()).enumerate()
+        {
+            if name != expected_name {
+                panic!(
+                    "Expected dim '{expected_name}' got '{name}' at position {dim} of {self}, \
+                     expected {expected}"
+                );
+            }
+        }
+
+        #[cfg(not(debug_assertions))]
+        let _ = expected;
+    }
+
+    /// The names with the given dimensions swapped.
+    pub fn swap(mut self, dim1: usize, dim2: usize) -> Self {
+        self.names.swap(dim1, dim2);
+        self
+    }
+
+    /// The names in the order of the given dimensions.
+    pub fn permute(&self, axes: [usize; D]) -> Self {
+        Self {
+            names: axes.map(|axis| self.names[axis]),
+        }
+    }
+
+    fn format(names: &[&'static str]) -> String {
+        format!("[{}]", names.join(", "))
+    }
+}
+
+impl<const D: usize> core::fmt::Display for DimNames<D> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&Self::format(&self.names))
+    }
+}
+
+/// A tensor with the [names of its dimensions](DimNames), which are propagated through the
+/// operations and checked in debug builds.
+///
+/// The dimensions are addressed by their names instead of their indices, so that porting code
+/// that shuffles the batch, sequence and heads dimensions fails with errors like
+/// `expected dim 'seq' got 'heads'` instead of wrong results.
+#[derive(Debug, Clone)]
+pub struct DynNamedTensor<B: Backend, const D: usize, K: BasicOps<B> = Float> {
+    tensor: Tensor<B, D, K>,
+    names: DimNames<D>,
+}
+
+impl<B, const D: usize, K> DynNamedTensor<B, D, K>
+where
+    B: Backend,
+    K: BasicOps<B>,
+{
+    /// Attach the names of the dimensions to the tensor.
+    pub fn new(tensor: Tensor<B, D, K>, names: [&'static str; D]) -> Self {
+        Self {
+            tensor,
+            names: DimNames::new(names),
+        }
+    }
+
+    /// The names of the dimensions.
+    pub fn names(&self) -> &DimNames<D> {
+        &self.names
+    }
+
+    /// The tensor without the names of its dimensions.
+    pub fn tensor(&self) -> &Tensor<B, D, K> {
+        &self.tensor
+    }
+
+    /// Convert into the tensor without the names of its dimensions.
+    pub fn into_tensor(self) -> Tensor<B, D, K> {
+        self.tensor
+    }
+
+    /// The shape of the tensor.
+    pub fn shape(&self) -> Shape {
+        self.tensor.shape()
+    }
+
+    /// The size of the dimension with the given name.
+    ///
+    /// # Panics
+    ///
+    /// If no dimension has the given name.
+    pub fn dim(&self, name: &str) -> usize {
+        self.tensor.dims()[self.names.index(name)]
+    }
+
+    /// Check that the dimensions have the expected names, in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// With the first dimension that doesn't have the expected name.
+    pub fn check(self, names: [&'static str; D]) -> Self {
+        self.names.check(&DimNames::new(names));
+        self
+    }
+
+    /// Rename the dimensions, without checking their current names.
+    pub fn rename(self, names: [&'static str; D]) -> Self {
+        Self::new(self.tensor, names)
+    }
+
+    /// Swap the two dimensions with the given names.
+    pub fn swap_dims(self, name1: &str, name2: &str) -> Self {
+        let dim1 = self.names.index(name1);
+        let dim2 = self.names.index(name2);
+
+        Self {
+            tensor: self.tensor.swap_dims(dim1, dim2),
+            names: self.names.swap(dim1, dim2),
+        }
+    }
+
+    /// Permute the dimensions to the order of the given names.
+    ///
+    /// # Panics
+    ///
+    /// If a name isn't a dimension of the tensor.
+    pub fn align_to(self, names: [&'static str; D]) -> Self {
+        let axes = names.map(|name| self.names.index(name));
+
+        Self {
+            tensor: self.tensor.permute(axes.map(|axis| axis as isize)),
+            names: self.names.permute(axes),
+        }
+    }
+
+    /// Reshape the tensor, naming the dimensions of the new shape.
+    ///
+    /// The sizes of the new dimensions can be computed from the named dimensions, for instance
+    /// splitting the features into heads with `[x.dim("batch"), x.dim("seq"), n_heads, d_k]`.
+    pub fn reshape<const D2: usize>(
+        self,
+        shape: [usize; D2],
+        names: [&'static str; D2],
+    ) -> DynNamedTensor<B, D2, K> {
+        DynNamedTensor::new(self.tensor.reshape(shape), names)
+    }
+
+    /// Concatenate the tensors along the dimension with the given name.
+    ///
+    /// # Panics
+    ///
+    /// If the tensors don't have the same dimension names, in debug builds.
+    pub fn cat(tensors: Vec<Self>, name: &str) -> Self {
+        let names = tensors
+            .first()
+            .expect("At least one tensor to concatenate")
+            .names;
+        let dim = names.index(name);
+        let tensors = tensors
+            .into_iter()
+            .map(|tensor| {
+                tensor.names.check(&names);
+                tensor.tensor
+            })
+            .collect();
+
+        Self {
+            tensor: Tensor::cat(tensors, dim),
+            names,
+        }
+    }
+
+    /// Returns a tensor containing the elements within the given range of the dimension with the
+    /// given name.
+    pub fn narrow(self, name: &str, start: usize, length: usize) -> Self {
+        let dim = self.names.index(name);
+
+        Self {
+            tensor: self.tensor.narrow(dim, start, length),
+            names: self.names,
+        }
+    }
+
+    fn map_checked<F>(self, rhs: Self, func: F) -> Self
+    where
+        F: FnOnce(Tensor<B, D, K>, Tensor<B, D, K>) -> Tensor<B, D, K>,
+    {
+        rhs.names.check(&self.names);
+
+        Self {
+            tensor: func(self.tensor, rhs.tensor),
+            names: self.names,
+        }
+    }
+}
+
+impl<B, const D: usize, K> DynNamedTensor<B, D, K>
+where
+    B: Backend,
+    K: Numeric<B>,
+    K::Elem: crate::Element,
+{
+    /// Applies element wise addition, the dimensions of both tensors having the same names.
+    #[allow(clippy::should_implement_trait)]
+    pub fn add(self, rhs: Self) -> Self {
+        self.map_checked(rhs, Tensor::add)
+    }
+
+    /// Applies element wise subtraction, the dimensions of both tensors having the same names.
+    #[allow(clippy::should_implement_trait)]
+    pub fn sub(self, rhs: Self) -> Self {
+        self.map_checked(rhs, Tensor::sub)
+    }
+
+    /// Applies element wise multiplication, the dimensions of both tensors having the same names.
+    #[allow(clippy::should_implement_trait)]
+    pub fn mul(self, rhs: Self) -> Self {
+        self.map_checked(rhs, Tensor::mul)
+    }
+
+    /// Applies element wise division, the dimensions of both tensors having the same names.
+    #[allow(clippy::should_implement_trait)]
+    pub fn div(self, rhs: Self) -> Self {
+        self.map_checked(rhs, Tensor::div)
+    }
+
+    /// Sum the elements along the dimension with the given name, keeping it with a size of 1.
+    pub fn sum_dim(self, name: &str) -> Self {
+        let dim = self.names.index(name);
+
+        Self {
+            tensor: self.tensor.sum_dim(dim),
+            names: self.names,
+        }
+    }
+
+    /// Mean of the elements along the dimension with the given name, keeping it with a size of 1.
+    pub fn mean_dim(self, name: &str) -> Self {
+        let dim = self.names.index(name);
+
+        Self {
+            tensor: self.tensor.mean_dim(dim),
+            names: self.names,
+        }
+    }
+}
+
+impl<B: Backend, const D: usize> DynNamedTensor<B, D> {
+    /// Applies the matrix multiplication operation.
+    ///
+    /// The batch dimensions of both tensors have the same names and the last dimension of `self`
+    /// is the second to last of `rhs`, e.g. `[batch, heads, seq, d_k] x [batch, heads, d_k, seq_kv]`
+    /// gives `[batch, heads, seq, seq_kv]`.
+    ///
+    /// The output can have two dimensions with the same name, e.g. `[batch, heads, seq, seq]` for
+    /// self-attention scores. Such dimensions can't be addressed by name until they are
+    /// [renamed](Self::rename).
+    ///
+    /// # Panics
+    ///
+    /// If the names of the dimensions don't match, in debug builds.
+    pub fn matmul(self, rhs: Self) -> Self {
+        #[cfg(debug_assertions)]
+        {
+            let lhs_names = self.names.names();
+            let rhs_names = rhs.names.names();
+
+            for dim in 0..D - 2 {
+                if lhs_names[dim] != rhs_names[dim] {
+                    panic!(
+                        "Expected dim '{}' got '{}' at position {dim} of the rhs {}, the lhs \
+                         being {}",
+                        lhs_names[dim], rhs_names[dim], rhs.names, self.names
+                    );
+                }
+            }
+
+            if lhs_names[D - 1] != rhs_names[D - 2] {
+                panic!(
+                    "Expected dim '{}' got '{}' at position {} of the rhs {}, the lhs being {}",
+                    lhs_names[D - 1],
+                    rhs_names[D - 2],
+                    D - 2,
+                    rhs.names,
+                    self.names
+                );
+            }
+        }
+
+        // The output may have the same name twice, e.g. `[batch, heads, seq, seq]` for the
+        // attention scores, which are addressed once renamed.
+        let mut names = self.names;
+        names.names[D - 1] = rhs.names.names[D - 1];
+
+        Self {
+            tensor: self.tensor.matmul(rhs.tensor),
+            names,
+        }
+    }
+}
+
+impl<B: Backend, const D: usize, K: BasicOps<B>> Tensor<B, D, K> {
+    /// Attach the names of the dimensions to the tensor, see [DynNamedTensor].
+    pub fn with_dim_names(self, names: [&'static str; D]) -> DynNamedTensor<B, D, K> {
+        DynNamedTensor::new(self, names)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_find_dims_by_name() {
+        let names = DimNames::new(["batch", "seq", "heads", "d_k"]);
+
+        assert_eq!(names.index("heads"), 2);
+        assert_eq!(names.swap(1, 2).names(), &["batch", "heads", "seq", "d_k"]);
+        assert_eq!(
+            names.permute([0, 2, 3, 1]).names(),
+            &["batch", "heads", "d_k", "seq"]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Ambiguous dim 'seq' in [batch, seq, seq]")]
+    fn should_panic_with_ambiguous_dim() {
+        DimNames {
+            names: ["batch", "seq", "seq"],
+        }
+        .index("seq");
+    }
+
+    #[test]
+    #[should_panic(expected = "No dim 'heads' in [batch, seq]")]
+    fn should_panic_with_unknown_dim() {
+        DimNames::new(["batch", "seq"]).index("heads");
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Expected dim 'seq' got 'heads'")]
+    fn should_panic_with_different_dims() {
+        let names = DimNames::new(["batch", "heads", "seq"]);
+
+        names.check(&DimNames::new(["batch", "seq", "heads"]));
+    }
+}
@@ -1,7 +1,9 @@
 mod base;
 mod dims;
+mod dyn_named;
 mod matmul;
 mod swap_dims;
 
 pub use base::*;
 pub use dims::*;
+pub use dyn_named::*;
@@ -35,6 +35,27 @@ pub trait BoolTensorOps<B: Backend> {
     /// The data structure with the tensor's data.
     fn bool_into_data(tensor: BoolTensor<B>) -> impl Future<Output = TensorData> + 'static + Send;
 
+    /// Converts the selected elements of the tensor to a data structure.
+    ///
+    /// Only the selected elements are transferred, which is cheaper than reading the whole
+    /// tensor when inspecting a small part of it.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `ranges` - The ranges to read.
+    ///
+    /// # Returns
+    ///
+    /// The data structure with the selected elements.
+    fn bool_into_data_slice(
+        tensor: BoolTensor<B>,
+        ranges: Vec<Range<usize>>,
+    ) -> impl Future<Output = TensorData> + 'static + Send {
+        // Slicing doesn't copy the data on most backends, so only the selected elements are read
+        Self::bool_into_data(Self::bool_slice(tensor, &ranges))
+    }
+
     /// Creates a tensor from the data structure.
     ///
     /// # Arguments
@@ -83,6 +104,14 @@ pub trait BoolTensorOps<B: Backend> {
     /// Moves the tensor to the device.
     fn bool_to_device(tensor: BoolTensor<B>, device: &Device<B>) -> BoolTensor<B>;
 
+    /// Moves the tensor to the device without blocking the host until the copy is done.
+    ///
+    /// Backends supporting non-blocking copies should override this function, by default the
+    /// tensor is moved with [bool_to_device](BoolTensorOps::bool_to_device).
+    fn bool_to_device_async(tensor: BoolTensor<B>, device: &Device<B>) -> BoolTensor<B> {
+        B::bool_to_device(tensor, device)
+    }
+
     /// Reshapes the tensor.
     ///
     /// # Arguments
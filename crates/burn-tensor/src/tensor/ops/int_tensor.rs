@@ -8,7 +8,10 @@ use alloc::vec::Vec;
 use core::future::Future;
 use core::ops::Range;
 
-use crate::{argsort, sort, sort_with_indices, TensorMetadata};
+use crate::{
+    argsort, bincount, kthvalue_with_indices, scatter_reduce, sort, sort_with_indices, unique,
+    ScatterReduceOptions, TensorMetadata,
+};
 
 /// Int Tensor API for basic and numeric operations, see [tensor](crate::Tensor)
 /// for documentation on each function.
@@ -36,6 +39,27 @@ pub trait IntTensorOps<B: Backend> {
     /// The data structure with the tensor's data.
     fn int_into_data(tensor: IntTensor<B>) -> impl Future<Output = TensorData> + 'static + Send;
 
+    /// Converts the selected elements of the tensor to a data structure.
+    ///
+    /// Only the selected elements are transferred, which is cheaper than reading the whole
+    /// tensor when inspecting a small part of it.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `ranges` - The ranges to read.
+    ///
+    /// # Returns
+    ///
+    /// The data structure with the selected elements.
+    fn int_into_data_slice(
+        tensor: IntTensor<B>,
+        ranges: Vec<Range<usize>>,
+    ) -> impl Future<Output = TensorData> + 'static + Send {
+        // Slicing doesn't copy the data on most backends, so only the selected elements are read
+        Self::int_into_data(Self::int_slice(tensor, &ranges))
+    }
+
     /// Creates a tensor from the data structure.
     ///
     /// # Arguments
@@ -62,6 +86,14 @@ pub trait IntTensorOps<B: Backend> {
     /// Moves the tensor to the given device.
     fn int_to_device(tensor: IntTensor<B>, device: &Device<B>) -> IntTensor<B>;
 
+    /// Moves the tensor to the given device without blocking the host until the copy is done.
+    ///
+    /// Backends supporting non-blocking copies should override this function, by default the
+    /// tensor is moved with [int_to_device](IntTensorOps::int_to_device).
+    fn int_to_device_async(tensor: IntTensor<B>, device: &Device<B>) -> IntTensor<B> {
+        B::int_to_device(tensor, device)
+    }
+
     /// Reshapes the tensor.
     ///
     /// # Arguments
@@ -172,6 +204,30 @@ pub trait IntTensorOps<B: Backend> {
         value: IntTensor<B>,
     ) -> IntTensor<B>;
 
+    /// Scatter the values to the tensor at the given indices, reducing the values scattered to
+    /// the same position.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension to scatter to.
+    /// * `tensor` - The tensor.
+    /// * `indices` - The indices.
+    /// * `values` - The values.
+    /// * `options` - The reduction of the values.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the values reduced.
+    fn int_scatter_reduce(
+        dim: usize,
+        tensor: IntTensor<B>,
+        indices: IntTensor<B>,
+        values: IntTensor<B>,
+        options: ScatterReduceOptions,
+    ) -> IntTensor<B> {
+        scatter_reduce::<B, Int>(dim, tensor, indices, values, options)
+    }
+
     /// Select tensor elements along the given dimension corresponding to the given indices.
     ///
     /// # Arguments
@@ -996,6 +1052,22 @@ pub trait IntTensorOps<B: Backend> {
     ///  The tensor with the given shape and random values.
     fn int_random(shape: Shape, distribution: Distribution, device: &Device<B>) -> IntTensor<B>;
 
+    /// Creates a new int tensor with a random permutation of the integers from `0` to `n - 1`.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The number of integers.
+    /// * `device` - The device to create the tensor on.
+    ///
+    /// # Returns
+    ///
+    /// The tensor of shape `[n]` with the permuted integers.
+    fn int_randperm(n: usize, device: &Device<B>) -> IntTensor<B> {
+        // Sorting uniform random keys gives each permutation the same probability.
+        let keys = B::float_random(Shape::new([n]), Distribution::Default, device);
+        B::float_argsort(keys, 0, false)
+    }
+
     /// Creates a new tensor with values from the given range with the given step size.
     ///
     /// # Arguments
@@ -1186,6 +1258,62 @@ pub trait IntTensorOps<B: Backend> {
         argsort::<B, Int>(tensor, dim, descending)
     }
 
+    /// Find the `k`-th smallest element of the input `tensor` along a given dimension, without
+    /// sorting the whole dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `k` - The zero-based rank of the element to select.
+    /// * `dim` - The axis along which to select.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor except for the size of `dim` which is one,
+    /// where the elements are the `k`-th smallest values, and the corresponding indices along
+    /// `dim`. When the value is repeated, any of its indices can be returned.
+    fn int_kthvalue_with_indices(
+        tensor: IntTensor<B>,
+        k: usize,
+        dim: usize,
+    ) -> (IntTensor<B>, IntTensor<B>) {
+        kthvalue_with_indices::<B, Int>(tensor, k, dim)
+    }
+
+    /// Count the number of occurrences of each value of the input `tensor`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor of shape `[num_elements]`, with non-negative values.
+    /// * `min_length` - The minimum number of bins.
+    ///
+    /// # Returns
+    ///
+    /// A tensor of shape `[max(max_value + 1, min_length)]` with the count of each value.
+    fn int_bincount(tensor: IntTensor<B>, min_length: usize) -> IntTensor<B> {
+        bincount::<B>(tensor, min_length)
+    }
+
+    /// Find the unique values of the input `tensor`, along with the inverse indices and the
+    /// counts.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor of shape `[num_elements]`.
+    /// * `consecutive` - If only the consecutive repeated values are merged, in which case the
+    ///   values are kept in their order. Otherwise, the unique values are sorted.
+    ///
+    /// # Returns
+    ///
+    /// The unique values, the index of the unique value of each element of the input tensor, and
+    /// the number of elements of each unique value.
+    fn int_unique(
+        tensor: IntTensor<B>,
+        consecutive: bool,
+    ) -> (IntTensor<B>, IntTensor<B>, IntTensor<B>) {
+        unique::<B>(tensor, consecutive)
+    }
+
     /// Bitwise AND operation for Int Tensors
     fn bitwise_and(lhs: IntTensor<B>, rhs: IntTensor<B>) -> IntTensor<B>;
 
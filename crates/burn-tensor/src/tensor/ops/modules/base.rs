@@ -1,6 +1,9 @@
 use core::num::NonZeroUsize;
 
-use super::{conv, pool, unfold::unfold4d_using_conv2d};
+use super::{
+    conv, grid_sample, nms, norm, pool, roi, rotary,
+    unfold::{fold4d_using_conv_transpose2d, unfold4d_using_conv2d},
+};
 use crate::{
     backend::Backend,
     ops::{FloatTensor, IntTensor},
@@ -86,6 +89,23 @@ pub struct MaxPool2dWithIndices<B: Backend> {
     pub indices: IntTensor<B>,
 }
 
+/// Gradient computed during the backward pass for each tensor used by [max_pool3d](ModuleOps::max_pool3d).
+#[derive(new)]
+pub struct MaxPool3dBackward<B: Backend> {
+    /// Gradient.
+    pub x_grad: FloatTensor<B>,
+}
+
+/// Results from [max_pool3d](ModuleOps::max_pool3d_with_indices).
+#[derive(new)]
+pub struct MaxPool3dWithIndices<B: Backend> {
+    /// The output tensor.
+    pub output: FloatTensor<B>,
+
+    /// The indices tensor.
+    pub indices: IntTensor<B>,
+}
+
 /// Check that the parameter value is non-zero.
 // NOTE: for now we keep usize but we could refactor the parameters to hold `NonZeroUsize`.
 pub(crate) fn check_nonzero(value: usize, msg: &str) -> usize {
@@ -257,6 +277,148 @@ pub struct InterpolateBackward<B: Backend> {
     pub x_grad: FloatTensor<B>,
 }
 
+/// Algorithm used to sample the input of [grid_sample](ModuleOps::grid_sample).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub enum GridSampleMode {
+    /// Bilinear interpolation of the four closest pixels.
+    #[default]
+    Bilinear,
+
+    /// Value of the closest pixel.
+    Nearest,
+}
+
+/// Values used for the locations of [grid_sample](ModuleOps::grid_sample) outside of the input.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub enum GridSamplePaddingMode {
+    /// Zeros are used outside of the input.
+    #[default]
+    Zeros,
+
+    /// The values of the border of the input are used outside of the input.
+    Border,
+
+    /// The locations outside of the input are reflected by its border, then clamped to the input.
+    Reflection,
+}
+
+/// Options of [grid_sample](ModuleOps::grid_sample).
+#[derive(new, Debug, Clone, Default)]
+pub struct GridSampleOptions {
+    /// Algorithm used to sample the input.
+    pub mode: GridSampleMode,
+
+    /// Values used outside of the input.
+    pub padding_mode: GridSamplePaddingMode,
+
+    /// If `true`, the extrema `-1` and `1` of the grid are the centers of the corner pixels,
+    /// otherwise they are the outer edges of the corner pixels.
+    pub align_corners: bool,
+}
+
+/// Options of [roi_align](ModuleOps::roi_align).
+#[derive(Debug, Clone, PartialEq)]
+pub struct RoiAlignOptions {
+    /// The size `[height, width]` of the output of each region.
+    pub output_size: [usize; 2],
+
+    /// The scale converting the coordinates of the boxes into coordinates of the input, for
+    /// instance `1 / 16` for a feature map with a stride of 16.
+    pub spatial_scale: f32,
+
+    /// The number of sampling points along each dimension of an output bin, zero to use
+    /// `ceil(roi_size / output_size)` points for each region.
+    pub sampling_ratio: usize,
+
+    /// If `true`, the boxes are shifted by half a pixel, so pixel centers are at integer
+    /// coordinates plus `0.5`, which aligns them with the pixels of the input.
+    pub aligned: bool,
+}
+
+impl RoiAlignOptions {
+    /// Constructs a new `RoiAlignOptions`.
+    pub fn new(
+        output_size: [usize; 2],
+        spatial_scale: f32,
+        sampling_ratio: usize,
+        aligned: bool,
+    ) -> Self {
+        Self {
+            output_size: output_size.map(|s| check_nonzero(s, "output size must be non-zero")),
+            spatial_scale,
+            sampling_ratio,
+            aligned,
+        }
+    }
+}
+
+/// Options of [roi_pool](ModuleOps::roi_pool).
+#[derive(Debug, Clone, PartialEq)]
+pub struct RoiPoolOptions {
+    /// The size `[height, width]` of the output of each region.
+    pub output_size: [usize; 2],
+
+    /// The scale converting the coordinates of the boxes into coordinates of the input.
+    pub spatial_scale: f32,
+}
+
+impl RoiPoolOptions {
+    /// Constructs a new `RoiPoolOptions`.
+    pub fn new(output_size: [usize; 2], spatial_scale: f32) -> Self {
+        Self {
+            output_size: output_size.map(|s| check_nonzero(s, "output size must be non-zero")),
+            spatial_scale,
+        }
+    }
+}
+
+/// Options of [nms](ModuleOps::nms).
+#[derive(new, Debug, Clone, PartialEq)]
+pub struct NmsOptions {
+    /// The boxes overlapping a selected box with an intersection over union greater than this
+    /// threshold are suppressed.
+    pub iou_threshold: f32,
+
+    /// The boxes with a score lower than or equal to this threshold are removed, if any.
+    pub score_threshold: Option<f32>,
+
+    /// The maximum number of boxes selected for each batch and class, unlimited if `None`.
+    pub max_output_boxes_per_class: Option<usize>,
+
+    /// If `true`, the boxes are `[x_center, y_center, width, height]`, otherwise they are any
+    /// diagonal pair of corners, such as `[x1, y1, x2, y2]` or `[y1, x1, y2, x2]`.
+    pub center_point_box: bool,
+}
+
+/// Gradient computed during the backward pass for each tensor used by [rms_norm](ModuleOps::rms_norm).
+#[derive(new)]
+pub struct RmsNormBackward<B: Backend> {
+    /// Gradient of the input.
+    pub x_grad: FloatTensor<B>,
+    /// Gradient of the scale.
+    pub gamma_grad: FloatTensor<B>,
+}
+
+/// Gradient computed during the backward pass for each tensor used by [layer_norm](ModuleOps::layer_norm).
+#[derive(new)]
+pub struct LayerNormBackward<B: Backend> {
+    /// Gradient of the input.
+    pub x_grad: FloatTensor<B>,
+    /// Gradient of the scale.
+    pub gamma_grad: FloatTensor<B>,
+    /// Gradient of the bias.
+    pub beta_grad: FloatTensor<B>,
+}
+
+/// Queries and keys rotated by [rotary_qk](ModuleOps::rotary_qk).
+#[derive(new)]
+pub struct RotaryQk<B: Backend> {
+    /// The rotated queries.
+    pub queries: FloatTensor<B>,
+    /// The rotated keys.
+    pub keys: FloatTensor<B>,
+}
+
 /// Module operations trait.
 pub trait ModuleOps<B: Backend> {
     /// Embedding operation.
@@ -587,6 +749,24 @@ pub trait ModuleOps<B: Backend> {
         unfold4d_using_conv2d::<B>(x, kernel_size, options)
     }
 
+    /// Four-dimensional folding, the inverse of [unfolding](ModuleOps::unfold4d) where the values
+    /// of overlapping blocks are summed.
+    ///
+    /// The options are the ones of the unfolding that produced the blocks.
+    ///
+    /// # Shapes
+    ///
+    /// x:      `[batch_size, channels_out * kernel_size_1 * kernel_size_2, number of blocks]`,
+    /// returns: `[batch_size, channels_out, output_size_1, output_size_2]`,
+    fn fold4d(
+        x: FloatTensor<B>,
+        output_size: [usize; 2],
+        kernel_size: [usize; 2],
+        options: UnfoldOptions,
+    ) -> FloatTensor<B> {
+        fold4d_using_conv_transpose2d::<B>(x, output_size, kernel_size, options)
+    }
+
     /// One dimensional avg pooling.
     ///
     /// # Shapes
@@ -746,6 +926,91 @@ pub trait ModuleOps<B: Backend> {
         indices: IntTensor<B>,
     ) -> MaxPool2dBackward<B>;
 
+    /// Three dimensional avg pooling.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, depth, height, width],
+    fn avg_pool3d(
+        x: FloatTensor<B>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        count_include_pad: bool,
+    ) -> FloatTensor<B> {
+        pool::avg_pool3d_from_2d::<B>(x, kernel_size, stride, padding, count_include_pad)
+    }
+    /// Backward pass for the [avg pooling 3d](ModuleOps::avg_pool3d) operation.
+    fn avg_pool3d_backward(
+        x: FloatTensor<B>,
+        grad: FloatTensor<B>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        count_include_pad: bool,
+    ) -> FloatTensor<B> {
+        pool::avg_pool3d_backward_from_2d::<B>(
+            x,
+            grad,
+            kernel_size,
+            stride,
+            padding,
+            count_include_pad,
+        )
+    }
+
+    /// Three dimensional max pooling.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, depth, height, width],
+    fn max_pool3d(
+        x: FloatTensor<B>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+    ) -> FloatTensor<B> {
+        pool::max_pool3d_from_2d::<B>(x, kernel_size, stride, padding, dilation)
+    }
+
+    /// Three dimensional max pooling with indices.
+    ///
+    /// The indices are flattened over the depth, height and width of the input.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, depth, height, width],
+    fn max_pool3d_with_indices(
+        x: FloatTensor<B>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+    ) -> MaxPool3dWithIndices<B> {
+        pool::max_pool3d_with_indices_from_2d::<B>(x, kernel_size, stride, padding, dilation)
+    }
+    /// Backward pass for the [max pooling 3d](ModuleOps::max_pool3d_with_indices) operation.
+    fn max_pool3d_with_indices_backward(
+        x: FloatTensor<B>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+        output_grad: FloatTensor<B>,
+        indices: IntTensor<B>,
+    ) -> MaxPool3dBackward<B> {
+        pool::max_pool3d_with_indices_backward_using_scatter::<B>(
+            x,
+            kernel_size,
+            stride,
+            padding,
+            dilation,
+            output_grad,
+            indices,
+        )
+    }
+
     /// Down/up samples the input.
     ///
     /// # Shapes
@@ -764,6 +1029,169 @@ pub trait ModuleOps<B: Backend> {
         output_size: [usize; 2],
         options: InterpolateOptions,
     ) -> FloatTensor<B>;
+
+    /// Samples the input at the locations of the grid.
+    ///
+    /// The locations are normalized, `(-1, -1)` being the top-left corner of the input and
+    /// `(1, 1)` its bottom-right corner, with the x coordinate first.
+    ///
+    /// # Shapes
+    ///
+    /// x: `[batch_size, channels, height_in, width_in]`,
+    /// grid: `[batch_size, height_out, width_out, 2]`,
+    /// output: `[batch_size, channels, height_out, width_out]`,
+    fn grid_sample(
+        x: FloatTensor<B>,
+        grid: FloatTensor<B>,
+        options: GridSampleOptions,
+    ) -> FloatTensor<B> {
+        grid_sample::grid_sample_using_gather::<B>(x, grid, options)
+    }
+
+    /// Generates the sampling grid of the affine transformations, to be used with
+    /// [grid_sample](ModuleOps::grid_sample).
+    ///
+    /// Each location of the output grid is the transformation of the normalized coordinates
+    /// `(x, y, 1)` of the corresponding location of the output, with the same convention as
+    /// [grid_sample](ModuleOps::grid_sample) for `align_corners`.
+    ///
+    /// # Shapes
+    ///
+    /// theta: `[batch_size, 2, 3]`,
+    /// size: `[batch_size, channels, height_out, width_out]`,
+    /// output: `[batch_size, height_out, width_out, 2]`,
+    fn affine_grid(theta: FloatTensor<B>, size: [usize; 4], align_corners: bool) -> FloatTensor<B> {
+        grid_sample::affine_grid_using_matmul::<B>(theta, size, align_corners)
+    }
+
+    /// Pools each region of interest of the input into a fixed size output, by averaging the
+    /// bilinear interpolation of regularly sampled points of each output bin (RoIAlign).
+    ///
+    /// Each box is `[batch_index, x1, y1, x2, y2]`, in the coordinates of the image the input is
+    /// computed from, see [spatial_scale](RoiAlignOptions::spatial_scale).
+    ///
+    /// # Shapes
+    ///
+    /// x: `[batch_size, channels, height, width]`,
+    /// boxes: `[num_boxes, 5]`,
+    /// output: `[num_boxes, channels, output_height, output_width]`,
+    fn roi_align(
+        x: FloatTensor<B>,
+        boxes: FloatTensor<B>,
+        options: RoiAlignOptions,
+    ) -> FloatTensor<B> {
+        roi::roi_align_using_select::<B>(x, boxes, options)
+    }
+
+    /// Pools each region of interest of the input into a fixed size output, by taking the
+    /// maximum of each output bin, whose boundaries are rounded to the pixels of the input
+    /// (RoIPool).
+    ///
+    /// Each box is `[batch_index, x1, y1, x2, y2]`, in the coordinates of the image the input is
+    /// computed from, see [spatial_scale](RoiPoolOptions::spatial_scale).
+    ///
+    /// # Shapes
+    ///
+    /// x: `[batch_size, channels, height, width]`,
+    /// boxes: `[num_boxes, 5]`,
+    /// output: `[num_boxes, channels, output_height, output_width]`,
+    fn roi_pool(
+        x: FloatTensor<B>,
+        boxes: FloatTensor<B>,
+        options: RoiPoolOptions,
+    ) -> FloatTensor<B> {
+        roi::roi_pool_using_segment_max::<B>(x, boxes, options)
+    }
+
+    /// Greedily selects the boxes with the highest scores, suppressing the boxes overlapping an
+    /// already selected box, independently for each batch and class (non-maximum suppression).
+    ///
+    /// The selected boxes are returned as `[batch_index, class_index, box_index]`, ordered by
+    /// batch, class and decreasing score.
+    ///
+    /// # Shapes
+    ///
+    /// boxes: `[batch_size, num_boxes, 4]`,
+    /// scores: `[batch_size, num_classes, num_boxes]`,
+    /// output: `[num_selected, 3]`,
+    fn nms(boxes: FloatTensor<B>, scores: FloatTensor<B>, options: NmsOptions) -> IntTensor<B> {
+        nms::nms_on_host::<B>(boxes, scores, options)
+    }
+
+    /// Root mean square normalization over the last dimension.
+    ///
+    /// `y = x / sqrt(mean(x^2) + epsilon) * gamma`
+    ///
+    /// # Shapes
+    ///
+    /// x:     `[..., d_model]`,
+    /// gamma: `[d_model]`,
+    fn rms_norm(x: FloatTensor<B>, gamma: FloatTensor<B>, epsilon: f64) -> FloatTensor<B> {
+        norm::rms_norm::<B>(x, gamma, epsilon)
+    }
+
+    /// Backward pass for the [rms norm](ModuleOps::rms_norm) operation.
+    fn rms_norm_backward(
+        x: FloatTensor<B>,
+        gamma: FloatTensor<B>,
+        output_grad: FloatTensor<B>,
+        epsilon: f64,
+    ) -> RmsNormBackward<B> {
+        norm::rms_norm_backward::<B>(x, gamma, output_grad, epsilon)
+    }
+
+    /// Layer normalization over the last dimension.
+    ///
+    /// `y = (x - mean(x)) / sqrt(var(x) + epsilon) * gamma + beta`
+    ///
+    /// # Shapes
+    ///
+    /// x:     `[..., d_model]`,
+    /// gamma: `[d_model]`,
+    /// beta:  `[d_model]`,
+    fn layer_norm(
+        x: FloatTensor<B>,
+        gamma: FloatTensor<B>,
+        beta: Option<FloatTensor<B>>,
+        epsilon: f64,
+    ) -> FloatTensor<B> {
+        norm::layer_norm::<B>(x, gamma, beta, epsilon)
+    }
+
+    /// Backward pass for the [layer norm](ModuleOps::layer_norm) operation.
+    fn layer_norm_backward(
+        x: FloatTensor<B>,
+        gamma: FloatTensor<B>,
+        output_grad: FloatTensor<B>,
+        epsilon: f64,
+    ) -> LayerNormBackward<B> {
+        norm::layer_norm_backward::<B>(x, gamma, output_grad, epsilon)
+    }
+
+    /// Applies rotary position embeddings to the queries and keys of an attention layer, and
+    /// swaps their heads with the sequence so that they are returned in the layout expected by
+    /// the attention.
+    ///
+    /// The frequencies hold the cosine and the sine of the angle of each position, repeated for
+    /// both features of each rotated pair. Only the first `rotary_dim` features of each head are
+    /// rotated, the other ones are passed through. The positions start at `start`, the number of
+    /// positions already processed when generating with a cache.
+    ///
+    /// # Shapes
+    ///
+    /// queries:     `[batch_size, seq_length, n_heads, d_head]`,
+    /// keys:        `[batch_size, seq_length, n_heads_kv, d_head]`,
+    /// frequencies: `[max_seq_length, rotary_dim, 2]`,
+    /// output:      `[batch_size, n_heads, seq_length, d_head]` and
+    ///              `[batch_size, n_heads_kv, seq_length, d_head]`,
+    fn rotary_qk(
+        queries: FloatTensor<B>,
+        keys: FloatTensor<B>,
+        frequencies: FloatTensor<B>,
+        start: usize,
+    ) -> RotaryQk<B> {
+        rotary::rotary_qk::<B>(queries, keys, frequencies, start)
+    }
 }
 
 #[cfg(test)]
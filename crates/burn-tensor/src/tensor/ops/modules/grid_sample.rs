@@ -0,0 +1,235 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::backend::Backend;
+use crate::ops::FloatTensor;
+use crate::{ElementConversion, Shape, TensorData, TensorMetadata};
+
+use super::{GridSampleMode, GridSampleOptions, GridSamplePaddingMode};
+
+/// Samples the input at the locations of the grid by gathering the neighboring pixels of each
+/// location, see [grid_sample](crate::ops::ModuleOps::grid_sample).
+///
+/// Only differentiable operations are used, so the gradients with respect to the input and the
+/// grid are computed by the backward passes of the gather and the interpolation weights.
+pub(crate) fn grid_sample_using_gather<B: Backend>(
+    x: FloatTensor<B>,
+    grid: FloatTensor<B>,
+    options: GridSampleOptions,
+) -> FloatTensor<B> {
+    let [batch_size, channels, height, width] = x.shape().dims();
+    let [_, height_out, width_out, _] = grid.shape().dims();
+    let num_points = height_out * width_out;
+
+    let x = B::float_reshape(x, Shape::new([batch_size, channels, height * width]));
+    let grid = B::float_reshape(grid, Shape::new([batch_size, num_points, 2]));
+    let coordinate = |index: usize, size: usize| {
+        let coordinate = B::float_slice(
+            grid.clone(),
+            &[0..batch_size, 0..num_points, index..index + 1],
+        );
+        let coordinate = B::float_reshape(coordinate, Shape::new([batch_size, 1, num_points]));
+
+        source_coordinate::<B>(coordinate, size, &options)
+    };
+    let coord_x = coordinate(0, width);
+    let coord_y = coordinate(1, height);
+
+    let output = match options.mode {
+        GridSampleMode::Nearest => {
+            let coord_x = B::float_round(coord_x);
+            let coord_y = B::float_round(coord_y);
+            let weight = B::float_ones(
+                Shape::new([batch_size, 1, num_points]),
+                &B::float_device(&x),
+            );
+
+            sample::<B>(x, coord_x, coord_y, weight, [height, width])
+        }
+        GridSampleMode::Bilinear => {
+            let x0 = B::float_floor(coord_x.clone());
+            let y0 = B::float_floor(coord_y.clone());
+            let x1 = B::float_add_scalar(x0.clone(), 1.elem());
+            let y1 = B::float_add_scalar(y0.clone(), 1.elem());
+
+            let weight_x1 = B::float_sub(coord_x.clone(), x0.clone());
+            let weight_x0 = B::float_sub(x1.clone(), coord_x);
+            let weight_y1 = B::float_sub(coord_y.clone(), y0.clone());
+            let weight_y0 = B::float_sub(y1.clone(), coord_y);
+
+            let corners = [
+                (x0.clone(), y0.clone(), weight_x0.clone(), weight_y0.clone()),
+                (x1.clone(), y0, weight_x1.clone(), weight_y0),
+                (x0, y1.clone(), weight_x0, weight_y1.clone()),
+                (x1, y1, weight_x1, weight_y1),
+            ];
+
+            corners
+                .into_iter()
+                .map(|(corner_x, corner_y, weight_x, weight_y)| {
+                    let weight = B::float_mul(weight_x, weight_y);
+                    sample::<B>(x.clone(), corner_x, corner_y, weight, [height, width])
+                })
+                .reduce(B::float_add)
+                .unwrap()
+        }
+    };
+
+    B::float_reshape(
+        output,
+        Shape::new([batch_size, channels, height_out, width_out]),
+    )
+}
+
+/// Generates the [affine_grid](crate::ops::ModuleOps::affine_grid) with a matrix multiplication
+/// of the normalized coordinates of the output by the transformations.
+///
+/// The gradient with respect to the transformations is computed by the backward pass of the
+/// matrix multiplication.
+pub(crate) fn affine_grid_using_matmul<B: Backend>(
+    theta: FloatTensor<B>,
+    [batch_size, _, height, width]: [usize; 4],
+    align_corners: bool,
+) -> FloatTensor<B> {
+    let num_points = height * width;
+    let mut base = Vec::with_capacity(num_points * 3);
+
+    for y in normalized_coordinates(height, align_corners) {
+        for x in normalized_coordinates(width, align_corners) {
+            base.extend([x, y, 1.0]);
+        }
+    }
+
+    let base = B::float_from_data(
+        TensorData::new(base, [1, num_points, 3]).convert::<B::FloatElem>(),
+        &B::float_device(&theta),
+    );
+    let base = B::float_expand(base, Shape::new([batch_size, num_points, 3]));
+    let grid = B::float_matmul(base, B::float_swap_dims(theta, 1, 2));
+
+    B::float_reshape(grid, Shape::new([batch_size, height, width, 2]))
+}
+
+/// The normalized coordinates of the pixels along a dimension of the given size.
+fn normalized_coordinates(size: usize, align_corners: bool) -> Vec<f32> {
+    if size <= 1 {
+        return vec![0.0];
+    }
+
+    // The centers of the corner pixels are at `-1` and `1` when the corners are aligned,
+    // otherwise the outer edges of the corner pixels are.
+    let scale = match align_corners {
+        true => 1.0,
+        false => (size - 1) as f32 / size as f32,
+    };
+
+    (0..size)
+        .map(|index| (2.0 * index as f32 / (size - 1) as f32 - 1.0) * scale)
+        .collect()
+}
+
+/// Converts the normalized coordinates of the grid into coordinates in the input, in pixels.
+fn source_coordinate<B: Backend>(
+    coordinate: FloatTensor<B>,
+    size: usize,
+    options: &GridSampleOptions,
+) -> FloatTensor<B> {
+    let size = size as f64;
+
+    // From [-1, 1] to [0, size - 1] when the corners are aligned, otherwise to [-0.5, size - 0.5].
+    let scale = match options.align_corners {
+        true => (size - 1.0) / 2.0,
+        false => size / 2.0,
+    };
+    let coordinate = B::float_add_scalar(
+        B::float_mul_scalar(coordinate, scale.elem()),
+        ((size - 1.0) / 2.0).elem(),
+    );
+
+    match options.padding_mode {
+        GridSamplePaddingMode::Zeros => coordinate,
+        GridSamplePaddingMode::Border => B::float_clamp(coordinate, 0.elem(), (size - 1.0).elem()),
+        GridSamplePaddingMode::Reflection => {
+            // Reflected by the centers of the border pixels when the corners are aligned,
+            // otherwise by their outer edges.
+            let coordinate = match options.align_corners {
+                true => reflect_coordinate::<B>(coordinate, 0.0, size - 1.0),
+                false => reflect_coordinate::<B>(coordinate, -0.5, size),
+            };
+
+            B::float_clamp(coordinate, 0.elem(), (size - 1.0).elem())
+        }
+    }
+}
+
+/// Reflects the coordinate into `[min, min + span]`, as many times as needed.
+fn reflect_coordinate<B: Backend>(
+    coordinate: FloatTensor<B>,
+    min: f64,
+    span: f64,
+) -> FloatTensor<B> {
+    if span <= 0.0 {
+        return B::float_zeros(coordinate.shape(), &B::float_device(&coordinate));
+    }
+
+    let distance = B::float_abs(B::float_sub_scalar(coordinate, min.elem()));
+    let flips = B::float_floor(B::float_div_scalar(distance.clone(), span.elem()));
+    let extra = B::float_sub(distance, B::float_mul_scalar(flips.clone(), span.elem()));
+
+    // An odd number of reflections flips the direction of the coordinate.
+    let odd = B::float_equal_elem(B::float_remainder_scalar(flips, 2.elem()), 1.elem());
+    let coordinate = B::float_add_scalar(extra.clone(), min.elem());
+    let flipped = B::float_add_scalar(B::float_neg(extra), (min + span).elem());
+
+    B::float_mask_where(coordinate, odd, flipped)
+}
+
+/// Gathers the pixels at the given integer coordinates, scaled by the weights.
+///
+/// The weights of the coordinates outside of the input are zero.
+fn sample<B: Backend>(
+    x: FloatTensor<B>,
+    coord_x: FloatTensor<B>,
+    coord_y: FloatTensor<B>,
+    weight: FloatTensor<B>,
+    [height, width]: [usize; 2],
+) -> FloatTensor<B> {
+    let [batch_size, channels, _] = x.shape().dims();
+    let [_, _, num_points] = coord_x.shape().dims();
+
+    let weight = mask_outside::<B>(weight, coord_x.clone(), width);
+    let weight = mask_outside::<B>(weight, coord_y.clone(), height);
+
+    let coord_x = B::float_into_int(B::float_clamp(
+        coord_x,
+        0.elem(),
+        (width as f64 - 1.0).elem(),
+    ));
+    let coord_y = B::float_into_int(B::float_clamp(
+        coord_y,
+        0.elem(),
+        (height as f64 - 1.0).elem(),
+    ));
+    let indices = B::int_add(B::int_mul_scalar(coord_y, (width as i64).elem()), coord_x);
+    let indices = B::int_expand(indices, Shape::new([batch_size, channels, num_points]));
+
+    B::float_mul(B::float_gather(2, x, indices), weight)
+}
+
+fn mask_outside<B: Backend>(
+    weight: FloatTensor<B>,
+    coordinate: FloatTensor<B>,
+    size: usize,
+) -> FloatTensor<B> {
+    let weight = B::float_mask_fill(
+        weight,
+        B::float_lower_elem(coordinate.clone(), 0.elem()),
+        0.elem(),
+    );
+
+    B::float_mask_fill(
+        weight,
+        B::float_greater_elem(coordinate, (size as f64 - 1.0).elem()),
+        0.elem(),
+    )
+}
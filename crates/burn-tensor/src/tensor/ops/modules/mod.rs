@@ -3,11 +3,23 @@ pub mod conv;
 
 /// Module with cat operation
 pub(crate) mod cat;
+/// Module with grid sampling operations.
+pub(crate) mod grid_sample;
+/// Module with non-maximum suppression operation.
+pub(crate) mod nms;
 /// Module with repeat operation
 pub(crate) mod repeat_dim;
+/// Module with region of interest pooling operations.
+pub(crate) mod roi;
 /// Module with unfold operations.
 pub(crate) mod unfold;
 
+/// Module with normalization operations.
+pub(crate) mod norm;
+
+/// Module with rotary position embedding operations.
+pub(crate) mod rotary;
+
 /// Module with pooling operations.
 pub mod pool;
 
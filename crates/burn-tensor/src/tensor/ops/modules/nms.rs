@@ -0,0 +1,126 @@
+use alloc::vec::Vec;
+
+use crate::backend::Backend;
+use crate::ops::{FloatTensor, IntTensor};
+use crate::{TensorData, TensorMetadata};
+
+use super::NmsOptions;
+
+/// Computes [nms](crate::ops::ModuleOps::nms) on the host, since the greedy selection is
+/// sequential and the number of selected boxes is data dependent.
+pub(crate) fn nms_on_host<B: Backend>(
+    boxes: FloatTensor<B>,
+    scores: FloatTensor<B>,
+    options: NmsOptions,
+) -> IntTensor<B> {
+    let device = B::float_device(&boxes);
+    let [batch_size, num_classes, num_boxes] = scores.shape().dims();
+
+    let boxes = read_values::<B>(boxes)
+        .chunks_exact(4)
+        .map(|coordinates| corners(coordinates, options.center_point_box))
+        .collect::<Vec<_>>();
+    let scores = read_values::<B>(scores);
+
+    let mut selected = Vec::new();
+
+    for batch in 0..batch_size {
+        let boxes = &boxes[batch * num_boxes..(batch + 1) * num_boxes];
+
+        for class in 0..num_classes {
+            let start = (batch * num_classes + class) * num_boxes;
+            let scores = &scores[start..start + num_boxes];
+
+            for index in select(boxes, scores, &options) {
+                selected.extend([batch as i64, class as i64, index as i64]);
+            }
+        }
+    }
+
+    let num_selected = selected.len() / 3;
+
+    B::int_from_data(
+        TensorData::new(selected, [num_selected, 3]).convert::<B::IntElem>(),
+        &device,
+    )
+}
+
+/// The indices of the boxes selected for a batch and a class, by decreasing score.
+fn select(boxes: &[[f32; 4]], scores: &[f32], options: &NmsOptions) -> Vec<usize> {
+    let mut candidates = (0..boxes.len())
+        .filter(|&index| match options.score_threshold {
+            Some(threshold) => scores[index] > threshold,
+            None => true,
+        })
+        .collect::<Vec<_>>();
+
+    // The sort is stable, so the boxes with equal scores keep their order.
+    candidates.sort_by(|&a, &b| scores[b].total_cmp(&scores[a]));
+
+    let max_selected = options.max_output_boxes_per_class.unwrap_or(usize::MAX);
+    let mut selected: Vec<usize> = Vec::new();
+
+    for candidate in candidates {
+        if selected.len() >= max_selected {
+            break;
+        }
+
+        let suppressed = selected.iter().any(|&index| {
+            intersection_over_union(&boxes[index], &boxes[candidate]) > options.iou_threshold
+        });
+
+        if !suppressed {
+            selected.push(candidate);
+        }
+    }
+
+    selected
+}
+
+/// The corners `[min_0, min_1, max_0, max_1]` of a box.
+fn corners(coordinates: &[f32], center_point_box: bool) -> [f32; 4] {
+    if center_point_box {
+        let [center_x, center_y, width, height] = [
+            coordinates[0],
+            coordinates[1],
+            coordinates[2] / 2.0,
+            coordinates[3] / 2.0,
+        ];
+
+        return [
+            center_x - width,
+            center_y - height,
+            center_x + width,
+            center_y + height,
+        ];
+    }
+
+    [
+        coordinates[0].min(coordinates[2]),
+        coordinates[1].min(coordinates[3]),
+        coordinates[0].max(coordinates[2]),
+        coordinates[1].max(coordinates[3]),
+    ]
+}
+
+fn intersection_over_union(lhs: &[f32; 4], rhs: &[f32; 4]) -> f32 {
+    let area = |corners: &[f32; 4]| (corners[2] - corners[0]) * (corners[3] - corners[1]);
+
+    let intersection_0 = (lhs[2].min(rhs[2]) - lhs[0].max(rhs[0])).max(0.0);
+    let intersection_1 = (lhs[3].min(rhs[3]) - lhs[1].max(rhs[1])).max(0.0);
+    let intersection = intersection_0 * intersection_1;
+    let union = area(lhs) + area(rhs) - intersection;
+
+    if union <= 0.0 {
+        return 0.0;
+    }
+
+    intersection / union
+}
+
+fn read_values<B: Backend>(tensor: FloatTensor<B>) -> Vec<f32> {
+    crate::try_read_sync(B::float_into_data(tensor))
+        .expect("Failed to read the tensor synchronously, which is required by the fallback.")
+        .iter::<f32>()
+        .collect()
+}
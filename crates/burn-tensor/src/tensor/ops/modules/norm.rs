@@ -0,0 +1,153 @@
+use crate::{
+    backend::Backend, ops::FloatTensor, ElementConversion, FloatDType, Shape, TensorMetadata,
+};
+
+use super::{LayerNormBackward, RmsNormBackward};
+
+pub(crate) fn rms_norm<B: Backend>(
+    x: FloatTensor<B>,
+    gamma: FloatTensor<B>,
+    epsilon: f64,
+) -> FloatTensor<B> {
+    let shape = x.shape();
+    let x = reshape_rows::<B>(x);
+    let rstd = rms_rstd::<B>(x.clone(), epsilon);
+
+    let output = B::float_mul(B::float_mul(x, rstd), reshape_row::<B>(gamma));
+
+    B::float_reshape(output, shape)
+}
+
+pub(crate) fn rms_norm_backward<B: Backend>(
+    x: FloatTensor<B>,
+    gamma: FloatTensor<B>,
+    output_grad: FloatTensor<B>,
+    epsilon: f64,
+) -> RmsNormBackward<B> {
+    let shape = x.shape();
+    let gamma_shape = gamma.shape();
+    let x = reshape_rows::<B>(x);
+    let output_grad = reshape_rows::<B>(output_grad);
+    let rstd = rms_rstd::<B>(x.clone(), epsilon);
+
+    let x_normalized = B::float_mul(x, rstd.clone());
+    let grad_normalized = B::float_mul(output_grad.clone(), reshape_row::<B>(gamma));
+
+    // x_grad = rstd * (grad_normalized - x_normalized * mean(grad_normalized * x_normalized))
+    let projection = B::float_mean_dim(
+        B::float_mul(grad_normalized.clone(), x_normalized.clone()),
+        1,
+    );
+    let x_grad = B::float_mul(
+        B::float_sub(
+            grad_normalized,
+            B::float_mul(x_normalized.clone(), projection),
+        ),
+        rstd,
+    );
+    let gamma_grad = B::float_sum_dim(B::float_mul(output_grad, x_normalized), 0);
+
+    RmsNormBackward::new(
+        B::float_reshape(x_grad, shape),
+        B::float_reshape(gamma_grad, gamma_shape),
+    )
+}
+
+pub(crate) fn layer_norm<B: Backend>(
+    x: FloatTensor<B>,
+    gamma: FloatTensor<B>,
+    beta: Option<FloatTensor<B>>,
+    epsilon: f64,
+) -> FloatTensor<B> {
+    let shape = x.shape();
+    let x = reshape_rows::<B>(x);
+    let x_normalized = layer_normalize::<B>(x, epsilon).0;
+
+    let output = B::float_mul(x_normalized, reshape_row::<B>(gamma));
+    let output = match beta {
+        Some(beta) => B::float_add(output, reshape_row::<B>(beta)),
+        None => output,
+    };
+
+    B::float_reshape(output, shape)
+}
+
+pub(crate) fn layer_norm_backward<B: Backend>(
+    x: FloatTensor<B>,
+    gamma: FloatTensor<B>,
+    output_grad: FloatTensor<B>,
+    epsilon: f64,
+) -> LayerNormBackward<B> {
+    let shape = x.shape();
+    let gamma_shape = gamma.shape();
+    let x = reshape_rows::<B>(x);
+    let output_grad = reshape_rows::<B>(output_grad);
+    let (x_normalized, rstd) = layer_normalize::<B>(x, epsilon);
+
+    let grad_normalized = B::float_mul(output_grad.clone(), reshape_row::<B>(gamma));
+
+    // x_grad = rstd * (grad_normalized - mean(grad_normalized)
+    //                  - x_normalized * mean(grad_normalized * x_normalized))
+    let grad_mean = B::float_mean_dim(grad_normalized.clone(), 1);
+    let projection = B::float_mean_dim(
+        B::float_mul(grad_normalized.clone(), x_normalized.clone()),
+        1,
+    );
+    let x_grad = B::float_sub(
+        B::float_sub(grad_normalized, grad_mean),
+        B::float_mul(x_normalized.clone(), projection),
+    );
+    let x_grad = B::float_mul(x_grad, rstd);
+    let gamma_grad = B::float_sum_dim(B::float_mul(output_grad.clone(), x_normalized), 0);
+    let beta_grad = B::float_sum_dim(output_grad, 0);
+
+    LayerNormBackward::new(
+        B::float_reshape(x_grad, shape),
+        B::float_reshape(gamma_grad, gamma_shape.clone()),
+        B::float_reshape(beta_grad, gamma_shape),
+    )
+}
+
+/// Reshapes the tensor to `[num_rows, d_model]`, normalizing along the last dimension.
+fn reshape_rows<B: Backend>(tensor: FloatTensor<B>) -> FloatTensor<B> {
+    let shape = tensor.shape();
+    let d_model = shape.dims[shape.num_dims() - 1];
+    let num_rows = shape.num_elements() / d_model;
+
+    B::float_reshape(tensor, Shape::new([num_rows, d_model]))
+}
+
+/// Reshapes the affine parameter to `[1, d_model]`.
+fn reshape_row<B: Backend>(tensor: FloatTensor<B>) -> FloatTensor<B> {
+    let [d_model] = tensor.shape().dims();
+
+    B::float_reshape(tensor, Shape::new([1, d_model]))
+}
+
+/// The reciprocal of the root mean square of each row, computed in full precision.
+fn rms_rstd<B: Backend>(x: FloatTensor<B>, epsilon: f64) -> FloatTensor<B> {
+    let dtype = x.dtype();
+    let x = B::float_cast(x, FloatDType::F32);
+
+    let mean_square = B::float_mean_dim(B::float_powf_scalar(x, 2.0), 1);
+    let rstd = B::float_recip(B::float_sqrt(B::float_add_scalar(
+        mean_square,
+        epsilon.elem(),
+    )));
+
+    B::float_cast(rstd, dtype.into())
+}
+
+/// Returns the normalized rows and the reciprocal of their standard deviation.
+fn layer_normalize<B: Backend>(
+    x: FloatTensor<B>,
+    epsilon: f64,
+) -> (FloatTensor<B>, FloatTensor<B>) {
+    let mean = B::float_mean_dim(x.clone(), 1);
+    let centered = B::float_sub(x, mean);
+
+    let var = B::float_mean_dim(B::float_powf_scalar(centered.clone(), 2.0), 1);
+    let rstd = B::float_recip(B::float_sqrt(B::float_add_scalar(var, epsilon.elem())));
+
+    (B::float_mul(centered, rstd.clone()), rstd)
+}
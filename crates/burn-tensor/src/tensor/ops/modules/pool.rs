@@ -1,10 +1,10 @@
 use crate::{
     backend::Backend,
     ops::{FloatTensor, IntTensor},
-    Shape, TensorMetadata,
+    ElementConversion, Shape, TensorMetadata,
 };
 
-use super::{MaxPool1dBackward, MaxPool1dWithIndices};
+use super::{MaxPool1dBackward, MaxPool1dWithIndices, MaxPool3dBackward, MaxPool3dWithIndices};
 
 pub(crate) fn avg_pool1d_from_2d<B: Backend>(
     x: FloatTensor<B>,
@@ -165,3 +165,232 @@ pub(crate) fn max_pool1d_with_indices_backward_from_2d<B: Backend>(
         Shape::from([batch_size, channels, length_in]),
     ))
 }
+
+// The 3D pooling operations are separable: the height and width are pooled first, with the depth
+// folded in the channels, then the depth is pooled with the height and width flattened. The number
+// of elements in the padding also factorizes per dimension, so averaging without the padding is
+// still exact.
+
+pub(crate) fn avg_pool3d_from_2d<B: Backend>(
+    x: FloatTensor<B>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    count_include_pad: bool,
+) -> FloatTensor<B> {
+    let [batch_size, channels, depth, height, width] = x.shape().dims();
+
+    let x = B::float_reshape(
+        x,
+        Shape::from([batch_size, channels * depth, height, width]),
+    );
+    let x = B::avg_pool2d(
+        x,
+        [kernel_size[1], kernel_size[2]],
+        [stride[1], stride[2]],
+        [padding[1], padding[2]],
+        count_include_pad,
+    );
+    let [_, _, height_out, width_out] = x.shape().dims();
+
+    let x = B::float_reshape(
+        x,
+        Shape::from([batch_size, channels, depth, height_out * width_out]),
+    );
+    let x = B::avg_pool2d(
+        x,
+        [kernel_size[0], 1],
+        [stride[0], 1],
+        [padding[0], 0],
+        count_include_pad,
+    );
+    let [_, _, depth_out, _] = x.shape().dims();
+
+    B::float_reshape(
+        x,
+        Shape::from([batch_size, channels, depth_out, height_out, width_out]),
+    )
+}
+
+pub(crate) fn avg_pool3d_backward_from_2d<B: Backend>(
+    x: FloatTensor<B>,
+    grad: FloatTensor<B>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    count_include_pad: bool,
+) -> FloatTensor<B> {
+    let [batch_size, channels, depth, height, width] = x.shape().dims();
+    let [_, _, depth_out, height_out, width_out] = grad.shape().dims();
+    let device = B::float_device(&x);
+
+    // Only the shape of the intermediate tensor, pooled over the height and width, is needed.
+    let x_depth = B::float_empty(
+        Shape::from([batch_size, channels, depth, height_out * width_out]),
+        &device,
+    );
+    let grad = B::float_reshape(
+        grad,
+        Shape::from([batch_size, channels, depth_out, height_out * width_out]),
+    );
+    let grad = B::avg_pool2d_backward(
+        x_depth,
+        grad,
+        [kernel_size[0], 1],
+        [stride[0], 1],
+        [padding[0], 0],
+        count_include_pad,
+    );
+
+    let x = B::float_reshape(
+        x,
+        Shape::from([batch_size, channels * depth, height, width]),
+    );
+    let grad = B::float_reshape(
+        grad,
+        Shape::from([batch_size, channels * depth, height_out, width_out]),
+    );
+    let grad = B::avg_pool2d_backward(
+        x,
+        grad,
+        [kernel_size[1], kernel_size[2]],
+        [stride[1], stride[2]],
+        [padding[1], padding[2]],
+        count_include_pad,
+    );
+
+    B::float_reshape(
+        grad,
+        Shape::from([batch_size, channels, depth, height, width]),
+    )
+}
+
+pub(crate) fn max_pool3d_from_2d<B: Backend>(
+    x: FloatTensor<B>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    dilation: [usize; 3],
+) -> FloatTensor<B> {
+    let [batch_size, channels, depth, height, width] = x.shape().dims();
+
+    let x = B::float_reshape(
+        x,
+        Shape::from([batch_size, channels * depth, height, width]),
+    );
+    let x = B::max_pool2d(
+        x,
+        [kernel_size[1], kernel_size[2]],
+        [stride[1], stride[2]],
+        [padding[1], padding[2]],
+        [dilation[1], dilation[2]],
+    );
+    let [_, _, height_out, width_out] = x.shape().dims();
+
+    let x = B::float_reshape(
+        x,
+        Shape::from([batch_size, channels, depth, height_out * width_out]),
+    );
+    let x = B::max_pool2d(
+        x,
+        [kernel_size[0], 1],
+        [stride[0], 1],
+        [padding[0], 0],
+        [dilation[0], 1],
+    );
+    let [_, _, depth_out, _] = x.shape().dims();
+
+    B::float_reshape(
+        x,
+        Shape::from([batch_size, channels, depth_out, height_out, width_out]),
+    )
+}
+
+pub(crate) fn max_pool3d_with_indices_from_2d<B: Backend>(
+    x: FloatTensor<B>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    dilation: [usize; 3],
+) -> MaxPool3dWithIndices<B> {
+    let [batch_size, channels, depth, height, width] = x.shape().dims();
+
+    let x = B::float_reshape(
+        x,
+        Shape::from([batch_size, channels * depth, height, width]),
+    );
+    let x = B::max_pool2d_with_indices(
+        x,
+        [kernel_size[1], kernel_size[2]],
+        [stride[1], stride[2]],
+        [padding[1], padding[2]],
+        [dilation[1], dilation[2]],
+    );
+    let [_, _, height_out, width_out] = x.output.shape().dims();
+    let plane_out = height_out * width_out;
+
+    let output = B::float_reshape(
+        x.output,
+        Shape::from([batch_size, channels, depth, plane_out]),
+    );
+    let output = B::max_pool2d_with_indices(
+        output,
+        [kernel_size[0], 1],
+        [stride[0], 1],
+        [padding[0], 0],
+        [dilation[0], 1],
+    );
+    let [_, _, depth_out, _] = output.output.shape().dims();
+
+    // The indices of the second pooling are flattened over `[depth, plane_out]`, they select
+    // the depth and the index in the plane found by the first pooling.
+    let indices_plane = B::int_reshape(
+        x.indices,
+        Shape::from([batch_size, channels, depth * plane_out]),
+    );
+    let indices_depth = B::int_reshape(
+        output.indices,
+        Shape::from([batch_size, channels, depth_out * plane_out]),
+    );
+    let indices_plane = B::int_gather(2, indices_plane, indices_depth.clone());
+    let depth_index = B::int_div_scalar(indices_depth, (plane_out as i64).elem());
+    let indices = B::int_add(
+        B::int_mul_scalar(depth_index, ((height * width) as i64).elem()),
+        indices_plane,
+    );
+
+    let shape_out = Shape::from([batch_size, channels, depth_out, height_out, width_out]);
+    let output = B::float_reshape(output.output, shape_out.clone());
+    let indices = B::int_reshape(indices, shape_out);
+
+    MaxPool3dWithIndices::new(output, indices)
+}
+
+pub(crate) fn max_pool3d_with_indices_backward_using_scatter<B: Backend>(
+    x: FloatTensor<B>,
+    _kernel_size: [usize; 3],
+    _stride: [usize; 3],
+    _padding: [usize; 3],
+    _dilation: [usize; 3],
+    output_grad: FloatTensor<B>,
+    indices: IntTensor<B>,
+) -> MaxPool3dBackward<B> {
+    let shape = x.shape();
+    let [batch_size, channels, depth, height, width] = shape.dims();
+    let num_elems_out = output_grad.shape().num_elements() / (batch_size * channels);
+    let device = B::float_device(&x);
+
+    // Overlapping windows may select the same element, the scatter sums their gradients.
+    let output_grad = B::float_reshape(
+        output_grad,
+        Shape::from([batch_size, channels, num_elems_out]),
+    );
+    let indices = B::int_reshape(indices, Shape::from([batch_size, channels, num_elems_out]));
+    let x_grad = B::float_zeros(
+        Shape::from([batch_size, channels, depth * height * width]),
+        &device,
+    );
+    let x_grad = B::float_scatter(2, x_grad, indices, output_grad);
+
+    MaxPool3dBackward::new(B::float_reshape(x_grad, shape))
+}
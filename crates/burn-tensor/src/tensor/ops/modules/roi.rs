@@ -0,0 +1,259 @@
+use alloc::vec::Vec;
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+use crate::backend::Backend;
+use crate::ops::FloatTensor;
+use crate::{SegmentReduction, Shape, TensorData, TensorMetadata};
+
+use super::{RoiAlignOptions, RoiPoolOptions};
+
+/// The points of the input contributing to each output bin, as flat indices into the pixels of
+/// the input, and the ids of the bins.
+#[derive(Default)]
+struct Contributions {
+    pixels: Vec<i64>,
+    bins: Vec<i64>,
+    weights: Vec<f32>,
+}
+
+/// Computes [roi_align](crate::ops::ModuleOps::roi_align) with a select of the pixels contributing
+/// to each output bin, and a select assign summing them into the bins.
+///
+/// The boxes are read to compute the sampling points and their interpolation weights, the
+/// gradient with respect to the input is then computed by the backward passes of the selects.
+pub(crate) fn roi_align_using_select<B: Backend>(
+    x: FloatTensor<B>,
+    boxes: FloatTensor<B>,
+    options: RoiAlignOptions,
+) -> FloatTensor<B> {
+    let [_, _, height, width] = x.shape().dims();
+    let boxes = read_boxes::<B>(boxes);
+    let [pooled_height, pooled_width] = options.output_size;
+    let offset = if options.aligned { 0.5 } else { 0.0 };
+    let mut contributions = Contributions::default();
+
+    for (roi, roi_box) in boxes.iter().enumerate() {
+        let batch = roi_box[0] as usize;
+        let start_x = roi_box[1] * options.spatial_scale - offset;
+        let start_y = roi_box[2] * options.spatial_scale - offset;
+        let mut roi_width = roi_box[3] * options.spatial_scale - offset - start_x;
+        let mut roi_height = roi_box[4] * options.spatial_scale - offset - start_y;
+
+        if !options.aligned {
+            // Malformed boxes are forced to be 1x1.
+            roi_width = roi_width.max(1.0);
+            roi_height = roi_height.max(1.0);
+        }
+
+        let bin_height = roi_height / pooled_height as f32;
+        let bin_width = roi_width / pooled_width as f32;
+        let (grid_height, grid_width) = match options.sampling_ratio {
+            0 => (bin_height.ceil() as usize, bin_width.ceil() as usize),
+            ratio => (ratio, ratio),
+        };
+        let count = usize::max(grid_height * grid_width, 1) as f32;
+
+        for ph in 0..pooled_height {
+            for pw in 0..pooled_width {
+                let bin = ((roi * pooled_height + ph) * pooled_width + pw) as i64;
+
+                for iy in 0..grid_height {
+                    let y = start_y
+                        + ph as f32 * bin_height
+                        + (iy as f32 + 0.5) * bin_height / grid_height as f32;
+
+                    for ix in 0..grid_width {
+                        let x = start_x
+                            + pw as f32 * bin_width
+                            + (ix as f32 + 0.5) * bin_width / grid_width as f32;
+
+                        for (pixel_y, pixel_x, weight) in
+                            bilinear_weights(y, x, height, width).into_iter().flatten()
+                        {
+                            contributions
+                                .pixels
+                                .push(((batch * height + pixel_y) * width + pixel_x) as i64);
+                            contributions.bins.push(bin);
+                            contributions.weights.push(weight / count);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    pool_contributions::<B>(x, boxes.len(), contributions, options.output_size, false)
+}
+
+/// Computes [roi_pool](crate::ops::ModuleOps::roi_pool) with a select of the pixels of each
+/// output bin, and a segment maximum over the bins.
+///
+/// The boxes are read to compute the pixels of each bin, the gradient with respect to the input
+/// is then computed by the backward passes of the select and the segment maximum.
+pub(crate) fn roi_pool_using_segment_max<B: Backend>(
+    x: FloatTensor<B>,
+    boxes: FloatTensor<B>,
+    options: RoiPoolOptions,
+) -> FloatTensor<B> {
+    let [_, _, height, width] = x.shape().dims();
+    let boxes = read_boxes::<B>(boxes);
+    let [pooled_height, pooled_width] = options.output_size;
+    let mut contributions = Contributions::default();
+
+    for (roi, roi_box) in boxes.iter().enumerate() {
+        let batch = roi_box[0] as usize;
+        let start_x = (roi_box[1] * options.spatial_scale).round() as i64;
+        let start_y = (roi_box[2] * options.spatial_scale).round() as i64;
+        let end_x = (roi_box[3] * options.spatial_scale).round() as i64;
+        let end_y = (roi_box[4] * options.spatial_scale).round() as i64;
+
+        // Malformed boxes are forced to be 1x1.
+        let roi_width = i64::max(end_x - start_x + 1, 1);
+        let roi_height = i64::max(end_y - start_y + 1, 1);
+        let bin_height = roi_height as f32 / pooled_height as f32;
+        let bin_width = roi_width as f32 / pooled_width as f32;
+
+        for ph in 0..pooled_height {
+            let (y_start, y_end) = bin_range(ph, bin_height, start_y, height);
+
+            for pw in 0..pooled_width {
+                let (x_start, x_end) = bin_range(pw, bin_width, start_x, width);
+                let bin = ((roi * pooled_height + ph) * pooled_width + pw) as i64;
+
+                for pixel_y in y_start..y_end {
+                    for pixel_x in x_start..x_end {
+                        contributions
+                            .pixels
+                            .push(((batch * height + pixel_y) * width + pixel_x) as i64);
+                        contributions.bins.push(bin);
+                    }
+                }
+            }
+        }
+    }
+
+    pool_contributions::<B>(x, boxes.len(), contributions, options.output_size, true)
+}
+
+/// Reduces the pixels contributing to each bin, with a weighted sum or a maximum.
+fn pool_contributions<B: Backend>(
+    x: FloatTensor<B>,
+    num_boxes: usize,
+    contributions: Contributions,
+    [pooled_height, pooled_width]: [usize; 2],
+    max: bool,
+) -> FloatTensor<B> {
+    let device = B::float_device(&x);
+    let [batch_size, channels, height, width] = x.shape().dims();
+    let num_bins = num_boxes * pooled_height * pooled_width;
+    let num_contributions = contributions.pixels.len();
+
+    if num_contributions == 0 {
+        return B::float_zeros(
+            Shape::new([num_boxes, channels, pooled_height, pooled_width]),
+            &device,
+        );
+    }
+
+    // The pixels are the rows of the input, so they are selected with all their channels.
+    let x = B::float_permute(x, &[0, 2, 3, 1]);
+    let x = B::float_reshape(x, Shape::new([batch_size * height * width, channels]));
+
+    let pixels = int_tensor::<B>(contributions.pixels, &device);
+    let bins = int_tensor::<B>(contributions.bins, &device);
+    let values = B::float_select(x, 0, pixels);
+
+    let output = match max {
+        // The bins are pushed in order, so the ids are sorted.
+        true => B::float_segment_reduce(values, bins, num_bins, SegmentReduction::Max, true),
+        false => {
+            let weights = B::float_from_data(
+                TensorData::new(contributions.weights, [num_contributions, 1])
+                    .convert::<B::FloatElem>(),
+                &device,
+            );
+            let values = B::float_mul(values, weights);
+            let output = B::float_zeros(Shape::new([num_bins, channels]), &device);
+
+            B::float_select_assign(output, 0, bins, values)
+        }
+    };
+
+    let output = B::float_reshape(
+        output,
+        Shape::new([num_boxes, pooled_height, pooled_width, channels]),
+    );
+    B::float_permute(output, &[0, 3, 1, 2])
+}
+
+/// The pixels and weights of the bilinear interpolation at `(y, x)`, `None` when the point is
+/// more than one pixel outside of the input.
+///
+/// The points between the border and the center of the border pixels take the value of the
+/// border pixels.
+fn bilinear_weights(
+    y: f32,
+    x: f32,
+    height: usize,
+    width: usize,
+) -> Option<[(usize, usize, f32); 4]> {
+    if y < -1.0 || y > height as f32 || x < -1.0 || x > width as f32 {
+        return None;
+    }
+
+    let (y_low, y_high, ly) = interpolation_neighbors(y, height);
+    let (x_low, x_high, lx) = interpolation_neighbors(x, width);
+    let (hy, hx) = (1.0 - ly, 1.0 - lx);
+
+    Some([
+        (y_low, x_low, hy * hx),
+        (y_low, x_high, hy * lx),
+        (y_high, x_low, ly * hx),
+        (y_high, x_high, ly * lx),
+    ])
+}
+
+/// The low and high neighbors of the coordinate along a dimension, with the weight of the high
+/// neighbor.
+fn interpolation_neighbors(coordinate: f32, size: usize) -> (usize, usize, f32) {
+    let coordinate = coordinate.max(0.0);
+    let low = coordinate as usize;
+
+    if low >= size - 1 {
+        (size - 1, size - 1, 0.0)
+    } else {
+        (low, low + 1, coordinate - low as f32)
+    }
+}
+
+/// The range of pixels of a bin along a dimension, clamped to the input.
+fn bin_range(bin: usize, bin_size: f32, start: i64, size: usize) -> (usize, usize) {
+    let bin_start = (bin as f32 * bin_size).floor() as i64 + start;
+    let bin_end = ((bin + 1) as f32 * bin_size).ceil() as i64 + start;
+
+    (
+        bin_start.clamp(0, size as i64) as usize,
+        bin_end.clamp(0, size as i64) as usize,
+    )
+}
+
+fn read_boxes<B: Backend>(boxes: FloatTensor<B>) -> Vec<[f32; 5]> {
+    let data = crate::try_read_sync(B::float_into_data(boxes))
+        .expect("Failed to read the boxes synchronously, which is required by the fallback.");
+
+    data.iter::<f32>()
+        .collect::<Vec<_>>()
+        .chunks_exact(5)
+        .map(|roi_box| [roi_box[0], roi_box[1], roi_box[2], roi_box[3], roi_box[4]])
+        .collect()
+}
+
+fn int_tensor<B: Backend>(values: Vec<i64>, device: &B::Device) -> crate::ops::IntTensor<B> {
+    let num_values = values.len();
+
+    B::int_from_data(
+        TensorData::new(values, [num_values]).convert::<B::IntElem>(),
+        device,
+    )
+}
@@ -0,0 +1,83 @@
+use alloc::vec;
+
+use crate::{backend::Backend, ops::FloatTensor, Shape, TensorMetadata};
+
+use super::RotaryQk;
+
+pub(crate) fn rotary_qk<B: Backend>(
+    queries: FloatTensor<B>,
+    keys: FloatTensor<B>,
+    frequencies: FloatTensor<B>,
+    start: usize,
+) -> RotaryQk<B> {
+    let queries = rotary::<B>(queries, frequencies.clone(), start);
+    let keys = rotary::<B>(keys, frequencies, start);
+
+    RotaryQk::new(queries, keys)
+}
+
+/// Rotates the features of the tensor of shape `[batch_size, seq_length, n_heads, d_head]` and
+/// returns them with shape `[batch_size, n_heads, seq_length, d_head]`.
+fn rotary<B: Backend>(
+    x: FloatTensor<B>,
+    frequencies: FloatTensor<B>,
+    start: usize,
+) -> FloatTensor<B> {
+    let [batch_size, seq_length, n_heads, d_head] = x.shape().dims();
+    let [_, rotary_dim, _] = frequencies.shape().dims();
+    let x = B::float_swap_dims(x, 1, 2);
+
+    let rotated = B::float_slice(
+        x.clone(),
+        &[0..batch_size, 0..n_heads, 0..seq_length, 0..rotary_dim],
+    );
+    let rotated = rotate::<B>(rotated, frequencies, start);
+
+    if rotary_dim == d_head {
+        return rotated;
+    }
+
+    let passed = B::float_slice(
+        x,
+        &[0..batch_size, 0..n_heads, 0..seq_length, rotary_dim..d_head],
+    );
+    B::float_cat(vec![rotated, passed], 3)
+}
+
+/// Rotates each pair of features `(x0, x1)` by the angle of its position:
+/// `(x0 * cos - x1 * sin, x1 * cos + x0 * sin)`.
+fn rotate<B: Backend>(
+    x: FloatTensor<B>,
+    frequencies: FloatTensor<B>,
+    start: usize,
+) -> FloatTensor<B> {
+    let shape = x.shape();
+    let [batch_size, n_heads, seq_length, rotary_dim] = shape.dims();
+    let num_rows = batch_size * n_heads;
+
+    let pairs = B::float_reshape(
+        x.clone(),
+        Shape::new([num_rows, seq_length, rotary_dim / 2, 2]),
+    );
+    let first = B::float_slice(
+        pairs.clone(),
+        &[0..num_rows, 0..seq_length, 0..rotary_dim / 2, 0..1],
+    );
+    let second = B::float_slice(
+        pairs,
+        &[0..num_rows, 0..seq_length, 0..rotary_dim / 2, 1..2],
+    );
+    let swapped = B::float_cat(vec![B::float_neg(second), first], 3);
+    let swapped = B::float_reshape(swapped, shape.clone());
+
+    let positions = start..start + seq_length;
+    let cos = B::float_slice(
+        frequencies.clone(),
+        &[positions.clone(), 0..rotary_dim, 0..1],
+    );
+    let sin = B::float_slice(frequencies, &[positions, 0..rotary_dim, 1..2]);
+    let cos = B::float_reshape(cos, Shape::new([1, 1, seq_length, rotary_dim]));
+    let sin = B::float_reshape(sin, Shape::new([1, 1, seq_length, rotary_dim]));
+
+    B::float_add(B::float_mul(x, cos), B::float_mul(swapped, sin))
+}
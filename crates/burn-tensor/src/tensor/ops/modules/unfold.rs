@@ -4,7 +4,7 @@ use crate::{ElementConversion, Shape, TensorData, TensorMetadata};
 use alloc::vec;
 use alloc::vec::Vec;
 
-use super::{ConvOptions, UnfoldOptions};
+use super::{conv::calculate_conv_output_size, ConvOptions, ConvTransposeOptions, UnfoldOptions};
 
 /// Constructs a special weight tensor used for unfolding.
 ///
@@ -79,3 +79,54 @@ pub(crate) fn unfold4d_using_conv2d<B: Backend>(
         Shape::new([batch_size, channels_out, out_height * out_width]),
     )
 }
+
+/// Compute the fold4d operation using the conv_transpose2d operations.
+///
+/// Folding is the adjoint of unfolding, so it uses the transposed convolution with the same
+/// weight, which sums the values of the overlapping blocks.
+pub(crate) fn fold4d_using_conv_transpose2d<B: Backend>(
+    x: FloatTensor<B>,
+    output_size: [usize; 2],
+    kernel_size: [usize; 2],
+    options: UnfoldOptions,
+) -> FloatTensor<B> {
+    let [batch_size, channels_col, _num_blocks] = x.shape().dims();
+    let channels_out = channels_col / (kernel_size[0] * kernel_size[1]);
+    let blocks = [0, 1].map(|i| {
+        calculate_conv_output_size(
+            kernel_size[i],
+            options.stride[i],
+            options.padding[i],
+            options.dilation[i],
+            output_size[i],
+        )
+    });
+
+    // The transposed convolution can produce a smaller output when the stride skips the last
+    // rows or columns, which are then added as output padding.
+    let padding_out = [0, 1].map(|i| {
+        let size =
+            (blocks[i] - 1) * options.stride[i] + options.dilation[i] * (kernel_size[i] - 1) + 1
+                - 2 * options.padding[i];
+        output_size[i] - size
+    });
+
+    let x = B::float_reshape(
+        x,
+        Shape::new([batch_size, channels_col, blocks[0], blocks[1]]),
+    );
+    let weight = create_unfolding_weight::<B>(channels_out, kernel_size, &B::float_device(&x));
+
+    B::conv_transpose2d(
+        x,
+        weight,
+        None,
+        ConvTransposeOptions::new(
+            options.stride,
+            options.padding,
+            padding_out,
+            options.dilation,
+            1,
+        ),
+    )
+}
@@ -11,7 +11,10 @@ use alloc::vec::Vec;
 use core::future::Future;
 use core::ops::Range;
 
-use crate::{argsort, sort, sort_with_indices};
+use crate::{
+    argsort, multinomial, scatter_reduce, searchsorted, segment_reduce, sort, sort_with_indices,
+    ScatterReduceOptions, SegmentReduction,
+};
 
 /// Operations on float tensors.
 pub trait FloatTensorOps<B: Backend> {
@@ -96,6 +99,27 @@ pub trait FloatTensorOps<B: Backend> {
     fn float_into_data(tensor: FloatTensor<B>)
         -> impl Future<Output = TensorData> + 'static + Send;
 
+    /// Converts the selected elements of the tensor to a data structure.
+    ///
+    /// Only the selected elements are transferred, which is cheaper than reading the whole
+    /// tensor when inspecting a small part of it.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `ranges` - The ranges to read.
+    ///
+    /// # Returns
+    ///
+    /// The data structure with the selected elements.
+    fn float_into_data_slice(
+        tensor: FloatTensor<B>,
+        ranges: Vec<Range<usize>>,
+    ) -> impl Future<Output = TensorData> + 'static + Send {
+        // Slicing doesn't copy the data on most backends, so only the selected elements are read
+        Self::float_into_data(Self::float_slice(tensor, &ranges))
+    }
+
     /// Gets the device of the tensor.
     ///
     /// # Arguments
@@ -119,6 +143,23 @@ pub trait FloatTensorOps<B: Backend> {
     /// The tensor on the given device.
     fn float_to_device(tensor: FloatTensor<B>, device: &Device<B>) -> FloatTensor<B>;
 
+    /// Moves the tensor to the given device without blocking the host until the copy is done.
+    ///
+    /// Backends supporting non-blocking copies should override this function, by default the
+    /// tensor is moved with [float_to_device](FloatTensorOps::float_to_device).
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `device` - The device to move the tensor to.
+    ///
+    /// # Returns
+    ///
+    /// The tensor on the given device.
+    fn float_to_device_async(tensor: FloatTensor<B>, device: &Device<B>) -> FloatTensor<B> {
+        B::float_to_device(tensor, device)
+    }
+
     /// Converts float tensor to int tensor.
     ///
     /// # Arguments
@@ -327,6 +368,34 @@ pub trait FloatTensorOps<B: Backend> {
     /// The result of multiplying the two tensors together using matrix multiplication.
     fn float_matmul(lhs: FloatTensor<B>, rhs: FloatTensor<B>) -> FloatTensor<B>;
 
+    /// Multiplies two tensors together using matrix multiplication, after converting them to FP8.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `lhs_scaling` - How the left hand side tensor is converted to FP8.
+    /// * `rhs` - The right hand side tensor, with the same batch dimensions.
+    /// * `rhs_scaling` - How the right hand side tensor is converted to FP8.
+    ///
+    /// # Returns
+    ///
+    /// The product of the FP8 tensors multiplied by their scales, accumulated in `f32`.
+    ///
+    /// # Notes
+    ///
+    /// The default implementation multiplies the tensors with their float precision, for backends
+    /// without FP8 kernels.
+    #[cfg(feature = "fp8")]
+    fn float_matmul_fp8(
+        lhs: FloatTensor<B>,
+        lhs_scaling: crate::Fp8Scaling,
+        rhs: FloatTensor<B>,
+        rhs_scaling: crate::Fp8Scaling,
+    ) -> FloatTensor<B> {
+        let _ = (lhs_scaling, rhs_scaling);
+        B::float_matmul(lhs, rhs)
+    }
+
     /// Negates a tensor element-wise.
     fn float_neg(tensor: FloatTensor<B>) -> FloatTensor<B> {
         Self::float_mul_scalar(tensor, (-1.0_f32).elem::<FloatElem<B>>())
@@ -427,6 +496,36 @@ pub trait FloatTensorOps<B: Backend> {
         value: FloatTensor<B>,
     ) -> FloatTensor<B>;
 
+    /// Scatter elements into a tensor, reducing the elements scattered to the same position.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension to scatter into.
+    /// * `tensor` - The tensor to scatter into.
+    /// * `indices` - The indices to scatter into.
+    /// * `values` - The values to scatter.
+    /// * `options` - The reduction of the values.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the reduced elements.
+    fn float_scatter_reduce(
+        dim: usize,
+        tensor: FloatTensor<B>,
+        indices: IntTensor<B>,
+        values: FloatTensor<B>,
+        options: ScatterReduceOptions,
+    ) -> FloatTensor<B> {
+        scatter_reduce::<B, Float>(
+            dim,
+            TensorPrimitive::Float(tensor),
+            indices,
+            TensorPrimitive::Float(values),
+            options,
+        )
+        .tensor()
+    }
+
     /// Select tensor elements along the given dimension corresponding for the given indices.
     ///
     /// # Arguments
@@ -691,6 +790,13 @@ pub trait FloatTensorOps<B: Backend> {
         false
     }
 
+    /// Returns true if the tensor wraps memory allocated outside of the backend without copying
+    /// it.
+    fn float_is_external(_tensor: &FloatTensor<B>) -> bool {
+        // Should only be overridden by backends able to import external memory.
+        false
+    }
+
     /// Sum of all elements in a tensor.
     ///
     /// # Arguments
@@ -1348,4 +1454,71 @@ pub trait FloatTensorOps<B: Backend> {
     fn float_argsort(tensor: FloatTensor<B>, dim: usize, descending: bool) -> IntTensor<B> {
         argsort::<B, Float>(TensorPrimitive::Float(tensor), dim, descending)
     }
+
+    /// Reduce the slices of the input `tensor` along the first dimension sharing the same
+    /// segment id.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor, of shape `[num_elements, ...]`.
+    /// * `ids` - The segment id of each slice, of shape `[num_elements]`, in `[0, num_segments)`.
+    /// * `num_segments` - The number of segments.
+    /// * `reduction` - The reduction applied to the slices of each segment.
+    /// * `sorted` - If the ids are sorted in ascending order, so the segments are contiguous.
+    ///
+    /// # Returns
+    ///
+    /// A tensor of shape `[num_segments, ...]`, where empty segments are zero.
+    fn float_segment_reduce(
+        tensor: FloatTensor<B>,
+        ids: IntTensor<B>,
+        num_segments: usize,
+        reduction: SegmentReduction,
+        sorted: bool,
+    ) -> FloatTensor<B> {
+        // The fallback doesn't rely on the order of the ids.
+        let _ = sorted;
+        segment_reduce::<B>(tensor, ids, num_segments, reduction)
+    }
+
+    /// Draw indices of categories from the probabilities of the last dimension of the input
+    /// `tensor`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The probabilities of shape `[..., num_categories]`, non-negative and with at
+    ///   least one positive probability per distribution.
+    /// * `num_samples` - The number of samples drawn from each distribution.
+    /// * `replacement` - If a category can be drawn more than once.
+    ///
+    /// # Returns
+    ///
+    /// The indices of the drawn categories, of shape `[..., num_samples]`.
+    fn float_multinomial(
+        tensor: FloatTensor<B>,
+        num_samples: usize,
+        replacement: bool,
+    ) -> IntTensor<B> {
+        multinomial::<B>(tensor, num_samples, replacement)
+    }
+
+    /// Find the indices where the `values` would be inserted in the `sorted_sequence` to keep it
+    /// sorted.
+    ///
+    /// # Arguments
+    ///
+    /// * `sorted_sequence` - The sequence sorted in ascending order, of shape `[num_elements]`.
+    /// * `values` - The values to insert, of any shape.
+    /// * `right` - If the index is after the elements equal to the value, instead of before them.
+    ///
+    /// # Returns
+    ///
+    /// The indices in `[0, num_elements]`, of the same shape as the `values`.
+    fn float_searchsorted(
+        sorted_sequence: FloatTensor<B>,
+        values: FloatTensor<B>,
+        right: bool,
+    ) -> IntTensor<B> {
+        searchsorted::<B>(sorted_sequence, values, right)
+    }
 }
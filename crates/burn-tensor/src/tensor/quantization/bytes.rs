@@ -4,15 +4,16 @@ use crate::{Bytes, Element};
 use alloc::vec::Vec;
 
 use super::{
-    pack_i8s_to_u32s, unpack_u32s_to_i8s, AffineQuantization, QParams, Quantization,
-    QuantizationScheme, QuantizationStrategy, QuantizationType, SymmetricQuantization,
+    pack_q_values_to_u32s, unpack_u32s_to_q_values, QParams, QuantizationScheme,
+    QuantizationStrategy,
 };
 
 /// Quantized data bytes representation.
 ///
 /// # Notes
 /// 1) The quantized values are packed into 32-bit unsigned integers. For example, int8
-///    quantized values pack 4 grouped values into a single `u32`. When unpacking these values,
+///    quantized values pack 4 grouped values into a single `u32`, and int4 quantized values
+///    pack 8 grouped values. When unpacking these values,
 ///    we make sure to retrieve only the meaningful values (and ignore the alignment padding).
 /// 2) Quantization parameters are appended to the tensor data.
 ///    As such, the last bytes always correspond to the scale parameter.
@@ -29,18 +30,21 @@ pub struct QuantizedBytes {
 impl QuantizedBytes {
     /// Creates a new quantized bytes representation.
     pub fn new<E: Element>(value: Vec<E>, strategy: QuantizationStrategy) -> Self {
-        let mut bytes: Bytes;
         let num_elements = value.len();
+        let scheme = strategy.scheme();
+
+        let mut bytes = if TypeId::of::<E>() == TypeId::of::<i8>() {
+            // Re-interpret `Vec<E>` as `Vec<i8>` with `Vec::from_raw_parts`
+            let values = bytemuck::allocation::cast_vec(value);
+            Bytes::from_elems(pack_q_values_to_u32s(values, scheme.q_type()))
+        } else {
+            panic!("Invalid quantized type");
+        };
 
         match strategy {
-            QuantizationStrategy::PerTensorAffineInt8(q) => {
-                if TypeId::of::<E>() == TypeId::of::<i8>() {
-                    // Re-interpret `Vec<E>` as `Vec<i8>` with `Vec::from_raw_parts`
-                    let u32s = pack_i8s_to_u32s(bytemuck::allocation::cast_vec(value));
-                    bytes = Bytes::from_elems(u32s);
-                } else {
-                    panic!("Invalid quantized type");
-                }
+            QuantizationStrategy::PerTensorAffineInt8(q)
+            | QuantizationStrategy::PerTensorAffineInt4(q)
+            | QuantizationStrategy::PerTensorAffineInt2(q) => {
                 // Scale is always stored as f32 and zero-point offset as i32
                 let offset = q.offset as i32;
                 let scale_bytes = bytemuck::bytes_of(&q.scale);
@@ -48,14 +52,9 @@ impl QuantizedBytes {
                 bytes.extend_from_byte_slice_aligned(offset_bytes, align_of::<i32>());
                 bytes.extend_from_byte_slice_aligned(scale_bytes, align_of::<f32>());
             }
-            QuantizationStrategy::PerTensorSymmetricInt8(q) => {
-                if TypeId::of::<E>() == TypeId::of::<i8>() {
-                    // Re-interpret `Vec<E>` as `Vec<i8>` with `Vec::from_raw_parts`
-                    let u32s = pack_i8s_to_u32s(bytemuck::allocation::cast_vec(value));
-                    bytes = Bytes::from_elems(u32s);
-                } else {
-                    panic!("Invalid quantized type");
-                }
+            QuantizationStrategy::PerTensorSymmetricInt8(q)
+            | QuantizationStrategy::PerTensorSymmetricInt4(q)
+            | QuantizationStrategy::PerTensorSymmetricInt2(q) => {
                 let scale_bytes = bytemuck::bytes_of(&q.scale);
                 bytes.extend_from_byte_slice_aligned(scale_bytes, align_of::<f32>());
             }
@@ -63,18 +62,20 @@ impl QuantizedBytes {
 
         Self {
             bytes,
-            scheme: strategy.scheme(),
+            scheme,
             num_elements,
         }
     }
 
-    /// Returns the int8 quantized values with the quantization parameters.
+    /// Returns the quantized values with the quantization parameters.
+    ///
+    /// Sub-byte quantized values are unpacked and sign-extended to `i8`.
     pub fn into_vec_i8(self) -> (Vec<i8>, QParams<f32, i8>) {
         let numel = self.num_elements;
         let scheme = self.scheme;
         let (values, qparams) = self.split_values_off();
 
-        let values = unpack_u32s_to_i8s(values, numel);
+        let values = unpack_u32s_to_q_values(values, numel, scheme.q_type());
 
         // Quantization parameters are added at the end of the tensor data.
         // As such, the last bytes always correspond to the scale parameter.
@@ -113,7 +114,7 @@ impl QuantizedBytes {
                 }
                 #[cfg(target_endian = "big")]
                 {
-                    pack_i8s_to_u32s(bytemuck::allocation::cast_vec(bytes))
+                    super::pack_i8s_to_u32s(bytemuck::allocation::cast_vec(bytes))
                 }
             }
             4 => self.bytes.try_into_vec::<u32>().unwrap(),
@@ -123,7 +124,7 @@ impl QuantizedBytes {
         let scale_size = 1; // f32 scale is the same number of bytes as u32
         let mut values_end = values.len() - scale_size;
 
-        if let QuantizationScheme::PerTensorAffine(QuantizationType::QInt8) = self.scheme {
+        if let QuantizationScheme::PerTensorAffine(_) = self.scheme {
             values_end -= 1; // zero-point offset is stored as i32 (same number of bytes as u32)
         }
 
@@ -134,21 +135,11 @@ impl QuantizedBytes {
 
     /// Dequantizes the data according to its quantization scheme.
     pub fn dequantize(self) -> (Vec<f32>, QParams<f32, i8>) {
-        match self.scheme {
-            QuantizationScheme::PerTensorAffine(QuantizationType::QInt8) => {
-                let (values, qparams) = self.into_vec_i8();
-                let strategy = AffineQuantization::<f32, i8, i32>::init(
-                    qparams.scale,
-                    qparams.offset.unwrap(),
-                );
-                (strategy.dequantize(&values), qparams)
-            }
-            QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt8) => {
-                let (values, qparams) = self.into_vec_i8();
-                let strategy = SymmetricQuantization::<f32, i8>::init(qparams.scale);
-                (strategy.dequantize(&values), qparams)
-            }
-        }
+        let scheme = self.scheme;
+        let (values, qparams) = self.into_vec_i8();
+        let strategy = QuantizationStrategy::from_scheme(scheme, qparams.scale, qparams.offset);
+
+        (strategy.dequantize(&values), qparams)
     }
 }
 
@@ -185,6 +176,7 @@ unsafe fn reinterpret_vec<T, U>(mut input: Vec<T>) -> Vec<U> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::quantization::{AffineQuantization, SymmetricQuantization};
     use alloc::vec;
 
     #[test]
@@ -224,4 +216,26 @@ mod tests {
 
         assert_eq!(q_values, values);
     }
+
+    #[test]
+    fn should_pack_unpack_quantization_parameters_affine_int4() {
+        let scale = 0.33333334;
+        let offset = -8;
+        // Quantized [[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]
+        let values = vec![-8i8, -5, -2, 1, 4, 7];
+        let q_bytes = QuantizedBytes::new(
+            values.clone(),
+            QuantizationStrategy::PerTensorAffineInt4(AffineQuantization::init(scale, offset)),
+        );
+
+        // Six 4-bit values are packed into a single u32, followed by the offset and the scale.
+        assert_eq!(q_bytes.bytes.len(), 3 * size_of::<u32>());
+
+        let (q_values, qparams) = q_bytes.into_vec_i8();
+
+        assert_eq!(qparams.scale, scale);
+        assert_eq!(qparams.offset, Some(offset));
+
+        assert_eq!(q_values, values);
+    }
 }
@@ -1,5 +1,7 @@
 use alloc::vec::Vec;
 
+use super::QuantizationType;
+
 /// Pack signed 8-bit integer values into a sequence of unsigned 32-bit integers.
 pub fn pack_i8s_to_u32s(values: Vec<i8>) -> Vec<u32> {
     // Shift and combine groups of four 8-bit values into a u32.
@@ -82,6 +84,65 @@ pub fn unpack_u32s_to_i8s(values: Vec<u32>, numel: usize) -> Vec<i8> {
     }
 }
 
+/// Pack signed sub-byte integer values into a sequence of unsigned 32-bit integers.
+///
+/// The values are stored in the lowest `bits` of each `i8`, and `32 / bits` values are packed into
+/// each `u32` starting from the least significant bits, so the layout matches
+/// [pack_i8s_to_u32s] for 8-bit values.
+pub fn pack_sub_byte_to_u32s(values: &[i8], bits: usize) -> Vec<u32> {
+    let num_packed = u32::BITS as usize / bits;
+    let mask = (1u32 << bits) - 1;
+
+    values
+        .chunks(num_packed)
+        .map(|x| {
+            x.iter()
+                .enumerate()
+                .fold(0u32, |acc, (i, x)| acc | (*x as u32 & mask) << (i * bits))
+        })
+        .collect()
+}
+
+/// Unpack 32-bit unsigned integer values into a sequence of signed sub-byte integers, each
+/// sign-extended to an `i8`.
+pub fn unpack_u32s_to_sub_byte(values: &[u32], bits: usize, numel: usize) -> Vec<i8> {
+    let num_packed = u32::BITS as usize / bits;
+    let mask = (1u32 << bits) - 1;
+    // Shift the sign bit of the value into the sign bit of the `i8` and back to extend it.
+    let shift = 8 - bits as u32;
+
+    values
+        .iter()
+        .flat_map(|packed| (0..num_packed).map(move |i| ((packed >> (i * bits)) & mask) as i8))
+        .take(numel)
+        .map(|x| (x << shift) >> shift)
+        .collect()
+}
+
+/// Pack the quantized values into a sequence of unsigned 32-bit integers.
+pub fn pack_q_values_to_u32s(values: Vec<i8>, q_type: QuantizationType) -> Vec<u32> {
+    match q_type {
+        QuantizationType::QInt8 => pack_i8s_to_u32s(values),
+        QuantizationType::QInt4 | QuantizationType::QInt2 => {
+            pack_sub_byte_to_u32s(&values, q_type.bits())
+        }
+    }
+}
+
+/// Unpack 32-bit unsigned integer values into a sequence of quantized values.
+pub fn unpack_u32s_to_q_values(
+    values: Vec<u32>,
+    numel: usize,
+    q_type: QuantizationType,
+) -> Vec<i8> {
+    match q_type {
+        QuantizationType::QInt8 => unpack_u32s_to_i8s(values, numel),
+        QuantizationType::QInt4 | QuantizationType::QInt2 => {
+            unpack_u32s_to_sub_byte(&values, q_type.bits(), numel)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -116,4 +177,29 @@ mod tests {
 
         assert_eq!(unpacked, vec![55]);
     }
+
+    #[test]
+    fn should_pack_i4s_to_u32() {
+        let packed = pack_sub_byte_to_u32s(&[-8, 2, -3, 7, 0, 1, -1, 4], 4);
+
+        assert_eq!(packed, vec![0x4f107d28]);
+    }
+
+    #[test]
+    fn should_unpack_u32s_to_i4s_padded() {
+        let unpacked = unpack_u32s_to_sub_byte(&[0x4f107d28, 0x9], 4, 9);
+
+        assert_eq!(unpacked, vec![-8, 2, -3, 7, 0, 1, -1, 4, -7]);
+    }
+
+    #[test]
+    fn should_pack_unpack_i2s() {
+        let values = vec![-2, 1, -1, 0, 1, 1, -2];
+
+        let packed = pack_q_values_to_u32s(values.clone(), QuantizationType::QInt2);
+        let unpacked = unpack_u32s_to_q_values(packed.clone(), 7, QuantizationType::QInt2);
+
+        assert_eq!(packed.len(), 1);
+        assert_eq!(unpacked, values);
+    }
 }
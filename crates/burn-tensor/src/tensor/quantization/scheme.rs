@@ -15,6 +15,33 @@ use cubecl::prelude::*;
 pub enum QuantizationType {
     /// 8-bit signed integer.
     QInt8,
+    /// 4-bit signed integer, packed eight values per `u32`.
+    QInt4,
+    /// 2-bit signed integer, packed sixteen values per `u32`.
+    QInt2,
+}
+
+impl QuantizationType {
+    /// The number of bits of a quantized value.
+    pub const fn bits(&self) -> usize {
+        match self {
+            QuantizationType::QInt8 => 8,
+            QuantizationType::QInt4 => 4,
+            QuantizationType::QInt2 => 2,
+        }
+    }
+
+    /// The number of quantized values packed into a single `u32`.
+    pub const fn num_packed(&self) -> usize {
+        u32::BITS as usize / self.bits()
+    }
+
+    /// The range `[a, b]` of the quantized values.
+    pub const fn range(&self) -> (i32, i32) {
+        let b = (1 << (self.bits() - 1)) - 1;
+
+        (-b - 1, b)
+    }
 }
 
 /// Quantization scheme.
@@ -49,43 +76,46 @@ impl QuantizationScheme {
         range: CalibrationRange<B>,
     ) -> QuantizationParameters<B> {
         match self {
-            QuantizationScheme::PerTensorAffine(dtype) => match dtype {
-                QuantizationType::QInt8 => {
-                    // Quantized range `[a, b]`
-                    let a = i8::MIN as i32;
-                    let b = i8::MAX as i32;
-
-                    // We extend the `[min, max]` interval to ensure that it contains 0.
-                    // Otherwise, we would not meet the requirement that 0 be an exactly
-                    // representable value (zero-point).
-                    let zero = Tensor::zeros_like(&range.min);
-                    let min = range.min.min_pair(zero);
-                    let zero = Tensor::zeros_like(&range.max);
-                    let max = range.max.max_pair(zero);
-
-                    // If scale is 0 (most likely due to a tensor full of zeros), we arbitrarily adjust the
-                    // scale to 0.1 to avoid division by zero.
-                    let scale = max.sub(min.clone()).div_scalar(b - a);
-                    let scale = scale.clone().mask_fill(scale.equal_elem(0.), 0.1);
-                    let offset = Some(-(min.div(scale.clone()).sub_scalar(a)).int());
-                    QuantizationParameters { scale, offset }
-                }
-            },
-            QuantizationScheme::PerTensorSymmetric(dtype) => match dtype {
-                QuantizationType::QInt8 => {
-                    // Quantized range `[a, b]`
-                    let b = i8::MAX as i32;
-                    let a = -b;
-
-                    // Compute scale to convert an input value in range `[-alpha, alpha]`
-                    let values_range = range.min.abs().max_pair(range.max.abs()).mul_scalar(2);
-
-                    QuantizationParameters {
-                        scale: values_range.div_scalar(b - a),
-                        offset: None,
-                    }
+            QuantizationScheme::PerTensorAffine(dtype) => {
+                // Quantized range `[a, b]`
+                let (a, b) = dtype.range();
+
+                // We extend the `[min, max]` interval to ensure that it contains 0.
+                // Otherwise, we would not meet the requirement that 0 be an exactly
+                // representable value (zero-point).
+                let zero = Tensor::zeros_like(&range.min);
+                let min = range.min.min_pair(zero);
+                let zero = Tensor::zeros_like(&range.max);
+                let max = range.max.max_pair(zero);
+
+                // If scale is 0 (most likely due to a tensor full of zeros), we arbitrarily adjust the
+                // scale to 0.1 to avoid division by zero.
+                let scale = max.sub(min.clone()).div_scalar(b - a);
+                let scale = scale.clone().mask_fill(scale.equal_elem(0.), 0.1);
+                let offset = Some(-(min.div(scale.clone()).sub_scalar(a)).int());
+                QuantizationParameters { scale, offset }
+            }
+            QuantizationScheme::PerTensorSymmetric(dtype) => {
+                // Quantized range `[a, b]`
+                let (_, b) = dtype.range();
+                let a = -b;
+
+                // Compute scale to convert an input value in range `[-alpha, alpha]`
+                let values_range = range.min.abs().max_pair(range.max.abs()).mul_scalar(2);
+
+                QuantizationParameters {
+                    scale: values_range.div_scalar(b - a),
+                    offset: None,
                 }
-            },
+            }
+        }
+    }
+
+    /// The quantization data type.
+    pub fn q_type(&self) -> QuantizationType {
+        match self {
+            QuantizationScheme::PerTensorAffine(dtype)
+            | QuantizationScheme::PerTensorSymmetric(dtype) => *dtype,
         }
     }
 
@@ -11,12 +11,23 @@ use serde::{Deserialize, Serialize};
 use super::{QuantizationScheme, QuantizationType};
 
 /// Quantization strategy.
+///
+/// The sub-byte integer strategies store each quantized value in an `i8`, and are packed into
+/// `u32` with the other values of the tensor.
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub enum QuantizationStrategy {
     /// Per-tensor `int8` affine/asymmetric quantization.
     PerTensorAffineInt8(AffineQuantization<f32, i8, i32>),
     /// Per-tensor `int8` symmetric quantization.
     PerTensorSymmetricInt8(SymmetricQuantization<f32, i8>),
+    /// Per-tensor `int4` affine/asymmetric quantization.
+    PerTensorAffineInt4(AffineQuantization<f32, i8, i32>),
+    /// Per-tensor `int4` symmetric quantization.
+    PerTensorSymmetricInt4(SymmetricQuantization<f32, i8>),
+    /// Per-tensor `int2` affine/asymmetric quantization.
+    PerTensorAffineInt2(AffineQuantization<f32, i8, i32>),
+    /// Per-tensor `int2` symmetric quantization.
+    PerTensorSymmetricInt2(SymmetricQuantization<f32, i8>),
 }
 
 impl QuantizationStrategy {
@@ -29,6 +40,81 @@ impl QuantizationStrategy {
             QuantizationStrategy::PerTensorSymmetricInt8(_) => {
                 QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt8)
             }
+            QuantizationStrategy::PerTensorAffineInt4(_) => {
+                QuantizationScheme::PerTensorAffine(QuantizationType::QInt4)
+            }
+            QuantizationStrategy::PerTensorSymmetricInt4(_) => {
+                QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt4)
+            }
+            QuantizationStrategy::PerTensorAffineInt2(_) => {
+                QuantizationScheme::PerTensorAffine(QuantizationType::QInt2)
+            }
+            QuantizationStrategy::PerTensorSymmetricInt2(_) => {
+                QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt2)
+            }
+        }
+    }
+
+    /// Creates the strategy of the quantization scheme with the given parameters.
+    pub fn from_scheme(scheme: QuantizationScheme, scale: f32, offset: Option<i8>) -> Self {
+        match scheme {
+            QuantizationScheme::PerTensorAffine(q_type) => {
+                let strategy = AffineQuantization::init(
+                    scale,
+                    offset.expect("Affine quantization requires a zero-point offset."),
+                );
+
+                match q_type {
+                    QuantizationType::QInt8 => QuantizationStrategy::PerTensorAffineInt8(strategy),
+                    QuantizationType::QInt4 => QuantizationStrategy::PerTensorAffineInt4(strategy),
+                    QuantizationType::QInt2 => QuantizationStrategy::PerTensorAffineInt2(strategy),
+                }
+            }
+            QuantizationScheme::PerTensorSymmetric(q_type) => {
+                let strategy = SymmetricQuantization::init(scale);
+
+                match q_type {
+                    QuantizationType::QInt8 => {
+                        QuantizationStrategy::PerTensorSymmetricInt8(strategy)
+                    }
+                    QuantizationType::QInt4 => {
+                        QuantizationStrategy::PerTensorSymmetricInt4(strategy)
+                    }
+                    QuantizationType::QInt2 => {
+                        QuantizationStrategy::PerTensorSymmetricInt2(strategy)
+                    }
+                }
+            }
+        }
+    }
+
+    /// Convert the values to the quantized data type of the strategy.
+    pub fn quantize(&self, values: &[f32]) -> Vec<i8> {
+        let (a, b) = self.scheme().q_type().range();
+
+        match self {
+            QuantizationStrategy::PerTensorAffineInt8(strategy)
+            | QuantizationStrategy::PerTensorAffineInt4(strategy)
+            | QuantizationStrategy::PerTensorAffineInt2(strategy) => {
+                strategy.quantize_in_range(values, a as i8, b as i8)
+            }
+            QuantizationStrategy::PerTensorSymmetricInt8(strategy)
+            | QuantizationStrategy::PerTensorSymmetricInt4(strategy)
+            | QuantizationStrategy::PerTensorSymmetricInt2(strategy) => {
+                strategy.quantize_in_range(values, -b as i8, b as i8)
+            }
+        }
+    }
+
+    /// Convert the quantized values back to `f32`.
+    pub fn dequantize(&self, values: &[i8]) -> Vec<f32> {
+        match self {
+            QuantizationStrategy::PerTensorAffineInt8(strategy)
+            | QuantizationStrategy::PerTensorAffineInt4(strategy)
+            | QuantizationStrategy::PerTensorAffineInt2(strategy) => strategy.dequantize(values),
+            QuantizationStrategy::PerTensorSymmetricInt8(strategy)
+            | QuantizationStrategy::PerTensorSymmetricInt4(strategy)
+            | QuantizationStrategy::PerTensorSymmetricInt2(strategy) => strategy.dequantize(values),
         }
     }
 }
@@ -66,7 +152,9 @@ fn valid_scale<E: Float>(mut scale: E) -> E {
     scale
 }
 
-impl<E: Float + Send + Sync, Q: PrimInt + Send + Sync, A: PrimInt> AffineQuantization<E, Q, A> {
+impl<E: Float + Send + Sync, Q: PrimInt + Send + Sync, A: PrimInt + Send + Sync>
+    AffineQuantization<E, Q, A>
+{
     /// Initialize an affine quantization scheme with the given parameters.
     pub fn init(scale: E, offset: Q) -> Self {
         Self {
@@ -75,6 +163,22 @@ impl<E: Float + Send + Sync, Q: PrimInt + Send + Sync, A: PrimInt> AffineQuantiz
             _a: PhantomData,
         }
     }
+
+    /// Convert the values to a lower precision data type, clamped to the quantized range `[a, b]`
+    /// narrower than the range of `Q` for sub-byte data types.
+    pub fn quantize_in_range(&self, values: &[E], a: Q, b: Q) -> Vec<Q> {
+        // Quantized range `[a, b]`
+        let a = E::from(a).unwrap();
+        let b = E::from(b).unwrap();
+
+        // x_q = clamp(round(x / scale + offset), a, b)
+        let z = E::from(self.offset).unwrap();
+        run_par!(|| {
+            iter_slice_par!(values)
+                .map(|x| Q::from(x.div(self.scale).add(z).round().clamp(a, b)).unwrap())
+                .collect()
+        })
+    }
 }
 
 impl<E: Float + Send + Sync, Q: PrimInt + Send + Sync, A: PrimInt + Send + Sync> Quantization<E, Q>
@@ -102,17 +206,7 @@ impl<E: Float + Send + Sync, Q: PrimInt + Send + Sync, A: PrimInt + Send + Sync>
     }
 
     fn quantize(&self, values: &[E]) -> Vec<Q> {
-        // Quantized range `[a, b]`
-        let a = E::from(Q::min_value()).unwrap();
-        let b = E::from(Q::max_value()).unwrap();
-
-        // x_q = clamp(round(x / scale + offset), a, b)
-        let z = E::from(self.offset).unwrap();
-        run_par!(|| {
-            iter_slice_par!(values)
-                .map(|x| Q::from(x.div(self.scale).add(z).round().clamp(a, b)).unwrap())
-                .collect()
-        })
+        self.quantize_in_range(values, Q::min_value(), Q::max_value())
     }
 
     fn dequantize(&self, values: &[Q]) -> Vec<E> {
@@ -150,6 +244,20 @@ impl<E: Float + Send + Sync, Q: PrimInt + Send + Sync> SymmetricQuantization<E,
             _q: PhantomData,
         }
     }
+
+    /// Convert the values to a lower precision data type, clamped to the quantized range `[a, b]`
+    /// narrower than the range of `Q` for sub-byte data types.
+    pub fn quantize_in_range(&self, values: &[E], a: Q, b: Q) -> Vec<Q> {
+        self.quantize_clamped(values, E::from(a).unwrap(), E::from(b).unwrap())
+    }
+
+    fn quantize_clamped(&self, values: &[E], a: E, b: E) -> Vec<Q> {
+        // x_q = clamp(round(x / scale), a, b)
+        values
+            .iter()
+            .map(|x| Q::from(x.div(self.scale).round().clamp(a, b)).unwrap())
+            .collect()
+    }
 }
 
 impl<E: Float + Send + Sync, Q: PrimInt + Send + Sync> Quantization<E, Q>
@@ -179,11 +287,7 @@ impl<E: Float + Send + Sync, Q: PrimInt + Send + Sync> Quantization<E, Q>
         let b = E::from(Q::max_value()).unwrap();
         let a = b.neg();
 
-        // x_q = clamp(round(x / scale), a, b)
-        values
-            .iter()
-            .map(|x| Q::from(x.div(self.scale).round().clamp(a, b)).unwrap())
-            .collect()
+        self.quantize_clamped(values, a, b)
     }
 
     fn dequantize(&self, values: &[Q]) -> Vec<E> {
@@ -313,4 +417,31 @@ mod tests {
 
         assert_eq!(d, expected_d);
     }
+
+    #[test]
+    fn test_int4_affine_quantization_strategy() {
+        let x: [f32; 4] = [-1.8, -1.0, 0.0, 0.5];
+        let (a, b) = QuantizationType::QInt4.range();
+        let strategy = QuantizationStrategy::PerTensorAffineInt4(AffineQuantization::init(
+            2.3 / (b - a) as f32,
+            6,
+        ));
+
+        let q = strategy.quantize(&x);
+
+        assert_eq!(q, vec![-6, -1, 6, 7]);
+    }
+
+    #[test]
+    fn test_int2_symmetric_quantization_strategy() {
+        let x: [f32; 4] = [-1.8, -1.0, 0.0, 0.5];
+        let strategy =
+            QuantizationStrategy::PerTensorSymmetricInt2(SymmetricQuantization::init(0.9));
+
+        let q = strategy.quantize(&x);
+        let d = strategy.dequantize(&q);
+
+        assert_eq!(q, vec![-1, -1, 0, 1]);
+        assert_eq!(d, vec![-0.9, -0.9, 0.0, 0.9]);
+    }
 }
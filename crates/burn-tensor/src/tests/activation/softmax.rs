@@ -12,4 +12,29 @@ mod tests {
 
         output.into_data().assert_approx_eq(&expected, 3);
     }
+
+    #[test]
+    fn test_softmax_d2_dim_0() {
+        let tensor = TestTensor::<2>::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+
+        let output = activation::softmax(tensor, 0);
+        let expected = TensorData::from([
+            [1.5876e-02, 1.5876e-02],
+            [1.1731e-01, 1.1731e-01],
+            [8.6681e-01, 8.6681e-01],
+        ]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn test_softmax_long_rows() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::ones([2, 1000], &device);
+
+        let output = activation::softmax(tensor, 1);
+        let expected = TensorData::full([2, 1000], 1e-3f32);
+
+        output.into_data().assert_approx_eq(&expected, 5);
+    }
 }
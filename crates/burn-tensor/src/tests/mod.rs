@@ -194,15 +194,23 @@ macro_rules! testgen_with_float_param {
         burn_tensor::testgen_module_conv_transpose2d!();
         burn_tensor::testgen_module_conv_transpose3d!();
         burn_tensor::testgen_module_unfold4d!();
+        burn_tensor::testgen_module_fold4d!();
+        burn_tensor::testgen_module_grid_sample!();
+        burn_tensor::testgen_module_roi!();
+        burn_tensor::testgen_module_nms!();
         burn_tensor::testgen_module_max_pool1d!();
         burn_tensor::testgen_module_max_pool2d!();
+        burn_tensor::testgen_module_max_pool3d!();
         burn_tensor::testgen_module_avg_pool1d!();
         burn_tensor::testgen_module_avg_pool2d!();
+        burn_tensor::testgen_module_avg_pool3d!();
         burn_tensor::testgen_module_adaptive_avg_pool1d!();
         burn_tensor::testgen_module_adaptive_avg_pool2d!();
         burn_tensor::testgen_module_nearest_interpolate!();
         burn_tensor::testgen_module_bilinear_interpolate!();
         burn_tensor::testgen_module_bicubic_interpolate!();
+        burn_tensor::testgen_module_norm!();
+        burn_tensor::testgen_module_rotary!();
 
         // test ops
         burn_tensor::testgen_gather_scatter!();
@@ -220,6 +228,7 @@ macro_rules! testgen_with_float_param {
         burn_tensor::testgen_cos!();
         burn_tensor::testgen_create_like!();
         burn_tensor::testgen_div!();
+        burn_tensor::testgen_dyn_named!();
         burn_tensor::testgen_erf!();
         burn_tensor::testgen_exp!();
         burn_tensor::testgen_flatten!();
@@ -251,6 +260,7 @@ macro_rules! testgen_with_float_param {
         burn_tensor::testgen_tanh!();
         burn_tensor::testgen_transpose!();
         burn_tensor::testgen_tri!();
+        burn_tensor::testgen_banded!();
         burn_tensor::testgen_powf!();
         burn_tensor::testgen_any!();
         burn_tensor::testgen_all_op!();
@@ -264,6 +274,12 @@ macro_rules! testgen_with_float_param {
         burn_tensor::testgen_tri_mask!();
         burn_tensor::testgen_sort_argsort!();
         burn_tensor::testgen_topk!();
+        burn_tensor::testgen_kthvalue!();
+        burn_tensor::testgen_scatter_reduce!();
+        burn_tensor::testgen_searchsorted!();
+        burn_tensor::testgen_segment!();
+        burn_tensor::testgen_unique!();
+        burn_tensor::testgen_multinomial!();
         burn_tensor::testgen_remainder!();
         burn_tensor::testgen_cartesian_grid!();
         burn_tensor::testgen_nan!();
@@ -272,12 +288,15 @@ macro_rules! testgen_with_float_param {
         burn_tensor::testgen_ceil!();
         burn_tensor::testgen_select!();
         burn_tensor::testgen_split!();
+        burn_tensor::testgen_to_device!();
+        burn_tensor::testgen_stream!();
         burn_tensor::testgen_prod!();
 
         // test stats
         burn_tensor::testgen_var!();
         burn_tensor::testgen_cov!();
         burn_tensor::testgen_eye!();
+        burn_tensor::testgen_summary!();
 
         // test padding
         burn_tensor::testgen_padding!();
@@ -311,6 +330,7 @@ macro_rules! testgen_with_int_param {
         burn_tensor::testgen_transpose!();
         burn_tensor::testgen_gather_scatter!();
         burn_tensor::testgen_bitwise!();
+        burn_tensor::testgen_kthvalue!();
 
         // test stats
         burn_tensor::testgen_eye!();
@@ -0,0 +1,120 @@
+#[burn_tensor_testgen::testgen(module_avg_pool3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::avg_pool3d;
+    use burn_tensor::Shape;
+
+    #[test]
+    fn test_avg_pool3d_simple() {
+        let test = AvgPool3dTestCase {
+            batch_size: 1,
+            channels: 2,
+            kernel_size: [2, 2, 2],
+            padding: [0, 0, 0],
+            stride: [1, 1, 1],
+            depth: 2,
+            height: 3,
+            width: 3,
+            count_include_pad: true,
+        };
+
+        test.assert_output(TestTensor::from([[
+            [[[6.5, 7.5], [9.5, 10.5]]],
+            [[[24.5, 25.5], [27.5, 28.5]]],
+        ]]));
+    }
+
+    #[test]
+    fn test_avg_pool3d_complex() {
+        let test = AvgPool3dTestCase {
+            batch_size: 1,
+            channels: 2,
+            kernel_size: [2, 2, 2],
+            padding: [1, 1, 1],
+            stride: [1, 2, 2],
+            depth: 2,
+            height: 3,
+            width: 3,
+            count_include_pad: true,
+        };
+
+        test.assert_output(TestTensor::from([[
+            [
+                [[0.0, 0.375], [1.125, 3.0]],
+                [[1.125, 3.0], [4.5, 10.5]],
+                [[1.125, 2.625], [3.375, 7.5]],
+            ],
+            [
+                [[2.25, 4.875], [5.625, 12.0]],
+                [[5.625, 12.0], [13.5, 28.5]],
+                [[3.375, 7.125], [7.875, 16.5]],
+            ],
+        ]]));
+    }
+
+    #[test]
+    fn test_avg_pool3d_complex_dont_include_pad() {
+        let test = AvgPool3dTestCase {
+            batch_size: 1,
+            channels: 2,
+            kernel_size: [2, 2, 2],
+            padding: [1, 1, 1],
+            stride: [1, 2, 2],
+            depth: 2,
+            height: 3,
+            width: 3,
+            count_include_pad: false,
+        };
+
+        test.assert_output(TestTensor::from([[
+            [
+                [[0.0, 1.5], [4.5, 6.0]],
+                [[4.5, 6.0], [9.0, 10.5]],
+                [[9.0, 10.5], [13.5, 15.0]],
+            ],
+            [
+                [[18.0, 19.5], [22.5, 24.0]],
+                [[22.5, 24.0], [27.0, 28.5]],
+                [[27.0, 28.5], [31.5, 33.0]],
+            ],
+        ]]));
+    }
+
+    struct AvgPool3dTestCase {
+        batch_size: usize,
+        channels: usize,
+        kernel_size: [usize; 3],
+        padding: [usize; 3],
+        stride: [usize; 3],
+        depth: usize,
+        height: usize,
+        width: usize,
+        count_include_pad: bool,
+    }
+
+    impl AvgPool3dTestCase {
+        fn assert_output(self, y: TestTensor<5>) {
+            let shape_x = Shape::new([
+                self.batch_size,
+                self.channels,
+                self.depth,
+                self.height,
+                self.width,
+            ]);
+            let x = TestTensor::from(
+                TestTensorInt::arange(0..shape_x.num_elements() as i64, &y.device())
+                    .reshape::<5, _>(shape_x)
+                    .into_data(),
+            );
+            let output = avg_pool3d(
+                x,
+                self.kernel_size,
+                self.stride,
+                self.padding,
+                self.count_include_pad,
+            );
+
+            y.to_data().assert_approx_eq(&output.into_data(), 3);
+        }
+    }
+}
@@ -0,0 +1,59 @@
+#[burn_tensor_testgen::testgen(module_fold4d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{fold4d, unfold4d};
+    use burn_tensor::ops::UnfoldOptions;
+    use burn_tensor::{Shape, TensorData};
+
+    #[test]
+    fn test_fold4d_non_overlapping_is_inverse_of_unfold() {
+        let shape = Shape::new([2, 3, 4, 6]);
+        let x = TestTensor::from(
+            TestTensorInt::arange(0..shape.num_elements() as i64, &Default::default())
+                .reshape::<4, _>(shape)
+                .into_data(),
+        );
+        let options = UnfoldOptions::new([2, 2], [0, 0], [1, 1]);
+
+        let columns = unfold4d(x.clone(), [2, 2], options.clone());
+        let output = fold4d(columns, [4, 6], [2, 2], options);
+
+        output.into_data().assert_approx_eq(&x.into_data(), 3);
+    }
+
+    #[test]
+    fn test_fold4d_sums_overlapping_blocks() {
+        let x = TestTensor::<4>::ones([1, 1, 3, 3], &Default::default());
+        let options = UnfoldOptions::new([1, 1], [0, 0], [1, 1]);
+
+        let columns = unfold4d(x, [2, 2], options.clone());
+        let output = fold4d(columns, [3, 3], [2, 2], options);
+
+        output.into_data().assert_approx_eq(
+            &TensorData::from([[[[1., 2., 1.], [2., 4., 2.], [1., 2., 1.]]]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_fold4d_complex() {
+        let x = TestTensor::<3>::ones([1, 6, 2], &Default::default());
+        let options = UnfoldOptions::new([1, 2], [0, 1], [1, 2]);
+
+        let output = fold4d(x, [3, 4], [2, 3], options);
+
+        output.into_data().assert_approx_eq(
+            &TensorData::from([[[[0., 1., 0., 1.], [0., 2., 0., 2.], [0., 1., 0., 1.]]]]),
+            3,
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_fold4d_invalid_number_of_blocks() {
+        let x = TestTensor::<3>::ones([1, 4, 5], &Default::default());
+        let options = UnfoldOptions::new([1, 1], [0, 0], [1, 1]);
+
+        let _output = fold4d(x, [3, 3], [2, 2], options);
+    }
+}
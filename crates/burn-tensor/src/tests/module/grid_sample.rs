@@ -0,0 +1,148 @@
+#[burn_tensor_testgen::testgen(module_grid_sample)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{affine_grid, grid_sample};
+    use burn_tensor::ops::{GridSampleMode, GridSampleOptions, GridSamplePaddingMode};
+    use burn_tensor::{Shape, TensorData};
+
+    fn input() -> TestTensor<4> {
+        TestTensor::from(
+            TestTensorInt::arange(0..9, &Default::default())
+                .reshape::<4, _>(Shape::new([1, 1, 3, 3]))
+                .into_data(),
+        )
+    }
+
+    fn grid() -> TestTensor<4> {
+        TestTensor::from([[[[0.0, 0.0], [1.0, -1.0], [0.5, 0.25], [-1.0, -1.0]]]])
+    }
+
+    #[test]
+    fn test_grid_sample_identity_with_aligned_corners() {
+        let grid = TestTensor::from([[
+            [[-1.0, -1.0], [0.0, -1.0], [1.0, -1.0]],
+            [[-1.0, 0.0], [0.0, 0.0], [1.0, 0.0]],
+            [[-1.0, 1.0], [0.0, 1.0], [1.0, 1.0]],
+        ]]);
+        let options =
+            GridSampleOptions::new(GridSampleMode::Bilinear, GridSamplePaddingMode::Zeros, true);
+
+        let output = grid_sample(input(), grid, options);
+
+        output.into_data().assert_approx_eq(&input().into_data(), 3);
+    }
+
+    #[test]
+    fn test_grid_sample_bilinear_zeros_padding() {
+        let output = grid_sample(input(), grid(), GridSampleOptions::default());
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[[[4.0, 0.5, 5.875, 0.0]]]]), 3);
+    }
+
+    #[test]
+    fn test_grid_sample_bilinear_border_padding() {
+        let options = GridSampleOptions::new(
+            GridSampleMode::Bilinear,
+            GridSamplePaddingMode::Border,
+            false,
+        );
+
+        let output = grid_sample(input(), grid(), options);
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[[[4.0, 2.0, 5.875, 0.0]]]]), 3);
+    }
+
+    #[test]
+    fn test_grid_sample_nearest() {
+        let grid = TestTensor::from([[[[0.5, 0.25], [-0.6, 0.6], [1.5, 0.0], [0.0, 0.0]]]]);
+        let options =
+            GridSampleOptions::new(GridSampleMode::Nearest, GridSamplePaddingMode::Zeros, false);
+
+        let output = grid_sample(input(), grid, options);
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[[[5.0, 6.0, 0.0, 4.0]]]]), 3);
+    }
+
+    #[test]
+    fn test_grid_sample_bilinear_reflection_padding() {
+        let grid = TestTensor::from([[[[-1.5, 0.0], [1.0, -1.0]]]]);
+        let options = GridSampleOptions::new(
+            GridSampleMode::Bilinear,
+            GridSamplePaddingMode::Reflection,
+            false,
+        );
+
+        let output = grid_sample(input(), grid, options);
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[[[3.25, 2.0]]]]), 3);
+    }
+
+    #[test]
+    fn test_grid_sample_bilinear_reflection_padding_with_aligned_corners() {
+        let grid = TestTensor::from([[[[-1.5, 0.0], [1.0, -1.0]]]]);
+        let options = GridSampleOptions::new(
+            GridSampleMode::Bilinear,
+            GridSamplePaddingMode::Reflection,
+            true,
+        );
+
+        let output = grid_sample(input(), grid, options);
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[[[3.5, 2.0]]]]), 3);
+    }
+
+    #[test]
+    fn test_affine_grid_identity() {
+        let theta = TestTensor::from([[[1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]]);
+
+        let grid = affine_grid(theta, [1, 1, 2, 3], false);
+
+        grid.into_data().assert_approx_eq(
+            &TensorData::from([[
+                [[-0.6667, -0.5], [0.0, -0.5], [0.6667, -0.5]],
+                [[-0.6667, 0.5], [0.0, 0.5], [0.6667, 0.5]],
+            ]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_affine_grid_with_aligned_corners() {
+        let theta = TestTensor::from([[[1.0, 0.0, 0.5], [0.0, 2.0, 0.0]]]);
+
+        let grid = affine_grid(theta, [1, 1, 2, 2], true);
+
+        grid.into_data().assert_approx_eq(
+            &TensorData::from([[[[-0.5, -2.0], [1.5, -2.0]], [[-0.5, 2.0], [1.5, 2.0]]]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_grid_sample_identity_affine_grid() {
+        let theta = TestTensor::from([[[1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]]);
+
+        let grid = affine_grid(theta, [1, 1, 3, 3], false);
+        let output = grid_sample(input(), grid, GridSampleOptions::default());
+
+        output.into_data().assert_approx_eq(&input().into_data(), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_grid_sample_invalid_grid() {
+        let grid = TestTensor::<4>::zeros([1, 2, 2, 3], &Default::default());
+
+        let _output = grid_sample(input(), grid, GridSampleOptions::default());
+    }
+}
@@ -272,6 +272,18 @@ mod tests {
         output_indices.into_data().assert_eq(&indices, false);
     }
 
+    #[test]
+    fn test_max_pool2d_with_indices_non_square() {
+        let x = TestTensor::from([[[[1.0, 5.0, 2.0], [3.0, 4.0, 6.0]]]]);
+        let indices = TensorData::from([[[[1, 5]]]]);
+        let y = TestTensor::<4>::from([[[[5.0, 6.0]]]]);
+
+        let (output, output_indices) = max_pool2d_with_indices(x, [2, 2], [1, 1], [0, 0], [1, 1]);
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+        output_indices.into_data().assert_eq(&indices, false);
+    }
+
     #[test]
     fn test_max_pool2d_complex() {
         let batch_size = 1;
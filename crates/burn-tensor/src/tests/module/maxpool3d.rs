@@ -0,0 +1,94 @@
+#[burn_tensor_testgen::testgen(module_max_pool3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{max_pool3d, max_pool3d_with_indices};
+    use burn_tensor::{Shape, TensorData};
+
+    #[test]
+    fn test_max_pool3d_simple() {
+        let x = arange_input([1, 1, 3, 4, 4]);
+        let y =
+            TestTensor::<5>::from([[[[[21.0, 23.0], [29.0, 31.0]], [[37.0, 39.0], [45.0, 47.0]]]]]);
+
+        let output = max_pool3d(x, [2, 2, 2], [1, 2, 2], [0, 0, 0], [1, 1, 1]);
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+    }
+
+    #[test]
+    fn test_max_pool3d_with_indices_padding_stride() {
+        let x = shuffled_input();
+        let indices = TensorData::from([[[
+            [[5, 6], [5, 6], [13, 13], [13, 13]],
+            [[20, 6], [20, 27], [20, 27], [13, 27]],
+            [[20, 34], [41, 41], [41, 41], [41, 41]],
+            [[33, 34], [41, 41], [41, 41], [41, 41]],
+        ]]]);
+        let y = TestTensor::<5>::from([[[
+            [[35.0, 42.0], [35.0, 42.0], [43.0, 43.0], [43.0, 43.0]],
+            [[44.0, 42.0], [44.0, 45.0], [44.0, 45.0], [43.0, 45.0]],
+            [[44.0, 46.0], [47.0, 47.0], [47.0, 47.0], [47.0, 47.0]],
+            [[39.0, 46.0], [47.0, 47.0], [47.0, 47.0], [47.0, 47.0]],
+        ]]]);
+
+        let (output, output_indices) =
+            max_pool3d_with_indices(x, [2, 3, 3], [1, 1, 2], [1, 1, 1], [1, 1, 1]);
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+        output_indices.into_data().assert_eq(&indices, false);
+    }
+
+    #[test]
+    fn test_max_pool3d_with_indices_dilation() {
+        let x = shuffled_input();
+        let indices = TensorData::from([[[
+            [[26, 27], [20, 13]],
+            [[34, 41], [20, 13]],
+            [[34, 41], [20, 47]],
+        ]]]);
+        let y = TestTensor::<5>::from([[[
+            [[38.0, 45.0], [44.0, 43.0]],
+            [[46.0, 47.0], [44.0, 43.0]],
+            [[46.0, 47.0], [44.0, 41.0]],
+        ]]]);
+
+        let (output, output_indices) =
+            max_pool3d_with_indices(x, [3, 2, 2], [1, 1, 1], [1, 0, 0], [1, 2, 2]);
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+        output_indices.into_data().assert_eq(&indices, false);
+    }
+
+    fn arange_input(shape: [usize; 5]) -> TestTensor<5> {
+        let shape = Shape::new(shape);
+        TestTensor::from(
+            TestTensorInt::arange(0..shape.num_elements() as i64, &Default::default())
+                .reshape::<5, _>(shape)
+                .into_data(),
+        )
+    }
+
+    /// A permutation of `0..48`, so the maximum of every window is unique.
+    fn shuffled_input() -> TestTensor<5> {
+        TestTensor::from([[[
+            [
+                [0.0, 7.0, 14.0, 21.0],
+                [28.0, 35.0, 42.0, 1.0],
+                [8.0, 15.0, 22.0, 29.0],
+                [36.0, 43.0, 2.0, 9.0],
+            ],
+            [
+                [16.0, 23.0, 30.0, 37.0],
+                [44.0, 3.0, 10.0, 17.0],
+                [24.0, 31.0, 38.0, 45.0],
+                [4.0, 11.0, 18.0, 25.0],
+            ],
+            [
+                [32.0, 39.0, 46.0, 5.0],
+                [12.0, 19.0, 26.0, 33.0],
+                [40.0, 47.0, 6.0, 13.0],
+                [20.0, 27.0, 34.0, 41.0],
+            ],
+        ]]])
+    }
+}
@@ -2,6 +2,7 @@ mod adaptive_avgpool1d;
 mod adaptive_avgpool2d;
 mod avgpool1d;
 mod avgpool2d;
+mod avgpool3d;
 mod bicubic_interpolate;
 mod bilinear_interpolate;
 mod conv1d;
@@ -11,8 +12,15 @@ mod conv_transpose1d;
 mod conv_transpose2d;
 mod conv_transpose3d;
 mod deform_conv2d;
+mod fold4d;
 mod forward;
+mod grid_sample;
 mod maxpool1d;
 mod maxpool2d;
+mod maxpool3d;
 mod nearest_interpolate;
+mod nms;
+mod norm;
+mod roi;
+mod rotary;
 mod unfold4d;
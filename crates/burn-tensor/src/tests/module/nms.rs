@@ -0,0 +1,84 @@
+#[burn_tensor_testgen::testgen(module_nms)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{nms, nms_with_options};
+    use burn_tensor::ops::NmsOptions;
+    use burn_tensor::TensorData;
+
+    fn scores() -> TestTensor<3> {
+        TestTensor::from([[[0.9, 0.8, 0.7], [0.1, 0.6, 0.3]]])
+    }
+
+    #[test]
+    fn test_nms() {
+        // The second box overlaps the first one with an intersection over union of 81 / 119.
+        let boxes = TestTensor::from([[
+            [0.0, 0.0, 10.0, 10.0],
+            [1.0, 1.0, 11.0, 11.0],
+            [20.0, 20.0, 30.0, 30.0],
+        ]]);
+
+        let output = nms(boxes, scores(), 0.5);
+
+        output.into_data().assert_eq(
+            &TensorData::from([[0, 0, 0], [0, 0, 2], [0, 1, 1], [0, 1, 2]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn test_nms_keeps_boxes_below_iou_threshold() {
+        let boxes = TestTensor::from([[
+            [0.0, 0.0, 10.0, 10.0],
+            [11.0, 11.0, 1.0, 1.0],
+            [20.0, 20.0, 30.0, 30.0],
+        ]]);
+
+        let output = nms(boxes, scores(), 0.7);
+
+        output.into_data().assert_eq(
+            &TensorData::from([
+                [0, 0, 0],
+                [0, 0, 1],
+                [0, 0, 2],
+                [0, 1, 1],
+                [0, 1, 2],
+                [0, 1, 0],
+            ]),
+            false,
+        );
+    }
+
+    #[test]
+    fn test_nms_with_score_threshold_and_max_output() {
+        let boxes = TestTensor::from([[
+            [0.0, 0.0, 10.0, 10.0],
+            [1.0, 1.0, 11.0, 11.0],
+            [20.0, 20.0, 30.0, 30.0],
+        ]]);
+        let options = NmsOptions::new(0.5, Some(0.5), Some(1), false);
+
+        let output = nms_with_options(boxes, scores(), options);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[0, 0, 0], [0, 1, 1]]), false);
+    }
+
+    #[test]
+    fn test_nms_center_point_box() {
+        let boxes = TestTensor::from([[
+            [5.0, 5.0, 10.0, 10.0],
+            [6.0, 6.0, 10.0, 10.0],
+            [25.0, 25.0, 10.0, 10.0],
+        ]]);
+        let options = NmsOptions::new(0.5, None, None, true);
+
+        let output = nms_with_options(boxes, scores(), options);
+
+        output.into_data().assert_eq(
+            &TensorData::from([[0, 0, 0], [0, 0, 2], [0, 1, 1], [0, 1, 2]]),
+            false,
+        );
+    }
+}
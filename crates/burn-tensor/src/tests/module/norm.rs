@@ -0,0 +1,56 @@
+#[burn_tensor_testgen::testgen(module_norm)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{layer_norm, rms_norm};
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn test_rms_norm() {
+        let device = Default::default();
+        let x = TestTensorInt::<1>::arange(0..9, &device)
+            .float()
+            .reshape([3, 3]);
+        let gamma = TestTensor::<1>::from([1.0, 2.0, 0.5]);
+
+        let output = rms_norm(x, gamma, 1e-5);
+
+        let expected = TensorData::from([
+            [0.0000, 1.5492, 0.7746],
+            [0.7348, 1.9596, 0.6124],
+            [0.8514, 1.9865, 0.5676],
+        ]);
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn test_layer_norm() {
+        let x = TestTensor::<3>::from([
+            [[-0.5, 1.0, 2.0, 0.5], [3.0, -1.0, 0.0, 2.0]],
+            [[0.0, 0.0, 1.0, 1.0], [4.0, 2.0, -2.0, 0.0]],
+        ]);
+        let gamma = TestTensor::<1>::from([1.0, 0.5, 2.0, -1.0]);
+        let beta = TestTensor::<1>::from([0.1, 0.2, -0.3, 0.0]);
+
+        let output = layer_norm(x, gamma, Some(beta), 1e-5);
+
+        let expected = TensorData::from([
+            [
+                [-1.2867, 0.3387, 2.4735, 0.2773],
+                [1.3649, -0.4325, -1.5649, -0.6325],
+            ],
+            [[-0.9, -0.3, 1.7, -1.0], [1.4416, 0.4236, -2.9833, 0.4472]],
+        ]);
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn test_layer_norm_without_beta() {
+        let x = TestTensor::<2>::from([[0.0, 0.0, 1.0, 1.0]]);
+        let gamma = TestTensor::<1>::from([1.0, 1.0, 1.0, 1.0]);
+
+        let output = layer_norm(x, gamma, None, 1e-5);
+
+        let expected = TensorData::from([[-1.0, -1.0, 1.0, 1.0]]);
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+}
@@ -0,0 +1,89 @@
+#[burn_tensor_testgen::testgen(module_roi)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{roi_align, roi_pool};
+    use burn_tensor::ops::{RoiAlignOptions, RoiPoolOptions};
+    use burn_tensor::{Shape, TensorData};
+
+    /// The value of each pixel is `4 * y + x`, so the bilinear interpolation is exact.
+    fn input() -> TestTensor<4> {
+        TestTensor::from(
+            TestTensorInt::arange(0..16, &Default::default())
+                .reshape::<4, _>(Shape::new([1, 1, 4, 4]))
+                .into_data(),
+        )
+    }
+
+    #[test]
+    fn test_roi_align() {
+        let boxes = TestTensor::from([[0.0, 0.0, 0.0, 3.0, 3.0]]);
+        let options = RoiAlignOptions::new([2, 2], 1.0, 1, false);
+
+        let output = roi_align(input(), boxes, options);
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[[[3.75, 5.25], [9.75, 11.25]]]]), 3);
+    }
+
+    #[test]
+    fn test_roi_align_adaptive_sampling_with_scale() {
+        let boxes = TestTensor::from([[0.0, 0.0, 0.0, 6.0, 6.0]]);
+        let options = RoiAlignOptions::new([2, 2], 0.5, 0, false);
+
+        let output = roi_align(input(), boxes, options);
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[[[3.75, 5.25], [9.75, 11.25]]]]), 3);
+    }
+
+    #[test]
+    fn test_roi_align_aligned() {
+        let boxes = TestTensor::from([[0.0, 0.5, 0.5, 3.5, 3.5]]);
+        let options = RoiAlignOptions::new([2, 2], 1.0, 1, true);
+
+        let output = roi_align(input(), boxes, options);
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[[[3.75, 5.25], [9.75, 11.25]]]]), 3);
+    }
+
+    #[test]
+    fn test_roi_align_clamps_points_past_the_border() {
+        let boxes = TestTensor::from([[0.0, 2.0, 2.0, 4.0, 4.0], [0.0, 5.0, 5.0, 7.0, 7.0]]);
+        let options = RoiAlignOptions::new([1, 1], 1.0, 1, false);
+
+        let output = roi_align(input(), boxes, options);
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[[[15.0]]], [[[0.0]]]]), 3);
+    }
+
+    #[test]
+    fn test_roi_pool() {
+        let boxes = TestTensor::from([[0.0, 0.0, 0.0, 3.0, 3.0], [0.0, 1.0, 1.0, 2.0, 2.0]]);
+        let options = RoiPoolOptions::new([2, 2], 1.0);
+
+        let output = roi_pool(input(), boxes, options);
+
+        output.into_data().assert_eq(
+            &TensorData::from([[[[5.0, 7.0], [13.0, 15.0]]], [[[5.0, 6.0], [9.0, 10.0]]]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn test_roi_pool_overlapping_bins() {
+        let boxes = TestTensor::from([[0.0, 0.0, 0.0, 2.0, 2.0]]);
+        let options = RoiPoolOptions::new([2, 2], 1.0);
+
+        let output = roi_pool(input(), boxes, options);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[[[5.0, 6.0], [9.0, 10.0]]]]), false);
+    }
+}
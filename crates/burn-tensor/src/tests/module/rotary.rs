@@ -0,0 +1,46 @@
+#[burn_tensor_testgen::testgen(module_rotary)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::rotary_qk;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn test_rotary_qk() {
+        // Positions rotated by 0, 90 and 180 degrees.
+        let frequencies = TestTensor::<3>::from([
+            [[1.0, 0.0], [1.0, 0.0]],
+            [[0.0, 1.0], [0.0, 1.0]],
+            [[-1.0, 0.0], [-1.0, 0.0]],
+        ]);
+        let queries = TestTensor::<4>::from([[[[1.0, 2.0, 3.0, 4.0]], [[5.0, 6.0, 7.0, 8.0]]]]);
+        let keys = TestTensor::<4>::from([[
+            [[1.0, 0.0, 1.0, 0.0], [0.0, 1.0, 0.0, 1.0]],
+            [[2.0, 3.0, 0.0, 0.0], [1.0, 1.0, 1.0, 1.0]],
+        ]]);
+
+        // Only the first two features of each head are rotated, from the second position.
+        let (queries, keys) = rotary_qk(queries, keys, frequencies, 1);
+
+        queries.into_data().assert_approx_eq(
+            &TensorData::from([[[[-2.0, 1.0, 3.0, 4.0], [-5.0, -6.0, 7.0, 8.0]]]]),
+            3,
+        );
+        keys.into_data().assert_approx_eq(
+            &TensorData::from([[
+                [[0.0, 1.0, 1.0, 0.0], [-2.0, -3.0, 0.0, 0.0]],
+                [[-1.0, 0.0, 0.0, 1.0], [-1.0, -1.0, 1.0, 1.0]],
+            ]]),
+            3,
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_rotary_qk_exceeding_max_sequence_length() {
+        let frequencies = TestTensor::<3>::zeros([2, 4, 2], &Default::default());
+        let queries = TestTensor::<4>::zeros([1, 2, 1, 4], &Default::default());
+        let keys = TestTensor::<4>::zeros([1, 2, 1, 4], &Default::default());
+
+        let _output = rotary_qk(queries, keys, frequencies, 1);
+    }
+}
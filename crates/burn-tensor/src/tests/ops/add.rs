@@ -71,6 +71,57 @@ mod tests {
             .assert_eq(&TensorData::from([[4.0, 7.0], [5.0, 8.0]]), false);
     }
 
+    #[test]
+    fn test_add_same_ops_different_shapes() {
+        // Backends reusing the same kernels when the same operations are executed again must
+        // still handle the new shapes and strides.
+        let add = |tensor_1: TestTensor<2>, tensor_2: TestTensor<2>| {
+            ((tensor_1 * 2) + tensor_2 - 1).into_data()
+        };
+
+        let output = add(
+            TestTensor::from([[0.0, 1.0], [2.0, 3.0]]),
+            TestTensor::from([[4.0, 5.0], [6.0, 7.0]]),
+        );
+        output.assert_eq(&TensorData::from([[3.0, 6.0], [9.0, 12.0]]), false);
+
+        let output = add(
+            TestTensor::from([[0.0, 1.0, 2.0]]),
+            TestTensor::from([[3.0, 4.0, 5.0]]),
+        );
+        output.assert_eq(&TensorData::from([[2.0, 5.0, 8.0]]), false);
+
+        let output = add(
+            TestTensor::from([[0.0, 1.0], [2.0, 3.0]]).transpose(),
+            TestTensor::from([[4.0, 5.0], [6.0, 7.0]]),
+        );
+        output.assert_eq(&TensorData::from([[3.0, 8.0], [7.0, 12.0]]), false);
+
+        let output = add(
+            TestTensor::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]).transpose(),
+            TestTensor::from([[6.0, 7.0], [8.0, 9.0], [10.0, 11.0]]),
+        );
+        output.assert_eq(
+            &TensorData::from([[5.0, 12.0], [9.0, 16.0], [13.0, 20.0]]),
+            false,
+        );
+
+        let output = add(
+            TestTensor::from([[0.0, 1.0], [2.0, 3.0]]).transpose(),
+            TestTensor::from([[4.0, 5.0], [6.0, 7.0]]).transpose(),
+        );
+        output.assert_eq(&TensorData::from([[3.0, 9.0], [6.0, 12.0]]), false);
+
+        let output = add(
+            TestTensor::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]).transpose(),
+            TestTensor::from([[6.0, 7.0, 8.0], [9.0, 10.0, 11.0]]).transpose(),
+        );
+        output.assert_eq(
+            &TensorData::from([[5.0, 14.0], [8.0, 17.0], [11.0, 20.0]]),
+            false,
+        );
+    }
+
     #[test]
     fn should_support_add_scalar_ops() {
         let scalar = 2.0;
@@ -0,0 +1,135 @@
+#[burn_tensor_testgen::testgen(banded)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    fn matrix() -> TestTensor<2> {
+        TestTensor::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]])
+    }
+
+    #[test]
+    fn test_diagonal() {
+        let tensor = matrix();
+
+        tensor
+            .clone()
+            .diagonal::<1>(0)
+            .into_data()
+            .assert_eq(&TensorData::from([1.0, 5.0, 9.0]), false);
+        tensor
+            .clone()
+            .diagonal::<1>(1)
+            .into_data()
+            .assert_eq(&TensorData::from([2.0, 6.0]), false);
+        tensor
+            .diagonal::<1>(-2)
+            .into_data()
+            .assert_eq(&TensorData::from([7.0]), false);
+    }
+
+    #[test]
+    fn test_diagonal_batch() {
+        let tensor = TestTensor::<3>::from([[[1.0, 2.0], [3.0, 4.0]], [[5.0, 6.0], [7.0, 8.0]]]);
+
+        tensor
+            .diagonal::<2>(0)
+            .into_data()
+            .assert_eq(&TensorData::from([[1.0, 4.0], [5.0, 8.0]]), false);
+    }
+
+    #[test]
+    fn test_put_on_diagonal() {
+        let values = TestTensor::<1>::from([10.0, 20.0]);
+
+        let output = matrix().put_on_diagonal(values, -1);
+
+        output.into_data().assert_eq(
+            &TensorData::from([[1.0, 2.0, 3.0], [10.0, 5.0, 6.0], [7.0, 20.0, 9.0]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn test_put_on_diagonal_rectangular() {
+        let tensor = TestTensorInt::<2>::zeros([2, 4], &Default::default());
+        let values = TestTensorInt::<1>::from([1, 2]);
+
+        let output = tensor.put_on_diagonal(values, 2);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[0, 0, 1, 0], [0, 0, 0, 2]]), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_put_on_diagonal_invalid_length() {
+        let values = TestTensor::<1>::from([1.0, 2.0, 3.0]);
+
+        let _output = matrix().put_on_diagonal(values, 1);
+    }
+
+    #[test]
+    fn test_band() {
+        let output = matrix().band(0, 1);
+
+        output.into_data().assert_eq(
+            &TensorData::from([[1.0, 2.0, 0.0], [0.0, 5.0, 6.0], [0.0, 0.0, 9.0]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn test_bands_round_trip() {
+        let bands = matrix().bands(1, 1);
+
+        bands.clone().into_data().assert_eq(
+            &TensorData::from([[0.0, 4.0, 8.0], [1.0, 5.0, 9.0], [2.0, 6.0, 0.0]]),
+            false,
+        );
+        TestTensor::from_bands(bands, 1, 1, 3)
+            .into_data()
+            .assert_eq(&matrix().band(1, 1).into_data(), false);
+    }
+
+    #[test]
+    fn test_banded_matmul() {
+        let bands = matrix().bands(1, 1);
+        let rhs = TestTensor::from([[1.0, 0.0], [0.0, 1.0], [1.0, 1.0]]);
+
+        let output = bands.banded_matmul(rhs.clone(), 1, 1);
+
+        output
+            .into_data()
+            .assert_approx_eq(&matrix().band(1, 1).matmul(rhs).into_data(), 3);
+    }
+
+    #[test]
+    fn test_banded_matmul_rectangular() {
+        let tensor = TestTensor::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let bands = tensor.bands(0, 2);
+        let rhs = TestTensor::<2>::ones([3, 1], &Default::default());
+
+        bands.clone().into_data().assert_eq(
+            &TensorData::from([[1.0, 5.0], [2.0, 6.0], [3.0, 0.0]]),
+            false,
+        );
+        bands
+            .banded_matmul(rhs, 0, 2)
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[6.0], [11.0]]), 3);
+    }
+
+    #[test]
+    fn test_toeplitz() {
+        let column = TestTensor::<1>::from([1.0, 2.0, 3.0]);
+        let row = TestTensor::<1>::from([1.0, 4.0]);
+
+        let output = TestTensor::toeplitz(column, row);
+
+        output.into_data().assert_eq(
+            &TensorData::from([[1.0, 4.0], [2.0, 1.0], [3.0, 2.0]]),
+            false,
+        );
+    }
+}
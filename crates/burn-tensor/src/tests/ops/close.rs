@@ -21,4 +21,21 @@ mod tests {
         let tensor2 = TestTensor::from([[0.0, 1.0, 0.0], [1.0, -1.0, 1.0]]) + 1e-9;
         assert!(tensor1.all_close(tensor2, None, None));
     }
+
+    #[test]
+    fn test_is_close_infinities() {
+        let tensor1 = TestTensor::<1>::from([f32::INFINITY, f32::NEG_INFINITY, f32::INFINITY, 1.0]);
+        let tensor2 = TestTensor::from([f32::INFINITY, f32::NEG_INFINITY, f32::NEG_INFINITY, 1.0]);
+
+        let data_actual = tensor1
+            .clone()
+            .is_close(tensor2.clone(), None, None)
+            .into_data();
+        let data_expected = TensorData::from([true, true, false, true]);
+        assert_eq!(data_expected, data_actual);
+
+        // The reduction stays on the device until the result is read.
+        let all_close = tensor1.is_close(tensor2, None, None).all();
+        assert_eq!(all_close.into_data(), TensorData::from([false]));
+    }
 }
@@ -0,0 +1,88 @@
+#[burn_tensor_testgen::testgen(dyn_named)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Distribution, Tensor};
+
+    fn random<const D: usize>(shape: [usize; D]) -> TestTensor<D> {
+        TestTensor::random(shape, Distribution::Default, &Default::default())
+    }
+
+    #[test]
+    fn should_matmul_self_attention_scores() {
+        let query = random([1, 2, 3, 4]);
+        let key = random([1, 2, 3, 4]);
+        let expected = query.clone().matmul(key.clone().swap_dims(2, 3));
+
+        let query = query.with_dim_names(["batch", "heads", "seq", "d_k"]);
+        let key = key.with_dim_names(["batch", "heads", "seq", "d_k"]);
+        let scores = query.matmul(key.swap_dims("seq", "d_k"));
+
+        assert_eq!(scores.names().names(), &["batch", "heads", "seq", "seq"]);
+        assert_eq!(scores.dim("heads"), 2);
+        scores
+            .into_tensor()
+            .into_data()
+            .assert_eq(&expected.into_data(), true);
+    }
+
+    #[test]
+    fn should_address_matmul_output_once_renamed() {
+        let scores = random([2, 3, 4])
+            .with_dim_names(["batch", "seq", "d_k"])
+            .matmul(random([2, 4, 5]).with_dim_names(["batch", "d_k", "seq_kv"]))
+            .check(["batch", "seq", "seq_kv"]);
+        assert_eq!(scores.shape().dims, [2, 3, 5]);
+
+        let scores = random([2, 3, 4])
+            .with_dim_names(["batch", "seq", "d_k"])
+            .matmul(random([2, 4, 3]).with_dim_names(["batch", "d_k", "seq"]))
+            .rename(["batch", "seq_q", "seq_k"]);
+        assert_eq!(scores.dim("seq_k"), 3);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Expected dim 'd_k' got 'seq'")]
+    fn should_panic_matmul_with_mismatched_dims() {
+        let query = random([1, 3, 4]).with_dim_names(["batch", "seq", "d_k"]);
+        let key = random([1, 3, 4]).with_dim_names(["batch", "seq", "d_k"]);
+
+        let _ = query.matmul(key);
+    }
+
+    #[test]
+    fn should_swap_dims_by_name() {
+        let tensor = random([2, 3, 4]);
+        let expected = tensor.clone().swap_dims(1, 2);
+
+        let swapped = tensor
+            .with_dim_names(["batch", "seq", "d_model"])
+            .swap_dims("seq", "d_model");
+
+        assert_eq!(swapped.names().names(), &["batch", "d_model", "seq"]);
+        assert_eq!(swapped.dim("seq"), 3);
+        swapped
+            .into_tensor()
+            .into_data()
+            .assert_eq(&expected.into_data(), true);
+    }
+
+    #[test]
+    fn should_reduce_dims_by_name() {
+        let tensor = TestTensor::<3>::from([[[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]]);
+        let named = tensor.with_dim_names(["batch", "seq", "d_model"]);
+
+        let sum = named.clone().sum_dim("seq");
+        assert_eq!(sum.names().names(), &["batch", "seq", "d_model"]);
+        sum.into_tensor()
+            .into_data()
+            .assert_eq(&TestTensor::<3>::from([[[9.0, 12.0]]]).into_data(), false);
+
+        let mean = named.mean_dim("d_model");
+        assert_eq!(mean.shape().dims, [1, 3, 1]);
+        mean.into_tensor().into_data().assert_eq(
+            &TestTensor::<3>::from([[[1.5], [3.5], [5.5]]]).into_data(),
+            false,
+        );
+    }
+}
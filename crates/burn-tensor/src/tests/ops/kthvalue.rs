@@ -0,0 +1,88 @@
+#[burn_tensor_testgen::testgen(kthvalue)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn test_kthvalue_1d() {
+        let tensor = TestTensorInt::<1>::from([5, 1, 4, 2, 3]);
+
+        let values = tensor.kthvalue(1, 0);
+
+        values.into_data().assert_eq(&TensorData::from([2]), false);
+    }
+
+    #[test]
+    fn test_kthvalue_with_indices_3d() {
+        let tensor = TestTensorInt::<3>::from([[[1, 4, 7], [2, 5, 6]], [[3, 0, 9], [8, 2, -8]]]);
+
+        let (values, indices) = tensor.kthvalue_with_indices(2, 2);
+
+        values
+            .into_data()
+            .assert_eq(&TensorData::from([[[7], [6]], [[9], [8]]]), false);
+        indices
+            .into_data()
+            .assert_eq(&TensorData::from([[[2], [2]], [[2], [0]]]), false);
+    }
+
+    #[test]
+    fn test_kthvalue_middle_dim() {
+        let tensor = TestTensorInt::<3>::from([[[1, 4, 7], [2, 5, 6]], [[3, 0, 9], [8, 2, -8]]]);
+
+        let (values, indices) = tensor.kthvalue_with_indices(0, 1);
+
+        values
+            .into_data()
+            .assert_eq(&TensorData::from([[[1, 4, 6]], [[3, 0, -8]]]), false);
+        indices
+            .into_data()
+            .assert_eq(&TensorData::from([[[0, 0, 1]], [[0, 0, 1]]]), false);
+    }
+
+    #[test]
+    fn test_kthvalue_with_extreme_values() {
+        let max = IntType::MAX;
+        let min = IntType::MIN;
+        let tensor = TestTensorInt::<2>::from([[max, min, 0 as IntType, min + 1]]);
+
+        let values = tensor.kthvalue(2, 1);
+
+        values
+            .into_data()
+            .assert_eq(&TensorData::from([[0 as IntType]]), false);
+    }
+
+    #[test]
+    fn test_median_dim() {
+        let tensor = TestTensorInt::<2>::from([[7, 1, 4, 3], [2, 9, 5, 6], [1, 1, 1, 1]]);
+
+        let values = tensor.median_dim(1);
+
+        values
+            .into_data()
+            .assert_eq(&TensorData::from([[3], [5], [1]]), false);
+    }
+
+    #[test]
+    fn test_median_dim_odd() {
+        let tensor = TestTensorInt::<2>::from([[5, 3, 1], [2, 8, 4]]);
+
+        let (values, indices) = tensor.median_dim_with_indices(1);
+
+        values
+            .into_data()
+            .assert_eq(&TensorData::from([[3], [4]]), false);
+        indices
+            .into_data()
+            .assert_eq(&TensorData::from([[1], [2]]), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_kthvalue_out_of_range() {
+        let tensor = TestTensorInt::<1>::from([5, 1, 4]);
+
+        let _values = tensor.kthvalue(3, 0);
+    }
+}
@@ -173,4 +173,90 @@ mod tests {
 
         output.into_data().assert_eq(&expected, false);
     }
+
+    #[test]
+    fn should_support_mask_assign_ops() {
+        let device = Default::default();
+        let tensor = TestTensor::from_data([[1.0, 7.0], [2.0, f32::NEG_INFINITY]], &device);
+        let mask = TestTensorBool::<2>::from_bool(
+            TensorData::from([[true, false], [false, true]]),
+            &device,
+        );
+        let values = TestTensor::<1>::from_data([1.8, 4.8], &device);
+
+        let output = tensor.mask_assign(mask, values);
+        let expected = TensorData::from([[1.8, 7.0], [2.0, 4.8]]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn should_support_int_mask_assign_ops() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<2>::from_data([[1, 7], [2, 3]], &device);
+        let mask = TestTensorBool::<2>::from_bool(
+            TensorData::from([[false, true], [true, true]]),
+            &device,
+        );
+        let values = TestTensorInt::<1>::from_data([4, 5, 6], &device);
+
+        let output = tensor.mask_assign(mask, values);
+        let expected = TensorData::from([[1, 4], [5, 6]]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn should_support_mask_assign_with_empty_mask() {
+        let device = Default::default();
+        let tensor = TestTensor::from_data([[1.0, 7.0], [2.0, 3.0]], &device);
+        let mask = TestTensorBool::<2>::from_bool(
+            TensorData::from([[false, false], [false, false]]),
+            &device,
+        );
+        let values = TestTensor::<1>::empty([0], &device);
+
+        let output = tensor.mask_assign(mask, values);
+        let expected = TensorData::from([[1.0, 7.0], [2.0, 3.0]]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_mask_assign_values_dont_match_the_mask() {
+        let device = Default::default();
+        let tensor = TestTensor::from_data([[1.0, 7.0], [2.0, 3.0]], &device);
+        let mask = TestTensorBool::<2>::from_bool(
+            TensorData::from([[true, false], [false, true]]),
+            &device,
+        );
+        let values = TestTensor::<1>::from_data([1.8, 2.8, 4.8], &device);
+
+        let _output = tensor.mask_assign(mask, values);
+    }
+
+    #[test]
+    fn should_support_index_fill_ops() {
+        let device = Default::default();
+        let tensor = TestTensor::from_data([[1.0, 7.0, 3.0], [2.0, 3.0, 4.0]], &device);
+        let indices = TestTensorInt::<1>::from_data([0, 2], &device);
+
+        let output = tensor.index_fill(1, indices, -1.0);
+        let expected = TensorData::from([[-1.0, 7.0, -1.0], [-1.0, 3.0, -1.0]]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn should_support_int_index_fill_ops() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<2>::from_data([[1, 7, 3], [2, 3, 4]], &device);
+        let indices = TestTensorInt::<1>::from_data([1, 1], &device);
+
+        let output = tensor.index_fill(0, indices, 9);
+        let expected = TensorData::from([[1, 7, 3], [9, 9, 9]]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
 }
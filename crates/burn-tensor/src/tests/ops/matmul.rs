@@ -1,7 +1,7 @@
 #[burn_tensor_testgen::testgen(matmul)]
 mod tests {
     use super::*;
-    use burn_tensor::{Int, Tensor, TensorData};
+    use burn_tensor::{activation, Int, Tensor, TensorData};
 
     #[test]
     fn test_matmul_d2() {
@@ -16,6 +16,21 @@ mod tests {
         tensor_3.into_data().assert_eq(&expected, false);
     }
 
+    #[test]
+    fn test_matmul_d2_bias_relu_residual() {
+        let device = Default::default();
+        let tensor_1 = TestTensor::<2>::from_floats([[1.0, 7.0], [2.0, 3.0], [1.0, 5.0]], &device);
+        let tensor_2 = TestTensor::from_floats([[4.0, 7.0, 5.0], [2.0, 3.0, 5.0]], &device);
+        let bias = TestTensor::<1>::from_floats([-20.0, 0.0, 5.0], &device);
+        let residual = TestTensor::<2>::ones([3, 3], &device);
+
+        let output = tensor_1.matmul(tensor_2) + bias.unsqueeze();
+        let output = activation::relu(output) + residual;
+        let expected = TensorData::from([[1.0, 29.0, 46.0], [1.0, 24.0, 31.0], [1.0, 23.0, 36.0]]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+
     #[test]
     fn test_matmul_d3() {
         let device = Default::default();
@@ -7,6 +7,7 @@ mod arange;
 mod arange_step;
 mod arg;
 mod argwhere_nonzero;
+mod banded;
 mod bitwise;
 mod bool;
 mod cartesian_grid;
@@ -19,6 +20,8 @@ mod close;
 mod cos;
 mod create_like;
 mod div;
+#[cfg(feature = "experimental-named-tensor")]
+mod dyn_named;
 mod erf;
 mod exp;
 mod expand;
@@ -29,6 +32,7 @@ mod full;
 mod gather_scatter;
 mod init;
 mod iter_dim;
+mod kthvalue;
 mod log;
 mod log1p;
 mod map_comparison;
@@ -37,6 +41,7 @@ mod matmul;
 mod maxmin;
 mod movedim;
 mod mul;
+mod multinomial;
 mod nan;
 mod narrow;
 mod neg;
@@ -53,6 +58,9 @@ mod repeat;
 mod repeat_dim;
 mod reshape;
 mod round;
+mod scatter_reduce;
+mod searchsorted;
+mod segment;
 mod select;
 mod sign;
 mod sin;
@@ -62,9 +70,20 @@ mod split;
 mod sqrt;
 mod squeeze;
 mod stack;
+mod stream;
 mod sub;
 mod tanh;
+mod to_device;
 mod topk;
 mod transpose;
 mod tri;
 mod tri_mask;
+mod unique;
+
+// The tensors with named dimensions are only tested when they are enabled.
+#[cfg(not(feature = "experimental-named-tensor"))]
+#[allow(missing_docs)]
+#[macro_export]
+macro_rules! testgen_dyn_named {
+    () => {};
+}
@@ -0,0 +1,59 @@
+#[burn_tensor_testgen::testgen(multinomial)]
+mod tests {
+    use super::*;
+    use burn_tensor::{ElementConversion, TensorData};
+
+    #[test]
+    fn test_multinomial_one_hot_probabilities() {
+        let tensor = TestTensor::<2>::from([[0.0, 0.0, 1.0], [1.0, 0.0, 0.0]]);
+
+        let output = tensor.multinomial(4, true);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[2, 2, 2, 2], [0, 0, 0, 0]]), false);
+    }
+
+    #[test]
+    fn test_multinomial_without_replacement_draws_distinct_categories() {
+        let tensor = TestTensor::<1>::from([0.5, 0.0, 2.0, 0.0, 1.0]);
+
+        let output = tensor.multinomial(3, false);
+
+        let mut indices = output.into_data().to_vec::<i64>().unwrap();
+        indices.sort();
+        assert_eq!(indices, vec![0, 2, 4]);
+    }
+
+    #[test]
+    fn test_multinomial_frequencies_follow_probabilities() {
+        let num_samples = 4000;
+        let tensor = TestTensor::<1>::from([0.1, 0.2, 0.7]);
+
+        let output = tensor.multinomial(num_samples, true);
+
+        for (category, probability) in [0.1, 0.2, 0.7].into_iter().enumerate() {
+            let count = output
+                .clone()
+                .equal_elem(category as i64)
+                .int()
+                .sum()
+                .into_scalar()
+                .elem::<f64>();
+            let frequency = count / num_samples as f64;
+
+            assert!(
+                (frequency - probability).abs() < 0.05,
+                "Category {category} was drawn with frequency {frequency}, expected {probability}"
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_multinomial_without_replacement_too_many_samples() {
+        let tensor = TestTensor::<1>::from([0.5, 0.5]);
+
+        let _ = tensor.multinomial(3, false);
+    }
+}
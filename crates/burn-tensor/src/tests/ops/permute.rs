@@ -31,6 +31,47 @@ mod tests {
         permuted.into_data().assert_eq(&tensor.into_data(), true);
     }
 
+    #[test]
+    fn permute_float_chain() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<1>::arange(0..24, &device)
+            .reshape([2, 3, 4])
+            .float();
+
+        // Permutations cancelling each other
+        let permuted = tensor.clone().permute([2, 0, 1]).permute([1, 2, 0]);
+        permuted.into_data().assert_eq(&tensor.to_data(), true);
+
+        let swapped = tensor.clone().swap_dims(1, 2).swap_dims(2, 1);
+        swapped.into_data().assert_eq(&tensor.to_data(), true);
+
+        // Consecutive permutations
+        let permuted = tensor
+            .clone()
+            .permute([1, 0, 2])
+            .swap_dims(0, 2)
+            .permute([0, 2, 1]);
+        let expected = tensor.clone().permute([2, 1, 0]);
+        permuted.into_data().assert_eq(&expected.into_data(), true);
+
+        // The views don't affect the operations on the permuted tensors
+        let permuted = tensor.clone().permute([2, 1, 0]);
+        let output = (permuted.clone() + 1.0).permute([2, 1, 0]);
+        output
+            .into_data()
+            .assert_eq(&(tensor.clone() + 1.0).into_data(), true);
+        permuted
+            .sum()
+            .into_data()
+            .assert_eq(&TensorData::from([276.0]), false);
+
+        // The permuted tensor is dropped before the view is permuted again
+        let permuted = (tensor.clone() * 2.0).permute([2, 0, 1]).permute([1, 2, 0]);
+        permuted
+            .into_data()
+            .assert_eq(&(tensor * 2.0).into_data(), true);
+    }
+
     #[test]
     fn permute_float() {
         let device = Default::default();
@@ -1,7 +1,7 @@
 #[burn_tensor_testgen::testgen(random)]
 mod tests {
     use super::*;
-    use burn_tensor::{cast::ToElement, tests::Float, Distribution, Tensor};
+    use burn_tensor::{cast::ToElement, tests::Float, Distribution, Tensor, TensorData};
 
     #[test]
     fn rand_default() {
@@ -35,4 +35,104 @@ mod tests {
 
         assert_eq!(tensor.into_data(), [FloatType::new(1f32); 20].into());
     }
+
+    #[test]
+    fn rand_exponential() {
+        let tensor =
+            TestTensor::<1>::random([20], Distribution::Exponential(2.), &Default::default());
+
+        tensor.into_data().assert_within_range(0.0..f32::INFINITY);
+    }
+
+    #[test]
+    fn rand_gamma() {
+        let tensor =
+            TestTensor::<1>::random([20], Distribution::Gamma(0.5, 2.), &Default::default());
+
+        tensor.into_data().assert_within_range(0.0..f32::INFINITY);
+    }
+
+    #[test]
+    fn rand_poisson() {
+        let tensor = TestTensor::<1>::random([20], Distribution::Poisson(3.), &Default::default());
+
+        for value in tensor.into_data().iter::<f32>() {
+            assert!(
+                value >= 0.0 && value == value.round(),
+                "Invalid sample {value}"
+            );
+        }
+    }
+
+    #[test]
+    fn rand_dirichlet() {
+        let tensor = TestTensor::<2>::random_dirichlet([4, 5], 0.8, &Default::default());
+
+        let tensor = tensor.sum_dim(1);
+
+        tensor
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[1.0], [1.0], [1.0], [1.0]]), 3);
+    }
+
+    #[test]
+    fn randperm() {
+        let tensor = TestTensorInt::<1>::randperm(10, &Default::default());
+
+        let mut values = tensor.into_data().iter::<i64>().collect::<Vec<_>>();
+        values.sort();
+        assert_eq!(values, (0..10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn rand_distributions_moments() {
+        let distributions = [
+            Distribution::Default,
+            Distribution::Uniform(-2., 4.),
+            Distribution::Bernoulli(0.3),
+            Distribution::Normal(2., 0.5),
+            Distribution::Exponential(2.),
+            Distribution::Gamma(2., 1.5),
+            Distribution::Poisson(3.),
+        ];
+
+        for distribution in distributions {
+            assert_moments(distribution);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn rand_invalid_distribution() {
+        let _tensor =
+            TestTensor::<1>::random([20], Distribution::Normal(0., -1.), &Default::default());
+    }
+
+    /// Check that the sample mean and variance match the distribution, the tolerance of the mean
+    /// being five standard errors.
+    fn assert_moments(distribution: Distribution) {
+        let num_samples = 10_000;
+        let tensor = TestTensor::<1>::random([num_samples], distribution, &Default::default());
+
+        let values = tensor.into_data().iter::<f64>().collect::<Vec<_>>();
+        let mean = values.iter().sum::<f64>() / num_samples as f64;
+        let variance = values
+            .iter()
+            .map(|value| (value - mean).powi(2))
+            .sum::<f64>()
+            / num_samples as f64;
+
+        let expected_mean = distribution.mean();
+        let expected_variance = distribution.variance();
+        let tolerance = 5.0 * (expected_variance / num_samples as f64).sqrt();
+
+        assert!(
+            (mean - expected_mean).abs() < tolerance,
+            "{distribution:?}: expected mean {expected_mean}, got {mean}"
+        );
+        assert!(
+            (variance - expected_variance).abs() < 0.15 * expected_variance,
+            "{distribution:?}: expected variance {expected_variance}, got {variance}"
+        );
+    }
 }
@@ -0,0 +1,125 @@
+#[burn_tensor_testgen::testgen(scatter_reduce)]
+mod tests {
+    use super::*;
+    use burn_tensor::{ScatterReduceOptions, ScatterReduction, TensorData};
+
+    fn input() -> (TestTensor<2>, TestTensorInt<2>, TestTensor<2>) {
+        let tensor = TestTensor::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let indices = TestTensorInt::from([[0, 0, 2], [1, 1, 1]]);
+        let values = TestTensor::from([[5.0, -1.0, 2.0], [3.0, 7.0, 1.0]]);
+
+        (tensor, indices, values)
+    }
+
+    #[test]
+    fn should_scatter_reduce_sum() {
+        let (tensor, indices, values) = input();
+
+        let output = tensor.scatter_reduce(1, indices, values, ScatterReduction::Sum);
+
+        output.into_data().assert_eq(
+            &TensorData::from([[5.0, 2.0, 5.0], [4.0, 16.0, 6.0]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn should_scatter_reduce_prod() {
+        let (tensor, indices, values) = input();
+
+        let output = tensor.scatter_reduce(1, indices, values, ScatterReduction::Prod);
+
+        output.into_data().assert_eq(
+            &TensorData::from([[-5.0, 2.0, 6.0], [4.0, 105.0, 6.0]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn should_scatter_reduce_mean() {
+        let (tensor, indices, values) = input();
+
+        let output = tensor.scatter_reduce(1, indices, values, ScatterReduction::Mean);
+
+        output.into_data().assert_approx_eq(
+            &TensorData::from([[5.0 / 3.0, 2.0, 2.5], [4.0, 4.0, 6.0]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn should_scatter_reduce_mean_excluding_self() {
+        let (tensor, indices, values) = input();
+        let options = ScatterReduceOptions::new(ScatterReduction::Mean).with_include_self(false);
+
+        let output = tensor.scatter_reduce(1, indices, values, options);
+
+        output.into_data().assert_approx_eq(
+            &TensorData::from([[2.0, 2.0, 2.0], [4.0, 11.0 / 3.0, 6.0]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn should_scatter_reduce_max() {
+        let (tensor, indices, values) = input();
+
+        let output = tensor.scatter_reduce(1, indices, values, ScatterReduction::Max);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[5.0, 2.0, 3.0], [4.0, 7.0, 6.0]]), false);
+    }
+
+    #[test]
+    fn should_scatter_reduce_max_excluding_self() {
+        let (tensor, indices, values) = input();
+        let options = ScatterReduceOptions::new(ScatterReduction::Max).with_include_self(false);
+
+        let output = tensor.scatter_reduce(1, indices, values, options);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[5.0, 2.0, 2.0], [4.0, 7.0, 6.0]]), false);
+    }
+
+    #[test]
+    fn should_scatter_reduce_min_deterministic() {
+        let (tensor, indices, values) = input();
+        let options = ScatterReduceOptions::new(ScatterReduction::Min).with_deterministic(true);
+
+        let output = tensor.scatter_reduce(1, indices, values, options);
+
+        output.into_data().assert_eq(
+            &TensorData::from([[-1.0, 2.0, 2.0], [4.0, 1.0, 6.0]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn should_scatter_reduce_dim_0() {
+        let tensor = TestTensor::<2>::from([[0.0, 0.0], [0.0, 0.0]]);
+        let indices = TestTensorInt::from([[1, 0], [1, 0], [0, 0]]);
+        let values = TestTensor::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+        let options = ScatterReduceOptions::new(ScatterReduction::Max).with_include_self(false);
+
+        let output = tensor.scatter_reduce(0, indices, values, options);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[5.0, 6.0], [3.0, 0.0]]), false);
+    }
+
+    #[test]
+    fn should_scatter_reduce_int() {
+        let tensor = TestTensorInt::<2>::from([[1, 2, 3], [4, 5, 6]]);
+        let indices = TestTensorInt::from([[0, 0, 2], [1, 1, 1]]);
+        let values = TestTensorInt::from([[5, -1, 2], [3, 7, 1]]);
+
+        let output = tensor.scatter_reduce(1, indices, values, ScatterReduction::Max);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[5, 2, 3], [4, 7, 6]]), false);
+    }
+}
@@ -0,0 +1,54 @@
+#[burn_tensor_testgen::testgen(searchsorted)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_support_searchsorted_left() {
+        let sorted = TestTensor::<1>::from([1.0, 3.0, 3.0, 5.0, 7.0]);
+        let values = TestTensor::<2>::from([[3.0, 6.0, 0.0], [9.0, 1.0, 5.0]]);
+
+        let output = sorted.searchsorted(values, false);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[1, 4, 0], [5, 0, 3]]), false);
+    }
+
+    #[test]
+    fn should_support_searchsorted_right() {
+        let sorted = TestTensor::<1>::from([1.0, 3.0, 3.0, 5.0, 7.0]);
+        let values = TestTensor::<2>::from([[3.0, 6.0, 0.0], [9.0, 1.0, 5.0]]);
+
+        let output = sorted.searchsorted(values, true);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[3, 4, 0], [5, 1, 4]]), false);
+    }
+
+    #[test]
+    fn should_support_bucketize() {
+        let tensor = TestTensor::<1>::from([4.0, 12.0, 18.0, 35.0, 70.0]);
+        let boundaries = TestTensor::<1>::from([12.0, 18.0, 65.0]);
+
+        let output = tensor.clone().bucketize(boundaries.clone(), false);
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([0, 0, 1, 2, 3]), false);
+
+        let output = tensor.bucketize(boundaries, true);
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([0, 1, 2, 2, 3]), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_sorted_sequence_is_not_1d() {
+        let sorted = TestTensor::<2>::from([[1.0, 3.0], [5.0, 7.0]]);
+        let values = TestTensor::<1>::from([2.0]);
+
+        let _output = sorted.searchsorted(values, false);
+    }
+}
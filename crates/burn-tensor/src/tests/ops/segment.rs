@@ -0,0 +1,78 @@
+#[burn_tensor_testgen::testgen(segment)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    fn sorted_input() -> (TestTensor<2>, TestTensorInt<1>) {
+        let tensor = TestTensor::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0], [-1.0, -7.0]]);
+        let ids = TestTensorInt::from([0, 0, 2, 2]);
+
+        (tensor, ids)
+    }
+
+    #[test]
+    fn test_segment_sum() {
+        let (tensor, ids) = sorted_input();
+
+        let output = tensor.segment_sum(ids, 4);
+
+        output.into_data().assert_eq(
+            &TensorData::from([[4.0, 6.0], [0.0, 0.0], [4.0, -1.0], [0.0, 0.0]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn test_segment_mean() {
+        let (tensor, ids) = sorted_input();
+
+        let output = tensor.segment_mean(ids, 4);
+
+        output.into_data().assert_approx_eq(
+            &TensorData::from([[2.0, 3.0], [0.0, 0.0], [2.0, -0.5], [0.0, 0.0]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_segment_max() {
+        let (tensor, ids) = sorted_input();
+
+        let output = tensor.segment_max(ids, 4);
+
+        output.into_data().assert_eq(
+            &TensorData::from([[3.0, 4.0], [0.0, 0.0], [5.0, 6.0], [0.0, 0.0]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn test_segment_max_negative_values() {
+        let tensor = TestTensor::<2>::from([[-1.0, -2.0], [-3.0, 4.0], [-5.0, 6.0], [-1.0, -7.0]]);
+        let ids = TestTensorInt::from([0, 1, 1, 2]);
+
+        let output = tensor.segment_max(ids, 3);
+
+        output.into_data().assert_eq(
+            &TensorData::from([[-1.0, -2.0], [-3.0, 6.0], [-1.0, -7.0]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn test_unsorted_segment_3d() {
+        let tensor = TestTensor::<3>::from([[[1.0, 2.0]], [[3.0, 4.0]], [[5.0, 6.0]]]);
+        let ids = TestTensorInt::from([1, 0, 1]);
+
+        let sum = tensor.clone().unsorted_segment_sum(ids.clone(), 2);
+        let mean = tensor.clone().unsorted_segment_mean(ids.clone(), 2);
+        let max = tensor.unsorted_segment_max(ids, 2);
+
+        sum.into_data()
+            .assert_eq(&TensorData::from([[[3.0, 4.0]], [[6.0, 8.0]]]), false);
+        mean.into_data()
+            .assert_approx_eq(&TensorData::from([[[3.0, 4.0]], [[3.0, 4.0]]]), 3);
+        max.into_data()
+            .assert_eq(&TensorData::from([[[3.0, 4.0]], [[5.0, 6.0]]]), false);
+    }
+}
@@ -244,4 +244,30 @@ mod tests {
 
         output.into_data().assert_eq(&data, false);
     }
+
+    #[test]
+    fn should_support_into_data_slice() {
+        let tensor = TestTensor::<2>::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+
+        tensor
+            .to_data_slice([1..2, 0..2])
+            .assert_eq(&TensorData::from([[3.0, 4.0]]), false);
+        tensor
+            .into_data_slice([0..2, 2..3])
+            .assert_eq(&TensorData::from([[2.0], [5.0]]), false);
+    }
+
+    #[test]
+    fn should_support_into_data_slice_int_bool() {
+        let tensor = TestTensorInt::<2>::from([[0, 1, 2], [3, 4, 5]]);
+
+        tensor
+            .clone()
+            .into_data_slice([0..1])
+            .assert_eq(&TensorData::from([[0, 1, 2]]), false);
+        tensor
+            .greater_elem(2)
+            .into_data_slice([1..2, 1..3])
+            .assert_eq(&TensorData::from([[true, true]]), false);
+    }
 }
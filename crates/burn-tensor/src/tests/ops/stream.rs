@@ -0,0 +1,30 @@
+#[burn_tensor_testgen::testgen(stream)]
+mod tests {
+    use super::*;
+    use burn_tensor::backend::stream;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_support_independent_streams() {
+        let tensor = TestTensor::<2>::from([[0.0, 1.0], [2.0, 3.0]]);
+
+        let branch = stream(|| (tensor.clone() * 2).exp().log());
+        let nested = stream(|| stream(|| tensor.clone() - 1) + 1);
+        let output = (tensor + 1) * 3 + branch - nested;
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[3.0, 7.0], [11.0, 15.0]]), 3);
+    }
+
+    #[test]
+    fn should_read_tensor_of_ended_stream() {
+        let tensor = TestTensor::<1>::from([1.0, 2.0, 3.0]);
+
+        let output = stream(|| tensor.mul_scalar(2.0).add_scalar(1.0));
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([3.0, 5.0, 7.0]), false);
+    }
+}
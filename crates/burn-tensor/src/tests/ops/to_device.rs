@@ -0,0 +1,47 @@
+#[burn_tensor_testgen::testgen(to_device)]
+mod tests {
+    use super::*;
+    use burn_tensor::{try_read_sync, TensorData};
+
+    #[test]
+    fn should_support_to_device_async_wait() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+
+        let transfer = tensor.to_device_async(&device);
+        // The tensor can be used before the transfer is completed.
+        let output = transfer.tensor().add_scalar(1.0);
+
+        transfer
+            .wait()
+            .into_data()
+            .assert_eq(&TensorData::from([[1.0, 2.0], [3.0, 4.0]]), false);
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[2.0, 3.0], [4.0, 5.0]]), false);
+    }
+
+    #[test]
+    fn should_support_to_device_async_completed() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<1>::arange(0..4, &device);
+
+        let transfer = tensor.to_device_async(&device);
+        let output = try_read_sync(transfer.completed()).expect("Can read synchronously");
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([0, 1, 2, 3]), false);
+    }
+
+    #[test]
+    fn should_support_to_device_async_completed_empty_tensor() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::empty([0, 2], &device);
+
+        let transfer = tensor.to_device_async(&device);
+        let output = try_read_sync(transfer.completed()).expect("Can read synchronously");
+
+        assert_eq!(output.dims(), [0, 2]);
+    }
+}
@@ -0,0 +1,70 @@
+#[burn_tensor_testgen::testgen(unique)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn test_bincount() {
+        let tensor = TestTensorInt::<1>::from([1, 3, 1, 0, 1]);
+
+        let output = tensor.bincount(0);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([1, 3, 0, 1]), false);
+    }
+
+    #[test]
+    fn test_bincount_min_length() {
+        let tensor = TestTensorInt::<1>::from([1, 1]);
+
+        let output = tensor.bincount(4);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([0, 2, 0, 0]), false);
+    }
+
+    #[test]
+    fn test_unique() {
+        let tensor = TestTensorInt::<1>::from([3, 1, 3, 2, 1, 3]);
+
+        let (unique, inverse, counts) = tensor.unique_with_inverse_and_counts();
+
+        unique
+            .into_data()
+            .assert_eq(&TensorData::from([1, 2, 3]), false);
+        inverse
+            .into_data()
+            .assert_eq(&TensorData::from([2, 0, 2, 1, 0, 2]), false);
+        counts
+            .into_data()
+            .assert_eq(&TensorData::from([2, 1, 3]), false);
+    }
+
+    #[test]
+    fn test_unique_consecutive() {
+        let tensor = TestTensorInt::<1>::from([3, 3, 1, 1, 1, 3]);
+
+        let (unique, inverse, counts) = tensor.unique_consecutive_with_inverse_and_counts();
+
+        unique
+            .into_data()
+            .assert_eq(&TensorData::from([3, 1, 3]), false);
+        inverse
+            .into_data()
+            .assert_eq(&TensorData::from([0, 0, 1, 1, 1, 2]), false);
+        counts
+            .into_data()
+            .assert_eq(&TensorData::from([2, 3, 1]), false);
+    }
+
+    #[test]
+    fn test_unique_empty() {
+        let tensor = TestTensorInt::<1>::empty([0], &Default::default());
+
+        let unique = tensor.unique();
+
+        assert_eq!(unique.dims(), [0]);
+    }
+}
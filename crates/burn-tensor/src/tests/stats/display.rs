@@ -281,6 +281,48 @@ mod tests {
         );
         assert_eq!(output, expected);
     }
+    #[test]
+    fn test_display_tensor_summarize_values() {
+        let tensor = TestTensorInt::<1>::arange(0..2000, &Default::default()).reshape([1000, 2]);
+
+        let output = format!("{}", tensor);
+        let expected = format!(
+            r#"Tensor {{
+  data:
+[[0, 1],
+ [2, 3],
+ [4, 5],
+ ...
+ [1994, 1995],
+ [1996, 1997],
+ [1998, 1999]],
+  shape:  [1000, 2],
+  device:  {:?},
+  backend:  {:?},
+  kind:  "Int",
+  dtype:  "{dtype}",
+}}"#,
+            tensor.device(),
+            TestBackend::name(),
+            dtype = core::any::type_name::<IntElem>(),
+        );
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_debug_tensor() {
+        let tensor = TestTensorInt::<1>::arange(0..2000, &Default::default()).reshape([2, 1000]);
+
+        let output = format!("{:?}", tensor);
+        let expected = format!(
+            "Tensor {{ data: [[0, 1, 2, ..., 997, 998, 999], [1000, 1001, 1002, ..., 1997, 1998, \
+             1999]], shape: [2, 1000], device: {:?}, dtype: \"{dtype}\" }}",
+            tensor.device(),
+            dtype = core::any::type_name::<IntElem>(),
+        );
+        assert_eq!(output, expected);
+    }
+
     #[test]
     fn test_display_precision() {
         let tensor = TestTensor::<2>::full([1, 1], 0.123456789, &Default::default());
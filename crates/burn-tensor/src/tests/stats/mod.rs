@@ -1,4 +1,5 @@
 mod cov;
 mod display;
 mod eye;
+mod summary;
 mod var;
@@ -0,0 +1,36 @@
+#[burn_tensor_testgen::testgen(summary)]
+mod tests {
+    use super::*;
+    use burn_tensor::Shape;
+
+    #[test]
+    fn test_summary() {
+        let tensor = TestTensor::<2>::from_data(
+            [[0.5, 1.8, 0.2, -2.0], [3.0, -4.0, 5.0, 0.0]],
+            &Default::default(),
+        );
+
+        let summary = tensor.summary();
+
+        assert_eq!(summary.shape, Shape::new([2, 4]));
+        assert_eq!(summary.min, -4.0);
+        assert_eq!(summary.max, 5.0);
+        assert!((summary.mean - 0.5625).abs() < 1e-4);
+        assert!((summary.std - 2.6220).abs() < 1e-3);
+        assert_eq!(
+            format!("{:.2}", summary),
+            format!(
+                "shape: [2, 4], dtype: {}, min: -4.00, max: 5.00, mean: 0.56, std: 2.62",
+                summary.dtype.name()
+            )
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Can't summarize an empty tensor")]
+    fn test_summary_empty() {
+        let tensor = TestTensor::<2>::empty([0, 4], &Default::default());
+
+        tensor.summary();
+    }
+}
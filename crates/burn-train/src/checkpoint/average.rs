@@ -0,0 +1,154 @@
+use super::{Checkpointer, CheckpointerError};
+use burn_core::{
+    module::{Module, ModuleAverager},
+    tensor::backend::Backend,
+};
+
+/// Averages the models saved by a checkpointer at the given epochs.
+///
+/// Averaging the weights of the last checkpoints of a training is a cheap way to improve the
+/// quality of a model, commonly used for translation and speech recognition models.
+///
+/// The float tensors of the modules are averaged with a [module averager](ModuleAverager),
+/// including the running statistics of normalization layers, which are averaged like the weights.
+/// The integer and boolean tensors can't be averaged, so they are taken from the checkpoint of the
+/// last epoch.
+///
+/// # Notes
+///
+/// The checkpoints of all the given epochs must be kept by the
+/// [checkpointing strategy](crate::checkpoint::CheckpointingStrategy), for instance using
+/// [KeepLastNCheckpoints](crate::checkpoint::KeepLastNCheckpoints) with the number of checkpoints
+/// to average.
+///
+/// # Arguments
+///
+/// * `model` - The model used to load the records.
+/// * `checkpointer` - The checkpointer of the model records.
+/// * `epochs` - The epochs of the checkpoints to average.
+/// * `device` - The device used to restore the records.
+///
+/// # Returns
+///
+/// The averaged model.
+pub fn average_checkpoints<B, M, C>(
+    model: M,
+    checkpointer: &C,
+    epochs: impl IntoIterator<Item = usize>,
+    device: &B::Device,
+) -> Result<M, CheckpointerError>
+where
+    B: Backend,
+    M: Module<B>,
+    C: Checkpointer<M::Record, B>,
+{
+    let mut averager = ModuleAverager::new();
+    let mut last = None;
+
+    for epoch in epochs {
+        let record = checkpointer.restore(epoch, device)?;
+        let module = model.clone().load_record(record);
+
+        averager.add(&module);
+        last = Some(module);
+    }
+
+    let last = last.ok_or_else(|| {
+        CheckpointerError::Unknown("At least one checkpoint is required to average.".into())
+    })?;
+
+    Ok(averager.average(last))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_core::{
+        module::Param,
+        nn::{Linear, LinearRecord},
+        tensor::{Tensor, TensorData},
+    };
+    use std::{collections::HashMap, sync::Mutex};
+
+    #[derive(Default)]
+    struct MemoryCheckpointer {
+        records: Mutex<HashMap<usize, LinearRecord<TestBackend>>>,
+    }
+
+    impl Checkpointer<LinearRecord<TestBackend>, TestBackend> for MemoryCheckpointer {
+        fn save(
+            &self,
+            epoch: usize,
+            record: LinearRecord<TestBackend>,
+        ) -> Result<(), CheckpointerError> {
+            self.records.lock().unwrap().insert(epoch, record);
+            Ok(())
+        }
+
+        fn delete(&self, epoch: usize) -> Result<(), CheckpointerError> {
+            self.records.lock().unwrap().remove(&epoch);
+            Ok(())
+        }
+
+        fn restore(
+            &self,
+            epoch: usize,
+            _device: &<TestBackend as Backend>::Device,
+        ) -> Result<LinearRecord<TestBackend>, CheckpointerError> {
+            self.records
+                .lock()
+                .unwrap()
+                .remove(&epoch)
+                .ok_or_else(|| CheckpointerError::Unknown(format!("No checkpoint {epoch}")))
+        }
+    }
+
+    fn linear(weight: f32, bias: f32) -> Linear<TestBackend> {
+        let device = Default::default();
+
+        Linear {
+            weight: Param::from_tensor(Tensor::full([2, 2], weight, &device)),
+            bias: Some(Param::from_tensor(Tensor::full([2], bias, &device))),
+        }
+    }
+
+    #[test]
+    fn test_average_checkpoints() {
+        let checkpointer = MemoryCheckpointer::default();
+        for (epoch, value) in [(1, 10.0), (2, 1.0), (3, 2.0), (4, 6.0)] {
+            checkpointer
+                .save(epoch, linear(value, -value).into_record())
+                .unwrap();
+        }
+
+        let model =
+            average_checkpoints(linear(0.0, 0.0), &checkpointer, 2..=4, &Default::default())
+                .unwrap();
+
+        model
+            .weight
+            .val()
+            .into_data()
+            .assert_eq(&TensorData::from([[3.0f32, 3.0], [3.0, 3.0]]), false);
+        model
+            .bias
+            .unwrap()
+            .val()
+            .into_data()
+            .assert_eq(&TensorData::from([-3.0f32, -3.0]), false);
+    }
+
+    #[test]
+    fn test_average_checkpoints_fails_when_a_checkpoint_is_missing() {
+        let checkpointer = MemoryCheckpointer::default();
+        checkpointer
+            .save(1, linear(1.0, 1.0).into_record())
+            .unwrap();
+
+        let result =
+            average_checkpoints(linear(0.0, 0.0), &checkpointer, 1..=2, &Default::default());
+
+        assert!(result.is_err());
+    }
+}
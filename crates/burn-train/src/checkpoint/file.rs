@@ -1,6 +1,6 @@
 use std::path::{Path, PathBuf};
 
-use super::{Checkpointer, CheckpointerError};
+use super::{CheckpointUploader, Checkpointer, CheckpointerError};
 use burn_core::{
     record::{FileRecorder, Record},
     tensor::backend::Backend,
@@ -11,6 +11,7 @@ pub struct FileCheckpointer<FR> {
     directory: PathBuf,
     name: String,
     recorder: FR,
+    uploader: Option<CheckpointUploader>,
 }
 
 impl<FR> FileCheckpointer<FR> {
@@ -29,11 +30,31 @@ impl<FR> FileCheckpointer<FR> {
             directory: directory.to_path_buf(),
             name: name.to_string(),
             recorder,
+            uploader: None,
         }
     }
 
+    /// Upload the saved checkpoints to a remote store with the given
+    /// [uploader](CheckpointUploader).
+    pub fn with_uploader(mut self, uploader: CheckpointUploader) -> Self {
+        self.uploader = Some(uploader);
+        self
+    }
+
     fn path_for_epoch(&self, epoch: usize) -> PathBuf {
-        self.directory.join(format!("{}-{}", self.name, epoch))
+        // The recorder replaces the extension of the path with its own, so a placeholder extension
+        // keeps the names containing a '.' whole, e.g. `model-1.5`.
+        self.directory
+            .join(format!("{}-{}.checkpoint", self.name, epoch))
+    }
+
+    /// The file saved by the recorder, whose extension is appended to the name of the checkpoint.
+    fn file_for_epoch<B: Backend>(&self, epoch: usize) -> PathBuf
+    where
+        FR: FileRecorder<B>,
+    {
+        self.directory
+            .join(format!("{}-{}.{}", self.name, epoch, FR::file_extension()))
     }
 }
 
@@ -44,43 +65,74 @@ where
     B: Backend,
 {
     fn save(&self, epoch: usize, record: R) -> Result<(), CheckpointerError> {
-        let file_path = self.path_for_epoch(epoch);
+        let file_path = self.file_for_epoch::<B>(epoch);
         log::info!("Saving checkpoint {} to {}", epoch, file_path.display());
 
         self.recorder
-            .record(record, file_path)
+            .record(record, self.path_for_epoch(epoch))
             .map_err(CheckpointerError::RecorderError)?;
 
+        if let Some(uploader) = &self.uploader {
+            uploader.upload(file_path, &self.name);
+        }
+
         Ok(())
     }
 
     fn restore(&self, epoch: usize, device: &B::Device) -> Result<R, CheckpointerError> {
-        let file_path = self.path_for_epoch(epoch);
         log::info!(
             "Restoring checkpoint {} from {}",
             epoch,
-            file_path.display()
+            self.file_for_epoch::<B>(epoch).display()
         );
         let record = self
             .recorder
-            .load(file_path, device)
+            .load(self.path_for_epoch(epoch), device)
             .map_err(CheckpointerError::RecorderError)?;
 
         Ok(record)
     }
 
     fn delete(&self, epoch: usize) -> Result<(), CheckpointerError> {
-        let file_to_remove = format!(
-            "{}.{}",
-            self.path_for_epoch(epoch).display(),
-            FR::file_extension(),
-        );
+        let file_to_remove = self.file_for_epoch::<B>(epoch);
+
+        // The file is only removed once uploaded, and kept if its upload failed.
+        if let Some(uploader) = &self.uploader {
+            uploader.delete_local(file_to_remove);
+            return Ok(());
+        }
 
-        if std::path::Path::new(&file_to_remove).exists() {
-            log::info!("Removing checkpoint {}", file_to_remove);
+        if file_to_remove.exists() {
+            log::info!("Removing checkpoint {}", file_to_remove.display());
             std::fs::remove_file(file_to_remove).map_err(CheckpointerError::IOError)?;
         }
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_core::record::DefaultRecorder;
+
+    #[test]
+    fn should_append_the_extension_to_names_containing_a_dot() {
+        let directory = tempfile::tempdir().unwrap();
+        let checkpointer = FileCheckpointer::new(DefaultRecorder::new(), &directory, "model-1.5");
+
+        Checkpointer::<usize, TestBackend>::save(&checkpointer, 2, 2).unwrap();
+        let extension = <DefaultRecorder as FileRecorder<TestBackend>>::file_extension();
+        let file = directory.path().join(format!("model-1.5-2.{extension}"));
+        assert!(file.exists());
+
+        let record: usize =
+            Checkpointer::<usize, TestBackend>::restore(&checkpointer, 2, &Default::default())
+                .unwrap();
+        assert_eq!(record, 2);
+
+        Checkpointer::<usize, TestBackend>::delete(&checkpointer, 2).unwrap();
+        assert!(!file.exists());
+    }
+}
@@ -1,9 +1,13 @@
 mod async_checkpoint;
+mod average;
 mod base;
 mod file;
 mod strategy;
+mod upload;
 
 pub use async_checkpoint::*;
+pub use average::*;
 pub use base::*;
 pub use file::*;
 pub use strategy::*;
+pub use upload::*;
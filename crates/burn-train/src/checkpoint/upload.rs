@@ -0,0 +1,440 @@
+use super::CheckpointerError;
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    path::{Path, PathBuf},
+    process::Command,
+    sync::{mpsc, Arc},
+    time::Duration,
+};
+
+/// A remote storage where the checkpoint files are archived.
+pub trait RemoteStore: Send {
+    /// Upload the file under the given key.
+    fn upload(&self, file: &Path, key: &str) -> Result<(), CheckpointerError>;
+
+    /// Delete the file stored under the given key.
+    fn delete(&self, key: &str) -> Result<(), CheckpointerError>;
+}
+
+/// A [remote store](RemoteStore) copying the files to a directory, e.g. a bucket mounted with
+/// `s3fs` or `gcsfuse`, or a network file system.
+pub struct DirectoryStore {
+    directory: PathBuf,
+}
+
+impl DirectoryStore {
+    /// Creates a store copying the files to the given directory.
+    pub fn new(directory: impl AsRef<Path>) -> Self {
+        Self {
+            directory: directory.as_ref().to_path_buf(),
+        }
+    }
+}
+
+impl RemoteStore for DirectoryStore {
+    fn upload(&self, file: &Path, key: &str) -> Result<(), CheckpointerError> {
+        let path = self.directory.join(key);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(CheckpointerError::IOError)?;
+        }
+
+        // Copy to a temporary file first, so an interrupted copy never looks like a checkpoint.
+        let mut partial = path.clone().into_os_string();
+        partial.push(".partial");
+        std::fs::copy(file, &partial).map_err(CheckpointerError::IOError)?;
+        std::fs::rename(partial, path).map_err(CheckpointerError::IOError)
+    }
+
+    fn delete(&self, key: &str) -> Result<(), CheckpointerError> {
+        let path = self.directory.join(key);
+        if path.exists() {
+            std::fs::remove_file(path).map_err(CheckpointerError::IOError)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A [remote store](RemoteStore) running the command line tool of a cloud provider.
+///
+/// The arguments of the commands may contain the placeholders `{file}`, replaced by the path of
+/// the local file, and `{key}`, replaced by the key of the file in the store.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// // Equivalent to `CommandStore::s3("s3://bucket/experiment")`.
+/// let store = CommandStore::new(["aws", "s3", "cp", "{file}", "s3://bucket/experiment/{key}"])
+///     .with_delete(["aws", "s3", "rm", "s3://bucket/experiment/{key}"]);
+/// ```
+pub struct CommandStore {
+    upload: Vec<String>,
+    delete: Option<Vec<String>>,
+}
+
+impl CommandStore {
+    /// Creates a store uploading the files with the given command.
+    ///
+    /// Without a [delete command](Self::with_delete), the files are never deleted from the store.
+    pub fn new<I: IntoIterator<Item = S>, S: Into<String>>(upload: I) -> Self {
+        Self {
+            upload: upload.into_iter().map(Into::into).collect(),
+            delete: None,
+        }
+    }
+
+    /// Sets the command deleting the files from the store.
+    pub fn with_delete<I: IntoIterator<Item = S>, S: Into<String>>(mut self, delete: I) -> Self {
+        self.delete = Some(delete.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Creates a store uploading to an Amazon S3 prefix, e.g. `s3://bucket/experiment`, with the
+    /// AWS command line interface.
+    pub fn s3(url: &str) -> Self {
+        let url = url.trim_end_matches('/');
+        Self::new(["aws", "s3", "cp", "{file}", &format!("{url}/{{key}}")]).with_delete([
+            "aws",
+            "s3",
+            "rm",
+            &format!("{url}/{{key}}"),
+        ])
+    }
+
+    /// Creates a store uploading to a Google Cloud Storage prefix, e.g. `gs://bucket/experiment`,
+    /// with the `gcloud` command line interface.
+    pub fn gcs(url: &str) -> Self {
+        let url = url.trim_end_matches('/');
+        Self::new([
+            "gcloud",
+            "storage",
+            "cp",
+            "{file}",
+            &format!("{url}/{{key}}"),
+        ])
+        .with_delete(["gcloud", "storage", "rm", &format!("{url}/{{key}}")])
+    }
+
+    /// Creates a store uploading to an [rclone](https://rclone.org) remote, e.g.
+    /// `webdav:experiment`, supporting WebDAV among many other storage systems.
+    pub fn rclone(remote: &str) -> Self {
+        let remote = remote.trim_end_matches('/');
+        Self::new(["rclone", "copyto", "{file}", &format!("{remote}/{{key}}")]).with_delete([
+            "rclone",
+            "deletefile",
+            &format!("{remote}/{{key}}"),
+        ])
+    }
+
+    fn run(command: &[String], file: Option<&Path>, key: &str) -> Result<(), CheckpointerError> {
+        let file = file
+            .map(|file| file.display().to_string())
+            .unwrap_or_default();
+        let mut args = command
+            .iter()
+            .map(|arg| arg.replace("{file}", &file).replace("{key}", key));
+        let program = args
+            .next()
+            .ok_or_else(|| CheckpointerError::Unknown("Empty store command".to_string()))?;
+
+        let output = Command::new(&program)
+            .args(args)
+            .output()
+            .map_err(CheckpointerError::IOError)?;
+
+        if !output.status.success() {
+            return Err(CheckpointerError::Unknown(format!(
+                "{program} failed with {}: {}",
+                output.status,
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+impl RemoteStore for CommandStore {
+    fn upload(&self, file: &Path, key: &str) -> Result<(), CheckpointerError> {
+        Self::run(&self.upload, Some(file), key)
+    }
+
+    fn delete(&self, key: &str) -> Result<(), CheckpointerError> {
+        match &self.delete {
+            Some(command) => Self::run(command, None, key),
+            None => Ok(()),
+        }
+    }
+}
+
+enum Message {
+    Upload { file: PathBuf, name: String },
+    DeleteLocal(PathBuf),
+}
+
+/// The number of times an upload is attempted before giving up on the file.
+const UPLOAD_ATTEMPTS: u32 = 3;
+
+/// The delay before the first retry of an upload, doubled with each retry.
+const UPLOAD_BACKOFF: Duration = Duration::from_secs(1);
+
+struct UploaderThread<S> {
+    store: S,
+    keep_last: Option<usize>,
+    backoff: Duration,
+    // The keys of the uploaded files of each checkpointer, oldest first.
+    uploaded: HashMap<String, VecDeque<String>>,
+    // The local files whose last version is uploaded, the only ones that can be removed.
+    archived: HashSet<PathBuf>,
+    receiver: mpsc::Receiver<Message>,
+}
+
+impl<S: RemoteStore> UploaderThread<S> {
+    fn run(mut self) {
+        while let Ok(message) = self.receiver.recv() {
+            match message {
+                Message::Upload { file, name } => self.upload(&file, name),
+                Message::DeleteLocal(file) => self.delete_local(file),
+            }
+        }
+    }
+
+    fn upload(&mut self, file: &Path, name: String) {
+        let key = match file.file_name().and_then(|name| name.to_str()) {
+            Some(key) => key.to_string(),
+            None => return,
+        };
+
+        // The file is overwritten when a checkpoint is saved again for the same epoch.
+        self.archived.remove(file);
+
+        let mut attempt = 1;
+        while let Err(err) = self.store.upload(file, &key) {
+            if attempt == UPLOAD_ATTEMPTS {
+                log::error!(
+                    "Can't upload checkpoint {}, keeping it locally: {err:?}",
+                    file.display()
+                );
+                return;
+            }
+            let delay = self.backoff * 2u32.pow(attempt - 1);
+            log::warn!(
+                "Upload of checkpoint {} failed, retrying in {delay:?}: {err:?}",
+                file.display()
+            );
+            std::thread::sleep(delay);
+            attempt += 1;
+        }
+        log::info!("Uploaded checkpoint {}", file.display());
+        self.archived.insert(file.to_path_buf());
+
+        let uploaded = self.uploaded.entry(name).or_default();
+        uploaded.retain(|uploaded| uploaded != &key);
+        uploaded.push_back(key);
+
+        if let Some(keep_last) = self.keep_last {
+            while uploaded.len() > keep_last {
+                let key = uploaded.pop_front().unwrap();
+                if let Err(err) = self.store.delete(&key) {
+                    log::error!("Can't delete archived checkpoint {key}: {err:?}");
+                }
+            }
+        }
+    }
+
+    fn delete_local(&mut self, file: PathBuf) {
+        if !self.archived.remove(&file) {
+            if file.exists() {
+                log::warn!(
+                    "Keeping checkpoint {}, which couldn't be uploaded",
+                    file.display()
+                );
+            }
+            return;
+        }
+
+        log::info!("Removing checkpoint {}", file.display());
+        if let Err(err) = std::fs::remove_file(&file) {
+            log::error!("Can't remove checkpoint {}: {err}", file.display());
+        }
+    }
+}
+
+struct UploaderHandle {
+    sender: Option<mpsc::Sender<Message>>,
+    handler: Option<std::thread::JoinHandle<()>>,
+}
+
+impl Drop for UploaderHandle {
+    fn drop(&mut self) {
+        // Closing the channel lets the thread finish the pending uploads before stopping.
+        self.sender.take();
+
+        if let Some(handler) = self.handler.take() {
+            handler.join().expect("The uploader thread should stop.");
+        }
+    }
+}
+
+/// Uploads the checkpoint files to a [remote store](RemoteStore) on a background thread, once
+/// they are saved by a [file checkpointer](crate::checkpoint::FileCheckpointer).
+///
+/// The uploads don't slow down the training. A failed upload is retried a few times with an
+/// exponential backoff. The local checkpoints deleted by the
+/// [checkpointing strategy](crate::checkpoint::CheckpointingStrategy) are only removed once their
+/// uploads succeeded, so the checkpoints that couldn't be uploaded are kept locally. The archived
+/// checkpoints are kept in the store unless a number of checkpoints to keep is given.
+///
+/// The uploader can be cloned to be shared by multiple checkpointers, and the pending uploads
+/// are finished when the last clone is dropped.
+#[derive(Clone)]
+pub struct CheckpointUploader {
+    handle: Arc<UploaderHandle>,
+}
+
+impl CheckpointUploader {
+    /// Creates an uploader keeping every archived checkpoint.
+    pub fn new<S: RemoteStore + 'static>(store: S) -> Self {
+        Self::spawn(store, None, UPLOAD_BACKOFF)
+    }
+
+    /// Creates an uploader keeping only the last `num_checkpoints` archived checkpoints of each
+    /// checkpointer, e.g. of the model and of the optimizer.
+    pub fn keep_last<S: RemoteStore + 'static>(store: S, num_checkpoints: usize) -> Self {
+        Self::spawn(store, Some(num_checkpoints), UPLOAD_BACKOFF)
+    }
+
+    fn spawn<S: RemoteStore + 'static>(
+        store: S,
+        keep_last: Option<usize>,
+        backoff: Duration,
+    ) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        let thread = UploaderThread {
+            store,
+            keep_last,
+            backoff,
+            uploaded: HashMap::new(),
+            archived: HashSet::new(),
+            receiver,
+        };
+        let handler = std::thread::spawn(move || thread.run());
+
+        Self {
+            handle: Arc::new(UploaderHandle {
+                sender: Some(sender),
+                handler: Some(handler),
+            }),
+        }
+    }
+
+    /// Queue the upload of a saved checkpoint file of the checkpointer with the given name.
+    pub(crate) fn upload(&self, file: PathBuf, name: &str) {
+        self.send(Message::Upload {
+            file,
+            name: name.to_string(),
+        });
+    }
+
+    /// Queue the removal of a local checkpoint file, after the pending uploads.
+    pub(crate) fn delete_local(&self, file: PathBuf) {
+        self.send(Message::DeleteLocal(file));
+    }
+
+    fn send(&self, message: Message) {
+        if let Some(sender) = &self.handle.sender {
+            sender
+                .send(message)
+                .expect("Can send message to the uploader thread.");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checkpoint::{Checkpointer, FileCheckpointer};
+    use crate::TestBackend;
+    use burn_core::record::{DefaultRecorder, FileRecorder, Recorder};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct FailingStore {
+        attempts: Arc<AtomicUsize>,
+    }
+
+    impl RemoteStore for FailingStore {
+        fn upload(&self, _file: &Path, _key: &str) -> Result<(), CheckpointerError> {
+            self.attempts.fetch_add(1, Ordering::Relaxed);
+            Err(CheckpointerError::Unknown("Unreachable store".to_string()))
+        }
+
+        fn delete(&self, _key: &str) -> Result<(), CheckpointerError> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn should_keep_the_checkpoints_that_failed_to_upload() {
+        let directory = tempfile::tempdir().unwrap();
+        let directory = directory.path();
+
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let store = FailingStore {
+            attempts: attempts.clone(),
+        };
+        let uploader = CheckpointUploader::spawn(store, None, Duration::from_millis(1));
+        let checkpointer = FileCheckpointer::new(DefaultRecorder::new(), directory, "model")
+            .with_uploader(uploader);
+
+        Checkpointer::<usize, TestBackend>::save(&checkpointer, 1, 1).unwrap();
+        Checkpointer::<usize, TestBackend>::delete(&checkpointer, 1).unwrap();
+        core::mem::drop(checkpointer);
+
+        let extension = <DefaultRecorder as FileRecorder<TestBackend>>::file_extension();
+        assert!(directory.join(format!("model-1.{extension}")).exists());
+        assert_eq!(attempts.load(Ordering::Relaxed), UPLOAD_ATTEMPTS as usize);
+    }
+
+    #[test]
+    fn should_archive_the_last_checkpoints() {
+        let directory = tempfile::tempdir().unwrap();
+        let (local, remote) = (
+            directory.path().join("local"),
+            directory.path().join("remote"),
+        );
+
+        let uploader = CheckpointUploader::keep_last(DirectoryStore::new(&remote), 2);
+        let checkpointer =
+            FileCheckpointer::new(DefaultRecorder::new(), &local, "model").with_uploader(uploader);
+
+        for epoch in 1..=3 {
+            Checkpointer::<usize, TestBackend>::save(&checkpointer, epoch, epoch).unwrap();
+        }
+        Checkpointer::<usize, TestBackend>::delete(&checkpointer, 1).unwrap();
+        // Finishes the pending uploads.
+        core::mem::drop(checkpointer);
+
+        let extension = <DefaultRecorder as FileRecorder<TestBackend>>::file_extension();
+        let exists = |directory: &Path, epoch: usize| {
+            directory
+                .join(format!("model-{epoch}.{extension}"))
+                .exists()
+        };
+        assert_eq!(
+            [1, 2, 3].map(|epoch| exists(&local, epoch)),
+            [false, true, true]
+        );
+        assert_eq!(
+            [1, 2, 3].map(|epoch| exists(&remote, epoch)),
+            [false, true, true]
+        );
+
+        let record: usize = Recorder::<TestBackend>::load(
+            &DefaultRecorder::new(),
+            remote.join("model-3"),
+            &Default::default(),
+        )
+        .unwrap();
+        assert_eq!(record, 3);
+    }
+}
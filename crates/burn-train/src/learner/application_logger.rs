@@ -26,44 +26,54 @@ impl FileApplicationLoggerInstaller {
 
 impl ApplicationLoggerInstaller for FileApplicationLoggerInstaller {
     fn install(&self) -> Result<(), String> {
-        let path = Path::new(&self.path);
-        let writer = tracing_appender::rolling::never(
-            path.parent().unwrap_or_else(|| Path::new(".")),
-            path.file_name().unwrap_or_else(|| {
-                panic!("The path '{}' to point to a file.", self.path.display())
-            }),
-        );
-        let layer = tracing_subscriber::fmt::layer()
-            .with_ansi(false)
-            .with_writer(writer)
-            .with_filter(LevelFilter::INFO)
-            .with_filter(filter_fn(|m| {
-                if let Some(path) = m.module_path() {
-                    // The wgpu crate is logging too much, so we skip `info` level.
-                    if path.starts_with("wgpu") && *m.level() >= Level::INFO {
-                        return false;
-                    }
-                }
-                true
-            }));
-
-        if registry().with(layer).try_init().is_err() {
+        if registry().with(file_layer(&self.path)).try_init().is_err() {
             return Err("Failed to install the file logger.".to_string());
         }
 
-        let hook = std::panic::take_hook();
-        let file_path = self.path.to_owned();
-
-        std::panic::set_hook(Box::new(move |info| {
-            log::error!("PANIC => {}", info.to_string());
-            eprintln!(
-                "=== PANIC ===\nA fatal error happened, you can check the experiment logs here => \
-                    '{}'\n=============",
-                file_path.display()
-            );
-            hook(info);
-        }));
+        install_panic_hook(&self.path);
 
         Ok(())
     }
 }
+
+/// The layer writing the logs to the given file.
+pub(crate) fn file_layer<S>(path: &Path) -> impl Layer<S>
+where
+    S: tracing_core::Subscriber + for<'a> tracing_subscriber::registry::LookupSpan<'a>,
+{
+    let writer = tracing_appender::rolling::never(
+        path.parent().unwrap_or_else(|| Path::new(".")),
+        path.file_name()
+            .unwrap_or_else(|| panic!("The path '{}' to point to a file.", path.display())),
+    );
+
+    tracing_subscriber::fmt::layer()
+        .with_ansi(false)
+        .with_writer(writer)
+        .with_filter(LevelFilter::INFO)
+        .with_filter(filter_fn(|m| {
+            if let Some(path) = m.module_path() {
+                // The wgpu crate is logging too much, so we skip `info` level.
+                if path.starts_with("wgpu") && *m.level() >= Level::INFO {
+                    return false;
+                }
+            }
+            true
+        }))
+}
+
+/// Log the panics and point to the log file.
+pub(crate) fn install_panic_hook(path: &Path) {
+    let hook = std::panic::take_hook();
+    let file_path = path.to_owned();
+
+    std::panic::set_hook(Box::new(move |info| {
+        log::error!("PANIC => {}", info.to_string());
+        eprintln!(
+            "=== PANIC ===\nA fatal error happened, you can check the experiment logs here => \
+                '{}'\n=============",
+            file_path.display()
+        );
+        hook(info);
+    }));
+}
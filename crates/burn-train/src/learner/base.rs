@@ -1,13 +1,20 @@
-use crate::checkpoint::{Checkpointer, CheckpointingAction, CheckpointingStrategy};
+use crate::checkpoint::{
+    Checkpointer, CheckpointerError, CheckpointingAction, CheckpointingStrategy,
+};
 use crate::components::LearnerComponents;
-use crate::learner::EarlyStoppingStrategy;
-use crate::metric::store::EventStoreClient;
+use crate::learner::{
+    DeviceError, DeviceRecovery, DeviceWatchdog, EarlyStoppingStrategy, GradAccumulation,
+    ReproducibilityBundle, TrainCallback, TrainEpochState, TrainingDiagnosticsConfig,
+};
+use crate::metric::store::{Aggregate, Direction, EventStoreClient, Split};
 use crate::LearnerSummaryConfig;
+use burn_core::config::Config;
 use burn_core::lr_scheduler::LrScheduler;
 use burn_core::module::Module;
 use burn_core::optim::Optimizer;
 use burn_core::tensor::backend::Backend;
 use burn_core::tensor::Device;
+use std::path::PathBuf;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
@@ -20,7 +27,7 @@ pub struct Learner<LC: LearnerComponents> {
     pub(crate) lr_scheduler: LC::LrScheduler,
     pub(crate) num_epochs: usize,
     pub(crate) checkpoint: Option<usize>,
-    pub(crate) grad_accumulation: Option<usize>,
+    pub(crate) grad_accumulation: Option<GradAccumulation>,
     pub(crate) checkpointer: Option<LearnerCheckpointer<LC>>,
     pub(crate) devices: Vec<<LC::Backend as Backend>::Device>,
     pub(crate) interrupter: TrainingInterrupter,
@@ -28,6 +35,22 @@ pub struct Learner<LC: LearnerComponents> {
     pub(crate) event_processor: LC::EventProcessor,
     pub(crate) event_store: Arc<EventStoreClient>,
     pub(crate) summary: Option<LearnerSummaryConfig>,
+    pub(crate) watchdog: Option<DeviceWatchdog>,
+    pub(crate) recovery: Option<LearnerRecovery<LC>>,
+    pub(crate) overfit_subset: Option<usize>,
+    pub(crate) best_model: Option<BestModelSelection>,
+    pub(crate) checkpoint_interval: Option<usize>,
+    pub(crate) callbacks: Vec<Box<dyn TrainCallback<LC::Model>>>,
+    pub(crate) reproducibility: Option<(ReproducibilityBundle, PathBuf)>,
+    pub(crate) diagnostics: Option<TrainingDiagnosticsConfig>,
+}
+
+/// The metric used to select the epoch of the model returned by the learner.
+pub(crate) struct BestModelSelection {
+    pub(crate) name: String,
+    pub(crate) aggregate: Aggregate,
+    pub(crate) direction: Direction,
+    pub(crate) split: Split,
 }
 
 #[derive(new)]
@@ -36,9 +59,68 @@ pub(crate) struct LearnerCheckpointer<LC: LearnerComponents> {
     optim: LC::CheckpointerOptimizer,
     lr_scheduler: LC::CheckpointerLrScheduler,
     strategy: LC::CheckpointerStrategy,
+    #[new(default)]
+    state_file: Option<PathBuf>,
+    #[new(default)]
+    epoch_in_progress: Option<usize>,
 }
 
 impl<LC: LearnerComponents> LearnerCheckpointer<LC> {
+    /// Save the [state](TrainEpochState) of the checkpoints saved during an epoch to the given
+    /// file.
+    pub(crate) fn with_state_file(mut self, state_file: PathBuf) -> Self {
+        self.state_file = Some(state_file);
+        self
+    }
+
+    /// Save a checkpoint during an epoch, along with the state required to resume it.
+    pub(crate) fn checkpoint_iteration(
+        &mut self,
+        model: &LC::Model,
+        optim: &LC::Optimizer,
+        scheduler: &LC::LrScheduler,
+        state: TrainEpochState,
+    ) {
+        let state_file = match &self.state_file {
+            Some(state_file) => state_file,
+            None => return,
+        };
+
+        // The checkpoint of the epoch in progress is replaced by the one saved at the end of the
+        // epoch, if any.
+        self.save(model, optim, scheduler, state.epoch);
+        // Records are saved in order, so the state is written after the records it refers to
+        // are queued.
+        state
+            .save(state_file)
+            .expect("Can save the training state.");
+
+        self.epoch_in_progress = Some(state.epoch);
+    }
+
+    /// Load the [state](TrainEpochState) of the last checkpoint saved during an epoch, if any.
+    ///
+    /// The epoch of the state is in progress, so its state and its checkpoint are removed once
+    /// the epoch ends.
+    pub(crate) fn load_state(&mut self) -> Option<TrainEpochState> {
+        let state_file = self.state_file.as_ref()?;
+
+        if !state_file.exists() {
+            return None;
+        }
+
+        match TrainEpochState::load(state_file) {
+            Ok(state) => {
+                self.epoch_in_progress = Some(state.epoch);
+                Some(state)
+            }
+            Err(err) => {
+                log::warn!("Can't load the training state: {err}");
+                None
+            }
+        }
+    }
+
     pub(crate) fn checkpoint(
         &mut self,
         model: &LC::Model,
@@ -46,35 +128,60 @@ impl<LC: LearnerComponents> LearnerCheckpointer<LC> {
         scheduler: &LC::LrScheduler,
         epoch: usize,
         store: &EventStoreClient,
-    ) {
+    ) -> bool {
         let actions = self.strategy.checkpointing(epoch, store);
+        let mut saved = false;
 
         for action in actions {
             match action {
-                CheckpointingAction::Delete(epoch) => {
-                    self.model
-                        .delete(epoch)
-                        .expect("Can delete model checkpoint.");
-                    self.optim
-                        .delete(epoch)
-                        .expect("Can delete optimizer checkpoint.");
-                    self.lr_scheduler
-                        .delete(epoch)
-                        .expect("Can delete learning rate scheduler checkpoint.");
-                }
+                CheckpointingAction::Delete(epoch) => self.delete(epoch),
                 CheckpointingAction::Save => {
-                    self.model
-                        .save(epoch, model.clone().into_record())
-                        .expect("Can save model checkpoint.");
-                    self.optim
-                        .save(epoch, optim.to_record())
-                        .expect("Can save optimizer checkpoint.");
-                    self.lr_scheduler
-                        .save(epoch, scheduler.to_record())
-                        .expect("Can save learning rate scheduler checkpoint.");
+                    self.save(model, optim, scheduler, epoch);
+                    saved = true;
                 }
             }
         }
+
+        if self.epoch_in_progress.take() == Some(epoch) {
+            if !saved {
+                self.delete(epoch);
+            }
+            if let Some(state_file) = &self.state_file {
+                std::fs::remove_file(state_file).ok();
+            }
+        }
+
+        saved
+    }
+
+    fn save(
+        &self,
+        model: &LC::Model,
+        optim: &LC::Optimizer,
+        scheduler: &LC::LrScheduler,
+        epoch: usize,
+    ) {
+        self.model
+            .save(epoch, model.clone().into_record())
+            .expect("Can save model checkpoint.");
+        self.optim
+            .save(epoch, optim.to_record())
+            .expect("Can save optimizer checkpoint.");
+        self.lr_scheduler
+            .save(epoch, scheduler.to_record())
+            .expect("Can save learning rate scheduler checkpoint.");
+    }
+
+    fn delete(&self, epoch: usize) {
+        self.model
+            .delete(epoch)
+            .expect("Can delete model checkpoint.");
+        self.optim
+            .delete(epoch)
+            .expect("Can delete optimizer checkpoint.");
+        self.lr_scheduler
+            .delete(epoch)
+            .expect("Can delete learning rate scheduler checkpoint.");
     }
 
     pub(crate) fn load_checkpoint(
@@ -85,11 +192,9 @@ impl<LC: LearnerComponents> LearnerCheckpointer<LC> {
         device: &Device<LC::Backend>,
         epoch: usize,
     ) -> (LC::Model, LC::Optimizer, LC::LrScheduler) {
-        let record = self
-            .model
-            .restore(epoch, device)
+        let model = self
+            .load_model(model, device, epoch)
             .expect("Can load model checkpoint.");
-        let model = model.load_record(record);
 
         let record = self
             .optim
@@ -105,6 +210,79 @@ impl<LC: LearnerComponents> LearnerCheckpointer<LC> {
 
         (model, optim, scheduler)
     }
+
+    pub(crate) fn load_model(
+        &self,
+        model: LC::Model,
+        device: &Device<LC::Backend>,
+        epoch: usize,
+    ) -> Result<LC::Model, CheckpointerError> {
+        let record = self.model.restore(epoch, device)?;
+        Ok(model.load_record(record))
+    }
+}
+
+/// The [recovery policy](DeviceRecovery) of a learner, along with copies of the model and the
+/// optimizer made before training, in which the last checkpoint is loaded since the ones being
+/// trained are lost when a step fails.
+pub(crate) struct LearnerRecovery<LC: LearnerComponents> {
+    policy: DeviceRecovery,
+    clone_optim: fn(&LC::Optimizer) -> LC::Optimizer,
+    initial: Option<(LC::Model, LC::Optimizer)>,
+    retries: usize,
+}
+
+impl<LC: LearnerComponents> LearnerRecovery<LC> {
+    pub(crate) fn new(
+        policy: DeviceRecovery,
+        clone_optim: fn(&LC::Optimizer) -> LC::Optimizer,
+    ) -> Self {
+        Self {
+            policy,
+            clone_optim,
+            initial: None,
+            retries: 0,
+        }
+    }
+
+    /// Keep a copy of the model and the optimizer before training.
+    pub(crate) fn init(&mut self, model: &LC::Model, optim: &LC::Optimizer) {
+        self.initial = Some((model.clone(), (self.clone_optim)(optim)));
+    }
+
+    /// Re-create the device and load the last checkpoint saved before the error, returning the
+    /// epoch to resume the training from.
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn recover(
+        &mut self,
+        error: DeviceError,
+        checkpointer: Option<&LearnerCheckpointer<LC>>,
+        scheduler: LC::LrScheduler,
+        device: &Device<LC::Backend>,
+    ) -> Result<(LC::Model, LC::Optimizer, LC::LrScheduler, usize), DeviceError> {
+        let (Some(checkpointer), Some(epoch), Some((model, optim))) =
+            (checkpointer, error.last_checkpoint(), &self.initial)
+        else {
+            log::error!("Can't recover from the device error without a checkpoint.");
+            return Err(error);
+        };
+
+        if !self.policy.reset(&error, self.retries) {
+            return Err(error);
+        }
+        self.retries += 1;
+
+        let (model, optim, scheduler) = checkpointer.load_checkpoint(
+            model.clone(),
+            (self.clone_optim)(optim),
+            scheduler,
+            device,
+            epoch,
+        );
+        log::info!("Resuming the training from the checkpoint of epoch {epoch}.");
+
+        Ok((model, optim, scheduler, epoch + 1))
+    }
 }
 
 #[derive(Clone, Default)]
@@ -128,4 +306,9 @@ impl TrainingInterrupter {
     pub fn should_stop(&self) -> bool {
         self.state.load(Ordering::Relaxed)
     }
+
+    /// Let the training continue after it was stopped.
+    pub(crate) fn reset(&self) {
+        self.state.store(false, Ordering::Relaxed);
+    }
 }
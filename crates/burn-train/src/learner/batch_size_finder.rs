@@ -0,0 +1,299 @@
+use std::panic::AssertUnwindSafe;
+
+use burn_core::module::AutodiffModule;
+use burn_core::optim::Optimizer;
+use burn_core::record::{BinBytesRecorder, FullPrecisionSettings, Recorder};
+use burn_core::tensor::backend::{AutodiffBackend, Backend};
+
+use crate::{TrainStep, TrainingInterrupter};
+
+/// Configuration of the [batch size finder](crate::LearnerBuilder::batch_size_find).
+///
+/// The batch size doubles from `start_batch_size` until a training step runs out of memory or
+/// `max_batch_size` is reached, then a binary search finds the largest batch size that fits.
+#[derive(Debug, Clone)]
+pub struct BatchSizeFinderConfig {
+    /// The batch size of the first probe.
+    pub start_batch_size: usize,
+    /// The largest batch size to probe.
+    pub max_batch_size: usize,
+    /// The number of training steps of each probe, more than one so the state of the optimizer
+    /// is allocated.
+    pub num_steps: usize,
+}
+
+impl Default for BatchSizeFinderConfig {
+    fn default() -> Self {
+        Self {
+            start_batch_size: 1,
+            max_batch_size: 4096,
+            num_steps: 2,
+        }
+    }
+}
+
+impl BatchSizeFinderConfig {
+    /// Sets the range of batch sizes to probe.
+    pub fn with_batch_size_range(mut self, start_batch_size: usize, max_batch_size: usize) -> Self {
+        self.start_batch_size = start_batch_size;
+        self.max_batch_size = max_batch_size;
+        self
+    }
+
+    /// Sets the number of training steps of each probe.
+    pub fn with_num_steps(mut self, num_steps: usize) -> Self {
+        self.num_steps = num_steps;
+        self
+    }
+}
+
+/// A probe of the [batch size finder](crate::LearnerBuilder::batch_size_find).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BatchSizeProbe {
+    /// The probed batch size.
+    pub batch_size: usize,
+    /// If the training steps ran without running out of memory.
+    pub fits: bool,
+}
+
+/// The estimated memory used by the training, in bytes.
+///
+/// The activations, which grow with the batch size, take the remaining memory of the device.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MemoryBreakdown {
+    /// The parameters of the model.
+    pub parameters: usize,
+    /// The gradients of the parameters, computed for each step.
+    pub gradients: usize,
+    /// The state of the optimizer, such as the moments of Adam.
+    pub optimizer_state: usize,
+}
+
+impl MemoryBreakdown {
+    /// The memory used independently of the batch size.
+    pub fn total(&self) -> usize {
+        self.parameters + self.gradients + self.optimizer_state
+    }
+}
+
+/// The result of the [batch size finder](crate::LearnerBuilder::batch_size_find).
+#[derive(Debug, Clone, Default)]
+pub struct BatchSizeFinderResult {
+    /// The largest batch size that fits in memory, if any.
+    pub max_batch_size: Option<usize>,
+    /// The probes, in execution order.
+    pub probes: Vec<BatchSizeProbe>,
+    /// The estimated memory used independently of the batch size.
+    pub memory: MemoryBreakdown,
+}
+
+/// Searches the largest batch size for which `fits` returns `true`, assuming larger batch sizes
+/// don't fit once one doesn't.
+fn search(
+    config: &BatchSizeFinderConfig,
+    mut fits: impl FnMut(usize) -> Option<bool>,
+) -> (Option<usize>, Vec<BatchSizeProbe>) {
+    let mut probes = Vec::new();
+    let mut probe = |batch_size: usize| {
+        let result = fits(batch_size);
+        if let Some(fits) = result {
+            probes.push(BatchSizeProbe { batch_size, fits });
+        }
+        result
+    };
+
+    let max = config.max_batch_size;
+    let mut fitting = None;
+    let mut failing = None;
+    let mut batch_size = config.start_batch_size.clamp(1, max.max(1));
+
+    // Grow exponentially until a batch size doesn't fit.
+    while batch_size <= max {
+        match probe(batch_size) {
+            Some(true) => fitting = Some(batch_size),
+            Some(false) => {
+                failing = Some(batch_size);
+                break;
+            }
+            None => return (fitting, probes),
+        }
+
+        if batch_size == max {
+            break;
+        }
+        batch_size = usize::min(batch_size * 2, max);
+    }
+
+    let mut failing = match failing {
+        Some(failing) => failing,
+        None => return (fitting, probes),
+    };
+
+    // Binary search between the largest fitting and the smallest failing batch sizes.
+    let mut low = fitting.unwrap_or(0);
+    while failing - low > 1 {
+        let batch_size = low + (failing - low) / 2;
+
+        match probe(batch_size) {
+            Some(true) => {
+                low = batch_size;
+                fitting = Some(batch_size);
+            }
+            Some(false) => failing = batch_size,
+            None => break,
+        }
+    }
+
+    (fitting, probes)
+}
+
+/// If the message of a panic reports that the device ran out of memory.
+fn is_out_of_memory(message: &str) -> bool {
+    let message = message.to_lowercase();
+    message.contains("out of memory") || message.contains("outofmemory")
+}
+
+/// Runs the batch size finder.
+pub(crate) fn batch_size_find<B, M, O, InputTrain, OutputTrain>(
+    model: M,
+    mut optim: O,
+    batch: impl Fn(usize) -> InputTrain,
+    config: BatchSizeFinderConfig,
+    interrupter: &TrainingInterrupter,
+) -> BatchSizeFinderResult
+where
+    B: AutodiffBackend,
+    M: AutodiffModule<B> + TrainStep<InputTrain, OutputTrain>,
+    O: Optimizer<M, B>,
+{
+    let devices = model.devices();
+
+    let (max_batch_size, probes) = search(&config, |batch_size| {
+        if interrupter.should_stop() {
+            return None;
+        }
+
+        let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+            let mut model = model.clone();
+
+            for _ in 0..config.num_steps {
+                let output = model.step(batch(batch_size));
+                model = model.optimize(&mut optim, 0.0, output.grads);
+            }
+
+            // Operations may be executed lazily, so they must complete inside the probe.
+            for device in devices.iter() {
+                B::sync(device);
+            }
+        }));
+
+        match result {
+            Ok(()) => Some(true),
+            Err(payload) => {
+                let message = match payload.downcast_ref::<&str>() {
+                    Some(message) => message.to_string(),
+                    None => match payload.downcast_ref::<String>() {
+                        Some(message) => message.clone(),
+                        None => "Unknown error".to_string(),
+                    },
+                };
+
+                if !is_out_of_memory(&message) {
+                    std::panic::resume_unwind(payload);
+                }
+
+                log::info!("The batch size {batch_size} doesn't fit in memory: {message}");
+                Some(false)
+            }
+        }
+    });
+
+    let parameters = model.num_params() * core::mem::size_of::<<B as Backend>::FloatElem>();
+    let recorder = BinBytesRecorder::<FullPrecisionSettings>::default();
+    let optimizer_state = Recorder::<B>::record(&recorder, optim.to_record(), ())
+        .map(|bytes| bytes.len())
+        .unwrap_or_default();
+
+    BatchSizeFinderResult {
+        max_batch_size,
+        probes,
+        memory: MemoryBreakdown {
+            parameters,
+            gradients: parameters,
+            optimizer_state,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn probes(batch_sizes: &[(usize, bool)]) -> Vec<BatchSizeProbe> {
+        batch_sizes
+            .iter()
+            .map(|(batch_size, fits)| BatchSizeProbe {
+                batch_size: *batch_size,
+                fits: *fits,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn should_find_largest_fitting_batch_size() {
+        let config = BatchSizeFinderConfig::default();
+
+        let (max_batch_size, result) = search(&config, |batch_size| Some(batch_size <= 37));
+
+        assert_eq!(max_batch_size, Some(37));
+        assert_eq!(
+            result,
+            probes(&[
+                (1, true),
+                (2, true),
+                (4, true),
+                (8, true),
+                (16, true),
+                (32, true),
+                (64, false),
+                (48, false),
+                (40, false),
+                (36, true),
+                (38, false),
+                (37, true),
+            ])
+        );
+    }
+
+    #[test]
+    fn should_stop_at_max_batch_size() {
+        let config = BatchSizeFinderConfig::default().with_batch_size_range(4, 20);
+
+        let (max_batch_size, result) = search(&config, |_| Some(true));
+
+        assert_eq!(max_batch_size, Some(20));
+        assert_eq!(
+            result,
+            probes(&[(4, true), (8, true), (16, true), (20, true)])
+        );
+    }
+
+    #[test]
+    fn should_report_no_batch_size_when_nothing_fits() {
+        let config = BatchSizeFinderConfig::default().with_batch_size_range(4, 64);
+
+        let (max_batch_size, result) = search(&config, |_| Some(false));
+
+        assert_eq!(max_batch_size, None);
+        assert_eq!(result, probes(&[(4, false), (2, false), (1, false)]));
+    }
+
+    #[test]
+    fn should_detect_out_of_memory_messages() {
+        assert!(is_out_of_memory(
+            "CUDA out of memory. Tried to allocate 2.00 GiB"
+        ));
+        assert!(is_out_of_memory("Allocation failed: OutOfMemory"));
+        assert!(!is_out_of_memory("Shapes are incompatible"));
+    }
+}
@@ -4,21 +4,29 @@ use std::sync::Arc;
 
 use super::Learner;
 use crate::checkpoint::{
-    AsyncCheckpointer, CheckpointingStrategy, ComposedCheckpointingStrategy, FileCheckpointer,
-    KeepLastNCheckpoints, MetricCheckpointingStrategy,
+    AsyncCheckpointer, CheckpointUploader, CheckpointingStrategy, ComposedCheckpointingStrategy,
+    FileCheckpointer, KeepLastNCheckpoints, MetricCheckpointingStrategy,
 };
 use crate::components::LearnerComponentsMarker;
+use crate::learner::base::BestModelSelection;
 use crate::learner::base::TrainingInterrupter;
-use crate::learner::EarlyStoppingStrategy;
+use crate::learner::batch_size_finder::batch_size_find;
+use crate::learner::lr_finder::lr_find;
+use crate::learner::{
+    BatchSizeFinderConfig, BatchSizeFinderResult, DeviceRecovery, DeviceWatchdog,
+    EarlyStoppingStrategy, GradAccumulation, LrFinderConfig, LrFinderResult, ReproducibilityBundle,
+    TrainCallback, TrainStep, TrainingDiagnosticsConfig,
+};
 use crate::logger::{FileMetricLogger, MetricLogger};
 use crate::metric::processor::{AsyncProcessor, FullEventProcessor, ItemLazy, Metrics};
 use crate::metric::store::{Aggregate, Direction, EventStoreClient, LogEventStore, Split};
-use crate::metric::{Adaptor, LossMetric, Metric};
+use crate::metric::{Adaptor, LossInput, LossMetric, Metric};
 use crate::renderer::{default_renderer, MetricsRenderer};
 use crate::{
     ApplicationLoggerInstaller, FileApplicationLoggerInstaller, LearnerCheckpointer,
-    LearnerSummaryConfig,
+    LearnerRecovery, LearnerSummaryConfig,
 };
+use burn_core::data::dataloader::DataLoader;
 use burn_core::lr_scheduler::LrScheduler;
 use burn_core::module::AutodiffModule;
 use burn_core::optim::Optimizer;
@@ -47,7 +55,7 @@ where
     num_epochs: usize,
     checkpoint: Option<usize>,
     directory: PathBuf,
-    grad_accumulation: Option<usize>,
+    grad_accumulation: Option<GradAccumulation>,
     devices: Vec<B::Device>,
     renderer: Option<Box<dyn MetricsRenderer + 'static>>,
     metrics: Metrics<T, V>,
@@ -59,6 +67,15 @@ where
     early_stopping: Option<Box<dyn EarlyStoppingStrategy>>,
     summary_metrics: HashSet<String>,
     summary: bool,
+    watchdog: Option<DeviceWatchdog>,
+    #[allow(clippy::type_complexity)]
+    recovery: Option<(DeviceRecovery, fn(&O) -> O)>,
+    overfit_subset: Option<usize>,
+    best_model: Option<(BestModelSelection, MetricCheckpointingStrategy)>,
+    checkpoint_interval: Option<usize>,
+    callbacks: Vec<Box<dyn TrainCallback<M>>>,
+    reproducibility: Option<ReproducibilityBundle>,
+    diagnostics: Option<TrainingDiagnosticsConfig>,
 }
 
 impl<B, T, V, M, O, S> LearnerBuilder<B, T, V, M, O, S>
@@ -106,6 +123,14 @@ where
             early_stopping: None,
             summary_metrics: HashSet::new(),
             summary: false,
+            watchdog: None,
+            recovery: None,
+            overfit_subset: None,
+            best_model: None,
+            checkpoint_interval: None,
+            callbacks: Vec::new(),
+            reproducibility: None,
+            diagnostics: None,
         }
     }
 
@@ -176,8 +201,28 @@ where
     ///
     /// The effect is similar to increasing the `batch size` and the `learning rate` by the `accumulation`
     /// amount.
+    ///
+    /// See [with_grad_accumulation](Self::with_grad_accumulation) to average the gradients
+    /// instead.
     pub fn grads_accumulation(mut self, accumulation: usize) -> Self {
-        self.grad_accumulation = Some(accumulation);
+        self.grad_accumulation = Some(GradAccumulation::Sum(accumulation));
+        self
+    }
+
+    /// Enable gradients accumulation over `num_micro_batches` micro-batches per optimizer step.
+    ///
+    /// # Notes
+    ///
+    /// The gradients of the micro-batches are averaged, which is equivalent to scaling the loss of
+    /// each micro-batch by `1 / num_micro_batches`, so the learning rate doesn't need to be
+    /// adjusted. The learning rate scheduler steps once per optimizer step and the metrics report
+    /// the effective steps as iterations, so the training behaves like training with batches
+    /// `num_micro_batches` times larger.
+    ///
+    /// Modules computing statistics over the batch, such as batch normalization, still compute
+    /// them on each micro-batch.
+    pub fn with_grad_accumulation(mut self, num_micro_batches: usize) -> Self {
+        self.grad_accumulation = Some(GradAccumulation::Mean(num_micro_batches));
         self
     }
 
@@ -217,12 +262,129 @@ where
         self
     }
 
+    /// Debug mode training and validating on the first `num_items` items of each dataloader only.
+    ///
+    /// The same items are used at every epoch, in the same order and without shuffling, and the
+    /// training loss is logged after every epoch. It is useful to quickly validate that a new
+    /// model implementation is able to learn at all: the training loss should get close to zero.
+    /// To overfit a single batch, use the batch size as the number of items.
+    pub fn overfit_subset(mut self, num_items: usize) -> Self {
+        self.overfit_subset = Some(num_items);
+        self
+    }
+
     /// The epoch from which the training must resume.
     pub fn checkpoint(mut self, checkpoint: usize) -> Self {
         self.checkpoint = Some(checkpoint);
         self
     }
 
+    /// Save a checkpoint every `num_iterations` training iterations, along with the state of the
+    /// training dataloader, so an interrupted epoch can be resumed from the exact iteration with
+    /// [resume](Learner::resume).
+    ///
+    /// # Notes
+    ///
+    /// Requires a checkpointer registered using
+    /// [with_file_checkpointer](Self::with_file_checkpointer).
+    pub fn checkpoint_interval(mut self, num_iterations: usize) -> Self {
+        self.checkpoint_interval = Some(num_iterations);
+        self
+    }
+
+    /// Register a [callback](TrainCallback) called at different stages of the training.
+    ///
+    /// Callbacks are called in the order they are registered.
+    pub fn callback<C>(mut self, callback: C) -> Self
+    where
+        C: TrainCallback<M> + 'static,
+    {
+        self.callbacks.push(Box::new(callback));
+        self
+    }
+
+    /// Save a [reproducibility bundle](ReproducibilityBundle) to `reproducibility.json` in the
+    /// artifact directory when the training starts.
+    ///
+    /// When the training is resumed from a checkpoint, the bundle is verified against the bundle
+    /// of the original run instead, and a warning is logged for each component that differs.
+    pub fn reproducibility_bundle(mut self, bundle: ReproducibilityBundle) -> Self {
+        self.reproducibility = Some(bundle);
+        self
+    }
+
+    /// Sample the [training diagnostics](crate::TrainingDiagnostics) every few training
+    /// iterations.
+    ///
+    /// The diagnostics are exposed to the metrics, register the
+    /// [GradientNoiseScaleMetric](crate::metric::GradientNoiseScaleMetric) and the
+    /// [UpdateRatioMetric](crate::metric::UpdateRatioMetric) to track them. Sampling reads the
+    /// gradients and the parameters of the model, which slows down the sampled iterations.
+    pub fn training_diagnostics(mut self, config: TrainingDiagnosticsConfig) -> Self {
+        self.diagnostics = Some(config);
+        self
+    }
+
+    /// Runs a learning rate range test to find a good maximum learning rate for the model.
+    ///
+    /// The model is trained with a learning rate growing exponentially for a few hundred
+    /// iterations, recording the loss for each learning rate until it diverges. The model and
+    /// the optimizer are consumed by the test, so they should be created again for the training.
+    ///
+    /// The [result](LrFinderResult) contains the loss for each learning rate, to be plotted, and
+    /// a [suggested learning rate](LrFinderResult::suggested_lr).
+    pub fn lr_find<InputTrain>(
+        &self,
+        model: M,
+        optim: O,
+        dataloader_train: Arc<dyn DataLoader<InputTrain>>,
+        config: LrFinderConfig,
+    ) -> LrFinderResult
+    where
+        M: TrainStep<InputTrain, T>,
+        T: Adaptor<LossInput<B>>,
+    {
+        // The test runs on the first device, like the training.
+        let model = match self.devices.first() {
+            Some(device) => model.fork(device),
+            None => model,
+        };
+
+        lr_find(model, optim, dataloader_train, config, &self.interrupter)
+    }
+
+    /// Finds the largest batch size for which a training step fits in the memory of the device.
+    ///
+    /// The `batch` function creates a training batch of the given size, for instance by
+    /// repeating a sample batch or filling random tensors with the shape of the inputs. The batch
+    /// size grows exponentially until the device runs out of memory, then a binary search narrows
+    /// it down, each probe running the forward and backward passes and the optimizer step. The
+    /// model and the optimizer are consumed by the search, so they should be created again for
+    /// the training.
+    ///
+    /// # Notes
+    ///
+    /// Only backends reporting out of memory errors with a panic can be probed, a backend
+    /// aborting the process can't recover from the failing probe. Other panics are propagated.
+    pub fn batch_size_find<InputTrain>(
+        &self,
+        model: M,
+        optim: O,
+        batch: impl Fn(usize) -> InputTrain,
+        config: BatchSizeFinderConfig,
+    ) -> BatchSizeFinderResult
+    where
+        M: TrainStep<InputTrain, T>,
+    {
+        // The search runs on the first device, like the training.
+        let model = match self.devices.first() {
+            Some(device) => model.fork(device),
+            None => model,
+        };
+
+        batch_size_find(model, optim, batch, config, &self.interrupter)
+    }
+
     /// Provides a handle that can be used to interrupt training.
     pub fn interrupter(&self) -> TrainingInterrupter {
         self.interrupter.clone()
@@ -238,6 +400,65 @@ where
         self
     }
 
+    /// Return the model of the best epoch from `.fit()` instead of the model of the last epoch,
+    /// based on the given metric.
+    ///
+    /// # Notes
+    ///
+    /// The best model is restored from its checkpoint, so a checkpointer must be registered using
+    /// [with_file_checkpointer](Self::with_file_checkpointer). The checkpoint of the best epoch is
+    /// always kept, whatever the [checkpointing strategy](CheckpointingStrategy).
+    pub fn restore_best_model<Me: Metric>(
+        mut self,
+        aggregate: Aggregate,
+        direction: Direction,
+        split: Split,
+    ) -> Self {
+        let selection = BestModelSelection {
+            name: Me::NAME.to_string(),
+            aggregate,
+            direction,
+            split,
+        };
+        let strategy = MetricCheckpointingStrategy::new::<Me>(aggregate, direction, split);
+        self.best_model = Some((selection, strategy));
+        self
+    }
+
+    /// Register a [device watchdog](DeviceWatchdog) raising an error when no training or
+    /// validation step completes within its timeout, or when a step fails because the device
+    /// was lost.
+    ///
+    /// The error is returned by [try_fit](crate::Learner::try_fit), a clone of the watchdog can
+    /// be kept to poll the [error](DeviceWatchdog::error) from another thread when a step hangs.
+    ///
+    /// # Notes
+    ///
+    /// The first steps might be slower than the others because of kernel compilation and
+    /// autotuning, so the timeout should be large enough to account for it.
+    pub fn with_device_watchdog(mut self, watchdog: DeviceWatchdog) -> Self {
+        self.watchdog = Some(watchdog);
+        self
+    }
+
+    /// Resume the training from the last checkpoint with the given [recovery policy](DeviceRecovery)
+    /// when the [device watchdog](Self::with_device_watchdog) detects an error, instead of
+    /// returning the error from [try_fit](crate::Learner::try_fit).
+    ///
+    /// # Notes
+    ///
+    /// The checkpoints are saved at the end of the epochs by the
+    /// [file checkpointer](Self::with_file_checkpointer), so the training resumes from the
+    /// beginning of the epoch following the last checkpoint, and the metrics of the epochs that
+    /// were interrupted are logged again.
+    pub fn with_device_recovery(mut self, recovery: DeviceRecovery) -> Self
+    where
+        O: Clone,
+    {
+        self.recovery = Some((recovery, O::clone));
+        self
+    }
+
     /// By default, Rust logs are captured and written into
     /// `experiment.log`. If disabled, standard Rust log handling
     /// will apply.
@@ -251,7 +472,48 @@ where
 
     /// Register a checkpointer that will save the [optimizer](Optimizer), the
     /// [model](AutodiffModule) and the [scheduler](LrScheduler) to different files.
-    pub fn with_file_checkpointer<FR>(mut self, recorder: FR) -> Self
+    ///
+    /// The checkpoints are written on background threads, so the precision settings and the
+    /// compression of the recorder don't slow down training.
+    pub fn with_file_checkpointer<FR>(self, recorder: FR) -> Self
+    where
+        FR: FileRecorder<B> + 'static,
+        FR: FileRecorder<B::InnerBackend> + 'static,
+        O::Record: 'static,
+        M::Record: 'static,
+        S::Record<B>: 'static,
+    {
+        self.register_file_checkpointers(recorder, None)
+    }
+
+    /// Register a [file checkpointer](Self::with_file_checkpointer) uploading the checkpoints to
+    /// a remote store, e.g. S3, once they are saved.
+    ///
+    /// The uploads run on a background thread, and the local checkpoints deleted by the
+    /// [checkpointing strategy](CheckpointingStrategy) are only removed once uploaded. The
+    /// archived checkpoints are kept according to the retention of the
+    /// [uploader](CheckpointUploader), and the pending uploads are finished at the end of the
+    /// training.
+    pub fn with_remote_file_checkpointer<FR>(
+        self,
+        recorder: FR,
+        uploader: CheckpointUploader,
+    ) -> Self
+    where
+        FR: FileRecorder<B> + 'static,
+        FR: FileRecorder<B::InnerBackend> + 'static,
+        O::Record: 'static,
+        M::Record: 'static,
+        S::Record<B>: 'static,
+    {
+        self.register_file_checkpointers(recorder, Some(uploader))
+    }
+
+    fn register_file_checkpointers<FR>(
+        mut self,
+        recorder: FR,
+        uploader: Option<CheckpointUploader>,
+    ) -> Self
     where
         FR: FileRecorder<B> + 'static,
         FR: FileRecorder<B::InnerBackend> + 'static,
@@ -260,11 +522,25 @@ where
         S::Record<B>: 'static,
     {
         let checkpoint_dir = self.directory.join("checkpoint");
-        let checkpointer_model = FileCheckpointer::new(recorder.clone(), &checkpoint_dir, "model");
-        let checkpointer_optimizer =
-            FileCheckpointer::new(recorder.clone(), &checkpoint_dir, "optim");
-        let checkpointer_scheduler: FileCheckpointer<FR> =
-            FileCheckpointer::new(recorder, &checkpoint_dir, "scheduler");
+        let with_uploader = |checkpointer: FileCheckpointer<FR>| match &uploader {
+            Some(uploader) => checkpointer.with_uploader(uploader.clone()),
+            None => checkpointer,
+        };
+        let checkpointer_model = with_uploader(FileCheckpointer::new(
+            recorder.clone(),
+            &checkpoint_dir,
+            "model",
+        ));
+        let checkpointer_optimizer = with_uploader(FileCheckpointer::new(
+            recorder.clone(),
+            &checkpoint_dir,
+            "optim",
+        ));
+        let checkpointer_scheduler = with_uploader(FileCheckpointer::new(
+            recorder,
+            &checkpoint_dir,
+            "scheduler",
+        ));
 
         self.checkpointers = Some((
             AsyncCheckpointer::new(checkpointer_model),
@@ -286,6 +562,11 @@ where
     /// Create the [learner](Learner) from a [model](AutodiffModule) and an [optimizer](Optimizer).
     /// The [learning rate scheduler](LrScheduler) can also be a simple
     /// [learning rate](burn_core::LearningRate).
+    ///
+    /// A scheduler adapting the learning rate to a [metric](LrScheduler::metric_name), like the
+    /// [plateau scheduler](burn_core::lr_scheduler::plateau::PlateauLrScheduler), receives the mean
+    /// of the metric at the end of each validation epoch, so the metric must be registered with
+    /// [metric_valid_numeric](LearnerBuilder::metric_valid_numeric).
     #[allow(clippy::type_complexity)] // The goal for the builder is to handle all types and
                                       // creates a clean learner.
     pub fn build(
@@ -334,10 +615,37 @@ where
             event_store.clone(),
         ));
 
+        let (best_model, checkpointer_strategy) = match self.best_model {
+            Some((selection, strategy)) => {
+                let strategy: Box<dyn CheckpointingStrategy> = Box::new(
+                    ComposedCheckpointingStrategy::builder()
+                        .add(self.checkpointer_strategy)
+                        .add(strategy)
+                        .build(),
+                );
+                (Some(selection), strategy)
+            }
+            None => (None, self.checkpointer_strategy),
+        };
+
+        if best_model.is_some() && self.checkpointers.is_none() {
+            log::warn!("Can't restore the best model without a checkpointer.");
+        }
+
+        if self.checkpoint_interval.is_some() && self.checkpointers.is_none() {
+            log::warn!("Can't save checkpoints during an epoch without a checkpointer.");
+        }
+
+        let state_file = self.directory.join("checkpoint").join("train-state.json");
         let checkpointer = self.checkpointers.map(|(model, optim, scheduler)| {
-            LearnerCheckpointer::new(model, optim, scheduler, self.checkpointer_strategy)
+            LearnerCheckpointer::new(model, optim, scheduler, checkpointer_strategy)
+                .with_state_file(state_file)
         });
 
+        let reproducibility = self
+            .reproducibility
+            .map(|bundle| (bundle, self.directory.join("reproducibility.json")));
+
         let summary = if self.summary {
             Some(LearnerSummaryConfig {
                 directory: self.directory,
@@ -361,6 +669,16 @@ where
             interrupter: self.interrupter,
             early_stopping: self.early_stopping,
             summary,
+            watchdog: self.watchdog,
+            recovery: self
+                .recovery
+                .map(|(policy, clone_optim)| LearnerRecovery::new(policy, clone_optim)),
+            overfit_subset: self.overfit_subset,
+            best_model,
+            checkpoint_interval: self.checkpoint_interval,
+            callbacks: self.callbacks,
+            reproducibility,
+            diagnostics: self.diagnostics,
         }
     }
 }
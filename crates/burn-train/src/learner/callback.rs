@@ -0,0 +1,154 @@
+use crate::learner::TrainingInterrupter;
+use crate::metric::store::EventStoreClient;
+
+/// The state of the training provided to the [callbacks](TrainCallback).
+pub struct TrainCallbackContext<'a, M> {
+    /// The model being trained.
+    pub model: &'a M,
+    /// The current epoch.
+    pub epoch: usize,
+    /// The number of training iterations processed during the current epoch.
+    pub iteration: usize,
+    /// The store of the metrics collected during training and validation.
+    pub store: &'a EventStoreClient,
+    /// The interrupter, which can be used to stop the training.
+    pub interrupter: &'a TrainingInterrupter,
+}
+
+impl<M> TrainCallbackContext<'_, M> {
+    /// Request the learner to stop the training.
+    ///
+    /// The training stops after the current step, skipping the rest of the epoch.
+    pub fn stop(&self) {
+        self.interrupter.stop();
+    }
+}
+
+/// Hooks called by the [learner](crate::learner::Learner) during training, to add custom behaviors
+/// without rewriting the training loop.
+///
+/// Every hook has an empty default implementation, so only the required hooks need to be
+/// implemented.
+pub trait TrainCallback<M> {
+    /// Called once before the first training epoch.
+    fn on_train_begin(&mut self, _context: &TrainCallbackContext<'_, M>) {}
+
+    /// Called after each training iteration, once the model has been updated.
+    ///
+    /// The metrics are collected asynchronously, so the store may not include the metrics of the
+    /// last iterations yet.
+    fn on_batch_end(&mut self, _context: &TrainCallbackContext<'_, M>) {}
+
+    /// Called at the end of each epoch, after the validation.
+    fn on_valid_end(&mut self, _context: &TrainCallbackContext<'_, M>) {}
+
+    /// Called after the checkpoint of an epoch has been saved.
+    fn on_checkpoint(&mut self, _context: &TrainCallbackContext<'_, M>) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::learner::{dataloader, learner_builder, model, optimizer, TestModel};
+    use std::sync::{Arc, Mutex};
+
+    /// A hook called with its epoch and iteration.
+    type Call = (&'static str, usize, usize);
+
+    /// Records the hooks called with their epoch and iteration, and stops the training at the
+    /// given iteration.
+    #[derive(Clone, Default)]
+    struct Hooks {
+        calls: Arc<Mutex<Vec<Call>>>,
+        stop_at: Option<usize>,
+    }
+
+    impl Hooks {
+        fn record(&self, hook: &'static str, context: &TrainCallbackContext<'_, TestModel>) {
+            self.calls
+                .lock()
+                .unwrap()
+                .push((hook, context.epoch, context.iteration));
+        }
+
+        fn take(&self) -> Vec<Call> {
+            core::mem::take(&mut self.calls.lock().unwrap())
+        }
+    }
+
+    impl TrainCallback<TestModel> for Hooks {
+        fn on_train_begin(&mut self, context: &TrainCallbackContext<'_, TestModel>) {
+            self.record("train_begin", context);
+        }
+
+        fn on_batch_end(&mut self, context: &TrainCallbackContext<'_, TestModel>) {
+            self.record("batch_end", context);
+
+            if self.stop_at == Some(context.iteration) {
+                context.stop();
+            }
+        }
+
+        fn on_valid_end(&mut self, context: &TrainCallbackContext<'_, TestModel>) {
+            self.record("valid_end", context);
+        }
+
+        fn on_checkpoint(&mut self, context: &TrainCallbackContext<'_, TestModel>) {
+            self.record("checkpoint", context);
+        }
+    }
+
+    #[test]
+    fn should_call_hooks_in_order() {
+        let directory = tempfile::tempdir().unwrap();
+        let hooks = Hooks::default();
+
+        // 4 items in batches of 2, so 2 iterations per epoch.
+        learner_builder(directory.path())
+            .num_epochs(2)
+            .callback(hooks.clone())
+            .build(model(), optimizer(), 0.01)
+            .fit(dataloader(4), dataloader(4));
+
+        assert_eq!(
+            hooks.take(),
+            [
+                ("train_begin", 1, 0),
+                ("batch_end", 1, 1),
+                ("batch_end", 1, 2),
+                ("valid_end", 1, 2),
+                ("checkpoint", 1, 2),
+                ("batch_end", 2, 1),
+                ("batch_end", 2, 2),
+                ("valid_end", 2, 2),
+                ("checkpoint", 2, 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_stop_training_when_requested_by_callback() {
+        let directory = tempfile::tempdir().unwrap();
+        let hooks = Hooks {
+            stop_at: Some(2),
+            ..Default::default()
+        };
+
+        // 10 items in batches of 2, so 5 iterations per epoch.
+        learner_builder(directory.path())
+            .num_epochs(3)
+            .callback(hooks.clone())
+            .build(model(), optimizer(), 0.01)
+            .fit(dataloader(10), dataloader(10));
+
+        // The rest of the epoch, the validation and the next epochs are skipped.
+        assert_eq!(
+            hooks.take(),
+            [
+                ("train_begin", 1, 0),
+                ("batch_end", 1, 1),
+                ("batch_end", 1, 2),
+            ]
+        );
+    }
+}
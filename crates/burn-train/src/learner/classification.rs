@@ -1,9 +1,13 @@
+use super::combine_ensemble_outputs;
 use crate::metric::{
-    processor::ItemLazy, AccuracyInput, Adaptor, ConfusionStatsInput, HammingScoreInput, LossInput,
+    processor::ItemLazy, AccuracyInput, Adaptor, CalibrationInput, ConfusionStatsInput,
+    HammingScoreInput, LossInput,
 };
+use burn_core::module::{EnsembleOutput, EnsembleReduction};
 use burn_core::tensor::backend::Backend;
-use burn_core::tensor::{Int, Tensor, Transaction};
+use burn_core::tensor::{Int, Tensor, TensorData, Transaction};
 use burn_ndarray::NdArray;
+use std::future::Future;
 
 /// Simple classification output adapted for multiple metrics.
 #[derive(new)]
@@ -22,17 +26,31 @@ impl<B: Backend> ItemLazy for ClassificationOutput<B> {
     type ItemSync = ClassificationOutput<NdArray>;
 
     fn sync(self) -> Self::ItemSync {
-        let [output, loss, targets] = Transaction::default()
+        ClassificationOutput::from_data(self.transaction().execute())
+    }
+
+    fn sync_async(self) -> impl Future<Output = Self::ItemSync> + Send + 'static {
+        let data = self.transaction().execute_async();
+
+        async move { ClassificationOutput::from_data(data.await) }
+    }
+}
+
+impl<B: Backend> ClassificationOutput<B> {
+    fn transaction(self) -> Transaction<B> {
+        Transaction::default()
             .register(self.output)
             .register(self.loss)
             .register(self.targets)
-            .execute()
-            .try_into()
-            .expect("Correct amount of tensor data");
+    }
+}
 
+impl ClassificationOutput<NdArray> {
+    fn from_data(data: Vec<TensorData>) -> Self {
+        let [output, loss, targets] = data.try_into().expect("Correct amount of tensor data");
         let device = &Default::default();
 
-        ClassificationOutput {
+        Self {
             output: Tensor::from_data(output, device),
             loss: Tensor::from_data(loss, device),
             targets: Tensor::from_data(targets, device),
@@ -40,12 +58,35 @@ impl<B: Backend> ItemLazy for ClassificationOutput<B> {
     }
 }
 
+impl<B: Backend> EnsembleOutput for ClassificationOutput<B> {
+    fn combine(outputs: Vec<Self>, reduction: EnsembleReduction) -> Self {
+        let (loss, output, targets) = combine_ensemble_outputs(
+            outputs
+                .into_iter()
+                .map(|output| (output.loss, output.output, output.targets)),
+            reduction,
+        );
+
+        Self {
+            loss,
+            output,
+            targets,
+        }
+    }
+}
+
 impl<B: Backend> Adaptor<AccuracyInput<B>> for ClassificationOutput<B> {
     fn adapt(&self) -> AccuracyInput<B> {
         AccuracyInput::new(self.output.clone(), self.targets.clone())
     }
 }
 
+impl<B: Backend> Adaptor<CalibrationInput<B>> for ClassificationOutput<B> {
+    fn adapt(&self) -> CalibrationInput<B> {
+        CalibrationInput::new(self.output.clone(), self.targets.clone())
+    }
+}
+
 impl<B: Backend> Adaptor<LossInput<B>> for ClassificationOutput<B> {
     fn adapt(&self) -> LossInput<B> {
         LossInput::new(self.loss.clone())
@@ -86,17 +127,31 @@ impl<B: Backend> ItemLazy for MultiLabelClassificationOutput<B> {
     type ItemSync = MultiLabelClassificationOutput<NdArray>;
 
     fn sync(self) -> Self::ItemSync {
-        let [output, loss, targets] = Transaction::default()
+        MultiLabelClassificationOutput::from_data(self.transaction().execute())
+    }
+
+    fn sync_async(self) -> impl Future<Output = Self::ItemSync> + Send + 'static {
+        let data = self.transaction().execute_async();
+
+        async move { MultiLabelClassificationOutput::from_data(data.await) }
+    }
+}
+
+impl<B: Backend> MultiLabelClassificationOutput<B> {
+    fn transaction(self) -> Transaction<B> {
+        Transaction::default()
             .register(self.output)
             .register(self.loss)
             .register(self.targets)
-            .execute()
-            .try_into()
-            .expect("Correct amount of tensor data");
+    }
+}
 
+impl MultiLabelClassificationOutput<NdArray> {
+    fn from_data(data: Vec<TensorData>) -> Self {
+        let [output, loss, targets] = data.try_into().expect("Correct amount of tensor data");
         let device = &Default::default();
 
-        MultiLabelClassificationOutput {
+        Self {
             output: Tensor::from_data(output, device),
             loss: Tensor::from_data(loss, device),
             targets: Tensor::from_data(targets, device),
@@ -104,6 +159,23 @@ impl<B: Backend> ItemLazy for MultiLabelClassificationOutput<B> {
     }
 }
 
+impl<B: Backend> EnsembleOutput for MultiLabelClassificationOutput<B> {
+    fn combine(outputs: Vec<Self>, reduction: EnsembleReduction) -> Self {
+        let (loss, output, targets) = combine_ensemble_outputs(
+            outputs
+                .into_iter()
+                .map(|output| (output.loss, output.output, output.targets)),
+            reduction,
+        );
+
+        Self {
+            loss,
+            output,
+            targets,
+        }
+    }
+}
+
 impl<B: Backend> Adaptor<HammingScoreInput<B>> for MultiLabelClassificationOutput<B> {
     fn adapt(&self) -> HammingScoreInput<B> {
         HammingScoreInput::new(self.output.clone(), self.targets.clone())
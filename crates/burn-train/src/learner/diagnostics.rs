@@ -0,0 +1,345 @@
+use std::collections::HashMap;
+
+use burn_core::{
+    self as burn,
+    config::Config,
+    module::{AutodiffModule, ModuleVisitor, ParamId},
+    optim::GradientsParams,
+    tensor::{
+        backend::{AutodiffBackend, Backend},
+        ElementConversion, Tensor,
+    },
+};
+
+/// Configuration of the [training diagnostics](TrainingDiagnostics) sampled by the learner.
+#[derive(Config, Debug)]
+pub struct TrainingDiagnosticsConfig {
+    /// The number of iterations between two samples of the diagnostics.
+    #[config(default = 100)]
+    pub interval: usize,
+    /// The number of consecutive gradients compared to estimate the gradient noise scale, at
+    /// least two.
+    #[config(default = 8)]
+    pub num_gradients: usize,
+    /// The number of items of each batch. The gradient noise scale is expressed in batches when
+    /// it isn't provided.
+    pub batch_size: Option<usize>,
+}
+
+/// Diagnostics of the training dynamics, sampled every few iterations, helping to choose the batch
+/// size and the learning rate.
+///
+/// They are exposed to the metrics through the [metadata](crate::metric::MetricMetadata) of the
+/// iteration where they were sampled, see
+/// [GradientNoiseScaleMetric](crate::metric::GradientNoiseScaleMetric) and
+/// [UpdateRatioMetric](crate::metric::UpdateRatioMetric).
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrainingDiagnostics {
+    /// The simple gradient noise scale, the batch size beyond which larger batches yield
+    /// diminishing returns.
+    ///
+    /// It is estimated from the norms of consecutive gradients and of their mean, as described in
+    /// [An Empirical Model of Large-Batch Training](https://arxiv.org/abs/1812.06162), and is
+    /// noisy for a single sample.
+    pub gradient_noise_scale: Option<f64>,
+    /// The update-to-weight ratio of each parameter, in the order of the fields of the modules.
+    pub update_ratios: Vec<UpdateRatio>,
+}
+
+/// The ratio between the norm of the update of a parameter by an optimizer step and the norm of
+/// the parameter before the step.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UpdateRatio {
+    /// The id of the parameter.
+    pub id: ParamId,
+    /// The shape of the parameter.
+    pub shape: Vec<usize>,
+    /// The ratio, usually around `1e-3` for a well tuned learning rate.
+    pub ratio: f64,
+}
+
+/// Flattened float tensors of a module, by parameter id.
+type Flattened<B> = Vec<(ParamId, Vec<usize>, Tensor<B, 1>)>;
+
+/// Samples the [training diagnostics](TrainingDiagnostics) during a training epoch.
+pub(crate) struct DiagnosticsCollector<B: AutodiffBackend> {
+    config: TrainingDiagnosticsConfig,
+    gradients_sum: HashMap<ParamId, Tensor<B::InnerBackend, 1>>,
+    squared_norms_sum: f64,
+    num_gradients: usize,
+    update_due: bool,
+    pending: Option<TrainingDiagnostics>,
+}
+
+impl<B: AutodiffBackend> DiagnosticsCollector<B> {
+    pub(crate) fn new(config: TrainingDiagnosticsConfig) -> Self {
+        assert!(
+            config.num_gradients >= 2,
+            "At least two gradients are required to estimate the gradient noise scale"
+        );
+
+        Self {
+            config,
+            gradients_sum: HashMap::new(),
+            squared_norms_sum: 0.0,
+            num_gradients: 0,
+            update_due: false,
+            pending: None,
+        }
+    }
+
+    /// Collects the gradients of the iteration when it is part of a sample, starting every
+    /// `interval` iterations.
+    pub(crate) fn observe_gradients<M: AutodiffModule<B>>(
+        &mut self,
+        model: &M,
+        grads: &GradientsParams,
+        iteration: usize,
+    ) {
+        let position = (iteration - 1) % self.config.interval;
+
+        if position == 0 {
+            self.update_due = true;
+        }
+        if position >= self.config.num_gradients {
+            return;
+        }
+
+        let mut visitor = GradientsVisitor::<B::InnerBackend> {
+            grads,
+            output: Vec::new(),
+        };
+        model.visit(&mut visitor);
+
+        let squared_norms = visitor
+            .output
+            .iter()
+            .map(|(_, _, grad)| grad.clone().powi_scalar(2).sum())
+            .collect();
+        self.squared_norms_sum += sum_scalars(squared_norms);
+
+        for (id, _, grad) in visitor.output {
+            let sum = match self.gradients_sum.remove(&id) {
+                Some(sum) => sum + grad,
+                None => grad,
+            };
+            self.gradients_sum.insert(id, sum);
+        }
+
+        self.num_gradients += 1;
+        if self.num_gradients == self.config.num_gradients {
+            let gradient_noise_scale = self.gradient_noise_scale();
+            self.pending_mut().gradient_noise_scale = gradient_noise_scale;
+        }
+    }
+
+    /// The parameters before the optimizer step, when the update ratios are sampled by this step.
+    pub(crate) fn params_before_update<M: AutodiffModule<B>>(
+        &self,
+        model: &M,
+    ) -> Option<Flattened<B::InnerBackend>> {
+        match self.update_due {
+            true => Some(flatten_params(model)),
+            false => None,
+        }
+    }
+
+    /// Computes the update ratios of the optimizer step from the parameters before the step.
+    pub(crate) fn observe_update<M: AutodiffModule<B>>(
+        &mut self,
+        model: &M,
+        params_before: Flattened<B::InnerBackend>,
+    ) {
+        self.update_due = false;
+
+        let mut params_after: HashMap<_, _> = flatten_params(model)
+            .into_iter()
+            .map(|(id, _, param)| (id, param))
+            .collect();
+
+        let mut params = Vec::new();
+        let mut squared_norms_update = Vec::new();
+        let mut squared_norms_param = Vec::new();
+
+        for (id, shape, before) in params_before {
+            let Some(after) = params_after.remove(&id) else {
+                continue;
+            };
+
+            squared_norms_update.push((after - before.clone()).powi_scalar(2).sum());
+            squared_norms_param.push(before.powi_scalar(2).sum());
+            params.push((id, shape));
+        }
+
+        if params.is_empty() {
+            return;
+        }
+
+        let squared_norms_update = read_scalars(squared_norms_update);
+        let squared_norms_param = read_scalars(squared_norms_param);
+
+        self.pending_mut().update_ratios = params
+            .into_iter()
+            .zip(squared_norms_update.into_iter().zip(squared_norms_param))
+            .map(|((id, shape), (update, param))| UpdateRatio {
+                id,
+                shape,
+                ratio: (update / param).sqrt(),
+            })
+            .collect();
+    }
+
+    /// The diagnostics sampled since the last call.
+    pub(crate) fn take(&mut self) -> Option<TrainingDiagnostics> {
+        self.pending.take()
+    }
+
+    fn pending_mut(&mut self) -> &mut TrainingDiagnostics {
+        self.pending.get_or_insert_with(|| TrainingDiagnostics {
+            gradient_noise_scale: None,
+            update_ratios: Vec::new(),
+        })
+    }
+
+    fn gradient_noise_scale(&mut self) -> Option<f64> {
+        let num_gradients = self.num_gradients as f64;
+        let squared_norm_small = self.squared_norms_sum / num_gradients;
+        let squared_norm_big = sum_scalars(
+            self.gradients_sum
+                .drain()
+                .map(|(_, sum)| (sum / num_gradients).powi_scalar(2).sum())
+                .collect(),
+        );
+
+        self.squared_norms_sum = 0.0;
+        self.num_gradients = 0;
+
+        let batch_size = self.config.batch_size.unwrap_or(1) as f64;
+        gradient_noise_scale(
+            squared_norm_small,
+            squared_norm_big,
+            batch_size,
+            batch_size * num_gradients,
+        )
+    }
+}
+
+/// Unbiased estimate of the simple gradient noise scale from the squared norms of the gradients
+/// of a small and a big batch size, see Appendix A of
+/// [An Empirical Model of Large-Batch Training](https://arxiv.org/abs/1812.06162).
+fn gradient_noise_scale(
+    squared_norm_small: f64,
+    squared_norm_big: f64,
+    batch_size_small: f64,
+    batch_size_big: f64,
+) -> Option<f64> {
+    let squared_norm_true = (batch_size_big * squared_norm_big
+        - batch_size_small * squared_norm_small)
+        / (batch_size_big - batch_size_small);
+    let trace_covariance =
+        (squared_norm_small - squared_norm_big) / (1.0 / batch_size_small - 1.0 / batch_size_big);
+
+    // The estimate of the true gradient norm can be negative when the gradients are too noisy.
+    match squared_norm_true > 0.0 {
+        true => Some(trace_covariance / squared_norm_true),
+        false => None,
+    }
+}
+
+struct GradientsVisitor<'a, B: Backend> {
+    grads: &'a GradientsParams,
+    output: Flattened<B>,
+}
+
+impl<B: AutodiffBackend> ModuleVisitor<B> for GradientsVisitor<'_, B::InnerBackend> {
+    fn visit_float<const D: usize>(&mut self, id: ParamId, tensor: &Tensor<B, D>) {
+        if let Some(grad) = self.grads.get::<B::InnerBackend, D>(id) {
+            let shape = tensor.dims().to_vec();
+            self.output.push((id, shape, grad.flatten(0, D - 1)));
+        }
+    }
+}
+
+struct ParamsVisitor<B: Backend> {
+    output: Flattened<B>,
+}
+
+impl<B: AutodiffBackend> ModuleVisitor<B> for ParamsVisitor<B::InnerBackend> {
+    fn visit_float<const D: usize>(&mut self, id: ParamId, tensor: &Tensor<B, D>) {
+        let shape = tensor.dims().to_vec();
+        self.output
+            .push((id, shape, tensor.clone().inner().flatten(0, D - 1)));
+    }
+}
+
+fn flatten_params<B: AutodiffBackend, M: AutodiffModule<B>>(
+    model: &M,
+) -> Flattened<B::InnerBackend> {
+    let mut visitor = ParamsVisitor::<B::InnerBackend> { output: Vec::new() };
+    model.visit(&mut visitor);
+    visitor.output
+}
+
+/// Reads the scalar tensors with a single synchronization.
+fn read_scalars<B: Backend>(scalars: Vec<Tensor<B, 1>>) -> Vec<f64> {
+    if scalars.is_empty() {
+        return Vec::new();
+    }
+
+    Tensor::cat(scalars, 0).into_data().iter::<f64>().collect()
+}
+
+fn sum_scalars<B: Backend>(scalars: Vec<Tensor<B, 1>>) -> f64 {
+    match scalars.is_empty() {
+        true => 0.0,
+        false => Tensor::cat(scalars, 0).sum().into_scalar().elem(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestAutodiffBackend;
+    use burn_core::nn::{Linear, LinearConfig};
+
+    #[test]
+    fn test_gradient_noise_scale_of_known_statistics() {
+        // With a true squared norm of 1 and a trace of the covariance of 10, the squared norm of
+        // the gradient of a batch of size b is 1 + 10 / b.
+        let scale = gradient_noise_scale(1.0 + 10.0 / 4.0, 1.0 + 10.0 / 32.0, 4.0, 32.0);
+
+        assert!((scale.unwrap() - 10.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_gradient_noise_scale_too_noisy() {
+        assert_eq!(gradient_noise_scale(1.0, 0.0, 1.0, 8.0), None);
+    }
+
+    #[test]
+    fn test_update_ratios_of_optimizer_step() {
+        let device = Default::default();
+        let model: Linear<TestAutodiffBackend> = LinearConfig::new(4, 2).init(&device);
+        let mut collector = DiagnosticsCollector::<TestAutodiffBackend>::new(
+            TrainingDiagnosticsConfig::new().with_interval(10),
+        );
+
+        collector.observe_gradients(&model, &GradientsParams::new(), 1);
+        let params_before = collector.params_before_update(&model).unwrap();
+        let weight = model.weight.val();
+        let model = Linear {
+            weight: model.weight.map(|weight| weight.clone() + weight * 0.01),
+            bias: model.bias,
+        };
+        collector.observe_update(&model, params_before);
+
+        let diagnostics = collector.take().unwrap();
+        let weight_ratio = diagnostics
+            .update_ratios
+            .iter()
+            .find(|ratio| ratio.shape == weight.dims().to_vec())
+            .unwrap();
+        assert!((weight_ratio.ratio - 0.01).abs() < 1e-4);
+        assert!(collector.params_before_update(&model).is_none());
+    }
+}
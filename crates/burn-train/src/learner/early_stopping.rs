@@ -28,6 +28,7 @@ pub struct MetricEarlyStoppingStrategy {
     split: Split,
     best_epoch: usize,
     best_value: f64,
+    min_delta: f64,
 }
 
 impl EarlyStoppingStrategy for MetricEarlyStoppingStrategy {
@@ -42,8 +43,8 @@ impl EarlyStoppingStrategy for MetricEarlyStoppingStrategy {
             };
 
         let is_best = match self.direction {
-            Direction::Lowest => current_value < self.best_value,
-            Direction::Highest => current_value > self.best_value,
+            Direction::Lowest => current_value < self.best_value - self.min_delta,
+            Direction::Highest => current_value > self.best_value + self.min_delta,
         };
 
         if is_best {
@@ -107,8 +108,16 @@ impl MetricEarlyStoppingStrategy {
             split,
             best_epoch: 1,
             best_value: init_value,
+            min_delta: 0.0,
         }
     }
+
+    /// Only consider the metric improved when it gets better than the best value by more than
+    /// `min_delta`.
+    pub fn with_min_delta(mut self, min_delta: f64) -> Self {
+        self.min_delta = min_delta;
+        self
+    }
 }
 
 #[cfg(test)]
@@ -184,13 +193,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn early_stop_when_improvement_is_smaller_than_min_delta() {
+        test_early_stopping_with_min_delta(
+            2,
+            0.1,
+            &[
+                (&[0.5, 0.3], false, "Should not stop first epoch"),
+                (&[0.2, 0.2], false, "Should not stop when improving"),
+                (
+                    &[0.2, 0.15],
+                    false,
+                    "Should not stop first time it improves less than min delta",
+                ),
+                (
+                    &[0.15, 0.15],
+                    true,
+                    "Should stop since two following epochs didn't improve by min delta",
+                ),
+            ],
+        );
+    }
+
     fn test_early_stopping(n_epochs: usize, data: &[(&[f64], bool, &str)]) {
+        test_early_stopping_with_min_delta(n_epochs, 0.0, data)
+    }
+
+    fn test_early_stopping_with_min_delta(
+        n_epochs: usize,
+        min_delta: f64,
+        data: &[(&[f64], bool, &str)],
+    ) {
         let mut early_stopping = MetricEarlyStoppingStrategy::new::<LossMetric<TestBackend>>(
             Aggregate::Mean,
             Direction::Lowest,
             Split::Train,
             StoppingCondition::NoImprovementSince { n_epochs },
-        );
+        )
+        .with_min_delta(min_delta);
         let mut store = LogEventStore::default();
         let mut metrics = Metrics::<f64, f64>::default();
 
@@ -1,9 +1,16 @@
 use burn_core::{
-    data::dataloader::DataLoader, lr_scheduler::LrScheduler, module::AutodiffModule,
-    optim::GradientsAccumulator, tensor::backend::Backend,
+    self as burn,
+    config::Config,
+    data::dataloader::{DataLoader, DataLoaderIterator, DataLoaderState},
+    lr_scheduler::LrScheduler,
+    module::AutodiffModule,
+    optim::{GradientsAccumulator, GradientsParams},
+    tensor::backend::{AutodiffBackend, Backend, RngState},
+    LearningRate,
 };
 use std::sync::Arc;
 
+use crate::learner::{DeviceWatchdog, DiagnosticsCollector, TrainingDiagnosticsConfig};
 use crate::metric::processor::{Event, EventProcessor, LearnerItem};
 use crate::{components::LearnerComponents, learner::base::TrainingInterrupter};
 use crate::{MultiDevicesTrainStep, TrainStep, ValidStep};
@@ -14,6 +21,8 @@ pub struct ValidEpoch<VI> {
     dataloader: Arc<dyn DataLoader<VI>>,
     epoch: usize,
     epoch_total: usize,
+    #[new(default)]
+    watchdog: Option<DeviceWatchdog>,
 }
 
 /// A training epoch.
@@ -22,10 +31,65 @@ pub struct TrainEpoch<TI> {
     dataloader: Arc<dyn DataLoader<TI>>,
     epoch: usize,
     epoch_total: usize,
-    grad_accumulation: Option<usize>,
+    grad_accumulation: Option<GradAccumulation>,
+    #[new(default)]
+    watchdog: Option<DeviceWatchdog>,
+    #[new(default)]
+    state: Option<TrainEpochState>,
+    #[new(default)]
+    checkpoint_interval: Option<usize>,
+    #[new(default)]
+    diagnostics: Option<TrainingDiagnosticsConfig>,
 }
 
+/// How the gradients of multiple iterations are accumulated before each optimizer step.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GradAccumulation {
+    /// Sum the gradients of the given number of iterations.
+    ///
+    /// The learning rate scheduler and the reported iterations still advance on every iteration,
+    /// and the gradients accumulated at the end of an epoch without reaching an optimizer step
+    /// are dropped.
+    Sum(usize),
+    /// Average the gradients of the given number of micro-batches, which is equivalent to scaling
+    /// the loss of each micro-batch by the inverse of the number of micro-batches.
+    ///
+    /// The learning rate scheduler and the reported iterations advance once per optimizer step, so
+    /// training with micro-batches of size `b` behaves like training with batches of size `n * b`,
+    /// except for the modules computing statistics over the batch, such as batch normalization,
+    /// which still see each micro-batch independently. The gradients accumulated at the end of an
+    /// epoch are averaged and applied in a last, smaller, optimizer step.
+    Mean(usize),
+}
+
+/// The state of a training epoch, used to resume it from the iteration where it was checkpointed.
+#[derive(Config, Debug)]
+pub struct TrainEpochState {
+    /// The epoch.
+    pub epoch: usize,
+    /// The number of iterations that have been processed.
+    pub iteration: usize,
+    /// The state of the training dataloader.
+    pub dataloader: DataLoaderState,
+    /// The state of the random number generator of the backend, restored with the epoch so the
+    /// following iterations draw the same random numbers, when the backend exposes it.
+    pub rng: Option<RngState>,
+}
+
+/// Function called with the model, the optimizer, the learning rate scheduler and the
+/// [state](TrainEpochState) of the epoch when a checkpoint should be saved during the epoch.
+pub type TrainEpochCheckpoint<'a, M, O, S> = dyn FnMut(&M, &O, &S, TrainEpochState) + 'a;
+
+/// Function called with the model and the iteration after each training iteration.
+pub type TrainEpochBatchEnd<'a, M> = dyn FnMut(&M, usize) + 'a;
+
 impl<VI> ValidEpoch<VI> {
+    /// Notify the given [watchdog](DeviceWatchdog) after every step.
+    pub fn with_watchdog(mut self, watchdog: Option<DeviceWatchdog>) -> Self {
+        self.watchdog = watchdog;
+        self
+    }
+
     /// Runs the validation epoch.
     ///
     /// # Arguments
@@ -47,11 +111,13 @@ impl<VI> ValidEpoch<VI> {
         let mut iterator = self.dataloader.iter();
         let mut iteration = 0;
 
-        while let Some(item) = iterator.next() {
+        while let Some(item) = tracing::info_span!("dataloader").in_scope(|| iterator.next()) {
             let progress = iterator.progress();
             iteration += 1;
+            let _span =
+                tracing::info_span!("valid_iteration", epoch = self.epoch, iteration).entered();
 
-            let item = model.step(item);
+            let item = tracing::info_span!("valid_step").in_scope(|| model.step(item));
             let item = LearnerItem::new(
                 item,
                 progress,
@@ -63,6 +129,10 @@ impl<VI> ValidEpoch<VI> {
 
             processor.process_valid(Event::ProcessedItem(item));
 
+            if let Some(watchdog) = &self.watchdog {
+                watchdog.beat();
+            }
+
             if interrupter.should_stop() {
                 log::info!("Training interrupted.");
                 break;
@@ -73,6 +143,61 @@ impl<VI> ValidEpoch<VI> {
 }
 
 impl<TI> TrainEpoch<TI> {
+    /// Notify the given [watchdog](DeviceWatchdog) after every step.
+    pub fn with_watchdog(mut self, watchdog: Option<DeviceWatchdog>) -> Self {
+        self.watchdog = watchdog;
+        self
+    }
+
+    /// Resume the epoch from the given [state](TrainEpochState) instead of starting from the
+    /// first iteration.
+    pub fn with_state(mut self, state: Option<TrainEpochState>) -> Self {
+        self.state = state;
+        self
+    }
+
+    /// Save a checkpoint during the epoch every `checkpoint_interval` iterations.
+    ///
+    /// With gradient accumulation, the checkpoint is delayed until the accumulated gradients are
+    /// applied.
+    pub fn with_checkpoint_interval(mut self, checkpoint_interval: Option<usize>) -> Self {
+        self.checkpoint_interval = checkpoint_interval;
+        self
+    }
+
+    /// Sample the [training diagnostics](crate::TrainingDiagnostics) with the given configuration.
+    pub fn with_diagnostics(mut self, diagnostics: Option<TrainingDiagnosticsConfig>) -> Self {
+        self.diagnostics = diagnostics;
+        self
+    }
+
+    fn iter<B: Backend>(&self) -> (Box<dyn DataLoaderIterator<TI> + '_>, usize) {
+        match &self.state {
+            Some(state) => {
+                log::info!(
+                    "Resuming epoch {} from iteration {}",
+                    self.epoch,
+                    state.iteration
+                );
+                if let Some(rng) = state.rng {
+                    B::set_rng_state(rng);
+                }
+                (
+                    self.dataloader.iter_from(&state.dataloader),
+                    state.iteration,
+                )
+            }
+            None => (self.dataloader.iter(), 0),
+        }
+    }
+
+    fn should_checkpoint(&self, iteration: usize, last_checkpoint: usize) -> bool {
+        match self.checkpoint_interval {
+            Some(interval) => iteration - last_checkpoint >= interval,
+            None => false,
+        }
+    }
+
     /// Runs the training epoch.
     ///
     /// # Arguments
@@ -81,10 +206,13 @@ impl<TI> TrainEpoch<TI> {
     /// * `optim` - The optimizer to use.
     /// * `scheduler` - The learning rate scheduler to use.
     /// * `processor` - The event processor to use.
+    /// * `checkpoint` - The function saving a checkpoint during the epoch.
+    /// * `batch_end` - The function called after each iteration.
     ///
     /// # Returns
     ///
     /// The trained model and the optimizer.
+    #[allow(clippy::too_many_arguments)]
     pub fn run<LC: LearnerComponents, TO>(
         &self,
         mut model: LC::Model,
@@ -92,6 +220,8 @@ impl<TI> TrainEpoch<TI> {
         scheduler: &mut LC::LrScheduler,
         processor: &mut LC::EventProcessor,
         interrupter: &TrainingInterrupter,
+        checkpoint: &mut TrainEpochCheckpoint<'_, LC::Model, LC::Optimizer, LC::LrScheduler>,
+        batch_end: &mut TrainEpochBatchEnd<'_, LC::Model>,
     ) -> (LC::Model, LC::Optimizer)
     where
         LC::EventProcessor: EventProcessor<ItemTrain = TO>,
@@ -99,49 +229,78 @@ impl<TI> TrainEpoch<TI> {
     {
         log::info!("Executing training step for epoch {}", self.epoch,);
 
-        let mut iterator = self.dataloader.iter();
-        let mut iteration = 0;
-        let mut accumulator = GradientsAccumulator::new();
-        let mut accumulation_current = 0;
-
-        while let Some(item) = iterator.next() {
+        let (mut iterator, mut iteration) = self.iter::<LC::Backend>();
+        let mut last_checkpoint = iteration;
+        let mut accumulation = Accumulation::new(self.grad_accumulation, 1);
+        let mut diagnostics = self
+            .diagnostics
+            .clone()
+            .map(DiagnosticsCollector::<LC::Backend>::new);
+        let mut step = accumulation.num_optimizer_steps(iteration);
+        let mut lr: LearningRate = 0.0;
+
+        while let Some(item) = tracing::info_span!("dataloader").in_scope(|| iterator.next()) {
             iteration += 1;
-            let lr = scheduler.step();
+            let _span =
+                tracing::info_span!("train_iteration", epoch = self.epoch, iteration).entered();
+            if accumulation.is_step_start() {
+                lr = scheduler.step();
+                step += 1;
+            }
             log::info!("Iteration {}", iteration);
 
             let progress = iterator.progress();
-            let item = model.step(item);
+            let item = tracing::info_span!("train_step").in_scope(|| model.step(item));
 
-            match self.grad_accumulation {
-                Some(accumulation) => {
-                    accumulator.accumulate(&model, item.grads);
-                    accumulation_current += 1;
+            if let Some(diagnostics) = diagnostics.as_mut() {
+                diagnostics.observe_gradients(&model, &item.grads, iteration);
+            }
 
-                    if accumulation <= accumulation_current {
-                        let grads = accumulator.grads();
-                        model = model.optimize(&mut optim, lr, grads);
-                        accumulation_current = 0;
-                    }
+            if let Some(grads) = accumulation.accumulate(&model, item.grads) {
+                let params = diagnostics
+                    .as_ref()
+                    .and_then(|diagnostics| diagnostics.params_before_update(&model));
+                model = tracing::info_span!("optimizer_step", lr)
+                    .in_scope(|| model.optimize(&mut optim, lr, grads));
+
+                if let (Some(diagnostics), Some(params)) = (diagnostics.as_mut(), params) {
+                    diagnostics.observe_update(&model, params);
                 }
-                None => model = model.optimize(&mut optim, lr, item.grads),
             }
 
-            let item = LearnerItem::new(
+            let mut item = LearnerItem::new(
                 item.item,
                 progress,
                 self.epoch,
                 self.epoch_total,
-                iteration,
+                step,
                 Some(lr),
             );
+            item.diagnostics = diagnostics.as_mut().and_then(DiagnosticsCollector::take);
 
             processor.process_train(Event::ProcessedItem(item));
+            batch_end(&model, step);
+
+            if let Some(watchdog) = &self.watchdog {
+                watchdog.beat();
+            }
+
+            if accumulation.is_boundary() && self.should_checkpoint(iteration, last_checkpoint) {
+                let state = TrainEpochState::new(self.epoch, iteration, iterator.state())
+                    .with_rng(LC::Backend::rng_state());
+                checkpoint(&model, &optim, scheduler, state);
+                last_checkpoint = iteration;
+            }
 
             if interrupter.should_stop() {
                 log::info!("Training interrupted.");
                 break;
             }
         }
+
+        if let Some(grads) = accumulation.remaining(&model) {
+            model = model.optimize(&mut optim, lr, grads);
+        }
         processor.process_train(Event::EndEpoch(self.epoch));
 
         (model, optim)
@@ -158,10 +317,13 @@ impl<TI> TrainEpoch<TI> {
     /// * `lr_scheduler` - The learning rate scheduler to use.
     /// * `processor` - The event processor to use.
     /// * `devices` - The devices to use.
+    /// * `checkpoint` - The function saving a checkpoint during the epoch.
+    /// * `batch_end` - The function called after each iteration.
     ///
     /// # Returns
     ///
     /// The trained model and the optimizer.
+    #[allow(clippy::too_many_arguments)]
     pub fn run_multi_device<LC: LearnerComponents, TO>(
         &self,
         mut model: LC::Model,
@@ -170,6 +332,8 @@ impl<TI> TrainEpoch<TI> {
         processor: &mut LC::EventProcessor,
         devices: Vec<<LC::Backend as Backend>::Device>,
         interrupter: &TrainingInterrupter,
+        checkpoint: &mut TrainEpochCheckpoint<'_, LC::Model, LC::Optimizer, LC::LrScheduler>,
+        batch_end: &mut TrainEpochBatchEnd<'_, LC::Model>,
     ) -> (LC::Model, LC::Optimizer)
     where
         LC::EventProcessor: EventProcessor<ItemTrain = TO>,
@@ -183,12 +347,16 @@ impl<TI> TrainEpoch<TI> {
             devices
         );
 
-        let mut iterator = self.dataloader.iter();
-        let mut iteration = 0;
-        let mut accumulator = GradientsAccumulator::new();
-        let mut accumulation_current = 0;
+        let (mut iterator, mut iteration) = self.iter::<LC::Backend>();
+        let mut last_checkpoint = iteration;
+        let mut accumulation = Accumulation::new(self.grad_accumulation, devices.len());
+        let mut diagnostics = self
+            .diagnostics
+            .clone()
+            .map(DiagnosticsCollector::<LC::Backend>::new);
+        let mut optimizer_step = accumulation.num_optimizer_steps(iteration);
+        let mut lr: LearningRate = 0.0;
 
-        let accumulation = self.grad_accumulation.unwrap_or(1) * devices.len();
         let step = MultiDevicesTrainStep::new(&devices);
 
         // The main device is always the first in the list.
@@ -196,37 +364,54 @@ impl<TI> TrainEpoch<TI> {
         let mut interrupted = false;
 
         loop {
-            let items = step.step(&mut iterator, &model);
+            let items = tracing::info_span!("train_step", devices = devices.len())
+                .in_scope(|| step.step(&mut iterator, &model));
             if items.is_empty() {
                 break;
             }
 
             for item in items {
                 iteration += 1;
-                let lr = lr_scheduler.step();
+                if accumulation.is_step_start() {
+                    lr = lr_scheduler.step();
+                    optimizer_step += 1;
+                }
                 let progress = iterator.progress();
 
                 let grads = item.grads.to_device(&device_main, &model);
 
-                accumulator.accumulate(&model, grads);
-                accumulation_current += 1;
+                if let Some(diagnostics) = diagnostics.as_mut() {
+                    diagnostics.observe_gradients(&model, &grads, iteration);
+                }
+
+                if let Some(grads) = accumulation.accumulate(&model, grads) {
+                    let params = diagnostics
+                        .as_ref()
+                        .and_then(|diagnostics| diagnostics.params_before_update(&model));
+                    model = tracing::info_span!("optimizer_step", lr)
+                        .in_scope(|| model.optimize(&mut optim, lr, grads));
 
-                if accumulation <= accumulation_current {
-                    let grads = accumulator.grads();
-                    model = model.optimize(&mut optim, lr, grads);
-                    accumulation_current = 0;
+                    if let (Some(diagnostics), Some(params)) = (diagnostics.as_mut(), params) {
+                        diagnostics.observe_update(&model, params);
+                    }
                 }
 
-                let item = LearnerItem::new(
+                let mut item = LearnerItem::new(
                     item.item,
                     progress,
                     self.epoch,
                     self.epoch_total,
-                    iteration,
+                    optimizer_step,
                     Some(lr),
                 );
+                item.diagnostics = diagnostics.as_mut().and_then(DiagnosticsCollector::take);
 
                 processor.process_train(Event::ProcessedItem(item));
+                batch_end(&model, optimizer_step);
+
+                if let Some(watchdog) = &self.watchdog {
+                    watchdog.beat();
+                }
 
                 if interrupter.should_stop() {
                     log::info!("Training interrupted.");
@@ -238,10 +423,111 @@ impl<TI> TrainEpoch<TI> {
             if interrupted {
                 break;
             }
+
+            if accumulation.is_boundary() && self.should_checkpoint(iteration, last_checkpoint) {
+                let state = TrainEpochState::new(self.epoch, iteration, iterator.state())
+                    .with_rng(LC::Backend::rng_state());
+                checkpoint(&model, &optim, lr_scheduler, state);
+                last_checkpoint = iteration;
+            }
         }
 
+        if let Some(grads) = accumulation.remaining(&model) {
+            model = model.optimize(&mut optim, lr, grads);
+        }
         processor.process_train(Event::EndEpoch(self.epoch));
 
         (model, optim)
     }
 }
+
+/// Accumulates the gradients of the iterations of an epoch until an optimizer step is reached.
+struct Accumulation<M> {
+    accumulator: GradientsAccumulator<M>,
+    mode: Option<GradAccumulation>,
+    num_iterations: usize,
+    current: usize,
+}
+
+impl<M> Accumulation<M> {
+    fn new(mode: Option<GradAccumulation>, num_devices: usize) -> Self {
+        let num_iterations = match mode {
+            Some(GradAccumulation::Sum(num) | GradAccumulation::Mean(num)) => num,
+            None => 1,
+        };
+
+        Self {
+            accumulator: GradientsAccumulator::new(),
+            mode,
+            num_iterations: num_iterations * num_devices,
+            current: 0,
+        }
+    }
+
+    fn is_mean(&self) -> bool {
+        matches!(self.mode, Some(GradAccumulation::Mean(_)))
+    }
+
+    /// Whether the next iteration starts a new optimizer step, advancing the learning rate
+    /// scheduler and the reported iteration.
+    ///
+    /// When the gradients are summed, every iteration is considered a step.
+    fn is_step_start(&self) -> bool {
+        !self.is_mean() || self.is_boundary()
+    }
+
+    /// Whether no gradients are waiting to be applied by an optimizer step.
+    fn is_boundary(&self) -> bool {
+        self.current == 0
+    }
+
+    /// The number of optimizer steps started after the given number of iterations.
+    fn num_optimizer_steps(&self, iterations: usize) -> usize {
+        match self.is_mean() {
+            true => iterations.div_ceil(self.num_iterations),
+            false => iterations,
+        }
+    }
+
+    /// Accumulates the gradients, returning the gradients to apply when an optimizer step is
+    /// reached.
+    fn accumulate<B: AutodiffBackend>(
+        &mut self,
+        model: &M,
+        grads: GradientsParams,
+    ) -> Option<GradientsParams>
+    where
+        M: AutodiffModule<B>,
+    {
+        if self.mode.is_none() && self.num_iterations == 1 {
+            return Some(grads);
+        }
+
+        self.accumulator.accumulate(model, grads);
+        self.current += 1;
+
+        if self.current < self.num_iterations {
+            return None;
+        }
+
+        self.current = 0;
+        match self.is_mean() {
+            true => Some(self.accumulator.grads_mean(model)),
+            false => Some(self.accumulator.grads()),
+        }
+    }
+
+    /// Returns the mean of the gradients accumulated since the last optimizer step, if they should
+    /// be applied at the end of the epoch.
+    fn remaining<B: AutodiffBackend>(&mut self, model: &M) -> Option<GradientsParams>
+    where
+        M: AutodiffModule<B>,
+    {
+        if !self.is_mean() || self.is_boundary() {
+            return None;
+        }
+
+        self.current = 0;
+        Some(self.accumulator.grads_mean(model))
+    }
+}
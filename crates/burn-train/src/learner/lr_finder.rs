@@ -0,0 +1,257 @@
+use std::sync::Arc;
+
+use burn_core::data::dataloader::DataLoader;
+use burn_core::module::AutodiffModule;
+use burn_core::optim::Optimizer;
+use burn_core::tensor::backend::AutodiffBackend;
+use burn_core::{self as burn, config::Config};
+
+use crate::metric::{Adaptor, LossInput};
+use crate::{TrainStep, TrainingInterrupter};
+
+/// Configuration of the [learning rate range test](crate::LearnerBuilder::lr_find).
+///
+/// The learning rate grows exponentially from `start_lr` to `end_lr` over `num_iterations`
+/// training steps, and the test stops early when the loss diverges.
+#[derive(Config, Debug)]
+pub struct LrFinderConfig {
+    /// The learning rate of the first iteration.
+    #[config(default = 1e-7)]
+    pub start_lr: f64,
+    /// The learning rate of the last iteration.
+    #[config(default = 10.0)]
+    pub end_lr: f64,
+    /// The number of training iterations, the dataloader is iterated multiple times if needed.
+    #[config(default = 100)]
+    pub num_iterations: usize,
+    /// The factor of the exponential moving average smoothing the loss, zero disables smoothing.
+    #[config(default = 0.98)]
+    pub smoothing: f64,
+    /// The loss is considered diverging once the smoothed loss exceeds the lowest smoothed loss
+    /// by this factor.
+    #[config(default = 4.0)]
+    pub divergence_threshold: f64,
+}
+
+impl LrFinderConfig {
+    /// The learning rate of the given iteration.
+    fn lr(&self, iteration: usize) -> f64 {
+        if self.num_iterations <= 1 {
+            return self.start_lr;
+        }
+
+        let progress = iteration as f64 / (self.num_iterations - 1) as f64;
+        self.start_lr * (self.end_lr / self.start_lr).powf(progress)
+    }
+}
+
+/// A point of the [learning rate range test](crate::LearnerBuilder::lr_find).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LrFinderPoint {
+    /// The learning rate used for the iteration.
+    pub lr: f64,
+    /// The loss of the iteration.
+    pub loss: f64,
+    /// The smoothed loss up to the iteration.
+    pub smoothed_loss: f64,
+}
+
+/// The result of the [learning rate range test](crate::LearnerBuilder::lr_find).
+#[derive(Debug, Clone, Default)]
+pub struct LrFinderResult {
+    /// The recorded points, in increasing learning rate order.
+    pub points: Vec<LrFinderPoint>,
+    /// The learning rate at which the loss started to diverge, if it diverged.
+    pub divergence_lr: Option<f64>,
+}
+
+impl LrFinderResult {
+    /// The learning rates and smoothed losses of the test, to plot the loss against the learning
+    /// rate (usually on a logarithmic scale).
+    pub fn series(&self) -> (Vec<f64>, Vec<f64>) {
+        self.points
+            .iter()
+            .map(|point| (point.lr, point.smoothed_loss))
+            .unzip()
+    }
+
+    /// The learning rate reaching the lowest smoothed loss.
+    pub fn min_loss_lr(&self) -> Option<f64> {
+        self.points
+            .iter()
+            .min_by(|a, b| a.smoothed_loss.total_cmp(&b.smoothed_loss))
+            .map(|point| point.lr)
+    }
+
+    /// The suggested maximum learning rate, an order of magnitude below the learning rate
+    /// reaching the lowest loss, where the loss is still decreasing steadily.
+    pub fn suggested_lr(&self) -> Option<f64> {
+        self.min_loss_lr().map(|lr| lr / 10.0)
+    }
+}
+
+/// Records the losses of the range test and detects when they diverge.
+struct LrRecorder {
+    smoothing: f64,
+    divergence_threshold: f64,
+    average: f64,
+    best: f64,
+    result: LrFinderResult,
+}
+
+impl LrRecorder {
+    fn new(config: &LrFinderConfig) -> Self {
+        Self {
+            smoothing: config.smoothing,
+            divergence_threshold: config.divergence_threshold,
+            average: 0.0,
+            best: f64::INFINITY,
+            result: LrFinderResult::default(),
+        }
+    }
+
+    /// Records the loss of an iteration, returning `true` if the loss diverged.
+    fn record(&mut self, lr: f64, loss: f64) -> bool {
+        if !loss.is_finite() {
+            self.result.divergence_lr = Some(lr);
+            return true;
+        }
+
+        // Exponential moving average with bias correction, so the first values aren't biased
+        // towards zero.
+        let num_points = self.result.points.len() as i32 + 1;
+        self.average = self.smoothing * self.average + (1.0 - self.smoothing) * loss;
+        let smoothed_loss = match self.smoothing > 0.0 {
+            true => self.average / (1.0 - self.smoothing.powi(num_points)),
+            false => loss,
+        };
+
+        self.result.points.push(LrFinderPoint {
+            lr,
+            loss,
+            smoothed_loss,
+        });
+
+        if smoothed_loss > self.divergence_threshold * self.best {
+            self.result.divergence_lr = Some(lr);
+            return true;
+        }
+
+        self.best = f64::min(self.best, smoothed_loss);
+        false
+    }
+}
+
+/// Runs the learning rate range test.
+pub(crate) fn lr_find<B, M, O, InputTrain, OutputTrain>(
+    mut model: M,
+    mut optim: O,
+    dataloader: Arc<dyn DataLoader<InputTrain>>,
+    config: LrFinderConfig,
+    interrupter: &TrainingInterrupter,
+) -> LrFinderResult
+where
+    B: AutodiffBackend,
+    M: AutodiffModule<B> + TrainStep<InputTrain, OutputTrain>,
+    O: Optimizer<M, B>,
+    OutputTrain: Adaptor<LossInput<B>>,
+{
+    let mut recorder = LrRecorder::new(&config);
+    let mut iteration = 0;
+
+    'outer: while iteration < config.num_iterations {
+        let mut items = dataloader.iter().peekable();
+
+        if items.peek().is_none() {
+            log::warn!("The dataloader is empty, the learning rate range test can't run.");
+            break;
+        }
+
+        for item in items {
+            if iteration >= config.num_iterations || interrupter.should_stop() {
+                break 'outer;
+            }
+
+            let lr = config.lr(iteration);
+            let output = model.step(item);
+            let loss = output.item.adapt().mean();
+
+            if recorder.record(lr, loss) {
+                log::info!("The loss diverged at learning rate {lr:e}.");
+                break 'outer;
+            }
+
+            model = model.optimize(&mut optim, lr, output.grads);
+            iteration += 1;
+        }
+    }
+
+    recorder.result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lr_grows_exponentially_over_the_range() {
+        let config = LrFinderConfig::new()
+            .with_start_lr(1e-4)
+            .with_end_lr(1.0)
+            .with_num_iterations(5);
+
+        let lrs = (0..5).map(|i| config.lr(i)).collect::<Vec<_>>();
+
+        for (lr, expected) in lrs.iter().zip([1e-4, 1e-3, 1e-2, 1e-1, 1.0]) {
+            assert!(
+                (lr - expected).abs() < expected * 1e-9,
+                "{lr} != {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn should_detect_divergence_and_suggest_lr() {
+        let config = LrFinderConfig::new().with_smoothing(0.0);
+        let mut recorder = LrRecorder::new(&config);
+
+        let losses = [2.0, 1.5, 1.0, 0.5, 0.8, 1.5, 2.5];
+        let lrs = [1e-5, 1e-4, 1e-3, 1e-2, 1e-1, 1.0, 10.0];
+
+        let diverged = lrs
+            .iter()
+            .zip(losses)
+            .map(|(lr, loss)| recorder.record(*lr, loss))
+            .collect::<Vec<_>>();
+
+        assert_eq!(diverged, [false, false, false, false, false, false, true]);
+        assert_eq!(recorder.result.divergence_lr, Some(10.0));
+        assert_eq!(recorder.result.min_loss_lr(), Some(1e-2));
+        assert_eq!(recorder.result.suggested_lr(), Some(1e-3));
+    }
+
+    #[test]
+    fn should_stop_on_non_finite_loss() {
+        let mut recorder = LrRecorder::new(&LrFinderConfig::new());
+
+        assert!(!recorder.record(1e-3, 1.0));
+        assert!(recorder.record(1e-2, f64::NAN));
+        assert_eq!(recorder.result.points.len(), 1);
+        assert_eq!(recorder.result.divergence_lr, Some(1e-2));
+    }
+
+    #[test]
+    fn should_smooth_loss_without_bias() {
+        let config = LrFinderConfig::new().with_smoothing(0.9);
+        let mut recorder = LrRecorder::new(&config);
+
+        recorder.record(1e-3, 2.0);
+        recorder.record(1e-2, 2.0);
+
+        let (lrs, losses) = recorder.result.series();
+        assert_eq!(lrs, vec![1e-3, 1e-2]);
+        for loss in losses {
+            assert!((loss - 2.0).abs() < 1e-9);
+        }
+    }
+}
@@ -1,22 +1,35 @@
 mod application_logger;
 mod base;
+mod batch_size_finder;
 mod builder;
+mod callback;
 mod classification;
+mod diagnostics;
 mod early_stopping;
 mod epoch;
+mod lr_finder;
 mod regression;
+mod reproducibility;
 mod step;
 mod summary;
+mod tbptt;
 mod train_val;
+mod watchdog;
 
 pub use application_logger::*;
 pub use base::*;
+pub use batch_size_finder::*;
 pub use builder::*;
+pub use callback::*;
 pub use classification::*;
+pub use diagnostics::*;
 pub use early_stopping::*;
 pub use epoch::*;
+pub use lr_finder::*;
 pub use regression::*;
+pub use reproducibility::*;
 pub use step::*;
 pub use summary::*;
 pub use train::*;
 pub use train_val::*;
+pub use watchdog::*;
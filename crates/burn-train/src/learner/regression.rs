@@ -1,8 +1,11 @@
+use super::combine_ensemble_outputs;
 use crate::metric::processor::ItemLazy;
-use crate::metric::{Adaptor, LossInput};
+use crate::metric::{Adaptor, LossInput, MaeInput, R2Input, RmseInput};
+use burn_core::module::{EnsembleOutput, EnsembleReduction};
 use burn_core::tensor::backend::Backend;
-use burn_core::tensor::{Tensor, Transaction};
+use burn_core::tensor::{Tensor, TensorData, Transaction};
 use burn_ndarray::NdArray;
+use std::future::Future;
 
 /// Simple regression output adapted for multiple metrics.
 #[derive(new)]
@@ -17,27 +20,76 @@ pub struct RegressionOutput<B: Backend> {
     pub targets: Tensor<B, 2>,
 }
 
+impl<B: Backend> EnsembleOutput for RegressionOutput<B> {
+    fn combine(outputs: Vec<Self>, reduction: EnsembleReduction) -> Self {
+        let (loss, output, targets) = combine_ensemble_outputs(
+            outputs
+                .into_iter()
+                .map(|output| (output.loss, output.output, output.targets)),
+            reduction,
+        );
+
+        Self {
+            loss,
+            output,
+            targets,
+        }
+    }
+}
+
 impl<B: Backend> Adaptor<LossInput<B>> for RegressionOutput<B> {
     fn adapt(&self) -> LossInput<B> {
         LossInput::new(self.loss.clone())
     }
 }
 
+impl<B: Backend> Adaptor<MaeInput<B>> for RegressionOutput<B> {
+    fn adapt(&self) -> MaeInput<B> {
+        MaeInput::new(self.output.clone(), self.targets.clone())
+    }
+}
+
+impl<B: Backend> Adaptor<RmseInput<B>> for RegressionOutput<B> {
+    fn adapt(&self) -> RmseInput<B> {
+        RmseInput::new(self.output.clone(), self.targets.clone())
+    }
+}
+
+impl<B: Backend> Adaptor<R2Input<B>> for RegressionOutput<B> {
+    fn adapt(&self) -> R2Input<B> {
+        R2Input::new(self.output.clone(), self.targets.clone())
+    }
+}
+
 impl<B: Backend> ItemLazy for RegressionOutput<B> {
     type ItemSync = RegressionOutput<NdArray>;
 
     fn sync(self) -> Self::ItemSync {
-        let [output, loss, targets] = Transaction::default()
+        RegressionOutput::from_data(self.transaction().execute())
+    }
+
+    fn sync_async(self) -> impl Future<Output = Self::ItemSync> + Send + 'static {
+        let data = self.transaction().execute_async();
+
+        async move { RegressionOutput::from_data(data.await) }
+    }
+}
+
+impl<B: Backend> RegressionOutput<B> {
+    fn transaction(self) -> Transaction<B> {
+        Transaction::default()
             .register(self.output)
             .register(self.loss)
             .register(self.targets)
-            .execute()
-            .try_into()
-            .expect("Correct amount of tensor data");
+    }
+}
 
+impl RegressionOutput<NdArray> {
+    fn from_data(data: Vec<TensorData>) -> Self {
+        let [output, loss, targets] = data.try_into().expect("Correct amount of tensor data");
         let device = &Default::default();
 
-        RegressionOutput {
+        Self {
             output: Tensor::from_data(output, device),
             loss: Tensor::from_data(loss, device),
             targets: Tensor::from_data(targets, device),
@@ -0,0 +1,272 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::process::Command;
+
+use burn_core::config::Config;
+use burn_core::tensor::backend::Backend;
+use serde::{Deserialize, Serialize};
+
+/// Everything needed to reproduce a training run, saved in the artifact directory when the
+/// training starts and verified when it is resumed.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// B::seed(config.seed);
+///
+/// let bundle = ReproducibilityBundle::capture::<B>(&devices)
+///     .with_seed(config.seed)
+///     .with_config(&config)
+///     .with_crate_version(env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"))
+///     .with_env_var("CUDA_VISIBLE_DEVICES");
+///
+/// let learner = LearnerBuilder::new(artifact_dir)
+///     .reproducibility_bundle(bundle)
+///     .build(model, optim, lr);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReproducibilityBundle {
+    /// The version of burn.
+    pub burn_version: String,
+    /// The versions of the crates of the training program, by name.
+    pub crate_versions: BTreeMap<String, String>,
+    /// The name of the backend.
+    pub backend: String,
+    /// The devices used for the training.
+    pub devices: Vec<String>,
+    /// The operating system and the architecture.
+    pub platform: String,
+    /// The seed of the random number generators.
+    pub seed: Option<u64>,
+    /// The full configuration of the training.
+    pub config: Option<serde_json::Value>,
+    /// The git commit of the code, when the training runs from a git repository.
+    pub git_commit: Option<String>,
+    /// If the tracked files of the git repository had uncommitted changes, which aren't captured
+    /// by the commit.
+    pub git_dirty: bool,
+    /// The environment variables, by name.
+    pub env: BTreeMap<String, String>,
+}
+
+impl ReproducibilityBundle {
+    /// Captures the versions, the backend, the devices and the git commit of the current run.
+    ///
+    /// The seed, the configuration, the versions of the other crates and the environment
+    /// variables must be added to the bundle.
+    pub fn capture<B: Backend>(devices: &[B::Device]) -> Self {
+        let git_commit = git(&["rev-parse", "HEAD"]);
+        let git_dirty = git_commit.is_some()
+            && git(&["status", "--porcelain", "--untracked-files=no"])
+                .is_some_and(|status| !status.is_empty());
+
+        Self {
+            burn_version: env!("CARGO_PKG_VERSION").to_string(),
+            crate_versions: BTreeMap::new(),
+            backend: B::name(),
+            devices: devices.iter().map(|device| format!("{device:?}")).collect(),
+            platform: format!("{}-{}", std::env::consts::OS, std::env::consts::ARCH),
+            seed: None,
+            config: None,
+            git_commit,
+            git_dirty,
+            env: BTreeMap::new(),
+        }
+    }
+
+    /// Sets the seed of the random number generators.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Sets the full configuration of the training.
+    pub fn with_config<C: Config>(mut self, config: &C) -> Self {
+        self.config = Some(serde_json::to_value(config).expect("Can serialize the config"));
+        self
+    }
+
+    /// Adds the version of a crate of the training program.
+    pub fn with_crate_version(
+        mut self,
+        name: impl Into<String>,
+        version: impl Into<String>,
+    ) -> Self {
+        self.crate_versions.insert(name.into(), version.into());
+        self
+    }
+
+    /// Adds the value of an environment variable, when it is set.
+    pub fn with_env_var(mut self, name: impl Into<String>) -> Self {
+        let name = name.into();
+
+        if let Ok(value) = std::env::var(&name) {
+            self.env.insert(name, value);
+        }
+
+        self
+    }
+
+    /// Saves the bundle to a json file.
+    pub fn save(&self, file: impl AsRef<Path>) -> std::io::Result<()> {
+        if let Some(directory) = file.as_ref().parent() {
+            std::fs::create_dir_all(directory)?;
+        }
+
+        let content = serde_json::to_string_pretty(self).map_err(std::io::Error::other)?;
+        std::fs::write(file, content)
+    }
+
+    /// Loads a bundle from a json file.
+    pub fn load(file: impl AsRef<Path>) -> std::io::Result<Self> {
+        let content = std::fs::read_to_string(file)?;
+
+        serde_json::from_str(&content).map_err(std::io::Error::other)
+    }
+
+    /// Describes each component of the bundle differing from the bundle of the original run.
+    pub fn differences(&self, original: &Self) -> Vec<String> {
+        let mut differences = Vec::new();
+        let mut compare = |name: &str, current: String, original: String| {
+            if current != original {
+                differences.push(format!("{name}: {original} -> {current}"));
+            }
+        };
+
+        compare(
+            "burn version",
+            self.burn_version.clone(),
+            original.burn_version.clone(),
+        );
+        compare(
+            "crate versions",
+            format!("{:?}", self.crate_versions),
+            format!("{:?}", original.crate_versions),
+        );
+        compare("backend", self.backend.clone(), original.backend.clone());
+        compare(
+            "devices",
+            format!("{:?}", self.devices),
+            format!("{:?}", original.devices),
+        );
+        compare("platform", self.platform.clone(), original.platform.clone());
+        compare(
+            "seed",
+            format!("{:?}", self.seed),
+            format!("{:?}", original.seed),
+        );
+        compare(
+            "config",
+            format_optional(&self.config),
+            format_optional(&original.config),
+        );
+        compare(
+            "git commit",
+            format_optional(&self.git_commit),
+            format_optional(&original.git_commit),
+        );
+        compare(
+            "git uncommitted changes",
+            self.git_dirty.to_string(),
+            original.git_dirty.to_string(),
+        );
+        compare(
+            "environment variables",
+            format!("{:?}", self.env),
+            format!("{:?}", original.env),
+        );
+
+        differences
+    }
+
+    /// Saves the bundle of a new run, or verifies it against the bundle of the original run when
+    /// the training is resumed, warning about each differing component.
+    pub(crate) fn save_or_verify(&self, file: &Path, resume: bool) {
+        let original = match resume {
+            true => Self::load(file).ok(),
+            false => None,
+        };
+
+        let Some(original) = original else {
+            if resume {
+                log::warn!(
+                    "No reproducibility bundle was found for the original run, saving the bundle of the resumed run."
+                );
+            }
+            if let Err(err) = self.save(file) {
+                log::warn!("Failed to save the reproducibility bundle: {err}");
+            }
+            return;
+        };
+
+        let differences = self.differences(&original);
+
+        if !differences.is_empty() {
+            log::warn!(
+                "The resumed run differs from the original run, the training might not be reproducible:\n{}",
+                differences.join("\n")
+            );
+        }
+    }
+}
+
+fn format_optional<T: core::fmt::Display>(value: &Option<T>) -> String {
+    match value {
+        Some(value) => value.to_string(),
+        None => "none".to_string(),
+    }
+}
+
+/// The trimmed output of a git command, or `None` if it failed.
+fn git(args: &[&str]) -> Option<String> {
+    let output = Command::new("git").args(args).output().ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn_core as burn;
+    use burn_ndarray::NdArray;
+
+    #[derive(Config, Debug)]
+    struct TrainingConfig {
+        learning_rate: f64,
+    }
+
+    fn bundle(seed: u64, learning_rate: f64) -> ReproducibilityBundle {
+        ReproducibilityBundle::capture::<NdArray>(&[Default::default()])
+            .with_seed(seed)
+            .with_config(&TrainingConfig::new(learning_rate))
+    }
+
+    #[test]
+    fn test_same_run_has_no_differences() {
+        assert!(bundle(42, 1e-3).differences(&bundle(42, 1e-3)).is_empty());
+    }
+
+    #[test]
+    fn test_differences_list_each_component() {
+        let differences = bundle(7, 1e-2).differences(&bundle(42, 1e-3));
+
+        assert_eq!(differences.len(), 2);
+        assert_eq!(differences[0], "seed: Some(42) -> Some(7)");
+        assert!(differences[1].starts_with("config: "));
+    }
+
+    #[test]
+    fn test_save_load_roundtrip() {
+        let directory = tempfile::tempdir().unwrap();
+        let file = directory.path().join("artifacts").join("bundle.json");
+        let bundle = bundle(42, 1e-3).with_crate_version("training", "0.1.0");
+
+        bundle.save(&file).unwrap();
+
+        assert_eq!(ReproducibilityBundle::load(&file).unwrap(), bundle);
+    }
+}
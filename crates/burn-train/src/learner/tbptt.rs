@@ -0,0 +1,33 @@
+use burn_core::nn::{RecurrentState, TruncatedBptt};
+
+use crate::learner::{TrainCallback, TrainCallbackContext};
+
+/// Streaming sequence training with [truncated backpropagation through time](TruncatedBptt).
+///
+/// The model keeps a clone of the handle to carry its recurrent state from one training batch to
+/// the next, while the learner drops the carried state before the first epoch and at the end of
+/// each epoch, since the stream restarts from its beginning with the next epoch.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// // The model has a `tbptt: Ignored<TruncatedBptt<LstmState<B, 2>>>` field used in its step.
+/// let tbptt = TruncatedBptt::new(32);
+/// let model = ModelConfig::new().init(tbptt.clone(), &device);
+///
+/// let learner = LearnerBuilder::new(ARTIFACT_DIR)
+///     .callback(tbptt)
+///     .build(model, optim, lr);
+/// ```
+impl<M, S> TrainCallback<M> for TruncatedBptt<S>
+where
+    S: RecurrentState,
+{
+    fn on_train_begin(&mut self, _context: &TrainCallbackContext<'_, M>) {
+        self.reset();
+    }
+
+    fn on_valid_end(&mut self, _context: &TrainCallbackContext<'_, M>) {
+        self.reset();
+    }
+}
@@ -1,10 +1,17 @@
 use crate::components::LearnerComponents;
 use crate::metric::processor::EventProcessor;
-use crate::{Learner, TrainEpoch, ValidEpoch};
+use crate::metric::store::{Aggregate, Split};
+use crate::metric::{LossMetric, Metric};
+use crate::{
+    DeviceError, DeviceWatchdog, Learner, TrainCallbackContext, TrainEpoch, TrainEpochState,
+    ValidEpoch,
+};
 use burn_core::data::dataloader::DataLoader;
-use burn_core::module::{AutodiffModule, Module};
+use burn_core::lr_scheduler::LrScheduler;
+use burn_core::module::{AutodiffModule, Ensemble, EnsembleOutput, EnsembleReduction, Module};
 use burn_core::optim::{GradientsParams, Optimizer};
-use burn_core::tensor::backend::AutodiffBackend;
+use burn_core::tensor::backend::{AutodiffBackend, Backend};
+use burn_core::tensor::Tensor;
 use std::sync::Arc;
 
 /// A training output.
@@ -97,6 +104,43 @@ pub trait ValidStep<VI, VO> {
     fn step(&self, item: VI) -> VO;
 }
 
+impl<M, VI, VO> ValidStep<VI, VO> for Ensemble<M>
+where
+    M: ValidStep<VI, VO> + Clone + Send,
+    VI: Clone + Send,
+    VO: EnsembleOutput + Send,
+{
+    fn step(&self, item: VI) -> VO {
+        self.forward_parallel(item, |model, item| model.step(item))
+    }
+}
+
+/// Combines the outputs of the models of an [ensemble](Ensemble) made of a loss, predictions and
+/// targets.
+///
+/// The losses are averaged, the predictions are combined with the reduction of the ensemble and
+/// the targets, which are the same for all the models, are taken from the first model.
+pub(crate) fn combine_ensemble_outputs<B: Backend, const D: usize, T>(
+    outputs: impl Iterator<Item = (Tensor<B, 1>, Tensor<B, D>, T)>,
+    reduction: EnsembleReduction,
+) -> (Tensor<B, 1>, Tensor<B, D>, T) {
+    let mut losses = Vec::new();
+    let mut predictions = Vec::new();
+    let mut targets = None;
+
+    for (loss, prediction, target) in outputs {
+        losses.push(loss);
+        predictions.push(prediction);
+        targets.get_or_insert(target);
+    }
+
+    (
+        Tensor::combine(losses, EnsembleReduction::Mean),
+        Tensor::combine(predictions, reduction),
+        targets.expect("An ensemble has at least one model"),
+    )
+}
+
 impl<LC: LearnerComponents> Learner<LC> {
     /// Fits the model.
     ///
@@ -107,15 +151,121 @@ impl<LC: LearnerComponents> Learner<LC> {
     ///
     /// # Returns
     ///
-    /// The fitted model.
+    /// The fitted model, which is the model of the best epoch when
+    /// [restore_best_model](crate::LearnerBuilder::restore_best_model) is used.
+    ///
+    /// # Panics
+    ///
+    /// When the [device watchdog](crate::LearnerBuilder::with_device_watchdog) detects an error,
+    /// use [try_fit](Self::try_fit) to handle it.
     pub fn fit<InputTrain, InputValid, OutputTrain, OutputValid>(
-        mut self,
+        self,
+        dataloader_train: Arc<dyn DataLoader<InputTrain>>,
+        dataloader_valid: Arc<dyn DataLoader<InputValid>>,
+    ) -> LC::Model
+    where
+        InputTrain: Send + 'static,
+        InputValid: Send + 'static,
+        OutputTrain: Send + 'static,
+        OutputValid: Send,
+        LC::Model: TrainStep<InputTrain, OutputTrain>,
+        <LC::Model as AutodiffModule<LC::Backend>>::InnerModule: ValidStep<InputValid, OutputValid>,
+        LC::EventProcessor: EventProcessor<ItemTrain = OutputTrain, ItemValid = OutputValid>,
+    {
+        self.try_fit(dataloader_train, dataloader_valid)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Fits the model like [fit](Self::fit), returning the error detected by the
+    /// [device watchdog](crate::LearnerBuilder::with_device_watchdog) if any.
+    pub fn try_fit<InputTrain, InputValid, OutputTrain, OutputValid>(
+        self,
+        dataloader_train: Arc<dyn DataLoader<InputTrain>>,
+        dataloader_valid: Arc<dyn DataLoader<InputValid>>,
+    ) -> Result<LC::Model, DeviceError>
+    where
+        InputTrain: Send + 'static,
+        InputValid: Send + 'static,
+        OutputTrain: Send + 'static,
+        OutputValid: Send,
+        LC::Model: TrainStep<InputTrain, OutputTrain>,
+        <LC::Model as AutodiffModule<LC::Backend>>::InnerModule: ValidStep<InputValid, OutputValid>,
+        LC::EventProcessor: EventProcessor<ItemTrain = OutputTrain, ItemValid = OutputValid>,
+    {
+        self.train(dataloader_train, dataloader_valid, false)
+    }
+
+    /// Resumes the training from the last checkpoint saved during an epoch, continuing from the
+    /// exact iteration it was saved.
+    ///
+    /// Checkpoints are saved during an epoch when
+    /// [checkpoint_interval](crate::LearnerBuilder::checkpoint_interval) is used. When no such
+    /// checkpoint exists, the training starts like [fit](Self::fit).
+    ///
+    /// # Notes
+    ///
+    /// The dataloaders must be created the same way as the interrupted training, including the
+    /// shuffling seed, so the remaining items of the epoch are the same.
+    ///
+    /// # Arguments
+    ///
+    /// * `dataloader_train` - The training dataloader.
+    /// * `dataloader_valid` - The validation dataloader.
+    ///
+    /// # Returns
+    ///
+    /// The fitted model.
+    ///
+    /// # Panics
+    ///
+    /// When the [device watchdog](crate::LearnerBuilder::with_device_watchdog) detects an error,
+    /// use [try_resume](Self::try_resume) to handle it.
+    pub fn resume<InputTrain, InputValid, OutputTrain, OutputValid>(
+        self,
         dataloader_train: Arc<dyn DataLoader<InputTrain>>,
         dataloader_valid: Arc<dyn DataLoader<InputValid>>,
     ) -> LC::Model
     where
         InputTrain: Send + 'static,
-        InputValid: Send,
+        InputValid: Send + 'static,
+        OutputTrain: Send + 'static,
+        OutputValid: Send,
+        LC::Model: TrainStep<InputTrain, OutputTrain>,
+        <LC::Model as AutodiffModule<LC::Backend>>::InnerModule: ValidStep<InputValid, OutputValid>,
+        LC::EventProcessor: EventProcessor<ItemTrain = OutputTrain, ItemValid = OutputValid>,
+    {
+        self.try_resume(dataloader_train, dataloader_valid)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Resumes the training like [resume](Self::resume), returning the error detected by the
+    /// [device watchdog](crate::LearnerBuilder::with_device_watchdog) if any.
+    pub fn try_resume<InputTrain, InputValid, OutputTrain, OutputValid>(
+        self,
+        dataloader_train: Arc<dyn DataLoader<InputTrain>>,
+        dataloader_valid: Arc<dyn DataLoader<InputValid>>,
+    ) -> Result<LC::Model, DeviceError>
+    where
+        InputTrain: Send + 'static,
+        InputValid: Send + 'static,
+        OutputTrain: Send + 'static,
+        OutputValid: Send,
+        LC::Model: TrainStep<InputTrain, OutputTrain>,
+        <LC::Model as AutodiffModule<LC::Backend>>::InnerModule: ValidStep<InputValid, OutputValid>,
+        LC::EventProcessor: EventProcessor<ItemTrain = OutputTrain, ItemValid = OutputValid>,
+    {
+        self.train(dataloader_train, dataloader_valid, true)
+    }
+
+    fn train<InputTrain, InputValid, OutputTrain, OutputValid>(
+        mut self,
+        dataloader_train: Arc<dyn DataLoader<InputTrain>>,
+        dataloader_valid: Arc<dyn DataLoader<InputValid>>,
+        resume: bool,
+    ) -> Result<LC::Model, DeviceError>
+    where
+        InputTrain: Send + 'static,
+        InputValid: Send + 'static,
         OutputTrain: Send + 'static,
         OutputValid: Send,
         LC::Model: TrainStep<InputTrain, OutputTrain>,
@@ -128,8 +278,32 @@ impl<LC: LearnerComponents> Learner<LC> {
             self.model = self.model.fork(device);
         }
 
-        let starting_epoch = match self.checkpoint {
-            Some(checkpoint) => {
+        let mut state = match (resume, &mut self.checkpointer) {
+            (true, Some(checkpointer)) => checkpointer.load_state(),
+            _ => None,
+        };
+        if resume && state.is_none() {
+            log::warn!("No checkpoint saved during an epoch was found, starting the training.");
+        }
+
+        if let Some((bundle, file)) = &self.reproducibility {
+            bundle.save_or_verify(file, state.is_some() || self.checkpoint.is_some());
+        }
+
+        let starting_epoch = match (&state, self.checkpoint) {
+            (Some(state), _) => {
+                if let Some(checkpointer) = &mut self.checkpointer {
+                    (self.model, self.optim, self.lr_scheduler) = checkpointer.load_checkpoint(
+                        self.model,
+                        self.optim,
+                        self.lr_scheduler,
+                        &Default::default(), // Load the checkpoint on the default device.
+                        state.epoch,
+                    );
+                }
+                state.epoch
+            }
+            (None, Some(checkpoint)) => {
                 if let Some(checkpointer) = &mut self.checkpointer {
                     (self.model, self.optim, self.lr_scheduler) = checkpointer.load_checkpoint(
                         self.model,
@@ -141,55 +315,216 @@ impl<LC: LearnerComponents> Learner<LC> {
                 }
                 checkpoint + 1
             }
-            None => 1,
+            (None, None) => 1,
         };
 
-        for epoch in starting_epoch..self.num_epochs + 1 {
+        let (dataloader_train, dataloader_valid) = match self.overfit_subset {
+            Some(num_items) => {
+                log::warn!(
+                    "Debug mode: training and validating on the first {num_items} items of each \
+                     dataloader only."
+                );
+                (
+                    overfit_subset(dataloader_train, num_items),
+                    overfit_subset(dataloader_valid, num_items),
+                )
+            }
+            None => (dataloader_train, dataloader_valid),
+        };
+        let mut overfit_losses = Vec::new();
+
+        if let Some(recovery) = &mut self.recovery {
+            recovery.init(&self.model, &self.optim);
+        }
+
+        let monitor = self
+            .watchdog
+            .as_ref()
+            .map(|watchdog| watchdog.start(&self.interrupter));
+
+        let context = TrainCallbackContext {
+            model: &self.model,
+            epoch: starting_epoch,
+            iteration: 0,
+            store: &self.event_store,
+            interrupter: &self.interrupter,
+        };
+        for callback in self.callbacks.iter_mut() {
+            callback.on_train_begin(&context);
+        }
+
+        let mut epoch = starting_epoch;
+        while epoch <= self.num_epochs {
             let epoch_train = TrainEpoch::new(
                 dataloader_train.clone(),
                 epoch,
                 self.num_epochs,
                 self.grad_accumulation,
-            );
+            )
+            .with_watchdog(self.watchdog.clone())
+            .with_state(state.take())
+            .with_checkpoint_interval(self.checkpoint_interval)
+            .with_diagnostics(self.diagnostics.clone());
 
-            if self.devices.len() > 1 {
-                (self.model, self.optim) = epoch_train.run_multi_device::<LC, OutputTrain>(
-                    self.model,
-                    self.optim,
-                    &mut self.lr_scheduler,
+            let checkpointer = &mut self.checkpointer;
+            let mut checkpoint = |model: &LC::Model,
+                                  optim: &LC::Optimizer,
+                                  scheduler: &LC::LrScheduler,
+                                  state: TrainEpochState| {
+                if let Some(checkpointer) = checkpointer.as_mut() {
+                    checkpointer.checkpoint_iteration(model, optim, scheduler, state);
+                }
+            };
+
+            let callbacks = &mut self.callbacks;
+            let store = &self.event_store;
+            let interrupter = &self.interrupter;
+            let mut iteration_last = 0;
+            let mut batch_end = |model: &LC::Model, iteration: usize| {
+                iteration_last = iteration;
+                let context = TrainCallbackContext {
+                    model,
+                    epoch,
+                    iteration,
+                    store,
+                    interrupter,
+                };
+                for callback in callbacks.iter_mut() {
+                    callback.on_batch_end(&context);
+                }
+            };
+
+            let trained = DeviceWatchdog::guard(self.watchdog.as_ref(), || {
+                if self.devices.len() > 1 {
+                    epoch_train.run_multi_device::<LC, OutputTrain>(
+                        self.model,
+                        self.optim,
+                        &mut self.lr_scheduler,
+                        &mut self.event_processor,
+                        self.devices.clone(),
+                        &self.interrupter,
+                        &mut checkpoint,
+                        &mut batch_end,
+                    )
+                } else {
+                    epoch_train.run::<LC, OutputTrain>(
+                        self.model,
+                        self.optim,
+                        &mut self.lr_scheduler,
+                        &mut self.event_processor,
+                        &self.interrupter,
+                        &mut checkpoint,
+                        &mut batch_end,
+                    )
+                }
+            })
+            .and_then(|trained| detected_error(self.watchdog.as_ref()).map_or(Ok(trained), Err));
+
+            (self.model, self.optim) = match trained {
+                Ok(trained) => trained,
+                Err(error) => {
+                    let Some(recovery) = &mut self.recovery else {
+                        return Err(error);
+                    };
+                    let device = self.devices.first().cloned().unwrap_or_default();
+                    (self.model, self.optim, self.lr_scheduler, epoch) = recovery.recover(
+                        error,
+                        self.checkpointer.as_ref(),
+                        self.lr_scheduler,
+                        &device,
+                    )?;
+                    self.restart();
+                    continue;
+                }
+            };
+
+            if self.interrupter.should_stop() {
+                break;
+            }
+
+            let epoch_valid = ValidEpoch::new(dataloader_valid.clone(), epoch, self.num_epochs)
+                .with_watchdog(self.watchdog.clone());
+            let validated = DeviceWatchdog::guard(self.watchdog.as_ref(), || {
+                epoch_valid.run::<LC, OutputValid>(
+                    &self.model,
                     &mut self.event_processor,
-                    self.devices.clone(),
                     &self.interrupter,
                 )
-            } else {
-                (self.model, self.optim) = epoch_train.run::<LC, OutputTrain>(
-                    self.model,
-                    self.optim,
-                    &mut self.lr_scheduler,
-                    &mut self.event_processor,
-                    &self.interrupter,
-                );
+            })
+            .and_then(|_| detected_error(self.watchdog.as_ref()).map_or(Ok(()), Err));
+
+            if let Err(error) = validated {
+                let Some(recovery) = &mut self.recovery else {
+                    return Err(error);
+                };
+                let device = self.devices.first().cloned().unwrap_or_default();
+                (self.model, self.optim, self.lr_scheduler, epoch) = recovery.recover(
+                    error,
+                    self.checkpointer.as_ref(),
+                    self.lr_scheduler,
+                    &device,
+                )?;
+                self.restart();
+                continue;
             }
 
-            if self.interrupter.should_stop() {
-                break;
+            let context = TrainCallbackContext {
+                model: &self.model,
+                epoch,
+                iteration: iteration_last,
+                store: &self.event_store,
+                interrupter: &self.interrupter,
+            };
+            for callback in self.callbacks.iter_mut() {
+                callback.on_valid_end(&context);
             }
 
-            let epoch_valid = ValidEpoch::new(dataloader_valid.clone(), epoch, self.num_epochs);
-            epoch_valid.run::<LC, OutputValid>(
-                &self.model,
-                &mut self.event_processor,
-                &self.interrupter,
-            );
+            if let Some(name) = self.lr_scheduler.metric_name().map(String::from) {
+                match self
+                    .event_store
+                    .find_metric(&name, epoch, Aggregate::Mean, Split::Valid)
+                {
+                    Some(value) => self.lr_scheduler.on_metric(value),
+                    None => log::warn!("Can't find metric {name} for the learning rate scheduler."),
+                }
+            }
+
+            if self.overfit_subset.is_some() {
+                let name = <LossMetric<LC::Backend> as Metric>::NAME;
+                if let Some(loss) =
+                    self.event_store
+                        .find_metric(name, epoch, Aggregate::Mean, Split::Train)
+                {
+                    log::info!("Debug mode: epoch {epoch} training loss {loss}");
+                    overfit_losses.push(loss);
+                }
+            }
 
             if let Some(checkpointer) = &mut self.checkpointer {
-                checkpointer.checkpoint(
+                let saved = checkpointer.checkpoint(
                     &self.model,
                     &self.optim,
                     &self.lr_scheduler,
                     epoch,
                     &self.event_store,
                 );
+
+                if let (true, Some(watchdog)) = (saved, &self.watchdog) {
+                    watchdog.checkpointed(epoch);
+                }
+
+                if saved {
+                    let context = TrainCallbackContext {
+                        model: &self.model,
+                        epoch,
+                        iteration: iteration_last,
+                        store: &self.event_store,
+                        interrupter: &self.interrupter,
+                    };
+                    for callback in self.callbacks.iter_mut() {
+                        callback.on_checkpoint(&context);
+                    }
+                }
             }
 
             if let Some(early_stopping) = &mut self.early_stopping {
@@ -197,6 +532,48 @@ impl<LC: LearnerComponents> Learner<LC> {
                     break;
                 }
             }
+
+            if self.interrupter.should_stop() {
+                break;
+            }
+
+            epoch += 1;
+        }
+
+        core::mem::drop(monitor);
+        if let Some(error) = self.watchdog.as_ref().and_then(DeviceWatchdog::error) {
+            return Err(error);
+        }
+
+        if let (Some(best), Some(checkpointer)) = (&self.best_model, &self.checkpointer) {
+            let epoch =
+                self.event_store
+                    .find_epoch(&best.name, best.aggregate, best.direction, best.split);
+
+            if let Some(epoch) = epoch {
+                let device = self.devices.first().cloned().unwrap_or_default();
+
+                match checkpointer.load_model(self.model.clone(), &device, epoch) {
+                    Ok(model) => {
+                        log::info!("Restored the model of the best epoch {epoch}");
+                        self.model = model;
+                    }
+                    Err(err) => log::warn!(
+                        "Can't restore the model of the best epoch {epoch}, returning the model \
+                         of the last epoch: {err:?}"
+                    ),
+                }
+            }
+        }
+
+        if let (Some(first), Some(last)) = (overfit_losses.first(), overfit_losses.last()) {
+            // A model able to learn should at least halve its loss on a few items.
+            if overfit_losses.len() > 1 && *last > first * 0.5 {
+                log::warn!(
+                    "Debug mode: the training loss went from {first} to {last}, the model might \
+                     not be able to learn."
+                );
+            }
         }
 
         // Display learner summary
@@ -212,6 +589,202 @@ impl<LC: LearnerComponents> Learner<LC> {
             }
         }
 
-        self.model
+        Ok(self.model)
+    }
+
+    /// Continue the training after recovering from a device error.
+    fn restart(&mut self) {
+        if let Some(watchdog) = &self.watchdog {
+            watchdog.reset();
+        }
+        self.interrupter.reset();
+    }
+}
+
+/// The error detected by the watchdog, if any.
+fn detected_error(watchdog: Option<&DeviceWatchdog>) -> Option<DeviceError> {
+    watchdog.and_then(DeviceWatchdog::error)
+}
+
+/// The first `num_items` items of the dataloader, or all of its items when it can't be
+/// [sliced](DataLoader::slice).
+fn overfit_subset<I: 'static>(
+    dataloader: Arc<dyn DataLoader<I>>,
+    num_items: usize,
+) -> Arc<dyn DataLoader<I>> {
+    match dataloader.slice(0, num_items) {
+        Some(dataloader) => Arc::new(dataloader),
+        None => {
+            log::warn!(
+                "The dataloader can't be sliced, the overfit subset uses all of its {} items.",
+                dataloader.num_items()
+            );
+            dataloader
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::learner::{dataloader, learner_builder, model, optimizer, TestModel};
+    use crate::{DeviceRecovery, DeviceWatchdog, TrainCallback, TrainCallbackContext};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    /// Records the iterations of the training, and stops it at the given iteration.
+    #[derive(Clone, Default)]
+    struct Iterations {
+        iterations: Arc<Mutex<Vec<(usize, usize)>>>,
+        stop_at: Option<usize>,
+    }
+
+    impl Iterations {
+        fn take(&self) -> Vec<(usize, usize)> {
+            core::mem::take(&mut self.iterations.lock().unwrap())
+        }
+    }
+
+    impl TrainCallback<TestModel> for Iterations {
+        fn on_batch_end(&mut self, context: &TrainCallbackContext<'_, TestModel>) {
+            self.iterations
+                .lock()
+                .unwrap()
+                .push((context.epoch, context.iteration));
+
+            if self.stop_at == Some(context.iteration) {
+                context.stop();
+            }
+        }
+    }
+
+    #[test]
+    fn should_resume_an_interrupted_epoch_once() {
+        let directory = tempfile::tempdir().unwrap();
+        let state_file = directory.path().join("checkpoint").join("train-state.json");
+        // 10 items in batches of 2, so 5 iterations per epoch.
+        let fit = |resume: bool, iterations: Iterations| {
+            let learner = learner_builder(directory.path())
+                .checkpoint_interval(2)
+                .callback(iterations)
+                .build(model(), optimizer(), 0.01);
+
+            if resume {
+                learner.resume(dataloader(10), dataloader(10));
+            } else {
+                learner.fit(dataloader(10), dataloader(10));
+            }
+        };
+
+        // Interrupted after the checkpoint of iteration 2.
+        let iterations = Iterations {
+            stop_at: Some(3),
+            ..Default::default()
+        };
+        fit(false, iterations.clone());
+        assert_eq!(iterations.take(), [(1, 1), (1, 2), (1, 3)]);
+        assert!(state_file.exists());
+
+        let iterations = Iterations::default();
+        fit(true, iterations.clone());
+        assert_eq!(iterations.take(), [(1, 3), (1, 4), (1, 5)]);
+        // The epoch is completed, so its state is removed.
+        assert!(!state_file.exists());
+
+        // Nothing is left to resume, so the training starts from the beginning instead of
+        // replaying the end of the epoch.
+        let iterations = Iterations::default();
+        fit(true, iterations.clone());
+        assert_eq!(iterations.take(), [(1, 1), (1, 2), (1, 3), (1, 4), (1, 5)]);
+    }
+
+    /// Simulates a lost device by panicking at the given iteration of the second epoch, the
+    /// given number of times.
+    struct DeviceLost {
+        iteration: usize,
+        remaining: Arc<AtomicUsize>,
+    }
+
+    impl TrainCallback<TestModel> for DeviceLost {
+        fn on_batch_end(&mut self, context: &TrainCallbackContext<'_, TestModel>) {
+            if context.epoch == 2
+                && context.iteration == self.iteration
+                && self.remaining.load(Ordering::Relaxed) > 0
+            {
+                self.remaining.fetch_sub(1, Ordering::Relaxed);
+                panic!("Parent device is lost");
+            }
+        }
+    }
+
+    fn fit_with_device_lost(num_lost: usize, max_retries: usize) -> (Vec<(usize, usize)>, usize) {
+        let directory = tempfile::tempdir().unwrap();
+        let iterations = Iterations::default();
+        let resets = Arc::new(AtomicUsize::new(0));
+        let recovery = DeviceRecovery::new(max_retries, {
+            let resets = resets.clone();
+            move |_error| {
+                resets.fetch_add(1, Ordering::Relaxed);
+                Ok(())
+            }
+        });
+
+        // 6 items in batches of 2, so 3 iterations per epoch.
+        let result = learner_builder(directory.path())
+            .num_epochs(2)
+            .callback(iterations.clone())
+            .callback(DeviceLost {
+                iteration: 2,
+                remaining: Arc::new(AtomicUsize::new(num_lost)),
+            })
+            .with_device_watchdog(DeviceWatchdog::new(Duration::from_secs(60)))
+            .with_device_recovery(recovery)
+            .build(model(), optimizer(), 0.01)
+            .try_fit(dataloader(6), dataloader(6));
+
+        assert_eq!(result.is_ok(), num_lost <= max_retries);
+        (iterations.take(), resets.load(Ordering::Relaxed))
+    }
+
+    #[test]
+    fn should_resume_from_the_last_checkpoint_when_the_device_is_lost() {
+        let (iterations, resets) = fit_with_device_lost(1, 2);
+
+        assert_eq!(resets, 1);
+        assert_eq!(
+            iterations,
+            [
+                (1, 1),
+                (1, 2),
+                (1, 3),
+                (2, 1),
+                (2, 2),
+                (2, 1),
+                (2, 2),
+                (2, 3)
+            ]
+        );
+    }
+
+    #[test]
+    fn should_return_the_device_error_after_the_maximum_number_of_retries() {
+        let (iterations, resets) = fit_with_device_lost(3, 2);
+
+        assert_eq!(resets, 2);
+        assert_eq!(
+            iterations,
+            [
+                (1, 1),
+                (1, 2),
+                (1, 3),
+                (2, 1),
+                (2, 2),
+                (2, 1),
+                (2, 2),
+                (2, 1),
+                (2, 2)
+            ]
+        );
     }
 }
@@ -0,0 +1,375 @@
+use std::panic::AssertUnwindSafe;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+use super::TrainingInterrupter;
+
+/// Patterns, in lowercase, of the messages reported by the backends when the device is lost.
+const DEVICE_LOST_PATTERNS: [&str; 7] = [
+    "device lost",
+    "device is lost",
+    "device was lost",
+    "devicelost",
+    "cuda_error_launch_failed",
+    "cuda_error_illegal_address",
+    "cuda_error_ecc_uncorrectable",
+];
+
+/// Error raised when the device used for training stops responding.
+#[derive(Debug, Clone)]
+pub enum DeviceError {
+    /// No training or validation step completed within the configured timeout.
+    ///
+    /// This usually means a submission is hung on the device.
+    Timeout {
+        /// The time elapsed since the last completed step.
+        elapsed: Duration,
+        /// The last epoch that was checkpointed, if any.
+        last_checkpoint: Option<usize>,
+    },
+    /// A step failed because the device was lost.
+    Lost {
+        /// The message reported by the backend.
+        message: String,
+        /// The last epoch that was checkpointed, if any.
+        last_checkpoint: Option<usize>,
+    },
+}
+
+impl DeviceError {
+    /// The last epoch that was checkpointed before the error, from which training can be
+    /// resumed using [checkpoint](crate::LearnerBuilder::checkpoint).
+    pub fn last_checkpoint(&self) -> Option<usize> {
+        match self {
+            DeviceError::Timeout {
+                last_checkpoint, ..
+            } => *last_checkpoint,
+            DeviceError::Lost {
+                last_checkpoint, ..
+            } => *last_checkpoint,
+        }
+    }
+}
+
+impl core::fmt::Display for DeviceError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DeviceError::Timeout { elapsed, .. } => write!(
+                f,
+                "The device didn't respond in {elapsed:?}, it is probably hung"
+            )?,
+            DeviceError::Lost { message, .. } => write!(f, "The device was lost: {message}")?,
+        };
+
+        match self.last_checkpoint() {
+            Some(epoch) => write!(f, ", training can be resumed from epoch {epoch}."),
+            None => write!(f, ", no checkpoint is available to resume training."),
+        }
+    }
+}
+
+impl std::error::Error for DeviceError {}
+
+/// Watchdog that detects when the device used for training hangs or is lost.
+///
+/// Every completed training and validation step notifies the watchdog. A background thread
+/// raises a [device error](DeviceError::Timeout) when no step completes within the timeout, and
+/// stops the training once the blocked step returns. A step panicking because the device was lost
+/// raises a [device error](DeviceError::Lost), other panics are propagated.
+///
+/// The error is returned by [try_fit](crate::Learner::try_fit), and can be polled from another
+/// thread with [error](DeviceWatchdog::error) by keeping a clone of the watchdog, since a hung
+/// step might never return. The training can instead be resumed from the last checkpoint with a
+/// [recovery policy](DeviceRecovery). Otherwise, since the compute client of a device lives for
+/// the whole process, recovering from a hung device usually means restarting the process,
+/// reloading the module state with [checkpoint](crate::LearnerBuilder::checkpoint) and the epoch
+/// provided by [last_checkpoint](DeviceError::last_checkpoint).
+#[derive(Clone)]
+pub struct DeviceWatchdog {
+    state: Arc<WatchdogState>,
+}
+
+struct WatchdogState {
+    timeout: Duration,
+    last_beat: Mutex<Instant>,
+    // Epoch + 1, zero when no checkpoint has been saved.
+    last_checkpoint: AtomicUsize,
+    armed: AtomicBool,
+    error: Mutex<Option<DeviceError>>,
+}
+
+impl WatchdogState {
+    fn last_checkpoint(&self) -> Option<usize> {
+        match self.last_checkpoint.load(Ordering::Relaxed) {
+            0 => None,
+            epoch => Some(epoch - 1),
+        }
+    }
+
+    fn raise(&self, error: DeviceError) {
+        log::error!("Device watchdog: {error}");
+        self.error.lock().unwrap().get_or_insert(error);
+    }
+}
+
+/// Monitors the steps in a background thread, which is stopped and joined on drop.
+pub(crate) struct WatchdogMonitor {
+    stopped: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl Drop for WatchdogMonitor {
+    fn drop(&mut self) {
+        self.stopped.store(true, Ordering::Relaxed);
+
+        if let Some(handle) = self.handle.take() {
+            handle.thread().unpark();
+            handle.join().ok();
+        }
+    }
+}
+
+impl DeviceWatchdog {
+    /// Create a new watchdog raising an error when no step completes within `timeout`.
+    pub fn new(timeout: Duration) -> Self {
+        Self {
+            state: Arc::new(WatchdogState {
+                timeout,
+                last_beat: Mutex::new(Instant::now()),
+                last_checkpoint: AtomicUsize::new(0),
+                armed: AtomicBool::new(false),
+                error: Mutex::new(None),
+            }),
+        }
+    }
+
+    /// Notify the watchdog that a step completed.
+    pub fn beat(&self) {
+        *self.state.last_beat.lock().unwrap() = Instant::now();
+        self.state.armed.store(true, Ordering::Relaxed);
+    }
+
+    /// Notify the watchdog that a checkpoint was saved for the given epoch.
+    pub fn checkpointed(&self, epoch: usize) {
+        self.state
+            .last_checkpoint
+            .store(epoch + 1, Ordering::Relaxed);
+    }
+
+    /// The first error detected by the watchdog, if any.
+    pub fn error(&self) -> Option<DeviceError> {
+        self.state.error.lock().unwrap().clone()
+    }
+
+    /// Clear the detected error once the training recovered from it.
+    pub(crate) fn reset(&self) {
+        *self.state.error.lock().unwrap() = None;
+        self.beat();
+    }
+
+    /// Start monitoring the steps in a background thread, stopping the training with the
+    /// interrupter when no step completes within the timeout.
+    pub(crate) fn start(&self, interrupter: &TrainingInterrupter) -> WatchdogMonitor {
+        self.beat();
+
+        let state = self.state.clone();
+        let interrupter = interrupter.clone();
+        let stopped = Arc::new(AtomicBool::new(false));
+        let interval =
+            (self.state.timeout / 10).clamp(Duration::from_millis(10), Duration::from_secs(1));
+
+        let handle = std::thread::spawn({
+            let stopped = stopped.clone();
+
+            move || {
+                while !stopped.load(Ordering::Relaxed) {
+                    std::thread::park_timeout(interval);
+
+                    if !state.armed.load(Ordering::Relaxed) {
+                        continue;
+                    }
+
+                    let elapsed = state.last_beat.lock().unwrap().elapsed();
+                    if elapsed > state.timeout {
+                        // Only report a hang once, until a step completes again.
+                        state.armed.store(false, Ordering::Relaxed);
+                        state.raise(DeviceError::Timeout {
+                            elapsed,
+                            last_checkpoint: state.last_checkpoint(),
+                        });
+                        interrupter.stop();
+                    }
+                }
+            }
+        });
+
+        WatchdogMonitor {
+            stopped,
+            handle: Some(handle),
+        }
+    }
+
+    /// Run the given function, returning a [device error](DeviceError::Lost) when it panics
+    /// because the device was lost. Other panics are propagated.
+    pub(crate) fn guard<T>(
+        watchdog: Option<&Self>,
+        func: impl FnOnce() -> T,
+    ) -> Result<T, DeviceError> {
+        let watchdog = match watchdog {
+            Some(watchdog) => watchdog,
+            None => return Ok(func()),
+        };
+
+        match std::panic::catch_unwind(AssertUnwindSafe(func)) {
+            Ok(output) => Ok(output),
+            Err(payload) => {
+                let message = payload
+                    .downcast_ref::<&str>()
+                    .map(|message| message.to_string())
+                    .or_else(|| payload.downcast_ref::<String>().cloned());
+
+                match message {
+                    Some(message) if is_device_lost(&message) => Err(watchdog.device_lost(message)),
+                    _ => std::panic::resume_unwind(payload),
+                }
+            }
+        }
+    }
+
+    fn device_lost(&self, message: String) -> DeviceError {
+        let error = DeviceError::Lost {
+            message,
+            last_checkpoint: self.state.last_checkpoint(),
+        };
+        self.state.raise(error.clone());
+        error
+    }
+}
+
+/// Policy resuming the training from the last checkpoint when the
+/// [device watchdog](DeviceWatchdog) detects an error.
+///
+/// The reset function is called with the error to re-create the device, e.g. by initializing the
+/// runtime of the backend again, before the model, the optimizer and the learning rate scheduler
+/// are loaded from the last checkpoint and the training continues with the next epoch. The
+/// training fails with the error once the maximum number of retries is reached, when the reset
+/// function fails or when no checkpoint was saved yet.
+pub struct DeviceRecovery {
+    max_retries: usize,
+    #[allow(clippy::type_complexity)]
+    reset: Box<dyn FnMut(&DeviceError) -> Result<(), String> + Send>,
+}
+
+impl DeviceRecovery {
+    /// Create a new policy recovering at most `max_retries` times from device errors, using the
+    /// given function to re-create the device.
+    pub fn new<F>(max_retries: usize, reset: F) -> Self
+    where
+        F: FnMut(&DeviceError) -> Result<(), String> + Send + 'static,
+    {
+        Self {
+            max_retries,
+            reset: Box::new(reset),
+        }
+    }
+
+    /// Re-create the device after the given error, returning false when the training can't be
+    /// recovered.
+    pub(crate) fn reset(&mut self, error: &DeviceError, retries: usize) -> bool {
+        if retries >= self.max_retries {
+            log::error!("Can't recover from the device error after {retries} retries.");
+            return false;
+        }
+
+        log::warn!(
+            "Recovering from the device error, retry {} of {}.",
+            retries + 1,
+            self.max_retries
+        );
+        match (self.reset)(error) {
+            Ok(()) => true,
+            Err(err) => {
+                log::error!("Can't re-create the device: {err}");
+                false
+            }
+        }
+    }
+}
+
+/// If the message of a panic reports that the device was lost.
+fn is_device_lost(message: &str) -> bool {
+    let message = message.to_lowercase();
+
+    DEVICE_LOST_PATTERNS
+        .iter()
+        .any(|pattern| message.contains(pattern))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_format_last_checkpoint() {
+        let watchdog = DeviceWatchdog::new(Duration::from_secs(1));
+        watchdog.checkpointed(3);
+
+        let error = watchdog.device_lost("Parent device is lost".to_string());
+
+        assert_eq!(error.last_checkpoint(), Some(3));
+        assert_eq!(
+            error.to_string(),
+            "The device was lost: Parent device is lost, training can be resumed from epoch 3."
+        );
+    }
+
+    #[test]
+    fn should_not_report_checkpoint_when_none_saved() {
+        let watchdog = DeviceWatchdog::new(Duration::from_secs(1));
+
+        let error = watchdog.device_lost("Parent device is lost".to_string());
+
+        assert_eq!(error.last_checkpoint(), None);
+    }
+
+    #[test]
+    fn should_return_device_lost_error() {
+        let watchdog = DeviceWatchdog::new(Duration::from_secs(1));
+
+        let result = DeviceWatchdog::guard(Some(&watchdog), || -> usize {
+            panic!("Validation Error: Parent device is lost")
+        });
+
+        assert!(matches!(result, Err(DeviceError::Lost { .. })));
+        assert!(watchdog.error().is_some());
+    }
+
+    #[test]
+    #[should_panic = "Shapes don't match"]
+    fn should_propagate_other_panics() {
+        let watchdog = DeviceWatchdog::new(Duration::from_secs(1));
+
+        DeviceWatchdog::guard(Some(&watchdog), || -> usize {
+            panic!("Shapes don't match")
+        })
+        .ok();
+    }
+
+    #[test]
+    fn should_stop_training_on_timeout() {
+        let watchdog = DeviceWatchdog::new(Duration::from_millis(20));
+        let interrupter = TrainingInterrupter::new();
+
+        let monitor = watchdog.start(&interrupter);
+        std::thread::sleep(Duration::from_millis(200));
+        drop(monitor);
+
+        assert!(matches!(
+            watchdog.error(),
+            Some(DeviceError::Timeout { .. })
+        ));
+        assert!(interrupter.should_stop());
+    }
+}
@@ -27,6 +27,9 @@ pub use learner::*;
 #[cfg(test)]
 pub(crate) type TestBackend = burn_ndarray::NdArray<f32>;
 
+#[cfg(test)]
+pub(crate) type TestAutodiffBackend = burn_autodiff::Autodiff<TestBackend>;
+
 #[cfg(test)]
 pub(crate) mod tests {
     use crate::TestBackend;
@@ -105,4 +108,137 @@ pub(crate) mod tests {
             }
         }
     }
+
+    /// A linear regression trained by the learner in tests.
+    pub mod learner {
+        use crate::renderer::{MetricState, MetricsRenderer, TrainingProgress};
+        use crate::{
+            LearnerBuilder, RegressionOutput, TestAutodiffBackend, TestBackend, TrainOutput,
+            TrainStep, ValidStep,
+        };
+        use burn_core::data::dataloader::{batcher::Batcher, DataLoader, DataLoaderBuilder};
+        use burn_core::data::dataset::InMemDataset;
+        use burn_core::nn::loss::{MseLoss, Reduction};
+        use burn_core::nn::{Linear, LinearConfig};
+        use burn_core::optim::{adaptor::OptimizerAdaptor, Sgd, SgdConfig};
+        use burn_core::record::CompactRecorder;
+        use burn_core::tensor::{backend::Backend, Tensor};
+        use burn_core::LearningRate;
+        use std::path::Path;
+        use std::sync::Arc;
+
+        pub type TestModel = Linear<TestAutodiffBackend>;
+
+        pub type TestLearnerBuilder = LearnerBuilder<
+            TestAutodiffBackend,
+            RegressionOutput<TestAutodiffBackend>,
+            RegressionOutput<TestBackend>,
+            TestModel,
+            OptimizerAdaptor<Sgd<TestBackend>, TestModel, TestAutodiffBackend>,
+            LearningRate,
+        >;
+
+        #[derive(Clone, Debug)]
+        pub struct RegressionBatch<B: Backend> {
+            inputs: Tensor<B, 2>,
+            targets: Tensor<B, 2>,
+        }
+
+        #[derive(Clone)]
+        struct RegressionBatcher;
+
+        impl<B: Backend> Batcher<[f32; 2], RegressionBatch<B>> for RegressionBatcher {
+            fn batch(&self, items: Vec<[f32; 2]>) -> RegressionBatch<B> {
+                let device = Default::default();
+                let (inputs, targets): (Vec<_>, Vec<_>) = items
+                    .into_iter()
+                    .map(|[input, target]| {
+                        (
+                            Tensor::<B, 2>::from_floats([[input]], &device),
+                            Tensor::<B, 2>::from_floats([[target]], &device),
+                        )
+                    })
+                    .unzip();
+
+                RegressionBatch {
+                    inputs: Tensor::cat(inputs, 0),
+                    targets: Tensor::cat(targets, 0),
+                }
+            }
+        }
+
+        fn regression<B: Backend>(
+            model: &Linear<B>,
+            batch: RegressionBatch<B>,
+        ) -> RegressionOutput<B> {
+            let output = model.forward(batch.inputs);
+            let loss =
+                MseLoss::new().forward(output.clone(), batch.targets.clone(), Reduction::Mean);
+
+            RegressionOutput::new(loss, output, batch.targets)
+        }
+
+        impl TrainStep<RegressionBatch<TestAutodiffBackend>, RegressionOutput<TestAutodiffBackend>>
+            for TestModel
+        {
+            fn step(
+                &self,
+                batch: RegressionBatch<TestAutodiffBackend>,
+            ) -> TrainOutput<RegressionOutput<TestAutodiffBackend>> {
+                let item = regression(self, batch);
+
+                TrainOutput::new(self, item.loss.backward(), item)
+            }
+        }
+
+        impl ValidStep<RegressionBatch<TestBackend>, RegressionOutput<TestBackend>>
+            for Linear<TestBackend>
+        {
+            fn step(&self, batch: RegressionBatch<TestBackend>) -> RegressionOutput<TestBackend> {
+                regression(self, batch)
+            }
+        }
+
+        struct NoRenderer;
+
+        impl MetricsRenderer for NoRenderer {
+            fn update_train(&mut self, _state: MetricState) {}
+            fn update_valid(&mut self, _state: MetricState) {}
+            fn render_train(&mut self, _item: TrainingProgress) {}
+            fn render_valid(&mut self, _item: TrainingProgress) {}
+        }
+
+        /// A learner builder saving its checkpoints and logs in the given directory, without
+        /// rendering the progress.
+        pub fn learner_builder(directory: &Path) -> TestLearnerBuilder {
+            LearnerBuilder::new(directory)
+                .renderer(NoRenderer)
+                .with_application_logger(None)
+                .with_file_checkpointer(CompactRecorder::new())
+        }
+
+        pub fn model() -> TestModel {
+            LinearConfig::new(1, 1).init(&Default::default())
+        }
+
+        pub fn optimizer() -> OptimizerAdaptor<Sgd<TestBackend>, TestModel, TestAutodiffBackend> {
+            SgdConfig::new().init()
+        }
+
+        /// A dataloader of `num_items` items, in batches of two items.
+        pub fn dataloader<B: Backend>(num_items: usize) -> Arc<dyn DataLoader<RegressionBatch<B>>> {
+            let items = (0..num_items)
+                .map(|i| {
+                    [
+                        i as f32 / num_items as f32,
+                        2.0 * i as f32 / num_items as f32,
+                    ]
+                })
+                .collect();
+
+            DataLoaderBuilder::new(RegressionBatcher)
+                .batch_size(2)
+                .build(InMemDataset::new(items))
+        }
+    }
 }
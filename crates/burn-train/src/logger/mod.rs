@@ -3,9 +3,15 @@ mod base;
 mod file;
 mod in_memory;
 mod metric;
+#[cfg(feature = "otel")]
+mod otel;
+mod tensorboard;
 
 pub use async_logger::*;
 pub use base::*;
 pub use file::*;
 pub use in_memory::*;
 pub use metric::*;
+#[cfg(feature = "otel")]
+pub use otel::*;
+pub use tensorboard::*;
@@ -0,0 +1,252 @@
+use super::{InMemoryMetricLogger, MetricLogger};
+use crate::learner::{file_layer, install_panic_hook, ApplicationLoggerInstaller};
+use crate::metric::{MetricEntry, NumericEntry};
+use opentelemetry::metrics::{Gauge, Meter};
+use opentelemetry::trace::TracerProvider as _;
+use opentelemetry::{global, KeyValue};
+use opentelemetry_otlp::{MetricExporter, SpanExporter, WithExportConfig};
+use opentelemetry_sdk::metrics::{PeriodicReader, SdkMeterProvider};
+use opentelemetry_sdk::runtime::TokioCurrentThread;
+use opentelemetry_sdk::trace::TracerProvider;
+use opentelemetry_sdk::Resource;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tracing_subscriber::prelude::*;
+use tracing_subscriber::registry;
+
+/// The providers installed by the [OpenTelemetry installer](OpenTelemetryInstaller), kept to
+/// flush them on [shutdown](shutdown_opentelemetry).
+static PROVIDERS: Mutex<Option<(TracerProvider, SdkMeterProvider)>> = Mutex::new(None);
+
+/// Installs an application logger exporting the spans of the training to an
+/// [OpenTelemetry](https://opentelemetry.io) collector, along with a meter provider used by the
+/// [OpenTelemetry metric logger](OpenTelemetryMetricLogger).
+///
+/// The spans and metrics are sent with the OTLP protocol over HTTP, in batches sent from a
+/// background thread. The learner records spans for the dataloader, the training and validation
+/// steps and the optimizer steps, and the backends may record coarser spans, e.g. for the fused
+/// kernels with the `tracing` feature of `burn-jit`.
+///
+/// # Notes
+///
+/// Call [shutdown_opentelemetry] before exiting, so the last batches are exported.
+pub struct OpenTelemetryInstaller {
+    endpoint: String,
+    service_name: String,
+    file: Option<PathBuf>,
+}
+
+impl OpenTelemetryInstaller {
+    /// Create a new installer exporting to the collector at the given endpoint, e.g.
+    /// `http://localhost:4318`.
+    pub fn new(endpoint: impl Into<String>) -> Self {
+        Self {
+            endpoint: endpoint.into(),
+            service_name: "burn".to_string(),
+            file: None,
+        }
+    }
+
+    /// The name of the service reported with the spans and metrics. Default: `burn`
+    pub fn with_service_name(mut self, service_name: impl Into<String>) -> Self {
+        self.service_name = service_name.into();
+        self
+    }
+
+    /// Also write the logs to the given file, like the
+    /// [file application logger](crate::learner::FileApplicationLoggerInstaller).
+    pub fn with_file(mut self, path: impl AsRef<Path>) -> Self {
+        self.file = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    fn providers(&self) -> Result<(TracerProvider, SdkMeterProvider), String> {
+        let endpoint = self.endpoint.trim_end_matches('/');
+        let resource = Resource::new([KeyValue::new("service.name", self.service_name.clone())]);
+
+        let spans = SpanExporter::builder()
+            .with_http()
+            .with_endpoint(format!("{endpoint}/v1/traces"))
+            .build()
+            .map_err(|err| format!("Failed to create the span exporter: {err}"))?;
+        let tracer_provider = TracerProvider::builder()
+            .with_batch_exporter(spans, TokioCurrentThread)
+            .with_resource(resource.clone())
+            .build();
+
+        let metrics = MetricExporter::builder()
+            .with_http()
+            .with_endpoint(format!("{endpoint}/v1/metrics"))
+            .build()
+            .map_err(|err| format!("Failed to create the metric exporter: {err}"))?;
+        let meter_provider = SdkMeterProvider::builder()
+            .with_reader(PeriodicReader::builder(metrics, TokioCurrentThread).build())
+            .with_resource(resource)
+            .build();
+
+        Ok((tracer_provider, meter_provider))
+    }
+}
+
+impl ApplicationLoggerInstaller for OpenTelemetryInstaller {
+    fn install(&self) -> Result<(), String> {
+        let (tracer_provider, meter_provider) = self.providers()?;
+        let tracer = tracer_provider.tracer("burn");
+        let file = self.file.as_deref().map(file_layer);
+
+        if registry()
+            .with(file)
+            .with(tracing_opentelemetry::layer().with_tracer(tracer))
+            .try_init()
+            .is_err()
+        {
+            return Err("Failed to install the OpenTelemetry logger.".to_string());
+        }
+
+        if let Some(path) = &self.file {
+            install_panic_hook(path);
+        }
+
+        global::set_tracer_provider(tracer_provider.clone());
+        global::set_meter_provider(meter_provider.clone());
+        *PROVIDERS.lock().unwrap() = Some((tracer_provider, meter_provider));
+
+        Ok(())
+    }
+}
+
+/// Flush and stop the exporters installed by the [OpenTelemetry installer](OpenTelemetryInstaller).
+pub fn shutdown_opentelemetry() {
+    if let Some((tracer_provider, meter_provider)) = PROVIDERS.lock().unwrap().take() {
+        if let Err(err) = tracer_provider.shutdown() {
+            log::warn!("Failed to shutdown the OpenTelemetry tracer provider: {err}");
+        }
+        if let Err(err) = meter_provider.shutdown() {
+            log::warn!("Failed to shutdown the OpenTelemetry meter provider: {err}");
+        }
+    }
+}
+
+/// Metric logger recording every numeric metric as an [OpenTelemetry](https://opentelemetry.io)
+/// gauge named `burn.<name>`, with the split and the epoch as attributes.
+///
+/// The gauges are exported by the meter provider installed with the
+/// [OpenTelemetry installer](OpenTelemetryInstaller). Register one logger for training and one for
+/// validation with their split.
+///
+/// The meter is retrieved from the global meter provider when the first metric is logged, so the
+/// loggers can be created before the learner installs the application logger when it is built.
+pub struct OpenTelemetryMetricLogger {
+    meter: Option<Meter>,
+    gauges: HashMap<String, Gauge<f64>>,
+    split: &'static str,
+    epoch: usize,
+    // Keep the numeric entries so the logger can be read by checkpointing and early stopping
+    // strategies.
+    values: InMemoryMetricLogger,
+}
+
+impl OpenTelemetryMetricLogger {
+    /// Create a new metric logger for the training split.
+    pub fn train() -> Self {
+        Self::new("train")
+    }
+
+    /// Create a new metric logger for the validation split.
+    pub fn valid() -> Self {
+        Self::new("valid")
+    }
+
+    fn new(split: &'static str) -> Self {
+        Self {
+            meter: None,
+            gauges: HashMap::new(),
+            split,
+            epoch: 1,
+            values: InMemoryMetricLogger::new(),
+        }
+    }
+}
+
+impl MetricLogger for OpenTelemetryMetricLogger {
+    fn log(&mut self, item: &MetricEntry) {
+        self.values.log(item);
+
+        let value = match NumericEntry::deserialize(&item.serialize) {
+            Ok(NumericEntry::Value(value)) => value,
+            Ok(NumericEntry::Aggregated(value, _)) => value,
+            Ok(NumericEntry::Running(value, _, _)) => value,
+            // Not a numeric metric.
+            Err(_) => return,
+        };
+
+        let meter = self.meter.get_or_insert_with(|| global::meter("burn"));
+        let gauge = self.gauges.entry(item.name.clone()).or_insert_with(|| {
+            let name = item.name.to_lowercase().replace(' ', "_");
+            meter.f64_gauge(format!("burn.{name}")).build()
+        });
+
+        gauge.record(
+            value,
+            &[
+                KeyValue::new("split", self.split),
+                KeyValue::new("epoch", self.epoch as i64),
+            ],
+        );
+    }
+
+    fn end_epoch(&mut self, epoch: usize) {
+        self.epoch = epoch + 1;
+        self.values.end_epoch(epoch);
+    }
+
+    fn read_numeric(&mut self, name: &str, epoch: usize) -> Result<Vec<NumericEntry>, String> {
+        self.values.read_numeric(name, epoch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opentelemetry_sdk::metrics::data::Gauge as GaugeData;
+    use opentelemetry_sdk::testing::metrics::InMemoryMetricExporter;
+
+    #[test]
+    fn should_export_metrics_to_the_provider_installed_after_creation() {
+        let mut logger = OpenTelemetryMetricLogger::train();
+
+        let exporter = InMemoryMetricExporter::default();
+        let meter_provider = SdkMeterProvider::builder()
+            .with_reader(PeriodicReader::builder(exporter.clone(), TokioCurrentThread).build())
+            .build();
+        global::set_meter_provider(meter_provider.clone());
+
+        logger.log(&MetricEntry::new(
+            "Loss".to_string(),
+            "0.5".to_string(),
+            NumericEntry::Value(0.5).serialize(),
+        ));
+        meter_provider.force_flush().unwrap();
+
+        let metrics = exporter.get_finished_metrics().unwrap();
+        let metric = metrics
+            .iter()
+            .flat_map(|resource| resource.scope_metrics.iter())
+            .flat_map(|scope| scope.metrics.iter())
+            .find(|metric| metric.name == "burn.loss")
+            .expect("The loss gauge should be exported");
+        let gauge = metric
+            .data
+            .as_any()
+            .downcast_ref::<GaugeData<f64>>()
+            .unwrap();
+
+        assert_eq!(gauge.data_points.len(), 1);
+        assert_eq!(gauge.data_points[0].value, 0.5);
+        assert!(gauge.data_points[0]
+            .attributes
+            .contains(&KeyValue::new("split", "train")));
+        meter_provider.shutdown().unwrap();
+    }
+}
@@ -0,0 +1,437 @@
+use super::{InMemoryMetricLogger, MetricLogger};
+use crate::metric::{MetricEntry, NumericEntry};
+use burn_core::module::{AutodiffModule, Module, ModuleVisitor, ParamId};
+use burn_core::optim::GradientsParams;
+use burn_core::tensor::backend::{AutodiffBackend, Backend};
+use burn_core::tensor::Tensor;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const NUM_HISTOGRAM_BUCKETS: usize = 30;
+
+/// Metric logger writing [TensorBoard](https://www.tensorflow.org/tensorboard) event files.
+///
+/// Every numeric metric is written as a scalar for each iteration under its name, and the mean
+/// of each epoch is written under `<name>/epoch`. Register one logger for training and one for
+/// validation in different directories to view them as two runs in TensorBoard.
+///
+/// Histograms of weights and gradients can be written with the [writer](TensorBoardWriter)
+/// returned by [writer](TensorBoardMetricLogger::writer).
+pub struct TensorBoardMetricLogger {
+    writer: TensorBoardWriter,
+    steps: HashMap<String, usize>,
+    epoch_values: HashMap<String, (f64, usize)>,
+    // Keep the numeric entries so the logger can be read by checkpointing and early stopping
+    // strategies.
+    values: InMemoryMetricLogger,
+}
+
+impl TensorBoardMetricLogger {
+    /// Create a new TensorBoard metric logger writing event files in the given directory.
+    pub fn new(directory: impl AsRef<Path>) -> Self {
+        Self {
+            writer: TensorBoardWriter::new(directory),
+            steps: HashMap::new(),
+            epoch_values: HashMap::new(),
+            values: InMemoryMetricLogger::new(),
+        }
+    }
+
+    /// Get a handle on the event file writer, which can be used to log additional data such as
+    /// histograms of weights and gradients.
+    pub fn writer(&self) -> TensorBoardWriter {
+        self.writer.clone()
+    }
+}
+
+impl MetricLogger for TensorBoardMetricLogger {
+    fn log(&mut self, item: &MetricEntry) {
+        self.values.log(item);
+
+        let (value, running) = match NumericEntry::deserialize(&item.serialize) {
+            Ok(NumericEntry::Value(value)) => (value, None),
+            Ok(NumericEntry::Aggregated(value, _)) => (value, None),
+            Ok(NumericEntry::Running(value, _, running)) => (value, Some(running)),
+            // Not a numeric metric.
+            Err(_) => return,
+        };
+
+        let step = self.steps.entry(item.name.clone()).or_insert(0);
+        *step += 1;
+        self.writer.add_scalar(&item.name, value, *step);
+
+        let (sum, count) = self
+            .epoch_values
+            .entry(item.name.clone())
+            .or_insert((0., 0));
+        match running {
+            // The epoch value of the metric is its last running value.
+            Some(running) => (*sum, *count) = (running, 1),
+            None => {
+                *sum += value;
+                *count += 1;
+            }
+        }
+    }
+
+    fn end_epoch(&mut self, epoch: usize) {
+        for (name, (sum, count)) in self.epoch_values.drain() {
+            self.writer
+                .add_scalar(&format!("{name}/epoch"), sum / count as f64, epoch);
+        }
+        self.writer.flush();
+        self.values.end_epoch(epoch);
+    }
+
+    fn read_numeric(&mut self, name: &str, epoch: usize) -> Result<Vec<NumericEntry>, String> {
+        self.values.read_numeric(name, epoch)
+    }
+}
+
+/// Writer of TensorBoard event files that can be shared between threads.
+#[derive(Clone)]
+pub struct TensorBoardWriter {
+    file: Arc<Mutex<EventFile>>,
+}
+
+impl TensorBoardWriter {
+    /// Create a new writer with a new event file in the given directory.
+    pub fn new(directory: impl AsRef<Path>) -> Self {
+        Self {
+            file: Arc::new(Mutex::new(EventFile::new(directory.as_ref()))),
+        }
+    }
+
+    /// Write a scalar value.
+    pub fn add_scalar(&self, tag: &str, value: f64, step: usize) {
+        let mut summary_value = Vec::new();
+        encode_string(&mut summary_value, 1, tag);
+        encode_float(&mut summary_value, 2, value as f32);
+
+        self.file
+            .lock()
+            .unwrap()
+            .write_summary(&summary_value, step);
+    }
+
+    /// Write a histogram of the given values.
+    pub fn add_histogram(&self, tag: &str, values: &[f64], step: usize) {
+        if values.is_empty() {
+            return;
+        }
+
+        let mut summary_value = Vec::new();
+        encode_string(&mut summary_value, 1, tag);
+        encode_bytes(&mut summary_value, 5, &encode_histogram(values));
+
+        self.file
+            .lock()
+            .unwrap()
+            .write_summary(&summary_value, step);
+    }
+
+    /// Write a histogram of every float parameter of the module, tagged with the given prefix and
+    /// the [parameter id](ParamId).
+    pub fn add_weights_histograms<B: Backend, M: Module<B>>(
+        &self,
+        prefix: &str,
+        module: &M,
+        step: usize,
+    ) {
+        let mut visitor = WeightsHistogramVisitor {
+            writer: self,
+            prefix,
+            step,
+        };
+        module.visit(&mut visitor);
+    }
+
+    /// Write a histogram of the gradients of every float parameter of the module, tagged with
+    /// the given prefix and the [parameter id](ParamId).
+    pub fn add_grads_histograms<B: AutodiffBackend, M: AutodiffModule<B>>(
+        &self,
+        prefix: &str,
+        module: &M,
+        grads: &GradientsParams,
+        step: usize,
+    ) {
+        let mut visitor = GradsHistogramVisitor {
+            writer: self,
+            prefix,
+            step,
+            grads,
+        };
+        module.visit(&mut visitor);
+    }
+
+    /// Flush the event file.
+    pub fn flush(&self) {
+        self.file.lock().unwrap().flush();
+    }
+
+    fn add_tensor_histogram<B: Backend, const D: usize>(
+        &self,
+        prefix: &str,
+        id: ParamId,
+        tensor: Tensor<B, D>,
+        step: usize,
+    ) {
+        let values = tensor.into_data().iter::<f64>().collect::<Vec<_>>();
+        self.add_histogram(&format!("{prefix}/{id}"), &values, step);
+    }
+}
+
+struct WeightsHistogramVisitor<'a> {
+    writer: &'a TensorBoardWriter,
+    prefix: &'a str,
+    step: usize,
+}
+
+impl<B: Backend> ModuleVisitor<B> for WeightsHistogramVisitor<'_> {
+    fn visit_float<const D: usize>(&mut self, id: ParamId, tensor: &Tensor<B, D>) {
+        self.writer
+            .add_tensor_histogram(self.prefix, id, tensor.clone(), self.step);
+    }
+}
+
+struct GradsHistogramVisitor<'a> {
+    writer: &'a TensorBoardWriter,
+    prefix: &'a str,
+    step: usize,
+    grads: &'a GradientsParams,
+}
+
+impl<B: AutodiffBackend> ModuleVisitor<B> for GradsHistogramVisitor<'_> {
+    fn visit_float<const D: usize>(&mut self, id: ParamId, _tensor: &Tensor<B, D>) {
+        if let Some(grad) = self.grads.get::<B::InnerBackend, D>(id) {
+            self.writer
+                .add_tensor_histogram(self.prefix, id, grad, self.step);
+        }
+    }
+}
+
+/// An event file, where each event is a serialized `Event` protobuf message stored in a
+/// TFRecord.
+struct EventFile {
+    writer: BufWriter<File>,
+}
+
+impl EventFile {
+    fn new(directory: &Path) -> Self {
+        std::fs::create_dir_all(directory).ok();
+
+        let timestamp = wall_time();
+        let path: PathBuf = directory.join(format!(
+            "events.out.tfevents.{}.burn.{}",
+            timestamp as u64,
+            std::process::id()
+        ));
+        let file = File::create(&path).unwrap_or_else(|err| {
+            panic!(
+                "Should be able to create the event file '{}': {}",
+                path.display(),
+                err
+            )
+        });
+
+        let mut event_file = Self {
+            writer: BufWriter::new(file),
+        };
+
+        // The first event of a file declares the version of the format.
+        let mut event = Vec::new();
+        encode_double(&mut event, 1, timestamp);
+        encode_string(&mut event, 3, "brain.Event:2");
+        event_file.write_record(&event);
+        event_file.flush();
+
+        event_file
+    }
+
+    fn write_summary(&mut self, summary_value: &[u8], step: usize) {
+        let mut summary = Vec::new();
+        encode_bytes(&mut summary, 1, summary_value);
+
+        let mut event = Vec::new();
+        encode_double(&mut event, 1, wall_time());
+        encode_varint_field(&mut event, 2, step as u64);
+        encode_bytes(&mut event, 5, &summary);
+
+        self.write_record(&event);
+    }
+
+    fn write_record(&mut self, data: &[u8]) {
+        let length = (data.len() as u64).to_le_bytes();
+
+        let mut record = Vec::with_capacity(data.len() + 16);
+        record.extend_from_slice(&length);
+        record.extend_from_slice(&masked_crc32c(&length).to_le_bytes());
+        record.extend_from_slice(data);
+        record.extend_from_slice(&masked_crc32c(data).to_le_bytes());
+
+        self.writer
+            .write_all(&record)
+            .expect("Can write to the event file.");
+    }
+
+    fn flush(&mut self) {
+        self.writer.flush().expect("Can flush the event file.");
+    }
+}
+
+impl Drop for EventFile {
+    fn drop(&mut self) {
+        self.writer.flush().ok();
+    }
+}
+
+fn wall_time() -> f64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs_f64())
+        .unwrap_or_default()
+}
+
+/// Encode a `HistogramProto` message with uniform buckets between the min and max values.
+fn encode_histogram(values: &[f64]) -> Vec<u8> {
+    let mut min = f64::INFINITY;
+    let mut max = f64::NEG_INFINITY;
+    let mut sum = 0.0;
+    let mut sum_squares = 0.0;
+
+    for value in values {
+        min = min.min(*value);
+        max = max.max(*value);
+        sum += value;
+        sum_squares += value * value;
+    }
+
+    let num_buckets = match max > min {
+        true => NUM_HISTOGRAM_BUCKETS,
+        false => 1,
+    };
+    let width = (max - min) / num_buckets as f64;
+
+    let mut bucket_limits = (1..=num_buckets)
+        .map(|i| min + width * i as f64)
+        .collect::<Vec<_>>();
+    bucket_limits[num_buckets - 1] = max;
+
+    let mut buckets = vec![0.0; num_buckets];
+    for value in values {
+        let index = match width > 0.0 {
+            true => (((value - min) / width) as usize).min(num_buckets - 1),
+            false => 0,
+        };
+        buckets[index] += 1.0;
+    }
+
+    let mut histogram = Vec::new();
+    encode_double(&mut histogram, 1, min);
+    encode_double(&mut histogram, 2, max);
+    encode_double(&mut histogram, 3, values.len() as f64);
+    encode_double(&mut histogram, 4, sum);
+    encode_double(&mut histogram, 5, sum_squares);
+    encode_packed_doubles(&mut histogram, 6, &bucket_limits);
+    encode_packed_doubles(&mut histogram, 7, &buckets);
+
+    histogram
+}
+
+fn encode_varint(buffer: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        buffer.push((value as u8 & 0x7F) | 0x80);
+        value >>= 7;
+    }
+    buffer.push(value as u8);
+}
+
+fn encode_key(buffer: &mut Vec<u8>, field: u32, wire_type: u32) {
+    encode_varint(buffer, ((field << 3) | wire_type) as u64);
+}
+
+fn encode_varint_field(buffer: &mut Vec<u8>, field: u32, value: u64) {
+    encode_key(buffer, field, 0);
+    encode_varint(buffer, value);
+}
+
+fn encode_double(buffer: &mut Vec<u8>, field: u32, value: f64) {
+    encode_key(buffer, field, 1);
+    buffer.extend_from_slice(&value.to_le_bytes());
+}
+
+fn encode_float(buffer: &mut Vec<u8>, field: u32, value: f32) {
+    encode_key(buffer, field, 5);
+    buffer.extend_from_slice(&value.to_le_bytes());
+}
+
+fn encode_bytes(buffer: &mut Vec<u8>, field: u32, value: &[u8]) {
+    encode_key(buffer, field, 2);
+    encode_varint(buffer, value.len() as u64);
+    buffer.extend_from_slice(value);
+}
+
+fn encode_string(buffer: &mut Vec<u8>, field: u32, value: &str) {
+    encode_bytes(buffer, field, value.as_bytes());
+}
+
+fn encode_packed_doubles(buffer: &mut Vec<u8>, field: u32, values: &[f64]) {
+    let bytes = values
+        .iter()
+        .flat_map(|value| value.to_le_bytes())
+        .collect::<Vec<_>>();
+    encode_bytes(buffer, field, &bytes);
+}
+
+/// CRC-32C (Castagnoli) checksum, masked as required by the TFRecord format.
+fn masked_crc32c(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for byte in data {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = match crc & 1 {
+                1 => (crc >> 1) ^ 0x82F6_3B78,
+                _ => crc >> 1,
+            };
+        }
+    }
+    let crc = !crc;
+
+    crc.rotate_right(15).wrapping_add(0xA282_EAD8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crc32c_should_match_reference() {
+        // Unmasked CRC-32C of "123456789" is 0xE3069283.
+        let crc = 0xE306_9283u32;
+        let expected = crc.rotate_right(15).wrapping_add(0xA282_EAD8);
+
+        assert_eq!(masked_crc32c(b"123456789"), expected);
+    }
+
+    #[test]
+    fn varint_should_encode_multiple_bytes() {
+        let mut buffer = Vec::new();
+        encode_varint(&mut buffer, 300);
+
+        assert_eq!(buffer, vec![0xAC, 0x02]);
+    }
+
+    #[test]
+    fn histogram_should_count_every_value() {
+        let values = [0.0, 1.0, 1.0, 2.0, 3.0];
+        let histogram = encode_histogram(&values);
+
+        // The `num` field is the third double.
+        let num = f64::from_le_bytes(histogram[19..27].try_into().unwrap());
+        assert_eq!(num, 5.0);
+    }
+}
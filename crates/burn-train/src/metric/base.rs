@@ -1,6 +1,14 @@
 use burn_core::{data::dataloader::Progress, LearningRate};
 
+use crate::TrainingDiagnostics;
+
 /// Metric metadata that can be used when computing metrics.
+///
+/// The metadata can't be built with a struct literal outside of this crate, so that fields can be
+/// added without breaking custom metrics. Use [new](MetricMetadata::new) instead, with
+/// [with_diagnostics](MetricMetadata::with_diagnostics) to set the training diagnostics.
+#[derive(new)]
+#[non_exhaustive]
 pub struct MetricMetadata {
     /// The current progress.
     pub progress: Progress,
@@ -16,9 +24,19 @@ pub struct MetricMetadata {
 
     /// The current learning rate.
     pub lr: Option<LearningRate>,
+
+    /// The training diagnostics, when they were sampled at the current iteration.
+    #[new(default)]
+    pub diagnostics: Option<TrainingDiagnostics>,
 }
 
 impl MetricMetadata {
+    /// Set the training diagnostics sampled at the current iteration.
+    pub fn with_diagnostics(mut self, diagnostics: TrainingDiagnostics) -> Self {
+        self.diagnostics = Some(diagnostics);
+        self
+    }
+
     /// Fake metric metadata
     #[cfg(test)]
     pub fn fake() -> Self {
@@ -31,6 +49,7 @@ impl MetricMetadata {
             epoch_total: 1,
             iteration: 0,
             lr: None,
+            diagnostics: None,
         }
     }
 }
@@ -91,6 +110,9 @@ pub enum NumericEntry {
     Value(f64),
     /// Aggregated numeric (value, number of elements).
     Aggregated(f64, usize),
+    /// Aggregated numeric with the value over all the items of the epoch seen so far, for metrics
+    /// that can't be averaged over batches (value, number of elements, epoch value).
+    Running(f64, usize, f64),
 }
 
 impl NumericEntry {
@@ -98,6 +120,7 @@ impl NumericEntry {
         match self {
             Self::Value(v) => v.to_string(),
             Self::Aggregated(v, n) => format!("{v},{n}"),
+            Self::Running(v, n, r) => format!("{v},{n},{r}"),
         }
     }
 
@@ -122,6 +145,12 @@ impl NumericEntry {
                 },
                 Err(err) => Err(err.to_string()),
             }
+        } else if num_values == 3 {
+            // Running numeric (value, number of elements, epoch value)
+            let value = values[0].parse::<f64>().map_err(|err| err.to_string())?;
+            let numel = values[1].parse::<usize>().map_err(|err| err.to_string())?;
+            let running = values[2].parse::<f64>().map_err(|err| err.to_string())?;
+            Ok(NumericEntry::Running(value, numel, running))
         } else {
             Err("Invalid number of values for numeric entry".to_string())
         }
@@ -0,0 +1,301 @@
+use core::marker::PhantomData;
+use std::sync::{Arc, Mutex};
+
+use super::state::{format_running, FormatOptions};
+use super::{MetricEntry, MetricMetadata, NumericEntry};
+use crate::metric::{Metric, Numeric};
+use burn_core::tensor::activation::softmax;
+use burn_core::tensor::backend::Backend;
+use burn_core::tensor::{Int, Tensor, Transaction};
+use serde::{Deserialize, Serialize};
+
+/// The expected calibration error (ECE) of a classifier, the gap between its confidence and its
+/// accuracy.
+///
+/// The predictions are grouped in bins by confidence, the probability of the predicted class, and
+/// the error is the average over the bins of the absolute difference between the accuracy and the
+/// mean confidence of the bin, weighted by the number of predictions in the bin. A perfectly
+/// calibrated classifier has an error of zero.
+///
+/// # Notes
+///
+/// The running value is computed from the bins accumulated over all items, which is not the same
+/// as averaging the error of each batch. The [reliability diagram](ReliabilityDiagram) of each
+/// epoch can be collected with [with_reliability_diagrams](CalibrationMetric::with_reliability_diagrams).
+pub struct CalibrationMetric<B: Backend> {
+    edges: Vec<f64>,
+    bins: Vec<BinStats>,
+    current: f64,
+    epoch: usize,
+    diagrams: Option<ReliabilityDiagrams>,
+    _b: PhantomData<B>,
+}
+
+/// The [calibration metric](CalibrationMetric) input type.
+#[derive(new)]
+pub struct CalibrationInput<B: Backend> {
+    outputs: Tensor<B, 2>,
+    targets: Tensor<B, 1, Int>,
+}
+
+/// The statistics of the predictions of a confidence bin.
+#[derive(Default, Clone, Copy)]
+struct BinStats {
+    count: usize,
+    sum_confidences: f64,
+    num_correct: usize,
+}
+
+/// A bin of a [reliability diagram](ReliabilityDiagram).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReliabilityBin {
+    /// The lower bound of the confidences of the bin, inclusive.
+    pub lower: f64,
+    /// The upper bound of the confidences of the bin, exclusive except for the last bin.
+    pub upper: f64,
+    /// The number of predictions in the bin.
+    pub count: usize,
+    /// The mean confidence of the predictions in the bin, `NaN` when the bin is empty.
+    pub confidence: f64,
+    /// The accuracy of the predictions in the bin, `NaN` when the bin is empty.
+    pub accuracy: f64,
+}
+
+/// The accuracy against the confidence of the predictions of a classifier over an epoch, which
+/// lies on the diagonal for a perfectly calibrated classifier.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReliabilityDiagram {
+    /// The epoch.
+    pub epoch: usize,
+    /// The bins, by increasing confidence.
+    pub bins: Vec<ReliabilityBin>,
+    /// The expected calibration error of the epoch.
+    pub expected_calibration_error: f64,
+}
+
+/// Shared collection of the [reliability diagrams](ReliabilityDiagram) of a
+/// [calibration metric](CalibrationMetric), one per epoch.
+///
+/// The metric is owned by the learner, so a clone of the collection is kept to read the diagrams
+/// during or after the training.
+#[derive(Clone, Default)]
+pub struct ReliabilityDiagrams {
+    diagrams: Arc<Mutex<Vec<ReliabilityDiagram>>>,
+}
+
+impl ReliabilityDiagrams {
+    /// Creates an empty collection.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The diagrams collected so far, in the order of the epochs.
+    pub fn diagrams(&self) -> Vec<ReliabilityDiagram> {
+        self.diagrams.lock().unwrap().clone()
+    }
+
+    /// The diagram of the last completed epoch, if any.
+    pub fn last(&self) -> Option<ReliabilityDiagram> {
+        self.diagrams.lock().unwrap().last().cloned()
+    }
+
+    fn push(&self, diagram: ReliabilityDiagram) {
+        self.diagrams.lock().unwrap().push(diagram);
+    }
+}
+
+impl<B: Backend> CalibrationMetric<B> {
+    /// Creates the metric with 15 bins of the same width.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Use the given number of bins of the same width.
+    pub fn with_num_bins(self, num_bins: usize) -> Self {
+        assert!(num_bins > 0, "At least one bin is required");
+
+        let edges = (0..=num_bins).map(|i| i as f64 / num_bins as f64).collect();
+        self.with_bin_edges(edges)
+    }
+
+    /// Use the bins delimited by the given increasing edges, from 0 to 1.
+    ///
+    /// Finer bins can be used for high confidences, where most predictions of an accurate
+    /// classifier lie.
+    pub fn with_bin_edges(mut self, edges: Vec<f64>) -> Self {
+        assert!(edges.len() >= 2, "At least two edges are required");
+        assert!(
+            edges[0] == 0.0 && edges[edges.len() - 1] == 1.0,
+            "The edges must cover the confidences from 0 to 1"
+        );
+        assert!(
+            edges.windows(2).all(|window| window[0] < window[1]),
+            "The edges must be increasing"
+        );
+
+        self.bins = vec![BinStats::default(); edges.len() - 1];
+        self.edges = edges;
+        self
+    }
+
+    /// Collect the [reliability diagram](ReliabilityDiagram) of each epoch in the given
+    /// collection, when the metric is cleared at the end of the epoch.
+    pub fn with_reliability_diagrams(mut self, diagrams: ReliabilityDiagrams) -> Self {
+        self.diagrams = Some(diagrams);
+        self
+    }
+
+    /// The expected calibration error over all items seen since the last clear.
+    pub fn running_value(&self) -> f64 {
+        expected_calibration_error(&self.bins)
+    }
+
+    /// The reliability diagram of all items seen since the last clear.
+    pub fn reliability_diagram(&self) -> ReliabilityDiagram {
+        let bins = self
+            .bins
+            .iter()
+            .enumerate()
+            .map(|(i, bin)| ReliabilityBin {
+                lower: self.edges[i],
+                upper: self.edges[i + 1],
+                count: bin.count,
+                confidence: bin.sum_confidences / bin.count as f64,
+                accuracy: bin.num_correct as f64 / bin.count as f64,
+            })
+            .collect();
+
+        ReliabilityDiagram {
+            epoch: self.epoch,
+            bins,
+            expected_calibration_error: self.running_value(),
+        }
+    }
+
+    fn bin_index(&self, confidence: f64) -> usize {
+        // The first edge is always zero.
+        let index = self.edges[1..].partition_point(|edge| *edge <= confidence);
+        // A confidence of one belongs to the last bin.
+        index.min(self.bins.len() - 1)
+    }
+}
+
+impl<B: Backend> Default for CalibrationMetric<B> {
+    fn default() -> Self {
+        Self {
+            edges: Vec::new(),
+            bins: Vec::new(),
+            current: f64::NAN,
+            epoch: 0,
+            diagrams: None,
+            _b: PhantomData,
+        }
+        .with_num_bins(15)
+    }
+}
+
+fn expected_calibration_error(bins: &[BinStats]) -> f64 {
+    let count: usize = bins.iter().map(|bin| bin.count).sum();
+
+    if count == 0 {
+        return f64::NAN;
+    }
+
+    bins.iter()
+        .filter(|bin| bin.count > 0)
+        .map(|bin| (bin.num_correct as f64 - bin.sum_confidences).abs())
+        .sum::<f64>()
+        / count as f64
+}
+
+impl<B: Backend> Metric for CalibrationMetric<B> {
+    const NAME: &'static str = "Expected Calibration Error";
+
+    type Input = CalibrationInput<B>;
+
+    fn update(&mut self, input: &CalibrationInput<B>, metadata: &MetricMetadata) -> MetricEntry {
+        let [batch_size, _num_classes] = input.outputs.dims();
+        let probabilities = softmax(input.outputs.clone(), 1);
+        let predictions = probabilities.clone().argmax(1).squeeze(1);
+        let correct = predictions.equal(input.targets.clone());
+
+        let [confidences, correct] = Transaction::default()
+            .register(probabilities.max_dim(1).squeeze::<1>(1))
+            .register(correct)
+            .execute()
+            .try_into()
+            .expect("Correct amount of tensor data");
+
+        let mut batch = vec![BinStats::default(); self.bins.len()];
+        for (confidence, correct) in confidences.iter::<f64>().zip(correct.iter::<bool>()) {
+            let bin = &mut batch[self.bin_index(confidence)];
+            bin.count += 1;
+            bin.sum_confidences += confidence;
+            bin.num_correct += correct as usize;
+        }
+
+        self.current = expected_calibration_error(&batch);
+        self.epoch = metadata.epoch;
+        for (bin, batch) in self.bins.iter_mut().zip(batch) {
+            bin.count += batch.count;
+            bin.sum_confidences += batch.sum_confidences;
+            bin.num_correct += batch.num_correct;
+        }
+
+        let format = FormatOptions::new(Self::NAME).precision(4);
+        let formatted = format_running(self.current, self.running_value(), &format);
+        let serialized = NumericEntry::Aggregated(self.current, batch_size).serialize();
+
+        MetricEntry::new(format.name, formatted, serialized)
+    }
+
+    fn clear(&mut self) {
+        let has_items = self.bins.iter().any(|bin| bin.count > 0);
+        if let (Some(diagrams), true) = (&self.diagrams, has_items) {
+            diagrams.push(self.reliability_diagram());
+        }
+
+        self.bins = vec![BinStats::default(); self.bins.len()];
+        self.current = f64::NAN;
+    }
+}
+
+impl<B: Backend> Numeric for CalibrationMetric<B> {
+    fn value(&self) -> f64 {
+        self.current
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn test_calibration_error() {
+        let device = Default::default();
+        let diagrams = ReliabilityDiagrams::new();
+        let mut metric = CalibrationMetric::<TestBackend>::new()
+            .with_num_bins(2)
+            .with_reliability_diagrams(diagrams.clone());
+
+        // All the predictions have the same confidence, with half of them being correct.
+        let outputs = Tensor::from_data([[1.0, 0.0], [0.0, 1.0], [1.0, 0.0], [0.0, 1.0]], &device);
+        let targets = Tensor::from_data([0, 1, 1, 0], &device);
+        let _entry = metric.update(
+            &CalibrationInput::new(outputs, targets),
+            &MetricMetadata::fake(),
+        );
+
+        let confidence = 1.0 / (1.0 + (-1.0f64).exp());
+        assert!((metric.value() - (confidence - 0.5)).abs() < 1e-6);
+
+        metric.clear();
+        let diagram = diagrams.last().unwrap();
+        assert_eq!(diagram.bins.len(), 2);
+        assert_eq!(diagram.bins[0].count, 0);
+        assert_eq!(diagram.bins[1].count, 4);
+        assert!((diagram.bins[1].accuracy - 0.5).abs() < 1e-6);
+        assert!(metric.running_value().is_nan());
+    }
+}
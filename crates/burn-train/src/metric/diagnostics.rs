@@ -0,0 +1,119 @@
+use super::{
+    state::{FormatOptions, NumericMetricState},
+    MetricMetadata, Numeric,
+};
+use crate::metric::{Metric, MetricEntry};
+
+/// Track the gradient noise scale, sampled by the
+/// [training diagnostics](crate::TrainingDiagnostics).
+///
+/// The last sampled value is reported until the next sample.
+pub struct GradientNoiseScaleMetric {
+    state: NumericMetricState,
+    last: f64,
+}
+
+impl GradientNoiseScaleMetric {
+    /// Creates a new gradient noise scale metric.
+    pub fn new() -> Self {
+        Self {
+            state: NumericMetricState::new(),
+            last: f64::NAN,
+        }
+    }
+}
+
+impl Default for GradientNoiseScaleMetric {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Metric for GradientNoiseScaleMetric {
+    const NAME: &'static str = "Gradient Noise Scale";
+
+    type Input = ();
+
+    fn update(&mut self, _item: &(), metadata: &MetricMetadata) -> MetricEntry {
+        let sample = metadata
+            .diagnostics
+            .as_ref()
+            .and_then(|diagnostics| diagnostics.gradient_noise_scale);
+
+        if let Some(sample) = sample {
+            self.last = sample;
+        }
+
+        self.state
+            .update(self.last, 1, FormatOptions::new(Self::NAME).precision(1))
+    }
+
+    fn clear(&mut self) {
+        self.state.reset()
+    }
+}
+
+impl Numeric for GradientNoiseScaleMetric {
+    fn value(&self) -> f64 {
+        self.state.value()
+    }
+}
+
+/// Track the largest update-to-weight ratio of the parameters, sampled by the
+/// [training diagnostics](crate::TrainingDiagnostics).
+///
+/// The last sampled value is reported until the next sample. The ratio of each parameter is
+/// available in the [metadata](MetricMetadata::diagnostics) of the sampled iterations.
+pub struct UpdateRatioMetric {
+    state: NumericMetricState,
+    last: f64,
+}
+
+impl UpdateRatioMetric {
+    /// Creates a new update ratio metric.
+    pub fn new() -> Self {
+        Self {
+            state: NumericMetricState::new(),
+            last: f64::NAN,
+        }
+    }
+}
+
+impl Default for UpdateRatioMetric {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Metric for UpdateRatioMetric {
+    const NAME: &'static str = "Update Ratio";
+
+    type Input = ();
+
+    fn update(&mut self, _item: &(), metadata: &MetricMetadata) -> MetricEntry {
+        let sample = metadata.diagnostics.as_ref().and_then(|diagnostics| {
+            diagnostics
+                .update_ratios
+                .iter()
+                .map(|update| update.ratio)
+                .reduce(f64::max)
+        });
+
+        if let Some(sample) = sample {
+            self.last = sample;
+        }
+
+        self.state
+            .update(self.last, 1, FormatOptions::new(Self::NAME).precision(5))
+    }
+
+    fn clear(&mut self) {
+        self.state.reset()
+    }
+}
+
+impl Numeric for UpdateRatioMetric {
+    fn value(&self) -> f64 {
+        self.state.value()
+    }
+}
@@ -0,0 +1,208 @@
+use core::marker::PhantomData;
+use std::sync::{Arc, Mutex};
+
+use super::{MetricEntry, MetricMetadata, NumericEntry};
+use crate::learner::{TrainCallback, TrainCallbackContext};
+use crate::metric::{Metric, Numeric};
+use burn_core::data::dataset::audit::TrainingDynamics;
+use burn_core::tensor::activation::softmax;
+use burn_core::tensor::backend::Backend;
+use burn_core::tensor::{Int, Tensor, Transaction};
+
+/// Records the [training dynamics](TrainingDynamics) of each sample: its loss, the confidence of
+/// the model in its label and if it is predicted correctly, once per epoch.
+///
+/// The value of the metric is the number of forgetting events of the epoch, the samples that were
+/// predicted correctly at the previous epoch but not anymore. The statistics of each sample are
+/// kept in a [recorder](TrainingDynamicsRecorder), to rank the samples that are likely mislabeled
+/// or hard to learn during or after the training.
+///
+/// # Notes
+///
+/// The samples are identified by their index in the dataset, so the batches must keep the indices
+/// of their items, for instance by wrapping the dataset in an
+/// [indexed dataset](burn_core::data::dataset::transform::IndexedDataset). The losses must not be
+/// reduced over the batch.
+pub struct TrainingDynamicsMetric<B: Backend> {
+    recorder: TrainingDynamicsRecorder,
+    forgetting_events: usize,
+    _b: PhantomData<B>,
+}
+
+/// The [training dynamics metric](TrainingDynamicsMetric) input type.
+#[derive(new)]
+pub struct TrainingDynamicsInput<B: Backend> {
+    /// The indices of the samples in the dataset.
+    indices: Tensor<B, 1, Int>,
+    /// The loss of each sample.
+    losses: Tensor<B, 1>,
+    /// The logits predicted for each sample.
+    outputs: Tensor<B, 2>,
+    /// The label of each sample.
+    targets: Tensor<B, 1, Int>,
+}
+
+/// Shared [training dynamics](TrainingDynamics) collected by a
+/// [training dynamics metric](TrainingDynamicsMetric).
+///
+/// The metric is owned by the learner, so a clone of the recorder is kept to read the statistics
+/// during or after the training. The recorder is also a [callback](TrainCallback) logging the
+/// samples that are likely mislabeled at the end of each epoch.
+#[derive(Clone)]
+pub struct TrainingDynamicsRecorder {
+    dynamics: Arc<Mutex<TrainingDynamics>>,
+    report_size: usize,
+}
+
+impl Default for TrainingDynamicsRecorder {
+    fn default() -> Self {
+        Self {
+            dynamics: Default::default(),
+            report_size: 10,
+        }
+    }
+}
+
+impl TrainingDynamicsRecorder {
+    /// Creates an empty recorder, logging the ten samples that are the most likely mislabeled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Log the given number of samples at the end of each epoch, none when zero.
+    pub fn with_report_size(mut self, report_size: usize) -> Self {
+        self.report_size = report_size;
+        self
+    }
+
+    /// The training dynamics recorded so far.
+    pub fn dynamics(&self) -> TrainingDynamics {
+        self.dynamics.lock().unwrap().clone()
+    }
+}
+
+impl<M> TrainCallback<M> for TrainingDynamicsRecorder {
+    fn on_valid_end(&mut self, context: &TrainCallbackContext<'_, M>) {
+        let dynamics = self.dynamics.lock().unwrap();
+
+        for report in dynamics.likely_mislabeled(self.report_size) {
+            log::info!(
+                "Epoch {} - Likely mislabeled sample {}: mean confidence {:.3}, variability {:.3}, \
+                 forgetting events {}",
+                context.epoch,
+                report.index,
+                report.mean_confidence,
+                report.variability,
+                report.forgetting_events,
+            );
+        }
+    }
+}
+
+impl<B: Backend> TrainingDynamicsMetric<B> {
+    /// Creates the metric, recording the training dynamics in the given recorder.
+    pub fn new(recorder: TrainingDynamicsRecorder) -> Self {
+        Self {
+            recorder,
+            forgetting_events: 0,
+            _b: PhantomData,
+        }
+    }
+}
+
+impl<B: Backend> Metric for TrainingDynamicsMetric<B> {
+    const NAME: &'static str = "Forgetting Events";
+
+    type Input = TrainingDynamicsInput<B>;
+
+    fn update(&mut self, input: &Self::Input, metadata: &MetricMetadata) -> MetricEntry {
+        let probabilities = softmax(input.outputs.clone(), 1);
+        let predictions = probabilities.clone().argmax(1).squeeze::<1>(1);
+        let correct = predictions.equal(input.targets.clone());
+        let confidences = probabilities
+            .gather(1, input.targets.clone().unsqueeze_dim(1))
+            .squeeze::<1>(1);
+
+        let [indices, losses, confidences, correct] = Transaction::default()
+            .register(input.indices.clone())
+            .register(input.losses.clone())
+            .register(confidences)
+            .register(correct)
+            .execute()
+            .try_into()
+            .expect("Correct amount of tensor data");
+
+        let mut dynamics = self.recorder.dynamics.lock().unwrap();
+        let samples = indices
+            .iter::<i64>()
+            .zip(losses.iter::<f32>())
+            .zip(confidences.iter::<f32>())
+            .zip(correct.iter::<bool>());
+
+        for (((index, loss), confidence), correct) in samples {
+            let index = index as usize;
+            let before = dynamics
+                .sample(index)
+                .map(|sample| sample.forgetting_events())
+                .unwrap_or(0);
+
+            dynamics.record(index, metadata.epoch, loss, confidence, correct);
+
+            let after = dynamics.sample(index).unwrap().forgetting_events();
+            self.forgetting_events += after.saturating_sub(before);
+        }
+
+        let formatted = format!("{}: {}", Self::NAME, self.forgetting_events);
+        let serialized = NumericEntry::Value(self.forgetting_events as f64).serialize();
+
+        MetricEntry::new(Self::NAME.to_string(), formatted, serialized)
+    }
+
+    fn clear(&mut self) {
+        self.forgetting_events = 0;
+    }
+}
+
+impl<B: Backend> Numeric for TrainingDynamicsMetric<B> {
+    fn value(&self) -> f64 {
+        self.forgetting_events as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn test_training_dynamics() {
+        let device = Default::default();
+        let recorder = TrainingDynamicsRecorder::new();
+        let mut metric = TrainingDynamicsMetric::<TestBackend>::new(recorder.clone());
+        let indices = Tensor::from_data([4, 7], &device);
+        let losses = Tensor::from_data([0.1, 2.0], &device);
+        let targets = Tensor::from_data([0, 1], &device);
+        let mut metadata = MetricMetadata::fake();
+
+        // Both samples are predicted as the first class.
+        let outputs = Tensor::from_data([[2.0, 0.0], [3.0, 0.0]], &device);
+        let input =
+            TrainingDynamicsInput::new(indices.clone(), losses.clone(), outputs, targets.clone());
+        let _entry = metric.update(&input, &metadata);
+        assert_eq!(metric.value(), 0.0);
+        metric.clear();
+
+        // Both samples are predicted as the second class, the first one is forgotten.
+        metadata.epoch = 1;
+        let outputs = Tensor::from_data([[0.0, 2.0], [0.0, 1.0]], &device);
+        let input = TrainingDynamicsInput::new(indices, losses, outputs, targets);
+        let _entry = metric.update(&input, &metadata);
+        assert_eq!(metric.value(), 1.0);
+
+        let dynamics = recorder.dynamics();
+        assert_eq!(dynamics.len(), 2);
+        assert_eq!(dynamics.report(4).unwrap().forgetting_events, 1);
+        assert_eq!(dynamics.sample(7).unwrap().correct, vec![false, true]);
+        assert_eq!(dynamics.likely_mislabeled(1)[0].index, 7);
+    }
+}
@@ -19,6 +19,19 @@ pub struct LossInput<B: Backend> {
     tensor: Tensor<B, 1>,
 }
 
+impl<B: Backend> LossInput<B> {
+    /// The mean of the loss.
+    pub(crate) fn mean(&self) -> f64 {
+        self.tensor
+            .clone()
+            .mean()
+            .into_data()
+            .iter::<f64>()
+            .next()
+            .unwrap()
+    }
+}
+
 impl<B: Backend> LossMetric<B> {
     /// Create the metric.
     pub fn new() -> Self {
@@ -33,14 +46,7 @@ impl<B: Backend> Metric for LossMetric<B> {
 
     fn update(&mut self, loss: &Self::Input, _metadata: &MetricMetadata) -> MetricEntry {
         let [batch_size] = loss.tensor.dims();
-        let loss = loss
-            .tensor
-            .clone()
-            .mean()
-            .into_data()
-            .iter::<f64>()
-            .next()
-            .unwrap();
+        let loss = loss.mean();
 
         self.state.update(
             loss,
@@ -0,0 +1,79 @@
+use core::marker::PhantomData;
+
+use super::state::{FormatOptions, NumericMetricState};
+use super::{MetricEntry, MetricMetadata};
+use crate::metric::{Metric, Numeric};
+use burn_core::tensor::backend::Backend;
+use burn_core::tensor::{ElementConversion, Tensor};
+
+/// The mean absolute error metric.
+#[derive(Default)]
+pub struct MaeMetric<B: Backend> {
+    state: NumericMetricState,
+    _b: PhantomData<B>,
+}
+
+/// The [mean absolute error metric](MaeMetric) input type.
+#[derive(new)]
+pub struct MaeInput<B: Backend> {
+    outputs: Tensor<B, 2>,
+    targets: Tensor<B, 2>,
+}
+
+impl<B: Backend> MaeMetric<B> {
+    /// Creates the metric.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<B: Backend> Metric for MaeMetric<B> {
+    const NAME: &'static str = "MAE";
+
+    type Input = MaeInput<B>;
+
+    fn update(&mut self, input: &MaeInput<B>, _metadata: &MetricMetadata) -> MetricEntry {
+        let [batch_size, _n_outputs] = input.outputs.dims();
+
+        // Every item has the same number of outputs, so the running weighted mean over the
+        // batches is the mean over all items.
+        let mae = (input.outputs.clone() - input.targets.clone())
+            .abs()
+            .mean()
+            .into_scalar()
+            .elem::<f64>();
+
+        self.state
+            .update(mae, batch_size, FormatOptions::new(Self::NAME).precision(4))
+    }
+
+    fn clear(&mut self) {
+        self.state.reset()
+    }
+}
+
+impl<B: Backend> Numeric for MaeMetric<B> {
+    fn value(&self) -> f64 {
+        self.state.value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn test_mae() {
+        let device = Default::default();
+        let mut metric = MaeMetric::<TestBackend>::new();
+
+        let outputs = Tensor::from_data([[1.0, 2.0], [3.0, 4.0]], &device);
+        let targets = Tensor::from_data([[1.5, 2.0], [2.0, 6.0]], &device);
+
+        let _entry = metric.update(&MaeInput::new(outputs, targets), &MetricMetadata::fake());
+
+        // (0.5 + 0.0 + 1.0 + 2.0) / 4
+        assert_eq!(0.875, metric.value());
+    }
+}
@@ -25,27 +25,39 @@ pub use memory_use::*;
 mod acc;
 mod auroc;
 mod base;
+mod calibration;
 mod confusion_stats;
+mod diagnostics;
+mod dynamics;
 mod fbetascore;
 mod hamming;
 mod iteration;
 mod learning_rate;
 mod loss;
+mod mae;
 mod precision;
+mod r2;
 mod recall;
+mod rmse;
 mod top_k_acc;
 
 pub use acc::*;
 pub use auroc::*;
 pub use base::*;
+pub use calibration::*;
 pub use confusion_stats::ConfusionStatsInput;
+pub use diagnostics::*;
+pub use dynamics::*;
 pub use fbetascore::*;
 pub use hamming::*;
 pub use iteration::*;
 pub use learning_rate::*;
 pub use loss::*;
+pub use mae::*;
 pub use precision::*;
+pub use r2::*;
 pub use recall::*;
+pub use rmse::*;
 pub use top_k_acc::*;
 
 pub(crate) mod classification;
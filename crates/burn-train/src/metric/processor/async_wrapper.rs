@@ -1,33 +1,43 @@
-use super::{Event, EventProcessor};
+use super::{Event, EventProcessor, ItemLazy, LearnerItem, SyncedEventProcessor};
 use async_channel::{Receiver, Sender};
+use burn_core::tensor::try_read_sync;
+use std::future::Future;
+use std::pin::Pin;
 
-pub struct AsyncProcessor<P: EventProcessor> {
+/// The number of items that can be read while the previous ones are processed, before the
+/// training loop waits for the metrics.
+const MAX_PENDING_ITEMS: usize = 4;
+
+type PendingItem<T> = Pin<Box<dyn Future<Output = LearnerItem<T>> + Send>>;
+type ItemSync<I> = <I as ItemLazy>::ItemSync;
+
+pub struct AsyncProcessor<P: SyncedEventProcessor> {
     sender: Sender<Message<P>>,
 }
 
-struct Worker<P: EventProcessor> {
+struct Worker<P: SyncedEventProcessor> {
     processor: P,
     rec: Receiver<Message<P>>,
 }
 
-impl<P: EventProcessor + 'static> Worker<P> {
+impl<P: SyncedEventProcessor + 'static> Worker<P> {
     pub fn start(processor: P, rec: Receiver<Message<P>>) {
         let mut worker = Self { processor, rec };
 
         std::thread::spawn(move || {
             while let Ok(msg) = worker.rec.recv_blocking() {
                 match msg {
-                    Message::Train(event) => worker.processor.process_train(event),
-                    Message::Valid(event) => worker.processor.process_valid(event),
+                    Message::Train(event) => worker.processor.process_train_synced(event.read()),
+                    Message::Valid(event) => worker.processor.process_valid_synced(event.read()),
                 }
             }
         });
     }
 }
 
-impl<P: EventProcessor + 'static> AsyncProcessor<P> {
+impl<P: SyncedEventProcessor + 'static> AsyncProcessor<P> {
     pub fn new(processor: P) -> Self {
-        let (sender, rec) = async_channel::bounded(1);
+        let (sender, rec) = async_channel::bounded(MAX_PENDING_ITEMS);
 
         Worker::start(processor, rec);
 
@@ -36,19 +46,47 @@ impl<P: EventProcessor + 'static> AsyncProcessor<P> {
 }
 
 enum Message<P: EventProcessor> {
-    Train(Event<P::ItemTrain>),
-    Valid(Event<P::ItemValid>),
+    Train(PendingEvent<ItemSync<P::ItemTrain>>),
+    Valid(PendingEvent<ItemSync<P::ItemValid>>),
+}
+
+/// An event whose item is being read.
+enum PendingEvent<T> {
+    ProcessedItem(PendingItem<T>),
+    EndEpoch(usize),
+}
+
+impl<T> PendingEvent<T> {
+    fn new<I: ItemLazy<ItemSync = T>>(event: Event<I>) -> Self {
+        match event {
+            Event::ProcessedItem(item) => Self::ProcessedItem(Box::pin(item.sync_async())),
+            Event::EndEpoch(epoch) => Self::EndEpoch(epoch),
+        }
+    }
+
+    fn read(self) -> Event<T> {
+        match self {
+            Self::ProcessedItem(item) => Event::ProcessedItem(
+                try_read_sync(item).expect("Failed to read the item of the event synchronously"),
+            ),
+            Self::EndEpoch(epoch) => Event::EndEpoch(epoch),
+        }
+    }
 }
 
-impl<P: EventProcessor> EventProcessor for AsyncProcessor<P> {
+impl<P: SyncedEventProcessor> EventProcessor for AsyncProcessor<P> {
     type ItemTrain = P::ItemTrain;
     type ItemValid = P::ItemValid;
 
     fn process_train(&mut self, event: Event<Self::ItemTrain>) {
-        self.sender.send_blocking(Message::Train(event)).unwrap();
+        self.sender
+            .send_blocking(Message::Train(PendingEvent::new(event)))
+            .unwrap();
     }
 
     fn process_valid(&mut self, event: Event<Self::ItemValid>) {
-        self.sender.send_blocking(Message::Valid(event)).unwrap();
+        self.sender
+            .send_blocking(Message::Valid(PendingEvent::new(event)))
+            .unwrap();
     }
 }
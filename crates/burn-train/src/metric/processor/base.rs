@@ -1,5 +1,8 @@
 use burn_core::data::dataloader::Progress;
 use burn_core::LearningRate;
+use std::future::Future;
+
+use crate::TrainingDiagnostics;
 
 /// Event happening during the training/validation process.
 pub enum Event<T> {
@@ -9,15 +12,39 @@ pub enum Event<T> {
     EndEpoch(usize),
 }
 
+impl<T: ItemLazy> Event<T> {
+    /// Sync the item of the event.
+    pub(crate) fn sync(self) -> Event<T::ItemSync> {
+        match self {
+            Event::ProcessedItem(item) => Event::ProcessedItem(item.sync()),
+            Event::EndEpoch(epoch) => Event::EndEpoch(epoch),
+        }
+    }
+}
+
 /// Items that are lazy are not ready to be processed by metrics.
 ///
 /// We want to sync them on a different thread to avoid blocking training.
-pub trait ItemLazy: Send {
+pub trait ItemLazy: Send + 'static {
     /// Item that is properly synced and ready to be processed by metrics.
     type ItemSync: Send;
 
     /// Sync the item.
     fn sync(self) -> Self::ItemSync;
+
+    /// Sync the item without blocking.
+    ///
+    /// The future is created on the training thread and awaited by the
+    /// [async processor](super::AsyncProcessor) on its own thread, so the training loop doesn't
+    /// wait for the tensors to be read. The default implementation syncs the item when the future
+    /// is polled, items made of tensors should read them with
+    /// [Transaction::execute_async](burn_core::tensor::Transaction::execute_async).
+    fn sync_async(self) -> impl Future<Output = Self::ItemSync> + Send + 'static
+    where
+        Self: Sized,
+    {
+        async move { self.sync() }
+    }
 }
 
 /// Process events happening during training and validation.
@@ -33,6 +60,18 @@ pub trait EventProcessor: Send {
     fn process_valid(&mut self, event: Event<Self::ItemValid>);
 }
 
+/// An [event processor](EventProcessor) that can process the events of items already
+/// [synced](ItemLazy::sync).
+///
+/// This lets the [async processor](super::AsyncProcessor) start reading the items on the training
+/// thread and process them once read on its own thread.
+pub trait SyncedEventProcessor: EventProcessor {
+    /// Collect a training event with a synced item.
+    fn process_train_synced(&mut self, event: Event<<Self::ItemTrain as ItemLazy>::ItemSync>);
+    /// Collect a validation event with a synced item.
+    fn process_valid_synced(&mut self, event: Event<<Self::ItemValid as ItemLazy>::ItemSync>);
+}
+
 /// A learner item.
 #[derive(new)]
 pub struct LearnerItem<T> {
@@ -53,6 +92,10 @@ pub struct LearnerItem<T> {
 
     /// The learning rate.
     pub lr: Option<LearningRate>,
+
+    /// The training diagnostics sampled at this iteration.
+    #[new(default)]
+    pub diagnostics: Option<TrainingDiagnostics>,
 }
 
 impl<T: ItemLazy> ItemLazy for LearnerItem<T> {
@@ -66,6 +109,32 @@ impl<T: ItemLazy> ItemLazy for LearnerItem<T> {
             epoch_total: self.epoch_total,
             iteration: self.iteration,
             lr: self.lr,
+            diagnostics: self.diagnostics,
+        }
+    }
+
+    fn sync_async(self) -> impl Future<Output = Self::ItemSync> + Send + 'static {
+        let LearnerItem {
+            item,
+            progress,
+            epoch,
+            epoch_total,
+            iteration,
+            lr,
+            diagnostics,
+        } = self;
+        let item = item.sync_async();
+
+        async move {
+            LearnerItem {
+                item: item.await,
+                progress,
+                epoch,
+                epoch_total,
+                iteration,
+                lr,
+                diagnostics,
+            }
         }
     }
 }
@@ -1,4 +1,4 @@
-use super::{Event, EventProcessor, ItemLazy, Metrics};
+use super::{Event, EventProcessor, ItemLazy, Metrics, SyncedEventProcessor};
 use crate::metric::store::EventStoreClient;
 use crate::renderer::{MetricState, MetricsRenderer};
 use std::sync::Arc;
@@ -31,9 +31,18 @@ impl<T: ItemLazy, V: ItemLazy> EventProcessor for FullEventProcessor<T, V> {
     type ItemValid = V;
 
     fn process_train(&mut self, event: Event<Self::ItemTrain>) {
+        self.process_train_synced(event.sync());
+    }
+
+    fn process_valid(&mut self, event: Event<Self::ItemValid>) {
+        self.process_valid_synced(event.sync());
+    }
+}
+
+impl<T: ItemLazy, V: ItemLazy> SyncedEventProcessor for FullEventProcessor<T, V> {
+    fn process_train_synced(&mut self, event: Event<T::ItemSync>) {
         match event {
             Event::ProcessedItem(item) => {
-                let item = item.sync();
                 let progress = (&item).into();
                 let metadata = (&item).into();
 
@@ -65,10 +74,9 @@ impl<T: ItemLazy, V: ItemLazy> EventProcessor for FullEventProcessor<T, V> {
         }
     }
 
-    fn process_valid(&mut self, event: Event<Self::ItemValid>) {
+    fn process_valid_synced(&mut self, event: Event<V::ItemSync>) {
         match event {
             Event::ProcessedItem(item) => {
-                let item = item.sync();
                 let progress = (&item).into();
                 let metadata = (&item).into();
 
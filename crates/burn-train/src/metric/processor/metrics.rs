@@ -147,6 +147,7 @@ impl<T> From<&LearnerItem<T>> for MetricMetadata {
             epoch_total: item.epoch_total,
             iteration: item.iteration,
             lr: item.lr,
+            diagnostics: item.diagnostics.clone(),
         }
     }
 }
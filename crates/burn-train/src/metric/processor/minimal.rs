@@ -1,4 +1,4 @@
-use super::{Event, EventProcessor, ItemLazy, Metrics};
+use super::{Event, EventProcessor, ItemLazy, Metrics, SyncedEventProcessor};
 use crate::metric::store::EventStoreClient;
 use std::sync::Arc;
 
@@ -15,9 +15,18 @@ impl<T: ItemLazy, V: ItemLazy> EventProcessor for MinimalEventProcessor<T, V> {
     type ItemValid = V;
 
     fn process_train(&mut self, event: Event<Self::ItemTrain>) {
+        self.process_train_synced(event.sync());
+    }
+
+    fn process_valid(&mut self, event: Event<Self::ItemValid>) {
+        self.process_valid_synced(event.sync());
+    }
+}
+
+impl<T: ItemLazy, V: ItemLazy> SyncedEventProcessor for MinimalEventProcessor<T, V> {
+    fn process_train_synced(&mut self, event: Event<T::ItemSync>) {
         match event {
             Event::ProcessedItem(item) => {
-                let item = item.sync();
                 let metadata = (&item).into();
 
                 let update = self.metrics.update_train(&item, &metadata);
@@ -33,10 +42,9 @@ impl<T: ItemLazy, V: ItemLazy> EventProcessor for MinimalEventProcessor<T, V> {
         }
     }
 
-    fn process_valid(&mut self, event: Event<Self::ItemValid>) {
+    fn process_valid_synced(&mut self, event: Event<V::ItemSync>) {
         match event {
             Event::ProcessedItem(item) => {
-                let item = item.sync();
                 let metadata = (&item).into();
 
                 let update = self.metrics.update_valid(&item, &metadata);
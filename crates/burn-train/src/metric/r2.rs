@@ -0,0 +1,202 @@
+use core::marker::PhantomData;
+
+use super::state::{format_running, FormatOptions};
+use super::{MetricEntry, MetricMetadata, NumericEntry};
+use crate::metric::{Metric, Numeric};
+use burn_core::tensor::backend::Backend;
+use burn_core::tensor::{Tensor, TensorData, Transaction};
+
+/// The coefficient of determination (R²) metric.
+///
+/// When the model has multiple outputs, the score of each output is computed independently and
+/// averaged uniformly.
+///
+/// # Notes
+///
+/// The running value, which is the value of the epoch, is computed from the sums of the targets,
+/// of their squares and of the squared residuals accumulated over all items, which is not the
+/// same as averaging the score of each batch.
+pub struct R2Metric<B: Backend> {
+    stats: R2Stats,
+    current: f64,
+    _b: PhantomData<B>,
+}
+
+/// The [coefficient of determination metric](R2Metric) input type.
+#[derive(new)]
+pub struct R2Input<B: Backend> {
+    outputs: Tensor<B, 2>,
+    targets: Tensor<B, 2>,
+}
+
+/// Per output sufficient statistics to compute the R² score.
+#[derive(Default, Clone)]
+struct R2Stats {
+    count: usize,
+    sum_targets: Vec<f64>,
+    sum_targets_squared: Vec<f64>,
+    sum_squared_residuals: Vec<f64>,
+}
+
+impl R2Stats {
+    fn add(&mut self, other: &R2Stats) {
+        if self.count == 0 {
+            *self = other.clone();
+            return;
+        }
+
+        self.count += other.count;
+        add_assign(&mut self.sum_targets, &other.sum_targets);
+        add_assign(&mut self.sum_targets_squared, &other.sum_targets_squared);
+        add_assign(
+            &mut self.sum_squared_residuals,
+            &other.sum_squared_residuals,
+        );
+    }
+
+    fn score(&self) -> f64 {
+        let n = self.count as f64;
+        let num_outputs = self.sum_targets.len();
+
+        let total = (0..num_outputs)
+            .map(|i| {
+                let sum = self.sum_targets[i];
+                let total_sum_squares = self.sum_targets_squared[i] - sum * sum / n;
+                let residual_sum_squares = self.sum_squared_residuals[i];
+
+                if total_sum_squares == 0.0 {
+                    // Constant targets: perfect predictions score 1, anything else 0.
+                    match residual_sum_squares == 0.0 {
+                        true => 1.0,
+                        false => 0.0,
+                    }
+                } else {
+                    1.0 - residual_sum_squares / total_sum_squares
+                }
+            })
+            .sum::<f64>();
+
+        total / num_outputs as f64
+    }
+}
+
+fn add_assign(lhs: &mut [f64], rhs: &[f64]) {
+    lhs.iter_mut().zip(rhs).for_each(|(a, b)| *a += b);
+}
+
+fn to_vec(data: TensorData) -> Vec<f64> {
+    data.iter::<f64>().collect()
+}
+
+impl<B: Backend> R2Metric<B> {
+    /// Creates the metric.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The R² score over all items seen since the last clear.
+    pub fn running_value(&self) -> f64 {
+        match self.stats.count {
+            0 => f64::NAN,
+            _ => self.stats.score(),
+        }
+    }
+}
+
+impl<B: Backend> Default for R2Metric<B> {
+    fn default() -> Self {
+        Self {
+            stats: R2Stats::default(),
+            current: f64::NAN,
+            _b: PhantomData,
+        }
+    }
+}
+
+impl<B: Backend> Metric for R2Metric<B> {
+    const NAME: &'static str = "R²";
+
+    type Input = R2Input<B>;
+
+    fn update(&mut self, input: &R2Input<B>, _metadata: &MetricMetadata) -> MetricEntry {
+        let [batch_size, _n_outputs] = input.outputs.dims();
+        let targets = input.targets.clone();
+        let residuals = input.outputs.clone() - targets.clone();
+
+        let [sum_targets, sum_targets_squared, sum_squared_residuals] = Transaction::default()
+            .register(targets.clone().sum_dim(0))
+            .register(targets.powi_scalar(2).sum_dim(0))
+            .register(residuals.powi_scalar(2).sum_dim(0))
+            .execute()
+            .try_into()
+            .expect("Correct amount of tensor data");
+
+        let batch = R2Stats {
+            count: batch_size,
+            sum_targets: to_vec(sum_targets),
+            sum_targets_squared: to_vec(sum_targets_squared),
+            sum_squared_residuals: to_vec(sum_squared_residuals),
+        };
+
+        self.current = batch.score();
+        self.stats.add(&batch);
+
+        let format = FormatOptions::new(Self::NAME).precision(4);
+        let formatted = format_running(self.current, self.running_value(), &format);
+        let serialized =
+            NumericEntry::Running(self.current, batch_size, self.running_value()).serialize();
+
+        MetricEntry::new(format.name, formatted, serialized)
+    }
+
+    fn clear(&mut self) {
+        self.stats = R2Stats::default();
+        self.current = f64::NAN;
+    }
+}
+
+impl<B: Backend> Numeric for R2Metric<B> {
+    fn value(&self) -> f64 {
+        self.current
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn test_r2_perfect_predictions() {
+        let device = Default::default();
+        let mut metric = R2Metric::<TestBackend>::new();
+
+        let targets = Tensor::from_data([[1.0], [2.0], [3.0]], &device);
+        let _entry = metric.update(
+            &R2Input::new(targets.clone(), targets),
+            &MetricMetadata::fake(),
+        );
+
+        assert_eq!(1.0, metric.value());
+    }
+
+    #[test]
+    fn test_r2_running_value() {
+        let device = Default::default();
+        let mut metric = R2Metric::<TestBackend>::new();
+
+        let outputs = Tensor::from_data([[2.0], [2.0]], &device);
+        let targets = Tensor::from_data([[1.0], [3.0]], &device);
+        let _entry = metric.update(&R2Input::new(outputs, targets), &MetricMetadata::fake());
+        // Predicting the mean scores 0.
+        assert_eq!(0.0, metric.value());
+
+        let outputs = Tensor::from_data([[5.0], [7.0]], &device);
+        let targets = Tensor::from_data([[5.0], [7.0]], &device);
+        let _entry = metric.update(&R2Input::new(outputs, targets), &MetricMetadata::fake());
+        assert_eq!(1.0, metric.value());
+
+        // Targets [1, 3, 5, 7]: mean 4, total sum of squares 20, residual sum of squares 2.
+        assert_eq!(0.9, metric.running_value());
+    }
+}
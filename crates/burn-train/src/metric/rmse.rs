@@ -0,0 +1,120 @@
+use core::marker::PhantomData;
+
+use super::state::{format_running, FormatOptions};
+use super::{MetricEntry, MetricMetadata, NumericEntry};
+use crate::metric::{Metric, Numeric};
+use burn_core::tensor::backend::Backend;
+use burn_core::tensor::{ElementConversion, Tensor};
+
+/// The root mean squared error metric.
+///
+/// # Notes
+///
+/// The running value, which is the value of the epoch, is computed from the squared errors
+/// accumulated over all items, not as the mean of the batch values, since the square root of a
+/// mean isn't the mean of the square roots.
+pub struct RmseMetric<B: Backend> {
+    sum_squared_error: f64,
+    count: usize,
+    current: f64,
+    _b: PhantomData<B>,
+}
+
+/// The [root mean squared error metric](RmseMetric) input type.
+#[derive(new)]
+pub struct RmseInput<B: Backend> {
+    outputs: Tensor<B, 2>,
+    targets: Tensor<B, 2>,
+}
+
+impl<B: Backend> RmseMetric<B> {
+    /// Creates the metric.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The root mean squared error over all items seen since the last clear.
+    pub fn running_value(&self) -> f64 {
+        (self.sum_squared_error / self.count as f64).sqrt()
+    }
+}
+
+impl<B: Backend> Default for RmseMetric<B> {
+    fn default() -> Self {
+        Self {
+            sum_squared_error: 0.0,
+            count: 0,
+            current: f64::NAN,
+            _b: PhantomData,
+        }
+    }
+}
+
+impl<B: Backend> Metric for RmseMetric<B> {
+    const NAME: &'static str = "RMSE";
+
+    type Input = RmseInput<B>;
+
+    fn update(&mut self, input: &RmseInput<B>, _metadata: &MetricMetadata) -> MetricEntry {
+        let [batch_size, n_outputs] = input.outputs.dims();
+        let num_elements = batch_size * n_outputs;
+
+        let sum_squared_error = (input.outputs.clone() - input.targets.clone())
+            .powi_scalar(2)
+            .sum()
+            .into_scalar()
+            .elem::<f64>();
+
+        self.sum_squared_error += sum_squared_error;
+        self.count += num_elements;
+        self.current = (sum_squared_error / num_elements as f64).sqrt();
+
+        let format = FormatOptions::new(Self::NAME).precision(4);
+        let formatted = format_running(self.current, self.running_value(), &format);
+        let serialized =
+            NumericEntry::Running(self.current, batch_size, self.running_value()).serialize();
+
+        MetricEntry::new(format.name, formatted, serialized)
+    }
+
+    fn clear(&mut self) {
+        self.sum_squared_error = 0.0;
+        self.count = 0;
+        self.current = f64::NAN;
+    }
+}
+
+impl<B: Backend> Numeric for RmseMetric<B> {
+    fn value(&self) -> f64 {
+        self.current
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn test_rmse() {
+        let device = Default::default();
+        let mut metric = RmseMetric::<TestBackend>::new();
+
+        let outputs = Tensor::from_data([[1.0], [2.0]], &device);
+        let targets = Tensor::from_data([[4.0], [6.0]], &device);
+        let _entry = metric.update(&RmseInput::new(outputs, targets), &MetricMetadata::fake());
+        // sqrt((9 + 16) / 2)
+        assert_eq!((12.5f64).sqrt(), metric.value());
+
+        let outputs = Tensor::from_data([[0.0], [0.0]], &device);
+        let targets = Tensor::from_data([[0.0], [2.0]], &device);
+        let entry = metric.update(&RmseInput::new(outputs, targets), &MetricMetadata::fake());
+        // sqrt(4 / 2) for the batch, sqrt(29 / 4) for all items seen so far.
+        assert_eq!((2.0f64).sqrt(), metric.value());
+        assert_eq!((7.25f64).sqrt(), metric.running_value());
+        assert_eq!(
+            entry.serialize,
+            NumericEntry::Running((2.0f64).sqrt(), 2, (7.25f64).sqrt()).serialize()
+        );
+    }
+}
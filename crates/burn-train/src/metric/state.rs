@@ -14,7 +14,7 @@ pub struct NumericMetricState {
 
 /// Formatting options for the [numeric metric state](NumericMetricState).
 pub struct FormatOptions {
-    name: String,
+    pub(crate) name: String,
     unit: Option<String>,
     precision: Option<usize>,
 }
@@ -69,23 +69,27 @@ impl NumericMetricState {
         let value_running = self.sum / self.count as f64;
         // Numeric metric state is an aggregated value
         let serialized = NumericEntry::Aggregated(value_current, batch_size).serialize();
+        let formatted = format_running(value_current, value_running, &format);
 
-        let (formatted_current, formatted_running) = match format.precision {
-            Some(precision) => (
-                format_float(value_current, precision),
-                format_float(value_running, precision),
-            ),
-            None => (format!("{value_current}"), format!("{value_running}")),
-        };
+        MetricEntry::new(format.name, formatted, serialized)
+    }
+}
 
-        let formatted = match format.unit {
-            Some(unit) => {
-                format!("epoch {formatted_running} {unit} - batch {formatted_current} {unit}")
-            }
-            None => format!("epoch {formatted_running} - batch {formatted_current}"),
-        };
+/// Format the current and running values of a numeric metric.
+pub(crate) fn format_running(current: f64, running: f64, format: &FormatOptions) -> String {
+    let (formatted_current, formatted_running) = match format.precision {
+        Some(precision) => (
+            format_float(current, precision),
+            format_float(running, precision),
+        ),
+        None => (format!("{current}"), format!("{running}")),
+    };
 
-        MetricEntry::new(format.name, formatted, serialized)
+    match &format.unit {
+        Some(unit) => {
+            format!("epoch {formatted_running} {unit} - batch {formatted_current} {unit}")
+        }
+        None => format!("epoch {formatted_running} - batch {formatted_current}"),
     }
 }
 
@@ -48,6 +48,13 @@ impl NumericMetricsAggregate {
             return None;
         }
 
+        // Metrics that can't be averaged over batches log their value over the epoch
+        if let Some(NumericEntry::Running(_, _, value)) = points.last() {
+            let value = *value;
+            self.value_for_each_epoch.insert(key, value);
+            return Some(value);
+        }
+
         // Accurately compute the aggregated value based on the *actual* number of points
         // since not all mini-batches are guaranteed to have the specified batch size
         let (sum, num_points) = points
@@ -56,7 +63,9 @@ impl NumericMetricsAggregate {
                 NumericEntry::Value(v) => (v, 1),
                 // Right now the mean is the only aggregate available, so we can assume that the sum
                 // of an entry corresponds to (value * number of elements)
-                NumericEntry::Aggregated(v, n) => (v * n as f64, n),
+                NumericEntry::Aggregated(v, n) | NumericEntry::Running(v, n, _) => {
+                    (v * n as f64, n)
+                }
             })
             .reduce(|(acc_v, acc_n), (v, n)| (acc_v + v, acc_n + n))
             .unwrap();
@@ -202,4 +211,30 @@ mod tests {
         // Average should be (0.5 + 1.25 * 2) / 3 = 1.0, not (0.5 + 1.25) / 2 = 0.875
         assert_eq!(value, 1.0);
     }
+
+    #[test]
+    fn should_aggregate_running_numeric_entry() {
+        let mut logger = InMemoryMetricLogger::default();
+        let mut aggregate = NumericMetricsAggregate::default();
+        let metric_name = "RMSE";
+
+        // Batch values sqrt(12.5) and sqrt(2), epoch value sqrt(29 / 4)
+        for (value, running) in [
+            (12.5f64.sqrt(), 12.5f64.sqrt()),
+            (2f64.sqrt(), 7.25f64.sqrt()),
+        ] {
+            let entry = MetricEntry::new(
+                metric_name.to_string(),
+                value.to_string(),
+                NumericEntry::Running(value, 2, running).serialize(),
+            );
+            logger.log(&entry);
+        }
+
+        let value = aggregate
+            .aggregate(metric_name, 1, Aggregate::Mean, &mut [Box::new(logger)])
+            .unwrap();
+
+        assert_eq!(value, 7.25f64.sqrt());
+    }
 }
@@ -6,6 +6,9 @@ pub use base::*;
 
 mod cli;
 
+mod plain;
+pub use plain::*;
+
 /// The tui renderer
 #[cfg(feature = "tui")]
 pub mod tui;
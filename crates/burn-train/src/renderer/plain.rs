@@ -0,0 +1,229 @@
+use crate::renderer::{MetricState, MetricsRenderer, TrainingProgress};
+use serde::Serialize;
+use std::io::Write;
+use std::time::{Duration, Instant};
+
+const DEFAULT_INTERVAL_MILLIS: u64 = 1000;
+
+/// A metrics renderer printing the progress as plain text lines, with an optional
+/// machine-readable [JSON Lines](https://jsonlines.org) stream.
+///
+/// Unlike the terminal UI, it works in notebooks, in CI and when the output is piped to a file.
+/// The progress is rendered at most once per interval, and always at the end of an epoch.
+pub struct PlainMetricsRenderer {
+    interval: Duration,
+    jsonl: Option<Box<dyn Write + Send + Sync>>,
+    metrics_train: Vec<PlainMetric>,
+    metrics_valid: Vec<PlainMetric>,
+    last_render: Option<Instant>,
+}
+
+struct PlainMetric {
+    name: String,
+    formatted: String,
+    value: Option<f64>,
+}
+
+#[derive(Serialize)]
+struct ProgressLine<'a> {
+    split: &'a str,
+    epoch: usize,
+    epoch_total: usize,
+    iteration: usize,
+    items_processed: usize,
+    items_total: usize,
+    metrics: Vec<MetricLine<'a>>,
+}
+
+#[derive(Serialize)]
+struct MetricLine<'a> {
+    name: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value: Option<f64>,
+    formatted: &'a str,
+}
+
+impl Default for PlainMetricsRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PlainMetricsRenderer {
+    /// Create a new plain metrics renderer printing the progress to `stdout`.
+    pub fn new() -> Self {
+        Self {
+            interval: Duration::from_millis(DEFAULT_INTERVAL_MILLIS),
+            jsonl: None,
+            metrics_train: Vec::new(),
+            metrics_valid: Vec::new(),
+            last_render: None,
+        }
+    }
+
+    /// Set the minimum duration between two rendered progress lines.
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Also write the progress and the metrics as one JSON object per line to the given writer,
+    /// for instance a [file](std::fs::File).
+    pub fn with_jsonl<W>(mut self, writer: W) -> Self
+    where
+        W: Write + Send + Sync + 'static,
+    {
+        self.jsonl = Some(Box::new(writer));
+        self
+    }
+
+    fn render(&mut self, split: &str, item: TrainingProgress) {
+        let end_of_epoch = item.progress.items_processed >= item.progress.items_total;
+        let now = Instant::now();
+
+        if let Some(last_render) = self.last_render {
+            if !end_of_epoch && now.duration_since(last_render) < self.interval {
+                return;
+            }
+        }
+        self.last_render = Some(now);
+
+        let metrics = match split {
+            "train" => &self.metrics_train,
+            _ => &self.metrics_valid,
+        };
+
+        let mut line = format!(
+            "[{split}] epoch {}/{} iteration {} items {}/{}",
+            item.epoch,
+            item.epoch_total,
+            item.iteration,
+            item.progress.items_processed,
+            item.progress.items_total
+        );
+        for metric in metrics.iter() {
+            line += &format!(" | {}: {}", metric.name, metric.formatted);
+        }
+        println!("{line}");
+
+        if let Some(writer) = self.jsonl.as_mut() {
+            let progress = ProgressLine {
+                split,
+                epoch: item.epoch,
+                epoch_total: item.epoch_total,
+                iteration: item.iteration,
+                items_processed: item.progress.items_processed,
+                items_total: item.progress.items_total,
+                metrics: metrics
+                    .iter()
+                    .map(|metric| MetricLine {
+                        name: &metric.name,
+                        value: metric.value,
+                        formatted: &metric.formatted,
+                    })
+                    .collect(),
+            };
+
+            let result = serde_json::to_writer(&mut *writer, &progress)
+                .map_err(std::io::Error::from)
+                .and_then(|_| writeln!(writer))
+                .and_then(|_| writer.flush());
+
+            if let Err(err) = result {
+                log::warn!("Failed to write the metrics to the JSON Lines stream: {err}");
+            }
+        }
+    }
+}
+
+fn update(metrics: &mut Vec<PlainMetric>, state: MetricState) {
+    let (entry, value) = match state {
+        MetricState::Generic(entry) => (entry, None),
+        MetricState::Numeric(entry, value) => (entry, Some(value)),
+    };
+
+    match metrics.iter_mut().find(|metric| metric.name == entry.name) {
+        Some(metric) => {
+            metric.formatted = entry.formatted;
+            metric.value = value;
+        }
+        None => metrics.push(PlainMetric {
+            name: entry.name,
+            formatted: entry.formatted,
+            value,
+        }),
+    }
+}
+
+impl MetricsRenderer for PlainMetricsRenderer {
+    fn update_train(&mut self, state: MetricState) {
+        update(&mut self.metrics_train, state);
+    }
+
+    fn update_valid(&mut self, state: MetricState) {
+        update(&mut self.metrics_valid, state);
+    }
+
+    fn render_train(&mut self, item: TrainingProgress) {
+        self.render("train", item);
+    }
+
+    fn render_valid(&mut self, item: TrainingProgress) {
+        self.render("valid", item);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metric::MetricEntry;
+    use burn_core::data::dataloader::Progress;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn progress(items_processed: usize) -> TrainingProgress {
+        TrainingProgress {
+            progress: Progress::new(items_processed, 10),
+            epoch: 1,
+            epoch_total: 2,
+            iteration: items_processed,
+        }
+    }
+
+    #[test]
+    fn test_jsonl_stream_contains_progress_and_metrics() {
+        let buffer = SharedBuffer::default();
+        let mut renderer = PlainMetricsRenderer::new().with_jsonl(buffer.clone());
+
+        renderer.update_train(MetricState::Numeric(
+            MetricEntry::new("Loss".into(), "0.500".into(), "0.5".into()),
+            0.5,
+        ));
+        renderer.render_train(progress(1));
+        // Throttled, since the interval didn't elapse.
+        renderer.render_train(progress(2));
+        // Always rendered at the end of the epoch.
+        renderer.render_train(progress(10));
+
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        let lines = output.lines().collect::<Vec<_>>();
+
+        assert_eq!(lines.len(), 2);
+        assert_eq!(
+            lines[0],
+            r#"{"split":"train","epoch":1,"epoch_total":2,"iteration":1,"items_processed":1,"items_total":10,"metrics":[{"name":"Loss","value":0.5,"formatted":"0.500"}]}"#
+        );
+    }
+}
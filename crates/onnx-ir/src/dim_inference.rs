@@ -21,6 +21,7 @@ pub fn dim_inference(node: &mut Node) {
         NodeType::Cast => cast_update_outputs(node),
         NodeType::Clip => same_as_input(node),
         NodeType::Concat => concat_update_outputs(node),
+        NodeType::ConcatFromSequence => concat_from_sequence_update_outputs(node),
         NodeType::Constant => constant_update_outputs(node),
         NodeType::ConstantOfShape => constant_of_shape_update_output(node),
         NodeType::Conv1d => conv1d_update_outputs(node),
@@ -56,6 +57,7 @@ pub fn dim_inference(node: &mut Node) {
         NodeType::Min => same_as_input_broadcast(node),
         NodeType::Mul => same_as_input(node),
         NodeType::Neg => same_as_input(node),
+        NodeType::NonMaxSuppression => non_max_suppression_update_outputs(node),
         NodeType::Not => same_as_input(node),
         NodeType::Pad => same_as_input(node),
         NodeType::PRelu => same_as_input_broadcast(node),
@@ -74,12 +76,16 @@ pub fn dim_inference(node: &mut Node) {
         NodeType::Relu => same_as_input(node),
         NodeType::Reshape => reshape_update_outputs(node),
         NodeType::Resize => same_as_input(node),
+        NodeType::RoiAlign => roi_align_update_outputs(node),
+        NodeType::SequenceAt => sequence_at_update_outputs(node),
+        NodeType::SequenceConstruct => sequence_construct_update_outputs(node),
         NodeType::Shape => shape_update_outputs(node),
         NodeType::Sigmoid => same_as_input(node),
         NodeType::Sign => same_as_input(node),
         NodeType::Sin => same_as_input(node),
         NodeType::Slice => same_as_input(node),
         NodeType::Softmax => same_as_input(node),
+        NodeType::SplitToSequence => split_to_sequence_update_outputs(node),
         NodeType::Squeeze => squeeze_update_output(node),
         NodeType::Sqrt => same_as_input(node),
         NodeType::Sub => same_as_input_broadcast(node),
@@ -347,9 +353,21 @@ fn reshape_update_outputs(node: &mut Node) {
         _ => panic!("Reshape: invalid output types"),
     };
 
-    if let Some(shape) = shape {
+    // When the shape is only known at runtime, e.g. computed from the batch size, the output rank
+    // is the length of the shape
+    let dim = match shape {
+        Some(shape) => Some(shape.len()),
+        None if node.inputs.len() == 2 => match &node.inputs[1].ty {
+            ArgType::Shape(rank) => Some(*rank),
+            ArgType::Tensor(tensor) => tensor.shape.as_ref().map(|shape| shape[0]),
+            _ => panic!("Reshape: invalid shape input types"),
+        },
+        None => None,
+    };
+
+    if let Some(dim) = dim {
         node.outputs[0].ty = ArgType::Tensor(TensorType {
-            dim: shape.len(),
+            dim,
             shape: None, // shape is calculated at runtime
             ..output
         });
@@ -407,6 +425,99 @@ fn argmax_update_outputs(node: &mut Node) {
     });
 }
 
+/// The selected indices are `[num_selected_indices, 3]`, with a data dependent first dimension.
+fn non_max_suppression_update_outputs(node: &mut Node) {
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        dim: 2,
+        shape: None,
+        elem_type: ElementType::Int64,
+    });
+}
+
+/// RoiAlign outputs a feature map of shape [num_rois, channels, output_height, output_width]
+fn roi_align_update_outputs(node: &mut Node) {
+    let tensor = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.clone(),
+        _ => panic!("RoiAlign: invalid input type"),
+    };
+
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        dim: 4,
+        shape: None,
+        elem_type: tensor.elem_type,
+    });
+}
+
+/// The sequence holds tensors with the type of the first input
+fn sequence_construct_update_outputs(node: &mut Node) {
+    let tensor = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.clone(),
+        _ => panic!("SequenceConstruct: invalid input type"),
+    };
+
+    node.outputs[0].ty = ArgType::Sequence(TensorType {
+        shape: None,
+        ..tensor
+    });
+}
+
+/// The output is a tensor of the sequence
+fn sequence_at_update_outputs(node: &mut Node) {
+    let tensor = match &node.inputs[0].ty {
+        ArgType::Sequence(tensor) => tensor.clone(),
+        _ => panic!("SequenceAt: invalid input type"),
+    };
+
+    node.outputs[0].ty = ArgType::Tensor(tensor);
+}
+
+/// The tensors of the sequence are concatenated, or stacked along a new axis
+fn concat_from_sequence_update_outputs(node: &mut Node) {
+    let tensor = match &node.inputs[0].ty {
+        ArgType::Sequence(tensor) => tensor.clone(),
+        _ => panic!("ConcatFromSequence: invalid input type"),
+    };
+
+    let new_axis = node
+        .attrs
+        .get("new_axis")
+        .map(|value| value.clone().into_i64())
+        .unwrap_or(0);
+
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        dim: tensor.dim + new_axis as usize,
+        shape: None,
+        elem_type: tensor.elem_type,
+    });
+}
+
+/// The tensors of the sequence have the rank of the input, minus the split axis when it is
+/// split in chunks of size 1 without keeping the dimension
+fn split_to_sequence_update_outputs(node: &mut Node) {
+    let tensor = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.clone(),
+        _ => panic!("SplitToSequence: invalid input type"),
+    };
+
+    let keepdims = node
+        .attrs
+        .get("keepdims")
+        .map(|value| value.clone().into_i64())
+        .unwrap_or(1);
+
+    let dim = if node.inputs.len() == 1 && keepdims == 0 {
+        tensor.dim - 1
+    } else {
+        tensor.dim
+    };
+
+    node.outputs[0].ty = ArgType::Sequence(TensorType {
+        dim,
+        shape: None,
+        elem_type: tensor.elem_type,
+    });
+}
+
 /// Update the output tensor dimension
 fn squeeze_update_output(node: &mut Node) {
     let axes = if node.inputs.len() == 2 {
@@ -566,9 +677,21 @@ fn expand_update_outputs(node: &mut Node) {
         _ => panic!("Expand: invalid output types"),
     };
 
-    if let Some(shape) = shape {
+    // When the shape is only known at runtime, e.g. computed from the batch size, the output rank
+    // is the length of the shape
+    let dim = match shape {
+        Some(shape) => Some(shape.len()),
+        None if node.inputs.len() == 2 => match &node.inputs[1].ty {
+            ArgType::Shape(rank) => Some(*rank),
+            ArgType::Tensor(tensor) => tensor.shape.as_ref().map(|shape| shape[0]),
+            _ => panic!("Reshape: invalid shape input types"),
+        },
+        None => None,
+    };
+
+    if let Some(dim) = dim {
         node.outputs[0].ty = ArgType::Tensor(TensorType {
-            dim: shape.len(),
+            dim,
             shape: None, // shape is calculated at runtime
             ..output
         });
@@ -913,6 +1036,7 @@ fn set_broadcasting_output_shape(node: &mut Node) {
                 }
                 *current_out_dim = *s;
             }
+            ArgType::Sequence(_) => panic!("Sequences can't be broadcast"),
         }
     }
 
@@ -936,5 +1060,6 @@ fn set_broadcasting_output_shape(node: &mut Node) {
             }
             *s = out_shape[0];
         }
+        ArgType::Sequence(_) => panic!("Output is a Sequence, which can't be broadcast"),
     }
 }
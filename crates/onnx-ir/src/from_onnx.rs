@@ -5,6 +5,7 @@ use std::{
 };
 
 use crate::node_remap::remap_node_type;
+use crate::normalize::normalize_node;
 
 use super::{
     coalesce::coalesce,
@@ -18,18 +19,25 @@ use super::ir::{ArgType, Argument, Node, NodeType};
 
 use protobuf::Message;
 
-const LIFT_CONSTANTS_FOR_NODE_TYPES: [NodeType; 12] = [
+const LIFT_CONSTANTS_FOR_NODE_TYPES: [NodeType; 19] = [
     NodeType::BatchNormalization,
     NodeType::Clip,
     NodeType::Conv1d,
     NodeType::Conv2d,
     NodeType::Dropout,
     NodeType::Expand,
+    NodeType::NonMaxSuppression,
     NodeType::Reshape,
     NodeType::Resize,
     NodeType::Unsqueeze,
+    NodeType::ReduceMax,
+    NodeType::ReduceMean,
+    NodeType::ReduceMin,
+    NodeType::ReduceProd,
     NodeType::ReduceSum,
+    NodeType::SequenceAt,
     NodeType::Slice,
+    NodeType::SplitToSequence,
     NodeType::Squeeze,
 ];
 
@@ -208,6 +216,14 @@ impl OnnxGraphBuilder {
             &model_proto.graph.initializer,
         );
 
+        // The version of the default ONNX domain, the newest one when it isn't specified
+        let opset_version = model_proto
+            .opset_import
+            .iter()
+            .find(|opset| opset.domain.is_empty() || opset.domain == "ai.onnx")
+            .map(|opset| opset.version)
+            .unwrap_or(i64::MAX);
+
         let mut node_iter = model_proto.graph.node.iter().peekable();
 
         while let Some(node_proto) = node_iter.next() {
@@ -218,6 +234,7 @@ impl OnnxGraphBuilder {
             coalesce(&mut node, &mut node_iter, &graph_data);
             self.handle_identity(&mut node, &graph_data);
             self.check_constants(&mut node, &graph_data);
+            normalize_node(&mut node, opset_version);
             // NOTE: potential start of custom functions
             // can filter, coalesce, or modify the nodes here
             // args : node, peek_iter, graph_data
@@ -74,6 +74,8 @@ pub enum ArgType {
     Scalar(ElementType),
     Shape(Dim),
     Tensor(TensorType),
+    /// A sequence of tensors with the same type.
+    Sequence(TensorType),
 }
 
 /// The type of an attribute.
@@ -142,6 +144,7 @@ impl ArgType {
             ArgType::Scalar(_) => 0,
             ArgType::Shape(_) => 1,
             ArgType::Tensor(t) => t.dim,
+            ArgType::Sequence(t) => t.dim,
         }
     }
 
@@ -151,6 +154,7 @@ impl ArgType {
             ArgType::Scalar(s) => s,
             ArgType::Shape(_) => panic!("ArgType::Shape has no ElementType"),
             ArgType::Tensor(t) => &t.elem_type,
+            ArgType::Sequence(t) => &t.elem_type,
         }
     }
 }
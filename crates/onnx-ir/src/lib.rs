@@ -3,6 +3,7 @@ mod dim_inference;
 mod from_onnx;
 pub mod ir;
 mod node_remap;
+mod normalize;
 mod proto_conversion;
 mod protos;
 pub mod util;
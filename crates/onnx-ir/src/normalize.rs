@@ -0,0 +1,179 @@
+use super::ir::{ArgType, AttributeValue, Node, NodeType};
+
+/// The opset from which Softmax and LogSoftmax apply on a single axis instead of coercing the
+/// input to 2D.
+const SOFTMAX_SINGLE_AXIS_OPSET: i64 = 13;
+
+/// The opset from which RoiAlign has a `coordinate_transformation_mode` attribute, which defaults
+/// to `half_pixel` instead of the previous `output_half_pixel` behavior.
+const ROI_ALIGN_COORDINATE_MODE_OPSET: i64 = 16;
+
+/// Rewrite the variants of the ops introduced by newer opsets into the canonical form handled by
+/// the dim inference and the code generation.
+///
+/// Models exported at different opsets then produce the same nodes, e.g. the axes of the reduce
+/// ops are an input since opset 18 (13 for ReduceSum, Squeeze and Unsqueeze) and are moved back
+/// to the `axes` attribute when they are constant.
+///
+/// Needs to be called after constant lifting to ensure that the constant input values exist.
+pub fn normalize_node(node: &mut Node, opset_version: i64) {
+    match node.node_type {
+        NodeType::ReduceMax
+        | NodeType::ReduceMin
+        | NodeType::ReduceMean
+        | NodeType::ReduceProd
+        | NodeType::ReduceSum
+        | NodeType::Squeeze
+        | NodeType::Unsqueeze => axes_input_to_attribute(node),
+        NodeType::Softmax | NodeType::LogSoftmax if opset_version < SOFTMAX_SINGLE_AXIS_OPSET => {
+            coerced_axis_to_attribute(node)
+        }
+        NodeType::RoiAlign if opset_version < ROI_ALIGN_COORDINATE_MODE_OPSET => {
+            node.attrs
+                .entry("coordinate_transformation_mode".to_string())
+                .or_insert_with(|| AttributeValue::String("output_half_pixel".to_string()));
+        }
+        _ => {}
+    }
+}
+
+/// Move the constant `axes` input to the `axes` attribute.
+///
+/// Axes only known at runtime are kept as an input.
+fn axes_input_to_attribute(node: &mut Node) {
+    if node.attrs.contains_key("axes") || node.inputs.len() != 2 {
+        return;
+    }
+
+    let Some(axes) = node.inputs[1].value.clone() else {
+        return;
+    };
+
+    log::debug!("moving the axes input of {} to an attribute", node.name);
+    node.attrs
+        .insert("axes".to_string(), AttributeValue::Int64s(axes.into_i64s()));
+    node.inputs.truncate(1);
+}
+
+/// Before opset 13, Softmax and LogSoftmax coerce the input to 2D at the axis (Default: 1), which
+/// is the same as applying them on the axis when it is the last dimension.
+fn coerced_axis_to_attribute(node: &mut Node) {
+    let rank = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.dim as i64,
+        _ => return,
+    };
+
+    let mut axis = node
+        .attrs
+        .get("axis")
+        .map(|value| value.clone().into_i64())
+        .unwrap_or(1);
+
+    if axis < 0 {
+        axis += rank;
+    }
+
+    if axis != rank - 1 {
+        panic!(
+            "{}: the input is coerced to 2D before opset {SOFTMAX_SINGLE_AXIS_OPSET}, which is \
+             only supported on the last dimension (got axis {axis} for rank {rank})",
+            node.node_type
+        );
+    }
+
+    node.attrs
+        .insert("axis".to_string(), AttributeValue::Int64(axis));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::{Argument, Data, ElementType, TensorType};
+
+    fn node(node_type: NodeType, dim: usize, axes: Option<Vec<i64>>) -> Node {
+        let tensor = ArgType::Tensor(TensorType {
+            elem_type: ElementType::Float32,
+            dim,
+            shape: None,
+        });
+        let mut inputs = vec![Argument {
+            ty: tensor.clone(),
+            ..Argument::new("input".to_string())
+        }];
+
+        if let Some(axes) = axes {
+            inputs.push(Argument {
+                ty: ArgType::Tensor(TensorType {
+                    elem_type: ElementType::Int64,
+                    dim: 1,
+                    shape: Some(vec![axes.len()]),
+                }),
+                value: Some(Data::Int64s(axes)),
+                ..Argument::new("axes".to_string())
+            });
+        }
+
+        Node {
+            node_type,
+            name: "node".to_string(),
+            inputs,
+            outputs: vec![Argument {
+                ty: tensor,
+                ..Argument::new("output".to_string())
+            }],
+            attrs: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_axes_input_to_attribute() {
+        let mut node = node(NodeType::ReduceMean, 3, Some(vec![1]));
+
+        normalize_node(&mut node, 18);
+
+        assert_eq!(node.inputs.len(), 1);
+        assert_eq!(node.attrs["axes"].clone().into_i64s(), vec![1]);
+    }
+
+    #[test]
+    fn test_runtime_axes_should_stay_an_input() {
+        let mut node = node(NodeType::Squeeze, 3, Some(vec![1]));
+        node.inputs[1].value = None;
+
+        normalize_node(&mut node, 13);
+
+        assert_eq!(node.inputs.len(), 2);
+        assert!(!node.attrs.contains_key("axes"));
+    }
+
+    #[test]
+    fn test_coerced_softmax_axis() {
+        let mut node = node(NodeType::Softmax, 2, None);
+
+        normalize_node(&mut node, 11);
+
+        assert_eq!(node.attrs["axis"].clone().into_i64(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "only supported on the last dimension")]
+    fn test_coerced_softmax_axis_should_be_last() {
+        let mut node = node(NodeType::Softmax, 3, None);
+
+        normalize_node(&mut node, 11);
+    }
+
+    #[test]
+    fn test_roi_align_default_coordinate_mode() {
+        let mut node = node(NodeType::RoiAlign, 4, None);
+
+        normalize_node(&mut node, 10);
+
+        assert_eq!(
+            node.attrs["coordinate_transformation_mode"]
+                .clone()
+                .into_string(),
+            "output_half_pixel"
+        );
+    }
+}
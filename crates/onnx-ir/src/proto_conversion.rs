@@ -214,6 +214,17 @@ fn to_string_vec(bytes: Vec<Vec<u8>>) -> Vec<String> {
     bytes.iter().map(|b| to_string(b.clone())).collect()
 }
 
+fn convert_elem_type(elem_type: i32) -> Result<ElementType, ParseError> {
+    match DataType::from_i32(elem_type).unwrap() {
+        DataType::FLOAT => Ok(ElementType::Float32),
+        DataType::INT32 => Ok(ElementType::Int32),
+        DataType::INT64 => Ok(ElementType::Int64),
+        DataType::DOUBLE => Ok(ElementType::Float64),
+        DataType::BOOL => Ok(ElementType::Bool),
+        _ => Err(ParseError::VariantNotFound),
+    }
+}
+
 fn convert_shape(shape: Vec<i64>) -> Vec<usize> {
     shape.iter().map(|s| *s as usize).collect()
 }
@@ -225,22 +236,33 @@ impl TryFrom<ValueInfoProto> for Argument {
         let name = value.name.clone();
         let proto_type = value.type_.unwrap();
 
+        if proto_type.has_sequence_type() {
+            let elem_proto = proto_type.sequence_type().elem_type.clone().unwrap();
+            if !elem_proto.has_tensor_type() {
+                panic!("Unsupported sequence element type {:?}", elem_proto);
+            }
+
+            // The tensors of a sequence can have different shapes, only their rank is kept
+            let tensor_type = TensorType {
+                dim: elem_proto.tensor_type().shape.dim.len(),
+                elem_type: convert_elem_type(elem_proto.tensor_type().elem_type)?,
+                shape: None,
+            };
+
+            return Ok(Argument {
+                ty: ArgType::Sequence(tensor_type),
+                name,
+                value: None,
+                passed: false,
+            });
+        }
+
         if !proto_type.has_tensor_type() {
             panic!("Unsupported argument type {:?}", proto_type);
         }
 
         let tensor_proto = proto_type.tensor_type();
-
-        let elem_type = match DataType::from_i32(tensor_proto.elem_type).unwrap() {
-            DataType::FLOAT => ElementType::Float32,
-            DataType::INT32 => ElementType::Int32,
-            DataType::INT64 => ElementType::Int64,
-            DataType::DOUBLE => ElementType::Float64,
-            DataType::BOOL => ElementType::Bool,
-            _ => {
-                return Err(ParseError::VariantNotFound);
-            }
-        };
+        let elem_type = convert_elem_type(tensor_proto.elem_type)?;
 
         let ty = if tensor_proto.shape.dim.is_empty() {
             // tensor_proto describes a scalar
@@ -250,14 +272,14 @@ impl TryFrom<ValueInfoProto> for Argument {
             let tensor_type = TensorType {
                 dim: tensor_proto.shape.dim.len(),
                 elem_type,
-                shape: Some(
-                    tensor_proto
-                        .shape
-                        .dim
-                        .iter()
-                        .map(|x| x.dim_value() as Dim)
-                        .collect(),
-                ),
+                // Symbolic dims (e.g. the batch size or sequence length) only have a name and
+                // are known at runtime, so the shape isn't static when one of them is present
+                shape: tensor_proto
+                    .shape
+                    .dim
+                    .iter()
+                    .map(|x| x.has_dim_value().then_some(x.dim_value() as Dim))
+                    .collect(),
             };
 
             ArgType::Tensor(tensor_type)
@@ -81,6 +81,7 @@ pub fn run<B: AutodiffBackend>(device: B::Device) {
             Split::Valid,
             StoppingCondition::NoImprovementSince { n_epochs: 1 },
         ))
+        .restore_best_model::<LossMetric<B>>(Aggregate::Mean, Direction::Lowest, Split::Valid)
         .devices(vec![device.clone()])
         .num_epochs(config.num_epochs)
         .summary()
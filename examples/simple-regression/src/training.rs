@@ -6,7 +6,10 @@ use burn::{
     prelude::*,
     record::{CompactRecorder, NoStdTrainingRecorder},
     tensor::backend::AutodiffBackend,
-    train::{metric::LossMetric, LearnerBuilder},
+    train::{
+        metric::{LossMetric, MaeMetric, R2Metric},
+        LearnerBuilder,
+    },
 };
 
 #[derive(Config)]
@@ -68,6 +71,8 @@ pub fn run<B: AutodiffBackend>(artifact_dir: &str, device: B::Device) {
     let learner = LearnerBuilder::new(artifact_dir)
         .metric_train_numeric(LossMetric::new())
         .metric_valid_numeric(LossMetric::new())
+        .metric_valid_numeric(MaeMetric::new())
+        .metric_valid_numeric(R2Metric::new())
         .with_file_checkpointer(CompactRecorder::new())
         .devices(vec![device.clone()])
         .num_epochs(config.num_epochs)